@@ -14,6 +14,7 @@
       in {
         devShells.default = pkgs.mkShell {
           buildInputs = [
+            pkgs.flatbuffers
             pkgs.markdown-toc
             pkgs.mermaid-cli
             pkgs.plantuml
@@ -19,6 +19,105 @@ use crate::dataset::*;
 use crate::formats::*;
 use crate::resource::*;
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Union variant that is not known to this version of the schemas, preserved verbatim
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum UnknownVariant {
+    /// Read from a manifest, carrying the discriminator value and the whole object
+    Json {
+        tag: String,
+        value: serde_json::Value,
+    },
+    /// Read from a flatbuffer, carrying the union type, the bytes of the table along with everything it refers to, and
+    /// the location of the table in them
+    Flatbuffers { tag: u8, bytes: Vec<u8>, loc: usize },
+}
+
+/// Enum value that is not known to this version of the schemas, preserved verbatim
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum UnknownEnumValue {
+    /// Read from a manifest
+    Json(String),
+    /// Read from a flatbuffer
+    Flatbuffers(i64),
+}
+
+/// Marks a required field of a builder that was not set yet
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Unset;
+
+/// Value of a `sensitive` string field, e.g. a password, that `Debug` prints as `***`
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct Sensitive<T>(T);
+
+impl<T> Sensitive<T> {
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Gives access to the underlying value, which should never be logged
+    pub fn expose(&self) -> &T {
+        &self.0
+    }
+
+    pub fn expose_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Sensitive<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T> std::fmt::Debug for Sensitive<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("***")
+    }
+}
+
+/// Implemented by DTOs that hold `sensitive` fields to get a copy that is safe to log or serialize
+pub trait Redact {
+    /// Returns a copy with every secret string replaced by `***`
+    fn redact(&self) -> Self;
+}
+
+impl Redact for String {
+    fn redact(&self) -> Self {
+        "***".to_string()
+    }
+}
+
+impl<T: Redact> Redact for Sensitive<T> {
+    fn redact(&self) -> Self {
+        Self(self.0.redact())
+    }
+}
+
+impl<T: Redact> Redact for Option<T> {
+    fn redact(&self) -> Self {
+        self.as_ref().map(Redact::redact)
+    }
+}
+
+impl<T: Redact> Redact for Vec<T> {
+    fn redact(&self) -> Self {
+        self.iter().map(Redact::redact).collect()
+    }
+}
+
+impl<T: Redact> Redact for Box<T> {
+    fn redact(&self) -> Self {
+        Box::new((**self).redact())
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // auth
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -52,6 +151,67 @@ pub mod auth {
     static ACCOUNT_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(ACCOUNT_SCHEMA_STR));
 
+    /// Builder for [`Account`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AccountBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl Account {
+        pub fn builder() -> AccountBuilder<Unset, Unset> {
+            AccountBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> AccountBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> AccountBuilder<resource::ResourceHeadersInput, TSpec> {
+            AccountBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> AccountBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the resource.
+        pub fn spec(
+            self,
+            v: impl Into<auth::AccountSpecInput>,
+        ) -> AccountBuilder<THeaders, auth::AccountSpecInput> {
+            AccountBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl AccountBuilder<resource::ResourceHeadersInput, auth::AccountSpecInput> {
+        pub fn build(self) -> Account {
+            Account {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl Redact for Account {
+        fn redact(&self) -> Self {
+            Self {
+                headers: self.headers.clone(),
+                spec: self.spec.redact(),
+            }
+        }
+    }
+
     /// Link to an account.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountHandle
@@ -66,6 +226,71 @@ pub mod auth {
     }
     impl IntoResourceRef for AccountHandle {}
 
+    /// Builder for [`AccountHandle`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AccountHandleBuilder<TId = Unset, TDid = Unset, TName = Unset> {
+        id: TId,
+        did: TDid,
+        name: TName,
+    }
+
+    impl AccountHandle {
+        pub fn builder() -> AccountHandleBuilder<Unset, Unset, Unset> {
+            AccountHandleBuilder {
+                id: Unset,
+                did: Unset,
+                name: Unset,
+            }
+        }
+    }
+
+    impl<TDid, TName> AccountHandleBuilder<Unset, TDid, TName> {
+        /// ID of the account resource.
+        pub fn id(self, v: impl Into<ResourceID>) -> AccountHandleBuilder<ResourceID, TDid, TName> {
+            AccountHandleBuilder {
+                id: v.into(),
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
+    impl<TId, TName> AccountHandleBuilder<TId, Unset, TName> {
+        /// DID of the account.
+        pub fn did(self, v: impl Into<AccountID>) -> AccountHandleBuilder<TId, AccountID, TName> {
+            AccountHandleBuilder {
+                id: self.id,
+                did: v.into(),
+                name: self.name,
+            }
+        }
+    }
+
+    impl<TId, TDid> AccountHandleBuilder<TId, TDid, Unset> {
+        /// Name of the account.
+        pub fn name(
+            self,
+            v: impl Into<AccountName>,
+        ) -> AccountHandleBuilder<TId, TDid, AccountName> {
+            AccountHandleBuilder {
+                id: self.id,
+                did: self.did,
+                name: v.into(),
+            }
+        }
+    }
+
+    impl AccountHandleBuilder<ResourceID, AccountID, AccountName> {
+        pub fn build(self) -> AccountHandle {
+            AccountHandle {
+                id: self.id,
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
     /// Reference to an account.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountRef
@@ -80,6 +305,53 @@ pub mod auth {
     }
     impl IntoResourceRef for AccountRef {}
 
+    /// Builder for [`AccountRef`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AccountRefBuilder {
+        id: Option<ResourceID>,
+        did: Option<AccountID>,
+        name: Option<AccountName>,
+    }
+
+    impl AccountRef {
+        pub fn builder() -> AccountRefBuilder {
+            AccountRefBuilder {
+                id: None,
+                did: None,
+                name: None,
+            }
+        }
+    }
+
+    impl AccountRefBuilder {
+        /// UUID of the account resource.
+        pub fn id(mut self, v: impl Into<ResourceID>) -> Self {
+            self.id = Some(v.into());
+            self
+        }
+        /// DID of the account.
+        pub fn did(mut self, v: impl Into<AccountID>) -> Self {
+            self.did = Some(v.into());
+            self
+        }
+        /// Name of the account.
+        pub fn name(mut self, v: impl Into<AccountName>) -> Self {
+            self.name = Some(v.into());
+            self
+        }
+    }
+
+    impl AccountRefBuilder {
+        pub fn build(self) -> AccountRef {
+            AccountRef {
+                id: self.id,
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
     /// Predefined account specification.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountSpec
@@ -126,6 +398,101 @@ pub mod auth {
         }
     }
 
+    /// Builder for [`AccountSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AccountSpecBuilder<TEmail = Unset> {
+        did: Option<AccountID>,
+        account_type: Option<auth::AccountType>,
+        display_name: Option<String>,
+        email: TEmail,
+        avatar_url: Option<String>,
+        password: Option<config::Secret>,
+    }
+
+    impl AccountSpec {
+        pub fn builder() -> AccountSpecBuilder<Unset> {
+            AccountSpecBuilder {
+                did: None,
+                account_type: None,
+                display_name: None,
+                email: Unset,
+                avatar_url: None,
+                password: None,
+            }
+        }
+    }
+
+    impl AccountSpecBuilder<Unset> {
+        /// Email address of the account.
+        pub fn email(self, v: impl Into<String>) -> AccountSpecBuilder<String> {
+            AccountSpecBuilder {
+                did: self.did,
+                account_type: self.account_type,
+                display_name: self.display_name,
+                email: v.into(),
+                avatar_url: self.avatar_url,
+                password: self.password,
+            }
+        }
+    }
+
+    impl<TEmail> AccountSpecBuilder<TEmail> {
+        /// DID associated with the account by ODF or an external system
+        pub fn did(mut self, v: impl Into<AccountID>) -> Self {
+            self.did = Some(v.into());
+            self
+        }
+        /// Type of the account.
+        ///
+        /// Defaults to: "User"
+        pub fn account_type(mut self, v: impl Into<auth::AccountType>) -> Self {
+            self.account_type = Some(v.into());
+            self
+        }
+        /// Human-friendly display name.
+        pub fn display_name(mut self, v: impl Into<String>) -> Self {
+            self.display_name = Some(v.into());
+            self
+        }
+        /// URL of the account's avatar image.
+        pub fn avatar_url(mut self, v: impl Into<String>) -> Self {
+            self.avatar_url = Some(v.into());
+            self
+        }
+        /// Password for local authentication. Absent for SSO or DID-based accounts.
+        pub fn password(mut self, v: impl Into<config::Secret>) -> Self {
+            self.password = Some(v.into());
+            self
+        }
+    }
+
+    impl AccountSpecBuilder<String> {
+        pub fn build(self) -> AccountSpec {
+            AccountSpec {
+                did: self.did,
+                account_type: self.account_type,
+                display_name: self.display_name,
+                email: self.email,
+                avatar_url: self.avatar_url,
+                password: self.password,
+            }
+        }
+    }
+
+    impl Redact for AccountSpec {
+        fn redact(&self) -> Self {
+            Self {
+                did: self.did.clone(),
+                account_type: self.account_type.clone(),
+                display_name: self.display_name.clone(),
+                email: self.email.clone(),
+                avatar_url: self.avatar_url.clone(),
+                password: self.password.redact(),
+            }
+        }
+    }
+
     /// Predefined account specification.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountSpecInput
@@ -172,6 +539,101 @@ pub mod auth {
         }
     }
 
+    /// Builder for [`AccountSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AccountSpecInputBuilder<TEmail = Unset> {
+        did: Option<AccountID>,
+        account_type: Option<auth::AccountType>,
+        display_name: Option<String>,
+        email: TEmail,
+        avatar_url: Option<String>,
+        password: Option<config::Secret>,
+    }
+
+    impl AccountSpecInput {
+        pub fn builder() -> AccountSpecInputBuilder<Unset> {
+            AccountSpecInputBuilder {
+                did: None,
+                account_type: None,
+                display_name: None,
+                email: Unset,
+                avatar_url: None,
+                password: None,
+            }
+        }
+    }
+
+    impl AccountSpecInputBuilder<Unset> {
+        /// Email address of the account.
+        pub fn email(self, v: impl Into<String>) -> AccountSpecInputBuilder<String> {
+            AccountSpecInputBuilder {
+                did: self.did,
+                account_type: self.account_type,
+                display_name: self.display_name,
+                email: v.into(),
+                avatar_url: self.avatar_url,
+                password: self.password,
+            }
+        }
+    }
+
+    impl<TEmail> AccountSpecInputBuilder<TEmail> {
+        /// DID associated with the account by ODF or an external system
+        pub fn did(mut self, v: impl Into<AccountID>) -> Self {
+            self.did = Some(v.into());
+            self
+        }
+        /// Type of the account.
+        ///
+        /// Defaults to: "User"
+        pub fn account_type(mut self, v: impl Into<auth::AccountType>) -> Self {
+            self.account_type = Some(v.into());
+            self
+        }
+        /// Human-friendly display name.
+        pub fn display_name(mut self, v: impl Into<String>) -> Self {
+            self.display_name = Some(v.into());
+            self
+        }
+        /// URL of the account's avatar image.
+        pub fn avatar_url(mut self, v: impl Into<String>) -> Self {
+            self.avatar_url = Some(v.into());
+            self
+        }
+        /// Password for local authentication. Absent for SSO or DID-based accounts.
+        pub fn password(mut self, v: impl Into<config::Secret>) -> Self {
+            self.password = Some(v.into());
+            self
+        }
+    }
+
+    impl AccountSpecInputBuilder<String> {
+        pub fn build(self) -> AccountSpecInput {
+            AccountSpecInput {
+                did: self.did,
+                account_type: self.account_type,
+                display_name: self.display_name,
+                email: self.email,
+                avatar_url: self.avatar_url,
+                password: self.password,
+            }
+        }
+    }
+
+    impl Redact for AccountSpecInput {
+        fn redact(&self) -> Self {
+            Self {
+                did: self.did.clone(),
+                account_type: self.account_type.clone(),
+                display_name: self.display_name.clone(),
+                email: self.email.clone(),
+                avatar_url: self.avatar_url.clone(),
+                password: self.password.redact(),
+            }
+        }
+    }
+
     /// Represents the type of an account.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountType
@@ -197,6 +659,74 @@ pub mod auth {
         pub value: serde_json::Value,
     }
 
+    /// Builder for [`Attribute`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AttributeBuilder<TObject = Unset, TName = Unset, TValue = Unset> {
+        object: TObject,
+        name: TName,
+        value: TValue,
+    }
+
+    impl Attribute {
+        pub fn builder() -> AttributeBuilder<Unset, Unset, Unset> {
+            AttributeBuilder {
+                object: Unset,
+                name: Unset,
+                value: Unset,
+            }
+        }
+    }
+
+    impl<TName, TValue> AttributeBuilder<Unset, TName, TValue> {
+        /// The resource this attribute is attached to.
+        pub fn object(
+            self,
+            v: impl Into<resource::ResourceHandle>,
+        ) -> AttributeBuilder<resource::ResourceHandle, TName, TValue> {
+            AttributeBuilder {
+                object: v.into(),
+                name: self.name,
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TObject, TValue> AttributeBuilder<TObject, Unset, TValue> {
+        /// Name of the attribute.
+        pub fn name(self, v: impl Into<String>) -> AttributeBuilder<TObject, String, TValue> {
+            AttributeBuilder {
+                object: self.object,
+                name: v.into(),
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TObject, TName> AttributeBuilder<TObject, TName, Unset> {
+        /// Value of the attribute.
+        pub fn value(
+            self,
+            v: impl Into<serde_json::Value>,
+        ) -> AttributeBuilder<TObject, TName, serde_json::Value> {
+            AttributeBuilder {
+                object: self.object,
+                name: self.name,
+                value: v.into(),
+            }
+        }
+    }
+
+    impl AttributeBuilder<resource::ResourceHandle, String, serde_json::Value> {
+        pub fn build(self) -> Attribute {
+            Attribute {
+                object: self.object,
+                name: self.name,
+                value: self.value,
+            }
+        }
+    }
+
     /// A named attribute attached to a resource, used by auth policies for access control decisions.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AttributeInput
@@ -210,6 +740,74 @@ pub mod auth {
         pub value: serde_json::Value,
     }
 
+    /// Builder for [`AttributeInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AttributeInputBuilder<TObject = Unset, TName = Unset, TValue = Unset> {
+        object: TObject,
+        name: TName,
+        value: TValue,
+    }
+
+    impl AttributeInput {
+        pub fn builder() -> AttributeInputBuilder<Unset, Unset, Unset> {
+            AttributeInputBuilder {
+                object: Unset,
+                name: Unset,
+                value: Unset,
+            }
+        }
+    }
+
+    impl<TName, TValue> AttributeInputBuilder<Unset, TName, TValue> {
+        /// The resource this attribute is attached to.
+        pub fn object(
+            self,
+            v: impl Into<resource::ResourceRef>,
+        ) -> AttributeInputBuilder<resource::ResourceRef, TName, TValue> {
+            AttributeInputBuilder {
+                object: v.into(),
+                name: self.name,
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TObject, TValue> AttributeInputBuilder<TObject, Unset, TValue> {
+        /// Name of the attribute e.g. `allowPublicRead`.
+        pub fn name(self, v: impl Into<String>) -> AttributeInputBuilder<TObject, String, TValue> {
+            AttributeInputBuilder {
+                object: self.object,
+                name: v.into(),
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TObject, TName> AttributeInputBuilder<TObject, TName, Unset> {
+        /// Value of the attribute.
+        pub fn value(
+            self,
+            v: impl Into<serde_json::Value>,
+        ) -> AttributeInputBuilder<TObject, TName, serde_json::Value> {
+            AttributeInputBuilder {
+                object: self.object,
+                name: self.name,
+                value: v.into(),
+            }
+        }
+    }
+
+    impl AttributeInputBuilder<resource::ResourceRef, String, serde_json::Value> {
+        pub fn build(self) -> AttributeInput {
+            AttributeInput {
+                object: self.object,
+                name: self.name,
+                value: self.value,
+            }
+        }
+    }
+
     /// A directed relationship between two resources, optionally carrying a typed value.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Relation
@@ -225,6 +823,88 @@ pub mod auth {
         pub object: resource::ResourceHandle,
     }
 
+    /// Builder for [`Relation`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RelationBuilder<TSubject = Unset, TRelation = Unset, TObject = Unset> {
+        subject: TSubject,
+        relation: TRelation,
+        value: Option<serde_json::Value>,
+        object: TObject,
+    }
+
+    impl Relation {
+        pub fn builder() -> RelationBuilder<Unset, Unset, Unset> {
+            RelationBuilder {
+                subject: Unset,
+                relation: Unset,
+                value: None,
+                object: Unset,
+            }
+        }
+    }
+
+    impl<TRelation, TObject> RelationBuilder<Unset, TRelation, TObject> {
+        /// The resource that holds the relation.
+        pub fn subject(
+            self,
+            v: impl Into<resource::ResourceHandle>,
+        ) -> RelationBuilder<resource::ResourceHandle, TRelation, TObject> {
+            RelationBuilder {
+                subject: v.into(),
+                relation: self.relation,
+                value: self.value,
+                object: self.object,
+            }
+        }
+    }
+
+    impl<TSubject, TObject> RelationBuilder<TSubject, Unset, TObject> {
+        /// Name of the relation e.g. `role`, `member`, `owner`.
+        pub fn relation(self, v: impl Into<String>) -> RelationBuilder<TSubject, String, TObject> {
+            RelationBuilder {
+                subject: self.subject,
+                relation: v.into(),
+                value: self.value,
+                object: self.object,
+            }
+        }
+    }
+
+    impl<TSubject, TRelation> RelationBuilder<TSubject, TRelation, Unset> {
+        /// The resource that is the target of the relation.
+        pub fn object(
+            self,
+            v: impl Into<resource::ResourceHandle>,
+        ) -> RelationBuilder<TSubject, TRelation, resource::ResourceHandle> {
+            RelationBuilder {
+                subject: self.subject,
+                relation: self.relation,
+                value: self.value,
+                object: v.into(),
+            }
+        }
+    }
+
+    impl<TSubject, TRelation, TObject> RelationBuilder<TSubject, TRelation, TObject> {
+        /// Optional value associated with the relation e.g. `maintainer` for a `role` relation.
+        pub fn value(mut self, v: impl Into<serde_json::Value>) -> Self {
+            self.value = Some(v.into());
+            self
+        }
+    }
+
+    impl RelationBuilder<resource::ResourceHandle, String, resource::ResourceHandle> {
+        pub fn build(self) -> Relation {
+            Relation {
+                subject: self.subject,
+                relation: self.relation,
+                value: self.value,
+                object: self.object,
+            }
+        }
+    }
+
     /// A directed relationship between two resources, optionally carrying a typed value.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationInput
@@ -240,7 +920,92 @@ pub mod auth {
         pub object: resource::ResourceRef,
     }
 
-    /// Specified relations between resources on which auth policies act upon.
+    /// Builder for [`RelationInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RelationInputBuilder<TSubject = Unset, TRelation = Unset, TObject = Unset> {
+        subject: TSubject,
+        relation: TRelation,
+        value: Option<serde_json::Value>,
+        object: TObject,
+    }
+
+    impl RelationInput {
+        pub fn builder() -> RelationInputBuilder<Unset, Unset, Unset> {
+            RelationInputBuilder {
+                subject: Unset,
+                relation: Unset,
+                value: None,
+                object: Unset,
+            }
+        }
+    }
+
+    impl<TRelation, TObject> RelationInputBuilder<Unset, TRelation, TObject> {
+        /// The resource that holds the relation.
+        pub fn subject(
+            self,
+            v: impl Into<resource::ResourceRef>,
+        ) -> RelationInputBuilder<resource::ResourceRef, TRelation, TObject> {
+            RelationInputBuilder {
+                subject: v.into(),
+                relation: self.relation,
+                value: self.value,
+                object: self.object,
+            }
+        }
+    }
+
+    impl<TSubject, TObject> RelationInputBuilder<TSubject, Unset, TObject> {
+        /// Name of the relation e.g. `role`, `member`, `owner`.
+        pub fn relation(
+            self,
+            v: impl Into<String>,
+        ) -> RelationInputBuilder<TSubject, String, TObject> {
+            RelationInputBuilder {
+                subject: self.subject,
+                relation: v.into(),
+                value: self.value,
+                object: self.object,
+            }
+        }
+    }
+
+    impl<TSubject, TRelation> RelationInputBuilder<TSubject, TRelation, Unset> {
+        /// The resource that is the target of the relation.
+        pub fn object(
+            self,
+            v: impl Into<resource::ResourceRef>,
+        ) -> RelationInputBuilder<TSubject, TRelation, resource::ResourceRef> {
+            RelationInputBuilder {
+                subject: self.subject,
+                relation: self.relation,
+                value: self.value,
+                object: v.into(),
+            }
+        }
+    }
+
+    impl<TSubject, TRelation, TObject> RelationInputBuilder<TSubject, TRelation, TObject> {
+        /// Optional value associated with the relation e.g. `maintainer` for a `role` relation.
+        pub fn value(mut self, v: impl Into<serde_json::Value>) -> Self {
+            self.value = Some(v.into());
+            self
+        }
+    }
+
+    impl RelationInputBuilder<resource::ResourceRef, String, resource::ResourceRef> {
+        pub fn build(self) -> RelationInput {
+            RelationInput {
+                subject: self.subject,
+                relation: self.relation,
+                value: self.value,
+                object: self.object,
+            }
+        }
+    }
+
+    /// Specified relations between resources on which auth policies act upon.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Relations
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -266,6 +1031,58 @@ pub mod auth {
     static RELATIONS_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(RELATIONS_SCHEMA_STR));
 
+    /// Builder for [`Relations`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RelationsBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl Relations {
+        pub fn builder() -> RelationsBuilder<Unset, Unset> {
+            RelationsBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> RelationsBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> RelationsBuilder<resource::ResourceHeadersInput, TSpec> {
+            RelationsBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> RelationsBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the resource.
+        pub fn spec(
+            self,
+            v: impl Into<auth::RelationsSpecInput>,
+        ) -> RelationsBuilder<THeaders, auth::RelationsSpecInput> {
+            RelationsBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl RelationsBuilder<resource::ResourceHeadersInput, auth::RelationsSpecInput> {
+        pub fn build(self) -> Relations {
+            Relations {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
     /// Specifies resource attributes and relations between resources on which auth policies act upon.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationsSpec
@@ -277,6 +1094,58 @@ pub mod auth {
         pub attributes: Vec<auth::Attribute>,
     }
 
+    /// Builder for [`RelationsSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RelationsSpecBuilder<TRelations = Unset, TAttributes = Unset> {
+        relations: TRelations,
+        attributes: TAttributes,
+    }
+
+    impl RelationsSpec {
+        pub fn builder() -> RelationsSpecBuilder<Unset, Unset> {
+            RelationsSpecBuilder {
+                relations: Unset,
+                attributes: Unset,
+            }
+        }
+    }
+
+    impl<TAttributes> RelationsSpecBuilder<Unset, TAttributes> {
+        /// Relations between resources.
+        pub fn relations(
+            self,
+            v: impl Into<Vec<auth::Relation>>,
+        ) -> RelationsSpecBuilder<Vec<auth::Relation>, TAttributes> {
+            RelationsSpecBuilder {
+                relations: v.into(),
+                attributes: self.attributes,
+            }
+        }
+    }
+
+    impl<TRelations> RelationsSpecBuilder<TRelations, Unset> {
+        /// Resource attributes.
+        pub fn attributes(
+            self,
+            v: impl Into<Vec<auth::Attribute>>,
+        ) -> RelationsSpecBuilder<TRelations, Vec<auth::Attribute>> {
+            RelationsSpecBuilder {
+                relations: self.relations,
+                attributes: v.into(),
+            }
+        }
+    }
+
+    impl RelationsSpecBuilder<Vec<auth::Relation>, Vec<auth::Attribute>> {
+        pub fn build(self) -> RelationsSpec {
+            RelationsSpec {
+                relations: self.relations,
+                attributes: self.attributes,
+            }
+        }
+    }
+
     /// Specifies resource attributes and relations between resources on which auth policies act upon.
     ///
     /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationsSpecInput
@@ -287,6 +1156,45 @@ pub mod auth {
         /// Resource attributes.
         pub attributes: Option<Vec<auth::AttributeInput>>,
     }
+
+    /// Builder for [`RelationsSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RelationsSpecInputBuilder {
+        relations: Option<Vec<auth::RelationInput>>,
+        attributes: Option<Vec<auth::AttributeInput>>,
+    }
+
+    impl RelationsSpecInput {
+        pub fn builder() -> RelationsSpecInputBuilder {
+            RelationsSpecInputBuilder {
+                relations: None,
+                attributes: None,
+            }
+        }
+    }
+
+    impl RelationsSpecInputBuilder {
+        /// Relations between resources.
+        pub fn relations(mut self, v: impl Into<Vec<auth::RelationInput>>) -> Self {
+            self.relations = Some(v.into());
+            self
+        }
+        /// Resource attributes.
+        pub fn attributes(mut self, v: impl Into<Vec<auth::AttributeInput>>) -> Self {
+            self.attributes = Some(v.into());
+            self
+        }
+    }
+
+    impl RelationsSpecInputBuilder {
+        pub fn build(self) -> RelationsSpecInput {
+            RelationsSpecInput {
+                relations: self.relations,
+                attributes: self.attributes,
+            }
+        }
+    }
 }
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // config
@@ -302,11 +1210,64 @@ pub mod config {
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub struct Secret {
         /// A secret value in raw or encoded form.
-        pub value: String,
+        pub value: super::Sensitive<String>,
         /// Represents the encoding of the value. Typically will be `jwe` after a raw secret gets encrypted.
         pub content_encoding: Option<String>,
     }
 
+    /// Builder for [`Secret`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SecretBuilder<TValue = Unset> {
+        value: TValue,
+        content_encoding: Option<String>,
+    }
+
+    impl Secret {
+        pub fn builder() -> SecretBuilder<Unset> {
+            SecretBuilder {
+                value: Unset,
+                content_encoding: None,
+            }
+        }
+    }
+
+    impl SecretBuilder<Unset> {
+        /// A secret value in raw or encoded form.
+        pub fn value(self, v: impl Into<String>) -> SecretBuilder<super::Sensitive<String>> {
+            SecretBuilder {
+                value: super::Sensitive::new(v.into()),
+                content_encoding: self.content_encoding,
+            }
+        }
+    }
+
+    impl<TValue> SecretBuilder<TValue> {
+        /// Represents the encoding of the value. Typically will be `jwe` after a raw secret gets encrypted.
+        pub fn content_encoding(mut self, v: impl Into<String>) -> Self {
+            self.content_encoding = Some(v.into());
+            self
+        }
+    }
+
+    impl SecretBuilder<super::Sensitive<String>> {
+        pub fn build(self) -> Secret {
+            Secret {
+                value: self.value,
+                content_encoding: self.content_encoding,
+            }
+        }
+    }
+
+    impl Redact for Secret {
+        fn redact(&self) -> Self {
+            Self {
+                value: self.value.redact(),
+                content_encoding: self.content_encoding.clone(),
+            }
+        }
+    }
+
     /// Defines a set of secrets stored and managed by the ODF node.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSet
@@ -333,6 +1294,67 @@ pub mod config {
     static SECRET_SET_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(SECRET_SET_SCHEMA_STR));
 
+    /// Builder for [`SecretSet`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SecretSetBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl SecretSet {
+        pub fn builder() -> SecretSetBuilder<Unset, Unset> {
+            SecretSetBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> SecretSetBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> SecretSetBuilder<resource::ResourceHeadersInput, TSpec> {
+            SecretSetBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> SecretSetBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the secret set.
+        pub fn spec(
+            self,
+            v: impl Into<config::SecretSetSpecInput>,
+        ) -> SecretSetBuilder<THeaders, config::SecretSetSpecInput> {
+            SecretSetBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl SecretSetBuilder<resource::ResourceHeadersInput, config::SecretSetSpecInput> {
+        pub fn build(self) -> SecretSet {
+            SecretSet {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl Redact for SecretSet {
+        fn redact(&self) -> Self {
+            Self {
+                headers: self.headers.clone(),
+                spec: self.spec.redact(),
+            }
+        }
+    }
+
     /// Defines a set of secrets stored and managed by the ODF node and accessible via embedded sercets provider.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSetSpec
@@ -342,6 +1364,45 @@ pub mod config {
         pub secrets: config::Secrets,
     }
 
+    /// Builder for [`SecretSetSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SecretSetSpecBuilder<TSecrets = Unset> {
+        secrets: TSecrets,
+    }
+
+    impl SecretSetSpec {
+        pub fn builder() -> SecretSetSpecBuilder<Unset> {
+            SecretSetSpecBuilder { secrets: Unset }
+        }
+    }
+
+    impl SecretSetSpecBuilder<Unset> {
+        /// Key value pairs of secrets.
+        pub fn secrets(
+            self,
+            v: impl Into<config::Secrets>,
+        ) -> SecretSetSpecBuilder<config::Secrets> {
+            SecretSetSpecBuilder { secrets: v.into() }
+        }
+    }
+
+    impl SecretSetSpecBuilder<config::Secrets> {
+        pub fn build(self) -> SecretSetSpec {
+            SecretSetSpec {
+                secrets: self.secrets,
+            }
+        }
+    }
+
+    impl Redact for SecretSetSpec {
+        fn redact(&self) -> Self {
+            Self {
+                secrets: self.secrets.redact(),
+            }
+        }
+    }
+
     /// Defines a set of secrets stored and managed by the ODF node and accessible via embedded sercets provider.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSetSpecInput
@@ -351,6 +1412,45 @@ pub mod config {
         pub secrets: config::Secrets,
     }
 
+    /// Builder for [`SecretSetSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SecretSetSpecInputBuilder<TSecrets = Unset> {
+        secrets: TSecrets,
+    }
+
+    impl SecretSetSpecInput {
+        pub fn builder() -> SecretSetSpecInputBuilder<Unset> {
+            SecretSetSpecInputBuilder { secrets: Unset }
+        }
+    }
+
+    impl SecretSetSpecInputBuilder<Unset> {
+        /// Key value pairs of secrets.
+        pub fn secrets(
+            self,
+            v: impl Into<config::Secrets>,
+        ) -> SecretSetSpecInputBuilder<config::Secrets> {
+            SecretSetSpecInputBuilder { secrets: v.into() }
+        }
+    }
+
+    impl SecretSetSpecInputBuilder<config::Secrets> {
+        pub fn build(self) -> SecretSetSpecInput {
+            SecretSetSpecInput {
+                secrets: self.secrets,
+            }
+        }
+    }
+
+    impl Redact for SecretSetSpecInput {
+        fn redact(&self) -> Self {
+            Self {
+                secrets: self.secrets.redact(),
+            }
+        }
+    }
+
     /// Container for key-value secrets. Every key must be a string. Values may be strings with raw unencrypted data or objects that signify the encoding.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Secrets
@@ -359,6 +1459,18 @@ pub mod config {
         pub entries: std::collections::BTreeMap<String, config::Secret>,
     }
 
+    impl Redact for Secrets {
+        fn redact(&self) -> Self {
+            Self {
+                entries: self
+                    .entries
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.redact()))
+                    .collect(),
+            }
+        }
+    }
+
     /// Reference to a value within a `VariableSet` or a `SecretSet`.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueHandle
@@ -381,6 +1493,113 @@ pub mod config {
     }
     impl IntoResourceRef for ValueHandle {}
 
+    /// Builder for [`ValueHandle`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ValueHandleBuilder<TAccount = Unset, TType = Unset, TId = Unset, TName = Unset> {
+        account: TAccount,
+        r#type: TType,
+        id: TId,
+        name: TName,
+        path: Option<String>,
+    }
+
+    impl ValueHandle {
+        pub fn builder() -> ValueHandleBuilder<Unset, Unset, Unset, Unset> {
+            ValueHandleBuilder {
+                account: Unset,
+                r#type: Unset,
+                id: Unset,
+                name: Unset,
+                path: None,
+            }
+        }
+    }
+
+    impl<TType, TId, TName> ValueHandleBuilder<Unset, TType, TId, TName> {
+        /// Account that owns the target resource.
+        pub fn account(
+            self,
+            v: impl Into<auth::AccountHandle>,
+        ) -> ValueHandleBuilder<auth::AccountHandle, TType, TId, TName> {
+            ValueHandleBuilder {
+                account: v.into(),
+                r#type: self.r#type,
+                id: self.id,
+                name: self.name,
+                path: self.path,
+            }
+        }
+    }
+
+    impl<TAccount, TId, TName> ValueHandleBuilder<TAccount, Unset, TId, TName> {
+        /// Type URI of the target resource.
+        pub fn r#type(
+            self,
+            v: impl Into<TypeUri>,
+        ) -> ValueHandleBuilder<TAccount, TypeUri, TId, TName> {
+            ValueHandleBuilder {
+                account: self.account,
+                r#type: v.into(),
+                id: self.id,
+                name: self.name,
+                path: self.path,
+            }
+        }
+    }
+
+    impl<TAccount, TType, TName> ValueHandleBuilder<TAccount, TType, Unset, TName> {
+        /// ID of the resource within a node.
+        pub fn id(
+            self,
+            v: impl Into<ResourceID>,
+        ) -> ValueHandleBuilder<TAccount, TType, ResourceID, TName> {
+            ValueHandleBuilder {
+                account: self.account,
+                r#type: self.r#type,
+                id: v.into(),
+                name: self.name,
+                path: self.path,
+            }
+        }
+    }
+
+    impl<TAccount, TType, TId> ValueHandleBuilder<TAccount, TType, TId, Unset> {
+        /// Name of a resource.
+        pub fn name(
+            self,
+            v: impl Into<ResourceName>,
+        ) -> ValueHandleBuilder<TAccount, TType, TId, ResourceName> {
+            ValueHandleBuilder {
+                account: self.account,
+                r#type: self.r#type,
+                id: self.id,
+                name: v.into(),
+                path: self.path,
+            }
+        }
+    }
+
+    impl<TAccount, TType, TId, TName> ValueHandleBuilder<TAccount, TType, TId, TName> {
+        /// JSON path to a value within a `VariableSet` or a `SecretSet`.
+        pub fn path(mut self, v: impl Into<String>) -> Self {
+            self.path = Some(v.into());
+            self
+        }
+    }
+
+    impl ValueHandleBuilder<auth::AccountHandle, TypeUri, ResourceID, ResourceName> {
+        pub fn build(self) -> ValueHandle {
+            ValueHandle {
+                account: self.account,
+                r#type: self.r#type,
+                id: self.id,
+                name: self.name,
+                path: self.path,
+            }
+        }
+    }
+
     /// Reference to a value within a `VariableSet` or a `SecretSet`.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRef
@@ -408,6 +1627,77 @@ pub mod config {
     }
     impl IntoResourceRef for ValueRef {}
 
+    /// Builder for [`ValueRef`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ValueRefBuilder<TType = Unset> {
+        account: Option<auth::AccountRef>,
+        r#type: TType,
+        id: Option<ResourceID>,
+        name: Option<ResourceName>,
+        path: Option<String>,
+    }
+
+    impl ValueRef {
+        pub fn builder() -> ValueRefBuilder<Unset> {
+            ValueRefBuilder {
+                account: None,
+                r#type: Unset,
+                id: None,
+                name: None,
+                path: None,
+            }
+        }
+    }
+
+    impl ValueRefBuilder<Unset> {
+        /// Short type name or full type URI of the target resource.
+        pub fn r#type(self, v: impl Into<TypeRef>) -> ValueRefBuilder<TypeRef> {
+            ValueRefBuilder {
+                account: self.account,
+                r#type: v.into(),
+                id: self.id,
+                name: self.name,
+                path: self.path,
+            }
+        }
+    }
+
+    impl<TType> ValueRefBuilder<TType> {
+        /// Reference to an account that owns the `VariableSet` or the `SecretSet`.
+        pub fn account(mut self, v: impl Into<auth::AccountRef>) -> Self {
+            self.account = Some(v.into());
+            self
+        }
+        /// ID of a resource.
+        pub fn id(mut self, v: impl Into<ResourceID>) -> Self {
+            self.id = Some(v.into());
+            self
+        }
+        /// Name of a resource.
+        pub fn name(mut self, v: impl Into<ResourceName>) -> Self {
+            self.name = Some(v.into());
+            self
+        }
+        /// JSON path to a value within a `VariableSet` or a `SecretSet`.
+        pub fn path(mut self, v: impl Into<String>) -> Self {
+            self.path = Some(v.into());
+            self
+        }
+    }
+
+    impl ValueRefBuilder<TypeRef> {
+        pub fn build(self) -> ValueRef {
+            ValueRef {
+                account: self.account,
+                r#type: self.r#type,
+                id: self.id,
+                name: self.name,
+                path: self.path,
+            }
+        }
+    }
+
     /// Container for key-value variables. Every key must be a string. Values shoud reference fields in `SecretSet`s and `VariableSet`s.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRefs
@@ -425,6 +1715,32 @@ pub mod config {
         pub value: String,
     }
 
+    /// Builder for [`Variable`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct VariableBuilder<TValue = Unset> {
+        value: TValue,
+    }
+
+    impl Variable {
+        pub fn builder() -> VariableBuilder<Unset> {
+            VariableBuilder { value: Unset }
+        }
+    }
+
+    impl VariableBuilder<Unset> {
+        /// A value in raw or encoded form.
+        pub fn value(self, v: impl Into<String>) -> VariableBuilder<String> {
+            VariableBuilder { value: v.into() }
+        }
+    }
+
+    impl VariableBuilder<String> {
+        pub fn build(self) -> Variable {
+            Variable { value: self.value }
+        }
+    }
+
     /// Defines a set of variables stored and managed by the ODF node.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSet
@@ -451,24 +1767,142 @@ pub mod config {
     static VARIABLE_SET_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(VARIABLE_SET_SCHEMA_STR));
 
-    /// Defines a set of variables stored and managed by the ODF node and accessible via embedded variables provider.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpec
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct VariableSetSpec {
-        /// Key value pairs of variables.
-        pub variables: config::Variables,
+    /// Builder for [`VariableSet`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct VariableSetBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
     }
 
-    /// Defines a set of variables stored and managed by the ODF node and accessible via embedded variables provider.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpecInput
-    #[derive(Clone, Debug, Eq, PartialEq)]
+    impl VariableSet {
+        pub fn builder() -> VariableSetBuilder<Unset, Unset> {
+            VariableSetBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> VariableSetBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> VariableSetBuilder<resource::ResourceHeadersInput, TSpec> {
+            VariableSetBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> VariableSetBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the variable set.
+        pub fn spec(
+            self,
+            v: impl Into<config::VariableSetSpecInput>,
+        ) -> VariableSetBuilder<THeaders, config::VariableSetSpecInput> {
+            VariableSetBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl VariableSetBuilder<resource::ResourceHeadersInput, config::VariableSetSpecInput> {
+        pub fn build(self) -> VariableSet {
+            VariableSet {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    /// Defines a set of variables stored and managed by the ODF node and accessible via embedded variables provider.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpec
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct VariableSetSpec {
+        /// Key value pairs of variables.
+        pub variables: config::Variables,
+    }
+
+    /// Builder for [`VariableSetSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct VariableSetSpecBuilder<TVariables = Unset> {
+        variables: TVariables,
+    }
+
+    impl VariableSetSpec {
+        pub fn builder() -> VariableSetSpecBuilder<Unset> {
+            VariableSetSpecBuilder { variables: Unset }
+        }
+    }
+
+    impl VariableSetSpecBuilder<Unset> {
+        /// Key value pairs of variables.
+        pub fn variables(
+            self,
+            v: impl Into<config::Variables>,
+        ) -> VariableSetSpecBuilder<config::Variables> {
+            VariableSetSpecBuilder {
+                variables: v.into(),
+            }
+        }
+    }
+
+    impl VariableSetSpecBuilder<config::Variables> {
+        pub fn build(self) -> VariableSetSpec {
+            VariableSetSpec {
+                variables: self.variables,
+            }
+        }
+    }
+
+    /// Defines a set of variables stored and managed by the ODF node and accessible via embedded variables provider.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpecInput
+    #[derive(Clone, Debug, Eq, PartialEq)]
     pub struct VariableSetSpecInput {
         /// Key value pairs of variables.
         pub variables: config::Variables,
     }
 
+    /// Builder for [`VariableSetSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct VariableSetSpecInputBuilder<TVariables = Unset> {
+        variables: TVariables,
+    }
+
+    impl VariableSetSpecInput {
+        pub fn builder() -> VariableSetSpecInputBuilder<Unset> {
+            VariableSetSpecInputBuilder { variables: Unset }
+        }
+    }
+
+    impl VariableSetSpecInputBuilder<Unset> {
+        /// Key value pairs of variables.
+        pub fn variables(
+            self,
+            v: impl Into<config::Variables>,
+        ) -> VariableSetSpecInputBuilder<config::Variables> {
+            VariableSetSpecInputBuilder {
+                variables: v.into(),
+            }
+        }
+    }
+
+    impl VariableSetSpecInputBuilder<config::Variables> {
+        pub fn build(self) -> VariableSetSpecInput {
+            VariableSetSpecInput {
+                variables: self.variables,
+            }
+        }
+    }
+
     /// Container for key-value variables. Every key must be a string. Values may be raw strings or objects that incorporate the encoding.
     ///
     /// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Variables
@@ -498,6 +1932,68 @@ pub mod data {
         pub extra: Option<data::ExtraAttributes>,
     }
 
+    /// Builder for [`DataField`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataFieldBuilder<TName = Unset, TType = Unset> {
+        name: TName,
+        r#type: TType,
+        extra: Option<data::ExtraAttributes>,
+    }
+
+    impl DataField {
+        pub fn builder() -> DataFieldBuilder<Unset, Unset> {
+            DataFieldBuilder {
+                name: Unset,
+                r#type: Unset,
+                extra: None,
+            }
+        }
+    }
+
+    impl<TType> DataFieldBuilder<Unset, TType> {
+        /// Name of the field
+        pub fn name(self, v: impl Into<String>) -> DataFieldBuilder<String, TType> {
+            DataFieldBuilder {
+                name: v.into(),
+                r#type: self.r#type,
+                extra: self.extra,
+            }
+        }
+    }
+
+    impl<TName> DataFieldBuilder<TName, Unset> {
+        /// Logical type of the field that defines its semantic behavior and value ranges
+        pub fn r#type(
+            self,
+            v: impl Into<data::DataType>,
+        ) -> DataFieldBuilder<TName, data::DataType> {
+            DataFieldBuilder {
+                name: self.name,
+                r#type: v.into(),
+                extra: self.extra,
+            }
+        }
+    }
+
+    impl<TName, TType> DataFieldBuilder<TName, TType> {
+        /// ODF extensions
+        pub fn extra(mut self, v: impl Into<data::ExtraAttributes>) -> Self {
+            self.extra = Some(v.into());
+            self
+        }
+    }
+
+    impl DataFieldBuilder<String, data::DataType> {
+        pub fn build(self) -> DataField {
+            DataField {
+                name: self.name,
+                r#type: self.r#type,
+                extra: self.extra,
+            }
+        }
+    }
+
     /// This schema aims to be a human-friendly variant of Arrow. Arrow currently specifies only the [flatbuffer format](https://github.com/apache/arrow/blob/f9301c0ba8a7ed1b0b63275cfdd4c44c26b04675/format/Schema.fbs) which has many legacy to it and is not suited to be defined by humans, so we had to define our own schema format. While inspired by Arrow - this format makes a clear separation between logical data types and encoding (physical layout) of data in the chunks.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataSchema
@@ -509,6 +2005,53 @@ pub mod data {
         pub extra: Option<data::ExtraAttributes>,
     }
 
+    /// Builder for [`DataSchema`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataSchemaBuilder<TFields = Unset> {
+        fields: TFields,
+        extra: Option<data::ExtraAttributes>,
+    }
+
+    impl DataSchema {
+        pub fn builder() -> DataSchemaBuilder<Unset> {
+            DataSchemaBuilder {
+                fields: Unset,
+                extra: None,
+            }
+        }
+    }
+
+    impl DataSchemaBuilder<Unset> {
+        /// Top-level fields (columns) of the schema.
+        pub fn fields(
+            self,
+            v: impl Into<Vec<data::DataField>>,
+        ) -> DataSchemaBuilder<Vec<data::DataField>> {
+            DataSchemaBuilder {
+                fields: v.into(),
+                extra: self.extra,
+            }
+        }
+    }
+
+    impl<TFields> DataSchemaBuilder<TFields> {
+        /// ODF extensions
+        pub fn extra(mut self, v: impl Into<data::ExtraAttributes>) -> Self {
+            self.extra = Some(v.into());
+            self
+        }
+    }
+
+    impl DataSchemaBuilder<Vec<data::DataField>> {
+        pub fn build(self) -> DataSchema {
+            DataSchema {
+                fields: self.fields,
+                extra: self.extra,
+            }
+        }
+    }
+
     /// Defines a logical type of the field. Logical type determines the semantics and boudaries of a type and how it can be operated on, without a concern about encoding and physical layout of the data in chunks.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType
@@ -575,6 +2118,35 @@ pub mod data {
         pub fixed_length: Option<u64>,
     }
 
+    /// Builder for [`DataTypeBinary`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeBinaryBuilder {
+        fixed_length: Option<u64>,
+    }
+
+    impl DataTypeBinary {
+        pub fn builder() -> DataTypeBinaryBuilder {
+            DataTypeBinaryBuilder { fixed_length: None }
+        }
+    }
+
+    impl DataTypeBinaryBuilder {
+        /// Number of bytes per value for fixed-size binary. If omitted, the binary is variable-length.
+        pub fn fixed_length(mut self, v: impl Into<u64>) -> Self {
+            self.fixed_length = Some(v.into());
+            self
+        }
+    }
+
+    impl DataTypeBinaryBuilder {
+        pub fn build(self) -> DataTypeBinary {
+            DataTypeBinary {
+                fixed_length: self.fixed_length,
+            }
+        }
+    }
+
     /// A boolean value representing true or false.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Bool
@@ -600,6 +2172,54 @@ pub mod data {
         pub scale: i32,
     }
 
+    /// Builder for [`DataTypeDecimal`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeDecimalBuilder<TPrecision = Unset, TScale = Unset> {
+        precision: TPrecision,
+        scale: TScale,
+    }
+
+    impl DataTypeDecimal {
+        pub fn builder() -> DataTypeDecimalBuilder<Unset, Unset> {
+            DataTypeDecimalBuilder {
+                precision: Unset,
+                scale: Unset,
+            }
+        }
+    }
+
+    impl<TScale> DataTypeDecimalBuilder<Unset, TScale> {
+        /// Total number of decimal digits that can be stored.
+        pub fn precision(self, v: impl Into<u32>) -> DataTypeDecimalBuilder<u32, TScale> {
+            DataTypeDecimalBuilder {
+                precision: v.into(),
+                scale: self.scale,
+            }
+        }
+    }
+
+    impl<TPrecision> DataTypeDecimalBuilder<TPrecision, Unset> {
+        /// Number of digits after the decimal point. In certain situations, scale could be negative number. For negative scale, it is the number of padding 0 to the right of the digits.
+        ///
+        /// For example the number 12300 could be treated as a decimal has precision 3 and scale -2.
+        pub fn scale(self, v: impl Into<i32>) -> DataTypeDecimalBuilder<TPrecision, i32> {
+            DataTypeDecimalBuilder {
+                precision: self.precision,
+                scale: v.into(),
+            }
+        }
+    }
+
+    impl DataTypeDecimalBuilder<u32, i32> {
+        pub fn build(self) -> DataTypeDecimal {
+            DataTypeDecimal {
+                precision: self.precision,
+                scale: self.scale,
+            }
+        }
+    }
+
     /// An elapsed time interval with a specified time unit.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Duration
@@ -627,6 +2247,35 @@ pub mod data {
         }
     }
 
+    /// Builder for [`DataTypeDuration`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeDurationBuilder {
+        unit: Option<data::TimeUnit>,
+    }
+
+    impl DataTypeDuration {
+        pub fn builder() -> DataTypeDurationBuilder {
+            DataTypeDurationBuilder { unit: None }
+        }
+    }
+
+    impl DataTypeDurationBuilder {
+        /// The unit of the duration measurement.
+        ///
+        /// Defaults to: "Millisecond"
+        pub fn unit(mut self, v: impl Into<data::TimeUnit>) -> Self {
+            self.unit = Some(v.into());
+            self
+        }
+    }
+
+    impl DataTypeDurationBuilder {
+        pub fn build(self) -> DataTypeDuration {
+            DataTypeDuration { unit: self.unit }
+        }
+    }
+
     /// A floating-point number.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Float16
@@ -680,6 +2329,53 @@ pub mod data {
         pub fixed_length: Option<u64>,
     }
 
+    /// Builder for [`DataTypeList`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeListBuilder<TItemType = Unset> {
+        item_type: TItemType,
+        fixed_length: Option<u64>,
+    }
+
+    impl DataTypeList {
+        pub fn builder() -> DataTypeListBuilder<Unset> {
+            DataTypeListBuilder {
+                item_type: Unset,
+                fixed_length: None,
+            }
+        }
+    }
+
+    impl DataTypeListBuilder<Unset> {
+        /// Data type of list items.
+        pub fn item_type(
+            self,
+            v: impl Into<Box<data::DataType>>,
+        ) -> DataTypeListBuilder<Box<data::DataType>> {
+            DataTypeListBuilder {
+                item_type: v.into(),
+                fixed_length: self.fixed_length,
+            }
+        }
+    }
+
+    impl<TItemType> DataTypeListBuilder<TItemType> {
+        /// Number of list items per value for fixed-size lists. If omitted, the list is variable-length.
+        pub fn fixed_length(mut self, v: impl Into<u64>) -> Self {
+            self.fixed_length = Some(v.into());
+            self
+        }
+    }
+
+    impl DataTypeListBuilder<Box<data::DataType>> {
+        pub fn build(self) -> DataTypeList {
+            DataTypeList {
+                item_type: self.item_type,
+                fixed_length: self.fixed_length,
+            }
+        }
+    }
+
     /// A map of key-value pairs, represented as a list of entries (structs with key and value fields).
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Map
@@ -693,6 +2389,71 @@ pub mod data {
         pub keys_sorted: Option<bool>,
     }
 
+    /// Builder for [`DataTypeMap`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeMapBuilder<TKeyType = Unset, TValueType = Unset> {
+        key_type: TKeyType,
+        value_type: TValueType,
+        keys_sorted: Option<bool>,
+    }
+
+    impl DataTypeMap {
+        pub fn builder() -> DataTypeMapBuilder<Unset, Unset> {
+            DataTypeMapBuilder {
+                key_type: Unset,
+                value_type: Unset,
+                keys_sorted: None,
+            }
+        }
+    }
+
+    impl<TValueType> DataTypeMapBuilder<Unset, TValueType> {
+        /// Data type of the map's keys.
+        pub fn key_type(
+            self,
+            v: impl Into<Box<data::DataType>>,
+        ) -> DataTypeMapBuilder<Box<data::DataType>, TValueType> {
+            DataTypeMapBuilder {
+                key_type: v.into(),
+                value_type: self.value_type,
+                keys_sorted: self.keys_sorted,
+            }
+        }
+    }
+
+    impl<TKeyType> DataTypeMapBuilder<TKeyType, Unset> {
+        /// Data type of the map's values.
+        pub fn value_type(
+            self,
+            v: impl Into<Box<data::DataType>>,
+        ) -> DataTypeMapBuilder<TKeyType, Box<data::DataType>> {
+            DataTypeMapBuilder {
+                key_type: self.key_type,
+                value_type: v.into(),
+                keys_sorted: self.keys_sorted,
+            }
+        }
+    }
+
+    impl<TKeyType, TValueType> DataTypeMapBuilder<TKeyType, TValueType> {
+        /// Set to true if the keys within each value are sorted.
+        pub fn keys_sorted(mut self, v: impl Into<bool>) -> Self {
+            self.keys_sorted = Some(v.into());
+            self
+        }
+    }
+
+    impl DataTypeMapBuilder<Box<data::DataType>, Box<data::DataType>> {
+        pub fn build(self) -> DataTypeMap {
+            DataTypeMap {
+                key_type: self.key_type,
+                value_type: self.value_type,
+                keys_sorted: self.keys_sorted,
+            }
+        }
+    }
+
     /// A type representing the absence of a value (null).
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Null
@@ -708,6 +2469,35 @@ pub mod data {
         pub inner: Box<data::DataType>,
     }
 
+    /// Builder for [`DataTypeOption`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeOptionBuilder<TInner = Unset> {
+        inner: TInner,
+    }
+
+    impl DataTypeOption {
+        pub fn builder() -> DataTypeOptionBuilder<Unset> {
+            DataTypeOptionBuilder { inner: Unset }
+        }
+    }
+
+    impl DataTypeOptionBuilder<Unset> {
+        /// Inner data type for the optional value.
+        pub fn inner(
+            self,
+            v: impl Into<Box<data::DataType>>,
+        ) -> DataTypeOptionBuilder<Box<data::DataType>> {
+            DataTypeOptionBuilder { inner: v.into() }
+        }
+    }
+
+    impl DataTypeOptionBuilder<Box<data::DataType>> {
+        pub fn build(self) -> DataTypeOption {
+            DataTypeOption { inner: self.inner }
+        }
+    }
+
     /// A Unicode string.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/String
@@ -723,6 +2513,37 @@ pub mod data {
         pub fields: Vec<data::DataField>,
     }
 
+    /// Builder for [`DataTypeStruct`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeStructBuilder<TFields = Unset> {
+        fields: TFields,
+    }
+
+    impl DataTypeStruct {
+        pub fn builder() -> DataTypeStructBuilder<Unset> {
+            DataTypeStructBuilder { fields: Unset }
+        }
+    }
+
+    impl DataTypeStructBuilder<Unset> {
+        /// Fields that make up the struct.
+        pub fn fields(
+            self,
+            v: impl Into<Vec<data::DataField>>,
+        ) -> DataTypeStructBuilder<Vec<data::DataField>> {
+            DataTypeStructBuilder { fields: v.into() }
+        }
+    }
+
+    impl DataTypeStructBuilder<Vec<data::DataField>> {
+        pub fn build(self) -> DataTypeStruct {
+            DataTypeStruct {
+                fields: self.fields,
+            }
+        }
+    }
+
     /// A time of day value, without a date, with a specified unit of granularity.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Time
@@ -750,6 +2571,35 @@ pub mod data {
         }
     }
 
+    /// Builder for [`DataTypeTime`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeTimeBuilder {
+        unit: Option<data::TimeUnit>,
+    }
+
+    impl DataTypeTime {
+        pub fn builder() -> DataTypeTimeBuilder {
+            DataTypeTimeBuilder { unit: None }
+        }
+    }
+
+    impl DataTypeTimeBuilder {
+        /// The unit of the time value.
+        ///
+        /// Defaults to: "Millisecond"
+        pub fn unit(mut self, v: impl Into<data::TimeUnit>) -> Self {
+            self.unit = Some(v.into());
+            self
+        }
+    }
+
+    impl DataTypeTimeBuilder {
+        pub fn build(self) -> DataTypeTime {
+            DataTypeTime { unit: self.unit }
+        }
+    }
+
     /// A point in time, represented as an offset from the Unix epoch in a specific timezone.
     ///
     /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Timestamp
@@ -801,23 +2651,72 @@ pub mod data {
         }
     }
 
-    /// An integer value.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt16
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct DataTypeUInt16 {}
+    /// Builder for [`DataTypeTimestamp`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataTypeTimestampBuilder {
+        unit: Option<data::TimeUnit>,
+        timezone: Option<String>,
+    }
 
-    /// An integer value.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt32
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct DataTypeUInt32 {}
+    impl DataTypeTimestamp {
+        pub fn builder() -> DataTypeTimestampBuilder {
+            DataTypeTimestampBuilder {
+                unit: None,
+                timezone: None,
+            }
+        }
+    }
 
-    /// An integer value.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt64
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct DataTypeUInt64 {}
+    impl DataTypeTimestampBuilder {
+        /// The unit of the timestamp value that determines its precision.
+        ///
+        /// Defaults to: "Millisecond"
+        pub fn unit(mut self, v: impl Into<data::TimeUnit>) -> Self {
+            self.unit = Some(v.into());
+            self
+        }
+        /// The timezone is an optional string indicating the name of a timezone
+        /// one of
+        ///
+        /// * As used in the Olson timezone database (the "tz database" or
+        ///   "tzdata"), such as "America/New_York".
+        /// * An absolute timezone offset of the form "+XX:XX" or "-XX:XX",
+        ///   such as "+07:30".
+        ///
+        /// Defaults to: "UTC"
+        pub fn timezone(mut self, v: impl Into<String>) -> Self {
+            self.timezone = Some(v.into());
+            self
+        }
+    }
+
+    impl DataTypeTimestampBuilder {
+        pub fn build(self) -> DataTypeTimestamp {
+            DataTypeTimestamp {
+                unit: self.unit,
+                timezone: self.timezone,
+            }
+        }
+    }
+
+    /// An integer value.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt16
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct DataTypeUInt16 {}
+
+    /// An integer value.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt32
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct DataTypeUInt32 {}
+
+    /// An integer value.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt64
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct DataTypeUInt64 {}
 
     /// An integer value.
     ///
@@ -875,6 +2774,85 @@ pub mod dataset {
         pub extra: Option<data::ExtraAttributes>,
     }
 
+    /// Builder for [`AddData`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AddDataBuilder {
+        prev_checkpoint: Option<Multihash>,
+        prev_offset: Option<u64>,
+        new_data: Option<dataset::DataSlice>,
+        new_checkpoint: Option<dataset::Checkpoint>,
+        new_watermark: Option<DateTime<Utc>>,
+        new_source_state: Option<source::SourceState>,
+        extra: Option<data::ExtraAttributes>,
+    }
+
+    impl AddData {
+        pub fn builder() -> AddDataBuilder {
+            AddDataBuilder {
+                prev_checkpoint: None,
+                prev_offset: None,
+                new_data: None,
+                new_checkpoint: None,
+                new_watermark: None,
+                new_source_state: None,
+                extra: None,
+            }
+        }
+    }
+
+    impl AddDataBuilder {
+        /// Hash of the checkpoint file used to restore ingestion state, if any.
+        pub fn prev_checkpoint(mut self, v: impl Into<Multihash>) -> Self {
+            self.prev_checkpoint = Some(v.into());
+            self
+        }
+        /// Last offset of the previous data slice, if any. Must be equal to the last non-empty `newData.offsetInterval.end`.
+        pub fn prev_offset(mut self, v: impl Into<u64>) -> Self {
+            self.prev_offset = Some(v.into());
+            self
+        }
+        /// Describes output data written during this transaction, if any.
+        pub fn new_data(mut self, v: impl Into<dataset::DataSlice>) -> Self {
+            self.new_data = Some(v.into());
+            self
+        }
+        /// Describes checkpoint written during this transaction, if any. If an engine operation resulted in no updates to the checkpoint, but checkpoint is still relevant for subsequent runs - a hash of the previous checkpoint should be specified.
+        pub fn new_checkpoint(mut self, v: impl Into<dataset::Checkpoint>) -> Self {
+            self.new_checkpoint = Some(v.into());
+            self
+        }
+        /// Last watermark of the output data stream, if any. Initial blocks may not have watermarks, but once watermark is set - all subsequent blocks should either carry the same watermark or specify a new (greater) one. Thus, watermarks are monotonically non-decreasing.
+        pub fn new_watermark(mut self, v: impl Into<DateTime<Utc>>) -> Self {
+            self.new_watermark = Some(v.into());
+            self
+        }
+        /// The state of the source the data was added from to allow fast resuming. If the state did not change but is still relevant for subsequent runs it should be carried, i.e. only the last state per source is considered when resuming.
+        pub fn new_source_state(mut self, v: impl Into<source::SourceState>) -> Self {
+            self.new_source_state = Some(v.into());
+            self
+        }
+        /// ODF extensions.
+        pub fn extra(mut self, v: impl Into<data::ExtraAttributes>) -> Self {
+            self.extra = Some(v.into());
+            self
+        }
+    }
+
+    impl AddDataBuilder {
+        pub fn build(self) -> AddData {
+            AddData {
+                prev_checkpoint: self.prev_checkpoint,
+                prev_offset: self.prev_offset,
+                new_data: self.new_data,
+                new_checkpoint: self.new_checkpoint,
+                new_watermark: self.new_watermark,
+                new_source_state: self.new_source_state,
+                extra: self.extra,
+            }
+        }
+    }
+
     /// Embedded attachment item.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/AttachmentEmbedded
@@ -886,6 +2864,52 @@ pub mod dataset {
         pub content: String,
     }
 
+    /// Builder for [`AttachmentEmbedded`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AttachmentEmbeddedBuilder<TPath = Unset, TContent = Unset> {
+        path: TPath,
+        content: TContent,
+    }
+
+    impl AttachmentEmbedded {
+        pub fn builder() -> AttachmentEmbeddedBuilder<Unset, Unset> {
+            AttachmentEmbeddedBuilder {
+                path: Unset,
+                content: Unset,
+            }
+        }
+    }
+
+    impl<TContent> AttachmentEmbeddedBuilder<Unset, TContent> {
+        /// Path to an attachment if it was materialized into a file.
+        pub fn path(self, v: impl Into<String>) -> AttachmentEmbeddedBuilder<String, TContent> {
+            AttachmentEmbeddedBuilder {
+                path: v.into(),
+                content: self.content,
+            }
+        }
+    }
+
+    impl<TPath> AttachmentEmbeddedBuilder<TPath, Unset> {
+        /// Content of the attachment.
+        pub fn content(self, v: impl Into<String>) -> AttachmentEmbeddedBuilder<TPath, String> {
+            AttachmentEmbeddedBuilder {
+                path: self.path,
+                content: v.into(),
+            }
+        }
+    }
+
+    impl AttachmentEmbeddedBuilder<String, String> {
+        pub fn build(self) -> AttachmentEmbedded {
+            AttachmentEmbedded {
+                path: self.path,
+                content: self.content,
+            }
+        }
+    }
+
     /// Defines the source of attachment files.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Attachments
@@ -906,6 +2930,35 @@ pub mod dataset {
         pub items: Vec<dataset::AttachmentEmbedded>,
     }
 
+    /// Builder for [`AttachmentsEmbedded`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AttachmentsEmbeddedBuilder<TItems = Unset> {
+        items: TItems,
+    }
+
+    impl AttachmentsEmbedded {
+        pub fn builder() -> AttachmentsEmbeddedBuilder<Unset> {
+            AttachmentsEmbeddedBuilder { items: Unset }
+        }
+    }
+
+    impl AttachmentsEmbeddedBuilder<Unset> {
+        /// List of embedded items.
+        pub fn items(
+            self,
+            v: impl Into<Vec<dataset::AttachmentEmbedded>>,
+        ) -> AttachmentsEmbeddedBuilder<Vec<dataset::AttachmentEmbedded>> {
+            AttachmentsEmbeddedBuilder { items: v.into() }
+        }
+    }
+
+    impl AttachmentsEmbeddedBuilder<Vec<dataset::AttachmentEmbedded>> {
+        pub fn build(self) -> AttachmentsEmbedded {
+            AttachmentsEmbedded { items: self.items }
+        }
+    }
+
     /// Describes a checkpoint produced by an engine
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Checkpoint
@@ -917,6 +2970,52 @@ pub mod dataset {
         pub size: u64,
     }
 
+    /// Builder for [`Checkpoint`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct CheckpointBuilder<TPhysicalHash = Unset, TSize = Unset> {
+        physical_hash: TPhysicalHash,
+        size: TSize,
+    }
+
+    impl Checkpoint {
+        pub fn builder() -> CheckpointBuilder<Unset, Unset> {
+            CheckpointBuilder {
+                physical_hash: Unset,
+                size: Unset,
+            }
+        }
+    }
+
+    impl<TSize> CheckpointBuilder<Unset, TSize> {
+        /// Hash sum of the checkpoint file.
+        pub fn physical_hash(self, v: impl Into<Multihash>) -> CheckpointBuilder<Multihash, TSize> {
+            CheckpointBuilder {
+                physical_hash: v.into(),
+                size: self.size,
+            }
+        }
+    }
+
+    impl<TPhysicalHash> CheckpointBuilder<TPhysicalHash, Unset> {
+        /// Size of checkpoint file in bytes.
+        pub fn size(self, v: impl Into<u64>) -> CheckpointBuilder<TPhysicalHash, u64> {
+            CheckpointBuilder {
+                physical_hash: self.physical_hash,
+                size: v.into(),
+            }
+        }
+    }
+
+    impl CheckpointBuilder<Multihash, u64> {
+        pub fn build(self) -> Checkpoint {
+            Checkpoint {
+                physical_hash: self.physical_hash,
+                size: self.size,
+            }
+        }
+    }
+
     /// Optional parameters to control ingestion behavior.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/CompactionParams
@@ -928,6 +3027,45 @@ pub mod dataset {
         pub max_slice_records: Option<u64>,
     }
 
+    /// Builder for [`CompactionParams`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct CompactionParamsBuilder {
+        max_slice_size: Option<ByteSize>,
+        max_slice_records: Option<u64>,
+    }
+
+    impl CompactionParams {
+        pub fn builder() -> CompactionParamsBuilder {
+            CompactionParamsBuilder {
+                max_slice_size: None,
+                max_slice_records: None,
+            }
+        }
+    }
+
+    impl CompactionParamsBuilder {
+        /// Target maximum size of each compacted data slice e.g. `100MiB`.
+        pub fn max_slice_size(mut self, v: impl Into<ByteSize>) -> Self {
+            self.max_slice_size = Some(v.into());
+            self
+        }
+        /// Target maximum number of records per compacted data slice.
+        pub fn max_slice_records(mut self, v: impl Into<u64>) -> Self {
+            self.max_slice_records = Some(v.into());
+            self
+        }
+    }
+
+    impl CompactionParamsBuilder {
+        pub fn build(self) -> CompactionParams {
+            CompactionParams {
+                max_slice_size: self.max_slice_size,
+                max_slice_records: self.max_slice_records,
+            }
+        }
+    }
+
     /// Describes a slice of data added to a dataset or produced via transformation
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DataSlice
@@ -943,6 +3081,111 @@ pub mod dataset {
         pub size: u64,
     }
 
+    /// Builder for [`DataSlice`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DataSliceBuilder<
+        TLogicalHash = Unset,
+        TPhysicalHash = Unset,
+        TOffsetInterval = Unset,
+        TSize = Unset,
+    > {
+        logical_hash: TLogicalHash,
+        physical_hash: TPhysicalHash,
+        offset_interval: TOffsetInterval,
+        size: TSize,
+    }
+
+    impl DataSlice {
+        pub fn builder() -> DataSliceBuilder<Unset, Unset, Unset, Unset> {
+            DataSliceBuilder {
+                logical_hash: Unset,
+                physical_hash: Unset,
+                offset_interval: Unset,
+                size: Unset,
+            }
+        }
+    }
+
+    impl<TPhysicalHash, TOffsetInterval, TSize>
+        DataSliceBuilder<Unset, TPhysicalHash, TOffsetInterval, TSize>
+    {
+        /// Logical hash sum of the data in this slice.
+        pub fn logical_hash(
+            self,
+            v: impl Into<Multihash>,
+        ) -> DataSliceBuilder<Multihash, TPhysicalHash, TOffsetInterval, TSize> {
+            DataSliceBuilder {
+                logical_hash: v.into(),
+                physical_hash: self.physical_hash,
+                offset_interval: self.offset_interval,
+                size: self.size,
+            }
+        }
+    }
+
+    impl<TLogicalHash, TOffsetInterval, TSize>
+        DataSliceBuilder<TLogicalHash, Unset, TOffsetInterval, TSize>
+    {
+        /// Hash sum of the data part file.
+        pub fn physical_hash(
+            self,
+            v: impl Into<Multihash>,
+        ) -> DataSliceBuilder<TLogicalHash, Multihash, TOffsetInterval, TSize> {
+            DataSliceBuilder {
+                logical_hash: self.logical_hash,
+                physical_hash: v.into(),
+                offset_interval: self.offset_interval,
+                size: self.size,
+            }
+        }
+    }
+
+    impl<TLogicalHash, TPhysicalHash, TSize>
+        DataSliceBuilder<TLogicalHash, TPhysicalHash, Unset, TSize>
+    {
+        /// Data slice produced by the transaction.
+        pub fn offset_interval(
+            self,
+            v: impl Into<dataset::OffsetInterval>,
+        ) -> DataSliceBuilder<TLogicalHash, TPhysicalHash, dataset::OffsetInterval, TSize> {
+            DataSliceBuilder {
+                logical_hash: self.logical_hash,
+                physical_hash: self.physical_hash,
+                offset_interval: v.into(),
+                size: self.size,
+            }
+        }
+    }
+
+    impl<TLogicalHash, TPhysicalHash, TOffsetInterval>
+        DataSliceBuilder<TLogicalHash, TPhysicalHash, TOffsetInterval, Unset>
+    {
+        /// Size of data file in bytes.
+        pub fn size(
+            self,
+            v: impl Into<u64>,
+        ) -> DataSliceBuilder<TLogicalHash, TPhysicalHash, TOffsetInterval, u64> {
+            DataSliceBuilder {
+                logical_hash: self.logical_hash,
+                physical_hash: self.physical_hash,
+                offset_interval: self.offset_interval,
+                size: v.into(),
+            }
+        }
+    }
+
+    impl DataSliceBuilder<Multihash, Multihash, dataset::OffsetInterval, u64> {
+        pub fn build(self) -> DataSlice {
+            DataSlice {
+                logical_hash: self.logical_hash,
+                physical_hash: self.physical_hash,
+                offset_interval: self.offset_interval,
+                size: self.size,
+            }
+        }
+    }
+
     /// Represents a desired state of a dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Dataset
@@ -968,6 +3211,67 @@ pub mod dataset {
     static DATASET_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(DATASET_SCHEMA_STR));
 
+    /// Builder for [`Dataset`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl Dataset {
+        pub fn builder() -> DatasetBuilder<Unset, Unset> {
+            DatasetBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> DatasetBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> DatasetBuilder<resource::ResourceHeadersInput, TSpec> {
+            DatasetBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> DatasetBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the resource.
+        pub fn spec(
+            self,
+            v: impl Into<dataset::DatasetSpecInput>,
+        ) -> DatasetBuilder<THeaders, dataset::DatasetSpecInput> {
+            DatasetBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl DatasetBuilder<resource::ResourceHeadersInput, dataset::DatasetSpecInput> {
+        pub fn build(self) -> Dataset {
+            Dataset {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl Redact for Dataset {
+        fn redact(&self) -> Self {
+            Self {
+                headers: self.headers.clone(),
+                spec: self.spec.redact(),
+            }
+        }
+    }
+
     /// Represents type of the dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetKind
@@ -994,6 +3298,116 @@ pub mod dataset {
         pub volume: resource::ResourceHandle,
     }
 
+    /// Builder for [`DatasetSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetSpecBuilder<TDid = Unset, TKind = Unset, TMetadata = Unset, TVolume = Unset> {
+        did: TDid,
+        kind: TKind,
+        metadata: TMetadata,
+        volume: TVolume,
+    }
+
+    impl DatasetSpec {
+        pub fn builder() -> DatasetSpecBuilder<Unset, Unset, Unset, Unset> {
+            DatasetSpecBuilder {
+                did: Unset,
+                kind: Unset,
+                metadata: Unset,
+                volume: Unset,
+            }
+        }
+    }
+
+    impl<TKind, TMetadata, TVolume> DatasetSpecBuilder<Unset, TKind, TMetadata, TVolume> {
+        /// DID of the dataset in global ODF network
+        pub fn did(
+            self,
+            v: impl Into<DatasetID>,
+        ) -> DatasetSpecBuilder<DatasetID, TKind, TMetadata, TVolume> {
+            DatasetSpecBuilder {
+                did: v.into(),
+                kind: self.kind,
+                metadata: self.metadata,
+                volume: self.volume,
+            }
+        }
+    }
+
+    impl<TDid, TMetadata, TVolume> DatasetSpecBuilder<TDid, Unset, TMetadata, TVolume> {
+        /// Type of the dataset.
+        pub fn kind(
+            self,
+            v: impl Into<dataset::DatasetKind>,
+        ) -> DatasetSpecBuilder<TDid, dataset::DatasetKind, TMetadata, TVolume> {
+            DatasetSpecBuilder {
+                did: self.did,
+                kind: v.into(),
+                metadata: self.metadata,
+                volume: self.volume,
+            }
+        }
+    }
+
+    impl<TDid, TKind, TVolume> DatasetSpecBuilder<TDid, TKind, Unset, TVolume> {
+        /// An array of metadata events that will be used to populate the chain. Here you can define polling and push sources, set licenses, add attachments etc.
+        pub fn metadata(
+            self,
+            v: impl Into<Vec<dataset::MetadataEvent>>,
+        ) -> DatasetSpecBuilder<TDid, TKind, Vec<dataset::MetadataEvent>, TVolume> {
+            DatasetSpecBuilder {
+                did: self.did,
+                kind: self.kind,
+                metadata: v.into(),
+                volume: self.volume,
+            }
+        }
+    }
+
+    impl<TDid, TKind, TMetadata> DatasetSpecBuilder<TDid, TKind, TMetadata, Unset> {
+        /// Reference to a storage volume where dataset data will be stored. If omitted, the node's default storage is used.
+        pub fn volume(
+            self,
+            v: impl Into<resource::ResourceHandle>,
+        ) -> DatasetSpecBuilder<TDid, TKind, TMetadata, resource::ResourceHandle> {
+            DatasetSpecBuilder {
+                did: self.did,
+                kind: self.kind,
+                metadata: self.metadata,
+                volume: v.into(),
+            }
+        }
+    }
+
+    impl
+        DatasetSpecBuilder<
+            DatasetID,
+            dataset::DatasetKind,
+            Vec<dataset::MetadataEvent>,
+            resource::ResourceHandle,
+        >
+    {
+        pub fn build(self) -> DatasetSpec {
+            DatasetSpec {
+                did: self.did,
+                kind: self.kind,
+                metadata: self.metadata,
+                volume: self.volume,
+            }
+        }
+    }
+
+    impl Redact for DatasetSpec {
+        fn redact(&self) -> Self {
+            Self {
+                did: self.did.clone(),
+                kind: self.kind.clone(),
+                metadata: self.metadata.redact(),
+                volume: self.volume.clone(),
+            }
+        }
+    }
+
     /// Represents a desired state of the dataset metadata.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpecInput
@@ -1009,6 +3423,92 @@ pub mod dataset {
         pub volume: Option<storage::PersistentVolumeRef>,
     }
 
+    /// Builder for [`DatasetSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetSpecInputBuilder<TKind = Unset, TMetadata = Unset> {
+        did: Option<DatasetID>,
+        kind: TKind,
+        metadata: TMetadata,
+        volume: Option<storage::PersistentVolumeRef>,
+    }
+
+    impl DatasetSpecInput {
+        pub fn builder() -> DatasetSpecInputBuilder<Unset, Unset> {
+            DatasetSpecInputBuilder {
+                did: None,
+                kind: Unset,
+                metadata: Unset,
+                volume: None,
+            }
+        }
+    }
+
+    impl<TMetadata> DatasetSpecInputBuilder<Unset, TMetadata> {
+        /// Type of the dataset.
+        pub fn kind(
+            self,
+            v: impl Into<dataset::DatasetKind>,
+        ) -> DatasetSpecInputBuilder<dataset::DatasetKind, TMetadata> {
+            DatasetSpecInputBuilder {
+                did: self.did,
+                kind: v.into(),
+                metadata: self.metadata,
+                volume: self.volume,
+            }
+        }
+    }
+
+    impl<TKind> DatasetSpecInputBuilder<TKind, Unset> {
+        /// An array of metadata events that will be used to populate the chain. Here you can define polling and push sources, set licenses, add attachments etc.
+        pub fn metadata(
+            self,
+            v: impl Into<Vec<dataset::MetadataEvent>>,
+        ) -> DatasetSpecInputBuilder<TKind, Vec<dataset::MetadataEvent>> {
+            DatasetSpecInputBuilder {
+                did: self.did,
+                kind: self.kind,
+                metadata: v.into(),
+                volume: self.volume,
+            }
+        }
+    }
+
+    impl<TKind, TMetadata> DatasetSpecInputBuilder<TKind, TMetadata> {
+        /// DID of the dataset in global ODF network
+        pub fn did(mut self, v: impl Into<DatasetID>) -> Self {
+            self.did = Some(v.into());
+            self
+        }
+        /// Reference to a storage volume where dataset data will be stored. If omitted, the node's default storage is used.
+        pub fn volume(mut self, v: impl Into<storage::PersistentVolumeRef>) -> Self {
+            self.volume = Some(v.into());
+            self
+        }
+    }
+
+    impl DatasetSpecInputBuilder<dataset::DatasetKind, Vec<dataset::MetadataEvent>> {
+        pub fn build(self) -> DatasetSpecInput {
+            DatasetSpecInput {
+                did: self.did,
+                kind: self.kind,
+                metadata: self.metadata,
+                volume: self.volume,
+            }
+        }
+    }
+
+    impl Redact for DatasetSpecInput {
+        fn redact(&self) -> Self {
+            Self {
+                did: self.did.clone(),
+                kind: self.kind.clone(),
+                metadata: self.metadata.redact(),
+                volume: self.volume.clone(),
+            }
+        }
+    }
+
     /// Specifies the mapping of system columns onto dataset schema.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetVocabulary
@@ -1103,12 +3603,75 @@ pub mod dataset {
         }
     }
 
-    /// Indicates that derivative transformation has been performed.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ExecuteTransform
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct ExecuteTransform {
-        /// Defines inputs used in this transaction. Slices corresponding to every input dataset must be present.
+    /// Builder for [`DatasetVocabulary`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetVocabularyBuilder {
+        offset_column: Option<String>,
+        operation_type_column: Option<String>,
+        system_time_column: Option<String>,
+        event_time_column: Option<String>,
+    }
+
+    impl DatasetVocabulary {
+        pub fn builder() -> DatasetVocabularyBuilder {
+            DatasetVocabularyBuilder {
+                offset_column: None,
+                operation_type_column: None,
+                system_time_column: None,
+                event_time_column: None,
+            }
+        }
+    }
+
+    impl DatasetVocabularyBuilder {
+        /// Name of the offset column.
+        ///
+        /// Defaults to: "offset"
+        pub fn offset_column(mut self, v: impl Into<String>) -> Self {
+            self.offset_column = Some(v.into());
+            self
+        }
+        /// Name of the operation type column.
+        ///
+        /// Defaults to: "op"
+        pub fn operation_type_column(mut self, v: impl Into<String>) -> Self {
+            self.operation_type_column = Some(v.into());
+            self
+        }
+        /// Name of the system time column.
+        ///
+        /// Defaults to: "system_time"
+        pub fn system_time_column(mut self, v: impl Into<String>) -> Self {
+            self.system_time_column = Some(v.into());
+            self
+        }
+        /// Name of the event time column.
+        ///
+        /// Defaults to: "event_time"
+        pub fn event_time_column(mut self, v: impl Into<String>) -> Self {
+            self.event_time_column = Some(v.into());
+            self
+        }
+    }
+
+    impl DatasetVocabularyBuilder {
+        pub fn build(self) -> DatasetVocabulary {
+            DatasetVocabulary {
+                offset_column: self.offset_column,
+                operation_type_column: self.operation_type_column,
+                system_time_column: self.system_time_column,
+                event_time_column: self.event_time_column,
+            }
+        }
+    }
+
+    /// Indicates that derivative transformation has been performed.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ExecuteTransform
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ExecuteTransform {
+        /// Defines inputs used in this transaction. Slices corresponding to every input dataset must be present.
         pub query_inputs: Vec<dataset::ExecuteTransformInput>,
         /// Hash of the checkpoint file used to restore transformation state, if any.
         pub prev_checkpoint: Option<Multihash>,
@@ -1122,6 +3685,89 @@ pub mod dataset {
         pub new_watermark: Option<DateTime<Utc>>,
     }
 
+    /// Builder for [`ExecuteTransform`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ExecuteTransformBuilder<TQueryInputs = Unset> {
+        query_inputs: TQueryInputs,
+        prev_checkpoint: Option<Multihash>,
+        prev_offset: Option<u64>,
+        new_data: Option<dataset::DataSlice>,
+        new_checkpoint: Option<dataset::Checkpoint>,
+        new_watermark: Option<DateTime<Utc>>,
+    }
+
+    impl ExecuteTransform {
+        pub fn builder() -> ExecuteTransformBuilder<Unset> {
+            ExecuteTransformBuilder {
+                query_inputs: Unset,
+                prev_checkpoint: None,
+                prev_offset: None,
+                new_data: None,
+                new_checkpoint: None,
+                new_watermark: None,
+            }
+        }
+    }
+
+    impl ExecuteTransformBuilder<Unset> {
+        /// Defines inputs used in this transaction. Slices corresponding to every input dataset must be present.
+        pub fn query_inputs(
+            self,
+            v: impl Into<Vec<dataset::ExecuteTransformInput>>,
+        ) -> ExecuteTransformBuilder<Vec<dataset::ExecuteTransformInput>> {
+            ExecuteTransformBuilder {
+                query_inputs: v.into(),
+                prev_checkpoint: self.prev_checkpoint,
+                prev_offset: self.prev_offset,
+                new_data: self.new_data,
+                new_checkpoint: self.new_checkpoint,
+                new_watermark: self.new_watermark,
+            }
+        }
+    }
+
+    impl<TQueryInputs> ExecuteTransformBuilder<TQueryInputs> {
+        /// Hash of the checkpoint file used to restore transformation state, if any.
+        pub fn prev_checkpoint(mut self, v: impl Into<Multihash>) -> Self {
+            self.prev_checkpoint = Some(v.into());
+            self
+        }
+        /// Last offset of the previous data slice, if any. Must be equal to the last non-empty `newData.offsetInterval.end`.
+        pub fn prev_offset(mut self, v: impl Into<u64>) -> Self {
+            self.prev_offset = Some(v.into());
+            self
+        }
+        /// Describes output data written during this transaction, if any.
+        pub fn new_data(mut self, v: impl Into<dataset::DataSlice>) -> Self {
+            self.new_data = Some(v.into());
+            self
+        }
+        /// Describes checkpoint written during this transaction, if any. If an engine operation resulted in no updates to the checkpoint, but checkpoint is still relevant for subsequent runs - a hash of the previous checkpoint should be specified.
+        pub fn new_checkpoint(mut self, v: impl Into<dataset::Checkpoint>) -> Self {
+            self.new_checkpoint = Some(v.into());
+            self
+        }
+        /// Last watermark of the output data stream, if any. Initial blocks may not have watermarks, but once watermark is set - all subsequent blocks should either carry the same watermark or specify a new (greater) one. Thus, watermarks are monotonically non-decreasing.
+        pub fn new_watermark(mut self, v: impl Into<DateTime<Utc>>) -> Self {
+            self.new_watermark = Some(v.into());
+            self
+        }
+    }
+
+    impl ExecuteTransformBuilder<Vec<dataset::ExecuteTransformInput>> {
+        pub fn build(self) -> ExecuteTransform {
+            ExecuteTransform {
+                query_inputs: self.query_inputs,
+                prev_checkpoint: self.prev_checkpoint,
+                prev_offset: self.prev_offset,
+                new_data: self.new_data,
+                new_checkpoint: self.new_checkpoint,
+                new_watermark: self.new_watermark,
+            }
+        }
+    }
+
     /// Describes a slice of the input dataset used during a transformation
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ExecuteTransformInput
@@ -1139,6 +3785,80 @@ pub mod dataset {
         pub new_offset: Option<u64>,
     }
 
+    /// Builder for [`ExecuteTransformInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ExecuteTransformInputBuilder<TDatasetId = Unset> {
+        dataset_id: TDatasetId,
+        prev_block_hash: Option<Multihash>,
+        new_block_hash: Option<Multihash>,
+        prev_offset: Option<u64>,
+        new_offset: Option<u64>,
+    }
+
+    impl ExecuteTransformInput {
+        pub fn builder() -> ExecuteTransformInputBuilder<Unset> {
+            ExecuteTransformInputBuilder {
+                dataset_id: Unset,
+                prev_block_hash: None,
+                new_block_hash: None,
+                prev_offset: None,
+                new_offset: None,
+            }
+        }
+    }
+
+    impl ExecuteTransformInputBuilder<Unset> {
+        /// Input dataset identifier.
+        pub fn dataset_id(
+            self,
+            v: impl Into<DatasetID>,
+        ) -> ExecuteTransformInputBuilder<DatasetID> {
+            ExecuteTransformInputBuilder {
+                dataset_id: v.into(),
+                prev_block_hash: self.prev_block_hash,
+                new_block_hash: self.new_block_hash,
+                prev_offset: self.prev_offset,
+                new_offset: self.new_offset,
+            }
+        }
+    }
+
+    impl<TDatasetId> ExecuteTransformInputBuilder<TDatasetId> {
+        /// Last block of the input dataset that was previously incorporated into the derivative transformation, if any. Must be equal to the last non-empty `newBlockHash`. Together with `newBlockHash` defines a half-open `(prevBlockHash, newBlockHash]` interval of blocks that will be considered in this transaction.
+        pub fn prev_block_hash(mut self, v: impl Into<Multihash>) -> Self {
+            self.prev_block_hash = Some(v.into());
+            self
+        }
+        /// Hash of the last block that will be incorporated into the derivative transformation. When present, defines a half-open `(prevBlockHash, newBlockHash]` interval of blocks that will be considered in this transaction.
+        pub fn new_block_hash(mut self, v: impl Into<Multihash>) -> Self {
+            self.new_block_hash = Some(v.into());
+            self
+        }
+        /// Last data record offset in the input dataset that was previously incorporated into the derivative transformation, if any. Must be equal to the last non-empty `newOffset`. Together with `newOffset` defines a half-open `(prevOffset, newOffset]` interval of data records that will be considered in this transaction.
+        pub fn prev_offset(mut self, v: impl Into<u64>) -> Self {
+            self.prev_offset = Some(v.into());
+            self
+        }
+        /// Offset of the last data record that will be incorporated into the derivative transformation, if any. When present, defines a half-open `(prevOffset, newOffset]` interval of data records that will be considered in this transaction.
+        pub fn new_offset(mut self, v: impl Into<u64>) -> Self {
+            self.new_offset = Some(v.into());
+            self
+        }
+    }
+
+    impl ExecuteTransformInputBuilder<DatasetID> {
+        pub fn build(self) -> ExecuteTransformInput {
+            ExecuteTransformInput {
+                dataset_id: self.dataset_id,
+                prev_block_hash: self.prev_block_hash,
+                new_block_hash: self.new_block_hash,
+                prev_offset: self.prev_offset,
+                new_offset: self.new_offset,
+            }
+        }
+    }
+
     /// An individual block in the metadata chain that captures the history of modifications of a dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/MetadataBlock
@@ -1154,6 +3874,104 @@ pub mod dataset {
         pub event: dataset::MetadataEvent,
     }
 
+    /// Builder for [`MetadataBlock`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct MetadataBlockBuilder<TSystemTime = Unset, TSequenceNumber = Unset, TEvent = Unset> {
+        system_time: TSystemTime,
+        prev_block_hash: Option<Multihash>,
+        sequence_number: TSequenceNumber,
+        event: TEvent,
+    }
+
+    impl MetadataBlock {
+        pub fn builder() -> MetadataBlockBuilder<Unset, Unset, Unset> {
+            MetadataBlockBuilder {
+                system_time: Unset,
+                prev_block_hash: None,
+                sequence_number: Unset,
+                event: Unset,
+            }
+        }
+    }
+
+    impl<TSequenceNumber, TEvent> MetadataBlockBuilder<Unset, TSequenceNumber, TEvent> {
+        /// System time when this block was written.
+        pub fn system_time(
+            self,
+            v: impl Into<DateTime<Utc>>,
+        ) -> MetadataBlockBuilder<DateTime<Utc>, TSequenceNumber, TEvent> {
+            MetadataBlockBuilder {
+                system_time: v.into(),
+                prev_block_hash: self.prev_block_hash,
+                sequence_number: self.sequence_number,
+                event: self.event,
+            }
+        }
+    }
+
+    impl<TSystemTime, TEvent> MetadataBlockBuilder<TSystemTime, Unset, TEvent> {
+        /// Block sequence number, starting from zero at the seed block.
+        pub fn sequence_number(
+            self,
+            v: impl Into<u64>,
+        ) -> MetadataBlockBuilder<TSystemTime, u64, TEvent> {
+            MetadataBlockBuilder {
+                system_time: self.system_time,
+                prev_block_hash: self.prev_block_hash,
+                sequence_number: v.into(),
+                event: self.event,
+            }
+        }
+    }
+
+    impl<TSystemTime, TSequenceNumber> MetadataBlockBuilder<TSystemTime, TSequenceNumber, Unset> {
+        /// Event data.
+        pub fn event(
+            self,
+            v: impl Into<dataset::MetadataEvent>,
+        ) -> MetadataBlockBuilder<TSystemTime, TSequenceNumber, dataset::MetadataEvent> {
+            MetadataBlockBuilder {
+                system_time: self.system_time,
+                prev_block_hash: self.prev_block_hash,
+                sequence_number: self.sequence_number,
+                event: v.into(),
+            }
+        }
+    }
+
+    impl<TSystemTime, TSequenceNumber, TEvent>
+        MetadataBlockBuilder<TSystemTime, TSequenceNumber, TEvent>
+    {
+        /// Hash sum of the preceding block.
+        pub fn prev_block_hash(mut self, v: impl Into<Multihash>) -> Self {
+            self.prev_block_hash = Some(v.into());
+            self
+        }
+    }
+
+    impl MetadataBlockBuilder<DateTime<Utc>, u64, dataset::MetadataEvent> {
+        pub fn build(self) -> MetadataBlock {
+            MetadataBlock {
+                system_time: self.system_time,
+                prev_block_hash: self.prev_block_hash,
+                sequence_number: self.sequence_number,
+                event: self.event,
+            }
+        }
+    }
+
+    impl Redact for MetadataBlock {
+        fn redact(&self) -> Self {
+            Self {
+                system_time: self.system_time.clone(),
+                prev_block_hash: self.prev_block_hash.clone(),
+                sequence_number: self.sequence_number.clone(),
+                event: self.event.redact(),
+            }
+        }
+    }
+
     /// Represents a transaction that occurred on a dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/MetadataEvent
@@ -1172,6 +3990,7 @@ pub mod dataset {
         AddPushSource(legacy::AddPushSource),
         DisablePushSource(legacy::DisablePushSource),
         DisablePollingSource(legacy::DisablePollingSource),
+        Unknown(UnknownVariant),
     }
 
     impl_enum_with_variants!(MetadataEvent);
@@ -1207,6 +4026,7 @@ pub mod dataset {
             const ADD_PUSH_SOURCE = 1 << 10;
             const DISABLE_PUSH_SOURCE = 1 << 11;
             const DISABLE_POLLING_SOURCE = 1 << 12;
+            const UNKNOWN = 1 << 31;
         }
     }
 
@@ -1226,6 +4046,16 @@ pub mod dataset {
                 MetadataEvent::AddPushSource(_) => Self::ADD_PUSH_SOURCE,
                 MetadataEvent::DisablePushSource(_) => Self::DISABLE_PUSH_SOURCE,
                 MetadataEvent::DisablePollingSource(_) => Self::DISABLE_POLLING_SOURCE,
+                MetadataEvent::Unknown(_) => Self::UNKNOWN,
+            }
+        }
+    }
+
+    impl Redact for MetadataEvent {
+        fn redact(&self) -> Self {
+            match self {
+                Self::SetPollingSource(v) => Self::SetPollingSource(v.redact()),
+                _ => self.clone(),
             }
         }
     }
@@ -1241,6 +4071,52 @@ pub mod dataset {
         pub end: u64,
     }
 
+    /// Builder for [`OffsetInterval`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct OffsetIntervalBuilder<TStart = Unset, TEnd = Unset> {
+        start: TStart,
+        end: TEnd,
+    }
+
+    impl OffsetInterval {
+        pub fn builder() -> OffsetIntervalBuilder<Unset, Unset> {
+            OffsetIntervalBuilder {
+                start: Unset,
+                end: Unset,
+            }
+        }
+    }
+
+    impl<TEnd> OffsetIntervalBuilder<Unset, TEnd> {
+        /// Start of the closed interval [start; end].
+        pub fn start(self, v: impl Into<u64>) -> OffsetIntervalBuilder<u64, TEnd> {
+            OffsetIntervalBuilder {
+                start: v.into(),
+                end: self.end,
+            }
+        }
+    }
+
+    impl<TStart> OffsetIntervalBuilder<TStart, Unset> {
+        /// End of the closed interval [start; end].
+        pub fn end(self, v: impl Into<u64>) -> OffsetIntervalBuilder<TStart, u64> {
+            OffsetIntervalBuilder {
+                start: self.start,
+                end: v.into(),
+            }
+        }
+    }
+
+    impl OffsetIntervalBuilder<u64, u64> {
+        pub fn build(self) -> OffsetInterval {
+            OffsetInterval {
+                start: self.start,
+                end: self.end,
+            }
+        }
+    }
+
     /// Represents a projection of a dataaset history into a state for fast lookups.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Projection
@@ -1267,6 +4143,58 @@ pub mod dataset {
     static PROJECTION_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(PROJECTION_SCHEMA_STR));
 
+    /// Builder for [`Projection`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ProjectionBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl Projection {
+        pub fn builder() -> ProjectionBuilder<Unset, Unset> {
+            ProjectionBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> ProjectionBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> ProjectionBuilder<resource::ResourceHeadersInput, TSpec> {
+            ProjectionBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> ProjectionBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the resource.
+        pub fn spec(
+            self,
+            v: impl Into<dataset::ProjectionSpecInput>,
+        ) -> ProjectionBuilder<THeaders, dataset::ProjectionSpecInput> {
+            ProjectionBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl ProjectionBuilder<resource::ResourceHeadersInput, dataset::ProjectionSpecInput> {
+        pub fn build(self) -> Projection {
+            Projection {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
     /// Represents a projection of a dataaset history into a state for fast lookups.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ProjectionSpec
@@ -1278,6 +4206,58 @@ pub mod dataset {
         pub project: dataset::Transform,
     }
 
+    /// Builder for [`ProjectionSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ProjectionSpecBuilder<TInputs = Unset, TProject = Unset> {
+        inputs: TInputs,
+        project: TProject,
+    }
+
+    impl ProjectionSpec {
+        pub fn builder() -> ProjectionSpecBuilder<Unset, Unset> {
+            ProjectionSpecBuilder {
+                inputs: Unset,
+                project: Unset,
+            }
+        }
+    }
+
+    impl<TProject> ProjectionSpecBuilder<Unset, TProject> {
+        /// Datasets that will be used as sources.
+        pub fn inputs(
+            self,
+            v: impl Into<Vec<dataset::TransformInput>>,
+        ) -> ProjectionSpecBuilder<Vec<dataset::TransformInput>, TProject> {
+            ProjectionSpecBuilder {
+                inputs: v.into(),
+                project: self.project,
+            }
+        }
+    }
+
+    impl<TInputs> ProjectionSpecBuilder<TInputs, Unset> {
+        /// Transformation that will be applied to produce new data.
+        pub fn project(
+            self,
+            v: impl Into<dataset::Transform>,
+        ) -> ProjectionSpecBuilder<TInputs, dataset::Transform> {
+            ProjectionSpecBuilder {
+                inputs: self.inputs,
+                project: v.into(),
+            }
+        }
+    }
+
+    impl ProjectionSpecBuilder<Vec<dataset::TransformInput>, dataset::Transform> {
+        pub fn build(self) -> ProjectionSpec {
+            ProjectionSpec {
+                inputs: self.inputs,
+                project: self.project,
+            }
+        }
+    }
+
     /// Represents a projection of a dataaset history into a state for fast lookups.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ProjectionSpecInput
@@ -1289,6 +4269,58 @@ pub mod dataset {
         pub project: dataset::Transform,
     }
 
+    /// Builder for [`ProjectionSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ProjectionSpecInputBuilder<TInputs = Unset, TProject = Unset> {
+        inputs: TInputs,
+        project: TProject,
+    }
+
+    impl ProjectionSpecInput {
+        pub fn builder() -> ProjectionSpecInputBuilder<Unset, Unset> {
+            ProjectionSpecInputBuilder {
+                inputs: Unset,
+                project: Unset,
+            }
+        }
+    }
+
+    impl<TProject> ProjectionSpecInputBuilder<Unset, TProject> {
+        /// Datasets that will be used as sources.
+        pub fn inputs(
+            self,
+            v: impl Into<Vec<dataset::TransformInput>>,
+        ) -> ProjectionSpecInputBuilder<Vec<dataset::TransformInput>, TProject> {
+            ProjectionSpecInputBuilder {
+                inputs: v.into(),
+                project: self.project,
+            }
+        }
+    }
+
+    impl<TInputs> ProjectionSpecInputBuilder<TInputs, Unset> {
+        /// Transformation that will be applied to produce new data.
+        pub fn project(
+            self,
+            v: impl Into<dataset::Transform>,
+        ) -> ProjectionSpecInputBuilder<TInputs, dataset::Transform> {
+            ProjectionSpecInputBuilder {
+                inputs: self.inputs,
+                project: v.into(),
+            }
+        }
+    }
+
+    impl ProjectionSpecInputBuilder<Vec<dataset::TransformInput>, dataset::Transform> {
+        pub fn build(self) -> ProjectionSpecInput {
+            ProjectionSpecInput {
+                inputs: self.inputs,
+                project: self.project,
+            }
+        }
+    }
+
     /// Establishes the identity of the dataset. Always the first metadata event in the chain.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Seed
@@ -1300,6 +4332,55 @@ pub mod dataset {
         pub dataset_kind: dataset::DatasetKind,
     }
 
+    /// Builder for [`Seed`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SeedBuilder<TDatasetId = Unset, TDatasetKind = Unset> {
+        dataset_id: TDatasetId,
+        dataset_kind: TDatasetKind,
+    }
+
+    impl Seed {
+        pub fn builder() -> SeedBuilder<Unset, Unset> {
+            SeedBuilder {
+                dataset_id: Unset,
+                dataset_kind: Unset,
+            }
+        }
+    }
+
+    impl<TDatasetKind> SeedBuilder<Unset, TDatasetKind> {
+        /// Unique identity of the dataset.
+        pub fn dataset_id(self, v: impl Into<DatasetID>) -> SeedBuilder<DatasetID, TDatasetKind> {
+            SeedBuilder {
+                dataset_id: v.into(),
+                dataset_kind: self.dataset_kind,
+            }
+        }
+    }
+
+    impl<TDatasetId> SeedBuilder<TDatasetId, Unset> {
+        /// Type of the dataset.
+        pub fn dataset_kind(
+            self,
+            v: impl Into<dataset::DatasetKind>,
+        ) -> SeedBuilder<TDatasetId, dataset::DatasetKind> {
+            SeedBuilder {
+                dataset_id: self.dataset_id,
+                dataset_kind: v.into(),
+            }
+        }
+    }
+
+    impl SeedBuilder<DatasetID, dataset::DatasetKind> {
+        pub fn build(self) -> Seed {
+            Seed {
+                dataset_id: self.dataset_id,
+                dataset_kind: self.dataset_kind,
+            }
+        }
+    }
+
     /// Associates a set of files with this dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetAttachments
@@ -1309,6 +4390,39 @@ pub mod dataset {
         pub attachments: dataset::Attachments,
     }
 
+    /// Builder for [`SetAttachments`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SetAttachmentsBuilder<TAttachments = Unset> {
+        attachments: TAttachments,
+    }
+
+    impl SetAttachments {
+        pub fn builder() -> SetAttachmentsBuilder<Unset> {
+            SetAttachmentsBuilder { attachments: Unset }
+        }
+    }
+
+    impl SetAttachmentsBuilder<Unset> {
+        /// One of the supported attachment sources.
+        pub fn attachments(
+            self,
+            v: impl Into<dataset::Attachments>,
+        ) -> SetAttachmentsBuilder<dataset::Attachments> {
+            SetAttachmentsBuilder {
+                attachments: v.into(),
+            }
+        }
+    }
+
+    impl SetAttachmentsBuilder<dataset::Attachments> {
+        pub fn build(self) -> SetAttachments {
+            SetAttachments {
+                attachments: self.attachments,
+            }
+        }
+    }
+
     /// Specifies the complete schema of Data Slices added to the Dataset following this event.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetDataSchema
@@ -1320,6 +4434,45 @@ pub mod dataset {
         pub schema: Option<data::DataSchema>,
     }
 
+    /// Builder for [`SetDataSchema`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SetDataSchemaBuilder {
+        raw_arrow_schema: Option<Vec<u8>>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl SetDataSchema {
+        pub fn builder() -> SetDataSchemaBuilder {
+            SetDataSchemaBuilder {
+                raw_arrow_schema: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl SetDataSchemaBuilder {
+        /// DEPRECATED: Apache Arrow schema encoded in its native flatbuffers representation.
+        pub fn raw_arrow_schema(mut self, v: impl Into<Vec<u8>>) -> Self {
+            self.raw_arrow_schema = Some(v.into());
+            self
+        }
+        /// Defines the logical schema of the data files that follow this event. Will become a required field after migration.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl SetDataSchemaBuilder {
+        pub fn build(self) -> SetDataSchema {
+            SetDataSchema {
+                raw_arrow_schema: self.raw_arrow_schema,
+                schema: self.schema,
+            }
+        }
+    }
+
     /// Provides basic human-readable information about a dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetInfo
@@ -1331,7 +4484,46 @@ pub mod dataset {
         pub keywords: Option<Vec<String>>,
     }
 
-    /// Defines a license that applies to this dataset.
+    /// Builder for [`SetInfo`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SetInfoBuilder {
+        description: Option<String>,
+        keywords: Option<Vec<String>>,
+    }
+
+    impl SetInfo {
+        pub fn builder() -> SetInfoBuilder {
+            SetInfoBuilder {
+                description: None,
+                keywords: None,
+            }
+        }
+    }
+
+    impl SetInfoBuilder {
+        /// Brief single-sentence summary of a dataset.
+        pub fn description(mut self, v: impl Into<String>) -> Self {
+            self.description = Some(v.into());
+            self
+        }
+        /// Keywords, search terms, or tags used to describe the dataset.
+        pub fn keywords(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.keywords = Some(v.into());
+            self
+        }
+    }
+
+    impl SetInfoBuilder {
+        pub fn build(self) -> SetInfo {
+            SetInfo {
+                description: self.description,
+                keywords: self.keywords,
+            }
+        }
+    }
+
+    /// Defines a license that applies to this dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetLicense
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1346,6 +4538,91 @@ pub mod dataset {
         pub website_url: String,
     }
 
+    /// Builder for [`SetLicense`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SetLicenseBuilder<TShortName = Unset, TName = Unset, TWebsiteUrl = Unset> {
+        short_name: TShortName,
+        name: TName,
+        spdx_id: Option<String>,
+        website_url: TWebsiteUrl,
+    }
+
+    impl SetLicense {
+        pub fn builder() -> SetLicenseBuilder<Unset, Unset, Unset> {
+            SetLicenseBuilder {
+                short_name: Unset,
+                name: Unset,
+                spdx_id: None,
+                website_url: Unset,
+            }
+        }
+    }
+
+    impl<TName, TWebsiteUrl> SetLicenseBuilder<Unset, TName, TWebsiteUrl> {
+        /// Abbreviated name of the license.
+        pub fn short_name(
+            self,
+            v: impl Into<String>,
+        ) -> SetLicenseBuilder<String, TName, TWebsiteUrl> {
+            SetLicenseBuilder {
+                short_name: v.into(),
+                name: self.name,
+                spdx_id: self.spdx_id,
+                website_url: self.website_url,
+            }
+        }
+    }
+
+    impl<TShortName, TWebsiteUrl> SetLicenseBuilder<TShortName, Unset, TWebsiteUrl> {
+        /// Full name of the license.
+        pub fn name(
+            self,
+            v: impl Into<String>,
+        ) -> SetLicenseBuilder<TShortName, String, TWebsiteUrl> {
+            SetLicenseBuilder {
+                short_name: self.short_name,
+                name: v.into(),
+                spdx_id: self.spdx_id,
+                website_url: self.website_url,
+            }
+        }
+    }
+
+    impl<TShortName, TName> SetLicenseBuilder<TShortName, TName, Unset> {
+        /// URL where licensing terms can be found.
+        pub fn website_url(
+            self,
+            v: impl Into<String>,
+        ) -> SetLicenseBuilder<TShortName, TName, String> {
+            SetLicenseBuilder {
+                short_name: self.short_name,
+                name: self.name,
+                spdx_id: self.spdx_id,
+                website_url: v.into(),
+            }
+        }
+    }
+
+    impl<TShortName, TName, TWebsiteUrl> SetLicenseBuilder<TShortName, TName, TWebsiteUrl> {
+        /// License identifier from the SPDX License List.
+        pub fn spdx_id(mut self, v: impl Into<String>) -> Self {
+            self.spdx_id = Some(v.into());
+            self
+        }
+    }
+
+    impl SetLicenseBuilder<String, String, String> {
+        pub fn build(self) -> SetLicense {
+            SetLicense {
+                short_name: self.short_name,
+                name: self.name,
+                spdx_id: self.spdx_id,
+                website_url: self.website_url,
+            }
+        }
+    }
+
     /// Defines a transformation that produces data in a derivative dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetTransform
@@ -1357,6 +4634,58 @@ pub mod dataset {
         pub transform: dataset::Transform,
     }
 
+    /// Builder for [`SetTransform`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SetTransformBuilder<TInputs = Unset, TTransform = Unset> {
+        inputs: TInputs,
+        transform: TTransform,
+    }
+
+    impl SetTransform {
+        pub fn builder() -> SetTransformBuilder<Unset, Unset> {
+            SetTransformBuilder {
+                inputs: Unset,
+                transform: Unset,
+            }
+        }
+    }
+
+    impl<TTransform> SetTransformBuilder<Unset, TTransform> {
+        /// Datasets that will be used as sources.
+        pub fn inputs(
+            self,
+            v: impl Into<Vec<dataset::TransformInput>>,
+        ) -> SetTransformBuilder<Vec<dataset::TransformInput>, TTransform> {
+            SetTransformBuilder {
+                inputs: v.into(),
+                transform: self.transform,
+            }
+        }
+    }
+
+    impl<TInputs> SetTransformBuilder<TInputs, Unset> {
+        /// Transformation that will be applied to produce new data.
+        pub fn transform(
+            self,
+            v: impl Into<dataset::Transform>,
+        ) -> SetTransformBuilder<TInputs, dataset::Transform> {
+            SetTransformBuilder {
+                inputs: self.inputs,
+                transform: v.into(),
+            }
+        }
+    }
+
+    impl SetTransformBuilder<Vec<dataset::TransformInput>, dataset::Transform> {
+        pub fn build(self) -> SetTransform {
+            SetTransform {
+                inputs: self.inputs,
+                transform: self.transform,
+            }
+        }
+    }
+
     /// Lets you manipulate names of the system columns to avoid conflicts.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetVocab
@@ -1372,6 +4701,61 @@ pub mod dataset {
         pub event_time_column: Option<String>,
     }
 
+    /// Builder for [`SetVocab`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SetVocabBuilder {
+        offset_column: Option<String>,
+        operation_type_column: Option<String>,
+        system_time_column: Option<String>,
+        event_time_column: Option<String>,
+    }
+
+    impl SetVocab {
+        pub fn builder() -> SetVocabBuilder {
+            SetVocabBuilder {
+                offset_column: None,
+                operation_type_column: None,
+                system_time_column: None,
+                event_time_column: None,
+            }
+        }
+    }
+
+    impl SetVocabBuilder {
+        /// Name of the offset column.
+        pub fn offset_column(mut self, v: impl Into<String>) -> Self {
+            self.offset_column = Some(v.into());
+            self
+        }
+        /// Name of the operation type column.
+        pub fn operation_type_column(mut self, v: impl Into<String>) -> Self {
+            self.operation_type_column = Some(v.into());
+            self
+        }
+        /// Name of the system time column.
+        pub fn system_time_column(mut self, v: impl Into<String>) -> Self {
+            self.system_time_column = Some(v.into());
+            self
+        }
+        /// Name of the event time column.
+        pub fn event_time_column(mut self, v: impl Into<String>) -> Self {
+            self.event_time_column = Some(v.into());
+            self
+        }
+    }
+
+    impl SetVocabBuilder {
+        pub fn build(self) -> SetVocab {
+            SetVocab {
+                offset_column: self.offset_column,
+                operation_type_column: self.operation_type_column,
+                system_time_column: self.system_time_column,
+                event_time_column: self.event_time_column,
+            }
+        }
+    }
+
     /// Defines a query in a multi-step SQL transformation.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SqlQueryStep
@@ -1383,6 +4767,50 @@ pub mod dataset {
         pub query: String,
     }
 
+    /// Builder for [`SqlQueryStep`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SqlQueryStepBuilder<TQuery = Unset> {
+        alias: Option<String>,
+        query: TQuery,
+    }
+
+    impl SqlQueryStep {
+        pub fn builder() -> SqlQueryStepBuilder<Unset> {
+            SqlQueryStepBuilder {
+                alias: None,
+                query: Unset,
+            }
+        }
+    }
+
+    impl SqlQueryStepBuilder<Unset> {
+        /// SQL query the result of which will be exposed under the alias.
+        pub fn query(self, v: impl Into<String>) -> SqlQueryStepBuilder<String> {
+            SqlQueryStepBuilder {
+                alias: self.alias,
+                query: v.into(),
+            }
+        }
+    }
+
+    impl<TQuery> SqlQueryStepBuilder<TQuery> {
+        /// Name of the temporary view that will be created from result of the query. Step without this alias will be treated as an output of the transformation.
+        pub fn alias(mut self, v: impl Into<String>) -> Self {
+            self.alias = Some(v.into());
+            self
+        }
+    }
+
+    impl SqlQueryStepBuilder<String> {
+        pub fn build(self) -> SqlQueryStep {
+            SqlQueryStep {
+                alias: self.alias,
+                query: self.query,
+            }
+        }
+    }
+
     /// Temporary Flink-specific extension for creating temporal tables from streams.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/TemporalTable
@@ -1394,6 +4822,55 @@ pub mod dataset {
         pub primary_key: Vec<String>,
     }
 
+    /// Builder for [`TemporalTable`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TemporalTableBuilder<TName = Unset, TPrimaryKey = Unset> {
+        name: TName,
+        primary_key: TPrimaryKey,
+    }
+
+    impl TemporalTable {
+        pub fn builder() -> TemporalTableBuilder<Unset, Unset> {
+            TemporalTableBuilder {
+                name: Unset,
+                primary_key: Unset,
+            }
+        }
+    }
+
+    impl<TPrimaryKey> TemporalTableBuilder<Unset, TPrimaryKey> {
+        /// Name of the dataset to be converted into a temporal table.
+        pub fn name(self, v: impl Into<String>) -> TemporalTableBuilder<String, TPrimaryKey> {
+            TemporalTableBuilder {
+                name: v.into(),
+                primary_key: self.primary_key,
+            }
+        }
+    }
+
+    impl<TName> TemporalTableBuilder<TName, Unset> {
+        /// Column names used as the primary key for creating a table.
+        pub fn primary_key(
+            self,
+            v: impl Into<Vec<String>>,
+        ) -> TemporalTableBuilder<TName, Vec<String>> {
+            TemporalTableBuilder {
+                name: self.name,
+                primary_key: v.into(),
+            }
+        }
+    }
+
+    impl TemporalTableBuilder<String, Vec<String>> {
+        pub fn build(self) -> TemporalTable {
+            TemporalTable {
+                name: self.name,
+                primary_key: self.primary_key,
+            }
+        }
+    }
+
     /// Engine-specific processing queries that shape the resulting data.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Transform
@@ -1416,6 +4893,50 @@ pub mod dataset {
         pub alias: Option<String>,
     }
 
+    /// Builder for [`TransformInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransformInputBuilder<TDatasetRef = Unset> {
+        dataset_ref: TDatasetRef,
+        alias: Option<String>,
+    }
+
+    impl TransformInput {
+        pub fn builder() -> TransformInputBuilder<Unset> {
+            TransformInputBuilder {
+                dataset_ref: Unset,
+                alias: None,
+            }
+        }
+    }
+
+    impl TransformInputBuilder<Unset> {
+        /// A local or remote dataset reference. When block is accepted this MUST be in the form of a DatasetId to guarantee reproducibility, as aliases can change over time.
+        pub fn dataset_ref(self, v: impl Into<DatasetRef>) -> TransformInputBuilder<DatasetRef> {
+            TransformInputBuilder {
+                dataset_ref: v.into(),
+                alias: self.alias,
+            }
+        }
+    }
+
+    impl<TDatasetRef> TransformInputBuilder<TDatasetRef> {
+        /// An alias under which this input will be available in queries. Will be populated from `datasetRef` if not provided before resolving it to DatasetId.
+        pub fn alias(mut self, v: impl Into<String>) -> Self {
+            self.alias = Some(v.into());
+            self
+        }
+    }
+
+    impl TransformInputBuilder<DatasetRef> {
+        pub fn build(self) -> TransformInput {
+            TransformInput {
+                dataset_ref: self.dataset_ref,
+                alias: self.alias,
+            }
+        }
+    }
+
     /// Transform using one of the SQL dialects.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Transform#/$defs/Sql
@@ -1433,6 +4954,77 @@ pub mod dataset {
         pub temporal_tables: Option<Vec<dataset::TemporalTable>>,
     }
 
+    /// Builder for [`TransformSql`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransformSqlBuilder<TEngine = Unset> {
+        engine: TEngine,
+        version: Option<String>,
+        query: Option<String>,
+        queries: Option<Vec<dataset::SqlQueryStep>>,
+        temporal_tables: Option<Vec<dataset::TemporalTable>>,
+    }
+
+    impl TransformSql {
+        pub fn builder() -> TransformSqlBuilder<Unset> {
+            TransformSqlBuilder {
+                engine: Unset,
+                version: None,
+                query: None,
+                queries: None,
+                temporal_tables: None,
+            }
+        }
+    }
+
+    impl TransformSqlBuilder<Unset> {
+        /// Identifier of the engine used for this transformation.
+        pub fn engine(self, v: impl Into<String>) -> TransformSqlBuilder<String> {
+            TransformSqlBuilder {
+                engine: v.into(),
+                version: self.version,
+                query: self.query,
+                queries: self.queries,
+                temporal_tables: self.temporal_tables,
+            }
+        }
+    }
+
+    impl<TEngine> TransformSqlBuilder<TEngine> {
+        /// Version of the engine to use.
+        pub fn version(mut self, v: impl Into<String>) -> Self {
+            self.version = Some(v.into());
+            self
+        }
+        /// SQL query the result of which will be used as an output. This is a convenience property meant only for defining queries by hand. When stored in the metadata this property will never be set and instead will be converted into a single-iter `queries` array.
+        pub fn query(mut self, v: impl Into<String>) -> Self {
+            self.query = Some(v.into());
+            self
+        }
+        /// Specifies multi-step SQL transformations. Each step acts as a shorthand for `CREATE TEMPORARY VIEW <alias> AS (<query>)`. Last query in the array should have no alias and will be treated as an output.
+        pub fn queries(mut self, v: impl Into<Vec<dataset::SqlQueryStep>>) -> Self {
+            self.queries = Some(v.into());
+            self
+        }
+        /// Temporary Flink-specific extension for creating temporal tables from streams.
+        pub fn temporal_tables(mut self, v: impl Into<Vec<dataset::TemporalTable>>) -> Self {
+            self.temporal_tables = Some(v.into());
+            self
+        }
+    }
+
+    impl TransformSqlBuilder<String> {
+        pub fn build(self) -> TransformSql {
+            TransformSql {
+                engine: self.engine,
+                version: self.version,
+                query: self.query,
+                queries: self.queries,
+                temporal_tables: self.temporal_tables,
+            }
+        }
+    }
+
     /// Represents a watermark in the event stream.
     ///
     /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Watermark
@@ -1443,6 +5035,58 @@ pub mod dataset {
         /// Moment in event time which watermark has reached.
         pub event_time: DateTime<Utc>,
     }
+
+    /// Builder for [`Watermark`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct WatermarkBuilder<TSystemTime = Unset, TEventTime = Unset> {
+        system_time: TSystemTime,
+        event_time: TEventTime,
+    }
+
+    impl Watermark {
+        pub fn builder() -> WatermarkBuilder<Unset, Unset> {
+            WatermarkBuilder {
+                system_time: Unset,
+                event_time: Unset,
+            }
+        }
+    }
+
+    impl<TEventTime> WatermarkBuilder<Unset, TEventTime> {
+        /// Moment in processing time when watermark was emitted.
+        pub fn system_time(
+            self,
+            v: impl Into<DateTime<Utc>>,
+        ) -> WatermarkBuilder<DateTime<Utc>, TEventTime> {
+            WatermarkBuilder {
+                system_time: v.into(),
+                event_time: self.event_time,
+            }
+        }
+    }
+
+    impl<TSystemTime> WatermarkBuilder<TSystemTime, Unset> {
+        /// Moment in event time which watermark has reached.
+        pub fn event_time(
+            self,
+            v: impl Into<DateTime<Utc>>,
+        ) -> WatermarkBuilder<TSystemTime, DateTime<Utc>> {
+            WatermarkBuilder {
+                system_time: self.system_time,
+                event_time: v.into(),
+            }
+        }
+    }
+
+    impl WatermarkBuilder<DateTime<Utc>, DateTime<Utc>> {
+        pub fn build(self) -> Watermark {
+            Watermark {
+                system_time: self.system_time,
+                event_time: self.event_time,
+            }
+        }
+    }
 }
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // engine
@@ -1465,6 +5109,83 @@ pub mod engine {
         pub output_data_path: PathBuf,
     }
 
+    /// Builder for [`RawQueryRequest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RawQueryRequestBuilder<
+        TInputDataPaths = Unset,
+        TTransform = Unset,
+        TOutputDataPath = Unset,
+    > {
+        input_data_paths: TInputDataPaths,
+        transform: TTransform,
+        output_data_path: TOutputDataPath,
+    }
+
+    impl RawQueryRequest {
+        pub fn builder() -> RawQueryRequestBuilder<Unset, Unset, Unset> {
+            RawQueryRequestBuilder {
+                input_data_paths: Unset,
+                transform: Unset,
+                output_data_path: Unset,
+            }
+        }
+    }
+
+    impl<TTransform, TOutputDataPath> RawQueryRequestBuilder<Unset, TTransform, TOutputDataPath> {
+        /// Paths to input data files to perform query over. Must all have identical schema.
+        pub fn input_data_paths(
+            self,
+            v: impl Into<Vec<PathBuf>>,
+        ) -> RawQueryRequestBuilder<Vec<PathBuf>, TTransform, TOutputDataPath> {
+            RawQueryRequestBuilder {
+                input_data_paths: v.into(),
+                transform: self.transform,
+                output_data_path: self.output_data_path,
+            }
+        }
+    }
+
+    impl<TInputDataPaths, TOutputDataPath>
+        RawQueryRequestBuilder<TInputDataPaths, Unset, TOutputDataPath>
+    {
+        /// Transformation that will be applied to produce new data.
+        pub fn transform(
+            self,
+            v: impl Into<dataset::Transform>,
+        ) -> RawQueryRequestBuilder<TInputDataPaths, dataset::Transform, TOutputDataPath> {
+            RawQueryRequestBuilder {
+                input_data_paths: self.input_data_paths,
+                transform: v.into(),
+                output_data_path: self.output_data_path,
+            }
+        }
+    }
+
+    impl<TInputDataPaths, TTransform> RawQueryRequestBuilder<TInputDataPaths, TTransform, Unset> {
+        /// Path where query result will be written.
+        pub fn output_data_path(
+            self,
+            v: impl Into<PathBuf>,
+        ) -> RawQueryRequestBuilder<TInputDataPaths, TTransform, PathBuf> {
+            RawQueryRequestBuilder {
+                input_data_paths: self.input_data_paths,
+                transform: self.transform,
+                output_data_path: v.into(),
+            }
+        }
+    }
+
+    impl RawQueryRequestBuilder<Vec<PathBuf>, dataset::Transform, PathBuf> {
+        pub fn build(self) -> RawQueryRequest {
+            RawQueryRequest {
+                input_data_paths: self.input_data_paths,
+                transform: self.transform,
+                output_data_path: self.output_data_path,
+            }
+        }
+    }
+
     /// Sent by an engine to coordinator when performing the raw query operation
     ///
     /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse
@@ -1497,6 +5218,50 @@ pub mod engine {
         pub backtrace: Option<String>,
     }
 
+    /// Builder for [`RawQueryResponseInternalError`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RawQueryResponseInternalErrorBuilder<TMessage = Unset> {
+        message: TMessage,
+        backtrace: Option<String>,
+    }
+
+    impl RawQueryResponseInternalError {
+        pub fn builder() -> RawQueryResponseInternalErrorBuilder<Unset> {
+            RawQueryResponseInternalErrorBuilder {
+                message: Unset,
+                backtrace: None,
+            }
+        }
+    }
+
+    impl RawQueryResponseInternalErrorBuilder<Unset> {
+        /// Brief description of an error
+        pub fn message(self, v: impl Into<String>) -> RawQueryResponseInternalErrorBuilder<String> {
+            RawQueryResponseInternalErrorBuilder {
+                message: v.into(),
+                backtrace: self.backtrace,
+            }
+        }
+    }
+
+    impl<TMessage> RawQueryResponseInternalErrorBuilder<TMessage> {
+        /// Details of an error (e.g. a backtrace)
+        pub fn backtrace(mut self, v: impl Into<String>) -> Self {
+            self.backtrace = Some(v.into());
+            self
+        }
+    }
+
+    impl RawQueryResponseInternalErrorBuilder<String> {
+        pub fn build(self) -> RawQueryResponseInternalError {
+            RawQueryResponseInternalError {
+                message: self.message,
+                backtrace: self.backtrace,
+            }
+        }
+    }
+
     /// Query did not pass validation
     ///
     /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/InvalidQuery
@@ -1506,6 +5271,34 @@ pub mod engine {
         pub message: String,
     }
 
+    /// Builder for [`RawQueryResponseInvalidQuery`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RawQueryResponseInvalidQueryBuilder<TMessage = Unset> {
+        message: TMessage,
+    }
+
+    impl RawQueryResponseInvalidQuery {
+        pub fn builder() -> RawQueryResponseInvalidQueryBuilder<Unset> {
+            RawQueryResponseInvalidQueryBuilder { message: Unset }
+        }
+    }
+
+    impl RawQueryResponseInvalidQueryBuilder<Unset> {
+        /// Explanation of an error
+        pub fn message(self, v: impl Into<String>) -> RawQueryResponseInvalidQueryBuilder<String> {
+            RawQueryResponseInvalidQueryBuilder { message: v.into() }
+        }
+    }
+
+    impl RawQueryResponseInvalidQueryBuilder<String> {
+        pub fn build(self) -> RawQueryResponseInvalidQuery {
+            RawQueryResponseInvalidQuery {
+                message: self.message,
+            }
+        }
+    }
+
     /// Reports query progress
     ///
     /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/Progress
@@ -1521,16 +5314,46 @@ pub mod engine {
         pub num_records: u64,
     }
 
-    /// Sent by the coordinator to an engine to perform the next step of data transformation
-    ///
-    /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformRequest
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct TransformRequest {
-        /// Unique identifier of the output dataset.
-        pub dataset_id: DatasetID,
-        /// Alias of the output dataset, for logging purposes only.
-        pub dataset_alias: DatasetAlias,
-        /// System time to use for new records.
+    /// Builder for [`RawQueryResponseSuccess`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RawQueryResponseSuccessBuilder<TNumRecords = Unset> {
+        num_records: TNumRecords,
+    }
+
+    impl RawQueryResponseSuccess {
+        pub fn builder() -> RawQueryResponseSuccessBuilder<Unset> {
+            RawQueryResponseSuccessBuilder { num_records: Unset }
+        }
+    }
+
+    impl RawQueryResponseSuccessBuilder<Unset> {
+        /// Number of records produced by the query
+        pub fn num_records(self, v: impl Into<u64>) -> RawQueryResponseSuccessBuilder<u64> {
+            RawQueryResponseSuccessBuilder {
+                num_records: v.into(),
+            }
+        }
+    }
+
+    impl RawQueryResponseSuccessBuilder<u64> {
+        pub fn build(self) -> RawQueryResponseSuccess {
+            RawQueryResponseSuccess {
+                num_records: self.num_records,
+            }
+        }
+    }
+
+    /// Sent by the coordinator to an engine to perform the next step of data transformation
+    ///
+    /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformRequest
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct TransformRequest {
+        /// Unique identifier of the output dataset.
+        pub dataset_id: DatasetID,
+        /// Alias of the output dataset, for logging purposes only.
+        pub dataset_alias: DatasetAlias,
+        /// System time to use for new records.
         pub system_time: DateTime<Utc>,
         /// Vocabulary of the output dataset.
         pub vocab: dataset::DatasetVocabulary,
@@ -1548,6 +5371,578 @@ pub mod engine {
         pub new_data_path: PathBuf,
     }
 
+    /// Builder for [`TransformRequest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransformRequestBuilder<
+        TDatasetId = Unset,
+        TDatasetAlias = Unset,
+        TSystemTime = Unset,
+        TVocab = Unset,
+        TTransform = Unset,
+        TQueryInputs = Unset,
+        TNextOffset = Unset,
+        TNewCheckpointPath = Unset,
+        TNewDataPath = Unset,
+    > {
+        dataset_id: TDatasetId,
+        dataset_alias: TDatasetAlias,
+        system_time: TSystemTime,
+        vocab: TVocab,
+        transform: TTransform,
+        query_inputs: TQueryInputs,
+        next_offset: TNextOffset,
+        prev_checkpoint_path: Option<PathBuf>,
+        new_checkpoint_path: TNewCheckpointPath,
+        new_data_path: TNewDataPath,
+    }
+
+    impl TransformRequest {
+        pub fn builder()
+        -> TransformRequestBuilder<Unset, Unset, Unset, Unset, Unset, Unset, Unset, Unset, Unset>
+        {
+            TransformRequestBuilder {
+                dataset_id: Unset,
+                dataset_alias: Unset,
+                system_time: Unset,
+                vocab: Unset,
+                transform: Unset,
+                query_inputs: Unset,
+                next_offset: Unset,
+                prev_checkpoint_path: None,
+                new_checkpoint_path: Unset,
+                new_data_path: Unset,
+            }
+        }
+    }
+
+    impl<
+        TDatasetAlias,
+        TSystemTime,
+        TVocab,
+        TTransform,
+        TQueryInputs,
+        TNextOffset,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            Unset,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// Unique identifier of the output dataset.
+        pub fn dataset_id(
+            self,
+            v: impl Into<DatasetID>,
+        ) -> TransformRequestBuilder<
+            DatasetID,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: v.into(),
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TSystemTime,
+        TVocab,
+        TTransform,
+        TQueryInputs,
+        TNextOffset,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            Unset,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// Alias of the output dataset, for logging purposes only.
+        pub fn dataset_alias(
+            self,
+            v: impl Into<DatasetAlias>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            DatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: v.into(),
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TVocab,
+        TTransform,
+        TQueryInputs,
+        TNextOffset,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            Unset,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// System time to use for new records.
+        pub fn system_time(
+            self,
+            v: impl Into<DateTime<Utc>>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            DateTime<Utc>,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: v.into(),
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TSystemTime,
+        TTransform,
+        TQueryInputs,
+        TNextOffset,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            Unset,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// Vocabulary of the output dataset.
+        pub fn vocab(
+            self,
+            v: impl Into<dataset::DatasetVocabulary>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            dataset::DatasetVocabulary,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: v.into(),
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TSystemTime,
+        TVocab,
+        TQueryInputs,
+        TNextOffset,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            Unset,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// Transformation that will be applied to produce new data.
+        pub fn transform(
+            self,
+            v: impl Into<dataset::Transform>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            dataset::Transform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: v.into(),
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TSystemTime,
+        TVocab,
+        TTransform,
+        TNextOffset,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            Unset,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// Defines inputs used in this transaction. Slices corresponding to every input dataset must be present.
+        pub fn query_inputs(
+            self,
+            v: impl Into<Vec<engine::TransformRequestInput>>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            Vec<engine::TransformRequestInput>,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: v.into(),
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TSystemTime,
+        TVocab,
+        TTransform,
+        TQueryInputs,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            Unset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// Starting offset to use for new data records.
+        pub fn next_offset(
+            self,
+            v: impl Into<u64>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            u64,
+            TNewCheckpointPath,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: v.into(),
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TSystemTime,
+        TVocab,
+        TTransform,
+        TQueryInputs,
+        TNextOffset,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            Unset,
+            TNewDataPath,
+        >
+    {
+        /// TODO: This will be removed when coordinator will be speaking to engines purely through Arrow.
+        pub fn new_checkpoint_path(
+            self,
+            v: impl Into<PathBuf>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            PathBuf,
+            TNewDataPath,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: v.into(),
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TSystemTime,
+        TVocab,
+        TTransform,
+        TQueryInputs,
+        TNextOffset,
+        TNewCheckpointPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            Unset,
+        >
+    {
+        /// TODO: This will be removed when coordinator will be speaking to engines purely through Arrow.
+        pub fn new_data_path(
+            self,
+            v: impl Into<PathBuf>,
+        ) -> TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            PathBuf,
+        > {
+            TransformRequestBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: v.into(),
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TSystemTime,
+        TVocab,
+        TTransform,
+        TQueryInputs,
+        TNextOffset,
+        TNewCheckpointPath,
+        TNewDataPath,
+    >
+        TransformRequestBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TSystemTime,
+            TVocab,
+            TTransform,
+            TQueryInputs,
+            TNextOffset,
+            TNewCheckpointPath,
+            TNewDataPath,
+        >
+    {
+        /// TODO: This will be removed when coordinator will be speaking to engines purely through Arrow.
+        pub fn prev_checkpoint_path(mut self, v: impl Into<PathBuf>) -> Self {
+            self.prev_checkpoint_path = Some(v.into());
+            self
+        }
+    }
+
+    impl
+        TransformRequestBuilder<
+            DatasetID,
+            DatasetAlias,
+            DateTime<Utc>,
+            dataset::DatasetVocabulary,
+            dataset::Transform,
+            Vec<engine::TransformRequestInput>,
+            u64,
+            PathBuf,
+            PathBuf,
+        >
+    {
+        pub fn build(self) -> TransformRequest {
+            TransformRequest {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                system_time: self.system_time,
+                vocab: self.vocab,
+                transform: self.transform,
+                query_inputs: self.query_inputs,
+                next_offset: self.next_offset,
+                prev_checkpoint_path: self.prev_checkpoint_path,
+                new_checkpoint_path: self.new_checkpoint_path,
+                new_data_path: self.new_data_path,
+            }
+        }
+    }
+
     /// Sent as part of the engine transform request operation to describe the input
     ///
     /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformRequestInput
@@ -1571,6 +5966,354 @@ pub mod engine {
         pub explicit_watermarks: Vec<dataset::Watermark>,
     }
 
+    /// Builder for [`TransformRequestInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransformRequestInputBuilder<
+        TDatasetId = Unset,
+        TDatasetAlias = Unset,
+        TQueryAlias = Unset,
+        TVocab = Unset,
+        TDataPaths = Unset,
+        TSchemaFile = Unset,
+        TExplicitWatermarks = Unset,
+    > {
+        dataset_id: TDatasetId,
+        dataset_alias: TDatasetAlias,
+        query_alias: TQueryAlias,
+        vocab: TVocab,
+        offset_interval: Option<dataset::OffsetInterval>,
+        data_paths: TDataPaths,
+        schema_file: TSchemaFile,
+        explicit_watermarks: TExplicitWatermarks,
+    }
+
+    impl TransformRequestInput {
+        pub fn builder()
+        -> TransformRequestInputBuilder<Unset, Unset, Unset, Unset, Unset, Unset, Unset> {
+            TransformRequestInputBuilder {
+                dataset_id: Unset,
+                dataset_alias: Unset,
+                query_alias: Unset,
+                vocab: Unset,
+                offset_interval: None,
+                data_paths: Unset,
+                schema_file: Unset,
+                explicit_watermarks: Unset,
+            }
+        }
+    }
+
+    impl<TDatasetAlias, TQueryAlias, TVocab, TDataPaths, TSchemaFile, TExplicitWatermarks>
+        TransformRequestInputBuilder<
+            Unset,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        >
+    {
+        /// Unique identifier of the dataset.
+        pub fn dataset_id(
+            self,
+            v: impl Into<DatasetID>,
+        ) -> TransformRequestInputBuilder<
+            DatasetID,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        > {
+            TransformRequestInputBuilder {
+                dataset_id: v.into(),
+                dataset_alias: self.dataset_alias,
+                query_alias: self.query_alias,
+                vocab: self.vocab,
+                offset_interval: self.offset_interval,
+                data_paths: self.data_paths,
+                schema_file: self.schema_file,
+                explicit_watermarks: self.explicit_watermarks,
+            }
+        }
+    }
+
+    impl<TDatasetId, TQueryAlias, TVocab, TDataPaths, TSchemaFile, TExplicitWatermarks>
+        TransformRequestInputBuilder<
+            TDatasetId,
+            Unset,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        >
+    {
+        /// Alias of the output dataset, for logging purposes only.
+        pub fn dataset_alias(
+            self,
+            v: impl Into<DatasetAlias>,
+        ) -> TransformRequestInputBuilder<
+            TDatasetId,
+            DatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        > {
+            TransformRequestInputBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: v.into(),
+                query_alias: self.query_alias,
+                vocab: self.vocab,
+                offset_interval: self.offset_interval,
+                data_paths: self.data_paths,
+                schema_file: self.schema_file,
+                explicit_watermarks: self.explicit_watermarks,
+            }
+        }
+    }
+
+    impl<TDatasetId, TDatasetAlias, TVocab, TDataPaths, TSchemaFile, TExplicitWatermarks>
+        TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            Unset,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        >
+    {
+        /// An alias of this input to be used in queries.
+        pub fn query_alias(
+            self,
+            v: impl Into<String>,
+        ) -> TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            String,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        > {
+            TransformRequestInputBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                query_alias: v.into(),
+                vocab: self.vocab,
+                offset_interval: self.offset_interval,
+                data_paths: self.data_paths,
+                schema_file: self.schema_file,
+                explicit_watermarks: self.explicit_watermarks,
+            }
+        }
+    }
+
+    impl<TDatasetId, TDatasetAlias, TQueryAlias, TDataPaths, TSchemaFile, TExplicitWatermarks>
+        TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            Unset,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        >
+    {
+        /// Vocabulary of the input dataset.
+        pub fn vocab(
+            self,
+            v: impl Into<dataset::DatasetVocabulary>,
+        ) -> TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            dataset::DatasetVocabulary,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        > {
+            TransformRequestInputBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                query_alias: self.query_alias,
+                vocab: v.into(),
+                offset_interval: self.offset_interval,
+                data_paths: self.data_paths,
+                schema_file: self.schema_file,
+                explicit_watermarks: self.explicit_watermarks,
+            }
+        }
+    }
+
+    impl<TDatasetId, TDatasetAlias, TQueryAlias, TVocab, TSchemaFile, TExplicitWatermarks>
+        TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            Unset,
+            TSchemaFile,
+            TExplicitWatermarks,
+        >
+    {
+        /// TODO: This will be removed when coordinator will be slicing data for the engine.
+        pub fn data_paths(
+            self,
+            v: impl Into<Vec<PathBuf>>,
+        ) -> TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            Vec<PathBuf>,
+            TSchemaFile,
+            TExplicitWatermarks,
+        > {
+            TransformRequestInputBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                query_alias: self.query_alias,
+                vocab: self.vocab,
+                offset_interval: self.offset_interval,
+                data_paths: v.into(),
+                schema_file: self.schema_file,
+                explicit_watermarks: self.explicit_watermarks,
+            }
+        }
+    }
+
+    impl<TDatasetId, TDatasetAlias, TQueryAlias, TVocab, TDataPaths, TExplicitWatermarks>
+        TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            Unset,
+            TExplicitWatermarks,
+        >
+    {
+        /// TODO: replace with actual DDL or Parquet schema.
+        pub fn schema_file(
+            self,
+            v: impl Into<PathBuf>,
+        ) -> TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            PathBuf,
+            TExplicitWatermarks,
+        > {
+            TransformRequestInputBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                query_alias: self.query_alias,
+                vocab: self.vocab,
+                offset_interval: self.offset_interval,
+                data_paths: self.data_paths,
+                schema_file: v.into(),
+                explicit_watermarks: self.explicit_watermarks,
+            }
+        }
+    }
+
+    impl<TDatasetId, TDatasetAlias, TQueryAlias, TVocab, TDataPaths, TSchemaFile>
+        TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            Unset,
+        >
+    {
+        /// Watermarks that should be injected into the stream to separate micro batches for reproducibility.
+        pub fn explicit_watermarks(
+            self,
+            v: impl Into<Vec<dataset::Watermark>>,
+        ) -> TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            Vec<dataset::Watermark>,
+        > {
+            TransformRequestInputBuilder {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                query_alias: self.query_alias,
+                vocab: self.vocab,
+                offset_interval: self.offset_interval,
+                data_paths: self.data_paths,
+                schema_file: self.schema_file,
+                explicit_watermarks: v.into(),
+            }
+        }
+    }
+
+    impl<
+        TDatasetId,
+        TDatasetAlias,
+        TQueryAlias,
+        TVocab,
+        TDataPaths,
+        TSchemaFile,
+        TExplicitWatermarks,
+    >
+        TransformRequestInputBuilder<
+            TDatasetId,
+            TDatasetAlias,
+            TQueryAlias,
+            TVocab,
+            TDataPaths,
+            TSchemaFile,
+            TExplicitWatermarks,
+        >
+    {
+        /// Subset of data that goes into this transaction.
+        pub fn offset_interval(mut self, v: impl Into<dataset::OffsetInterval>) -> Self {
+            self.offset_interval = Some(v.into());
+            self
+        }
+    }
+
+    impl
+        TransformRequestInputBuilder<
+            DatasetID,
+            DatasetAlias,
+            String,
+            dataset::DatasetVocabulary,
+            Vec<PathBuf>,
+            PathBuf,
+            Vec<dataset::Watermark>,
+        >
+    {
+        pub fn build(self) -> TransformRequestInput {
+            TransformRequestInput {
+                dataset_id: self.dataset_id,
+                dataset_alias: self.dataset_alias,
+                query_alias: self.query_alias,
+                vocab: self.vocab,
+                offset_interval: self.offset_interval,
+                data_paths: self.data_paths,
+                schema_file: self.schema_file,
+                explicit_watermarks: self.explicit_watermarks,
+            }
+        }
+    }
+
     /// Sent by an engine to coordinator when performing the data transformation
     ///
     /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse
@@ -1605,6 +6348,53 @@ pub mod engine {
         pub backtrace: Option<String>,
     }
 
+    /// Builder for [`TransformResponseInternalError`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransformResponseInternalErrorBuilder<TMessage = Unset> {
+        message: TMessage,
+        backtrace: Option<String>,
+    }
+
+    impl TransformResponseInternalError {
+        pub fn builder() -> TransformResponseInternalErrorBuilder<Unset> {
+            TransformResponseInternalErrorBuilder {
+                message: Unset,
+                backtrace: None,
+            }
+        }
+    }
+
+    impl TransformResponseInternalErrorBuilder<Unset> {
+        /// Brief description of an error
+        pub fn message(
+            self,
+            v: impl Into<String>,
+        ) -> TransformResponseInternalErrorBuilder<String> {
+            TransformResponseInternalErrorBuilder {
+                message: v.into(),
+                backtrace: self.backtrace,
+            }
+        }
+    }
+
+    impl<TMessage> TransformResponseInternalErrorBuilder<TMessage> {
+        /// Details of an error (e.g. a backtrace)
+        pub fn backtrace(mut self, v: impl Into<String>) -> Self {
+            self.backtrace = Some(v.into());
+            self
+        }
+    }
+
+    impl TransformResponseInternalErrorBuilder<String> {
+        pub fn build(self) -> TransformResponseInternalError {
+            TransformResponseInternalError {
+                message: self.message,
+                backtrace: self.backtrace,
+            }
+        }
+    }
+
     /// Query did not pass validation
     ///
     /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/InvalidQuery
@@ -1614,6 +6404,34 @@ pub mod engine {
         pub message: String,
     }
 
+    /// Builder for [`TransformResponseInvalidQuery`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransformResponseInvalidQueryBuilder<TMessage = Unset> {
+        message: TMessage,
+    }
+
+    impl TransformResponseInvalidQuery {
+        pub fn builder() -> TransformResponseInvalidQueryBuilder<Unset> {
+            TransformResponseInvalidQueryBuilder { message: Unset }
+        }
+    }
+
+    impl TransformResponseInvalidQueryBuilder<Unset> {
+        /// Explanation of an error
+        pub fn message(self, v: impl Into<String>) -> TransformResponseInvalidQueryBuilder<String> {
+            TransformResponseInvalidQueryBuilder { message: v.into() }
+        }
+    }
+
+    impl TransformResponseInvalidQueryBuilder<String> {
+        pub fn build(self) -> TransformResponseInvalidQuery {
+            TransformResponseInvalidQuery {
+                message: self.message,
+            }
+        }
+    }
+
     /// Reports query progress
     ///
     /// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/Progress
@@ -1630,6 +6448,45 @@ pub mod engine {
         /// Watermark advanced by the transaction, if any.
         pub new_watermark: Option<DateTime<Utc>>,
     }
+
+    /// Builder for [`TransformResponseSuccess`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransformResponseSuccessBuilder {
+        new_offset_interval: Option<dataset::OffsetInterval>,
+        new_watermark: Option<DateTime<Utc>>,
+    }
+
+    impl TransformResponseSuccess {
+        pub fn builder() -> TransformResponseSuccessBuilder {
+            TransformResponseSuccessBuilder {
+                new_offset_interval: None,
+                new_watermark: None,
+            }
+        }
+    }
+
+    impl TransformResponseSuccessBuilder {
+        /// Data slice produced by the transaction, if any.
+        pub fn new_offset_interval(mut self, v: impl Into<dataset::OffsetInterval>) -> Self {
+            self.new_offset_interval = Some(v.into());
+            self
+        }
+        /// Watermark advanced by the transaction, if any.
+        pub fn new_watermark(mut self, v: impl Into<DateTime<Utc>>) -> Self {
+            self.new_watermark = Some(v.into());
+            self
+        }
+    }
+
+    impl TransformResponseSuccessBuilder {
+        pub fn build(self) -> TransformResponseSuccess {
+            TransformResponseSuccess {
+                new_offset_interval: self.new_offset_interval,
+                new_watermark: self.new_watermark,
+            }
+        }
+    }
 }
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // event
@@ -1641,10 +6498,143 @@ pub mod event {
 
     /// Filters that work on domain event types and fields.
     ///
+    /// Variants are not tagged and are resolved by their shape in order of declaration.
+    ///
     /// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter
     #[derive(Clone, PartialEq, Eq, Debug)]
-    pub struct EventFilter {
-        pub entries: std::collections::BTreeMap<String, serde_json::Value>,
+    pub enum EventFilter {
+        And(event::EventFilterAnd),
+        Or(event::EventFilterOr),
+        Not(event::EventFilterNot),
+        Match(event::EventFilterMatch),
+    }
+
+    impl_enum_with_variants!(EventFilter);
+    impl_enum_variant!(EventFilter::And(event::EventFilterAnd));
+    impl_enum_variant!(EventFilter::Or(event::EventFilterOr));
+    impl_enum_variant!(EventFilter::Not(event::EventFilterNot));
+    impl_enum_variant!(EventFilter::Match(event::EventFilterMatch));
+
+    /// Matches when all of the nested filters match.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/And
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct EventFilterAnd {
+        /// Filters that all must match.
+        pub and: Vec<event::EventFilter>,
+    }
+
+    /// Builder for [`EventFilterAnd`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct EventFilterAndBuilder<TAnd = Unset> {
+        and: TAnd,
+    }
+
+    impl EventFilterAnd {
+        pub fn builder() -> EventFilterAndBuilder<Unset> {
+            EventFilterAndBuilder { and: Unset }
+        }
+    }
+
+    impl EventFilterAndBuilder<Unset> {
+        /// Filters that all must match.
+        pub fn and(
+            self,
+            v: impl Into<Vec<event::EventFilter>>,
+        ) -> EventFilterAndBuilder<Vec<event::EventFilter>> {
+            EventFilterAndBuilder { and: v.into() }
+        }
+    }
+
+    impl EventFilterAndBuilder<Vec<event::EventFilter>> {
+        pub fn build(self) -> EventFilterAnd {
+            EventFilterAnd { and: self.and }
+        }
+    }
+
+    /// Matches when every condition holds for the event field with the same name.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Match
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub struct EventFilterMatch {
+        pub entries: std::collections::BTreeMap<String, resource::FilterCondition>,
+    }
+
+    /// Matches when the nested filter does not match.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Not
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct EventFilterNot {
+        /// Filter to negate.
+        pub not: Box<event::EventFilter>,
+    }
+
+    /// Builder for [`EventFilterNot`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct EventFilterNotBuilder<TNot = Unset> {
+        not: TNot,
+    }
+
+    impl EventFilterNot {
+        pub fn builder() -> EventFilterNotBuilder<Unset> {
+            EventFilterNotBuilder { not: Unset }
+        }
+    }
+
+    impl EventFilterNotBuilder<Unset> {
+        /// Filter to negate.
+        pub fn not(
+            self,
+            v: impl Into<Box<event::EventFilter>>,
+        ) -> EventFilterNotBuilder<Box<event::EventFilter>> {
+            EventFilterNotBuilder { not: v.into() }
+        }
+    }
+
+    impl EventFilterNotBuilder<Box<event::EventFilter>> {
+        pub fn build(self) -> EventFilterNot {
+            EventFilterNot { not: self.not }
+        }
+    }
+
+    /// Matches when at least one of the nested filters matches.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Or
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct EventFilterOr {
+        /// Filters of which at least one must match.
+        pub or: Vec<event::EventFilter>,
+    }
+
+    /// Builder for [`EventFilterOr`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct EventFilterOrBuilder<TOr = Unset> {
+        or: TOr,
+    }
+
+    impl EventFilterOr {
+        pub fn builder() -> EventFilterOrBuilder<Unset> {
+            EventFilterOrBuilder { or: Unset }
+        }
+    }
+
+    impl EventFilterOrBuilder<Unset> {
+        /// Filters of which at least one must match.
+        pub fn or(
+            self,
+            v: impl Into<Vec<event::EventFilter>>,
+        ) -> EventFilterOrBuilder<Vec<event::EventFilter>> {
+            EventFilterOrBuilder { or: v.into() }
+        }
+    }
+
+    impl EventFilterOrBuilder<Vec<event::EventFilter>> {
+        pub fn build(self) -> EventFilterOr {
+            EventFilterOr { or: self.or }
+        }
     }
 }
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1680,6 +6670,58 @@ pub mod flow {
     static FLOW_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(FLOW_SCHEMA_STR));
 
+    /// Builder for [`Flow`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl Flow {
+        pub fn builder() -> FlowBuilder<Unset, Unset> {
+            FlowBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> FlowBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> FlowBuilder<resource::ResourceHeadersInput, TSpec> {
+            FlowBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> FlowBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the flow.
+        pub fn spec(
+            self,
+            v: impl Into<flow::FlowSpecInput>,
+        ) -> FlowBuilder<THeaders, flow::FlowSpecInput> {
+            FlowBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl FlowBuilder<resource::ResourceHeadersInput, flow::FlowSpecInput> {
+        pub fn build(self) -> Flow {
+            Flow {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
     /// Defines a sequence of tasks to be executed upon certain trigger conditions.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpec
@@ -1693,9 +6735,80 @@ pub mod flow {
         pub tasks: Vec<flow::TaskSpec>,
     }
 
-    /// Defines a sequence of tasks to be executed upon certain trigger conditions.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpecInput
+    /// Builder for [`FlowSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowSpecBuilder<TTarget = Unset, TTriggers = Unset, TTasks = Unset> {
+        target: TTarget,
+        triggers: TTriggers,
+        tasks: TTasks,
+    }
+
+    impl FlowSpec {
+        pub fn builder() -> FlowSpecBuilder<Unset, Unset, Unset> {
+            FlowSpecBuilder {
+                target: Unset,
+                triggers: Unset,
+                tasks: Unset,
+            }
+        }
+    }
+
+    impl<TTriggers, TTasks> FlowSpecBuilder<Unset, TTriggers, TTasks> {
+        /// Defines resources for which this flow will be instantiated.
+        pub fn target(
+            self,
+            v: impl Into<resource::ResourceSelector>,
+        ) -> FlowSpecBuilder<resource::ResourceSelector, TTriggers, TTasks> {
+            FlowSpecBuilder {
+                target: v.into(),
+                triggers: self.triggers,
+                tasks: self.tasks,
+            }
+        }
+    }
+
+    impl<TTarget, TTasks> FlowSpecBuilder<TTarget, Unset, TTasks> {
+        /// Conditions that cause this flow to execute.
+        pub fn triggers(
+            self,
+            v: impl Into<Vec<flow::FlowTrigger>>,
+        ) -> FlowSpecBuilder<TTarget, Vec<flow::FlowTrigger>, TTasks> {
+            FlowSpecBuilder {
+                target: self.target,
+                triggers: v.into(),
+                tasks: self.tasks,
+            }
+        }
+    }
+
+    impl<TTarget, TTriggers> FlowSpecBuilder<TTarget, TTriggers, Unset> {
+        /// List of tasks to run consecutively.
+        pub fn tasks(
+            self,
+            v: impl Into<Vec<flow::TaskSpec>>,
+        ) -> FlowSpecBuilder<TTarget, TTriggers, Vec<flow::TaskSpec>> {
+            FlowSpecBuilder {
+                target: self.target,
+                triggers: self.triggers,
+                tasks: v.into(),
+            }
+        }
+    }
+
+    impl FlowSpecBuilder<resource::ResourceSelector, Vec<flow::FlowTrigger>, Vec<flow::TaskSpec>> {
+        pub fn build(self) -> FlowSpec {
+            FlowSpec {
+                target: self.target,
+                triggers: self.triggers,
+                tasks: self.tasks,
+            }
+        }
+    }
+
+    /// Defines a sequence of tasks to be executed upon certain trigger conditions.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpecInput
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub struct FlowSpecInput {
         /// Defines resources for which this flow will be instantiated.
@@ -1706,6 +6819,83 @@ pub mod flow {
         pub tasks: Vec<flow::TaskSpecInput>,
     }
 
+    /// Builder for [`FlowSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowSpecInputBuilder<TTarget = Unset, TTriggers = Unset, TTasks = Unset> {
+        target: TTarget,
+        triggers: TTriggers,
+        tasks: TTasks,
+    }
+
+    impl FlowSpecInput {
+        pub fn builder() -> FlowSpecInputBuilder<Unset, Unset, Unset> {
+            FlowSpecInputBuilder {
+                target: Unset,
+                triggers: Unset,
+                tasks: Unset,
+            }
+        }
+    }
+
+    impl<TTriggers, TTasks> FlowSpecInputBuilder<Unset, TTriggers, TTasks> {
+        /// Defines resources for which this flow will be instantiated.
+        pub fn target(
+            self,
+            v: impl Into<resource::ResourceSelector>,
+        ) -> FlowSpecInputBuilder<resource::ResourceSelector, TTriggers, TTasks> {
+            FlowSpecInputBuilder {
+                target: v.into(),
+                triggers: self.triggers,
+                tasks: self.tasks,
+            }
+        }
+    }
+
+    impl<TTarget, TTasks> FlowSpecInputBuilder<TTarget, Unset, TTasks> {
+        /// Conditions that cause this flow to execute.
+        pub fn triggers(
+            self,
+            v: impl Into<Vec<flow::FlowTriggerInput>>,
+        ) -> FlowSpecInputBuilder<TTarget, Vec<flow::FlowTriggerInput>, TTasks> {
+            FlowSpecInputBuilder {
+                target: self.target,
+                triggers: v.into(),
+                tasks: self.tasks,
+            }
+        }
+    }
+
+    impl<TTarget, TTriggers> FlowSpecInputBuilder<TTarget, TTriggers, Unset> {
+        /// List of tasks to run consecutively.
+        pub fn tasks(
+            self,
+            v: impl Into<Vec<flow::TaskSpecInput>>,
+        ) -> FlowSpecInputBuilder<TTarget, TTriggers, Vec<flow::TaskSpecInput>> {
+            FlowSpecInputBuilder {
+                target: self.target,
+                triggers: self.triggers,
+                tasks: v.into(),
+            }
+        }
+    }
+
+    impl
+        FlowSpecInputBuilder<
+            resource::ResourceSelector,
+            Vec<flow::FlowTriggerInput>,
+            Vec<flow::TaskSpecInput>,
+        >
+    {
+        pub fn build(self) -> FlowSpecInput {
+            FlowSpecInput {
+                target: self.target,
+                triggers: self.triggers,
+                tasks: self.tasks,
+            }
+        }
+    }
+
     /// Condition that causes a flow to be executed.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger
@@ -1734,6 +6924,53 @@ pub mod flow {
         pub events: Option<Vec<String>>,
     }
 
+    /// Builder for [`FlowTriggerDataset`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerDatasetBuilder<TDataset = Unset> {
+        dataset: TDataset,
+        events: Option<Vec<String>>,
+    }
+
+    impl FlowTriggerDataset {
+        pub fn builder() -> FlowTriggerDatasetBuilder<Unset> {
+            FlowTriggerDatasetBuilder {
+                dataset: Unset,
+                events: None,
+            }
+        }
+    }
+
+    impl FlowTriggerDatasetBuilder<Unset> {
+        /// Selector that identifies which datasets can trigger this flow.
+        pub fn dataset(
+            self,
+            v: impl Into<dataset::DatasetSelector>,
+        ) -> FlowTriggerDatasetBuilder<dataset::DatasetSelector> {
+            FlowTriggerDatasetBuilder {
+                dataset: v.into(),
+                events: self.events,
+            }
+        }
+    }
+
+    impl<TDataset> FlowTriggerDatasetBuilder<TDataset> {
+        /// Set of event bus event IDs that this trigger will react to
+        pub fn events(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.events = Some(v.into());
+            self
+        }
+    }
+
+    impl FlowTriggerDatasetBuilder<dataset::DatasetSelector> {
+        pub fn build(self) -> FlowTriggerDataset {
+            FlowTriggerDataset {
+                dataset: self.dataset,
+                events: self.events,
+            }
+        }
+    }
+
     /// Triggers the flow when an event bus event matching one of the filters is observed.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Event
@@ -1747,6 +6984,62 @@ pub mod flow {
         pub cooldown_max_batch: Option<u64>,
     }
 
+    /// Builder for [`FlowTriggerEvent`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerEventBuilder<TEvents = Unset> {
+        events: TEvents,
+        cooldown: Option<DurationString>,
+        cooldown_max_batch: Option<u64>,
+    }
+
+    impl FlowTriggerEvent {
+        pub fn builder() -> FlowTriggerEventBuilder<Unset> {
+            FlowTriggerEventBuilder {
+                events: Unset,
+                cooldown: None,
+                cooldown_max_batch: None,
+            }
+        }
+    }
+
+    impl FlowTriggerEventBuilder<Unset> {
+        /// Filters the event by type and fields.
+        pub fn events(
+            self,
+            v: impl Into<event::EventFilter>,
+        ) -> FlowTriggerEventBuilder<event::EventFilter> {
+            FlowTriggerEventBuilder {
+                events: v.into(),
+                cooldown: self.cooldown,
+                cooldown_max_batch: self.cooldown_max_batch,
+            }
+        }
+    }
+
+    impl<TEvents> FlowTriggerEventBuilder<TEvents> {
+        /// The trigger will fire upon first observed event. If another event arrives withing the `cooldown` interval the firing will be postponed until `cooldown` interval ends. I.e. trigger is guaranteed to fire, but may batch multiple events together into one flow run.
+        pub fn cooldown(mut self, v: impl Into<DurationString>) -> Self {
+            self.cooldown = Some(v.into());
+            self
+        }
+        /// If an event is observed a `cooldownMaxBatch` number of times during the `cooldown` interval it will fire the trigger without waiting for cooldown to finish.
+        pub fn cooldown_max_batch(mut self, v: impl Into<u64>) -> Self {
+            self.cooldown_max_batch = Some(v.into());
+            self
+        }
+    }
+
+    impl FlowTriggerEventBuilder<event::EventFilter> {
+        pub fn build(self) -> FlowTriggerEvent {
+            FlowTriggerEvent {
+                events: self.events,
+                cooldown: self.cooldown,
+                cooldown_max_batch: self.cooldown_max_batch,
+            }
+        }
+    }
+
     /// Condition that causes a flow to be executed.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput
@@ -1775,6 +7068,53 @@ pub mod flow {
         pub events: Option<Vec<String>>,
     }
 
+    /// Builder for [`FlowTriggerInputDataset`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerInputDatasetBuilder<TDataset = Unset> {
+        dataset: TDataset,
+        events: Option<Vec<String>>,
+    }
+
+    impl FlowTriggerInputDataset {
+        pub fn builder() -> FlowTriggerInputDatasetBuilder<Unset> {
+            FlowTriggerInputDatasetBuilder {
+                dataset: Unset,
+                events: None,
+            }
+        }
+    }
+
+    impl FlowTriggerInputDatasetBuilder<Unset> {
+        /// Selector that identifies which datasets can trigger this flow.
+        pub fn dataset(
+            self,
+            v: impl Into<dataset::DatasetSelector>,
+        ) -> FlowTriggerInputDatasetBuilder<dataset::DatasetSelector> {
+            FlowTriggerInputDatasetBuilder {
+                dataset: v.into(),
+                events: self.events,
+            }
+        }
+    }
+
+    impl<TDataset> FlowTriggerInputDatasetBuilder<TDataset> {
+        /// Set of event bus event IDs that this trigger will react to
+        pub fn events(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.events = Some(v.into());
+            self
+        }
+    }
+
+    impl FlowTriggerInputDatasetBuilder<dataset::DatasetSelector> {
+        pub fn build(self) -> FlowTriggerInputDataset {
+            FlowTriggerInputDataset {
+                dataset: self.dataset,
+                events: self.events,
+            }
+        }
+    }
+
     /// Triggers the flow when an event bus event matching one of the filters is observed.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Event
@@ -1788,6 +7128,62 @@ pub mod flow {
         pub cooldown_max_batch: Option<u64>,
     }
 
+    /// Builder for [`FlowTriggerInputEvent`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerInputEventBuilder<TEvents = Unset> {
+        events: TEvents,
+        cooldown: Option<DurationString>,
+        cooldown_max_batch: Option<u64>,
+    }
+
+    impl FlowTriggerInputEvent {
+        pub fn builder() -> FlowTriggerInputEventBuilder<Unset> {
+            FlowTriggerInputEventBuilder {
+                events: Unset,
+                cooldown: None,
+                cooldown_max_batch: None,
+            }
+        }
+    }
+
+    impl FlowTriggerInputEventBuilder<Unset> {
+        /// Filters the event by type and fields.
+        pub fn events(
+            self,
+            v: impl Into<event::EventFilter>,
+        ) -> FlowTriggerInputEventBuilder<event::EventFilter> {
+            FlowTriggerInputEventBuilder {
+                events: v.into(),
+                cooldown: self.cooldown,
+                cooldown_max_batch: self.cooldown_max_batch,
+            }
+        }
+    }
+
+    impl<TEvents> FlowTriggerInputEventBuilder<TEvents> {
+        /// The trigger will fire upon first observed event. If another event arrives withing the `cooldown` interval the firing will be postponed until `cooldown` interval ends. I.e. trigger is guaranteed to fire, but may batch multiple events together into one flow run.
+        pub fn cooldown(mut self, v: impl Into<DurationString>) -> Self {
+            self.cooldown = Some(v.into());
+            self
+        }
+        /// If an event is observed a `cooldownMaxBatch` number of times during the `cooldown` interval it will fire the trigger without waiting for cooldown to finish.
+        pub fn cooldown_max_batch(mut self, v: impl Into<u64>) -> Self {
+            self.cooldown_max_batch = Some(v.into());
+            self
+        }
+    }
+
+    impl FlowTriggerInputEventBuilder<event::EventFilter> {
+        pub fn build(self) -> FlowTriggerInputEvent {
+            FlowTriggerInputEvent {
+                events: self.events,
+                cooldown: self.cooldown,
+                cooldown_max_batch: self.cooldown_max_batch,
+            }
+        }
+    }
+
     /// Triggers the flow on a cron schedule.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Schedule
@@ -1797,6 +7193,32 @@ pub mod flow {
         pub cron: String,
     }
 
+    /// Builder for [`FlowTriggerInputSchedule`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerInputScheduleBuilder<TCron = Unset> {
+        cron: TCron,
+    }
+
+    impl FlowTriggerInputSchedule {
+        pub fn builder() -> FlowTriggerInputScheduleBuilder<Unset> {
+            FlowTriggerInputScheduleBuilder { cron: Unset }
+        }
+    }
+
+    impl FlowTriggerInputScheduleBuilder<Unset> {
+        /// Cron5 expression defining the schedule e.g. `@daily` or `*/30 * * * *`.
+        pub fn cron(self, v: impl Into<String>) -> FlowTriggerInputScheduleBuilder<String> {
+            FlowTriggerInputScheduleBuilder { cron: v.into() }
+        }
+    }
+
+    impl FlowTriggerInputScheduleBuilder<String> {
+        pub fn build(self) -> FlowTriggerInputSchedule {
+            FlowTriggerInputSchedule { cron: self.cron }
+        }
+    }
+
     /// Triggers the flow when a source receives new data, with optional batching controls.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Source
@@ -1810,6 +7232,62 @@ pub mod flow {
         pub max_await_interval: Option<DurationString>,
     }
 
+    /// Builder for [`FlowTriggerInputSource`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerInputSourceBuilder<TSource = Unset> {
+        source: TSource,
+        min_records_to_await: Option<u64>,
+        max_await_interval: Option<DurationString>,
+    }
+
+    impl FlowTriggerInputSource {
+        pub fn builder() -> FlowTriggerInputSourceBuilder<Unset> {
+            FlowTriggerInputSourceBuilder {
+                source: Unset,
+                min_records_to_await: None,
+                max_await_interval: None,
+            }
+        }
+    }
+
+    impl FlowTriggerInputSourceBuilder<Unset> {
+        /// Reference to the source resource that drives this trigger.
+        pub fn source(
+            self,
+            v: impl Into<resource::ResourceRef>,
+        ) -> FlowTriggerInputSourceBuilder<resource::ResourceRef> {
+            FlowTriggerInputSourceBuilder {
+                source: v.into(),
+                min_records_to_await: self.min_records_to_await,
+                max_await_interval: self.max_await_interval,
+            }
+        }
+    }
+
+    impl<TSource> FlowTriggerInputSourceBuilder<TSource> {
+        /// Minimum number of new records to accumulate before triggering.
+        pub fn min_records_to_await(mut self, v: impl Into<u64>) -> Self {
+            self.min_records_to_await = Some(v.into());
+            self
+        }
+        /// Maximum time to wait for `minRecordsToAwait` before triggering anyway e.g. `1h`.
+        pub fn max_await_interval(mut self, v: impl Into<DurationString>) -> Self {
+            self.max_await_interval = Some(v.into());
+            self
+        }
+    }
+
+    impl FlowTriggerInputSourceBuilder<resource::ResourceRef> {
+        pub fn build(self) -> FlowTriggerInputSource {
+            FlowTriggerInputSource {
+                source: self.source,
+                min_records_to_await: self.min_records_to_await,
+                max_await_interval: self.max_await_interval,
+            }
+        }
+    }
+
     /// Triggers the flow on a cron schedule.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Schedule
@@ -1819,6 +7297,32 @@ pub mod flow {
         pub cron: String,
     }
 
+    /// Builder for [`FlowTriggerSchedule`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerScheduleBuilder<TCron = Unset> {
+        cron: TCron,
+    }
+
+    impl FlowTriggerSchedule {
+        pub fn builder() -> FlowTriggerScheduleBuilder<Unset> {
+            FlowTriggerScheduleBuilder { cron: Unset }
+        }
+    }
+
+    impl FlowTriggerScheduleBuilder<Unset> {
+        /// Cron5 expression defining the schedule e.g. `@daily` or `*/30 * * * *`.
+        pub fn cron(self, v: impl Into<String>) -> FlowTriggerScheduleBuilder<String> {
+            FlowTriggerScheduleBuilder { cron: v.into() }
+        }
+    }
+
+    impl FlowTriggerScheduleBuilder<String> {
+        pub fn build(self) -> FlowTriggerSchedule {
+            FlowTriggerSchedule { cron: self.cron }
+        }
+    }
+
     /// Triggers the flow when a source receives new data, with optional batching controls.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Source
@@ -1832,6 +7336,62 @@ pub mod flow {
         pub max_await_interval: Option<DurationString>,
     }
 
+    /// Builder for [`FlowTriggerSource`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FlowTriggerSourceBuilder<TSource = Unset> {
+        source: TSource,
+        min_records_to_await: Option<u64>,
+        max_await_interval: Option<DurationString>,
+    }
+
+    impl FlowTriggerSource {
+        pub fn builder() -> FlowTriggerSourceBuilder<Unset> {
+            FlowTriggerSourceBuilder {
+                source: Unset,
+                min_records_to_await: None,
+                max_await_interval: None,
+            }
+        }
+    }
+
+    impl FlowTriggerSourceBuilder<Unset> {
+        /// Reference to the source resource that drives this trigger.
+        pub fn source(
+            self,
+            v: impl Into<resource::ResourceHandle>,
+        ) -> FlowTriggerSourceBuilder<resource::ResourceHandle> {
+            FlowTriggerSourceBuilder {
+                source: v.into(),
+                min_records_to_await: self.min_records_to_await,
+                max_await_interval: self.max_await_interval,
+            }
+        }
+    }
+
+    impl<TSource> FlowTriggerSourceBuilder<TSource> {
+        /// Minimum number of new records to accumulate before triggering.
+        pub fn min_records_to_await(mut self, v: impl Into<u64>) -> Self {
+            self.min_records_to_await = Some(v.into());
+            self
+        }
+        /// Maximum time to wait for `minRecordsToAwait` before triggering anyway e.g. `1h`.
+        pub fn max_await_interval(mut self, v: impl Into<DurationString>) -> Self {
+            self.max_await_interval = Some(v.into());
+            self
+        }
+    }
+
+    impl FlowTriggerSourceBuilder<resource::ResourceHandle> {
+        pub fn build(self) -> FlowTriggerSource {
+            FlowTriggerSource {
+                source: self.source,
+                min_records_to_await: self.min_records_to_await,
+                max_await_interval: self.max_await_interval,
+            }
+        }
+    }
+
     /// An individual work item to be executed.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/Task
@@ -1857,6 +7417,53 @@ pub mod flow {
     static TASK_SCHEMA: std::sync::LazyLock<TypeUri> =
         std::sync::LazyLock::new(|| TypeUri::new_unchecked(TASK_SCHEMA_STR));
 
+    /// Builder for [`Task`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TaskBuilder<THeaders = Unset> {
+        headers: THeaders,
+        spec: Option<flow::TaskSpecInput>,
+    }
+
+    impl Task {
+        pub fn builder() -> TaskBuilder<Unset> {
+            TaskBuilder {
+                headers: Unset,
+                spec: None,
+            }
+        }
+    }
+
+    impl TaskBuilder<Unset> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> TaskBuilder<resource::ResourceHeadersInput> {
+            TaskBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> TaskBuilder<THeaders> {
+        /// Specifies the desired state of the task.
+        pub fn spec(mut self, v: impl Into<flow::TaskSpecInput>) -> Self {
+            self.spec = Some(v.into());
+            self
+        }
+    }
+
+    impl TaskBuilder<resource::ResourceHeadersInput> {
+        pub fn build(self) -> Task {
+            Task {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
     /// An individual work item to be executed as part of a flow.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec
@@ -1883,6 +7490,35 @@ pub mod flow {
         pub params: Option<dataset::CompactionParams>,
     }
 
+    /// Builder for [`TaskSpecCompaction`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TaskSpecCompactionBuilder {
+        params: Option<dataset::CompactionParams>,
+    }
+
+    impl TaskSpecCompaction {
+        pub fn builder() -> TaskSpecCompactionBuilder {
+            TaskSpecCompactionBuilder { params: None }
+        }
+    }
+
+    impl TaskSpecCompactionBuilder {
+        /// Optional parameters to control ingestion behavior.
+        pub fn params(mut self, v: impl Into<dataset::CompactionParams>) -> Self {
+            self.params = Some(v.into());
+            self
+        }
+    }
+
+    impl TaskSpecCompactionBuilder {
+        pub fn build(self) -> TaskSpecCompaction {
+            TaskSpecCompaction {
+                params: self.params,
+            }
+        }
+    }
+
     /// Removes unreferenced data files from matching datasets.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/GarbageCollection
@@ -1900,6 +7536,53 @@ pub mod flow {
         pub params: Option<source::IngestParams>,
     }
 
+    /// Builder for [`TaskSpecIngest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TaskSpecIngestBuilder<TSource = Unset> {
+        source: TSource,
+        params: Option<source::IngestParams>,
+    }
+
+    impl TaskSpecIngest {
+        pub fn builder() -> TaskSpecIngestBuilder<Unset> {
+            TaskSpecIngestBuilder {
+                source: Unset,
+                params: None,
+            }
+        }
+    }
+
+    impl TaskSpecIngestBuilder<Unset> {
+        /// Reference to the source resource that defines how to fetch data.
+        pub fn source(
+            self,
+            v: impl Into<resource::ResourceHandle>,
+        ) -> TaskSpecIngestBuilder<resource::ResourceHandle> {
+            TaskSpecIngestBuilder {
+                source: v.into(),
+                params: self.params,
+            }
+        }
+    }
+
+    impl<TSource> TaskSpecIngestBuilder<TSource> {
+        /// Optional parameters to control ingestion behavior.
+        pub fn params(mut self, v: impl Into<source::IngestParams>) -> Self {
+            self.params = Some(v.into());
+            self
+        }
+    }
+
+    impl TaskSpecIngestBuilder<resource::ResourceHandle> {
+        pub fn build(self) -> TaskSpecIngest {
+            TaskSpecIngest {
+                source: self.source,
+                params: self.params,
+            }
+        }
+    }
+
     /// An individual work item to be executed as part of a flow.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput
@@ -1928,11 +7611,40 @@ pub mod flow {
         pub params: Option<dataset::CompactionParams>,
     }
 
-    /// Removes unreferenced data files from matching datasets.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/GarbageCollection
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct TaskSpecInputGarbageCollection {}
+    /// Builder for [`TaskSpecInputCompaction`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TaskSpecInputCompactionBuilder {
+        params: Option<dataset::CompactionParams>,
+    }
+
+    impl TaskSpecInputCompaction {
+        pub fn builder() -> TaskSpecInputCompactionBuilder {
+            TaskSpecInputCompactionBuilder { params: None }
+        }
+    }
+
+    impl TaskSpecInputCompactionBuilder {
+        /// Optional parameters to control ingestion behavior.
+        pub fn params(mut self, v: impl Into<dataset::CompactionParams>) -> Self {
+            self.params = Some(v.into());
+            self
+        }
+    }
+
+    impl TaskSpecInputCompactionBuilder {
+        pub fn build(self) -> TaskSpecInputCompaction {
+            TaskSpecInputCompaction {
+                params: self.params,
+            }
+        }
+    }
+
+    /// Removes unreferenced data files from matching datasets.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/GarbageCollection
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct TaskSpecInputGarbageCollection {}
 
     /// Fetches data from a source and appends it to a dataset.
     ///
@@ -1945,6 +7657,53 @@ pub mod flow {
         pub params: Option<source::IngestParams>,
     }
 
+    /// Builder for [`TaskSpecInputIngest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TaskSpecInputIngestBuilder<TSource = Unset> {
+        source: TSource,
+        params: Option<source::IngestParams>,
+    }
+
+    impl TaskSpecInputIngest {
+        pub fn builder() -> TaskSpecInputIngestBuilder<Unset> {
+            TaskSpecInputIngestBuilder {
+                source: Unset,
+                params: None,
+            }
+        }
+    }
+
+    impl TaskSpecInputIngestBuilder<Unset> {
+        /// Reference to the source resource that defines how to fetch data.
+        pub fn source(
+            self,
+            v: impl Into<resource::ResourceRef>,
+        ) -> TaskSpecInputIngestBuilder<resource::ResourceRef> {
+            TaskSpecInputIngestBuilder {
+                source: v.into(),
+                params: self.params,
+            }
+        }
+    }
+
+    impl<TSource> TaskSpecInputIngestBuilder<TSource> {
+        /// Optional parameters to control ingestion behavior.
+        pub fn params(mut self, v: impl Into<source::IngestParams>) -> Self {
+            self.params = Some(v.into());
+            self
+        }
+    }
+
+    impl TaskSpecInputIngestBuilder<resource::ResourceRef> {
+        pub fn build(self) -> TaskSpecInputIngest {
+            TaskSpecInputIngest {
+                source: self.source,
+                params: self.params,
+            }
+        }
+    }
+
     /// Dispatches a certain payload to a specific `WebhookTarget`.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/WebhookCall
@@ -1956,6 +7715,53 @@ pub mod flow {
         pub payload: Option<String>,
     }
 
+    /// Builder for [`TaskSpecInputWebhookCall`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TaskSpecInputWebhookCallBuilder<TTarget = Unset> {
+        target: TTarget,
+        payload: Option<String>,
+    }
+
+    impl TaskSpecInputWebhookCall {
+        pub fn builder() -> TaskSpecInputWebhookCallBuilder<Unset> {
+            TaskSpecInputWebhookCallBuilder {
+                target: Unset,
+                payload: None,
+            }
+        }
+    }
+
+    impl TaskSpecInputWebhookCallBuilder<Unset> {
+        /// Reference to the `WebhookTarget`.
+        pub fn target(
+            self,
+            v: impl Into<resource::ResourceRef>,
+        ) -> TaskSpecInputWebhookCallBuilder<resource::ResourceRef> {
+            TaskSpecInputWebhookCallBuilder {
+                target: v.into(),
+                payload: self.payload,
+            }
+        }
+    }
+
+    impl<TTarget> TaskSpecInputWebhookCallBuilder<TTarget> {
+        /// The payload to send. May include templating.
+        pub fn payload(mut self, v: impl Into<String>) -> Self {
+            self.payload = Some(v.into());
+            self
+        }
+    }
+
+    impl TaskSpecInputWebhookCallBuilder<resource::ResourceRef> {
+        pub fn build(self) -> TaskSpecInputWebhookCall {
+            TaskSpecInputWebhookCall {
+                target: self.target,
+                payload: self.payload,
+            }
+        }
+    }
+
     /// Dispatches a certain payload to a specific `WebhookTarget`.
     ///
     /// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/WebhookCall
@@ -1966,6 +7772,53 @@ pub mod flow {
         /// The payload to send. May include templating.
         pub payload: Option<String>,
     }
+
+    /// Builder for [`TaskSpecWebhookCall`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TaskSpecWebhookCallBuilder<TTarget = Unset> {
+        target: TTarget,
+        payload: Option<String>,
+    }
+
+    impl TaskSpecWebhookCall {
+        pub fn builder() -> TaskSpecWebhookCallBuilder<Unset> {
+            TaskSpecWebhookCallBuilder {
+                target: Unset,
+                payload: None,
+            }
+        }
+    }
+
+    impl TaskSpecWebhookCallBuilder<Unset> {
+        /// Reference to the `WebhookTarget`.
+        pub fn target(
+            self,
+            v: impl Into<resource::ResourceHandle>,
+        ) -> TaskSpecWebhookCallBuilder<resource::ResourceHandle> {
+            TaskSpecWebhookCallBuilder {
+                target: v.into(),
+                payload: self.payload,
+            }
+        }
+    }
+
+    impl<TTarget> TaskSpecWebhookCallBuilder<TTarget> {
+        /// The payload to send. May include templating.
+        pub fn payload(mut self, v: impl Into<String>) -> Self {
+            self.payload = Some(v.into());
+            self
+        }
+    }
+
+    impl TaskSpecWebhookCallBuilder<resource::ResourceHandle> {
+        pub fn build(self) -> TaskSpecWebhookCall {
+            TaskSpecWebhookCall {
+                target: self.target,
+                payload: self.payload,
+            }
+        }
+    }
 }
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // legacy
@@ -1990,6 +7843,91 @@ pub mod legacy {
         pub merge: source::MergeStrategy,
     }
 
+    /// Builder for [`AddPushSource`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AddPushSourceBuilder<TSourceName = Unset, TRead = Unset, TMerge = Unset> {
+        source_name: TSourceName,
+        read: TRead,
+        preprocess: Option<dataset::Transform>,
+        merge: TMerge,
+    }
+
+    impl AddPushSource {
+        pub fn builder() -> AddPushSourceBuilder<Unset, Unset, Unset> {
+            AddPushSourceBuilder {
+                source_name: Unset,
+                read: Unset,
+                preprocess: None,
+                merge: Unset,
+            }
+        }
+    }
+
+    impl<TRead, TMerge> AddPushSourceBuilder<Unset, TRead, TMerge> {
+        /// Identifies the source within this dataset.
+        pub fn source_name(
+            self,
+            v: impl Into<String>,
+        ) -> AddPushSourceBuilder<String, TRead, TMerge> {
+            AddPushSourceBuilder {
+                source_name: v.into(),
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: self.merge,
+            }
+        }
+    }
+
+    impl<TSourceName, TMerge> AddPushSourceBuilder<TSourceName, Unset, TMerge> {
+        /// Defines how data is read into structured format.
+        pub fn read(
+            self,
+            v: impl Into<source::ReadStep>,
+        ) -> AddPushSourceBuilder<TSourceName, source::ReadStep, TMerge> {
+            AddPushSourceBuilder {
+                source_name: self.source_name,
+                read: v.into(),
+                preprocess: self.preprocess,
+                merge: self.merge,
+            }
+        }
+    }
+
+    impl<TSourceName, TRead> AddPushSourceBuilder<TSourceName, TRead, Unset> {
+        /// Determines how newly-ingested data should be merged with existing history.
+        pub fn merge(
+            self,
+            v: impl Into<source::MergeStrategy>,
+        ) -> AddPushSourceBuilder<TSourceName, TRead, source::MergeStrategy> {
+            AddPushSourceBuilder {
+                source_name: self.source_name,
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: v.into(),
+            }
+        }
+    }
+
+    impl<TSourceName, TRead, TMerge> AddPushSourceBuilder<TSourceName, TRead, TMerge> {
+        /// Pre-processing query that shapes the data.
+        pub fn preprocess(mut self, v: impl Into<dataset::Transform>) -> Self {
+            self.preprocess = Some(v.into());
+            self
+        }
+    }
+
+    impl AddPushSourceBuilder<String, source::ReadStep, source::MergeStrategy> {
+        pub fn build(self) -> AddPushSource {
+            AddPushSource {
+                source_name: self.source_name,
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: self.merge,
+            }
+        }
+    }
+
     /// Represents a projection of the dataset metadata at a single point in time.
     /// This type is typically used for defining new datasets and changing the existing ones.
     ///
@@ -2004,6 +7942,87 @@ pub mod legacy {
         pub metadata: Vec<dataset::MetadataEvent>,
     }
 
+    /// Builder for [`DatasetSnapshot`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetSnapshotBuilder<TName = Unset, TKind = Unset, TMetadata = Unset> {
+        name: TName,
+        kind: TKind,
+        metadata: TMetadata,
+    }
+
+    impl DatasetSnapshot {
+        pub fn builder() -> DatasetSnapshotBuilder<Unset, Unset, Unset> {
+            DatasetSnapshotBuilder {
+                name: Unset,
+                kind: Unset,
+                metadata: Unset,
+            }
+        }
+    }
+
+    impl<TKind, TMetadata> DatasetSnapshotBuilder<Unset, TKind, TMetadata> {
+        /// Alias of the dataset.
+        pub fn name(
+            self,
+            v: impl Into<DatasetAlias>,
+        ) -> DatasetSnapshotBuilder<DatasetAlias, TKind, TMetadata> {
+            DatasetSnapshotBuilder {
+                name: v.into(),
+                kind: self.kind,
+                metadata: self.metadata,
+            }
+        }
+    }
+
+    impl<TName, TMetadata> DatasetSnapshotBuilder<TName, Unset, TMetadata> {
+        /// Type of the dataset.
+        pub fn kind(
+            self,
+            v: impl Into<dataset::DatasetKind>,
+        ) -> DatasetSnapshotBuilder<TName, dataset::DatasetKind, TMetadata> {
+            DatasetSnapshotBuilder {
+                name: self.name,
+                kind: v.into(),
+                metadata: self.metadata,
+            }
+        }
+    }
+
+    impl<TName, TKind> DatasetSnapshotBuilder<TName, TKind, Unset> {
+        /// An array of metadata events that will be used to populate the chain. Here you can define polling and push sources, set licenses, add attachments etc.
+        pub fn metadata(
+            self,
+            v: impl Into<Vec<dataset::MetadataEvent>>,
+        ) -> DatasetSnapshotBuilder<TName, TKind, Vec<dataset::MetadataEvent>> {
+            DatasetSnapshotBuilder {
+                name: self.name,
+                kind: self.kind,
+                metadata: v.into(),
+            }
+        }
+    }
+
+    impl DatasetSnapshotBuilder<DatasetAlias, dataset::DatasetKind, Vec<dataset::MetadataEvent>> {
+        pub fn build(self) -> DatasetSnapshot {
+            DatasetSnapshot {
+                name: self.name,
+                kind: self.kind,
+                metadata: self.metadata,
+            }
+        }
+    }
+
+    impl Redact for DatasetSnapshot {
+        fn redact(&self) -> Self {
+            Self {
+                name: self.name.clone(),
+                kind: self.kind.clone(),
+                metadata: self.metadata.redact(),
+            }
+        }
+    }
+
     /// Disables the previously defined polling source.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePollingSource
@@ -2019,6 +8038,36 @@ pub mod legacy {
         pub source_name: String,
     }
 
+    /// Builder for [`DisablePushSource`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DisablePushSourceBuilder<TSourceName = Unset> {
+        source_name: TSourceName,
+    }
+
+    impl DisablePushSource {
+        pub fn builder() -> DisablePushSourceBuilder<Unset> {
+            DisablePushSourceBuilder { source_name: Unset }
+        }
+    }
+
+    impl DisablePushSourceBuilder<Unset> {
+        /// Identifies the source to be disabled.
+        pub fn source_name(self, v: impl Into<String>) -> DisablePushSourceBuilder<String> {
+            DisablePushSourceBuilder {
+                source_name: v.into(),
+            }
+        }
+    }
+
+    impl DisablePushSourceBuilder<String> {
+        pub fn build(self) -> DisablePushSource {
+            DisablePushSource {
+                source_name: self.source_name,
+            }
+        }
+    }
+
     /// Defines the external source of data.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep
@@ -2038,6 +8087,15 @@ pub mod legacy {
     impl_enum_variant!(FetchStep::Mqtt(legacy::FetchStepMqtt));
     impl_enum_variant!(FetchStep::EthereumLogs(legacy::FetchStepEthereumLogs));
 
+    impl Redact for FetchStep {
+        fn redact(&self) -> Self {
+            match self {
+                Self::Mqtt(v) => Self::Mqtt(v.redact()),
+                _ => self.clone(),
+            }
+        }
+    }
+
     /// Runs the specified OCI container to fetch data from an arbitrary source.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Container
@@ -2053,6 +8111,68 @@ pub mod legacy {
         pub env: Option<Vec<source::EnvVar>>,
     }
 
+    /// Builder for [`FetchStepContainer`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FetchStepContainerBuilder<TImage = Unset> {
+        image: TImage,
+        command: Option<Vec<String>>,
+        args: Option<Vec<String>>,
+        env: Option<Vec<source::EnvVar>>,
+    }
+
+    impl FetchStepContainer {
+        pub fn builder() -> FetchStepContainerBuilder<Unset> {
+            FetchStepContainerBuilder {
+                image: Unset,
+                command: None,
+                args: None,
+                env: None,
+            }
+        }
+    }
+
+    impl FetchStepContainerBuilder<Unset> {
+        /// Image name and and an optional tag.
+        pub fn image(self, v: impl Into<String>) -> FetchStepContainerBuilder<String> {
+            FetchStepContainerBuilder {
+                image: v.into(),
+                command: self.command,
+                args: self.args,
+                env: self.env,
+            }
+        }
+    }
+
+    impl<TImage> FetchStepContainerBuilder<TImage> {
+        /// Specifies the entrypoint. Not executed within a shell. The default OCI image's ENTRYPOINT is used if this is not provided.
+        pub fn command(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.command = Some(v.into());
+            self
+        }
+        /// Arguments to the entrypoint. The OCI image's CMD is used if this is not provided.
+        pub fn args(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.args = Some(v.into());
+            self
+        }
+        /// Environment variables to propagate into or set in the container.
+        pub fn env(mut self, v: impl Into<Vec<source::EnvVar>>) -> Self {
+            self.env = Some(v.into());
+            self
+        }
+    }
+
+    impl FetchStepContainerBuilder<String> {
+        pub fn build(self) -> FetchStepContainer {
+            FetchStepContainer {
+                image: self.image,
+                command: self.command,
+                args: self.args,
+                env: self.env,
+            }
+        }
+    }
+
     /// Connects to an Ethereum node to stream transaction logs.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/EthereumLogs
@@ -2071,6 +8191,61 @@ pub mod legacy {
         pub signature: Option<String>,
     }
 
+    /// Builder for [`FetchStepEthereumLogs`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FetchStepEthereumLogsBuilder {
+        chain_id: Option<u64>,
+        node_url: Option<String>,
+        filter: Option<String>,
+        signature: Option<String>,
+    }
+
+    impl FetchStepEthereumLogs {
+        pub fn builder() -> FetchStepEthereumLogsBuilder {
+            FetchStepEthereumLogsBuilder {
+                chain_id: None,
+                node_url: None,
+                filter: None,
+                signature: None,
+            }
+        }
+    }
+
+    impl FetchStepEthereumLogsBuilder {
+        /// Identifier of the chain to scan logs from. This parameter may be used for RPC endpoint lookup as well as asserting that provided `nodeUrl` corresponds to the expected chain.
+        pub fn chain_id(mut self, v: impl Into<u64>) -> Self {
+            self.chain_id = Some(v.into());
+            self
+        }
+        /// Url of the node.
+        pub fn node_url(mut self, v: impl Into<String>) -> Self {
+            self.node_url = Some(v.into());
+            self
+        }
+        /// An SQL WHERE clause that can be used to pre-filter the logs before fetching them from the ETH node.
+        pub fn filter(mut self, v: impl Into<String>) -> Self {
+            self.filter = Some(v.into());
+            self
+        }
+        /// Solidity log event signature to use for decoding. Using this field adds `event` to the output containing decoded log as JSON.
+        pub fn signature(mut self, v: impl Into<String>) -> Self {
+            self.signature = Some(v.into());
+            self
+        }
+    }
+
+    impl FetchStepEthereumLogsBuilder {
+        pub fn build(self) -> FetchStepEthereumLogs {
+            FetchStepEthereumLogs {
+                chain_id: self.chain_id,
+                node_url: self.node_url,
+                filter: self.filter,
+                signature: self.signature,
+            }
+        }
+    }
+
     /// Uses glob operator to match files on the local file system.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/FilesGlob
@@ -2088,6 +8263,70 @@ pub mod legacy {
         pub order: Option<source::SourceOrdering>,
     }
 
+    /// Builder for [`FetchStepFilesGlob`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FetchStepFilesGlobBuilder<TPath = Unset> {
+        path: TPath,
+        event_time: Option<source::EventTimeSource>,
+        cache: Option<source::SourceCaching>,
+        order: Option<source::SourceOrdering>,
+    }
+
+    impl FetchStepFilesGlob {
+        pub fn builder() -> FetchStepFilesGlobBuilder<Unset> {
+            FetchStepFilesGlobBuilder {
+                path: Unset,
+                event_time: None,
+                cache: None,
+                order: None,
+            }
+        }
+    }
+
+    impl FetchStepFilesGlobBuilder<Unset> {
+        /// Path with a glob pattern.
+        pub fn path(self, v: impl Into<String>) -> FetchStepFilesGlobBuilder<String> {
+            FetchStepFilesGlobBuilder {
+                path: v.into(),
+                event_time: self.event_time,
+                cache: self.cache,
+                order: self.order,
+            }
+        }
+    }
+
+    impl<TPath> FetchStepFilesGlobBuilder<TPath> {
+        /// Describes how event time is extracted from the source metadata.
+        pub fn event_time(mut self, v: impl Into<source::EventTimeSource>) -> Self {
+            self.event_time = Some(v.into());
+            self
+        }
+        /// Describes the caching settings used for this source.
+        pub fn cache(mut self, v: impl Into<source::SourceCaching>) -> Self {
+            self.cache = Some(v.into());
+            self
+        }
+        /// Specifies how input files should be ordered before ingestion.
+        /// Order is important as every file will be processed individually
+        /// and will advance the dataset's watermark.
+        pub fn order(mut self, v: impl Into<source::SourceOrdering>) -> Self {
+            self.order = Some(v.into());
+            self
+        }
+    }
+
+    impl FetchStepFilesGlobBuilder<String> {
+        pub fn build(self) -> FetchStepFilesGlob {
+            FetchStepFilesGlob {
+                path: self.path,
+                event_time: self.event_time,
+                cache: self.cache,
+                order: self.order,
+            }
+        }
+    }
+
     /// Connects to an MQTT broker to fetch events from the specified topic.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Mqtt
@@ -2100,11 +8339,113 @@ pub mod legacy {
         /// Username to use for auth with the broker.
         pub username: Option<String>,
         /// Password to use for auth with the broker (can be templated).
-        pub password: Option<String>,
+        pub password: Option<super::Sensitive<String>>,
         /// List of topic subscription parameters.
         pub topics: Vec<source::MqttTopicSubscription>,
     }
 
+    /// Builder for [`FetchStepMqtt`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FetchStepMqttBuilder<THost = Unset, TPort = Unset, TTopics = Unset> {
+        host: THost,
+        port: TPort,
+        username: Option<String>,
+        password: Option<super::Sensitive<String>>,
+        topics: TTopics,
+    }
+
+    impl FetchStepMqtt {
+        pub fn builder() -> FetchStepMqttBuilder<Unset, Unset, Unset> {
+            FetchStepMqttBuilder {
+                host: Unset,
+                port: Unset,
+                username: None,
+                password: None,
+                topics: Unset,
+            }
+        }
+    }
+
+    impl<TPort, TTopics> FetchStepMqttBuilder<Unset, TPort, TTopics> {
+        /// Hostname of the MQTT broker.
+        pub fn host(self, v: impl Into<String>) -> FetchStepMqttBuilder<String, TPort, TTopics> {
+            FetchStepMqttBuilder {
+                host: v.into(),
+                port: self.port,
+                username: self.username,
+                password: self.password,
+                topics: self.topics,
+            }
+        }
+    }
+
+    impl<THost, TTopics> FetchStepMqttBuilder<THost, Unset, TTopics> {
+        /// Port of the MQTT broker.
+        pub fn port(self, v: impl Into<i32>) -> FetchStepMqttBuilder<THost, i32, TTopics> {
+            FetchStepMqttBuilder {
+                host: self.host,
+                port: v.into(),
+                username: self.username,
+                password: self.password,
+                topics: self.topics,
+            }
+        }
+    }
+
+    impl<THost, TPort> FetchStepMqttBuilder<THost, TPort, Unset> {
+        /// List of topic subscription parameters.
+        pub fn topics(
+            self,
+            v: impl Into<Vec<source::MqttTopicSubscription>>,
+        ) -> FetchStepMqttBuilder<THost, TPort, Vec<source::MqttTopicSubscription>> {
+            FetchStepMqttBuilder {
+                host: self.host,
+                port: self.port,
+                username: self.username,
+                password: self.password,
+                topics: v.into(),
+            }
+        }
+    }
+
+    impl<THost, TPort, TTopics> FetchStepMqttBuilder<THost, TPort, TTopics> {
+        /// Username to use for auth with the broker.
+        pub fn username(mut self, v: impl Into<String>) -> Self {
+            self.username = Some(v.into());
+            self
+        }
+        /// Password to use for auth with the broker (can be templated).
+        pub fn password(mut self, v: impl Into<String>) -> Self {
+            self.password = Some(super::Sensitive::new(v.into()));
+            self
+        }
+    }
+
+    impl FetchStepMqttBuilder<String, i32, Vec<source::MqttTopicSubscription>> {
+        pub fn build(self) -> FetchStepMqtt {
+            FetchStepMqtt {
+                host: self.host,
+                port: self.port,
+                username: self.username,
+                password: self.password,
+                topics: self.topics,
+            }
+        }
+    }
+
+    impl Redact for FetchStepMqtt {
+        fn redact(&self) -> Self {
+            Self {
+                host: self.host.clone(),
+                port: self.port.clone(),
+                username: self.username.clone(),
+                password: self.password.redact(),
+                topics: self.topics.clone(),
+            }
+        }
+    }
+
     /// Pulls data from one of the supported sources by its URL.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Url
@@ -2120,8 +8461,70 @@ pub mod legacy {
         pub headers: Option<Vec<source::RequestHeader>>,
     }
 
-    /// An object that wraps the metadata resources providing versioning and type identification. All root-level resources are wrapped with a manifest when serialized to disk.
-    ///
+    /// Builder for [`FetchStepUrl`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FetchStepUrlBuilder<TUrl = Unset> {
+        url: TUrl,
+        event_time: Option<source::EventTimeSource>,
+        cache: Option<source::SourceCaching>,
+        headers: Option<Vec<source::RequestHeader>>,
+    }
+
+    impl FetchStepUrl {
+        pub fn builder() -> FetchStepUrlBuilder<Unset> {
+            FetchStepUrlBuilder {
+                url: Unset,
+                event_time: None,
+                cache: None,
+                headers: None,
+            }
+        }
+    }
+
+    impl FetchStepUrlBuilder<Unset> {
+        /// URL of the data source
+        pub fn url(self, v: impl Into<String>) -> FetchStepUrlBuilder<String> {
+            FetchStepUrlBuilder {
+                url: v.into(),
+                event_time: self.event_time,
+                cache: self.cache,
+                headers: self.headers,
+            }
+        }
+    }
+
+    impl<TUrl> FetchStepUrlBuilder<TUrl> {
+        /// Describes how event time is extracted from the source metadata.
+        pub fn event_time(mut self, v: impl Into<source::EventTimeSource>) -> Self {
+            self.event_time = Some(v.into());
+            self
+        }
+        /// Describes the caching settings used for this source.
+        pub fn cache(mut self, v: impl Into<source::SourceCaching>) -> Self {
+            self.cache = Some(v.into());
+            self
+        }
+        /// Headers to pass during the request (e.g. HTTP Authorization)
+        pub fn headers(mut self, v: impl Into<Vec<source::RequestHeader>>) -> Self {
+            self.headers = Some(v.into());
+            self
+        }
+    }
+
+    impl FetchStepUrlBuilder<String> {
+        pub fn build(self) -> FetchStepUrl {
+            FetchStepUrl {
+                url: self.url,
+                event_time: self.event_time,
+                cache: self.cache,
+                headers: self.headers,
+            }
+        }
+    }
+
+    /// An object that wraps the metadata resources providing versioning and type identification. All root-level resources are wrapped with a manifest when serialized to disk.
+    ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/Manifest
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub struct Manifest<ContentT> {
@@ -2133,6 +8536,86 @@ pub mod legacy {
         pub content: ContentT,
     }
 
+    /// Builder for [`Manifest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ManifestBuilder<ContentT, TKind = Unset, TVersion = Unset, TContent = Unset> {
+        kind: TKind,
+        version: TVersion,
+        content: TContent,
+        _marker: std::marker::PhantomData<(ContentT,)>,
+    }
+
+    impl<ContentT> Manifest<ContentT> {
+        pub fn builder() -> ManifestBuilder<ContentT, Unset, Unset, Unset> {
+            ManifestBuilder {
+                kind: Unset,
+                version: Unset,
+                content: Unset,
+                _marker: std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<ContentT, TVersion, TContent> ManifestBuilder<ContentT, Unset, TVersion, TContent> {
+        /// Type of the resource.
+        pub fn kind(
+            self,
+            v: impl Into<String>,
+        ) -> ManifestBuilder<ContentT, String, TVersion, TContent> {
+            ManifestBuilder {
+                kind: v.into(),
+                version: self.version,
+                content: self.content,
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<ContentT, TKind, TContent> ManifestBuilder<ContentT, TKind, Unset, TContent> {
+        /// Major version number of the resource contained in this manifest. It provides the mechanism for introducing compatibility breaking changes.
+        pub fn version(self, v: impl Into<i32>) -> ManifestBuilder<ContentT, TKind, i32, TContent> {
+            ManifestBuilder {
+                kind: self.kind,
+                version: v.into(),
+                content: self.content,
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<ContentT, TKind, TVersion> ManifestBuilder<ContentT, TKind, TVersion, Unset> {
+        /// Resource data.
+        pub fn content(self, v: ContentT) -> ManifestBuilder<ContentT, TKind, TVersion, ContentT> {
+            ManifestBuilder {
+                kind: self.kind,
+                version: self.version,
+                content: v.into(),
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<ContentT> ManifestBuilder<ContentT, String, i32, ContentT> {
+        pub fn build(self) -> Manifest<ContentT> {
+            Manifest {
+                kind: self.kind,
+                version: self.version,
+                content: self.content,
+            }
+        }
+    }
+
+    impl<ContentT: Redact> Redact for Manifest<ContentT> {
+        fn redact(&self) -> Self {
+            Self {
+                kind: self.kind.clone(),
+                version: self.version.clone(),
+                content: self.content.redact(),
+            }
+        }
+    }
+
     /// Contains information on how externally-hosted data can be ingested into the root dataset.
     ///
     /// Schema: https://opendatafabric.org/schemas/legacy/v0/SetPollingSource
@@ -2149,1302 +8632,14672 @@ pub mod legacy {
         /// Determines how newly-ingested data should be merged with existing history.
         pub merge: source::MergeStrategy,
     }
+
+    /// Builder for [`SetPollingSource`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SetPollingSourceBuilder<TFetch = Unset, TRead = Unset, TMerge = Unset> {
+        fetch: TFetch,
+        prepare: Option<Vec<source::PrepStep>>,
+        read: TRead,
+        preprocess: Option<dataset::Transform>,
+        merge: TMerge,
+    }
+
+    impl SetPollingSource {
+        pub fn builder() -> SetPollingSourceBuilder<Unset, Unset, Unset> {
+            SetPollingSourceBuilder {
+                fetch: Unset,
+                prepare: None,
+                read: Unset,
+                preprocess: None,
+                merge: Unset,
+            }
+        }
+    }
+
+    impl<TRead, TMerge> SetPollingSourceBuilder<Unset, TRead, TMerge> {
+        /// Determines where data is sourced from.
+        pub fn fetch(
+            self,
+            v: impl Into<legacy::FetchStep>,
+        ) -> SetPollingSourceBuilder<legacy::FetchStep, TRead, TMerge> {
+            SetPollingSourceBuilder {
+                fetch: v.into(),
+                prepare: self.prepare,
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: self.merge,
+            }
+        }
+    }
+
+    impl<TFetch, TMerge> SetPollingSourceBuilder<TFetch, Unset, TMerge> {
+        /// Defines how data is read into structured format.
+        pub fn read(
+            self,
+            v: impl Into<source::ReadStep>,
+        ) -> SetPollingSourceBuilder<TFetch, source::ReadStep, TMerge> {
+            SetPollingSourceBuilder {
+                fetch: self.fetch,
+                prepare: self.prepare,
+                read: v.into(),
+                preprocess: self.preprocess,
+                merge: self.merge,
+            }
+        }
+    }
+
+    impl<TFetch, TRead> SetPollingSourceBuilder<TFetch, TRead, Unset> {
+        /// Determines how newly-ingested data should be merged with existing history.
+        pub fn merge(
+            self,
+            v: impl Into<source::MergeStrategy>,
+        ) -> SetPollingSourceBuilder<TFetch, TRead, source::MergeStrategy> {
+            SetPollingSourceBuilder {
+                fetch: self.fetch,
+                prepare: self.prepare,
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: v.into(),
+            }
+        }
+    }
+
+    impl<TFetch, TRead, TMerge> SetPollingSourceBuilder<TFetch, TRead, TMerge> {
+        /// Defines how raw data is prepared before reading.
+        pub fn prepare(mut self, v: impl Into<Vec<source::PrepStep>>) -> Self {
+            self.prepare = Some(v.into());
+            self
+        }
+        /// Pre-processing query that shapes the data.
+        pub fn preprocess(mut self, v: impl Into<dataset::Transform>) -> Self {
+            self.preprocess = Some(v.into());
+            self
+        }
+    }
+
+    impl SetPollingSourceBuilder<legacy::FetchStep, source::ReadStep, source::MergeStrategy> {
+        pub fn build(self) -> SetPollingSource {
+            SetPollingSource {
+                fetch: self.fetch,
+                prepare: self.prepare,
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: self.merge,
+            }
+        }
+    }
+
+    impl Redact for SetPollingSource {
+        fn redact(&self) -> Self {
+            Self {
+                fetch: self.fetch.redact(),
+                prepare: self.prepare.clone(),
+                read: self.read.clone(),
+                preprocess: self.preprocess.clone(),
+                merge: self.merge.clone(),
+            }
+        }
+    }
 }
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// resource
+// protocol
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub mod resource {
+pub mod protocol {
     #[allow(unused_imports)]
     use super::*;
 
-    /// Filters that work on resource labels and identity headers.
+    /// Informs about error happened during one of API operations with the dataset.
     ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub struct LabelFilter {
-        pub entries: std::collections::BTreeMap<String, serde_json::Value>,
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetError
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct DatasetError {
+        /// Details of the error.
+        pub error_details: protocol::ErrorDetails,
     }
 
-    /// Top-level container for canonical representation of a resource that specifies the type and version of the resource, carries identity, ownership, and status information.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/Resource
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct Resource<SpecT> {
-        /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
-        pub schema: TypeUri,
-        /// Container for identity and ownership information of a resource.
-        pub headers: resource::ResourceHeaders,
-        /// Specifies the desired state of a resource.
-        pub spec: SpecT,
-        /// Resource lifecycle and reconciliation information.
-        pub status: resource::ResourceStatus,
+    /// Builder for [`DatasetError`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetErrorBuilder<TErrorDetails = Unset> {
+        error_details: TErrorDetails,
     }
 
-    /// Annotations is an unstructured key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceAnnotations
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub struct ResourceAnnotations {
-        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    impl DatasetError {
+        pub fn builder() -> DatasetErrorBuilder<Unset> {
+            DatasetErrorBuilder {
+                error_details: Unset,
+            }
+        }
     }
 
-    /// Container of feneric contditions that can be added by contollers to provide additional information about the state of a resource. Keys uniquely identify the condition and should be in the form of URL to a schema describing this condition, e.g. `https://opendatafabric.org/schemas/resource/ConditionReady.json`.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceConditions
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub struct ResourceConditions {
-        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    impl DatasetErrorBuilder<Unset> {
+        /// Details of the error.
+        pub fn error_details(
+            self,
+            v: impl Into<protocol::ErrorDetails>,
+        ) -> DatasetErrorBuilder<protocol::ErrorDetails> {
+            DatasetErrorBuilder {
+                error_details: v.into(),
+            }
+        }
     }
 
-    /// Lint to another resolved resource.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHandle
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct ResourceHandle {
-        /// Account that owns the target resource.
-        pub account: auth::AccountHandle,
-        /// Type URI of the target resource.
-        pub r#type: TypeUri,
-        /// ID of the resource within a node.
-        pub id: ResourceID,
-        /// DID of the resource, if applicable.
-        pub did: Option<Did>,
-        /// Name of a resource.
-        pub name: ResourceName,
+    impl DatasetErrorBuilder<protocol::ErrorDetails> {
+        pub fn build(self) -> DatasetError {
+            DatasetError {
+                error_details: self.error_details,
+            }
+        }
     }
-    impl IntoResourceRef for ResourceHandle {}
 
-    /// Container for identity and ownership information of a resource.
+    /// Response with the metadata for the requested pull range.
     ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeaders
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetMetadataPullResponse
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct ResourceHeaders {
-        /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
-        pub id: ResourceID,
-        /// Symbolic name of a resource that identifies it within a scope of an onwing account.
-        pub name: ResourceName,
-        /// Link to the account that owns the resource.
-        pub account: auth::AccountHandle,
-        /// Map of string keys and values that can be used to organize, categorize, and query resources.
-        pub labels: resource::ResourceLabels,
-        /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
-        pub annotations: resource::ResourceAnnotations,
-        /// A sequential number that changes every time the resource header and spec are updated. Does not increment on status changes, thus signifying changes to the desired state. Populated by the system. Starts with `1`.
-        pub generation: u64,
-        /// Time when the resource was first applied and assigned an identity.
-        pub created_at: DateTime<Utc>,
-        /// Time when the resource was last updated, including header, spec, and status updates.
-        pub updated_at: DateTime<Utc>,
-        /// Time when the resource was deleted.
-        pub deleted_at: Option<DateTime<Utc>>,
+    pub struct DatasetMetadataPullResponse {
+        /// Metadata blocks of the requested range.
+        pub blocks: protocol::ObjectsBatch,
     }
 
-    /// Container for identity and ownership information of a resource.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeadersInput
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct ResourceHeadersInput {
-        /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
-        pub id: Option<ResourceID>,
-        /// Symbolic name of a resource that identifies it within a scope of an onwing account.
-        pub name: ResourceName,
-        /// Reference to the account that owns the resource.
-        pub account: Option<auth::AccountRef>,
-        /// Map of string keys and values that can be used to organize, categorize, and query resources.
-        pub labels: Option<resource::ResourceLabels>,
-        /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
-        pub annotations: Option<resource::ResourceAnnotations>,
+    /// Builder for [`DatasetMetadataPullResponse`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetMetadataPullResponseBuilder<TBlocks = Unset> {
+        blocks: TBlocks,
     }
 
-    /// Top-level container for user-authored representation of a resource that specifies the type and version of the resource and its desired state.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceInput
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct ResourceInput<SpecT> {
-        /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
-        pub schema: TypeUri,
-        /// Container for identity and ownership information of a resource.
-        pub headers: resource::ResourceHeadersInput,
-        /// Specifies the desired state of a resource.
-        pub spec: SpecT,
+    impl DatasetMetadataPullResponse {
+        pub fn builder() -> DatasetMetadataPullResponseBuilder<Unset> {
+            DatasetMetadataPullResponseBuilder { blocks: Unset }
+        }
     }
 
-    /// Map of string keys and values that can be used to organize, categorize, and query resources.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceLabels
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub struct ResourceLabels {
-        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    impl DatasetMetadataPullResponseBuilder<Unset> {
+        /// Metadata blocks of the requested range.
+        pub fn blocks(
+            self,
+            v: impl Into<protocol::ObjectsBatch>,
+        ) -> DatasetMetadataPullResponseBuilder<protocol::ObjectsBatch> {
+            DatasetMetadataPullResponseBuilder { blocks: v.into() }
+        }
     }
 
-    /// Represents the lifecycle stage of a resource.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourcePhase
-    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-    pub enum ResourcePhase {
-        Pending,
-        Reconciling,
-        Ready,
-        Failed,
+    impl DatasetMetadataPullResponseBuilder<protocol::ObjectsBatch> {
+        pub fn build(self) -> DatasetMetadataPullResponse {
+            DatasetMetadataPullResponse {
+                blocks: self.blocks,
+            }
+        }
     }
 
-    /// Reference to another resource.
+    /// Requests download of the metadata from the ODF server.
     ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceRef
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullMetadataRequest
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct DatasetPullMetadataRequest {}
+
+    /// Requests to agree on the strategy for downloading 1 or more object files.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferRequest
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct ResourceRef {
-        /// Reference to an account that owns the target resource.
-        pub account: Option<auth::AccountRef>,
-        /// Short type name or full type URI of the target resource.
-        ///
-        /// Examples:
-        /// - "SecretSet"
-        /// - "https://opendatafabric.org/config/v1/SecretSet"
-        pub r#type: TypeRef,
-        /// ID of the resource within a node.
-        pub id: Option<ResourceID>,
-        /// DID of the resource.
-        pub did: Option<Did>,
-        /// Name of a resource.
-        pub name: Option<ResourceName>,
+    pub struct DatasetPullObjectsTransferRequest {
+        /// List of object file references in this bulk.
+        pub object_files: Vec<protocol::ObjectFileReference>,
     }
-    impl IntoResourceRef for ResourceRef {}
 
-    pub use crate::resource::ResourceSelector;
+    /// Builder for [`DatasetPullObjectsTransferRequest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPullObjectsTransferRequestBuilder<TObjectFiles = Unset> {
+        object_files: TObjectFiles,
+    }
 
-    /// Resource lifecycle and reconciliation information.
+    impl DatasetPullObjectsTransferRequest {
+        pub fn builder() -> DatasetPullObjectsTransferRequestBuilder<Unset> {
+            DatasetPullObjectsTransferRequestBuilder {
+                object_files: Unset,
+            }
+        }
+    }
+
+    impl DatasetPullObjectsTransferRequestBuilder<Unset> {
+        /// List of object file references in this bulk.
+        pub fn object_files(
+            self,
+            v: impl Into<Vec<protocol::ObjectFileReference>>,
+        ) -> DatasetPullObjectsTransferRequestBuilder<Vec<protocol::ObjectFileReference>> {
+            DatasetPullObjectsTransferRequestBuilder {
+                object_files: v.into(),
+            }
+        }
+    }
+
+    impl DatasetPullObjectsTransferRequestBuilder<Vec<protocol::ObjectFileReference>> {
+        pub fn build(self) -> DatasetPullObjectsTransferRequest {
+            DatasetPullObjectsTransferRequest {
+                object_files: self.object_files,
+            }
+        }
+    }
+
+    /// Response on the agreed strategy for objects downloading during the pull.
     ///
-    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceStatus
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferResponse
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct ResourceStatus {
-        /// Represents the lifecycle stage of a resource.
-        pub phase: resource::ResourcePhase,
-        /// Resource generation that was last processed by the main resource controller.
-        pub observed_generation: Option<u64>,
-        /// Time when the controller last reconciled the desired resource state as defined in `observedGeneration`.
-        pub reconciled_at: Option<DateTime<Utc>>,
-        /// Detailed conditions describing the state of the resource that are added by controllers.
-        pub conditions: resource::ResourceConditions,
+    pub struct DatasetPullObjectsTransferResponse {
+        /// Agreed download operations for the requested bulk of object files.
+        pub object_transfer_strategies: Vec<protocol::PullObjectTransferStrategy>,
     }
-}
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// sink
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-
-pub mod sink {
-    #[allow(unused_imports)]
-    use super::*;
 
-    /// Defines a webhook target endpoint that can receive event notifications and data.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTarget
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct WebhookTarget {
-        /// Container for identity and ownership information of a resource.
-        pub headers: resource::ResourceHeadersInput,
-        /// Specifies the desired state of the resource.
-        pub spec: sink::WebhookTargetSpecInput,
+    /// Builder for [`DatasetPullObjectsTransferResponse`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPullObjectsTransferResponseBuilder<TObjectTransferStrategies = Unset> {
+        object_transfer_strategies: TObjectTransferStrategies,
     }
 
-    impl WebhookTarget {
-        pub fn schema() -> &'static TypeUri {
-            &WEBHOOK_TARGET_SCHEMA
-        }
-        pub const fn schema_str() -> &'static str {
-            WEBHOOK_TARGET_SCHEMA_STR
+    impl DatasetPullObjectsTransferResponse {
+        pub fn builder() -> DatasetPullObjectsTransferResponseBuilder<Unset> {
+            DatasetPullObjectsTransferResponseBuilder {
+                object_transfer_strategies: Unset,
+            }
         }
     }
 
-    static WEBHOOK_TARGET_SCHEMA_STR: &str =
-        "https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTarget";
+    impl DatasetPullObjectsTransferResponseBuilder<Unset> {
+        /// Agreed download operations for the requested bulk of object files.
+        pub fn object_transfer_strategies(
+            self,
+            v: impl Into<Vec<protocol::PullObjectTransferStrategy>>,
+        ) -> DatasetPullObjectsTransferResponseBuilder<Vec<protocol::PullObjectTransferStrategy>>
+        {
+            DatasetPullObjectsTransferResponseBuilder {
+                object_transfer_strategies: v.into(),
+            }
+        }
+    }
 
-    static WEBHOOK_TARGET_SCHEMA: std::sync::LazyLock<TypeUri> =
-        std::sync::LazyLock::new(|| TypeUri::new_unchecked(WEBHOOK_TARGET_SCHEMA_STR));
+    impl DatasetPullObjectsTransferResponseBuilder<Vec<protocol::PullObjectTransferStrategy>> {
+        pub fn build(self) -> DatasetPullObjectsTransferResponse {
+            DatasetPullObjectsTransferResponse {
+                object_transfer_strategies: self.object_transfer_strategies,
+            }
+        }
+    }
 
-    /// Defines a webhook target endpoint that can receive event notifications and data.
+    /// Initiates dataset pull from the ODF server.
     ///
-    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpec
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullRequest
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct WebhookTargetSpec {
-        /// Target url of the webhook.
-        pub url: String,
-        /// Shared secret used for HMAC signature of the request payload for authentication.
-        pub secret: Option<config::Secret>,
+    pub struct DatasetPullRequest {
+        /// Dataset identifier.
+        pub dataset_id: DatasetID,
+        /// Hash of the last metadata block consumer already has (undefined for initial state).
+        pub begin_after: Option<Multihash>,
+        /// Hash of the last metadata block consumer would like to pull (undefined for head).
+        pub stop_at: Option<Multihash>,
     }
 
-    /// Defines a webhook target endpoint that can receive event notifications and data.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpecInput
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct WebhookTargetSpecInput {
-        /// Target url of the webhook.
-        pub url: String,
-        /// Shared secret used for HMAC signature of the request payload for authentication.
-        pub secret: Option<config::Secret>,
+    /// Builder for [`DatasetPullRequest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPullRequestBuilder<TDatasetId = Unset> {
+        dataset_id: TDatasetId,
+        begin_after: Option<Multihash>,
+        stop_at: Option<Multihash>,
     }
 
-    /// Represents the status of the webhook target endpoint.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct WebhookTargetStatus {
-        /// Status value.
-        pub value: sink::WebhookTargetStatusValue,
+    impl DatasetPullRequest {
+        pub fn builder() -> DatasetPullRequestBuilder<Unset> {
+            DatasetPullRequestBuilder {
+                dataset_id: Unset,
+                begin_after: None,
+                stop_at: None,
+            }
+        }
     }
 
-    /// Status of the target endpoint
-    ///
-    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus#/$defs/Value
-    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-    pub enum WebhookTargetStatusValue {
-        Ready,
-        Failed,
+    impl DatasetPullRequestBuilder<Unset> {
+        /// Dataset identifier.
+        pub fn dataset_id(self, v: impl Into<DatasetID>) -> DatasetPullRequestBuilder<DatasetID> {
+            DatasetPullRequestBuilder {
+                dataset_id: v.into(),
+                begin_after: self.begin_after,
+                stop_at: self.stop_at,
+            }
+        }
     }
-}
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// source
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub mod source {
-    #[allow(unused_imports)]
-    use super::*;
+    impl<TDatasetId> DatasetPullRequestBuilder<TDatasetId> {
+        /// Hash of the last metadata block consumer already has (undefined for initial state).
+        pub fn begin_after(mut self, v: impl Into<Multihash>) -> Self {
+            self.begin_after = Some(v.into());
+            self
+        }
+        /// Hash of the last metadata block consumer would like to pull (undefined for head).
+        pub fn stop_at(mut self, v: impl Into<Multihash>) -> Self {
+            self.stop_at = Some(v.into());
+            self
+        }
+    }
 
-    /// Defines a compression algorithm.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/CompressionFormat
-    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-    pub enum CompressionFormat {
-        Gzip,
-        Zip,
+    impl DatasetPullRequestBuilder<DatasetID> {
+        pub fn build(self) -> DatasetPullRequest {
+            DatasetPullRequest {
+                dataset_id: self.dataset_id,
+                begin_after: self.begin_after,
+                stop_at: self.stop_at,
+            }
+        }
     }
 
-    /// Defines an environment variable passed into some job.
+    /// Indicates a successful response to the initiated pull request and provides size estimation.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EnvVar
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullResponse
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct EnvVar {
-        /// Name of the variable.
-        pub name: String,
-        /// Value of the variable.
-        pub value: Option<String>,
+    pub struct DatasetPullResponse {
+        /// Estimated size of the data about to be transferred.
+        pub size_estimation: protocol::TransferSizeEstimation,
     }
 
-    /// Defines the external source of data.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum EventTimeSource {
-        FromMetadata(source::EventTimeSourceFromMetadata),
-        FromPath(source::EventTimeSourceFromPath),
-        FromSystemTime(source::EventTimeSourceFromSystemTime),
+    /// Builder for [`DatasetPullResponse`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPullResponseBuilder<TSizeEstimation = Unset> {
+        size_estimation: TSizeEstimation,
     }
 
-    impl_enum_with_variants!(EventTimeSource);
-    impl_enum_variant!(EventTimeSource::FromMetadata(
-        source::EventTimeSourceFromMetadata
-    ));
-    impl_enum_variant!(EventTimeSource::FromPath(source::EventTimeSourceFromPath));
-    impl_enum_variant!(EventTimeSource::FromSystemTime(
-        source::EventTimeSourceFromSystemTime
-    ));
+    impl DatasetPullResponse {
+        pub fn builder() -> DatasetPullResponseBuilder<Unset> {
+            DatasetPullResponseBuilder {
+                size_estimation: Unset,
+            }
+        }
+    }
 
-    /// Extracts event time from the source's metadata.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromMetadata
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct EventTimeSourceFromMetadata {}
+    impl DatasetPullResponseBuilder<Unset> {
+        /// Estimated size of the data about to be transferred.
+        pub fn size_estimation(
+            self,
+            v: impl Into<protocol::TransferSizeEstimation>,
+        ) -> DatasetPullResponseBuilder<protocol::TransferSizeEstimation> {
+            DatasetPullResponseBuilder {
+                size_estimation: v.into(),
+            }
+        }
+    }
 
-    /// Extracts event time from the path component of the source.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromPath
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct EventTimeSourceFromPath {
-        /// Regular expression where first group contains the timestamp string.
-        pub pattern: String,
-        /// Format of the expected timestamp in java.text.SimpleDateFormat form.
-        pub timestamp_format: Option<String>,
+    impl DatasetPullResponseBuilder<protocol::TransferSizeEstimation> {
+        pub fn build(self) -> DatasetPullResponse {
+            DatasetPullResponse {
+                size_estimation: self.size_estimation,
+            }
+        }
     }
 
-    /// Assigns event time from the system time source.
+    /// Informs ODF server the push operation is complete.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromSystemTime
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushComplete
     #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct EventTimeSourceFromSystemTime {}
+    pub struct DatasetPushComplete {}
 
-    /// Optional parameters to control ingestion behavior.
+    /// Informs consumer the push completion is confirmed.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngestParams
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushCompleteConfirmed
     #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct IngestParams {
-        /// Target number of records to ingest per data slice.
-        pub target_slice_records: Option<u64>,
-    }
+    pub struct DatasetPushCompleteConfirmed {}
 
-    /// Defines the point where data enters the system.
+    /// Initiates upload of the metadata to the ODF server.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum Ingress {
-        Url(source::IngressUrl),
-        FilesGlob(source::IngressFilesGlob),
-        Container(source::IngressContainer),
-        Mqtt(source::IngressMqtt),
-        EvmLogs(source::IngressEvmLogs),
-        RestEndpoint(source::IngressRestEndpoint),
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadata
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct DatasetPushMetadata {
+        /// Metadata blocks that are missing on the server.
+        pub new_blocks: protocol::ObjectsBatch,
     }
 
-    impl_enum_with_variants!(Ingress);
-    impl_enum_variant!(Ingress::Url(source::IngressUrl));
-    impl_enum_variant!(Ingress::FilesGlob(source::IngressFilesGlob));
-    impl_enum_variant!(Ingress::Container(source::IngressContainer));
-    impl_enum_variant!(Ingress::Mqtt(source::IngressMqtt));
-    impl_enum_variant!(Ingress::EvmLogs(source::IngressEvmLogs));
-    impl_enum_variant!(Ingress::RestEndpoint(source::IngressRestEndpoint));
+    /// Builder for [`DatasetPushMetadata`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPushMetadataBuilder<TNewBlocks = Unset> {
+        new_blocks: TNewBlocks,
+    }
 
-    /// Buffer configuration for holding pushed records until they are ingested.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum IngressBuffer {
-        Memory(source::IngressBufferMemory),
+    impl DatasetPushMetadata {
+        pub fn builder() -> DatasetPushMetadataBuilder<Unset> {
+            DatasetPushMetadataBuilder { new_blocks: Unset }
+        }
     }
 
-    impl_enum_with_variants!(IngressBuffer);
-    impl_enum_variant!(IngressBuffer::Memory(source::IngressBufferMemory));
+    impl DatasetPushMetadataBuilder<Unset> {
+        /// Metadata blocks that are missing on the server.
+        pub fn new_blocks(
+            self,
+            v: impl Into<protocol::ObjectsBatch>,
+        ) -> DatasetPushMetadataBuilder<protocol::ObjectsBatch> {
+            DatasetPushMetadataBuilder {
+                new_blocks: v.into(),
+            }
+        }
+    }
 
-    /// An in-memory buffer.
+    impl DatasetPushMetadataBuilder<protocol::ObjectsBatch> {
+        pub fn build(self) -> DatasetPushMetadata {
+            DatasetPushMetadata {
+                new_blocks: self.new_blocks,
+            }
+        }
+    }
+
+    /// Informs about successful acceptance of dataset metadata during push.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer#/$defs/Memory
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadataAccepted
     #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct IngressBufferMemory {
-        /// Maximum number of records to hold in the buffer.
-        pub buffer_size: Option<u64>,
-        /// Policy applied when the buffer is full.
-        pub overflow_policy: Option<String>,
-    }
+    pub struct DatasetPushMetadataAccepted {}
 
-    /// Runs the specified OCI container to fetch data from an arbitrary source.
+    /// Requests to agree on the strategy for uploading 1 or more object files.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Container
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferRequest
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct IngressContainer {
-        /// Image name and and an optional tag.
-        pub image: String,
-        /// Specifies the entrypoint. Not executed within a shell. The default OCI image's ENTRYPOINT is used if this is not provided.
-        pub command: Option<Vec<String>>,
-        /// Arguments to the entrypoint. The OCI image's CMD is used if this is not provided.
-        pub args: Option<Vec<String>>,
-        /// Environment variables to propagate into or set in the container.
-        pub env: Option<Vec<source::EnvVar>>,
+    pub struct DatasetPushObjectsTransferRequest {
+        /// List of object file references in this bulk.
+        pub object_files: Vec<protocol::ObjectFileReference>,
     }
 
-    /// Connects to an EVM (Ethereum) node to stream transaction logs.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/EvmLogs
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct IngressEvmLogs {
-        /// Identifier of the chain to scan logs from. This parameter may be used for RPC endpoint lookup as well as asserting that provided `nodeUrl` corresponds to the expected chain.
-        pub chain_id: Option<u64>,
-        /// Url of the node.
-        pub node_url: Option<String>,
-        /// An SQL WHERE clause that can be used to pre-filter the logs before fetching them from the ETH node.
-        ///
-        /// Examples:
-        /// - "block_number > 123 and address = X'5fbdb2315678afecb367f032d93f642f64180aa3' and topic1 = X'000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266'"
-        pub filter: Option<String>,
-        /// Solidity log event signature to use for decoding. Using this field adds `event` to the output containing decoded log as JSON.
-        pub signature: Option<String>,
+    /// Builder for [`DatasetPushObjectsTransferRequest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPushObjectsTransferRequestBuilder<TObjectFiles = Unset> {
+        object_files: TObjectFiles,
     }
 
-    /// Uses glob operator to match files on the local file system.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/FilesGlob
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct IngressFilesGlob {
-        /// Path with a glob pattern.
-        pub path: String,
-        /// Describes how event time is extracted from the source metadata.
-        pub event_time: Option<source::EventTimeSource>,
-        /// Describes the caching settings used for this source.
-        pub cache: Option<source::SourceCaching>,
-        /// Specifies how input files should be ordered before ingestion.
-        /// Order is important as every file will be processed individually
-        /// and will advance the dataset's watermark.
-        pub order: Option<source::SourceOrdering>,
+    impl DatasetPushObjectsTransferRequest {
+        pub fn builder() -> DatasetPushObjectsTransferRequestBuilder<Unset> {
+            DatasetPushObjectsTransferRequestBuilder {
+                object_files: Unset,
+            }
+        }
     }
 
-    /// Connects to an MQTT broker to fetch events from the specified topic.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Mqtt
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct IngressMqtt {
-        /// Hostname of the MQTT broker.
-        pub host: String,
-        /// Port of the MQTT broker.
-        pub port: i32,
-        /// Username to use for auth with the broker.
-        pub username: Option<String>,
-        /// Password to use for auth with the broker (can be templated).
-        pub password: Option<String>,
-        /// List of topic subscription parameters.
-        pub topics: Vec<source::MqttTopicSubscription>,
+    impl DatasetPushObjectsTransferRequestBuilder<Unset> {
+        /// List of object file references in this bulk.
+        pub fn object_files(
+            self,
+            v: impl Into<Vec<protocol::ObjectFileReference>>,
+        ) -> DatasetPushObjectsTransferRequestBuilder<Vec<protocol::ObjectFileReference>> {
+            DatasetPushObjectsTransferRequestBuilder {
+                object_files: v.into(),
+            }
+        }
     }
 
-    /// Exposes a REST HTTP endpoint that accepts pushed data records.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/RestEndpoint
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct IngressRestEndpoint {
-        /// Buffer configuration for holding records until they are ingested.
-        pub buffer: Option<source::IngressBuffer>,
+    impl DatasetPushObjectsTransferRequestBuilder<Vec<protocol::ObjectFileReference>> {
+        pub fn build(self) -> DatasetPushObjectsTransferRequest {
+            DatasetPushObjectsTransferRequest {
+                object_files: self.object_files,
+            }
+        }
     }
 
-    /// Pulls data from one of the supported sources by its URL.
+    /// Response on the agreed strategy for objects uploading during the push.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Url
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferResponse
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct IngressUrl {
-        /// URL of the data source
-        pub url: String,
-        /// Describes how event time is extracted from the source metadata.
-        pub event_time: Option<source::EventTimeSource>,
-        /// Describes the caching settings used for this source.
-        pub cache: Option<source::SourceCaching>,
-        /// Headers to pass during the request (e.g. HTTP Authorization)
-        pub headers: Option<Vec<source::RequestHeader>>,
+    pub struct DatasetPushObjectsTransferResponse {
+        /// Agreed upload operations for the requested bulk of object files.
+        pub object_transfer_strategies: Vec<protocol::PushObjectTransferStrategy>,
     }
 
-    /// Merge strategy determines how newly ingested data should be combined with the data that already exists in the dataset.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum MergeStrategy {
-        Append(source::MergeStrategyAppend),
-        Ledger(source::MergeStrategyLedger),
-        Snapshot(source::MergeStrategySnapshot),
-        ChangelogStream(source::MergeStrategyChangelogStream),
-        UpsertStream(source::MergeStrategyUpsertStream),
+    /// Builder for [`DatasetPushObjectsTransferResponse`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPushObjectsTransferResponseBuilder<TObjectTransferStrategies = Unset> {
+        object_transfer_strategies: TObjectTransferStrategies,
     }
 
-    impl_enum_with_variants!(MergeStrategy);
-    impl_enum_variant!(MergeStrategy::Append(source::MergeStrategyAppend));
-    impl_enum_variant!(MergeStrategy::Ledger(source::MergeStrategyLedger));
-    impl_enum_variant!(MergeStrategy::Snapshot(source::MergeStrategySnapshot));
-    impl_enum_variant!(MergeStrategy::ChangelogStream(
-        source::MergeStrategyChangelogStream
-    ));
-    impl_enum_variant!(MergeStrategy::UpsertStream(
-        source::MergeStrategyUpsertStream
-    ));
+    impl DatasetPushObjectsTransferResponse {
+        pub fn builder() -> DatasetPushObjectsTransferResponseBuilder<Unset> {
+            DatasetPushObjectsTransferResponseBuilder {
+                object_transfer_strategies: Unset,
+            }
+        }
+    }
 
-    /// Append merge strategy.
-    ///
-    /// Under this strategy new data will be appended to the dataset in its entirety, without any deduplication.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Append
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct MergeStrategyAppend {}
+    impl DatasetPushObjectsTransferResponseBuilder<Unset> {
+        /// Agreed upload operations for the requested bulk of object files.
+        pub fn object_transfer_strategies(
+            self,
+            v: impl Into<Vec<protocol::PushObjectTransferStrategy>>,
+        ) -> DatasetPushObjectsTransferResponseBuilder<Vec<protocol::PushObjectTransferStrategy>>
+        {
+            DatasetPushObjectsTransferResponseBuilder {
+                object_transfer_strategies: v.into(),
+            }
+        }
+    }
 
-    /// Changelog stream merge strategy.
-    ///
-    /// This is the native stream format for ODF that accurately describes the evolution of all event records including appends, retractions, and corrections as per RFC-015. No pre-processing except for format validation is done.
+    impl DatasetPushObjectsTransferResponseBuilder<Vec<protocol::PushObjectTransferStrategy>> {
+        pub fn build(self) -> DatasetPushObjectsTransferResponse {
+            DatasetPushObjectsTransferResponse {
+                object_transfer_strategies: self.object_transfer_strategies,
+            }
+        }
+    }
+
+    /// Initiates dataset push to the ODF server.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/ChangelogStream
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequest
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct MergeStrategyChangelogStream {
-        /// Names of the columns that uniquely identify the record throughout its lifetime
-        pub primary_key: Vec<String>,
+    pub struct DatasetPushRequest {
+        /// Dataset identifier.
+        pub dataset_id: DatasetID,
+        /// Hash of the head block that client assumes to be the current head on the server.
+        pub current_head: Multihash,
+        /// Estimated size of the data about to be transferred.
+        pub size_estimation: protocol::TransferSizeEstimation,
     }
 
-    /// Ledger merge strategy.
+    /// Builder for [`DatasetPushRequest`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct DatasetPushRequestBuilder<
+        TDatasetId = Unset,
+        TCurrentHead = Unset,
+        TSizeEstimation = Unset,
+    > {
+        dataset_id: TDatasetId,
+        current_head: TCurrentHead,
+        size_estimation: TSizeEstimation,
+    }
+
+    impl DatasetPushRequest {
+        pub fn builder() -> DatasetPushRequestBuilder<Unset, Unset, Unset> {
+            DatasetPushRequestBuilder {
+                dataset_id: Unset,
+                current_head: Unset,
+                size_estimation: Unset,
+            }
+        }
+    }
+
+    impl<TCurrentHead, TSizeEstimation>
+        DatasetPushRequestBuilder<Unset, TCurrentHead, TSizeEstimation>
+    {
+        /// Dataset identifier.
+        pub fn dataset_id(
+            self,
+            v: impl Into<DatasetID>,
+        ) -> DatasetPushRequestBuilder<DatasetID, TCurrentHead, TSizeEstimation> {
+            DatasetPushRequestBuilder {
+                dataset_id: v.into(),
+                current_head: self.current_head,
+                size_estimation: self.size_estimation,
+            }
+        }
+    }
+
+    impl<TDatasetId, TSizeEstimation> DatasetPushRequestBuilder<TDatasetId, Unset, TSizeEstimation> {
+        /// Hash of the head block that client assumes to be the current head on the server.
+        pub fn current_head(
+            self,
+            v: impl Into<Multihash>,
+        ) -> DatasetPushRequestBuilder<TDatasetId, Multihash, TSizeEstimation> {
+            DatasetPushRequestBuilder {
+                dataset_id: self.dataset_id,
+                current_head: v.into(),
+                size_estimation: self.size_estimation,
+            }
+        }
+    }
+
+    impl<TDatasetId, TCurrentHead> DatasetPushRequestBuilder<TDatasetId, TCurrentHead, Unset> {
+        /// Estimated size of the data about to be transferred.
+        pub fn size_estimation(
+            self,
+            v: impl Into<protocol::TransferSizeEstimation>,
+        ) -> DatasetPushRequestBuilder<TDatasetId, TCurrentHead, protocol::TransferSizeEstimation>
+        {
+            DatasetPushRequestBuilder {
+                dataset_id: self.dataset_id,
+                current_head: self.current_head,
+                size_estimation: v.into(),
+            }
+        }
+    }
+
+    impl DatasetPushRequestBuilder<DatasetID, Multihash, protocol::TransferSizeEstimation> {
+        pub fn build(self) -> DatasetPushRequest {
+            DatasetPushRequest {
+                dataset_id: self.dataset_id,
+                current_head: self.current_head,
+                size_estimation: self.size_estimation,
+            }
+        }
+    }
+
+    /// Informs about successful acceptance of dataset push request.
     ///
-    /// This strategy should be used for data sources containing ledgers of events. Currently this strategy will only perform deduplication of events using user-specified primary key columns. This means that the source data can contain partially overlapping set of records and only those records that were not previously seen will be appended.
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequestAccepted
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct DatasetPushRequestAccepted {}
+
+    /// Describes error happened during message processing.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Ledger
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ErrorDetails
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct MergeStrategyLedger {
-        /// Names of the columns that uniquely identify the record throughout its lifetime
-        pub primary_key: Vec<String>,
+    pub struct ErrorDetails {
+        /// Informative code of the error happened.
+        pub error_code: String,
+        /// Detailed description of the error happened.
+        pub description: String,
+        /// Optional extra fields associated with an error.
+        pub extras: Option<serde_json::Value>,
     }
 
-    /// Snapshot merge strategy.
-    ///
-    /// This strategy can be used for data state snapshots that are taken periodically and contain only the latest state of the observed entity or system. Over time such snapshots can have new rows added, and old rows either removed or modified.
-    ///
-    /// This strategy transforms snapshot data into an append-only event stream where data already added is immutable. It does so by performing Change Data Capture - essentially diffing the current state of data against the reconstructed previous state and recording differences as retractions or corrections. The Operation Type "op" column will contain:
-    ///   - append (`+A`) when a row appears for the first time
-    ///   - retraction (`-D`) when row disappears
-    ///   - correction (`-C`, `+C`) when row data has changed, with `-C` event carrying the old value of the row and `+C` carrying the new value.
-    ///
-    /// To correctly associate rows between old and new snapshots this strategy relies on user-specified primary key columns.
-    ///
-    /// To identify whether a row has changed this strategy will compare all other columns one by one. If the data contains a column that is guaranteed to change whenever any of the data columns changes (for example a last modification timestamp, an incremental version, or a data hash), then it can be specified in `compareColumns` property to speed up the detection of modified rows.
+    /// Builder for [`ErrorDetails`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ErrorDetailsBuilder<TErrorCode = Unset, TDescription = Unset> {
+        error_code: TErrorCode,
+        description: TDescription,
+        extras: Option<serde_json::Value>,
+    }
+
+    impl ErrorDetails {
+        pub fn builder() -> ErrorDetailsBuilder<Unset, Unset> {
+            ErrorDetailsBuilder {
+                error_code: Unset,
+                description: Unset,
+                extras: None,
+            }
+        }
+    }
+
+    impl<TDescription> ErrorDetailsBuilder<Unset, TDescription> {
+        /// Informative code of the error happened.
+        pub fn error_code(self, v: impl Into<String>) -> ErrorDetailsBuilder<String, TDescription> {
+            ErrorDetailsBuilder {
+                error_code: v.into(),
+                description: self.description,
+                extras: self.extras,
+            }
+        }
+    }
+
+    impl<TErrorCode> ErrorDetailsBuilder<TErrorCode, Unset> {
+        /// Detailed description of the error happened.
+        pub fn description(self, v: impl Into<String>) -> ErrorDetailsBuilder<TErrorCode, String> {
+            ErrorDetailsBuilder {
+                error_code: self.error_code,
+                description: v.into(),
+                extras: self.extras,
+            }
+        }
+    }
+
+    impl<TErrorCode, TDescription> ErrorDetailsBuilder<TErrorCode, TDescription> {
+        /// Optional extra fields associated with an error.
+        pub fn extras(mut self, v: impl Into<serde_json::Value>) -> Self {
+            self.extras = Some(v.into());
+            self
+        }
+    }
+
+    impl ErrorDetailsBuilder<String, String> {
+        pub fn build(self) -> ErrorDetails {
+            ErrorDetails {
+                error_code: self.error_code,
+                description: self.description,
+                extras: self.extras,
+            }
+        }
+    }
+
+    /// Reference to an object file of a dataset.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Snapshot
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectFileReference
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct MergeStrategySnapshot {
-        /// Names of the columns that uniquely identify the record throughout its lifetime.
-        pub primary_key: Vec<String>,
-        /// Names of the columns to compared to determine if a row has changed between two snapshots.
-        pub compare_columns: Option<Vec<String>>,
+    pub struct ObjectFileReference {
+        /// Type of the object.
+        pub object_type: protocol::ObjectType,
+        /// Physical hash of the object file.
+        pub physical_hash: Multihash,
     }
 
-    /// Upsert stream merge strategy.
+    /// Builder for [`ObjectFileReference`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ObjectFileReferenceBuilder<TObjectType = Unset, TPhysicalHash = Unset> {
+        object_type: TObjectType,
+        physical_hash: TPhysicalHash,
+    }
+
+    impl ObjectFileReference {
+        pub fn builder() -> ObjectFileReferenceBuilder<Unset, Unset> {
+            ObjectFileReferenceBuilder {
+                object_type: Unset,
+                physical_hash: Unset,
+            }
+        }
+    }
+
+    impl<TPhysicalHash> ObjectFileReferenceBuilder<Unset, TPhysicalHash> {
+        /// Type of the object.
+        pub fn object_type(
+            self,
+            v: impl Into<protocol::ObjectType>,
+        ) -> ObjectFileReferenceBuilder<protocol::ObjectType, TPhysicalHash> {
+            ObjectFileReferenceBuilder {
+                object_type: v.into(),
+                physical_hash: self.physical_hash,
+            }
+        }
+    }
+
+    impl<TObjectType> ObjectFileReferenceBuilder<TObjectType, Unset> {
+        /// Physical hash of the object file.
+        pub fn physical_hash(
+            self,
+            v: impl Into<Multihash>,
+        ) -> ObjectFileReferenceBuilder<TObjectType, Multihash> {
+            ObjectFileReferenceBuilder {
+                object_type: self.object_type,
+                physical_hash: v.into(),
+            }
+        }
+    }
+
+    impl ObjectFileReferenceBuilder<protocol::ObjectType, Multihash> {
+        pub fn build(self) -> ObjectFileReference {
+            ObjectFileReference {
+                object_type: self.object_type,
+                physical_hash: self.physical_hash,
+            }
+        }
+    }
+
+    /// Method of downloading an object file.
     ///
-    /// This strategy should be used for data sources containing ledgers of insert-or-update and delete events. Unlike ChangelogStream the insert-or-update events only carry the new values, so this strategy will use primary key to re-classify the events into an append or a correction from/to pair, looking up the previous values.
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPullStrategy
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum ObjectPullStrategy {
+        HttpDownload,
+    }
+
+    /// Method of uploading an object file.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/UpsertStream
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct MergeStrategyUpsertStream {
-        /// Names of the columns that uniquely identify the record throughout its lifetime
-        pub primary_key: Vec<String>,
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPushStrategy
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum ObjectPushStrategy {
+        SkipUpload,
+        HttpUpload,
     }
 
-    /// MQTT quality of service class.
+    /// Type of an object stored in a dataset.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttQos
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectType
     #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-    pub enum MqttQos {
-        AtMostOnce,
-        AtLeastOnce,
-        ExactlyOnce,
+    pub enum ObjectType {
+        MetadataBlock,
+        DataSlice,
+        Checkpoint,
     }
 
-    /// MQTT topic subscription parameters.
+    /// An archive of blocks segment being transferred.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttTopicSubscription
-    #[derive(Clone, Debug, Eq)]
-    pub struct MqttTopicSubscription {
-        /// Name of the topic (may include patterns).
-        pub path: String,
-        /// Quality of service class.
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectsBatch
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ObjectsBatch {
+        /// Number of objects included in the batch.
+        pub objects_count: u64,
+        /// Type of objects in the batch.
+        pub object_type: protocol::ObjectType,
+        /// Payload media type.
         ///
-        /// Defaults to: "AtMostOnce"
-        pub qos: Option<source::MqttQos>,
+        /// Examples:
+        /// - "application/tar"
+        pub media_type: String,
+        /// Payload encoding within the protocol.
+        ///
+        /// Examples:
+        /// - "base64"
+        pub encoding: String,
+        /// Encoded payload data.
+        pub payload: String,
     }
 
-    impl MqttTopicSubscription {
-        pub fn default_qos() -> source::MqttQos {
-            source::MqttQos::AtMostOnce
+    /// Builder for [`ObjectsBatch`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ObjectsBatchBuilder<
+        TObjectsCount = Unset,
+        TObjectType = Unset,
+        TMediaType = Unset,
+        TEncoding = Unset,
+        TPayload = Unset,
+    > {
+        objects_count: TObjectsCount,
+        object_type: TObjectType,
+        media_type: TMediaType,
+        encoding: TEncoding,
+        payload: TPayload,
+    }
+
+    impl ObjectsBatch {
+        pub fn builder() -> ObjectsBatchBuilder<Unset, Unset, Unset, Unset, Unset> {
+            ObjectsBatchBuilder {
+                objects_count: Unset,
+                object_type: Unset,
+                media_type: Unset,
+                encoding: Unset,
+                payload: Unset,
+            }
         }
-        pub fn qos(&self) -> source::MqttQos {
-            self.qos.unwrap_or(Self::default_qos())
+    }
+
+    impl<TObjectType, TMediaType, TEncoding, TPayload>
+        ObjectsBatchBuilder<Unset, TObjectType, TMediaType, TEncoding, TPayload>
+    {
+        /// Number of objects included in the batch.
+        pub fn objects_count(
+            self,
+            v: impl Into<u64>,
+        ) -> ObjectsBatchBuilder<u64, TObjectType, TMediaType, TEncoding, TPayload> {
+            ObjectsBatchBuilder {
+                objects_count: v.into(),
+                object_type: self.object_type,
+                media_type: self.media_type,
+                encoding: self.encoding,
+                payload: self.payload,
+            }
         }
     }
 
-    impl PartialEq for MqttTopicSubscription {
-        fn eq(&self, other: &Self) -> bool {
-            self.path == other.path
-                && self.qos.or_else(|| Some(Self::default_qos()))
-                    == other.qos.or_else(|| Some(Self::default_qos()))
+    impl<TObjectsCount, TMediaType, TEncoding, TPayload>
+        ObjectsBatchBuilder<TObjectsCount, Unset, TMediaType, TEncoding, TPayload>
+    {
+        /// Type of objects in the batch.
+        pub fn object_type(
+            self,
+            v: impl Into<protocol::ObjectType>,
+        ) -> ObjectsBatchBuilder<TObjectsCount, protocol::ObjectType, TMediaType, TEncoding, TPayload>
+        {
+            ObjectsBatchBuilder {
+                objects_count: self.objects_count,
+                object_type: v.into(),
+                media_type: self.media_type,
+                encoding: self.encoding,
+                payload: self.payload,
+            }
         }
     }
 
-    /// Defines the steps to prepare raw data for ingestion.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum PrepStep {
-        Decompress(source::PrepStepDecompress),
-        Pipe(source::PrepStepPipe),
+    impl<TObjectsCount, TObjectType, TEncoding, TPayload>
+        ObjectsBatchBuilder<TObjectsCount, TObjectType, Unset, TEncoding, TPayload>
+    {
+        /// Payload media type.
+        pub fn media_type(
+            self,
+            v: impl Into<String>,
+        ) -> ObjectsBatchBuilder<TObjectsCount, TObjectType, String, TEncoding, TPayload> {
+            ObjectsBatchBuilder {
+                objects_count: self.objects_count,
+                object_type: self.object_type,
+                media_type: v.into(),
+                encoding: self.encoding,
+                payload: self.payload,
+            }
+        }
     }
 
-    impl_enum_with_variants!(PrepStep);
-    impl_enum_variant!(PrepStep::Decompress(source::PrepStepDecompress));
-    impl_enum_variant!(PrepStep::Pipe(source::PrepStepPipe));
+    impl<TObjectsCount, TObjectType, TMediaType, TPayload>
+        ObjectsBatchBuilder<TObjectsCount, TObjectType, TMediaType, Unset, TPayload>
+    {
+        /// Payload encoding within the protocol.
+        pub fn encoding(
+            self,
+            v: impl Into<String>,
+        ) -> ObjectsBatchBuilder<TObjectsCount, TObjectType, TMediaType, String, TPayload> {
+            ObjectsBatchBuilder {
+                objects_count: self.objects_count,
+                object_type: self.object_type,
+                media_type: self.media_type,
+                encoding: v.into(),
+                payload: self.payload,
+            }
+        }
+    }
 
-    /// Pulls data from one of the supported sources by its URL.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Decompress
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct PrepStepDecompress {
-        /// Name of a compression algorithm used on data.
-        pub format: source::CompressionFormat,
-        /// Path to a data file within a multi-file archive. Can contain glob patterns.
-        pub sub_path: Option<String>,
+    impl<TObjectsCount, TObjectType, TMediaType, TEncoding>
+        ObjectsBatchBuilder<TObjectsCount, TObjectType, TMediaType, TEncoding, Unset>
+    {
+        /// Encoded payload data.
+        pub fn payload(
+            self,
+            v: impl Into<String>,
+        ) -> ObjectsBatchBuilder<TObjectsCount, TObjectType, TMediaType, TEncoding, String>
+        {
+            ObjectsBatchBuilder {
+                objects_count: self.objects_count,
+                object_type: self.object_type,
+                media_type: self.media_type,
+                encoding: self.encoding,
+                payload: v.into(),
+            }
+        }
     }
 
-    /// Executes external command to process the data using piped input/output.
+    impl ObjectsBatchBuilder<u64, protocol::ObjectType, String, String, String> {
+        pub fn build(self) -> ObjectsBatch {
+            ObjectsBatch {
+                objects_count: self.objects_count,
+                object_type: self.object_type,
+                media_type: self.media_type,
+                encoding: self.encoding,
+                payload: self.payload,
+            }
+        }
+    }
+
+    /// Agreed way of downloading an object file.
     ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Pipe
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PullObjectTransferStrategy
     #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct PrepStepPipe {
-        /// Command to execute and its arguments.
-        pub command: Vec<String>,
+    pub struct PullObjectTransferStrategy {
+        /// Object file to download.
+        pub object_file: protocol::ObjectFileReference,
+        /// Method of downloading the object file.
+        pub pull_strategy: protocol::ObjectPullStrategy,
+        /// Location to download the object file from.
+        pub download_from: protocol::TransferUrl,
     }
 
-    /// Defines how raw data should be read into the structured form.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum ReadStep {
-        Csv(source::ReadStepCsv),
-        GeoJson(source::ReadStepGeoJson),
-        EsriShapefile(source::ReadStepEsriShapefile),
-        Parquet(source::ReadStepParquet),
-        Json(source::ReadStepJson),
-        NdJson(source::ReadStepNdJson),
-        NdGeoJson(source::ReadStepNdGeoJson),
+    /// Builder for [`PullObjectTransferStrategy`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PullObjectTransferStrategyBuilder<
+        TObjectFile = Unset,
+        TPullStrategy = Unset,
+        TDownloadFrom = Unset,
+    > {
+        object_file: TObjectFile,
+        pull_strategy: TPullStrategy,
+        download_from: TDownloadFrom,
     }
 
-    impl_enum_with_variants!(ReadStep);
-    impl_enum_variant!(ReadStep::Csv(source::ReadStepCsv));
-    impl_enum_variant!(ReadStep::GeoJson(source::ReadStepGeoJson));
-    impl_enum_variant!(ReadStep::EsriShapefile(source::ReadStepEsriShapefile));
-    impl_enum_variant!(ReadStep::Parquet(source::ReadStepParquet));
-    impl_enum_variant!(ReadStep::Json(source::ReadStepJson));
-    impl_enum_variant!(ReadStep::NdJson(source::ReadStepNdJson));
-    impl_enum_variant!(ReadStep::NdGeoJson(source::ReadStepNdGeoJson));
+    impl PullObjectTransferStrategy {
+        pub fn builder() -> PullObjectTransferStrategyBuilder<Unset, Unset, Unset> {
+            PullObjectTransferStrategyBuilder {
+                object_file: Unset,
+                pull_strategy: Unset,
+                download_from: Unset,
+            }
+        }
+    }
 
-    /// Reader for comma-separated files.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Csv
-    #[derive(Clone, Debug, Eq, Default)]
-    pub struct ReadStepCsv {
-        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
-        ///
-        /// Examples:
-        /// - ["date TIMESTAMP","city STRING","population INT"]
-        pub ddl_schema: Option<Vec<String>>,
+    impl<TPullStrategy, TDownloadFrom>
+        PullObjectTransferStrategyBuilder<Unset, TPullStrategy, TDownloadFrom>
+    {
+        /// Object file to download.
+        pub fn object_file(
+            self,
+            v: impl Into<protocol::ObjectFileReference>,
+        ) -> PullObjectTransferStrategyBuilder<
+            protocol::ObjectFileReference,
+            TPullStrategy,
+            TDownloadFrom,
+        > {
+            PullObjectTransferStrategyBuilder {
+                object_file: v.into(),
+                pull_strategy: self.pull_strategy,
+                download_from: self.download_from,
+            }
+        }
+    }
+
+    impl<TObjectFile, TDownloadFrom>
+        PullObjectTransferStrategyBuilder<TObjectFile, Unset, TDownloadFrom>
+    {
+        /// Method of downloading the object file.
+        pub fn pull_strategy(
+            self,
+            v: impl Into<protocol::ObjectPullStrategy>,
+        ) -> PullObjectTransferStrategyBuilder<
+            TObjectFile,
+            protocol::ObjectPullStrategy,
+            TDownloadFrom,
+        > {
+            PullObjectTransferStrategyBuilder {
+                object_file: self.object_file,
+                pull_strategy: v.into(),
+                download_from: self.download_from,
+            }
+        }
+    }
+
+    impl<TObjectFile, TPullStrategy>
+        PullObjectTransferStrategyBuilder<TObjectFile, TPullStrategy, Unset>
+    {
+        /// Location to download the object file from.
+        pub fn download_from(
+            self,
+            v: impl Into<protocol::TransferUrl>,
+        ) -> PullObjectTransferStrategyBuilder<TObjectFile, TPullStrategy, protocol::TransferUrl>
+        {
+            PullObjectTransferStrategyBuilder {
+                object_file: self.object_file,
+                pull_strategy: self.pull_strategy,
+                download_from: v.into(),
+            }
+        }
+    }
+
+    impl
+        PullObjectTransferStrategyBuilder<
+            protocol::ObjectFileReference,
+            protocol::ObjectPullStrategy,
+            protocol::TransferUrl,
+        >
+    {
+        pub fn build(self) -> PullObjectTransferStrategy {
+            PullObjectTransferStrategy {
+                object_file: self.object_file,
+                pull_strategy: self.pull_strategy,
+                download_from: self.download_from,
+            }
+        }
+    }
+
+    /// Agreed way of uploading an object file.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PushObjectTransferStrategy
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct PushObjectTransferStrategy {
+        /// Object file to upload.
+        pub object_file: protocol::ObjectFileReference,
+        /// Method of uploading the object file.
+        pub push_strategy: protocol::ObjectPushStrategy,
+        /// Location to upload the object file to, absent when the upload is skipped.
+        pub upload_to: Option<protocol::TransferUrl>,
+    }
+
+    /// Builder for [`PushObjectTransferStrategy`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PushObjectTransferStrategyBuilder<TObjectFile = Unset, TPushStrategy = Unset> {
+        object_file: TObjectFile,
+        push_strategy: TPushStrategy,
+        upload_to: Option<protocol::TransferUrl>,
+    }
+
+    impl PushObjectTransferStrategy {
+        pub fn builder() -> PushObjectTransferStrategyBuilder<Unset, Unset> {
+            PushObjectTransferStrategyBuilder {
+                object_file: Unset,
+                push_strategy: Unset,
+                upload_to: None,
+            }
+        }
+    }
+
+    impl<TPushStrategy> PushObjectTransferStrategyBuilder<Unset, TPushStrategy> {
+        /// Object file to upload.
+        pub fn object_file(
+            self,
+            v: impl Into<protocol::ObjectFileReference>,
+        ) -> PushObjectTransferStrategyBuilder<protocol::ObjectFileReference, TPushStrategy>
+        {
+            PushObjectTransferStrategyBuilder {
+                object_file: v.into(),
+                push_strategy: self.push_strategy,
+                upload_to: self.upload_to,
+            }
+        }
+    }
+
+    impl<TObjectFile> PushObjectTransferStrategyBuilder<TObjectFile, Unset> {
+        /// Method of uploading the object file.
+        pub fn push_strategy(
+            self,
+            v: impl Into<protocol::ObjectPushStrategy>,
+        ) -> PushObjectTransferStrategyBuilder<TObjectFile, protocol::ObjectPushStrategy> {
+            PushObjectTransferStrategyBuilder {
+                object_file: self.object_file,
+                push_strategy: v.into(),
+                upload_to: self.upload_to,
+            }
+        }
+    }
+
+    impl<TObjectFile, TPushStrategy> PushObjectTransferStrategyBuilder<TObjectFile, TPushStrategy> {
+        /// Location to upload the object file to, absent when the upload is skipped.
+        pub fn upload_to(mut self, v: impl Into<protocol::TransferUrl>) -> Self {
+            self.upload_to = Some(v.into());
+            self
+        }
+    }
+
+    impl
+        PushObjectTransferStrategyBuilder<
+            protocol::ObjectFileReference,
+            protocol::ObjectPushStrategy,
+        >
+    {
+        pub fn build(self) -> PushObjectTransferStrategy {
+            PushObjectTransferStrategy {
+                object_file: self.object_file,
+                push_strategy: self.push_strategy,
+                upload_to: self.upload_to,
+            }
+        }
+    }
+
+    /// Estimated size of the data about to be transferred.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferSizeEstimation
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct TransferSizeEstimation {
+        /// Number of blocks about to transfer.
+        pub num_blocks: u64,
+        /// Number of objects about to transfer (can be 0 for pure declarative metadata).
+        pub num_objects: u64,
+        /// Raw (without compression) size of the blocks about to transfer.
+        pub bytes_in_raw_blocks: u64,
+        /// Raw (without compression) size of the object files about to transfer.
+        pub bytes_in_raw_objects: u64,
+    }
+
+    /// Builder for [`TransferSizeEstimation`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransferSizeEstimationBuilder<
+        TNumBlocks = Unset,
+        TNumObjects = Unset,
+        TBytesInRawBlocks = Unset,
+        TBytesInRawObjects = Unset,
+    > {
+        num_blocks: TNumBlocks,
+        num_objects: TNumObjects,
+        bytes_in_raw_blocks: TBytesInRawBlocks,
+        bytes_in_raw_objects: TBytesInRawObjects,
+    }
+
+    impl TransferSizeEstimation {
+        pub fn builder() -> TransferSizeEstimationBuilder<Unset, Unset, Unset, Unset> {
+            TransferSizeEstimationBuilder {
+                num_blocks: Unset,
+                num_objects: Unset,
+                bytes_in_raw_blocks: Unset,
+                bytes_in_raw_objects: Unset,
+            }
+        }
+    }
+
+    impl<TNumObjects, TBytesInRawBlocks, TBytesInRawObjects>
+        TransferSizeEstimationBuilder<Unset, TNumObjects, TBytesInRawBlocks, TBytesInRawObjects>
+    {
+        /// Number of blocks about to transfer.
+        pub fn num_blocks(
+            self,
+            v: impl Into<u64>,
+        ) -> TransferSizeEstimationBuilder<u64, TNumObjects, TBytesInRawBlocks, TBytesInRawObjects>
+        {
+            TransferSizeEstimationBuilder {
+                num_blocks: v.into(),
+                num_objects: self.num_objects,
+                bytes_in_raw_blocks: self.bytes_in_raw_blocks,
+                bytes_in_raw_objects: self.bytes_in_raw_objects,
+            }
+        }
+    }
+
+    impl<TNumBlocks, TBytesInRawBlocks, TBytesInRawObjects>
+        TransferSizeEstimationBuilder<TNumBlocks, Unset, TBytesInRawBlocks, TBytesInRawObjects>
+    {
+        /// Number of objects about to transfer (can be 0 for pure declarative metadata).
+        pub fn num_objects(
+            self,
+            v: impl Into<u64>,
+        ) -> TransferSizeEstimationBuilder<TNumBlocks, u64, TBytesInRawBlocks, TBytesInRawObjects>
+        {
+            TransferSizeEstimationBuilder {
+                num_blocks: self.num_blocks,
+                num_objects: v.into(),
+                bytes_in_raw_blocks: self.bytes_in_raw_blocks,
+                bytes_in_raw_objects: self.bytes_in_raw_objects,
+            }
+        }
+    }
+
+    impl<TNumBlocks, TNumObjects, TBytesInRawObjects>
+        TransferSizeEstimationBuilder<TNumBlocks, TNumObjects, Unset, TBytesInRawObjects>
+    {
+        /// Raw (without compression) size of the blocks about to transfer.
+        pub fn bytes_in_raw_blocks(
+            self,
+            v: impl Into<u64>,
+        ) -> TransferSizeEstimationBuilder<TNumBlocks, TNumObjects, u64, TBytesInRawObjects>
+        {
+            TransferSizeEstimationBuilder {
+                num_blocks: self.num_blocks,
+                num_objects: self.num_objects,
+                bytes_in_raw_blocks: v.into(),
+                bytes_in_raw_objects: self.bytes_in_raw_objects,
+            }
+        }
+    }
+
+    impl<TNumBlocks, TNumObjects, TBytesInRawBlocks>
+        TransferSizeEstimationBuilder<TNumBlocks, TNumObjects, TBytesInRawBlocks, Unset>
+    {
+        /// Raw (without compression) size of the object files about to transfer.
+        pub fn bytes_in_raw_objects(
+            self,
+            v: impl Into<u64>,
+        ) -> TransferSizeEstimationBuilder<TNumBlocks, TNumObjects, TBytesInRawBlocks, u64>
+        {
+            TransferSizeEstimationBuilder {
+                num_blocks: self.num_blocks,
+                num_objects: self.num_objects,
+                bytes_in_raw_blocks: self.bytes_in_raw_blocks,
+                bytes_in_raw_objects: v.into(),
+            }
+        }
+    }
+
+    impl TransferSizeEstimationBuilder<u64, u64, u64, u64> {
+        pub fn build(self) -> TransferSizeEstimation {
+            TransferSizeEstimation {
+                num_blocks: self.num_blocks,
+                num_objects: self.num_objects,
+                bytes_in_raw_blocks: self.bytes_in_raw_blocks,
+                bytes_in_raw_objects: self.bytes_in_raw_objects,
+            }
+        }
+    }
+
+    /// Pre-signed location of an object file.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferUrl
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct TransferUrl {
+        /// URL to transfer the object file with.
+        pub url: String,
+        /// Time after which the URL can no longer be used.
+        pub expires_at: Option<DateTime<Utc>>,
+    }
+
+    /// Builder for [`TransferUrl`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct TransferUrlBuilder<TUrl = Unset> {
+        url: TUrl,
+        expires_at: Option<DateTime<Utc>>,
+    }
+
+    impl TransferUrl {
+        pub fn builder() -> TransferUrlBuilder<Unset> {
+            TransferUrlBuilder {
+                url: Unset,
+                expires_at: None,
+            }
+        }
+    }
+
+    impl TransferUrlBuilder<Unset> {
+        /// URL to transfer the object file with.
+        pub fn url(self, v: impl Into<String>) -> TransferUrlBuilder<String> {
+            TransferUrlBuilder {
+                url: v.into(),
+                expires_at: self.expires_at,
+            }
+        }
+    }
+
+    impl<TUrl> TransferUrlBuilder<TUrl> {
+        /// Time after which the URL can no longer be used.
+        pub fn expires_at(mut self, v: impl Into<DateTime<Utc>>) -> Self {
+            self.expires_at = Some(v.into());
+            self
+        }
+    }
+
+    impl TransferUrlBuilder<String> {
+        pub fn build(self) -> TransferUrl {
+            TransferUrl {
+                url: self.url,
+                expires_at: self.expires_at,
+            }
+        }
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// resource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod resource {
+    #[allow(unused_imports)]
+    use super::*;
+
+    /// Condition on a single value of a filtered object. The short string form matches values equal to the string. Multiple operators must all hold.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/FilterCondition
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct FilterCondition {
+        /// Matches values equal to the specified one, use for non-string values.
+        pub eq: Option<serde_json::Value>,
+        /// Matches values equal to any of the specified ones.
+        pub r#in: Option<Vec<serde_json::Value>>,
+        /// Matches when the value is present (`true`) or absent (`false`).
+        pub exists: Option<bool>,
+        /// Matches string values against a glob pattern where `*` stands for any sequence of characters and `?` for any single character.
+        pub glob: Option<String>,
+    }
+
+    /// Builder for [`FilterCondition`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct FilterConditionBuilder {
+        eq: Option<serde_json::Value>,
+        r#in: Option<Vec<serde_json::Value>>,
+        exists: Option<bool>,
+        glob: Option<String>,
+    }
+
+    impl FilterCondition {
+        pub fn builder() -> FilterConditionBuilder {
+            FilterConditionBuilder {
+                eq: None,
+                r#in: None,
+                exists: None,
+                glob: None,
+            }
+        }
+    }
+
+    impl FilterConditionBuilder {
+        /// Matches values equal to the specified one, use for non-string values.
+        pub fn eq(mut self, v: impl Into<serde_json::Value>) -> Self {
+            self.eq = Some(v.into());
+            self
+        }
+        /// Matches values equal to any of the specified ones.
+        pub fn r#in(mut self, v: impl Into<Vec<serde_json::Value>>) -> Self {
+            self.r#in = Some(v.into());
+            self
+        }
+        /// Matches when the value is present (`true`) or absent (`false`).
+        pub fn exists(mut self, v: impl Into<bool>) -> Self {
+            self.exists = Some(v.into());
+            self
+        }
+        /// Matches string values against a glob pattern where `*` stands for any sequence of characters and `?` for any single character.
+        pub fn glob(mut self, v: impl Into<String>) -> Self {
+            self.glob = Some(v.into());
+            self
+        }
+    }
+
+    impl FilterConditionBuilder {
+        pub fn build(self) -> FilterCondition {
+            FilterCondition {
+                eq: self.eq,
+                r#in: self.r#in,
+                exists: self.exists,
+                glob: self.glob,
+            }
+        }
+    }
+
+    /// Filters that work on resource labels and identity headers.
+    ///
+    /// Variants are not tagged and are resolved by their shape in order of declaration.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum LabelFilter {
+        And(resource::LabelFilterAnd),
+        Or(resource::LabelFilterOr),
+        Not(resource::LabelFilterNot),
+        Match(resource::LabelFilterMatch),
+    }
+
+    impl_enum_with_variants!(LabelFilter);
+    impl_enum_variant!(LabelFilter::And(resource::LabelFilterAnd));
+    impl_enum_variant!(LabelFilter::Or(resource::LabelFilterOr));
+    impl_enum_variant!(LabelFilter::Not(resource::LabelFilterNot));
+    impl_enum_variant!(LabelFilter::Match(resource::LabelFilterMatch));
+
+    /// Matches when all of the nested filters match.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/And
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct LabelFilterAnd {
+        /// Filters that all must match.
+        pub and: Vec<resource::LabelFilter>,
+    }
+
+    /// Builder for [`LabelFilterAnd`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct LabelFilterAndBuilder<TAnd = Unset> {
+        and: TAnd,
+    }
+
+    impl LabelFilterAnd {
+        pub fn builder() -> LabelFilterAndBuilder<Unset> {
+            LabelFilterAndBuilder { and: Unset }
+        }
+    }
+
+    impl LabelFilterAndBuilder<Unset> {
+        /// Filters that all must match.
+        pub fn and(
+            self,
+            v: impl Into<Vec<resource::LabelFilter>>,
+        ) -> LabelFilterAndBuilder<Vec<resource::LabelFilter>> {
+            LabelFilterAndBuilder { and: v.into() }
+        }
+    }
+
+    impl LabelFilterAndBuilder<Vec<resource::LabelFilter>> {
+        pub fn build(self) -> LabelFilterAnd {
+            LabelFilterAnd { and: self.and }
+        }
+    }
+
+    /// Matches when every condition holds for the label or identity header with the same name.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Match
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub struct LabelFilterMatch {
+        pub entries: std::collections::BTreeMap<String, resource::FilterCondition>,
+    }
+
+    /// Matches when the nested filter does not match.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Not
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct LabelFilterNot {
+        /// Filter to negate.
+        pub not: Box<resource::LabelFilter>,
+    }
+
+    /// Builder for [`LabelFilterNot`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct LabelFilterNotBuilder<TNot = Unset> {
+        not: TNot,
+    }
+
+    impl LabelFilterNot {
+        pub fn builder() -> LabelFilterNotBuilder<Unset> {
+            LabelFilterNotBuilder { not: Unset }
+        }
+    }
+
+    impl LabelFilterNotBuilder<Unset> {
+        /// Filter to negate.
+        pub fn not(
+            self,
+            v: impl Into<Box<resource::LabelFilter>>,
+        ) -> LabelFilterNotBuilder<Box<resource::LabelFilter>> {
+            LabelFilterNotBuilder { not: v.into() }
+        }
+    }
+
+    impl LabelFilterNotBuilder<Box<resource::LabelFilter>> {
+        pub fn build(self) -> LabelFilterNot {
+            LabelFilterNot { not: self.not }
+        }
+    }
+
+    /// Matches when at least one of the nested filters matches.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Or
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct LabelFilterOr {
+        /// Filters of which at least one must match.
+        pub or: Vec<resource::LabelFilter>,
+    }
+
+    /// Builder for [`LabelFilterOr`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct LabelFilterOrBuilder<TOr = Unset> {
+        or: TOr,
+    }
+
+    impl LabelFilterOr {
+        pub fn builder() -> LabelFilterOrBuilder<Unset> {
+            LabelFilterOrBuilder { or: Unset }
+        }
+    }
+
+    impl LabelFilterOrBuilder<Unset> {
+        /// Filters of which at least one must match.
+        pub fn or(
+            self,
+            v: impl Into<Vec<resource::LabelFilter>>,
+        ) -> LabelFilterOrBuilder<Vec<resource::LabelFilter>> {
+            LabelFilterOrBuilder { or: v.into() }
+        }
+    }
+
+    impl LabelFilterOrBuilder<Vec<resource::LabelFilter>> {
+        pub fn build(self) -> LabelFilterOr {
+            LabelFilterOr { or: self.or }
+        }
+    }
+
+    /// Top-level container for canonical representation of a resource that specifies the type and version of the resource, carries identity, ownership, and status information.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/Resource
+    ///
+    /// Type parameters:
+    /// - `SpecT`: Type of the canonical resource specification, e.g. `DatasetSpec`.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Resource<SpecT> {
+        /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+        pub schema: TypeUri,
+        /// Container for identity and ownership information of a resource.
+        pub headers: resource::ResourceHeaders,
+        /// Specifies the desired state of a resource.
+        pub spec: SpecT,
+        /// Resource lifecycle and reconciliation information.
+        pub status: resource::ResourceStatus,
+    }
+
+    /// Builder for [`Resource`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ResourceBuilder<
+        SpecT,
+        TSchema = Unset,
+        THeaders = Unset,
+        TSpec = Unset,
+        TStatus = Unset,
+    > {
+        schema: TSchema,
+        headers: THeaders,
+        spec: TSpec,
+        status: TStatus,
+        _marker: std::marker::PhantomData<(SpecT,)>,
+    }
+
+    impl<SpecT> Resource<SpecT> {
+        pub fn builder() -> ResourceBuilder<SpecT, Unset, Unset, Unset, Unset> {
+            ResourceBuilder {
+                schema: Unset,
+                headers: Unset,
+                spec: Unset,
+                status: Unset,
+                _marker: std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<SpecT, THeaders, TSpec, TStatus> ResourceBuilder<SpecT, Unset, THeaders, TSpec, TStatus> {
+        /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+        pub fn schema(
+            self,
+            v: impl Into<TypeUri>,
+        ) -> ResourceBuilder<SpecT, TypeUri, THeaders, TSpec, TStatus> {
+            ResourceBuilder {
+                schema: v.into(),
+                headers: self.headers,
+                spec: self.spec,
+                status: self.status,
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<SpecT, TSchema, TSpec, TStatus> ResourceBuilder<SpecT, TSchema, Unset, TSpec, TStatus> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeaders>,
+        ) -> ResourceBuilder<SpecT, TSchema, resource::ResourceHeaders, TSpec, TStatus> {
+            ResourceBuilder {
+                schema: self.schema,
+                headers: v.into(),
+                spec: self.spec,
+                status: self.status,
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<SpecT, TSchema, THeaders, TStatus> ResourceBuilder<SpecT, TSchema, THeaders, Unset, TStatus> {
+        /// Specifies the desired state of a resource.
+        pub fn spec(self, v: SpecT) -> ResourceBuilder<SpecT, TSchema, THeaders, SpecT, TStatus> {
+            ResourceBuilder {
+                schema: self.schema,
+                headers: self.headers,
+                spec: v.into(),
+                status: self.status,
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<SpecT, TSchema, THeaders, TSpec> ResourceBuilder<SpecT, TSchema, THeaders, TSpec, Unset> {
+        /// Resource lifecycle and reconciliation information.
+        pub fn status(
+            self,
+            v: impl Into<resource::ResourceStatus>,
+        ) -> ResourceBuilder<SpecT, TSchema, THeaders, TSpec, resource::ResourceStatus> {
+            ResourceBuilder {
+                schema: self.schema,
+                headers: self.headers,
+                spec: self.spec,
+                status: v.into(),
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<SpecT>
+        ResourceBuilder<SpecT, TypeUri, resource::ResourceHeaders, SpecT, resource::ResourceStatus>
+    {
+        pub fn build(self) -> Resource<SpecT> {
+            Resource {
+                schema: self.schema,
+                headers: self.headers,
+                spec: self.spec,
+                status: self.status,
+            }
+        }
+    }
+
+    pub type ResourceAccountSpec = Resource<auth::AccountSpec>;
+    pub type ResourceDatasetSpec = Resource<dataset::DatasetSpec>;
+    pub type ResourceFlowSpec = Resource<flow::FlowSpec>;
+    pub type ResourcePersistentVolumeSpec = Resource<storage::PersistentVolumeSpec>;
+    pub type ResourceProjectionSpec = Resource<dataset::ProjectionSpec>;
+    pub type ResourceRelationsSpec = Resource<auth::RelationsSpec>;
+    pub type ResourceSecretSetSpec = Resource<config::SecretSetSpec>;
+    pub type ResourceSourceSpec = Resource<source::SourceSpec>;
+    pub type ResourceTaskSpec = Resource<flow::TaskSpec>;
+    pub type ResourceVariableSetSpec = Resource<config::VariableSetSpec>;
+    pub type ResourceWebhookTargetSpec = Resource<sink::WebhookTargetSpec>;
+
+    impl<SpecT: Redact> Redact for Resource<SpecT> {
+        fn redact(&self) -> Self {
+            Self {
+                schema: self.schema.clone(),
+                headers: self.headers.clone(),
+                spec: self.spec.redact(),
+                status: self.status.clone(),
+            }
+        }
+    }
+
+    /// Annotations is an unstructured key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceAnnotations
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub struct ResourceAnnotations {
+        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    }
+
+    /// Container of feneric contditions that can be added by contollers to provide additional information about the state of a resource. Keys uniquely identify the condition and should be in the form of URL to a schema describing this condition, e.g. `https://opendatafabric.org/schemas/resource/ConditionReady.json`.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceConditions
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub struct ResourceConditions {
+        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    }
+
+    /// Lint to another resolved resource.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHandle
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ResourceHandle {
+        /// Account that owns the target resource.
+        pub account: auth::AccountHandle,
+        /// Type URI of the target resource.
+        pub r#type: TypeUri,
+        /// ID of the resource within a node.
+        pub id: ResourceID,
+        /// DID of the resource, if applicable.
+        pub did: Option<Did>,
+        /// Name of a resource.
+        pub name: ResourceName,
+    }
+    impl IntoResourceRef for ResourceHandle {}
+
+    /// Builder for [`ResourceHandle`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ResourceHandleBuilder<TAccount = Unset, TType = Unset, TId = Unset, TName = Unset> {
+        account: TAccount,
+        r#type: TType,
+        id: TId,
+        did: Option<Did>,
+        name: TName,
+    }
+
+    impl ResourceHandle {
+        pub fn builder() -> ResourceHandleBuilder<Unset, Unset, Unset, Unset> {
+            ResourceHandleBuilder {
+                account: Unset,
+                r#type: Unset,
+                id: Unset,
+                did: None,
+                name: Unset,
+            }
+        }
+    }
+
+    impl<TType, TId, TName> ResourceHandleBuilder<Unset, TType, TId, TName> {
+        /// Account that owns the target resource.
+        pub fn account(
+            self,
+            v: impl Into<auth::AccountHandle>,
+        ) -> ResourceHandleBuilder<auth::AccountHandle, TType, TId, TName> {
+            ResourceHandleBuilder {
+                account: v.into(),
+                r#type: self.r#type,
+                id: self.id,
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
+    impl<TAccount, TId, TName> ResourceHandleBuilder<TAccount, Unset, TId, TName> {
+        /// Type URI of the target resource.
+        pub fn r#type(
+            self,
+            v: impl Into<TypeUri>,
+        ) -> ResourceHandleBuilder<TAccount, TypeUri, TId, TName> {
+            ResourceHandleBuilder {
+                account: self.account,
+                r#type: v.into(),
+                id: self.id,
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
+    impl<TAccount, TType, TName> ResourceHandleBuilder<TAccount, TType, Unset, TName> {
+        /// ID of the resource within a node.
+        pub fn id(
+            self,
+            v: impl Into<ResourceID>,
+        ) -> ResourceHandleBuilder<TAccount, TType, ResourceID, TName> {
+            ResourceHandleBuilder {
+                account: self.account,
+                r#type: self.r#type,
+                id: v.into(),
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
+    impl<TAccount, TType, TId> ResourceHandleBuilder<TAccount, TType, TId, Unset> {
+        /// Name of a resource.
+        pub fn name(
+            self,
+            v: impl Into<ResourceName>,
+        ) -> ResourceHandleBuilder<TAccount, TType, TId, ResourceName> {
+            ResourceHandleBuilder {
+                account: self.account,
+                r#type: self.r#type,
+                id: self.id,
+                did: self.did,
+                name: v.into(),
+            }
+        }
+    }
+
+    impl<TAccount, TType, TId, TName> ResourceHandleBuilder<TAccount, TType, TId, TName> {
+        /// DID of the resource, if applicable.
+        pub fn did(mut self, v: impl Into<Did>) -> Self {
+            self.did = Some(v.into());
+            self
+        }
+    }
+
+    impl ResourceHandleBuilder<auth::AccountHandle, TypeUri, ResourceID, ResourceName> {
+        pub fn build(self) -> ResourceHandle {
+            ResourceHandle {
+                account: self.account,
+                r#type: self.r#type,
+                id: self.id,
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
+    /// Container for identity and ownership information of a resource.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeaders
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ResourceHeaders {
+        /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
+        pub id: ResourceID,
+        /// Symbolic name of a resource that identifies it within a scope of an onwing account.
+        pub name: ResourceName,
+        /// Link to the account that owns the resource.
+        pub account: auth::AccountHandle,
+        /// Map of string keys and values that can be used to organize, categorize, and query resources.
+        pub labels: resource::ResourceLabels,
+        /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
+        pub annotations: resource::ResourceAnnotations,
+        /// A sequential number that changes every time the resource header and spec are updated. Does not increment on status changes, thus signifying changes to the desired state. Populated by the system. Starts with `1`.
+        pub generation: u64,
+        /// Time when the resource was first applied and assigned an identity.
+        pub created_at: DateTime<Utc>,
+        /// Time when the resource was last updated, including header, spec, and status updates.
+        pub updated_at: DateTime<Utc>,
+        /// Time when the resource was deleted.
+        pub deleted_at: Option<DateTime<Utc>>,
+    }
+
+    /// Builder for [`ResourceHeaders`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ResourceHeadersBuilder<
+        TId = Unset,
+        TName = Unset,
+        TAccount = Unset,
+        TLabels = Unset,
+        TAnnotations = Unset,
+        TGeneration = Unset,
+        TCreatedAt = Unset,
+        TUpdatedAt = Unset,
+    > {
+        id: TId,
+        name: TName,
+        account: TAccount,
+        labels: TLabels,
+        annotations: TAnnotations,
+        generation: TGeneration,
+        created_at: TCreatedAt,
+        updated_at: TUpdatedAt,
+        deleted_at: Option<DateTime<Utc>>,
+    }
+
+    impl ResourceHeaders {
+        pub fn builder()
+        -> ResourceHeadersBuilder<Unset, Unset, Unset, Unset, Unset, Unset, Unset, Unset> {
+            ResourceHeadersBuilder {
+                id: Unset,
+                name: Unset,
+                account: Unset,
+                labels: Unset,
+                annotations: Unset,
+                generation: Unset,
+                created_at: Unset,
+                updated_at: Unset,
+                deleted_at: None,
+            }
+        }
+    }
+
+    impl<TName, TAccount, TLabels, TAnnotations, TGeneration, TCreatedAt, TUpdatedAt>
+        ResourceHeadersBuilder<
+            Unset,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        >
+    {
+        /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
+        pub fn id(
+            self,
+            v: impl Into<ResourceID>,
+        ) -> ResourceHeadersBuilder<
+            ResourceID,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        > {
+            ResourceHeadersBuilder {
+                id: v.into(),
+                name: self.name,
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+                generation: self.generation,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TAccount, TLabels, TAnnotations, TGeneration, TCreatedAt, TUpdatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            Unset,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        >
+    {
+        /// Symbolic name of a resource that identifies it within a scope of an onwing account.
+        pub fn name(
+            self,
+            v: impl Into<ResourceName>,
+        ) -> ResourceHeadersBuilder<
+            TId,
+            ResourceName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        > {
+            ResourceHeadersBuilder {
+                id: self.id,
+                name: v.into(),
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+                generation: self.generation,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TName, TLabels, TAnnotations, TGeneration, TCreatedAt, TUpdatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            TName,
+            Unset,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        >
+    {
+        /// Link to the account that owns the resource.
+        pub fn account(
+            self,
+            v: impl Into<auth::AccountHandle>,
+        ) -> ResourceHeadersBuilder<
+            TId,
+            TName,
+            auth::AccountHandle,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        > {
+            ResourceHeadersBuilder {
+                id: self.id,
+                name: self.name,
+                account: v.into(),
+                labels: self.labels,
+                annotations: self.annotations,
+                generation: self.generation,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TName, TAccount, TAnnotations, TGeneration, TCreatedAt, TUpdatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            Unset,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        >
+    {
+        /// Map of string keys and values that can be used to organize, categorize, and query resources.
+        pub fn labels(
+            self,
+            v: impl Into<resource::ResourceLabels>,
+        ) -> ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            resource::ResourceLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        > {
+            ResourceHeadersBuilder {
+                id: self.id,
+                name: self.name,
+                account: self.account,
+                labels: v.into(),
+                annotations: self.annotations,
+                generation: self.generation,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TName, TAccount, TLabels, TGeneration, TCreatedAt, TUpdatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            Unset,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        >
+    {
+        /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
+        pub fn annotations(
+            self,
+            v: impl Into<resource::ResourceAnnotations>,
+        ) -> ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            resource::ResourceAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        > {
+            ResourceHeadersBuilder {
+                id: self.id,
+                name: self.name,
+                account: self.account,
+                labels: self.labels,
+                annotations: v.into(),
+                generation: self.generation,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TName, TAccount, TLabels, TAnnotations, TCreatedAt, TUpdatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            Unset,
+            TCreatedAt,
+            TUpdatedAt,
+        >
+    {
+        /// A sequential number that changes every time the resource header and spec are updated. Does not increment on status changes, thus signifying changes to the desired state. Populated by the system. Starts with `1`.
+        pub fn generation(
+            self,
+            v: impl Into<u64>,
+        ) -> ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            u64,
+            TCreatedAt,
+            TUpdatedAt,
+        > {
+            ResourceHeadersBuilder {
+                id: self.id,
+                name: self.name,
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+                generation: v.into(),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TName, TAccount, TLabels, TAnnotations, TGeneration, TUpdatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            Unset,
+            TUpdatedAt,
+        >
+    {
+        /// Time when the resource was first applied and assigned an identity.
+        pub fn created_at(
+            self,
+            v: impl Into<DateTime<Utc>>,
+        ) -> ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            DateTime<Utc>,
+            TUpdatedAt,
+        > {
+            ResourceHeadersBuilder {
+                id: self.id,
+                name: self.name,
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+                generation: self.generation,
+                created_at: v.into(),
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TName, TAccount, TLabels, TAnnotations, TGeneration, TCreatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            Unset,
+        >
+    {
+        /// Time when the resource was last updated, including header, spec, and status updates.
+        pub fn updated_at(
+            self,
+            v: impl Into<DateTime<Utc>>,
+        ) -> ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            DateTime<Utc>,
+        > {
+            ResourceHeadersBuilder {
+                id: self.id,
+                name: self.name,
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+                generation: self.generation,
+                created_at: self.created_at,
+                updated_at: v.into(),
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    impl<TId, TName, TAccount, TLabels, TAnnotations, TGeneration, TCreatedAt, TUpdatedAt>
+        ResourceHeadersBuilder<
+            TId,
+            TName,
+            TAccount,
+            TLabels,
+            TAnnotations,
+            TGeneration,
+            TCreatedAt,
+            TUpdatedAt,
+        >
+    {
+        /// Time when the resource was deleted.
+        pub fn deleted_at(mut self, v: impl Into<DateTime<Utc>>) -> Self {
+            self.deleted_at = Some(v.into());
+            self
+        }
+    }
+
+    impl
+        ResourceHeadersBuilder<
+            ResourceID,
+            ResourceName,
+            auth::AccountHandle,
+            resource::ResourceLabels,
+            resource::ResourceAnnotations,
+            u64,
+            DateTime<Utc>,
+            DateTime<Utc>,
+        >
+    {
+        pub fn build(self) -> ResourceHeaders {
+            ResourceHeaders {
+                id: self.id,
+                name: self.name,
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+                generation: self.generation,
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            }
+        }
+    }
+
+    /// Container for identity and ownership information of a resource.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeadersInput
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ResourceHeadersInput {
+        /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
+        pub id: Option<ResourceID>,
+        /// Symbolic name of a resource that identifies it within a scope of an onwing account.
+        pub name: ResourceName,
+        /// Reference to the account that owns the resource.
+        pub account: Option<auth::AccountRef>,
+        /// Map of string keys and values that can be used to organize, categorize, and query resources.
+        pub labels: Option<resource::ResourceLabels>,
+        /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
+        pub annotations: Option<resource::ResourceAnnotations>,
+    }
+
+    /// Builder for [`ResourceHeadersInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ResourceHeadersInputBuilder<TName = Unset> {
+        id: Option<ResourceID>,
+        name: TName,
+        account: Option<auth::AccountRef>,
+        labels: Option<resource::ResourceLabels>,
+        annotations: Option<resource::ResourceAnnotations>,
+    }
+
+    impl ResourceHeadersInput {
+        pub fn builder() -> ResourceHeadersInputBuilder<Unset> {
+            ResourceHeadersInputBuilder {
+                id: None,
+                name: Unset,
+                account: None,
+                labels: None,
+                annotations: None,
+            }
+        }
+    }
+
+    impl ResourceHeadersInputBuilder<Unset> {
+        /// Symbolic name of a resource that identifies it within a scope of an onwing account.
+        pub fn name(self, v: impl Into<ResourceName>) -> ResourceHeadersInputBuilder<ResourceName> {
+            ResourceHeadersInputBuilder {
+                id: self.id,
+                name: v.into(),
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+            }
+        }
+    }
+
+    impl<TName> ResourceHeadersInputBuilder<TName> {
+        /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
+        pub fn id(mut self, v: impl Into<ResourceID>) -> Self {
+            self.id = Some(v.into());
+            self
+        }
+        /// Reference to the account that owns the resource.
+        pub fn account(mut self, v: impl Into<auth::AccountRef>) -> Self {
+            self.account = Some(v.into());
+            self
+        }
+        /// Map of string keys and values that can be used to organize, categorize, and query resources.
+        pub fn labels(mut self, v: impl Into<resource::ResourceLabels>) -> Self {
+            self.labels = Some(v.into());
+            self
+        }
+        /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
+        pub fn annotations(mut self, v: impl Into<resource::ResourceAnnotations>) -> Self {
+            self.annotations = Some(v.into());
+            self
+        }
+    }
+
+    impl ResourceHeadersInputBuilder<ResourceName> {
+        pub fn build(self) -> ResourceHeadersInput {
+            ResourceHeadersInput {
+                id: self.id,
+                name: self.name,
+                account: self.account,
+                labels: self.labels,
+                annotations: self.annotations,
+            }
+        }
+    }
+
+    /// Top-level container for user-authored representation of a resource that specifies the type and version of the resource and its desired state.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceInput
+    ///
+    /// Type parameters:
+    /// - `SpecT`: Type of the user-authored resource specification, e.g. `DatasetSpecInput`.
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ResourceInput<SpecT> {
+        /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+        pub schema: TypeUri,
+        /// Container for identity and ownership information of a resource.
+        pub headers: resource::ResourceHeadersInput,
+        /// Specifies the desired state of a resource.
+        pub spec: SpecT,
+    }
+
+    /// Builder for [`ResourceInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ResourceInputBuilder<SpecT, TSchema = Unset, THeaders = Unset, TSpec = Unset> {
+        schema: TSchema,
+        headers: THeaders,
+        spec: TSpec,
+        _marker: std::marker::PhantomData<(SpecT,)>,
+    }
+
+    impl<SpecT> ResourceInput<SpecT> {
+        pub fn builder() -> ResourceInputBuilder<SpecT, Unset, Unset, Unset> {
+            ResourceInputBuilder {
+                schema: Unset,
+                headers: Unset,
+                spec: Unset,
+                _marker: std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<SpecT, THeaders, TSpec> ResourceInputBuilder<SpecT, Unset, THeaders, TSpec> {
+        /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+        pub fn schema(
+            self,
+            v: impl Into<TypeUri>,
+        ) -> ResourceInputBuilder<SpecT, TypeUri, THeaders, TSpec> {
+            ResourceInputBuilder {
+                schema: v.into(),
+                headers: self.headers,
+                spec: self.spec,
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<SpecT, TSchema, TSpec> ResourceInputBuilder<SpecT, TSchema, Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> ResourceInputBuilder<SpecT, TSchema, resource::ResourceHeadersInput, TSpec> {
+            ResourceInputBuilder {
+                schema: self.schema,
+                headers: v.into(),
+                spec: self.spec,
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<SpecT, TSchema, THeaders> ResourceInputBuilder<SpecT, TSchema, THeaders, Unset> {
+        /// Specifies the desired state of a resource.
+        pub fn spec(self, v: SpecT) -> ResourceInputBuilder<SpecT, TSchema, THeaders, SpecT> {
+            ResourceInputBuilder {
+                schema: self.schema,
+                headers: self.headers,
+                spec: v.into(),
+                _marker: self._marker,
+            }
+        }
+    }
+
+    impl<SpecT> ResourceInputBuilder<SpecT, TypeUri, resource::ResourceHeadersInput, SpecT> {
+        pub fn build(self) -> ResourceInput<SpecT> {
+            ResourceInput {
+                schema: self.schema,
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    pub type ResourceInputAccountSpecInput = ResourceInput<auth::AccountSpecInput>;
+    pub type ResourceInputDatasetSpecInput = ResourceInput<dataset::DatasetSpecInput>;
+    pub type ResourceInputFlowSpecInput = ResourceInput<flow::FlowSpecInput>;
+    pub type ResourceInputPersistentVolumeSpecInput =
+        ResourceInput<storage::PersistentVolumeSpecInput>;
+    pub type ResourceInputProjectionSpecInput = ResourceInput<dataset::ProjectionSpecInput>;
+    pub type ResourceInputRelationsSpecInput = ResourceInput<auth::RelationsSpecInput>;
+    pub type ResourceInputSecretSetSpecInput = ResourceInput<config::SecretSetSpecInput>;
+    pub type ResourceInputSourceSpecInput = ResourceInput<source::SourceSpecInput>;
+    pub type ResourceInputTaskSpecInput = ResourceInput<flow::TaskSpecInput>;
+    pub type ResourceInputVariableSetSpecInput = ResourceInput<config::VariableSetSpecInput>;
+    pub type ResourceInputWebhookTargetSpecInput = ResourceInput<sink::WebhookTargetSpecInput>;
+
+    impl<SpecT: Redact> Redact for ResourceInput<SpecT> {
+        fn redact(&self) -> Self {
+            Self {
+                schema: self.schema.clone(),
+                headers: self.headers.clone(),
+                spec: self.spec.redact(),
+            }
+        }
+    }
+
+    /// Map of string keys and values that can be used to organize, categorize, and query resources.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceLabels
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub struct ResourceLabels {
+        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    }
+
+    /// Represents the lifecycle stage of a resource.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourcePhase
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum ResourcePhase {
+        Pending,
+        Reconciling,
+        Ready,
+        Failed,
+    }
+
+    /// Reference to another resource.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceRef
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ResourceRef {
+        /// Reference to an account that owns the target resource.
+        pub account: Option<auth::AccountRef>,
+        /// Short type name or full type URI of the target resource.
+        ///
+        /// Examples:
+        /// - "SecretSet"
+        /// - "https://opendatafabric.org/config/v1/SecretSet"
+        pub r#type: TypeRef,
+        /// ID of the resource within a node.
+        pub id: Option<ResourceID>,
+        /// DID of the resource.
+        pub did: Option<Did>,
+        /// Name of a resource.
+        pub name: Option<ResourceName>,
+    }
+    impl IntoResourceRef for ResourceRef {}
+
+    /// Builder for [`ResourceRef`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ResourceRefBuilder<TType = Unset> {
+        account: Option<auth::AccountRef>,
+        r#type: TType,
+        id: Option<ResourceID>,
+        did: Option<Did>,
+        name: Option<ResourceName>,
+    }
+
+    impl ResourceRef {
+        pub fn builder() -> ResourceRefBuilder<Unset> {
+            ResourceRefBuilder {
+                account: None,
+                r#type: Unset,
+                id: None,
+                did: None,
+                name: None,
+            }
+        }
+    }
+
+    impl ResourceRefBuilder<Unset> {
+        /// Short type name or full type URI of the target resource.
+        pub fn r#type(self, v: impl Into<TypeRef>) -> ResourceRefBuilder<TypeRef> {
+            ResourceRefBuilder {
+                account: self.account,
+                r#type: v.into(),
+                id: self.id,
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
+    impl<TType> ResourceRefBuilder<TType> {
+        /// Reference to an account that owns the target resource.
+        pub fn account(mut self, v: impl Into<auth::AccountRef>) -> Self {
+            self.account = Some(v.into());
+            self
+        }
+        /// ID of the resource within a node.
+        pub fn id(mut self, v: impl Into<ResourceID>) -> Self {
+            self.id = Some(v.into());
+            self
+        }
+        /// DID of the resource.
+        pub fn did(mut self, v: impl Into<Did>) -> Self {
+            self.did = Some(v.into());
+            self
+        }
+        /// Name of a resource.
+        pub fn name(mut self, v: impl Into<ResourceName>) -> Self {
+            self.name = Some(v.into());
+            self
+        }
+    }
+
+    impl ResourceRefBuilder<TypeRef> {
+        pub fn build(self) -> ResourceRef {
+            ResourceRef {
+                account: self.account,
+                r#type: self.r#type,
+                id: self.id,
+                did: self.did,
+                name: self.name,
+            }
+        }
+    }
+
+    pub use crate::resource::ResourceSelector;
+
+    /// Resource lifecycle and reconciliation information.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceStatus
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct ResourceStatus {
+        /// Represents the lifecycle stage of a resource.
+        pub phase: resource::ResourcePhase,
+        /// Resource generation that was last processed by the main resource controller.
+        pub observed_generation: Option<u64>,
+        /// Time when the controller last reconciled the desired resource state as defined in `observedGeneration`.
+        pub reconciled_at: Option<DateTime<Utc>>,
+        /// Detailed conditions describing the state of the resource that are added by controllers.
+        pub conditions: resource::ResourceConditions,
+    }
+
+    /// Builder for [`ResourceStatus`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ResourceStatusBuilder<TPhase = Unset, TConditions = Unset> {
+        phase: TPhase,
+        observed_generation: Option<u64>,
+        reconciled_at: Option<DateTime<Utc>>,
+        conditions: TConditions,
+    }
+
+    impl ResourceStatus {
+        pub fn builder() -> ResourceStatusBuilder<Unset, Unset> {
+            ResourceStatusBuilder {
+                phase: Unset,
+                observed_generation: None,
+                reconciled_at: None,
+                conditions: Unset,
+            }
+        }
+    }
+
+    impl<TConditions> ResourceStatusBuilder<Unset, TConditions> {
+        /// Represents the lifecycle stage of a resource.
+        pub fn phase(
+            self,
+            v: impl Into<resource::ResourcePhase>,
+        ) -> ResourceStatusBuilder<resource::ResourcePhase, TConditions> {
+            ResourceStatusBuilder {
+                phase: v.into(),
+                observed_generation: self.observed_generation,
+                reconciled_at: self.reconciled_at,
+                conditions: self.conditions,
+            }
+        }
+    }
+
+    impl<TPhase> ResourceStatusBuilder<TPhase, Unset> {
+        /// Detailed conditions describing the state of the resource that are added by controllers.
+        pub fn conditions(
+            self,
+            v: impl Into<resource::ResourceConditions>,
+        ) -> ResourceStatusBuilder<TPhase, resource::ResourceConditions> {
+            ResourceStatusBuilder {
+                phase: self.phase,
+                observed_generation: self.observed_generation,
+                reconciled_at: self.reconciled_at,
+                conditions: v.into(),
+            }
+        }
+    }
+
+    impl<TPhase, TConditions> ResourceStatusBuilder<TPhase, TConditions> {
+        /// Resource generation that was last processed by the main resource controller.
+        pub fn observed_generation(mut self, v: impl Into<u64>) -> Self {
+            self.observed_generation = Some(v.into());
+            self
+        }
+        /// Time when the controller last reconciled the desired resource state as defined in `observedGeneration`.
+        pub fn reconciled_at(mut self, v: impl Into<DateTime<Utc>>) -> Self {
+            self.reconciled_at = Some(v.into());
+            self
+        }
+    }
+
+    impl ResourceStatusBuilder<resource::ResourcePhase, resource::ResourceConditions> {
+        pub fn build(self) -> ResourceStatus {
+            ResourceStatus {
+                phase: self.phase,
+                observed_generation: self.observed_generation,
+                reconciled_at: self.reconciled_at,
+                conditions: self.conditions,
+            }
+        }
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// sink
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod sink {
+    #[allow(unused_imports)]
+    use super::*;
+
+    /// Defines a webhook target endpoint that can receive event notifications and data.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTarget
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct WebhookTarget {
+        /// Container for identity and ownership information of a resource.
+        pub headers: resource::ResourceHeadersInput,
+        /// Specifies the desired state of the resource.
+        pub spec: sink::WebhookTargetSpecInput,
+    }
+
+    impl WebhookTarget {
+        pub fn schema() -> &'static TypeUri {
+            &WEBHOOK_TARGET_SCHEMA
+        }
+        pub const fn schema_str() -> &'static str {
+            WEBHOOK_TARGET_SCHEMA_STR
+        }
+    }
+
+    static WEBHOOK_TARGET_SCHEMA_STR: &str =
+        "https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTarget";
+
+    static WEBHOOK_TARGET_SCHEMA: std::sync::LazyLock<TypeUri> =
+        std::sync::LazyLock::new(|| TypeUri::new_unchecked(WEBHOOK_TARGET_SCHEMA_STR));
+
+    /// Builder for [`WebhookTarget`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct WebhookTargetBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl WebhookTarget {
+        pub fn builder() -> WebhookTargetBuilder<Unset, Unset> {
+            WebhookTargetBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> WebhookTargetBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> WebhookTargetBuilder<resource::ResourceHeadersInput, TSpec> {
+            WebhookTargetBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> WebhookTargetBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the resource.
+        pub fn spec(
+            self,
+            v: impl Into<sink::WebhookTargetSpecInput>,
+        ) -> WebhookTargetBuilder<THeaders, sink::WebhookTargetSpecInput> {
+            WebhookTargetBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl WebhookTargetBuilder<resource::ResourceHeadersInput, sink::WebhookTargetSpecInput> {
+        pub fn build(self) -> WebhookTarget {
+            WebhookTarget {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl Redact for WebhookTarget {
+        fn redact(&self) -> Self {
+            Self {
+                headers: self.headers.clone(),
+                spec: self.spec.redact(),
+            }
+        }
+    }
+
+    /// Defines a webhook target endpoint that can receive event notifications and data.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpec
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct WebhookTargetSpec {
+        /// Target url of the webhook.
+        pub url: String,
+        /// Shared secret used for HMAC signature of the request payload for authentication.
+        pub secret: Option<config::Secret>,
+    }
+
+    /// Builder for [`WebhookTargetSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct WebhookTargetSpecBuilder<TUrl = Unset> {
+        url: TUrl,
+        secret: Option<config::Secret>,
+    }
+
+    impl WebhookTargetSpec {
+        pub fn builder() -> WebhookTargetSpecBuilder<Unset> {
+            WebhookTargetSpecBuilder {
+                url: Unset,
+                secret: None,
+            }
+        }
+    }
+
+    impl WebhookTargetSpecBuilder<Unset> {
+        /// Target url of the webhook.
+        pub fn url(self, v: impl Into<String>) -> WebhookTargetSpecBuilder<String> {
+            WebhookTargetSpecBuilder {
+                url: v.into(),
+                secret: self.secret,
+            }
+        }
+    }
+
+    impl<TUrl> WebhookTargetSpecBuilder<TUrl> {
+        /// Shared secret used for HMAC signature of the request payload for authentication.
+        pub fn secret(mut self, v: impl Into<config::Secret>) -> Self {
+            self.secret = Some(v.into());
+            self
+        }
+    }
+
+    impl WebhookTargetSpecBuilder<String> {
+        pub fn build(self) -> WebhookTargetSpec {
+            WebhookTargetSpec {
+                url: self.url,
+                secret: self.secret,
+            }
+        }
+    }
+
+    impl Redact for WebhookTargetSpec {
+        fn redact(&self) -> Self {
+            Self {
+                url: self.url.clone(),
+                secret: self.secret.redact(),
+            }
+        }
+    }
+
+    /// Defines a webhook target endpoint that can receive event notifications and data.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpecInput
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct WebhookTargetSpecInput {
+        /// Target url of the webhook.
+        pub url: String,
+        /// Shared secret used for HMAC signature of the request payload for authentication.
+        pub secret: Option<config::Secret>,
+    }
+
+    /// Builder for [`WebhookTargetSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct WebhookTargetSpecInputBuilder<TUrl = Unset> {
+        url: TUrl,
+        secret: Option<config::Secret>,
+    }
+
+    impl WebhookTargetSpecInput {
+        pub fn builder() -> WebhookTargetSpecInputBuilder<Unset> {
+            WebhookTargetSpecInputBuilder {
+                url: Unset,
+                secret: None,
+            }
+        }
+    }
+
+    impl WebhookTargetSpecInputBuilder<Unset> {
+        /// Target url of the webhook.
+        pub fn url(self, v: impl Into<String>) -> WebhookTargetSpecInputBuilder<String> {
+            WebhookTargetSpecInputBuilder {
+                url: v.into(),
+                secret: self.secret,
+            }
+        }
+    }
+
+    impl<TUrl> WebhookTargetSpecInputBuilder<TUrl> {
+        /// Shared secret used for HMAC signature of the request payload for authentication.
+        pub fn secret(mut self, v: impl Into<config::Secret>) -> Self {
+            self.secret = Some(v.into());
+            self
+        }
+    }
+
+    impl WebhookTargetSpecInputBuilder<String> {
+        pub fn build(self) -> WebhookTargetSpecInput {
+            WebhookTargetSpecInput {
+                url: self.url,
+                secret: self.secret,
+            }
+        }
+    }
+
+    impl Redact for WebhookTargetSpecInput {
+        fn redact(&self) -> Self {
+            Self {
+                url: self.url.clone(),
+                secret: self.secret.redact(),
+            }
+        }
+    }
+
+    /// Represents the status of the webhook target endpoint.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct WebhookTargetStatus {
+        /// Status value.
+        pub value: sink::WebhookTargetStatusValue,
+        /// Time when the value last changed.
+        pub last_transition_time: DateTime<Utc>,
+        /// Machine-readable cause of the current value, e.g. `ConnectionRefused`.
+        pub reason: Option<String>,
+        /// Human-readable details about the current value.
+        pub message: Option<String>,
+    }
+
+    /// Builder for [`WebhookTargetStatus`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct WebhookTargetStatusBuilder<TValue = Unset, TLastTransitionTime = Unset> {
+        value: TValue,
+        last_transition_time: TLastTransitionTime,
+        reason: Option<String>,
+        message: Option<String>,
+    }
+
+    impl WebhookTargetStatus {
+        pub fn builder() -> WebhookTargetStatusBuilder<Unset, Unset> {
+            WebhookTargetStatusBuilder {
+                value: Unset,
+                last_transition_time: Unset,
+                reason: None,
+                message: None,
+            }
+        }
+    }
+
+    impl<TLastTransitionTime> WebhookTargetStatusBuilder<Unset, TLastTransitionTime> {
+        /// Status value.
+        pub fn value(
+            self,
+            v: impl Into<sink::WebhookTargetStatusValue>,
+        ) -> WebhookTargetStatusBuilder<sink::WebhookTargetStatusValue, TLastTransitionTime>
+        {
+            WebhookTargetStatusBuilder {
+                value: v.into(),
+                last_transition_time: self.last_transition_time,
+                reason: self.reason,
+                message: self.message,
+            }
+        }
+    }
+
+    impl<TValue> WebhookTargetStatusBuilder<TValue, Unset> {
+        /// Time when the value last changed.
+        pub fn last_transition_time(
+            self,
+            v: impl Into<DateTime<Utc>>,
+        ) -> WebhookTargetStatusBuilder<TValue, DateTime<Utc>> {
+            WebhookTargetStatusBuilder {
+                value: self.value,
+                last_transition_time: v.into(),
+                reason: self.reason,
+                message: self.message,
+            }
+        }
+    }
+
+    impl<TValue, TLastTransitionTime> WebhookTargetStatusBuilder<TValue, TLastTransitionTime> {
+        /// Machine-readable cause of the current value, e.g. `ConnectionRefused`.
+        pub fn reason(mut self, v: impl Into<String>) -> Self {
+            self.reason = Some(v.into());
+            self
+        }
+        /// Human-readable details about the current value.
+        pub fn message(mut self, v: impl Into<String>) -> Self {
+            self.message = Some(v.into());
+            self
+        }
+    }
+
+    impl WebhookTargetStatusBuilder<sink::WebhookTargetStatusValue, DateTime<Utc>> {
+        pub fn build(self) -> WebhookTargetStatus {
+            WebhookTargetStatus {
+                value: self.value,
+                last_transition_time: self.last_transition_time,
+                reason: self.reason,
+                message: self.message,
+            }
+        }
+    }
+
+    /// Status of the target endpoint
+    ///
+    /// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus#/$defs/Value
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum WebhookTargetStatusValue {
+        Ready,
+        Failed,
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// source
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod source {
+    #[allow(unused_imports)]
+    use super::*;
+
+    /// Defines a compression algorithm.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/CompressionFormat
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum CompressionFormat {
+        Gzip,
+        Zip,
+    }
+
+    /// Defines an environment variable passed into some job.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EnvVar
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct EnvVar {
+        /// Name of the variable.
+        pub name: String,
+        /// Value of the variable.
+        pub value: Option<String>,
+    }
+
+    /// Builder for [`EnvVar`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct EnvVarBuilder<TName = Unset> {
+        name: TName,
+        value: Option<String>,
+    }
+
+    impl EnvVar {
+        pub fn builder() -> EnvVarBuilder<Unset> {
+            EnvVarBuilder {
+                name: Unset,
+                value: None,
+            }
+        }
+    }
+
+    impl EnvVarBuilder<Unset> {
+        /// Name of the variable.
+        pub fn name(self, v: impl Into<String>) -> EnvVarBuilder<String> {
+            EnvVarBuilder {
+                name: v.into(),
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TName> EnvVarBuilder<TName> {
+        /// Value of the variable.
+        pub fn value(mut self, v: impl Into<String>) -> Self {
+            self.value = Some(v.into());
+            self
+        }
+    }
+
+    impl EnvVarBuilder<String> {
+        pub fn build(self) -> EnvVar {
+            EnvVar {
+                name: self.name,
+                value: self.value,
+            }
+        }
+    }
+
+    /// Defines the external source of data.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum EventTimeSource {
+        FromMetadata(source::EventTimeSourceFromMetadata),
+        FromPath(source::EventTimeSourceFromPath),
+        FromSystemTime(source::EventTimeSourceFromSystemTime),
+    }
+
+    impl_enum_with_variants!(EventTimeSource);
+    impl_enum_variant!(EventTimeSource::FromMetadata(
+        source::EventTimeSourceFromMetadata
+    ));
+    impl_enum_variant!(EventTimeSource::FromPath(source::EventTimeSourceFromPath));
+    impl_enum_variant!(EventTimeSource::FromSystemTime(
+        source::EventTimeSourceFromSystemTime
+    ));
+
+    /// Extracts event time from the source's metadata.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromMetadata
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct EventTimeSourceFromMetadata {}
+
+    /// Extracts event time from the path component of the source.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromPath
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct EventTimeSourceFromPath {
+        /// Regular expression where first group contains the timestamp string.
+        pub pattern: String,
+        /// Format of the expected timestamp in java.text.SimpleDateFormat form.
+        pub timestamp_format: Option<String>,
+    }
+
+    /// Builder for [`EventTimeSourceFromPath`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct EventTimeSourceFromPathBuilder<TPattern = Unset> {
+        pattern: TPattern,
+        timestamp_format: Option<String>,
+    }
+
+    impl EventTimeSourceFromPath {
+        pub fn builder() -> EventTimeSourceFromPathBuilder<Unset> {
+            EventTimeSourceFromPathBuilder {
+                pattern: Unset,
+                timestamp_format: None,
+            }
+        }
+    }
+
+    impl EventTimeSourceFromPathBuilder<Unset> {
+        /// Regular expression where first group contains the timestamp string.
+        pub fn pattern(self, v: impl Into<String>) -> EventTimeSourceFromPathBuilder<String> {
+            EventTimeSourceFromPathBuilder {
+                pattern: v.into(),
+                timestamp_format: self.timestamp_format,
+            }
+        }
+    }
+
+    impl<TPattern> EventTimeSourceFromPathBuilder<TPattern> {
+        /// Format of the expected timestamp in java.text.SimpleDateFormat form.
+        pub fn timestamp_format(mut self, v: impl Into<String>) -> Self {
+            self.timestamp_format = Some(v.into());
+            self
+        }
+    }
+
+    impl EventTimeSourceFromPathBuilder<String> {
+        pub fn build(self) -> EventTimeSourceFromPath {
+            EventTimeSourceFromPath {
+                pattern: self.pattern,
+                timestamp_format: self.timestamp_format,
+            }
+        }
+    }
+
+    /// Assigns event time from the system time source.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromSystemTime
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct EventTimeSourceFromSystemTime {}
+
+    /// Optional parameters to control ingestion behavior.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngestParams
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct IngestParams {
+        /// Target number of records to ingest per data slice.
+        pub target_slice_records: Option<u64>,
+    }
+
+    /// Builder for [`IngestParams`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngestParamsBuilder {
+        target_slice_records: Option<u64>,
+    }
+
+    impl IngestParams {
+        pub fn builder() -> IngestParamsBuilder {
+            IngestParamsBuilder {
+                target_slice_records: None,
+            }
+        }
+    }
+
+    impl IngestParamsBuilder {
+        /// Target number of records to ingest per data slice.
+        pub fn target_slice_records(mut self, v: impl Into<u64>) -> Self {
+            self.target_slice_records = Some(v.into());
+            self
+        }
+    }
+
+    impl IngestParamsBuilder {
+        pub fn build(self) -> IngestParams {
+            IngestParams {
+                target_slice_records: self.target_slice_records,
+            }
+        }
+    }
+
+    /// Defines the point where data enters the system.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum Ingress {
+        Url(source::IngressUrl),
+        FilesGlob(source::IngressFilesGlob),
+        Container(source::IngressContainer),
+        Mqtt(source::IngressMqtt),
+        EvmLogs(source::IngressEvmLogs),
+        RestEndpoint(source::IngressRestEndpoint),
+    }
+
+    impl_enum_with_variants!(Ingress);
+    impl_enum_variant!(Ingress::Url(source::IngressUrl));
+    impl_enum_variant!(Ingress::FilesGlob(source::IngressFilesGlob));
+    impl_enum_variant!(Ingress::Container(source::IngressContainer));
+    impl_enum_variant!(Ingress::Mqtt(source::IngressMqtt));
+    impl_enum_variant!(Ingress::EvmLogs(source::IngressEvmLogs));
+    impl_enum_variant!(Ingress::RestEndpoint(source::IngressRestEndpoint));
+
+    impl Redact for Ingress {
+        fn redact(&self) -> Self {
+            match self {
+                Self::Mqtt(v) => Self::Mqtt(v.redact()),
+                _ => self.clone(),
+            }
+        }
+    }
+
+    /// Buffer configuration for holding pushed records until they are ingested.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum IngressBuffer {
+        Memory(source::IngressBufferMemory),
+    }
+
+    impl_enum_with_variants!(IngressBuffer);
+    impl_enum_variant!(IngressBuffer::Memory(source::IngressBufferMemory));
+
+    /// An in-memory buffer.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer#/$defs/Memory
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct IngressBufferMemory {
+        /// Maximum number of records to hold in the buffer.
+        pub buffer_size: Option<u64>,
+        /// Policy applied when the buffer is full.
+        pub overflow_policy: Option<String>,
+    }
+
+    /// Builder for [`IngressBufferMemory`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngressBufferMemoryBuilder {
+        buffer_size: Option<u64>,
+        overflow_policy: Option<String>,
+    }
+
+    impl IngressBufferMemory {
+        pub fn builder() -> IngressBufferMemoryBuilder {
+            IngressBufferMemoryBuilder {
+                buffer_size: None,
+                overflow_policy: None,
+            }
+        }
+    }
+
+    impl IngressBufferMemoryBuilder {
+        /// Maximum number of records to hold in the buffer.
+        pub fn buffer_size(mut self, v: impl Into<u64>) -> Self {
+            self.buffer_size = Some(v.into());
+            self
+        }
+        /// Policy applied when the buffer is full.
+        pub fn overflow_policy(mut self, v: impl Into<String>) -> Self {
+            self.overflow_policy = Some(v.into());
+            self
+        }
+    }
+
+    impl IngressBufferMemoryBuilder {
+        pub fn build(self) -> IngressBufferMemory {
+            IngressBufferMemory {
+                buffer_size: self.buffer_size,
+                overflow_policy: self.overflow_policy,
+            }
+        }
+    }
+
+    /// Runs the specified OCI container to fetch data from an arbitrary source.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Container
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct IngressContainer {
+        /// Image name and and an optional tag.
+        pub image: String,
+        /// Specifies the entrypoint. Not executed within a shell. The default OCI image's ENTRYPOINT is used if this is not provided.
+        pub command: Option<Vec<String>>,
+        /// Arguments to the entrypoint. The OCI image's CMD is used if this is not provided.
+        pub args: Option<Vec<String>>,
+        /// Environment variables to propagate into or set in the container.
+        pub env: Option<Vec<source::EnvVar>>,
+    }
+
+    /// Builder for [`IngressContainer`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngressContainerBuilder<TImage = Unset> {
+        image: TImage,
+        command: Option<Vec<String>>,
+        args: Option<Vec<String>>,
+        env: Option<Vec<source::EnvVar>>,
+    }
+
+    impl IngressContainer {
+        pub fn builder() -> IngressContainerBuilder<Unset> {
+            IngressContainerBuilder {
+                image: Unset,
+                command: None,
+                args: None,
+                env: None,
+            }
+        }
+    }
+
+    impl IngressContainerBuilder<Unset> {
+        /// Image name and and an optional tag.
+        pub fn image(self, v: impl Into<String>) -> IngressContainerBuilder<String> {
+            IngressContainerBuilder {
+                image: v.into(),
+                command: self.command,
+                args: self.args,
+                env: self.env,
+            }
+        }
+    }
+
+    impl<TImage> IngressContainerBuilder<TImage> {
+        /// Specifies the entrypoint. Not executed within a shell. The default OCI image's ENTRYPOINT is used if this is not provided.
+        pub fn command(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.command = Some(v.into());
+            self
+        }
+        /// Arguments to the entrypoint. The OCI image's CMD is used if this is not provided.
+        pub fn args(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.args = Some(v.into());
+            self
+        }
+        /// Environment variables to propagate into or set in the container.
+        pub fn env(mut self, v: impl Into<Vec<source::EnvVar>>) -> Self {
+            self.env = Some(v.into());
+            self
+        }
+    }
+
+    impl IngressContainerBuilder<String> {
+        pub fn build(self) -> IngressContainer {
+            IngressContainer {
+                image: self.image,
+                command: self.command,
+                args: self.args,
+                env: self.env,
+            }
+        }
+    }
+
+    /// Connects to an EVM (Ethereum) node to stream transaction logs.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/EvmLogs
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct IngressEvmLogs {
+        /// Identifier of the chain to scan logs from. This parameter may be used for RPC endpoint lookup as well as asserting that provided `nodeUrl` corresponds to the expected chain.
+        pub chain_id: Option<u64>,
+        /// Url of the node.
+        pub node_url: Option<String>,
+        /// An SQL WHERE clause that can be used to pre-filter the logs before fetching them from the ETH node.
+        ///
+        /// Examples:
+        /// - "block_number > 123 and address = X'5fbdb2315678afecb367f032d93f642f64180aa3' and topic1 = X'000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266'"
+        pub filter: Option<String>,
+        /// Solidity log event signature to use for decoding. Using this field adds `event` to the output containing decoded log as JSON.
+        pub signature: Option<String>,
+    }
+
+    /// Builder for [`IngressEvmLogs`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngressEvmLogsBuilder {
+        chain_id: Option<u64>,
+        node_url: Option<String>,
+        filter: Option<String>,
+        signature: Option<String>,
+    }
+
+    impl IngressEvmLogs {
+        pub fn builder() -> IngressEvmLogsBuilder {
+            IngressEvmLogsBuilder {
+                chain_id: None,
+                node_url: None,
+                filter: None,
+                signature: None,
+            }
+        }
+    }
+
+    impl IngressEvmLogsBuilder {
+        /// Identifier of the chain to scan logs from. This parameter may be used for RPC endpoint lookup as well as asserting that provided `nodeUrl` corresponds to the expected chain.
+        pub fn chain_id(mut self, v: impl Into<u64>) -> Self {
+            self.chain_id = Some(v.into());
+            self
+        }
+        /// Url of the node.
+        pub fn node_url(mut self, v: impl Into<String>) -> Self {
+            self.node_url = Some(v.into());
+            self
+        }
+        /// An SQL WHERE clause that can be used to pre-filter the logs before fetching them from the ETH node.
+        pub fn filter(mut self, v: impl Into<String>) -> Self {
+            self.filter = Some(v.into());
+            self
+        }
+        /// Solidity log event signature to use for decoding. Using this field adds `event` to the output containing decoded log as JSON.
+        pub fn signature(mut self, v: impl Into<String>) -> Self {
+            self.signature = Some(v.into());
+            self
+        }
+    }
+
+    impl IngressEvmLogsBuilder {
+        pub fn build(self) -> IngressEvmLogs {
+            IngressEvmLogs {
+                chain_id: self.chain_id,
+                node_url: self.node_url,
+                filter: self.filter,
+                signature: self.signature,
+            }
+        }
+    }
+
+    /// Uses glob operator to match files on the local file system.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/FilesGlob
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct IngressFilesGlob {
+        /// Path with a glob pattern.
+        pub path: String,
+        /// Describes how event time is extracted from the source metadata.
+        pub event_time: Option<source::EventTimeSource>,
+        /// Describes the caching settings used for this source.
+        pub cache: Option<source::SourceCaching>,
+        /// Specifies how input files should be ordered before ingestion.
+        /// Order is important as every file will be processed individually
+        /// and will advance the dataset's watermark.
+        pub order: Option<source::SourceOrdering>,
+    }
+
+    /// Builder for [`IngressFilesGlob`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngressFilesGlobBuilder<TPath = Unset> {
+        path: TPath,
+        event_time: Option<source::EventTimeSource>,
+        cache: Option<source::SourceCaching>,
+        order: Option<source::SourceOrdering>,
+    }
+
+    impl IngressFilesGlob {
+        pub fn builder() -> IngressFilesGlobBuilder<Unset> {
+            IngressFilesGlobBuilder {
+                path: Unset,
+                event_time: None,
+                cache: None,
+                order: None,
+            }
+        }
+    }
+
+    impl IngressFilesGlobBuilder<Unset> {
+        /// Path with a glob pattern.
+        pub fn path(self, v: impl Into<String>) -> IngressFilesGlobBuilder<String> {
+            IngressFilesGlobBuilder {
+                path: v.into(),
+                event_time: self.event_time,
+                cache: self.cache,
+                order: self.order,
+            }
+        }
+    }
+
+    impl<TPath> IngressFilesGlobBuilder<TPath> {
+        /// Describes how event time is extracted from the source metadata.
+        pub fn event_time(mut self, v: impl Into<source::EventTimeSource>) -> Self {
+            self.event_time = Some(v.into());
+            self
+        }
+        /// Describes the caching settings used for this source.
+        pub fn cache(mut self, v: impl Into<source::SourceCaching>) -> Self {
+            self.cache = Some(v.into());
+            self
+        }
+        /// Specifies how input files should be ordered before ingestion.
+        /// Order is important as every file will be processed individually
+        /// and will advance the dataset's watermark.
+        pub fn order(mut self, v: impl Into<source::SourceOrdering>) -> Self {
+            self.order = Some(v.into());
+            self
+        }
+    }
+
+    impl IngressFilesGlobBuilder<String> {
+        pub fn build(self) -> IngressFilesGlob {
+            IngressFilesGlob {
+                path: self.path,
+                event_time: self.event_time,
+                cache: self.cache,
+                order: self.order,
+            }
+        }
+    }
+
+    /// Connects to an MQTT broker to fetch events from the specified topic.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Mqtt
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct IngressMqtt {
+        /// Hostname of the MQTT broker.
+        pub host: String,
+        /// Port of the MQTT broker.
+        pub port: i32,
+        /// Username to use for auth with the broker.
+        pub username: Option<String>,
+        /// Password to use for auth with the broker (can be templated).
+        pub password: Option<super::Sensitive<String>>,
+        /// List of topic subscription parameters.
+        pub topics: Vec<source::MqttTopicSubscription>,
+    }
+
+    /// Builder for [`IngressMqtt`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngressMqttBuilder<THost = Unset, TPort = Unset, TTopics = Unset> {
+        host: THost,
+        port: TPort,
+        username: Option<String>,
+        password: Option<super::Sensitive<String>>,
+        topics: TTopics,
+    }
+
+    impl IngressMqtt {
+        pub fn builder() -> IngressMqttBuilder<Unset, Unset, Unset> {
+            IngressMqttBuilder {
+                host: Unset,
+                port: Unset,
+                username: None,
+                password: None,
+                topics: Unset,
+            }
+        }
+    }
+
+    impl<TPort, TTopics> IngressMqttBuilder<Unset, TPort, TTopics> {
+        /// Hostname of the MQTT broker.
+        pub fn host(self, v: impl Into<String>) -> IngressMqttBuilder<String, TPort, TTopics> {
+            IngressMqttBuilder {
+                host: v.into(),
+                port: self.port,
+                username: self.username,
+                password: self.password,
+                topics: self.topics,
+            }
+        }
+    }
+
+    impl<THost, TTopics> IngressMqttBuilder<THost, Unset, TTopics> {
+        /// Port of the MQTT broker.
+        pub fn port(self, v: impl Into<i32>) -> IngressMqttBuilder<THost, i32, TTopics> {
+            IngressMqttBuilder {
+                host: self.host,
+                port: v.into(),
+                username: self.username,
+                password: self.password,
+                topics: self.topics,
+            }
+        }
+    }
+
+    impl<THost, TPort> IngressMqttBuilder<THost, TPort, Unset> {
+        /// List of topic subscription parameters.
+        pub fn topics(
+            self,
+            v: impl Into<Vec<source::MqttTopicSubscription>>,
+        ) -> IngressMqttBuilder<THost, TPort, Vec<source::MqttTopicSubscription>> {
+            IngressMqttBuilder {
+                host: self.host,
+                port: self.port,
+                username: self.username,
+                password: self.password,
+                topics: v.into(),
+            }
+        }
+    }
+
+    impl<THost, TPort, TTopics> IngressMqttBuilder<THost, TPort, TTopics> {
+        /// Username to use for auth with the broker.
+        pub fn username(mut self, v: impl Into<String>) -> Self {
+            self.username = Some(v.into());
+            self
+        }
+        /// Password to use for auth with the broker (can be templated).
+        pub fn password(mut self, v: impl Into<String>) -> Self {
+            self.password = Some(super::Sensitive::new(v.into()));
+            self
+        }
+    }
+
+    impl IngressMqttBuilder<String, i32, Vec<source::MqttTopicSubscription>> {
+        pub fn build(self) -> IngressMqtt {
+            IngressMqtt {
+                host: self.host,
+                port: self.port,
+                username: self.username,
+                password: self.password,
+                topics: self.topics,
+            }
+        }
+    }
+
+    impl Redact for IngressMqtt {
+        fn redact(&self) -> Self {
+            Self {
+                host: self.host.clone(),
+                port: self.port.clone(),
+                username: self.username.clone(),
+                password: self.password.redact(),
+                topics: self.topics.clone(),
+            }
+        }
+    }
+
+    /// Exposes a REST HTTP endpoint that accepts pushed data records.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/RestEndpoint
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct IngressRestEndpoint {
+        /// Buffer configuration for holding records until they are ingested.
+        pub buffer: Option<source::IngressBuffer>,
+    }
+
+    /// Builder for [`IngressRestEndpoint`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngressRestEndpointBuilder {
+        buffer: Option<source::IngressBuffer>,
+    }
+
+    impl IngressRestEndpoint {
+        pub fn builder() -> IngressRestEndpointBuilder {
+            IngressRestEndpointBuilder { buffer: None }
+        }
+    }
+
+    impl IngressRestEndpointBuilder {
+        /// Buffer configuration for holding records until they are ingested.
+        pub fn buffer(mut self, v: impl Into<source::IngressBuffer>) -> Self {
+            self.buffer = Some(v.into());
+            self
+        }
+    }
+
+    impl IngressRestEndpointBuilder {
+        pub fn build(self) -> IngressRestEndpoint {
+            IngressRestEndpoint {
+                buffer: self.buffer,
+            }
+        }
+    }
+
+    /// Pulls data from one of the supported sources by its URL.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Url
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct IngressUrl {
+        /// URL of the data source
+        pub url: String,
+        /// Describes how event time is extracted from the source metadata.
+        pub event_time: Option<source::EventTimeSource>,
+        /// Describes the caching settings used for this source.
+        pub cache: Option<source::SourceCaching>,
+        /// Headers to pass during the request (e.g. HTTP Authorization)
+        pub headers: Option<Vec<source::RequestHeader>>,
+    }
+
+    /// Builder for [`IngressUrl`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct IngressUrlBuilder<TUrl = Unset> {
+        url: TUrl,
+        event_time: Option<source::EventTimeSource>,
+        cache: Option<source::SourceCaching>,
+        headers: Option<Vec<source::RequestHeader>>,
+    }
+
+    impl IngressUrl {
+        pub fn builder() -> IngressUrlBuilder<Unset> {
+            IngressUrlBuilder {
+                url: Unset,
+                event_time: None,
+                cache: None,
+                headers: None,
+            }
+        }
+    }
+
+    impl IngressUrlBuilder<Unset> {
+        /// URL of the data source
+        pub fn url(self, v: impl Into<String>) -> IngressUrlBuilder<String> {
+            IngressUrlBuilder {
+                url: v.into(),
+                event_time: self.event_time,
+                cache: self.cache,
+                headers: self.headers,
+            }
+        }
+    }
+
+    impl<TUrl> IngressUrlBuilder<TUrl> {
+        /// Describes how event time is extracted from the source metadata.
+        pub fn event_time(mut self, v: impl Into<source::EventTimeSource>) -> Self {
+            self.event_time = Some(v.into());
+            self
+        }
+        /// Describes the caching settings used for this source.
+        pub fn cache(mut self, v: impl Into<source::SourceCaching>) -> Self {
+            self.cache = Some(v.into());
+            self
+        }
+        /// Headers to pass during the request (e.g. HTTP Authorization)
+        pub fn headers(mut self, v: impl Into<Vec<source::RequestHeader>>) -> Self {
+            self.headers = Some(v.into());
+            self
+        }
+    }
+
+    impl IngressUrlBuilder<String> {
+        pub fn build(self) -> IngressUrl {
+            IngressUrl {
+                url: self.url,
+                event_time: self.event_time,
+                cache: self.cache,
+                headers: self.headers,
+            }
+        }
+    }
+
+    /// Merge strategy determines how newly ingested data should be combined with the data that already exists in the dataset.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum MergeStrategy {
+        Append(source::MergeStrategyAppend),
+        Ledger(source::MergeStrategyLedger),
+        Snapshot(source::MergeStrategySnapshot),
+        ChangelogStream(source::MergeStrategyChangelogStream),
+        UpsertStream(source::MergeStrategyUpsertStream),
+    }
+
+    impl_enum_with_variants!(MergeStrategy);
+    impl_enum_variant!(MergeStrategy::Append(source::MergeStrategyAppend));
+    impl_enum_variant!(MergeStrategy::Ledger(source::MergeStrategyLedger));
+    impl_enum_variant!(MergeStrategy::Snapshot(source::MergeStrategySnapshot));
+    impl_enum_variant!(MergeStrategy::ChangelogStream(
+        source::MergeStrategyChangelogStream
+    ));
+    impl_enum_variant!(MergeStrategy::UpsertStream(
+        source::MergeStrategyUpsertStream
+    ));
+
+    /// Append merge strategy.
+    ///
+    /// Under this strategy new data will be appended to the dataset in its entirety, without any deduplication.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Append
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct MergeStrategyAppend {}
+
+    /// Changelog stream merge strategy.
+    ///
+    /// This is the native stream format for ODF that accurately describes the evolution of all event records including appends, retractions, and corrections as per RFC-015. No pre-processing except for format validation is done.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/ChangelogStream
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct MergeStrategyChangelogStream {
+        /// Names of the columns that uniquely identify the record throughout its lifetime
+        pub primary_key: Vec<String>,
+    }
+
+    /// Builder for [`MergeStrategyChangelogStream`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct MergeStrategyChangelogStreamBuilder<TPrimaryKey = Unset> {
+        primary_key: TPrimaryKey,
+    }
+
+    impl MergeStrategyChangelogStream {
+        pub fn builder() -> MergeStrategyChangelogStreamBuilder<Unset> {
+            MergeStrategyChangelogStreamBuilder { primary_key: Unset }
+        }
+    }
+
+    impl MergeStrategyChangelogStreamBuilder<Unset> {
+        /// Names of the columns that uniquely identify the record throughout its lifetime
+        pub fn primary_key(
+            self,
+            v: impl Into<Vec<String>>,
+        ) -> MergeStrategyChangelogStreamBuilder<Vec<String>> {
+            MergeStrategyChangelogStreamBuilder {
+                primary_key: v.into(),
+            }
+        }
+    }
+
+    impl MergeStrategyChangelogStreamBuilder<Vec<String>> {
+        pub fn build(self) -> MergeStrategyChangelogStream {
+            MergeStrategyChangelogStream {
+                primary_key: self.primary_key,
+            }
+        }
+    }
+
+    /// Ledger merge strategy.
+    ///
+    /// This strategy should be used for data sources containing ledgers of events. Currently this strategy will only perform deduplication of events using user-specified primary key columns. This means that the source data can contain partially overlapping set of records and only those records that were not previously seen will be appended.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Ledger
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct MergeStrategyLedger {
+        /// Names of the columns that uniquely identify the record throughout its lifetime
+        pub primary_key: Vec<String>,
+    }
+
+    /// Builder for [`MergeStrategyLedger`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct MergeStrategyLedgerBuilder<TPrimaryKey = Unset> {
+        primary_key: TPrimaryKey,
+    }
+
+    impl MergeStrategyLedger {
+        pub fn builder() -> MergeStrategyLedgerBuilder<Unset> {
+            MergeStrategyLedgerBuilder { primary_key: Unset }
+        }
+    }
+
+    impl MergeStrategyLedgerBuilder<Unset> {
+        /// Names of the columns that uniquely identify the record throughout its lifetime
+        pub fn primary_key(
+            self,
+            v: impl Into<Vec<String>>,
+        ) -> MergeStrategyLedgerBuilder<Vec<String>> {
+            MergeStrategyLedgerBuilder {
+                primary_key: v.into(),
+            }
+        }
+    }
+
+    impl MergeStrategyLedgerBuilder<Vec<String>> {
+        pub fn build(self) -> MergeStrategyLedger {
+            MergeStrategyLedger {
+                primary_key: self.primary_key,
+            }
+        }
+    }
+
+    /// Snapshot merge strategy.
+    ///
+    /// This strategy can be used for data state snapshots that are taken periodically and contain only the latest state of the observed entity or system. Over time such snapshots can have new rows added, and old rows either removed or modified.
+    ///
+    /// This strategy transforms snapshot data into an append-only event stream where data already added is immutable. It does so by performing Change Data Capture - essentially diffing the current state of data against the reconstructed previous state and recording differences as retractions or corrections. The Operation Type "op" column will contain:
+    ///   - append (`+A`) when a row appears for the first time
+    ///   - retraction (`-D`) when row disappears
+    ///   - correction (`-C`, `+C`) when row data has changed, with `-C` event carrying the old value of the row and `+C` carrying the new value.
+    ///
+    /// To correctly associate rows between old and new snapshots this strategy relies on user-specified primary key columns.
+    ///
+    /// To identify whether a row has changed this strategy will compare all other columns one by one. If the data contains a column that is guaranteed to change whenever any of the data columns changes (for example a last modification timestamp, an incremental version, or a data hash), then it can be specified in `compareColumns` property to speed up the detection of modified rows.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Snapshot
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct MergeStrategySnapshot {
+        /// Names of the columns that uniquely identify the record throughout its lifetime.
+        pub primary_key: Vec<String>,
+        /// Names of the columns to compared to determine if a row has changed between two snapshots.
+        pub compare_columns: Option<Vec<String>>,
+    }
+
+    /// Builder for [`MergeStrategySnapshot`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct MergeStrategySnapshotBuilder<TPrimaryKey = Unset> {
+        primary_key: TPrimaryKey,
+        compare_columns: Option<Vec<String>>,
+    }
+
+    impl MergeStrategySnapshot {
+        pub fn builder() -> MergeStrategySnapshotBuilder<Unset> {
+            MergeStrategySnapshotBuilder {
+                primary_key: Unset,
+                compare_columns: None,
+            }
+        }
+    }
+
+    impl MergeStrategySnapshotBuilder<Unset> {
+        /// Names of the columns that uniquely identify the record throughout its lifetime.
+        pub fn primary_key(
+            self,
+            v: impl Into<Vec<String>>,
+        ) -> MergeStrategySnapshotBuilder<Vec<String>> {
+            MergeStrategySnapshotBuilder {
+                primary_key: v.into(),
+                compare_columns: self.compare_columns,
+            }
+        }
+    }
+
+    impl<TPrimaryKey> MergeStrategySnapshotBuilder<TPrimaryKey> {
+        /// Names of the columns to compared to determine if a row has changed between two snapshots.
+        pub fn compare_columns(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.compare_columns = Some(v.into());
+            self
+        }
+    }
+
+    impl MergeStrategySnapshotBuilder<Vec<String>> {
+        pub fn build(self) -> MergeStrategySnapshot {
+            MergeStrategySnapshot {
+                primary_key: self.primary_key,
+                compare_columns: self.compare_columns,
+            }
+        }
+    }
+
+    /// Upsert stream merge strategy.
+    ///
+    /// This strategy should be used for data sources containing ledgers of insert-or-update and delete events. Unlike ChangelogStream the insert-or-update events only carry the new values, so this strategy will use primary key to re-classify the events into an append or a correction from/to pair, looking up the previous values.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/UpsertStream
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct MergeStrategyUpsertStream {
+        /// Names of the columns that uniquely identify the record throughout its lifetime
+        pub primary_key: Vec<String>,
+    }
+
+    /// Builder for [`MergeStrategyUpsertStream`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct MergeStrategyUpsertStreamBuilder<TPrimaryKey = Unset> {
+        primary_key: TPrimaryKey,
+    }
+
+    impl MergeStrategyUpsertStream {
+        pub fn builder() -> MergeStrategyUpsertStreamBuilder<Unset> {
+            MergeStrategyUpsertStreamBuilder { primary_key: Unset }
+        }
+    }
+
+    impl MergeStrategyUpsertStreamBuilder<Unset> {
+        /// Names of the columns that uniquely identify the record throughout its lifetime
+        pub fn primary_key(
+            self,
+            v: impl Into<Vec<String>>,
+        ) -> MergeStrategyUpsertStreamBuilder<Vec<String>> {
+            MergeStrategyUpsertStreamBuilder {
+                primary_key: v.into(),
+            }
+        }
+    }
+
+    impl MergeStrategyUpsertStreamBuilder<Vec<String>> {
+        pub fn build(self) -> MergeStrategyUpsertStream {
+            MergeStrategyUpsertStream {
+                primary_key: self.primary_key,
+            }
+        }
+    }
+
+    /// MQTT quality of service class.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttQos
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum MqttQos {
+        AtMostOnce,
+        AtLeastOnce,
+        ExactlyOnce,
+    }
+
+    /// MQTT topic subscription parameters.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttTopicSubscription
+    #[derive(Clone, Debug, Eq)]
+    pub struct MqttTopicSubscription {
+        /// Name of the topic (may include patterns).
+        pub path: String,
+        /// Quality of service class.
+        ///
+        /// Defaults to: "AtMostOnce"
+        pub qos: Option<source::MqttQos>,
+    }
+
+    impl MqttTopicSubscription {
+        pub fn default_qos() -> source::MqttQos {
+            source::MqttQos::AtMostOnce
+        }
+        pub fn qos(&self) -> source::MqttQos {
+            self.qos.unwrap_or(Self::default_qos())
+        }
+    }
+
+    impl PartialEq for MqttTopicSubscription {
+        fn eq(&self, other: &Self) -> bool {
+            self.path == other.path
+                && self.qos.or_else(|| Some(Self::default_qos()))
+                    == other.qos.or_else(|| Some(Self::default_qos()))
+        }
+    }
+
+    /// Builder for [`MqttTopicSubscription`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct MqttTopicSubscriptionBuilder<TPath = Unset> {
+        path: TPath,
+        qos: Option<source::MqttQos>,
+    }
+
+    impl MqttTopicSubscription {
+        pub fn builder() -> MqttTopicSubscriptionBuilder<Unset> {
+            MqttTopicSubscriptionBuilder {
+                path: Unset,
+                qos: None,
+            }
+        }
+    }
+
+    impl MqttTopicSubscriptionBuilder<Unset> {
+        /// Name of the topic (may include patterns).
+        pub fn path(self, v: impl Into<String>) -> MqttTopicSubscriptionBuilder<String> {
+            MqttTopicSubscriptionBuilder {
+                path: v.into(),
+                qos: self.qos,
+            }
+        }
+    }
+
+    impl<TPath> MqttTopicSubscriptionBuilder<TPath> {
+        /// Quality of service class.
+        ///
+        /// Defaults to: "AtMostOnce"
+        pub fn qos(mut self, v: impl Into<source::MqttQos>) -> Self {
+            self.qos = Some(v.into());
+            self
+        }
+    }
+
+    impl MqttTopicSubscriptionBuilder<String> {
+        pub fn build(self) -> MqttTopicSubscription {
+            MqttTopicSubscription {
+                path: self.path,
+                qos: self.qos,
+            }
+        }
+    }
+
+    /// Defines the steps to prepare raw data for ingestion.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum PrepStep {
+        Decompress(source::PrepStepDecompress),
+        Pipe(source::PrepStepPipe),
+    }
+
+    impl_enum_with_variants!(PrepStep);
+    impl_enum_variant!(PrepStep::Decompress(source::PrepStepDecompress));
+    impl_enum_variant!(PrepStep::Pipe(source::PrepStepPipe));
+
+    /// Pulls data from one of the supported sources by its URL.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Decompress
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct PrepStepDecompress {
+        /// Name of a compression algorithm used on data.
+        pub format: source::CompressionFormat,
+        /// Path to a data file within a multi-file archive. Can contain glob patterns.
+        pub sub_path: Option<String>,
+    }
+
+    /// Builder for [`PrepStepDecompress`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PrepStepDecompressBuilder<TFormat = Unset> {
+        format: TFormat,
+        sub_path: Option<String>,
+    }
+
+    impl PrepStepDecompress {
+        pub fn builder() -> PrepStepDecompressBuilder<Unset> {
+            PrepStepDecompressBuilder {
+                format: Unset,
+                sub_path: None,
+            }
+        }
+    }
+
+    impl PrepStepDecompressBuilder<Unset> {
+        /// Name of a compression algorithm used on data.
+        pub fn format(
+            self,
+            v: impl Into<source::CompressionFormat>,
+        ) -> PrepStepDecompressBuilder<source::CompressionFormat> {
+            PrepStepDecompressBuilder {
+                format: v.into(),
+                sub_path: self.sub_path,
+            }
+        }
+    }
+
+    impl<TFormat> PrepStepDecompressBuilder<TFormat> {
+        /// Path to a data file within a multi-file archive. Can contain glob patterns.
+        pub fn sub_path(mut self, v: impl Into<String>) -> Self {
+            self.sub_path = Some(v.into());
+            self
+        }
+    }
+
+    impl PrepStepDecompressBuilder<source::CompressionFormat> {
+        pub fn build(self) -> PrepStepDecompress {
+            PrepStepDecompress {
+                format: self.format,
+                sub_path: self.sub_path,
+            }
+        }
+    }
+
+    /// Executes external command to process the data using piped input/output.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Pipe
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct PrepStepPipe {
+        /// Command to execute and its arguments.
+        pub command: Vec<String>,
+    }
+
+    /// Builder for [`PrepStepPipe`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PrepStepPipeBuilder<TCommand = Unset> {
+        command: TCommand,
+    }
+
+    impl PrepStepPipe {
+        pub fn builder() -> PrepStepPipeBuilder<Unset> {
+            PrepStepPipeBuilder { command: Unset }
+        }
+    }
+
+    impl PrepStepPipeBuilder<Unset> {
+        /// Command to execute and its arguments.
+        pub fn command(self, v: impl Into<Vec<String>>) -> PrepStepPipeBuilder<Vec<String>> {
+            PrepStepPipeBuilder { command: v.into() }
+        }
+    }
+
+    impl PrepStepPipeBuilder<Vec<String>> {
+        pub fn build(self) -> PrepStepPipe {
+            PrepStepPipe {
+                command: self.command,
+            }
+        }
+    }
+
+    /// Defines how raw data should be read into the structured form.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum ReadStep {
+        Csv(source::ReadStepCsv),
+        GeoJson(source::ReadStepGeoJson),
+        EsriShapefile(source::ReadStepEsriShapefile),
+        Parquet(source::ReadStepParquet),
+        Json(source::ReadStepJson),
+        NdJson(source::ReadStepNdJson),
+        NdGeoJson(source::ReadStepNdGeoJson),
+    }
+
+    impl_enum_with_variants!(ReadStep);
+    impl_enum_variant!(ReadStep::Csv(source::ReadStepCsv));
+    impl_enum_variant!(ReadStep::GeoJson(source::ReadStepGeoJson));
+    impl_enum_variant!(ReadStep::EsriShapefile(source::ReadStepEsriShapefile));
+    impl_enum_variant!(ReadStep::Parquet(source::ReadStepParquet));
+    impl_enum_variant!(ReadStep::Json(source::ReadStepJson));
+    impl_enum_variant!(ReadStep::NdJson(source::ReadStepNdJson));
+    impl_enum_variant!(ReadStep::NdGeoJson(source::ReadStepNdGeoJson));
+
+    /// Reader for comma-separated files.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Csv
+    #[derive(Clone, Debug, Eq, Default)]
+    pub struct ReadStepCsv {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        ///
+        /// Examples:
+        /// - ["date TIMESTAMP","city STRING","population INT"]
+        pub ddl_schema: Option<Vec<String>>,
+        /// Sets a single character as a separator for each field and value.
+        ///
+        /// Defaults to: ","
+        pub separator: Option<String>,
+        /// Decodes the CSV files by the given encoding type.
+        ///
+        /// Defaults to: "utf8"
+        pub encoding: Option<String>,
+        /// Sets a single character used for escaping quoted values where the separator can be part of the value. Set an empty string to turn off quotations.
+        ///
+        /// Defaults to: "\""
+        pub quote: Option<String>,
+        /// Sets a single character used for escaping quotes inside an already quoted value.
+        ///
+        /// Defaults to: "\\"
+        pub escape: Option<String>,
+        /// Use the first line as names of columns.
+        ///
+        /// Defaults to: false
+        pub header: Option<bool>,
+        /// Infers the input schema automatically from data. It requires one extra pass over the data.
+        ///
+        /// Defaults to: false
+        pub infer_schema: Option<bool>,
+        /// Sets the string representation of a null value.
+        ///
+        /// Defaults to: ""
+        pub null_value: Option<String>,
+        /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+        ///
+        /// Defaults to: "rfc3339"
+        pub date_format: Option<String>,
+        /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+        ///
+        /// Defaults to: "rfc3339"
+        pub timestamp_format: Option<String>,
+        /// Schema used to coerce values into more appropriate data types.
+        pub schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepCsv {
+        pub fn default_separator() -> &'static str {
+            ","
+        }
+        pub fn separator(&self) -> &str {
+            self.separator
+                .as_deref()
+                .unwrap_or(Self::default_separator())
+        }
+        pub fn default_encoding() -> &'static str {
+            "utf8"
+        }
+        pub fn encoding(&self) -> &str {
+            self.encoding.as_deref().unwrap_or(Self::default_encoding())
+        }
+        pub fn default_quote() -> &'static str {
+            "\""
+        }
+        pub fn quote(&self) -> &str {
+            self.quote.as_deref().unwrap_or(Self::default_quote())
+        }
+        pub fn default_escape() -> &'static str {
+            "\\"
+        }
+        pub fn escape(&self) -> &str {
+            self.escape.as_deref().unwrap_or(Self::default_escape())
+        }
+        pub fn default_header() -> bool {
+            false
+        }
+        pub fn header(&self) -> bool {
+            self.header.unwrap_or(Self::default_header())
+        }
+        pub fn default_infer_schema() -> bool {
+            false
+        }
+        pub fn infer_schema(&self) -> bool {
+            self.infer_schema.unwrap_or(Self::default_infer_schema())
+        }
+        pub fn default_null_value() -> &'static str {
+            ""
+        }
+        pub fn null_value(&self) -> &str {
+            self.null_value
+                .as_deref()
+                .unwrap_or(Self::default_null_value())
+        }
+        pub fn default_date_format() -> &'static str {
+            "rfc3339"
+        }
+        pub fn date_format(&self) -> &str {
+            self.date_format
+                .as_deref()
+                .unwrap_or(Self::default_date_format())
+        }
+        pub fn default_timestamp_format() -> &'static str {
+            "rfc3339"
+        }
+        pub fn timestamp_format(&self) -> &str {
+            self.timestamp_format
+                .as_deref()
+                .unwrap_or(Self::default_timestamp_format())
+        }
+    }
+
+    impl PartialEq for ReadStepCsv {
+        fn eq(&self, other: &Self) -> bool {
+            self.ddl_schema == other.ddl_schema
+                && self
+                    .separator
+                    .as_deref()
+                    .or_else(|| Some(Self::default_separator()))
+                    == other
+                        .separator
+                        .as_deref()
+                        .or_else(|| Some(Self::default_separator()))
+                && self
+                    .encoding
+                    .as_deref()
+                    .or_else(|| Some(Self::default_encoding()))
+                    == other
+                        .encoding
+                        .as_deref()
+                        .or_else(|| Some(Self::default_encoding()))
+                && self
+                    .quote
+                    .as_deref()
+                    .or_else(|| Some(Self::default_quote()))
+                    == other
+                        .quote
+                        .as_deref()
+                        .or_else(|| Some(Self::default_quote()))
+                && self
+                    .escape
+                    .as_deref()
+                    .or_else(|| Some(Self::default_escape()))
+                    == other
+                        .escape
+                        .as_deref()
+                        .or_else(|| Some(Self::default_escape()))
+                && self.header.or_else(|| Some(Self::default_header()))
+                    == other.header.or_else(|| Some(Self::default_header()))
+                && self
+                    .infer_schema
+                    .or_else(|| Some(Self::default_infer_schema()))
+                    == other
+                        .infer_schema
+                        .or_else(|| Some(Self::default_infer_schema()))
+                && self
+                    .null_value
+                    .as_deref()
+                    .or_else(|| Some(Self::default_null_value()))
+                    == other
+                        .null_value
+                        .as_deref()
+                        .or_else(|| Some(Self::default_null_value()))
+                && self
+                    .date_format
+                    .as_deref()
+                    .or_else(|| Some(Self::default_date_format()))
+                    == other
+                        .date_format
+                        .as_deref()
+                        .or_else(|| Some(Self::default_date_format()))
+                && self
+                    .timestamp_format
+                    .as_deref()
+                    .or_else(|| Some(Self::default_timestamp_format()))
+                    == other
+                        .timestamp_format
+                        .as_deref()
+                        .or_else(|| Some(Self::default_timestamp_format()))
+                && self.schema == other.schema
+        }
+    }
+
+    /// Builder for [`ReadStepCsv`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ReadStepCsvBuilder {
+        ddl_schema: Option<Vec<String>>,
+        separator: Option<String>,
+        encoding: Option<String>,
+        quote: Option<String>,
+        escape: Option<String>,
+        header: Option<bool>,
+        infer_schema: Option<bool>,
+        null_value: Option<String>,
+        date_format: Option<String>,
+        timestamp_format: Option<String>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepCsv {
+        pub fn builder() -> ReadStepCsvBuilder {
+            ReadStepCsvBuilder {
+                ddl_schema: None,
+                separator: None,
+                encoding: None,
+                quote: None,
+                escape: None,
+                header: None,
+                infer_schema: None,
+                null_value: None,
+                date_format: None,
+                timestamp_format: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl ReadStepCsvBuilder {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub fn ddl_schema(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.ddl_schema = Some(v.into());
+            self
+        }
         /// Sets a single character as a separator for each field and value.
         ///
         /// Defaults to: ","
-        pub separator: Option<String>,
+        pub fn separator(mut self, v: impl Into<String>) -> Self {
+            self.separator = Some(v.into());
+            self
+        }
         /// Decodes the CSV files by the given encoding type.
         ///
         /// Defaults to: "utf8"
+        pub fn encoding(mut self, v: impl Into<String>) -> Self {
+            self.encoding = Some(v.into());
+            self
+        }
+        /// Sets a single character used for escaping quoted values where the separator can be part of the value. Set an empty string to turn off quotations.
+        ///
+        /// Defaults to: "\""
+        pub fn quote(mut self, v: impl Into<String>) -> Self {
+            self.quote = Some(v.into());
+            self
+        }
+        /// Sets a single character used for escaping quotes inside an already quoted value.
+        ///
+        /// Defaults to: "\\"
+        pub fn escape(mut self, v: impl Into<String>) -> Self {
+            self.escape = Some(v.into());
+            self
+        }
+        /// Use the first line as names of columns.
+        ///
+        /// Defaults to: false
+        pub fn header(mut self, v: impl Into<bool>) -> Self {
+            self.header = Some(v.into());
+            self
+        }
+        /// Infers the input schema automatically from data. It requires one extra pass over the data.
+        ///
+        /// Defaults to: false
+        pub fn infer_schema(mut self, v: impl Into<bool>) -> Self {
+            self.infer_schema = Some(v.into());
+            self
+        }
+        /// Sets the string representation of a null value.
+        ///
+        /// Defaults to: ""
+        pub fn null_value(mut self, v: impl Into<String>) -> Self {
+            self.null_value = Some(v.into());
+            self
+        }
+        /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+        ///
+        /// Defaults to: "rfc3339"
+        pub fn date_format(mut self, v: impl Into<String>) -> Self {
+            self.date_format = Some(v.into());
+            self
+        }
+        /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+        ///
+        /// Defaults to: "rfc3339"
+        pub fn timestamp_format(mut self, v: impl Into<String>) -> Self {
+            self.timestamp_format = Some(v.into());
+            self
+        }
+        /// Schema used to coerce values into more appropriate data types.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl ReadStepCsvBuilder {
+        pub fn build(self) -> ReadStepCsv {
+            ReadStepCsv {
+                ddl_schema: self.ddl_schema,
+                separator: self.separator,
+                encoding: self.encoding,
+                quote: self.quote,
+                escape: self.escape,
+                header: self.header,
+                infer_schema: self.infer_schema,
+                null_value: self.null_value,
+                date_format: self.date_format,
+                timestamp_format: self.timestamp_format,
+                schema: self.schema,
+            }
+        }
+    }
+
+    /// Reader for ESRI Shapefile format.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/EsriShapefile
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct ReadStepEsriShapefile {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub ddl_schema: Option<Vec<String>>,
+        /// If the ZIP archive contains multiple shapefiles use this field to specify a sub-path to the desired `.shp` file. Can contain glob patterns to act as a filter.
+        pub sub_path: Option<String>,
+        /// Schema used to coerce values into more appropriate data types.
+        pub schema: Option<data::DataSchema>,
+    }
+
+    /// Builder for [`ReadStepEsriShapefile`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ReadStepEsriShapefileBuilder {
+        ddl_schema: Option<Vec<String>>,
+        sub_path: Option<String>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepEsriShapefile {
+        pub fn builder() -> ReadStepEsriShapefileBuilder {
+            ReadStepEsriShapefileBuilder {
+                ddl_schema: None,
+                sub_path: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl ReadStepEsriShapefileBuilder {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub fn ddl_schema(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.ddl_schema = Some(v.into());
+            self
+        }
+        /// If the ZIP archive contains multiple shapefiles use this field to specify a sub-path to the desired `.shp` file. Can contain glob patterns to act as a filter.
+        pub fn sub_path(mut self, v: impl Into<String>) -> Self {
+            self.sub_path = Some(v.into());
+            self
+        }
+        /// Schema used to coerce values into more appropriate data types.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl ReadStepEsriShapefileBuilder {
+        pub fn build(self) -> ReadStepEsriShapefile {
+            ReadStepEsriShapefile {
+                ddl_schema: self.ddl_schema,
+                sub_path: self.sub_path,
+                schema: self.schema,
+            }
+        }
+    }
+
+    /// Reader for GeoJSON files. It expects one `FeatureCollection` object in the root and will create a record per each `Feature` inside it extracting the properties into individual columns and leaving the feature geometry in its own column.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/GeoJson
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct ReadStepGeoJson {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub ddl_schema: Option<Vec<String>>,
+        /// Schema used to coerce values into more appropriate data types.
+        pub schema: Option<data::DataSchema>,
+    }
+
+    /// Builder for [`ReadStepGeoJson`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ReadStepGeoJsonBuilder {
+        ddl_schema: Option<Vec<String>>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepGeoJson {
+        pub fn builder() -> ReadStepGeoJsonBuilder {
+            ReadStepGeoJsonBuilder {
+                ddl_schema: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl ReadStepGeoJsonBuilder {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub fn ddl_schema(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.ddl_schema = Some(v.into());
+            self
+        }
+        /// Schema used to coerce values into more appropriate data types.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl ReadStepGeoJsonBuilder {
+        pub fn build(self) -> ReadStepGeoJson {
+            ReadStepGeoJson {
+                ddl_schema: self.ddl_schema,
+                schema: self.schema,
+            }
+        }
+    }
+
+    /// Reader for JSON files that contain an array of objects within them.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Json
+    #[derive(Clone, Debug, Eq, Default)]
+    pub struct ReadStepJson {
+        /// Path in the form of `a.b.c` to a sub-element of the root JSON object that is an array or objects. If not specified it is assumed that the root element is an array.
+        pub sub_path: Option<String>,
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub ddl_schema: Option<Vec<String>>,
+        /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+        ///
+        /// Defaults to: "rfc3339"
+        pub date_format: Option<String>,
+        /// Allows to forcibly set one of standard basic or extended encodings.
+        ///
+        /// Defaults to: "utf8"
         pub encoding: Option<String>,
-        /// Sets a single character used for escaping quoted values where the separator can be part of the value. Set an empty string to turn off quotations.
-        ///
-        /// Defaults to: "\""
-        pub quote: Option<String>,
-        /// Sets a single character used for escaping quotes inside an already quoted value.
+        /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
         ///
-        /// Defaults to: "\\"
-        pub escape: Option<String>,
-        /// Use the first line as names of columns.
+        /// Defaults to: "rfc3339"
+        pub timestamp_format: Option<String>,
+        /// Schema used to coerce values into more appropriate data types.
+        pub schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepJson {
+        pub fn default_date_format() -> &'static str {
+            "rfc3339"
+        }
+        pub fn date_format(&self) -> &str {
+            self.date_format
+                .as_deref()
+                .unwrap_or(Self::default_date_format())
+        }
+        pub fn default_encoding() -> &'static str {
+            "utf8"
+        }
+        pub fn encoding(&self) -> &str {
+            self.encoding.as_deref().unwrap_or(Self::default_encoding())
+        }
+        pub fn default_timestamp_format() -> &'static str {
+            "rfc3339"
+        }
+        pub fn timestamp_format(&self) -> &str {
+            self.timestamp_format
+                .as_deref()
+                .unwrap_or(Self::default_timestamp_format())
+        }
+    }
+
+    impl PartialEq for ReadStepJson {
+        fn eq(&self, other: &Self) -> bool {
+            self.sub_path == other.sub_path
+                && self.ddl_schema == other.ddl_schema
+                && self
+                    .date_format
+                    .as_deref()
+                    .or_else(|| Some(Self::default_date_format()))
+                    == other
+                        .date_format
+                        .as_deref()
+                        .or_else(|| Some(Self::default_date_format()))
+                && self
+                    .encoding
+                    .as_deref()
+                    .or_else(|| Some(Self::default_encoding()))
+                    == other
+                        .encoding
+                        .as_deref()
+                        .or_else(|| Some(Self::default_encoding()))
+                && self
+                    .timestamp_format
+                    .as_deref()
+                    .or_else(|| Some(Self::default_timestamp_format()))
+                    == other
+                        .timestamp_format
+                        .as_deref()
+                        .or_else(|| Some(Self::default_timestamp_format()))
+                && self.schema == other.schema
+        }
+    }
+
+    /// Builder for [`ReadStepJson`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ReadStepJsonBuilder {
+        sub_path: Option<String>,
+        ddl_schema: Option<Vec<String>>,
+        date_format: Option<String>,
+        encoding: Option<String>,
+        timestamp_format: Option<String>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepJson {
+        pub fn builder() -> ReadStepJsonBuilder {
+            ReadStepJsonBuilder {
+                sub_path: None,
+                ddl_schema: None,
+                date_format: None,
+                encoding: None,
+                timestamp_format: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl ReadStepJsonBuilder {
+        /// Path in the form of `a.b.c` to a sub-element of the root JSON object that is an array or objects. If not specified it is assumed that the root element is an array.
+        pub fn sub_path(mut self, v: impl Into<String>) -> Self {
+            self.sub_path = Some(v.into());
+            self
+        }
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub fn ddl_schema(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.ddl_schema = Some(v.into());
+            self
+        }
+        /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
         ///
-        /// Defaults to: false
-        pub header: Option<bool>,
-        /// Infers the input schema automatically from data. It requires one extra pass over the data.
+        /// Defaults to: "rfc3339"
+        pub fn date_format(mut self, v: impl Into<String>) -> Self {
+            self.date_format = Some(v.into());
+            self
+        }
+        /// Allows to forcibly set one of standard basic or extended encodings.
         ///
-        /// Defaults to: false
-        pub infer_schema: Option<bool>,
-        /// Sets the string representation of a null value.
+        /// Defaults to: "utf8"
+        pub fn encoding(mut self, v: impl Into<String>) -> Self {
+            self.encoding = Some(v.into());
+            self
+        }
+        /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
         ///
-        /// Defaults to: ""
-        pub null_value: Option<String>,
+        /// Defaults to: "rfc3339"
+        pub fn timestamp_format(mut self, v: impl Into<String>) -> Self {
+            self.timestamp_format = Some(v.into());
+            self
+        }
+        /// Schema used to coerce values into more appropriate data types.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl ReadStepJsonBuilder {
+        pub fn build(self) -> ReadStepJson {
+            ReadStepJson {
+                sub_path: self.sub_path,
+                ddl_schema: self.ddl_schema,
+                date_format: self.date_format,
+                encoding: self.encoding,
+                timestamp_format: self.timestamp_format,
+                schema: self.schema,
+            }
+        }
+    }
+
+    /// Reader for Newline-delimited GeoJSON files. It is similar to `GeoJson` format but instead of `FeatureCollection` object in the root it expects every individual feature object to appear on its own line.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdGeoJson
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct ReadStepNdGeoJson {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub ddl_schema: Option<Vec<String>>,
+        /// Schema used to coerce values into more appropriate data types.
+        pub schema: Option<data::DataSchema>,
+    }
+
+    /// Builder for [`ReadStepNdGeoJson`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ReadStepNdGeoJsonBuilder {
+        ddl_schema: Option<Vec<String>>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepNdGeoJson {
+        pub fn builder() -> ReadStepNdGeoJsonBuilder {
+            ReadStepNdGeoJsonBuilder {
+                ddl_schema: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl ReadStepNdGeoJsonBuilder {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub fn ddl_schema(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.ddl_schema = Some(v.into());
+            self
+        }
+        /// Schema used to coerce values into more appropriate data types.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl ReadStepNdGeoJsonBuilder {
+        pub fn build(self) -> ReadStepNdGeoJson {
+            ReadStepNdGeoJson {
+                ddl_schema: self.ddl_schema,
+                schema: self.schema,
+            }
+        }
+    }
+
+    /// Reader for files containing multiple newline-delimited JSON objects with the same schema.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdJson
+    #[derive(Clone, Debug, Eq, Default)]
+    pub struct ReadStepNdJson {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub ddl_schema: Option<Vec<String>>,
         /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
         ///
         /// Defaults to: "rfc3339"
         pub date_format: Option<String>,
+        /// Allows to forcibly set one of standard basic or extended encodings.
+        ///
+        /// Defaults to: "utf8"
+        pub encoding: Option<String>,
         /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
         ///
         /// Defaults to: "rfc3339"
         pub timestamp_format: Option<String>,
         /// Schema used to coerce values into more appropriate data types.
-        pub schema: Option<data::DataSchema>,
+        pub schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepNdJson {
+        pub fn default_date_format() -> &'static str {
+            "rfc3339"
+        }
+        pub fn date_format(&self) -> &str {
+            self.date_format
+                .as_deref()
+                .unwrap_or(Self::default_date_format())
+        }
+        pub fn default_encoding() -> &'static str {
+            "utf8"
+        }
+        pub fn encoding(&self) -> &str {
+            self.encoding.as_deref().unwrap_or(Self::default_encoding())
+        }
+        pub fn default_timestamp_format() -> &'static str {
+            "rfc3339"
+        }
+        pub fn timestamp_format(&self) -> &str {
+            self.timestamp_format
+                .as_deref()
+                .unwrap_or(Self::default_timestamp_format())
+        }
+    }
+
+    impl PartialEq for ReadStepNdJson {
+        fn eq(&self, other: &Self) -> bool {
+            self.ddl_schema == other.ddl_schema
+                && self
+                    .date_format
+                    .as_deref()
+                    .or_else(|| Some(Self::default_date_format()))
+                    == other
+                        .date_format
+                        .as_deref()
+                        .or_else(|| Some(Self::default_date_format()))
+                && self
+                    .encoding
+                    .as_deref()
+                    .or_else(|| Some(Self::default_encoding()))
+                    == other
+                        .encoding
+                        .as_deref()
+                        .or_else(|| Some(Self::default_encoding()))
+                && self
+                    .timestamp_format
+                    .as_deref()
+                    .or_else(|| Some(Self::default_timestamp_format()))
+                    == other
+                        .timestamp_format
+                        .as_deref()
+                        .or_else(|| Some(Self::default_timestamp_format()))
+                && self.schema == other.schema
+        }
+    }
+
+    /// Builder for [`ReadStepNdJson`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ReadStepNdJsonBuilder {
+        ddl_schema: Option<Vec<String>>,
+        date_format: Option<String>,
+        encoding: Option<String>,
+        timestamp_format: Option<String>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepNdJson {
+        pub fn builder() -> ReadStepNdJsonBuilder {
+            ReadStepNdJsonBuilder {
+                ddl_schema: None,
+                date_format: None,
+                encoding: None,
+                timestamp_format: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl ReadStepNdJsonBuilder {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub fn ddl_schema(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.ddl_schema = Some(v.into());
+            self
+        }
+        /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+        ///
+        /// Defaults to: "rfc3339"
+        pub fn date_format(mut self, v: impl Into<String>) -> Self {
+            self.date_format = Some(v.into());
+            self
+        }
+        /// Allows to forcibly set one of standard basic or extended encodings.
+        ///
+        /// Defaults to: "utf8"
+        pub fn encoding(mut self, v: impl Into<String>) -> Self {
+            self.encoding = Some(v.into());
+            self
+        }
+        /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+        ///
+        /// Defaults to: "rfc3339"
+        pub fn timestamp_format(mut self, v: impl Into<String>) -> Self {
+            self.timestamp_format = Some(v.into());
+            self
+        }
+        /// Schema used to coerce values into more appropriate data types.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl ReadStepNdJsonBuilder {
+        pub fn build(self) -> ReadStepNdJson {
+            ReadStepNdJson {
+                ddl_schema: self.ddl_schema,
+                date_format: self.date_format,
+                encoding: self.encoding,
+                timestamp_format: self.timestamp_format,
+                schema: self.schema,
+            }
+        }
+    }
+
+    /// Reader for Apache Parquet format.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Parquet
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct ReadStepParquet {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub ddl_schema: Option<Vec<String>>,
+        /// Schema used to coerce values into more appropriate data types.
+        pub schema: Option<data::DataSchema>,
+    }
+
+    /// Builder for [`ReadStepParquet`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct ReadStepParquetBuilder {
+        ddl_schema: Option<Vec<String>>,
+        schema: Option<data::DataSchema>,
+    }
+
+    impl ReadStepParquet {
+        pub fn builder() -> ReadStepParquetBuilder {
+            ReadStepParquetBuilder {
+                ddl_schema: None,
+                schema: None,
+            }
+        }
+    }
+
+    impl ReadStepParquetBuilder {
+        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+        pub fn ddl_schema(mut self, v: impl Into<Vec<String>>) -> Self {
+            self.ddl_schema = Some(v.into());
+            self
+        }
+        /// Schema used to coerce values into more appropriate data types.
+        pub fn schema(mut self, v: impl Into<data::DataSchema>) -> Self {
+            self.schema = Some(v.into());
+            self
+        }
+    }
+
+    impl ReadStepParquetBuilder {
+        pub fn build(self) -> ReadStepParquet {
+            ReadStepParquet {
+                ddl_schema: self.ddl_schema,
+                schema: self.schema,
+            }
+        }
+    }
+
+    /// Defines a header (e.g. HTTP) to be passed into some request.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/RequestHeader
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct RequestHeader {
+        /// Name of the header.
+        pub name: String,
+        /// Value of the header.
+        pub value: String,
+    }
+
+    /// Builder for [`RequestHeader`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct RequestHeaderBuilder<TName = Unset, TValue = Unset> {
+        name: TName,
+        value: TValue,
+    }
+
+    impl RequestHeader {
+        pub fn builder() -> RequestHeaderBuilder<Unset, Unset> {
+            RequestHeaderBuilder {
+                name: Unset,
+                value: Unset,
+            }
+        }
+    }
+
+    impl<TValue> RequestHeaderBuilder<Unset, TValue> {
+        /// Name of the header.
+        pub fn name(self, v: impl Into<String>) -> RequestHeaderBuilder<String, TValue> {
+            RequestHeaderBuilder {
+                name: v.into(),
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TName> RequestHeaderBuilder<TName, Unset> {
+        /// Value of the header.
+        pub fn value(self, v: impl Into<String>) -> RequestHeaderBuilder<TName, String> {
+            RequestHeaderBuilder {
+                name: self.name,
+                value: v.into(),
+            }
+        }
+    }
+
+    impl RequestHeaderBuilder<String, String> {
+        pub fn build(self) -> RequestHeader {
+            RequestHeader {
+                name: self.name,
+                value: self.value,
+            }
+        }
+    }
+
+    /// Defines an external source of data for ingestion.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Source
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct Source {
+        /// Container for identity and ownership information of a resource.
+        pub headers: resource::ResourceHeadersInput,
+        /// Specifies the desired state of the resource.
+        pub spec: source::SourceSpecInput,
+    }
+
+    impl Source {
+        pub fn schema() -> &'static TypeUri {
+            &SOURCE_SCHEMA
+        }
+        pub const fn schema_str() -> &'static str {
+            SOURCE_SCHEMA_STR
+        }
+    }
+
+    static SOURCE_SCHEMA_STR: &str = "https://opendatafabric.org/schemas/source/v1alpha1/Source";
+
+    static SOURCE_SCHEMA: std::sync::LazyLock<TypeUri> =
+        std::sync::LazyLock::new(|| TypeUri::new_unchecked(SOURCE_SCHEMA_STR));
+
+    /// Builder for [`Source`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SourceBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl Source {
+        pub fn builder() -> SourceBuilder<Unset, Unset> {
+            SourceBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> SourceBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> SourceBuilder<resource::ResourceHeadersInput, TSpec> {
+            SourceBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> SourceBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the resource.
+        pub fn spec(
+            self,
+            v: impl Into<source::SourceSpecInput>,
+        ) -> SourceBuilder<THeaders, source::SourceSpecInput> {
+            SourceBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl SourceBuilder<resource::ResourceHeadersInput, source::SourceSpecInput> {
+        pub fn build(self) -> Source {
+            Source {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl Redact for Source {
+        fn redact(&self) -> Self {
+            Self {
+                headers: self.headers.clone(),
+                spec: self.spec.redact(),
+            }
+        }
+    }
+
+    /// Defines how external data should be cached.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum SourceCaching {
+        Forever(source::SourceCachingForever),
+    }
+
+    impl_enum_with_variants!(SourceCaching);
+    impl_enum_variant!(SourceCaching::Forever(source::SourceCachingForever));
+
+    /// After source was processed once it will never be ingested again.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching#/$defs/Forever
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct SourceCachingForever {}
+
+    /// Specifies how input files should be ordered before ingestion.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceOrdering
+    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
+    pub enum SourceOrdering {
+        ByEventTime,
+        ByName,
+    }
+
+    /// Specifies an external source of data for ingestion.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpec
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct SourceSpec {
+        /// Brings the configuration values into the local `config` context.
+        pub config: Option<config::ValueRefs>,
+        /// Determines where data is sourced from.
+        pub ingress: Option<source::Ingress>,
+        /// Defines how raw data is prepared before reading.
+        pub prepare: Option<Vec<source::PrepStep>>,
+        /// Defines how data is read into structured format.
+        pub read: source::ReadStep,
+        /// Pre-processing query that shapes the data.
+        pub preprocess: Option<dataset::Transform>,
+        /// Determines how newly-ingested data should be merged with existing history.
+        pub merge: Option<source::MergeStrategy>,
+        /// Defines the mapping of system fields to dataset column names.
+        pub vocab: Option<dataset::DatasetVocabulary>,
+    }
+
+    /// Builder for [`SourceSpec`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SourceSpecBuilder<TRead = Unset> {
+        config: Option<config::ValueRefs>,
+        ingress: Option<source::Ingress>,
+        prepare: Option<Vec<source::PrepStep>>,
+        read: TRead,
+        preprocess: Option<dataset::Transform>,
+        merge: Option<source::MergeStrategy>,
+        vocab: Option<dataset::DatasetVocabulary>,
+    }
+
+    impl SourceSpec {
+        pub fn builder() -> SourceSpecBuilder<Unset> {
+            SourceSpecBuilder {
+                config: None,
+                ingress: None,
+                prepare: None,
+                read: Unset,
+                preprocess: None,
+                merge: None,
+                vocab: None,
+            }
+        }
+    }
+
+    impl SourceSpecBuilder<Unset> {
+        /// Defines how data is read into structured format.
+        pub fn read(self, v: impl Into<source::ReadStep>) -> SourceSpecBuilder<source::ReadStep> {
+            SourceSpecBuilder {
+                config: self.config,
+                ingress: self.ingress,
+                prepare: self.prepare,
+                read: v.into(),
+                preprocess: self.preprocess,
+                merge: self.merge,
+                vocab: self.vocab,
+            }
+        }
+    }
+
+    impl<TRead> SourceSpecBuilder<TRead> {
+        /// Brings the configuration values into the local `config` context.
+        pub fn config(mut self, v: impl Into<config::ValueRefs>) -> Self {
+            self.config = Some(v.into());
+            self
+        }
+        /// Determines where data is sourced from.
+        pub fn ingress(mut self, v: impl Into<source::Ingress>) -> Self {
+            self.ingress = Some(v.into());
+            self
+        }
+        /// Defines how raw data is prepared before reading.
+        pub fn prepare(mut self, v: impl Into<Vec<source::PrepStep>>) -> Self {
+            self.prepare = Some(v.into());
+            self
+        }
+        /// Pre-processing query that shapes the data.
+        pub fn preprocess(mut self, v: impl Into<dataset::Transform>) -> Self {
+            self.preprocess = Some(v.into());
+            self
+        }
+        /// Determines how newly-ingested data should be merged with existing history.
+        pub fn merge(mut self, v: impl Into<source::MergeStrategy>) -> Self {
+            self.merge = Some(v.into());
+            self
+        }
+        /// Defines the mapping of system fields to dataset column names.
+        pub fn vocab(mut self, v: impl Into<dataset::DatasetVocabulary>) -> Self {
+            self.vocab = Some(v.into());
+            self
+        }
+    }
+
+    impl SourceSpecBuilder<source::ReadStep> {
+        pub fn build(self) -> SourceSpec {
+            SourceSpec {
+                config: self.config,
+                ingress: self.ingress,
+                prepare: self.prepare,
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: self.merge,
+                vocab: self.vocab,
+            }
+        }
+    }
+
+    impl Redact for SourceSpec {
+        fn redact(&self) -> Self {
+            Self {
+                config: self.config.clone(),
+                ingress: self.ingress.redact(),
+                prepare: self.prepare.clone(),
+                read: self.read.clone(),
+                preprocess: self.preprocess.clone(),
+                merge: self.merge.clone(),
+                vocab: self.vocab.clone(),
+            }
+        }
+    }
+
+    /// Specifies an external source of data for ingestion.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpecInput
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct SourceSpecInput {
+        /// Brings the configuration values into the local `config` context.
+        pub config: Option<config::ValueRefs>,
+        /// Determines where data is sourced from.
+        pub ingress: Option<source::Ingress>,
+        /// Defines how raw data is prepared before reading.
+        pub prepare: Option<Vec<source::PrepStep>>,
+        /// Defines how data is read into structured format.
+        pub read: source::ReadStep,
+        /// Pre-processing query that shapes the data.
+        pub preprocess: Option<dataset::Transform>,
+        /// Determines how newly-ingested data should be merged with existing history.
+        pub merge: Option<source::MergeStrategy>,
+        /// Defines the mapping of system fields to dataset column names.
+        pub vocab: Option<dataset::DatasetVocabulary>,
+    }
+
+    /// Builder for [`SourceSpecInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SourceSpecInputBuilder<TRead = Unset> {
+        config: Option<config::ValueRefs>,
+        ingress: Option<source::Ingress>,
+        prepare: Option<Vec<source::PrepStep>>,
+        read: TRead,
+        preprocess: Option<dataset::Transform>,
+        merge: Option<source::MergeStrategy>,
+        vocab: Option<dataset::DatasetVocabulary>,
+    }
+
+    impl SourceSpecInput {
+        pub fn builder() -> SourceSpecInputBuilder<Unset> {
+            SourceSpecInputBuilder {
+                config: None,
+                ingress: None,
+                prepare: None,
+                read: Unset,
+                preprocess: None,
+                merge: None,
+                vocab: None,
+            }
+        }
+    }
+
+    impl SourceSpecInputBuilder<Unset> {
+        /// Defines how data is read into structured format.
+        pub fn read(
+            self,
+            v: impl Into<source::ReadStep>,
+        ) -> SourceSpecInputBuilder<source::ReadStep> {
+            SourceSpecInputBuilder {
+                config: self.config,
+                ingress: self.ingress,
+                prepare: self.prepare,
+                read: v.into(),
+                preprocess: self.preprocess,
+                merge: self.merge,
+                vocab: self.vocab,
+            }
+        }
+    }
+
+    impl<TRead> SourceSpecInputBuilder<TRead> {
+        /// Brings the configuration values into the local `config` context.
+        pub fn config(mut self, v: impl Into<config::ValueRefs>) -> Self {
+            self.config = Some(v.into());
+            self
+        }
+        /// Determines where data is sourced from.
+        pub fn ingress(mut self, v: impl Into<source::Ingress>) -> Self {
+            self.ingress = Some(v.into());
+            self
+        }
+        /// Defines how raw data is prepared before reading.
+        pub fn prepare(mut self, v: impl Into<Vec<source::PrepStep>>) -> Self {
+            self.prepare = Some(v.into());
+            self
+        }
+        /// Pre-processing query that shapes the data.
+        pub fn preprocess(mut self, v: impl Into<dataset::Transform>) -> Self {
+            self.preprocess = Some(v.into());
+            self
+        }
+        /// Determines how newly-ingested data should be merged with existing history.
+        pub fn merge(mut self, v: impl Into<source::MergeStrategy>) -> Self {
+            self.merge = Some(v.into());
+            self
+        }
+        /// Defines the mapping of system fields to dataset column names.
+        pub fn vocab(mut self, v: impl Into<dataset::DatasetVocabulary>) -> Self {
+            self.vocab = Some(v.into());
+            self
+        }
+    }
+
+    impl SourceSpecInputBuilder<source::ReadStep> {
+        pub fn build(self) -> SourceSpecInput {
+            SourceSpecInput {
+                config: self.config,
+                ingress: self.ingress,
+                prepare: self.prepare,
+                read: self.read,
+                preprocess: self.preprocess,
+                merge: self.merge,
+                vocab: self.vocab,
+            }
+        }
+    }
+
+    impl Redact for SourceSpecInput {
+        fn redact(&self) -> Self {
+            Self {
+                config: self.config.clone(),
+                ingress: self.ingress.redact(),
+                prepare: self.prepare.clone(),
+                read: self.read.clone(),
+                preprocess: self.preprocess.clone(),
+                merge: self.merge.clone(),
+                vocab: self.vocab.clone(),
+            }
+        }
+    }
+
+    /// The state of the source the data was added from to allow fast resuming.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceState
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct SourceState {
+        /// Identifies the source that the state corresponds to.
+        pub source_name: String,
+        /// Identifies the type of the state. Standard types include: `odf/etag`, `odf/last-modified`.
+        pub kind: String,
+        /// Opaque value representing the state.
+        pub value: String,
+    }
+
+    /// Builder for [`SourceState`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct SourceStateBuilder<TSourceName = Unset, TKind = Unset, TValue = Unset> {
+        source_name: TSourceName,
+        kind: TKind,
+        value: TValue,
+    }
+
+    impl SourceState {
+        pub fn builder() -> SourceStateBuilder<Unset, Unset, Unset> {
+            SourceStateBuilder {
+                source_name: Unset,
+                kind: Unset,
+                value: Unset,
+            }
+        }
+    }
+
+    impl<TKind, TValue> SourceStateBuilder<Unset, TKind, TValue> {
+        /// Identifies the source that the state corresponds to.
+        pub fn source_name(
+            self,
+            v: impl Into<String>,
+        ) -> SourceStateBuilder<String, TKind, TValue> {
+            SourceStateBuilder {
+                source_name: v.into(),
+                kind: self.kind,
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TSourceName, TValue> SourceStateBuilder<TSourceName, Unset, TValue> {
+        /// Identifies the type of the state. Standard types include: `odf/etag`, `odf/last-modified`.
+        pub fn kind(self, v: impl Into<String>) -> SourceStateBuilder<TSourceName, String, TValue> {
+            SourceStateBuilder {
+                source_name: self.source_name,
+                kind: v.into(),
+                value: self.value,
+            }
+        }
+    }
+
+    impl<TSourceName, TKind> SourceStateBuilder<TSourceName, TKind, Unset> {
+        /// Opaque value representing the state.
+        pub fn value(self, v: impl Into<String>) -> SourceStateBuilder<TSourceName, TKind, String> {
+            SourceStateBuilder {
+                source_name: self.source_name,
+                kind: self.kind,
+                value: v.into(),
+            }
+        }
+    }
+
+    impl SourceStateBuilder<String, String, String> {
+        pub fn build(self) -> SourceState {
+            SourceState {
+                source_name: self.source_name,
+                kind: self.kind,
+                value: self.value,
+            }
+        }
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// storage
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod storage {
+    #[allow(unused_imports)]
+    use super::*;
+
+    /// Access credentials for AWS or an AWS-compatible service.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentials
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct AwsCredentials {
+        /// Reference to a secret containing the AWS access key ID.
+        pub access_key: Option<config::ValueHandle>,
+        /// Reference to a secret containing the AWS secret access key.
+        pub secret_key: Option<config::ValueHandle>,
+    }
+
+    /// Builder for [`AwsCredentials`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AwsCredentialsBuilder {
+        access_key: Option<config::ValueHandle>,
+        secret_key: Option<config::ValueHandle>,
+    }
+
+    impl AwsCredentials {
+        pub fn builder() -> AwsCredentialsBuilder {
+            AwsCredentialsBuilder {
+                access_key: None,
+                secret_key: None,
+            }
+        }
+    }
+
+    impl AwsCredentialsBuilder {
+        /// Reference to a secret containing the AWS access key ID.
+        pub fn access_key(mut self, v: impl Into<config::ValueHandle>) -> Self {
+            self.access_key = Some(v.into());
+            self
+        }
+        /// Reference to a secret containing the AWS secret access key.
+        pub fn secret_key(mut self, v: impl Into<config::ValueHandle>) -> Self {
+            self.secret_key = Some(v.into());
+            self
+        }
+    }
+
+    impl AwsCredentialsBuilder {
+        pub fn build(self) -> AwsCredentials {
+            AwsCredentials {
+                access_key: self.access_key,
+                secret_key: self.secret_key,
+            }
+        }
+    }
+
+    /// Access credentials for AWS or an AWS-compatible service.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentialsInput
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct AwsCredentialsInput {
+        /// Reference to a secret containing the AWS access key ID.
+        pub access_key: Option<config::ValueRef>,
+        /// Reference to a secret containing the AWS secret access key.
+        pub secret_key: Option<config::ValueRef>,
+    }
+
+    /// Builder for [`AwsCredentialsInput`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct AwsCredentialsInputBuilder {
+        access_key: Option<config::ValueRef>,
+        secret_key: Option<config::ValueRef>,
+    }
+
+    impl AwsCredentialsInput {
+        pub fn builder() -> AwsCredentialsInputBuilder {
+            AwsCredentialsInputBuilder {
+                access_key: None,
+                secret_key: None,
+            }
+        }
+    }
+
+    impl AwsCredentialsInputBuilder {
+        /// Reference to a secret containing the AWS access key ID.
+        pub fn access_key(mut self, v: impl Into<config::ValueRef>) -> Self {
+            self.access_key = Some(v.into());
+            self
+        }
+        /// Reference to a secret containing the AWS secret access key.
+        pub fn secret_key(mut self, v: impl Into<config::ValueRef>) -> Self {
+            self.secret_key = Some(v.into());
+            self
+        }
+    }
+
+    impl AwsCredentialsInputBuilder {
+        pub fn build(self) -> AwsCredentialsInput {
+            AwsCredentialsInput {
+                access_key: self.access_key,
+                secret_key: self.secret_key,
+            }
+        }
+    }
+
+    /// Defines a storage volume where data can be stored and its access credentials.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolume
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct PersistentVolume {
+        /// Container for identity and ownership information of a resource.
+        pub headers: resource::ResourceHeadersInput,
+        /// Specifies the desired state of the resource.
+        pub spec: storage::PersistentVolumeSpecInput,
+    }
+
+    impl PersistentVolume {
+        pub fn schema() -> &'static TypeUri {
+            &PERSISTENT_VOLUME_SCHEMA
+        }
+        pub const fn schema_str() -> &'static str {
+            PERSISTENT_VOLUME_SCHEMA_STR
+        }
+    }
+
+    static PERSISTENT_VOLUME_SCHEMA_STR: &str =
+        "https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolume";
+
+    static PERSISTENT_VOLUME_SCHEMA: std::sync::LazyLock<TypeUri> =
+        std::sync::LazyLock::new(|| TypeUri::new_unchecked(PERSISTENT_VOLUME_SCHEMA_STR));
+
+    /// Builder for [`PersistentVolume`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PersistentVolumeBuilder<THeaders = Unset, TSpec = Unset> {
+        headers: THeaders,
+        spec: TSpec,
+    }
+
+    impl PersistentVolume {
+        pub fn builder() -> PersistentVolumeBuilder<Unset, Unset> {
+            PersistentVolumeBuilder {
+                headers: Unset,
+                spec: Unset,
+            }
+        }
+    }
+
+    impl<TSpec> PersistentVolumeBuilder<Unset, TSpec> {
+        /// Container for identity and ownership information of a resource.
+        pub fn headers(
+            self,
+            v: impl Into<resource::ResourceHeadersInput>,
+        ) -> PersistentVolumeBuilder<resource::ResourceHeadersInput, TSpec> {
+            PersistentVolumeBuilder {
+                headers: v.into(),
+                spec: self.spec,
+            }
+        }
+    }
+
+    impl<THeaders> PersistentVolumeBuilder<THeaders, Unset> {
+        /// Specifies the desired state of the resource.
+        pub fn spec(
+            self,
+            v: impl Into<storage::PersistentVolumeSpecInput>,
+        ) -> PersistentVolumeBuilder<THeaders, storage::PersistentVolumeSpecInput> {
+            PersistentVolumeBuilder {
+                headers: self.headers,
+                spec: v.into(),
+            }
+        }
+    }
+
+    impl PersistentVolumeBuilder<resource::ResourceHeadersInput, storage::PersistentVolumeSpecInput> {
+        pub fn build(self) -> PersistentVolume {
+            PersistentVolume {
+                headers: self.headers,
+                spec: self.spec,
+            }
+        }
+    }
+
+    /// Reference to a `PersistentVolume`.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeRef
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct PersistentVolumeRef {
+        /// Reference to an account that owns the `PersistentVolume`.
+        pub account: Option<auth::AccountRef>,
+        /// ID of the resource.
+        pub id: Option<ResourceID>,
+        /// Name of the resource.
+        pub name: Option<ResourceName>,
+    }
+    impl IntoResourceRef for PersistentVolumeRef {}
+
+    /// Builder for [`PersistentVolumeRef`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PersistentVolumeRefBuilder {
+        account: Option<auth::AccountRef>,
+        id: Option<ResourceID>,
+        name: Option<ResourceName>,
+    }
+
+    impl PersistentVolumeRef {
+        pub fn builder() -> PersistentVolumeRefBuilder {
+            PersistentVolumeRefBuilder {
+                account: None,
+                id: None,
+                name: None,
+            }
+        }
+    }
+
+    impl PersistentVolumeRefBuilder {
+        /// Reference to an account that owns the `PersistentVolume`.
+        pub fn account(mut self, v: impl Into<auth::AccountRef>) -> Self {
+            self.account = Some(v.into());
+            self
+        }
+        /// ID of the resource.
+        pub fn id(mut self, v: impl Into<ResourceID>) -> Self {
+            self.id = Some(v.into());
+            self
+        }
+        /// Name of the resource.
+        pub fn name(mut self, v: impl Into<ResourceName>) -> Self {
+            self.name = Some(v.into());
+            self
+        }
+    }
+
+    impl PersistentVolumeRefBuilder {
+        pub fn build(self) -> PersistentVolumeRef {
+            PersistentVolumeRef {
+                account: self.account,
+                id: self.id,
+                name: self.name,
+            }
+        }
+    }
+
+    /// Defines a storage volume where data can be stored and its access credentials.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum PersistentVolumeSpec {
+        S3(storage::PersistentVolumeSpecS3),
+    }
+
+    impl_enum_with_variants!(PersistentVolumeSpec);
+    impl_enum_variant!(PersistentVolumeSpec::S3(storage::PersistentVolumeSpecS3));
+
+    /// Defines a storage volume where data can be stored and its access credentials.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum PersistentVolumeSpecInput {
+        S3(storage::PersistentVolumeSpecInputS3),
+    }
+
+    impl_enum_with_variants!(PersistentVolumeSpecInput);
+    impl_enum_variant!(PersistentVolumeSpecInput::S3(
+        storage::PersistentVolumeSpecInputS3
+    ));
+
+    /// An Amazon S3 or S3-compatible object storage bucket.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput#/$defs/S3
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct PersistentVolumeSpecInputS3 {
+        /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
+        pub endpoint: Option<String>,
+        /// AWS region where the bucket is located e.g. `us-west-2`.
+        pub region: Option<String>,
+        /// Name of the S3 bucket.
+        pub bucket: String,
+        /// Optional path prefix within the bucket.
+        pub prefix: Option<String>,
+        /// Storage capacity allocation.
+        pub capacity: Option<storage::VolumeCapacity>,
+        /// Access credentials for the bucket.
+        pub credentials: Option<storage::AwsCredentialsInput>,
+    }
+
+    /// Builder for [`PersistentVolumeSpecInputS3`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PersistentVolumeSpecInputS3Builder<TBucket = Unset> {
+        endpoint: Option<String>,
+        region: Option<String>,
+        bucket: TBucket,
+        prefix: Option<String>,
+        capacity: Option<storage::VolumeCapacity>,
+        credentials: Option<storage::AwsCredentialsInput>,
+    }
+
+    impl PersistentVolumeSpecInputS3 {
+        pub fn builder() -> PersistentVolumeSpecInputS3Builder<Unset> {
+            PersistentVolumeSpecInputS3Builder {
+                endpoint: None,
+                region: None,
+                bucket: Unset,
+                prefix: None,
+                capacity: None,
+                credentials: None,
+            }
+        }
+    }
+
+    impl PersistentVolumeSpecInputS3Builder<Unset> {
+        /// Name of the S3 bucket.
+        pub fn bucket(self, v: impl Into<String>) -> PersistentVolumeSpecInputS3Builder<String> {
+            PersistentVolumeSpecInputS3Builder {
+                endpoint: self.endpoint,
+                region: self.region,
+                bucket: v.into(),
+                prefix: self.prefix,
+                capacity: self.capacity,
+                credentials: self.credentials,
+            }
+        }
+    }
+
+    impl<TBucket> PersistentVolumeSpecInputS3Builder<TBucket> {
+        /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
+        pub fn endpoint(mut self, v: impl Into<String>) -> Self {
+            self.endpoint = Some(v.into());
+            self
+        }
+        /// AWS region where the bucket is located e.g. `us-west-2`.
+        pub fn region(mut self, v: impl Into<String>) -> Self {
+            self.region = Some(v.into());
+            self
+        }
+        /// Optional path prefix within the bucket.
+        pub fn prefix(mut self, v: impl Into<String>) -> Self {
+            self.prefix = Some(v.into());
+            self
+        }
+        /// Storage capacity allocation.
+        pub fn capacity(mut self, v: impl Into<storage::VolumeCapacity>) -> Self {
+            self.capacity = Some(v.into());
+            self
+        }
+        /// Access credentials for the bucket.
+        pub fn credentials(mut self, v: impl Into<storage::AwsCredentialsInput>) -> Self {
+            self.credentials = Some(v.into());
+            self
+        }
+    }
+
+    impl PersistentVolumeSpecInputS3Builder<String> {
+        pub fn build(self) -> PersistentVolumeSpecInputS3 {
+            PersistentVolumeSpecInputS3 {
+                endpoint: self.endpoint,
+                region: self.region,
+                bucket: self.bucket,
+                prefix: self.prefix,
+                capacity: self.capacity,
+                credentials: self.credentials,
+            }
+        }
+    }
+
+    /// An Amazon S3 or S3-compatible object storage bucket.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec#/$defs/S3
+    #[derive(Clone, Debug, Eq, PartialEq)]
+    pub struct PersistentVolumeSpecS3 {
+        /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
+        pub endpoint: Option<String>,
+        /// AWS region where the bucket is located e.g. `us-west-2`.
+        pub region: Option<String>,
+        /// Name of the S3 bucket.
+        pub bucket: String,
+        /// Optional path prefix within the bucket.
+        pub prefix: Option<String>,
+        /// Storage capacity allocation.
+        pub capacity: Option<storage::VolumeCapacity>,
+        /// Access credentials for the bucket.
+        pub credentials: Option<storage::AwsCredentials>,
+    }
+
+    /// Builder for [`PersistentVolumeSpecS3`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct PersistentVolumeSpecS3Builder<TBucket = Unset> {
+        endpoint: Option<String>,
+        region: Option<String>,
+        bucket: TBucket,
+        prefix: Option<String>,
+        capacity: Option<storage::VolumeCapacity>,
+        credentials: Option<storage::AwsCredentials>,
+    }
+
+    impl PersistentVolumeSpecS3 {
+        pub fn builder() -> PersistentVolumeSpecS3Builder<Unset> {
+            PersistentVolumeSpecS3Builder {
+                endpoint: None,
+                region: None,
+                bucket: Unset,
+                prefix: None,
+                capacity: None,
+                credentials: None,
+            }
+        }
+    }
+
+    impl PersistentVolumeSpecS3Builder<Unset> {
+        /// Name of the S3 bucket.
+        pub fn bucket(self, v: impl Into<String>) -> PersistentVolumeSpecS3Builder<String> {
+            PersistentVolumeSpecS3Builder {
+                endpoint: self.endpoint,
+                region: self.region,
+                bucket: v.into(),
+                prefix: self.prefix,
+                capacity: self.capacity,
+                credentials: self.credentials,
+            }
+        }
+    }
+
+    impl<TBucket> PersistentVolumeSpecS3Builder<TBucket> {
+        /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
+        pub fn endpoint(mut self, v: impl Into<String>) -> Self {
+            self.endpoint = Some(v.into());
+            self
+        }
+        /// AWS region where the bucket is located e.g. `us-west-2`.
+        pub fn region(mut self, v: impl Into<String>) -> Self {
+            self.region = Some(v.into());
+            self
+        }
+        /// Optional path prefix within the bucket.
+        pub fn prefix(mut self, v: impl Into<String>) -> Self {
+            self.prefix = Some(v.into());
+            self
+        }
+        /// Storage capacity allocation.
+        pub fn capacity(mut self, v: impl Into<storage::VolumeCapacity>) -> Self {
+            self.capacity = Some(v.into());
+            self
+        }
+        /// Access credentials for the bucket.
+        pub fn credentials(mut self, v: impl Into<storage::AwsCredentials>) -> Self {
+            self.credentials = Some(v.into());
+            self
+        }
+    }
+
+    impl PersistentVolumeSpecS3Builder<String> {
+        pub fn build(self) -> PersistentVolumeSpecS3 {
+            PersistentVolumeSpecS3 {
+                endpoint: self.endpoint,
+                region: self.region,
+                bucket: self.bucket,
+                prefix: self.prefix,
+                capacity: self.capacity,
+                credentials: self.credentials,
+            }
+        }
+    }
+
+    /// Storage capacity allocation.
+    ///
+    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/VolumeCapacity
+    #[derive(Clone, Debug, Eq, PartialEq, Default)]
+    pub struct VolumeCapacity {
+        /// Maximum storage size e.g. `10Gi`.
+        pub storage: Option<ByteSize>,
+    }
+
+    /// Builder for [`VolumeCapacity`] that requires all mandatory fields to be set before calling `build()`
+    #[must_use]
+    #[derive(Clone, Debug)]
+    pub struct VolumeCapacityBuilder {
+        storage: Option<ByteSize>,
+    }
+
+    impl VolumeCapacity {
+        pub fn builder() -> VolumeCapacityBuilder {
+            VolumeCapacityBuilder { storage: None }
+        }
+    }
+
+    impl VolumeCapacityBuilder {
+        /// Maximum storage size e.g. `10Gi`.
+        pub fn storage(mut self, v: impl Into<ByteSize>) -> Self {
+            self.storage = Some(v.into());
+            self
+        }
+    }
+
+    impl VolumeCapacityBuilder {
+        pub fn build(self) -> VolumeCapacity {
+            VolumeCapacity {
+                storage: self.storage,
+            }
+        }
+    }
+}
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Visitors
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod visit {
+    #[allow(unused_imports)]
+    use super::*;
+
+    /// Implemented by all DTOs to dispatch into the matching visitor method, e.g. for type parameters of generic types
+    pub trait Visitable {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V);
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V);
+    }
+
+    /// Read-only traversal of DTOs, e.g. to collect references.
+    ///
+    /// Default methods descend into all nested values, so implementations only override the ones they care about.
+    pub trait Visit {
+        fn visit_bool(&mut self, _v: &bool) {}
+        fn visit_i8(&mut self, _v: &i8) {}
+        fn visit_i16(&mut self, _v: &i16) {}
+        fn visit_i32(&mut self, _v: &i32) {}
+        fn visit_i64(&mut self, _v: &i64) {}
+        fn visit_u8(&mut self, _v: &u8) {}
+        fn visit_u16(&mut self, _v: &u16) {}
+        fn visit_u32(&mut self, _v: &u32) {}
+        fn visit_u64(&mut self, _v: &u64) {}
+        fn visit_string(&mut self, _v: &String) {}
+        fn visit_byte_size(&mut self, _v: &ByteSize) {}
+        fn visit_date_time(&mut self, _v: &DateTime<Utc>) {}
+        fn visit_duration(&mut self, _v: &DurationString) {}
+        fn visit_multicodec(&mut self, _v: &String) {}
+        fn visit_multihash(&mut self, _v: &Multihash) {}
+        fn visit_path(&mut self, _v: &PathBuf) {}
+        fn visit_regex(&mut self, _v: &String) {}
+        fn visit_url(&mut self, _v: &String) {}
+        fn visit_did(&mut self, _v: &Did) {}
+        fn visit_type_name(&mut self, _v: &TypeName) {}
+        fn visit_type_uri(&mut self, _v: &TypeUri) {}
+        fn visit_type_ref(&mut self, _v: &TypeRef) {}
+        fn visit_account_id(&mut self, _v: &AccountID) {}
+        fn visit_account_name(&mut self, _v: &AccountName) {}
+        fn visit_dataset_alias(&mut self, _v: &DatasetAlias) {}
+        fn visit_dataset_id(&mut self, _v: &DatasetID) {}
+        fn visit_dataset_ref(&mut self, _v: &DatasetRef) {}
+        fn visit_resource_id(&mut self, _v: &ResourceID) {}
+        fn visit_resource_name(&mut self, _v: &ResourceName) {}
+        fn visit_flatbuffers(&mut self, _v: &Vec<u8>) {}
+        fn visit_any_json(&mut self, _v: &serde_json::Value) {}
+
+        fn visit_auth_account(&mut self, v: &auth::Account) {
+            walk_auth_account(self, v)
+        }
+        fn visit_auth_account_handle(&mut self, v: &auth::AccountHandle) {
+            walk_auth_account_handle(self, v)
+        }
+        fn visit_auth_account_ref(&mut self, v: &auth::AccountRef) {
+            walk_auth_account_ref(self, v)
+        }
+        fn visit_auth_account_spec(&mut self, v: &auth::AccountSpec) {
+            walk_auth_account_spec(self, v)
+        }
+        fn visit_auth_account_spec_input(&mut self, v: &auth::AccountSpecInput) {
+            walk_auth_account_spec_input(self, v)
+        }
+        fn visit_auth_account_type(&mut self, _v: &auth::AccountType) {}
+        fn visit_auth_attribute(&mut self, v: &auth::Attribute) {
+            walk_auth_attribute(self, v)
+        }
+        fn visit_auth_attribute_input(&mut self, v: &auth::AttributeInput) {
+            walk_auth_attribute_input(self, v)
+        }
+        fn visit_auth_relation(&mut self, v: &auth::Relation) {
+            walk_auth_relation(self, v)
+        }
+        fn visit_auth_relation_input(&mut self, v: &auth::RelationInput) {
+            walk_auth_relation_input(self, v)
+        }
+        fn visit_auth_relations(&mut self, v: &auth::Relations) {
+            walk_auth_relations(self, v)
+        }
+        fn visit_auth_relations_spec(&mut self, v: &auth::RelationsSpec) {
+            walk_auth_relations_spec(self, v)
+        }
+        fn visit_auth_relations_spec_input(&mut self, v: &auth::RelationsSpecInput) {
+            walk_auth_relations_spec_input(self, v)
+        }
+        fn visit_config_secret(&mut self, v: &config::Secret) {
+            walk_config_secret(self, v)
+        }
+        fn visit_config_secret_set(&mut self, v: &config::SecretSet) {
+            walk_config_secret_set(self, v)
+        }
+        fn visit_config_secret_set_spec(&mut self, v: &config::SecretSetSpec) {
+            walk_config_secret_set_spec(self, v)
+        }
+        fn visit_config_secret_set_spec_input(&mut self, v: &config::SecretSetSpecInput) {
+            walk_config_secret_set_spec_input(self, v)
+        }
+        fn visit_config_secrets(&mut self, v: &config::Secrets) {
+            walk_config_secrets(self, v)
+        }
+        fn visit_config_value_handle(&mut self, v: &config::ValueHandle) {
+            walk_config_value_handle(self, v)
+        }
+        fn visit_config_value_ref(&mut self, v: &config::ValueRef) {
+            walk_config_value_ref(self, v)
+        }
+        fn visit_config_value_refs(&mut self, v: &config::ValueRefs) {
+            walk_config_value_refs(self, v)
+        }
+        fn visit_config_variable(&mut self, v: &config::Variable) {
+            walk_config_variable(self, v)
+        }
+        fn visit_config_variable_set(&mut self, v: &config::VariableSet) {
+            walk_config_variable_set(self, v)
+        }
+        fn visit_config_variable_set_spec(&mut self, v: &config::VariableSetSpec) {
+            walk_config_variable_set_spec(self, v)
+        }
+        fn visit_config_variable_set_spec_input(&mut self, v: &config::VariableSetSpecInput) {
+            walk_config_variable_set_spec_input(self, v)
+        }
+        fn visit_config_variables(&mut self, v: &config::Variables) {
+            walk_config_variables(self, v)
+        }
+        fn visit_data_data_field(&mut self, v: &data::DataField) {
+            walk_data_data_field(self, v)
+        }
+        fn visit_data_data_schema(&mut self, v: &data::DataSchema) {
+            walk_data_data_schema(self, v)
+        }
+        fn visit_data_data_type(&mut self, v: &data::DataType) {
+            walk_data_data_type(self, v)
+        }
+        fn visit_data_data_type_binary(&mut self, v: &data::DataTypeBinary) {
+            walk_data_data_type_binary(self, v)
+        }
+        fn visit_data_data_type_bool(&mut self, _v: &data::DataTypeBool) {}
+        fn visit_data_data_type_date(&mut self, _v: &data::DataTypeDate) {}
+        fn visit_data_data_type_decimal(&mut self, v: &data::DataTypeDecimal) {
+            walk_data_data_type_decimal(self, v)
+        }
+        fn visit_data_data_type_duration(&mut self, v: &data::DataTypeDuration) {
+            walk_data_data_type_duration(self, v)
+        }
+        fn visit_data_data_type_float_16(&mut self, _v: &data::DataTypeFloat16) {}
+        fn visit_data_data_type_float_32(&mut self, _v: &data::DataTypeFloat32) {}
+        fn visit_data_data_type_float_64(&mut self, _v: &data::DataTypeFloat64) {}
+        fn visit_data_data_type_int_16(&mut self, _v: &data::DataTypeInt16) {}
+        fn visit_data_data_type_int_32(&mut self, _v: &data::DataTypeInt32) {}
+        fn visit_data_data_type_int_64(&mut self, _v: &data::DataTypeInt64) {}
+        fn visit_data_data_type_int_8(&mut self, _v: &data::DataTypeInt8) {}
+        fn visit_data_data_type_list(&mut self, v: &data::DataTypeList) {
+            walk_data_data_type_list(self, v)
+        }
+        fn visit_data_data_type_map(&mut self, v: &data::DataTypeMap) {
+            walk_data_data_type_map(self, v)
+        }
+        fn visit_data_data_type_null(&mut self, _v: &data::DataTypeNull) {}
+        fn visit_data_data_type_option(&mut self, v: &data::DataTypeOption) {
+            walk_data_data_type_option(self, v)
+        }
+        fn visit_data_data_type_string(&mut self, _v: &data::DataTypeString) {}
+        fn visit_data_data_type_struct(&mut self, v: &data::DataTypeStruct) {
+            walk_data_data_type_struct(self, v)
+        }
+        fn visit_data_data_type_time(&mut self, v: &data::DataTypeTime) {
+            walk_data_data_type_time(self, v)
+        }
+        fn visit_data_data_type_timestamp(&mut self, v: &data::DataTypeTimestamp) {
+            walk_data_data_type_timestamp(self, v)
+        }
+        fn visit_data_data_type_u_int_16(&mut self, _v: &data::DataTypeUInt16) {}
+        fn visit_data_data_type_u_int_32(&mut self, _v: &data::DataTypeUInt32) {}
+        fn visit_data_data_type_u_int_64(&mut self, _v: &data::DataTypeUInt64) {}
+        fn visit_data_data_type_u_int_8(&mut self, _v: &data::DataTypeUInt8) {}
+        fn visit_data_extra_attributes(&mut self, v: &data::ExtraAttributes) {
+            walk_data_extra_attributes(self, v)
+        }
+        fn visit_data_operation_type(&mut self, _v: &data::OperationType) {}
+        fn visit_data_time_unit(&mut self, _v: &data::TimeUnit) {}
+        fn visit_dataset_add_data(&mut self, v: &dataset::AddData) {
+            walk_dataset_add_data(self, v)
+        }
+        fn visit_dataset_attachment_embedded(&mut self, v: &dataset::AttachmentEmbedded) {
+            walk_dataset_attachment_embedded(self, v)
+        }
+        fn visit_dataset_attachments(&mut self, v: &dataset::Attachments) {
+            walk_dataset_attachments(self, v)
+        }
+        fn visit_dataset_attachments_embedded(&mut self, v: &dataset::AttachmentsEmbedded) {
+            walk_dataset_attachments_embedded(self, v)
+        }
+        fn visit_dataset_checkpoint(&mut self, v: &dataset::Checkpoint) {
+            walk_dataset_checkpoint(self, v)
+        }
+        fn visit_dataset_compaction_params(&mut self, v: &dataset::CompactionParams) {
+            walk_dataset_compaction_params(self, v)
+        }
+        fn visit_dataset_data_slice(&mut self, v: &dataset::DataSlice) {
+            walk_dataset_data_slice(self, v)
+        }
+        fn visit_dataset_dataset(&mut self, v: &dataset::Dataset) {
+            walk_dataset_dataset(self, v)
+        }
+        fn visit_dataset_dataset_kind(&mut self, _v: &dataset::DatasetKind) {}
+        fn visit_dataset_dataset_selector(&mut self, _v: &dataset::DatasetSelector) {}
+        fn visit_dataset_dataset_spec(&mut self, v: &dataset::DatasetSpec) {
+            walk_dataset_dataset_spec(self, v)
+        }
+        fn visit_dataset_dataset_spec_input(&mut self, v: &dataset::DatasetSpecInput) {
+            walk_dataset_dataset_spec_input(self, v)
+        }
+        fn visit_dataset_dataset_vocabulary(&mut self, v: &dataset::DatasetVocabulary) {
+            walk_dataset_dataset_vocabulary(self, v)
+        }
+        fn visit_dataset_execute_transform(&mut self, v: &dataset::ExecuteTransform) {
+            walk_dataset_execute_transform(self, v)
+        }
+        fn visit_dataset_execute_transform_input(&mut self, v: &dataset::ExecuteTransformInput) {
+            walk_dataset_execute_transform_input(self, v)
+        }
+        fn visit_dataset_metadata_block(&mut self, v: &dataset::MetadataBlock) {
+            walk_dataset_metadata_block(self, v)
+        }
+        fn visit_dataset_metadata_event(&mut self, v: &dataset::MetadataEvent) {
+            walk_dataset_metadata_event(self, v)
+        }
+        fn visit_dataset_offset_interval(&mut self, v: &dataset::OffsetInterval) {
+            walk_dataset_offset_interval(self, v)
+        }
+        fn visit_dataset_projection(&mut self, v: &dataset::Projection) {
+            walk_dataset_projection(self, v)
+        }
+        fn visit_dataset_projection_spec(&mut self, v: &dataset::ProjectionSpec) {
+            walk_dataset_projection_spec(self, v)
+        }
+        fn visit_dataset_projection_spec_input(&mut self, v: &dataset::ProjectionSpecInput) {
+            walk_dataset_projection_spec_input(self, v)
+        }
+        fn visit_dataset_seed(&mut self, v: &dataset::Seed) {
+            walk_dataset_seed(self, v)
+        }
+        fn visit_dataset_set_attachments(&mut self, v: &dataset::SetAttachments) {
+            walk_dataset_set_attachments(self, v)
+        }
+        fn visit_dataset_set_data_schema(&mut self, v: &dataset::SetDataSchema) {
+            walk_dataset_set_data_schema(self, v)
+        }
+        fn visit_dataset_set_info(&mut self, v: &dataset::SetInfo) {
+            walk_dataset_set_info(self, v)
+        }
+        fn visit_dataset_set_license(&mut self, v: &dataset::SetLicense) {
+            walk_dataset_set_license(self, v)
+        }
+        fn visit_dataset_set_transform(&mut self, v: &dataset::SetTransform) {
+            walk_dataset_set_transform(self, v)
+        }
+        fn visit_dataset_set_vocab(&mut self, v: &dataset::SetVocab) {
+            walk_dataset_set_vocab(self, v)
+        }
+        fn visit_dataset_sql_query_step(&mut self, v: &dataset::SqlQueryStep) {
+            walk_dataset_sql_query_step(self, v)
+        }
+        fn visit_dataset_temporal_table(&mut self, v: &dataset::TemporalTable) {
+            walk_dataset_temporal_table(self, v)
+        }
+        fn visit_dataset_transform(&mut self, v: &dataset::Transform) {
+            walk_dataset_transform(self, v)
+        }
+        fn visit_dataset_transform_input(&mut self, v: &dataset::TransformInput) {
+            walk_dataset_transform_input(self, v)
+        }
+        fn visit_dataset_transform_sql(&mut self, v: &dataset::TransformSql) {
+            walk_dataset_transform_sql(self, v)
+        }
+        fn visit_dataset_watermark(&mut self, v: &dataset::Watermark) {
+            walk_dataset_watermark(self, v)
+        }
+        fn visit_engine_raw_query_request(&mut self, v: &engine::RawQueryRequest) {
+            walk_engine_raw_query_request(self, v)
+        }
+        fn visit_engine_raw_query_response(&mut self, v: &engine::RawQueryResponse) {
+            walk_engine_raw_query_response(self, v)
+        }
+        fn visit_engine_raw_query_response_internal_error(
+            &mut self,
+            v: &engine::RawQueryResponseInternalError,
+        ) {
+            walk_engine_raw_query_response_internal_error(self, v)
+        }
+        fn visit_engine_raw_query_response_invalid_query(
+            &mut self,
+            v: &engine::RawQueryResponseInvalidQuery,
+        ) {
+            walk_engine_raw_query_response_invalid_query(self, v)
+        }
+        fn visit_engine_raw_query_response_progress(
+            &mut self,
+            _v: &engine::RawQueryResponseProgress,
+        ) {
+        }
+        fn visit_engine_raw_query_response_success(&mut self, v: &engine::RawQueryResponseSuccess) {
+            walk_engine_raw_query_response_success(self, v)
+        }
+        fn visit_engine_transform_request(&mut self, v: &engine::TransformRequest) {
+            walk_engine_transform_request(self, v)
+        }
+        fn visit_engine_transform_request_input(&mut self, v: &engine::TransformRequestInput) {
+            walk_engine_transform_request_input(self, v)
+        }
+        fn visit_engine_transform_response(&mut self, v: &engine::TransformResponse) {
+            walk_engine_transform_response(self, v)
+        }
+        fn visit_engine_transform_response_internal_error(
+            &mut self,
+            v: &engine::TransformResponseInternalError,
+        ) {
+            walk_engine_transform_response_internal_error(self, v)
+        }
+        fn visit_engine_transform_response_invalid_query(
+            &mut self,
+            v: &engine::TransformResponseInvalidQuery,
+        ) {
+            walk_engine_transform_response_invalid_query(self, v)
+        }
+        fn visit_engine_transform_response_progress(
+            &mut self,
+            _v: &engine::TransformResponseProgress,
+        ) {
+        }
+        fn visit_engine_transform_response_success(
+            &mut self,
+            v: &engine::TransformResponseSuccess,
+        ) {
+            walk_engine_transform_response_success(self, v)
+        }
+        fn visit_event_event_filter(&mut self, v: &event::EventFilter) {
+            walk_event_event_filter(self, v)
+        }
+        fn visit_event_event_filter_and(&mut self, v: &event::EventFilterAnd) {
+            walk_event_event_filter_and(self, v)
+        }
+        fn visit_event_event_filter_match(&mut self, v: &event::EventFilterMatch) {
+            walk_event_event_filter_match(self, v)
+        }
+        fn visit_event_event_filter_not(&mut self, v: &event::EventFilterNot) {
+            walk_event_event_filter_not(self, v)
+        }
+        fn visit_event_event_filter_or(&mut self, v: &event::EventFilterOr) {
+            walk_event_event_filter_or(self, v)
+        }
+        fn visit_flow_flow(&mut self, v: &flow::Flow) {
+            walk_flow_flow(self, v)
+        }
+        fn visit_flow_flow_spec(&mut self, v: &flow::FlowSpec) {
+            walk_flow_flow_spec(self, v)
+        }
+        fn visit_flow_flow_spec_input(&mut self, v: &flow::FlowSpecInput) {
+            walk_flow_flow_spec_input(self, v)
+        }
+        fn visit_flow_flow_trigger(&mut self, v: &flow::FlowTrigger) {
+            walk_flow_flow_trigger(self, v)
+        }
+        fn visit_flow_flow_trigger_dataset(&mut self, v: &flow::FlowTriggerDataset) {
+            walk_flow_flow_trigger_dataset(self, v)
+        }
+        fn visit_flow_flow_trigger_event(&mut self, v: &flow::FlowTriggerEvent) {
+            walk_flow_flow_trigger_event(self, v)
+        }
+        fn visit_flow_flow_trigger_input(&mut self, v: &flow::FlowTriggerInput) {
+            walk_flow_flow_trigger_input(self, v)
+        }
+        fn visit_flow_flow_trigger_input_dataset(&mut self, v: &flow::FlowTriggerInputDataset) {
+            walk_flow_flow_trigger_input_dataset(self, v)
+        }
+        fn visit_flow_flow_trigger_input_event(&mut self, v: &flow::FlowTriggerInputEvent) {
+            walk_flow_flow_trigger_input_event(self, v)
+        }
+        fn visit_flow_flow_trigger_input_schedule(&mut self, v: &flow::FlowTriggerInputSchedule) {
+            walk_flow_flow_trigger_input_schedule(self, v)
+        }
+        fn visit_flow_flow_trigger_input_source(&mut self, v: &flow::FlowTriggerInputSource) {
+            walk_flow_flow_trigger_input_source(self, v)
+        }
+        fn visit_flow_flow_trigger_schedule(&mut self, v: &flow::FlowTriggerSchedule) {
+            walk_flow_flow_trigger_schedule(self, v)
+        }
+        fn visit_flow_flow_trigger_source(&mut self, v: &flow::FlowTriggerSource) {
+            walk_flow_flow_trigger_source(self, v)
+        }
+        fn visit_flow_task(&mut self, v: &flow::Task) {
+            walk_flow_task(self, v)
+        }
+        fn visit_flow_task_spec(&mut self, v: &flow::TaskSpec) {
+            walk_flow_task_spec(self, v)
+        }
+        fn visit_flow_task_spec_compaction(&mut self, v: &flow::TaskSpecCompaction) {
+            walk_flow_task_spec_compaction(self, v)
+        }
+        fn visit_flow_task_spec_garbage_collection(
+            &mut self,
+            _v: &flow::TaskSpecGarbageCollection,
+        ) {
+        }
+        fn visit_flow_task_spec_ingest(&mut self, v: &flow::TaskSpecIngest) {
+            walk_flow_task_spec_ingest(self, v)
+        }
+        fn visit_flow_task_spec_input(&mut self, v: &flow::TaskSpecInput) {
+            walk_flow_task_spec_input(self, v)
+        }
+        fn visit_flow_task_spec_input_compaction(&mut self, v: &flow::TaskSpecInputCompaction) {
+            walk_flow_task_spec_input_compaction(self, v)
+        }
+        fn visit_flow_task_spec_input_garbage_collection(
+            &mut self,
+            _v: &flow::TaskSpecInputGarbageCollection,
+        ) {
+        }
+        fn visit_flow_task_spec_input_ingest(&mut self, v: &flow::TaskSpecInputIngest) {
+            walk_flow_task_spec_input_ingest(self, v)
+        }
+        fn visit_flow_task_spec_input_webhook_call(&mut self, v: &flow::TaskSpecInputWebhookCall) {
+            walk_flow_task_spec_input_webhook_call(self, v)
+        }
+        fn visit_flow_task_spec_webhook_call(&mut self, v: &flow::TaskSpecWebhookCall) {
+            walk_flow_task_spec_webhook_call(self, v)
+        }
+        fn visit_legacy_add_push_source(&mut self, v: &legacy::AddPushSource) {
+            walk_legacy_add_push_source(self, v)
+        }
+        fn visit_legacy_dataset_snapshot(&mut self, v: &legacy::DatasetSnapshot) {
+            walk_legacy_dataset_snapshot(self, v)
+        }
+        fn visit_legacy_disable_polling_source(&mut self, _v: &legacy::DisablePollingSource) {}
+        fn visit_legacy_disable_push_source(&mut self, v: &legacy::DisablePushSource) {
+            walk_legacy_disable_push_source(self, v)
+        }
+        fn visit_legacy_fetch_step(&mut self, v: &legacy::FetchStep) {
+            walk_legacy_fetch_step(self, v)
+        }
+        fn visit_legacy_fetch_step_container(&mut self, v: &legacy::FetchStepContainer) {
+            walk_legacy_fetch_step_container(self, v)
+        }
+        fn visit_legacy_fetch_step_ethereum_logs(&mut self, v: &legacy::FetchStepEthereumLogs) {
+            walk_legacy_fetch_step_ethereum_logs(self, v)
+        }
+        fn visit_legacy_fetch_step_files_glob(&mut self, v: &legacy::FetchStepFilesGlob) {
+            walk_legacy_fetch_step_files_glob(self, v)
+        }
+        fn visit_legacy_fetch_step_mqtt(&mut self, v: &legacy::FetchStepMqtt) {
+            walk_legacy_fetch_step_mqtt(self, v)
+        }
+        fn visit_legacy_fetch_step_url(&mut self, v: &legacy::FetchStepUrl) {
+            walk_legacy_fetch_step_url(self, v)
+        }
+        fn visit_legacy_manifest<ContentT: Visitable>(&mut self, v: &legacy::Manifest<ContentT>) {
+            walk_legacy_manifest(self, v)
+        }
+        fn visit_legacy_set_polling_source(&mut self, v: &legacy::SetPollingSource) {
+            walk_legacy_set_polling_source(self, v)
+        }
+        fn visit_protocol_dataset_error(&mut self, v: &protocol::DatasetError) {
+            walk_protocol_dataset_error(self, v)
+        }
+        fn visit_protocol_dataset_metadata_pull_response(
+            &mut self,
+            v: &protocol::DatasetMetadataPullResponse,
+        ) {
+            walk_protocol_dataset_metadata_pull_response(self, v)
+        }
+        fn visit_protocol_dataset_pull_metadata_request(
+            &mut self,
+            _v: &protocol::DatasetPullMetadataRequest,
+        ) {
+        }
+        fn visit_protocol_dataset_pull_objects_transfer_request(
+            &mut self,
+            v: &protocol::DatasetPullObjectsTransferRequest,
+        ) {
+            walk_protocol_dataset_pull_objects_transfer_request(self, v)
+        }
+        fn visit_protocol_dataset_pull_objects_transfer_response(
+            &mut self,
+            v: &protocol::DatasetPullObjectsTransferResponse,
+        ) {
+            walk_protocol_dataset_pull_objects_transfer_response(self, v)
+        }
+        fn visit_protocol_dataset_pull_request(&mut self, v: &protocol::DatasetPullRequest) {
+            walk_protocol_dataset_pull_request(self, v)
+        }
+        fn visit_protocol_dataset_pull_response(&mut self, v: &protocol::DatasetPullResponse) {
+            walk_protocol_dataset_pull_response(self, v)
+        }
+        fn visit_protocol_dataset_push_complete(&mut self, _v: &protocol::DatasetPushComplete) {}
+        fn visit_protocol_dataset_push_complete_confirmed(
+            &mut self,
+            _v: &protocol::DatasetPushCompleteConfirmed,
+        ) {
+        }
+        fn visit_protocol_dataset_push_metadata(&mut self, v: &protocol::DatasetPushMetadata) {
+            walk_protocol_dataset_push_metadata(self, v)
+        }
+        fn visit_protocol_dataset_push_metadata_accepted(
+            &mut self,
+            _v: &protocol::DatasetPushMetadataAccepted,
+        ) {
+        }
+        fn visit_protocol_dataset_push_objects_transfer_request(
+            &mut self,
+            v: &protocol::DatasetPushObjectsTransferRequest,
+        ) {
+            walk_protocol_dataset_push_objects_transfer_request(self, v)
+        }
+        fn visit_protocol_dataset_push_objects_transfer_response(
+            &mut self,
+            v: &protocol::DatasetPushObjectsTransferResponse,
+        ) {
+            walk_protocol_dataset_push_objects_transfer_response(self, v)
+        }
+        fn visit_protocol_dataset_push_request(&mut self, v: &protocol::DatasetPushRequest) {
+            walk_protocol_dataset_push_request(self, v)
+        }
+        fn visit_protocol_dataset_push_request_accepted(
+            &mut self,
+            _v: &protocol::DatasetPushRequestAccepted,
+        ) {
+        }
+        fn visit_protocol_error_details(&mut self, v: &protocol::ErrorDetails) {
+            walk_protocol_error_details(self, v)
+        }
+        fn visit_protocol_object_file_reference(&mut self, v: &protocol::ObjectFileReference) {
+            walk_protocol_object_file_reference(self, v)
+        }
+        fn visit_protocol_object_pull_strategy(&mut self, _v: &protocol::ObjectPullStrategy) {}
+        fn visit_protocol_object_push_strategy(&mut self, _v: &protocol::ObjectPushStrategy) {}
+        fn visit_protocol_object_type(&mut self, _v: &protocol::ObjectType) {}
+        fn visit_protocol_objects_batch(&mut self, v: &protocol::ObjectsBatch) {
+            walk_protocol_objects_batch(self, v)
+        }
+        fn visit_protocol_pull_object_transfer_strategy(
+            &mut self,
+            v: &protocol::PullObjectTransferStrategy,
+        ) {
+            walk_protocol_pull_object_transfer_strategy(self, v)
+        }
+        fn visit_protocol_push_object_transfer_strategy(
+            &mut self,
+            v: &protocol::PushObjectTransferStrategy,
+        ) {
+            walk_protocol_push_object_transfer_strategy(self, v)
+        }
+        fn visit_protocol_transfer_size_estimation(
+            &mut self,
+            v: &protocol::TransferSizeEstimation,
+        ) {
+            walk_protocol_transfer_size_estimation(self, v)
+        }
+        fn visit_protocol_transfer_url(&mut self, v: &protocol::TransferUrl) {
+            walk_protocol_transfer_url(self, v)
+        }
+        fn visit_resource_filter_condition(&mut self, v: &resource::FilterCondition) {
+            walk_resource_filter_condition(self, v)
+        }
+        fn visit_resource_label_filter(&mut self, v: &resource::LabelFilter) {
+            walk_resource_label_filter(self, v)
+        }
+        fn visit_resource_label_filter_and(&mut self, v: &resource::LabelFilterAnd) {
+            walk_resource_label_filter_and(self, v)
+        }
+        fn visit_resource_label_filter_match(&mut self, v: &resource::LabelFilterMatch) {
+            walk_resource_label_filter_match(self, v)
+        }
+        fn visit_resource_label_filter_not(&mut self, v: &resource::LabelFilterNot) {
+            walk_resource_label_filter_not(self, v)
+        }
+        fn visit_resource_label_filter_or(&mut self, v: &resource::LabelFilterOr) {
+            walk_resource_label_filter_or(self, v)
+        }
+        fn visit_resource_resource<SpecT: Visitable>(&mut self, v: &resource::Resource<SpecT>) {
+            walk_resource_resource(self, v)
+        }
+        fn visit_resource_resource_annotations(&mut self, v: &resource::ResourceAnnotations) {
+            walk_resource_resource_annotations(self, v)
+        }
+        fn visit_resource_resource_conditions(&mut self, v: &resource::ResourceConditions) {
+            walk_resource_resource_conditions(self, v)
+        }
+        fn visit_resource_resource_handle(&mut self, v: &resource::ResourceHandle) {
+            walk_resource_resource_handle(self, v)
+        }
+        fn visit_resource_resource_headers(&mut self, v: &resource::ResourceHeaders) {
+            walk_resource_resource_headers(self, v)
+        }
+        fn visit_resource_resource_headers_input(&mut self, v: &resource::ResourceHeadersInput) {
+            walk_resource_resource_headers_input(self, v)
+        }
+        fn visit_resource_resource_input<SpecT: Visitable>(
+            &mut self,
+            v: &resource::ResourceInput<SpecT>,
+        ) {
+            walk_resource_resource_input(self, v)
+        }
+        fn visit_resource_resource_labels(&mut self, v: &resource::ResourceLabels) {
+            walk_resource_resource_labels(self, v)
+        }
+        fn visit_resource_resource_phase(&mut self, _v: &resource::ResourcePhase) {}
+        fn visit_resource_resource_ref(&mut self, v: &resource::ResourceRef) {
+            walk_resource_resource_ref(self, v)
+        }
+        fn visit_resource_resource_selector(&mut self, _v: &resource::ResourceSelector) {}
+        fn visit_resource_resource_status(&mut self, v: &resource::ResourceStatus) {
+            walk_resource_resource_status(self, v)
+        }
+        fn visit_sink_webhook_target(&mut self, v: &sink::WebhookTarget) {
+            walk_sink_webhook_target(self, v)
+        }
+        fn visit_sink_webhook_target_spec(&mut self, v: &sink::WebhookTargetSpec) {
+            walk_sink_webhook_target_spec(self, v)
+        }
+        fn visit_sink_webhook_target_spec_input(&mut self, v: &sink::WebhookTargetSpecInput) {
+            walk_sink_webhook_target_spec_input(self, v)
+        }
+        fn visit_sink_webhook_target_status(&mut self, v: &sink::WebhookTargetStatus) {
+            walk_sink_webhook_target_status(self, v)
+        }
+        fn visit_sink_webhook_target_status_value(&mut self, _v: &sink::WebhookTargetStatusValue) {}
+        fn visit_source_compression_format(&mut self, _v: &source::CompressionFormat) {}
+        fn visit_source_env_var(&mut self, v: &source::EnvVar) {
+            walk_source_env_var(self, v)
+        }
+        fn visit_source_event_time_source(&mut self, v: &source::EventTimeSource) {
+            walk_source_event_time_source(self, v)
+        }
+        fn visit_source_event_time_source_from_metadata(
+            &mut self,
+            _v: &source::EventTimeSourceFromMetadata,
+        ) {
+        }
+        fn visit_source_event_time_source_from_path(
+            &mut self,
+            v: &source::EventTimeSourceFromPath,
+        ) {
+            walk_source_event_time_source_from_path(self, v)
+        }
+        fn visit_source_event_time_source_from_system_time(
+            &mut self,
+            _v: &source::EventTimeSourceFromSystemTime,
+        ) {
+        }
+        fn visit_source_ingest_params(&mut self, v: &source::IngestParams) {
+            walk_source_ingest_params(self, v)
+        }
+        fn visit_source_ingress(&mut self, v: &source::Ingress) {
+            walk_source_ingress(self, v)
+        }
+        fn visit_source_ingress_buffer(&mut self, v: &source::IngressBuffer) {
+            walk_source_ingress_buffer(self, v)
+        }
+        fn visit_source_ingress_buffer_memory(&mut self, v: &source::IngressBufferMemory) {
+            walk_source_ingress_buffer_memory(self, v)
+        }
+        fn visit_source_ingress_container(&mut self, v: &source::IngressContainer) {
+            walk_source_ingress_container(self, v)
+        }
+        fn visit_source_ingress_evm_logs(&mut self, v: &source::IngressEvmLogs) {
+            walk_source_ingress_evm_logs(self, v)
+        }
+        fn visit_source_ingress_files_glob(&mut self, v: &source::IngressFilesGlob) {
+            walk_source_ingress_files_glob(self, v)
+        }
+        fn visit_source_ingress_mqtt(&mut self, v: &source::IngressMqtt) {
+            walk_source_ingress_mqtt(self, v)
+        }
+        fn visit_source_ingress_rest_endpoint(&mut self, v: &source::IngressRestEndpoint) {
+            walk_source_ingress_rest_endpoint(self, v)
+        }
+        fn visit_source_ingress_url(&mut self, v: &source::IngressUrl) {
+            walk_source_ingress_url(self, v)
+        }
+        fn visit_source_merge_strategy(&mut self, v: &source::MergeStrategy) {
+            walk_source_merge_strategy(self, v)
+        }
+        fn visit_source_merge_strategy_append(&mut self, _v: &source::MergeStrategyAppend) {}
+        fn visit_source_merge_strategy_changelog_stream(
+            &mut self,
+            v: &source::MergeStrategyChangelogStream,
+        ) {
+            walk_source_merge_strategy_changelog_stream(self, v)
+        }
+        fn visit_source_merge_strategy_ledger(&mut self, v: &source::MergeStrategyLedger) {
+            walk_source_merge_strategy_ledger(self, v)
+        }
+        fn visit_source_merge_strategy_snapshot(&mut self, v: &source::MergeStrategySnapshot) {
+            walk_source_merge_strategy_snapshot(self, v)
+        }
+        fn visit_source_merge_strategy_upsert_stream(
+            &mut self,
+            v: &source::MergeStrategyUpsertStream,
+        ) {
+            walk_source_merge_strategy_upsert_stream(self, v)
+        }
+        fn visit_source_mqtt_qos(&mut self, _v: &source::MqttQos) {}
+        fn visit_source_mqtt_topic_subscription(&mut self, v: &source::MqttTopicSubscription) {
+            walk_source_mqtt_topic_subscription(self, v)
+        }
+        fn visit_source_prep_step(&mut self, v: &source::PrepStep) {
+            walk_source_prep_step(self, v)
+        }
+        fn visit_source_prep_step_decompress(&mut self, v: &source::PrepStepDecompress) {
+            walk_source_prep_step_decompress(self, v)
+        }
+        fn visit_source_prep_step_pipe(&mut self, v: &source::PrepStepPipe) {
+            walk_source_prep_step_pipe(self, v)
+        }
+        fn visit_source_read_step(&mut self, v: &source::ReadStep) {
+            walk_source_read_step(self, v)
+        }
+        fn visit_source_read_step_csv(&mut self, v: &source::ReadStepCsv) {
+            walk_source_read_step_csv(self, v)
+        }
+        fn visit_source_read_step_esri_shapefile(&mut self, v: &source::ReadStepEsriShapefile) {
+            walk_source_read_step_esri_shapefile(self, v)
+        }
+        fn visit_source_read_step_geo_json(&mut self, v: &source::ReadStepGeoJson) {
+            walk_source_read_step_geo_json(self, v)
+        }
+        fn visit_source_read_step_json(&mut self, v: &source::ReadStepJson) {
+            walk_source_read_step_json(self, v)
+        }
+        fn visit_source_read_step_nd_geo_json(&mut self, v: &source::ReadStepNdGeoJson) {
+            walk_source_read_step_nd_geo_json(self, v)
+        }
+        fn visit_source_read_step_nd_json(&mut self, v: &source::ReadStepNdJson) {
+            walk_source_read_step_nd_json(self, v)
+        }
+        fn visit_source_read_step_parquet(&mut self, v: &source::ReadStepParquet) {
+            walk_source_read_step_parquet(self, v)
+        }
+        fn visit_source_request_header(&mut self, v: &source::RequestHeader) {
+            walk_source_request_header(self, v)
+        }
+        fn visit_source_source(&mut self, v: &source::Source) {
+            walk_source_source(self, v)
+        }
+        fn visit_source_source_caching(&mut self, v: &source::SourceCaching) {
+            walk_source_source_caching(self, v)
+        }
+        fn visit_source_source_caching_forever(&mut self, _v: &source::SourceCachingForever) {}
+        fn visit_source_source_ordering(&mut self, _v: &source::SourceOrdering) {}
+        fn visit_source_source_spec(&mut self, v: &source::SourceSpec) {
+            walk_source_source_spec(self, v)
+        }
+        fn visit_source_source_spec_input(&mut self, v: &source::SourceSpecInput) {
+            walk_source_source_spec_input(self, v)
+        }
+        fn visit_source_source_state(&mut self, v: &source::SourceState) {
+            walk_source_source_state(self, v)
+        }
+        fn visit_storage_aws_credentials(&mut self, v: &storage::AwsCredentials) {
+            walk_storage_aws_credentials(self, v)
+        }
+        fn visit_storage_aws_credentials_input(&mut self, v: &storage::AwsCredentialsInput) {
+            walk_storage_aws_credentials_input(self, v)
+        }
+        fn visit_storage_persistent_volume(&mut self, v: &storage::PersistentVolume) {
+            walk_storage_persistent_volume(self, v)
+        }
+        fn visit_storage_persistent_volume_ref(&mut self, v: &storage::PersistentVolumeRef) {
+            walk_storage_persistent_volume_ref(self, v)
+        }
+        fn visit_storage_persistent_volume_spec(&mut self, v: &storage::PersistentVolumeSpec) {
+            walk_storage_persistent_volume_spec(self, v)
+        }
+        fn visit_storage_persistent_volume_spec_input(
+            &mut self,
+            v: &storage::PersistentVolumeSpecInput,
+        ) {
+            walk_storage_persistent_volume_spec_input(self, v)
+        }
+        fn visit_storage_persistent_volume_spec_input_s_3(
+            &mut self,
+            v: &storage::PersistentVolumeSpecInputS3,
+        ) {
+            walk_storage_persistent_volume_spec_input_s_3(self, v)
+        }
+        fn visit_storage_persistent_volume_spec_s_3(
+            &mut self,
+            v: &storage::PersistentVolumeSpecS3,
+        ) {
+            walk_storage_persistent_volume_spec_s_3(self, v)
+        }
+        fn visit_storage_volume_capacity(&mut self, v: &storage::VolumeCapacity) {
+            walk_storage_volume_capacity(self, v)
+        }
+    }
+
+    /// Traversal of DTOs that can modify values in place, e.g. to rewrite references.
+    ///
+    /// Default methods descend into all nested values, so implementations only override the ones they care about.
+    pub trait VisitMut {
+        fn visit_bool_mut(&mut self, _v: &mut bool) {}
+        fn visit_i8_mut(&mut self, _v: &mut i8) {}
+        fn visit_i16_mut(&mut self, _v: &mut i16) {}
+        fn visit_i32_mut(&mut self, _v: &mut i32) {}
+        fn visit_i64_mut(&mut self, _v: &mut i64) {}
+        fn visit_u8_mut(&mut self, _v: &mut u8) {}
+        fn visit_u16_mut(&mut self, _v: &mut u16) {}
+        fn visit_u32_mut(&mut self, _v: &mut u32) {}
+        fn visit_u64_mut(&mut self, _v: &mut u64) {}
+        fn visit_string_mut(&mut self, _v: &mut String) {}
+        fn visit_byte_size_mut(&mut self, _v: &mut ByteSize) {}
+        fn visit_date_time_mut(&mut self, _v: &mut DateTime<Utc>) {}
+        fn visit_duration_mut(&mut self, _v: &mut DurationString) {}
+        fn visit_multicodec_mut(&mut self, _v: &mut String) {}
+        fn visit_multihash_mut(&mut self, _v: &mut Multihash) {}
+        fn visit_path_mut(&mut self, _v: &mut PathBuf) {}
+        fn visit_regex_mut(&mut self, _v: &mut String) {}
+        fn visit_url_mut(&mut self, _v: &mut String) {}
+        fn visit_did_mut(&mut self, _v: &mut Did) {}
+        fn visit_type_name_mut(&mut self, _v: &mut TypeName) {}
+        fn visit_type_uri_mut(&mut self, _v: &mut TypeUri) {}
+        fn visit_type_ref_mut(&mut self, _v: &mut TypeRef) {}
+        fn visit_account_id_mut(&mut self, _v: &mut AccountID) {}
+        fn visit_account_name_mut(&mut self, _v: &mut AccountName) {}
+        fn visit_dataset_alias_mut(&mut self, _v: &mut DatasetAlias) {}
+        fn visit_dataset_id_mut(&mut self, _v: &mut DatasetID) {}
+        fn visit_dataset_ref_mut(&mut self, _v: &mut DatasetRef) {}
+        fn visit_resource_id_mut(&mut self, _v: &mut ResourceID) {}
+        fn visit_resource_name_mut(&mut self, _v: &mut ResourceName) {}
+        fn visit_flatbuffers_mut(&mut self, _v: &mut Vec<u8>) {}
+        fn visit_any_json_mut(&mut self, _v: &mut serde_json::Value) {}
+
+        fn visit_auth_account_mut(&mut self, v: &mut auth::Account) {
+            walk_auth_account_mut(self, v)
+        }
+        fn visit_auth_account_handle_mut(&mut self, v: &mut auth::AccountHandle) {
+            walk_auth_account_handle_mut(self, v)
+        }
+        fn visit_auth_account_ref_mut(&mut self, v: &mut auth::AccountRef) {
+            walk_auth_account_ref_mut(self, v)
+        }
+        fn visit_auth_account_spec_mut(&mut self, v: &mut auth::AccountSpec) {
+            walk_auth_account_spec_mut(self, v)
+        }
+        fn visit_auth_account_spec_input_mut(&mut self, v: &mut auth::AccountSpecInput) {
+            walk_auth_account_spec_input_mut(self, v)
+        }
+        fn visit_auth_account_type_mut(&mut self, _v: &mut auth::AccountType) {}
+        fn visit_auth_attribute_mut(&mut self, v: &mut auth::Attribute) {
+            walk_auth_attribute_mut(self, v)
+        }
+        fn visit_auth_attribute_input_mut(&mut self, v: &mut auth::AttributeInput) {
+            walk_auth_attribute_input_mut(self, v)
+        }
+        fn visit_auth_relation_mut(&mut self, v: &mut auth::Relation) {
+            walk_auth_relation_mut(self, v)
+        }
+        fn visit_auth_relation_input_mut(&mut self, v: &mut auth::RelationInput) {
+            walk_auth_relation_input_mut(self, v)
+        }
+        fn visit_auth_relations_mut(&mut self, v: &mut auth::Relations) {
+            walk_auth_relations_mut(self, v)
+        }
+        fn visit_auth_relations_spec_mut(&mut self, v: &mut auth::RelationsSpec) {
+            walk_auth_relations_spec_mut(self, v)
+        }
+        fn visit_auth_relations_spec_input_mut(&mut self, v: &mut auth::RelationsSpecInput) {
+            walk_auth_relations_spec_input_mut(self, v)
+        }
+        fn visit_config_secret_mut(&mut self, v: &mut config::Secret) {
+            walk_config_secret_mut(self, v)
+        }
+        fn visit_config_secret_set_mut(&mut self, v: &mut config::SecretSet) {
+            walk_config_secret_set_mut(self, v)
+        }
+        fn visit_config_secret_set_spec_mut(&mut self, v: &mut config::SecretSetSpec) {
+            walk_config_secret_set_spec_mut(self, v)
+        }
+        fn visit_config_secret_set_spec_input_mut(&mut self, v: &mut config::SecretSetSpecInput) {
+            walk_config_secret_set_spec_input_mut(self, v)
+        }
+        fn visit_config_secrets_mut(&mut self, v: &mut config::Secrets) {
+            walk_config_secrets_mut(self, v)
+        }
+        fn visit_config_value_handle_mut(&mut self, v: &mut config::ValueHandle) {
+            walk_config_value_handle_mut(self, v)
+        }
+        fn visit_config_value_ref_mut(&mut self, v: &mut config::ValueRef) {
+            walk_config_value_ref_mut(self, v)
+        }
+        fn visit_config_value_refs_mut(&mut self, v: &mut config::ValueRefs) {
+            walk_config_value_refs_mut(self, v)
+        }
+        fn visit_config_variable_mut(&mut self, v: &mut config::Variable) {
+            walk_config_variable_mut(self, v)
+        }
+        fn visit_config_variable_set_mut(&mut self, v: &mut config::VariableSet) {
+            walk_config_variable_set_mut(self, v)
+        }
+        fn visit_config_variable_set_spec_mut(&mut self, v: &mut config::VariableSetSpec) {
+            walk_config_variable_set_spec_mut(self, v)
+        }
+        fn visit_config_variable_set_spec_input_mut(
+            &mut self,
+            v: &mut config::VariableSetSpecInput,
+        ) {
+            walk_config_variable_set_spec_input_mut(self, v)
+        }
+        fn visit_config_variables_mut(&mut self, v: &mut config::Variables) {
+            walk_config_variables_mut(self, v)
+        }
+        fn visit_data_data_field_mut(&mut self, v: &mut data::DataField) {
+            walk_data_data_field_mut(self, v)
+        }
+        fn visit_data_data_schema_mut(&mut self, v: &mut data::DataSchema) {
+            walk_data_data_schema_mut(self, v)
+        }
+        fn visit_data_data_type_mut(&mut self, v: &mut data::DataType) {
+            walk_data_data_type_mut(self, v)
+        }
+        fn visit_data_data_type_binary_mut(&mut self, v: &mut data::DataTypeBinary) {
+            walk_data_data_type_binary_mut(self, v)
+        }
+        fn visit_data_data_type_bool_mut(&mut self, _v: &mut data::DataTypeBool) {}
+        fn visit_data_data_type_date_mut(&mut self, _v: &mut data::DataTypeDate) {}
+        fn visit_data_data_type_decimal_mut(&mut self, v: &mut data::DataTypeDecimal) {
+            walk_data_data_type_decimal_mut(self, v)
+        }
+        fn visit_data_data_type_duration_mut(&mut self, v: &mut data::DataTypeDuration) {
+            walk_data_data_type_duration_mut(self, v)
+        }
+        fn visit_data_data_type_float_16_mut(&mut self, _v: &mut data::DataTypeFloat16) {}
+        fn visit_data_data_type_float_32_mut(&mut self, _v: &mut data::DataTypeFloat32) {}
+        fn visit_data_data_type_float_64_mut(&mut self, _v: &mut data::DataTypeFloat64) {}
+        fn visit_data_data_type_int_16_mut(&mut self, _v: &mut data::DataTypeInt16) {}
+        fn visit_data_data_type_int_32_mut(&mut self, _v: &mut data::DataTypeInt32) {}
+        fn visit_data_data_type_int_64_mut(&mut self, _v: &mut data::DataTypeInt64) {}
+        fn visit_data_data_type_int_8_mut(&mut self, _v: &mut data::DataTypeInt8) {}
+        fn visit_data_data_type_list_mut(&mut self, v: &mut data::DataTypeList) {
+            walk_data_data_type_list_mut(self, v)
+        }
+        fn visit_data_data_type_map_mut(&mut self, v: &mut data::DataTypeMap) {
+            walk_data_data_type_map_mut(self, v)
+        }
+        fn visit_data_data_type_null_mut(&mut self, _v: &mut data::DataTypeNull) {}
+        fn visit_data_data_type_option_mut(&mut self, v: &mut data::DataTypeOption) {
+            walk_data_data_type_option_mut(self, v)
+        }
+        fn visit_data_data_type_string_mut(&mut self, _v: &mut data::DataTypeString) {}
+        fn visit_data_data_type_struct_mut(&mut self, v: &mut data::DataTypeStruct) {
+            walk_data_data_type_struct_mut(self, v)
+        }
+        fn visit_data_data_type_time_mut(&mut self, v: &mut data::DataTypeTime) {
+            walk_data_data_type_time_mut(self, v)
+        }
+        fn visit_data_data_type_timestamp_mut(&mut self, v: &mut data::DataTypeTimestamp) {
+            walk_data_data_type_timestamp_mut(self, v)
+        }
+        fn visit_data_data_type_u_int_16_mut(&mut self, _v: &mut data::DataTypeUInt16) {}
+        fn visit_data_data_type_u_int_32_mut(&mut self, _v: &mut data::DataTypeUInt32) {}
+        fn visit_data_data_type_u_int_64_mut(&mut self, _v: &mut data::DataTypeUInt64) {}
+        fn visit_data_data_type_u_int_8_mut(&mut self, _v: &mut data::DataTypeUInt8) {}
+        fn visit_data_extra_attributes_mut(&mut self, v: &mut data::ExtraAttributes) {
+            walk_data_extra_attributes_mut(self, v)
+        }
+        fn visit_data_operation_type_mut(&mut self, _v: &mut data::OperationType) {}
+        fn visit_data_time_unit_mut(&mut self, _v: &mut data::TimeUnit) {}
+        fn visit_dataset_add_data_mut(&mut self, v: &mut dataset::AddData) {
+            walk_dataset_add_data_mut(self, v)
+        }
+        fn visit_dataset_attachment_embedded_mut(&mut self, v: &mut dataset::AttachmentEmbedded) {
+            walk_dataset_attachment_embedded_mut(self, v)
+        }
+        fn visit_dataset_attachments_mut(&mut self, v: &mut dataset::Attachments) {
+            walk_dataset_attachments_mut(self, v)
+        }
+        fn visit_dataset_attachments_embedded_mut(&mut self, v: &mut dataset::AttachmentsEmbedded) {
+            walk_dataset_attachments_embedded_mut(self, v)
+        }
+        fn visit_dataset_checkpoint_mut(&mut self, v: &mut dataset::Checkpoint) {
+            walk_dataset_checkpoint_mut(self, v)
+        }
+        fn visit_dataset_compaction_params_mut(&mut self, v: &mut dataset::CompactionParams) {
+            walk_dataset_compaction_params_mut(self, v)
+        }
+        fn visit_dataset_data_slice_mut(&mut self, v: &mut dataset::DataSlice) {
+            walk_dataset_data_slice_mut(self, v)
+        }
+        fn visit_dataset_dataset_mut(&mut self, v: &mut dataset::Dataset) {
+            walk_dataset_dataset_mut(self, v)
+        }
+        fn visit_dataset_dataset_kind_mut(&mut self, _v: &mut dataset::DatasetKind) {}
+        fn visit_dataset_dataset_selector_mut(&mut self, _v: &mut dataset::DatasetSelector) {}
+        fn visit_dataset_dataset_spec_mut(&mut self, v: &mut dataset::DatasetSpec) {
+            walk_dataset_dataset_spec_mut(self, v)
+        }
+        fn visit_dataset_dataset_spec_input_mut(&mut self, v: &mut dataset::DatasetSpecInput) {
+            walk_dataset_dataset_spec_input_mut(self, v)
+        }
+        fn visit_dataset_dataset_vocabulary_mut(&mut self, v: &mut dataset::DatasetVocabulary) {
+            walk_dataset_dataset_vocabulary_mut(self, v)
+        }
+        fn visit_dataset_execute_transform_mut(&mut self, v: &mut dataset::ExecuteTransform) {
+            walk_dataset_execute_transform_mut(self, v)
+        }
+        fn visit_dataset_execute_transform_input_mut(
+            &mut self,
+            v: &mut dataset::ExecuteTransformInput,
+        ) {
+            walk_dataset_execute_transform_input_mut(self, v)
+        }
+        fn visit_dataset_metadata_block_mut(&mut self, v: &mut dataset::MetadataBlock) {
+            walk_dataset_metadata_block_mut(self, v)
+        }
+        fn visit_dataset_metadata_event_mut(&mut self, v: &mut dataset::MetadataEvent) {
+            walk_dataset_metadata_event_mut(self, v)
+        }
+        fn visit_dataset_offset_interval_mut(&mut self, v: &mut dataset::OffsetInterval) {
+            walk_dataset_offset_interval_mut(self, v)
+        }
+        fn visit_dataset_projection_mut(&mut self, v: &mut dataset::Projection) {
+            walk_dataset_projection_mut(self, v)
+        }
+        fn visit_dataset_projection_spec_mut(&mut self, v: &mut dataset::ProjectionSpec) {
+            walk_dataset_projection_spec_mut(self, v)
+        }
+        fn visit_dataset_projection_spec_input_mut(
+            &mut self,
+            v: &mut dataset::ProjectionSpecInput,
+        ) {
+            walk_dataset_projection_spec_input_mut(self, v)
+        }
+        fn visit_dataset_seed_mut(&mut self, v: &mut dataset::Seed) {
+            walk_dataset_seed_mut(self, v)
+        }
+        fn visit_dataset_set_attachments_mut(&mut self, v: &mut dataset::SetAttachments) {
+            walk_dataset_set_attachments_mut(self, v)
+        }
+        fn visit_dataset_set_data_schema_mut(&mut self, v: &mut dataset::SetDataSchema) {
+            walk_dataset_set_data_schema_mut(self, v)
+        }
+        fn visit_dataset_set_info_mut(&mut self, v: &mut dataset::SetInfo) {
+            walk_dataset_set_info_mut(self, v)
+        }
+        fn visit_dataset_set_license_mut(&mut self, v: &mut dataset::SetLicense) {
+            walk_dataset_set_license_mut(self, v)
+        }
+        fn visit_dataset_set_transform_mut(&mut self, v: &mut dataset::SetTransform) {
+            walk_dataset_set_transform_mut(self, v)
+        }
+        fn visit_dataset_set_vocab_mut(&mut self, v: &mut dataset::SetVocab) {
+            walk_dataset_set_vocab_mut(self, v)
+        }
+        fn visit_dataset_sql_query_step_mut(&mut self, v: &mut dataset::SqlQueryStep) {
+            walk_dataset_sql_query_step_mut(self, v)
+        }
+        fn visit_dataset_temporal_table_mut(&mut self, v: &mut dataset::TemporalTable) {
+            walk_dataset_temporal_table_mut(self, v)
+        }
+        fn visit_dataset_transform_mut(&mut self, v: &mut dataset::Transform) {
+            walk_dataset_transform_mut(self, v)
+        }
+        fn visit_dataset_transform_input_mut(&mut self, v: &mut dataset::TransformInput) {
+            walk_dataset_transform_input_mut(self, v)
+        }
+        fn visit_dataset_transform_sql_mut(&mut self, v: &mut dataset::TransformSql) {
+            walk_dataset_transform_sql_mut(self, v)
+        }
+        fn visit_dataset_watermark_mut(&mut self, v: &mut dataset::Watermark) {
+            walk_dataset_watermark_mut(self, v)
+        }
+        fn visit_engine_raw_query_request_mut(&mut self, v: &mut engine::RawQueryRequest) {
+            walk_engine_raw_query_request_mut(self, v)
+        }
+        fn visit_engine_raw_query_response_mut(&mut self, v: &mut engine::RawQueryResponse) {
+            walk_engine_raw_query_response_mut(self, v)
+        }
+        fn visit_engine_raw_query_response_internal_error_mut(
+            &mut self,
+            v: &mut engine::RawQueryResponseInternalError,
+        ) {
+            walk_engine_raw_query_response_internal_error_mut(self, v)
+        }
+        fn visit_engine_raw_query_response_invalid_query_mut(
+            &mut self,
+            v: &mut engine::RawQueryResponseInvalidQuery,
+        ) {
+            walk_engine_raw_query_response_invalid_query_mut(self, v)
+        }
+        fn visit_engine_raw_query_response_progress_mut(
+            &mut self,
+            _v: &mut engine::RawQueryResponseProgress,
+        ) {
+        }
+        fn visit_engine_raw_query_response_success_mut(
+            &mut self,
+            v: &mut engine::RawQueryResponseSuccess,
+        ) {
+            walk_engine_raw_query_response_success_mut(self, v)
+        }
+        fn visit_engine_transform_request_mut(&mut self, v: &mut engine::TransformRequest) {
+            walk_engine_transform_request_mut(self, v)
+        }
+        fn visit_engine_transform_request_input_mut(
+            &mut self,
+            v: &mut engine::TransformRequestInput,
+        ) {
+            walk_engine_transform_request_input_mut(self, v)
+        }
+        fn visit_engine_transform_response_mut(&mut self, v: &mut engine::TransformResponse) {
+            walk_engine_transform_response_mut(self, v)
+        }
+        fn visit_engine_transform_response_internal_error_mut(
+            &mut self,
+            v: &mut engine::TransformResponseInternalError,
+        ) {
+            walk_engine_transform_response_internal_error_mut(self, v)
+        }
+        fn visit_engine_transform_response_invalid_query_mut(
+            &mut self,
+            v: &mut engine::TransformResponseInvalidQuery,
+        ) {
+            walk_engine_transform_response_invalid_query_mut(self, v)
+        }
+        fn visit_engine_transform_response_progress_mut(
+            &mut self,
+            _v: &mut engine::TransformResponseProgress,
+        ) {
+        }
+        fn visit_engine_transform_response_success_mut(
+            &mut self,
+            v: &mut engine::TransformResponseSuccess,
+        ) {
+            walk_engine_transform_response_success_mut(self, v)
+        }
+        fn visit_event_event_filter_mut(&mut self, v: &mut event::EventFilter) {
+            walk_event_event_filter_mut(self, v)
+        }
+        fn visit_event_event_filter_and_mut(&mut self, v: &mut event::EventFilterAnd) {
+            walk_event_event_filter_and_mut(self, v)
+        }
+        fn visit_event_event_filter_match_mut(&mut self, v: &mut event::EventFilterMatch) {
+            walk_event_event_filter_match_mut(self, v)
+        }
+        fn visit_event_event_filter_not_mut(&mut self, v: &mut event::EventFilterNot) {
+            walk_event_event_filter_not_mut(self, v)
+        }
+        fn visit_event_event_filter_or_mut(&mut self, v: &mut event::EventFilterOr) {
+            walk_event_event_filter_or_mut(self, v)
+        }
+        fn visit_flow_flow_mut(&mut self, v: &mut flow::Flow) {
+            walk_flow_flow_mut(self, v)
+        }
+        fn visit_flow_flow_spec_mut(&mut self, v: &mut flow::FlowSpec) {
+            walk_flow_flow_spec_mut(self, v)
+        }
+        fn visit_flow_flow_spec_input_mut(&mut self, v: &mut flow::FlowSpecInput) {
+            walk_flow_flow_spec_input_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_mut(&mut self, v: &mut flow::FlowTrigger) {
+            walk_flow_flow_trigger_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_dataset_mut(&mut self, v: &mut flow::FlowTriggerDataset) {
+            walk_flow_flow_trigger_dataset_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_event_mut(&mut self, v: &mut flow::FlowTriggerEvent) {
+            walk_flow_flow_trigger_event_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_input_mut(&mut self, v: &mut flow::FlowTriggerInput) {
+            walk_flow_flow_trigger_input_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_input_dataset_mut(
+            &mut self,
+            v: &mut flow::FlowTriggerInputDataset,
+        ) {
+            walk_flow_flow_trigger_input_dataset_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_input_event_mut(&mut self, v: &mut flow::FlowTriggerInputEvent) {
+            walk_flow_flow_trigger_input_event_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_input_schedule_mut(
+            &mut self,
+            v: &mut flow::FlowTriggerInputSchedule,
+        ) {
+            walk_flow_flow_trigger_input_schedule_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_input_source_mut(
+            &mut self,
+            v: &mut flow::FlowTriggerInputSource,
+        ) {
+            walk_flow_flow_trigger_input_source_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_schedule_mut(&mut self, v: &mut flow::FlowTriggerSchedule) {
+            walk_flow_flow_trigger_schedule_mut(self, v)
+        }
+        fn visit_flow_flow_trigger_source_mut(&mut self, v: &mut flow::FlowTriggerSource) {
+            walk_flow_flow_trigger_source_mut(self, v)
+        }
+        fn visit_flow_task_mut(&mut self, v: &mut flow::Task) {
+            walk_flow_task_mut(self, v)
+        }
+        fn visit_flow_task_spec_mut(&mut self, v: &mut flow::TaskSpec) {
+            walk_flow_task_spec_mut(self, v)
+        }
+        fn visit_flow_task_spec_compaction_mut(&mut self, v: &mut flow::TaskSpecCompaction) {
+            walk_flow_task_spec_compaction_mut(self, v)
+        }
+        fn visit_flow_task_spec_garbage_collection_mut(
+            &mut self,
+            _v: &mut flow::TaskSpecGarbageCollection,
+        ) {
+        }
+        fn visit_flow_task_spec_ingest_mut(&mut self, v: &mut flow::TaskSpecIngest) {
+            walk_flow_task_spec_ingest_mut(self, v)
+        }
+        fn visit_flow_task_spec_input_mut(&mut self, v: &mut flow::TaskSpecInput) {
+            walk_flow_task_spec_input_mut(self, v)
+        }
+        fn visit_flow_task_spec_input_compaction_mut(
+            &mut self,
+            v: &mut flow::TaskSpecInputCompaction,
+        ) {
+            walk_flow_task_spec_input_compaction_mut(self, v)
+        }
+        fn visit_flow_task_spec_input_garbage_collection_mut(
+            &mut self,
+            _v: &mut flow::TaskSpecInputGarbageCollection,
+        ) {
+        }
+        fn visit_flow_task_spec_input_ingest_mut(&mut self, v: &mut flow::TaskSpecInputIngest) {
+            walk_flow_task_spec_input_ingest_mut(self, v)
+        }
+        fn visit_flow_task_spec_input_webhook_call_mut(
+            &mut self,
+            v: &mut flow::TaskSpecInputWebhookCall,
+        ) {
+            walk_flow_task_spec_input_webhook_call_mut(self, v)
+        }
+        fn visit_flow_task_spec_webhook_call_mut(&mut self, v: &mut flow::TaskSpecWebhookCall) {
+            walk_flow_task_spec_webhook_call_mut(self, v)
+        }
+        fn visit_legacy_add_push_source_mut(&mut self, v: &mut legacy::AddPushSource) {
+            walk_legacy_add_push_source_mut(self, v)
+        }
+        fn visit_legacy_dataset_snapshot_mut(&mut self, v: &mut legacy::DatasetSnapshot) {
+            walk_legacy_dataset_snapshot_mut(self, v)
+        }
+        fn visit_legacy_disable_polling_source_mut(
+            &mut self,
+            _v: &mut legacy::DisablePollingSource,
+        ) {
+        }
+        fn visit_legacy_disable_push_source_mut(&mut self, v: &mut legacy::DisablePushSource) {
+            walk_legacy_disable_push_source_mut(self, v)
+        }
+        fn visit_legacy_fetch_step_mut(&mut self, v: &mut legacy::FetchStep) {
+            walk_legacy_fetch_step_mut(self, v)
+        }
+        fn visit_legacy_fetch_step_container_mut(&mut self, v: &mut legacy::FetchStepContainer) {
+            walk_legacy_fetch_step_container_mut(self, v)
+        }
+        fn visit_legacy_fetch_step_ethereum_logs_mut(
+            &mut self,
+            v: &mut legacy::FetchStepEthereumLogs,
+        ) {
+            walk_legacy_fetch_step_ethereum_logs_mut(self, v)
+        }
+        fn visit_legacy_fetch_step_files_glob_mut(&mut self, v: &mut legacy::FetchStepFilesGlob) {
+            walk_legacy_fetch_step_files_glob_mut(self, v)
+        }
+        fn visit_legacy_fetch_step_mqtt_mut(&mut self, v: &mut legacy::FetchStepMqtt) {
+            walk_legacy_fetch_step_mqtt_mut(self, v)
+        }
+        fn visit_legacy_fetch_step_url_mut(&mut self, v: &mut legacy::FetchStepUrl) {
+            walk_legacy_fetch_step_url_mut(self, v)
+        }
+        fn visit_legacy_manifest_mut<ContentT: Visitable>(
+            &mut self,
+            v: &mut legacy::Manifest<ContentT>,
+        ) {
+            walk_legacy_manifest_mut(self, v)
+        }
+        fn visit_legacy_set_polling_source_mut(&mut self, v: &mut legacy::SetPollingSource) {
+            walk_legacy_set_polling_source_mut(self, v)
+        }
+        fn visit_protocol_dataset_error_mut(&mut self, v: &mut protocol::DatasetError) {
+            walk_protocol_dataset_error_mut(self, v)
+        }
+        fn visit_protocol_dataset_metadata_pull_response_mut(
+            &mut self,
+            v: &mut protocol::DatasetMetadataPullResponse,
+        ) {
+            walk_protocol_dataset_metadata_pull_response_mut(self, v)
+        }
+        fn visit_protocol_dataset_pull_metadata_request_mut(
+            &mut self,
+            _v: &mut protocol::DatasetPullMetadataRequest,
+        ) {
+        }
+        fn visit_protocol_dataset_pull_objects_transfer_request_mut(
+            &mut self,
+            v: &mut protocol::DatasetPullObjectsTransferRequest,
+        ) {
+            walk_protocol_dataset_pull_objects_transfer_request_mut(self, v)
+        }
+        fn visit_protocol_dataset_pull_objects_transfer_response_mut(
+            &mut self,
+            v: &mut protocol::DatasetPullObjectsTransferResponse,
+        ) {
+            walk_protocol_dataset_pull_objects_transfer_response_mut(self, v)
+        }
+        fn visit_protocol_dataset_pull_request_mut(
+            &mut self,
+            v: &mut protocol::DatasetPullRequest,
+        ) {
+            walk_protocol_dataset_pull_request_mut(self, v)
+        }
+        fn visit_protocol_dataset_pull_response_mut(
+            &mut self,
+            v: &mut protocol::DatasetPullResponse,
+        ) {
+            walk_protocol_dataset_pull_response_mut(self, v)
+        }
+        fn visit_protocol_dataset_push_complete_mut(
+            &mut self,
+            _v: &mut protocol::DatasetPushComplete,
+        ) {
+        }
+        fn visit_protocol_dataset_push_complete_confirmed_mut(
+            &mut self,
+            _v: &mut protocol::DatasetPushCompleteConfirmed,
+        ) {
+        }
+        fn visit_protocol_dataset_push_metadata_mut(
+            &mut self,
+            v: &mut protocol::DatasetPushMetadata,
+        ) {
+            walk_protocol_dataset_push_metadata_mut(self, v)
+        }
+        fn visit_protocol_dataset_push_metadata_accepted_mut(
+            &mut self,
+            _v: &mut protocol::DatasetPushMetadataAccepted,
+        ) {
+        }
+        fn visit_protocol_dataset_push_objects_transfer_request_mut(
+            &mut self,
+            v: &mut protocol::DatasetPushObjectsTransferRequest,
+        ) {
+            walk_protocol_dataset_push_objects_transfer_request_mut(self, v)
+        }
+        fn visit_protocol_dataset_push_objects_transfer_response_mut(
+            &mut self,
+            v: &mut protocol::DatasetPushObjectsTransferResponse,
+        ) {
+            walk_protocol_dataset_push_objects_transfer_response_mut(self, v)
+        }
+        fn visit_protocol_dataset_push_request_mut(
+            &mut self,
+            v: &mut protocol::DatasetPushRequest,
+        ) {
+            walk_protocol_dataset_push_request_mut(self, v)
+        }
+        fn visit_protocol_dataset_push_request_accepted_mut(
+            &mut self,
+            _v: &mut protocol::DatasetPushRequestAccepted,
+        ) {
+        }
+        fn visit_protocol_error_details_mut(&mut self, v: &mut protocol::ErrorDetails) {
+            walk_protocol_error_details_mut(self, v)
+        }
+        fn visit_protocol_object_file_reference_mut(
+            &mut self,
+            v: &mut protocol::ObjectFileReference,
+        ) {
+            walk_protocol_object_file_reference_mut(self, v)
+        }
+        fn visit_protocol_object_pull_strategy_mut(
+            &mut self,
+            _v: &mut protocol::ObjectPullStrategy,
+        ) {
+        }
+        fn visit_protocol_object_push_strategy_mut(
+            &mut self,
+            _v: &mut protocol::ObjectPushStrategy,
+        ) {
+        }
+        fn visit_protocol_object_type_mut(&mut self, _v: &mut protocol::ObjectType) {}
+        fn visit_protocol_objects_batch_mut(&mut self, v: &mut protocol::ObjectsBatch) {
+            walk_protocol_objects_batch_mut(self, v)
+        }
+        fn visit_protocol_pull_object_transfer_strategy_mut(
+            &mut self,
+            v: &mut protocol::PullObjectTransferStrategy,
+        ) {
+            walk_protocol_pull_object_transfer_strategy_mut(self, v)
+        }
+        fn visit_protocol_push_object_transfer_strategy_mut(
+            &mut self,
+            v: &mut protocol::PushObjectTransferStrategy,
+        ) {
+            walk_protocol_push_object_transfer_strategy_mut(self, v)
+        }
+        fn visit_protocol_transfer_size_estimation_mut(
+            &mut self,
+            v: &mut protocol::TransferSizeEstimation,
+        ) {
+            walk_protocol_transfer_size_estimation_mut(self, v)
+        }
+        fn visit_protocol_transfer_url_mut(&mut self, v: &mut protocol::TransferUrl) {
+            walk_protocol_transfer_url_mut(self, v)
+        }
+        fn visit_resource_filter_condition_mut(&mut self, v: &mut resource::FilterCondition) {
+            walk_resource_filter_condition_mut(self, v)
+        }
+        fn visit_resource_label_filter_mut(&mut self, v: &mut resource::LabelFilter) {
+            walk_resource_label_filter_mut(self, v)
+        }
+        fn visit_resource_label_filter_and_mut(&mut self, v: &mut resource::LabelFilterAnd) {
+            walk_resource_label_filter_and_mut(self, v)
+        }
+        fn visit_resource_label_filter_match_mut(&mut self, v: &mut resource::LabelFilterMatch) {
+            walk_resource_label_filter_match_mut(self, v)
+        }
+        fn visit_resource_label_filter_not_mut(&mut self, v: &mut resource::LabelFilterNot) {
+            walk_resource_label_filter_not_mut(self, v)
+        }
+        fn visit_resource_label_filter_or_mut(&mut self, v: &mut resource::LabelFilterOr) {
+            walk_resource_label_filter_or_mut(self, v)
+        }
+        fn visit_resource_resource_mut<SpecT: Visitable>(
+            &mut self,
+            v: &mut resource::Resource<SpecT>,
+        ) {
+            walk_resource_resource_mut(self, v)
+        }
+        fn visit_resource_resource_annotations_mut(
+            &mut self,
+            v: &mut resource::ResourceAnnotations,
+        ) {
+            walk_resource_resource_annotations_mut(self, v)
+        }
+        fn visit_resource_resource_conditions_mut(&mut self, v: &mut resource::ResourceConditions) {
+            walk_resource_resource_conditions_mut(self, v)
+        }
+        fn visit_resource_resource_handle_mut(&mut self, v: &mut resource::ResourceHandle) {
+            walk_resource_resource_handle_mut(self, v)
+        }
+        fn visit_resource_resource_headers_mut(&mut self, v: &mut resource::ResourceHeaders) {
+            walk_resource_resource_headers_mut(self, v)
+        }
+        fn visit_resource_resource_headers_input_mut(
+            &mut self,
+            v: &mut resource::ResourceHeadersInput,
+        ) {
+            walk_resource_resource_headers_input_mut(self, v)
+        }
+        fn visit_resource_resource_input_mut<SpecT: Visitable>(
+            &mut self,
+            v: &mut resource::ResourceInput<SpecT>,
+        ) {
+            walk_resource_resource_input_mut(self, v)
+        }
+        fn visit_resource_resource_labels_mut(&mut self, v: &mut resource::ResourceLabels) {
+            walk_resource_resource_labels_mut(self, v)
+        }
+        fn visit_resource_resource_phase_mut(&mut self, _v: &mut resource::ResourcePhase) {}
+        fn visit_resource_resource_ref_mut(&mut self, v: &mut resource::ResourceRef) {
+            walk_resource_resource_ref_mut(self, v)
+        }
+        fn visit_resource_resource_selector_mut(&mut self, _v: &mut resource::ResourceSelector) {}
+        fn visit_resource_resource_status_mut(&mut self, v: &mut resource::ResourceStatus) {
+            walk_resource_resource_status_mut(self, v)
+        }
+        fn visit_sink_webhook_target_mut(&mut self, v: &mut sink::WebhookTarget) {
+            walk_sink_webhook_target_mut(self, v)
+        }
+        fn visit_sink_webhook_target_spec_mut(&mut self, v: &mut sink::WebhookTargetSpec) {
+            walk_sink_webhook_target_spec_mut(self, v)
+        }
+        fn visit_sink_webhook_target_spec_input_mut(
+            &mut self,
+            v: &mut sink::WebhookTargetSpecInput,
+        ) {
+            walk_sink_webhook_target_spec_input_mut(self, v)
+        }
+        fn visit_sink_webhook_target_status_mut(&mut self, v: &mut sink::WebhookTargetStatus) {
+            walk_sink_webhook_target_status_mut(self, v)
+        }
+        fn visit_sink_webhook_target_status_value_mut(
+            &mut self,
+            _v: &mut sink::WebhookTargetStatusValue,
+        ) {
+        }
+        fn visit_source_compression_format_mut(&mut self, _v: &mut source::CompressionFormat) {}
+        fn visit_source_env_var_mut(&mut self, v: &mut source::EnvVar) {
+            walk_source_env_var_mut(self, v)
+        }
+        fn visit_source_event_time_source_mut(&mut self, v: &mut source::EventTimeSource) {
+            walk_source_event_time_source_mut(self, v)
+        }
+        fn visit_source_event_time_source_from_metadata_mut(
+            &mut self,
+            _v: &mut source::EventTimeSourceFromMetadata,
+        ) {
+        }
+        fn visit_source_event_time_source_from_path_mut(
+            &mut self,
+            v: &mut source::EventTimeSourceFromPath,
+        ) {
+            walk_source_event_time_source_from_path_mut(self, v)
+        }
+        fn visit_source_event_time_source_from_system_time_mut(
+            &mut self,
+            _v: &mut source::EventTimeSourceFromSystemTime,
+        ) {
+        }
+        fn visit_source_ingest_params_mut(&mut self, v: &mut source::IngestParams) {
+            walk_source_ingest_params_mut(self, v)
+        }
+        fn visit_source_ingress_mut(&mut self, v: &mut source::Ingress) {
+            walk_source_ingress_mut(self, v)
+        }
+        fn visit_source_ingress_buffer_mut(&mut self, v: &mut source::IngressBuffer) {
+            walk_source_ingress_buffer_mut(self, v)
+        }
+        fn visit_source_ingress_buffer_memory_mut(&mut self, v: &mut source::IngressBufferMemory) {
+            walk_source_ingress_buffer_memory_mut(self, v)
+        }
+        fn visit_source_ingress_container_mut(&mut self, v: &mut source::IngressContainer) {
+            walk_source_ingress_container_mut(self, v)
+        }
+        fn visit_source_ingress_evm_logs_mut(&mut self, v: &mut source::IngressEvmLogs) {
+            walk_source_ingress_evm_logs_mut(self, v)
+        }
+        fn visit_source_ingress_files_glob_mut(&mut self, v: &mut source::IngressFilesGlob) {
+            walk_source_ingress_files_glob_mut(self, v)
+        }
+        fn visit_source_ingress_mqtt_mut(&mut self, v: &mut source::IngressMqtt) {
+            walk_source_ingress_mqtt_mut(self, v)
+        }
+        fn visit_source_ingress_rest_endpoint_mut(&mut self, v: &mut source::IngressRestEndpoint) {
+            walk_source_ingress_rest_endpoint_mut(self, v)
+        }
+        fn visit_source_ingress_url_mut(&mut self, v: &mut source::IngressUrl) {
+            walk_source_ingress_url_mut(self, v)
+        }
+        fn visit_source_merge_strategy_mut(&mut self, v: &mut source::MergeStrategy) {
+            walk_source_merge_strategy_mut(self, v)
+        }
+        fn visit_source_merge_strategy_append_mut(&mut self, _v: &mut source::MergeStrategyAppend) {
+        }
+        fn visit_source_merge_strategy_changelog_stream_mut(
+            &mut self,
+            v: &mut source::MergeStrategyChangelogStream,
+        ) {
+            walk_source_merge_strategy_changelog_stream_mut(self, v)
+        }
+        fn visit_source_merge_strategy_ledger_mut(&mut self, v: &mut source::MergeStrategyLedger) {
+            walk_source_merge_strategy_ledger_mut(self, v)
+        }
+        fn visit_source_merge_strategy_snapshot_mut(
+            &mut self,
+            v: &mut source::MergeStrategySnapshot,
+        ) {
+            walk_source_merge_strategy_snapshot_mut(self, v)
+        }
+        fn visit_source_merge_strategy_upsert_stream_mut(
+            &mut self,
+            v: &mut source::MergeStrategyUpsertStream,
+        ) {
+            walk_source_merge_strategy_upsert_stream_mut(self, v)
+        }
+        fn visit_source_mqtt_qos_mut(&mut self, _v: &mut source::MqttQos) {}
+        fn visit_source_mqtt_topic_subscription_mut(
+            &mut self,
+            v: &mut source::MqttTopicSubscription,
+        ) {
+            walk_source_mqtt_topic_subscription_mut(self, v)
+        }
+        fn visit_source_prep_step_mut(&mut self, v: &mut source::PrepStep) {
+            walk_source_prep_step_mut(self, v)
+        }
+        fn visit_source_prep_step_decompress_mut(&mut self, v: &mut source::PrepStepDecompress) {
+            walk_source_prep_step_decompress_mut(self, v)
+        }
+        fn visit_source_prep_step_pipe_mut(&mut self, v: &mut source::PrepStepPipe) {
+            walk_source_prep_step_pipe_mut(self, v)
+        }
+        fn visit_source_read_step_mut(&mut self, v: &mut source::ReadStep) {
+            walk_source_read_step_mut(self, v)
+        }
+        fn visit_source_read_step_csv_mut(&mut self, v: &mut source::ReadStepCsv) {
+            walk_source_read_step_csv_mut(self, v)
+        }
+        fn visit_source_read_step_esri_shapefile_mut(
+            &mut self,
+            v: &mut source::ReadStepEsriShapefile,
+        ) {
+            walk_source_read_step_esri_shapefile_mut(self, v)
+        }
+        fn visit_source_read_step_geo_json_mut(&mut self, v: &mut source::ReadStepGeoJson) {
+            walk_source_read_step_geo_json_mut(self, v)
+        }
+        fn visit_source_read_step_json_mut(&mut self, v: &mut source::ReadStepJson) {
+            walk_source_read_step_json_mut(self, v)
+        }
+        fn visit_source_read_step_nd_geo_json_mut(&mut self, v: &mut source::ReadStepNdGeoJson) {
+            walk_source_read_step_nd_geo_json_mut(self, v)
+        }
+        fn visit_source_read_step_nd_json_mut(&mut self, v: &mut source::ReadStepNdJson) {
+            walk_source_read_step_nd_json_mut(self, v)
+        }
+        fn visit_source_read_step_parquet_mut(&mut self, v: &mut source::ReadStepParquet) {
+            walk_source_read_step_parquet_mut(self, v)
+        }
+        fn visit_source_request_header_mut(&mut self, v: &mut source::RequestHeader) {
+            walk_source_request_header_mut(self, v)
+        }
+        fn visit_source_source_mut(&mut self, v: &mut source::Source) {
+            walk_source_source_mut(self, v)
+        }
+        fn visit_source_source_caching_mut(&mut self, v: &mut source::SourceCaching) {
+            walk_source_source_caching_mut(self, v)
+        }
+        fn visit_source_source_caching_forever_mut(
+            &mut self,
+            _v: &mut source::SourceCachingForever,
+        ) {
+        }
+        fn visit_source_source_ordering_mut(&mut self, _v: &mut source::SourceOrdering) {}
+        fn visit_source_source_spec_mut(&mut self, v: &mut source::SourceSpec) {
+            walk_source_source_spec_mut(self, v)
+        }
+        fn visit_source_source_spec_input_mut(&mut self, v: &mut source::SourceSpecInput) {
+            walk_source_source_spec_input_mut(self, v)
+        }
+        fn visit_source_source_state_mut(&mut self, v: &mut source::SourceState) {
+            walk_source_source_state_mut(self, v)
+        }
+        fn visit_storage_aws_credentials_mut(&mut self, v: &mut storage::AwsCredentials) {
+            walk_storage_aws_credentials_mut(self, v)
+        }
+        fn visit_storage_aws_credentials_input_mut(
+            &mut self,
+            v: &mut storage::AwsCredentialsInput,
+        ) {
+            walk_storage_aws_credentials_input_mut(self, v)
+        }
+        fn visit_storage_persistent_volume_mut(&mut self, v: &mut storage::PersistentVolume) {
+            walk_storage_persistent_volume_mut(self, v)
+        }
+        fn visit_storage_persistent_volume_ref_mut(
+            &mut self,
+            v: &mut storage::PersistentVolumeRef,
+        ) {
+            walk_storage_persistent_volume_ref_mut(self, v)
+        }
+        fn visit_storage_persistent_volume_spec_mut(
+            &mut self,
+            v: &mut storage::PersistentVolumeSpec,
+        ) {
+            walk_storage_persistent_volume_spec_mut(self, v)
+        }
+        fn visit_storage_persistent_volume_spec_input_mut(
+            &mut self,
+            v: &mut storage::PersistentVolumeSpecInput,
+        ) {
+            walk_storage_persistent_volume_spec_input_mut(self, v)
+        }
+        fn visit_storage_persistent_volume_spec_input_s_3_mut(
+            &mut self,
+            v: &mut storage::PersistentVolumeSpecInputS3,
+        ) {
+            walk_storage_persistent_volume_spec_input_s_3_mut(self, v)
+        }
+        fn visit_storage_persistent_volume_spec_s_3_mut(
+            &mut self,
+            v: &mut storage::PersistentVolumeSpecS3,
+        ) {
+            walk_storage_persistent_volume_spec_s_3_mut(self, v)
+        }
+        fn visit_storage_volume_capacity_mut(&mut self, v: &mut storage::VolumeCapacity) {
+            walk_storage_volume_capacity_mut(self, v)
+        }
+    }
+
+    pub fn walk_auth_account<V: Visit + ?Sized>(visitor: &mut V, v: &auth::Account) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_auth_account_spec_input(&v.spec);
+    }
+
+    pub fn walk_auth_account_handle<V: Visit + ?Sized>(visitor: &mut V, v: &auth::AccountHandle) {
+        visitor.visit_resource_id(&v.id);
+        visitor.visit_account_id(&v.did);
+        visitor.visit_account_name(&v.name);
+    }
+
+    pub fn walk_auth_account_ref<V: Visit + ?Sized>(visitor: &mut V, v: &auth::AccountRef) {
+        if let Some(x) = &v.id {
+            visitor.visit_resource_id(x);
+        }
+        if let Some(x) = &v.did {
+            visitor.visit_account_id(x);
+        }
+        if let Some(x) = &v.name {
+            visitor.visit_account_name(x);
+        }
+    }
+
+    pub fn walk_auth_account_spec<V: Visit + ?Sized>(visitor: &mut V, v: &auth::AccountSpec) {
+        if let Some(x) = &v.did {
+            visitor.visit_account_id(x);
+        }
+        if let Some(x) = &v.account_type {
+            visitor.visit_auth_account_type(x);
+        }
+        if let Some(x) = &v.display_name {
+            visitor.visit_string(x);
+        }
+        visitor.visit_string(&v.email);
+        if let Some(x) = &v.avatar_url {
+            visitor.visit_url(x);
+        }
+        if let Some(x) = &v.password {
+            visitor.visit_config_secret(x);
+        }
+    }
+
+    pub fn walk_auth_account_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &auth::AccountSpecInput,
+    ) {
+        if let Some(x) = &v.did {
+            visitor.visit_account_id(x);
+        }
+        if let Some(x) = &v.account_type {
+            visitor.visit_auth_account_type(x);
+        }
+        if let Some(x) = &v.display_name {
+            visitor.visit_string(x);
+        }
+        visitor.visit_string(&v.email);
+        if let Some(x) = &v.avatar_url {
+            visitor.visit_url(x);
+        }
+        if let Some(x) = &v.password {
+            visitor.visit_config_secret(x);
+        }
+    }
+
+    pub fn walk_auth_attribute<V: Visit + ?Sized>(visitor: &mut V, v: &auth::Attribute) {
+        visitor.visit_resource_resource_handle(&v.object);
+        visitor.visit_string(&v.name);
+        visitor.visit_any_json(&v.value);
+    }
+
+    pub fn walk_auth_attribute_input<V: Visit + ?Sized>(visitor: &mut V, v: &auth::AttributeInput) {
+        visitor.visit_resource_resource_ref(&v.object);
+        visitor.visit_string(&v.name);
+        visitor.visit_any_json(&v.value);
+    }
+
+    pub fn walk_auth_relation<V: Visit + ?Sized>(visitor: &mut V, v: &auth::Relation) {
+        visitor.visit_resource_resource_handle(&v.subject);
+        visitor.visit_string(&v.relation);
+        if let Some(x) = &v.value {
+            visitor.visit_any_json(x);
+        }
+        visitor.visit_resource_resource_handle(&v.object);
+    }
+
+    pub fn walk_auth_relation_input<V: Visit + ?Sized>(visitor: &mut V, v: &auth::RelationInput) {
+        visitor.visit_resource_resource_ref(&v.subject);
+        visitor.visit_string(&v.relation);
+        if let Some(x) = &v.value {
+            visitor.visit_any_json(x);
+        }
+        visitor.visit_resource_resource_ref(&v.object);
+    }
+
+    pub fn walk_auth_relations<V: Visit + ?Sized>(visitor: &mut V, v: &auth::Relations) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_auth_relations_spec_input(&v.spec);
+    }
+
+    pub fn walk_auth_relations_spec<V: Visit + ?Sized>(visitor: &mut V, v: &auth::RelationsSpec) {
+        for x1 in &v.relations {
+            visitor.visit_auth_relation(x1);
+        }
+        for x1 in &v.attributes {
+            visitor.visit_auth_attribute(x1);
+        }
+    }
+
+    pub fn walk_auth_relations_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &auth::RelationsSpecInput,
+    ) {
+        if let Some(x) = &v.relations {
+            for x1 in x {
+                visitor.visit_auth_relation_input(x1);
+            }
+        }
+        if let Some(x) = &v.attributes {
+            for x1 in x {
+                visitor.visit_auth_attribute_input(x1);
+            }
+        }
+    }
+
+    pub fn walk_config_secret<V: Visit + ?Sized>(visitor: &mut V, v: &config::Secret) {
+        visitor.visit_string(v.value.expose());
+        if let Some(x) = &v.content_encoding {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_config_secret_set<V: Visit + ?Sized>(visitor: &mut V, v: &config::SecretSet) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_config_secret_set_spec_input(&v.spec);
+    }
+
+    pub fn walk_config_secret_set_spec<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &config::SecretSetSpec,
+    ) {
+        visitor.visit_config_secrets(&v.secrets);
+    }
+
+    pub fn walk_config_secret_set_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &config::SecretSetSpecInput,
+    ) {
+        visitor.visit_config_secrets(&v.secrets);
+    }
+
+    pub fn walk_config_secrets<V: Visit + ?Sized>(visitor: &mut V, v: &config::Secrets) {
+        for x in v.entries.values() {
+            visitor.visit_config_secret(x);
+        }
+    }
+
+    pub fn walk_config_value_handle<V: Visit + ?Sized>(visitor: &mut V, v: &config::ValueHandle) {
+        visitor.visit_auth_account_handle(&v.account);
+        visitor.visit_type_uri(&v.r#type);
+        visitor.visit_resource_id(&v.id);
+        visitor.visit_resource_name(&v.name);
+        if let Some(x) = &v.path {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_config_value_ref<V: Visit + ?Sized>(visitor: &mut V, v: &config::ValueRef) {
+        if let Some(x) = &v.account {
+            visitor.visit_auth_account_ref(x);
+        }
+        visitor.visit_type_ref(&v.r#type);
+        if let Some(x) = &v.id {
+            visitor.visit_resource_id(x);
+        }
+        if let Some(x) = &v.name {
+            visitor.visit_resource_name(x);
+        }
+        if let Some(x) = &v.path {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_config_value_refs<V: Visit + ?Sized>(visitor: &mut V, v: &config::ValueRefs) {
+        for x in v.entries.values() {
+            visitor.visit_config_value_ref(x);
+        }
+    }
+
+    pub fn walk_config_variable<V: Visit + ?Sized>(visitor: &mut V, v: &config::Variable) {
+        visitor.visit_string(&v.value);
+    }
+
+    pub fn walk_config_variable_set<V: Visit + ?Sized>(visitor: &mut V, v: &config::VariableSet) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_config_variable_set_spec_input(&v.spec);
+    }
+
+    pub fn walk_config_variable_set_spec<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &config::VariableSetSpec,
+    ) {
+        visitor.visit_config_variables(&v.variables);
+    }
+
+    pub fn walk_config_variable_set_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &config::VariableSetSpecInput,
+    ) {
+        visitor.visit_config_variables(&v.variables);
+    }
+
+    pub fn walk_config_variables<V: Visit + ?Sized>(visitor: &mut V, v: &config::Variables) {
+        for x in v.entries.values() {
+            visitor.visit_config_variable(x);
+        }
+    }
+
+    pub fn walk_data_data_field<V: Visit + ?Sized>(visitor: &mut V, v: &data::DataField) {
+        visitor.visit_string(&v.name);
+        visitor.visit_data_data_type(&v.r#type);
+        if let Some(x) = &v.extra {
+            visitor.visit_data_extra_attributes(x);
+        }
+    }
+
+    pub fn walk_data_data_schema<V: Visit + ?Sized>(visitor: &mut V, v: &data::DataSchema) {
+        for x1 in &v.fields {
+            visitor.visit_data_data_field(x1);
+        }
+        if let Some(x) = &v.extra {
+            visitor.visit_data_extra_attributes(x);
+        }
+    }
+
+    pub fn walk_data_data_type<V: Visit + ?Sized>(visitor: &mut V, v: &data::DataType) {
+        match v {
+            data::DataType::Binary(x) => visitor.visit_data_data_type_binary(x),
+            data::DataType::Bool(x) => visitor.visit_data_data_type_bool(x),
+            data::DataType::Date(x) => visitor.visit_data_data_type_date(x),
+            data::DataType::Decimal(x) => visitor.visit_data_data_type_decimal(x),
+            data::DataType::Duration(x) => visitor.visit_data_data_type_duration(x),
+            data::DataType::Float16(x) => visitor.visit_data_data_type_float_16(x),
+            data::DataType::Float32(x) => visitor.visit_data_data_type_float_32(x),
+            data::DataType::Float64(x) => visitor.visit_data_data_type_float_64(x),
+            data::DataType::Int8(x) => visitor.visit_data_data_type_int_8(x),
+            data::DataType::Int16(x) => visitor.visit_data_data_type_int_16(x),
+            data::DataType::Int32(x) => visitor.visit_data_data_type_int_32(x),
+            data::DataType::Int64(x) => visitor.visit_data_data_type_int_64(x),
+            data::DataType::UInt8(x) => visitor.visit_data_data_type_u_int_8(x),
+            data::DataType::UInt16(x) => visitor.visit_data_data_type_u_int_16(x),
+            data::DataType::UInt32(x) => visitor.visit_data_data_type_u_int_32(x),
+            data::DataType::UInt64(x) => visitor.visit_data_data_type_u_int_64(x),
+            data::DataType::List(x) => visitor.visit_data_data_type_list(x),
+            data::DataType::Map(x) => visitor.visit_data_data_type_map(x),
+            data::DataType::Null(x) => visitor.visit_data_data_type_null(x),
+            data::DataType::Option(x) => visitor.visit_data_data_type_option(x),
+            data::DataType::Struct(x) => visitor.visit_data_data_type_struct(x),
+            data::DataType::Time(x) => visitor.visit_data_data_type_time(x),
+            data::DataType::Timestamp(x) => visitor.visit_data_data_type_timestamp(x),
+            data::DataType::String(x) => visitor.visit_data_data_type_string(x),
+        }
+    }
+
+    pub fn walk_data_data_type_binary<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &data::DataTypeBinary,
+    ) {
+        if let Some(x) = &v.fixed_length {
+            visitor.visit_u64(x);
+        }
+    }
+
+    pub fn walk_data_data_type_decimal<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &data::DataTypeDecimal,
+    ) {
+        visitor.visit_u32(&v.precision);
+        visitor.visit_i32(&v.scale);
+    }
+
+    pub fn walk_data_data_type_duration<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &data::DataTypeDuration,
+    ) {
+        if let Some(x) = &v.unit {
+            visitor.visit_data_time_unit(x);
+        }
+    }
+
+    pub fn walk_data_data_type_list<V: Visit + ?Sized>(visitor: &mut V, v: &data::DataTypeList) {
+        visitor.visit_data_data_type(&v.item_type);
+        if let Some(x) = &v.fixed_length {
+            visitor.visit_u64(x);
+        }
+    }
+
+    pub fn walk_data_data_type_map<V: Visit + ?Sized>(visitor: &mut V, v: &data::DataTypeMap) {
+        visitor.visit_data_data_type(&v.key_type);
+        visitor.visit_data_data_type(&v.value_type);
+        if let Some(x) = &v.keys_sorted {
+            visitor.visit_bool(x);
+        }
+    }
+
+    pub fn walk_data_data_type_option<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &data::DataTypeOption,
+    ) {
+        visitor.visit_data_data_type(&v.inner);
+    }
+
+    pub fn walk_data_data_type_struct<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &data::DataTypeStruct,
+    ) {
+        for x1 in &v.fields {
+            visitor.visit_data_data_field(x1);
+        }
+    }
+
+    pub fn walk_data_data_type_time<V: Visit + ?Sized>(visitor: &mut V, v: &data::DataTypeTime) {
+        if let Some(x) = &v.unit {
+            visitor.visit_data_time_unit(x);
+        }
+    }
+
+    pub fn walk_data_data_type_timestamp<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &data::DataTypeTimestamp,
+    ) {
+        if let Some(x) = &v.unit {
+            visitor.visit_data_time_unit(x);
+        }
+        if let Some(x) = &v.timezone {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_data_extra_attributes<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &data::ExtraAttributes,
+    ) {
+        for x in v.entries.values() {
+            visitor.visit_any_json(x);
+        }
+    }
+
+    pub fn walk_dataset_add_data<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::AddData) {
+        if let Some(x) = &v.prev_checkpoint {
+            visitor.visit_multihash(x);
+        }
+        if let Some(x) = &v.prev_offset {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.new_data {
+            visitor.visit_dataset_data_slice(x);
+        }
+        if let Some(x) = &v.new_checkpoint {
+            visitor.visit_dataset_checkpoint(x);
+        }
+        if let Some(x) = &v.new_watermark {
+            visitor.visit_date_time(x);
+        }
+        if let Some(x) = &v.new_source_state {
+            visitor.visit_source_source_state(x);
+        }
+        if let Some(x) = &v.extra {
+            visitor.visit_data_extra_attributes(x);
+        }
+    }
+
+    pub fn walk_dataset_attachment_embedded<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::AttachmentEmbedded,
+    ) {
+        visitor.visit_string(&v.path);
+        visitor.visit_string(&v.content);
+    }
+
+    pub fn walk_dataset_attachments<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::Attachments) {
+        match v {
+            dataset::Attachments::Embedded(x) => visitor.visit_dataset_attachments_embedded(x),
+        }
+    }
+
+    pub fn walk_dataset_attachments_embedded<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::AttachmentsEmbedded,
+    ) {
+        for x1 in &v.items {
+            visitor.visit_dataset_attachment_embedded(x1);
+        }
+    }
+
+    pub fn walk_dataset_checkpoint<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::Checkpoint) {
+        visitor.visit_multihash(&v.physical_hash);
+        visitor.visit_u64(&v.size);
+    }
+
+    pub fn walk_dataset_compaction_params<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::CompactionParams,
+    ) {
+        if let Some(x) = &v.max_slice_size {
+            visitor.visit_byte_size(x);
+        }
+        if let Some(x) = &v.max_slice_records {
+            visitor.visit_u64(x);
+        }
+    }
+
+    pub fn walk_dataset_data_slice<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::DataSlice) {
+        visitor.visit_multihash(&v.logical_hash);
+        visitor.visit_multihash(&v.physical_hash);
+        visitor.visit_dataset_offset_interval(&v.offset_interval);
+        visitor.visit_u64(&v.size);
+    }
+
+    pub fn walk_dataset_dataset<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::Dataset) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_dataset_dataset_spec_input(&v.spec);
+    }
+
+    pub fn walk_dataset_dataset_spec<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::DatasetSpec) {
+        visitor.visit_dataset_id(&v.did);
+        visitor.visit_dataset_dataset_kind(&v.kind);
+        for x1 in &v.metadata {
+            visitor.visit_dataset_metadata_event(x1);
+        }
+        visitor.visit_resource_resource_handle(&v.volume);
+    }
+
+    pub fn walk_dataset_dataset_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::DatasetSpecInput,
+    ) {
+        if let Some(x) = &v.did {
+            visitor.visit_dataset_id(x);
+        }
+        visitor.visit_dataset_dataset_kind(&v.kind);
+        for x1 in &v.metadata {
+            visitor.visit_dataset_metadata_event(x1);
+        }
+        if let Some(x) = &v.volume {
+            visitor.visit_storage_persistent_volume_ref(x);
+        }
+    }
+
+    pub fn walk_dataset_dataset_vocabulary<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::DatasetVocabulary,
+    ) {
+        if let Some(x) = &v.offset_column {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.operation_type_column {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.system_time_column {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.event_time_column {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_dataset_execute_transform<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::ExecuteTransform,
+    ) {
+        for x1 in &v.query_inputs {
+            visitor.visit_dataset_execute_transform_input(x1);
+        }
+        if let Some(x) = &v.prev_checkpoint {
+            visitor.visit_multihash(x);
+        }
+        if let Some(x) = &v.prev_offset {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.new_data {
+            visitor.visit_dataset_data_slice(x);
+        }
+        if let Some(x) = &v.new_checkpoint {
+            visitor.visit_dataset_checkpoint(x);
+        }
+        if let Some(x) = &v.new_watermark {
+            visitor.visit_date_time(x);
+        }
+    }
+
+    pub fn walk_dataset_execute_transform_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::ExecuteTransformInput,
+    ) {
+        visitor.visit_dataset_id(&v.dataset_id);
+        if let Some(x) = &v.prev_block_hash {
+            visitor.visit_multihash(x);
+        }
+        if let Some(x) = &v.new_block_hash {
+            visitor.visit_multihash(x);
+        }
+        if let Some(x) = &v.prev_offset {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.new_offset {
+            visitor.visit_u64(x);
+        }
+    }
+
+    pub fn walk_dataset_metadata_block<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::MetadataBlock,
+    ) {
+        visitor.visit_date_time(&v.system_time);
+        if let Some(x) = &v.prev_block_hash {
+            visitor.visit_multihash(x);
+        }
+        visitor.visit_u64(&v.sequence_number);
+        visitor.visit_dataset_metadata_event(&v.event);
+    }
+
+    pub fn walk_dataset_metadata_event<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::MetadataEvent,
+    ) {
+        match v {
+            dataset::MetadataEvent::AddData(x) => visitor.visit_dataset_add_data(x),
+            dataset::MetadataEvent::ExecuteTransform(x) => {
+                visitor.visit_dataset_execute_transform(x)
+            }
+            dataset::MetadataEvent::Seed(x) => visitor.visit_dataset_seed(x),
+            dataset::MetadataEvent::SetPollingSource(x) => {
+                visitor.visit_legacy_set_polling_source(x)
+            }
+            dataset::MetadataEvent::SetTransform(x) => visitor.visit_dataset_set_transform(x),
+            dataset::MetadataEvent::SetVocab(x) => visitor.visit_dataset_set_vocab(x),
+            dataset::MetadataEvent::SetAttachments(x) => visitor.visit_dataset_set_attachments(x),
+            dataset::MetadataEvent::SetInfo(x) => visitor.visit_dataset_set_info(x),
+            dataset::MetadataEvent::SetLicense(x) => visitor.visit_dataset_set_license(x),
+            dataset::MetadataEvent::SetDataSchema(x) => visitor.visit_dataset_set_data_schema(x),
+            dataset::MetadataEvent::AddPushSource(x) => visitor.visit_legacy_add_push_source(x),
+            dataset::MetadataEvent::DisablePushSource(x) => {
+                visitor.visit_legacy_disable_push_source(x)
+            }
+            dataset::MetadataEvent::DisablePollingSource(x) => {
+                visitor.visit_legacy_disable_polling_source(x)
+            }
+            dataset::MetadataEvent::Unknown(_) => {}
+        }
+    }
+
+    pub fn walk_dataset_offset_interval<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::OffsetInterval,
+    ) {
+        visitor.visit_u64(&v.start);
+        visitor.visit_u64(&v.end);
+    }
+
+    pub fn walk_dataset_projection<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::Projection) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_dataset_projection_spec_input(&v.spec);
+    }
+
+    pub fn walk_dataset_projection_spec<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::ProjectionSpec,
+    ) {
+        for x1 in &v.inputs {
+            visitor.visit_dataset_transform_input(x1);
+        }
+        visitor.visit_dataset_transform(&v.project);
+    }
+
+    pub fn walk_dataset_projection_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::ProjectionSpecInput,
+    ) {
+        for x1 in &v.inputs {
+            visitor.visit_dataset_transform_input(x1);
+        }
+        visitor.visit_dataset_transform(&v.project);
+    }
+
+    pub fn walk_dataset_seed<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::Seed) {
+        visitor.visit_dataset_id(&v.dataset_id);
+        visitor.visit_dataset_dataset_kind(&v.dataset_kind);
+    }
+
+    pub fn walk_dataset_set_attachments<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::SetAttachments,
+    ) {
+        visitor.visit_dataset_attachments(&v.attachments);
+    }
+
+    pub fn walk_dataset_set_data_schema<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::SetDataSchema,
+    ) {
+        if let Some(x) = &v.raw_arrow_schema {
+            visitor.visit_flatbuffers(x);
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_dataset_set_info<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::SetInfo) {
+        if let Some(x) = &v.description {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.keywords {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+    }
+
+    pub fn walk_dataset_set_license<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::SetLicense) {
+        visitor.visit_string(&v.short_name);
+        visitor.visit_string(&v.name);
+        if let Some(x) = &v.spdx_id {
+            visitor.visit_string(x);
+        }
+        visitor.visit_url(&v.website_url);
+    }
+
+    pub fn walk_dataset_set_transform<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::SetTransform,
+    ) {
+        for x1 in &v.inputs {
+            visitor.visit_dataset_transform_input(x1);
+        }
+        visitor.visit_dataset_transform(&v.transform);
+    }
+
+    pub fn walk_dataset_set_vocab<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::SetVocab) {
+        if let Some(x) = &v.offset_column {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.operation_type_column {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.system_time_column {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.event_time_column {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_dataset_sql_query_step<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::SqlQueryStep,
+    ) {
+        if let Some(x) = &v.alias {
+            visitor.visit_string(x);
+        }
+        visitor.visit_string(&v.query);
+    }
+
+    pub fn walk_dataset_temporal_table<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::TemporalTable,
+    ) {
+        visitor.visit_string(&v.name);
+        for x1 in &v.primary_key {
+            visitor.visit_string(x1);
+        }
+    }
+
+    pub fn walk_dataset_transform<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::Transform) {
+        match v {
+            dataset::Transform::Sql(x) => visitor.visit_dataset_transform_sql(x),
+        }
+    }
+
+    pub fn walk_dataset_transform_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::TransformInput,
+    ) {
+        visitor.visit_dataset_ref(&v.dataset_ref);
+        if let Some(x) = &v.alias {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_dataset_transform_sql<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &dataset::TransformSql,
+    ) {
+        visitor.visit_string(&v.engine);
+        if let Some(x) = &v.version {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.query {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.queries {
+            for x1 in x {
+                visitor.visit_dataset_sql_query_step(x1);
+            }
+        }
+        if let Some(x) = &v.temporal_tables {
+            for x1 in x {
+                visitor.visit_dataset_temporal_table(x1);
+            }
+        }
+    }
+
+    pub fn walk_dataset_watermark<V: Visit + ?Sized>(visitor: &mut V, v: &dataset::Watermark) {
+        visitor.visit_date_time(&v.system_time);
+        visitor.visit_date_time(&v.event_time);
+    }
+
+    pub fn walk_engine_raw_query_request<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::RawQueryRequest,
+    ) {
+        for x1 in &v.input_data_paths {
+            visitor.visit_path(x1);
+        }
+        visitor.visit_dataset_transform(&v.transform);
+        visitor.visit_path(&v.output_data_path);
+    }
+
+    pub fn walk_engine_raw_query_response<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::RawQueryResponse,
+    ) {
+        match v {
+            engine::RawQueryResponse::Progress(x) => {
+                visitor.visit_engine_raw_query_response_progress(x)
+            }
+            engine::RawQueryResponse::Success(x) => {
+                visitor.visit_engine_raw_query_response_success(x)
+            }
+            engine::RawQueryResponse::InvalidQuery(x) => {
+                visitor.visit_engine_raw_query_response_invalid_query(x)
+            }
+            engine::RawQueryResponse::InternalError(x) => {
+                visitor.visit_engine_raw_query_response_internal_error(x)
+            }
+        }
+    }
+
+    pub fn walk_engine_raw_query_response_internal_error<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::RawQueryResponseInternalError,
+    ) {
+        visitor.visit_string(&v.message);
+        if let Some(x) = &v.backtrace {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_engine_raw_query_response_invalid_query<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::RawQueryResponseInvalidQuery,
+    ) {
+        visitor.visit_string(&v.message);
+    }
+
+    pub fn walk_engine_raw_query_response_success<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::RawQueryResponseSuccess,
+    ) {
+        visitor.visit_u64(&v.num_records);
+    }
+
+    pub fn walk_engine_transform_request<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::TransformRequest,
+    ) {
+        visitor.visit_dataset_id(&v.dataset_id);
+        visitor.visit_dataset_alias(&v.dataset_alias);
+        visitor.visit_date_time(&v.system_time);
+        visitor.visit_dataset_dataset_vocabulary(&v.vocab);
+        visitor.visit_dataset_transform(&v.transform);
+        for x1 in &v.query_inputs {
+            visitor.visit_engine_transform_request_input(x1);
+        }
+        visitor.visit_u64(&v.next_offset);
+        if let Some(x) = &v.prev_checkpoint_path {
+            visitor.visit_path(x);
+        }
+        visitor.visit_path(&v.new_checkpoint_path);
+        visitor.visit_path(&v.new_data_path);
+    }
+
+    pub fn walk_engine_transform_request_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::TransformRequestInput,
+    ) {
+        visitor.visit_dataset_id(&v.dataset_id);
+        visitor.visit_dataset_alias(&v.dataset_alias);
+        visitor.visit_string(&v.query_alias);
+        visitor.visit_dataset_dataset_vocabulary(&v.vocab);
+        if let Some(x) = &v.offset_interval {
+            visitor.visit_dataset_offset_interval(x);
+        }
+        for x1 in &v.data_paths {
+            visitor.visit_path(x1);
+        }
+        visitor.visit_path(&v.schema_file);
+        for x1 in &v.explicit_watermarks {
+            visitor.visit_dataset_watermark(x1);
+        }
+    }
+
+    pub fn walk_engine_transform_response<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::TransformResponse,
+    ) {
+        match v {
+            engine::TransformResponse::Progress(x) => {
+                visitor.visit_engine_transform_response_progress(x)
+            }
+            engine::TransformResponse::Success(x) => {
+                visitor.visit_engine_transform_response_success(x)
+            }
+            engine::TransformResponse::InvalidQuery(x) => {
+                visitor.visit_engine_transform_response_invalid_query(x)
+            }
+            engine::TransformResponse::InternalError(x) => {
+                visitor.visit_engine_transform_response_internal_error(x)
+            }
+        }
+    }
+
+    pub fn walk_engine_transform_response_internal_error<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::TransformResponseInternalError,
+    ) {
+        visitor.visit_string(&v.message);
+        if let Some(x) = &v.backtrace {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_engine_transform_response_invalid_query<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::TransformResponseInvalidQuery,
+    ) {
+        visitor.visit_string(&v.message);
+    }
+
+    pub fn walk_engine_transform_response_success<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &engine::TransformResponseSuccess,
+    ) {
+        if let Some(x) = &v.new_offset_interval {
+            visitor.visit_dataset_offset_interval(x);
+        }
+        if let Some(x) = &v.new_watermark {
+            visitor.visit_date_time(x);
+        }
+    }
+
+    pub fn walk_event_event_filter<V: Visit + ?Sized>(visitor: &mut V, v: &event::EventFilter) {
+        match v {
+            event::EventFilter::And(x) => visitor.visit_event_event_filter_and(x),
+            event::EventFilter::Or(x) => visitor.visit_event_event_filter_or(x),
+            event::EventFilter::Not(x) => visitor.visit_event_event_filter_not(x),
+            event::EventFilter::Match(x) => visitor.visit_event_event_filter_match(x),
+        }
+    }
+
+    pub fn walk_event_event_filter_and<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &event::EventFilterAnd,
+    ) {
+        for x1 in &v.and {
+            visitor.visit_event_event_filter(x1);
+        }
+    }
+
+    pub fn walk_event_event_filter_match<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &event::EventFilterMatch,
+    ) {
+        for x in v.entries.values() {
+            visitor.visit_resource_filter_condition(x);
+        }
+    }
+
+    pub fn walk_event_event_filter_not<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &event::EventFilterNot,
+    ) {
+        visitor.visit_event_event_filter(&v.not);
+    }
+
+    pub fn walk_event_event_filter_or<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &event::EventFilterOr,
+    ) {
+        for x1 in &v.or {
+            visitor.visit_event_event_filter(x1);
+        }
+    }
+
+    pub fn walk_flow_flow<V: Visit + ?Sized>(visitor: &mut V, v: &flow::Flow) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_flow_flow_spec_input(&v.spec);
+    }
+
+    pub fn walk_flow_flow_spec<V: Visit + ?Sized>(visitor: &mut V, v: &flow::FlowSpec) {
+        visitor.visit_resource_resource_selector(&v.target);
+        for x1 in &v.triggers {
+            visitor.visit_flow_flow_trigger(x1);
+        }
+        for x1 in &v.tasks {
+            visitor.visit_flow_task_spec(x1);
+        }
+    }
+
+    pub fn walk_flow_flow_spec_input<V: Visit + ?Sized>(visitor: &mut V, v: &flow::FlowSpecInput) {
+        visitor.visit_resource_resource_selector(&v.target);
+        for x1 in &v.triggers {
+            visitor.visit_flow_flow_trigger_input(x1);
+        }
+        for x1 in &v.tasks {
+            visitor.visit_flow_task_spec_input(x1);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger<V: Visit + ?Sized>(visitor: &mut V, v: &flow::FlowTrigger) {
+        match v {
+            flow::FlowTrigger::Schedule(x) => visitor.visit_flow_flow_trigger_schedule(x),
+            flow::FlowTrigger::Event(x) => visitor.visit_flow_flow_trigger_event(x),
+            flow::FlowTrigger::Source(x) => visitor.visit_flow_flow_trigger_source(x),
+            flow::FlowTrigger::Dataset(x) => visitor.visit_flow_flow_trigger_dataset(x),
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_dataset<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerDataset,
+    ) {
+        visitor.visit_dataset_dataset_selector(&v.dataset);
+        if let Some(x) = &v.events {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_event<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerEvent,
+    ) {
+        visitor.visit_event_event_filter(&v.events);
+        if let Some(x) = &v.cooldown {
+            visitor.visit_duration(x);
+        }
+        if let Some(x) = &v.cooldown_max_batch {
+            visitor.visit_u64(x);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerInput,
+    ) {
+        match v {
+            flow::FlowTriggerInput::Schedule(x) => {
+                visitor.visit_flow_flow_trigger_input_schedule(x)
+            }
+            flow::FlowTriggerInput::Event(x) => visitor.visit_flow_flow_trigger_input_event(x),
+            flow::FlowTriggerInput::Source(x) => visitor.visit_flow_flow_trigger_input_source(x),
+            flow::FlowTriggerInput::Dataset(x) => visitor.visit_flow_flow_trigger_input_dataset(x),
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input_dataset<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerInputDataset,
+    ) {
+        visitor.visit_dataset_dataset_selector(&v.dataset);
+        if let Some(x) = &v.events {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input_event<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerInputEvent,
+    ) {
+        visitor.visit_event_event_filter(&v.events);
+        if let Some(x) = &v.cooldown {
+            visitor.visit_duration(x);
+        }
+        if let Some(x) = &v.cooldown_max_batch {
+            visitor.visit_u64(x);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input_schedule<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerInputSchedule,
+    ) {
+        visitor.visit_string(&v.cron);
+    }
+
+    pub fn walk_flow_flow_trigger_input_source<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerInputSource,
+    ) {
+        visitor.visit_resource_resource_ref(&v.source);
+        if let Some(x) = &v.min_records_to_await {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.max_await_interval {
+            visitor.visit_duration(x);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_schedule<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerSchedule,
+    ) {
+        visitor.visit_string(&v.cron);
+    }
+
+    pub fn walk_flow_flow_trigger_source<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::FlowTriggerSource,
+    ) {
+        visitor.visit_resource_resource_handle(&v.source);
+        if let Some(x) = &v.min_records_to_await {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.max_await_interval {
+            visitor.visit_duration(x);
+        }
+    }
+
+    pub fn walk_flow_task<V: Visit + ?Sized>(visitor: &mut V, v: &flow::Task) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        if let Some(x) = &v.spec {
+            visitor.visit_flow_task_spec_input(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec<V: Visit + ?Sized>(visitor: &mut V, v: &flow::TaskSpec) {
+        match v {
+            flow::TaskSpec::Ingest(x) => visitor.visit_flow_task_spec_ingest(x),
+            flow::TaskSpec::Compaction(x) => visitor.visit_flow_task_spec_compaction(x),
+            flow::TaskSpec::GarbageCollection(x) => {
+                visitor.visit_flow_task_spec_garbage_collection(x)
+            }
+            flow::TaskSpec::WebhookCall(x) => visitor.visit_flow_task_spec_webhook_call(x),
+        }
+    }
+
+    pub fn walk_flow_task_spec_compaction<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::TaskSpecCompaction,
+    ) {
+        if let Some(x) = &v.params {
+            visitor.visit_dataset_compaction_params(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_ingest<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::TaskSpecIngest,
+    ) {
+        visitor.visit_resource_resource_handle(&v.source);
+        if let Some(x) = &v.params {
+            visitor.visit_source_ingest_params(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_input<V: Visit + ?Sized>(visitor: &mut V, v: &flow::TaskSpecInput) {
+        match v {
+            flow::TaskSpecInput::Ingest(x) => visitor.visit_flow_task_spec_input_ingest(x),
+            flow::TaskSpecInput::Compaction(x) => visitor.visit_flow_task_spec_input_compaction(x),
+            flow::TaskSpecInput::GarbageCollection(x) => {
+                visitor.visit_flow_task_spec_input_garbage_collection(x)
+            }
+            flow::TaskSpecInput::WebhookCall(x) => {
+                visitor.visit_flow_task_spec_input_webhook_call(x)
+            }
+        }
+    }
+
+    pub fn walk_flow_task_spec_input_compaction<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::TaskSpecInputCompaction,
+    ) {
+        if let Some(x) = &v.params {
+            visitor.visit_dataset_compaction_params(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_input_ingest<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::TaskSpecInputIngest,
+    ) {
+        visitor.visit_resource_resource_ref(&v.source);
+        if let Some(x) = &v.params {
+            visitor.visit_source_ingest_params(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_input_webhook_call<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::TaskSpecInputWebhookCall,
+    ) {
+        visitor.visit_resource_resource_ref(&v.target);
+        if let Some(x) = &v.payload {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_webhook_call<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &flow::TaskSpecWebhookCall,
+    ) {
+        visitor.visit_resource_resource_handle(&v.target);
+        if let Some(x) = &v.payload {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_legacy_add_push_source<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::AddPushSource,
+    ) {
+        visitor.visit_string(&v.source_name);
+        visitor.visit_source_read_step(&v.read);
+        if let Some(x) = &v.preprocess {
+            visitor.visit_dataset_transform(x);
+        }
+        visitor.visit_source_merge_strategy(&v.merge);
+    }
+
+    pub fn walk_legacy_dataset_snapshot<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::DatasetSnapshot,
+    ) {
+        visitor.visit_dataset_alias(&v.name);
+        visitor.visit_dataset_dataset_kind(&v.kind);
+        for x1 in &v.metadata {
+            visitor.visit_dataset_metadata_event(x1);
+        }
+    }
+
+    pub fn walk_legacy_disable_push_source<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::DisablePushSource,
+    ) {
+        visitor.visit_string(&v.source_name);
+    }
+
+    pub fn walk_legacy_fetch_step<V: Visit + ?Sized>(visitor: &mut V, v: &legacy::FetchStep) {
+        match v {
+            legacy::FetchStep::Url(x) => visitor.visit_legacy_fetch_step_url(x),
+            legacy::FetchStep::FilesGlob(x) => visitor.visit_legacy_fetch_step_files_glob(x),
+            legacy::FetchStep::Container(x) => visitor.visit_legacy_fetch_step_container(x),
+            legacy::FetchStep::Mqtt(x) => visitor.visit_legacy_fetch_step_mqtt(x),
+            legacy::FetchStep::EthereumLogs(x) => visitor.visit_legacy_fetch_step_ethereum_logs(x),
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_container<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::FetchStepContainer,
+    ) {
+        visitor.visit_string(&v.image);
+        if let Some(x) = &v.command {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.args {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.env {
+            for x1 in x {
+                visitor.visit_source_env_var(x1);
+            }
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_ethereum_logs<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::FetchStepEthereumLogs,
+    ) {
+        if let Some(x) = &v.chain_id {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.node_url {
+            visitor.visit_url(x);
+        }
+        if let Some(x) = &v.filter {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.signature {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_files_glob<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::FetchStepFilesGlob,
+    ) {
+        visitor.visit_string(&v.path);
+        if let Some(x) = &v.event_time {
+            visitor.visit_source_event_time_source(x);
+        }
+        if let Some(x) = &v.cache {
+            visitor.visit_source_source_caching(x);
+        }
+        if let Some(x) = &v.order {
+            visitor.visit_source_source_ordering(x);
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_mqtt<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::FetchStepMqtt,
+    ) {
+        visitor.visit_string(&v.host);
+        visitor.visit_i32(&v.port);
+        if let Some(x) = &v.username {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.password {
+            visitor.visit_string(x.expose());
+        }
+        for x1 in &v.topics {
+            visitor.visit_source_mqtt_topic_subscription(x1);
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_url<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::FetchStepUrl,
+    ) {
+        visitor.visit_url(&v.url);
+        if let Some(x) = &v.event_time {
+            visitor.visit_source_event_time_source(x);
+        }
+        if let Some(x) = &v.cache {
+            visitor.visit_source_source_caching(x);
+        }
+        if let Some(x) = &v.headers {
+            for x1 in x {
+                visitor.visit_source_request_header(x1);
+            }
+        }
+    }
+
+    pub fn walk_legacy_manifest<V: Visit + ?Sized, ContentT: Visitable>(
+        visitor: &mut V,
+        v: &legacy::Manifest<ContentT>,
+    ) {
+        visitor.visit_multicodec(&v.kind);
+        visitor.visit_i32(&v.version);
+        v.content.accept(visitor);
+    }
+
+    pub fn walk_legacy_set_polling_source<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &legacy::SetPollingSource,
+    ) {
+        visitor.visit_legacy_fetch_step(&v.fetch);
+        if let Some(x) = &v.prepare {
+            for x1 in x {
+                visitor.visit_source_prep_step(x1);
+            }
+        }
+        visitor.visit_source_read_step(&v.read);
+        if let Some(x) = &v.preprocess {
+            visitor.visit_dataset_transform(x);
+        }
+        visitor.visit_source_merge_strategy(&v.merge);
+    }
+
+    pub fn walk_protocol_dataset_error<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetError,
+    ) {
+        visitor.visit_protocol_error_details(&v.error_details);
+    }
+
+    pub fn walk_protocol_dataset_metadata_pull_response<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetMetadataPullResponse,
+    ) {
+        visitor.visit_protocol_objects_batch(&v.blocks);
+    }
+
+    pub fn walk_protocol_dataset_pull_objects_transfer_request<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPullObjectsTransferRequest,
+    ) {
+        for x1 in &v.object_files {
+            visitor.visit_protocol_object_file_reference(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_pull_objects_transfer_response<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPullObjectsTransferResponse,
+    ) {
+        for x1 in &v.object_transfer_strategies {
+            visitor.visit_protocol_pull_object_transfer_strategy(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_pull_request<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPullRequest,
+    ) {
+        visitor.visit_dataset_id(&v.dataset_id);
+        if let Some(x) = &v.begin_after {
+            visitor.visit_multihash(x);
+        }
+        if let Some(x) = &v.stop_at {
+            visitor.visit_multihash(x);
+        }
+    }
+
+    pub fn walk_protocol_dataset_pull_response<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPullResponse,
+    ) {
+        visitor.visit_protocol_transfer_size_estimation(&v.size_estimation);
+    }
+
+    pub fn walk_protocol_dataset_push_metadata<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPushMetadata,
+    ) {
+        visitor.visit_protocol_objects_batch(&v.new_blocks);
+    }
+
+    pub fn walk_protocol_dataset_push_objects_transfer_request<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPushObjectsTransferRequest,
+    ) {
+        for x1 in &v.object_files {
+            visitor.visit_protocol_object_file_reference(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_push_objects_transfer_response<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPushObjectsTransferResponse,
+    ) {
+        for x1 in &v.object_transfer_strategies {
+            visitor.visit_protocol_push_object_transfer_strategy(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_push_request<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::DatasetPushRequest,
+    ) {
+        visitor.visit_dataset_id(&v.dataset_id);
+        visitor.visit_multihash(&v.current_head);
+        visitor.visit_protocol_transfer_size_estimation(&v.size_estimation);
+    }
+
+    pub fn walk_protocol_error_details<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::ErrorDetails,
+    ) {
+        visitor.visit_string(&v.error_code);
+        visitor.visit_string(&v.description);
+        if let Some(x) = &v.extras {
+            visitor.visit_any_json(x);
+        }
+    }
+
+    pub fn walk_protocol_object_file_reference<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::ObjectFileReference,
+    ) {
+        visitor.visit_protocol_object_type(&v.object_type);
+        visitor.visit_multihash(&v.physical_hash);
+    }
+
+    pub fn walk_protocol_objects_batch<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::ObjectsBatch,
+    ) {
+        visitor.visit_u64(&v.objects_count);
+        visitor.visit_protocol_object_type(&v.object_type);
+        visitor.visit_string(&v.media_type);
+        visitor.visit_string(&v.encoding);
+        visitor.visit_string(&v.payload);
+    }
+
+    pub fn walk_protocol_pull_object_transfer_strategy<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::PullObjectTransferStrategy,
+    ) {
+        visitor.visit_protocol_object_file_reference(&v.object_file);
+        visitor.visit_protocol_object_pull_strategy(&v.pull_strategy);
+        visitor.visit_protocol_transfer_url(&v.download_from);
+    }
+
+    pub fn walk_protocol_push_object_transfer_strategy<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::PushObjectTransferStrategy,
+    ) {
+        visitor.visit_protocol_object_file_reference(&v.object_file);
+        visitor.visit_protocol_object_push_strategy(&v.push_strategy);
+        if let Some(x) = &v.upload_to {
+            visitor.visit_protocol_transfer_url(x);
+        }
+    }
+
+    pub fn walk_protocol_transfer_size_estimation<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::TransferSizeEstimation,
+    ) {
+        visitor.visit_u64(&v.num_blocks);
+        visitor.visit_u64(&v.num_objects);
+        visitor.visit_u64(&v.bytes_in_raw_blocks);
+        visitor.visit_u64(&v.bytes_in_raw_objects);
+    }
+
+    pub fn walk_protocol_transfer_url<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &protocol::TransferUrl,
+    ) {
+        visitor.visit_url(&v.url);
+        if let Some(x) = &v.expires_at {
+            visitor.visit_date_time(x);
+        }
+    }
+
+    pub fn walk_resource_filter_condition<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::FilterCondition,
+    ) {
+        if let Some(x) = &v.eq {
+            visitor.visit_any_json(x);
+        }
+        if let Some(x) = &v.r#in {
+            for x1 in x {
+                visitor.visit_any_json(x1);
+            }
+        }
+        if let Some(x) = &v.exists {
+            visitor.visit_bool(x);
+        }
+        if let Some(x) = &v.glob {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_resource_label_filter<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::LabelFilter,
+    ) {
+        match v {
+            resource::LabelFilter::And(x) => visitor.visit_resource_label_filter_and(x),
+            resource::LabelFilter::Or(x) => visitor.visit_resource_label_filter_or(x),
+            resource::LabelFilter::Not(x) => visitor.visit_resource_label_filter_not(x),
+            resource::LabelFilter::Match(x) => visitor.visit_resource_label_filter_match(x),
+        }
+    }
+
+    pub fn walk_resource_label_filter_and<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::LabelFilterAnd,
+    ) {
+        for x1 in &v.and {
+            visitor.visit_resource_label_filter(x1);
+        }
+    }
+
+    pub fn walk_resource_label_filter_match<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::LabelFilterMatch,
+    ) {
+        for x in v.entries.values() {
+            visitor.visit_resource_filter_condition(x);
+        }
+    }
+
+    pub fn walk_resource_label_filter_not<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::LabelFilterNot,
+    ) {
+        visitor.visit_resource_label_filter(&v.not);
+    }
+
+    pub fn walk_resource_label_filter_or<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::LabelFilterOr,
+    ) {
+        for x1 in &v.or {
+            visitor.visit_resource_label_filter(x1);
+        }
+    }
+
+    pub fn walk_resource_resource<V: Visit + ?Sized, SpecT: Visitable>(
+        visitor: &mut V,
+        v: &resource::Resource<SpecT>,
+    ) {
+        visitor.visit_type_uri(&v.schema);
+        visitor.visit_resource_resource_headers(&v.headers);
+        v.spec.accept(visitor);
+        visitor.visit_resource_resource_status(&v.status);
+    }
+
+    pub fn walk_resource_resource_annotations<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceAnnotations,
+    ) {
+        for x in v.entries.values() {
+            visitor.visit_any_json(x);
+        }
+    }
+
+    pub fn walk_resource_resource_conditions<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceConditions,
+    ) {
+        for x in v.entries.values() {
+            visitor.visit_any_json(x);
+        }
+    }
+
+    pub fn walk_resource_resource_handle<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceHandle,
+    ) {
+        visitor.visit_auth_account_handle(&v.account);
+        visitor.visit_type_uri(&v.r#type);
+        visitor.visit_resource_id(&v.id);
+        if let Some(x) = &v.did {
+            visitor.visit_did(x);
+        }
+        visitor.visit_resource_name(&v.name);
+    }
+
+    pub fn walk_resource_resource_headers<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceHeaders,
+    ) {
+        visitor.visit_resource_id(&v.id);
+        visitor.visit_resource_name(&v.name);
+        visitor.visit_auth_account_handle(&v.account);
+        visitor.visit_resource_resource_labels(&v.labels);
+        visitor.visit_resource_resource_annotations(&v.annotations);
+        visitor.visit_u64(&v.generation);
+        visitor.visit_date_time(&v.created_at);
+        visitor.visit_date_time(&v.updated_at);
+        if let Some(x) = &v.deleted_at {
+            visitor.visit_date_time(x);
+        }
+    }
+
+    pub fn walk_resource_resource_headers_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceHeadersInput,
+    ) {
+        if let Some(x) = &v.id {
+            visitor.visit_resource_id(x);
+        }
+        visitor.visit_resource_name(&v.name);
+        if let Some(x) = &v.account {
+            visitor.visit_auth_account_ref(x);
+        }
+        if let Some(x) = &v.labels {
+            visitor.visit_resource_resource_labels(x);
+        }
+        if let Some(x) = &v.annotations {
+            visitor.visit_resource_resource_annotations(x);
+        }
+    }
+
+    pub fn walk_resource_resource_input<V: Visit + ?Sized, SpecT: Visitable>(
+        visitor: &mut V,
+        v: &resource::ResourceInput<SpecT>,
+    ) {
+        visitor.visit_type_uri(&v.schema);
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        v.spec.accept(visitor);
+    }
+
+    pub fn walk_resource_resource_labels<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceLabels,
+    ) {
+        for x in v.entries.values() {
+            visitor.visit_any_json(x);
+        }
+    }
+
+    pub fn walk_resource_resource_ref<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceRef,
+    ) {
+        if let Some(x) = &v.account {
+            visitor.visit_auth_account_ref(x);
+        }
+        visitor.visit_type_ref(&v.r#type);
+        if let Some(x) = &v.id {
+            visitor.visit_resource_id(x);
+        }
+        if let Some(x) = &v.did {
+            visitor.visit_did(x);
+        }
+        if let Some(x) = &v.name {
+            visitor.visit_resource_name(x);
+        }
+    }
+
+    pub fn walk_resource_resource_status<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &resource::ResourceStatus,
+    ) {
+        visitor.visit_resource_resource_phase(&v.phase);
+        if let Some(x) = &v.observed_generation {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.reconciled_at {
+            visitor.visit_date_time(x);
+        }
+        visitor.visit_resource_resource_conditions(&v.conditions);
+    }
+
+    pub fn walk_sink_webhook_target<V: Visit + ?Sized>(visitor: &mut V, v: &sink::WebhookTarget) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_sink_webhook_target_spec_input(&v.spec);
+    }
+
+    pub fn walk_sink_webhook_target_spec<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &sink::WebhookTargetSpec,
+    ) {
+        visitor.visit_url(&v.url);
+        if let Some(x) = &v.secret {
+            visitor.visit_config_secret(x);
+        }
+    }
+
+    pub fn walk_sink_webhook_target_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &sink::WebhookTargetSpecInput,
+    ) {
+        visitor.visit_url(&v.url);
+        if let Some(x) = &v.secret {
+            visitor.visit_config_secret(x);
+        }
+    }
+
+    pub fn walk_sink_webhook_target_status<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &sink::WebhookTargetStatus,
+    ) {
+        visitor.visit_sink_webhook_target_status_value(&v.value);
+        visitor.visit_date_time(&v.last_transition_time);
+        if let Some(x) = &v.reason {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.message {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_source_env_var<V: Visit + ?Sized>(visitor: &mut V, v: &source::EnvVar) {
+        visitor.visit_string(&v.name);
+        if let Some(x) = &v.value {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_source_event_time_source<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::EventTimeSource,
+    ) {
+        match v {
+            source::EventTimeSource::FromMetadata(x) => {
+                visitor.visit_source_event_time_source_from_metadata(x)
+            }
+            source::EventTimeSource::FromPath(x) => {
+                visitor.visit_source_event_time_source_from_path(x)
+            }
+            source::EventTimeSource::FromSystemTime(x) => {
+                visitor.visit_source_event_time_source_from_system_time(x)
+            }
+        }
+    }
+
+    pub fn walk_source_event_time_source_from_path<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::EventTimeSourceFromPath,
+    ) {
+        visitor.visit_regex(&v.pattern);
+        if let Some(x) = &v.timestamp_format {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_source_ingest_params<V: Visit + ?Sized>(visitor: &mut V, v: &source::IngestParams) {
+        if let Some(x) = &v.target_slice_records {
+            visitor.visit_u64(x);
+        }
+    }
+
+    pub fn walk_source_ingress<V: Visit + ?Sized>(visitor: &mut V, v: &source::Ingress) {
+        match v {
+            source::Ingress::Url(x) => visitor.visit_source_ingress_url(x),
+            source::Ingress::FilesGlob(x) => visitor.visit_source_ingress_files_glob(x),
+            source::Ingress::Container(x) => visitor.visit_source_ingress_container(x),
+            source::Ingress::Mqtt(x) => visitor.visit_source_ingress_mqtt(x),
+            source::Ingress::EvmLogs(x) => visitor.visit_source_ingress_evm_logs(x),
+            source::Ingress::RestEndpoint(x) => visitor.visit_source_ingress_rest_endpoint(x),
+        }
+    }
+
+    pub fn walk_source_ingress_buffer<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::IngressBuffer,
+    ) {
+        match v {
+            source::IngressBuffer::Memory(x) => visitor.visit_source_ingress_buffer_memory(x),
+        }
+    }
+
+    pub fn walk_source_ingress_buffer_memory<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::IngressBufferMemory,
+    ) {
+        if let Some(x) = &v.buffer_size {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.overflow_policy {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_source_ingress_container<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::IngressContainer,
+    ) {
+        visitor.visit_string(&v.image);
+        if let Some(x) = &v.command {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.args {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.env {
+            for x1 in x {
+                visitor.visit_source_env_var(x1);
+            }
+        }
+    }
+
+    pub fn walk_source_ingress_evm_logs<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::IngressEvmLogs,
+    ) {
+        if let Some(x) = &v.chain_id {
+            visitor.visit_u64(x);
+        }
+        if let Some(x) = &v.node_url {
+            visitor.visit_url(x);
+        }
+        if let Some(x) = &v.filter {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.signature {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_source_ingress_files_glob<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::IngressFilesGlob,
+    ) {
+        visitor.visit_string(&v.path);
+        if let Some(x) = &v.event_time {
+            visitor.visit_source_event_time_source(x);
+        }
+        if let Some(x) = &v.cache {
+            visitor.visit_source_source_caching(x);
+        }
+        if let Some(x) = &v.order {
+            visitor.visit_source_source_ordering(x);
+        }
+    }
+
+    pub fn walk_source_ingress_mqtt<V: Visit + ?Sized>(visitor: &mut V, v: &source::IngressMqtt) {
+        visitor.visit_string(&v.host);
+        visitor.visit_i32(&v.port);
+        if let Some(x) = &v.username {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.password {
+            visitor.visit_string(x.expose());
+        }
+        for x1 in &v.topics {
+            visitor.visit_source_mqtt_topic_subscription(x1);
+        }
+    }
+
+    pub fn walk_source_ingress_rest_endpoint<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::IngressRestEndpoint,
+    ) {
+        if let Some(x) = &v.buffer {
+            visitor.visit_source_ingress_buffer(x);
+        }
+    }
+
+    pub fn walk_source_ingress_url<V: Visit + ?Sized>(visitor: &mut V, v: &source::IngressUrl) {
+        visitor.visit_url(&v.url);
+        if let Some(x) = &v.event_time {
+            visitor.visit_source_event_time_source(x);
+        }
+        if let Some(x) = &v.cache {
+            visitor.visit_source_source_caching(x);
+        }
+        if let Some(x) = &v.headers {
+            for x1 in x {
+                visitor.visit_source_request_header(x1);
+            }
+        }
+    }
+
+    pub fn walk_source_merge_strategy<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::MergeStrategy,
+    ) {
+        match v {
+            source::MergeStrategy::Append(x) => visitor.visit_source_merge_strategy_append(x),
+            source::MergeStrategy::Ledger(x) => visitor.visit_source_merge_strategy_ledger(x),
+            source::MergeStrategy::Snapshot(x) => visitor.visit_source_merge_strategy_snapshot(x),
+            source::MergeStrategy::ChangelogStream(x) => {
+                visitor.visit_source_merge_strategy_changelog_stream(x)
+            }
+            source::MergeStrategy::UpsertStream(x) => {
+                visitor.visit_source_merge_strategy_upsert_stream(x)
+            }
+        }
+    }
+
+    pub fn walk_source_merge_strategy_changelog_stream<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::MergeStrategyChangelogStream,
+    ) {
+        for x1 in &v.primary_key {
+            visitor.visit_string(x1);
+        }
+    }
+
+    pub fn walk_source_merge_strategy_ledger<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::MergeStrategyLedger,
+    ) {
+        for x1 in &v.primary_key {
+            visitor.visit_string(x1);
+        }
+    }
+
+    pub fn walk_source_merge_strategy_snapshot<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::MergeStrategySnapshot,
+    ) {
+        for x1 in &v.primary_key {
+            visitor.visit_string(x1);
+        }
+        if let Some(x) = &v.compare_columns {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+    }
+
+    pub fn walk_source_merge_strategy_upsert_stream<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::MergeStrategyUpsertStream,
+    ) {
+        for x1 in &v.primary_key {
+            visitor.visit_string(x1);
+        }
+    }
+
+    pub fn walk_source_mqtt_topic_subscription<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::MqttTopicSubscription,
+    ) {
+        visitor.visit_string(&v.path);
+        if let Some(x) = &v.qos {
+            visitor.visit_source_mqtt_qos(x);
+        }
+    }
+
+    pub fn walk_source_prep_step<V: Visit + ?Sized>(visitor: &mut V, v: &source::PrepStep) {
+        match v {
+            source::PrepStep::Decompress(x) => visitor.visit_source_prep_step_decompress(x),
+            source::PrepStep::Pipe(x) => visitor.visit_source_prep_step_pipe(x),
+        }
+    }
+
+    pub fn walk_source_prep_step_decompress<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::PrepStepDecompress,
+    ) {
+        visitor.visit_source_compression_format(&v.format);
+        if let Some(x) = &v.sub_path {
+            visitor.visit_string(x);
+        }
+    }
+
+    pub fn walk_source_prep_step_pipe<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::PrepStepPipe,
+    ) {
+        for x1 in &v.command {
+            visitor.visit_string(x1);
+        }
+    }
+
+    pub fn walk_source_read_step<V: Visit + ?Sized>(visitor: &mut V, v: &source::ReadStep) {
+        match v {
+            source::ReadStep::Csv(x) => visitor.visit_source_read_step_csv(x),
+            source::ReadStep::GeoJson(x) => visitor.visit_source_read_step_geo_json(x),
+            source::ReadStep::EsriShapefile(x) => visitor.visit_source_read_step_esri_shapefile(x),
+            source::ReadStep::Parquet(x) => visitor.visit_source_read_step_parquet(x),
+            source::ReadStep::Json(x) => visitor.visit_source_read_step_json(x),
+            source::ReadStep::NdJson(x) => visitor.visit_source_read_step_nd_json(x),
+            source::ReadStep::NdGeoJson(x) => visitor.visit_source_read_step_nd_geo_json(x),
+        }
+    }
+
+    pub fn walk_source_read_step_csv<V: Visit + ?Sized>(visitor: &mut V, v: &source::ReadStepCsv) {
+        if let Some(x) = &v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.separator {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.encoding {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.quote {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.escape {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.header {
+            visitor.visit_bool(x);
+        }
+        if let Some(x) = &v.infer_schema {
+            visitor.visit_bool(x);
+        }
+        if let Some(x) = &v.null_value {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.date_format {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.timestamp_format {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_source_read_step_esri_shapefile<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::ReadStepEsriShapefile,
+    ) {
+        if let Some(x) = &v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.sub_path {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_source_read_step_geo_json<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::ReadStepGeoJson,
+    ) {
+        if let Some(x) = &v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_source_read_step_json<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::ReadStepJson,
+    ) {
+        if let Some(x) = &v.sub_path {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.date_format {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.encoding {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.timestamp_format {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_source_read_step_nd_geo_json<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::ReadStepNdGeoJson,
+    ) {
+        if let Some(x) = &v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_source_read_step_nd_json<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::ReadStepNdJson,
+    ) {
+        if let Some(x) = &v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.date_format {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.encoding {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.timestamp_format {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_source_read_step_parquet<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::ReadStepParquet,
+    ) {
+        if let Some(x) = &v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string(x1);
+            }
+        }
+        if let Some(x) = &v.schema {
+            visitor.visit_data_data_schema(x);
+        }
+    }
+
+    pub fn walk_source_request_header<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::RequestHeader,
+    ) {
+        visitor.visit_string(&v.name);
+        visitor.visit_string(&v.value);
+    }
+
+    pub fn walk_source_source<V: Visit + ?Sized>(visitor: &mut V, v: &source::Source) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_source_source_spec_input(&v.spec);
+    }
+
+    pub fn walk_source_source_caching<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::SourceCaching,
+    ) {
+        match v {
+            source::SourceCaching::Forever(x) => visitor.visit_source_source_caching_forever(x),
+        }
+    }
+
+    pub fn walk_source_source_spec<V: Visit + ?Sized>(visitor: &mut V, v: &source::SourceSpec) {
+        if let Some(x) = &v.config {
+            visitor.visit_config_value_refs(x);
+        }
+        if let Some(x) = &v.ingress {
+            visitor.visit_source_ingress(x);
+        }
+        if let Some(x) = &v.prepare {
+            for x1 in x {
+                visitor.visit_source_prep_step(x1);
+            }
+        }
+        visitor.visit_source_read_step(&v.read);
+        if let Some(x) = &v.preprocess {
+            visitor.visit_dataset_transform(x);
+        }
+        if let Some(x) = &v.merge {
+            visitor.visit_source_merge_strategy(x);
+        }
+        if let Some(x) = &v.vocab {
+            visitor.visit_dataset_dataset_vocabulary(x);
+        }
+    }
+
+    pub fn walk_source_source_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &source::SourceSpecInput,
+    ) {
+        if let Some(x) = &v.config {
+            visitor.visit_config_value_refs(x);
+        }
+        if let Some(x) = &v.ingress {
+            visitor.visit_source_ingress(x);
+        }
+        if let Some(x) = &v.prepare {
+            for x1 in x {
+                visitor.visit_source_prep_step(x1);
+            }
+        }
+        visitor.visit_source_read_step(&v.read);
+        if let Some(x) = &v.preprocess {
+            visitor.visit_dataset_transform(x);
+        }
+        if let Some(x) = &v.merge {
+            visitor.visit_source_merge_strategy(x);
+        }
+        if let Some(x) = &v.vocab {
+            visitor.visit_dataset_dataset_vocabulary(x);
+        }
+    }
+
+    pub fn walk_source_source_state<V: Visit + ?Sized>(visitor: &mut V, v: &source::SourceState) {
+        visitor.visit_string(&v.source_name);
+        visitor.visit_string(&v.kind);
+        visitor.visit_string(&v.value);
+    }
+
+    pub fn walk_storage_aws_credentials<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::AwsCredentials,
+    ) {
+        if let Some(x) = &v.access_key {
+            visitor.visit_config_value_handle(x);
+        }
+        if let Some(x) = &v.secret_key {
+            visitor.visit_config_value_handle(x);
+        }
+    }
+
+    pub fn walk_storage_aws_credentials_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::AwsCredentialsInput,
+    ) {
+        if let Some(x) = &v.access_key {
+            visitor.visit_config_value_ref(x);
+        }
+        if let Some(x) = &v.secret_key {
+            visitor.visit_config_value_ref(x);
+        }
+    }
+
+    pub fn walk_storage_persistent_volume<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::PersistentVolume,
+    ) {
+        visitor.visit_resource_resource_headers_input(&v.headers);
+        visitor.visit_storage_persistent_volume_spec_input(&v.spec);
+    }
+
+    pub fn walk_storage_persistent_volume_ref<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::PersistentVolumeRef,
+    ) {
+        if let Some(x) = &v.account {
+            visitor.visit_auth_account_ref(x);
+        }
+        if let Some(x) = &v.id {
+            visitor.visit_resource_id(x);
+        }
+        if let Some(x) = &v.name {
+            visitor.visit_resource_name(x);
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::PersistentVolumeSpec,
+    ) {
+        match v {
+            storage::PersistentVolumeSpec::S3(x) => {
+                visitor.visit_storage_persistent_volume_spec_s_3(x)
+            }
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec_input<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::PersistentVolumeSpecInput,
+    ) {
+        match v {
+            storage::PersistentVolumeSpecInput::S3(x) => {
+                visitor.visit_storage_persistent_volume_spec_input_s_3(x)
+            }
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec_input_s_3<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::PersistentVolumeSpecInputS3,
+    ) {
+        if let Some(x) = &v.endpoint {
+            visitor.visit_url(x);
+        }
+        if let Some(x) = &v.region {
+            visitor.visit_string(x);
+        }
+        visitor.visit_string(&v.bucket);
+        if let Some(x) = &v.prefix {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.capacity {
+            visitor.visit_storage_volume_capacity(x);
+        }
+        if let Some(x) = &v.credentials {
+            visitor.visit_storage_aws_credentials_input(x);
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec_s_3<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::PersistentVolumeSpecS3,
+    ) {
+        if let Some(x) = &v.endpoint {
+            visitor.visit_url(x);
+        }
+        if let Some(x) = &v.region {
+            visitor.visit_string(x);
+        }
+        visitor.visit_string(&v.bucket);
+        if let Some(x) = &v.prefix {
+            visitor.visit_string(x);
+        }
+        if let Some(x) = &v.capacity {
+            visitor.visit_storage_volume_capacity(x);
+        }
+        if let Some(x) = &v.credentials {
+            visitor.visit_storage_aws_credentials(x);
+        }
+    }
+
+    pub fn walk_storage_volume_capacity<V: Visit + ?Sized>(
+        visitor: &mut V,
+        v: &storage::VolumeCapacity,
+    ) {
+        if let Some(x) = &v.storage {
+            visitor.visit_byte_size(x);
+        }
+    }
+
+    pub fn walk_auth_account_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut auth::Account) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_auth_account_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_auth_account_handle_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::AccountHandle,
+    ) {
+        visitor.visit_resource_id_mut(&mut v.id);
+        visitor.visit_account_id_mut(&mut v.did);
+        visitor.visit_account_name_mut(&mut v.name);
+    }
+
+    pub fn walk_auth_account_ref_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::AccountRef,
+    ) {
+        if let Some(x) = &mut v.id {
+            visitor.visit_resource_id_mut(x);
+        }
+        if let Some(x) = &mut v.did {
+            visitor.visit_account_id_mut(x);
+        }
+        if let Some(x) = &mut v.name {
+            visitor.visit_account_name_mut(x);
+        }
+    }
+
+    pub fn walk_auth_account_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::AccountSpec,
+    ) {
+        if let Some(x) = &mut v.did {
+            visitor.visit_account_id_mut(x);
+        }
+        if let Some(x) = &mut v.account_type {
+            visitor.visit_auth_account_type_mut(x);
+        }
+        if let Some(x) = &mut v.display_name {
+            visitor.visit_string_mut(x);
+        }
+        visitor.visit_string_mut(&mut v.email);
+        if let Some(x) = &mut v.avatar_url {
+            visitor.visit_url_mut(x);
+        }
+        if let Some(x) = &mut v.password {
+            visitor.visit_config_secret_mut(x);
+        }
+    }
+
+    pub fn walk_auth_account_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::AccountSpecInput,
+    ) {
+        if let Some(x) = &mut v.did {
+            visitor.visit_account_id_mut(x);
+        }
+        if let Some(x) = &mut v.account_type {
+            visitor.visit_auth_account_type_mut(x);
+        }
+        if let Some(x) = &mut v.display_name {
+            visitor.visit_string_mut(x);
+        }
+        visitor.visit_string_mut(&mut v.email);
+        if let Some(x) = &mut v.avatar_url {
+            visitor.visit_url_mut(x);
+        }
+        if let Some(x) = &mut v.password {
+            visitor.visit_config_secret_mut(x);
+        }
+    }
+
+    pub fn walk_auth_attribute_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut auth::Attribute) {
+        visitor.visit_resource_resource_handle_mut(&mut v.object);
+        visitor.visit_string_mut(&mut v.name);
+        visitor.visit_any_json_mut(&mut v.value);
+    }
+
+    pub fn walk_auth_attribute_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::AttributeInput,
+    ) {
+        visitor.visit_resource_resource_ref_mut(&mut v.object);
+        visitor.visit_string_mut(&mut v.name);
+        visitor.visit_any_json_mut(&mut v.value);
+    }
+
+    pub fn walk_auth_relation_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut auth::Relation) {
+        visitor.visit_resource_resource_handle_mut(&mut v.subject);
+        visitor.visit_string_mut(&mut v.relation);
+        if let Some(x) = &mut v.value {
+            visitor.visit_any_json_mut(x);
+        }
+        visitor.visit_resource_resource_handle_mut(&mut v.object);
+    }
+
+    pub fn walk_auth_relation_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::RelationInput,
+    ) {
+        visitor.visit_resource_resource_ref_mut(&mut v.subject);
+        visitor.visit_string_mut(&mut v.relation);
+        if let Some(x) = &mut v.value {
+            visitor.visit_any_json_mut(x);
+        }
+        visitor.visit_resource_resource_ref_mut(&mut v.object);
+    }
+
+    pub fn walk_auth_relations_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut auth::Relations) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_auth_relations_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_auth_relations_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::RelationsSpec,
+    ) {
+        for x1 in &mut v.relations {
+            visitor.visit_auth_relation_mut(x1);
+        }
+        for x1 in &mut v.attributes {
+            visitor.visit_auth_attribute_mut(x1);
+        }
+    }
+
+    pub fn walk_auth_relations_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut auth::RelationsSpecInput,
+    ) {
+        if let Some(x) = &mut v.relations {
+            for x1 in x {
+                visitor.visit_auth_relation_input_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.attributes {
+            for x1 in x {
+                visitor.visit_auth_attribute_input_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_config_secret_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut config::Secret) {
+        visitor.visit_string_mut(v.value.expose_mut());
+        if let Some(x) = &mut v.content_encoding {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_config_secret_set_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::SecretSet,
+    ) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_config_secret_set_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_config_secret_set_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::SecretSetSpec,
+    ) {
+        visitor.visit_config_secrets_mut(&mut v.secrets);
+    }
+
+    pub fn walk_config_secret_set_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::SecretSetSpecInput,
+    ) {
+        visitor.visit_config_secrets_mut(&mut v.secrets);
+    }
+
+    pub fn walk_config_secrets_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut config::Secrets) {
+        for x in v.entries.values_mut() {
+            visitor.visit_config_secret_mut(x);
+        }
+    }
+
+    pub fn walk_config_value_handle_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::ValueHandle,
+    ) {
+        visitor.visit_auth_account_handle_mut(&mut v.account);
+        visitor.visit_type_uri_mut(&mut v.r#type);
+        visitor.visit_resource_id_mut(&mut v.id);
+        visitor.visit_resource_name_mut(&mut v.name);
+        if let Some(x) = &mut v.path {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_config_value_ref_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::ValueRef,
+    ) {
+        if let Some(x) = &mut v.account {
+            visitor.visit_auth_account_ref_mut(x);
+        }
+        visitor.visit_type_ref_mut(&mut v.r#type);
+        if let Some(x) = &mut v.id {
+            visitor.visit_resource_id_mut(x);
+        }
+        if let Some(x) = &mut v.name {
+            visitor.visit_resource_name_mut(x);
+        }
+        if let Some(x) = &mut v.path {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_config_value_refs_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::ValueRefs,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_config_value_ref_mut(x);
+        }
+    }
+
+    pub fn walk_config_variable_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::Variable,
+    ) {
+        visitor.visit_string_mut(&mut v.value);
+    }
+
+    pub fn walk_config_variable_set_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::VariableSet,
+    ) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_config_variable_set_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_config_variable_set_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::VariableSetSpec,
+    ) {
+        visitor.visit_config_variables_mut(&mut v.variables);
+    }
+
+    pub fn walk_config_variable_set_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::VariableSetSpecInput,
+    ) {
+        visitor.visit_config_variables_mut(&mut v.variables);
+    }
+
+    pub fn walk_config_variables_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut config::Variables,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_config_variable_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_field_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataField,
+    ) {
+        visitor.visit_string_mut(&mut v.name);
+        visitor.visit_data_data_type_mut(&mut v.r#type);
+        if let Some(x) = &mut v.extra {
+            visitor.visit_data_extra_attributes_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_schema_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataSchema,
+    ) {
+        for x1 in &mut v.fields {
+            visitor.visit_data_data_field_mut(x1);
+        }
+        if let Some(x) = &mut v.extra {
+            visitor.visit_data_extra_attributes_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_type_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut data::DataType) {
+        match v {
+            data::DataType::Binary(x) => visitor.visit_data_data_type_binary_mut(x),
+            data::DataType::Bool(x) => visitor.visit_data_data_type_bool_mut(x),
+            data::DataType::Date(x) => visitor.visit_data_data_type_date_mut(x),
+            data::DataType::Decimal(x) => visitor.visit_data_data_type_decimal_mut(x),
+            data::DataType::Duration(x) => visitor.visit_data_data_type_duration_mut(x),
+            data::DataType::Float16(x) => visitor.visit_data_data_type_float_16_mut(x),
+            data::DataType::Float32(x) => visitor.visit_data_data_type_float_32_mut(x),
+            data::DataType::Float64(x) => visitor.visit_data_data_type_float_64_mut(x),
+            data::DataType::Int8(x) => visitor.visit_data_data_type_int_8_mut(x),
+            data::DataType::Int16(x) => visitor.visit_data_data_type_int_16_mut(x),
+            data::DataType::Int32(x) => visitor.visit_data_data_type_int_32_mut(x),
+            data::DataType::Int64(x) => visitor.visit_data_data_type_int_64_mut(x),
+            data::DataType::UInt8(x) => visitor.visit_data_data_type_u_int_8_mut(x),
+            data::DataType::UInt16(x) => visitor.visit_data_data_type_u_int_16_mut(x),
+            data::DataType::UInt32(x) => visitor.visit_data_data_type_u_int_32_mut(x),
+            data::DataType::UInt64(x) => visitor.visit_data_data_type_u_int_64_mut(x),
+            data::DataType::List(x) => visitor.visit_data_data_type_list_mut(x),
+            data::DataType::Map(x) => visitor.visit_data_data_type_map_mut(x),
+            data::DataType::Null(x) => visitor.visit_data_data_type_null_mut(x),
+            data::DataType::Option(x) => visitor.visit_data_data_type_option_mut(x),
+            data::DataType::Struct(x) => visitor.visit_data_data_type_struct_mut(x),
+            data::DataType::Time(x) => visitor.visit_data_data_type_time_mut(x),
+            data::DataType::Timestamp(x) => visitor.visit_data_data_type_timestamp_mut(x),
+            data::DataType::String(x) => visitor.visit_data_data_type_string_mut(x),
+        }
+    }
+
+    pub fn walk_data_data_type_binary_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeBinary,
+    ) {
+        if let Some(x) = &mut v.fixed_length {
+            visitor.visit_u64_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_type_decimal_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeDecimal,
+    ) {
+        visitor.visit_u32_mut(&mut v.precision);
+        visitor.visit_i32_mut(&mut v.scale);
+    }
+
+    pub fn walk_data_data_type_duration_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeDuration,
+    ) {
+        if let Some(x) = &mut v.unit {
+            visitor.visit_data_time_unit_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_type_list_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeList,
+    ) {
+        visitor.visit_data_data_type_mut(&mut v.item_type);
+        if let Some(x) = &mut v.fixed_length {
+            visitor.visit_u64_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_type_map_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeMap,
+    ) {
+        visitor.visit_data_data_type_mut(&mut v.key_type);
+        visitor.visit_data_data_type_mut(&mut v.value_type);
+        if let Some(x) = &mut v.keys_sorted {
+            visitor.visit_bool_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_type_option_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeOption,
+    ) {
+        visitor.visit_data_data_type_mut(&mut v.inner);
+    }
+
+    pub fn walk_data_data_type_struct_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeStruct,
+    ) {
+        for x1 in &mut v.fields {
+            visitor.visit_data_data_field_mut(x1);
+        }
+    }
+
+    pub fn walk_data_data_type_time_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeTime,
+    ) {
+        if let Some(x) = &mut v.unit {
+            visitor.visit_data_time_unit_mut(x);
+        }
+    }
+
+    pub fn walk_data_data_type_timestamp_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::DataTypeTimestamp,
+    ) {
+        if let Some(x) = &mut v.unit {
+            visitor.visit_data_time_unit_mut(x);
+        }
+        if let Some(x) = &mut v.timezone {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_data_extra_attributes_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut data::ExtraAttributes,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_any_json_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_add_data_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::AddData,
+    ) {
+        if let Some(x) = &mut v.prev_checkpoint {
+            visitor.visit_multihash_mut(x);
+        }
+        if let Some(x) = &mut v.prev_offset {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.new_data {
+            visitor.visit_dataset_data_slice_mut(x);
+        }
+        if let Some(x) = &mut v.new_checkpoint {
+            visitor.visit_dataset_checkpoint_mut(x);
+        }
+        if let Some(x) = &mut v.new_watermark {
+            visitor.visit_date_time_mut(x);
+        }
+        if let Some(x) = &mut v.new_source_state {
+            visitor.visit_source_source_state_mut(x);
+        }
+        if let Some(x) = &mut v.extra {
+            visitor.visit_data_extra_attributes_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_attachment_embedded_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::AttachmentEmbedded,
+    ) {
+        visitor.visit_string_mut(&mut v.path);
+        visitor.visit_string_mut(&mut v.content);
+    }
+
+    pub fn walk_dataset_attachments_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::Attachments,
+    ) {
+        match v {
+            dataset::Attachments::Embedded(x) => visitor.visit_dataset_attachments_embedded_mut(x),
+        }
+    }
+
+    pub fn walk_dataset_attachments_embedded_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::AttachmentsEmbedded,
+    ) {
+        for x1 in &mut v.items {
+            visitor.visit_dataset_attachment_embedded_mut(x1);
+        }
+    }
+
+    pub fn walk_dataset_checkpoint_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::Checkpoint,
+    ) {
+        visitor.visit_multihash_mut(&mut v.physical_hash);
+        visitor.visit_u64_mut(&mut v.size);
+    }
+
+    pub fn walk_dataset_compaction_params_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::CompactionParams,
+    ) {
+        if let Some(x) = &mut v.max_slice_size {
+            visitor.visit_byte_size_mut(x);
+        }
+        if let Some(x) = &mut v.max_slice_records {
+            visitor.visit_u64_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_data_slice_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::DataSlice,
+    ) {
+        visitor.visit_multihash_mut(&mut v.logical_hash);
+        visitor.visit_multihash_mut(&mut v.physical_hash);
+        visitor.visit_dataset_offset_interval_mut(&mut v.offset_interval);
+        visitor.visit_u64_mut(&mut v.size);
+    }
+
+    pub fn walk_dataset_dataset_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::Dataset,
+    ) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_dataset_dataset_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_dataset_dataset_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::DatasetSpec,
+    ) {
+        visitor.visit_dataset_id_mut(&mut v.did);
+        visitor.visit_dataset_dataset_kind_mut(&mut v.kind);
+        for x1 in &mut v.metadata {
+            visitor.visit_dataset_metadata_event_mut(x1);
+        }
+        visitor.visit_resource_resource_handle_mut(&mut v.volume);
+    }
+
+    pub fn walk_dataset_dataset_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::DatasetSpecInput,
+    ) {
+        if let Some(x) = &mut v.did {
+            visitor.visit_dataset_id_mut(x);
+        }
+        visitor.visit_dataset_dataset_kind_mut(&mut v.kind);
+        for x1 in &mut v.metadata {
+            visitor.visit_dataset_metadata_event_mut(x1);
+        }
+        if let Some(x) = &mut v.volume {
+            visitor.visit_storage_persistent_volume_ref_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_dataset_vocabulary_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::DatasetVocabulary,
+    ) {
+        if let Some(x) = &mut v.offset_column {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.operation_type_column {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.system_time_column {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.event_time_column {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_execute_transform_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::ExecuteTransform,
+    ) {
+        for x1 in &mut v.query_inputs {
+            visitor.visit_dataset_execute_transform_input_mut(x1);
+        }
+        if let Some(x) = &mut v.prev_checkpoint {
+            visitor.visit_multihash_mut(x);
+        }
+        if let Some(x) = &mut v.prev_offset {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.new_data {
+            visitor.visit_dataset_data_slice_mut(x);
+        }
+        if let Some(x) = &mut v.new_checkpoint {
+            visitor.visit_dataset_checkpoint_mut(x);
+        }
+        if let Some(x) = &mut v.new_watermark {
+            visitor.visit_date_time_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_execute_transform_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::ExecuteTransformInput,
+    ) {
+        visitor.visit_dataset_id_mut(&mut v.dataset_id);
+        if let Some(x) = &mut v.prev_block_hash {
+            visitor.visit_multihash_mut(x);
+        }
+        if let Some(x) = &mut v.new_block_hash {
+            visitor.visit_multihash_mut(x);
+        }
+        if let Some(x) = &mut v.prev_offset {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.new_offset {
+            visitor.visit_u64_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_metadata_block_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::MetadataBlock,
+    ) {
+        visitor.visit_date_time_mut(&mut v.system_time);
+        if let Some(x) = &mut v.prev_block_hash {
+            visitor.visit_multihash_mut(x);
+        }
+        visitor.visit_u64_mut(&mut v.sequence_number);
+        visitor.visit_dataset_metadata_event_mut(&mut v.event);
+    }
+
+    pub fn walk_dataset_metadata_event_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::MetadataEvent,
+    ) {
+        match v {
+            dataset::MetadataEvent::AddData(x) => visitor.visit_dataset_add_data_mut(x),
+            dataset::MetadataEvent::ExecuteTransform(x) => {
+                visitor.visit_dataset_execute_transform_mut(x)
+            }
+            dataset::MetadataEvent::Seed(x) => visitor.visit_dataset_seed_mut(x),
+            dataset::MetadataEvent::SetPollingSource(x) => {
+                visitor.visit_legacy_set_polling_source_mut(x)
+            }
+            dataset::MetadataEvent::SetTransform(x) => visitor.visit_dataset_set_transform_mut(x),
+            dataset::MetadataEvent::SetVocab(x) => visitor.visit_dataset_set_vocab_mut(x),
+            dataset::MetadataEvent::SetAttachments(x) => {
+                visitor.visit_dataset_set_attachments_mut(x)
+            }
+            dataset::MetadataEvent::SetInfo(x) => visitor.visit_dataset_set_info_mut(x),
+            dataset::MetadataEvent::SetLicense(x) => visitor.visit_dataset_set_license_mut(x),
+            dataset::MetadataEvent::SetDataSchema(x) => {
+                visitor.visit_dataset_set_data_schema_mut(x)
+            }
+            dataset::MetadataEvent::AddPushSource(x) => visitor.visit_legacy_add_push_source_mut(x),
+            dataset::MetadataEvent::DisablePushSource(x) => {
+                visitor.visit_legacy_disable_push_source_mut(x)
+            }
+            dataset::MetadataEvent::DisablePollingSource(x) => {
+                visitor.visit_legacy_disable_polling_source_mut(x)
+            }
+            dataset::MetadataEvent::Unknown(_) => {}
+        }
+    }
+
+    pub fn walk_dataset_offset_interval_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::OffsetInterval,
+    ) {
+        visitor.visit_u64_mut(&mut v.start);
+        visitor.visit_u64_mut(&mut v.end);
+    }
+
+    pub fn walk_dataset_projection_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::Projection,
+    ) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_dataset_projection_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_dataset_projection_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::ProjectionSpec,
+    ) {
+        for x1 in &mut v.inputs {
+            visitor.visit_dataset_transform_input_mut(x1);
+        }
+        visitor.visit_dataset_transform_mut(&mut v.project);
+    }
+
+    pub fn walk_dataset_projection_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::ProjectionSpecInput,
+    ) {
+        for x1 in &mut v.inputs {
+            visitor.visit_dataset_transform_input_mut(x1);
+        }
+        visitor.visit_dataset_transform_mut(&mut v.project);
+    }
+
+    pub fn walk_dataset_seed_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut dataset::Seed) {
+        visitor.visit_dataset_id_mut(&mut v.dataset_id);
+        visitor.visit_dataset_dataset_kind_mut(&mut v.dataset_kind);
+    }
+
+    pub fn walk_dataset_set_attachments_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::SetAttachments,
+    ) {
+        visitor.visit_dataset_attachments_mut(&mut v.attachments);
+    }
+
+    pub fn walk_dataset_set_data_schema_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::SetDataSchema,
+    ) {
+        if let Some(x) = &mut v.raw_arrow_schema {
+            visitor.visit_flatbuffers_mut(x);
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_set_info_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::SetInfo,
+    ) {
+        if let Some(x) = &mut v.description {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.keywords {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_dataset_set_license_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::SetLicense,
+    ) {
+        visitor.visit_string_mut(&mut v.short_name);
+        visitor.visit_string_mut(&mut v.name);
+        if let Some(x) = &mut v.spdx_id {
+            visitor.visit_string_mut(x);
+        }
+        visitor.visit_url_mut(&mut v.website_url);
+    }
+
+    pub fn walk_dataset_set_transform_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::SetTransform,
+    ) {
+        for x1 in &mut v.inputs {
+            visitor.visit_dataset_transform_input_mut(x1);
+        }
+        visitor.visit_dataset_transform_mut(&mut v.transform);
+    }
+
+    pub fn walk_dataset_set_vocab_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::SetVocab,
+    ) {
+        if let Some(x) = &mut v.offset_column {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.operation_type_column {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.system_time_column {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.event_time_column {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_sql_query_step_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::SqlQueryStep,
+    ) {
+        if let Some(x) = &mut v.alias {
+            visitor.visit_string_mut(x);
+        }
+        visitor.visit_string_mut(&mut v.query);
+    }
+
+    pub fn walk_dataset_temporal_table_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::TemporalTable,
+    ) {
+        visitor.visit_string_mut(&mut v.name);
+        for x1 in &mut v.primary_key {
+            visitor.visit_string_mut(x1);
+        }
+    }
+
+    pub fn walk_dataset_transform_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::Transform,
+    ) {
+        match v {
+            dataset::Transform::Sql(x) => visitor.visit_dataset_transform_sql_mut(x),
+        }
+    }
+
+    pub fn walk_dataset_transform_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::TransformInput,
+    ) {
+        visitor.visit_dataset_ref_mut(&mut v.dataset_ref);
+        if let Some(x) = &mut v.alias {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_dataset_transform_sql_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::TransformSql,
+    ) {
+        visitor.visit_string_mut(&mut v.engine);
+        if let Some(x) = &mut v.version {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.query {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.queries {
+            for x1 in x {
+                visitor.visit_dataset_sql_query_step_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.temporal_tables {
+            for x1 in x {
+                visitor.visit_dataset_temporal_table_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_dataset_watermark_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut dataset::Watermark,
+    ) {
+        visitor.visit_date_time_mut(&mut v.system_time);
+        visitor.visit_date_time_mut(&mut v.event_time);
+    }
+
+    pub fn walk_engine_raw_query_request_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::RawQueryRequest,
+    ) {
+        for x1 in &mut v.input_data_paths {
+            visitor.visit_path_mut(x1);
+        }
+        visitor.visit_dataset_transform_mut(&mut v.transform);
+        visitor.visit_path_mut(&mut v.output_data_path);
+    }
+
+    pub fn walk_engine_raw_query_response_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::RawQueryResponse,
+    ) {
+        match v {
+            engine::RawQueryResponse::Progress(x) => {
+                visitor.visit_engine_raw_query_response_progress_mut(x)
+            }
+            engine::RawQueryResponse::Success(x) => {
+                visitor.visit_engine_raw_query_response_success_mut(x)
+            }
+            engine::RawQueryResponse::InvalidQuery(x) => {
+                visitor.visit_engine_raw_query_response_invalid_query_mut(x)
+            }
+            engine::RawQueryResponse::InternalError(x) => {
+                visitor.visit_engine_raw_query_response_internal_error_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_engine_raw_query_response_internal_error_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::RawQueryResponseInternalError,
+    ) {
+        visitor.visit_string_mut(&mut v.message);
+        if let Some(x) = &mut v.backtrace {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_engine_raw_query_response_invalid_query_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::RawQueryResponseInvalidQuery,
+    ) {
+        visitor.visit_string_mut(&mut v.message);
+    }
+
+    pub fn walk_engine_raw_query_response_success_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::RawQueryResponseSuccess,
+    ) {
+        visitor.visit_u64_mut(&mut v.num_records);
+    }
+
+    pub fn walk_engine_transform_request_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::TransformRequest,
+    ) {
+        visitor.visit_dataset_id_mut(&mut v.dataset_id);
+        visitor.visit_dataset_alias_mut(&mut v.dataset_alias);
+        visitor.visit_date_time_mut(&mut v.system_time);
+        visitor.visit_dataset_dataset_vocabulary_mut(&mut v.vocab);
+        visitor.visit_dataset_transform_mut(&mut v.transform);
+        for x1 in &mut v.query_inputs {
+            visitor.visit_engine_transform_request_input_mut(x1);
+        }
+        visitor.visit_u64_mut(&mut v.next_offset);
+        if let Some(x) = &mut v.prev_checkpoint_path {
+            visitor.visit_path_mut(x);
+        }
+        visitor.visit_path_mut(&mut v.new_checkpoint_path);
+        visitor.visit_path_mut(&mut v.new_data_path);
+    }
+
+    pub fn walk_engine_transform_request_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::TransformRequestInput,
+    ) {
+        visitor.visit_dataset_id_mut(&mut v.dataset_id);
+        visitor.visit_dataset_alias_mut(&mut v.dataset_alias);
+        visitor.visit_string_mut(&mut v.query_alias);
+        visitor.visit_dataset_dataset_vocabulary_mut(&mut v.vocab);
+        if let Some(x) = &mut v.offset_interval {
+            visitor.visit_dataset_offset_interval_mut(x);
+        }
+        for x1 in &mut v.data_paths {
+            visitor.visit_path_mut(x1);
+        }
+        visitor.visit_path_mut(&mut v.schema_file);
+        for x1 in &mut v.explicit_watermarks {
+            visitor.visit_dataset_watermark_mut(x1);
+        }
+    }
+
+    pub fn walk_engine_transform_response_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::TransformResponse,
+    ) {
+        match v {
+            engine::TransformResponse::Progress(x) => {
+                visitor.visit_engine_transform_response_progress_mut(x)
+            }
+            engine::TransformResponse::Success(x) => {
+                visitor.visit_engine_transform_response_success_mut(x)
+            }
+            engine::TransformResponse::InvalidQuery(x) => {
+                visitor.visit_engine_transform_response_invalid_query_mut(x)
+            }
+            engine::TransformResponse::InternalError(x) => {
+                visitor.visit_engine_transform_response_internal_error_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_engine_transform_response_internal_error_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::TransformResponseInternalError,
+    ) {
+        visitor.visit_string_mut(&mut v.message);
+        if let Some(x) = &mut v.backtrace {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_engine_transform_response_invalid_query_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::TransformResponseInvalidQuery,
+    ) {
+        visitor.visit_string_mut(&mut v.message);
+    }
+
+    pub fn walk_engine_transform_response_success_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut engine::TransformResponseSuccess,
+    ) {
+        if let Some(x) = &mut v.new_offset_interval {
+            visitor.visit_dataset_offset_interval_mut(x);
+        }
+        if let Some(x) = &mut v.new_watermark {
+            visitor.visit_date_time_mut(x);
+        }
+    }
+
+    pub fn walk_event_event_filter_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut event::EventFilter,
+    ) {
+        match v {
+            event::EventFilter::And(x) => visitor.visit_event_event_filter_and_mut(x),
+            event::EventFilter::Or(x) => visitor.visit_event_event_filter_or_mut(x),
+            event::EventFilter::Not(x) => visitor.visit_event_event_filter_not_mut(x),
+            event::EventFilter::Match(x) => visitor.visit_event_event_filter_match_mut(x),
+        }
+    }
+
+    pub fn walk_event_event_filter_and_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut event::EventFilterAnd,
+    ) {
+        for x1 in &mut v.and {
+            visitor.visit_event_event_filter_mut(x1);
+        }
+    }
+
+    pub fn walk_event_event_filter_match_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut event::EventFilterMatch,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_resource_filter_condition_mut(x);
+        }
+    }
+
+    pub fn walk_event_event_filter_not_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut event::EventFilterNot,
+    ) {
+        visitor.visit_event_event_filter_mut(&mut v.not);
+    }
+
+    pub fn walk_event_event_filter_or_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut event::EventFilterOr,
+    ) {
+        for x1 in &mut v.or {
+            visitor.visit_event_event_filter_mut(x1);
+        }
+    }
+
+    pub fn walk_flow_flow_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut flow::Flow) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_flow_flow_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_flow_flow_spec_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut flow::FlowSpec) {
+        visitor.visit_resource_resource_selector_mut(&mut v.target);
+        for x1 in &mut v.triggers {
+            visitor.visit_flow_flow_trigger_mut(x1);
+        }
+        for x1 in &mut v.tasks {
+            visitor.visit_flow_task_spec_mut(x1);
+        }
+    }
+
+    pub fn walk_flow_flow_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowSpecInput,
+    ) {
+        visitor.visit_resource_resource_selector_mut(&mut v.target);
+        for x1 in &mut v.triggers {
+            visitor.visit_flow_flow_trigger_input_mut(x1);
+        }
+        for x1 in &mut v.tasks {
+            visitor.visit_flow_task_spec_input_mut(x1);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTrigger,
+    ) {
+        match v {
+            flow::FlowTrigger::Schedule(x) => visitor.visit_flow_flow_trigger_schedule_mut(x),
+            flow::FlowTrigger::Event(x) => visitor.visit_flow_flow_trigger_event_mut(x),
+            flow::FlowTrigger::Source(x) => visitor.visit_flow_flow_trigger_source_mut(x),
+            flow::FlowTrigger::Dataset(x) => visitor.visit_flow_flow_trigger_dataset_mut(x),
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_dataset_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerDataset,
+    ) {
+        visitor.visit_dataset_dataset_selector_mut(&mut v.dataset);
+        if let Some(x) = &mut v.events {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_event_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerEvent,
+    ) {
+        visitor.visit_event_event_filter_mut(&mut v.events);
+        if let Some(x) = &mut v.cooldown {
+            visitor.visit_duration_mut(x);
+        }
+        if let Some(x) = &mut v.cooldown_max_batch {
+            visitor.visit_u64_mut(x);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerInput,
+    ) {
+        match v {
+            flow::FlowTriggerInput::Schedule(x) => {
+                visitor.visit_flow_flow_trigger_input_schedule_mut(x)
+            }
+            flow::FlowTriggerInput::Event(x) => visitor.visit_flow_flow_trigger_input_event_mut(x),
+            flow::FlowTriggerInput::Source(x) => {
+                visitor.visit_flow_flow_trigger_input_source_mut(x)
+            }
+            flow::FlowTriggerInput::Dataset(x) => {
+                visitor.visit_flow_flow_trigger_input_dataset_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input_dataset_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerInputDataset,
+    ) {
+        visitor.visit_dataset_dataset_selector_mut(&mut v.dataset);
+        if let Some(x) = &mut v.events {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input_event_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerInputEvent,
+    ) {
+        visitor.visit_event_event_filter_mut(&mut v.events);
+        if let Some(x) = &mut v.cooldown {
+            visitor.visit_duration_mut(x);
+        }
+        if let Some(x) = &mut v.cooldown_max_batch {
+            visitor.visit_u64_mut(x);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_input_schedule_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerInputSchedule,
+    ) {
+        visitor.visit_string_mut(&mut v.cron);
+    }
+
+    pub fn walk_flow_flow_trigger_input_source_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerInputSource,
+    ) {
+        visitor.visit_resource_resource_ref_mut(&mut v.source);
+        if let Some(x) = &mut v.min_records_to_await {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.max_await_interval {
+            visitor.visit_duration_mut(x);
+        }
+    }
+
+    pub fn walk_flow_flow_trigger_schedule_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerSchedule,
+    ) {
+        visitor.visit_string_mut(&mut v.cron);
+    }
+
+    pub fn walk_flow_flow_trigger_source_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::FlowTriggerSource,
+    ) {
+        visitor.visit_resource_resource_handle_mut(&mut v.source);
+        if let Some(x) = &mut v.min_records_to_await {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.max_await_interval {
+            visitor.visit_duration_mut(x);
+        }
+    }
+
+    pub fn walk_flow_task_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut flow::Task) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        if let Some(x) = &mut v.spec {
+            visitor.visit_flow_task_spec_input_mut(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut flow::TaskSpec) {
+        match v {
+            flow::TaskSpec::Ingest(x) => visitor.visit_flow_task_spec_ingest_mut(x),
+            flow::TaskSpec::Compaction(x) => visitor.visit_flow_task_spec_compaction_mut(x),
+            flow::TaskSpec::GarbageCollection(x) => {
+                visitor.visit_flow_task_spec_garbage_collection_mut(x)
+            }
+            flow::TaskSpec::WebhookCall(x) => visitor.visit_flow_task_spec_webhook_call_mut(x),
+        }
+    }
+
+    pub fn walk_flow_task_spec_compaction_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::TaskSpecCompaction,
+    ) {
+        if let Some(x) = &mut v.params {
+            visitor.visit_dataset_compaction_params_mut(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_ingest_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::TaskSpecIngest,
+    ) {
+        visitor.visit_resource_resource_handle_mut(&mut v.source);
+        if let Some(x) = &mut v.params {
+            visitor.visit_source_ingest_params_mut(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::TaskSpecInput,
+    ) {
+        match v {
+            flow::TaskSpecInput::Ingest(x) => visitor.visit_flow_task_spec_input_ingest_mut(x),
+            flow::TaskSpecInput::Compaction(x) => {
+                visitor.visit_flow_task_spec_input_compaction_mut(x)
+            }
+            flow::TaskSpecInput::GarbageCollection(x) => {
+                visitor.visit_flow_task_spec_input_garbage_collection_mut(x)
+            }
+            flow::TaskSpecInput::WebhookCall(x) => {
+                visitor.visit_flow_task_spec_input_webhook_call_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_flow_task_spec_input_compaction_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::TaskSpecInputCompaction,
+    ) {
+        if let Some(x) = &mut v.params {
+            visitor.visit_dataset_compaction_params_mut(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_input_ingest_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::TaskSpecInputIngest,
+    ) {
+        visitor.visit_resource_resource_ref_mut(&mut v.source);
+        if let Some(x) = &mut v.params {
+            visitor.visit_source_ingest_params_mut(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_input_webhook_call_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::TaskSpecInputWebhookCall,
+    ) {
+        visitor.visit_resource_resource_ref_mut(&mut v.target);
+        if let Some(x) = &mut v.payload {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_flow_task_spec_webhook_call_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut flow::TaskSpecWebhookCall,
+    ) {
+        visitor.visit_resource_resource_handle_mut(&mut v.target);
+        if let Some(x) = &mut v.payload {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_legacy_add_push_source_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::AddPushSource,
+    ) {
+        visitor.visit_string_mut(&mut v.source_name);
+        visitor.visit_source_read_step_mut(&mut v.read);
+        if let Some(x) = &mut v.preprocess {
+            visitor.visit_dataset_transform_mut(x);
+        }
+        visitor.visit_source_merge_strategy_mut(&mut v.merge);
+    }
+
+    pub fn walk_legacy_dataset_snapshot_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::DatasetSnapshot,
+    ) {
+        visitor.visit_dataset_alias_mut(&mut v.name);
+        visitor.visit_dataset_dataset_kind_mut(&mut v.kind);
+        for x1 in &mut v.metadata {
+            visitor.visit_dataset_metadata_event_mut(x1);
+        }
+    }
+
+    pub fn walk_legacy_disable_push_source_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::DisablePushSource,
+    ) {
+        visitor.visit_string_mut(&mut v.source_name);
+    }
+
+    pub fn walk_legacy_fetch_step_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::FetchStep,
+    ) {
+        match v {
+            legacy::FetchStep::Url(x) => visitor.visit_legacy_fetch_step_url_mut(x),
+            legacy::FetchStep::FilesGlob(x) => visitor.visit_legacy_fetch_step_files_glob_mut(x),
+            legacy::FetchStep::Container(x) => visitor.visit_legacy_fetch_step_container_mut(x),
+            legacy::FetchStep::Mqtt(x) => visitor.visit_legacy_fetch_step_mqtt_mut(x),
+            legacy::FetchStep::EthereumLogs(x) => {
+                visitor.visit_legacy_fetch_step_ethereum_logs_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_container_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::FetchStepContainer,
+    ) {
+        visitor.visit_string_mut(&mut v.image);
+        if let Some(x) = &mut v.command {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.args {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.env {
+            for x1 in x {
+                visitor.visit_source_env_var_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_ethereum_logs_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::FetchStepEthereumLogs,
+    ) {
+        if let Some(x) = &mut v.chain_id {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.node_url {
+            visitor.visit_url_mut(x);
+        }
+        if let Some(x) = &mut v.filter {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.signature {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_files_glob_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::FetchStepFilesGlob,
+    ) {
+        visitor.visit_string_mut(&mut v.path);
+        if let Some(x) = &mut v.event_time {
+            visitor.visit_source_event_time_source_mut(x);
+        }
+        if let Some(x) = &mut v.cache {
+            visitor.visit_source_source_caching_mut(x);
+        }
+        if let Some(x) = &mut v.order {
+            visitor.visit_source_source_ordering_mut(x);
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_mqtt_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::FetchStepMqtt,
+    ) {
+        visitor.visit_string_mut(&mut v.host);
+        visitor.visit_i32_mut(&mut v.port);
+        if let Some(x) = &mut v.username {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.password {
+            visitor.visit_string_mut(x.expose_mut());
+        }
+        for x1 in &mut v.topics {
+            visitor.visit_source_mqtt_topic_subscription_mut(x1);
+        }
+    }
+
+    pub fn walk_legacy_fetch_step_url_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::FetchStepUrl,
+    ) {
+        visitor.visit_url_mut(&mut v.url);
+        if let Some(x) = &mut v.event_time {
+            visitor.visit_source_event_time_source_mut(x);
+        }
+        if let Some(x) = &mut v.cache {
+            visitor.visit_source_source_caching_mut(x);
+        }
+        if let Some(x) = &mut v.headers {
+            for x1 in x {
+                visitor.visit_source_request_header_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_legacy_manifest_mut<V: VisitMut + ?Sized, ContentT: Visitable>(
+        visitor: &mut V,
+        v: &mut legacy::Manifest<ContentT>,
+    ) {
+        visitor.visit_multicodec_mut(&mut v.kind);
+        visitor.visit_i32_mut(&mut v.version);
+        v.content.accept_mut(visitor);
+    }
+
+    pub fn walk_legacy_set_polling_source_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut legacy::SetPollingSource,
+    ) {
+        visitor.visit_legacy_fetch_step_mut(&mut v.fetch);
+        if let Some(x) = &mut v.prepare {
+            for x1 in x {
+                visitor.visit_source_prep_step_mut(x1);
+            }
+        }
+        visitor.visit_source_read_step_mut(&mut v.read);
+        if let Some(x) = &mut v.preprocess {
+            visitor.visit_dataset_transform_mut(x);
+        }
+        visitor.visit_source_merge_strategy_mut(&mut v.merge);
+    }
+
+    pub fn walk_protocol_dataset_error_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetError,
+    ) {
+        visitor.visit_protocol_error_details_mut(&mut v.error_details);
+    }
+
+    pub fn walk_protocol_dataset_metadata_pull_response_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetMetadataPullResponse,
+    ) {
+        visitor.visit_protocol_objects_batch_mut(&mut v.blocks);
+    }
+
+    pub fn walk_protocol_dataset_pull_objects_transfer_request_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPullObjectsTransferRequest,
+    ) {
+        for x1 in &mut v.object_files {
+            visitor.visit_protocol_object_file_reference_mut(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_pull_objects_transfer_response_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPullObjectsTransferResponse,
+    ) {
+        for x1 in &mut v.object_transfer_strategies {
+            visitor.visit_protocol_pull_object_transfer_strategy_mut(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_pull_request_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPullRequest,
+    ) {
+        visitor.visit_dataset_id_mut(&mut v.dataset_id);
+        if let Some(x) = &mut v.begin_after {
+            visitor.visit_multihash_mut(x);
+        }
+        if let Some(x) = &mut v.stop_at {
+            visitor.visit_multihash_mut(x);
+        }
+    }
+
+    pub fn walk_protocol_dataset_pull_response_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPullResponse,
+    ) {
+        visitor.visit_protocol_transfer_size_estimation_mut(&mut v.size_estimation);
+    }
+
+    pub fn walk_protocol_dataset_push_metadata_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPushMetadata,
+    ) {
+        visitor.visit_protocol_objects_batch_mut(&mut v.new_blocks);
+    }
+
+    pub fn walk_protocol_dataset_push_objects_transfer_request_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPushObjectsTransferRequest,
+    ) {
+        for x1 in &mut v.object_files {
+            visitor.visit_protocol_object_file_reference_mut(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_push_objects_transfer_response_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPushObjectsTransferResponse,
+    ) {
+        for x1 in &mut v.object_transfer_strategies {
+            visitor.visit_protocol_push_object_transfer_strategy_mut(x1);
+        }
+    }
+
+    pub fn walk_protocol_dataset_push_request_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::DatasetPushRequest,
+    ) {
+        visitor.visit_dataset_id_mut(&mut v.dataset_id);
+        visitor.visit_multihash_mut(&mut v.current_head);
+        visitor.visit_protocol_transfer_size_estimation_mut(&mut v.size_estimation);
+    }
+
+    pub fn walk_protocol_error_details_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::ErrorDetails,
+    ) {
+        visitor.visit_string_mut(&mut v.error_code);
+        visitor.visit_string_mut(&mut v.description);
+        if let Some(x) = &mut v.extras {
+            visitor.visit_any_json_mut(x);
+        }
+    }
+
+    pub fn walk_protocol_object_file_reference_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::ObjectFileReference,
+    ) {
+        visitor.visit_protocol_object_type_mut(&mut v.object_type);
+        visitor.visit_multihash_mut(&mut v.physical_hash);
+    }
+
+    pub fn walk_protocol_objects_batch_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::ObjectsBatch,
+    ) {
+        visitor.visit_u64_mut(&mut v.objects_count);
+        visitor.visit_protocol_object_type_mut(&mut v.object_type);
+        visitor.visit_string_mut(&mut v.media_type);
+        visitor.visit_string_mut(&mut v.encoding);
+        visitor.visit_string_mut(&mut v.payload);
+    }
+
+    pub fn walk_protocol_pull_object_transfer_strategy_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::PullObjectTransferStrategy,
+    ) {
+        visitor.visit_protocol_object_file_reference_mut(&mut v.object_file);
+        visitor.visit_protocol_object_pull_strategy_mut(&mut v.pull_strategy);
+        visitor.visit_protocol_transfer_url_mut(&mut v.download_from);
+    }
+
+    pub fn walk_protocol_push_object_transfer_strategy_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::PushObjectTransferStrategy,
+    ) {
+        visitor.visit_protocol_object_file_reference_mut(&mut v.object_file);
+        visitor.visit_protocol_object_push_strategy_mut(&mut v.push_strategy);
+        if let Some(x) = &mut v.upload_to {
+            visitor.visit_protocol_transfer_url_mut(x);
+        }
+    }
+
+    pub fn walk_protocol_transfer_size_estimation_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::TransferSizeEstimation,
+    ) {
+        visitor.visit_u64_mut(&mut v.num_blocks);
+        visitor.visit_u64_mut(&mut v.num_objects);
+        visitor.visit_u64_mut(&mut v.bytes_in_raw_blocks);
+        visitor.visit_u64_mut(&mut v.bytes_in_raw_objects);
+    }
+
+    pub fn walk_protocol_transfer_url_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut protocol::TransferUrl,
+    ) {
+        visitor.visit_url_mut(&mut v.url);
+        if let Some(x) = &mut v.expires_at {
+            visitor.visit_date_time_mut(x);
+        }
+    }
+
+    pub fn walk_resource_filter_condition_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::FilterCondition,
+    ) {
+        if let Some(x) = &mut v.eq {
+            visitor.visit_any_json_mut(x);
+        }
+        if let Some(x) = &mut v.r#in {
+            for x1 in x {
+                visitor.visit_any_json_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.exists {
+            visitor.visit_bool_mut(x);
+        }
+        if let Some(x) = &mut v.glob {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_resource_label_filter_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::LabelFilter,
+    ) {
+        match v {
+            resource::LabelFilter::And(x) => visitor.visit_resource_label_filter_and_mut(x),
+            resource::LabelFilter::Or(x) => visitor.visit_resource_label_filter_or_mut(x),
+            resource::LabelFilter::Not(x) => visitor.visit_resource_label_filter_not_mut(x),
+            resource::LabelFilter::Match(x) => visitor.visit_resource_label_filter_match_mut(x),
+        }
+    }
+
+    pub fn walk_resource_label_filter_and_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::LabelFilterAnd,
+    ) {
+        for x1 in &mut v.and {
+            visitor.visit_resource_label_filter_mut(x1);
+        }
+    }
+
+    pub fn walk_resource_label_filter_match_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::LabelFilterMatch,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_resource_filter_condition_mut(x);
+        }
+    }
+
+    pub fn walk_resource_label_filter_not_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::LabelFilterNot,
+    ) {
+        visitor.visit_resource_label_filter_mut(&mut v.not);
+    }
+
+    pub fn walk_resource_label_filter_or_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::LabelFilterOr,
+    ) {
+        for x1 in &mut v.or {
+            visitor.visit_resource_label_filter_mut(x1);
+        }
+    }
+
+    pub fn walk_resource_resource_mut<V: VisitMut + ?Sized, SpecT: Visitable>(
+        visitor: &mut V,
+        v: &mut resource::Resource<SpecT>,
+    ) {
+        visitor.visit_type_uri_mut(&mut v.schema);
+        visitor.visit_resource_resource_headers_mut(&mut v.headers);
+        v.spec.accept_mut(visitor);
+        visitor.visit_resource_resource_status_mut(&mut v.status);
+    }
+
+    pub fn walk_resource_resource_annotations_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceAnnotations,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_any_json_mut(x);
+        }
+    }
+
+    pub fn walk_resource_resource_conditions_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceConditions,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_any_json_mut(x);
+        }
+    }
+
+    pub fn walk_resource_resource_handle_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceHandle,
+    ) {
+        visitor.visit_auth_account_handle_mut(&mut v.account);
+        visitor.visit_type_uri_mut(&mut v.r#type);
+        visitor.visit_resource_id_mut(&mut v.id);
+        if let Some(x) = &mut v.did {
+            visitor.visit_did_mut(x);
+        }
+        visitor.visit_resource_name_mut(&mut v.name);
+    }
+
+    pub fn walk_resource_resource_headers_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceHeaders,
+    ) {
+        visitor.visit_resource_id_mut(&mut v.id);
+        visitor.visit_resource_name_mut(&mut v.name);
+        visitor.visit_auth_account_handle_mut(&mut v.account);
+        visitor.visit_resource_resource_labels_mut(&mut v.labels);
+        visitor.visit_resource_resource_annotations_mut(&mut v.annotations);
+        visitor.visit_u64_mut(&mut v.generation);
+        visitor.visit_date_time_mut(&mut v.created_at);
+        visitor.visit_date_time_mut(&mut v.updated_at);
+        if let Some(x) = &mut v.deleted_at {
+            visitor.visit_date_time_mut(x);
+        }
+    }
+
+    pub fn walk_resource_resource_headers_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceHeadersInput,
+    ) {
+        if let Some(x) = &mut v.id {
+            visitor.visit_resource_id_mut(x);
+        }
+        visitor.visit_resource_name_mut(&mut v.name);
+        if let Some(x) = &mut v.account {
+            visitor.visit_auth_account_ref_mut(x);
+        }
+        if let Some(x) = &mut v.labels {
+            visitor.visit_resource_resource_labels_mut(x);
+        }
+        if let Some(x) = &mut v.annotations {
+            visitor.visit_resource_resource_annotations_mut(x);
+        }
+    }
+
+    pub fn walk_resource_resource_input_mut<V: VisitMut + ?Sized, SpecT: Visitable>(
+        visitor: &mut V,
+        v: &mut resource::ResourceInput<SpecT>,
+    ) {
+        visitor.visit_type_uri_mut(&mut v.schema);
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        v.spec.accept_mut(visitor);
+    }
+
+    pub fn walk_resource_resource_labels_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceLabels,
+    ) {
+        for x in v.entries.values_mut() {
+            visitor.visit_any_json_mut(x);
+        }
+    }
+
+    pub fn walk_resource_resource_ref_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceRef,
+    ) {
+        if let Some(x) = &mut v.account {
+            visitor.visit_auth_account_ref_mut(x);
+        }
+        visitor.visit_type_ref_mut(&mut v.r#type);
+        if let Some(x) = &mut v.id {
+            visitor.visit_resource_id_mut(x);
+        }
+        if let Some(x) = &mut v.did {
+            visitor.visit_did_mut(x);
+        }
+        if let Some(x) = &mut v.name {
+            visitor.visit_resource_name_mut(x);
+        }
+    }
+
+    pub fn walk_resource_resource_status_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut resource::ResourceStatus,
+    ) {
+        visitor.visit_resource_resource_phase_mut(&mut v.phase);
+        if let Some(x) = &mut v.observed_generation {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.reconciled_at {
+            visitor.visit_date_time_mut(x);
+        }
+        visitor.visit_resource_resource_conditions_mut(&mut v.conditions);
+    }
+
+    pub fn walk_sink_webhook_target_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut sink::WebhookTarget,
+    ) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_sink_webhook_target_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_sink_webhook_target_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut sink::WebhookTargetSpec,
+    ) {
+        visitor.visit_url_mut(&mut v.url);
+        if let Some(x) = &mut v.secret {
+            visitor.visit_config_secret_mut(x);
+        }
+    }
+
+    pub fn walk_sink_webhook_target_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut sink::WebhookTargetSpecInput,
+    ) {
+        visitor.visit_url_mut(&mut v.url);
+        if let Some(x) = &mut v.secret {
+            visitor.visit_config_secret_mut(x);
+        }
+    }
+
+    pub fn walk_sink_webhook_target_status_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut sink::WebhookTargetStatus,
+    ) {
+        visitor.visit_sink_webhook_target_status_value_mut(&mut v.value);
+        visitor.visit_date_time_mut(&mut v.last_transition_time);
+        if let Some(x) = &mut v.reason {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.message {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_source_env_var_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut source::EnvVar) {
+        visitor.visit_string_mut(&mut v.name);
+        if let Some(x) = &mut v.value {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_source_event_time_source_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::EventTimeSource,
+    ) {
+        match v {
+            source::EventTimeSource::FromMetadata(x) => {
+                visitor.visit_source_event_time_source_from_metadata_mut(x)
+            }
+            source::EventTimeSource::FromPath(x) => {
+                visitor.visit_source_event_time_source_from_path_mut(x)
+            }
+            source::EventTimeSource::FromSystemTime(x) => {
+                visitor.visit_source_event_time_source_from_system_time_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_source_event_time_source_from_path_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::EventTimeSourceFromPath,
+    ) {
+        visitor.visit_regex_mut(&mut v.pattern);
+        if let Some(x) = &mut v.timestamp_format {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_source_ingest_params_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngestParams,
+    ) {
+        if let Some(x) = &mut v.target_slice_records {
+            visitor.visit_u64_mut(x);
+        }
+    }
+
+    pub fn walk_source_ingress_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut source::Ingress) {
+        match v {
+            source::Ingress::Url(x) => visitor.visit_source_ingress_url_mut(x),
+            source::Ingress::FilesGlob(x) => visitor.visit_source_ingress_files_glob_mut(x),
+            source::Ingress::Container(x) => visitor.visit_source_ingress_container_mut(x),
+            source::Ingress::Mqtt(x) => visitor.visit_source_ingress_mqtt_mut(x),
+            source::Ingress::EvmLogs(x) => visitor.visit_source_ingress_evm_logs_mut(x),
+            source::Ingress::RestEndpoint(x) => visitor.visit_source_ingress_rest_endpoint_mut(x),
+        }
+    }
+
+    pub fn walk_source_ingress_buffer_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressBuffer,
+    ) {
+        match v {
+            source::IngressBuffer::Memory(x) => visitor.visit_source_ingress_buffer_memory_mut(x),
+        }
+    }
+
+    pub fn walk_source_ingress_buffer_memory_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressBufferMemory,
+    ) {
+        if let Some(x) = &mut v.buffer_size {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.overflow_policy {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_source_ingress_container_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressContainer,
+    ) {
+        visitor.visit_string_mut(&mut v.image);
+        if let Some(x) = &mut v.command {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.args {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.env {
+            for x1 in x {
+                visitor.visit_source_env_var_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_source_ingress_evm_logs_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressEvmLogs,
+    ) {
+        if let Some(x) = &mut v.chain_id {
+            visitor.visit_u64_mut(x);
+        }
+        if let Some(x) = &mut v.node_url {
+            visitor.visit_url_mut(x);
+        }
+        if let Some(x) = &mut v.filter {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.signature {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_source_ingress_files_glob_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressFilesGlob,
+    ) {
+        visitor.visit_string_mut(&mut v.path);
+        if let Some(x) = &mut v.event_time {
+            visitor.visit_source_event_time_source_mut(x);
+        }
+        if let Some(x) = &mut v.cache {
+            visitor.visit_source_source_caching_mut(x);
+        }
+        if let Some(x) = &mut v.order {
+            visitor.visit_source_source_ordering_mut(x);
+        }
+    }
+
+    pub fn walk_source_ingress_mqtt_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressMqtt,
+    ) {
+        visitor.visit_string_mut(&mut v.host);
+        visitor.visit_i32_mut(&mut v.port);
+        if let Some(x) = &mut v.username {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.password {
+            visitor.visit_string_mut(x.expose_mut());
+        }
+        for x1 in &mut v.topics {
+            visitor.visit_source_mqtt_topic_subscription_mut(x1);
+        }
+    }
+
+    pub fn walk_source_ingress_rest_endpoint_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressRestEndpoint,
+    ) {
+        if let Some(x) = &mut v.buffer {
+            visitor.visit_source_ingress_buffer_mut(x);
+        }
+    }
+
+    pub fn walk_source_ingress_url_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::IngressUrl,
+    ) {
+        visitor.visit_url_mut(&mut v.url);
+        if let Some(x) = &mut v.event_time {
+            visitor.visit_source_event_time_source_mut(x);
+        }
+        if let Some(x) = &mut v.cache {
+            visitor.visit_source_source_caching_mut(x);
+        }
+        if let Some(x) = &mut v.headers {
+            for x1 in x {
+                visitor.visit_source_request_header_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_source_merge_strategy_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::MergeStrategy,
+    ) {
+        match v {
+            source::MergeStrategy::Append(x) => visitor.visit_source_merge_strategy_append_mut(x),
+            source::MergeStrategy::Ledger(x) => visitor.visit_source_merge_strategy_ledger_mut(x),
+            source::MergeStrategy::Snapshot(x) => {
+                visitor.visit_source_merge_strategy_snapshot_mut(x)
+            }
+            source::MergeStrategy::ChangelogStream(x) => {
+                visitor.visit_source_merge_strategy_changelog_stream_mut(x)
+            }
+            source::MergeStrategy::UpsertStream(x) => {
+                visitor.visit_source_merge_strategy_upsert_stream_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_source_merge_strategy_changelog_stream_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::MergeStrategyChangelogStream,
+    ) {
+        for x1 in &mut v.primary_key {
+            visitor.visit_string_mut(x1);
+        }
+    }
+
+    pub fn walk_source_merge_strategy_ledger_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::MergeStrategyLedger,
+    ) {
+        for x1 in &mut v.primary_key {
+            visitor.visit_string_mut(x1);
+        }
+    }
+
+    pub fn walk_source_merge_strategy_snapshot_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::MergeStrategySnapshot,
+    ) {
+        for x1 in &mut v.primary_key {
+            visitor.visit_string_mut(x1);
+        }
+        if let Some(x) = &mut v.compare_columns {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+    }
+
+    pub fn walk_source_merge_strategy_upsert_stream_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::MergeStrategyUpsertStream,
+    ) {
+        for x1 in &mut v.primary_key {
+            visitor.visit_string_mut(x1);
+        }
+    }
+
+    pub fn walk_source_mqtt_topic_subscription_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::MqttTopicSubscription,
+    ) {
+        visitor.visit_string_mut(&mut v.path);
+        if let Some(x) = &mut v.qos {
+            visitor.visit_source_mqtt_qos_mut(x);
+        }
+    }
+
+    pub fn walk_source_prep_step_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::PrepStep,
+    ) {
+        match v {
+            source::PrepStep::Decompress(x) => visitor.visit_source_prep_step_decompress_mut(x),
+            source::PrepStep::Pipe(x) => visitor.visit_source_prep_step_pipe_mut(x),
+        }
+    }
+
+    pub fn walk_source_prep_step_decompress_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::PrepStepDecompress,
+    ) {
+        visitor.visit_source_compression_format_mut(&mut v.format);
+        if let Some(x) = &mut v.sub_path {
+            visitor.visit_string_mut(x);
+        }
+    }
+
+    pub fn walk_source_prep_step_pipe_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::PrepStepPipe,
+    ) {
+        for x1 in &mut v.command {
+            visitor.visit_string_mut(x1);
+        }
+    }
+
+    pub fn walk_source_read_step_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStep,
+    ) {
+        match v {
+            source::ReadStep::Csv(x) => visitor.visit_source_read_step_csv_mut(x),
+            source::ReadStep::GeoJson(x) => visitor.visit_source_read_step_geo_json_mut(x),
+            source::ReadStep::EsriShapefile(x) => {
+                visitor.visit_source_read_step_esri_shapefile_mut(x)
+            }
+            source::ReadStep::Parquet(x) => visitor.visit_source_read_step_parquet_mut(x),
+            source::ReadStep::Json(x) => visitor.visit_source_read_step_json_mut(x),
+            source::ReadStep::NdJson(x) => visitor.visit_source_read_step_nd_json_mut(x),
+            source::ReadStep::NdGeoJson(x) => visitor.visit_source_read_step_nd_geo_json_mut(x),
+        }
+    }
+
+    pub fn walk_source_read_step_csv_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStepCsv,
+    ) {
+        if let Some(x) = &mut v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.separator {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.encoding {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.quote {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.escape {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.header {
+            visitor.visit_bool_mut(x);
+        }
+        if let Some(x) = &mut v.infer_schema {
+            visitor.visit_bool_mut(x);
+        }
+        if let Some(x) = &mut v.null_value {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.date_format {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.timestamp_format {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_source_read_step_esri_shapefile_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStepEsriShapefile,
+    ) {
+        if let Some(x) = &mut v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.sub_path {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_source_read_step_geo_json_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStepGeoJson,
+    ) {
+        if let Some(x) = &mut v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_source_read_step_json_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStepJson,
+    ) {
+        if let Some(x) = &mut v.sub_path {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.date_format {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.encoding {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.timestamp_format {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_source_read_step_nd_geo_json_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStepNdGeoJson,
+    ) {
+        if let Some(x) = &mut v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_source_read_step_nd_json_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStepNdJson,
+    ) {
+        if let Some(x) = &mut v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.date_format {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.encoding {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.timestamp_format {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_source_read_step_parquet_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::ReadStepParquet,
+    ) {
+        if let Some(x) = &mut v.ddl_schema {
+            for x1 in x {
+                visitor.visit_string_mut(x1);
+            }
+        }
+        if let Some(x) = &mut v.schema {
+            visitor.visit_data_data_schema_mut(x);
+        }
+    }
+
+    pub fn walk_source_request_header_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::RequestHeader,
+    ) {
+        visitor.visit_string_mut(&mut v.name);
+        visitor.visit_string_mut(&mut v.value);
+    }
+
+    pub fn walk_source_source_mut<V: VisitMut + ?Sized>(visitor: &mut V, v: &mut source::Source) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_source_source_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_source_source_caching_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::SourceCaching,
+    ) {
+        match v {
+            source::SourceCaching::Forever(x) => visitor.visit_source_source_caching_forever_mut(x),
+        }
+    }
+
+    pub fn walk_source_source_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::SourceSpec,
+    ) {
+        if let Some(x) = &mut v.config {
+            visitor.visit_config_value_refs_mut(x);
+        }
+        if let Some(x) = &mut v.ingress {
+            visitor.visit_source_ingress_mut(x);
+        }
+        if let Some(x) = &mut v.prepare {
+            for x1 in x {
+                visitor.visit_source_prep_step_mut(x1);
+            }
+        }
+        visitor.visit_source_read_step_mut(&mut v.read);
+        if let Some(x) = &mut v.preprocess {
+            visitor.visit_dataset_transform_mut(x);
+        }
+        if let Some(x) = &mut v.merge {
+            visitor.visit_source_merge_strategy_mut(x);
+        }
+        if let Some(x) = &mut v.vocab {
+            visitor.visit_dataset_dataset_vocabulary_mut(x);
+        }
+    }
+
+    pub fn walk_source_source_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::SourceSpecInput,
+    ) {
+        if let Some(x) = &mut v.config {
+            visitor.visit_config_value_refs_mut(x);
+        }
+        if let Some(x) = &mut v.ingress {
+            visitor.visit_source_ingress_mut(x);
+        }
+        if let Some(x) = &mut v.prepare {
+            for x1 in x {
+                visitor.visit_source_prep_step_mut(x1);
+            }
+        }
+        visitor.visit_source_read_step_mut(&mut v.read);
+        if let Some(x) = &mut v.preprocess {
+            visitor.visit_dataset_transform_mut(x);
+        }
+        if let Some(x) = &mut v.merge {
+            visitor.visit_source_merge_strategy_mut(x);
+        }
+        if let Some(x) = &mut v.vocab {
+            visitor.visit_dataset_dataset_vocabulary_mut(x);
+        }
+    }
+
+    pub fn walk_source_source_state_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut source::SourceState,
+    ) {
+        visitor.visit_string_mut(&mut v.source_name);
+        visitor.visit_string_mut(&mut v.kind);
+        visitor.visit_string_mut(&mut v.value);
+    }
+
+    pub fn walk_storage_aws_credentials_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::AwsCredentials,
+    ) {
+        if let Some(x) = &mut v.access_key {
+            visitor.visit_config_value_handle_mut(x);
+        }
+        if let Some(x) = &mut v.secret_key {
+            visitor.visit_config_value_handle_mut(x);
+        }
+    }
+
+    pub fn walk_storage_aws_credentials_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::AwsCredentialsInput,
+    ) {
+        if let Some(x) = &mut v.access_key {
+            visitor.visit_config_value_ref_mut(x);
+        }
+        if let Some(x) = &mut v.secret_key {
+            visitor.visit_config_value_ref_mut(x);
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::PersistentVolume,
+    ) {
+        visitor.visit_resource_resource_headers_input_mut(&mut v.headers);
+        visitor.visit_storage_persistent_volume_spec_input_mut(&mut v.spec);
+    }
+
+    pub fn walk_storage_persistent_volume_ref_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::PersistentVolumeRef,
+    ) {
+        if let Some(x) = &mut v.account {
+            visitor.visit_auth_account_ref_mut(x);
+        }
+        if let Some(x) = &mut v.id {
+            visitor.visit_resource_id_mut(x);
+        }
+        if let Some(x) = &mut v.name {
+            visitor.visit_resource_name_mut(x);
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::PersistentVolumeSpec,
+    ) {
+        match v {
+            storage::PersistentVolumeSpec::S3(x) => {
+                visitor.visit_storage_persistent_volume_spec_s_3_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec_input_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::PersistentVolumeSpecInput,
+    ) {
+        match v {
+            storage::PersistentVolumeSpecInput::S3(x) => {
+                visitor.visit_storage_persistent_volume_spec_input_s_3_mut(x)
+            }
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec_input_s_3_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::PersistentVolumeSpecInputS3,
+    ) {
+        if let Some(x) = &mut v.endpoint {
+            visitor.visit_url_mut(x);
+        }
+        if let Some(x) = &mut v.region {
+            visitor.visit_string_mut(x);
+        }
+        visitor.visit_string_mut(&mut v.bucket);
+        if let Some(x) = &mut v.prefix {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.capacity {
+            visitor.visit_storage_volume_capacity_mut(x);
+        }
+        if let Some(x) = &mut v.credentials {
+            visitor.visit_storage_aws_credentials_input_mut(x);
+        }
+    }
+
+    pub fn walk_storage_persistent_volume_spec_s_3_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::PersistentVolumeSpecS3,
+    ) {
+        if let Some(x) = &mut v.endpoint {
+            visitor.visit_url_mut(x);
+        }
+        if let Some(x) = &mut v.region {
+            visitor.visit_string_mut(x);
+        }
+        visitor.visit_string_mut(&mut v.bucket);
+        if let Some(x) = &mut v.prefix {
+            visitor.visit_string_mut(x);
+        }
+        if let Some(x) = &mut v.capacity {
+            visitor.visit_storage_volume_capacity_mut(x);
+        }
+        if let Some(x) = &mut v.credentials {
+            visitor.visit_storage_aws_credentials_mut(x);
+        }
+    }
+
+    pub fn walk_storage_volume_capacity_mut<V: VisitMut + ?Sized>(
+        visitor: &mut V,
+        v: &mut storage::VolumeCapacity,
+    ) {
+        if let Some(x) = &mut v.storage {
+            visitor.visit_byte_size_mut(x);
+        }
+    }
+
+    impl Visitable for auth::Account {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_account(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_account_mut(self)
+        }
+    }
+
+    impl Visitable for auth::AccountHandle {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_account_handle(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_account_handle_mut(self)
+        }
+    }
+
+    impl Visitable for auth::AccountRef {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_account_ref(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_account_ref_mut(self)
+        }
+    }
+
+    impl Visitable for auth::AccountSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_account_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_account_spec_mut(self)
+        }
+    }
+
+    impl Visitable for auth::AccountSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_account_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_account_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for auth::AccountType {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_account_type(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_account_type_mut(self)
+        }
+    }
+
+    impl Visitable for auth::Attribute {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_attribute(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_attribute_mut(self)
+        }
+    }
+
+    impl Visitable for auth::AttributeInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_attribute_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_attribute_input_mut(self)
+        }
+    }
+
+    impl Visitable for auth::Relation {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_relation(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_relation_mut(self)
+        }
+    }
+
+    impl Visitable for auth::RelationInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_relation_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_relation_input_mut(self)
+        }
+    }
+
+    impl Visitable for auth::Relations {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_relations(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_relations_mut(self)
+        }
+    }
+
+    impl Visitable for auth::RelationsSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_relations_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_relations_spec_mut(self)
+        }
+    }
+
+    impl Visitable for auth::RelationsSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_auth_relations_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_auth_relations_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for config::Secret {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_secret(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_secret_mut(self)
+        }
+    }
+
+    impl Visitable for config::SecretSet {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_secret_set(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_secret_set_mut(self)
+        }
+    }
+
+    impl Visitable for config::SecretSetSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_secret_set_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_secret_set_spec_mut(self)
+        }
+    }
+
+    impl Visitable for config::SecretSetSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_secret_set_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_secret_set_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for config::Secrets {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_secrets(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_secrets_mut(self)
+        }
+    }
+
+    impl Visitable for config::ValueHandle {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_value_handle(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_value_handle_mut(self)
+        }
+    }
+
+    impl Visitable for config::ValueRef {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_value_ref(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_value_ref_mut(self)
+        }
+    }
+
+    impl Visitable for config::ValueRefs {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_value_refs(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_value_refs_mut(self)
+        }
+    }
+
+    impl Visitable for config::Variable {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_variable(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_variable_mut(self)
+        }
+    }
+
+    impl Visitable for config::VariableSet {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_variable_set(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_variable_set_mut(self)
+        }
+    }
+
+    impl Visitable for config::VariableSetSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_variable_set_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_variable_set_spec_mut(self)
+        }
+    }
+
+    impl Visitable for config::VariableSetSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_variable_set_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_variable_set_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for config::Variables {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_config_variables(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_config_variables_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataField {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_field(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_field_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataSchema {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_schema(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_schema_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataType {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeBinary {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_binary(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_binary_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeBool {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_bool(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_bool_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeDate {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_date(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_date_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeDecimal {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_decimal(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_decimal_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeDuration {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_duration(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_duration_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeFloat16 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_float_16(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_float_16_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeFloat32 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_float_32(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_float_32_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeFloat64 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_float_64(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_float_64_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeInt16 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_16(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_16_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeInt32 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_32(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_32_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeInt64 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_64(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_64_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeInt8 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_8(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_int_8_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeList {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_list(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_list_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeMap {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_map(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_map_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeNull {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_null(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_null_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeOption {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_option(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_option_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeString {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_string(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_string_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeStruct {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_struct(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_struct_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeTime {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_time(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_time_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeTimestamp {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_timestamp(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_timestamp_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeUInt16 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_16(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_16_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeUInt32 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_32(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_32_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeUInt64 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_64(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_64_mut(self)
+        }
+    }
+
+    impl Visitable for data::DataTypeUInt8 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_8(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_data_type_u_int_8_mut(self)
+        }
+    }
+
+    impl Visitable for data::ExtraAttributes {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_extra_attributes(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_extra_attributes_mut(self)
+        }
+    }
+
+    impl Visitable for data::OperationType {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_operation_type(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_operation_type_mut(self)
+        }
+    }
+
+    impl Visitable for data::TimeUnit {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_data_time_unit(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_data_time_unit_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::AddData {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_add_data(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_add_data_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::AttachmentEmbedded {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_attachment_embedded(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_attachment_embedded_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::Attachments {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_attachments(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_attachments_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::AttachmentsEmbedded {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_attachments_embedded(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_attachments_embedded_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::Checkpoint {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_checkpoint(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_checkpoint_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::CompactionParams {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_compaction_params(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_compaction_params_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::DataSlice {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_data_slice(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_data_slice_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::Dataset {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_dataset(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::DatasetKind {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_kind(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_kind_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::DatasetSelector {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_selector(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_selector_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::DatasetSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_spec_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::DatasetSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::DatasetVocabulary {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_vocabulary(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_dataset_vocabulary_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::ExecuteTransform {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_execute_transform(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_execute_transform_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::ExecuteTransformInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_execute_transform_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_execute_transform_input_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::MetadataBlock {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_metadata_block(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_metadata_block_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::MetadataEvent {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_metadata_event(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_metadata_event_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::OffsetInterval {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_offset_interval(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_offset_interval_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::Projection {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_projection(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_projection_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::ProjectionSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_projection_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_projection_spec_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::ProjectionSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_projection_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_projection_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::Seed {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_seed(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_seed_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::SetAttachments {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_set_attachments(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_set_attachments_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::SetDataSchema {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_set_data_schema(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_set_data_schema_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::SetInfo {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_set_info(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_set_info_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::SetLicense {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_set_license(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_set_license_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::SetTransform {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_set_transform(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_set_transform_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::SetVocab {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_set_vocab(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_set_vocab_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::SqlQueryStep {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_sql_query_step(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_sql_query_step_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::TemporalTable {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_temporal_table(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_temporal_table_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::Transform {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_transform(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_transform_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::TransformInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_transform_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_transform_input_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::TransformSql {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_transform_sql(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_transform_sql_mut(self)
+        }
+    }
+
+    impl Visitable for dataset::Watermark {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_dataset_watermark(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_dataset_watermark_mut(self)
+        }
+    }
+
+    impl Visitable for engine::RawQueryRequest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_request(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_request_mut(self)
+        }
+    }
+
+    impl Visitable for engine::RawQueryResponse {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_mut(self)
+        }
+    }
+
+    impl Visitable for engine::RawQueryResponseInternalError {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_internal_error(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_internal_error_mut(self)
+        }
+    }
+
+    impl Visitable for engine::RawQueryResponseInvalidQuery {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_invalid_query(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_invalid_query_mut(self)
+        }
+    }
+
+    impl Visitable for engine::RawQueryResponseProgress {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_progress(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_progress_mut(self)
+        }
+    }
+
+    impl Visitable for engine::RawQueryResponseSuccess {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_success(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_raw_query_response_success_mut(self)
+        }
+    }
+
+    impl Visitable for engine::TransformRequest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_transform_request(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_transform_request_mut(self)
+        }
+    }
+
+    impl Visitable for engine::TransformRequestInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_transform_request_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_transform_request_input_mut(self)
+        }
+    }
+
+    impl Visitable for engine::TransformResponse {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_transform_response(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_mut(self)
+        }
+    }
+
+    impl Visitable for engine::TransformResponseInternalError {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_internal_error(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_internal_error_mut(self)
+        }
+    }
+
+    impl Visitable for engine::TransformResponseInvalidQuery {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_invalid_query(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_invalid_query_mut(self)
+        }
+    }
+
+    impl Visitable for engine::TransformResponseProgress {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_progress(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_progress_mut(self)
+        }
+    }
+
+    impl Visitable for engine::TransformResponseSuccess {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_success(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_engine_transform_response_success_mut(self)
+        }
+    }
+
+    impl Visitable for event::EventFilter {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_event_event_filter(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_event_event_filter_mut(self)
+        }
+    }
+
+    impl Visitable for event::EventFilterAnd {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_event_event_filter_and(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_event_event_filter_and_mut(self)
+        }
+    }
+
+    impl Visitable for event::EventFilterMatch {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_event_event_filter_match(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_event_event_filter_match_mut(self)
+        }
+    }
+
+    impl Visitable for event::EventFilterNot {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_event_event_filter_not(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_event_event_filter_not_mut(self)
+        }
+    }
+
+    impl Visitable for event::EventFilterOr {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_event_event_filter_or(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_event_event_filter_or_mut(self)
+        }
+    }
+
+    impl Visitable for flow::Flow {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_spec_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTrigger {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerDataset {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_dataset(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_dataset_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerEvent {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_event(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_event_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerInputDataset {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_dataset(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_dataset_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerInputEvent {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_event(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_event_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerInputSchedule {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_schedule(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_schedule_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerInputSource {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_input_source_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerSchedule {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_schedule(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_schedule_mut(self)
+        }
+    }
+
+    impl Visitable for flow::FlowTriggerSource {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_flow_trigger_source_mut(self)
+        }
+    }
+
+    impl Visitable for flow::Task {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecCompaction {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_compaction(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_compaction_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecGarbageCollection {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_garbage_collection(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_garbage_collection_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecIngest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_ingest(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_ingest_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecInputCompaction {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_compaction(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_compaction_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecInputGarbageCollection {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_garbage_collection(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_garbage_collection_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecInputIngest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_ingest(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_ingest_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecInputWebhookCall {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_webhook_call(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_input_webhook_call_mut(self)
+        }
+    }
+
+    impl Visitable for flow::TaskSpecWebhookCall {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_webhook_call(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_flow_task_spec_webhook_call_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::AddPushSource {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_add_push_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_add_push_source_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::DatasetSnapshot {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_dataset_snapshot(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_dataset_snapshot_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::DisablePollingSource {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_disable_polling_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_disable_polling_source_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::DisablePushSource {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_disable_push_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_disable_push_source_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::FetchStep {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::FetchStepContainer {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_container(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_container_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::FetchStepEthereumLogs {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_ethereum_logs(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_ethereum_logs_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::FetchStepFilesGlob {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_files_glob(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_files_glob_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::FetchStepMqtt {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_mqtt(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_mqtt_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::FetchStepUrl {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_url(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_fetch_step_url_mut(self)
+        }
+    }
+
+    impl<ContentT: Visitable> Visitable for legacy::Manifest<ContentT> {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_manifest(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_manifest_mut(self)
+        }
+    }
+
+    impl Visitable for legacy::SetPollingSource {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_legacy_set_polling_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_legacy_set_polling_source_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::DatasetError {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_error(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_error_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::DatasetMetadataPullResponse {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_metadata_pull_response(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_metadata_pull_response_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::DatasetPullMetadataRequest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_metadata_request(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_metadata_request_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::DatasetPullObjectsTransferRequest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_objects_transfer_request(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_objects_transfer_request_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::DatasetPullObjectsTransferResponse {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_objects_transfer_response(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_objects_transfer_response_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::DatasetPullRequest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_request(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_request_mut(self)
+        }
     }
 
-    impl ReadStepCsv {
-        pub fn default_separator() -> &'static str {
-            ","
+    impl Visitable for protocol::DatasetPullResponse {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_response(self)
         }
-        pub fn separator(&self) -> &str {
-            self.separator
-                .as_deref()
-                .unwrap_or(Self::default_separator())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_pull_response_mut(self)
         }
-        pub fn default_encoding() -> &'static str {
-            "utf8"
+    }
+
+    impl Visitable for protocol::DatasetPushComplete {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_complete(self)
         }
-        pub fn encoding(&self) -> &str {
-            self.encoding.as_deref().unwrap_or(Self::default_encoding())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_complete_mut(self)
         }
-        pub fn default_quote() -> &'static str {
-            "\""
+    }
+
+    impl Visitable for protocol::DatasetPushCompleteConfirmed {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_complete_confirmed(self)
         }
-        pub fn quote(&self) -> &str {
-            self.quote.as_deref().unwrap_or(Self::default_quote())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_complete_confirmed_mut(self)
         }
-        pub fn default_escape() -> &'static str {
-            "\\"
+    }
+
+    impl Visitable for protocol::DatasetPushMetadata {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_metadata(self)
         }
-        pub fn escape(&self) -> &str {
-            self.escape.as_deref().unwrap_or(Self::default_escape())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_metadata_mut(self)
         }
-        pub fn default_header() -> bool {
-            false
+    }
+
+    impl Visitable for protocol::DatasetPushMetadataAccepted {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_metadata_accepted(self)
         }
-        pub fn header(&self) -> bool {
-            self.header.unwrap_or(Self::default_header())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_metadata_accepted_mut(self)
         }
-        pub fn default_infer_schema() -> bool {
-            false
+    }
+
+    impl Visitable for protocol::DatasetPushObjectsTransferRequest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_objects_transfer_request(self)
         }
-        pub fn infer_schema(&self) -> bool {
-            self.infer_schema.unwrap_or(Self::default_infer_schema())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_objects_transfer_request_mut(self)
         }
-        pub fn default_null_value() -> &'static str {
-            ""
+    }
+
+    impl Visitable for protocol::DatasetPushObjectsTransferResponse {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_objects_transfer_response(self)
         }
-        pub fn null_value(&self) -> &str {
-            self.null_value
-                .as_deref()
-                .unwrap_or(Self::default_null_value())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_objects_transfer_response_mut(self)
         }
-        pub fn default_date_format() -> &'static str {
-            "rfc3339"
+    }
+
+    impl Visitable for protocol::DatasetPushRequest {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_request(self)
         }
-        pub fn date_format(&self) -> &str {
-            self.date_format
-                .as_deref()
-                .unwrap_or(Self::default_date_format())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_request_mut(self)
         }
-        pub fn default_timestamp_format() -> &'static str {
-            "rfc3339"
+    }
+
+    impl Visitable for protocol::DatasetPushRequestAccepted {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_request_accepted(self)
         }
-        pub fn timestamp_format(&self) -> &str {
-            self.timestamp_format
-                .as_deref()
-                .unwrap_or(Self::default_timestamp_format())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_dataset_push_request_accepted_mut(self)
         }
     }
 
-    impl PartialEq for ReadStepCsv {
-        fn eq(&self, other: &Self) -> bool {
-            self.ddl_schema == other.ddl_schema
-                && self
-                    .separator
-                    .as_deref()
-                    .or_else(|| Some(Self::default_separator()))
-                    == other
-                        .separator
-                        .as_deref()
-                        .or_else(|| Some(Self::default_separator()))
-                && self
-                    .encoding
-                    .as_deref()
-                    .or_else(|| Some(Self::default_encoding()))
-                    == other
-                        .encoding
-                        .as_deref()
-                        .or_else(|| Some(Self::default_encoding()))
-                && self
-                    .quote
-                    .as_deref()
-                    .or_else(|| Some(Self::default_quote()))
-                    == other
-                        .quote
-                        .as_deref()
-                        .or_else(|| Some(Self::default_quote()))
-                && self
-                    .escape
-                    .as_deref()
-                    .or_else(|| Some(Self::default_escape()))
-                    == other
-                        .escape
-                        .as_deref()
-                        .or_else(|| Some(Self::default_escape()))
-                && self.header.or_else(|| Some(Self::default_header()))
-                    == other.header.or_else(|| Some(Self::default_header()))
-                && self
-                    .infer_schema
-                    .or_else(|| Some(Self::default_infer_schema()))
-                    == other
-                        .infer_schema
-                        .or_else(|| Some(Self::default_infer_schema()))
-                && self
-                    .null_value
-                    .as_deref()
-                    .or_else(|| Some(Self::default_null_value()))
-                    == other
-                        .null_value
-                        .as_deref()
-                        .or_else(|| Some(Self::default_null_value()))
-                && self
-                    .date_format
-                    .as_deref()
-                    .or_else(|| Some(Self::default_date_format()))
-                    == other
-                        .date_format
-                        .as_deref()
-                        .or_else(|| Some(Self::default_date_format()))
-                && self
-                    .timestamp_format
-                    .as_deref()
-                    .or_else(|| Some(Self::default_timestamp_format()))
-                    == other
-                        .timestamp_format
-                        .as_deref()
-                        .or_else(|| Some(Self::default_timestamp_format()))
-                && self.schema == other.schema
+    impl Visitable for protocol::ErrorDetails {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_error_details(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_error_details_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::ObjectFileReference {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_object_file_reference(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_object_file_reference_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::ObjectPullStrategy {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_object_pull_strategy(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_object_pull_strategy_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::ObjectPushStrategy {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_object_push_strategy(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_object_push_strategy_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::ObjectType {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_object_type(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_object_type_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::ObjectsBatch {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_objects_batch(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_objects_batch_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::PullObjectTransferStrategy {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_pull_object_transfer_strategy(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_pull_object_transfer_strategy_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::PushObjectTransferStrategy {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_push_object_transfer_strategy(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_push_object_transfer_strategy_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::TransferSizeEstimation {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_transfer_size_estimation(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_transfer_size_estimation_mut(self)
+        }
+    }
+
+    impl Visitable for protocol::TransferUrl {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_protocol_transfer_url(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_protocol_transfer_url_mut(self)
+        }
+    }
+
+    impl Visitable for resource::FilterCondition {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_filter_condition(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_filter_condition_mut(self)
+        }
+    }
+
+    impl Visitable for resource::LabelFilter {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_label_filter(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_mut(self)
+        }
+    }
+
+    impl Visitable for resource::LabelFilterAnd {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_and(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_and_mut(self)
+        }
+    }
+
+    impl Visitable for resource::LabelFilterMatch {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_match(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_match_mut(self)
+        }
+    }
+
+    impl Visitable for resource::LabelFilterNot {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_not(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_not_mut(self)
+        }
+    }
+
+    impl Visitable for resource::LabelFilterOr {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_or(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_label_filter_or_mut(self)
+        }
+    }
+
+    impl<SpecT: Visitable> Visitable for resource::Resource<SpecT> {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceAnnotations {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_annotations(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_annotations_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceConditions {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_conditions(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_conditions_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceHandle {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_handle(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_handle_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceHeaders {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_headers(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_headers_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceHeadersInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_headers_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_headers_input_mut(self)
+        }
+    }
+
+    impl<SpecT: Visitable> Visitable for resource::ResourceInput<SpecT> {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_input_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceLabels {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_labels(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_labels_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourcePhase {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_phase(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_phase_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceRef {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_ref(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_ref_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceSelector {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_selector(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_selector_mut(self)
+        }
+    }
+
+    impl Visitable for resource::ResourceStatus {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_resource_resource_status(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_resource_resource_status_mut(self)
+        }
+    }
+
+    impl Visitable for sink::WebhookTarget {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_mut(self)
+        }
+    }
+
+    impl Visitable for sink::WebhookTargetSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_spec_mut(self)
+        }
+    }
+
+    impl Visitable for sink::WebhookTargetSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_spec_input_mut(self)
+        }
+    }
+
+    impl Visitable for sink::WebhookTargetStatus {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_status(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_status_mut(self)
+        }
+    }
+
+    impl Visitable for sink::WebhookTargetStatusValue {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_status_value(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_sink_webhook_target_status_value_mut(self)
         }
     }
 
-    /// Reader for ESRI Shapefile format.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/EsriShapefile
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct ReadStepEsriShapefile {
-        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
-        pub ddl_schema: Option<Vec<String>>,
-        /// If the ZIP archive contains multiple shapefiles use this field to specify a sub-path to the desired `.shp` file. Can contain glob patterns to act as a filter.
-        pub sub_path: Option<String>,
-        /// Schema used to coerce values into more appropriate data types.
-        pub schema: Option<data::DataSchema>,
+    impl Visitable for source::CompressionFormat {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_compression_format(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_compression_format_mut(self)
+        }
     }
 
-    /// Reader for GeoJSON files. It expects one `FeatureCollection` object in the root and will create a record per each `Feature` inside it extracting the properties into individual columns and leaving the feature geometry in its own column.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/GeoJson
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct ReadStepGeoJson {
-        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
-        pub ddl_schema: Option<Vec<String>>,
-        /// Schema used to coerce values into more appropriate data types.
-        pub schema: Option<data::DataSchema>,
+    impl Visitable for source::EnvVar {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_env_var(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_env_var_mut(self)
+        }
     }
 
-    /// Reader for JSON files that contain an array of objects within them.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Json
-    #[derive(Clone, Debug, Eq, Default)]
-    pub struct ReadStepJson {
-        /// Path in the form of `a.b.c` to a sub-element of the root JSON object that is an array or objects. If not specified it is assumed that the root element is an array.
-        pub sub_path: Option<String>,
-        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
-        pub ddl_schema: Option<Vec<String>>,
-        /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
-        ///
-        /// Defaults to: "rfc3339"
-        pub date_format: Option<String>,
-        /// Allows to forcibly set one of standard basic or extended encodings.
-        ///
-        /// Defaults to: "utf8"
-        pub encoding: Option<String>,
-        /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
-        ///
-        /// Defaults to: "rfc3339"
-        pub timestamp_format: Option<String>,
-        /// Schema used to coerce values into more appropriate data types.
-        pub schema: Option<data::DataSchema>,
+    impl Visitable for source::EventTimeSource {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_event_time_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_event_time_source_mut(self)
+        }
     }
 
-    impl ReadStepJson {
-        pub fn default_date_format() -> &'static str {
-            "rfc3339"
+    impl Visitable for source::EventTimeSourceFromMetadata {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_event_time_source_from_metadata(self)
         }
-        pub fn date_format(&self) -> &str {
-            self.date_format
-                .as_deref()
-                .unwrap_or(Self::default_date_format())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_event_time_source_from_metadata_mut(self)
         }
-        pub fn default_encoding() -> &'static str {
-            "utf8"
+    }
+
+    impl Visitable for source::EventTimeSourceFromPath {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_event_time_source_from_path(self)
         }
-        pub fn encoding(&self) -> &str {
-            self.encoding.as_deref().unwrap_or(Self::default_encoding())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_event_time_source_from_path_mut(self)
         }
-        pub fn default_timestamp_format() -> &'static str {
-            "rfc3339"
+    }
+
+    impl Visitable for source::EventTimeSourceFromSystemTime {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_event_time_source_from_system_time(self)
         }
-        pub fn timestamp_format(&self) -> &str {
-            self.timestamp_format
-                .as_deref()
-                .unwrap_or(Self::default_timestamp_format())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_event_time_source_from_system_time_mut(self)
         }
     }
 
-    impl PartialEq for ReadStepJson {
-        fn eq(&self, other: &Self) -> bool {
-            self.sub_path == other.sub_path
-                && self.ddl_schema == other.ddl_schema
-                && self
-                    .date_format
-                    .as_deref()
-                    .or_else(|| Some(Self::default_date_format()))
-                    == other
-                        .date_format
-                        .as_deref()
-                        .or_else(|| Some(Self::default_date_format()))
-                && self
-                    .encoding
-                    .as_deref()
-                    .or_else(|| Some(Self::default_encoding()))
-                    == other
-                        .encoding
-                        .as_deref()
-                        .or_else(|| Some(Self::default_encoding()))
-                && self
-                    .timestamp_format
-                    .as_deref()
-                    .or_else(|| Some(Self::default_timestamp_format()))
-                    == other
-                        .timestamp_format
-                        .as_deref()
-                        .or_else(|| Some(Self::default_timestamp_format()))
-                && self.schema == other.schema
+    impl Visitable for source::IngestParams {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingest_params(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingest_params_mut(self)
         }
     }
 
-    /// Reader for Newline-delimited GeoJSON files. It is similar to `GeoJson` format but instead of `FeatureCollection` object in the root it expects every individual feature object to appear on its own line.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdGeoJson
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct ReadStepNdGeoJson {
-        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
-        pub ddl_schema: Option<Vec<String>>,
-        /// Schema used to coerce values into more appropriate data types.
-        pub schema: Option<data::DataSchema>,
+    impl Visitable for source::Ingress {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_mut(self)
+        }
     }
 
-    /// Reader for files containing multiple newline-delimited JSON objects with the same schema.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdJson
-    #[derive(Clone, Debug, Eq, Default)]
-    pub struct ReadStepNdJson {
-        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
-        pub ddl_schema: Option<Vec<String>>,
-        /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
-        ///
-        /// Defaults to: "rfc3339"
-        pub date_format: Option<String>,
-        /// Allows to forcibly set one of standard basic or extended encodings.
-        ///
-        /// Defaults to: "utf8"
-        pub encoding: Option<String>,
-        /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
-        ///
-        /// Defaults to: "rfc3339"
-        pub timestamp_format: Option<String>,
-        /// Schema used to coerce values into more appropriate data types.
-        pub schema: Option<data::DataSchema>,
+    impl Visitable for source::IngressBuffer {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_buffer(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_buffer_mut(self)
+        }
     }
 
-    impl ReadStepNdJson {
-        pub fn default_date_format() -> &'static str {
-            "rfc3339"
+    impl Visitable for source::IngressBufferMemory {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_buffer_memory(self)
         }
-        pub fn date_format(&self) -> &str {
-            self.date_format
-                .as_deref()
-                .unwrap_or(Self::default_date_format())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_buffer_memory_mut(self)
         }
-        pub fn default_encoding() -> &'static str {
-            "utf8"
+    }
+
+    impl Visitable for source::IngressContainer {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_container(self)
         }
-        pub fn encoding(&self) -> &str {
-            self.encoding.as_deref().unwrap_or(Self::default_encoding())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_container_mut(self)
         }
-        pub fn default_timestamp_format() -> &'static str {
-            "rfc3339"
+    }
+
+    impl Visitable for source::IngressEvmLogs {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_evm_logs(self)
         }
-        pub fn timestamp_format(&self) -> &str {
-            self.timestamp_format
-                .as_deref()
-                .unwrap_or(Self::default_timestamp_format())
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_evm_logs_mut(self)
         }
     }
 
-    impl PartialEq for ReadStepNdJson {
-        fn eq(&self, other: &Self) -> bool {
-            self.ddl_schema == other.ddl_schema
-                && self
-                    .date_format
-                    .as_deref()
-                    .or_else(|| Some(Self::default_date_format()))
-                    == other
-                        .date_format
-                        .as_deref()
-                        .or_else(|| Some(Self::default_date_format()))
-                && self
-                    .encoding
-                    .as_deref()
-                    .or_else(|| Some(Self::default_encoding()))
-                    == other
-                        .encoding
-                        .as_deref()
-                        .or_else(|| Some(Self::default_encoding()))
-                && self
-                    .timestamp_format
-                    .as_deref()
-                    .or_else(|| Some(Self::default_timestamp_format()))
-                    == other
-                        .timestamp_format
-                        .as_deref()
-                        .or_else(|| Some(Self::default_timestamp_format()))
-                && self.schema == other.schema
+    impl Visitable for source::IngressFilesGlob {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_files_glob(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_files_glob_mut(self)
         }
     }
 
-    /// Reader for Apache Parquet format.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Parquet
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct ReadStepParquet {
-        /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
-        pub ddl_schema: Option<Vec<String>>,
-        /// Schema used to coerce values into more appropriate data types.
-        pub schema: Option<data::DataSchema>,
+    impl Visitable for source::IngressMqtt {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_mqtt(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_mqtt_mut(self)
+        }
     }
 
-    /// Defines a header (e.g. HTTP) to be passed into some request.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/RequestHeader
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct RequestHeader {
-        /// Name of the header.
-        pub name: String,
-        /// Value of the header.
-        pub value: String,
+    impl Visitable for source::IngressRestEndpoint {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_rest_endpoint(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_rest_endpoint_mut(self)
+        }
+    }
+
+    impl Visitable for source::IngressUrl {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_ingress_url(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_ingress_url_mut(self)
+        }
+    }
+
+    impl Visitable for source::MergeStrategy {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_mut(self)
+        }
+    }
+
+    impl Visitable for source::MergeStrategyAppend {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_append(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_append_mut(self)
+        }
+    }
+
+    impl Visitable for source::MergeStrategyChangelogStream {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_changelog_stream(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_changelog_stream_mut(self)
+        }
+    }
+
+    impl Visitable for source::MergeStrategyLedger {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_ledger(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_ledger_mut(self)
+        }
+    }
+
+    impl Visitable for source::MergeStrategySnapshot {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_snapshot(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_snapshot_mut(self)
+        }
+    }
+
+    impl Visitable for source::MergeStrategyUpsertStream {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_upsert_stream(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_merge_strategy_upsert_stream_mut(self)
+        }
+    }
+
+    impl Visitable for source::MqttQos {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_mqtt_qos(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_mqtt_qos_mut(self)
+        }
+    }
+
+    impl Visitable for source::MqttTopicSubscription {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_mqtt_topic_subscription(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_mqtt_topic_subscription_mut(self)
+        }
+    }
+
+    impl Visitable for source::PrepStep {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_prep_step(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_prep_step_mut(self)
+        }
+    }
+
+    impl Visitable for source::PrepStepDecompress {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_prep_step_decompress(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_prep_step_decompress_mut(self)
+        }
+    }
+
+    impl Visitable for source::PrepStepPipe {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_prep_step_pipe(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_prep_step_pipe_mut(self)
+        }
     }
 
-    /// Defines an external source of data for ingestion.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Source
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct Source {
-        /// Container for identity and ownership information of a resource.
-        pub headers: resource::ResourceHeadersInput,
-        /// Specifies the desired state of the resource.
-        pub spec: source::SourceSpecInput,
+    impl Visitable for source::ReadStep {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_mut(self)
+        }
     }
 
-    impl Source {
-        pub fn schema() -> &'static TypeUri {
-            &SOURCE_SCHEMA
+    impl Visitable for source::ReadStepCsv {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step_csv(self)
         }
-        pub const fn schema_str() -> &'static str {
-            SOURCE_SCHEMA_STR
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_csv_mut(self)
         }
     }
 
-    static SOURCE_SCHEMA_STR: &str = "https://opendatafabric.org/schemas/source/v1alpha1/Source";
-
-    static SOURCE_SCHEMA: std::sync::LazyLock<TypeUri> =
-        std::sync::LazyLock::new(|| TypeUri::new_unchecked(SOURCE_SCHEMA_STR));
+    impl Visitable for source::ReadStepEsriShapefile {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step_esri_shapefile(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_esri_shapefile_mut(self)
+        }
+    }
 
-    /// Defines how external data should be cached.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum SourceCaching {
-        Forever(source::SourceCachingForever),
+    impl Visitable for source::ReadStepGeoJson {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step_geo_json(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_geo_json_mut(self)
+        }
     }
 
-    impl_enum_with_variants!(SourceCaching);
-    impl_enum_variant!(SourceCaching::Forever(source::SourceCachingForever));
+    impl Visitable for source::ReadStepJson {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step_json(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_json_mut(self)
+        }
+    }
 
-    /// After source was processed once it will never be ingested again.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching#/$defs/Forever
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct SourceCachingForever {}
+    impl Visitable for source::ReadStepNdGeoJson {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step_nd_geo_json(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_nd_geo_json_mut(self)
+        }
+    }
 
-    /// Specifies how input files should be ordered before ingestion.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceOrdering
-    #[derive(Clone, Copy, PartialEq, Eq, Debug)]
-    pub enum SourceOrdering {
-        ByEventTime,
-        ByName,
+    impl Visitable for source::ReadStepNdJson {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step_nd_json(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_nd_json_mut(self)
+        }
     }
 
-    /// Specifies an external source of data for ingestion.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpec
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct SourceSpec {
-        /// Brings the configuration values into the local `config` context.
-        pub config: Option<config::ValueRefs>,
-        /// Determines where data is sourced from.
-        pub ingress: Option<source::Ingress>,
-        /// Defines how raw data is prepared before reading.
-        pub prepare: Option<Vec<source::PrepStep>>,
-        /// Defines how data is read into structured format.
-        pub read: source::ReadStep,
-        /// Pre-processing query that shapes the data.
-        pub preprocess: Option<dataset::Transform>,
-        /// Determines how newly-ingested data should be merged with existing history.
-        pub merge: Option<source::MergeStrategy>,
-        /// Defines the mapping of system fields to dataset column names.
-        pub vocab: Option<dataset::DatasetVocabulary>,
+    impl Visitable for source::ReadStepParquet {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_read_step_parquet(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_read_step_parquet_mut(self)
+        }
     }
 
-    /// Specifies an external source of data for ingestion.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpecInput
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct SourceSpecInput {
-        /// Brings the configuration values into the local `config` context.
-        pub config: Option<config::ValueRefs>,
-        /// Determines where data is sourced from.
-        pub ingress: Option<source::Ingress>,
-        /// Defines how raw data is prepared before reading.
-        pub prepare: Option<Vec<source::PrepStep>>,
-        /// Defines how data is read into structured format.
-        pub read: source::ReadStep,
-        /// Pre-processing query that shapes the data.
-        pub preprocess: Option<dataset::Transform>,
-        /// Determines how newly-ingested data should be merged with existing history.
-        pub merge: Option<source::MergeStrategy>,
-        /// Defines the mapping of system fields to dataset column names.
-        pub vocab: Option<dataset::DatasetVocabulary>,
+    impl Visitable for source::RequestHeader {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_request_header(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_request_header_mut(self)
+        }
     }
 
-    /// The state of the source the data was added from to allow fast resuming.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceState
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct SourceState {
-        /// Identifies the source that the state corresponds to.
-        pub source_name: String,
-        /// Identifies the type of the state. Standard types include: `odf/etag`, `odf/last-modified`.
-        pub kind: String,
-        /// Opaque value representing the state.
-        pub value: String,
+    impl Visitable for source::Source {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_source(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_source_mut(self)
+        }
     }
-}
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// storage
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub mod storage {
-    #[allow(unused_imports)]
-    use super::*;
+    impl Visitable for source::SourceCaching {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_source_caching(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_source_caching_mut(self)
+        }
+    }
 
-    /// Access credentials for AWS or an AWS-compatible service.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentials
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct AwsCredentials {
-        /// Reference to a secret containing the AWS access key ID.
-        pub access_key: Option<config::ValueHandle>,
-        /// Reference to a secret containing the AWS secret access key.
-        pub secret_key: Option<config::ValueHandle>,
+    impl Visitable for source::SourceCachingForever {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_source_caching_forever(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_source_caching_forever_mut(self)
+        }
     }
 
-    /// Access credentials for AWS or an AWS-compatible service.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentialsInput
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct AwsCredentialsInput {
-        /// Reference to a secret containing the AWS access key ID.
-        pub access_key: Option<config::ValueRef>,
-        /// Reference to a secret containing the AWS secret access key.
-        pub secret_key: Option<config::ValueRef>,
+    impl Visitable for source::SourceOrdering {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_source_ordering(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_source_ordering_mut(self)
+        }
     }
 
-    /// Defines a storage volume where data can be stored and its access credentials.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolume
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct PersistentVolume {
-        /// Container for identity and ownership information of a resource.
-        pub headers: resource::ResourceHeadersInput,
-        /// Specifies the desired state of the resource.
-        pub spec: storage::PersistentVolumeSpecInput,
+    impl Visitable for source::SourceSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_source_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_source_spec_mut(self)
+        }
     }
 
-    impl PersistentVolume {
-        pub fn schema() -> &'static TypeUri {
-            &PERSISTENT_VOLUME_SCHEMA
+    impl Visitable for source::SourceSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_source_spec_input(self)
         }
-        pub const fn schema_str() -> &'static str {
-            PERSISTENT_VOLUME_SCHEMA_STR
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_source_spec_input_mut(self)
         }
     }
 
-    static PERSISTENT_VOLUME_SCHEMA_STR: &str =
-        "https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolume";
+    impl Visitable for source::SourceState {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_source_source_state(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_source_source_state_mut(self)
+        }
+    }
 
-    static PERSISTENT_VOLUME_SCHEMA: std::sync::LazyLock<TypeUri> =
-        std::sync::LazyLock::new(|| TypeUri::new_unchecked(PERSISTENT_VOLUME_SCHEMA_STR));
+    impl Visitable for storage::AwsCredentials {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_aws_credentials(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_aws_credentials_mut(self)
+        }
+    }
 
-    /// Reference to a `PersistentVolume`.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeRef
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct PersistentVolumeRef {
-        /// Reference to an account that owns the `PersistentVolume`.
-        pub account: Option<auth::AccountRef>,
-        /// ID of the resource.
-        pub id: Option<ResourceID>,
-        /// Name of the resource.
-        pub name: Option<ResourceName>,
+    impl Visitable for storage::AwsCredentialsInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_aws_credentials_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_aws_credentials_input_mut(self)
+        }
     }
-    impl IntoResourceRef for PersistentVolumeRef {}
 
-    /// Defines a storage volume where data can be stored and its access credentials.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum PersistentVolumeSpec {
-        S3(storage::PersistentVolumeSpecS3),
+    impl Visitable for storage::PersistentVolume {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_mut(self)
+        }
     }
 
-    impl_enum_with_variants!(PersistentVolumeSpec);
-    impl_enum_variant!(PersistentVolumeSpec::S3(storage::PersistentVolumeSpecS3));
+    impl Visitable for storage::PersistentVolumeRef {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_ref(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_ref_mut(self)
+        }
+    }
 
-    /// Defines a storage volume where data can be stored and its access credentials.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput
-    #[derive(Clone, PartialEq, Eq, Debug)]
-    pub enum PersistentVolumeSpecInput {
-        S3(storage::PersistentVolumeSpecInputS3),
+    impl Visitable for storage::PersistentVolumeSpec {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec_mut(self)
+        }
     }
 
-    impl_enum_with_variants!(PersistentVolumeSpecInput);
-    impl_enum_variant!(PersistentVolumeSpecInput::S3(
-        storage::PersistentVolumeSpecInputS3
-    ));
+    impl Visitable for storage::PersistentVolumeSpecInput {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec_input(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec_input_mut(self)
+        }
+    }
 
-    /// An Amazon S3 or S3-compatible object storage bucket.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput#/$defs/S3
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct PersistentVolumeSpecInputS3 {
-        /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
-        pub endpoint: Option<String>,
-        /// AWS region where the bucket is located e.g. `us-west-2`.
-        pub region: Option<String>,
-        /// Name of the S3 bucket.
-        pub bucket: String,
-        /// Optional path prefix within the bucket.
-        pub prefix: Option<String>,
-        /// Storage capacity allocation.
-        pub capacity: Option<storage::VolumeCapacity>,
-        /// Access credentials for the bucket.
-        pub credentials: Option<storage::AwsCredentialsInput>,
+    impl Visitable for storage::PersistentVolumeSpecInputS3 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec_input_s_3(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec_input_s_3_mut(self)
+        }
     }
 
-    /// An Amazon S3 or S3-compatible object storage bucket.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec#/$defs/S3
-    #[derive(Clone, Debug, Eq, PartialEq)]
-    pub struct PersistentVolumeSpecS3 {
-        /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
-        pub endpoint: Option<String>,
-        /// AWS region where the bucket is located e.g. `us-west-2`.
-        pub region: Option<String>,
-        /// Name of the S3 bucket.
-        pub bucket: String,
-        /// Optional path prefix within the bucket.
-        pub prefix: Option<String>,
-        /// Storage capacity allocation.
-        pub capacity: Option<storage::VolumeCapacity>,
-        /// Access credentials for the bucket.
-        pub credentials: Option<storage::AwsCredentials>,
+    impl Visitable for storage::PersistentVolumeSpecS3 {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec_s_3(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_persistent_volume_spec_s_3_mut(self)
+        }
     }
 
-    /// Storage capacity allocation.
-    ///
-    /// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/VolumeCapacity
-    #[derive(Clone, Debug, Eq, PartialEq, Default)]
-    pub struct VolumeCapacity {
-        /// Maximum storage size e.g. `10Gi`.
-        pub storage: Option<ByteSize>,
+    impl Visitable for storage::VolumeCapacity {
+        fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {
+            visitor.visit_storage_volume_capacity(self)
+        }
+        fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {
+            visitor.visit_storage_volume_capacity_mut(self)
+        }
     }
 }
@@ -0,0 +1,10606 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WARNING: This file is auto-generated from Open Data Fabric Schemas
+// See: http://opendatafabric.org/
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+#![allow(clippy::pedantic)]
+
+use super::convertors_generated::*;
+use super::proxies_generated as fb;
+use crate as odf;
+use std::path::Path;
+
+use ::flatbuffers::Table;
+use chrono::prelude::*;
+use setty::types::{ByteSize, DurationString};
+
+/// Verifies the buffer within the specified limits and returns a view over its root table
+pub fn view_verified<'fb, P, V>(
+    buf: &'fb [u8],
+    limits: &FlatbuffersDecodeLimits,
+) -> Result<V, FlatbuffersDecodeError>
+where
+    P: ::flatbuffers::Follow<'fb, Inner = P> + ::flatbuffers::Verifiable + 'fb,
+    V: From<P>,
+{
+    root_verified::<P>(buf, limits).map(V::from)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountHandle
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountHandle
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::AccountHandle`]
+#[derive(Debug, Clone, Copy)]
+pub struct AccountHandleView<'fb> {
+    proxy: fb::AccountHandle<'fb>,
+}
+
+impl<'fb> From<fb::AccountHandle<'fb>> for AccountHandleView<'fb> {
+    fn from(proxy: fb::AccountHandle<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AccountHandleView<'fb> {
+    /// ID of the account resource.
+    pub fn id(&self) -> Result<odf::resource::ResourceID, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountHandle.id"))
+    }
+
+    /// DID of the account.
+    pub fn did(&self) -> Result<odf::auth::AccountID, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::auth::AccountID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountHandle.did"))
+    }
+
+    /// Name of the account.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountHandle.name"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::AccountHandle, FlatbuffersDecodeError> {
+        odf::auth::AccountHandle::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountRef
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::AccountRef`]
+#[derive(Debug, Clone, Copy)]
+pub struct AccountRefView<'fb> {
+    proxy: fb::AccountRef<'fb>,
+}
+
+impl<'fb> From<fb::AccountRef<'fb>> for AccountRefView<'fb> {
+    fn from(proxy: fb::AccountRef<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AccountRefView<'fb> {
+    /// UUID of the account resource.
+    pub fn id(&self) -> Result<Option<odf::resource::ResourceID>, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .map(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountRef.id"))
+    }
+
+    /// DID of the account.
+    pub fn did(&self) -> Result<Option<odf::auth::AccountID>, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .map(|v| {
+                odf::auth::AccountID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountRef.did"))
+    }
+
+    /// Name of the account.
+    pub fn name(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountRef.name"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::AccountRef, FlatbuffersDecodeError> {
+        odf::auth::AccountRef::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountSpec
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::AccountSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct AccountSpecView<'fb> {
+    proxy: fb::AccountSpec<'fb>,
+}
+
+impl<'fb> From<fb::AccountSpec<'fb>> for AccountSpecView<'fb> {
+    fn from(proxy: fb::AccountSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AccountSpecView<'fb> {
+    /// DID associated with the account by ODF or an external system
+    pub fn did(&self) -> Result<Option<odf::auth::AccountID>, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .map(|v| {
+                odf::auth::AccountID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.did"))
+    }
+
+    /// Type of the account.
+    pub fn account_type(&self) -> Result<Option<odf::auth::AccountType>, FlatbuffersDecodeError> {
+        self.proxy
+            .account_type()
+            .map(|v| odf::auth::AccountType::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.accountType"))
+    }
+
+    /// Human-friendly display name.
+    pub fn display_name(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .display_name()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.displayName"))
+    }
+
+    /// Email address of the account.
+    pub fn email(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .email()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.email"))
+    }
+
+    /// URL of the account's avatar image.
+    pub fn avatar_url(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .avatar_url()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.avatarUrl"))
+    }
+
+    /// Password for local authentication. Absent for SSO or DID-based accounts.
+    pub fn password(&self) -> Result<Option<SecretView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .password()
+            .map(|v| Ok(SecretView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.password"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::AccountSpec, FlatbuffersDecodeError> {
+        odf::auth::AccountSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountSpecInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::AccountSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct AccountSpecInputView<'fb> {
+    proxy: fb::AccountSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::AccountSpecInput<'fb>> for AccountSpecInputView<'fb> {
+    fn from(proxy: fb::AccountSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AccountSpecInputView<'fb> {
+    /// DID associated with the account by ODF or an external system
+    pub fn did(&self) -> Result<Option<odf::auth::AccountID>, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .map(|v| {
+                odf::auth::AccountID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.did"))
+    }
+
+    /// Type of the account.
+    pub fn account_type(&self) -> Result<Option<odf::auth::AccountType>, FlatbuffersDecodeError> {
+        self.proxy
+            .account_type()
+            .map(|v| odf::auth::AccountType::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.accountType"))
+    }
+
+    /// Human-friendly display name.
+    pub fn display_name(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .display_name()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.displayName"))
+    }
+
+    /// Email address of the account.
+    pub fn email(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .email()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.email"))
+    }
+
+    /// URL of the account's avatar image.
+    pub fn avatar_url(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .avatar_url()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.avatarUrl"))
+    }
+
+    /// Password for local authentication. Absent for SSO or DID-based accounts.
+    pub fn password(&self) -> Result<Option<SecretView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .password()
+            .map(|v| Ok(SecretView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.password"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::AccountSpecInput, FlatbuffersDecodeError> {
+        odf::auth::AccountSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AddData
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/AddData
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::AddData`]
+#[derive(Debug, Clone, Copy)]
+pub struct AddDataView<'fb> {
+    proxy: fb::AddData<'fb>,
+}
+
+impl<'fb> From<fb::AddData<'fb>> for AddDataView<'fb> {
+    fn from(proxy: fb::AddData<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AddDataView<'fb> {
+    /// Hash of the checkpoint file used to restore ingestion state, if any.
+    pub fn prev_checkpoint(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_checkpoint()
+            .map(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddData.prevCheckpoint"))
+    }
+
+    /// Last offset of the previous data slice, if any. Must be equal to the last non-empty `newData.offsetInterval.end`.
+    pub fn prev_offset(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_offset()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddData.prevOffset"))
+    }
+
+    /// Describes output data written during this transaction, if any.
+    pub fn new_data(&self) -> Result<Option<DataSliceView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_data()
+            .map(|v| Ok(DataSliceView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newData"))
+    }
+
+    /// Describes checkpoint written during this transaction, if any. If an engine operation resulted in no updates to the checkpoint, but checkpoint is still relevant for subsequent runs - a hash of the previous checkpoint should be specified.
+    pub fn new_checkpoint(&self) -> Result<Option<CheckpointView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_checkpoint()
+            .map(|v| Ok(CheckpointView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newCheckpoint"))
+    }
+
+    /// Last watermark of the output data stream, if any. Initial blocks may not have watermarks, but once watermark is set - all subsequent blocks should either carry the same watermark or specify a new (greater) one. Thus, watermarks are monotonically non-decreasing.
+    pub fn new_watermark(&self) -> Result<Option<DateTime<Utc>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_watermark()
+            .map(|v| fb_to_datetime(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newWatermark"))
+    }
+
+    /// The state of the source the data was added from to allow fast resuming. If the state did not change but is still relevant for subsequent runs it should be carried, i.e. only the last state per source is considered when resuming.
+    pub fn new_source_state(&self) -> Result<Option<SourceStateView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_source_state()
+            .map(|v| Ok(SourceStateView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newSourceState"))
+    }
+
+    /// ODF extensions.
+    pub fn extra(&self) -> Result<Option<ExtraAttributesView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .extra()
+            .map(|v| Ok(ExtraAttributesView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddData.extra"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::AddData, FlatbuffersDecodeError> {
+        odf::dataset::AddData::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AddPushSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/AddPushSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::AddPushSource`]
+#[derive(Debug, Clone, Copy)]
+pub struct AddPushSourceView<'fb> {
+    proxy: fb::AddPushSource<'fb>,
+}
+
+impl<'fb> From<fb::AddPushSource<'fb>> for AddPushSourceView<'fb> {
+    fn from(proxy: fb::AddPushSource<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AddPushSourceView<'fb> {
+    /// Identifies the source within this dataset.
+    pub fn source_name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .source_name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.sourceName"))
+    }
+
+    /// Defines how data is read into structured format.
+    pub fn read(&self) -> Result<ReadStepView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .read()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| ReadStepView::new(v, self.proxy.read_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.read"))
+    }
+
+    /// Pre-processing query that shapes the data.
+    pub fn preprocess(&self) -> Result<Option<TransformView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .preprocess()
+            .map(|v| TransformView::new(v, self.proxy.preprocess_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.preprocess"))
+    }
+
+    /// Determines how newly-ingested data should be merged with existing history.
+    pub fn merge(&self) -> Result<MergeStrategyView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .merge()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| MergeStrategyView::new(v, self.proxy.merge_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.merge"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::AddPushSource, FlatbuffersDecodeError> {
+        odf::legacy::AddPushSource::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AttachmentEmbedded
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/AttachmentEmbedded
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::AttachmentEmbedded`]
+#[derive(Debug, Clone, Copy)]
+pub struct AttachmentEmbeddedView<'fb> {
+    proxy: fb::AttachmentEmbedded<'fb>,
+}
+
+impl<'fb> From<fb::AttachmentEmbedded<'fb>> for AttachmentEmbeddedView<'fb> {
+    fn from(proxy: fb::AttachmentEmbedded<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AttachmentEmbeddedView<'fb> {
+    /// Path to an attachment if it was materialized into a file.
+    pub fn path(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .path()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AttachmentEmbedded.path"))
+    }
+
+    /// Content of the attachment.
+    pub fn content(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .content()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AttachmentEmbedded.content"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::AttachmentEmbedded, FlatbuffersDecodeError> {
+        odf::dataset::AttachmentEmbedded::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Attachments
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Attachments
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::Attachments`]
+#[derive(Debug, Clone, Copy)]
+pub enum AttachmentsView<'fb> {
+    Embedded(AttachmentsEmbeddedView<'fb>),
+}
+
+impl<'fb> AttachmentsView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::Attachments) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::Attachments::AttachmentsEmbedded => {
+                Ok(Self::Embedded(AttachmentsEmbeddedView::from(unsafe {
+                    fb::AttachmentsEmbedded::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "Attachments",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::Attachments, FlatbuffersDecodeError> {
+        match self {
+            Self::Embedded(v) => v.to_owned().map(odf::dataset::Attachments::Embedded),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AttachmentsEmbedded
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Attachments#/$defs/Embedded
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::AttachmentsEmbedded`]
+#[derive(Debug, Clone, Copy)]
+pub struct AttachmentsEmbeddedView<'fb> {
+    proxy: fb::AttachmentsEmbedded<'fb>,
+}
+
+impl<'fb> From<fb::AttachmentsEmbedded<'fb>> for AttachmentsEmbeddedView<'fb> {
+    fn from(proxy: fb::AttachmentsEmbedded<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AttachmentsEmbeddedView<'fb> {
+    /// List of embedded items.
+    pub fn items(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<AttachmentEmbeddedView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .items()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(AttachmentEmbeddedView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AttachmentsEmbedded.items"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::AttachmentsEmbedded, FlatbuffersDecodeError> {
+        odf::dataset::AttachmentsEmbedded::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Attribute
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Attribute
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::Attribute`]
+#[derive(Debug, Clone, Copy)]
+pub struct AttributeView<'fb> {
+    proxy: fb::Attribute<'fb>,
+}
+
+impl<'fb> From<fb::Attribute<'fb>> for AttributeView<'fb> {
+    fn from(proxy: fb::Attribute<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AttributeView<'fb> {
+    /// The resource this attribute is attached to.
+    pub fn object(&self) -> Result<ResourceHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .object()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Attribute.object"))
+    }
+
+    /// Name of the attribute.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Attribute.name"))
+    }
+
+    /// Value of the attribute.
+    pub fn value(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Attribute.value"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::Attribute, FlatbuffersDecodeError> {
+        odf::auth::Attribute::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AttributeInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AttributeInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::AttributeInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct AttributeInputView<'fb> {
+    proxy: fb::AttributeInput<'fb>,
+}
+
+impl<'fb> From<fb::AttributeInput<'fb>> for AttributeInputView<'fb> {
+    fn from(proxy: fb::AttributeInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AttributeInputView<'fb> {
+    /// The resource this attribute is attached to.
+    pub fn object(&self) -> Result<ResourceRefView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .object()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceRefView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AttributeInput.object"))
+    }
+
+    /// Name of the attribute e.g. `allowPublicRead`.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AttributeInput.name"))
+    }
+
+    /// Value of the attribute.
+    pub fn value(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("AttributeInput.value"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::AttributeInput, FlatbuffersDecodeError> {
+        odf::auth::AttributeInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AwsCredentials
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentials
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::AwsCredentials`]
+#[derive(Debug, Clone, Copy)]
+pub struct AwsCredentialsView<'fb> {
+    proxy: fb::AwsCredentials<'fb>,
+}
+
+impl<'fb> From<fb::AwsCredentials<'fb>> for AwsCredentialsView<'fb> {
+    fn from(proxy: fb::AwsCredentials<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AwsCredentialsView<'fb> {
+    /// Reference to a secret containing the AWS access key ID.
+    pub fn access_key(&self) -> Result<Option<ValueHandleView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .access_key()
+            .map(|v| Ok(ValueHandleView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentials.accessKey"))
+    }
+
+    /// Reference to a secret containing the AWS secret access key.
+    pub fn secret_key(&self) -> Result<Option<ValueHandleView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .secret_key()
+            .map(|v| Ok(ValueHandleView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentials.secretKey"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::storage::AwsCredentials, FlatbuffersDecodeError> {
+        odf::storage::AwsCredentials::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AwsCredentialsInput
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentialsInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::AwsCredentialsInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct AwsCredentialsInputView<'fb> {
+    proxy: fb::AwsCredentialsInput<'fb>,
+}
+
+impl<'fb> From<fb::AwsCredentialsInput<'fb>> for AwsCredentialsInputView<'fb> {
+    fn from(proxy: fb::AwsCredentialsInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> AwsCredentialsInputView<'fb> {
+    /// Reference to a secret containing the AWS access key ID.
+    pub fn access_key(&self) -> Result<Option<ValueRefView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .access_key()
+            .map(|v| Ok(ValueRefView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentialsInput.accessKey"))
+    }
+
+    /// Reference to a secret containing the AWS secret access key.
+    pub fn secret_key(&self) -> Result<Option<ValueRefView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .secret_key()
+            .map(|v| Ok(ValueRefView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentialsInput.secretKey"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::storage::AwsCredentialsInput, FlatbuffersDecodeError> {
+        odf::storage::AwsCredentialsInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Checkpoint
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Checkpoint
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::Checkpoint`]
+#[derive(Debug, Clone, Copy)]
+pub struct CheckpointView<'fb> {
+    proxy: fb::Checkpoint<'fb>,
+}
+
+impl<'fb> From<fb::Checkpoint<'fb>> for CheckpointView<'fb> {
+    fn from(proxy: fb::Checkpoint<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> CheckpointView<'fb> {
+    /// Hash sum of the checkpoint file.
+    pub fn physical_hash(&self) -> Result<odf::Multihash, FlatbuffersDecodeError> {
+        self.proxy
+            .physical_hash()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("Checkpoint.physicalHash"))
+    }
+
+    /// Size of checkpoint file in bytes.
+    pub fn size(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.size_()).map_err(|e: FlatbuffersDecodeError| e.at("Checkpoint.size"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::Checkpoint, FlatbuffersDecodeError> {
+        odf::dataset::Checkpoint::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// CompactionParams
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/CompactionParams
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::CompactionParams`]
+#[derive(Debug, Clone, Copy)]
+pub struct CompactionParamsView<'fb> {
+    proxy: fb::CompactionParams<'fb>,
+}
+
+impl<'fb> From<fb::CompactionParams<'fb>> for CompactionParamsView<'fb> {
+    fn from(proxy: fb::CompactionParams<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> CompactionParamsView<'fb> {
+    /// Target maximum size of each compacted data slice e.g. `100MiB`.
+    pub fn max_slice_size(&self) -> Result<Option<ByteSize>, FlatbuffersDecodeError> {
+        self.proxy
+            .max_slice_size()
+            .map(|v| Ok(ByteSize::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("CompactionParams.maxSliceSize"))
+    }
+
+    /// Target maximum number of records per compacted data slice.
+    pub fn max_slice_records(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .max_slice_records()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("CompactionParams.maxSliceRecords"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::CompactionParams, FlatbuffersDecodeError> {
+        odf::dataset::CompactionParams::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataField
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataField
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataField`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataFieldView<'fb> {
+    proxy: fb::DataField<'fb>,
+}
+
+impl<'fb> From<fb::DataField<'fb>> for DataFieldView<'fb> {
+    fn from(proxy: fb::DataField<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataFieldView<'fb> {
+    /// Name of the field
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataField.name"))
+    }
+
+    /// Logical type of the field that defines its semantic behavior and value ranges
+    pub fn r#type(&self) -> Result<DataTypeView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .type_()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| DataTypeView::new(v, self.proxy.type_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataField.type"))
+    }
+
+    /// ODF extensions
+    pub fn extra(&self) -> Result<Option<ExtraAttributesView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .extra()
+            .map(|v| Ok(ExtraAttributesView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataField.extra"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataField, FlatbuffersDecodeError> {
+        odf::data::DataField::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataSchema
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataSchema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataSchema`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataSchemaView<'fb> {
+    proxy: fb::DataSchema<'fb>,
+}
+
+impl<'fb> From<fb::DataSchema<'fb>> for DataSchemaView<'fb> {
+    fn from(proxy: fb::DataSchema<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataSchemaView<'fb> {
+    /// Top-level fields (columns) of the schema.
+    pub fn fields(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<DataFieldView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .fields()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(DataFieldView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataSchema.fields"))
+    }
+
+    /// ODF extensions
+    pub fn extra(&self) -> Result<Option<ExtraAttributesView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .extra()
+            .map(|v| Ok(ExtraAttributesView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataSchema.extra"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataSchema, FlatbuffersDecodeError> {
+        odf::data::DataSchema::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataSlice
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DataSlice
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::DataSlice`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataSliceView<'fb> {
+    proxy: fb::DataSlice<'fb>,
+}
+
+impl<'fb> From<fb::DataSlice<'fb>> for DataSliceView<'fb> {
+    fn from(proxy: fb::DataSlice<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataSliceView<'fb> {
+    /// Logical hash sum of the data in this slice.
+    pub fn logical_hash(&self) -> Result<odf::Multihash, FlatbuffersDecodeError> {
+        self.proxy
+            .logical_hash()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.logicalHash"))
+    }
+
+    /// Hash sum of the data part file.
+    pub fn physical_hash(&self) -> Result<odf::Multihash, FlatbuffersDecodeError> {
+        self.proxy
+            .physical_hash()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.physicalHash"))
+    }
+
+    /// Data slice produced by the transaction.
+    pub fn offset_interval(&self) -> Result<OffsetIntervalView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .offset_interval()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(OffsetIntervalView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.offsetInterval"))
+    }
+
+    /// Size of data file in bytes.
+    pub fn size(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.size_()).map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.size"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::DataSlice, FlatbuffersDecodeError> {
+        odf::dataset::DataSlice::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataType
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataType`]
+#[derive(Debug, Clone, Copy)]
+pub enum DataTypeView<'fb> {
+    Binary(DataTypeBinaryView<'fb>),
+    Bool(DataTypeBoolView<'fb>),
+    Date(DataTypeDateView<'fb>),
+    Decimal(DataTypeDecimalView<'fb>),
+    Duration(DataTypeDurationView<'fb>),
+    Float16(DataTypeFloat16View<'fb>),
+    Float32(DataTypeFloat32View<'fb>),
+    Float64(DataTypeFloat64View<'fb>),
+    Int8(DataTypeInt8View<'fb>),
+    Int16(DataTypeInt16View<'fb>),
+    Int32(DataTypeInt32View<'fb>),
+    Int64(DataTypeInt64View<'fb>),
+    UInt8(DataTypeUInt8View<'fb>),
+    UInt16(DataTypeUInt16View<'fb>),
+    UInt32(DataTypeUInt32View<'fb>),
+    UInt64(DataTypeUInt64View<'fb>),
+    List(DataTypeListView<'fb>),
+    Map(DataTypeMapView<'fb>),
+    Null(DataTypeNullView<'fb>),
+    Option(DataTypeOptionView<'fb>),
+    Struct(DataTypeStructView<'fb>),
+    Time(DataTypeTimeView<'fb>),
+    Timestamp(DataTypeTimestampView<'fb>),
+    String(DataTypeStringView<'fb>),
+}
+
+impl<'fb> DataTypeView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::DataType) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::DataType::DataTypeBinary => Ok(Self::Binary(DataTypeBinaryView::from(unsafe {
+                fb::DataTypeBinary::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeBool => Ok(Self::Bool(DataTypeBoolView::from(unsafe {
+                fb::DataTypeBool::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeDate => Ok(Self::Date(DataTypeDateView::from(unsafe {
+                fb::DataTypeDate::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeDecimal => Ok(Self::Decimal(DataTypeDecimalView::from(unsafe {
+                fb::DataTypeDecimal::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeDuration => {
+                Ok(Self::Duration(DataTypeDurationView::from(unsafe {
+                    fb::DataTypeDuration::init_from_table(table)
+                })))
+            }
+            fb::DataType::DataTypeFloat16 => Ok(Self::Float16(DataTypeFloat16View::from(unsafe {
+                fb::DataTypeFloat16::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeFloat32 => Ok(Self::Float32(DataTypeFloat32View::from(unsafe {
+                fb::DataTypeFloat32::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeFloat64 => Ok(Self::Float64(DataTypeFloat64View::from(unsafe {
+                fb::DataTypeFloat64::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeInt8 => Ok(Self::Int8(DataTypeInt8View::from(unsafe {
+                fb::DataTypeInt8::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeInt16 => Ok(Self::Int16(DataTypeInt16View::from(unsafe {
+                fb::DataTypeInt16::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeInt32 => Ok(Self::Int32(DataTypeInt32View::from(unsafe {
+                fb::DataTypeInt32::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeInt64 => Ok(Self::Int64(DataTypeInt64View::from(unsafe {
+                fb::DataTypeInt64::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeUInt8 => Ok(Self::UInt8(DataTypeUInt8View::from(unsafe {
+                fb::DataTypeUInt8::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeUInt16 => Ok(Self::UInt16(DataTypeUInt16View::from(unsafe {
+                fb::DataTypeUInt16::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeUInt32 => Ok(Self::UInt32(DataTypeUInt32View::from(unsafe {
+                fb::DataTypeUInt32::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeUInt64 => Ok(Self::UInt64(DataTypeUInt64View::from(unsafe {
+                fb::DataTypeUInt64::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeList => Ok(Self::List(DataTypeListView::from(unsafe {
+                fb::DataTypeList::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeMap => Ok(Self::Map(DataTypeMapView::from(unsafe {
+                fb::DataTypeMap::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeNull => Ok(Self::Null(DataTypeNullView::from(unsafe {
+                fb::DataTypeNull::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeOption => Ok(Self::Option(DataTypeOptionView::from(unsafe {
+                fb::DataTypeOption::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeStruct => Ok(Self::Struct(DataTypeStructView::from(unsafe {
+                fb::DataTypeStruct::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeTime => Ok(Self::Time(DataTypeTimeView::from(unsafe {
+                fb::DataTypeTime::init_from_table(table)
+            }))),
+            fb::DataType::DataTypeTimestamp => {
+                Ok(Self::Timestamp(DataTypeTimestampView::from(unsafe {
+                    fb::DataTypeTimestamp::init_from_table(table)
+                })))
+            }
+            fb::DataType::DataTypeString => Ok(Self::String(DataTypeStringView::from(unsafe {
+                fb::DataTypeString::init_from_table(table)
+            }))),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "DataType",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataType, FlatbuffersDecodeError> {
+        match self {
+            Self::Binary(v) => v.to_owned().map(odf::data::DataType::Binary),
+            Self::Bool(v) => v.to_owned().map(odf::data::DataType::Bool),
+            Self::Date(v) => v.to_owned().map(odf::data::DataType::Date),
+            Self::Decimal(v) => v.to_owned().map(odf::data::DataType::Decimal),
+            Self::Duration(v) => v.to_owned().map(odf::data::DataType::Duration),
+            Self::Float16(v) => v.to_owned().map(odf::data::DataType::Float16),
+            Self::Float32(v) => v.to_owned().map(odf::data::DataType::Float32),
+            Self::Float64(v) => v.to_owned().map(odf::data::DataType::Float64),
+            Self::Int8(v) => v.to_owned().map(odf::data::DataType::Int8),
+            Self::Int16(v) => v.to_owned().map(odf::data::DataType::Int16),
+            Self::Int32(v) => v.to_owned().map(odf::data::DataType::Int32),
+            Self::Int64(v) => v.to_owned().map(odf::data::DataType::Int64),
+            Self::UInt8(v) => v.to_owned().map(odf::data::DataType::UInt8),
+            Self::UInt16(v) => v.to_owned().map(odf::data::DataType::UInt16),
+            Self::UInt32(v) => v.to_owned().map(odf::data::DataType::UInt32),
+            Self::UInt64(v) => v.to_owned().map(odf::data::DataType::UInt64),
+            Self::List(v) => v.to_owned().map(odf::data::DataType::List),
+            Self::Map(v) => v.to_owned().map(odf::data::DataType::Map),
+            Self::Null(v) => v.to_owned().map(odf::data::DataType::Null),
+            Self::Option(v) => v.to_owned().map(odf::data::DataType::Option),
+            Self::Struct(v) => v.to_owned().map(odf::data::DataType::Struct),
+            Self::Time(v) => v.to_owned().map(odf::data::DataType::Time),
+            Self::Timestamp(v) => v.to_owned().map(odf::data::DataType::Timestamp),
+            Self::String(v) => v.to_owned().map(odf::data::DataType::String),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeBinary
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Binary
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeBinary`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeBinaryView<'fb> {
+    proxy: fb::DataTypeBinary<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeBinary<'fb>> for DataTypeBinaryView<'fb> {
+    fn from(proxy: fb::DataTypeBinary<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeBinaryView<'fb> {
+    /// Number of bytes per value for fixed-size binary. If omitted, the binary is variable-length.
+    pub fn fixed_length(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .fixed_length()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeBinary.fixedLength"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeBinary, FlatbuffersDecodeError> {
+        odf::data::DataTypeBinary::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeBool
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Bool
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeBool`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeBoolView<'fb> {
+    proxy: fb::DataTypeBool<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeBool<'fb>> for DataTypeBoolView<'fb> {
+    fn from(proxy: fb::DataTypeBool<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeBoolView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeBool, FlatbuffersDecodeError> {
+        odf::data::DataTypeBool::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeDate
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Date
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeDate`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeDateView<'fb> {
+    proxy: fb::DataTypeDate<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeDate<'fb>> for DataTypeDateView<'fb> {
+    fn from(proxy: fb::DataTypeDate<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeDateView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeDate, FlatbuffersDecodeError> {
+        odf::data::DataTypeDate::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeDecimal
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Decimal
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeDecimal`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeDecimalView<'fb> {
+    proxy: fb::DataTypeDecimal<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeDecimal<'fb>> for DataTypeDecimalView<'fb> {
+    fn from(proxy: fb::DataTypeDecimal<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeDecimalView<'fb> {
+    /// Total number of decimal digits that can be stored.
+    pub fn precision(&self) -> Result<u32, FlatbuffersDecodeError> {
+        Ok(self.proxy.precision())
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeDecimal.precision"))
+    }
+
+    /// Number of digits after the decimal point. In certain situations, scale could be negative number. For negative scale, it is the number of padding 0 to the right of the digits.
+    ///
+    /// For example the number 12300 could be treated as a decimal has precision 3 and scale -2.
+    pub fn scale(&self) -> Result<i32, FlatbuffersDecodeError> {
+        Ok(self.proxy.scale()).map_err(|e: FlatbuffersDecodeError| e.at("DataTypeDecimal.scale"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeDecimal, FlatbuffersDecodeError> {
+        odf::data::DataTypeDecimal::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeDuration
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Duration
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeDuration`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeDurationView<'fb> {
+    proxy: fb::DataTypeDuration<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeDuration<'fb>> for DataTypeDurationView<'fb> {
+    fn from(proxy: fb::DataTypeDuration<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeDurationView<'fb> {
+    /// The unit of the duration measurement.
+    pub fn unit(&self) -> Result<Option<odf::data::TimeUnit>, FlatbuffersDecodeError> {
+        self.proxy
+            .unit()
+            .map(|v| odf::data::TimeUnit::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeDuration.unit"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeDuration, FlatbuffersDecodeError> {
+        odf::data::DataTypeDuration::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeFloat16
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Float16
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeFloat16`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeFloat16View<'fb> {
+    proxy: fb::DataTypeFloat16<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeFloat16<'fb>> for DataTypeFloat16View<'fb> {
+    fn from(proxy: fb::DataTypeFloat16<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeFloat16View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeFloat16, FlatbuffersDecodeError> {
+        odf::data::DataTypeFloat16::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeFloat32
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Float32
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeFloat32`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeFloat32View<'fb> {
+    proxy: fb::DataTypeFloat32<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeFloat32<'fb>> for DataTypeFloat32View<'fb> {
+    fn from(proxy: fb::DataTypeFloat32<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeFloat32View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeFloat32, FlatbuffersDecodeError> {
+        odf::data::DataTypeFloat32::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeFloat64
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Float64
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeFloat64`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeFloat64View<'fb> {
+    proxy: fb::DataTypeFloat64<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeFloat64<'fb>> for DataTypeFloat64View<'fb> {
+    fn from(proxy: fb::DataTypeFloat64<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeFloat64View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeFloat64, FlatbuffersDecodeError> {
+        odf::data::DataTypeFloat64::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt16
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int16
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeInt16`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeInt16View<'fb> {
+    proxy: fb::DataTypeInt16<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeInt16<'fb>> for DataTypeInt16View<'fb> {
+    fn from(proxy: fb::DataTypeInt16<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeInt16View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeInt16, FlatbuffersDecodeError> {
+        odf::data::DataTypeInt16::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt32
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int32
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeInt32`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeInt32View<'fb> {
+    proxy: fb::DataTypeInt32<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeInt32<'fb>> for DataTypeInt32View<'fb> {
+    fn from(proxy: fb::DataTypeInt32<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeInt32View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeInt32, FlatbuffersDecodeError> {
+        odf::data::DataTypeInt32::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt64
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int64
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeInt64`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeInt64View<'fb> {
+    proxy: fb::DataTypeInt64<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeInt64<'fb>> for DataTypeInt64View<'fb> {
+    fn from(proxy: fb::DataTypeInt64<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeInt64View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeInt64, FlatbuffersDecodeError> {
+        odf::data::DataTypeInt64::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt8
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int8
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeInt8`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeInt8View<'fb> {
+    proxy: fb::DataTypeInt8<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeInt8<'fb>> for DataTypeInt8View<'fb> {
+    fn from(proxy: fb::DataTypeInt8<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeInt8View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeInt8, FlatbuffersDecodeError> {
+        odf::data::DataTypeInt8::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeList
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/List
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeList`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeListView<'fb> {
+    proxy: fb::DataTypeList<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeList<'fb>> for DataTypeListView<'fb> {
+    fn from(proxy: fb::DataTypeList<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeListView<'fb> {
+    /// Data type of list items.
+    pub fn item_type(&self) -> Result<DataTypeView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .item_type()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| DataTypeView::new(v, self.proxy.item_type_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeList.itemType"))
+    }
+
+    /// Number of list items per value for fixed-size lists. If omitted, the list is variable-length.
+    pub fn fixed_length(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .fixed_length()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeList.fixedLength"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeList, FlatbuffersDecodeError> {
+        odf::data::DataTypeList::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeMap
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Map
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeMap`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeMapView<'fb> {
+    proxy: fb::DataTypeMap<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeMap<'fb>> for DataTypeMapView<'fb> {
+    fn from(proxy: fb::DataTypeMap<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeMapView<'fb> {
+    /// Data type of the map's keys.
+    pub fn key_type(&self) -> Result<DataTypeView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .key_type()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| DataTypeView::new(v, self.proxy.key_type_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeMap.keyType"))
+    }
+
+    /// Data type of the map's values.
+    pub fn value_type(&self) -> Result<DataTypeView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .value_type()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| DataTypeView::new(v, self.proxy.value_type_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeMap.valueType"))
+    }
+
+    /// Set to true if the keys within each value are sorted.
+    pub fn keys_sorted(&self) -> Result<Option<bool>, FlatbuffersDecodeError> {
+        self.proxy
+            .keys_sorted()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeMap.keysSorted"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeMap, FlatbuffersDecodeError> {
+        odf::data::DataTypeMap::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeNull
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Null
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeNull`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeNullView<'fb> {
+    proxy: fb::DataTypeNull<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeNull<'fb>> for DataTypeNullView<'fb> {
+    fn from(proxy: fb::DataTypeNull<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeNullView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeNull, FlatbuffersDecodeError> {
+        odf::data::DataTypeNull::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeOption
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Option
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeOption`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeOptionView<'fb> {
+    proxy: fb::DataTypeOption<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeOption<'fb>> for DataTypeOptionView<'fb> {
+    fn from(proxy: fb::DataTypeOption<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeOptionView<'fb> {
+    /// Inner data type for the optional value.
+    pub fn inner(&self) -> Result<DataTypeView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .inner()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| DataTypeView::new(v, self.proxy.inner_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeOption.inner"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeOption, FlatbuffersDecodeError> {
+        odf::data::DataTypeOption::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeString
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/String
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeString`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeStringView<'fb> {
+    proxy: fb::DataTypeString<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeString<'fb>> for DataTypeStringView<'fb> {
+    fn from(proxy: fb::DataTypeString<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeStringView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeString, FlatbuffersDecodeError> {
+        odf::data::DataTypeString::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeStruct
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Struct
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeStruct`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeStructView<'fb> {
+    proxy: fb::DataTypeStruct<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeStruct<'fb>> for DataTypeStructView<'fb> {
+    fn from(proxy: fb::DataTypeStruct<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeStructView<'fb> {
+    /// Fields that make up the struct.
+    pub fn fields(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<DataFieldView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .fields()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(DataFieldView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeStruct.fields"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeStruct, FlatbuffersDecodeError> {
+        odf::data::DataTypeStruct::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeTime
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Time
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeTime`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeTimeView<'fb> {
+    proxy: fb::DataTypeTime<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeTime<'fb>> for DataTypeTimeView<'fb> {
+    fn from(proxy: fb::DataTypeTime<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeTimeView<'fb> {
+    /// The unit of the time value.
+    pub fn unit(&self) -> Result<Option<odf::data::TimeUnit>, FlatbuffersDecodeError> {
+        self.proxy
+            .unit()
+            .map(|v| odf::data::TimeUnit::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeTime.unit"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeTime, FlatbuffersDecodeError> {
+        odf::data::DataTypeTime::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeTimestamp
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Timestamp
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeTimestamp`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeTimestampView<'fb> {
+    proxy: fb::DataTypeTimestamp<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeTimestamp<'fb>> for DataTypeTimestampView<'fb> {
+    fn from(proxy: fb::DataTypeTimestamp<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeTimestampView<'fb> {
+    /// The unit of the timestamp value that determines its precision.
+    pub fn unit(&self) -> Result<Option<odf::data::TimeUnit>, FlatbuffersDecodeError> {
+        self.proxy
+            .unit()
+            .map(|v| odf::data::TimeUnit::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeTimestamp.unit"))
+    }
+
+    /// The timezone is an optional string indicating the name of a timezone
+    /// one of
+    ///
+    /// * As used in the Olson timezone database (the "tz database" or
+    ///   "tzdata"), such as "America/New_York".
+    /// * An absolute timezone offset of the form "+XX:XX" or "-XX:XX",
+    ///   such as "+07:30".
+    pub fn timezone(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .timezone()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeTimestamp.timezone"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeTimestamp, FlatbuffersDecodeError> {
+        odf::data::DataTypeTimestamp::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt16
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt16
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeUInt16`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeUInt16View<'fb> {
+    proxy: fb::DataTypeUInt16<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeUInt16<'fb>> for DataTypeUInt16View<'fb> {
+    fn from(proxy: fb::DataTypeUInt16<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeUInt16View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeUInt16, FlatbuffersDecodeError> {
+        odf::data::DataTypeUInt16::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt32
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt32
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeUInt32`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeUInt32View<'fb> {
+    proxy: fb::DataTypeUInt32<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeUInt32<'fb>> for DataTypeUInt32View<'fb> {
+    fn from(proxy: fb::DataTypeUInt32<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeUInt32View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeUInt32, FlatbuffersDecodeError> {
+        odf::data::DataTypeUInt32::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt64
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt64
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeUInt64`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeUInt64View<'fb> {
+    proxy: fb::DataTypeUInt64<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeUInt64<'fb>> for DataTypeUInt64View<'fb> {
+    fn from(proxy: fb::DataTypeUInt64<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeUInt64View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeUInt64, FlatbuffersDecodeError> {
+        odf::data::DataTypeUInt64::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt8
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt8
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::DataTypeUInt8`]
+#[derive(Debug, Clone, Copy)]
+pub struct DataTypeUInt8View<'fb> {
+    proxy: fb::DataTypeUInt8<'fb>,
+}
+
+impl<'fb> From<fb::DataTypeUInt8<'fb>> for DataTypeUInt8View<'fb> {
+    fn from(proxy: fb::DataTypeUInt8<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DataTypeUInt8View<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::DataTypeUInt8, FlatbuffersDecodeError> {
+        odf::data::DataTypeUInt8::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetError
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetError
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetError`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetErrorView<'fb> {
+    proxy: fb::DatasetError<'fb>,
+}
+
+impl<'fb> From<fb::DatasetError<'fb>> for DatasetErrorView<'fb> {
+    fn from(proxy: fb::DatasetError<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetErrorView<'fb> {
+    /// Details of the error.
+    pub fn error_details(&self) -> Result<ErrorDetailsView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .error_details()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ErrorDetailsView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetError.errorDetails"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::DatasetError, FlatbuffersDecodeError> {
+        odf::protocol::DatasetError::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetMetadataPullResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetMetadataPullResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetMetadataPullResponse`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetMetadataPullResponseView<'fb> {
+    proxy: fb::DatasetMetadataPullResponse<'fb>,
+}
+
+impl<'fb> From<fb::DatasetMetadataPullResponse<'fb>> for DatasetMetadataPullResponseView<'fb> {
+    fn from(proxy: fb::DatasetMetadataPullResponse<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetMetadataPullResponseView<'fb> {
+    /// Metadata blocks of the requested range.
+    pub fn blocks(&self) -> Result<ObjectsBatchView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .blocks()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ObjectsBatchView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetMetadataPullResponse.blocks"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetMetadataPullResponse, FlatbuffersDecodeError> {
+        odf::protocol::DatasetMetadataPullResponse::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullMetadataRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullMetadataRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPullMetadataRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPullMetadataRequestView<'fb> {
+    proxy: fb::DatasetPullMetadataRequest<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPullMetadataRequest<'fb>> for DatasetPullMetadataRequestView<'fb> {
+    fn from(proxy: fb::DatasetPullMetadataRequest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPullMetadataRequestView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPullMetadataRequest, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPullMetadataRequest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullObjectsTransferRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPullObjectsTransferRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPullObjectsTransferRequestView<'fb> {
+    proxy: fb::DatasetPullObjectsTransferRequest<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPullObjectsTransferRequest<'fb>>
+    for DatasetPullObjectsTransferRequestView<'fb>
+{
+    fn from(proxy: fb::DatasetPullObjectsTransferRequest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPullObjectsTransferRequestView<'fb> {
+    /// List of object file references in this bulk.
+    pub fn object_files(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<ObjectFileReferenceView<'fb>, FlatbuffersDecodeError>>
+        + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .object_files()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(ObjectFileReferenceView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| {
+                e.at("DatasetPullObjectsTransferRequest.objectFiles")
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPullObjectsTransferRequest, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPullObjectsTransferRequest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullObjectsTransferResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPullObjectsTransferResponse`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPullObjectsTransferResponseView<'fb> {
+    proxy: fb::DatasetPullObjectsTransferResponse<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPullObjectsTransferResponse<'fb>>
+    for DatasetPullObjectsTransferResponseView<'fb>
+{
+    fn from(proxy: fb::DatasetPullObjectsTransferResponse<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPullObjectsTransferResponseView<'fb> {
+    /// Agreed download operations for the requested bulk of object files.
+    pub fn object_transfer_strategies(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<
+            Item = Result<PullObjectTransferStrategyView<'fb>, FlatbuffersDecodeError>,
+        > + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .object_transfer_strategies()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter()
+                    .map(|i| Ok(PullObjectTransferStrategyView::from(i))))
+            })
+            .map_err(|e: FlatbuffersDecodeError| {
+                e.at("DatasetPullObjectsTransferResponse.objectTransferStrategies")
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPullObjectsTransferResponse, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPullObjectsTransferResponse::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPullRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPullRequestView<'fb> {
+    proxy: fb::DatasetPullRequest<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPullRequest<'fb>> for DatasetPullRequestView<'fb> {
+    fn from(proxy: fb::DatasetPullRequest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPullRequestView<'fb> {
+    /// Dataset identifier.
+    pub fn dataset_id(&self) -> Result<odf::dataset::DatasetID, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullRequest.datasetId"))
+    }
+
+    /// Hash of the last metadata block consumer already has (undefined for initial state).
+    pub fn begin_after(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {
+        self.proxy
+            .begin_after()
+            .map(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullRequest.beginAfter"))
+    }
+
+    /// Hash of the last metadata block consumer would like to pull (undefined for head).
+    pub fn stop_at(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {
+        self.proxy
+            .stop_at()
+            .map(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullRequest.stopAt"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::DatasetPullRequest, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPullRequest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPullResponse`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPullResponseView<'fb> {
+    proxy: fb::DatasetPullResponse<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPullResponse<'fb>> for DatasetPullResponseView<'fb> {
+    fn from(proxy: fb::DatasetPullResponse<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPullResponseView<'fb> {
+    /// Estimated size of the data about to be transferred.
+    pub fn size_estimation(
+        &self,
+    ) -> Result<TransferSizeEstimationView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .size_estimation()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(TransferSizeEstimationView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullResponse.sizeEstimation"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::DatasetPullResponse, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPullResponse::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushComplete
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushComplete
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushComplete`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushCompleteView<'fb> {
+    proxy: fb::DatasetPushComplete<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushComplete<'fb>> for DatasetPushCompleteView<'fb> {
+    fn from(proxy: fb::DatasetPushComplete<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushCompleteView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::DatasetPushComplete, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushComplete::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushCompleteConfirmed
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushCompleteConfirmed
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushCompleteConfirmed`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushCompleteConfirmedView<'fb> {
+    proxy: fb::DatasetPushCompleteConfirmed<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushCompleteConfirmed<'fb>> for DatasetPushCompleteConfirmedView<'fb> {
+    fn from(proxy: fb::DatasetPushCompleteConfirmed<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushCompleteConfirmedView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPushCompleteConfirmed, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushCompleteConfirmed::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushMetadata
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadata
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushMetadata`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushMetadataView<'fb> {
+    proxy: fb::DatasetPushMetadata<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushMetadata<'fb>> for DatasetPushMetadataView<'fb> {
+    fn from(proxy: fb::DatasetPushMetadata<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushMetadataView<'fb> {
+    /// Metadata blocks that are missing on the server.
+    pub fn new_blocks(&self) -> Result<ObjectsBatchView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_blocks()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ObjectsBatchView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushMetadata.newBlocks"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::DatasetPushMetadata, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushMetadata::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushMetadataAccepted
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadataAccepted
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushMetadataAccepted`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushMetadataAcceptedView<'fb> {
+    proxy: fb::DatasetPushMetadataAccepted<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushMetadataAccepted<'fb>> for DatasetPushMetadataAcceptedView<'fb> {
+    fn from(proxy: fb::DatasetPushMetadataAccepted<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushMetadataAcceptedView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPushMetadataAccepted, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushMetadataAccepted::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushObjectsTransferRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushObjectsTransferRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushObjectsTransferRequestView<'fb> {
+    proxy: fb::DatasetPushObjectsTransferRequest<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushObjectsTransferRequest<'fb>>
+    for DatasetPushObjectsTransferRequestView<'fb>
+{
+    fn from(proxy: fb::DatasetPushObjectsTransferRequest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushObjectsTransferRequestView<'fb> {
+    /// List of object file references in this bulk.
+    pub fn object_files(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<ObjectFileReferenceView<'fb>, FlatbuffersDecodeError>>
+        + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .object_files()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(ObjectFileReferenceView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| {
+                e.at("DatasetPushObjectsTransferRequest.objectFiles")
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPushObjectsTransferRequest, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushObjectsTransferRequest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushObjectsTransferResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushObjectsTransferResponse`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushObjectsTransferResponseView<'fb> {
+    proxy: fb::DatasetPushObjectsTransferResponse<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushObjectsTransferResponse<'fb>>
+    for DatasetPushObjectsTransferResponseView<'fb>
+{
+    fn from(proxy: fb::DatasetPushObjectsTransferResponse<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushObjectsTransferResponseView<'fb> {
+    /// Agreed upload operations for the requested bulk of object files.
+    pub fn object_transfer_strategies(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<
+            Item = Result<PushObjectTransferStrategyView<'fb>, FlatbuffersDecodeError>,
+        > + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .object_transfer_strategies()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter()
+                    .map(|i| Ok(PushObjectTransferStrategyView::from(i))))
+            })
+            .map_err(|e: FlatbuffersDecodeError| {
+                e.at("DatasetPushObjectsTransferResponse.objectTransferStrategies")
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPushObjectsTransferResponse, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushObjectsTransferResponse::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushRequestView<'fb> {
+    proxy: fb::DatasetPushRequest<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushRequest<'fb>> for DatasetPushRequestView<'fb> {
+    fn from(proxy: fb::DatasetPushRequest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushRequestView<'fb> {
+    /// Dataset identifier.
+    pub fn dataset_id(&self) -> Result<odf::dataset::DatasetID, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushRequest.datasetId"))
+    }
+
+    /// Hash of the head block that client assumes to be the current head on the server.
+    pub fn current_head(&self) -> Result<odf::Multihash, FlatbuffersDecodeError> {
+        self.proxy
+            .current_head()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushRequest.currentHead"))
+    }
+
+    /// Estimated size of the data about to be transferred.
+    pub fn size_estimation(
+        &self,
+    ) -> Result<TransferSizeEstimationView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .size_estimation()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(TransferSizeEstimationView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushRequest.sizeEstimation"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::DatasetPushRequest, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushRequest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushRequestAccepted
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequestAccepted
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::DatasetPushRequestAccepted`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetPushRequestAcceptedView<'fb> {
+    proxy: fb::DatasetPushRequestAccepted<'fb>,
+}
+
+impl<'fb> From<fb::DatasetPushRequestAccepted<'fb>> for DatasetPushRequestAcceptedView<'fb> {
+    fn from(proxy: fb::DatasetPushRequestAccepted<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetPushRequestAcceptedView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::DatasetPushRequestAccepted, FlatbuffersDecodeError> {
+        odf::protocol::DatasetPushRequestAccepted::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetSpec
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::DatasetSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetSpecView<'fb> {
+    proxy: fb::DatasetSpec<'fb>,
+}
+
+impl<'fb> From<fb::DatasetSpec<'fb>> for DatasetSpecView<'fb> {
+    fn from(proxy: fb::DatasetSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetSpecView<'fb> {
+    /// DID of the dataset in global ODF network
+    pub fn did(&self) -> Result<odf::dataset::DatasetID, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.did"))
+    }
+
+    /// Type of the dataset.
+    pub fn kind(&self) -> Result<odf::dataset::DatasetKind, FlatbuffersDecodeError> {
+        odf::dataset::DatasetKind::try_from(self.proxy.kind())
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.kind"))
+    }
+
+    /// An array of metadata events that will be used to populate the chain. Here you can define polling and push sources, set licenses, add attachments etc.
+    pub fn metadata(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<MetadataEventView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .metadata()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| MetadataEventView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.metadata"))
+    }
+
+    /// Reference to a storage volume where dataset data will be stored. If omitted, the node's default storage is used.
+    pub fn volume(&self) -> Result<ResourceHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .volume()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.volume"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::DatasetSpec, FlatbuffersDecodeError> {
+        odf::dataset::DatasetSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetSpecInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::DatasetSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetSpecInputView<'fb> {
+    proxy: fb::DatasetSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::DatasetSpecInput<'fb>> for DatasetSpecInputView<'fb> {
+    fn from(proxy: fb::DatasetSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetSpecInputView<'fb> {
+    /// DID of the dataset in global ODF network
+    pub fn did(&self) -> Result<Option<odf::dataset::DatasetID>, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .map(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.did"))
+    }
+
+    /// Type of the dataset.
+    pub fn kind(&self) -> Result<odf::dataset::DatasetKind, FlatbuffersDecodeError> {
+        odf::dataset::DatasetKind::try_from(self.proxy.kind())
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.kind"))
+    }
+
+    /// An array of metadata events that will be used to populate the chain. Here you can define polling and push sources, set licenses, add attachments etc.
+    pub fn metadata(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<MetadataEventView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .metadata()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| MetadataEventView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.metadata"))
+    }
+
+    /// Reference to a storage volume where dataset data will be stored. If omitted, the node's default storage is used.
+    pub fn volume(&self) -> Result<Option<PersistentVolumeRefView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .volume()
+            .map(|v| Ok(PersistentVolumeRefView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.volume"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::DatasetSpecInput, FlatbuffersDecodeError> {
+        odf::dataset::DatasetSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetVocabulary
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetVocabulary
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::DatasetVocabulary`]
+#[derive(Debug, Clone, Copy)]
+pub struct DatasetVocabularyView<'fb> {
+    proxy: fb::DatasetVocabulary<'fb>,
+}
+
+impl<'fb> From<fb::DatasetVocabulary<'fb>> for DatasetVocabularyView<'fb> {
+    fn from(proxy: fb::DatasetVocabulary<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DatasetVocabularyView<'fb> {
+    /// Name of the offset column.
+    pub fn offset_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .offset_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetVocabulary.offsetColumn"))
+    }
+
+    /// Name of the operation type column.
+    pub fn operation_type_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .operation_type_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetVocabulary.operationTypeColumn"))
+    }
+
+    /// Name of the system time column.
+    pub fn system_time_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .system_time_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetVocabulary.systemTimeColumn"))
+    }
+
+    /// Name of the event time column.
+    pub fn event_time_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .event_time_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("DatasetVocabulary.eventTimeColumn"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::DatasetVocabulary, FlatbuffersDecodeError> {
+        odf::dataset::DatasetVocabulary::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DisablePollingSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePollingSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::DisablePollingSource`]
+#[derive(Debug, Clone, Copy)]
+pub struct DisablePollingSourceView<'fb> {
+    proxy: fb::DisablePollingSource<'fb>,
+}
+
+impl<'fb> From<fb::DisablePollingSource<'fb>> for DisablePollingSourceView<'fb> {
+    fn from(proxy: fb::DisablePollingSource<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DisablePollingSourceView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::DisablePollingSource, FlatbuffersDecodeError> {
+        odf::legacy::DisablePollingSource::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DisablePushSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePushSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::DisablePushSource`]
+#[derive(Debug, Clone, Copy)]
+pub struct DisablePushSourceView<'fb> {
+    proxy: fb::DisablePushSource<'fb>,
+}
+
+impl<'fb> From<fb::DisablePushSource<'fb>> for DisablePushSourceView<'fb> {
+    fn from(proxy: fb::DisablePushSource<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> DisablePushSourceView<'fb> {
+    /// Identifies the source to be disabled.
+    pub fn source_name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .source_name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("DisablePushSource.sourceName"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::DisablePushSource, FlatbuffersDecodeError> {
+        odf::legacy::DisablePushSource::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EnvVar
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EnvVar
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::EnvVar`]
+#[derive(Debug, Clone, Copy)]
+pub struct EnvVarView<'fb> {
+    proxy: fb::EnvVar<'fb>,
+}
+
+impl<'fb> From<fb::EnvVar<'fb>> for EnvVarView<'fb> {
+    fn from(proxy: fb::EnvVar<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EnvVarView<'fb> {
+    /// Name of the variable.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("EnvVar.name"))
+    }
+
+    /// Value of the variable.
+    pub fn value(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("EnvVar.value"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::EnvVar, FlatbuffersDecodeError> {
+        odf::source::EnvVar::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ErrorDetails
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ErrorDetails
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::ErrorDetails`]
+#[derive(Debug, Clone, Copy)]
+pub struct ErrorDetailsView<'fb> {
+    proxy: fb::ErrorDetails<'fb>,
+}
+
+impl<'fb> From<fb::ErrorDetails<'fb>> for ErrorDetailsView<'fb> {
+    fn from(proxy: fb::ErrorDetails<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ErrorDetailsView<'fb> {
+    /// Informative code of the error happened.
+    pub fn error_code(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .error_code()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ErrorDetails.errorCode"))
+    }
+
+    /// Detailed description of the error happened.
+    pub fn description(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .description()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ErrorDetails.description"))
+    }
+
+    /// Optional extra fields associated with an error.
+    pub fn extras(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .extras()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ErrorDetails.extras"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::ErrorDetails, FlatbuffersDecodeError> {
+        odf::protocol::ErrorDetails::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilter
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::event::EventFilter`]
+#[derive(Debug, Clone, Copy)]
+pub enum EventFilterView<'fb> {
+    And(EventFilterAndView<'fb>),
+    Or(EventFilterOrView<'fb>),
+    Not(EventFilterNotView<'fb>),
+    Match(EventFilterMatchView<'fb>),
+}
+
+impl<'fb> EventFilterView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::EventFilter) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::EventFilter::EventFilterAnd => Ok(Self::And(EventFilterAndView::from(unsafe {
+                fb::EventFilterAnd::init_from_table(table)
+            }))),
+            fb::EventFilter::EventFilterOr => Ok(Self::Or(EventFilterOrView::from(unsafe {
+                fb::EventFilterOr::init_from_table(table)
+            }))),
+            fb::EventFilter::EventFilterNot => Ok(Self::Not(EventFilterNotView::from(unsafe {
+                fb::EventFilterNot::init_from_table(table)
+            }))),
+            fb::EventFilter::EventFilterMatch => {
+                Ok(Self::Match(EventFilterMatchView::from(unsafe {
+                    fb::EventFilterMatch::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "EventFilter",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::event::EventFilter, FlatbuffersDecodeError> {
+        match self {
+            Self::And(v) => v.to_owned().map(odf::event::EventFilter::And),
+            Self::Or(v) => v.to_owned().map(odf::event::EventFilter::Or),
+            Self::Not(v) => v.to_owned().map(odf::event::EventFilter::Not),
+            Self::Match(v) => v.to_owned().map(odf::event::EventFilter::Match),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterAnd
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/And
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::event::EventFilterAnd`]
+#[derive(Debug, Clone, Copy)]
+pub struct EventFilterAndView<'fb> {
+    proxy: fb::EventFilterAnd<'fb>,
+}
+
+impl<'fb> From<fb::EventFilterAnd<'fb>> for EventFilterAndView<'fb> {
+    fn from(proxy: fb::EventFilterAnd<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EventFilterAndView<'fb> {
+    /// Filters that all must match.
+    pub fn and(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<EventFilterView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .and()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| EventFilterView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("EventFilterAnd.$and"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::event::EventFilterAnd, FlatbuffersDecodeError> {
+        odf::event::EventFilterAnd::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterMatch
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Match
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::event::EventFilterMatch`]
+#[derive(Debug, Clone, Copy)]
+pub struct EventFilterMatchView<'fb> {
+    proxy: fb::EventFilterMatch<'fb>,
+}
+
+impl<'fb> From<fb::EventFilterMatch<'fb>> for EventFilterMatchView<'fb> {
+    fn from(proxy: fb::EventFilterMatch<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EventFilterMatchView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<
+        Item = Result<(&'fb str, FilterConditionView<'fb>), FlatbuffersDecodeError>,
+    > + 'fb {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(FilterConditionView::from(v)))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("EventFilterMatch.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::event::EventFilterMatch, FlatbuffersDecodeError> {
+        odf::event::EventFilterMatch::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterNot
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Not
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::event::EventFilterNot`]
+#[derive(Debug, Clone, Copy)]
+pub struct EventFilterNotView<'fb> {
+    proxy: fb::EventFilterNot<'fb>,
+}
+
+impl<'fb> From<fb::EventFilterNot<'fb>> for EventFilterNotView<'fb> {
+    fn from(proxy: fb::EventFilterNot<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EventFilterNotView<'fb> {
+    /// Filter to negate.
+    pub fn not(&self) -> Result<EventFilterView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .not()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| EventFilterView::new(v, self.proxy.not_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("EventFilterNot.$not"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::event::EventFilterNot, FlatbuffersDecodeError> {
+        odf::event::EventFilterNot::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterOr
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Or
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::event::EventFilterOr`]
+#[derive(Debug, Clone, Copy)]
+pub struct EventFilterOrView<'fb> {
+    proxy: fb::EventFilterOr<'fb>,
+}
+
+impl<'fb> From<fb::EventFilterOr<'fb>> for EventFilterOrView<'fb> {
+    fn from(proxy: fb::EventFilterOr<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EventFilterOrView<'fb> {
+    /// Filters of which at least one must match.
+    pub fn or(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<EventFilterView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .or()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| EventFilterView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("EventFilterOr.$or"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::event::EventFilterOr, FlatbuffersDecodeError> {
+        odf::event::EventFilterOr::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSource
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::EventTimeSource`]
+#[derive(Debug, Clone, Copy)]
+pub enum EventTimeSourceView<'fb> {
+    FromMetadata(EventTimeSourceFromMetadataView<'fb>),
+    FromPath(EventTimeSourceFromPathView<'fb>),
+    FromSystemTime(EventTimeSourceFromSystemTimeView<'fb>),
+}
+
+impl<'fb> EventTimeSourceView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::EventTimeSource) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::EventTimeSource::EventTimeSourceFromMetadata => {
+                Ok(Self::FromMetadata(EventTimeSourceFromMetadataView::from(
+                    unsafe { fb::EventTimeSourceFromMetadata::init_from_table(table) },
+                )))
+            }
+            fb::EventTimeSource::EventTimeSourceFromPath => {
+                Ok(Self::FromPath(EventTimeSourceFromPathView::from(unsafe {
+                    fb::EventTimeSourceFromPath::init_from_table(table)
+                })))
+            }
+            fb::EventTimeSource::EventTimeSourceFromSystemTime => Ok(Self::FromSystemTime(
+                EventTimeSourceFromSystemTimeView::from(unsafe {
+                    fb::EventTimeSourceFromSystemTime::init_from_table(table)
+                }),
+            )),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "EventTimeSource",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::EventTimeSource, FlatbuffersDecodeError> {
+        match self {
+            Self::FromMetadata(v) => v.to_owned().map(odf::source::EventTimeSource::FromMetadata),
+            Self::FromPath(v) => v.to_owned().map(odf::source::EventTimeSource::FromPath),
+            Self::FromSystemTime(v) => v
+                .to_owned()
+                .map(odf::source::EventTimeSource::FromSystemTime),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSourceFromMetadata
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromMetadata
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::EventTimeSourceFromMetadata`]
+#[derive(Debug, Clone, Copy)]
+pub struct EventTimeSourceFromMetadataView<'fb> {
+    proxy: fb::EventTimeSourceFromMetadata<'fb>,
+}
+
+impl<'fb> From<fb::EventTimeSourceFromMetadata<'fb>> for EventTimeSourceFromMetadataView<'fb> {
+    fn from(proxy: fb::EventTimeSourceFromMetadata<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EventTimeSourceFromMetadataView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::source::EventTimeSourceFromMetadata, FlatbuffersDecodeError> {
+        odf::source::EventTimeSourceFromMetadata::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSourceFromPath
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromPath
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::EventTimeSourceFromPath`]
+#[derive(Debug, Clone, Copy)]
+pub struct EventTimeSourceFromPathView<'fb> {
+    proxy: fb::EventTimeSourceFromPath<'fb>,
+}
+
+impl<'fb> From<fb::EventTimeSourceFromPath<'fb>> for EventTimeSourceFromPathView<'fb> {
+    fn from(proxy: fb::EventTimeSourceFromPath<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EventTimeSourceFromPathView<'fb> {
+    /// Regular expression where first group contains the timestamp string.
+    pub fn pattern(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .pattern()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("EventTimeSourceFromPath.pattern"))
+    }
+
+    /// Format of the expected timestamp in java.text.SimpleDateFormat form.
+    pub fn timestamp_format(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .timestamp_format()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("EventTimeSourceFromPath.timestampFormat"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::EventTimeSourceFromPath, FlatbuffersDecodeError> {
+        odf::source::EventTimeSourceFromPath::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSourceFromSystemTime
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromSystemTime
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::EventTimeSourceFromSystemTime`]
+#[derive(Debug, Clone, Copy)]
+pub struct EventTimeSourceFromSystemTimeView<'fb> {
+    proxy: fb::EventTimeSourceFromSystemTime<'fb>,
+}
+
+impl<'fb> From<fb::EventTimeSourceFromSystemTime<'fb>> for EventTimeSourceFromSystemTimeView<'fb> {
+    fn from(proxy: fb::EventTimeSourceFromSystemTime<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> EventTimeSourceFromSystemTimeView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::source::EventTimeSourceFromSystemTime, FlatbuffersDecodeError> {
+        odf::source::EventTimeSourceFromSystemTime::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ExecuteTransform
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ExecuteTransform
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::ExecuteTransform`]
+#[derive(Debug, Clone, Copy)]
+pub struct ExecuteTransformView<'fb> {
+    proxy: fb::ExecuteTransform<'fb>,
+}
+
+impl<'fb> From<fb::ExecuteTransform<'fb>> for ExecuteTransformView<'fb> {
+    fn from(proxy: fb::ExecuteTransform<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ExecuteTransformView<'fb> {
+    /// Defines inputs used in this transaction. Slices corresponding to every input dataset must be present.
+    pub fn query_inputs(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<ExecuteTransformInputView<'fb>, FlatbuffersDecodeError>>
+        + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .query_inputs()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(ExecuteTransformInputView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.queryInputs"))
+    }
+
+    /// Hash of the checkpoint file used to restore transformation state, if any.
+    pub fn prev_checkpoint(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_checkpoint()
+            .map(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.prevCheckpoint"))
+    }
+
+    /// Last offset of the previous data slice, if any. Must be equal to the last non-empty `newData.offsetInterval.end`.
+    pub fn prev_offset(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_offset()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.prevOffset"))
+    }
+
+    /// Describes output data written during this transaction, if any.
+    pub fn new_data(&self) -> Result<Option<DataSliceView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_data()
+            .map(|v| Ok(DataSliceView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.newData"))
+    }
+
+    /// Describes checkpoint written during this transaction, if any. If an engine operation resulted in no updates to the checkpoint, but checkpoint is still relevant for subsequent runs - a hash of the previous checkpoint should be specified.
+    pub fn new_checkpoint(&self) -> Result<Option<CheckpointView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_checkpoint()
+            .map(|v| Ok(CheckpointView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.newCheckpoint"))
+    }
+
+    /// Last watermark of the output data stream, if any. Initial blocks may not have watermarks, but once watermark is set - all subsequent blocks should either carry the same watermark or specify a new (greater) one. Thus, watermarks are monotonically non-decreasing.
+    pub fn new_watermark(&self) -> Result<Option<DateTime<Utc>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_watermark()
+            .map(|v| fb_to_datetime(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.newWatermark"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::ExecuteTransform, FlatbuffersDecodeError> {
+        odf::dataset::ExecuteTransform::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ExecuteTransformInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ExecuteTransformInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::ExecuteTransformInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct ExecuteTransformInputView<'fb> {
+    proxy: fb::ExecuteTransformInput<'fb>,
+}
+
+impl<'fb> From<fb::ExecuteTransformInput<'fb>> for ExecuteTransformInputView<'fb> {
+    fn from(proxy: fb::ExecuteTransformInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ExecuteTransformInputView<'fb> {
+    /// Input dataset identifier.
+    pub fn dataset_id(&self) -> Result<odf::dataset::DatasetID, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.datasetId"))
+    }
+
+    /// Last block of the input dataset that was previously incorporated into the derivative transformation, if any. Must be equal to the last non-empty `newBlockHash`. Together with `newBlockHash` defines a half-open `(prevBlockHash, newBlockHash]` interval of blocks that will be considered in this transaction.
+    pub fn prev_block_hash(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_block_hash()
+            .map(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.prevBlockHash"))
+    }
+
+    /// Hash of the last block that will be incorporated into the derivative transformation. When present, defines a half-open `(prevBlockHash, newBlockHash]` interval of blocks that will be considered in this transaction.
+    pub fn new_block_hash(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_block_hash()
+            .map(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.newBlockHash"))
+    }
+
+    /// Last data record offset in the input dataset that was previously incorporated into the derivative transformation, if any. Must be equal to the last non-empty `newOffset`. Together with `newOffset` defines a half-open `(prevOffset, newOffset]` interval of data records that will be considered in this transaction.
+    pub fn prev_offset(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_offset()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.prevOffset"))
+    }
+
+    /// Offset of the last data record that will be incorporated into the derivative transformation, if any. When present, defines a half-open `(prevOffset, newOffset]` interval of data records that will be considered in this transaction.
+    pub fn new_offset(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_offset()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.newOffset"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::ExecuteTransformInput, FlatbuffersDecodeError> {
+        odf::dataset::ExecuteTransformInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ExtraAttributes
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/ExtraAttributes
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::data::ExtraAttributes`]
+#[derive(Debug, Clone, Copy)]
+pub struct ExtraAttributesView<'fb> {
+    proxy: fb::ExtraAttributes<'fb>,
+}
+
+impl<'fb> From<fb::ExtraAttributes<'fb>> for ExtraAttributesView<'fb> {
+    fn from(proxy: fb::ExtraAttributes<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ExtraAttributesView<'fb> {
+    /// Entries encoded as a JSON object
+    pub fn entries_json(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .entries()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .map_err(|e| e.at("ExtraAttributes.entries"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::data::ExtraAttributes, FlatbuffersDecodeError> {
+        odf::data::ExtraAttributes::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStep
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::FetchStep`]
+#[derive(Debug, Clone, Copy)]
+pub enum FetchStepView<'fb> {
+    Url(FetchStepUrlView<'fb>),
+    FilesGlob(FetchStepFilesGlobView<'fb>),
+    Container(FetchStepContainerView<'fb>),
+    Mqtt(FetchStepMqttView<'fb>),
+    EthereumLogs(FetchStepEthereumLogsView<'fb>),
+}
+
+impl<'fb> FetchStepView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::FetchStep) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::FetchStep::FetchStepUrl => Ok(Self::Url(FetchStepUrlView::from(unsafe {
+                fb::FetchStepUrl::init_from_table(table)
+            }))),
+            fb::FetchStep::FetchStepFilesGlob => {
+                Ok(Self::FilesGlob(FetchStepFilesGlobView::from(unsafe {
+                    fb::FetchStepFilesGlob::init_from_table(table)
+                })))
+            }
+            fb::FetchStep::FetchStepContainer => {
+                Ok(Self::Container(FetchStepContainerView::from(unsafe {
+                    fb::FetchStepContainer::init_from_table(table)
+                })))
+            }
+            fb::FetchStep::FetchStepMqtt => Ok(Self::Mqtt(FetchStepMqttView::from(unsafe {
+                fb::FetchStepMqtt::init_from_table(table)
+            }))),
+            fb::FetchStep::FetchStepEthereumLogs => {
+                Ok(Self::EthereumLogs(FetchStepEthereumLogsView::from(
+                    unsafe { fb::FetchStepEthereumLogs::init_from_table(table) },
+                )))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "FetchStep",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::FetchStep, FlatbuffersDecodeError> {
+        match self {
+            Self::Url(v) => v.to_owned().map(odf::legacy::FetchStep::Url),
+            Self::FilesGlob(v) => v.to_owned().map(odf::legacy::FetchStep::FilesGlob),
+            Self::Container(v) => v.to_owned().map(odf::legacy::FetchStep::Container),
+            Self::Mqtt(v) => v.to_owned().map(odf::legacy::FetchStep::Mqtt),
+            Self::EthereumLogs(v) => v.to_owned().map(odf::legacy::FetchStep::EthereumLogs),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepContainer
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Container
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::FetchStepContainer`]
+#[derive(Debug, Clone, Copy)]
+pub struct FetchStepContainerView<'fb> {
+    proxy: fb::FetchStepContainer<'fb>,
+}
+
+impl<'fb> From<fb::FetchStepContainer<'fb>> for FetchStepContainerView<'fb> {
+    fn from(proxy: fb::FetchStepContainer<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FetchStepContainerView<'fb> {
+    /// Image name and and an optional tag.
+    pub fn image(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .image()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.image"))
+    }
+
+    /// Specifies the entrypoint. Not executed within a shell. The default OCI image's ENTRYPOINT is used if this is not provided.
+    pub fn command(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .command()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.command"))
+    }
+
+    /// Arguments to the entrypoint. The OCI image's CMD is used if this is not provided.
+    pub fn args(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .args()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.args"))
+    }
+
+    /// Environment variables to propagate into or set in the container.
+    pub fn env(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<EnvVarView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .env()
+            .map(|v| Ok(v.iter().map(|i| Ok(EnvVarView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.env"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::FetchStepContainer, FlatbuffersDecodeError> {
+        odf::legacy::FetchStepContainer::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepEthereumLogs
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/EthereumLogs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::FetchStepEthereumLogs`]
+#[derive(Debug, Clone, Copy)]
+pub struct FetchStepEthereumLogsView<'fb> {
+    proxy: fb::FetchStepEthereumLogs<'fb>,
+}
+
+impl<'fb> From<fb::FetchStepEthereumLogs<'fb>> for FetchStepEthereumLogsView<'fb> {
+    fn from(proxy: fb::FetchStepEthereumLogs<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FetchStepEthereumLogsView<'fb> {
+    /// Identifier of the chain to scan logs from. This parameter may be used for RPC endpoint lookup as well as asserting that provided `nodeUrl` corresponds to the expected chain.
+    pub fn chain_id(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .chain_id()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.chainId"))
+    }
+
+    /// Url of the node.
+    pub fn node_url(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .node_url()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.nodeUrl"))
+    }
+
+    /// An SQL WHERE clause that can be used to pre-filter the logs before fetching them from the ETH node.
+    pub fn filter(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .filter()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.filter"))
+    }
+
+    /// Solidity log event signature to use for decoding. Using this field adds `event` to the output containing decoded log as JSON.
+    pub fn signature(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .signature()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.signature"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::FetchStepEthereumLogs, FlatbuffersDecodeError> {
+        odf::legacy::FetchStepEthereumLogs::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepFilesGlob
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/FilesGlob
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::FetchStepFilesGlob`]
+#[derive(Debug, Clone, Copy)]
+pub struct FetchStepFilesGlobView<'fb> {
+    proxy: fb::FetchStepFilesGlob<'fb>,
+}
+
+impl<'fb> From<fb::FetchStepFilesGlob<'fb>> for FetchStepFilesGlobView<'fb> {
+    fn from(proxy: fb::FetchStepFilesGlob<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FetchStepFilesGlobView<'fb> {
+    /// Path with a glob pattern.
+    pub fn path(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .path()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.path"))
+    }
+
+    /// Describes how event time is extracted from the source metadata.
+    pub fn event_time(&self) -> Result<Option<EventTimeSourceView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .event_time()
+            .map(|v| EventTimeSourceView::new(v, self.proxy.event_time_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.eventTime"))
+    }
+
+    /// Describes the caching settings used for this source.
+    pub fn cache(&self) -> Result<Option<SourceCachingView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .cache()
+            .map(|v| SourceCachingView::new(v, self.proxy.cache_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.cache"))
+    }
+
+    /// Specifies how input files should be ordered before ingestion.
+    /// Order is important as every file will be processed individually
+    /// and will advance the dataset's watermark.
+    pub fn order(&self) -> Result<Option<odf::source::SourceOrdering>, FlatbuffersDecodeError> {
+        self.proxy
+            .order()
+            .map(|v| odf::source::SourceOrdering::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.order"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::FetchStepFilesGlob, FlatbuffersDecodeError> {
+        odf::legacy::FetchStepFilesGlob::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepMqtt
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Mqtt
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::FetchStepMqtt`]
+#[derive(Debug, Clone, Copy)]
+pub struct FetchStepMqttView<'fb> {
+    proxy: fb::FetchStepMqtt<'fb>,
+}
+
+impl<'fb> From<fb::FetchStepMqtt<'fb>> for FetchStepMqttView<'fb> {
+    fn from(proxy: fb::FetchStepMqtt<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FetchStepMqttView<'fb> {
+    /// Hostname of the MQTT broker.
+    pub fn host(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .host()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.host"))
+    }
+
+    /// Port of the MQTT broker.
+    pub fn port(&self) -> Result<i32, FlatbuffersDecodeError> {
+        Ok(self.proxy.port()).map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.port"))
+    }
+
+    /// Username to use for auth with the broker.
+    pub fn username(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .username()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.username"))
+    }
+
+    /// Password to use for auth with the broker (can be templated).
+    pub fn password(
+        &self,
+    ) -> Result<Option<odf::dtos::Sensitive<&'fb str>>, FlatbuffersDecodeError> {
+        self.proxy
+            .password()
+            .map(|v| Ok(v).map(odf::dtos::Sensitive::new))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.password"))
+    }
+
+    /// List of topic subscription parameters.
+    pub fn topics(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<MqttTopicSubscriptionView<'fb>, FlatbuffersDecodeError>>
+        + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .topics()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(MqttTopicSubscriptionView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.topics"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::FetchStepMqtt, FlatbuffersDecodeError> {
+        odf::legacy::FetchStepMqtt::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepUrl
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Url
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::FetchStepUrl`]
+#[derive(Debug, Clone, Copy)]
+pub struct FetchStepUrlView<'fb> {
+    proxy: fb::FetchStepUrl<'fb>,
+}
+
+impl<'fb> From<fb::FetchStepUrl<'fb>> for FetchStepUrlView<'fb> {
+    fn from(proxy: fb::FetchStepUrl<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FetchStepUrlView<'fb> {
+    /// URL of the data source
+    pub fn url(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .url()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.url"))
+    }
+
+    /// Describes how event time is extracted from the source metadata.
+    pub fn event_time(&self) -> Result<Option<EventTimeSourceView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .event_time()
+            .map(|v| EventTimeSourceView::new(v, self.proxy.event_time_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.eventTime"))
+    }
+
+    /// Describes the caching settings used for this source.
+    pub fn cache(&self) -> Result<Option<SourceCachingView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .cache()
+            .map(|v| SourceCachingView::new(v, self.proxy.cache_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.cache"))
+    }
+
+    /// Headers to pass during the request (e.g. HTTP Authorization)
+    pub fn headers(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<RequestHeaderView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .headers()
+            .map(|v| Ok(v.iter().map(|i| Ok(RequestHeaderView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.headers"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::FetchStepUrl, FlatbuffersDecodeError> {
+        odf::legacy::FetchStepUrl::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FilterCondition
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/FilterCondition
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::FilterCondition`]
+#[derive(Debug, Clone, Copy)]
+pub struct FilterConditionView<'fb> {
+    proxy: fb::FilterCondition<'fb>,
+}
+
+impl<'fb> From<fb::FilterCondition<'fb>> for FilterConditionView<'fb> {
+    fn from(proxy: fb::FilterCondition<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FilterConditionView<'fb> {
+    /// Matches values equal to the specified one, use for non-string values.
+    pub fn eq(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .eq()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$eq"))
+    }
+
+    /// Matches values equal to any of the specified ones.
+    pub fn r#in(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .in_()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$in"))
+    }
+
+    /// Matches when the value is present (`true`) or absent (`false`).
+    pub fn exists(&self) -> Result<Option<bool>, FlatbuffersDecodeError> {
+        self.proxy
+            .exists()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$exists"))
+    }
+
+    /// Matches string values against a glob pattern where `*` stands for any sequence of characters and `?` for any single character.
+    pub fn glob(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .glob()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$glob"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::FilterCondition, FlatbuffersDecodeError> {
+        odf::resource::FilterCondition::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowSpec
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowSpecView<'fb> {
+    proxy: fb::FlowSpec<'fb>,
+}
+
+impl<'fb> From<fb::FlowSpec<'fb>> for FlowSpecView<'fb> {
+    fn from(proxy: fb::FlowSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowSpecView<'fb> {
+    /// Defines resources for which this flow will be instantiated.
+    pub fn target(&self) -> Result<odf::resource::ResourceSelector, FlatbuffersDecodeError> {
+        self.proxy
+            .target()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| odf::resource::ResourceSelector::deserialize(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpec.target"))
+    }
+
+    /// Conditions that cause this flow to execute.
+    pub fn triggers(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<FlowTriggerView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .triggers()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| FlowTriggerView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpec.triggers"))
+    }
+
+    /// List of tasks to run consecutively.
+    pub fn tasks(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<TaskSpecView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .tasks()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| TaskSpecView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpec.tasks"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowSpec, FlatbuffersDecodeError> {
+        odf::flow::FlowSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowSpecInput
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowSpecInputView<'fb> {
+    proxy: fb::FlowSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::FlowSpecInput<'fb>> for FlowSpecInputView<'fb> {
+    fn from(proxy: fb::FlowSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowSpecInputView<'fb> {
+    /// Defines resources for which this flow will be instantiated.
+    pub fn target(&self) -> Result<odf::resource::ResourceSelector, FlatbuffersDecodeError> {
+        self.proxy
+            .target()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| odf::resource::ResourceSelector::deserialize(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpecInput.target"))
+    }
+
+    /// Conditions that cause this flow to execute.
+    pub fn triggers(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<FlowTriggerInputView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .triggers()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| FlowTriggerInputView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpecInput.triggers"))
+    }
+
+    /// List of tasks to run consecutively.
+    pub fn tasks(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<TaskSpecInputView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .tasks()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| TaskSpecInputView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpecInput.tasks"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowSpecInput, FlatbuffersDecodeError> {
+        odf::flow::FlowSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTrigger
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTrigger`]
+#[derive(Debug, Clone, Copy)]
+pub enum FlowTriggerView<'fb> {
+    Schedule(FlowTriggerScheduleView<'fb>),
+    Event(FlowTriggerEventView<'fb>),
+    Source(FlowTriggerSourceView<'fb>),
+    Dataset(FlowTriggerDatasetView<'fb>),
+}
+
+impl<'fb> FlowTriggerView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::FlowTrigger) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::FlowTrigger::FlowTriggerSchedule => {
+                Ok(Self::Schedule(FlowTriggerScheduleView::from(unsafe {
+                    fb::FlowTriggerSchedule::init_from_table(table)
+                })))
+            }
+            fb::FlowTrigger::FlowTriggerEvent => {
+                Ok(Self::Event(FlowTriggerEventView::from(unsafe {
+                    fb::FlowTriggerEvent::init_from_table(table)
+                })))
+            }
+            fb::FlowTrigger::FlowTriggerSource => {
+                Ok(Self::Source(FlowTriggerSourceView::from(unsafe {
+                    fb::FlowTriggerSource::init_from_table(table)
+                })))
+            }
+            fb::FlowTrigger::FlowTriggerDataset => {
+                Ok(Self::Dataset(FlowTriggerDatasetView::from(unsafe {
+                    fb::FlowTriggerDataset::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "FlowTrigger",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTrigger, FlatbuffersDecodeError> {
+        match self {
+            Self::Schedule(v) => v.to_owned().map(odf::flow::FlowTrigger::Schedule),
+            Self::Event(v) => v.to_owned().map(odf::flow::FlowTrigger::Event),
+            Self::Source(v) => v.to_owned().map(odf::flow::FlowTrigger::Source),
+            Self::Dataset(v) => v.to_owned().map(odf::flow::FlowTrigger::Dataset),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerDataset
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Dataset
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerDataset`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerDatasetView<'fb> {
+    proxy: fb::FlowTriggerDataset<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerDataset<'fb>> for FlowTriggerDatasetView<'fb> {
+    fn from(proxy: fb::FlowTriggerDataset<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerDatasetView<'fb> {
+    /// Selector that identifies which datasets can trigger this flow.
+    pub fn dataset(&self) -> Result<odf::dataset::DatasetSelector, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| odf::dataset::DatasetSelector::deserialize(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerDataset.dataset"))
+    }
+
+    /// Set of event bus event IDs that this trigger will react to
+    pub fn events(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .events()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerDataset.events"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerDataset, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerDataset::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerEvent
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Event
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerEvent`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerEventView<'fb> {
+    proxy: fb::FlowTriggerEvent<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerEvent<'fb>> for FlowTriggerEventView<'fb> {
+    fn from(proxy: fb::FlowTriggerEvent<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerEventView<'fb> {
+    /// Filters the event by type and fields.
+    pub fn events(&self) -> Result<EventFilterView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .events()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| EventFilterView::new(v, self.proxy.events_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerEvent.events"))
+    }
+
+    /// The trigger will fire upon first observed event. If another event arrives withing the `cooldown` interval the firing will be postponed until `cooldown` interval ends. I.e. trigger is guaranteed to fire, but may batch multiple events together into one flow run.
+    pub fn cooldown(&self) -> Result<Option<DurationString>, FlatbuffersDecodeError> {
+        self.proxy
+            .cooldown()
+            .map(|v| Ok(fb_to_duration(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerEvent.cooldown"))
+    }
+
+    /// If an event is observed a `cooldownMaxBatch` number of times during the `cooldown` interval it will fire the trigger without waiting for cooldown to finish.
+    pub fn cooldown_max_batch(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .cooldown_max_batch()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerEvent.cooldownMaxBatch"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerEvent, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerEvent::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerSchedule
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Schedule
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerSchedule`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerScheduleView<'fb> {
+    proxy: fb::FlowTriggerSchedule<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerSchedule<'fb>> for FlowTriggerScheduleView<'fb> {
+    fn from(proxy: fb::FlowTriggerSchedule<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerScheduleView<'fb> {
+    /// Cron5 expression defining the schedule e.g. `@daily` or `*/30 * * * *`.
+    pub fn cron(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .cron()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSchedule.cron"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerSchedule, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerSchedule::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerSource
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Source
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerSource`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerSourceView<'fb> {
+    proxy: fb::FlowTriggerSource<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerSource<'fb>> for FlowTriggerSourceView<'fb> {
+    fn from(proxy: fb::FlowTriggerSource<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerSourceView<'fb> {
+    /// Reference to the source resource that drives this trigger.
+    pub fn source(&self) -> Result<ResourceHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .source()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSource.source"))
+    }
+
+    /// Minimum number of new records to accumulate before triggering.
+    pub fn min_records_to_await(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .min_records_to_await()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSource.minRecordsToAwait"))
+    }
+
+    /// Maximum time to wait for `minRecordsToAwait` before triggering anyway e.g. `1h`.
+    pub fn max_await_interval(&self) -> Result<Option<DurationString>, FlatbuffersDecodeError> {
+        self.proxy
+            .max_await_interval()
+            .map(|v| Ok(fb_to_duration(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSource.maxAwaitInterval"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerSource, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerSource::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInput
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerInput`]
+#[derive(Debug, Clone, Copy)]
+pub enum FlowTriggerInputView<'fb> {
+    Schedule(FlowTriggerInputScheduleView<'fb>),
+    Event(FlowTriggerInputEventView<'fb>),
+    Source(FlowTriggerInputSourceView<'fb>),
+    Dataset(FlowTriggerInputDatasetView<'fb>),
+}
+
+impl<'fb> FlowTriggerInputView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::FlowTriggerInput) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::FlowTriggerInput::FlowTriggerInputSchedule => {
+                Ok(Self::Schedule(FlowTriggerInputScheduleView::from(unsafe {
+                    fb::FlowTriggerInputSchedule::init_from_table(table)
+                })))
+            }
+            fb::FlowTriggerInput::FlowTriggerInputEvent => {
+                Ok(Self::Event(FlowTriggerInputEventView::from(unsafe {
+                    fb::FlowTriggerInputEvent::init_from_table(table)
+                })))
+            }
+            fb::FlowTriggerInput::FlowTriggerInputSource => {
+                Ok(Self::Source(FlowTriggerInputSourceView::from(unsafe {
+                    fb::FlowTriggerInputSource::init_from_table(table)
+                })))
+            }
+            fb::FlowTriggerInput::FlowTriggerInputDataset => {
+                Ok(Self::Dataset(FlowTriggerInputDatasetView::from(unsafe {
+                    fb::FlowTriggerInputDataset::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "FlowTriggerInput",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerInput, FlatbuffersDecodeError> {
+        match self {
+            Self::Schedule(v) => v.to_owned().map(odf::flow::FlowTriggerInput::Schedule),
+            Self::Event(v) => v.to_owned().map(odf::flow::FlowTriggerInput::Event),
+            Self::Source(v) => v.to_owned().map(odf::flow::FlowTriggerInput::Source),
+            Self::Dataset(v) => v.to_owned().map(odf::flow::FlowTriggerInput::Dataset),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputDataset
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Dataset
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerInputDataset`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerInputDatasetView<'fb> {
+    proxy: fb::FlowTriggerInputDataset<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerInputDataset<'fb>> for FlowTriggerInputDatasetView<'fb> {
+    fn from(proxy: fb::FlowTriggerInputDataset<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerInputDatasetView<'fb> {
+    /// Selector that identifies which datasets can trigger this flow.
+    pub fn dataset(&self) -> Result<odf::dataset::DatasetSelector, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| odf::dataset::DatasetSelector::deserialize(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputDataset.dataset"))
+    }
+
+    /// Set of event bus event IDs that this trigger will react to
+    pub fn events(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .events()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputDataset.events"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerInputDataset, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerInputDataset::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputEvent
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Event
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerInputEvent`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerInputEventView<'fb> {
+    proxy: fb::FlowTriggerInputEvent<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerInputEvent<'fb>> for FlowTriggerInputEventView<'fb> {
+    fn from(proxy: fb::FlowTriggerInputEvent<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerInputEventView<'fb> {
+    /// Filters the event by type and fields.
+    pub fn events(&self) -> Result<EventFilterView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .events()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| EventFilterView::new(v, self.proxy.events_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputEvent.events"))
+    }
+
+    /// The trigger will fire upon first observed event. If another event arrives withing the `cooldown` interval the firing will be postponed until `cooldown` interval ends. I.e. trigger is guaranteed to fire, but may batch multiple events together into one flow run.
+    pub fn cooldown(&self) -> Result<Option<DurationString>, FlatbuffersDecodeError> {
+        self.proxy
+            .cooldown()
+            .map(|v| Ok(fb_to_duration(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputEvent.cooldown"))
+    }
+
+    /// If an event is observed a `cooldownMaxBatch` number of times during the `cooldown` interval it will fire the trigger without waiting for cooldown to finish.
+    pub fn cooldown_max_batch(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .cooldown_max_batch()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputEvent.cooldownMaxBatch"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerInputEvent, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerInputEvent::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputSchedule
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Schedule
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerInputSchedule`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerInputScheduleView<'fb> {
+    proxy: fb::FlowTriggerInputSchedule<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerInputSchedule<'fb>> for FlowTriggerInputScheduleView<'fb> {
+    fn from(proxy: fb::FlowTriggerInputSchedule<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerInputScheduleView<'fb> {
+    /// Cron5 expression defining the schedule e.g. `@daily` or `*/30 * * * *`.
+    pub fn cron(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .cron()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputSchedule.cron"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerInputSchedule, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerInputSchedule::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputSource
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Source
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::FlowTriggerInputSource`]
+#[derive(Debug, Clone, Copy)]
+pub struct FlowTriggerInputSourceView<'fb> {
+    proxy: fb::FlowTriggerInputSource<'fb>,
+}
+
+impl<'fb> From<fb::FlowTriggerInputSource<'fb>> for FlowTriggerInputSourceView<'fb> {
+    fn from(proxy: fb::FlowTriggerInputSource<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> FlowTriggerInputSourceView<'fb> {
+    /// Reference to the source resource that drives this trigger.
+    pub fn source(&self) -> Result<ResourceRefView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .source()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceRefView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputSource.source"))
+    }
+
+    /// Minimum number of new records to accumulate before triggering.
+    pub fn min_records_to_await(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .min_records_to_await()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputSource.minRecordsToAwait"))
+    }
+
+    /// Maximum time to wait for `minRecordsToAwait` before triggering anyway e.g. `1h`.
+    pub fn max_await_interval(&self) -> Result<Option<DurationString>, FlatbuffersDecodeError> {
+        self.proxy
+            .max_await_interval()
+            .map(|v| Ok(fb_to_duration(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputSource.maxAwaitInterval"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::FlowTriggerInputSource, FlatbuffersDecodeError> {
+        odf::flow::FlowTriggerInputSource::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngestParams
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngestParams
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngestParams`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngestParamsView<'fb> {
+    proxy: fb::IngestParams<'fb>,
+}
+
+impl<'fb> From<fb::IngestParams<'fb>> for IngestParamsView<'fb> {
+    fn from(proxy: fb::IngestParams<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngestParamsView<'fb> {
+    /// Target number of records to ingest per data slice.
+    pub fn target_slice_records(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .target_slice_records()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngestParams.targetSliceRecords"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngestParams, FlatbuffersDecodeError> {
+        odf::source::IngestParams::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Ingress
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::Ingress`]
+#[derive(Debug, Clone, Copy)]
+pub enum IngressView<'fb> {
+    Url(IngressUrlView<'fb>),
+    FilesGlob(IngressFilesGlobView<'fb>),
+    Container(IngressContainerView<'fb>),
+    Mqtt(IngressMqttView<'fb>),
+    EvmLogs(IngressEvmLogsView<'fb>),
+    RestEndpoint(IngressRestEndpointView<'fb>),
+}
+
+impl<'fb> IngressView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::Ingress) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::Ingress::IngressUrl => Ok(Self::Url(IngressUrlView::from(unsafe {
+                fb::IngressUrl::init_from_table(table)
+            }))),
+            fb::Ingress::IngressFilesGlob => {
+                Ok(Self::FilesGlob(IngressFilesGlobView::from(unsafe {
+                    fb::IngressFilesGlob::init_from_table(table)
+                })))
+            }
+            fb::Ingress::IngressContainer => {
+                Ok(Self::Container(IngressContainerView::from(unsafe {
+                    fb::IngressContainer::init_from_table(table)
+                })))
+            }
+            fb::Ingress::IngressMqtt => Ok(Self::Mqtt(IngressMqttView::from(unsafe {
+                fb::IngressMqtt::init_from_table(table)
+            }))),
+            fb::Ingress::IngressEvmLogs => Ok(Self::EvmLogs(IngressEvmLogsView::from(unsafe {
+                fb::IngressEvmLogs::init_from_table(table)
+            }))),
+            fb::Ingress::IngressRestEndpoint => {
+                Ok(Self::RestEndpoint(IngressRestEndpointView::from(unsafe {
+                    fb::IngressRestEndpoint::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "Ingress",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::Ingress, FlatbuffersDecodeError> {
+        match self {
+            Self::Url(v) => v.to_owned().map(odf::source::Ingress::Url),
+            Self::FilesGlob(v) => v.to_owned().map(odf::source::Ingress::FilesGlob),
+            Self::Container(v) => v.to_owned().map(odf::source::Ingress::Container),
+            Self::Mqtt(v) => v.to_owned().map(odf::source::Ingress::Mqtt),
+            Self::EvmLogs(v) => v.to_owned().map(odf::source::Ingress::EvmLogs),
+            Self::RestEndpoint(v) => v.to_owned().map(odf::source::Ingress::RestEndpoint),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressContainer
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Container
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressContainer`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngressContainerView<'fb> {
+    proxy: fb::IngressContainer<'fb>,
+}
+
+impl<'fb> From<fb::IngressContainer<'fb>> for IngressContainerView<'fb> {
+    fn from(proxy: fb::IngressContainer<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngressContainerView<'fb> {
+    /// Image name and and an optional tag.
+    pub fn image(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .image()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.image"))
+    }
+
+    /// Specifies the entrypoint. Not executed within a shell. The default OCI image's ENTRYPOINT is used if this is not provided.
+    pub fn command(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .command()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.command"))
+    }
+
+    /// Arguments to the entrypoint. The OCI image's CMD is used if this is not provided.
+    pub fn args(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .args()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.args"))
+    }
+
+    /// Environment variables to propagate into or set in the container.
+    pub fn env(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<EnvVarView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .env()
+            .map(|v| Ok(v.iter().map(|i| Ok(EnvVarView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.env"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressContainer, FlatbuffersDecodeError> {
+        odf::source::IngressContainer::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressEvmLogs
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/EvmLogs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressEvmLogs`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngressEvmLogsView<'fb> {
+    proxy: fb::IngressEvmLogs<'fb>,
+}
+
+impl<'fb> From<fb::IngressEvmLogs<'fb>> for IngressEvmLogsView<'fb> {
+    fn from(proxy: fb::IngressEvmLogs<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngressEvmLogsView<'fb> {
+    /// Identifier of the chain to scan logs from. This parameter may be used for RPC endpoint lookup as well as asserting that provided `nodeUrl` corresponds to the expected chain.
+    pub fn chain_id(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .chain_id()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.chainId"))
+    }
+
+    /// Url of the node.
+    pub fn node_url(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .node_url()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.nodeUrl"))
+    }
+
+    /// An SQL WHERE clause that can be used to pre-filter the logs before fetching them from the ETH node.
+    pub fn filter(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .filter()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.filter"))
+    }
+
+    /// Solidity log event signature to use for decoding. Using this field adds `event` to the output containing decoded log as JSON.
+    pub fn signature(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .signature()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.signature"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressEvmLogs, FlatbuffersDecodeError> {
+        odf::source::IngressEvmLogs::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressFilesGlob
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/FilesGlob
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressFilesGlob`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngressFilesGlobView<'fb> {
+    proxy: fb::IngressFilesGlob<'fb>,
+}
+
+impl<'fb> From<fb::IngressFilesGlob<'fb>> for IngressFilesGlobView<'fb> {
+    fn from(proxy: fb::IngressFilesGlob<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngressFilesGlobView<'fb> {
+    /// Path with a glob pattern.
+    pub fn path(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .path()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.path"))
+    }
+
+    /// Describes how event time is extracted from the source metadata.
+    pub fn event_time(&self) -> Result<Option<EventTimeSourceView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .event_time()
+            .map(|v| EventTimeSourceView::new(v, self.proxy.event_time_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.eventTime"))
+    }
+
+    /// Describes the caching settings used for this source.
+    pub fn cache(&self) -> Result<Option<SourceCachingView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .cache()
+            .map(|v| SourceCachingView::new(v, self.proxy.cache_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.cache"))
+    }
+
+    /// Specifies how input files should be ordered before ingestion.
+    /// Order is important as every file will be processed individually
+    /// and will advance the dataset's watermark.
+    pub fn order(&self) -> Result<Option<odf::source::SourceOrdering>, FlatbuffersDecodeError> {
+        self.proxy
+            .order()
+            .map(|v| odf::source::SourceOrdering::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.order"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressFilesGlob, FlatbuffersDecodeError> {
+        odf::source::IngressFilesGlob::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressMqtt
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Mqtt
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressMqtt`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngressMqttView<'fb> {
+    proxy: fb::IngressMqtt<'fb>,
+}
+
+impl<'fb> From<fb::IngressMqtt<'fb>> for IngressMqttView<'fb> {
+    fn from(proxy: fb::IngressMqtt<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngressMqttView<'fb> {
+    /// Hostname of the MQTT broker.
+    pub fn host(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .host()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.host"))
+    }
+
+    /// Port of the MQTT broker.
+    pub fn port(&self) -> Result<i32, FlatbuffersDecodeError> {
+        Ok(self.proxy.port()).map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.port"))
+    }
+
+    /// Username to use for auth with the broker.
+    pub fn username(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .username()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.username"))
+    }
+
+    /// Password to use for auth with the broker (can be templated).
+    pub fn password(
+        &self,
+    ) -> Result<Option<odf::dtos::Sensitive<&'fb str>>, FlatbuffersDecodeError> {
+        self.proxy
+            .password()
+            .map(|v| Ok(v).map(odf::dtos::Sensitive::new))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.password"))
+    }
+
+    /// List of topic subscription parameters.
+    pub fn topics(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<MqttTopicSubscriptionView<'fb>, FlatbuffersDecodeError>>
+        + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .topics()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(MqttTopicSubscriptionView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.topics"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressMqtt, FlatbuffersDecodeError> {
+        odf::source::IngressMqtt::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressRestEndpoint
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/RestEndpoint
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressRestEndpoint`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngressRestEndpointView<'fb> {
+    proxy: fb::IngressRestEndpoint<'fb>,
+}
+
+impl<'fb> From<fb::IngressRestEndpoint<'fb>> for IngressRestEndpointView<'fb> {
+    fn from(proxy: fb::IngressRestEndpoint<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngressRestEndpointView<'fb> {
+    /// Buffer configuration for holding records until they are ingested.
+    pub fn buffer(&self) -> Result<Option<IngressBufferView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .buffer()
+            .map(|v| IngressBufferView::new(v, self.proxy.buffer_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressRestEndpoint.buffer"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressRestEndpoint, FlatbuffersDecodeError> {
+        odf::source::IngressRestEndpoint::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressUrl
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Url
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressUrl`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngressUrlView<'fb> {
+    proxy: fb::IngressUrl<'fb>,
+}
+
+impl<'fb> From<fb::IngressUrl<'fb>> for IngressUrlView<'fb> {
+    fn from(proxy: fb::IngressUrl<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngressUrlView<'fb> {
+    /// URL of the data source
+    pub fn url(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .url()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.url"))
+    }
+
+    /// Describes how event time is extracted from the source metadata.
+    pub fn event_time(&self) -> Result<Option<EventTimeSourceView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .event_time()
+            .map(|v| EventTimeSourceView::new(v, self.proxy.event_time_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.eventTime"))
+    }
+
+    /// Describes the caching settings used for this source.
+    pub fn cache(&self) -> Result<Option<SourceCachingView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .cache()
+            .map(|v| SourceCachingView::new(v, self.proxy.cache_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.cache"))
+    }
+
+    /// Headers to pass during the request (e.g. HTTP Authorization)
+    pub fn headers(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<RequestHeaderView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .headers()
+            .map(|v| Ok(v.iter().map(|i| Ok(RequestHeaderView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.headers"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressUrl, FlatbuffersDecodeError> {
+        odf::source::IngressUrl::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressBuffer
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressBuffer`]
+#[derive(Debug, Clone, Copy)]
+pub enum IngressBufferView<'fb> {
+    Memory(IngressBufferMemoryView<'fb>),
+}
+
+impl<'fb> IngressBufferView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::IngressBuffer) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::IngressBuffer::IngressBufferMemory => {
+                Ok(Self::Memory(IngressBufferMemoryView::from(unsafe {
+                    fb::IngressBufferMemory::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "IngressBuffer",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressBuffer, FlatbuffersDecodeError> {
+        match self {
+            Self::Memory(v) => v.to_owned().map(odf::source::IngressBuffer::Memory),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressBufferMemory
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer#/$defs/Memory
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::IngressBufferMemory`]
+#[derive(Debug, Clone, Copy)]
+pub struct IngressBufferMemoryView<'fb> {
+    proxy: fb::IngressBufferMemory<'fb>,
+}
+
+impl<'fb> From<fb::IngressBufferMemory<'fb>> for IngressBufferMemoryView<'fb> {
+    fn from(proxy: fb::IngressBufferMemory<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> IngressBufferMemoryView<'fb> {
+    /// Maximum number of records to hold in the buffer.
+    pub fn buffer_size(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .buffer_size()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressBufferMemory.bufferSize"))
+    }
+
+    /// Policy applied when the buffer is full.
+    pub fn overflow_policy(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .overflow_policy()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("IngressBufferMemory.overflowPolicy"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::IngressBufferMemory, FlatbuffersDecodeError> {
+        odf::source::IngressBufferMemory::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilter
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::LabelFilter`]
+#[derive(Debug, Clone, Copy)]
+pub enum LabelFilterView<'fb> {
+    And(LabelFilterAndView<'fb>),
+    Or(LabelFilterOrView<'fb>),
+    Not(LabelFilterNotView<'fb>),
+    Match(LabelFilterMatchView<'fb>),
+}
+
+impl<'fb> LabelFilterView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::LabelFilter) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::LabelFilter::LabelFilterAnd => Ok(Self::And(LabelFilterAndView::from(unsafe {
+                fb::LabelFilterAnd::init_from_table(table)
+            }))),
+            fb::LabelFilter::LabelFilterOr => Ok(Self::Or(LabelFilterOrView::from(unsafe {
+                fb::LabelFilterOr::init_from_table(table)
+            }))),
+            fb::LabelFilter::LabelFilterNot => Ok(Self::Not(LabelFilterNotView::from(unsafe {
+                fb::LabelFilterNot::init_from_table(table)
+            }))),
+            fb::LabelFilter::LabelFilterMatch => {
+                Ok(Self::Match(LabelFilterMatchView::from(unsafe {
+                    fb::LabelFilterMatch::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "LabelFilter",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::LabelFilter, FlatbuffersDecodeError> {
+        match self {
+            Self::And(v) => v.to_owned().map(odf::resource::LabelFilter::And),
+            Self::Or(v) => v.to_owned().map(odf::resource::LabelFilter::Or),
+            Self::Not(v) => v.to_owned().map(odf::resource::LabelFilter::Not),
+            Self::Match(v) => v.to_owned().map(odf::resource::LabelFilter::Match),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterAnd
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/And
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::LabelFilterAnd`]
+#[derive(Debug, Clone, Copy)]
+pub struct LabelFilterAndView<'fb> {
+    proxy: fb::LabelFilterAnd<'fb>,
+}
+
+impl<'fb> From<fb::LabelFilterAnd<'fb>> for LabelFilterAndView<'fb> {
+    fn from(proxy: fb::LabelFilterAnd<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> LabelFilterAndView<'fb> {
+    /// Filters that all must match.
+    pub fn and(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<LabelFilterView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .and()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| LabelFilterView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("LabelFilterAnd.$and"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::LabelFilterAnd, FlatbuffersDecodeError> {
+        odf::resource::LabelFilterAnd::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterMatch
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Match
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::LabelFilterMatch`]
+#[derive(Debug, Clone, Copy)]
+pub struct LabelFilterMatchView<'fb> {
+    proxy: fb::LabelFilterMatch<'fb>,
+}
+
+impl<'fb> From<fb::LabelFilterMatch<'fb>> for LabelFilterMatchView<'fb> {
+    fn from(proxy: fb::LabelFilterMatch<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> LabelFilterMatchView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<
+        Item = Result<(&'fb str, FilterConditionView<'fb>), FlatbuffersDecodeError>,
+    > + 'fb {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(FilterConditionView::from(v)))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("LabelFilterMatch.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::LabelFilterMatch, FlatbuffersDecodeError> {
+        odf::resource::LabelFilterMatch::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterNot
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Not
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::LabelFilterNot`]
+#[derive(Debug, Clone, Copy)]
+pub struct LabelFilterNotView<'fb> {
+    proxy: fb::LabelFilterNot<'fb>,
+}
+
+impl<'fb> From<fb::LabelFilterNot<'fb>> for LabelFilterNotView<'fb> {
+    fn from(proxy: fb::LabelFilterNot<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> LabelFilterNotView<'fb> {
+    /// Filter to negate.
+    pub fn not(&self) -> Result<LabelFilterView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .not()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| LabelFilterView::new(v, self.proxy.not_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("LabelFilterNot.$not"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::LabelFilterNot, FlatbuffersDecodeError> {
+        odf::resource::LabelFilterNot::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterOr
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Or
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::LabelFilterOr`]
+#[derive(Debug, Clone, Copy)]
+pub struct LabelFilterOrView<'fb> {
+    proxy: fb::LabelFilterOr<'fb>,
+}
+
+impl<'fb> From<fb::LabelFilterOr<'fb>> for LabelFilterOrView<'fb> {
+    fn from(proxy: fb::LabelFilterOr<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> LabelFilterOrView<'fb> {
+    /// Filters of which at least one must match.
+    pub fn or(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<LabelFilterView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .or()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| LabelFilterView::new(v, i.value_type()))
+                }))
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("LabelFilterOr.$or"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::LabelFilterOr, FlatbuffersDecodeError> {
+        odf::resource::LabelFilterOr::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategy
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::MergeStrategy`]
+#[derive(Debug, Clone, Copy)]
+pub enum MergeStrategyView<'fb> {
+    Append(MergeStrategyAppendView<'fb>),
+    Ledger(MergeStrategyLedgerView<'fb>),
+    Snapshot(MergeStrategySnapshotView<'fb>),
+    ChangelogStream(MergeStrategyChangelogStreamView<'fb>),
+    UpsertStream(MergeStrategyUpsertStreamView<'fb>),
+}
+
+impl<'fb> MergeStrategyView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::MergeStrategy) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::MergeStrategy::MergeStrategyAppend => {
+                Ok(Self::Append(MergeStrategyAppendView::from(unsafe {
+                    fb::MergeStrategyAppend::init_from_table(table)
+                })))
+            }
+            fb::MergeStrategy::MergeStrategyLedger => {
+                Ok(Self::Ledger(MergeStrategyLedgerView::from(unsafe {
+                    fb::MergeStrategyLedger::init_from_table(table)
+                })))
+            }
+            fb::MergeStrategy::MergeStrategySnapshot => {
+                Ok(Self::Snapshot(MergeStrategySnapshotView::from(unsafe {
+                    fb::MergeStrategySnapshot::init_from_table(table)
+                })))
+            }
+            fb::MergeStrategy::MergeStrategyChangelogStream => Ok(Self::ChangelogStream(
+                MergeStrategyChangelogStreamView::from(unsafe {
+                    fb::MergeStrategyChangelogStream::init_from_table(table)
+                }),
+            )),
+            fb::MergeStrategy::MergeStrategyUpsertStream => {
+                Ok(Self::UpsertStream(MergeStrategyUpsertStreamView::from(
+                    unsafe { fb::MergeStrategyUpsertStream::init_from_table(table) },
+                )))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "MergeStrategy",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::MergeStrategy, FlatbuffersDecodeError> {
+        match self {
+            Self::Append(v) => v.to_owned().map(odf::source::MergeStrategy::Append),
+            Self::Ledger(v) => v.to_owned().map(odf::source::MergeStrategy::Ledger),
+            Self::Snapshot(v) => v.to_owned().map(odf::source::MergeStrategy::Snapshot),
+            Self::ChangelogStream(v) => v
+                .to_owned()
+                .map(odf::source::MergeStrategy::ChangelogStream),
+            Self::UpsertStream(v) => v.to_owned().map(odf::source::MergeStrategy::UpsertStream),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyAppend
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Append
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::MergeStrategyAppend`]
+#[derive(Debug, Clone, Copy)]
+pub struct MergeStrategyAppendView<'fb> {
+    proxy: fb::MergeStrategyAppend<'fb>,
+}
+
+impl<'fb> From<fb::MergeStrategyAppend<'fb>> for MergeStrategyAppendView<'fb> {
+    fn from(proxy: fb::MergeStrategyAppend<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> MergeStrategyAppendView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::MergeStrategyAppend, FlatbuffersDecodeError> {
+        odf::source::MergeStrategyAppend::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyChangelogStream
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/ChangelogStream
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::MergeStrategyChangelogStream`]
+#[derive(Debug, Clone, Copy)]
+pub struct MergeStrategyChangelogStreamView<'fb> {
+    proxy: fb::MergeStrategyChangelogStream<'fb>,
+}
+
+impl<'fb> From<fb::MergeStrategyChangelogStream<'fb>> for MergeStrategyChangelogStreamView<'fb> {
+    fn from(proxy: fb::MergeStrategyChangelogStream<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> MergeStrategyChangelogStreamView<'fb> {
+    /// Names of the columns that uniquely identify the record throughout its lifetime
+    pub fn primary_key(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .primary_key()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(i))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("MergeStrategyChangelogStream.primaryKey"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::source::MergeStrategyChangelogStream, FlatbuffersDecodeError> {
+        odf::source::MergeStrategyChangelogStream::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyLedger
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Ledger
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::MergeStrategyLedger`]
+#[derive(Debug, Clone, Copy)]
+pub struct MergeStrategyLedgerView<'fb> {
+    proxy: fb::MergeStrategyLedger<'fb>,
+}
+
+impl<'fb> From<fb::MergeStrategyLedger<'fb>> for MergeStrategyLedgerView<'fb> {
+    fn from(proxy: fb::MergeStrategyLedger<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> MergeStrategyLedgerView<'fb> {
+    /// Names of the columns that uniquely identify the record throughout its lifetime
+    pub fn primary_key(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .primary_key()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(i))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("MergeStrategyLedger.primaryKey"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::MergeStrategyLedger, FlatbuffersDecodeError> {
+        odf::source::MergeStrategyLedger::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategySnapshot
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Snapshot
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::MergeStrategySnapshot`]
+#[derive(Debug, Clone, Copy)]
+pub struct MergeStrategySnapshotView<'fb> {
+    proxy: fb::MergeStrategySnapshot<'fb>,
+}
+
+impl<'fb> From<fb::MergeStrategySnapshot<'fb>> for MergeStrategySnapshotView<'fb> {
+    fn from(proxy: fb::MergeStrategySnapshot<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> MergeStrategySnapshotView<'fb> {
+    /// Names of the columns that uniquely identify the record throughout its lifetime.
+    pub fn primary_key(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .primary_key()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(i))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("MergeStrategySnapshot.primaryKey"))
+    }
+
+    /// Names of the columns to compared to determine if a row has changed between two snapshots.
+    pub fn compare_columns(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .compare_columns()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("MergeStrategySnapshot.compareColumns"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::MergeStrategySnapshot, FlatbuffersDecodeError> {
+        odf::source::MergeStrategySnapshot::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyUpsertStream
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/UpsertStream
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::MergeStrategyUpsertStream`]
+#[derive(Debug, Clone, Copy)]
+pub struct MergeStrategyUpsertStreamView<'fb> {
+    proxy: fb::MergeStrategyUpsertStream<'fb>,
+}
+
+impl<'fb> From<fb::MergeStrategyUpsertStream<'fb>> for MergeStrategyUpsertStreamView<'fb> {
+    fn from(proxy: fb::MergeStrategyUpsertStream<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> MergeStrategyUpsertStreamView<'fb> {
+    /// Names of the columns that uniquely identify the record throughout its lifetime
+    pub fn primary_key(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .primary_key()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(i))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("MergeStrategyUpsertStream.primaryKey"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::source::MergeStrategyUpsertStream, FlatbuffersDecodeError> {
+        odf::source::MergeStrategyUpsertStream::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MetadataBlock
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/MetadataBlock
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::MetadataBlock`]
+#[derive(Debug, Clone, Copy)]
+pub struct MetadataBlockView<'fb> {
+    proxy: fb::MetadataBlock<'fb>,
+}
+
+impl<'fb> From<fb::MetadataBlock<'fb>> for MetadataBlockView<'fb> {
+    fn from(proxy: fb::MetadataBlock<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> MetadataBlockView<'fb> {
+    /// System time when this block was written.
+    pub fn system_time(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        self.proxy
+            .system_time()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| fb_to_datetime(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.systemTime"))
+    }
+
+    /// Hash sum of the preceding block.
+    pub fn prev_block_hash(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_block_hash()
+            .map(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.prevBlockHash"))
+    }
+
+    /// Block sequence number, starting from zero at the seed block.
+    pub fn sequence_number(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.sequence_number())
+            .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.sequenceNumber"))
+    }
+
+    /// Event data.
+    pub fn event(&self) -> Result<MetadataEventView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .event()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| MetadataEventView::new(v, self.proxy.event_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.event"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::MetadataBlock, FlatbuffersDecodeError> {
+        odf::dataset::MetadataBlock::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MetadataEvent
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/MetadataEvent
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::MetadataEvent`]
+#[derive(Debug, Clone, Copy)]
+pub enum MetadataEventView<'fb> {
+    AddData(AddDataView<'fb>),
+    ExecuteTransform(ExecuteTransformView<'fb>),
+    Seed(SeedView<'fb>),
+    SetPollingSource(SetPollingSourceView<'fb>),
+    SetTransform(SetTransformView<'fb>),
+    SetVocab(SetVocabView<'fb>),
+    SetAttachments(SetAttachmentsView<'fb>),
+    SetInfo(SetInfoView<'fb>),
+    SetLicense(SetLicenseView<'fb>),
+    SetDataSchema(SetDataSchemaView<'fb>),
+    AddPushSource(AddPushSourceView<'fb>),
+    DisablePushSource(DisablePushSourceView<'fb>),
+    DisablePollingSource(DisablePollingSourceView<'fb>),
+    /// Variant that is not known to this version of the schemas
+    Unknown {
+        tag: u8,
+        table: Table<'fb>,
+    },
+}
+
+impl<'fb> MetadataEventView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::MetadataEvent) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::MetadataEvent::AddData => Ok(Self::AddData(AddDataView::from(unsafe {
+                fb::AddData::init_from_table(table)
+            }))),
+            fb::MetadataEvent::ExecuteTransform => {
+                Ok(Self::ExecuteTransform(ExecuteTransformView::from(unsafe {
+                    fb::ExecuteTransform::init_from_table(table)
+                })))
+            }
+            fb::MetadataEvent::Seed => Ok(Self::Seed(SeedView::from(unsafe {
+                fb::Seed::init_from_table(table)
+            }))),
+            fb::MetadataEvent::SetPollingSource => {
+                Ok(Self::SetPollingSource(SetPollingSourceView::from(unsafe {
+                    fb::SetPollingSource::init_from_table(table)
+                })))
+            }
+            fb::MetadataEvent::SetTransform => {
+                Ok(Self::SetTransform(SetTransformView::from(unsafe {
+                    fb::SetTransform::init_from_table(table)
+                })))
+            }
+            fb::MetadataEvent::SetVocab => Ok(Self::SetVocab(SetVocabView::from(unsafe {
+                fb::SetVocab::init_from_table(table)
+            }))),
+            fb::MetadataEvent::SetAttachments => {
+                Ok(Self::SetAttachments(SetAttachmentsView::from(unsafe {
+                    fb::SetAttachments::init_from_table(table)
+                })))
+            }
+            fb::MetadataEvent::SetInfo => Ok(Self::SetInfo(SetInfoView::from(unsafe {
+                fb::SetInfo::init_from_table(table)
+            }))),
+            fb::MetadataEvent::SetLicense => Ok(Self::SetLicense(SetLicenseView::from(unsafe {
+                fb::SetLicense::init_from_table(table)
+            }))),
+            fb::MetadataEvent::SetDataSchema => {
+                Ok(Self::SetDataSchema(SetDataSchemaView::from(unsafe {
+                    fb::SetDataSchema::init_from_table(table)
+                })))
+            }
+            fb::MetadataEvent::AddPushSource => {
+                Ok(Self::AddPushSource(AddPushSourceView::from(unsafe {
+                    fb::AddPushSource::init_from_table(table)
+                })))
+            }
+            fb::MetadataEvent::DisablePushSource => {
+                Ok(Self::DisablePushSource(DisablePushSourceView::from(
+                    unsafe { fb::DisablePushSource::init_from_table(table) },
+                )))
+            }
+            fb::MetadataEvent::DisablePollingSource => {
+                Ok(Self::DisablePollingSource(DisablePollingSourceView::from(
+                    unsafe { fb::DisablePollingSource::init_from_table(table) },
+                )))
+            }
+            fb::MetadataEvent::NONE => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "MetadataEvent",
+                    value: t.0,
+                },
+            )),
+            _ => Ok(Self::Unknown { tag: t.0, table }),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::MetadataEvent, FlatbuffersDecodeError> {
+        match self {
+            Self::AddData(v) => v.to_owned().map(odf::dataset::MetadataEvent::AddData),
+            Self::ExecuteTransform(v) => v
+                .to_owned()
+                .map(odf::dataset::MetadataEvent::ExecuteTransform),
+            Self::Seed(v) => v.to_owned().map(odf::dataset::MetadataEvent::Seed),
+            Self::SetPollingSource(v) => v
+                .to_owned()
+                .map(odf::dataset::MetadataEvent::SetPollingSource),
+            Self::SetTransform(v) => v.to_owned().map(odf::dataset::MetadataEvent::SetTransform),
+            Self::SetVocab(v) => v.to_owned().map(odf::dataset::MetadataEvent::SetVocab),
+            Self::SetAttachments(v) => v
+                .to_owned()
+                .map(odf::dataset::MetadataEvent::SetAttachments),
+            Self::SetInfo(v) => v.to_owned().map(odf::dataset::MetadataEvent::SetInfo),
+            Self::SetLicense(v) => v.to_owned().map(odf::dataset::MetadataEvent::SetLicense),
+            Self::SetDataSchema(v) => v.to_owned().map(odf::dataset::MetadataEvent::SetDataSchema),
+            Self::AddPushSource(v) => v.to_owned().map(odf::dataset::MetadataEvent::AddPushSource),
+            Self::DisablePushSource(v) => v
+                .to_owned()
+                .map(odf::dataset::MetadataEvent::DisablePushSource),
+            Self::DisablePollingSource(v) => v
+                .to_owned()
+                .map(odf::dataset::MetadataEvent::DisablePollingSource),
+            Self::Unknown { tag, table } => {
+                unknown_variant_from_table(*table, *tag).map(odf::dataset::MetadataEvent::Unknown)
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MqttTopicSubscription
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttTopicSubscription
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::MqttTopicSubscription`]
+#[derive(Debug, Clone, Copy)]
+pub struct MqttTopicSubscriptionView<'fb> {
+    proxy: fb::MqttTopicSubscription<'fb>,
+}
+
+impl<'fb> From<fb::MqttTopicSubscription<'fb>> for MqttTopicSubscriptionView<'fb> {
+    fn from(proxy: fb::MqttTopicSubscription<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> MqttTopicSubscriptionView<'fb> {
+    /// Name of the topic (may include patterns).
+    pub fn path(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .path()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("MqttTopicSubscription.path"))
+    }
+
+    /// Quality of service class.
+    pub fn qos(&self) -> Result<Option<odf::source::MqttQos>, FlatbuffersDecodeError> {
+        self.proxy
+            .qos()
+            .map(|v| odf::source::MqttQos::try_from(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("MqttTopicSubscription.qos"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::MqttTopicSubscription, FlatbuffersDecodeError> {
+        odf::source::MqttTopicSubscription::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectFileReference
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectFileReference
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::ObjectFileReference`]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectFileReferenceView<'fb> {
+    proxy: fb::ObjectFileReference<'fb>,
+}
+
+impl<'fb> From<fb::ObjectFileReference<'fb>> for ObjectFileReferenceView<'fb> {
+    fn from(proxy: fb::ObjectFileReference<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ObjectFileReferenceView<'fb> {
+    /// Type of the object.
+    pub fn object_type(&self) -> Result<odf::protocol::ObjectType, FlatbuffersDecodeError> {
+        odf::protocol::ObjectType::try_from(self.proxy.object_type())
+            .map_err(|e: FlatbuffersDecodeError| e.at("ObjectFileReference.objectType"))
+    }
+
+    /// Physical hash of the object file.
+    pub fn physical_hash(&self) -> Result<odf::Multihash, FlatbuffersDecodeError> {
+        self.proxy
+            .physical_hash()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::Multihash::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("ObjectFileReference.physicalHash"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::ObjectFileReference, FlatbuffersDecodeError> {
+        odf::protocol::ObjectFileReference::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectsBatch
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectsBatch
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::ObjectsBatch`]
+#[derive(Debug, Clone, Copy)]
+pub struct ObjectsBatchView<'fb> {
+    proxy: fb::ObjectsBatch<'fb>,
+}
+
+impl<'fb> From<fb::ObjectsBatch<'fb>> for ObjectsBatchView<'fb> {
+    fn from(proxy: fb::ObjectsBatch<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ObjectsBatchView<'fb> {
+    /// Number of objects included in the batch.
+    pub fn objects_count(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.objects_count())
+            .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.objectsCount"))
+    }
+
+    /// Type of objects in the batch.
+    pub fn object_type(&self) -> Result<odf::protocol::ObjectType, FlatbuffersDecodeError> {
+        odf::protocol::ObjectType::try_from(self.proxy.object_type())
+            .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.objectType"))
+    }
+
+    /// Payload media type.
+    pub fn media_type(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .media_type()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.mediaType"))
+    }
+
+    /// Payload encoding within the protocol.
+    pub fn encoding(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .encoding()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.encoding"))
+    }
+
+    /// Encoded payload data.
+    pub fn payload(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .payload()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.payload"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::ObjectsBatch, FlatbuffersDecodeError> {
+        odf::protocol::ObjectsBatch::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// OffsetInterval
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/OffsetInterval
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::OffsetInterval`]
+#[derive(Debug, Clone, Copy)]
+pub struct OffsetIntervalView<'fb> {
+    proxy: fb::OffsetInterval<'fb>,
+}
+
+impl<'fb> From<fb::OffsetInterval<'fb>> for OffsetIntervalView<'fb> {
+    fn from(proxy: fb::OffsetInterval<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> OffsetIntervalView<'fb> {
+    /// Start of the closed interval [start; end].
+    pub fn start(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.start()).map_err(|e: FlatbuffersDecodeError| e.at("OffsetInterval.start"))
+    }
+
+    /// End of the closed interval [start; end].
+    pub fn end(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.end()).map_err(|e: FlatbuffersDecodeError| e.at("OffsetInterval.end"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::OffsetInterval, FlatbuffersDecodeError> {
+        odf::dataset::OffsetInterval::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeRef
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::PersistentVolumeRef`]
+#[derive(Debug, Clone, Copy)]
+pub struct PersistentVolumeRefView<'fb> {
+    proxy: fb::PersistentVolumeRef<'fb>,
+}
+
+impl<'fb> From<fb::PersistentVolumeRef<'fb>> for PersistentVolumeRefView<'fb> {
+    fn from(proxy: fb::PersistentVolumeRef<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> PersistentVolumeRefView<'fb> {
+    /// Reference to an account that owns the `PersistentVolume`.
+    pub fn account(&self) -> Result<Option<AccountRefView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .account()
+            .map(|v| Ok(AccountRefView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeRef.account"))
+    }
+
+    /// ID of the resource.
+    pub fn id(&self) -> Result<Option<odf::resource::ResourceID>, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .map(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeRef.id"))
+    }
+
+    /// Name of the resource.
+    pub fn name(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeRef.name"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::storage::PersistentVolumeRef, FlatbuffersDecodeError> {
+        odf::storage::PersistentVolumeRef::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpec
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::PersistentVolumeSpec`]
+#[derive(Debug, Clone, Copy)]
+pub enum PersistentVolumeSpecView<'fb> {
+    S3(PersistentVolumeSpecS3View<'fb>),
+}
+
+impl<'fb> PersistentVolumeSpecView<'fb> {
+    pub fn new(
+        table: Table<'fb>,
+        t: fb::PersistentVolumeSpec,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::PersistentVolumeSpec::PersistentVolumeSpecS3 => {
+                Ok(Self::S3(PersistentVolumeSpecS3View::from(unsafe {
+                    fb::PersistentVolumeSpecS3::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "PersistentVolumeSpec",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::storage::PersistentVolumeSpec, FlatbuffersDecodeError> {
+        match self {
+            Self::S3(v) => v.to_owned().map(odf::storage::PersistentVolumeSpec::S3),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpecS3
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec#/$defs/S3
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::PersistentVolumeSpecS3`]
+#[derive(Debug, Clone, Copy)]
+pub struct PersistentVolumeSpecS3View<'fb> {
+    proxy: fb::PersistentVolumeSpecS3<'fb>,
+}
+
+impl<'fb> From<fb::PersistentVolumeSpecS3<'fb>> for PersistentVolumeSpecS3View<'fb> {
+    fn from(proxy: fb::PersistentVolumeSpecS3<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> PersistentVolumeSpecS3View<'fb> {
+    /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
+    pub fn endpoint(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .endpoint()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.endpoint"))
+    }
+
+    /// AWS region where the bucket is located e.g. `us-west-2`.
+    pub fn region(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .region()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.region"))
+    }
+
+    /// Name of the S3 bucket.
+    pub fn bucket(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .bucket()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.bucket"))
+    }
+
+    /// Optional path prefix within the bucket.
+    pub fn prefix(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .prefix()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.prefix"))
+    }
+
+    /// Storage capacity allocation.
+    pub fn capacity(&self) -> Result<Option<VolumeCapacityView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .capacity()
+            .map(|v| Ok(VolumeCapacityView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.capacity"))
+    }
+
+    /// Access credentials for the bucket.
+    pub fn credentials(&self) -> Result<Option<AwsCredentialsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .credentials()
+            .map(|v| Ok(AwsCredentialsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.credentials"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::storage::PersistentVolumeSpecS3, FlatbuffersDecodeError> {
+        odf::storage::PersistentVolumeSpecS3::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpecInput
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::PersistentVolumeSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub enum PersistentVolumeSpecInputView<'fb> {
+    S3(PersistentVolumeSpecInputS3View<'fb>),
+}
+
+impl<'fb> PersistentVolumeSpecInputView<'fb> {
+    pub fn new(
+        table: Table<'fb>,
+        t: fb::PersistentVolumeSpecInput,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::PersistentVolumeSpecInput::PersistentVolumeSpecInputS3 => {
+                Ok(Self::S3(PersistentVolumeSpecInputS3View::from(unsafe {
+                    fb::PersistentVolumeSpecInputS3::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "PersistentVolumeSpecInput",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::storage::PersistentVolumeSpecInput, FlatbuffersDecodeError> {
+        match self {
+            Self::S3(v) => v
+                .to_owned()
+                .map(odf::storage::PersistentVolumeSpecInput::S3),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpecInputS3
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput#/$defs/S3
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::PersistentVolumeSpecInputS3`]
+#[derive(Debug, Clone, Copy)]
+pub struct PersistentVolumeSpecInputS3View<'fb> {
+    proxy: fb::PersistentVolumeSpecInputS3<'fb>,
+}
+
+impl<'fb> From<fb::PersistentVolumeSpecInputS3<'fb>> for PersistentVolumeSpecInputS3View<'fb> {
+    fn from(proxy: fb::PersistentVolumeSpecInputS3<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> PersistentVolumeSpecInputS3View<'fb> {
+    /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
+    pub fn endpoint(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .endpoint()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.endpoint"))
+    }
+
+    /// AWS region where the bucket is located e.g. `us-west-2`.
+    pub fn region(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .region()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.region"))
+    }
+
+    /// Name of the S3 bucket.
+    pub fn bucket(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .bucket()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.bucket"))
+    }
+
+    /// Optional path prefix within the bucket.
+    pub fn prefix(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .prefix()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.prefix"))
+    }
+
+    /// Storage capacity allocation.
+    pub fn capacity(&self) -> Result<Option<VolumeCapacityView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .capacity()
+            .map(|v| Ok(VolumeCapacityView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.capacity"))
+    }
+
+    /// Access credentials for the bucket.
+    pub fn credentials(
+        &self,
+    ) -> Result<Option<AwsCredentialsInputView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .credentials()
+            .map(|v| Ok(AwsCredentialsInputView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.credentials"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::storage::PersistentVolumeSpecInputS3, FlatbuffersDecodeError> {
+        odf::storage::PersistentVolumeSpecInputS3::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PrepStep
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::PrepStep`]
+#[derive(Debug, Clone, Copy)]
+pub enum PrepStepView<'fb> {
+    Decompress(PrepStepDecompressView<'fb>),
+    Pipe(PrepStepPipeView<'fb>),
+}
+
+impl<'fb> PrepStepView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::PrepStep) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::PrepStep::PrepStepDecompress => {
+                Ok(Self::Decompress(PrepStepDecompressView::from(unsafe {
+                    fb::PrepStepDecompress::init_from_table(table)
+                })))
+            }
+            fb::PrepStep::PrepStepPipe => Ok(Self::Pipe(PrepStepPipeView::from(unsafe {
+                fb::PrepStepPipe::init_from_table(table)
+            }))),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "PrepStep",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::PrepStep, FlatbuffersDecodeError> {
+        match self {
+            Self::Decompress(v) => v.to_owned().map(odf::source::PrepStep::Decompress),
+            Self::Pipe(v) => v.to_owned().map(odf::source::PrepStep::Pipe),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PrepStepDecompress
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Decompress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::PrepStepDecompress`]
+#[derive(Debug, Clone, Copy)]
+pub struct PrepStepDecompressView<'fb> {
+    proxy: fb::PrepStepDecompress<'fb>,
+}
+
+impl<'fb> From<fb::PrepStepDecompress<'fb>> for PrepStepDecompressView<'fb> {
+    fn from(proxy: fb::PrepStepDecompress<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> PrepStepDecompressView<'fb> {
+    /// Name of a compression algorithm used on data.
+    pub fn format(&self) -> Result<odf::source::CompressionFormat, FlatbuffersDecodeError> {
+        odf::source::CompressionFormat::try_from(self.proxy.format())
+            .map_err(|e: FlatbuffersDecodeError| e.at("PrepStepDecompress.format"))
+    }
+
+    /// Path to a data file within a multi-file archive. Can contain glob patterns.
+    pub fn sub_path(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .sub_path()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PrepStepDecompress.subPath"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::PrepStepDecompress, FlatbuffersDecodeError> {
+        odf::source::PrepStepDecompress::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PrepStepPipe
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Pipe
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::PrepStepPipe`]
+#[derive(Debug, Clone, Copy)]
+pub struct PrepStepPipeView<'fb> {
+    proxy: fb::PrepStepPipe<'fb>,
+}
+
+impl<'fb> From<fb::PrepStepPipe<'fb>> for PrepStepPipeView<'fb> {
+    fn from(proxy: fb::PrepStepPipe<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> PrepStepPipeView<'fb> {
+    /// Command to execute and its arguments.
+    pub fn command(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .command()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(i))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("PrepStepPipe.command"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::PrepStepPipe, FlatbuffersDecodeError> {
+        odf::source::PrepStepPipe::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ProjectionSpec
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ProjectionSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::ProjectionSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectionSpecView<'fb> {
+    proxy: fb::ProjectionSpec<'fb>,
+}
+
+impl<'fb> From<fb::ProjectionSpec<'fb>> for ProjectionSpecView<'fb> {
+    fn from(proxy: fb::ProjectionSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ProjectionSpecView<'fb> {
+    /// Datasets that will be used as sources.
+    pub fn inputs(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<TransformInputView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .inputs()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(TransformInputView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpec.inputs"))
+    }
+
+    /// Transformation that will be applied to produce new data.
+    pub fn project(&self) -> Result<TransformView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .project()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| TransformView::new(v, self.proxy.project_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpec.project"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::ProjectionSpec, FlatbuffersDecodeError> {
+        odf::dataset::ProjectionSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ProjectionSpecInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ProjectionSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::ProjectionSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectionSpecInputView<'fb> {
+    proxy: fb::ProjectionSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::ProjectionSpecInput<'fb>> for ProjectionSpecInputView<'fb> {
+    fn from(proxy: fb::ProjectionSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ProjectionSpecInputView<'fb> {
+    /// Datasets that will be used as sources.
+    pub fn inputs(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<TransformInputView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .inputs()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(TransformInputView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpecInput.inputs"))
+    }
+
+    /// Transformation that will be applied to produce new data.
+    pub fn project(&self) -> Result<TransformView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .project()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| TransformView::new(v, self.proxy.project_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpecInput.project"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::ProjectionSpecInput, FlatbuffersDecodeError> {
+        odf::dataset::ProjectionSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PullObjectTransferStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PullObjectTransferStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::PullObjectTransferStrategy`]
+#[derive(Debug, Clone, Copy)]
+pub struct PullObjectTransferStrategyView<'fb> {
+    proxy: fb::PullObjectTransferStrategy<'fb>,
+}
+
+impl<'fb> From<fb::PullObjectTransferStrategy<'fb>> for PullObjectTransferStrategyView<'fb> {
+    fn from(proxy: fb::PullObjectTransferStrategy<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> PullObjectTransferStrategyView<'fb> {
+    /// Object file to download.
+    pub fn object_file(&self) -> Result<ObjectFileReferenceView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .object_file()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ObjectFileReferenceView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("PullObjectTransferStrategy.objectFile"))
+    }
+
+    /// Method of downloading the object file.
+    pub fn pull_strategy(
+        &self,
+    ) -> Result<odf::protocol::ObjectPullStrategy, FlatbuffersDecodeError> {
+        odf::protocol::ObjectPullStrategy::try_from(self.proxy.pull_strategy())
+            .map_err(|e: FlatbuffersDecodeError| e.at("PullObjectTransferStrategy.pullStrategy"))
+    }
+
+    /// Location to download the object file from.
+    pub fn download_from(&self) -> Result<TransferUrlView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .download_from()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(TransferUrlView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("PullObjectTransferStrategy.downloadFrom"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::PullObjectTransferStrategy, FlatbuffersDecodeError> {
+        odf::protocol::PullObjectTransferStrategy::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PushObjectTransferStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PushObjectTransferStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::PushObjectTransferStrategy`]
+#[derive(Debug, Clone, Copy)]
+pub struct PushObjectTransferStrategyView<'fb> {
+    proxy: fb::PushObjectTransferStrategy<'fb>,
+}
+
+impl<'fb> From<fb::PushObjectTransferStrategy<'fb>> for PushObjectTransferStrategyView<'fb> {
+    fn from(proxy: fb::PushObjectTransferStrategy<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> PushObjectTransferStrategyView<'fb> {
+    /// Object file to upload.
+    pub fn object_file(&self) -> Result<ObjectFileReferenceView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .object_file()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ObjectFileReferenceView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("PushObjectTransferStrategy.objectFile"))
+    }
+
+    /// Method of uploading the object file.
+    pub fn push_strategy(
+        &self,
+    ) -> Result<odf::protocol::ObjectPushStrategy, FlatbuffersDecodeError> {
+        odf::protocol::ObjectPushStrategy::try_from(self.proxy.push_strategy())
+            .map_err(|e: FlatbuffersDecodeError| e.at("PushObjectTransferStrategy.pushStrategy"))
+    }
+
+    /// Location to upload the object file to, absent when the upload is skipped.
+    pub fn upload_to(&self) -> Result<Option<TransferUrlView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .upload_to()
+            .map(|v| Ok(TransferUrlView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("PushObjectTransferStrategy.uploadTo"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::PushObjectTransferStrategy, FlatbuffersDecodeError> {
+        odf::protocol::PushObjectTransferStrategy::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryRequest
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::RawQueryRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct RawQueryRequestView<'fb> {
+    proxy: fb::RawQueryRequest<'fb>,
+}
+
+impl<'fb> From<fb::RawQueryRequest<'fb>> for RawQueryRequestView<'fb> {
+    fn from(proxy: fb::RawQueryRequest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RawQueryRequestView<'fb> {
+    /// Paths to input data files to perform query over. Must all have identical schema.
+    pub fn input_data_paths(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb Path, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .input_data_paths()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(Path::new(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryRequest.inputDataPaths"))
+    }
+
+    /// Transformation that will be applied to produce new data.
+    pub fn transform(&self) -> Result<TransformView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .transform()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| TransformView::new(v, self.proxy.transform_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryRequest.transform"))
+    }
+
+    /// Path where query result will be written.
+    pub fn output_data_path(&self) -> Result<&'fb Path, FlatbuffersDecodeError> {
+        self.proxy
+            .output_data_path()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(Path::new(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryRequest.outputDataPath"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::engine::RawQueryRequest, FlatbuffersDecodeError> {
+        odf::engine::RawQueryRequest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponse
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::RawQueryResponse`]
+#[derive(Debug, Clone, Copy)]
+pub enum RawQueryResponseView<'fb> {
+    Progress(RawQueryResponseProgressView<'fb>),
+    Success(RawQueryResponseSuccessView<'fb>),
+    InvalidQuery(RawQueryResponseInvalidQueryView<'fb>),
+    InternalError(RawQueryResponseInternalErrorView<'fb>),
+}
+
+impl<'fb> RawQueryResponseView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::RawQueryResponse) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::RawQueryResponse::RawQueryResponseProgress => {
+                Ok(Self::Progress(RawQueryResponseProgressView::from(unsafe {
+                    fb::RawQueryResponseProgress::init_from_table(table)
+                })))
+            }
+            fb::RawQueryResponse::RawQueryResponseSuccess => {
+                Ok(Self::Success(RawQueryResponseSuccessView::from(unsafe {
+                    fb::RawQueryResponseSuccess::init_from_table(table)
+                })))
+            }
+            fb::RawQueryResponse::RawQueryResponseInvalidQuery => {
+                Ok(Self::InvalidQuery(RawQueryResponseInvalidQueryView::from(
+                    unsafe { fb::RawQueryResponseInvalidQuery::init_from_table(table) },
+                )))
+            }
+            fb::RawQueryResponse::RawQueryResponseInternalError => Ok(Self::InternalError(
+                RawQueryResponseInternalErrorView::from(unsafe {
+                    fb::RawQueryResponseInternalError::init_from_table(table)
+                }),
+            )),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "RawQueryResponse",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::engine::RawQueryResponse, FlatbuffersDecodeError> {
+        match self {
+            Self::Progress(v) => v.to_owned().map(odf::engine::RawQueryResponse::Progress),
+            Self::Success(v) => v.to_owned().map(odf::engine::RawQueryResponse::Success),
+            Self::InvalidQuery(v) => v
+                .to_owned()
+                .map(odf::engine::RawQueryResponse::InvalidQuery),
+            Self::InternalError(v) => v
+                .to_owned()
+                .map(odf::engine::RawQueryResponse::InternalError),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseInternalError
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/InternalError
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::RawQueryResponseInternalError`]
+#[derive(Debug, Clone, Copy)]
+pub struct RawQueryResponseInternalErrorView<'fb> {
+    proxy: fb::RawQueryResponseInternalError<'fb>,
+}
+
+impl<'fb> From<fb::RawQueryResponseInternalError<'fb>> for RawQueryResponseInternalErrorView<'fb> {
+    fn from(proxy: fb::RawQueryResponseInternalError<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RawQueryResponseInternalErrorView<'fb> {
+    /// Brief description of an error
+    pub fn message(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .message()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryResponseInternalError.message"))
+    }
+
+    /// Details of an error (e.g. a backtrace)
+    pub fn backtrace(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .backtrace()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryResponseInternalError.backtrace"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::engine::RawQueryResponseInternalError, FlatbuffersDecodeError> {
+        odf::engine::RawQueryResponseInternalError::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseInvalidQuery
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/InvalidQuery
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::RawQueryResponseInvalidQuery`]
+#[derive(Debug, Clone, Copy)]
+pub struct RawQueryResponseInvalidQueryView<'fb> {
+    proxy: fb::RawQueryResponseInvalidQuery<'fb>,
+}
+
+impl<'fb> From<fb::RawQueryResponseInvalidQuery<'fb>> for RawQueryResponseInvalidQueryView<'fb> {
+    fn from(proxy: fb::RawQueryResponseInvalidQuery<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RawQueryResponseInvalidQueryView<'fb> {
+    /// Explanation of an error
+    pub fn message(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .message()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryResponseInvalidQuery.message"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::engine::RawQueryResponseInvalidQuery, FlatbuffersDecodeError> {
+        odf::engine::RawQueryResponseInvalidQuery::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseProgress
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/Progress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::RawQueryResponseProgress`]
+#[derive(Debug, Clone, Copy)]
+pub struct RawQueryResponseProgressView<'fb> {
+    proxy: fb::RawQueryResponseProgress<'fb>,
+}
+
+impl<'fb> From<fb::RawQueryResponseProgress<'fb>> for RawQueryResponseProgressView<'fb> {
+    fn from(proxy: fb::RawQueryResponseProgress<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RawQueryResponseProgressView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::engine::RawQueryResponseProgress, FlatbuffersDecodeError> {
+        odf::engine::RawQueryResponseProgress::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseSuccess
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/Success
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::RawQueryResponseSuccess`]
+#[derive(Debug, Clone, Copy)]
+pub struct RawQueryResponseSuccessView<'fb> {
+    proxy: fb::RawQueryResponseSuccess<'fb>,
+}
+
+impl<'fb> From<fb::RawQueryResponseSuccess<'fb>> for RawQueryResponseSuccessView<'fb> {
+    fn from(proxy: fb::RawQueryResponseSuccess<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RawQueryResponseSuccessView<'fb> {
+    /// Number of records produced by the query
+    pub fn num_records(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.num_records())
+            .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryResponseSuccess.numRecords"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::engine::RawQueryResponseSuccess, FlatbuffersDecodeError> {
+        odf::engine::RawQueryResponseSuccess::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStep
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStep`]
+#[derive(Debug, Clone, Copy)]
+pub enum ReadStepView<'fb> {
+    Csv(ReadStepCsvView<'fb>),
+    GeoJson(ReadStepGeoJsonView<'fb>),
+    EsriShapefile(ReadStepEsriShapefileView<'fb>),
+    Parquet(ReadStepParquetView<'fb>),
+    Json(ReadStepJsonView<'fb>),
+    NdJson(ReadStepNdJsonView<'fb>),
+    NdGeoJson(ReadStepNdGeoJsonView<'fb>),
+}
+
+impl<'fb> ReadStepView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::ReadStep) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::ReadStep::ReadStepCsv => Ok(Self::Csv(ReadStepCsvView::from(unsafe {
+                fb::ReadStepCsv::init_from_table(table)
+            }))),
+            fb::ReadStep::ReadStepGeoJson => Ok(Self::GeoJson(ReadStepGeoJsonView::from(unsafe {
+                fb::ReadStepGeoJson::init_from_table(table)
+            }))),
+            fb::ReadStep::ReadStepEsriShapefile => {
+                Ok(Self::EsriShapefile(ReadStepEsriShapefileView::from(
+                    unsafe { fb::ReadStepEsriShapefile::init_from_table(table) },
+                )))
+            }
+            fb::ReadStep::ReadStepParquet => Ok(Self::Parquet(ReadStepParquetView::from(unsafe {
+                fb::ReadStepParquet::init_from_table(table)
+            }))),
+            fb::ReadStep::ReadStepJson => Ok(Self::Json(ReadStepJsonView::from(unsafe {
+                fb::ReadStepJson::init_from_table(table)
+            }))),
+            fb::ReadStep::ReadStepNdJson => Ok(Self::NdJson(ReadStepNdJsonView::from(unsafe {
+                fb::ReadStepNdJson::init_from_table(table)
+            }))),
+            fb::ReadStep::ReadStepNdGeoJson => {
+                Ok(Self::NdGeoJson(ReadStepNdGeoJsonView::from(unsafe {
+                    fb::ReadStepNdGeoJson::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "ReadStep",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStep, FlatbuffersDecodeError> {
+        match self {
+            Self::Csv(v) => v.to_owned().map(odf::source::ReadStep::Csv),
+            Self::GeoJson(v) => v.to_owned().map(odf::source::ReadStep::GeoJson),
+            Self::EsriShapefile(v) => v.to_owned().map(odf::source::ReadStep::EsriShapefile),
+            Self::Parquet(v) => v.to_owned().map(odf::source::ReadStep::Parquet),
+            Self::Json(v) => v.to_owned().map(odf::source::ReadStep::Json),
+            Self::NdJson(v) => v.to_owned().map(odf::source::ReadStep::NdJson),
+            Self::NdGeoJson(v) => v.to_owned().map(odf::source::ReadStep::NdGeoJson),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepCsv
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Csv
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStepCsv`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadStepCsvView<'fb> {
+    proxy: fb::ReadStepCsv<'fb>,
+}
+
+impl<'fb> From<fb::ReadStepCsv<'fb>> for ReadStepCsvView<'fb> {
+    fn from(proxy: fb::ReadStepCsv<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ReadStepCsvView<'fb> {
+    /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+    pub fn ddl_schema(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .ddl_schema()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.ddlSchema"))
+    }
+
+    /// Sets a single character as a separator for each field and value.
+    pub fn separator(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .separator()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.separator"))
+    }
+
+    /// Decodes the CSV files by the given encoding type.
+    pub fn encoding(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .encoding()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.encoding"))
+    }
+
+    /// Sets a single character used for escaping quoted values where the separator can be part of the value. Set an empty string to turn off quotations.
+    pub fn quote(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .quote()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.quote"))
+    }
+
+    /// Sets a single character used for escaping quotes inside an already quoted value.
+    pub fn escape(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .escape()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.escape"))
+    }
+
+    /// Use the first line as names of columns.
+    pub fn header(&self) -> Result<Option<bool>, FlatbuffersDecodeError> {
+        self.proxy
+            .header()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.header"))
+    }
+
+    /// Infers the input schema automatically from data. It requires one extra pass over the data.
+    pub fn infer_schema(&self) -> Result<Option<bool>, FlatbuffersDecodeError> {
+        self.proxy
+            .infer_schema()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.inferSchema"))
+    }
+
+    /// Sets the string representation of a null value.
+    pub fn null_value(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .null_value()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.nullValue"))
+    }
+
+    /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+    pub fn date_format(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .date_format()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.dateFormat"))
+    }
+
+    /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+    pub fn timestamp_format(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .timestamp_format()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.timestampFormat"))
+    }
+
+    /// Schema used to coerce values into more appropriate data types.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStepCsv, FlatbuffersDecodeError> {
+        odf::source::ReadStepCsv::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepEsriShapefile
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/EsriShapefile
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStepEsriShapefile`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadStepEsriShapefileView<'fb> {
+    proxy: fb::ReadStepEsriShapefile<'fb>,
+}
+
+impl<'fb> From<fb::ReadStepEsriShapefile<'fb>> for ReadStepEsriShapefileView<'fb> {
+    fn from(proxy: fb::ReadStepEsriShapefile<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ReadStepEsriShapefileView<'fb> {
+    /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+    pub fn ddl_schema(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .ddl_schema()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepEsriShapefile.ddlSchema"))
+    }
+
+    /// If the ZIP archive contains multiple shapefiles use this field to specify a sub-path to the desired `.shp` file. Can contain glob patterns to act as a filter.
+    pub fn sub_path(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .sub_path()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepEsriShapefile.subPath"))
+    }
+
+    /// Schema used to coerce values into more appropriate data types.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepEsriShapefile.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStepEsriShapefile, FlatbuffersDecodeError> {
+        odf::source::ReadStepEsriShapefile::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepGeoJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/GeoJson
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStepGeoJson`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadStepGeoJsonView<'fb> {
+    proxy: fb::ReadStepGeoJson<'fb>,
+}
+
+impl<'fb> From<fb::ReadStepGeoJson<'fb>> for ReadStepGeoJsonView<'fb> {
+    fn from(proxy: fb::ReadStepGeoJson<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ReadStepGeoJsonView<'fb> {
+    /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+    pub fn ddl_schema(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .ddl_schema()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepGeoJson.ddlSchema"))
+    }
+
+    /// Schema used to coerce values into more appropriate data types.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepGeoJson.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStepGeoJson, FlatbuffersDecodeError> {
+        odf::source::ReadStepGeoJson::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Json
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStepJson`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadStepJsonView<'fb> {
+    proxy: fb::ReadStepJson<'fb>,
+}
+
+impl<'fb> From<fb::ReadStepJson<'fb>> for ReadStepJsonView<'fb> {
+    fn from(proxy: fb::ReadStepJson<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ReadStepJsonView<'fb> {
+    /// Path in the form of `a.b.c` to a sub-element of the root JSON object that is an array or objects. If not specified it is assumed that the root element is an array.
+    pub fn sub_path(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .sub_path()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.subPath"))
+    }
+
+    /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+    pub fn ddl_schema(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .ddl_schema()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.ddlSchema"))
+    }
+
+    /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+    pub fn date_format(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .date_format()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.dateFormat"))
+    }
+
+    /// Allows to forcibly set one of standard basic or extended encodings.
+    pub fn encoding(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .encoding()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.encoding"))
+    }
+
+    /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+    pub fn timestamp_format(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .timestamp_format()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.timestampFormat"))
+    }
+
+    /// Schema used to coerce values into more appropriate data types.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStepJson, FlatbuffersDecodeError> {
+        odf::source::ReadStepJson::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepNdGeoJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdGeoJson
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStepNdGeoJson`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadStepNdGeoJsonView<'fb> {
+    proxy: fb::ReadStepNdGeoJson<'fb>,
+}
+
+impl<'fb> From<fb::ReadStepNdGeoJson<'fb>> for ReadStepNdGeoJsonView<'fb> {
+    fn from(proxy: fb::ReadStepNdGeoJson<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ReadStepNdGeoJsonView<'fb> {
+    /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+    pub fn ddl_schema(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .ddl_schema()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdGeoJson.ddlSchema"))
+    }
+
+    /// Schema used to coerce values into more appropriate data types.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdGeoJson.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStepNdGeoJson, FlatbuffersDecodeError> {
+        odf::source::ReadStepNdGeoJson::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepNdJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdJson
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStepNdJson`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadStepNdJsonView<'fb> {
+    proxy: fb::ReadStepNdJson<'fb>,
+}
+
+impl<'fb> From<fb::ReadStepNdJson<'fb>> for ReadStepNdJsonView<'fb> {
+    fn from(proxy: fb::ReadStepNdJson<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ReadStepNdJsonView<'fb> {
+    /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+    pub fn ddl_schema(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .ddl_schema()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.ddlSchema"))
+    }
+
+    /// Sets the string that indicates a date format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+    pub fn date_format(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .date_format()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.dateFormat"))
+    }
+
+    /// Allows to forcibly set one of standard basic or extended encodings.
+    pub fn encoding(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .encoding()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.encoding"))
+    }
+
+    /// Sets the string that indicates a timestamp format. The `rfc3339` is the only required format, the other format strings are implementation-specific.
+    pub fn timestamp_format(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .timestamp_format()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.timestampFormat"))
+    }
+
+    /// Schema used to coerce values into more appropriate data types.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStepNdJson, FlatbuffersDecodeError> {
+        odf::source::ReadStepNdJson::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepParquet
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Parquet
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::ReadStepParquet`]
+#[derive(Debug, Clone, Copy)]
+pub struct ReadStepParquetView<'fb> {
+    proxy: fb::ReadStepParquet<'fb>,
+}
+
+impl<'fb> From<fb::ReadStepParquet<'fb>> for ReadStepParquetView<'fb> {
+    fn from(proxy: fb::ReadStepParquet<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ReadStepParquetView<'fb> {
+    /// DEPRECATED: A DDL-formatted schema. Schema can be used to coerce values into more appropriate data types.
+    pub fn ddl_schema(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .ddl_schema()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepParquet.ddlSchema"))
+    }
+
+    /// Schema used to coerce values into more appropriate data types.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepParquet.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::ReadStepParquet, FlatbuffersDecodeError> {
+        odf::source::ReadStepParquet::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Relation
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Relation
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::Relation`]
+#[derive(Debug, Clone, Copy)]
+pub struct RelationView<'fb> {
+    proxy: fb::Relation<'fb>,
+}
+
+impl<'fb> From<fb::Relation<'fb>> for RelationView<'fb> {
+    fn from(proxy: fb::Relation<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RelationView<'fb> {
+    /// The resource that holds the relation.
+    pub fn subject(&self) -> Result<ResourceHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .subject()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Relation.subject"))
+    }
+
+    /// Name of the relation e.g. `role`, `member`, `owner`.
+    pub fn relation(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .relation()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Relation.relation"))
+    }
+
+    /// Optional value associated with the relation e.g. `maintainer` for a `role` relation.
+    pub fn value(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("Relation.value"))
+    }
+
+    /// The resource that is the target of the relation.
+    pub fn object(&self) -> Result<ResourceHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .object()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Relation.object"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::Relation, FlatbuffersDecodeError> {
+        odf::auth::Relation::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RelationInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::RelationInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct RelationInputView<'fb> {
+    proxy: fb::RelationInput<'fb>,
+}
+
+impl<'fb> From<fb::RelationInput<'fb>> for RelationInputView<'fb> {
+    fn from(proxy: fb::RelationInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RelationInputView<'fb> {
+    /// The resource that holds the relation.
+    pub fn subject(&self) -> Result<ResourceRefView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .subject()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceRefView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.subject"))
+    }
+
+    /// Name of the relation e.g. `role`, `member`, `owner`.
+    pub fn relation(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .relation()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.relation"))
+    }
+
+    /// Optional value associated with the relation e.g. `maintainer` for a `role` relation.
+    pub fn value(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.value"))
+    }
+
+    /// The resource that is the target of the relation.
+    pub fn object(&self) -> Result<ResourceRefView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .object()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceRefView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.object"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::RelationInput, FlatbuffersDecodeError> {
+        odf::auth::RelationInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RelationsSpec
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationsSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::RelationsSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct RelationsSpecView<'fb> {
+    proxy: fb::RelationsSpec<'fb>,
+}
+
+impl<'fb> From<fb::RelationsSpec<'fb>> for RelationsSpecView<'fb> {
+    fn from(proxy: fb::RelationsSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RelationsSpecView<'fb> {
+    /// Relations between resources.
+    pub fn relations(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<RelationView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .relations()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(RelationView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpec.relations"))
+    }
+
+    /// Resource attributes.
+    pub fn attributes(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<AttributeView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .attributes()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(AttributeView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpec.attributes"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::RelationsSpec, FlatbuffersDecodeError> {
+        odf::auth::RelationsSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RelationsSpecInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationsSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::auth::RelationsSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct RelationsSpecInputView<'fb> {
+    proxy: fb::RelationsSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::RelationsSpecInput<'fb>> for RelationsSpecInputView<'fb> {
+    fn from(proxy: fb::RelationsSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RelationsSpecInputView<'fb> {
+    /// Relations between resources.
+    pub fn relations(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<RelationInputView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .relations()
+            .map(|v| Ok(v.iter().map(|i| Ok(RelationInputView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpecInput.relations"))
+    }
+
+    /// Resource attributes.
+    pub fn attributes(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<AttributeInputView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .attributes()
+            .map(|v| Ok(v.iter().map(|i| Ok(AttributeInputView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpecInput.attributes"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::auth::RelationsSpecInput, FlatbuffersDecodeError> {
+        odf::auth::RelationsSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RequestHeader
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/RequestHeader
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::RequestHeader`]
+#[derive(Debug, Clone, Copy)]
+pub struct RequestHeaderView<'fb> {
+    proxy: fb::RequestHeader<'fb>,
+}
+
+impl<'fb> From<fb::RequestHeader<'fb>> for RequestHeaderView<'fb> {
+    fn from(proxy: fb::RequestHeader<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> RequestHeaderView<'fb> {
+    /// Name of the header.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RequestHeader.name"))
+    }
+
+    /// Value of the header.
+    pub fn value(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("RequestHeader.value"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::RequestHeader, FlatbuffersDecodeError> {
+        odf::source::RequestHeader::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceAnnotations
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceAnnotations
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceAnnotations`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceAnnotationsView<'fb> {
+    proxy: fb::ResourceAnnotations<'fb>,
+}
+
+impl<'fb> From<fb::ResourceAnnotations<'fb>> for ResourceAnnotationsView<'fb> {
+    fn from(proxy: fb::ResourceAnnotations<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceAnnotationsView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<(&'fb str, &'fb str), FlatbuffersDecodeError>> + 'fb
+    {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(v))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("ResourceAnnotations.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceAnnotations, FlatbuffersDecodeError> {
+        odf::resource::ResourceAnnotations::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceConditions
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceConditions
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceConditions`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceConditionsView<'fb> {
+    proxy: fb::ResourceConditions<'fb>,
+}
+
+impl<'fb> From<fb::ResourceConditions<'fb>> for ResourceConditionsView<'fb> {
+    fn from(proxy: fb::ResourceConditions<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceConditionsView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<(&'fb str, &'fb str), FlatbuffersDecodeError>> + 'fb
+    {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(v))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("ResourceConditions.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceConditions, FlatbuffersDecodeError> {
+        odf::resource::ResourceConditions::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceHandle
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHandle
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceHandle`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceHandleView<'fb> {
+    proxy: fb::ResourceHandle<'fb>,
+}
+
+impl<'fb> From<fb::ResourceHandle<'fb>> for ResourceHandleView<'fb> {
+    fn from(proxy: fb::ResourceHandle<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceHandleView<'fb> {
+    /// Account that owns the target resource.
+    pub fn account(&self) -> Result<AccountHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .account()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(AccountHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.account"))
+    }
+
+    /// Type URI of the target resource.
+    pub fn r#type(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .type_()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.type"))
+    }
+
+    /// ID of the resource within a node.
+    pub fn id(&self) -> Result<odf::resource::ResourceID, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.id"))
+    }
+
+    /// DID of the resource, if applicable.
+    pub fn did(&self) -> Result<Option<odf::Did>, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .map(|v| odf::Did::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.did"))
+    }
+
+    /// Name of a resource.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.name"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceHandle, FlatbuffersDecodeError> {
+        odf::resource::ResourceHandle::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceHeaders
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeaders
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceHeaders`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceHeadersView<'fb> {
+    proxy: fb::ResourceHeaders<'fb>,
+}
+
+impl<'fb> From<fb::ResourceHeaders<'fb>> for ResourceHeadersView<'fb> {
+    fn from(proxy: fb::ResourceHeaders<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceHeadersView<'fb> {
+    /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
+    pub fn id(&self) -> Result<odf::resource::ResourceID, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.id"))
+    }
+
+    /// Symbolic name of a resource that identifies it within a scope of an onwing account.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.name"))
+    }
+
+    /// Link to the account that owns the resource.
+    pub fn account(&self) -> Result<AccountHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .account()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(AccountHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.account"))
+    }
+
+    /// Map of string keys and values that can be used to organize, categorize, and query resources.
+    pub fn labels(&self) -> Result<ResourceLabelsView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .labels()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceLabelsView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.labels"))
+    }
+
+    /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
+    pub fn annotations(&self) -> Result<ResourceAnnotationsView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .annotations()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceAnnotationsView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.annotations"))
+    }
+
+    /// A sequential number that changes every time the resource header and spec are updated. Does not increment on status changes, thus signifying changes to the desired state. Populated by the system. Starts with `1`.
+    pub fn generation(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.generation())
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.generation"))
+    }
+
+    /// Time when the resource was first applied and assigned an identity.
+    pub fn created_at(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        self.proxy
+            .created_at()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| fb_to_datetime(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.createdAt"))
+    }
+
+    /// Time when the resource was last updated, including header, spec, and status updates.
+    pub fn updated_at(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        self.proxy
+            .updated_at()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| fb_to_datetime(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.updatedAt"))
+    }
+
+    /// Time when the resource was deleted.
+    pub fn deleted_at(&self) -> Result<Option<DateTime<Utc>>, FlatbuffersDecodeError> {
+        self.proxy
+            .deleted_at()
+            .map(|v| fb_to_datetime(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.deletedAt"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceHeaders, FlatbuffersDecodeError> {
+        odf::resource::ResourceHeaders::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceHeadersInput
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeadersInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceHeadersInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceHeadersInputView<'fb> {
+    proxy: fb::ResourceHeadersInput<'fb>,
+}
+
+impl<'fb> From<fb::ResourceHeadersInput<'fb>> for ResourceHeadersInputView<'fb> {
+    fn from(proxy: fb::ResourceHeadersInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceHeadersInputView<'fb> {
+    /// Unique identifier of a resource within entire ODF node. Automatically assigned upon resource creation.
+    pub fn id(&self) -> Result<Option<odf::resource::ResourceID>, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .map(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.id"))
+    }
+
+    /// Symbolic name of a resource that identifies it within a scope of an onwing account.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.name"))
+    }
+
+    /// Reference to the account that owns the resource.
+    pub fn account(&self) -> Result<Option<AccountRefView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .account()
+            .map(|v| Ok(AccountRefView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.account"))
+    }
+
+    /// Map of string keys and values that can be used to organize, categorize, and query resources.
+    pub fn labels(&self) -> Result<Option<ResourceLabelsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .labels()
+            .map(|v| Ok(ResourceLabelsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.labels"))
+    }
+
+    /// Annotations is a key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
+    pub fn annotations(
+        &self,
+    ) -> Result<Option<ResourceAnnotationsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .annotations()
+            .map(|v| Ok(ResourceAnnotationsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.annotations"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceHeadersInput, FlatbuffersDecodeError> {
+        odf::resource::ResourceHeadersInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceLabels
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceLabels
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceLabels`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceLabelsView<'fb> {
+    proxy: fb::ResourceLabels<'fb>,
+}
+
+impl<'fb> From<fb::ResourceLabels<'fb>> for ResourceLabelsView<'fb> {
+    fn from(proxy: fb::ResourceLabels<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceLabelsView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<(&'fb str, &'fb str), FlatbuffersDecodeError>> + 'fb
+    {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(v))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("ResourceLabels.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceLabels, FlatbuffersDecodeError> {
+        odf::resource::ResourceLabels::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceRef
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceRef`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceRefView<'fb> {
+    proxy: fb::ResourceRef<'fb>,
+}
+
+impl<'fb> From<fb::ResourceRef<'fb>> for ResourceRefView<'fb> {
+    fn from(proxy: fb::ResourceRef<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceRefView<'fb> {
+    /// Reference to an account that owns the target resource.
+    pub fn account(&self) -> Result<Option<AccountRefView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .account()
+            .map(|v| Ok(AccountRefView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.account"))
+    }
+
+    /// Short type name or full type URI of the target resource.
+    pub fn r#type(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .type_()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.type"))
+    }
+
+    /// ID of the resource within a node.
+    pub fn id(&self) -> Result<Option<odf::resource::ResourceID>, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .map(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.id"))
+    }
+
+    /// DID of the resource.
+    pub fn did(&self) -> Result<Option<odf::Did>, FlatbuffersDecodeError> {
+        self.proxy
+            .did()
+            .map(|v| odf::Did::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.did"))
+    }
+
+    /// Name of a resource.
+    pub fn name(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.name"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceRef, FlatbuffersDecodeError> {
+        odf::resource::ResourceRef::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceStatus
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceStatus
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::resource::ResourceStatus`]
+#[derive(Debug, Clone, Copy)]
+pub struct ResourceStatusView<'fb> {
+    proxy: fb::ResourceStatus<'fb>,
+}
+
+impl<'fb> From<fb::ResourceStatus<'fb>> for ResourceStatusView<'fb> {
+    fn from(proxy: fb::ResourceStatus<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ResourceStatusView<'fb> {
+    /// Represents the lifecycle stage of a resource.
+    pub fn phase(&self) -> Result<odf::resource::ResourcePhase, FlatbuffersDecodeError> {
+        odf::resource::ResourcePhase::try_from(self.proxy.phase())
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.phase"))
+    }
+
+    /// Resource generation that was last processed by the main resource controller.
+    pub fn observed_generation(&self) -> Result<Option<u64>, FlatbuffersDecodeError> {
+        self.proxy
+            .observed_generation()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.observedGeneration"))
+    }
+
+    /// Time when the controller last reconciled the desired resource state as defined in `observedGeneration`.
+    pub fn reconciled_at(&self) -> Result<Option<DateTime<Utc>>, FlatbuffersDecodeError> {
+        self.proxy
+            .reconciled_at()
+            .map(|v| fb_to_datetime(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.reconciledAt"))
+    }
+
+    /// Detailed conditions describing the state of the resource that are added by controllers.
+    pub fn conditions(&self) -> Result<ResourceConditionsView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .conditions()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceConditionsView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.conditions"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::resource::ResourceStatus, FlatbuffersDecodeError> {
+        odf::resource::ResourceStatus::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Secret
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Secret
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::Secret`]
+#[derive(Debug, Clone, Copy)]
+pub struct SecretView<'fb> {
+    proxy: fb::Secret<'fb>,
+}
+
+impl<'fb> From<fb::Secret<'fb>> for SecretView<'fb> {
+    fn from(proxy: fb::Secret<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SecretView<'fb> {
+    /// A secret value in raw or encoded form.
+    pub fn value(&self) -> Result<odf::dtos::Sensitive<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v).map(odf::dtos::Sensitive::new))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Secret.value"))
+    }
+
+    /// Represents the encoding of the value. Typically will be `jwe` after a raw secret gets encrypted.
+    pub fn content_encoding(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .content_encoding()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("Secret.contentEncoding"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::Secret, FlatbuffersDecodeError> {
+        odf::config::Secret::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SecretSetSpec
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::SecretSetSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct SecretSetSpecView<'fb> {
+    proxy: fb::SecretSetSpec<'fb>,
+}
+
+impl<'fb> From<fb::SecretSetSpec<'fb>> for SecretSetSpecView<'fb> {
+    fn from(proxy: fb::SecretSetSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SecretSetSpecView<'fb> {
+    /// Key value pairs of secrets.
+    pub fn secrets(&self) -> Result<SecretsView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .secrets()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(SecretsView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SecretSetSpec.secrets"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::SecretSetSpec, FlatbuffersDecodeError> {
+        odf::config::SecretSetSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SecretSetSpecInput
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::SecretSetSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct SecretSetSpecInputView<'fb> {
+    proxy: fb::SecretSetSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::SecretSetSpecInput<'fb>> for SecretSetSpecInputView<'fb> {
+    fn from(proxy: fb::SecretSetSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SecretSetSpecInputView<'fb> {
+    /// Key value pairs of secrets.
+    pub fn secrets(&self) -> Result<SecretsView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .secrets()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(SecretsView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SecretSetSpecInput.secrets"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::SecretSetSpecInput, FlatbuffersDecodeError> {
+        odf::config::SecretSetSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Secrets
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Secrets
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::Secrets`]
+#[derive(Debug, Clone, Copy)]
+pub struct SecretsView<'fb> {
+    proxy: fb::Secrets<'fb>,
+}
+
+impl<'fb> From<fb::Secrets<'fb>> for SecretsView<'fb> {
+    fn from(proxy: fb::Secrets<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SecretsView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<(&'fb str, SecretView<'fb>), FlatbuffersDecodeError>> + 'fb
+    {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(SecretView::from(v)))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("Secrets.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::Secrets, FlatbuffersDecodeError> {
+        odf::config::Secrets::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Seed
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Seed
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::Seed`]
+#[derive(Debug, Clone, Copy)]
+pub struct SeedView<'fb> {
+    proxy: fb::Seed<'fb>,
+}
+
+impl<'fb> From<fb::Seed<'fb>> for SeedView<'fb> {
+    fn from(proxy: fb::Seed<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SeedView<'fb> {
+    /// Unique identity of the dataset.
+    pub fn dataset_id(&self) -> Result<odf::dataset::DatasetID, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("Seed.datasetId"))
+    }
+
+    /// Type of the dataset.
+    pub fn dataset_kind(&self) -> Result<odf::dataset::DatasetKind, FlatbuffersDecodeError> {
+        odf::dataset::DatasetKind::try_from(self.proxy.dataset_kind())
+            .map_err(|e: FlatbuffersDecodeError| e.at("Seed.datasetKind"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::Seed, FlatbuffersDecodeError> {
+        odf::dataset::Seed::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetAttachments
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetAttachments
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::SetAttachments`]
+#[derive(Debug, Clone, Copy)]
+pub struct SetAttachmentsView<'fb> {
+    proxy: fb::SetAttachments<'fb>,
+}
+
+impl<'fb> From<fb::SetAttachments<'fb>> for SetAttachmentsView<'fb> {
+    fn from(proxy: fb::SetAttachments<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SetAttachmentsView<'fb> {
+    /// One of the supported attachment sources.
+    pub fn attachments(&self) -> Result<AttachmentsView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .attachments()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| AttachmentsView::new(v, self.proxy.attachments_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetAttachments.attachments"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::SetAttachments, FlatbuffersDecodeError> {
+        odf::dataset::SetAttachments::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetDataSchema
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetDataSchema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::SetDataSchema`]
+#[derive(Debug, Clone, Copy)]
+pub struct SetDataSchemaView<'fb> {
+    proxy: fb::SetDataSchema<'fb>,
+}
+
+impl<'fb> From<fb::SetDataSchema<'fb>> for SetDataSchemaView<'fb> {
+    fn from(proxy: fb::SetDataSchema<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SetDataSchemaView<'fb> {
+    /// DEPRECATED: Apache Arrow schema encoded in its native flatbuffers representation.
+    pub fn raw_arrow_schema(&self) -> Result<Option<&'fb [u8]>, FlatbuffersDecodeError> {
+        self.proxy
+            .raw_arrow_schema()
+            .map(|v| Ok(v.bytes()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetDataSchema.rawArrowSchema"))
+    }
+
+    /// Defines the logical schema of the data files that follow this event. Will become a required field after migration.
+    pub fn schema(&self) -> Result<Option<DataSchemaView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .schema()
+            .map(|v| Ok(DataSchemaView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetDataSchema.schema"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::SetDataSchema, FlatbuffersDecodeError> {
+        odf::dataset::SetDataSchema::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetInfo
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetInfo
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::SetInfo`]
+#[derive(Debug, Clone, Copy)]
+pub struct SetInfoView<'fb> {
+    proxy: fb::SetInfo<'fb>,
+}
+
+impl<'fb> From<fb::SetInfo<'fb>> for SetInfoView<'fb> {
+    fn from(proxy: fb::SetInfo<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SetInfoView<'fb> {
+    /// Brief single-sentence summary of a dataset.
+    pub fn description(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .description()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetInfo.description"))
+    }
+
+    /// Keywords, search terms, or tags used to describe the dataset.
+    pub fn keywords(
+        &self,
+    ) -> Result<
+        Option<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb>,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .keywords()
+            .map(|v| Ok(v.iter().map(|i| Ok(i))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetInfo.keywords"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::SetInfo, FlatbuffersDecodeError> {
+        odf::dataset::SetInfo::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetLicense
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetLicense
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::SetLicense`]
+#[derive(Debug, Clone, Copy)]
+pub struct SetLicenseView<'fb> {
+    proxy: fb::SetLicense<'fb>,
+}
+
+impl<'fb> From<fb::SetLicense<'fb>> for SetLicenseView<'fb> {
+    fn from(proxy: fb::SetLicense<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SetLicenseView<'fb> {
+    /// Abbreviated name of the license.
+    pub fn short_name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .short_name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.shortName"))
+    }
+
+    /// Full name of the license.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.name"))
+    }
+
+    /// License identifier from the SPDX License List.
+    pub fn spdx_id(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .spdx_id()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.spdxId"))
+    }
+
+    /// URL where licensing terms can be found.
+    pub fn website_url(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .website_url()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.websiteUrl"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::SetLicense, FlatbuffersDecodeError> {
+        odf::dataset::SetLicense::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetPollingSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/SetPollingSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::legacy::SetPollingSource`]
+#[derive(Debug, Clone, Copy)]
+pub struct SetPollingSourceView<'fb> {
+    proxy: fb::SetPollingSource<'fb>,
+}
+
+impl<'fb> From<fb::SetPollingSource<'fb>> for SetPollingSourceView<'fb> {
+    fn from(proxy: fb::SetPollingSource<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SetPollingSourceView<'fb> {
+    /// Determines where data is sourced from.
+    pub fn fetch(&self) -> Result<FetchStepView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .fetch()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| FetchStepView::new(v, self.proxy.fetch_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.fetch"))
+    }
+
+    /// Defines how raw data is prepared before reading.
+    pub fn prepare(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<PrepStepView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .prepare()
+            .map(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| PrepStepView::new(v, i.value_type()))
+                }))
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.prepare"))
+    }
+
+    /// Defines how data is read into structured format.
+    pub fn read(&self) -> Result<ReadStepView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .read()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| ReadStepView::new(v, self.proxy.read_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.read"))
+    }
+
+    /// Pre-processing query that shapes the data.
+    pub fn preprocess(&self) -> Result<Option<TransformView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .preprocess()
+            .map(|v| TransformView::new(v, self.proxy.preprocess_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.preprocess"))
+    }
+
+    /// Determines how newly-ingested data should be merged with existing history.
+    pub fn merge(&self) -> Result<MergeStrategyView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .merge()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| MergeStrategyView::new(v, self.proxy.merge_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.merge"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::legacy::SetPollingSource, FlatbuffersDecodeError> {
+        odf::legacy::SetPollingSource::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetTransform
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetTransform
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::SetTransform`]
+#[derive(Debug, Clone, Copy)]
+pub struct SetTransformView<'fb> {
+    proxy: fb::SetTransform<'fb>,
+}
+
+impl<'fb> From<fb::SetTransform<'fb>> for SetTransformView<'fb> {
+    fn from(proxy: fb::SetTransform<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SetTransformView<'fb> {
+    /// Datasets that will be used as sources.
+    pub fn inputs(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<TransformInputView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .inputs()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(TransformInputView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetTransform.inputs"))
+    }
+
+    /// Transformation that will be applied to produce new data.
+    pub fn transform(&self) -> Result<TransformView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .transform()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| TransformView::new(v, self.proxy.transform_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetTransform.transform"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::SetTransform, FlatbuffersDecodeError> {
+        odf::dataset::SetTransform::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetVocab
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetVocab
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::SetVocab`]
+#[derive(Debug, Clone, Copy)]
+pub struct SetVocabView<'fb> {
+    proxy: fb::SetVocab<'fb>,
+}
+
+impl<'fb> From<fb::SetVocab<'fb>> for SetVocabView<'fb> {
+    fn from(proxy: fb::SetVocab<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SetVocabView<'fb> {
+    /// Name of the offset column.
+    pub fn offset_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .offset_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.offsetColumn"))
+    }
+
+    /// Name of the operation type column.
+    pub fn operation_type_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .operation_type_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.operationTypeColumn"))
+    }
+
+    /// Name of the system time column.
+    pub fn system_time_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .system_time_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.systemTimeColumn"))
+    }
+
+    /// Name of the event time column.
+    pub fn event_time_column(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .event_time_column()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.eventTimeColumn"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::SetVocab, FlatbuffersDecodeError> {
+        odf::dataset::SetVocab::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceCaching
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::SourceCaching`]
+#[derive(Debug, Clone, Copy)]
+pub enum SourceCachingView<'fb> {
+    Forever(SourceCachingForeverView<'fb>),
+}
+
+impl<'fb> SourceCachingView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::SourceCaching) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::SourceCaching::SourceCachingForever => {
+                Ok(Self::Forever(SourceCachingForeverView::from(unsafe {
+                    fb::SourceCachingForever::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "SourceCaching",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::SourceCaching, FlatbuffersDecodeError> {
+        match self {
+            Self::Forever(v) => v.to_owned().map(odf::source::SourceCaching::Forever),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceCachingForever
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching#/$defs/Forever
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::SourceCachingForever`]
+#[derive(Debug, Clone, Copy)]
+pub struct SourceCachingForeverView<'fb> {
+    proxy: fb::SourceCachingForever<'fb>,
+}
+
+impl<'fb> From<fb::SourceCachingForever<'fb>> for SourceCachingForeverView<'fb> {
+    fn from(proxy: fb::SourceCachingForever<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SourceCachingForeverView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::SourceCachingForever, FlatbuffersDecodeError> {
+        odf::source::SourceCachingForever::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceSpec
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::SourceSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct SourceSpecView<'fb> {
+    proxy: fb::SourceSpec<'fb>,
+}
+
+impl<'fb> From<fb::SourceSpec<'fb>> for SourceSpecView<'fb> {
+    fn from(proxy: fb::SourceSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SourceSpecView<'fb> {
+    /// Brings the configuration values into the local `config` context.
+    pub fn config(&self) -> Result<Option<ValueRefsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .config()
+            .map(|v| Ok(ValueRefsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.config"))
+    }
+
+    /// Determines where data is sourced from.
+    pub fn ingress(&self) -> Result<Option<IngressView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .ingress()
+            .map(|v| IngressView::new(v, self.proxy.ingress_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.ingress"))
+    }
+
+    /// Defines how raw data is prepared before reading.
+    pub fn prepare(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<PrepStepView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .prepare()
+            .map(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| PrepStepView::new(v, i.value_type()))
+                }))
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.prepare"))
+    }
+
+    /// Defines how data is read into structured format.
+    pub fn read(&self) -> Result<ReadStepView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .read()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| ReadStepView::new(v, self.proxy.read_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.read"))
+    }
+
+    /// Pre-processing query that shapes the data.
+    pub fn preprocess(&self) -> Result<Option<TransformView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .preprocess()
+            .map(|v| TransformView::new(v, self.proxy.preprocess_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.preprocess"))
+    }
+
+    /// Determines how newly-ingested data should be merged with existing history.
+    pub fn merge(&self) -> Result<Option<MergeStrategyView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .merge()
+            .map(|v| MergeStrategyView::new(v, self.proxy.merge_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.merge"))
+    }
+
+    /// Defines the mapping of system fields to dataset column names.
+    pub fn vocab(&self) -> Result<Option<DatasetVocabularyView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .vocab()
+            .map(|v| Ok(DatasetVocabularyView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.vocab"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::SourceSpec, FlatbuffersDecodeError> {
+        odf::source::SourceSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceSpecInput
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::SourceSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct SourceSpecInputView<'fb> {
+    proxy: fb::SourceSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::SourceSpecInput<'fb>> for SourceSpecInputView<'fb> {
+    fn from(proxy: fb::SourceSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SourceSpecInputView<'fb> {
+    /// Brings the configuration values into the local `config` context.
+    pub fn config(&self) -> Result<Option<ValueRefsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .config()
+            .map(|v| Ok(ValueRefsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.config"))
+    }
+
+    /// Determines where data is sourced from.
+    pub fn ingress(&self) -> Result<Option<IngressView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .ingress()
+            .map(|v| IngressView::new(v, self.proxy.ingress_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.ingress"))
+    }
+
+    /// Defines how raw data is prepared before reading.
+    pub fn prepare(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<PrepStepView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .prepare()
+            .map(|v| {
+                Ok(v.iter().map(|i| {
+                    i.value()
+                        .ok_or_else(FlatbuffersDecodeError::missing_field)
+                        .and_then(|v| PrepStepView::new(v, i.value_type()))
+                }))
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.prepare"))
+    }
+
+    /// Defines how data is read into structured format.
+    pub fn read(&self) -> Result<ReadStepView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .read()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| ReadStepView::new(v, self.proxy.read_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.read"))
+    }
+
+    /// Pre-processing query that shapes the data.
+    pub fn preprocess(&self) -> Result<Option<TransformView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .preprocess()
+            .map(|v| TransformView::new(v, self.proxy.preprocess_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.preprocess"))
+    }
+
+    /// Determines how newly-ingested data should be merged with existing history.
+    pub fn merge(&self) -> Result<Option<MergeStrategyView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .merge()
+            .map(|v| MergeStrategyView::new(v, self.proxy.merge_type()))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.merge"))
+    }
+
+    /// Defines the mapping of system fields to dataset column names.
+    pub fn vocab(&self) -> Result<Option<DatasetVocabularyView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .vocab()
+            .map(|v| Ok(DatasetVocabularyView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.vocab"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::SourceSpecInput, FlatbuffersDecodeError> {
+        odf::source::SourceSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceState
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceState
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::source::SourceState`]
+#[derive(Debug, Clone, Copy)]
+pub struct SourceStateView<'fb> {
+    proxy: fb::SourceState<'fb>,
+}
+
+impl<'fb> From<fb::SourceState<'fb>> for SourceStateView<'fb> {
+    fn from(proxy: fb::SourceState<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SourceStateView<'fb> {
+    /// Identifies the source that the state corresponds to.
+    pub fn source_name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .source_name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceState.sourceName"))
+    }
+
+    /// Identifies the type of the state. Standard types include: `odf/etag`, `odf/last-modified`.
+    pub fn kind(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .kind()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceState.kind"))
+    }
+
+    /// Opaque value representing the state.
+    pub fn value(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SourceState.value"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::source::SourceState, FlatbuffersDecodeError> {
+        odf::source::SourceState::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SqlQueryStep
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SqlQueryStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::SqlQueryStep`]
+#[derive(Debug, Clone, Copy)]
+pub struct SqlQueryStepView<'fb> {
+    proxy: fb::SqlQueryStep<'fb>,
+}
+
+impl<'fb> From<fb::SqlQueryStep<'fb>> for SqlQueryStepView<'fb> {
+    fn from(proxy: fb::SqlQueryStep<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> SqlQueryStepView<'fb> {
+    /// Name of the temporary view that will be created from result of the query. Step without this alias will be treated as an output of the transformation.
+    pub fn alias(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .alias()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("SqlQueryStep.alias"))
+    }
+
+    /// SQL query the result of which will be exposed under the alias.
+    pub fn query(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .query()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("SqlQueryStep.query"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::SqlQueryStep, FlatbuffersDecodeError> {
+        odf::dataset::SqlQueryStep::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpec
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpec`]
+#[derive(Debug, Clone, Copy)]
+pub enum TaskSpecView<'fb> {
+    Ingest(TaskSpecIngestView<'fb>),
+    Compaction(TaskSpecCompactionView<'fb>),
+    GarbageCollection(TaskSpecGarbageCollectionView<'fb>),
+    WebhookCall(TaskSpecWebhookCallView<'fb>),
+}
+
+impl<'fb> TaskSpecView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::TaskSpec) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::TaskSpec::TaskSpecIngest => Ok(Self::Ingest(TaskSpecIngestView::from(unsafe {
+                fb::TaskSpecIngest::init_from_table(table)
+            }))),
+            fb::TaskSpec::TaskSpecCompaction => {
+                Ok(Self::Compaction(TaskSpecCompactionView::from(unsafe {
+                    fb::TaskSpecCompaction::init_from_table(table)
+                })))
+            }
+            fb::TaskSpec::TaskSpecGarbageCollection => Ok(Self::GarbageCollection(
+                TaskSpecGarbageCollectionView::from(unsafe {
+                    fb::TaskSpecGarbageCollection::init_from_table(table)
+                }),
+            )),
+            fb::TaskSpec::TaskSpecWebhookCall => {
+                Ok(Self::WebhookCall(TaskSpecWebhookCallView::from(unsafe {
+                    fb::TaskSpecWebhookCall::init_from_table(table)
+                })))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "TaskSpec",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpec, FlatbuffersDecodeError> {
+        match self {
+            Self::Ingest(v) => v.to_owned().map(odf::flow::TaskSpec::Ingest),
+            Self::Compaction(v) => v.to_owned().map(odf::flow::TaskSpec::Compaction),
+            Self::GarbageCollection(v) => v.to_owned().map(odf::flow::TaskSpec::GarbageCollection),
+            Self::WebhookCall(v) => v.to_owned().map(odf::flow::TaskSpec::WebhookCall),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecCompaction
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/Compaction
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecCompaction`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecCompactionView<'fb> {
+    proxy: fb::TaskSpecCompaction<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecCompaction<'fb>> for TaskSpecCompactionView<'fb> {
+    fn from(proxy: fb::TaskSpecCompaction<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecCompactionView<'fb> {
+    /// Optional parameters to control ingestion behavior.
+    pub fn params(&self) -> Result<Option<CompactionParamsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .params()
+            .map(|v| Ok(CompactionParamsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecCompaction.params"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecCompaction, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecCompaction::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecGarbageCollection
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/GarbageCollection
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecGarbageCollection`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecGarbageCollectionView<'fb> {
+    proxy: fb::TaskSpecGarbageCollection<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecGarbageCollection<'fb>> for TaskSpecGarbageCollectionView<'fb> {
+    fn from(proxy: fb::TaskSpecGarbageCollection<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecGarbageCollectionView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecGarbageCollection, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecGarbageCollection::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecIngest
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/Ingest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecIngest`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecIngestView<'fb> {
+    proxy: fb::TaskSpecIngest<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecIngest<'fb>> for TaskSpecIngestView<'fb> {
+    fn from(proxy: fb::TaskSpecIngest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecIngestView<'fb> {
+    /// Reference to the source resource that defines how to fetch data.
+    pub fn source(&self) -> Result<ResourceHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .source()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecIngest.source"))
+    }
+
+    /// Optional parameters to control ingestion behavior.
+    pub fn params(&self) -> Result<Option<IngestParamsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .params()
+            .map(|v| Ok(IngestParamsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecIngest.params"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecIngest, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecIngest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecWebhookCall
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/WebhookCall
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecWebhookCall`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecWebhookCallView<'fb> {
+    proxy: fb::TaskSpecWebhookCall<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecWebhookCall<'fb>> for TaskSpecWebhookCallView<'fb> {
+    fn from(proxy: fb::TaskSpecWebhookCall<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecWebhookCallView<'fb> {
+    /// Reference to the `WebhookTarget`.
+    pub fn target(&self) -> Result<ResourceHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .target()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecWebhookCall.target"))
+    }
+
+    /// The payload to send. May include templating.
+    pub fn payload(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .payload()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecWebhookCall.payload"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecWebhookCall, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecWebhookCall::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInput
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub enum TaskSpecInputView<'fb> {
+    Ingest(TaskSpecInputIngestView<'fb>),
+    Compaction(TaskSpecInputCompactionView<'fb>),
+    GarbageCollection(TaskSpecInputGarbageCollectionView<'fb>),
+    WebhookCall(TaskSpecInputWebhookCallView<'fb>),
+}
+
+impl<'fb> TaskSpecInputView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::TaskSpecInput) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::TaskSpecInput::TaskSpecInputIngest => {
+                Ok(Self::Ingest(TaskSpecInputIngestView::from(unsafe {
+                    fb::TaskSpecInputIngest::init_from_table(table)
+                })))
+            }
+            fb::TaskSpecInput::TaskSpecInputCompaction => {
+                Ok(Self::Compaction(TaskSpecInputCompactionView::from(
+                    unsafe { fb::TaskSpecInputCompaction::init_from_table(table) },
+                )))
+            }
+            fb::TaskSpecInput::TaskSpecInputGarbageCollection => Ok(Self::GarbageCollection(
+                TaskSpecInputGarbageCollectionView::from(unsafe {
+                    fb::TaskSpecInputGarbageCollection::init_from_table(table)
+                }),
+            )),
+            fb::TaskSpecInput::TaskSpecInputWebhookCall => {
+                Ok(Self::WebhookCall(TaskSpecInputWebhookCallView::from(
+                    unsafe { fb::TaskSpecInputWebhookCall::init_from_table(table) },
+                )))
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "TaskSpecInput",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecInput, FlatbuffersDecodeError> {
+        match self {
+            Self::Ingest(v) => v.to_owned().map(odf::flow::TaskSpecInput::Ingest),
+            Self::Compaction(v) => v.to_owned().map(odf::flow::TaskSpecInput::Compaction),
+            Self::GarbageCollection(v) => v
+                .to_owned()
+                .map(odf::flow::TaskSpecInput::GarbageCollection),
+            Self::WebhookCall(v) => v.to_owned().map(odf::flow::TaskSpecInput::WebhookCall),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputCompaction
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/Compaction
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecInputCompaction`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecInputCompactionView<'fb> {
+    proxy: fb::TaskSpecInputCompaction<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecInputCompaction<'fb>> for TaskSpecInputCompactionView<'fb> {
+    fn from(proxy: fb::TaskSpecInputCompaction<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecInputCompactionView<'fb> {
+    /// Optional parameters to control ingestion behavior.
+    pub fn params(&self) -> Result<Option<CompactionParamsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .params()
+            .map(|v| Ok(CompactionParamsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputCompaction.params"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecInputCompaction, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecInputCompaction::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputGarbageCollection
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/GarbageCollection
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecInputGarbageCollection`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecInputGarbageCollectionView<'fb> {
+    proxy: fb::TaskSpecInputGarbageCollection<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecInputGarbageCollection<'fb>>
+    for TaskSpecInputGarbageCollectionView<'fb>
+{
+    fn from(proxy: fb::TaskSpecInputGarbageCollection<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecInputGarbageCollectionView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::flow::TaskSpecInputGarbageCollection, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecInputGarbageCollection::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputIngest
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/Ingest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecInputIngest`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecInputIngestView<'fb> {
+    proxy: fb::TaskSpecInputIngest<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecInputIngest<'fb>> for TaskSpecInputIngestView<'fb> {
+    fn from(proxy: fb::TaskSpecInputIngest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecInputIngestView<'fb> {
+    /// Reference to the source resource that defines how to fetch data.
+    pub fn source(&self) -> Result<ResourceRefView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .source()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceRefView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputIngest.source"))
+    }
+
+    /// Optional parameters to control ingestion behavior.
+    pub fn params(&self) -> Result<Option<IngestParamsView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .params()
+            .map(|v| Ok(IngestParamsView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputIngest.params"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecInputIngest, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecInputIngest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputWebhookCall
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/WebhookCall
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::flow::TaskSpecInputWebhookCall`]
+#[derive(Debug, Clone, Copy)]
+pub struct TaskSpecInputWebhookCallView<'fb> {
+    proxy: fb::TaskSpecInputWebhookCall<'fb>,
+}
+
+impl<'fb> From<fb::TaskSpecInputWebhookCall<'fb>> for TaskSpecInputWebhookCallView<'fb> {
+    fn from(proxy: fb::TaskSpecInputWebhookCall<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TaskSpecInputWebhookCallView<'fb> {
+    /// Reference to the `WebhookTarget`.
+    pub fn target(&self) -> Result<ResourceRefView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .target()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(ResourceRefView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputWebhookCall.target"))
+    }
+
+    /// The payload to send. May include templating.
+    pub fn payload(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .payload()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputWebhookCall.payload"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::flow::TaskSpecInputWebhookCall, FlatbuffersDecodeError> {
+        odf::flow::TaskSpecInputWebhookCall::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TemporalTable
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/TemporalTable
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::TemporalTable`]
+#[derive(Debug, Clone, Copy)]
+pub struct TemporalTableView<'fb> {
+    proxy: fb::TemporalTable<'fb>,
+}
+
+impl<'fb> From<fb::TemporalTable<'fb>> for TemporalTableView<'fb> {
+    fn from(proxy: fb::TemporalTable<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TemporalTableView<'fb> {
+    /// Name of the dataset to be converted into a temporal table.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TemporalTable.name"))
+    }
+
+    /// Column names used as the primary key for creating a table.
+    pub fn primary_key(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .primary_key()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(i))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TemporalTable.primaryKey"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::TemporalTable, FlatbuffersDecodeError> {
+        odf::dataset::TemporalTable::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransferSizeEstimation
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferSizeEstimation
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::TransferSizeEstimation`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransferSizeEstimationView<'fb> {
+    proxy: fb::TransferSizeEstimation<'fb>,
+}
+
+impl<'fb> From<fb::TransferSizeEstimation<'fb>> for TransferSizeEstimationView<'fb> {
+    fn from(proxy: fb::TransferSizeEstimation<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransferSizeEstimationView<'fb> {
+    /// Number of blocks about to transfer.
+    pub fn num_blocks(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.num_blocks())
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.numBlocks"))
+    }
+
+    /// Number of objects about to transfer (can be 0 for pure declarative metadata).
+    pub fn num_objects(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.num_objects())
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.numObjects"))
+    }
+
+    /// Raw (without compression) size of the blocks about to transfer.
+    pub fn bytes_in_raw_blocks(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.bytes_in_raw_blocks())
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.bytesInRawBlocks"))
+    }
+
+    /// Raw (without compression) size of the object files about to transfer.
+    pub fn bytes_in_raw_objects(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.bytes_in_raw_objects())
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.bytesInRawObjects"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::protocol::TransferSizeEstimation, FlatbuffersDecodeError> {
+        odf::protocol::TransferSizeEstimation::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransferUrl
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferUrl
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::protocol::TransferUrl`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransferUrlView<'fb> {
+    proxy: fb::TransferUrl<'fb>,
+}
+
+impl<'fb> From<fb::TransferUrl<'fb>> for TransferUrlView<'fb> {
+    fn from(proxy: fb::TransferUrl<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransferUrlView<'fb> {
+    /// URL to transfer the object file with.
+    pub fn url(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .url()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransferUrl.url"))
+    }
+
+    /// Time after which the URL can no longer be used.
+    pub fn expires_at(&self) -> Result<Option<DateTime<Utc>>, FlatbuffersDecodeError> {
+        self.proxy
+            .expires_at()
+            .map(|v| fb_to_datetime(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransferUrl.expiresAt"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::protocol::TransferUrl, FlatbuffersDecodeError> {
+        odf::protocol::TransferUrl::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Transform
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Transform
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::Transform`]
+#[derive(Debug, Clone, Copy)]
+pub enum TransformView<'fb> {
+    Sql(TransformSqlView<'fb>),
+}
+
+impl<'fb> TransformView<'fb> {
+    pub fn new(table: Table<'fb>, t: fb::Transform) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::Transform::TransformSql => Ok(Self::Sql(TransformSqlView::from(unsafe {
+                fb::TransformSql::init_from_table(table)
+            }))),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "Transform",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::Transform, FlatbuffersDecodeError> {
+        match self {
+            Self::Sql(v) => v.to_owned().map(odf::dataset::Transform::Sql),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformSql
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Transform#/$defs/Sql
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::TransformSql`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformSqlView<'fb> {
+    proxy: fb::TransformSql<'fb>,
+}
+
+impl<'fb> From<fb::TransformSql<'fb>> for TransformSqlView<'fb> {
+    fn from(proxy: fb::TransformSql<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformSqlView<'fb> {
+    /// Identifier of the engine used for this transformation.
+    pub fn engine(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .engine()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.engine"))
+    }
+
+    /// Version of the engine to use.
+    pub fn version(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .version()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.version"))
+    }
+
+    /// SQL query the result of which will be used as an output. This is a convenience property meant only for defining queries by hand. When stored in the metadata this property will never be set and instead will be converted into a single-iter `queries` array.
+    pub fn query(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .query()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.query"))
+    }
+
+    /// Specifies multi-step SQL transformations. Each step acts as a shorthand for `CREATE TEMPORARY VIEW <alias> AS (<query>)`. Last query in the array should have no alias and will be treated as an output.
+    pub fn queries(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<SqlQueryStepView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .queries()
+            .map(|v| Ok(v.iter().map(|i| Ok(SqlQueryStepView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.queries"))
+    }
+
+    /// Temporary Flink-specific extension for creating temporal tables from streams.
+    pub fn temporal_tables(
+        &self,
+    ) -> Result<
+        Option<
+            impl ExactSizeIterator<Item = Result<TemporalTableView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        >,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .temporal_tables()
+            .map(|v| Ok(v.iter().map(|i| Ok(TemporalTableView::from(i)))))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.temporalTables"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::TransformSql, FlatbuffersDecodeError> {
+        odf::dataset::TransformSql::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/TransformInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::TransformInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformInputView<'fb> {
+    proxy: fb::TransformInput<'fb>,
+}
+
+impl<'fb> From<fb::TransformInput<'fb>> for TransformInputView<'fb> {
+    fn from(proxy: fb::TransformInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformInputView<'fb> {
+    /// A local or remote dataset reference. When block is accepted this MUST be in the form of a DatasetId to guarantee reproducibility, as aliases can change over time.
+    pub fn dataset_ref(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_ref()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformInput.datasetRef"))
+    }
+
+    /// An alias under which this input will be available in queries. Will be populated from `datasetRef` if not provided before resolving it to DatasetId.
+    pub fn alias(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .alias()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformInput.alias"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::TransformInput, FlatbuffersDecodeError> {
+        odf::dataset::TransformInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformRequest
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::TransformRequest`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformRequestView<'fb> {
+    proxy: fb::TransformRequest<'fb>,
+}
+
+impl<'fb> From<fb::TransformRequest<'fb>> for TransformRequestView<'fb> {
+    fn from(proxy: fb::TransformRequest<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformRequestView<'fb> {
+    /// Unique identifier of the output dataset.
+    pub fn dataset_id(&self) -> Result<odf::dataset::DatasetID, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.datasetId"))
+    }
+
+    /// Alias of the output dataset, for logging purposes only.
+    pub fn dataset_alias(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_alias()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.datasetAlias"))
+    }
+
+    /// System time to use for new records.
+    pub fn system_time(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        self.proxy
+            .system_time()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| fb_to_datetime(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.systemTime"))
+    }
+
+    /// Vocabulary of the output dataset.
+    pub fn vocab(&self) -> Result<DatasetVocabularyView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .vocab()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(DatasetVocabularyView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.vocab"))
+    }
+
+    /// Transformation that will be applied to produce new data.
+    pub fn transform(&self) -> Result<TransformView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .transform()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| TransformView::new(v, self.proxy.transform_type()))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.transform"))
+    }
+
+    /// Defines inputs used in this transaction. Slices corresponding to every input dataset must be present.
+    pub fn query_inputs(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<TransformRequestInputView<'fb>, FlatbuffersDecodeError>>
+        + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .query_inputs()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(TransformRequestInputView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.queryInputs"))
+    }
+
+    /// Starting offset to use for new data records.
+    pub fn next_offset(&self) -> Result<u64, FlatbuffersDecodeError> {
+        Ok(self.proxy.next_offset())
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.nextOffset"))
+    }
+
+    /// TODO: This will be removed when coordinator will be speaking to engines purely through Arrow.
+    pub fn prev_checkpoint_path(&self) -> Result<Option<&'fb Path>, FlatbuffersDecodeError> {
+        self.proxy
+            .prev_checkpoint_path()
+            .map(|v| Ok(Path::new(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.prevCheckpointPath"))
+    }
+
+    /// TODO: This will be removed when coordinator will be speaking to engines purely through Arrow.
+    pub fn new_checkpoint_path(&self) -> Result<&'fb Path, FlatbuffersDecodeError> {
+        self.proxy
+            .new_checkpoint_path()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(Path::new(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.newCheckpointPath"))
+    }
+
+    /// TODO: This will be removed when coordinator will be speaking to engines purely through Arrow.
+    pub fn new_data_path(&self) -> Result<&'fb Path, FlatbuffersDecodeError> {
+        self.proxy
+            .new_data_path()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(Path::new(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.newDataPath"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::engine::TransformRequest, FlatbuffersDecodeError> {
+        odf::engine::TransformRequest::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformRequestInput
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformRequestInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::TransformRequestInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformRequestInputView<'fb> {
+    proxy: fb::TransformRequestInput<'fb>,
+}
+
+impl<'fb> From<fb::TransformRequestInput<'fb>> for TransformRequestInputView<'fb> {
+    fn from(proxy: fb::TransformRequestInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformRequestInputView<'fb> {
+    /// Unique identifier of the dataset.
+    pub fn dataset_id(&self) -> Result<odf::dataset::DatasetID, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::dataset::DatasetID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.datasetId"))
+    }
+
+    /// Alias of the output dataset, for logging purposes only.
+    pub fn dataset_alias(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .dataset_alias()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.datasetAlias"))
+    }
+
+    /// An alias of this input to be used in queries.
+    pub fn query_alias(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .query_alias()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.queryAlias"))
+    }
+
+    /// Vocabulary of the input dataset.
+    pub fn vocab(&self) -> Result<DatasetVocabularyView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .vocab()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(DatasetVocabularyView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.vocab"))
+    }
+
+    /// Subset of data that goes into this transaction.
+    pub fn offset_interval(
+        &self,
+    ) -> Result<Option<OffsetIntervalView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .offset_interval()
+            .map(|v| Ok(OffsetIntervalView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.offsetInterval"))
+    }
+
+    /// TODO: This will be removed when coordinator will be slicing data for the engine.
+    pub fn data_paths(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<&'fb Path, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .data_paths()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(Path::new(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.dataPaths"))
+    }
+
+    /// TODO: replace with actual DDL or Parquet schema.
+    pub fn schema_file(&self) -> Result<&'fb Path, FlatbuffersDecodeError> {
+        self.proxy
+            .schema_file()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(Path::new(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.schemaFile"))
+    }
+
+    /// Watermarks that should be injected into the stream to separate micro batches for reproducibility.
+    pub fn explicit_watermarks(
+        &self,
+    ) -> Result<
+        impl ExactSizeIterator<Item = Result<WatermarkView<'fb>, FlatbuffersDecodeError>> + 'fb,
+        FlatbuffersDecodeError,
+    > {
+        self.proxy
+            .explicit_watermarks()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v.iter().map(|i| Ok(WatermarkView::from(i)))))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.explicitWatermarks"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::engine::TransformRequestInput, FlatbuffersDecodeError> {
+        odf::engine::TransformRequestInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponse
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::TransformResponse`]
+#[derive(Debug, Clone, Copy)]
+pub enum TransformResponseView<'fb> {
+    Progress(TransformResponseProgressView<'fb>),
+    Success(TransformResponseSuccessView<'fb>),
+    InvalidQuery(TransformResponseInvalidQueryView<'fb>),
+    InternalError(TransformResponseInternalErrorView<'fb>),
+}
+
+impl<'fb> TransformResponseView<'fb> {
+    pub fn new(
+        table: Table<'fb>,
+        t: fb::TransformResponse,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::TransformResponse::TransformResponseProgress => {
+                Ok(Self::Progress(TransformResponseProgressView::from(
+                    unsafe { fb::TransformResponseProgress::init_from_table(table) },
+                )))
+            }
+            fb::TransformResponse::TransformResponseSuccess => {
+                Ok(Self::Success(TransformResponseSuccessView::from(unsafe {
+                    fb::TransformResponseSuccess::init_from_table(table)
+                })))
+            }
+            fb::TransformResponse::TransformResponseInvalidQuery => {
+                Ok(Self::InvalidQuery(TransformResponseInvalidQueryView::from(
+                    unsafe { fb::TransformResponseInvalidQuery::init_from_table(table) },
+                )))
+            }
+            fb::TransformResponse::TransformResponseInternalError => Ok(Self::InternalError(
+                TransformResponseInternalErrorView::from(unsafe {
+                    fb::TransformResponseInternalError::init_from_table(table)
+                }),
+            )),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "TransformResponse",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::engine::TransformResponse, FlatbuffersDecodeError> {
+        match self {
+            Self::Progress(v) => v.to_owned().map(odf::engine::TransformResponse::Progress),
+            Self::Success(v) => v.to_owned().map(odf::engine::TransformResponse::Success),
+            Self::InvalidQuery(v) => v
+                .to_owned()
+                .map(odf::engine::TransformResponse::InvalidQuery),
+            Self::InternalError(v) => v
+                .to_owned()
+                .map(odf::engine::TransformResponse::InternalError),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseInternalError
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/InternalError
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::TransformResponseInternalError`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformResponseInternalErrorView<'fb> {
+    proxy: fb::TransformResponseInternalError<'fb>,
+}
+
+impl<'fb> From<fb::TransformResponseInternalError<'fb>>
+    for TransformResponseInternalErrorView<'fb>
+{
+    fn from(proxy: fb::TransformResponseInternalError<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformResponseInternalErrorView<'fb> {
+    /// Brief description of an error
+    pub fn message(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .message()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformResponseInternalError.message"))
+    }
+
+    /// Details of an error (e.g. a backtrace)
+    pub fn backtrace(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .backtrace()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformResponseInternalError.backtrace"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::engine::TransformResponseInternalError, FlatbuffersDecodeError> {
+        odf::engine::TransformResponseInternalError::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseInvalidQuery
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/InvalidQuery
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::TransformResponseInvalidQuery`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformResponseInvalidQueryView<'fb> {
+    proxy: fb::TransformResponseInvalidQuery<'fb>,
+}
+
+impl<'fb> From<fb::TransformResponseInvalidQuery<'fb>> for TransformResponseInvalidQueryView<'fb> {
+    fn from(proxy: fb::TransformResponseInvalidQuery<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformResponseInvalidQueryView<'fb> {
+    /// Explanation of an error
+    pub fn message(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .message()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformResponseInvalidQuery.message"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::engine::TransformResponseInvalidQuery, FlatbuffersDecodeError> {
+        odf::engine::TransformResponseInvalidQuery::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseProgress
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/Progress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::TransformResponseProgress`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformResponseProgressView<'fb> {
+    proxy: fb::TransformResponseProgress<'fb>,
+}
+
+impl<'fb> From<fb::TransformResponseProgress<'fb>> for TransformResponseProgressView<'fb> {
+    fn from(proxy: fb::TransformResponseProgress<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformResponseProgressView<'fb> {
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::engine::TransformResponseProgress, FlatbuffersDecodeError> {
+        odf::engine::TransformResponseProgress::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseSuccess
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/Success
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::engine::TransformResponseSuccess`]
+#[derive(Debug, Clone, Copy)]
+pub struct TransformResponseSuccessView<'fb> {
+    proxy: fb::TransformResponseSuccess<'fb>,
+}
+
+impl<'fb> From<fb::TransformResponseSuccess<'fb>> for TransformResponseSuccessView<'fb> {
+    fn from(proxy: fb::TransformResponseSuccess<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> TransformResponseSuccessView<'fb> {
+    /// Data slice produced by the transaction, if any.
+    pub fn new_offset_interval(
+        &self,
+    ) -> Result<Option<OffsetIntervalView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_offset_interval()
+            .map(|v| Ok(OffsetIntervalView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformResponseSuccess.newOffsetInterval"))
+    }
+
+    /// Watermark advanced by the transaction, if any.
+    pub fn new_watermark(&self) -> Result<Option<DateTime<Utc>>, FlatbuffersDecodeError> {
+        self.proxy
+            .new_watermark()
+            .map(|v| fb_to_datetime(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("TransformResponseSuccess.newWatermark"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(
+        &self,
+    ) -> Result<odf::engine::TransformResponseSuccess, FlatbuffersDecodeError> {
+        odf::engine::TransformResponseSuccess::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ValueHandle
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueHandle
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::ValueHandle`]
+#[derive(Debug, Clone, Copy)]
+pub struct ValueHandleView<'fb> {
+    proxy: fb::ValueHandle<'fb>,
+}
+
+impl<'fb> From<fb::ValueHandle<'fb>> for ValueHandleView<'fb> {
+    fn from(proxy: fb::ValueHandle<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ValueHandleView<'fb> {
+    /// Account that owns the target resource.
+    pub fn account(&self) -> Result<AccountHandleView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .account()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(AccountHandleView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.account"))
+    }
+
+    /// Type URI of the target resource.
+    pub fn r#type(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .type_()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.type"))
+    }
+
+    /// ID of the resource within a node.
+    pub fn id(&self) -> Result<odf::resource::ResourceID, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.id"))
+    }
+
+    /// Name of a resource.
+    pub fn name(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.name"))
+    }
+
+    /// JSON path to a value within a `VariableSet` or a `SecretSet`.
+    pub fn path(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .path()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.path"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::ValueHandle, FlatbuffersDecodeError> {
+        odf::config::ValueHandle::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ValueRef
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::ValueRef`]
+#[derive(Debug, Clone, Copy)]
+pub struct ValueRefView<'fb> {
+    proxy: fb::ValueRef<'fb>,
+}
+
+impl<'fb> From<fb::ValueRef<'fb>> for ValueRefView<'fb> {
+    fn from(proxy: fb::ValueRef<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ValueRefView<'fb> {
+    /// Reference to an account that owns the `VariableSet` or the `SecretSet`.
+    pub fn account(&self) -> Result<Option<AccountRefView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .account()
+            .map(|v| Ok(AccountRefView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.account"))
+    }
+
+    /// Short type name or full type URI of the target resource.
+    pub fn r#type(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .type_()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.type"))
+    }
+
+    /// ID of a resource.
+    pub fn id(&self) -> Result<Option<odf::resource::ResourceID>, FlatbuffersDecodeError> {
+        self.proxy
+            .id()
+            .map(|v| {
+                odf::resource::ResourceID::from_bytes(v.bytes())
+                    .map_err(FlatbuffersDecodeError::invalid_value)
+            })
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.id"))
+    }
+
+    /// Name of a resource.
+    pub fn name(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .name()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.name"))
+    }
+
+    /// JSON path to a value within a `VariableSet` or a `SecretSet`.
+    pub fn path(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .path()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.path"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::ValueRef, FlatbuffersDecodeError> {
+        odf::config::ValueRef::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ValueRefs
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRefs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::ValueRefs`]
+#[derive(Debug, Clone, Copy)]
+pub struct ValueRefsView<'fb> {
+    proxy: fb::ValueRefs<'fb>,
+}
+
+impl<'fb> From<fb::ValueRefs<'fb>> for ValueRefsView<'fb> {
+    fn from(proxy: fb::ValueRefs<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> ValueRefsView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<(&'fb str, ValueRefView<'fb>), FlatbuffersDecodeError>> + 'fb
+    {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(ValueRefView::from(v)))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("ValueRefs.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::ValueRefs, FlatbuffersDecodeError> {
+        odf::config::ValueRefs::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Variable
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Variable
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::Variable`]
+#[derive(Debug, Clone, Copy)]
+pub struct VariableView<'fb> {
+    proxy: fb::Variable<'fb>,
+}
+
+impl<'fb> From<fb::Variable<'fb>> for VariableView<'fb> {
+    fn from(proxy: fb::Variable<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> VariableView<'fb> {
+    /// A value in raw or encoded form.
+    pub fn value(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .value()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Variable.value"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::Variable, FlatbuffersDecodeError> {
+        odf::config::Variable::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// VariableSetSpec
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::VariableSetSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct VariableSetSpecView<'fb> {
+    proxy: fb::VariableSetSpec<'fb>,
+}
+
+impl<'fb> From<fb::VariableSetSpec<'fb>> for VariableSetSpecView<'fb> {
+    fn from(proxy: fb::VariableSetSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> VariableSetSpecView<'fb> {
+    /// Key value pairs of variables.
+    pub fn variables(&self) -> Result<VariablesView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .variables()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(VariablesView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("VariableSetSpec.variables"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::VariableSetSpec, FlatbuffersDecodeError> {
+        odf::config::VariableSetSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// VariableSetSpecInput
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::VariableSetSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct VariableSetSpecInputView<'fb> {
+    proxy: fb::VariableSetSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::VariableSetSpecInput<'fb>> for VariableSetSpecInputView<'fb> {
+    fn from(proxy: fb::VariableSetSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> VariableSetSpecInputView<'fb> {
+    /// Key value pairs of variables.
+    pub fn variables(&self) -> Result<VariablesView<'fb>, FlatbuffersDecodeError> {
+        self.proxy
+            .variables()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(VariablesView::from(v)))
+            .map_err(|e: FlatbuffersDecodeError| e.at("VariableSetSpecInput.variables"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::VariableSetSpecInput, FlatbuffersDecodeError> {
+        odf::config::VariableSetSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Variables
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Variables
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::config::Variables`]
+#[derive(Debug, Clone, Copy)]
+pub struct VariablesView<'fb> {
+    proxy: fb::Variables<'fb>,
+}
+
+impl<'fb> From<fb::Variables<'fb>> for VariablesView<'fb> {
+    fn from(proxy: fb::Variables<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> VariablesView<'fb> {
+    pub fn entries(
+        &self,
+    ) -> impl ExactSizeIterator<Item = Result<(&'fb str, VariableView<'fb>), FlatbuffersDecodeError>> + 'fb
+    {
+        self.proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| {
+                entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| Ok(VariableView::from(v)))
+                    .map(|value| (entry.key(), value))
+                    .map_err(|e: FlatbuffersDecodeError| e.at("Variables.entries"))
+            })
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::config::Variables, FlatbuffersDecodeError> {
+        odf::config::Variables::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// VolumeCapacity
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/VolumeCapacity
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::storage::VolumeCapacity`]
+#[derive(Debug, Clone, Copy)]
+pub struct VolumeCapacityView<'fb> {
+    proxy: fb::VolumeCapacity<'fb>,
+}
+
+impl<'fb> From<fb::VolumeCapacity<'fb>> for VolumeCapacityView<'fb> {
+    fn from(proxy: fb::VolumeCapacity<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> VolumeCapacityView<'fb> {
+    /// Maximum storage size e.g. `10Gi`.
+    pub fn storage(&self) -> Result<Option<ByteSize>, FlatbuffersDecodeError> {
+        self.proxy
+            .storage()
+            .map(|v| Ok(ByteSize::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("VolumeCapacity.storage"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::storage::VolumeCapacity, FlatbuffersDecodeError> {
+        odf::storage::VolumeCapacity::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Watermark
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Watermark
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::dataset::Watermark`]
+#[derive(Debug, Clone, Copy)]
+pub struct WatermarkView<'fb> {
+    proxy: fb::Watermark<'fb>,
+}
+
+impl<'fb> From<fb::Watermark<'fb>> for WatermarkView<'fb> {
+    fn from(proxy: fb::Watermark<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> WatermarkView<'fb> {
+    /// Moment in processing time when watermark was emitted.
+    pub fn system_time(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        self.proxy
+            .system_time()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| fb_to_datetime(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Watermark.systemTime"))
+    }
+
+    /// Moment in event time which watermark has reached.
+    pub fn event_time(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        self.proxy
+            .event_time()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| fb_to_datetime(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("Watermark.eventTime"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::dataset::Watermark, FlatbuffersDecodeError> {
+        odf::dataset::Watermark::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTargetSpec
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::sink::WebhookTargetSpec`]
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookTargetSpecView<'fb> {
+    proxy: fb::WebhookTargetSpec<'fb>,
+}
+
+impl<'fb> From<fb::WebhookTargetSpec<'fb>> for WebhookTargetSpecView<'fb> {
+    fn from(proxy: fb::WebhookTargetSpec<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> WebhookTargetSpecView<'fb> {
+    /// Target url of the webhook.
+    pub fn url(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .url()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpec.url"))
+    }
+
+    /// Shared secret used for HMAC signature of the request payload for authentication.
+    pub fn secret(&self) -> Result<Option<SecretView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .secret()
+            .map(|v| Ok(SecretView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpec.secret"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::sink::WebhookTargetSpec, FlatbuffersDecodeError> {
+        odf::sink::WebhookTargetSpec::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTargetSpecInput
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::sink::WebhookTargetSpecInput`]
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookTargetSpecInputView<'fb> {
+    proxy: fb::WebhookTargetSpecInput<'fb>,
+}
+
+impl<'fb> From<fb::WebhookTargetSpecInput<'fb>> for WebhookTargetSpecInputView<'fb> {
+    fn from(proxy: fb::WebhookTargetSpecInput<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> WebhookTargetSpecInputView<'fb> {
+    /// Target url of the webhook.
+    pub fn url(&self) -> Result<&'fb str, FlatbuffersDecodeError> {
+        self.proxy
+            .url()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| Ok(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpecInput.url"))
+    }
+
+    /// Shared secret used for HMAC signature of the request payload for authentication.
+    pub fn secret(&self) -> Result<Option<SecretView<'fb>>, FlatbuffersDecodeError> {
+        self.proxy
+            .secret()
+            .map(|v| Ok(SecretView::from(v)))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpecInput.secret"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::sink::WebhookTargetSpecInput, FlatbuffersDecodeError> {
+        odf::sink::WebhookTargetSpecInput::deserialize(self.proxy)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTargetStatus
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Read-only view over [`odf::sink::WebhookTargetStatus`]
+#[derive(Debug, Clone, Copy)]
+pub struct WebhookTargetStatusView<'fb> {
+    proxy: fb::WebhookTargetStatus<'fb>,
+}
+
+impl<'fb> From<fb::WebhookTargetStatus<'fb>> for WebhookTargetStatusView<'fb> {
+    fn from(proxy: fb::WebhookTargetStatus<'fb>) -> Self {
+        Self { proxy }
+    }
+}
+
+impl<'fb> WebhookTargetStatusView<'fb> {
+    /// Status value.
+    pub fn value(&self) -> Result<odf::sink::WebhookTargetStatusValue, FlatbuffersDecodeError> {
+        odf::sink::WebhookTargetStatusValue::try_from(self.proxy.value())
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetStatus.value"))
+    }
+
+    /// Time when the value last changed.
+    pub fn last_transition_time(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        self.proxy
+            .last_transition_time()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| fb_to_datetime(v))
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetStatus.lastTransitionTime"))
+    }
+
+    /// Machine-readable cause of the current value, e.g. `ConnectionRefused`.
+    pub fn reason(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .reason()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetStatus.reason"))
+    }
+
+    /// Human-readable details about the current value.
+    pub fn message(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {
+        self.proxy
+            .message()
+            .map(|v| Ok(v))
+            .transpose()
+            .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetStatus.message"))
+    }
+
+    /// Decodes the whole value into an owned DTO
+    pub fn to_owned(&self) -> Result<odf::sink::WebhookTargetStatus, FlatbuffersDecodeError> {
+        odf::sink::WebhookTargetStatus::deserialize(self.proxy)
+    }
+}
@@ -17,6 +17,25 @@ use crate::queries::Dataset;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+#[derive(SimpleObject, Debug, Clone)]
+pub struct UnknownVariant {
+    /// Discriminator of the variant as written by the producer
+    pub tag: String,
+}
+
+impl From<odf::metadata::dtos::UnknownVariant> for UnknownVariant {
+    fn from(v: odf::metadata::dtos::UnknownVariant) -> Self {
+        match v {
+            odf::metadata::dtos::UnknownVariant::Json { tag, .. } => Self { tag },
+            odf::metadata::dtos::UnknownVariant::Flatbuffers { tag, .. } => Self {
+                tag: tag.to_string(),
+            },
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Link to an account.
 ///
 /// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountHandle
@@ -42,6 +61,76 @@ impl From<odf::metadata::auth::AccountHandle> for AccountHandle {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Reference to an account.
+///
+/// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountRef
+#[derive(SimpleObject, Debug, Clone)]
+pub struct AccountRef {
+    /// UUID of the account resource.
+    pub id: Option<ResourceID<'static>>,
+    /// DID of the account.
+    pub did: Option<AccountID<'static>>,
+    /// Name of the account.
+    pub name: Option<AccountName<'static>>,
+}
+
+impl From<odf::metadata::auth::AccountRef> for AccountRef {
+    fn from(v: odf::metadata::auth::AccountRef) -> Self {
+        Self {
+            id: v.id.map(Into::into),
+            did: v.did.map(Into::into),
+            name: v.name.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Predefined account specification.
+///
+/// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountSpec
+#[derive(SimpleObject, Debug, Clone)]
+pub struct AccountSpec {
+    /// DID associated with the account by ODF or an external system
+    pub did: Option<AccountID<'static>>,
+    /// Type of the account.
+    ///
+    /// Defaults to: "User"
+    pub account_type: Option<AccountType>,
+    /// Human-friendly display name.
+    pub display_name: Option<String>,
+    /// Email address of the account.
+    pub email: String,
+    /// URL of the account's avatar image.
+    pub avatar_url: Option<String>,
+}
+
+impl From<odf::metadata::auth::AccountSpec> for AccountSpec {
+    fn from(v: odf::metadata::auth::AccountSpec) -> Self {
+        Self {
+            did: v.did.map(Into::into),
+            account_type: v.account_type.map(Into::into),
+            display_name: v.display_name.map(Into::into),
+            email: v.email.into(),
+            avatar_url: v.avatar_url.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Represents the type of an account.
+///
+/// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountType
+#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]
+#[graphql(remote = "odf::metadata::auth::AccountType")]
+pub enum AccountType {
+    User,
+    Organization,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Indicates that data has been ingested into a root dataset.
 ///
 /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/AddData
@@ -166,6 +255,56 @@ impl From<odf::metadata::dataset::AttachmentsEmbedded> for AttachmentsEmbedded {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// A named attribute attached to a resource, used by auth policies for access control decisions.
+///
+/// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Attribute
+#[derive(SimpleObject, Debug, Clone)]
+pub struct Attribute {
+    /// The resource this attribute is attached to.
+    pub object: ResourceHandle,
+    /// Name of the attribute.
+    ///
+    /// Examples:
+    /// - "allowPublicRead"
+    pub name: String,
+    /// Value of the attribute.
+    pub value: serde_json::Value,
+}
+
+impl From<odf::metadata::auth::Attribute> for Attribute {
+    fn from(v: odf::metadata::auth::Attribute) -> Self {
+        Self {
+            object: v.object.into(),
+            name: v.name.into(),
+            value: v.value.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Access credentials for AWS or an AWS-compatible service.
+///
+/// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentials
+#[derive(SimpleObject, Debug, Clone)]
+pub struct AwsCredentials {
+    /// Reference to a secret containing the AWS access key ID.
+    pub access_key: Option<ValueHandle>,
+    /// Reference to a secret containing the AWS secret access key.
+    pub secret_key: Option<ValueHandle>,
+}
+
+impl From<odf::metadata::storage::AwsCredentials> for AwsCredentials {
+    fn from(v: odf::metadata::storage::AwsCredentials) -> Self {
+        Self {
+            access_key: v.access_key.map(Into::into),
+            secret_key: v.secret_key.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Describes a checkpoint produced by an engine
 ///
 /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Checkpoint
@@ -188,6 +327,28 @@ impl From<odf::metadata::dataset::Checkpoint> for Checkpoint {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Optional parameters to control ingestion behavior.
+///
+/// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/CompactionParams
+#[derive(SimpleObject, Debug, Clone)]
+pub struct CompactionParams {
+    /// Target maximum size of each compacted data slice e.g. `100MiB`.
+    pub max_slice_size: Option<ByteSize>,
+    /// Target maximum number of records per compacted data slice.
+    pub max_slice_records: Option<UInt64>,
+}
+
+impl From<odf::metadata::dataset::CompactionParams> for CompactionParams {
+    fn from(v: odf::metadata::dataset::CompactionParams) -> Self {
+        Self {
+            max_slice_size: v.max_slice_size.map(Into::into),
+            max_slice_records: v.max_slice_records.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Defines a compression algorithm.
 ///
 /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/CompressionFormat
@@ -240,6 +401,70 @@ pub enum DatasetKind {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Represents a desired state of the dataset metadata.
+///
+/// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpec
+#[derive(SimpleObject, Debug, Clone)]
+pub struct DatasetSpec {
+    /// DID of the dataset in global ODF network
+    pub did: DatasetID<'static>,
+    /// Type of the dataset.
+    pub kind: DatasetKind,
+    /// An array of metadata events that will be used to populate the chain. Here you can define polling and push sources, set licenses, add attachments etc.
+    pub metadata: Vec<MetadataEvent>,
+    /// Reference to a storage volume where dataset data will be stored. If omitted, the node's default storage is used.
+    pub volume: ResourceHandle,
+}
+
+impl From<odf::metadata::dataset::DatasetSpec> for DatasetSpec {
+    fn from(v: odf::metadata::dataset::DatasetSpec) -> Self {
+        Self {
+            did: v.did.into(),
+            kind: v.kind.into(),
+            metadata: v.metadata.into_iter().map(Into::into).collect(),
+            volume: v.volume.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Specifies the mapping of system columns onto dataset schema.
+///
+/// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetVocabulary
+#[derive(SimpleObject, Debug, Clone)]
+pub struct DatasetVocabulary {
+    /// Name of the offset column.
+    ///
+    /// Defaults to: "offset"
+    pub offset_column: Option<String>,
+    /// Name of the operation type column.
+    ///
+    /// Defaults to: "op"
+    pub operation_type_column: Option<String>,
+    /// Name of the system time column.
+    ///
+    /// Defaults to: "system_time"
+    pub system_time_column: Option<String>,
+    /// Name of the event time column.
+    ///
+    /// Defaults to: "event_time"
+    pub event_time_column: Option<String>,
+}
+
+impl From<odf::metadata::dataset::DatasetVocabulary> for DatasetVocabulary {
+    fn from(v: odf::metadata::dataset::DatasetVocabulary) -> Self {
+        Self {
+            offset_column: v.offset_column.map(Into::into),
+            operation_type_column: v.operation_type_column.map(Into::into),
+            system_time_column: v.system_time_column.map(Into::into),
+            event_time_column: v.event_time_column.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Disables the previously defined polling source.
 ///
 /// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePollingSource
@@ -297,6 +522,122 @@ impl From<odf::metadata::source::EnvVar> for EnvVar {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Filters that work on domain event types and fields.
+///
+/// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter
+///
+/// Variants are not tagged and are resolved by their shape in order of declaration.
+
+#[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
+pub struct EventFilter(odf::metadata::event::EventFilter);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for EventFilter {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let proxy: odf::metadata::serde::yaml::event::EventFilter =
+            async_graphql::from_value(value)?;
+        let dto = proxy
+            .try_into()
+            .map_err(|e: odf::metadata::errors::ValidationError| {
+                async_graphql::InputValueError::custom(e.to_string())
+            })?;
+        Ok(Self::new(dto))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::event::EventFilter = self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when all of the nested filters match.
+///
+/// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/And
+#[derive(SimpleObject, Debug, Clone)]
+pub struct EventFilterAnd {
+    /// Filters that all must match.
+    pub and: Vec<EventFilter>,
+}
+
+impl From<odf::metadata::event::EventFilterAnd> for EventFilterAnd {
+    fn from(v: odf::metadata::event::EventFilterAnd) -> Self {
+        Self {
+            and: v.and.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when every condition holds for the event field with the same name.
+///
+/// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Match
+
+#[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
+pub struct EventFilterMatch(odf::metadata::event::EventFilterMatch);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for EventFilterMatch {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let proxy: odf::metadata::serde::yaml::event::EventFilterMatch =
+            async_graphql::from_value(value)?;
+        let dto = proxy
+            .try_into()
+            .map_err(|e: odf::metadata::errors::ValidationError| {
+                async_graphql::InputValueError::custom(e.to_string())
+            })?;
+        Ok(Self::new(dto))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::event::EventFilterMatch =
+            self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when the nested filter does not match.
+///
+/// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Not
+#[derive(SimpleObject, Debug, Clone)]
+pub struct EventFilterNot {
+    /// Filter to negate.
+    pub not: Box<EventFilter>,
+}
+
+impl From<odf::metadata::event::EventFilterNot> for EventFilterNot {
+    fn from(v: odf::metadata::event::EventFilterNot) -> Self {
+        Self {
+            not: Box::new((*v.not).into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when at least one of the nested filters matches.
+///
+/// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Or
+#[derive(SimpleObject, Debug, Clone)]
+pub struct EventFilterOr {
+    /// Filters of which at least one must match.
+    pub or: Vec<EventFilter>,
+}
+
+impl From<odf::metadata::event::EventFilterOr> for EventFilterOr {
+    fn from(v: odf::metadata::event::EventFilterOr) -> Self {
+        Self {
+            or: v.or.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Defines the external source of data.
 ///
 /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource
@@ -594,8 +935,6 @@ pub struct FetchStepMqtt {
     pub port: i32,
     /// Username to use for auth with the broker.
     pub username: Option<String>,
-    /// Password to use for auth with the broker (can be templated).
-    pub password: Option<String>,
     /// List of topic subscription parameters.
     pub topics: Vec<MqttTopicSubscription>,
 }
@@ -606,7 +945,6 @@ impl From<odf::metadata::legacy::FetchStepMqtt> for FetchStepMqtt {
             host: v.host.into(),
             port: v.port.into(),
             username: v.username.map(Into::into),
-            password: v.password.map(Into::into),
             topics: v.topics.into_iter().map(Into::into).collect(),
         }
     }
@@ -642,95 +980,630 @@ impl From<odf::metadata::legacy::FetchStepUrl> for FetchStepUrl {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Merge strategy determines how newly ingested data should be combined with the data that already exists in the dataset.
+/// Condition on a single value of a filtered object. The short string form matches values equal to the string. Multiple operators must all hold.
 ///
-/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy
-#[derive(Union, Debug, Clone)]
-pub enum MergeStrategy {
-    Append(MergeStrategyAppend),
-    Ledger(MergeStrategyLedger),
-    Snapshot(MergeStrategySnapshot),
-    ChangelogStream(MergeStrategyChangelogStream),
-    UpsertStream(MergeStrategyUpsertStream),
-}
-
-impl From<odf::metadata::source::MergeStrategy> for MergeStrategy {
-    fn from(v: odf::metadata::source::MergeStrategy) -> Self {
-        match v {
-            odf::metadata::source::MergeStrategy::Append(v) => Self::Append(v.into()),
-            odf::metadata::source::MergeStrategy::Ledger(v) => Self::Ledger(v.into()),
-            odf::metadata::source::MergeStrategy::Snapshot(v) => Self::Snapshot(v.into()),
-            odf::metadata::source::MergeStrategy::ChangelogStream(v) => {
-                Self::ChangelogStream(v.into())
-            }
-            odf::metadata::source::MergeStrategy::UpsertStream(v) => Self::UpsertStream(v.into()),
+/// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/FilterCondition
+#[derive(SimpleObject, Debug, Clone)]
+pub struct FilterCondition {
+    /// Matches values equal to the specified one, use for non-string values.
+    pub eq: Option<serde_json::Value>,
+    /// Matches values equal to any of the specified ones.
+    pub r#in: Option<Vec<serde_json::Value>>,
+    /// Matches when the value is present (`true`) or absent (`false`).
+    pub exists: Option<bool>,
+    /// Matches string values against a glob pattern where `*` stands for any sequence of characters and `?` for any single character.
+    pub glob: Option<String>,
+}
+
+impl From<odf::metadata::resource::FilterCondition> for FilterCondition {
+    fn from(v: odf::metadata::resource::FilterCondition) -> Self {
+        Self {
+            eq: v.eq.map(Into::into),
+            r#in: v.r#in.map(|v| v.into_iter().map(Into::into).collect()),
+            exists: v.exists.map(Into::into),
+            glob: v.glob.map(Into::into),
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Append merge strategy.
-///
-/// Under this strategy new data will be appended to the dataset in its entirety, without any deduplication.
+/// Defines a sequence of tasks to be executed upon certain trigger conditions.
 ///
-/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Append
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpec
 #[derive(SimpleObject, Debug, Clone)]
-pub struct MergeStrategyAppend {
-    pub _dummy: Option<String>,
+pub struct FlowSpec {
+    /// Defines resources for which this flow will be instantiated.
+    pub target: ResourceSelector,
+    /// Conditions that cause this flow to execute.
+    pub triggers: Vec<FlowTrigger>,
+    /// List of tasks to run consecutively.
+    pub tasks: Vec<TaskSpec>,
 }
 
-impl From<odf::metadata::source::MergeStrategyAppend> for MergeStrategyAppend {
-    fn from(v: odf::metadata::source::MergeStrategyAppend) -> Self {
-        Self { _dummy: None }
+impl From<odf::metadata::flow::FlowSpec> for FlowSpec {
+    fn from(v: odf::metadata::flow::FlowSpec) -> Self {
+        Self {
+            target: v.target.into(),
+            triggers: v.triggers.into_iter().map(Into::into).collect(),
+            tasks: v.tasks.into_iter().map(Into::into).collect(),
+        }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Changelog stream merge strategy.
+/// Condition that causes a flow to be executed.
 ///
-/// This is the native stream format for ODF that accurately describes the evolution of all event records including appends, retractions, and corrections as per RFC-015. No pre-processing except for format validation is done.
-///
-/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/ChangelogStream
-#[derive(SimpleObject, Debug, Clone)]
-pub struct MergeStrategyChangelogStream {
-    /// Names of the columns that uniquely identify the record throughout its lifetime
-    pub primary_key: Vec<String>,
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger
+#[derive(Union, Debug, Clone)]
+pub enum FlowTrigger {
+    Schedule(FlowTriggerSchedule),
+    Event(FlowTriggerEvent),
+    Source(FlowTriggerSource),
+    Dataset(FlowTriggerDataset),
 }
 
-impl From<odf::metadata::source::MergeStrategyChangelogStream> for MergeStrategyChangelogStream {
-    fn from(v: odf::metadata::source::MergeStrategyChangelogStream) -> Self {
-        Self {
-            primary_key: v.primary_key.into_iter().map(Into::into).collect(),
+impl From<odf::metadata::flow::FlowTrigger> for FlowTrigger {
+    fn from(v: odf::metadata::flow::FlowTrigger) -> Self {
+        match v {
+            odf::metadata::flow::FlowTrigger::Schedule(v) => Self::Schedule(v.into()),
+            odf::metadata::flow::FlowTrigger::Event(v) => Self::Event(v.into()),
+            odf::metadata::flow::FlowTrigger::Source(v) => Self::Source(v.into()),
+            odf::metadata::flow::FlowTrigger::Dataset(v) => Self::Dataset(v.into()),
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Ledger merge strategy.
-///
-/// This strategy should be used for data sources containing ledgers of events. Currently this strategy will only perform deduplication of events using user-specified primary key columns. This means that the source data can contain partially overlapping set of records and only those records that were not previously seen will be appended.
+/// Triggers the flow when matching datasets are updated.
 ///
-/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Ledger
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Dataset
 #[derive(SimpleObject, Debug, Clone)]
-pub struct MergeStrategyLedger {
-    /// Names of the columns that uniquely identify the record throughout its lifetime
-    pub primary_key: Vec<String>,
+pub struct FlowTriggerDataset {
+    /// Selector that identifies which datasets can trigger this flow.
+    pub dataset: DatasetSelector,
+    /// Set of event bus event IDs that this trigger will react to
+    pub events: Option<Vec<String>>,
 }
 
-impl From<odf::metadata::source::MergeStrategyLedger> for MergeStrategyLedger {
-    fn from(v: odf::metadata::source::MergeStrategyLedger) -> Self {
+impl From<odf::metadata::flow::FlowTriggerDataset> for FlowTriggerDataset {
+    fn from(v: odf::metadata::flow::FlowTriggerDataset) -> Self {
         Self {
-            primary_key: v.primary_key.into_iter().map(Into::into).collect(),
+            dataset: v.dataset.into(),
+            events: v.events.map(|v| v.into_iter().map(Into::into).collect()),
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Snapshot merge strategy.
+/// Triggers the flow when an event bus event matching one of the filters is observed.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Event
+#[derive(SimpleObject, Debug, Clone)]
+pub struct FlowTriggerEvent {
+    /// Filters the event by type and fields.
+    pub events: EventFilter,
+    /// The trigger will fire upon first observed event. If another event arrives withing the `cooldown` interval the firing will be postponed until `cooldown` interval ends. I.e. trigger is guaranteed to fire, but may batch multiple events together into one flow run.
+    pub cooldown: Option<DurationString>,
+    /// If an event is observed a `cooldownMaxBatch` number of times during the `cooldown` interval it will fire the trigger without waiting for cooldown to finish.
+    pub cooldown_max_batch: Option<UInt64>,
+}
+
+impl From<odf::metadata::flow::FlowTriggerEvent> for FlowTriggerEvent {
+    fn from(v: odf::metadata::flow::FlowTriggerEvent) -> Self {
+        Self {
+            events: v.events.into(),
+            cooldown: v.cooldown.map(Into::into),
+            cooldown_max_batch: v.cooldown_max_batch.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Triggers the flow on a cron schedule.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Schedule
+#[derive(SimpleObject, Debug, Clone)]
+pub struct FlowTriggerSchedule {
+    /// Cron5 expression defining the schedule e.g. `@daily` or `*/30 * * * *`.
+    pub cron: String,
+}
+
+impl From<odf::metadata::flow::FlowTriggerSchedule> for FlowTriggerSchedule {
+    fn from(v: odf::metadata::flow::FlowTriggerSchedule) -> Self {
+        Self {
+            cron: v.cron.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Triggers the flow when a source receives new data, with optional batching controls.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Source
+#[derive(SimpleObject, Debug, Clone)]
+pub struct FlowTriggerSource {
+    /// Reference to the source resource that drives this trigger.
+    pub source: ResourceHandle,
+    /// Minimum number of new records to accumulate before triggering.
+    pub min_records_to_await: Option<UInt64>,
+    /// Maximum time to wait for `minRecordsToAwait` before triggering anyway e.g. `1h`.
+    pub max_await_interval: Option<DurationString>,
+}
+
+impl From<odf::metadata::flow::FlowTriggerSource> for FlowTriggerSource {
+    fn from(v: odf::metadata::flow::FlowTriggerSource) -> Self {
+        Self {
+            source: v.source.into(),
+            min_records_to_await: v.min_records_to_await.map(Into::into),
+            max_await_interval: v.max_await_interval.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Optional parameters to control ingestion behavior.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngestParams
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngestParams {
+    /// Target number of records to ingest per data slice.
+    pub target_slice_records: Option<UInt64>,
+}
+
+impl From<odf::metadata::source::IngestParams> for IngestParams {
+    fn from(v: odf::metadata::source::IngestParams) -> Self {
+        Self {
+            target_slice_records: v.target_slice_records.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines the point where data enters the system.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress
+#[derive(Union, Debug, Clone)]
+pub enum Ingress {
+    Url(IngressUrl),
+    FilesGlob(IngressFilesGlob),
+    Container(IngressContainer),
+    Mqtt(IngressMqtt),
+    EvmLogs(IngressEvmLogs),
+    RestEndpoint(IngressRestEndpoint),
+}
+
+impl From<odf::metadata::source::Ingress> for Ingress {
+    fn from(v: odf::metadata::source::Ingress) -> Self {
+        match v {
+            odf::metadata::source::Ingress::Url(v) => Self::Url(v.into()),
+            odf::metadata::source::Ingress::FilesGlob(v) => Self::FilesGlob(v.into()),
+            odf::metadata::source::Ingress::Container(v) => Self::Container(v.into()),
+            odf::metadata::source::Ingress::Mqtt(v) => Self::Mqtt(v.into()),
+            odf::metadata::source::Ingress::EvmLogs(v) => Self::EvmLogs(v.into()),
+            odf::metadata::source::Ingress::RestEndpoint(v) => Self::RestEndpoint(v.into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Runs the specified OCI container to fetch data from an arbitrary source.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Container
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngressContainer {
+    /// Image name and and an optional tag.
+    pub image: String,
+    /// Specifies the entrypoint. Not executed within a shell. The default OCI image's ENTRYPOINT is used if this is not provided.
+    pub command: Option<Vec<String>>,
+    /// Arguments to the entrypoint. The OCI image's CMD is used if this is not provided.
+    pub args: Option<Vec<String>>,
+    /// Environment variables to propagate into or set in the container.
+    pub env: Option<Vec<EnvVar>>,
+}
+
+impl From<odf::metadata::source::IngressContainer> for IngressContainer {
+    fn from(v: odf::metadata::source::IngressContainer) -> Self {
+        Self {
+            image: v.image.into(),
+            command: v.command.map(|v| v.into_iter().map(Into::into).collect()),
+            args: v.args.map(|v| v.into_iter().map(Into::into).collect()),
+            env: v.env.map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Connects to an EVM (Ethereum) node to stream transaction logs.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/EvmLogs
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngressEvmLogs {
+    /// Identifier of the chain to scan logs from. This parameter may be used for RPC endpoint lookup as well as asserting that provided `nodeUrl` corresponds to the expected chain.
+    pub chain_id: Option<UInt64>,
+    /// Url of the node.
+    pub node_url: Option<String>,
+    /// An SQL WHERE clause that can be used to pre-filter the logs before fetching them from the ETH node.
+    ///
+    /// Examples:
+    /// - "block_number > 123 and address = X'5fbdb2315678afecb367f032d93f642f64180aa3' and topic1 = X'000000000000000000000000f39fd6e51aad88f6f4ce6ab8827279cfffb92266'"
+    pub filter: Option<String>,
+    /// Solidity log event signature to use for decoding. Using this field adds `event` to the output containing decoded log as JSON.
+    pub signature: Option<String>,
+}
+
+impl From<odf::metadata::source::IngressEvmLogs> for IngressEvmLogs {
+    fn from(v: odf::metadata::source::IngressEvmLogs) -> Self {
+        Self {
+            chain_id: v.chain_id.map(Into::into),
+            node_url: v.node_url.map(Into::into),
+            filter: v.filter.map(Into::into),
+            signature: v.signature.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Uses glob operator to match files on the local file system.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/FilesGlob
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngressFilesGlob {
+    /// Path with a glob pattern.
+    pub path: String,
+    /// Describes how event time is extracted from the source metadata.
+    pub event_time: Option<EventTimeSource>,
+    /// Describes the caching settings used for this source.
+    pub cache: Option<SourceCaching>,
+    /// Specifies how input files should be ordered before ingestion.
+    /// Order is important as every file will be processed individually
+    /// and will advance the dataset's watermark.
+    pub order: Option<SourceOrdering>,
+}
+
+impl From<odf::metadata::source::IngressFilesGlob> for IngressFilesGlob {
+    fn from(v: odf::metadata::source::IngressFilesGlob) -> Self {
+        Self {
+            path: v.path.into(),
+            event_time: v.event_time.map(Into::into),
+            cache: v.cache.map(Into::into),
+            order: v.order.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Connects to an MQTT broker to fetch events from the specified topic.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Mqtt
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngressMqtt {
+    /// Hostname of the MQTT broker.
+    pub host: String,
+    /// Port of the MQTT broker.
+    pub port: i32,
+    /// Username to use for auth with the broker.
+    pub username: Option<String>,
+    /// List of topic subscription parameters.
+    pub topics: Vec<MqttTopicSubscription>,
+}
+
+impl From<odf::metadata::source::IngressMqtt> for IngressMqtt {
+    fn from(v: odf::metadata::source::IngressMqtt) -> Self {
+        Self {
+            host: v.host.into(),
+            port: v.port.into(),
+            username: v.username.map(Into::into),
+            topics: v.topics.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Exposes a REST HTTP endpoint that accepts pushed data records.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/RestEndpoint
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngressRestEndpoint {
+    /// Buffer configuration for holding records until they are ingested.
+    pub buffer: Option<IngressBuffer>,
+}
+
+impl From<odf::metadata::source::IngressRestEndpoint> for IngressRestEndpoint {
+    fn from(v: odf::metadata::source::IngressRestEndpoint) -> Self {
+        Self {
+            buffer: v.buffer.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Pulls data from one of the supported sources by its URL.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Url
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngressUrl {
+    /// URL of the data source
+    pub url: String,
+    /// Describes how event time is extracted from the source metadata.
+    pub event_time: Option<EventTimeSource>,
+    /// Describes the caching settings used for this source.
+    pub cache: Option<SourceCaching>,
+    /// Headers to pass during the request (e.g. HTTP Authorization)
+    pub headers: Option<Vec<RequestHeader>>,
+}
+
+impl From<odf::metadata::source::IngressUrl> for IngressUrl {
+    fn from(v: odf::metadata::source::IngressUrl) -> Self {
+        Self {
+            url: v.url.into(),
+            event_time: v.event_time.map(Into::into),
+            cache: v.cache.map(Into::into),
+            headers: v.headers.map(|v| v.into_iter().map(Into::into).collect()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Buffer configuration for holding pushed records until they are ingested.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer
+#[derive(Union, Debug, Clone)]
+pub enum IngressBuffer {
+    Memory(IngressBufferMemory),
+}
+
+impl From<odf::metadata::source::IngressBuffer> for IngressBuffer {
+    fn from(v: odf::metadata::source::IngressBuffer) -> Self {
+        match v {
+            odf::metadata::source::IngressBuffer::Memory(v) => Self::Memory(v.into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// An in-memory buffer.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer#/$defs/Memory
+#[derive(SimpleObject, Debug, Clone)]
+pub struct IngressBufferMemory {
+    /// Maximum number of records to hold in the buffer.
+    pub buffer_size: Option<UInt64>,
+    /// Policy applied when the buffer is full.
+    pub overflow_policy: Option<String>,
+}
+
+impl From<odf::metadata::source::IngressBufferMemory> for IngressBufferMemory {
+    fn from(v: odf::metadata::source::IngressBufferMemory) -> Self {
+        Self {
+            buffer_size: v.buffer_size.map(Into::into),
+            overflow_policy: v.overflow_policy.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Filters that work on resource labels and identity headers.
+///
+/// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
+///
+/// Variants are not tagged and are resolved by their shape in order of declaration.
+
+#[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
+pub struct LabelFilter(odf::metadata::resource::LabelFilter);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for LabelFilter {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let proxy: odf::metadata::serde::yaml::resource::LabelFilter =
+            async_graphql::from_value(value)?;
+        let dto = proxy
+            .try_into()
+            .map_err(|e: odf::metadata::errors::ValidationError| {
+                async_graphql::InputValueError::custom(e.to_string())
+            })?;
+        Ok(Self::new(dto))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::resource::LabelFilter = self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when all of the nested filters match.
+///
+/// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/And
+#[derive(SimpleObject, Debug, Clone)]
+pub struct LabelFilterAnd {
+    /// Filters that all must match.
+    pub and: Vec<LabelFilter>,
+}
+
+impl From<odf::metadata::resource::LabelFilterAnd> for LabelFilterAnd {
+    fn from(v: odf::metadata::resource::LabelFilterAnd) -> Self {
+        Self {
+            and: v.and.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when every condition holds for the label or identity header with the same name.
+///
+/// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Match
+
+#[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
+pub struct LabelFilterMatch(odf::metadata::resource::LabelFilterMatch);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for LabelFilterMatch {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let proxy: odf::metadata::serde::yaml::resource::LabelFilterMatch =
+            async_graphql::from_value(value)?;
+        let dto = proxy
+            .try_into()
+            .map_err(|e: odf::metadata::errors::ValidationError| {
+                async_graphql::InputValueError::custom(e.to_string())
+            })?;
+        Ok(Self::new(dto))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::resource::LabelFilterMatch =
+            self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when the nested filter does not match.
+///
+/// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Not
+#[derive(SimpleObject, Debug, Clone)]
+pub struct LabelFilterNot {
+    /// Filter to negate.
+    pub not: Box<LabelFilter>,
+}
+
+impl From<odf::metadata::resource::LabelFilterNot> for LabelFilterNot {
+    fn from(v: odf::metadata::resource::LabelFilterNot) -> Self {
+        Self {
+            not: Box::new((*v.not).into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Matches when at least one of the nested filters matches.
+///
+/// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Or
+#[derive(SimpleObject, Debug, Clone)]
+pub struct LabelFilterOr {
+    /// Filters of which at least one must match.
+    pub or: Vec<LabelFilter>,
+}
+
+impl From<odf::metadata::resource::LabelFilterOr> for LabelFilterOr {
+    fn from(v: odf::metadata::resource::LabelFilterOr) -> Self {
+        Self {
+            or: v.or.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Merge strategy determines how newly ingested data should be combined with the data that already exists in the dataset.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy
+#[derive(Union, Debug, Clone)]
+pub enum MergeStrategy {
+    Append(MergeStrategyAppend),
+    Ledger(MergeStrategyLedger),
+    Snapshot(MergeStrategySnapshot),
+    ChangelogStream(MergeStrategyChangelogStream),
+    UpsertStream(MergeStrategyUpsertStream),
+}
+
+impl From<odf::metadata::source::MergeStrategy> for MergeStrategy {
+    fn from(v: odf::metadata::source::MergeStrategy) -> Self {
+        match v {
+            odf::metadata::source::MergeStrategy::Append(v) => Self::Append(v.into()),
+            odf::metadata::source::MergeStrategy::Ledger(v) => Self::Ledger(v.into()),
+            odf::metadata::source::MergeStrategy::Snapshot(v) => Self::Snapshot(v.into()),
+            odf::metadata::source::MergeStrategy::ChangelogStream(v) => {
+                Self::ChangelogStream(v.into())
+            }
+            odf::metadata::source::MergeStrategy::UpsertStream(v) => Self::UpsertStream(v.into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Append merge strategy.
+///
+/// Under this strategy new data will be appended to the dataset in its entirety, without any deduplication.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Append
+#[derive(SimpleObject, Debug, Clone)]
+pub struct MergeStrategyAppend {
+    pub _dummy: Option<String>,
+}
+
+impl From<odf::metadata::source::MergeStrategyAppend> for MergeStrategyAppend {
+    fn from(v: odf::metadata::source::MergeStrategyAppend) -> Self {
+        Self { _dummy: None }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Changelog stream merge strategy.
+///
+/// This is the native stream format for ODF that accurately describes the evolution of all event records including appends, retractions, and corrections as per RFC-015. No pre-processing except for format validation is done.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/ChangelogStream
+#[derive(SimpleObject, Debug, Clone)]
+pub struct MergeStrategyChangelogStream {
+    /// Names of the columns that uniquely identify the record throughout its lifetime
+    pub primary_key: Vec<String>,
+}
+
+impl From<odf::metadata::source::MergeStrategyChangelogStream> for MergeStrategyChangelogStream {
+    fn from(v: odf::metadata::source::MergeStrategyChangelogStream) -> Self {
+        Self {
+            primary_key: v.primary_key.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Ledger merge strategy.
+///
+/// This strategy should be used for data sources containing ledgers of events. Currently this strategy will only perform deduplication of events using user-specified primary key columns. This means that the source data can contain partially overlapping set of records and only those records that were not previously seen will be appended.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Ledger
+#[derive(SimpleObject, Debug, Clone)]
+pub struct MergeStrategyLedger {
+    /// Names of the columns that uniquely identify the record throughout its lifetime
+    pub primary_key: Vec<String>,
+}
+
+impl From<odf::metadata::source::MergeStrategyLedger> for MergeStrategyLedger {
+    fn from(v: odf::metadata::source::MergeStrategyLedger) -> Self {
+        Self {
+            primary_key: v.primary_key.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Snapshot merge strategy.
 ///
 /// This strategy can be used for data state snapshots that are taken periodically and contain only the latest state of the observed entity or system. Over time such snapshots can have new rows added, and old rows either removed or modified.
 ///
@@ -832,6 +1705,7 @@ pub enum MetadataEvent {
     AddPushSource(AddPushSource),
     DisablePushSource(DisablePushSource),
     DisablePollingSource(DisablePollingSource),
+    Unknown(UnknownVariant),
 }
 
 impl From<odf::metadata::dataset::MetadataEvent> for MetadataEvent {
@@ -864,6 +1738,7 @@ impl From<odf::metadata::dataset::MetadataEvent> for MetadataEvent {
             odf::metadata::dataset::MetadataEvent::DisablePollingSource(v) => {
                 Self::DisablePollingSource(v.into())
             }
+            odf::metadata::dataset::MetadataEvent::Unknown(v) => Self::Unknown(v.into()),
         }
     }
 }
@@ -929,6 +1804,58 @@ impl From<odf::metadata::dataset::OffsetInterval> for OffsetInterval {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Defines a storage volume where data can be stored and its access credentials.
+///
+/// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec
+#[derive(Union, Debug, Clone)]
+pub enum PersistentVolumeSpec {
+    S3(PersistentVolumeSpecS3),
+}
+
+impl From<odf::metadata::storage::PersistentVolumeSpec> for PersistentVolumeSpec {
+    fn from(v: odf::metadata::storage::PersistentVolumeSpec) -> Self {
+        match v {
+            odf::metadata::storage::PersistentVolumeSpec::S3(v) => Self::S3(v.into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// An Amazon S3 or S3-compatible object storage bucket.
+///
+/// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec#/$defs/S3
+#[derive(SimpleObject, Debug, Clone)]
+pub struct PersistentVolumeSpecS3 {
+    /// S3 endpoint URL. If omitted, defaults to AWS S3. Use for S3-compatible stores e.g. `https://s3.amazonaws.com`.
+    pub endpoint: Option<String>,
+    /// AWS region where the bucket is located e.g. `us-west-2`.
+    pub region: Option<String>,
+    /// Name of the S3 bucket.
+    pub bucket: String,
+    /// Optional path prefix within the bucket.
+    pub prefix: Option<String>,
+    /// Storage capacity allocation.
+    pub capacity: Option<VolumeCapacity>,
+    /// Access credentials for the bucket.
+    pub credentials: Option<AwsCredentials>,
+}
+
+impl From<odf::metadata::storage::PersistentVolumeSpecS3> for PersistentVolumeSpecS3 {
+    fn from(v: odf::metadata::storage::PersistentVolumeSpecS3) -> Self {
+        Self {
+            endpoint: v.endpoint.map(Into::into),
+            region: v.region.map(Into::into),
+            bucket: v.bucket.into(),
+            prefix: v.prefix.map(Into::into),
+            capacity: v.capacity.map(Into::into),
+            credentials: v.credentials.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Defines the steps to prepare raw data for ingestion.
 ///
 /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep
@@ -971,19 +1898,41 @@ impl From<odf::metadata::source::PrepStepDecompress> for PrepStepDecompress {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Executes external command to process the data using piped input/output.
+/// Executes external command to process the data using piped input/output.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Pipe
+#[derive(SimpleObject, Debug, Clone)]
+pub struct PrepStepPipe {
+    /// Command to execute and its arguments.
+    pub command: Vec<String>,
+}
+
+impl From<odf::metadata::source::PrepStepPipe> for PrepStepPipe {
+    fn from(v: odf::metadata::source::PrepStepPipe) -> Self {
+        Self {
+            command: v.command.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Represents a projection of a dataaset history into a state for fast lookups.
 ///
-/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Pipe
+/// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ProjectionSpec
 #[derive(SimpleObject, Debug, Clone)]
-pub struct PrepStepPipe {
-    /// Command to execute and its arguments.
-    pub command: Vec<String>,
+pub struct ProjectionSpec {
+    /// Datasets that will be used as sources.
+    pub inputs: Vec<TransformInput>,
+    /// Transformation that will be applied to produce new data.
+    pub project: Transform,
 }
 
-impl From<odf::metadata::source::PrepStepPipe> for PrepStepPipe {
-    fn from(v: odf::metadata::source::PrepStepPipe) -> Self {
+impl From<odf::metadata::dataset::ProjectionSpec> for ProjectionSpec {
+    fn from(v: odf::metadata::dataset::ProjectionSpec) -> Self {
         Self {
-            command: v.command.into_iter().map(Into::into).collect(),
+            inputs: v.inputs.into_iter().map(Into::into).collect(),
+            project: v.project.into(),
         }
     }
 }
@@ -1473,6 +2422,56 @@ impl ReadStepParquet {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// A directed relationship between two resources, optionally carrying a typed value.
+///
+/// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Relation
+#[derive(SimpleObject, Debug, Clone)]
+pub struct Relation {
+    /// The resource that holds the relation.
+    pub subject: ResourceHandle,
+    /// Name of the relation e.g. `role`, `member`, `owner`.
+    pub relation: String,
+    /// Optional value associated with the relation e.g. `maintainer` for a `role` relation.
+    pub value: Option<serde_json::Value>,
+    /// The resource that is the target of the relation.
+    pub object: ResourceHandle,
+}
+
+impl From<odf::metadata::auth::Relation> for Relation {
+    fn from(v: odf::metadata::auth::Relation) -> Self {
+        Self {
+            subject: v.subject.into(),
+            relation: v.relation.into(),
+            value: v.value.map(Into::into),
+            object: v.object.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Specifies resource attributes and relations between resources on which auth policies act upon.
+///
+/// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationsSpec
+#[derive(SimpleObject, Debug, Clone)]
+pub struct RelationsSpec {
+    /// Relations between resources.
+    pub relations: Vec<Relation>,
+    /// Resource attributes.
+    pub attributes: Vec<Attribute>,
+}
+
+impl From<odf::metadata::auth::RelationsSpec> for RelationsSpec {
+    fn from(v: odf::metadata::auth::RelationsSpec) -> Self {
+        Self {
+            relations: v.relations.into_iter().map(Into::into).collect(),
+            attributes: v.attributes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Defines a header (e.g. HTTP) to be passed into some request.
 ///
 /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/RequestHeader
@@ -1521,6 +2520,279 @@ impl From<odf::metadata::resource::Resource<serde_json::Value>> for Resource {
     }
 }
 
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceAccountSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: AccountSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::auth::AccountSpec>>
+    for ResourceAccountSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::auth::AccountSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceDatasetSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: DatasetSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::dataset::DatasetSpec>>
+    for ResourceDatasetSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::dataset::DatasetSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceFlowSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: FlowSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::flow::FlowSpec>> for ResourceFlowSpec {
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::flow::FlowSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourcePersistentVolumeSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: PersistentVolumeSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::storage::PersistentVolumeSpec>>
+    for ResourcePersistentVolumeSpec
+{
+    fn from(
+        v: odf::metadata::resource::Resource<odf::metadata::storage::PersistentVolumeSpec>,
+    ) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceProjectionSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: ProjectionSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::dataset::ProjectionSpec>>
+    for ResourceProjectionSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::dataset::ProjectionSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceRelationsSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: RelationsSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::auth::RelationsSpec>>
+    for ResourceRelationsSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::auth::RelationsSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceSecretSetSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: SecretSetSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::config::SecretSetSpec>>
+    for ResourceSecretSetSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::config::SecretSetSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceSourceSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: SourceSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::source::SourceSpec>>
+    for ResourceSourceSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::source::SourceSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceTaskSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: TaskSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::flow::TaskSpec>> for ResourceTaskSpec {
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::flow::TaskSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceVariableSetSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: VariableSetSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::config::VariableSetSpec>>
+    for ResourceVariableSetSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::config::VariableSetSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceWebhookTargetSpec {
+    /// Identifies the controlling entity, a bounded context that this resource belongs to, and the version. Url should follow the pattern `{base-url}/{context}/{version}/{name}.json` e.g. `https://opendatafabric.org/schemas/dataset/v1/Dataset.json`.
+    pub schema: TypeUri<'static>,
+    /// Container for identity and ownership information of a resource.
+    pub headers: ResourceHeaders,
+    /// Specifies the desired state of a resource.
+    pub spec: WebhookTargetSpec,
+    /// Resource lifecycle and reconciliation information.
+    pub status: ResourceStatus,
+}
+
+impl From<odf::metadata::resource::Resource<odf::metadata::sink::WebhookTargetSpec>>
+    for ResourceWebhookTargetSpec
+{
+    fn from(v: odf::metadata::resource::Resource<odf::metadata::sink::WebhookTargetSpec>) -> Self {
+        Self {
+            schema: v.schema.into(),
+            headers: v.headers.into(),
+            spec: v.spec.into(),
+            status: v.status.into(),
+        }
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 /// Annotations is an unstructured key value map stored with a resource that may be set by external tools to store and retrieve arbitrary metadata. Unlike labels, annotations are not indexed and cannot be queried by.
@@ -1572,10 +2844,41 @@ impl async_graphql::ScalarType for ResourceConditions {
         Ok(Self::new(dto))
     }
 
-    fn to_value(&self) -> async_graphql::Value {
-        let value: odf::metadata::serde::yaml::resource::ResourceConditions =
-            self.as_ref().clone().into();
-        async_graphql::to_value(&value).unwrap()
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::resource::ResourceConditions =
+            self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Lint to another resolved resource.
+///
+/// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHandle
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ResourceHandle {
+    /// Account that owns the target resource.
+    pub account: AccountHandle,
+    /// Type URI of the target resource.
+    pub r#type: TypeUri<'static>,
+    /// ID of the resource within a node.
+    pub id: ResourceID<'static>,
+    /// DID of the resource, if applicable.
+    pub did: Option<Did<'static>>,
+    /// Name of a resource.
+    pub name: ResourceName<'static>,
+}
+
+impl From<odf::metadata::resource::ResourceHandle> for ResourceHandle {
+    fn from(v: odf::metadata::resource::ResourceHandle) -> Self {
+        Self {
+            account: v.account.into(),
+            r#type: v.r#type.into(),
+            id: v.id.into(),
+            did: v.did.map(Into::into),
+            name: v.name.into(),
+        }
     }
 }
 
@@ -1695,6 +2998,71 @@ impl From<odf::metadata::resource::ResourceStatus> for ResourceStatus {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Individual secret in raw or encrypted form.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Secret
+#[derive(SimpleObject, Debug, Clone)]
+pub struct Secret {
+    /// Represents the encoding of the value. Typically will be `jwe` after a raw secret gets encrypted.
+    pub content_encoding: Option<String>,
+}
+
+impl From<odf::metadata::config::Secret> for Secret {
+    fn from(v: odf::metadata::config::Secret) -> Self {
+        Self {
+            content_encoding: v.content_encoding.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines a set of secrets stored and managed by the ODF node and accessible via embedded sercets provider.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSetSpec
+#[derive(SimpleObject, Debug, Clone)]
+pub struct SecretSetSpec {
+    /// Key value pairs of secrets.
+    pub secrets: Secrets,
+}
+
+impl From<odf::metadata::config::SecretSetSpec> for SecretSetSpec {
+    fn from(v: odf::metadata::config::SecretSetSpec) -> Self {
+        Self {
+            secrets: v.secrets.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Container for key-value secrets. Every key must be a string. Values may be strings with raw unencrypted data or objects that signify the encoding.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Secrets
+
+#[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
+pub struct Secrets(odf::metadata::config::Secrets);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for Secrets {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let proxy: odf::metadata::serde::yaml::config::Secrets = async_graphql::from_value(value)?;
+        let dto = proxy
+            .try_into()
+            .map_err(|e: odf::metadata::errors::ValidationError| {
+                async_graphql::InputValueError::custom(e.to_string())
+            })?;
+        Ok(Self::new(dto))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::config::Secrets = self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Establishes the identity of the dataset. Always the first metadata event in the chain.
 ///
 /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Seed
@@ -1942,6 +3310,43 @@ pub enum SourceOrdering {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Specifies an external source of data for ingestion.
+///
+/// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpec
+#[derive(SimpleObject, Debug, Clone)]
+pub struct SourceSpec {
+    /// Brings the configuration values into the local `config` context.
+    pub config: Option<ValueRefs>,
+    /// Determines where data is sourced from.
+    pub ingress: Option<Ingress>,
+    /// Defines how raw data is prepared before reading.
+    pub prepare: Option<Vec<PrepStep>>,
+    /// Defines how data is read into structured format.
+    pub read: ReadStep,
+    /// Pre-processing query that shapes the data.
+    pub preprocess: Option<Transform>,
+    /// Determines how newly-ingested data should be merged with existing history.
+    pub merge: Option<MergeStrategy>,
+    /// Defines the mapping of system fields to dataset column names.
+    pub vocab: Option<DatasetVocabulary>,
+}
+
+impl From<odf::metadata::source::SourceSpec> for SourceSpec {
+    fn from(v: odf::metadata::source::SourceSpec) -> Self {
+        Self {
+            config: v.config.map(Into::into),
+            ingress: v.ingress.map(Into::into),
+            prepare: v.prepare.map(|v| v.into_iter().map(Into::into).collect()),
+            read: v.read.into(),
+            preprocess: v.preprocess.map(Into::into),
+            merge: v.merge.map(Into::into),
+            vocab: v.vocab.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// The state of the source the data was added from to allow fast resuming.
 ///
 /// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceState
@@ -1989,6 +3394,111 @@ impl From<odf::metadata::dataset::SqlQueryStep> for SqlQueryStep {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// An individual work item to be executed as part of a flow.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec
+#[derive(Union, Debug, Clone)]
+pub enum TaskSpec {
+    Ingest(TaskSpecIngest),
+    Compaction(TaskSpecCompaction),
+    GarbageCollection(TaskSpecGarbageCollection),
+    WebhookCall(TaskSpecWebhookCall),
+}
+
+impl From<odf::metadata::flow::TaskSpec> for TaskSpec {
+    fn from(v: odf::metadata::flow::TaskSpec) -> Self {
+        match v {
+            odf::metadata::flow::TaskSpec::Ingest(v) => Self::Ingest(v.into()),
+            odf::metadata::flow::TaskSpec::Compaction(v) => Self::Compaction(v.into()),
+            odf::metadata::flow::TaskSpec::GarbageCollection(v) => {
+                Self::GarbageCollection(v.into())
+            }
+            odf::metadata::flow::TaskSpec::WebhookCall(v) => Self::WebhookCall(v.into()),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Compacts data files in matching datasets to improve query performance.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/Compaction
+#[derive(SimpleObject, Debug, Clone)]
+pub struct TaskSpecCompaction {
+    /// Optional parameters to control ingestion behavior.
+    pub params: Option<CompactionParams>,
+}
+
+impl From<odf::metadata::flow::TaskSpecCompaction> for TaskSpecCompaction {
+    fn from(v: odf::metadata::flow::TaskSpecCompaction) -> Self {
+        Self {
+            params: v.params.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Removes unreferenced data files from matching datasets.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/GarbageCollection
+#[derive(SimpleObject, Debug, Clone)]
+pub struct TaskSpecGarbageCollection {
+    pub _dummy: Option<String>,
+}
+
+impl From<odf::metadata::flow::TaskSpecGarbageCollection> for TaskSpecGarbageCollection {
+    fn from(v: odf::metadata::flow::TaskSpecGarbageCollection) -> Self {
+        Self { _dummy: None }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Fetches data from a source and appends it to a dataset.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/Ingest
+#[derive(SimpleObject, Debug, Clone)]
+pub struct TaskSpecIngest {
+    /// Reference to the source resource that defines how to fetch data.
+    pub source: ResourceHandle,
+    /// Optional parameters to control ingestion behavior.
+    pub params: Option<IngestParams>,
+}
+
+impl From<odf::metadata::flow::TaskSpecIngest> for TaskSpecIngest {
+    fn from(v: odf::metadata::flow::TaskSpecIngest) -> Self {
+        Self {
+            source: v.source.into(),
+            params: v.params.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Dispatches a certain payload to a specific `WebhookTarget`.
+///
+/// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/WebhookCall
+#[derive(SimpleObject, Debug, Clone)]
+pub struct TaskSpecWebhookCall {
+    /// Reference to the `WebhookTarget`.
+    pub target: ResourceHandle,
+    /// The payload to send. May include templating.
+    pub payload: Option<String>,
+}
+
+impl From<odf::metadata::flow::TaskSpecWebhookCall> for TaskSpecWebhookCall {
+    fn from(v: odf::metadata::flow::TaskSpecWebhookCall) -> Self {
+        Self {
+            target: v.target.into(),
+            payload: v.payload.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 /// Temporary Flink-specific extension for creating temporal tables from streams.
 ///
 /// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/TemporalTable
@@ -2137,3 +3647,208 @@ impl From<odf::metadata::TransformInput> for TransformInput {
         }
     }
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reference to a value within a `VariableSet` or a `SecretSet`.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueHandle
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ValueHandle {
+    /// Account that owns the target resource.
+    pub account: AccountHandle,
+    /// Type URI of the target resource.
+    pub r#type: TypeUri<'static>,
+    /// ID of the resource within a node.
+    pub id: ResourceID<'static>,
+    /// Name of a resource.
+    pub name: ResourceName<'static>,
+    /// JSON path to a value within a `VariableSet` or a `SecretSet`.
+    ///
+    /// Examples:
+    /// - "port"
+    /// - "postgres.schemaName"
+    pub path: Option<String>,
+}
+
+impl From<odf::metadata::config::ValueHandle> for ValueHandle {
+    fn from(v: odf::metadata::config::ValueHandle) -> Self {
+        Self {
+            account: v.account.into(),
+            r#type: v.r#type.into(),
+            id: v.id.into(),
+            name: v.name.into(),
+            path: v.path.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reference to a value within a `VariableSet` or a `SecretSet`.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRef
+#[derive(SimpleObject, Debug, Clone)]
+pub struct ValueRef {
+    /// Reference to an account that owns the `VariableSet` or the `SecretSet`.
+    pub account: Option<AccountRef>,
+    /// Short type name or full type URI of the target resource.
+    ///
+    /// Examples:
+    /// - "SecretSet"
+    /// - "VariableSet"
+    /// - "https://opendatafabric.org/config/v1/SecretSet"
+    pub r#type: TypeRef<'static>,
+    /// ID of a resource.
+    pub id: Option<ResourceID<'static>>,
+    /// Name of a resource.
+    pub name: Option<ResourceName<'static>>,
+    /// JSON path to a value within a `VariableSet` or a `SecretSet`.
+    ///
+    /// Examples:
+    /// - "port"
+    /// - "postgres.schemaName"
+    pub path: Option<String>,
+}
+
+impl From<odf::metadata::config::ValueRef> for ValueRef {
+    fn from(v: odf::metadata::config::ValueRef) -> Self {
+        Self {
+            account: v.account.map(Into::into),
+            r#type: v.r#type.into(),
+            id: v.id.map(Into::into),
+            name: v.name.map(Into::into),
+            path: v.path.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Container for key-value variables. Every key must be a string. Values shoud reference fields in `SecretSet`s and `VariableSet`s.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRefs
+
+#[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
+pub struct ValueRefs(odf::metadata::config::ValueRefs);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for ValueRefs {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let proxy: odf::metadata::serde::yaml::config::ValueRefs =
+            async_graphql::from_value(value)?;
+        let dto = proxy
+            .try_into()
+            .map_err(|e: odf::metadata::errors::ValidationError| {
+                async_graphql::InputValueError::custom(e.to_string())
+            })?;
+        Ok(Self::new(dto))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::config::ValueRefs = self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Individual variable.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Variable
+#[derive(SimpleObject, Debug, Clone)]
+pub struct Variable {
+    /// A value in raw or encoded form.
+    pub value: String,
+}
+
+impl From<odf::metadata::config::Variable> for Variable {
+    fn from(v: odf::metadata::config::Variable) -> Self {
+        Self {
+            value: v.value.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines a set of variables stored and managed by the ODF node and accessible via embedded variables provider.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpec
+#[derive(SimpleObject, Debug, Clone)]
+pub struct VariableSetSpec {
+    /// Key value pairs of variables.
+    pub variables: Variables,
+}
+
+impl From<odf::metadata::config::VariableSetSpec> for VariableSetSpec {
+    fn from(v: odf::metadata::config::VariableSetSpec) -> Self {
+        Self {
+            variables: v.variables.into(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Container for key-value variables. Every key must be a string. Values may be raw strings or objects that incorporate the encoding.
+///
+/// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Variables
+
+#[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
+pub struct Variables(odf::metadata::config::Variables);
+
+#[async_graphql::Scalar]
+impl async_graphql::ScalarType for Variables {
+    fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {
+        let proxy: odf::metadata::serde::yaml::config::Variables =
+            async_graphql::from_value(value)?;
+        let dto = proxy
+            .try_into()
+            .map_err(|e: odf::metadata::errors::ValidationError| {
+                async_graphql::InputValueError::custom(e.to_string())
+            })?;
+        Ok(Self::new(dto))
+    }
+
+    fn to_value(&self) -> async_graphql::Value {
+        let value: odf::metadata::serde::yaml::config::Variables = self.as_ref().clone().into();
+        async_graphql::to_value(&value).unwrap()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Storage capacity allocation.
+///
+/// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/VolumeCapacity
+#[derive(SimpleObject, Debug, Clone)]
+pub struct VolumeCapacity {
+    /// Maximum storage size e.g. `10Gi`.
+    pub storage: Option<ByteSize>,
+}
+
+impl From<odf::metadata::storage::VolumeCapacity> for VolumeCapacity {
+    fn from(v: odf::metadata::storage::VolumeCapacity) -> Self {
+        Self {
+            storage: v.storage.map(Into::into),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Defines a webhook target endpoint that can receive event notifications and data.
+///
+/// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpec
+#[derive(SimpleObject, Debug, Clone)]
+pub struct WebhookTargetSpec {
+    /// Target url of the webhook.
+    pub url: String,
+}
+
+impl From<odf::metadata::sink::WebhookTargetSpec> for WebhookTargetSpec {
+    fn from(v: odf::metadata::sink::WebhookTargetSpec) -> Self {
+        Self { url: v.url.into() }
+    }
+}
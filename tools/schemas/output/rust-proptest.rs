@@ -0,0 +1,6589 @@
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WARNING: This file is auto-generated from Open Data Fabric Schemas
+// See: http://opendatafabric.org/
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#![allow(unused_imports)]
+#![allow(clippy::all)]
+#![allow(clippy::pedantic)]
+
+use crate as odf;
+use crate::serde::flatbuffers::convertors_generated::*;
+use crate::serde::flatbuffers::proxies_generated as fb;
+use crate::serde::yaml::derivations_generated as yaml;
+
+use chrono::prelude::*;
+use proptest::prelude::*;
+use setty::types::{ByteSize, DurationString};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Patterns of ODF string formats that values are generated from
+pub const DID: &str = r"did:odf:fed01[0-9a-f]{64}";
+pub const MULTIHASH: &str = r"f1620[0-9a-f]{64}";
+pub const MULTICODEC: &str = r"[a-z][a-z0-9-]{0,15}";
+pub const NAME: &str = r"[a-z]([a-z0-9-]{0,14}[a-z0-9])?";
+pub const DATASET_ALIAS: &str =
+    r"([a-z]([a-z0-9-]{0,14}[a-z0-9])?/)?[a-z]([a-z0-9-]{0,14}[a-z0-9])?";
+pub const DATASET_REF: &str =
+    r"did:odf:fed01[0-9a-f]{64}|([a-z]([a-z0-9-]{0,14}[a-z0-9])?/)?[a-z]([a-z0-9-]{0,14}[a-z0-9])?";
+pub const RESOURCE_ID: &str =
+    r"[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}";
+pub const TYPE_NAME: &str = r"[A-Z][A-Za-z0-9]{0,15}";
+pub const TYPE_URI: &str =
+    r"https://opendatafabric\.org/schemas/[a-z]{1,8}/v1alpha1/[A-Z][A-Za-z0-9]{0,15}";
+pub const TYPE_REF: &str = r"[A-Z][A-Za-z0-9]{0,15}|https://opendatafabric\.org/schemas/[a-z]{1,8}/v1alpha1/[A-Z][A-Za-z0-9]{0,15}";
+pub const DURATION: &str = r"[1-9][0-9]{0,4}(ms|s|m|h|d)";
+pub const PATH: &str = r"(/[a-z0-9_.-]{1,8}){1,4}";
+pub const URL: &str = r"https://[a-z]{1,8}\.example\.com(/[a-z0-9]{1,8}){0,3}";
+pub const REGEX: &str = r"\^?[a-z]{1,8}(\.\*)?\$?";
+
+/// Generates values from their text representation, parsing them the same way manifests are read
+pub fn from_text<T: serde::de::DeserializeOwned + std::fmt::Debug>(
+    pattern: &'static str,
+) -> impl Strategy<Value = T> {
+    proptest::string::string_regex(pattern)
+        .unwrap()
+        .prop_map(|s| serde_json::from_value(serde_json::Value::String(s)).unwrap())
+}
+
+pub fn any_byte_size() -> impl Strategy<Value = ByteSize> {
+    any::<u32>().prop_map(|v| serde_json::from_value(v.into()).unwrap())
+}
+
+/// Timestamps between the epoch and the end of year 9999 with nanosecond precision
+pub fn any_date_time() -> impl Strategy<Value = DateTime<Utc>> {
+    (0i64..253402300800, 0u32..1_000_000_000).prop_map(|(s, ns)| Utc.timestamp_opt(s, ns).unwrap())
+}
+
+/// JSON values without floats, as those don't survive a round trip through YAML
+pub fn any_json() -> impl Strategy<Value = serde_json::Value> {
+    let leaf = prop_oneof![
+        Just(serde_json::Value::Null),
+        any::<bool>().prop_map(serde_json::Value::from),
+        any::<i64>().prop_map(serde_json::Value::from),
+        "[a-z0-9 ]{0,8}".prop_map(serde_json::Value::from),
+    ];
+    leaf.prop_recursive(3, 16, 4, |inner| {
+        prop_oneof![
+            proptest::collection::vec(inner.clone(), 0..4).prop_map(serde_json::Value::from),
+            proptest::collection::btree_map("[a-z]{1,8}", inner, 0..4)
+                .prop_map(|m| serde_json::Value::Object(m.into_iter().collect())),
+        ]
+    })
+}
+
+/// Asserts that the value survives a round trip through JSON and YAML using its serde proxy `P`
+pub fn check_serde_roundtrip<T, P>(value: &T) -> Result<(), TestCaseError>
+where
+    T: Clone + PartialEq + std::fmt::Debug,
+    P: From<T> + TryInto<T> + serde::Serialize + serde::de::DeserializeOwned,
+    <P as TryInto<T>>::Error: std::fmt::Debug,
+{
+    let json = serde_json::to_string(&P::from(value.clone())).unwrap();
+    let actual: T = serde_json::from_str::<P>(&json)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    prop_assert_eq!(&actual, value, "JSON: {}", json);
+
+    let yaml = serde_yaml::to_string(&P::from(value.clone())).unwrap();
+    let actual: T = serde_yaml::from_str::<P>(&yaml)
+        .unwrap()
+        .try_into()
+        .unwrap();
+    prop_assert_eq!(&actual, value, "YAML: {}", yaml);
+    Ok(())
+}
+
+/// Asserts that the value survives a round trip through a verified flatbuffer with root proxy `$proxy`
+macro_rules! check_flatbuffers_roundtrip {
+    ($value:expr, $dto:ty, $proxy:ty) => {{
+        let mut fb = ::flatbuffers::FlatBufferBuilder::new();
+        let offset = FlatbuffersSerializable::serialize(&$value, &mut fb).unwrap();
+        fb.finish(offset, None);
+        let actual: $dto = deserialize_verified::<$proxy, $dto>(
+            fb.finished_data(),
+            &FlatbuffersDecodeLimits::default(),
+        )
+        .unwrap();
+        prop_assert_eq!(&actual, &$value);
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Account
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Account
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::Account {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::auth::AccountSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountHandle
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountHandle
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::AccountHandle {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::ResourceID>(RESOURCE_ID),
+            from_text::<odf::AccountID>(DID),
+            from_text::<odf::AccountName>(NAME),
+        )
+            .prop_map(|(id, did, name)| Self { id, did, name })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountRef
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::AccountRef {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<odf::ResourceID>(RESOURCE_ID)),
+            proptest::option::of(from_text::<odf::AccountID>(DID)),
+            proptest::option::of(from_text::<odf::AccountName>(NAME)),
+        )
+            .prop_map(|(id, did, name)| Self { id, did, name })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountSpec
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::AccountSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<odf::AccountID>(DID)),
+            proptest::option::of(any::<odf::auth::AccountType>()),
+            proptest::option::of(any::<String>()),
+            any::<String>(),
+            proptest::option::of(from_text::<String>(URL)),
+            proptest::option::of(any::<odf::config::Secret>()),
+        )
+            .prop_map(
+                |(did, account_type, display_name, email, avatar_url, password)| Self {
+                    did,
+                    account_type,
+                    display_name,
+                    email,
+                    avatar_url,
+                    password,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountSpecInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::AccountSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<odf::AccountID>(DID)),
+            proptest::option::of(any::<odf::auth::AccountType>()),
+            proptest::option::of(any::<String>()),
+            any::<String>(),
+            proptest::option::of(from_text::<String>(URL)),
+            proptest::option::of(any::<odf::config::Secret>()),
+        )
+            .prop_map(
+                |(did, account_type, display_name, email, avatar_url, password)| Self {
+                    did,
+                    account_type,
+                    display_name,
+                    email,
+                    avatar_url,
+                    password,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AccountType
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountType
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::AccountType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::User, Self::Organization]).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AddData
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/AddData
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::AddData {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(any::<odf::dataset::DataSlice>()),
+            proptest::option::of(any::<odf::dataset::Checkpoint>()),
+            proptest::option::of(any_date_time()),
+            proptest::option::of(any::<odf::source::SourceState>()),
+            proptest::option::of(any::<odf::data::ExtraAttributes>()),
+        )
+            .prop_map(
+                |(
+                    prev_checkpoint,
+                    prev_offset,
+                    new_data,
+                    new_checkpoint,
+                    new_watermark,
+                    new_source_state,
+                    extra,
+                )| Self {
+                    prev_checkpoint,
+                    prev_offset,
+                    new_data,
+                    new_checkpoint,
+                    new_watermark,
+                    new_source_state,
+                    extra,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AddPushSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/AddPushSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::AddPushSource {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<odf::source::ReadStep>(),
+            proptest::option::of(any::<odf::dataset::Transform>()),
+            any::<odf::source::MergeStrategy>(),
+        )
+            .prop_map(|(source_name, read, preprocess, merge)| Self {
+                source_name,
+                read,
+                preprocess,
+                merge,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AttachmentEmbedded
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/AttachmentEmbedded
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::AttachmentEmbedded {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), any::<String>())
+            .prop_map(|(path, content)| Self { path, content })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Attachments
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Attachments
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::Attachments {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::dataset::AttachmentsEmbedded>()
+                .prop_map(odf::dataset::Attachments::Embedded),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AttachmentsEmbedded
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Attachments#/$defs/Embedded
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::AttachmentsEmbedded {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(
+            any::<odf::dataset::AttachmentEmbedded>(),
+            0..4,
+        ),)
+            .prop_map(|(items,)| Self { items })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Attribute
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Attribute
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::Attribute {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHandle>(),
+            any::<String>(),
+            any_json(),
+        )
+            .prop_map(|(object, name, value)| Self {
+                object,
+                name,
+                value,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AttributeInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AttributeInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::AttributeInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceRef>(),
+            any::<String>(),
+            any_json(),
+        )
+            .prop_map(|(object, name, value)| Self {
+                object,
+                name,
+                value,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AwsCredentials
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentials
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::AwsCredentials {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::config::ValueHandle>()),
+            proptest::option::of(any::<odf::config::ValueHandle>()),
+        )
+            .prop_map(|(access_key, secret_key)| Self {
+                access_key,
+                secret_key,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// AwsCredentialsInput
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/AwsCredentialsInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::AwsCredentialsInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::config::ValueRef>()),
+            proptest::option::of(any::<odf::config::ValueRef>()),
+        )
+            .prop_map(|(access_key, secret_key)| Self {
+                access_key,
+                secret_key,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Checkpoint
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Checkpoint
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::Checkpoint {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (from_text::<odf::Multihash>(MULTIHASH), any::<u64>())
+            .prop_map(|(physical_hash, size)| Self {
+                physical_hash,
+                size,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// CompactionParams
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/CompactionParams
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::CompactionParams {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any_byte_size()),
+            proptest::option::of(any::<u64>()),
+        )
+            .prop_map(|(max_slice_size, max_slice_records)| Self {
+                max_slice_size,
+                max_slice_records,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// CompressionFormat
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/CompressionFormat
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::CompressionFormat {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::Gzip, Self::Zip]).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataField
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataField
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::data::DataField`] with nested [`odf::data::DataType`] values drawn from the bounded strategy
+pub fn arbitrary_data_data_field(
+    inner: BoxedStrategy<odf::data::DataType>,
+) -> BoxedStrategy<odf::data::DataField> {
+    (
+        any::<String>(),
+        inner.clone(),
+        proptest::option::of(any::<odf::data::ExtraAttributes>()),
+    )
+        .prop_map(|(name, r#type, extra)| odf::data::DataField {
+            name,
+            r#type,
+            extra,
+        })
+        .boxed()
+}
+
+impl Arbitrary for odf::data::DataField {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_data_data_field(any::<odf::data::DataType>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataSchema
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataSchema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataSchema {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<odf::data::DataField>(), 0..4),
+            proptest::option::of(any::<odf::data::ExtraAttributes>()),
+        )
+            .prop_map(|(fields, extra)| Self { fields, extra })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataSlice
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DataSlice
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::DataSlice {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::Multihash>(MULTIHASH),
+            from_text::<odf::Multihash>(MULTIHASH),
+            any::<odf::dataset::OffsetInterval>(),
+            any::<u64>(),
+        )
+            .prop_map(
+                |(logical_hash, physical_hash, offset_interval, size)| Self {
+                    logical_hash,
+                    physical_hash,
+                    offset_interval,
+                    size,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataType
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let leaf = prop_oneof![
+            any::<odf::data::DataTypeBinary>().prop_map(odf::data::DataType::Binary),
+            any::<odf::data::DataTypeBool>().prop_map(odf::data::DataType::Bool),
+            any::<odf::data::DataTypeDate>().prop_map(odf::data::DataType::Date),
+            any::<odf::data::DataTypeDecimal>().prop_map(odf::data::DataType::Decimal),
+            any::<odf::data::DataTypeDuration>().prop_map(odf::data::DataType::Duration),
+            any::<odf::data::DataTypeFloat16>().prop_map(odf::data::DataType::Float16),
+            any::<odf::data::DataTypeFloat32>().prop_map(odf::data::DataType::Float32),
+            any::<odf::data::DataTypeFloat64>().prop_map(odf::data::DataType::Float64),
+            any::<odf::data::DataTypeInt8>().prop_map(odf::data::DataType::Int8),
+            any::<odf::data::DataTypeInt16>().prop_map(odf::data::DataType::Int16),
+            any::<odf::data::DataTypeInt32>().prop_map(odf::data::DataType::Int32),
+            any::<odf::data::DataTypeInt64>().prop_map(odf::data::DataType::Int64),
+            any::<odf::data::DataTypeUInt8>().prop_map(odf::data::DataType::UInt8),
+            any::<odf::data::DataTypeUInt16>().prop_map(odf::data::DataType::UInt16),
+            any::<odf::data::DataTypeUInt32>().prop_map(odf::data::DataType::UInt32),
+            any::<odf::data::DataTypeUInt64>().prop_map(odf::data::DataType::UInt64),
+            any::<odf::data::DataTypeNull>().prop_map(odf::data::DataType::Null),
+            any::<odf::data::DataTypeTime>().prop_map(odf::data::DataType::Time),
+            any::<odf::data::DataTypeTimestamp>().prop_map(odf::data::DataType::Timestamp),
+            any::<odf::data::DataTypeString>().prop_map(odf::data::DataType::String),
+        ];
+        leaf.prop_recursive(3, 16, 4, |inner| {
+            prop_oneof![
+                arbitrary_data_data_type_list(inner.clone()).prop_map(odf::data::DataType::List),
+                arbitrary_data_data_type_map(inner.clone()).prop_map(odf::data::DataType::Map),
+                arbitrary_data_data_type_option(inner.clone())
+                    .prop_map(odf::data::DataType::Option),
+                arbitrary_data_data_type_struct(inner.clone())
+                    .prop_map(odf::data::DataType::Struct),
+            ]
+        })
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeBinary
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Binary
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeBinary {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<u64>()),)
+            .prop_map(|(fixed_length,)| Self { fixed_length })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeBool
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Bool
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeBool {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeDate
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Date
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeDate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeDecimal
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Decimal
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeDecimal {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u32>(), any::<i32>())
+            .prop_map(|(precision, scale)| Self { precision, scale })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeDuration
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Duration
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeDuration {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<odf::data::TimeUnit>()),)
+            .prop_map(|(unit,)| Self { unit })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeFloat16
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Float16
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeFloat16 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeFloat32
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Float32
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeFloat32 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeFloat64
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Float64
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeFloat64 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt16
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int16
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeInt16 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt32
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int32
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeInt32 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt64
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int64
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeInt64 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeInt8
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Int8
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeInt8 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeList
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/List
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::data::DataTypeList`] with nested [`odf::data::DataType`] values drawn from the bounded strategy
+pub fn arbitrary_data_data_type_list(
+    inner: BoxedStrategy<odf::data::DataType>,
+) -> BoxedStrategy<odf::data::DataTypeList> {
+    (
+        inner.clone().prop_map(Box::new),
+        proptest::option::of(any::<u64>()),
+    )
+        .prop_map(|(item_type, fixed_length)| odf::data::DataTypeList {
+            item_type,
+            fixed_length,
+        })
+        .boxed()
+}
+
+impl Arbitrary for odf::data::DataTypeList {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_data_data_type_list(any::<odf::data::DataType>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeMap
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Map
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::data::DataTypeMap`] with nested [`odf::data::DataType`] values drawn from the bounded strategy
+pub fn arbitrary_data_data_type_map(
+    inner: BoxedStrategy<odf::data::DataType>,
+) -> BoxedStrategy<odf::data::DataTypeMap> {
+    (
+        inner.clone().prop_map(Box::new),
+        inner.clone().prop_map(Box::new),
+        proptest::option::of(any::<bool>()),
+    )
+        .prop_map(
+            |(key_type, value_type, keys_sorted)| odf::data::DataTypeMap {
+                key_type,
+                value_type,
+                keys_sorted,
+            },
+        )
+        .boxed()
+}
+
+impl Arbitrary for odf::data::DataTypeMap {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_data_data_type_map(any::<odf::data::DataType>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeNull
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Null
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeNull {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeOption
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Option
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::data::DataTypeOption`] with nested [`odf::data::DataType`] values drawn from the bounded strategy
+pub fn arbitrary_data_data_type_option(
+    inner: BoxedStrategy<odf::data::DataType>,
+) -> BoxedStrategy<odf::data::DataTypeOption> {
+    (inner.clone().prop_map(Box::new),)
+        .prop_map(|(inner,)| odf::data::DataTypeOption { inner })
+        .boxed()
+}
+
+impl Arbitrary for odf::data::DataTypeOption {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_data_data_type_option(any::<odf::data::DataType>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeString
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/String
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeString {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeStruct
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Struct
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::data::DataTypeStruct`] with nested [`odf::data::DataType`] values drawn from the bounded strategy
+pub fn arbitrary_data_data_type_struct(
+    inner: BoxedStrategy<odf::data::DataType>,
+) -> BoxedStrategy<odf::data::DataTypeStruct> {
+    (proptest::collection::vec(
+        arbitrary_data_data_field(inner.clone()),
+        0..4,
+    ),)
+        .prop_map(|(fields,)| odf::data::DataTypeStruct { fields })
+        .boxed()
+}
+
+impl Arbitrary for odf::data::DataTypeStruct {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_data_data_type_struct(any::<odf::data::DataType>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeTime
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Time
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeTime {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<odf::data::TimeUnit>()),)
+            .prop_map(|(unit,)| Self { unit })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeTimestamp
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/Timestamp
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeTimestamp {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::data::TimeUnit>()),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(unit, timezone)| Self { unit, timezone })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt16
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt16
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeUInt16 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt32
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt32
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeUInt32 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt64
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt64
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeUInt64 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DataTypeUInt8
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/DataType#/$defs/UInt8
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::DataTypeUInt8 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Dataset
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Dataset
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::Dataset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::dataset::DatasetSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetError
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetError
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetError {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::protocol::ErrorDetails>(),)
+            .prop_map(|(error_details,)| Self { error_details })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetKind
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetKind
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::DatasetKind {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::Root, Self::Derivative]).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetMetadataPullResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetMetadataPullResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetMetadataPullResponse {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::protocol::ObjectsBatch>(),)
+            .prop_map(|(blocks,)| Self { blocks })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullMetadataRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullMetadataRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPullMetadataRequest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullObjectsTransferRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPullObjectsTransferRequest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(
+            any::<odf::protocol::ObjectFileReference>(),
+            0..4,
+        ),)
+            .prop_map(|(object_files,)| Self { object_files })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullObjectsTransferResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPullObjectsTransferResponse {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(
+            any::<odf::protocol::PullObjectTransferStrategy>(),
+            0..4,
+        ),)
+            .prop_map(|(object_transfer_strategies,)| Self {
+                object_transfer_strategies,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPullRequest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetID>(DID),
+            proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),
+            proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),
+        )
+            .prop_map(|(dataset_id, begin_after, stop_at)| Self {
+                dataset_id,
+                begin_after,
+                stop_at,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPullResponse {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::protocol::TransferSizeEstimation>(),)
+            .prop_map(|(size_estimation,)| Self { size_estimation })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushComplete
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushComplete
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushComplete {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushCompleteConfirmed
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushCompleteConfirmed
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushCompleteConfirmed {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushMetadata
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadata
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushMetadata {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::protocol::ObjectsBatch>(),)
+            .prop_map(|(new_blocks,)| Self { new_blocks })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushMetadataAccepted
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadataAccepted
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushMetadataAccepted {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushObjectsTransferRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushObjectsTransferRequest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(
+            any::<odf::protocol::ObjectFileReference>(),
+            0..4,
+        ),)
+            .prop_map(|(object_files,)| Self { object_files })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushObjectsTransferResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushObjectsTransferResponse {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(
+            any::<odf::protocol::PushObjectTransferStrategy>(),
+            0..4,
+        ),)
+            .prop_map(|(object_transfer_strategies,)| Self {
+                object_transfer_strategies,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushRequest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetID>(DID),
+            from_text::<odf::Multihash>(MULTIHASH),
+            any::<odf::protocol::TransferSizeEstimation>(),
+        )
+            .prop_map(|(dataset_id, current_head, size_estimation)| Self {
+                dataset_id,
+                current_head,
+                size_estimation,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushRequestAccepted
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequestAccepted
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::DatasetPushRequestAccepted {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetSnapshot
+// Schema: https://opendatafabric.org/schemas/legacy/v0/DatasetSnapshot
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::DatasetSnapshot {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetAlias>(DATASET_ALIAS),
+            any::<odf::dataset::DatasetKind>(),
+            proptest::collection::vec(any::<odf::dataset::MetadataEvent>(), 0..4),
+        )
+            .prop_map(|(name, kind, metadata)| Self {
+                name,
+                kind,
+                metadata,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetSpec
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::DatasetSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetID>(DID),
+            any::<odf::dataset::DatasetKind>(),
+            proptest::collection::vec(any::<odf::dataset::MetadataEvent>(), 0..4),
+            any::<odf::resource::ResourceHandle>(),
+        )
+            .prop_map(|(did, kind, metadata, volume)| Self {
+                did,
+                kind,
+                metadata,
+                volume,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetSpecInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::DatasetSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<odf::DatasetID>(DID)),
+            any::<odf::dataset::DatasetKind>(),
+            proptest::collection::vec(any::<odf::dataset::MetadataEvent>(), 0..4),
+            proptest::option::of(any::<odf::storage::PersistentVolumeRef>()),
+        )
+            .prop_map(|(did, kind, metadata, volume)| Self {
+                did,
+                kind,
+                metadata,
+                volume,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetVocabulary
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetVocabulary
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::DatasetVocabulary {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(
+                |(offset_column, operation_type_column, system_time_column, event_time_column)| {
+                    Self {
+                        offset_column,
+                        operation_type_column,
+                        system_time_column,
+                        event_time_column,
+                    }
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DisablePollingSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePollingSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::DisablePollingSource {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DisablePushSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePushSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::DisablePushSource {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(),)
+            .prop_map(|(source_name,)| Self { source_name })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EnvVar
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EnvVar
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::EnvVar {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), proptest::option::of(any::<String>()))
+            .prop_map(|(name, value)| Self { name, value })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ErrorDetails
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ErrorDetails
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::ErrorDetails {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<String>(),
+            proptest::option::of(any_json()),
+        )
+            .prop_map(|(error_code, description, extras)| Self {
+                error_code,
+                description,
+                extras,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilter
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::event::EventFilter {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let leaf = prop_oneof![
+            any::<odf::event::EventFilterMatch>().prop_map(odf::event::EventFilter::Match),
+        ];
+        leaf.prop_recursive(3, 16, 4, |inner| {
+            prop_oneof![
+                arbitrary_event_event_filter_and(inner.clone())
+                    .prop_map(odf::event::EventFilter::And),
+                arbitrary_event_event_filter_or(inner.clone())
+                    .prop_map(odf::event::EventFilter::Or),
+                arbitrary_event_event_filter_not(inner.clone())
+                    .prop_map(odf::event::EventFilter::Not),
+            ]
+        })
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterAnd
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/And
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::event::EventFilterAnd`] with nested [`odf::event::EventFilter`] values drawn from the bounded strategy
+pub fn arbitrary_event_event_filter_and(
+    inner: BoxedStrategy<odf::event::EventFilter>,
+) -> BoxedStrategy<odf::event::EventFilterAnd> {
+    (proptest::collection::vec(inner.clone(), 0..4),)
+        .prop_map(|(and,)| odf::event::EventFilterAnd { and })
+        .boxed()
+}
+
+impl Arbitrary for odf::event::EventFilterAnd {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_event_event_filter_and(any::<odf::event::EventFilter>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterMatch
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Match
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::event::EventFilterMatch {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(
+            any::<String>(),
+            any::<odf::resource::FilterCondition>(),
+            0..4,
+        )
+        .prop_map(|entries| Self { entries })
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterNot
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Not
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::event::EventFilterNot`] with nested [`odf::event::EventFilter`] values drawn from the bounded strategy
+pub fn arbitrary_event_event_filter_not(
+    inner: BoxedStrategy<odf::event::EventFilter>,
+) -> BoxedStrategy<odf::event::EventFilterNot> {
+    (inner.clone().prop_map(Box::new),)
+        .prop_map(|(not,)| odf::event::EventFilterNot { not })
+        .boxed()
+}
+
+impl Arbitrary for odf::event::EventFilterNot {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_event_event_filter_not(any::<odf::event::EventFilter>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterOr
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Or
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::event::EventFilterOr`] with nested [`odf::event::EventFilter`] values drawn from the bounded strategy
+pub fn arbitrary_event_event_filter_or(
+    inner: BoxedStrategy<odf::event::EventFilter>,
+) -> BoxedStrategy<odf::event::EventFilterOr> {
+    (proptest::collection::vec(inner.clone(), 0..4),)
+        .prop_map(|(or,)| odf::event::EventFilterOr { or })
+        .boxed()
+}
+
+impl Arbitrary for odf::event::EventFilterOr {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_event_event_filter_or(any::<odf::event::EventFilter>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSource
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::EventTimeSource {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::source::EventTimeSourceFromMetadata>()
+                .prop_map(odf::source::EventTimeSource::FromMetadata),
+            any::<odf::source::EventTimeSourceFromPath>()
+                .prop_map(odf::source::EventTimeSource::FromPath),
+            any::<odf::source::EventTimeSourceFromSystemTime>()
+                .prop_map(odf::source::EventTimeSource::FromSystemTime),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSourceFromMetadata
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromMetadata
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::EventTimeSourceFromMetadata {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSourceFromPath
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromPath
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::EventTimeSourceFromPath {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<String>(REGEX),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(pattern, timestamp_format)| Self {
+                pattern,
+                timestamp_format,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventTimeSourceFromSystemTime
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EventTimeSource#/$defs/FromSystemTime
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::EventTimeSourceFromSystemTime {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ExecuteTransform
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ExecuteTransform
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::ExecuteTransform {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<odf::dataset::ExecuteTransformInput>(), 0..4),
+            proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(any::<odf::dataset::DataSlice>()),
+            proptest::option::of(any::<odf::dataset::Checkpoint>()),
+            proptest::option::of(any_date_time()),
+        )
+            .prop_map(
+                |(
+                    query_inputs,
+                    prev_checkpoint,
+                    prev_offset,
+                    new_data,
+                    new_checkpoint,
+                    new_watermark,
+                )| Self {
+                    query_inputs,
+                    prev_checkpoint,
+                    prev_offset,
+                    new_data,
+                    new_checkpoint,
+                    new_watermark,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ExecuteTransformInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ExecuteTransformInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::ExecuteTransformInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetID>(DID),
+            proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),
+            proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(any::<u64>()),
+        )
+            .prop_map(
+                |(dataset_id, prev_block_hash, new_block_hash, prev_offset, new_offset)| Self {
+                    dataset_id,
+                    prev_block_hash,
+                    new_block_hash,
+                    prev_offset,
+                    new_offset,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ExtraAttributes
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/ExtraAttributes
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::ExtraAttributes {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(any::<String>(), any_json(), 0..4)
+            .prop_map(|entries| Self { entries })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStep
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::FetchStep {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::legacy::FetchStepUrl>().prop_map(odf::legacy::FetchStep::Url),
+            any::<odf::legacy::FetchStepFilesGlob>().prop_map(odf::legacy::FetchStep::FilesGlob),
+            any::<odf::legacy::FetchStepContainer>().prop_map(odf::legacy::FetchStep::Container),
+            any::<odf::legacy::FetchStepMqtt>().prop_map(odf::legacy::FetchStep::Mqtt),
+            any::<odf::legacy::FetchStepEthereumLogs>()
+                .prop_map(odf::legacy::FetchStep::EthereumLogs),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepContainer
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Container
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::FetchStepContainer {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::source::EnvVar>(),
+                0..4,
+            )),
+        )
+            .prop_map(|(image, command, args, env)| Self {
+                image,
+                command,
+                args,
+                env,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepEthereumLogs
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/EthereumLogs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::FetchStepEthereumLogs {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(from_text::<String>(URL)),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(chain_id, node_url, filter, signature)| Self {
+                chain_id,
+                node_url,
+                filter,
+                signature,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepFilesGlob
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/FilesGlob
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::FetchStepFilesGlob {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            proptest::option::of(any::<odf::source::EventTimeSource>()),
+            proptest::option::of(any::<odf::source::SourceCaching>()),
+            proptest::option::of(any::<odf::source::SourceOrdering>()),
+        )
+            .prop_map(|(path, event_time, cache, order)| Self {
+                path,
+                event_time,
+                cache,
+                order,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepMqtt
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Mqtt
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::FetchStepMqtt {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<i32>(),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>().prop_map(odf::dtos::Sensitive::new)),
+            proptest::collection::vec(any::<odf::source::MqttTopicSubscription>(), 0..4),
+        )
+            .prop_map(|(host, port, username, password, topics)| Self {
+                host,
+                port,
+                username,
+                password,
+                topics,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FetchStepUrl
+// Schema: https://opendatafabric.org/schemas/legacy/v0/FetchStep#/$defs/Url
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::FetchStepUrl {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<String>(URL),
+            proptest::option::of(any::<odf::source::EventTimeSource>()),
+            proptest::option::of(any::<odf::source::SourceCaching>()),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::source::RequestHeader>(),
+                0..4,
+            )),
+        )
+            .prop_map(|(url, event_time, cache, headers)| Self {
+                url,
+                event_time,
+                cache,
+                headers,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FilterCondition
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/FilterCondition
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::FilterCondition {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any_json()),
+            proptest::option::of(proptest::collection::vec(any_json(), 0..4)),
+            proptest::option::of(any::<bool>()),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(eq, r#in, exists, glob)| Self {
+                eq,
+                r#in,
+                exists,
+                glob,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Flow
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/Flow
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::Flow {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::flow::FlowSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowSpec
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceSelector>(),
+            proptest::collection::vec(any::<odf::flow::FlowTrigger>(), 0..4),
+            proptest::collection::vec(any::<odf::flow::TaskSpec>(), 0..4),
+        )
+            .prop_map(|(target, triggers, tasks)| Self {
+                target,
+                triggers,
+                tasks,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowSpecInput
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceSelector>(),
+            proptest::collection::vec(any::<odf::flow::FlowTriggerInput>(), 0..4),
+            proptest::collection::vec(any::<odf::flow::TaskSpecInput>(), 0..4),
+        )
+            .prop_map(|(target, triggers, tasks)| Self {
+                target,
+                triggers,
+                tasks,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTrigger
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTrigger {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::flow::FlowTriggerSchedule>().prop_map(odf::flow::FlowTrigger::Schedule),
+            any::<odf::flow::FlowTriggerEvent>().prop_map(odf::flow::FlowTrigger::Event),
+            any::<odf::flow::FlowTriggerSource>().prop_map(odf::flow::FlowTrigger::Source),
+            any::<odf::flow::FlowTriggerDataset>().prop_map(odf::flow::FlowTrigger::Dataset),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerDataset
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Dataset
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerDataset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::dataset::DatasetSelector>(),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+        )
+            .prop_map(|(dataset, events)| Self { dataset, events })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerEvent
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Event
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerEvent {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::event::EventFilter>(),
+            proptest::option::of(from_text::<DurationString>(DURATION)),
+            proptest::option::of(any::<u64>()),
+        )
+            .prop_map(|(events, cooldown, cooldown_max_batch)| Self {
+                events,
+                cooldown,
+                cooldown_max_batch,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerSchedule
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Schedule
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerSchedule {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(),).prop_map(|(cron,)| Self { cron }).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerSource
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTrigger#/$defs/Source
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerSource {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHandle>(),
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(from_text::<DurationString>(DURATION)),
+        )
+            .prop_map(|(source, min_records_to_await, max_await_interval)| Self {
+                source,
+                min_records_to_await,
+                max_await_interval,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInput
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::flow::FlowTriggerInputSchedule>()
+                .prop_map(odf::flow::FlowTriggerInput::Schedule),
+            any::<odf::flow::FlowTriggerInputEvent>().prop_map(odf::flow::FlowTriggerInput::Event),
+            any::<odf::flow::FlowTriggerInputSource>()
+                .prop_map(odf::flow::FlowTriggerInput::Source),
+            any::<odf::flow::FlowTriggerInputDataset>()
+                .prop_map(odf::flow::FlowTriggerInput::Dataset),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputDataset
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Dataset
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerInputDataset {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::dataset::DatasetSelector>(),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+        )
+            .prop_map(|(dataset, events)| Self { dataset, events })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputEvent
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Event
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerInputEvent {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::event::EventFilter>(),
+            proptest::option::of(from_text::<DurationString>(DURATION)),
+            proptest::option::of(any::<u64>()),
+        )
+            .prop_map(|(events, cooldown, cooldown_max_batch)| Self {
+                events,
+                cooldown,
+                cooldown_max_batch,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputSchedule
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Schedule
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerInputSchedule {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(),).prop_map(|(cron,)| Self { cron }).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FlowTriggerInputSource
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/FlowTriggerInput#/$defs/Source
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::FlowTriggerInputSource {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceRef>(),
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(from_text::<DurationString>(DURATION)),
+        )
+            .prop_map(|(source, min_records_to_await, max_await_interval)| Self {
+                source,
+                min_records_to_await,
+                max_await_interval,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngestParams
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngestParams
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngestParams {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<u64>()),)
+            .prop_map(|(target_slice_records,)| Self {
+                target_slice_records,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Ingress
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::Ingress {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::source::IngressUrl>().prop_map(odf::source::Ingress::Url),
+            any::<odf::source::IngressFilesGlob>().prop_map(odf::source::Ingress::FilesGlob),
+            any::<odf::source::IngressContainer>().prop_map(odf::source::Ingress::Container),
+            any::<odf::source::IngressMqtt>().prop_map(odf::source::Ingress::Mqtt),
+            any::<odf::source::IngressEvmLogs>().prop_map(odf::source::Ingress::EvmLogs),
+            any::<odf::source::IngressRestEndpoint>().prop_map(odf::source::Ingress::RestEndpoint),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressContainer
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Container
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressContainer {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::source::EnvVar>(),
+                0..4,
+            )),
+        )
+            .prop_map(|(image, command, args, env)| Self {
+                image,
+                command,
+                args,
+                env,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressEvmLogs
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/EvmLogs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressEvmLogs {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(from_text::<String>(URL)),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(chain_id, node_url, filter, signature)| Self {
+                chain_id,
+                node_url,
+                filter,
+                signature,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressFilesGlob
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/FilesGlob
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressFilesGlob {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            proptest::option::of(any::<odf::source::EventTimeSource>()),
+            proptest::option::of(any::<odf::source::SourceCaching>()),
+            proptest::option::of(any::<odf::source::SourceOrdering>()),
+        )
+            .prop_map(|(path, event_time, cache, order)| Self {
+                path,
+                event_time,
+                cache,
+                order,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressMqtt
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Mqtt
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressMqtt {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<i32>(),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>().prop_map(odf::dtos::Sensitive::new)),
+            proptest::collection::vec(any::<odf::source::MqttTopicSubscription>(), 0..4),
+        )
+            .prop_map(|(host, port, username, password, topics)| Self {
+                host,
+                port,
+                username,
+                password,
+                topics,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressRestEndpoint
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/RestEndpoint
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressRestEndpoint {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<odf::source::IngressBuffer>()),)
+            .prop_map(|(buffer,)| Self { buffer })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressUrl
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Ingress#/$defs/Url
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressUrl {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<String>(URL),
+            proptest::option::of(any::<odf::source::EventTimeSource>()),
+            proptest::option::of(any::<odf::source::SourceCaching>()),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::source::RequestHeader>(),
+                0..4,
+            )),
+        )
+            .prop_map(|(url, event_time, cache, headers)| Self {
+                url,
+                event_time,
+                cache,
+                headers,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressBuffer
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressBuffer {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::source::IngressBufferMemory>().prop_map(odf::source::IngressBuffer::Memory),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// IngressBufferMemory
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/IngressBuffer#/$defs/Memory
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::IngressBufferMemory {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(
+                proptest::sample::select(vec![r#""Reject""#, r#""DropOldest""#])
+                    .prop_map(|v| serde_json::from_str(v).unwrap()),
+            ),
+        )
+            .prop_map(|(buffer_size, overflow_policy)| Self {
+                buffer_size,
+                overflow_policy,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilter
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::LabelFilter {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let leaf = prop_oneof![
+            any::<odf::resource::LabelFilterMatch>().prop_map(odf::resource::LabelFilter::Match),
+        ];
+        leaf.prop_recursive(3, 16, 4, |inner| {
+            prop_oneof![
+                arbitrary_resource_label_filter_and(inner.clone())
+                    .prop_map(odf::resource::LabelFilter::And),
+                arbitrary_resource_label_filter_or(inner.clone())
+                    .prop_map(odf::resource::LabelFilter::Or),
+                arbitrary_resource_label_filter_not(inner.clone())
+                    .prop_map(odf::resource::LabelFilter::Not),
+            ]
+        })
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterAnd
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/And
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::resource::LabelFilterAnd`] with nested [`odf::resource::LabelFilter`] values drawn from the bounded strategy
+pub fn arbitrary_resource_label_filter_and(
+    inner: BoxedStrategy<odf::resource::LabelFilter>,
+) -> BoxedStrategy<odf::resource::LabelFilterAnd> {
+    (proptest::collection::vec(inner.clone(), 0..4),)
+        .prop_map(|(and,)| odf::resource::LabelFilterAnd { and })
+        .boxed()
+}
+
+impl Arbitrary for odf::resource::LabelFilterAnd {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_resource_label_filter_and(any::<odf::resource::LabelFilter>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterMatch
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Match
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::LabelFilterMatch {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(
+            any::<String>(),
+            any::<odf::resource::FilterCondition>(),
+            0..4,
+        )
+        .prop_map(|entries| Self { entries })
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterNot
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Not
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::resource::LabelFilterNot`] with nested [`odf::resource::LabelFilter`] values drawn from the bounded strategy
+pub fn arbitrary_resource_label_filter_not(
+    inner: BoxedStrategy<odf::resource::LabelFilter>,
+) -> BoxedStrategy<odf::resource::LabelFilterNot> {
+    (inner.clone().prop_map(Box::new),)
+        .prop_map(|(not,)| odf::resource::LabelFilterNot { not })
+        .boxed()
+}
+
+impl Arbitrary for odf::resource::LabelFilterNot {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_resource_label_filter_not(any::<odf::resource::LabelFilter>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterOr
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Or
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generates [`odf::resource::LabelFilterOr`] with nested [`odf::resource::LabelFilter`] values drawn from the bounded strategy
+pub fn arbitrary_resource_label_filter_or(
+    inner: BoxedStrategy<odf::resource::LabelFilter>,
+) -> BoxedStrategy<odf::resource::LabelFilterOr> {
+    (proptest::collection::vec(inner.clone(), 0..4),)
+        .prop_map(|(or,)| odf::resource::LabelFilterOr { or })
+        .boxed()
+}
+
+impl Arbitrary for odf::resource::LabelFilterOr {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        arbitrary_resource_label_filter_or(any::<odf::resource::LabelFilter>())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Manifest
+// Schema: https://opendatafabric.org/schemas/legacy/v0/Manifest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<ContentT: Arbitrary + 'static> Arbitrary for odf::legacy::Manifest<ContentT> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<String>(MULTICODEC),
+            any::<i32>(),
+            any::<ContentT>(),
+        )
+            .prop_map(|(kind, version, content)| Self {
+                kind,
+                version,
+                content,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategy
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MergeStrategy {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::source::MergeStrategyAppend>().prop_map(odf::source::MergeStrategy::Append),
+            any::<odf::source::MergeStrategyLedger>().prop_map(odf::source::MergeStrategy::Ledger),
+            any::<odf::source::MergeStrategySnapshot>()
+                .prop_map(odf::source::MergeStrategy::Snapshot),
+            any::<odf::source::MergeStrategyChangelogStream>()
+                .prop_map(odf::source::MergeStrategy::ChangelogStream),
+            any::<odf::source::MergeStrategyUpsertStream>()
+                .prop_map(odf::source::MergeStrategy::UpsertStream),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyAppend
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Append
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MergeStrategyAppend {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyChangelogStream
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/ChangelogStream
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MergeStrategyChangelogStream {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(any::<String>(), 0..4),)
+            .prop_map(|(primary_key,)| Self { primary_key })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyLedger
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Ledger
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MergeStrategyLedger {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(any::<String>(), 0..4),)
+            .prop_map(|(primary_key,)| Self { primary_key })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategySnapshot
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/Snapshot
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MergeStrategySnapshot {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<String>(), 0..4),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+        )
+            .prop_map(|(primary_key, compare_columns)| Self {
+                primary_key,
+                compare_columns,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MergeStrategyUpsertStream
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MergeStrategy#/$defs/UpsertStream
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MergeStrategyUpsertStream {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(any::<String>(), 0..4),)
+            .prop_map(|(primary_key,)| Self { primary_key })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MetadataBlock
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/MetadataBlock
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::MetadataBlock {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any_date_time(),
+            proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),
+            any::<u64>(),
+            any::<odf::dataset::MetadataEvent>(),
+        )
+            .prop_map(
+                |(system_time, prev_block_hash, sequence_number, event)| Self {
+                    system_time,
+                    prev_block_hash,
+                    sequence_number,
+                    event,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MetadataEvent
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/MetadataEvent
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::MetadataEvent {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::dataset::AddData>().prop_map(odf::dataset::MetadataEvent::AddData),
+            any::<odf::dataset::ExecuteTransform>()
+                .prop_map(odf::dataset::MetadataEvent::ExecuteTransform),
+            any::<odf::dataset::Seed>().prop_map(odf::dataset::MetadataEvent::Seed),
+            any::<odf::legacy::SetPollingSource>()
+                .prop_map(odf::dataset::MetadataEvent::SetPollingSource),
+            any::<odf::dataset::SetTransform>().prop_map(odf::dataset::MetadataEvent::SetTransform),
+            any::<odf::dataset::SetVocab>().prop_map(odf::dataset::MetadataEvent::SetVocab),
+            any::<odf::dataset::SetAttachments>()
+                .prop_map(odf::dataset::MetadataEvent::SetAttachments),
+            any::<odf::dataset::SetInfo>().prop_map(odf::dataset::MetadataEvent::SetInfo),
+            any::<odf::dataset::SetLicense>().prop_map(odf::dataset::MetadataEvent::SetLicense),
+            any::<odf::dataset::SetDataSchema>()
+                .prop_map(odf::dataset::MetadataEvent::SetDataSchema),
+            any::<odf::legacy::AddPushSource>()
+                .prop_map(odf::dataset::MetadataEvent::AddPushSource),
+            any::<odf::legacy::DisablePushSource>()
+                .prop_map(odf::dataset::MetadataEvent::DisablePushSource),
+            any::<odf::legacy::DisablePollingSource>()
+                .prop_map(odf::dataset::MetadataEvent::DisablePollingSource),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MqttQos
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttQos
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MqttQos {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::AtMostOnce, Self::AtLeastOnce, Self::ExactlyOnce])
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// MqttTopicSubscription
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttTopicSubscription
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::MqttTopicSubscription {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            proptest::option::of(any::<odf::source::MqttQos>()),
+        )
+            .prop_map(|(path, qos)| Self { path, qos })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectFileReference
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectFileReference
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::ObjectFileReference {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::protocol::ObjectType>(),
+            from_text::<odf::Multihash>(MULTIHASH),
+        )
+            .prop_map(|(object_type, physical_hash)| Self {
+                object_type,
+                physical_hash,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectPullStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPullStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::ObjectPullStrategy {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::HttpDownload]).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectPushStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPushStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::ObjectPushStrategy {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::SkipUpload, Self::HttpUpload]).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectType
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectType
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::ObjectType {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::MetadataBlock, Self::DataSlice, Self::Checkpoint])
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectsBatch
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectsBatch
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::ObjectsBatch {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<u64>(),
+            any::<odf::protocol::ObjectType>(),
+            any::<String>(),
+            any::<String>(),
+            any::<String>(),
+        )
+            .prop_map(
+                |(objects_count, object_type, media_type, encoding, payload)| Self {
+                    objects_count,
+                    object_type,
+                    media_type,
+                    encoding,
+                    payload,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// OffsetInterval
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/OffsetInterval
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::OffsetInterval {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u64>(), any::<u64>())
+            .prop_map(|(start, end)| Self { start, end })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolume
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolume
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::PersistentVolume {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::storage::PersistentVolumeSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeRef
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::PersistentVolumeRef {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::auth::AccountRef>()),
+            proptest::option::of(from_text::<odf::ResourceID>(RESOURCE_ID)),
+            proptest::option::of(from_text::<odf::ResourceName>(NAME)),
+        )
+            .prop_map(|(account, id, name)| Self { account, id, name })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpec
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::PersistentVolumeSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::storage::PersistentVolumeSpecS3>()
+                .prop_map(odf::storage::PersistentVolumeSpec::S3),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpecS3
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpec#/$defs/S3
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::PersistentVolumeSpecS3 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<String>(URL)),
+            proptest::option::of(any::<String>()),
+            any::<String>(),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<odf::storage::VolumeCapacity>()),
+            proptest::option::of(any::<odf::storage::AwsCredentials>()),
+        )
+            .prop_map(
+                |(endpoint, region, bucket, prefix, capacity, credentials)| Self {
+                    endpoint,
+                    region,
+                    bucket,
+                    prefix,
+                    capacity,
+                    credentials,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpecInput
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::PersistentVolumeSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::storage::PersistentVolumeSpecInputS3>()
+                .prop_map(odf::storage::PersistentVolumeSpecInput::S3),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PersistentVolumeSpecInputS3
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolumeSpecInput#/$defs/S3
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::PersistentVolumeSpecInputS3 {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<String>(URL)),
+            proptest::option::of(any::<String>()),
+            any::<String>(),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<odf::storage::VolumeCapacity>()),
+            proptest::option::of(any::<odf::storage::AwsCredentialsInput>()),
+        )
+            .prop_map(
+                |(endpoint, region, bucket, prefix, capacity, credentials)| Self {
+                    endpoint,
+                    region,
+                    bucket,
+                    prefix,
+                    capacity,
+                    credentials,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PrepStep
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::PrepStep {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::source::PrepStepDecompress>().prop_map(odf::source::PrepStep::Decompress),
+            any::<odf::source::PrepStepPipe>().prop_map(odf::source::PrepStep::Pipe),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PrepStepDecompress
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Decompress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::PrepStepDecompress {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::source::CompressionFormat>(),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(format, sub_path)| Self { format, sub_path })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PrepStepPipe
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/PrepStep#/$defs/Pipe
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::PrepStepPipe {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::collection::vec(any::<String>(), 0..4),)
+            .prop_map(|(command,)| Self { command })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Projection
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Projection
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::Projection {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::dataset::ProjectionSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ProjectionSpec
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ProjectionSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::ProjectionSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<odf::dataset::TransformInput>(), 0..4),
+            any::<odf::dataset::Transform>(),
+        )
+            .prop_map(|(inputs, project)| Self { inputs, project })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ProjectionSpecInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/ProjectionSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::ProjectionSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<odf::dataset::TransformInput>(), 0..4),
+            any::<odf::dataset::Transform>(),
+        )
+            .prop_map(|(inputs, project)| Self { inputs, project })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PullObjectTransferStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PullObjectTransferStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::PullObjectTransferStrategy {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::protocol::ObjectFileReference>(),
+            any::<odf::protocol::ObjectPullStrategy>(),
+            any::<odf::protocol::TransferUrl>(),
+        )
+            .prop_map(|(object_file, pull_strategy, download_from)| Self {
+                object_file,
+                pull_strategy,
+                download_from,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PushObjectTransferStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PushObjectTransferStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::PushObjectTransferStrategy {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::protocol::ObjectFileReference>(),
+            any::<odf::protocol::ObjectPushStrategy>(),
+            proptest::option::of(any::<odf::protocol::TransferUrl>()),
+        )
+            .prop_map(|(object_file, push_strategy, upload_to)| Self {
+                object_file,
+                push_strategy,
+                upload_to,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryRequest
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::RawQueryRequest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(from_text::<std::path::PathBuf>(PATH), 0..4),
+            any::<odf::dataset::Transform>(),
+            from_text::<std::path::PathBuf>(PATH),
+        )
+            .prop_map(|(input_data_paths, transform, output_data_path)| Self {
+                input_data_paths,
+                transform,
+                output_data_path,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponse
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::RawQueryResponse {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::engine::RawQueryResponseProgress>()
+                .prop_map(odf::engine::RawQueryResponse::Progress),
+            any::<odf::engine::RawQueryResponseSuccess>()
+                .prop_map(odf::engine::RawQueryResponse::Success),
+            any::<odf::engine::RawQueryResponseInvalidQuery>()
+                .prop_map(odf::engine::RawQueryResponse::InvalidQuery),
+            any::<odf::engine::RawQueryResponseInternalError>()
+                .prop_map(odf::engine::RawQueryResponse::InternalError),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseInternalError
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/InternalError
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::RawQueryResponseInternalError {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), proptest::option::of(any::<String>()))
+            .prop_map(|(message, backtrace)| Self { message, backtrace })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseInvalidQuery
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/InvalidQuery
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::RawQueryResponseInvalidQuery {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(),)
+            .prop_map(|(message,)| Self { message })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseProgress
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/Progress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::RawQueryResponseProgress {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RawQueryResponseSuccess
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/RawQueryResponse#/$defs/Success
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::RawQueryResponseSuccess {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u64>(),)
+            .prop_map(|(num_records,)| Self { num_records })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStep
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStep {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::source::ReadStepCsv>().prop_map(odf::source::ReadStep::Csv),
+            any::<odf::source::ReadStepGeoJson>().prop_map(odf::source::ReadStep::GeoJson),
+            any::<odf::source::ReadStepEsriShapefile>()
+                .prop_map(odf::source::ReadStep::EsriShapefile),
+            any::<odf::source::ReadStepParquet>().prop_map(odf::source::ReadStep::Parquet),
+            any::<odf::source::ReadStepJson>().prop_map(odf::source::ReadStep::Json),
+            any::<odf::source::ReadStepNdJson>().prop_map(odf::source::ReadStep::NdJson),
+            any::<odf::source::ReadStepNdGeoJson>().prop_map(odf::source::ReadStep::NdGeoJson),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepCsv
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Csv
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStepCsv {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<bool>()),
+            proptest::option::of(any::<bool>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(
+                |(
+                    ddl_schema,
+                    separator,
+                    encoding,
+                    quote,
+                    escape,
+                    header,
+                    infer_schema,
+                    null_value,
+                    date_format,
+                    timestamp_format,
+                    schema,
+                )| Self {
+                    ddl_schema,
+                    separator,
+                    encoding,
+                    quote,
+                    escape,
+                    header,
+                    infer_schema,
+                    null_value,
+                    date_format,
+                    timestamp_format,
+                    schema,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepEsriShapefile
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/EsriShapefile
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStepEsriShapefile {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(|(ddl_schema, sub_path, schema)| Self {
+                ddl_schema,
+                sub_path,
+                schema,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepGeoJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/GeoJson
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStepGeoJson {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(|(ddl_schema, schema)| Self { ddl_schema, schema })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Json
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStepJson {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<String>()),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(
+                |(sub_path, ddl_schema, date_format, encoding, timestamp_format, schema)| Self {
+                    sub_path,
+                    ddl_schema,
+                    date_format,
+                    encoding,
+                    timestamp_format,
+                    schema,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepNdGeoJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdGeoJson
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStepNdGeoJson {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(|(ddl_schema, schema)| Self { ddl_schema, schema })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepNdJson
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/NdJson
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStepNdJson {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(
+                |(ddl_schema, date_format, encoding, timestamp_format, schema)| Self {
+                    ddl_schema,
+                    date_format,
+                    encoding,
+                    timestamp_format,
+                    schema,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ReadStepParquet
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/ReadStep#/$defs/Parquet
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::ReadStepParquet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(|(ddl_schema, schema)| Self { ddl_schema, schema })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Relation
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Relation
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::Relation {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHandle>(),
+            any::<String>(),
+            proptest::option::of(any_json()),
+            any::<odf::resource::ResourceHandle>(),
+        )
+            .prop_map(|(subject, relation, value, object)| Self {
+                subject,
+                relation,
+                value,
+                object,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RelationInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::RelationInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceRef>(),
+            any::<String>(),
+            proptest::option::of(any_json()),
+            any::<odf::resource::ResourceRef>(),
+        )
+            .prop_map(|(subject, relation, value, object)| Self {
+                subject,
+                relation,
+                value,
+                object,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Relations
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/Relations
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::Relations {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::auth::RelationsSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RelationsSpec
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationsSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::RelationsSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<odf::auth::Relation>(), 0..4),
+            proptest::collection::vec(any::<odf::auth::Attribute>(), 0..4),
+        )
+            .prop_map(|(relations, attributes)| Self {
+                relations,
+                attributes,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RelationsSpecInput
+// Schema: https://opendatafabric.org/schemas/auth/v1alpha1/RelationsSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::auth::RelationsSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::auth::RelationInput>(),
+                0..4,
+            )),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::auth::AttributeInput>(),
+                0..4,
+            )),
+        )
+            .prop_map(|(relations, attributes)| Self {
+                relations,
+                attributes,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// RequestHeader
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/RequestHeader
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::RequestHeader {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), any::<String>())
+            .prop_map(|(name, value)| Self { name, value })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Resource
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/Resource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<SpecT: Arbitrary + 'static> Arbitrary for odf::resource::Resource<SpecT> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::TypeUri>(TYPE_URI),
+            any::<odf::resource::ResourceHeaders>(),
+            any::<SpecT>(),
+            any::<odf::resource::ResourceStatus>(),
+        )
+            .prop_map(|(schema, headers, spec, status)| Self {
+                schema,
+                headers,
+                spec,
+                status,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceAnnotations
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceAnnotations
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceAnnotations {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(from_text::<odf::TypeRef>(TYPE_REF), any_json(), 0..4)
+            .prop_map(|entries| Self { entries })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceConditions
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceConditions
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceConditions {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(from_text::<odf::TypeRef>(TYPE_REF), any_json(), 0..4)
+            .prop_map(|entries| Self { entries })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceHandle
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHandle
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceHandle {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::auth::AccountHandle>(),
+            from_text::<odf::TypeUri>(TYPE_URI),
+            from_text::<odf::ResourceID>(RESOURCE_ID),
+            proptest::option::of(from_text::<odf::Did>(DID)),
+            from_text::<odf::ResourceName>(NAME),
+        )
+            .prop_map(|(account, r#type, id, did, name)| Self {
+                account,
+                r#type,
+                id,
+                did,
+                name,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceHeaders
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeaders
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceHeaders {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::ResourceID>(RESOURCE_ID),
+            from_text::<odf::ResourceName>(NAME),
+            any::<odf::auth::AccountHandle>(),
+            any::<odf::resource::ResourceLabels>(),
+            any::<odf::resource::ResourceAnnotations>(),
+            any::<u64>(),
+            any_date_time(),
+            any_date_time(),
+            proptest::option::of(any_date_time()),
+        )
+            .prop_map(
+                |(
+                    id,
+                    name,
+                    account,
+                    labels,
+                    annotations,
+                    generation,
+                    created_at,
+                    updated_at,
+                    deleted_at,
+                )| Self {
+                    id,
+                    name,
+                    account,
+                    labels,
+                    annotations,
+                    generation,
+                    created_at,
+                    updated_at,
+                    deleted_at,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceHeadersInput
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeadersInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceHeadersInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(from_text::<odf::ResourceID>(RESOURCE_ID)),
+            from_text::<odf::ResourceName>(NAME),
+            proptest::option::of(any::<odf::auth::AccountRef>()),
+            proptest::option::of(any::<odf::resource::ResourceLabels>()),
+            proptest::option::of(any::<odf::resource::ResourceAnnotations>()),
+        )
+            .prop_map(|(id, name, account, labels, annotations)| Self {
+                id,
+                name,
+                account,
+                labels,
+                annotations,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceInput
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<SpecT: Arbitrary + 'static> Arbitrary for odf::resource::ResourceInput<SpecT> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::TypeUri>(TYPE_URI),
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<SpecT>(),
+        )
+            .prop_map(|(schema, headers, spec)| Self {
+                schema,
+                headers,
+                spec,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceLabels
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceLabels
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceLabels {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(from_text::<odf::TypeRef>(TYPE_REF), any_json(), 0..4)
+            .prop_map(|entries| Self { entries })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourcePhase
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourcePhase
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourcePhase {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![
+            Self::Pending,
+            Self::Reconciling,
+            Self::Ready,
+            Self::Failed,
+        ])
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceRef
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceRef {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::auth::AccountRef>()),
+            from_text::<odf::TypeRef>(TYPE_REF),
+            proptest::option::of(from_text::<odf::ResourceID>(RESOURCE_ID)),
+            proptest::option::of(from_text::<odf::Did>(DID)),
+            proptest::option::of(from_text::<odf::ResourceName>(NAME)),
+        )
+            .prop_map(|(account, r#type, id, did, name)| Self {
+                account,
+                r#type,
+                id,
+                did,
+                name,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ResourceStatus
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceStatus
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::resource::ResourceStatus {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourcePhase>(),
+            proptest::option::of(any::<u64>()),
+            proptest::option::of(any_date_time()),
+            any::<odf::resource::ResourceConditions>(),
+        )
+            .prop_map(
+                |(phase, observed_generation, reconciled_at, conditions)| Self {
+                    phase,
+                    observed_generation,
+                    reconciled_at,
+                    conditions,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Secret
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Secret
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::Secret {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>().prop_map(odf::dtos::Sensitive::new),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(value, content_encoding)| Self {
+                value,
+                content_encoding,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SecretSet
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSet
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::SecretSet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::config::SecretSetSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SecretSetSpec
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::SecretSetSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::config::Secrets>(),)
+            .prop_map(|(secrets,)| Self { secrets })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SecretSetSpecInput
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/SecretSetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::SecretSetSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::config::Secrets>(),)
+            .prop_map(|(secrets,)| Self { secrets })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Secrets
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Secrets
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::Secrets {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(any::<String>(), any::<odf::config::Secret>(), 0..4)
+            .prop_map(|entries| Self { entries })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Seed
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Seed
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::Seed {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetID>(DID),
+            any::<odf::dataset::DatasetKind>(),
+        )
+            .prop_map(|(dataset_id, dataset_kind)| Self {
+                dataset_id,
+                dataset_kind,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetAttachments
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetAttachments
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::SetAttachments {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::dataset::Attachments>(),)
+            .prop_map(|(attachments,)| Self { attachments })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetDataSchema
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetDataSchema
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::SetDataSchema {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(proptest::collection::vec(any::<u8>(), 0..64)),
+            proptest::option::of(any::<odf::data::DataSchema>()),
+        )
+            .prop_map(|(raw_arrow_schema, schema)| Self {
+                raw_arrow_schema,
+                schema,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetInfo
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetInfo
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::SetInfo {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<String>()),
+            proptest::option::of(proptest::collection::vec(any::<String>(), 0..4)),
+        )
+            .prop_map(|(description, keywords)| Self {
+                description,
+                keywords,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetLicense
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetLicense
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::SetLicense {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            any::<String>(),
+            proptest::option::of(any::<String>()),
+            from_text::<String>(URL),
+        )
+            .prop_map(|(short_name, name, spdx_id, website_url)| Self {
+                short_name,
+                name,
+                spdx_id,
+                website_url,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetPollingSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/SetPollingSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::legacy::SetPollingSource {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::legacy::FetchStep>(),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::source::PrepStep>(),
+                0..4,
+            )),
+            any::<odf::source::ReadStep>(),
+            proptest::option::of(any::<odf::dataset::Transform>()),
+            any::<odf::source::MergeStrategy>(),
+        )
+            .prop_map(|(fetch, prepare, read, preprocess, merge)| Self {
+                fetch,
+                prepare,
+                read,
+                preprocess,
+                merge,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetTransform
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetTransform
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::SetTransform {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::collection::vec(any::<odf::dataset::TransformInput>(), 0..4),
+            any::<odf::dataset::Transform>(),
+        )
+            .prop_map(|(inputs, transform)| Self { inputs, transform })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SetVocab
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SetVocab
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::SetVocab {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(
+                |(offset_column, operation_type_column, system_time_column, event_time_column)| {
+                    Self {
+                        offset_column,
+                        operation_type_column,
+                        system_time_column,
+                        event_time_column,
+                    }
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Source
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/Source
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::Source {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::source::SourceSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceCaching
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::SourceCaching {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::source::SourceCachingForever>()
+                .prop_map(odf::source::SourceCaching::Forever),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceCachingForever
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceCaching#/$defs/Forever
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::SourceCachingForever {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceOrdering
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceOrdering
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::SourceOrdering {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::ByEventTime, Self::ByName]).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceSpec
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::SourceSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::config::ValueRefs>()),
+            proptest::option::of(any::<odf::source::Ingress>()),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::source::PrepStep>(),
+                0..4,
+            )),
+            any::<odf::source::ReadStep>(),
+            proptest::option::of(any::<odf::dataset::Transform>()),
+            proptest::option::of(any::<odf::source::MergeStrategy>()),
+            proptest::option::of(any::<odf::dataset::DatasetVocabulary>()),
+        )
+            .prop_map(
+                |(config, ingress, prepare, read, preprocess, merge, vocab)| Self {
+                    config,
+                    ingress,
+                    prepare,
+                    read,
+                    preprocess,
+                    merge,
+                    vocab,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceSpecInput
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::SourceSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::config::ValueRefs>()),
+            proptest::option::of(any::<odf::source::Ingress>()),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::source::PrepStep>(),
+                0..4,
+            )),
+            any::<odf::source::ReadStep>(),
+            proptest::option::of(any::<odf::dataset::Transform>()),
+            proptest::option::of(any::<odf::source::MergeStrategy>()),
+            proptest::option::of(any::<odf::dataset::DatasetVocabulary>()),
+        )
+            .prop_map(
+                |(config, ingress, prepare, read, preprocess, merge, vocab)| Self {
+                    config,
+                    ingress,
+                    prepare,
+                    read,
+                    preprocess,
+                    merge,
+                    vocab,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SourceState
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceState
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::source::SourceState {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), any::<String>(), any::<String>())
+            .prop_map(|(source_name, kind, value)| Self {
+                source_name,
+                kind,
+                value,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// SqlQueryStep
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/SqlQueryStep
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::SqlQueryStep {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<String>()), any::<String>())
+            .prop_map(|(alias, query)| Self { alias, query })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Task
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/Task
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::Task {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            proptest::option::of(any::<odf::flow::TaskSpecInput>()),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpec
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::flow::TaskSpecIngest>().prop_map(odf::flow::TaskSpec::Ingest),
+            any::<odf::flow::TaskSpecCompaction>().prop_map(odf::flow::TaskSpec::Compaction),
+            any::<odf::flow::TaskSpecGarbageCollection>()
+                .prop_map(odf::flow::TaskSpec::GarbageCollection),
+            any::<odf::flow::TaskSpecWebhookCall>().prop_map(odf::flow::TaskSpec::WebhookCall),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecCompaction
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/Compaction
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecCompaction {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<odf::dataset::CompactionParams>()),)
+            .prop_map(|(params,)| Self { params })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecGarbageCollection
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/GarbageCollection
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecGarbageCollection {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecIngest
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/Ingest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecIngest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHandle>(),
+            proptest::option::of(any::<odf::source::IngestParams>()),
+        )
+            .prop_map(|(source, params)| Self { source, params })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecWebhookCall
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpec#/$defs/WebhookCall
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecWebhookCall {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHandle>(),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(target, payload)| Self { target, payload })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInput
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::flow::TaskSpecInputIngest>().prop_map(odf::flow::TaskSpecInput::Ingest),
+            any::<odf::flow::TaskSpecInputCompaction>()
+                .prop_map(odf::flow::TaskSpecInput::Compaction),
+            any::<odf::flow::TaskSpecInputGarbageCollection>()
+                .prop_map(odf::flow::TaskSpecInput::GarbageCollection),
+            any::<odf::flow::TaskSpecInputWebhookCall>()
+                .prop_map(odf::flow::TaskSpecInput::WebhookCall),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputCompaction
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/Compaction
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecInputCompaction {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any::<odf::dataset::CompactionParams>()),)
+            .prop_map(|(params,)| Self { params })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputGarbageCollection
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/GarbageCollection
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecInputGarbageCollection {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputIngest
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/Ingest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecInputIngest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceRef>(),
+            proptest::option::of(any::<odf::source::IngestParams>()),
+        )
+            .prop_map(|(source, params)| Self { source, params })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TaskSpecInputWebhookCall
+// Schema: https://opendatafabric.org/schemas/flow/v1alpha1/TaskSpecInput#/$defs/WebhookCall
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::flow::TaskSpecInputWebhookCall {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceRef>(),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(target, payload)| Self { target, payload })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TemporalTable
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/TemporalTable
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::TemporalTable {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            proptest::collection::vec(any::<String>(), 0..4),
+        )
+            .prop_map(|(name, primary_key)| Self { name, primary_key })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TimeUnit
+// Schema: https://opendatafabric.org/schemas/data/v1alpha1/TimeUnit
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::data::TimeUnit {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![
+            Self::Second,
+            Self::Millisecond,
+            Self::Microsecond,
+            Self::Nanosecond,
+        ])
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransferSizeEstimation
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferSizeEstimation
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::TransferSizeEstimation {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<u64>(), any::<u64>(), any::<u64>(), any::<u64>())
+            .prop_map(
+                |(num_blocks, num_objects, bytes_in_raw_blocks, bytes_in_raw_objects)| Self {
+                    num_blocks,
+                    num_objects,
+                    bytes_in_raw_blocks,
+                    bytes_in_raw_objects,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransferUrl
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferUrl
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::protocol::TransferUrl {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<String>(URL),
+            proptest::option::of(any_date_time()),
+        )
+            .prop_map(|(url, expires_at)| Self { url, expires_at })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Transform
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Transform
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::Transform {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![any::<odf::dataset::TransformSql>().prop_map(odf::dataset::Transform::Sql),]
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformSql
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Transform#/$defs/Sql
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::TransformSql {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<String>(),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::dataset::SqlQueryStep>(),
+                0..4,
+            )),
+            proptest::option::of(proptest::collection::vec(
+                any::<odf::dataset::TemporalTable>(),
+                0..4,
+            )),
+        )
+            .prop_map(|(engine, version, query, queries, temporal_tables)| Self {
+                engine,
+                version,
+                query,
+                queries,
+                temporal_tables,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/TransformInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::TransformInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetRef>(DATASET_REF),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(dataset_ref, alias)| Self { dataset_ref, alias })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformRequest
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::TransformRequest {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetID>(DID),
+            from_text::<odf::DatasetAlias>(DATASET_ALIAS),
+            any_date_time(),
+            any::<odf::dataset::DatasetVocabulary>(),
+            any::<odf::dataset::Transform>(),
+            proptest::collection::vec(any::<odf::engine::TransformRequestInput>(), 0..4),
+            any::<u64>(),
+            proptest::option::of(from_text::<std::path::PathBuf>(PATH)),
+            from_text::<std::path::PathBuf>(PATH),
+            from_text::<std::path::PathBuf>(PATH),
+        )
+            .prop_map(
+                |(
+                    dataset_id,
+                    dataset_alias,
+                    system_time,
+                    vocab,
+                    transform,
+                    query_inputs,
+                    next_offset,
+                    prev_checkpoint_path,
+                    new_checkpoint_path,
+                    new_data_path,
+                )| Self {
+                    dataset_id,
+                    dataset_alias,
+                    system_time,
+                    vocab,
+                    transform,
+                    query_inputs,
+                    next_offset,
+                    prev_checkpoint_path,
+                    new_checkpoint_path,
+                    new_data_path,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformRequestInput
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformRequestInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::TransformRequestInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<odf::DatasetID>(DID),
+            from_text::<odf::DatasetAlias>(DATASET_ALIAS),
+            any::<String>(),
+            any::<odf::dataset::DatasetVocabulary>(),
+            proptest::option::of(any::<odf::dataset::OffsetInterval>()),
+            proptest::collection::vec(from_text::<std::path::PathBuf>(PATH), 0..4),
+            from_text::<std::path::PathBuf>(PATH),
+            proptest::collection::vec(any::<odf::dataset::Watermark>(), 0..4),
+        )
+            .prop_map(
+                |(
+                    dataset_id,
+                    dataset_alias,
+                    query_alias,
+                    vocab,
+                    offset_interval,
+                    data_paths,
+                    schema_file,
+                    explicit_watermarks,
+                )| Self {
+                    dataset_id,
+                    dataset_alias,
+                    query_alias,
+                    vocab,
+                    offset_interval,
+                    data_paths,
+                    schema_file,
+                    explicit_watermarks,
+                },
+            )
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponse
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::TransformResponse {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<odf::engine::TransformResponseProgress>()
+                .prop_map(odf::engine::TransformResponse::Progress),
+            any::<odf::engine::TransformResponseSuccess>()
+                .prop_map(odf::engine::TransformResponse::Success),
+            any::<odf::engine::TransformResponseInvalidQuery>()
+                .prop_map(odf::engine::TransformResponse::InvalidQuery),
+            any::<odf::engine::TransformResponseInternalError>()
+                .prop_map(odf::engine::TransformResponse::InternalError),
+        ]
+        .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseInternalError
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/InternalError
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::TransformResponseInternalError {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(), proptest::option::of(any::<String>()))
+            .prop_map(|(message, backtrace)| Self { message, backtrace })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseInvalidQuery
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/InvalidQuery
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::TransformResponseInvalidQuery {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(),)
+            .prop_map(|(message,)| Self { message })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseProgress
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/Progress
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::TransformResponseProgress {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        Just(Self {}).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransformResponseSuccess
+// Schema: https://opendatafabric.org/schemas/engine/v1alpha1/TransformResponse#/$defs/Success
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::engine::TransformResponseSuccess {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::dataset::OffsetInterval>()),
+            proptest::option::of(any_date_time()),
+        )
+            .prop_map(|(new_offset_interval, new_watermark)| Self {
+                new_offset_interval,
+                new_watermark,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ValueHandle
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueHandle
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::ValueHandle {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::auth::AccountHandle>(),
+            from_text::<odf::TypeUri>(TYPE_URI),
+            from_text::<odf::ResourceID>(RESOURCE_ID),
+            from_text::<odf::ResourceName>(NAME),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(account, r#type, id, name, path)| Self {
+                account,
+                r#type,
+                id,
+                name,
+                path,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ValueRef
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRef
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::ValueRef {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            proptest::option::of(any::<odf::auth::AccountRef>()),
+            from_text::<odf::TypeRef>(TYPE_REF),
+            proptest::option::of(from_text::<odf::ResourceID>(RESOURCE_ID)),
+            proptest::option::of(from_text::<odf::ResourceName>(NAME)),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(account, r#type, id, name, path)| Self {
+                account,
+                r#type,
+                id,
+                name,
+                path,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ValueRefs
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/ValueRefs
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::ValueRefs {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(any::<String>(), any::<odf::config::ValueRef>(), 0..4)
+            .prop_map(|entries| Self { entries })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Variable
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Variable
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::Variable {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<String>(),)
+            .prop_map(|(value,)| Self { value })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// VariableSet
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSet
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::VariableSet {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::config::VariableSetSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// VariableSetSpec
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::VariableSetSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::config::Variables>(),)
+            .prop_map(|(variables,)| Self { variables })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// VariableSetSpecInput
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/VariableSetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::VariableSetSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any::<odf::config::Variables>(),)
+            .prop_map(|(variables,)| Self { variables })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Variables
+// Schema: https://opendatafabric.org/schemas/config/v1alpha1/Variables
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::config::Variables {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::collection::btree_map(any::<String>(), any::<odf::config::Variable>(), 0..4)
+            .prop_map(|entries| Self { entries })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// VolumeCapacity
+// Schema: https://opendatafabric.org/schemas/storage/v1alpha1/VolumeCapacity
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::storage::VolumeCapacity {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (proptest::option::of(any_byte_size()),)
+            .prop_map(|(storage,)| Self { storage })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Watermark
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Watermark
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::dataset::Watermark {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (any_date_time(), any_date_time())
+            .prop_map(|(system_time, event_time)| Self {
+                system_time,
+                event_time,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTarget
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTarget
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::sink::WebhookTarget {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::resource::ResourceHeadersInput>(),
+            any::<odf::sink::WebhookTargetSpecInput>(),
+        )
+            .prop_map(|(headers, spec)| Self { headers, spec })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTargetSpec
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::sink::WebhookTargetSpec {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<String>(URL),
+            proptest::option::of(any::<odf::config::Secret>()),
+        )
+            .prop_map(|(url, secret)| Self { url, secret })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTargetSpecInput
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::sink::WebhookTargetSpecInput {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            from_text::<String>(URL),
+            proptest::option::of(any::<odf::config::Secret>()),
+        )
+            .prop_map(|(url, secret)| Self { url, secret })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTargetStatus
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::sink::WebhookTargetStatus {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<odf::sink::WebhookTargetStatusValue>(),
+            any_date_time(),
+            proptest::option::of(any::<String>()),
+            proptest::option::of(any::<String>()),
+        )
+            .prop_map(|(value, last_transition_time, reason, message)| Self {
+                value,
+                last_transition_time,
+                reason,
+                message,
+            })
+            .boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// WebhookTargetStatusValue
+// Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus#/$defs/Value
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl Arbitrary for odf::sink::WebhookTargetStatusValue {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![Self::Ready, Self::Failed]).boxed()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Round trips
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+    #[test]
+    fn test_roundtrip_auth_account_handle(value in any::<odf::auth::AccountHandle>()) {
+    check_serde_roundtrip::<_, yaml::auth::AccountHandle>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::AccountHandle, fb::AccountHandle<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_account_ref(value in any::<odf::auth::AccountRef>()) {
+    check_serde_roundtrip::<_, yaml::auth::AccountRef>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::AccountRef, fb::AccountRef<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_account_spec(value in any::<odf::auth::AccountSpec>()) {
+    check_serde_roundtrip::<_, yaml::auth::AccountSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::AccountSpec, fb::AccountSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_account_spec_input(value in any::<odf::auth::AccountSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::auth::AccountSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::AccountSpecInput, fb::AccountSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_account_type(value in any::<odf::auth::AccountType>()) {
+    check_serde_roundtrip::<_, yaml::auth::AccountType>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_add_data(value in any::<odf::dataset::AddData>()) {
+    check_serde_roundtrip::<_, yaml::dataset::AddData>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::AddData, fb::AddData<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_add_push_source(value in any::<odf::legacy::AddPushSource>()) {
+    check_serde_roundtrip::<_, yaml::legacy::AddPushSource>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::AddPushSource, fb::AddPushSource<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_attachment_embedded(value in any::<odf::dataset::AttachmentEmbedded>()) {
+    check_serde_roundtrip::<_, yaml::dataset::AttachmentEmbedded>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::AttachmentEmbedded, fb::AttachmentEmbedded<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_attachments(value in any::<odf::dataset::Attachments>()) {
+    check_serde_roundtrip::<_, yaml::dataset::Attachments>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_attachments_embedded(value in any::<odf::dataset::AttachmentsEmbedded>()) {
+    check_serde_roundtrip::<_, yaml::dataset::AttachmentsEmbedded>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::AttachmentsEmbedded, fb::AttachmentsEmbedded<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_attribute(value in any::<odf::auth::Attribute>()) {
+    check_serde_roundtrip::<_, yaml::auth::Attribute>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::Attribute, fb::Attribute<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_attribute_input(value in any::<odf::auth::AttributeInput>()) {
+    check_serde_roundtrip::<_, yaml::auth::AttributeInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::AttributeInput, fb::AttributeInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_storage_aws_credentials(value in any::<odf::storage::AwsCredentials>()) {
+    check_serde_roundtrip::<_, yaml::storage::AwsCredentials>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::storage::AwsCredentials, fb::AwsCredentials<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_storage_aws_credentials_input(value in any::<odf::storage::AwsCredentialsInput>()) {
+    check_serde_roundtrip::<_, yaml::storage::AwsCredentialsInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::storage::AwsCredentialsInput, fb::AwsCredentialsInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_checkpoint(value in any::<odf::dataset::Checkpoint>()) {
+    check_serde_roundtrip::<_, yaml::dataset::Checkpoint>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::Checkpoint, fb::Checkpoint<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_compaction_params(value in any::<odf::dataset::CompactionParams>()) {
+    check_serde_roundtrip::<_, yaml::dataset::CompactionParams>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::CompactionParams, fb::CompactionParams<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_compression_format(value in any::<odf::source::CompressionFormat>()) {
+    check_serde_roundtrip::<_, yaml::source::CompressionFormat>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_field(value in any::<odf::data::DataField>()) {
+    check_serde_roundtrip::<_, yaml::data::DataField>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataField, fb::DataField<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_schema(value in any::<odf::data::DataSchema>()) {
+    check_serde_roundtrip::<_, yaml::data::DataSchema>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataSchema, fb::DataSchema<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_data_slice(value in any::<odf::dataset::DataSlice>()) {
+    check_serde_roundtrip::<_, yaml::dataset::DataSlice>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::DataSlice, fb::DataSlice<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type(value in any::<odf::data::DataType>()) {
+    check_serde_roundtrip::<_, yaml::data::DataType>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_binary(value in any::<odf::data::DataTypeBinary>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeBinary>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeBinary, fb::DataTypeBinary<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_bool(value in any::<odf::data::DataTypeBool>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeBool>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeBool, fb::DataTypeBool<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_date(value in any::<odf::data::DataTypeDate>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeDate>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeDate, fb::DataTypeDate<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_decimal(value in any::<odf::data::DataTypeDecimal>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeDecimal>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeDecimal, fb::DataTypeDecimal<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_duration(value in any::<odf::data::DataTypeDuration>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeDuration>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeDuration, fb::DataTypeDuration<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_float_16(value in any::<odf::data::DataTypeFloat16>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeFloat16>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeFloat16, fb::DataTypeFloat16<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_float_32(value in any::<odf::data::DataTypeFloat32>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeFloat32>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeFloat32, fb::DataTypeFloat32<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_float_64(value in any::<odf::data::DataTypeFloat64>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeFloat64>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeFloat64, fb::DataTypeFloat64<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_int_16(value in any::<odf::data::DataTypeInt16>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeInt16>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeInt16, fb::DataTypeInt16<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_int_32(value in any::<odf::data::DataTypeInt32>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeInt32>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeInt32, fb::DataTypeInt32<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_int_64(value in any::<odf::data::DataTypeInt64>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeInt64>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeInt64, fb::DataTypeInt64<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_int_8(value in any::<odf::data::DataTypeInt8>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeInt8>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeInt8, fb::DataTypeInt8<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_list(value in any::<odf::data::DataTypeList>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeList>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeList, fb::DataTypeList<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_map(value in any::<odf::data::DataTypeMap>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeMap>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeMap, fb::DataTypeMap<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_null(value in any::<odf::data::DataTypeNull>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeNull>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeNull, fb::DataTypeNull<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_option(value in any::<odf::data::DataTypeOption>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeOption>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeOption, fb::DataTypeOption<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_string(value in any::<odf::data::DataTypeString>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeString>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeString, fb::DataTypeString<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_struct(value in any::<odf::data::DataTypeStruct>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeStruct>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeStruct, fb::DataTypeStruct<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_time(value in any::<odf::data::DataTypeTime>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeTime>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeTime, fb::DataTypeTime<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_timestamp(value in any::<odf::data::DataTypeTimestamp>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeTimestamp>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeTimestamp, fb::DataTypeTimestamp<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_u_int_16(value in any::<odf::data::DataTypeUInt16>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeUInt16>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeUInt16, fb::DataTypeUInt16<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_u_int_32(value in any::<odf::data::DataTypeUInt32>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeUInt32>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeUInt32, fb::DataTypeUInt32<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_u_int_64(value in any::<odf::data::DataTypeUInt64>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeUInt64>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeUInt64, fb::DataTypeUInt64<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_data_type_u_int_8(value in any::<odf::data::DataTypeUInt8>()) {
+    check_serde_roundtrip::<_, yaml::data::DataTypeUInt8>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::data::DataTypeUInt8, fb::DataTypeUInt8<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_error(value in any::<odf::protocol::DatasetError>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetError>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetError, fb::DatasetError<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_dataset_kind(value in any::<odf::dataset::DatasetKind>()) {
+    check_serde_roundtrip::<_, yaml::dataset::DatasetKind>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_metadata_pull_response(value in any::<odf::protocol::DatasetMetadataPullResponse>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetMetadataPullResponse>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetMetadataPullResponse, fb::DatasetMetadataPullResponse<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_pull_metadata_request(value in any::<odf::protocol::DatasetPullMetadataRequest>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPullMetadataRequest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPullMetadataRequest, fb::DatasetPullMetadataRequest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_pull_objects_transfer_request(value in any::<odf::protocol::DatasetPullObjectsTransferRequest>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPullObjectsTransferRequest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPullObjectsTransferRequest, fb::DatasetPullObjectsTransferRequest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_pull_objects_transfer_response(value in any::<odf::protocol::DatasetPullObjectsTransferResponse>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPullObjectsTransferResponse>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPullObjectsTransferResponse, fb::DatasetPullObjectsTransferResponse<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_pull_request(value in any::<odf::protocol::DatasetPullRequest>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPullRequest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPullRequest, fb::DatasetPullRequest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_pull_response(value in any::<odf::protocol::DatasetPullResponse>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPullResponse>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPullResponse, fb::DatasetPullResponse<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_complete(value in any::<odf::protocol::DatasetPushComplete>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushComplete>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushComplete, fb::DatasetPushComplete<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_complete_confirmed(value in any::<odf::protocol::DatasetPushCompleteConfirmed>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushCompleteConfirmed>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushCompleteConfirmed, fb::DatasetPushCompleteConfirmed<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_metadata(value in any::<odf::protocol::DatasetPushMetadata>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushMetadata>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushMetadata, fb::DatasetPushMetadata<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_metadata_accepted(value in any::<odf::protocol::DatasetPushMetadataAccepted>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushMetadataAccepted>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushMetadataAccepted, fb::DatasetPushMetadataAccepted<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_objects_transfer_request(value in any::<odf::protocol::DatasetPushObjectsTransferRequest>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushObjectsTransferRequest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushObjectsTransferRequest, fb::DatasetPushObjectsTransferRequest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_objects_transfer_response(value in any::<odf::protocol::DatasetPushObjectsTransferResponse>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushObjectsTransferResponse>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushObjectsTransferResponse, fb::DatasetPushObjectsTransferResponse<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_request(value in any::<odf::protocol::DatasetPushRequest>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushRequest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushRequest, fb::DatasetPushRequest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_dataset_push_request_accepted(value in any::<odf::protocol::DatasetPushRequestAccepted>()) {
+    check_serde_roundtrip::<_, yaml::protocol::DatasetPushRequestAccepted>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::DatasetPushRequestAccepted, fb::DatasetPushRequestAccepted<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_dataset_snapshot(value in any::<odf::legacy::DatasetSnapshot>()) {
+    check_serde_roundtrip::<_, yaml::legacy::DatasetSnapshot>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_dataset_spec(value in any::<odf::dataset::DatasetSpec>()) {
+    check_serde_roundtrip::<_, yaml::dataset::DatasetSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::DatasetSpec, fb::DatasetSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_dataset_spec_input(value in any::<odf::dataset::DatasetSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::dataset::DatasetSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::DatasetSpecInput, fb::DatasetSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_dataset_vocabulary(value in any::<odf::dataset::DatasetVocabulary>()) {
+    check_serde_roundtrip::<_, yaml::dataset::DatasetVocabulary>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::DatasetVocabulary, fb::DatasetVocabulary<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_disable_polling_source(value in any::<odf::legacy::DisablePollingSource>()) {
+    check_serde_roundtrip::<_, yaml::legacy::DisablePollingSource>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::DisablePollingSource, fb::DisablePollingSource<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_disable_push_source(value in any::<odf::legacy::DisablePushSource>()) {
+    check_serde_roundtrip::<_, yaml::legacy::DisablePushSource>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::DisablePushSource, fb::DisablePushSource<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_env_var(value in any::<odf::source::EnvVar>()) {
+    check_serde_roundtrip::<_, yaml::source::EnvVar>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::EnvVar, fb::EnvVar<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_error_details(value in any::<odf::protocol::ErrorDetails>()) {
+    check_serde_roundtrip::<_, yaml::protocol::ErrorDetails>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::ErrorDetails, fb::ErrorDetails<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_event_event_filter(value in any::<odf::event::EventFilter>()) {
+    check_serde_roundtrip::<_, yaml::event::EventFilter>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_event_event_filter_and(value in any::<odf::event::EventFilterAnd>()) {
+    check_serde_roundtrip::<_, yaml::event::EventFilterAnd>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::event::EventFilterAnd, fb::EventFilterAnd<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_event_event_filter_match(value in any::<odf::event::EventFilterMatch>()) {
+    check_serde_roundtrip::<_, yaml::event::EventFilterMatch>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_event_event_filter_not(value in any::<odf::event::EventFilterNot>()) {
+    check_serde_roundtrip::<_, yaml::event::EventFilterNot>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::event::EventFilterNot, fb::EventFilterNot<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_event_event_filter_or(value in any::<odf::event::EventFilterOr>()) {
+    check_serde_roundtrip::<_, yaml::event::EventFilterOr>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::event::EventFilterOr, fb::EventFilterOr<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_event_time_source(value in any::<odf::source::EventTimeSource>()) {
+    check_serde_roundtrip::<_, yaml::source::EventTimeSource>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_event_time_source_from_metadata(value in any::<odf::source::EventTimeSourceFromMetadata>()) {
+    check_serde_roundtrip::<_, yaml::source::EventTimeSourceFromMetadata>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::EventTimeSourceFromMetadata, fb::EventTimeSourceFromMetadata<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_event_time_source_from_path(value in any::<odf::source::EventTimeSourceFromPath>()) {
+    check_serde_roundtrip::<_, yaml::source::EventTimeSourceFromPath>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::EventTimeSourceFromPath, fb::EventTimeSourceFromPath<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_event_time_source_from_system_time(value in any::<odf::source::EventTimeSourceFromSystemTime>()) {
+    check_serde_roundtrip::<_, yaml::source::EventTimeSourceFromSystemTime>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::EventTimeSourceFromSystemTime, fb::EventTimeSourceFromSystemTime<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_execute_transform(value in any::<odf::dataset::ExecuteTransform>()) {
+    check_serde_roundtrip::<_, yaml::dataset::ExecuteTransform>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::ExecuteTransform, fb::ExecuteTransform<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_execute_transform_input(value in any::<odf::dataset::ExecuteTransformInput>()) {
+    check_serde_roundtrip::<_, yaml::dataset::ExecuteTransformInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::ExecuteTransformInput, fb::ExecuteTransformInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_extra_attributes(value in any::<odf::data::ExtraAttributes>()) {
+    check_serde_roundtrip::<_, yaml::data::ExtraAttributes>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_fetch_step(value in any::<odf::legacy::FetchStep>()) {
+    check_serde_roundtrip::<_, yaml::legacy::FetchStep>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_fetch_step_container(value in any::<odf::legacy::FetchStepContainer>()) {
+    check_serde_roundtrip::<_, yaml::legacy::FetchStepContainer>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::FetchStepContainer, fb::FetchStepContainer<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_fetch_step_ethereum_logs(value in any::<odf::legacy::FetchStepEthereumLogs>()) {
+    check_serde_roundtrip::<_, yaml::legacy::FetchStepEthereumLogs>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::FetchStepEthereumLogs, fb::FetchStepEthereumLogs<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_fetch_step_files_glob(value in any::<odf::legacy::FetchStepFilesGlob>()) {
+    check_serde_roundtrip::<_, yaml::legacy::FetchStepFilesGlob>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::FetchStepFilesGlob, fb::FetchStepFilesGlob<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_fetch_step_mqtt(value in any::<odf::legacy::FetchStepMqtt>()) {
+    check_serde_roundtrip::<_, yaml::legacy::FetchStepMqtt>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::FetchStepMqtt, fb::FetchStepMqtt<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_fetch_step_url(value in any::<odf::legacy::FetchStepUrl>()) {
+    check_serde_roundtrip::<_, yaml::legacy::FetchStepUrl>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::FetchStepUrl, fb::FetchStepUrl<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_filter_condition(value in any::<odf::resource::FilterCondition>()) {
+    check_serde_roundtrip::<_, yaml::resource::FilterCondition>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::FilterCondition, fb::FilterCondition<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_spec(value in any::<odf::flow::FlowSpec>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowSpec, fb::FlowSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_spec_input(value in any::<odf::flow::FlowSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowSpecInput, fb::FlowSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger(value in any::<odf::flow::FlowTrigger>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTrigger>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_dataset(value in any::<odf::flow::FlowTriggerDataset>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerDataset>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerDataset, fb::FlowTriggerDataset<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_event(value in any::<odf::flow::FlowTriggerEvent>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerEvent>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerEvent, fb::FlowTriggerEvent<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_schedule(value in any::<odf::flow::FlowTriggerSchedule>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerSchedule>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerSchedule, fb::FlowTriggerSchedule<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_source(value in any::<odf::flow::FlowTriggerSource>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerSource>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerSource, fb::FlowTriggerSource<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_input(value in any::<odf::flow::FlowTriggerInput>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerInput>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_input_dataset(value in any::<odf::flow::FlowTriggerInputDataset>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerInputDataset>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerInputDataset, fb::FlowTriggerInputDataset<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_input_event(value in any::<odf::flow::FlowTriggerInputEvent>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerInputEvent>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerInputEvent, fb::FlowTriggerInputEvent<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_input_schedule(value in any::<odf::flow::FlowTriggerInputSchedule>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerInputSchedule>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerInputSchedule, fb::FlowTriggerInputSchedule<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_flow_trigger_input_source(value in any::<odf::flow::FlowTriggerInputSource>()) {
+    check_serde_roundtrip::<_, yaml::flow::FlowTriggerInputSource>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::FlowTriggerInputSource, fb::FlowTriggerInputSource<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingest_params(value in any::<odf::source::IngestParams>()) {
+    check_serde_roundtrip::<_, yaml::source::IngestParams>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngestParams, fb::IngestParams<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress(value in any::<odf::source::Ingress>()) {
+    check_serde_roundtrip::<_, yaml::source::Ingress>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_container(value in any::<odf::source::IngressContainer>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressContainer>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngressContainer, fb::IngressContainer<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_evm_logs(value in any::<odf::source::IngressEvmLogs>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressEvmLogs>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngressEvmLogs, fb::IngressEvmLogs<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_files_glob(value in any::<odf::source::IngressFilesGlob>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressFilesGlob>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngressFilesGlob, fb::IngressFilesGlob<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_mqtt(value in any::<odf::source::IngressMqtt>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressMqtt>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngressMqtt, fb::IngressMqtt<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_rest_endpoint(value in any::<odf::source::IngressRestEndpoint>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressRestEndpoint>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngressRestEndpoint, fb::IngressRestEndpoint<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_url(value in any::<odf::source::IngressUrl>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressUrl>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngressUrl, fb::IngressUrl<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_buffer(value in any::<odf::source::IngressBuffer>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressBuffer>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_ingress_buffer_memory(value in any::<odf::source::IngressBufferMemory>()) {
+    check_serde_roundtrip::<_, yaml::source::IngressBufferMemory>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::IngressBufferMemory, fb::IngressBufferMemory<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_label_filter(value in any::<odf::resource::LabelFilter>()) {
+    check_serde_roundtrip::<_, yaml::resource::LabelFilter>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_resource_label_filter_and(value in any::<odf::resource::LabelFilterAnd>()) {
+    check_serde_roundtrip::<_, yaml::resource::LabelFilterAnd>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::LabelFilterAnd, fb::LabelFilterAnd<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_label_filter_match(value in any::<odf::resource::LabelFilterMatch>()) {
+    check_serde_roundtrip::<_, yaml::resource::LabelFilterMatch>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_resource_label_filter_not(value in any::<odf::resource::LabelFilterNot>()) {
+    check_serde_roundtrip::<_, yaml::resource::LabelFilterNot>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::LabelFilterNot, fb::LabelFilterNot<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_label_filter_or(value in any::<odf::resource::LabelFilterOr>()) {
+    check_serde_roundtrip::<_, yaml::resource::LabelFilterOr>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::LabelFilterOr, fb::LabelFilterOr<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_merge_strategy(value in any::<odf::source::MergeStrategy>()) {
+    check_serde_roundtrip::<_, yaml::source::MergeStrategy>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_merge_strategy_append(value in any::<odf::source::MergeStrategyAppend>()) {
+    check_serde_roundtrip::<_, yaml::source::MergeStrategyAppend>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::MergeStrategyAppend, fb::MergeStrategyAppend<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_merge_strategy_changelog_stream(value in any::<odf::source::MergeStrategyChangelogStream>()) {
+    check_serde_roundtrip::<_, yaml::source::MergeStrategyChangelogStream>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::MergeStrategyChangelogStream, fb::MergeStrategyChangelogStream<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_merge_strategy_ledger(value in any::<odf::source::MergeStrategyLedger>()) {
+    check_serde_roundtrip::<_, yaml::source::MergeStrategyLedger>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::MergeStrategyLedger, fb::MergeStrategyLedger<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_merge_strategy_snapshot(value in any::<odf::source::MergeStrategySnapshot>()) {
+    check_serde_roundtrip::<_, yaml::source::MergeStrategySnapshot>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::MergeStrategySnapshot, fb::MergeStrategySnapshot<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_merge_strategy_upsert_stream(value in any::<odf::source::MergeStrategyUpsertStream>()) {
+    check_serde_roundtrip::<_, yaml::source::MergeStrategyUpsertStream>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::MergeStrategyUpsertStream, fb::MergeStrategyUpsertStream<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_metadata_block(value in any::<odf::dataset::MetadataBlock>()) {
+    check_serde_roundtrip::<_, yaml::dataset::MetadataBlock>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::MetadataBlock, fb::MetadataBlock<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_metadata_event(value in any::<odf::dataset::MetadataEvent>()) {
+    check_serde_roundtrip::<_, yaml::dataset::MetadataEvent>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_mqtt_qos(value in any::<odf::source::MqttQos>()) {
+    check_serde_roundtrip::<_, yaml::source::MqttQos>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_mqtt_topic_subscription(value in any::<odf::source::MqttTopicSubscription>()) {
+    check_serde_roundtrip::<_, yaml::source::MqttTopicSubscription>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::MqttTopicSubscription, fb::MqttTopicSubscription<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_object_file_reference(value in any::<odf::protocol::ObjectFileReference>()) {
+    check_serde_roundtrip::<_, yaml::protocol::ObjectFileReference>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::ObjectFileReference, fb::ObjectFileReference<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_object_pull_strategy(value in any::<odf::protocol::ObjectPullStrategy>()) {
+    check_serde_roundtrip::<_, yaml::protocol::ObjectPullStrategy>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_object_push_strategy(value in any::<odf::protocol::ObjectPushStrategy>()) {
+    check_serde_roundtrip::<_, yaml::protocol::ObjectPushStrategy>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_object_type(value in any::<odf::protocol::ObjectType>()) {
+    check_serde_roundtrip::<_, yaml::protocol::ObjectType>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_objects_batch(value in any::<odf::protocol::ObjectsBatch>()) {
+    check_serde_roundtrip::<_, yaml::protocol::ObjectsBatch>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::ObjectsBatch, fb::ObjectsBatch<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_offset_interval(value in any::<odf::dataset::OffsetInterval>()) {
+    check_serde_roundtrip::<_, yaml::dataset::OffsetInterval>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::OffsetInterval, fb::OffsetInterval<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_storage_persistent_volume_ref(value in any::<odf::storage::PersistentVolumeRef>()) {
+    check_serde_roundtrip::<_, yaml::storage::PersistentVolumeRef>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::storage::PersistentVolumeRef, fb::PersistentVolumeRef<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_storage_persistent_volume_spec(value in any::<odf::storage::PersistentVolumeSpec>()) {
+    check_serde_roundtrip::<_, yaml::storage::PersistentVolumeSpec>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_storage_persistent_volume_spec_s_3(value in any::<odf::storage::PersistentVolumeSpecS3>()) {
+    check_serde_roundtrip::<_, yaml::storage::PersistentVolumeSpecS3>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::storage::PersistentVolumeSpecS3, fb::PersistentVolumeSpecS3<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_storage_persistent_volume_spec_input(value in any::<odf::storage::PersistentVolumeSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::storage::PersistentVolumeSpecInput>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_storage_persistent_volume_spec_input_s_3(value in any::<odf::storage::PersistentVolumeSpecInputS3>()) {
+    check_serde_roundtrip::<_, yaml::storage::PersistentVolumeSpecInputS3>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::storage::PersistentVolumeSpecInputS3, fb::PersistentVolumeSpecInputS3<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_prep_step(value in any::<odf::source::PrepStep>()) {
+    check_serde_roundtrip::<_, yaml::source::PrepStep>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_prep_step_decompress(value in any::<odf::source::PrepStepDecompress>()) {
+    check_serde_roundtrip::<_, yaml::source::PrepStepDecompress>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::PrepStepDecompress, fb::PrepStepDecompress<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_prep_step_pipe(value in any::<odf::source::PrepStepPipe>()) {
+    check_serde_roundtrip::<_, yaml::source::PrepStepPipe>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::PrepStepPipe, fb::PrepStepPipe<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_projection_spec(value in any::<odf::dataset::ProjectionSpec>()) {
+    check_serde_roundtrip::<_, yaml::dataset::ProjectionSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::ProjectionSpec, fb::ProjectionSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_projection_spec_input(value in any::<odf::dataset::ProjectionSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::dataset::ProjectionSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::ProjectionSpecInput, fb::ProjectionSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_pull_object_transfer_strategy(value in any::<odf::protocol::PullObjectTransferStrategy>()) {
+    check_serde_roundtrip::<_, yaml::protocol::PullObjectTransferStrategy>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::PullObjectTransferStrategy, fb::PullObjectTransferStrategy<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_push_object_transfer_strategy(value in any::<odf::protocol::PushObjectTransferStrategy>()) {
+    check_serde_roundtrip::<_, yaml::protocol::PushObjectTransferStrategy>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::PushObjectTransferStrategy, fb::PushObjectTransferStrategy<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_raw_query_request(value in any::<odf::engine::RawQueryRequest>()) {
+    check_serde_roundtrip::<_, yaml::engine::RawQueryRequest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::RawQueryRequest, fb::RawQueryRequest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_raw_query_response(value in any::<odf::engine::RawQueryResponse>()) {
+    check_serde_roundtrip::<_, yaml::engine::RawQueryResponse>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_engine_raw_query_response_internal_error(value in any::<odf::engine::RawQueryResponseInternalError>()) {
+    check_serde_roundtrip::<_, yaml::engine::RawQueryResponseInternalError>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::RawQueryResponseInternalError, fb::RawQueryResponseInternalError<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_raw_query_response_invalid_query(value in any::<odf::engine::RawQueryResponseInvalidQuery>()) {
+    check_serde_roundtrip::<_, yaml::engine::RawQueryResponseInvalidQuery>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::RawQueryResponseInvalidQuery, fb::RawQueryResponseInvalidQuery<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_raw_query_response_progress(value in any::<odf::engine::RawQueryResponseProgress>()) {
+    check_serde_roundtrip::<_, yaml::engine::RawQueryResponseProgress>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::RawQueryResponseProgress, fb::RawQueryResponseProgress<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_raw_query_response_success(value in any::<odf::engine::RawQueryResponseSuccess>()) {
+    check_serde_roundtrip::<_, yaml::engine::RawQueryResponseSuccess>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::RawQueryResponseSuccess, fb::RawQueryResponseSuccess<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step(value in any::<odf::source::ReadStep>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStep>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step_csv(value in any::<odf::source::ReadStepCsv>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStepCsv>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::ReadStepCsv, fb::ReadStepCsv<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step_esri_shapefile(value in any::<odf::source::ReadStepEsriShapefile>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStepEsriShapefile>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::ReadStepEsriShapefile, fb::ReadStepEsriShapefile<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step_geo_json(value in any::<odf::source::ReadStepGeoJson>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStepGeoJson>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::ReadStepGeoJson, fb::ReadStepGeoJson<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step_json(value in any::<odf::source::ReadStepJson>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStepJson>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::ReadStepJson, fb::ReadStepJson<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step_nd_geo_json(value in any::<odf::source::ReadStepNdGeoJson>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStepNdGeoJson>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::ReadStepNdGeoJson, fb::ReadStepNdGeoJson<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step_nd_json(value in any::<odf::source::ReadStepNdJson>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStepNdJson>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::ReadStepNdJson, fb::ReadStepNdJson<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_read_step_parquet(value in any::<odf::source::ReadStepParquet>()) {
+    check_serde_roundtrip::<_, yaml::source::ReadStepParquet>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::ReadStepParquet, fb::ReadStepParquet<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_relation(value in any::<odf::auth::Relation>()) {
+    check_serde_roundtrip::<_, yaml::auth::Relation>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::Relation, fb::Relation<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_relation_input(value in any::<odf::auth::RelationInput>()) {
+    check_serde_roundtrip::<_, yaml::auth::RelationInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::RelationInput, fb::RelationInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_relations_spec(value in any::<odf::auth::RelationsSpec>()) {
+    check_serde_roundtrip::<_, yaml::auth::RelationsSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::RelationsSpec, fb::RelationsSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_auth_relations_spec_input(value in any::<odf::auth::RelationsSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::auth::RelationsSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::auth::RelationsSpecInput, fb::RelationsSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_request_header(value in any::<odf::source::RequestHeader>()) {
+    check_serde_roundtrip::<_, yaml::source::RequestHeader>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::RequestHeader, fb::RequestHeader<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_annotations(value in any::<odf::resource::ResourceAnnotations>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceAnnotations>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_conditions(value in any::<odf::resource::ResourceConditions>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceConditions>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_handle(value in any::<odf::resource::ResourceHandle>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceHandle>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::ResourceHandle, fb::ResourceHandle<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_headers(value in any::<odf::resource::ResourceHeaders>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceHeaders>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::ResourceHeaders, fb::ResourceHeaders<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_headers_input(value in any::<odf::resource::ResourceHeadersInput>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceHeadersInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::ResourceHeadersInput, fb::ResourceHeadersInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_labels(value in any::<odf::resource::ResourceLabels>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceLabels>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_phase(value in any::<odf::resource::ResourcePhase>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourcePhase>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_ref(value in any::<odf::resource::ResourceRef>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceRef>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::ResourceRef, fb::ResourceRef<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_resource_resource_status(value in any::<odf::resource::ResourceStatus>()) {
+    check_serde_roundtrip::<_, yaml::resource::ResourceStatus>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::resource::ResourceStatus, fb::ResourceStatus<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_secret(value in any::<odf::config::Secret>()) {
+    check_serde_roundtrip::<_, yaml::config::Secret>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::Secret, fb::Secret<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_secret_set_spec(value in any::<odf::config::SecretSetSpec>()) {
+    check_serde_roundtrip::<_, yaml::config::SecretSetSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::SecretSetSpec, fb::SecretSetSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_secret_set_spec_input(value in any::<odf::config::SecretSetSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::config::SecretSetSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::SecretSetSpecInput, fb::SecretSetSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_secrets(value in any::<odf::config::Secrets>()) {
+    check_serde_roundtrip::<_, yaml::config::Secrets>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_seed(value in any::<odf::dataset::Seed>()) {
+    check_serde_roundtrip::<_, yaml::dataset::Seed>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::Seed, fb::Seed<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_set_attachments(value in any::<odf::dataset::SetAttachments>()) {
+    check_serde_roundtrip::<_, yaml::dataset::SetAttachments>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::SetAttachments, fb::SetAttachments<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_set_data_schema(value in any::<odf::dataset::SetDataSchema>()) {
+    check_serde_roundtrip::<_, yaml::dataset::SetDataSchema>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::SetDataSchema, fb::SetDataSchema<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_set_info(value in any::<odf::dataset::SetInfo>()) {
+    check_serde_roundtrip::<_, yaml::dataset::SetInfo>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::SetInfo, fb::SetInfo<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_set_license(value in any::<odf::dataset::SetLicense>()) {
+    check_serde_roundtrip::<_, yaml::dataset::SetLicense>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::SetLicense, fb::SetLicense<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_legacy_set_polling_source(value in any::<odf::legacy::SetPollingSource>()) {
+    check_serde_roundtrip::<_, yaml::legacy::SetPollingSource>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::legacy::SetPollingSource, fb::SetPollingSource<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_set_transform(value in any::<odf::dataset::SetTransform>()) {
+    check_serde_roundtrip::<_, yaml::dataset::SetTransform>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::SetTransform, fb::SetTransform<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_set_vocab(value in any::<odf::dataset::SetVocab>()) {
+    check_serde_roundtrip::<_, yaml::dataset::SetVocab>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::SetVocab, fb::SetVocab<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_source_caching(value in any::<odf::source::SourceCaching>()) {
+    check_serde_roundtrip::<_, yaml::source::SourceCaching>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_source_caching_forever(value in any::<odf::source::SourceCachingForever>()) {
+    check_serde_roundtrip::<_, yaml::source::SourceCachingForever>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::SourceCachingForever, fb::SourceCachingForever<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_source_ordering(value in any::<odf::source::SourceOrdering>()) {
+    check_serde_roundtrip::<_, yaml::source::SourceOrdering>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_source_source_spec(value in any::<odf::source::SourceSpec>()) {
+    check_serde_roundtrip::<_, yaml::source::SourceSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::SourceSpec, fb::SourceSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_source_spec_input(value in any::<odf::source::SourceSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::source::SourceSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::SourceSpecInput, fb::SourceSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_source_source_state(value in any::<odf::source::SourceState>()) {
+    check_serde_roundtrip::<_, yaml::source::SourceState>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::source::SourceState, fb::SourceState<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_sql_query_step(value in any::<odf::dataset::SqlQueryStep>()) {
+    check_serde_roundtrip::<_, yaml::dataset::SqlQueryStep>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::SqlQueryStep, fb::SqlQueryStep<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec(value in any::<odf::flow::TaskSpec>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpec>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_compaction(value in any::<odf::flow::TaskSpecCompaction>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecCompaction>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecCompaction, fb::TaskSpecCompaction<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_garbage_collection(value in any::<odf::flow::TaskSpecGarbageCollection>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecGarbageCollection>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecGarbageCollection, fb::TaskSpecGarbageCollection<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_ingest(value in any::<odf::flow::TaskSpecIngest>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecIngest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecIngest, fb::TaskSpecIngest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_webhook_call(value in any::<odf::flow::TaskSpecWebhookCall>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecWebhookCall>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecWebhookCall, fb::TaskSpecWebhookCall<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_input(value in any::<odf::flow::TaskSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecInput>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_input_compaction(value in any::<odf::flow::TaskSpecInputCompaction>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecInputCompaction>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecInputCompaction, fb::TaskSpecInputCompaction<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_input_garbage_collection(value in any::<odf::flow::TaskSpecInputGarbageCollection>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecInputGarbageCollection>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecInputGarbageCollection, fb::TaskSpecInputGarbageCollection<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_input_ingest(value in any::<odf::flow::TaskSpecInputIngest>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecInputIngest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecInputIngest, fb::TaskSpecInputIngest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_flow_task_spec_input_webhook_call(value in any::<odf::flow::TaskSpecInputWebhookCall>()) {
+    check_serde_roundtrip::<_, yaml::flow::TaskSpecInputWebhookCall>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::flow::TaskSpecInputWebhookCall, fb::TaskSpecInputWebhookCall<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_temporal_table(value in any::<odf::dataset::TemporalTable>()) {
+    check_serde_roundtrip::<_, yaml::dataset::TemporalTable>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::TemporalTable, fb::TemporalTable<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_data_time_unit(value in any::<odf::data::TimeUnit>()) {
+    check_serde_roundtrip::<_, yaml::data::TimeUnit>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_transfer_size_estimation(value in any::<odf::protocol::TransferSizeEstimation>()) {
+    check_serde_roundtrip::<_, yaml::protocol::TransferSizeEstimation>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::TransferSizeEstimation, fb::TransferSizeEstimation<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_protocol_transfer_url(value in any::<odf::protocol::TransferUrl>()) {
+    check_serde_roundtrip::<_, yaml::protocol::TransferUrl>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::protocol::TransferUrl, fb::TransferUrl<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_transform(value in any::<odf::dataset::Transform>()) {
+    check_serde_roundtrip::<_, yaml::dataset::Transform>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_transform_sql(value in any::<odf::dataset::TransformSql>()) {
+    check_serde_roundtrip::<_, yaml::dataset::TransformSql>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::TransformSql, fb::TransformSql<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_transform_input(value in any::<odf::dataset::TransformInput>()) {
+    check_serde_roundtrip::<_, yaml::dataset::TransformInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::TransformInput, fb::TransformInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_transform_request(value in any::<odf::engine::TransformRequest>()) {
+    check_serde_roundtrip::<_, yaml::engine::TransformRequest>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::TransformRequest, fb::TransformRequest<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_transform_request_input(value in any::<odf::engine::TransformRequestInput>()) {
+    check_serde_roundtrip::<_, yaml::engine::TransformRequestInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::TransformRequestInput, fb::TransformRequestInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_transform_response(value in any::<odf::engine::TransformResponse>()) {
+    check_serde_roundtrip::<_, yaml::engine::TransformResponse>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_engine_transform_response_internal_error(value in any::<odf::engine::TransformResponseInternalError>()) {
+    check_serde_roundtrip::<_, yaml::engine::TransformResponseInternalError>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::TransformResponseInternalError, fb::TransformResponseInternalError<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_transform_response_invalid_query(value in any::<odf::engine::TransformResponseInvalidQuery>()) {
+    check_serde_roundtrip::<_, yaml::engine::TransformResponseInvalidQuery>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::TransformResponseInvalidQuery, fb::TransformResponseInvalidQuery<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_transform_response_progress(value in any::<odf::engine::TransformResponseProgress>()) {
+    check_serde_roundtrip::<_, yaml::engine::TransformResponseProgress>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::TransformResponseProgress, fb::TransformResponseProgress<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_engine_transform_response_success(value in any::<odf::engine::TransformResponseSuccess>()) {
+    check_serde_roundtrip::<_, yaml::engine::TransformResponseSuccess>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::engine::TransformResponseSuccess, fb::TransformResponseSuccess<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_value_handle(value in any::<odf::config::ValueHandle>()) {
+    check_serde_roundtrip::<_, yaml::config::ValueHandle>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::ValueHandle, fb::ValueHandle<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_value_ref(value in any::<odf::config::ValueRef>()) {
+    check_serde_roundtrip::<_, yaml::config::ValueRef>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::ValueRef, fb::ValueRef<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_value_refs(value in any::<odf::config::ValueRefs>()) {
+    check_serde_roundtrip::<_, yaml::config::ValueRefs>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_config_variable(value in any::<odf::config::Variable>()) {
+    check_serde_roundtrip::<_, yaml::config::Variable>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::Variable, fb::Variable<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_variable_set_spec(value in any::<odf::config::VariableSetSpec>()) {
+    check_serde_roundtrip::<_, yaml::config::VariableSetSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::VariableSetSpec, fb::VariableSetSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_variable_set_spec_input(value in any::<odf::config::VariableSetSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::config::VariableSetSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::config::VariableSetSpecInput, fb::VariableSetSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_config_variables(value in any::<odf::config::Variables>()) {
+    check_serde_roundtrip::<_, yaml::config::Variables>(&value)?;
+    }
+
+    #[test]
+    fn test_roundtrip_storage_volume_capacity(value in any::<odf::storage::VolumeCapacity>()) {
+    check_serde_roundtrip::<_, yaml::storage::VolumeCapacity>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::storage::VolumeCapacity, fb::VolumeCapacity<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_dataset_watermark(value in any::<odf::dataset::Watermark>()) {
+    check_serde_roundtrip::<_, yaml::dataset::Watermark>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::dataset::Watermark, fb::Watermark<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_sink_webhook_target_spec(value in any::<odf::sink::WebhookTargetSpec>()) {
+    check_serde_roundtrip::<_, yaml::sink::WebhookTargetSpec>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::sink::WebhookTargetSpec, fb::WebhookTargetSpec<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_sink_webhook_target_spec_input(value in any::<odf::sink::WebhookTargetSpecInput>()) {
+    check_serde_roundtrip::<_, yaml::sink::WebhookTargetSpecInput>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::sink::WebhookTargetSpecInput, fb::WebhookTargetSpecInput<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_sink_webhook_target_status(value in any::<odf::sink::WebhookTargetStatus>()) {
+    check_serde_roundtrip::<_, yaml::sink::WebhookTargetStatus>(&value)?;
+    check_flatbuffers_roundtrip!(value, odf::sink::WebhookTargetStatus, fb::WebhookTargetStatus<'_>);
+    }
+
+    #[test]
+    fn test_roundtrip_sink_webhook_target_status_value(value in any::<odf::sink::WebhookTargetStatusValue>()) {
+    check_serde_roundtrip::<_, yaml::sink::WebhookTargetStatusValue>(&value)?;
+    }
+
+    }
+}
@@ -19,23 +19,299 @@ use setty::types::{ByteSize, DurationString};
 
 pub trait FlatbuffersSerializable<'fb> {
     type OffsetT;
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT;
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError>;
 }
 
 pub trait FlatbuffersDeserializable<T> {
-    fn deserialize(fb: T) -> Self;
+    fn deserialize(fb: T) -> Result<Self, FlatbuffersDecodeError>
+    where
+        Self: Sized;
 }
 
 pub trait FlatbuffersEnumSerializable<'fb, E> {
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> (E, WIPOffset<UnionWIPOffset>);
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<(E, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError>;
 }
 
 pub trait FlatbuffersEnumDeserializable<'fb, E> {
-    fn deserialize(table: Table<'fb>, t: E) -> Self
+    fn deserialize(table: Table<'fb>, t: E) -> Result<Self, FlatbuffersDecodeError>
     where
         Self: Sized;
 }
 
+/// Value identified by the hash of its canonical flatbuffers encoding
+pub trait FlatbuffersHashable {
+    /// Canonical flatbuffers encoding of the value
+    fn to_canonical_bytes(&self) -> Result<Vec<u8>, FlatbuffersEncodeError>;
+
+    /// Canonical encoding wrapped into a `Manifest` that the content hash is computed over
+    fn to_canonical_manifest(&self) -> Result<Vec<u8>, FlatbuffersEncodeError>;
+
+    /// SHA3-256 multihash of the canonical manifest
+    fn multihash(&self) -> Result<odf::Multihash, FlatbuffersEncodeError> {
+        Ok(odf::Multihash::from_digest_sha3_256(
+            &self.to_canonical_manifest()?,
+        ))
+    }
+}
+
+/// Returns a builder for the canonical encoding. Layout of the buffer is fully determined by the value when it is
+/// serialized via `FlatbuffersSerializable`:
+/// - All offsets of a table are serialized depth-first in order of field declaration before the table is started
+/// - Fields are added in order of declaration rather than by size
+/// - Scalars equal to their default value are omitted, unless the field is optional
+/// - Identical vtables are written once and shared by all tables that use them
+/// - Map entries and properties of JSON values are ordered by key
+/// - Structs are aligned to their largest field, as in proxies generated by `flatc` 24 or newer
+/// - Buffers have no file identifier and no size prefix
+pub fn canonical_builder<'fb>() -> FlatBufferBuilder<'fb> {
+    let mut fb = FlatBufferBuilder::with_capacity(0);
+    fb.force_defaults(false);
+    fb
+}
+
+/// Wraps the canonical encoding of a value into a `Manifest` of the specified kind and version
+pub fn canonical_manifest(kind: i64, version: i32, content: &[u8]) -> Vec<u8> {
+    let mut fb = canonical_builder();
+    let content_offset = fb.create_vector(content);
+    let mut builder = fb::ManifestBuilder::new(&mut fb);
+    builder.add_kind(kind);
+    builder.add_version(version);
+    builder.add_content(content_offset);
+    let offset = builder.finish();
+    fb.finish(offset, None);
+    fb.finished_data().to_vec()
+}
+
+/// Verifies the buffer within the specified limits and decodes its root table
+pub fn deserialize_verified<'fb, P, T>(
+    buf: &'fb [u8],
+    limits: &FlatbuffersDecodeLimits,
+) -> Result<T, FlatbuffersDecodeError>
+where
+    P: ::flatbuffers::Follow<'fb, Inner = P> + ::flatbuffers::Verifiable + 'fb,
+    T: FlatbuffersDeserializable<P>,
+{
+    T::deserialize(root_verified::<P>(buf, limits)?)
+}
+
+/// Verifies the buffer within the specified limits and returns its root proxy
+pub fn root_verified<'fb, P>(
+    buf: &'fb [u8],
+    limits: &FlatbuffersDecodeLimits,
+) -> Result<P, FlatbuffersDecodeError>
+where
+    P: ::flatbuffers::Follow<'fb, Inner = P> + ::flatbuffers::Verifiable + 'fb,
+{
+    if buf.len() > limits.max_buffer_size {
+        return Err(FlatbuffersDecodeError::new(
+            FlatbuffersDecodeErrorKind::BufferTooLarge {
+                size: buf.len(),
+                limit: limits.max_buffer_size,
+            },
+        ));
+    }
+
+    let opts = ::flatbuffers::VerifierOptions {
+        max_depth: limits.max_depth,
+        max_tables: limits.max_tables,
+        ..Default::default()
+    };
+
+    ::flatbuffers::root_with_opts::<P>(&opts, buf)
+        .map_err(|e| FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::Verification(e)))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Captures the table of an unknown union variant so that it can be written back as is.
+///
+/// A table only refers to values that were written before it, which lie after it in the buffer, while its vtable
+/// may be on either side. So the bytes from whichever comes first to the end of the buffer hold everything the table
+/// needs, along with values written before the table that it doesn't refer to, as types of its fields are unknown.
+/// They are padded at the front to keep alignment of values, which is relative to the end of the buffer.
+pub fn unknown_variant_from_table(
+    table: Table<'_>,
+    tag: u8,
+) -> Result<odf::dtos::UnknownVariant, FlatbuffersDecodeError> {
+    let buf = table.buf();
+    let loc = table.loc();
+
+    // Verifier doesn't look into tables of unknown types, so their location is not trusted
+    let vtable_loc = buf
+        .get(loc..)
+        .and_then(|b| b.get(..4))
+        .map(|b| loc as i64 - i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as i64)
+        .filter(|vtable_loc| *vtable_loc >= 0 && *vtable_loc as usize + 4 <= buf.len())
+        .ok_or_else(|| {
+            FlatbuffersDecodeError::invalid_value(format!(
+                "Table of unknown variant {tag} is out of bounds"
+            ))
+        })? as usize;
+
+    let start = loc.min(vtable_loc);
+    let padding = (8 - (buf.len() - start) % 8) % 8;
+    let mut bytes = vec![0; padding];
+    bytes.extend_from_slice(&buf[start..]);
+
+    Ok(odf::dtos::UnknownVariant::Flatbuffers {
+        tag,
+        bytes,
+        loc: padding + loc - start,
+    })
+}
+
+/// Writes back the bytes captured by [`unknown_variant_from_table`] and returns the offset of the table
+pub fn push_unknown_table<'fb>(
+    fb: &mut FlatBufferBuilder<'fb>,
+    bytes: &[u8],
+    loc: usize,
+) -> Result<WIPOffset<UnionWIPOffset>, FlatbuffersEncodeError> {
+    if bytes.len() % 8 != 0 || loc >= bytes.len() {
+        return Err(FlatbuffersEncodeError::invalid_value(format!(
+            "Table at {loc} is outside of {} captured bytes",
+            bytes.len()
+        )));
+    }
+
+    // Pushing whole words back to front places the bytes at the same alignment they had in the original buffer
+    let mut start = 0;
+    for word in bytes.chunks_exact(8).rev() {
+        start = fb
+            .push(u64::from_le_bytes(std::array::from_fn(|i| word[i])))
+            .value();
+    }
+    Ok(WIPOffset::new(start - loc as u32))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Limits enforced by the verifier when decoding untrusted buffers
+#[derive(Debug, Clone)]
+pub struct FlatbuffersDecodeLimits {
+    /// Maximum nesting depth of tables and vectors
+    pub max_depth: usize,
+    /// Maximum number of tables in the buffer
+    pub max_tables: usize,
+    /// Maximum size of the buffer in bytes
+    pub max_buffer_size: usize,
+}
+
+impl Default for FlatbuffersDecodeLimits {
+    fn default() -> Self {
+        Self {
+            max_depth: 64,
+            max_tables: 1_000_000,
+            max_buffer_size: 1 << 31,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug)]
+pub struct FlatbuffersDecodeError {
+    /// Path to the offending value, outermost first, e.g. `["MetadataBlock.event", "AddData.newData"]`
+    pub path: Vec<&'static str>,
+    pub kind: FlatbuffersDecodeErrorKind,
+}
+
+#[derive(Debug)]
+pub enum FlatbuffersDecodeErrorKind {
+    BufferTooLarge { size: usize, limit: usize },
+    Verification(::flatbuffers::InvalidFlatbuffer),
+    MissingField,
+    UnknownUnionVariant { typ: &'static str, value: u8 },
+    UnknownEnumValue { typ: &'static str, value: i64 },
+    InvalidValue(String),
+}
+
+impl FlatbuffersDecodeError {
+    pub fn new(kind: FlatbuffersDecodeErrorKind) -> Self {
+        Self {
+            path: Vec::new(),
+            kind,
+        }
+    }
+
+    pub fn missing_field() -> Self {
+        Self::new(FlatbuffersDecodeErrorKind::MissingField)
+    }
+
+    pub fn invalid_value<E: std::fmt::Display>(e: E) -> Self {
+        Self::new(FlatbuffersDecodeErrorKind::InvalidValue(e.to_string()))
+    }
+
+    /// Prepends a `Type.field` segment as the error propagates outwards
+    pub fn at(mut self, segment: &'static str) -> Self {
+        self.path.insert(0, segment);
+        self
+    }
+}
+
+impl std::fmt::Display for FlatbuffersDecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "{}: ", self.path.join(" > "))?;
+        }
+        match &self.kind {
+            FlatbuffersDecodeErrorKind::BufferTooLarge { size, limit } => {
+                write!(
+                    f,
+                    "Buffer of {size} bytes exceeds the limit of {limit} bytes"
+                )
+            }
+            FlatbuffersDecodeErrorKind::Verification(e) => write!(f, "Invalid flatbuffer: {e}"),
+            FlatbuffersDecodeErrorKind::MissingField => write!(f, "Missing required field"),
+            FlatbuffersDecodeErrorKind::UnknownUnionVariant { typ, value } => {
+                write!(f, "Unknown variant {value} of union {typ}")
+            }
+            FlatbuffersDecodeErrorKind::UnknownEnumValue { typ, value } => {
+                write!(f, "Unknown value {value} of enum {typ}")
+            }
+            FlatbuffersDecodeErrorKind::InvalidValue(e) => write!(f, "Invalid value: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for FlatbuffersDecodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match &self.kind {
+            FlatbuffersDecodeErrorKind::Verification(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Value that has no flatbuffers representation, e.g. an unknown variant that was read from a manifest
+#[derive(Debug)]
+pub struct FlatbuffersEncodeError {
+    pub message: String,
+}
+
+impl FlatbuffersEncodeError {
+    pub fn invalid_value<E: std::fmt::Display>(e: E) -> Self {
+        Self {
+            message: e.to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for FlatbuffersEncodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Value can't be encoded as flatbuffers: {}", self.message)
+    }
+}
+
+impl std::error::Error for FlatbuffersEncodeError {}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // AccountHandle
 // Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountHandle
@@ -44,7 +320,10 @@ pub trait FlatbuffersEnumDeserializable<'fb, E> {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::AccountHandle {
     type OffsetT = WIPOffset<fb::AccountHandle<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let id_offset = { fb.create_vector(&self.id.as_bytes()) };
         let did_offset = { fb.create_vector(&self.did.as_bytes()) };
         let name_offset = { fb.create_string(&self.name.to_string()) };
@@ -52,26 +331,38 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::AccountHandle {
         builder.add_id(id_offset);
         builder.add_did(did_offset);
         builder.add_name(name_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AccountHandle<'fb>> for odf::auth::AccountHandle {
-    fn deserialize(proxy: fb::AccountHandle<'fb>) -> Self {
-        odf::auth::AccountHandle {
+    fn deserialize(proxy: fb::AccountHandle<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::AccountHandle {
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountHandle.id"))?,
             did: proxy
                 .did()
-                .map(|v| odf::auth::AccountID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::auth::AccountID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountHandle.did"))?,
             name: proxy
                 .name()
-                .map(|v| odf::auth::AccountName::try_from(v).unwrap())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::auth::AccountName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountHandle.name"))?,
+        })
     }
 }
 
@@ -83,31 +374,58 @@ impl<'fb> FlatbuffersDeserializable<fb::AccountHandle<'fb>> for odf::auth::Accou
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::AccountRef {
     type OffsetT = WIPOffset<fb::AccountRef<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let id_offset = self.id.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let did_offset = self.did.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let name_offset = self.name.as_ref().map(|v| fb.create_string(&v.to_string()));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let id_offset = match &self.id {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let did_offset = match &self.did {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let name_offset = match &self.name {
+            Some(v) => Some({ fb.create_string(&v.to_string()) }),
+            None => None,
+        };
         let mut builder = fb::AccountRefBuilder::new(fb);
         id_offset.map(|off| builder.add_id(off));
         did_offset.map(|off| builder.add_did(off));
         name_offset.map(|off| builder.add_name(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AccountRef<'fb>> for odf::auth::AccountRef {
-    fn deserialize(proxy: fb::AccountRef<'fb>) -> Self {
-        odf::auth::AccountRef {
+    fn deserialize(proxy: fb::AccountRef<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::AccountRef {
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountRef.id"))?,
             did: proxy
                 .did()
-                .map(|v| odf::auth::AccountID::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::auth::AccountID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountRef.did"))?,
             name: proxy
                 .name()
-                .map(|v| odf::auth::AccountName::try_from(v).unwrap()),
-        }
+                .map(|v| {
+                    odf::auth::AccountName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountRef.name"))?,
+        })
     }
 }
 
@@ -119,38 +437,77 @@ impl<'fb> FlatbuffersDeserializable<fb::AccountRef<'fb>> for odf::auth::AccountR
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::AccountSpec {
     type OffsetT = WIPOffset<fb::AccountSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let did_offset = self.did.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let display_name_offset = self.display_name.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let did_offset = match &self.did {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let display_name_offset = match &self.display_name {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let email_offset = { fb.create_string(&self.email) };
-        let avatar_url_offset = self.avatar_url.as_ref().map(|v| fb.create_string(&v));
-        let password_offset = self.password.as_ref().map(|v| v.serialize(fb));
+        let avatar_url_offset = match &self.avatar_url {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let password_offset = match &self.password {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::AccountSpecBuilder::new(fb);
         did_offset.map(|off| builder.add_did(off));
-        self.account_type
-            .map(|v| builder.add_account_type(v.into()));
+        if let Some(v) = &self.account_type {
+            builder.add_account_type(fb::AccountType::try_from(v)?);
+        }
         display_name_offset.map(|off| builder.add_display_name(off));
         builder.add_email(email_offset);
         avatar_url_offset.map(|off| builder.add_avatar_url(off));
         password_offset.map(|off| builder.add_password(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AccountSpec<'fb>> for odf::auth::AccountSpec {
-    fn deserialize(proxy: fb::AccountSpec<'fb>) -> Self {
-        odf::auth::AccountSpec {
+    fn deserialize(proxy: fb::AccountSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::AccountSpec {
             did: proxy
                 .did()
-                .map(|v| odf::auth::AccountID::from_bytes(v.bytes()).unwrap()),
-            account_type: proxy.account_type().map(|v| v.into()),
-            display_name: proxy.display_name().map(|v| v.to_owned()),
-            email: proxy.email().map(|v| v.to_owned()).unwrap(),
-            avatar_url: proxy.avatar_url().map(|v| v.to_owned()),
+                .map(|v| {
+                    odf::auth::AccountID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.did"))?,
+            account_type: proxy
+                .account_type()
+                .map(|v| odf::auth::AccountType::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.accountType"))?,
+            display_name: proxy
+                .display_name()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.displayName"))?,
+            email: proxy
+                .email()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.email"))?,
+            avatar_url: proxy
+                .avatar_url()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.avatarUrl"))?,
             password: proxy
                 .password()
-                .map(|v| odf::config::Secret::deserialize(v)),
-        }
+                .map(|v| odf::config::Secret::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpec.password"))?,
+        })
     }
 }
 
@@ -162,38 +519,77 @@ impl<'fb> FlatbuffersDeserializable<fb::AccountSpec<'fb>> for odf::auth::Account
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::AccountSpecInput {
     type OffsetT = WIPOffset<fb::AccountSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let did_offset = self.did.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let display_name_offset = self.display_name.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let did_offset = match &self.did {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let display_name_offset = match &self.display_name {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let email_offset = { fb.create_string(&self.email) };
-        let avatar_url_offset = self.avatar_url.as_ref().map(|v| fb.create_string(&v));
-        let password_offset = self.password.as_ref().map(|v| v.serialize(fb));
+        let avatar_url_offset = match &self.avatar_url {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let password_offset = match &self.password {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::AccountSpecInputBuilder::new(fb);
         did_offset.map(|off| builder.add_did(off));
-        self.account_type
-            .map(|v| builder.add_account_type(v.into()));
+        if let Some(v) = &self.account_type {
+            builder.add_account_type(fb::AccountType::try_from(v)?);
+        }
         display_name_offset.map(|off| builder.add_display_name(off));
         builder.add_email(email_offset);
         avatar_url_offset.map(|off| builder.add_avatar_url(off));
         password_offset.map(|off| builder.add_password(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AccountSpecInput<'fb>> for odf::auth::AccountSpecInput {
-    fn deserialize(proxy: fb::AccountSpecInput<'fb>) -> Self {
-        odf::auth::AccountSpecInput {
+    fn deserialize(proxy: fb::AccountSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::AccountSpecInput {
             did: proxy
                 .did()
-                .map(|v| odf::auth::AccountID::from_bytes(v.bytes()).unwrap()),
-            account_type: proxy.account_type().map(|v| v.into()),
-            display_name: proxy.display_name().map(|v| v.to_owned()),
-            email: proxy.email().map(|v| v.to_owned()).unwrap(),
-            avatar_url: proxy.avatar_url().map(|v| v.to_owned()),
+                .map(|v| {
+                    odf::auth::AccountID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.did"))?,
+            account_type: proxy
+                .account_type()
+                .map(|v| odf::auth::AccountType::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.accountType"))?,
+            display_name: proxy
+                .display_name()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.displayName"))?,
+            email: proxy
+                .email()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.email"))?,
+            avatar_url: proxy
+                .avatar_url()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.avatarUrl"))?,
             password: proxy
                 .password()
-                .map(|v| odf::config::Secret::deserialize(v)),
-        }
+                .map(|v| odf::config::Secret::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AccountSpecInput.password"))?,
+        })
     }
 }
 
@@ -202,21 +598,30 @@ impl<'fb> FlatbuffersDeserializable<fb::AccountSpecInput<'fb>> for odf::auth::Ac
 // Schema: https://opendatafabric.org/schemas/auth/v1alpha1/AccountType
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::auth::AccountType> for fb::AccountType {
-    fn from(v: odf::auth::AccountType) -> Self {
+impl TryFrom<&odf::auth::AccountType> for fb::AccountType {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::auth::AccountType) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::auth::AccountType::User => fb::AccountType::User,
-            odf::auth::AccountType::Organization => fb::AccountType::Organization,
+            odf::auth::AccountType::User => Ok(fb::AccountType::User),
+            odf::auth::AccountType::Organization => Ok(fb::AccountType::Organization),
         }
     }
 }
 
-impl Into<odf::auth::AccountType> for fb::AccountType {
-    fn into(self) -> odf::auth::AccountType {
-        match self {
-            fb::AccountType::User => odf::auth::AccountType::User,
-            fb::AccountType::Organization => odf::auth::AccountType::Organization,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::AccountType> for odf::auth::AccountType {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::AccountType) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::AccountType::User => Ok(odf::auth::AccountType::User),
+            fb::AccountType::Organization => Ok(odf::auth::AccountType::Organization),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "AccountType",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
@@ -229,15 +634,30 @@ impl Into<odf::auth::AccountType> for fb::AccountType {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::AddData {
     type OffsetT = WIPOffset<fb::AddData<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let prev_checkpoint_offset = self
-            .prev_checkpoint
-            .as_ref()
-            .map(|v| fb.create_vector(&v.as_bytes().as_slice()));
-        let new_data_offset = self.new_data.as_ref().map(|v| v.serialize(fb));
-        let new_checkpoint_offset = self.new_checkpoint.as_ref().map(|v| v.serialize(fb));
-        let new_source_state_offset = self.new_source_state.as_ref().map(|v| v.serialize(fb));
-        let extra_offset = self.extra.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let prev_checkpoint_offset = match &self.prev_checkpoint {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes().as_slice()) }),
+            None => None,
+        };
+        let new_data_offset = match &self.new_data {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let new_checkpoint_offset = match &self.new_checkpoint {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let new_source_state_offset = match &self.new_source_state {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let extra_offset = match &self.extra {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::AddDataBuilder::new(fb);
         prev_checkpoint_offset.map(|off| builder.add_prev_checkpoint(off));
         self.prev_offset.map(|v| builder.add_prev_offset(v));
@@ -247,31 +667,52 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::AddData {
             .map(|v| builder.add_new_watermark(&datetime_to_fb(&v)));
         new_source_state_offset.map(|off| builder.add_new_source_state(off));
         extra_offset.map(|off| builder.add_extra(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AddData<'fb>> for odf::dataset::AddData {
-    fn deserialize(proxy: fb::AddData<'fb>) -> Self {
-        odf::dataset::AddData {
+    fn deserialize(proxy: fb::AddData<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::AddData {
             prev_checkpoint: proxy
                 .prev_checkpoint()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap()),
-            prev_offset: proxy.prev_offset().map(|v| v),
+                .map(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddData.prevCheckpoint"))?,
+            prev_offset: proxy
+                .prev_offset()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddData.prevOffset"))?,
             new_data: proxy
                 .new_data()
-                .map(|v| odf::dataset::DataSlice::deserialize(v)),
+                .map(|v| odf::dataset::DataSlice::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newData"))?,
             new_checkpoint: proxy
                 .new_checkpoint()
-                .map(|v| odf::dataset::Checkpoint::deserialize(v)),
-            new_watermark: proxy.new_watermark().map(|v| fb_to_datetime(v)),
+                .map(|v| odf::dataset::Checkpoint::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newCheckpoint"))?,
+            new_watermark: proxy
+                .new_watermark()
+                .map(|v| fb_to_datetime(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newWatermark"))?,
             new_source_state: proxy
                 .new_source_state()
-                .map(|v| odf::source::SourceState::deserialize(v)),
+                .map(|v| odf::source::SourceState::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddData.newSourceState"))?,
             extra: proxy
                 .extra()
-                .map(|v| odf::data::ExtraAttributes::deserialize(v)),
-        }
+                .map(|v| odf::data::ExtraAttributes::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddData.extra"))?,
+        })
     }
 }
 
@@ -283,11 +724,17 @@ impl<'fb> FlatbuffersDeserializable<fb::AddData<'fb>> for odf::dataset::AddData
 impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::AddPushSource {
     type OffsetT = WIPOffset<fb::AddPushSource<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let source_name_offset = { fb.create_string(&self.source_name) };
-        let read_offset = { self.read.serialize(fb) };
-        let preprocess_offset = self.preprocess.as_ref().map(|v| v.serialize(fb));
-        let merge_offset = { self.merge.serialize(fb) };
+        let read_offset = { self.read.serialize(fb)? };
+        let preprocess_offset = match &self.preprocess {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let merge_offset = { self.merge.serialize(fb)? };
         let mut builder = fb::AddPushSourceBuilder::new(fb);
         builder.add_source_name(source_name_offset);
         builder.add_read_type(read_offset.0);
@@ -298,26 +745,34 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::AddPushSource {
         });
         builder.add_merge_type(merge_offset.0);
         builder.add_merge(merge_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AddPushSource<'fb>> for odf::legacy::AddPushSource {
-    fn deserialize(proxy: fb::AddPushSource<'fb>) -> Self {
-        odf::legacy::AddPushSource {
-            source_name: proxy.source_name().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::AddPushSource<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::AddPushSource {
+            source_name: proxy
+                .source_name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.sourceName"))?,
             read: proxy
                 .read()
-                .map(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.read"))?,
             preprocess: proxy
                 .preprocess()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type())),
+                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.preprocess"))?,
             merge: proxy
                 .merge()
-                .map(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type()))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AddPushSource.merge"))?,
+        })
     }
 }
 
@@ -329,24 +784,35 @@ impl<'fb> FlatbuffersDeserializable<fb::AddPushSource<'fb>> for odf::legacy::Add
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::AttachmentEmbedded {
     type OffsetT = WIPOffset<fb::AttachmentEmbedded<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let path_offset = { fb.create_string(&self.path) };
         let content_offset = { fb.create_string(&self.content) };
         let mut builder = fb::AttachmentEmbeddedBuilder::new(fb);
         builder.add_path(path_offset);
         builder.add_content(content_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AttachmentEmbedded<'fb>>
     for odf::dataset::AttachmentEmbedded
 {
-    fn deserialize(proxy: fb::AttachmentEmbedded<'fb>) -> Self {
-        odf::dataset::AttachmentEmbedded {
-            path: proxy.path().map(|v| v.to_owned()).unwrap(),
-            content: proxy.content().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(proxy: fb::AttachmentEmbedded<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::AttachmentEmbedded {
+            path: proxy
+                .path()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AttachmentEmbedded.path"))?,
+            content: proxy
+                .content()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AttachmentEmbedded.content"))?,
+        })
     }
 }
 
@@ -359,25 +825,34 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::Attachments> for odf::dataset::At
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::Attachments, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::Attachments, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::dataset::Attachments::Embedded(v) => (
+            odf::dataset::Attachments::Embedded(v) => Ok((
                 fb::Attachments::AttachmentsEmbedded,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::Attachments> for odf::dataset::Attachments {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::Attachments) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::Attachments,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::Attachments::AttachmentsEmbedded => {
-                odf::dataset::Attachments::Embedded(odf::dataset::AttachmentsEmbedded::deserialize(
-                    unsafe { fb::AttachmentsEmbedded::init_from_table(table) },
-                ))
+                odf::dataset::AttachmentsEmbedded::deserialize(unsafe {
+                    fb::AttachmentsEmbedded::init_from_table(table)
+                })
+                .map(odf::dataset::Attachments::Embedded)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "Attachments",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -390,31 +865,39 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::Attachments> for odf::dataset::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::AttachmentsEmbedded {
     type OffsetT = WIPOffset<fb::AttachmentsEmbedded<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let items_offset = {
-            let offsets: Vec<_> = self.items.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .items
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::AttachmentsEmbeddedBuilder::new(fb);
         builder.add_items(items_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AttachmentsEmbedded<'fb>>
     for odf::dataset::AttachmentsEmbedded
 {
-    fn deserialize(proxy: fb::AttachmentsEmbedded<'fb>) -> Self {
-        odf::dataset::AttachmentsEmbedded {
+    fn deserialize(proxy: fb::AttachmentsEmbedded<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::AttachmentsEmbedded {
             items: proxy
                 .items()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::dataset::AttachmentEmbedded::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| e.at("AttachmentsEmbedded.items"))?,
+        })
     }
 }
 
@@ -426,31 +909,47 @@ impl<'fb> FlatbuffersDeserializable<fb::AttachmentsEmbedded<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::Attribute {
     type OffsetT = WIPOffset<fb::Attribute<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let object_offset = { self.object.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_offset = { self.object.serialize(fb)? };
         let name_offset = { fb.create_string(&self.name) };
-        let value_offset = { fb.create_string(&serde_json::to_string(&self.value).unwrap()) };
+        let value_offset = {
+            fb.create_string(
+                &serde_json::to_string(&self.value)
+                    .map_err(FlatbuffersEncodeError::invalid_value)?,
+            )
+        };
         let mut builder = fb::AttributeBuilder::new(fb);
         builder.add_object(object_offset);
         builder.add_name(name_offset);
         builder.add_value(value_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Attribute<'fb>> for odf::auth::Attribute {
-    fn deserialize(proxy: fb::Attribute<'fb>) -> Self {
-        odf::auth::Attribute {
+    fn deserialize(proxy: fb::Attribute<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::Attribute {
             object: proxy
                 .object()
-                .map(|v| odf::resource::ResourceHandle::deserialize(v))
-                .unwrap(),
-            name: proxy.name().map(|v| v.to_owned()).unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Attribute.object"))?,
+            name: proxy
+                .name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Attribute.name"))?,
             value: proxy
                 .value()
-                .map(|v| serde_json::from_str(v).unwrap())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("Attribute.value"))?,
+        })
     }
 }
 
@@ -462,31 +961,47 @@ impl<'fb> FlatbuffersDeserializable<fb::Attribute<'fb>> for odf::auth::Attribute
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::AttributeInput {
     type OffsetT = WIPOffset<fb::AttributeInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let object_offset = { self.object.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_offset = { self.object.serialize(fb)? };
         let name_offset = { fb.create_string(&self.name) };
-        let value_offset = { fb.create_string(&serde_json::to_string(&self.value).unwrap()) };
+        let value_offset = {
+            fb.create_string(
+                &serde_json::to_string(&self.value)
+                    .map_err(FlatbuffersEncodeError::invalid_value)?,
+            )
+        };
         let mut builder = fb::AttributeInputBuilder::new(fb);
         builder.add_object(object_offset);
         builder.add_name(name_offset);
         builder.add_value(value_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AttributeInput<'fb>> for odf::auth::AttributeInput {
-    fn deserialize(proxy: fb::AttributeInput<'fb>) -> Self {
-        odf::auth::AttributeInput {
+    fn deserialize(proxy: fb::AttributeInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::AttributeInput {
             object: proxy
                 .object()
-                .map(|v| odf::resource::ResourceRef::deserialize(v))
-                .unwrap(),
-            name: proxy.name().map(|v| v.to_owned()).unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceRef::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AttributeInput.object"))?,
+            name: proxy
+                .name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("AttributeInput.name"))?,
             value: proxy
                 .value()
-                .map(|v| serde_json::from_str(v).unwrap())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("AttributeInput.value"))?,
+        })
     }
 }
 
@@ -498,26 +1013,39 @@ impl<'fb> FlatbuffersDeserializable<fb::AttributeInput<'fb>> for odf::auth::Attr
 impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::AwsCredentials {
     type OffsetT = WIPOffset<fb::AwsCredentials<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let access_key_offset = self.access_key.as_ref().map(|v| v.serialize(fb));
-        let secret_key_offset = self.secret_key.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let access_key_offset = match &self.access_key {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let secret_key_offset = match &self.secret_key {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::AwsCredentialsBuilder::new(fb);
         access_key_offset.map(|off| builder.add_access_key(off));
         secret_key_offset.map(|off| builder.add_secret_key(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AwsCredentials<'fb>> for odf::storage::AwsCredentials {
-    fn deserialize(proxy: fb::AwsCredentials<'fb>) -> Self {
-        odf::storage::AwsCredentials {
+    fn deserialize(proxy: fb::AwsCredentials<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::storage::AwsCredentials {
             access_key: proxy
                 .access_key()
-                .map(|v| odf::config::ValueHandle::deserialize(v)),
+                .map(|v| odf::config::ValueHandle::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentials.accessKey"))?,
             secret_key: proxy
                 .secret_key()
-                .map(|v| odf::config::ValueHandle::deserialize(v)),
-        }
+                .map(|v| odf::config::ValueHandle::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentials.secretKey"))?,
+        })
     }
 }
 
@@ -529,28 +1057,41 @@ impl<'fb> FlatbuffersDeserializable<fb::AwsCredentials<'fb>> for odf::storage::A
 impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::AwsCredentialsInput {
     type OffsetT = WIPOffset<fb::AwsCredentialsInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let access_key_offset = self.access_key.as_ref().map(|v| v.serialize(fb));
-        let secret_key_offset = self.secret_key.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let access_key_offset = match &self.access_key {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let secret_key_offset = match &self.secret_key {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::AwsCredentialsInputBuilder::new(fb);
         access_key_offset.map(|off| builder.add_access_key(off));
         secret_key_offset.map(|off| builder.add_secret_key(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::AwsCredentialsInput<'fb>>
     for odf::storage::AwsCredentialsInput
 {
-    fn deserialize(proxy: fb::AwsCredentialsInput<'fb>) -> Self {
-        odf::storage::AwsCredentialsInput {
+    fn deserialize(proxy: fb::AwsCredentialsInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::storage::AwsCredentialsInput {
             access_key: proxy
                 .access_key()
-                .map(|v| odf::config::ValueRef::deserialize(v)),
+                .map(|v| odf::config::ValueRef::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentialsInput.accessKey"))?,
             secret_key: proxy
                 .secret_key()
-                .map(|v| odf::config::ValueRef::deserialize(v)),
-        }
+                .map(|v| odf::config::ValueRef::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("AwsCredentialsInput.secretKey"))?,
+        })
     }
 }
 
@@ -562,24 +1103,31 @@ impl<'fb> FlatbuffersDeserializable<fb::AwsCredentialsInput<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::Checkpoint {
     type OffsetT = WIPOffset<fb::Checkpoint<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let physical_hash_offset = { fb.create_vector(&self.physical_hash.as_bytes().as_slice()) };
         let mut builder = fb::CheckpointBuilder::new(fb);
         builder.add_physical_hash(physical_hash_offset);
-        builder.add_size(self.size);
-        builder.finish()
+        builder.add_size_(self.size);
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Checkpoint<'fb>> for odf::dataset::Checkpoint {
-    fn deserialize(proxy: fb::Checkpoint<'fb>) -> Self {
-        odf::dataset::Checkpoint {
+    fn deserialize(proxy: fb::Checkpoint<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::Checkpoint {
             physical_hash: proxy
                 .physical_hash()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
-            size: proxy.size(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("Checkpoint.physicalHash"))?,
+            size: Ok(proxy.size_()).map_err(|e: FlatbuffersDecodeError| e.at("Checkpoint.size"))?,
+        })
     }
 }
 
@@ -591,22 +1139,33 @@ impl<'fb> FlatbuffersDeserializable<fb::Checkpoint<'fb>> for odf::dataset::Check
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::CompactionParams {
     type OffsetT = WIPOffset<fb::CompactionParams<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::CompactionParamsBuilder::new(fb);
         self.max_slice_size
             .map(|v| builder.add_max_slice_size(v.as_u64()));
         self.max_slice_records
             .map(|v| builder.add_max_slice_records(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::CompactionParams<'fb>> for odf::dataset::CompactionParams {
-    fn deserialize(proxy: fb::CompactionParams<'fb>) -> Self {
-        odf::dataset::CompactionParams {
-            max_slice_size: proxy.max_slice_size().map(|v| ByteSize::from(v)),
-            max_slice_records: proxy.max_slice_records().map(|v| v),
-        }
+    fn deserialize(proxy: fb::CompactionParams<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::CompactionParams {
+            max_slice_size: proxy
+                .max_slice_size()
+                .map(|v| Ok(ByteSize::from(v)))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("CompactionParams.maxSliceSize"))?,
+            max_slice_records: proxy
+                .max_slice_records()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("CompactionParams.maxSliceRecords"))?,
+        })
     }
 }
 
@@ -615,21 +1174,30 @@ impl<'fb> FlatbuffersDeserializable<fb::CompactionParams<'fb>> for odf::dataset:
 // Schema: https://opendatafabric.org/schemas/source/v1alpha1/CompressionFormat
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::source::CompressionFormat> for fb::CompressionFormat {
-    fn from(v: odf::source::CompressionFormat) -> Self {
+impl TryFrom<&odf::source::CompressionFormat> for fb::CompressionFormat {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::source::CompressionFormat) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::source::CompressionFormat::Gzip => fb::CompressionFormat::Gzip,
-            odf::source::CompressionFormat::Zip => fb::CompressionFormat::Zip,
+            odf::source::CompressionFormat::Gzip => Ok(fb::CompressionFormat::Gzip),
+            odf::source::CompressionFormat::Zip => Ok(fb::CompressionFormat::Zip),
         }
     }
 }
 
-impl Into<odf::source::CompressionFormat> for fb::CompressionFormat {
-    fn into(self) -> odf::source::CompressionFormat {
-        match self {
-            fb::CompressionFormat::Gzip => odf::source::CompressionFormat::Gzip,
-            fb::CompressionFormat::Zip => odf::source::CompressionFormat::Zip,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::CompressionFormat> for odf::source::CompressionFormat {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::CompressionFormat) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::CompressionFormat::Gzip => Ok(odf::source::CompressionFormat::Gzip),
+            fb::CompressionFormat::Zip => Ok(odf::source::CompressionFormat::Zip),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "CompressionFormat",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
@@ -642,31 +1210,44 @@ impl Into<odf::source::CompressionFormat> for fb::CompressionFormat {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataField {
     type OffsetT = WIPOffset<fb::DataField<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let name_offset = { fb.create_string(&self.name) };
-        let r#type_offset = { self.r#type.serialize(fb) };
-        let extra_offset = self.extra.as_ref().map(|v| v.serialize(fb));
+        let r#type_offset = { self.r#type.serialize(fb)? };
+        let extra_offset = match &self.extra {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::DataFieldBuilder::new(fb);
         builder.add_name(name_offset);
         builder.add_type_type(type_offset.0);
         builder.add_type_(type_offset.1);
         extra_offset.map(|off| builder.add_extra(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataField<'fb>> for odf::data::DataField {
-    fn deserialize(proxy: fb::DataField<'fb>) -> Self {
-        odf::data::DataField {
-            name: proxy.name().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::DataField<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataField {
+            name: proxy
+                .name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataField.name"))?,
             r#type: proxy
                 .type_()
-                .map(|v| odf::data::DataType::deserialize(v, proxy.r#type_type()))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::data::DataType::deserialize(v, proxy.r#type_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataField.type"))?,
             extra: proxy
                 .extra()
-                .map(|v| odf::data::ExtraAttributes::deserialize(v)),
-        }
+                .map(|v| odf::data::ExtraAttributes::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataField.extra"))?,
+        })
     }
 }
 
@@ -678,34 +1259,47 @@ impl<'fb> FlatbuffersDeserializable<fb::DataField<'fb>> for odf::data::DataField
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataSchema {
     type OffsetT = WIPOffset<fb::DataSchema<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let fields_offset = {
-            let offsets: Vec<_> = self.fields.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .fields
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let extra_offset = self.extra.as_ref().map(|v| v.serialize(fb));
+        let extra_offset = match &self.extra {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::DataSchemaBuilder::new(fb);
         builder.add_fields(fields_offset);
         extra_offset.map(|off| builder.add_extra(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataSchema<'fb>> for odf::data::DataSchema {
-    fn deserialize(proxy: fb::DataSchema<'fb>) -> Self {
-        odf::data::DataSchema {
+    fn deserialize(proxy: fb::DataSchema<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataSchema {
             fields: proxy
                 .fields()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::data::DataField::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataSchema.fields"))?,
             extra: proxy
                 .extra()
-                .map(|v| odf::data::ExtraAttributes::deserialize(v)),
-        }
+                .map(|v| odf::data::ExtraAttributes::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataSchema.extra"))?,
+        })
     }
 }
 
@@ -717,36 +1311,48 @@ impl<'fb> FlatbuffersDeserializable<fb::DataSchema<'fb>> for odf::data::DataSche
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::DataSlice {
     type OffsetT = WIPOffset<fb::DataSlice<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let logical_hash_offset = { fb.create_vector(&self.logical_hash.as_bytes().as_slice()) };
         let physical_hash_offset = { fb.create_vector(&self.physical_hash.as_bytes().as_slice()) };
-        let offset_interval_offset = { self.offset_interval.serialize(fb) };
+        let offset_interval_offset = { self.offset_interval.serialize(fb)? };
         let mut builder = fb::DataSliceBuilder::new(fb);
         builder.add_logical_hash(logical_hash_offset);
         builder.add_physical_hash(physical_hash_offset);
         builder.add_offset_interval(offset_interval_offset);
-        builder.add_size(self.size);
-        builder.finish()
+        builder.add_size_(self.size);
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataSlice<'fb>> for odf::dataset::DataSlice {
-    fn deserialize(proxy: fb::DataSlice<'fb>) -> Self {
-        odf::dataset::DataSlice {
+    fn deserialize(proxy: fb::DataSlice<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::DataSlice {
             logical_hash: proxy
                 .logical_hash()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.logicalHash"))?,
             physical_hash: proxy
                 .physical_hash()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.physicalHash"))?,
             offset_interval: proxy
                 .offset_interval()
-                .map(|v| odf::dataset::OffsetInterval::deserialize(v))
-                .unwrap(),
-            size: proxy.size(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::OffsetInterval::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.offsetInterval"))?,
+            size: Ok(proxy.size_()).map_err(|e: FlatbuffersDecodeError| e.at("DataSlice.size"))?,
+        })
     }
 }
 
@@ -759,225 +1365,215 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::DataType> for odf::data::DataType
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::DataType, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::DataType, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::data::DataType::Binary(v) => (
+            odf::data::DataType::Binary(v) => Ok((
                 fb::DataType::DataTypeBinary,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Bool(v) => {
-                (fb::DataType::DataTypeBool, v.serialize(fb).as_union_value())
-            }
-            odf::data::DataType::Date(v) => {
-                (fb::DataType::DataTypeDate, v.serialize(fb).as_union_value())
-            }
-            odf::data::DataType::Decimal(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Bool(v) => Ok((
+                fb::DataType::DataTypeBool,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Date(v) => Ok((
+                fb::DataType::DataTypeDate,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Decimal(v) => Ok((
                 fb::DataType::DataTypeDecimal,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Duration(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Duration(v) => Ok((
                 fb::DataType::DataTypeDuration,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Float16(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Float16(v) => Ok((
                 fb::DataType::DataTypeFloat16,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Float32(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Float32(v) => Ok((
                 fb::DataType::DataTypeFloat32,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Float64(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Float64(v) => Ok((
                 fb::DataType::DataTypeFloat64,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Int8(v) => {
-                (fb::DataType::DataTypeInt8, v.serialize(fb).as_union_value())
-            }
-            odf::data::DataType::Int16(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Int8(v) => Ok((
+                fb::DataType::DataTypeInt8,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Int16(v) => Ok((
                 fb::DataType::DataTypeInt16,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Int32(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Int32(v) => Ok((
                 fb::DataType::DataTypeInt32,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Int64(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Int64(v) => Ok((
                 fb::DataType::DataTypeInt64,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::UInt8(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::UInt8(v) => Ok((
                 fb::DataType::DataTypeUInt8,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::UInt16(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::UInt16(v) => Ok((
                 fb::DataType::DataTypeUInt16,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::UInt32(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::UInt32(v) => Ok((
                 fb::DataType::DataTypeUInt32,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::UInt64(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::UInt64(v) => Ok((
                 fb::DataType::DataTypeUInt64,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::List(v) => {
-                (fb::DataType::DataTypeList, v.serialize(fb).as_union_value())
-            }
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::List(v) => Ok((
+                fb::DataType::DataTypeList,
+                v.serialize(fb)?.as_union_value(),
+            )),
             odf::data::DataType::Map(v) => {
-                (fb::DataType::DataTypeMap, v.serialize(fb).as_union_value())
-            }
-            odf::data::DataType::Null(v) => {
-                (fb::DataType::DataTypeNull, v.serialize(fb).as_union_value())
+                Ok((fb::DataType::DataTypeMap, v.serialize(fb)?.as_union_value()))
             }
-            odf::data::DataType::Option(v) => (
+            odf::data::DataType::Null(v) => Ok((
+                fb::DataType::DataTypeNull,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Option(v) => Ok((
                 fb::DataType::DataTypeOption,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Struct(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Struct(v) => Ok((
                 fb::DataType::DataTypeStruct,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::Time(v) => {
-                (fb::DataType::DataTypeTime, v.serialize(fb).as_union_value())
-            }
-            odf::data::DataType::Timestamp(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Time(v) => Ok((
+                fb::DataType::DataTypeTime,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::Timestamp(v) => Ok((
                 fb::DataType::DataTypeTimestamp,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::data::DataType::String(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::data::DataType::String(v) => Ok((
                 fb::DataType::DataTypeString,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::DataType> for odf::data::DataType {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::DataType) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::DataType,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
-            fb::DataType::DataTypeBinary => {
-                odf::data::DataType::Binary(odf::data::DataTypeBinary::deserialize(unsafe {
-                    fb::DataTypeBinary::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeBool => {
-                odf::data::DataType::Bool(odf::data::DataTypeBool::deserialize(unsafe {
-                    fb::DataTypeBool::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeDate => {
-                odf::data::DataType::Date(odf::data::DataTypeDate::deserialize(unsafe {
-                    fb::DataTypeDate::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeDecimal => {
-                odf::data::DataType::Decimal(odf::data::DataTypeDecimal::deserialize(unsafe {
-                    fb::DataTypeDecimal::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeDuration => {
-                odf::data::DataType::Duration(odf::data::DataTypeDuration::deserialize(unsafe {
-                    fb::DataTypeDuration::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeFloat16 => {
-                odf::data::DataType::Float16(odf::data::DataTypeFloat16::deserialize(unsafe {
-                    fb::DataTypeFloat16::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeFloat32 => {
-                odf::data::DataType::Float32(odf::data::DataTypeFloat32::deserialize(unsafe {
-                    fb::DataTypeFloat32::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeFloat64 => {
-                odf::data::DataType::Float64(odf::data::DataTypeFloat64::deserialize(unsafe {
-                    fb::DataTypeFloat64::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeInt8 => {
-                odf::data::DataType::Int8(odf::data::DataTypeInt8::deserialize(unsafe {
-                    fb::DataTypeInt8::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeInt16 => {
-                odf::data::DataType::Int16(odf::data::DataTypeInt16::deserialize(unsafe {
-                    fb::DataTypeInt16::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeInt32 => {
-                odf::data::DataType::Int32(odf::data::DataTypeInt32::deserialize(unsafe {
-                    fb::DataTypeInt32::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeInt64 => {
-                odf::data::DataType::Int64(odf::data::DataTypeInt64::deserialize(unsafe {
-                    fb::DataTypeInt64::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeUInt8 => {
-                odf::data::DataType::UInt8(odf::data::DataTypeUInt8::deserialize(unsafe {
-                    fb::DataTypeUInt8::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeUInt16 => {
-                odf::data::DataType::UInt16(odf::data::DataTypeUInt16::deserialize(unsafe {
-                    fb::DataTypeUInt16::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeUInt32 => {
-                odf::data::DataType::UInt32(odf::data::DataTypeUInt32::deserialize(unsafe {
-                    fb::DataTypeUInt32::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeUInt64 => {
-                odf::data::DataType::UInt64(odf::data::DataTypeUInt64::deserialize(unsafe {
-                    fb::DataTypeUInt64::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeList => {
-                odf::data::DataType::List(odf::data::DataTypeList::deserialize(unsafe {
-                    fb::DataTypeList::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeMap => {
-                odf::data::DataType::Map(odf::data::DataTypeMap::deserialize(unsafe {
-                    fb::DataTypeMap::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeNull => {
-                odf::data::DataType::Null(odf::data::DataTypeNull::deserialize(unsafe {
-                    fb::DataTypeNull::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeOption => {
-                odf::data::DataType::Option(odf::data::DataTypeOption::deserialize(unsafe {
-                    fb::DataTypeOption::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeStruct => {
-                odf::data::DataType::Struct(odf::data::DataTypeStruct::deserialize(unsafe {
-                    fb::DataTypeStruct::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeTime => {
-                odf::data::DataType::Time(odf::data::DataTypeTime::deserialize(unsafe {
-                    fb::DataTypeTime::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeTimestamp => {
-                odf::data::DataType::Timestamp(odf::data::DataTypeTimestamp::deserialize(unsafe {
-                    fb::DataTypeTimestamp::init_from_table(table)
-                }))
-            }
-            fb::DataType::DataTypeString => {
-                odf::data::DataType::String(odf::data::DataTypeString::deserialize(unsafe {
-                    fb::DataTypeString::init_from_table(table)
-                }))
-            }
-            _ => panic!("Invalid enum value: {}", t.0),
+            fb::DataType::DataTypeBinary => odf::data::DataTypeBinary::deserialize(unsafe {
+                fb::DataTypeBinary::init_from_table(table)
+            })
+            .map(odf::data::DataType::Binary),
+            fb::DataType::DataTypeBool => odf::data::DataTypeBool::deserialize(unsafe {
+                fb::DataTypeBool::init_from_table(table)
+            })
+            .map(odf::data::DataType::Bool),
+            fb::DataType::DataTypeDate => odf::data::DataTypeDate::deserialize(unsafe {
+                fb::DataTypeDate::init_from_table(table)
+            })
+            .map(odf::data::DataType::Date),
+            fb::DataType::DataTypeDecimal => odf::data::DataTypeDecimal::deserialize(unsafe {
+                fb::DataTypeDecimal::init_from_table(table)
+            })
+            .map(odf::data::DataType::Decimal),
+            fb::DataType::DataTypeDuration => odf::data::DataTypeDuration::deserialize(unsafe {
+                fb::DataTypeDuration::init_from_table(table)
+            })
+            .map(odf::data::DataType::Duration),
+            fb::DataType::DataTypeFloat16 => odf::data::DataTypeFloat16::deserialize(unsafe {
+                fb::DataTypeFloat16::init_from_table(table)
+            })
+            .map(odf::data::DataType::Float16),
+            fb::DataType::DataTypeFloat32 => odf::data::DataTypeFloat32::deserialize(unsafe {
+                fb::DataTypeFloat32::init_from_table(table)
+            })
+            .map(odf::data::DataType::Float32),
+            fb::DataType::DataTypeFloat64 => odf::data::DataTypeFloat64::deserialize(unsafe {
+                fb::DataTypeFloat64::init_from_table(table)
+            })
+            .map(odf::data::DataType::Float64),
+            fb::DataType::DataTypeInt8 => odf::data::DataTypeInt8::deserialize(unsafe {
+                fb::DataTypeInt8::init_from_table(table)
+            })
+            .map(odf::data::DataType::Int8),
+            fb::DataType::DataTypeInt16 => odf::data::DataTypeInt16::deserialize(unsafe {
+                fb::DataTypeInt16::init_from_table(table)
+            })
+            .map(odf::data::DataType::Int16),
+            fb::DataType::DataTypeInt32 => odf::data::DataTypeInt32::deserialize(unsafe {
+                fb::DataTypeInt32::init_from_table(table)
+            })
+            .map(odf::data::DataType::Int32),
+            fb::DataType::DataTypeInt64 => odf::data::DataTypeInt64::deserialize(unsafe {
+                fb::DataTypeInt64::init_from_table(table)
+            })
+            .map(odf::data::DataType::Int64),
+            fb::DataType::DataTypeUInt8 => odf::data::DataTypeUInt8::deserialize(unsafe {
+                fb::DataTypeUInt8::init_from_table(table)
+            })
+            .map(odf::data::DataType::UInt8),
+            fb::DataType::DataTypeUInt16 => odf::data::DataTypeUInt16::deserialize(unsafe {
+                fb::DataTypeUInt16::init_from_table(table)
+            })
+            .map(odf::data::DataType::UInt16),
+            fb::DataType::DataTypeUInt32 => odf::data::DataTypeUInt32::deserialize(unsafe {
+                fb::DataTypeUInt32::init_from_table(table)
+            })
+            .map(odf::data::DataType::UInt32),
+            fb::DataType::DataTypeUInt64 => odf::data::DataTypeUInt64::deserialize(unsafe {
+                fb::DataTypeUInt64::init_from_table(table)
+            })
+            .map(odf::data::DataType::UInt64),
+            fb::DataType::DataTypeList => odf::data::DataTypeList::deserialize(unsafe {
+                fb::DataTypeList::init_from_table(table)
+            })
+            .map(odf::data::DataType::List),
+            fb::DataType::DataTypeMap => odf::data::DataTypeMap::deserialize(unsafe {
+                fb::DataTypeMap::init_from_table(table)
+            })
+            .map(odf::data::DataType::Map),
+            fb::DataType::DataTypeNull => odf::data::DataTypeNull::deserialize(unsafe {
+                fb::DataTypeNull::init_from_table(table)
+            })
+            .map(odf::data::DataType::Null),
+            fb::DataType::DataTypeOption => odf::data::DataTypeOption::deserialize(unsafe {
+                fb::DataTypeOption::init_from_table(table)
+            })
+            .map(odf::data::DataType::Option),
+            fb::DataType::DataTypeStruct => odf::data::DataTypeStruct::deserialize(unsafe {
+                fb::DataTypeStruct::init_from_table(table)
+            })
+            .map(odf::data::DataType::Struct),
+            fb::DataType::DataTypeTime => odf::data::DataTypeTime::deserialize(unsafe {
+                fb::DataTypeTime::init_from_table(table)
+            })
+            .map(odf::data::DataType::Time),
+            fb::DataType::DataTypeTimestamp => odf::data::DataTypeTimestamp::deserialize(unsafe {
+                fb::DataTypeTimestamp::init_from_table(table)
+            })
+            .map(odf::data::DataType::Timestamp),
+            fb::DataType::DataTypeString => odf::data::DataTypeString::deserialize(unsafe {
+                fb::DataTypeString::init_from_table(table)
+            })
+            .map(odf::data::DataType::String),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "DataType",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -990,18 +1586,25 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::DataType> for odf::data::DataTy
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeBinary {
     type OffsetT = WIPOffset<fb::DataTypeBinary<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeBinaryBuilder::new(fb);
         self.fixed_length.map(|v| builder.add_fixed_length(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeBinary<'fb>> for odf::data::DataTypeBinary {
-    fn deserialize(proxy: fb::DataTypeBinary<'fb>) -> Self {
-        odf::data::DataTypeBinary {
-            fixed_length: proxy.fixed_length().map(|v| v),
-        }
+    fn deserialize(proxy: fb::DataTypeBinary<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeBinary {
+            fixed_length: proxy
+                .fixed_length()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeBinary.fixedLength"))?,
+        })
     }
 }
 
@@ -1013,15 +1616,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeBinary<'fb>> for odf::data::Data
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeBool {
     type OffsetT = WIPOffset<fb::DataTypeBool<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeBoolBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeBool<'fb>> for odf::data::DataTypeBool {
-    fn deserialize(proxy: fb::DataTypeBool<'fb>) -> Self {
-        odf::data::DataTypeBool {}
+    fn deserialize(proxy: fb::DataTypeBool<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeBool {})
     }
 }
 
@@ -1033,15 +1639,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeBool<'fb>> for odf::data::DataTy
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeDate {
     type OffsetT = WIPOffset<fb::DataTypeDate<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeDateBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeDate<'fb>> for odf::data::DataTypeDate {
-    fn deserialize(proxy: fb::DataTypeDate<'fb>) -> Self {
-        odf::data::DataTypeDate {}
+    fn deserialize(proxy: fb::DataTypeDate<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeDate {})
     }
 }
 
@@ -1053,20 +1662,25 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeDate<'fb>> for odf::data::DataTy
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeDecimal {
     type OffsetT = WIPOffset<fb::DataTypeDecimal<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeDecimalBuilder::new(fb);
         builder.add_precision(self.precision);
         builder.add_scale(self.scale);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeDecimal<'fb>> for odf::data::DataTypeDecimal {
-    fn deserialize(proxy: fb::DataTypeDecimal<'fb>) -> Self {
-        odf::data::DataTypeDecimal {
-            precision: proxy.precision(),
-            scale: proxy.scale(),
-        }
+    fn deserialize(proxy: fb::DataTypeDecimal<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeDecimal {
+            precision: Ok(proxy.precision())
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeDecimal.precision"))?,
+            scale: Ok(proxy.scale())
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeDecimal.scale"))?,
+        })
     }
 }
 
@@ -1078,18 +1692,27 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeDecimal<'fb>> for odf::data::Dat
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeDuration {
     type OffsetT = WIPOffset<fb::DataTypeDuration<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeDurationBuilder::new(fb);
-        self.unit.map(|v| builder.add_unit(v.into()));
-        builder.finish()
+        if let Some(v) = &self.unit {
+            builder.add_unit(fb::TimeUnit::try_from(v)?);
+        }
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeDuration<'fb>> for odf::data::DataTypeDuration {
-    fn deserialize(proxy: fb::DataTypeDuration<'fb>) -> Self {
-        odf::data::DataTypeDuration {
-            unit: proxy.unit().map(|v| v.into()),
-        }
+    fn deserialize(proxy: fb::DataTypeDuration<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeDuration {
+            unit: proxy
+                .unit()
+                .map(|v| odf::data::TimeUnit::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeDuration.unit"))?,
+        })
     }
 }
 
@@ -1101,15 +1724,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeDuration<'fb>> for odf::data::Da
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeFloat16 {
     type OffsetT = WIPOffset<fb::DataTypeFloat16<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeFloat16Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeFloat16<'fb>> for odf::data::DataTypeFloat16 {
-    fn deserialize(proxy: fb::DataTypeFloat16<'fb>) -> Self {
-        odf::data::DataTypeFloat16 {}
+    fn deserialize(proxy: fb::DataTypeFloat16<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeFloat16 {})
     }
 }
 
@@ -1121,15 +1747,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeFloat16<'fb>> for odf::data::Dat
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeFloat32 {
     type OffsetT = WIPOffset<fb::DataTypeFloat32<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeFloat32Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeFloat32<'fb>> for odf::data::DataTypeFloat32 {
-    fn deserialize(proxy: fb::DataTypeFloat32<'fb>) -> Self {
-        odf::data::DataTypeFloat32 {}
+    fn deserialize(proxy: fb::DataTypeFloat32<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeFloat32 {})
     }
 }
 
@@ -1141,15 +1770,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeFloat32<'fb>> for odf::data::Dat
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeFloat64 {
     type OffsetT = WIPOffset<fb::DataTypeFloat64<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeFloat64Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeFloat64<'fb>> for odf::data::DataTypeFloat64 {
-    fn deserialize(proxy: fb::DataTypeFloat64<'fb>) -> Self {
-        odf::data::DataTypeFloat64 {}
+    fn deserialize(proxy: fb::DataTypeFloat64<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeFloat64 {})
     }
 }
 
@@ -1161,15 +1793,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeFloat64<'fb>> for odf::data::Dat
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeInt16 {
     type OffsetT = WIPOffset<fb::DataTypeInt16<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeInt16Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt16<'fb>> for odf::data::DataTypeInt16 {
-    fn deserialize(proxy: fb::DataTypeInt16<'fb>) -> Self {
-        odf::data::DataTypeInt16 {}
+    fn deserialize(proxy: fb::DataTypeInt16<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeInt16 {})
     }
 }
 
@@ -1181,15 +1816,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt16<'fb>> for odf::data::DataT
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeInt32 {
     type OffsetT = WIPOffset<fb::DataTypeInt32<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeInt32Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt32<'fb>> for odf::data::DataTypeInt32 {
-    fn deserialize(proxy: fb::DataTypeInt32<'fb>) -> Self {
-        odf::data::DataTypeInt32 {}
+    fn deserialize(proxy: fb::DataTypeInt32<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeInt32 {})
     }
 }
 
@@ -1201,15 +1839,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt32<'fb>> for odf::data::DataT
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeInt64 {
     type OffsetT = WIPOffset<fb::DataTypeInt64<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeInt64Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt64<'fb>> for odf::data::DataTypeInt64 {
-    fn deserialize(proxy: fb::DataTypeInt64<'fb>) -> Self {
-        odf::data::DataTypeInt64 {}
+    fn deserialize(proxy: fb::DataTypeInt64<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeInt64 {})
     }
 }
 
@@ -1221,15 +1862,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt64<'fb>> for odf::data::DataT
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeInt8 {
     type OffsetT = WIPOffset<fb::DataTypeInt8<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeInt8Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt8<'fb>> for odf::data::DataTypeInt8 {
-    fn deserialize(proxy: fb::DataTypeInt8<'fb>) -> Self {
-        odf::data::DataTypeInt8 {}
+    fn deserialize(proxy: fb::DataTypeInt8<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeInt8 {})
     }
 }
 
@@ -1241,25 +1885,35 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeInt8<'fb>> for odf::data::DataTy
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeList {
     type OffsetT = WIPOffset<fb::DataTypeList<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let item_type_offset = { self.item_type.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let item_type_offset = { self.item_type.serialize(fb)? };
         let mut builder = fb::DataTypeListBuilder::new(fb);
         builder.add_item_type_type(item_type_offset.0);
         builder.add_item_type(item_type_offset.1);
         self.fixed_length.map(|v| builder.add_fixed_length(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeList<'fb>> for odf::data::DataTypeList {
-    fn deserialize(proxy: fb::DataTypeList<'fb>) -> Self {
-        odf::data::DataTypeList {
+    fn deserialize(proxy: fb::DataTypeList<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeList {
             item_type: proxy
                 .item_type()
-                .map(|v| Box::new(odf::data::DataType::deserialize(v, proxy.item_type_type())))
-                .unwrap(),
-            fixed_length: proxy.fixed_length().map(|v| v),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::data::DataType::deserialize(v, proxy.item_type_type()).map(Box::new)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeList.itemType"))?,
+            fixed_length: proxy
+                .fixed_length()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeList.fixedLength"))?,
+        })
     }
 }
 
@@ -1271,32 +1925,45 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeList<'fb>> for odf::data::DataTy
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeMap {
     type OffsetT = WIPOffset<fb::DataTypeMap<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let key_type_offset = { self.key_type.serialize(fb) };
-        let value_type_offset = { self.value_type.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let key_type_offset = { self.key_type.serialize(fb)? };
+        let value_type_offset = { self.value_type.serialize(fb)? };
         let mut builder = fb::DataTypeMapBuilder::new(fb);
         builder.add_key_type_type(key_type_offset.0);
         builder.add_key_type(key_type_offset.1);
         builder.add_value_type_type(value_type_offset.0);
         builder.add_value_type(value_type_offset.1);
         self.keys_sorted.map(|v| builder.add_keys_sorted(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeMap<'fb>> for odf::data::DataTypeMap {
-    fn deserialize(proxy: fb::DataTypeMap<'fb>) -> Self {
-        odf::data::DataTypeMap {
+    fn deserialize(proxy: fb::DataTypeMap<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeMap {
             key_type: proxy
                 .key_type()
-                .map(|v| Box::new(odf::data::DataType::deserialize(v, proxy.key_type_type())))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::data::DataType::deserialize(v, proxy.key_type_type()).map(Box::new)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeMap.keyType"))?,
             value_type: proxy
                 .value_type()
-                .map(|v| Box::new(odf::data::DataType::deserialize(v, proxy.value_type_type())))
-                .unwrap(),
-            keys_sorted: proxy.keys_sorted().map(|v| v),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::data::DataType::deserialize(v, proxy.value_type_type()).map(Box::new)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeMap.valueType"))?,
+            keys_sorted: proxy
+                .keys_sorted()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeMap.keysSorted"))?,
+        })
     }
 }
 
@@ -1308,15 +1975,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeMap<'fb>> for odf::data::DataTyp
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeNull {
     type OffsetT = WIPOffset<fb::DataTypeNull<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeNullBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeNull<'fb>> for odf::data::DataTypeNull {
-    fn deserialize(proxy: fb::DataTypeNull<'fb>) -> Self {
-        odf::data::DataTypeNull {}
+    fn deserialize(proxy: fb::DataTypeNull<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeNull {})
     }
 }
 
@@ -1328,23 +1998,27 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeNull<'fb>> for odf::data::DataTy
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeOption {
     type OffsetT = WIPOffset<fb::DataTypeOption<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let inner_offset = { self.inner.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let inner_offset = { self.inner.serialize(fb)? };
         let mut builder = fb::DataTypeOptionBuilder::new(fb);
         builder.add_inner_type(inner_offset.0);
         builder.add_inner(inner_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeOption<'fb>> for odf::data::DataTypeOption {
-    fn deserialize(proxy: fb::DataTypeOption<'fb>) -> Self {
-        odf::data::DataTypeOption {
+    fn deserialize(proxy: fb::DataTypeOption<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeOption {
             inner: proxy
                 .inner()
-                .map(|v| Box::new(odf::data::DataType::deserialize(v, proxy.inner_type())))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::data::DataType::deserialize(v, proxy.inner_type()).map(Box::new))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeOption.inner"))?,
+        })
     }
 }
 
@@ -1356,15 +2030,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeOption<'fb>> for odf::data::Data
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeString {
     type OffsetT = WIPOffset<fb::DataTypeString<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeStringBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeString<'fb>> for odf::data::DataTypeString {
-    fn deserialize(proxy: fb::DataTypeString<'fb>) -> Self {
-        odf::data::DataTypeString {}
+    fn deserialize(proxy: fb::DataTypeString<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeString {})
     }
 }
 
@@ -1376,29 +2053,37 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeString<'fb>> for odf::data::Data
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeStruct {
     type OffsetT = WIPOffset<fb::DataTypeStruct<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let fields_offset = {
-            let offsets: Vec<_> = self.fields.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .fields
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::DataTypeStructBuilder::new(fb);
         builder.add_fields(fields_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeStruct<'fb>> for odf::data::DataTypeStruct {
-    fn deserialize(proxy: fb::DataTypeStruct<'fb>) -> Self {
-        odf::data::DataTypeStruct {
+    fn deserialize(proxy: fb::DataTypeStruct<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeStruct {
             fields: proxy
                 .fields()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::data::DataField::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeStruct.fields"))?,
+        })
     }
 }
 
@@ -1410,18 +2095,27 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeStruct<'fb>> for odf::data::Data
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeTime {
     type OffsetT = WIPOffset<fb::DataTypeTime<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeTimeBuilder::new(fb);
-        self.unit.map(|v| builder.add_unit(v.into()));
-        builder.finish()
+        if let Some(v) = &self.unit {
+            builder.add_unit(fb::TimeUnit::try_from(v)?);
+        }
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeTime<'fb>> for odf::data::DataTypeTime {
-    fn deserialize(proxy: fb::DataTypeTime<'fb>) -> Self {
-        odf::data::DataTypeTime {
-            unit: proxy.unit().map(|v| v.into()),
-        }
+    fn deserialize(proxy: fb::DataTypeTime<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeTime {
+            unit: proxy
+                .unit()
+                .map(|v| odf::data::TimeUnit::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeTime.unit"))?,
+        })
     }
 }
 
@@ -1433,21 +2127,37 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeTime<'fb>> for odf::data::DataTy
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeTimestamp {
     type OffsetT = WIPOffset<fb::DataTypeTimestamp<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let timezone_offset = self.timezone.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let timezone_offset = match &self.timezone {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::DataTypeTimestampBuilder::new(fb);
-        self.unit.map(|v| builder.add_unit(v.into()));
+        if let Some(v) = &self.unit {
+            builder.add_unit(fb::TimeUnit::try_from(v)?);
+        }
         timezone_offset.map(|off| builder.add_timezone(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeTimestamp<'fb>> for odf::data::DataTypeTimestamp {
-    fn deserialize(proxy: fb::DataTypeTimestamp<'fb>) -> Self {
-        odf::data::DataTypeTimestamp {
-            unit: proxy.unit().map(|v| v.into()),
-            timezone: proxy.timezone().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::DataTypeTimestamp<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeTimestamp {
+            unit: proxy
+                .unit()
+                .map(|v| odf::data::TimeUnit::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeTimestamp.unit"))?,
+            timezone: proxy
+                .timezone()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DataTypeTimestamp.timezone"))?,
+        })
     }
 }
 
@@ -1459,15 +2169,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeTimestamp<'fb>> for odf::data::D
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeUInt16 {
     type OffsetT = WIPOffset<fb::DataTypeUInt16<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeUInt16Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt16<'fb>> for odf::data::DataTypeUInt16 {
-    fn deserialize(proxy: fb::DataTypeUInt16<'fb>) -> Self {
-        odf::data::DataTypeUInt16 {}
+    fn deserialize(proxy: fb::DataTypeUInt16<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeUInt16 {})
     }
 }
 
@@ -1479,15 +2192,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt16<'fb>> for odf::data::Data
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeUInt32 {
     type OffsetT = WIPOffset<fb::DataTypeUInt32<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeUInt32Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt32<'fb>> for odf::data::DataTypeUInt32 {
-    fn deserialize(proxy: fb::DataTypeUInt32<'fb>) -> Self {
-        odf::data::DataTypeUInt32 {}
+    fn deserialize(proxy: fb::DataTypeUInt32<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeUInt32 {})
     }
 }
 
@@ -1499,15 +2215,18 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt32<'fb>> for odf::data::Data
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeUInt64 {
     type OffsetT = WIPOffset<fb::DataTypeUInt64<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeUInt64Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt64<'fb>> for odf::data::DataTypeUInt64 {
-    fn deserialize(proxy: fb::DataTypeUInt64<'fb>) -> Self {
-        odf::data::DataTypeUInt64 {}
+    fn deserialize(proxy: fb::DataTypeUInt64<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeUInt64 {})
     }
 }
 
@@ -1519,15 +2238,49 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt64<'fb>> for odf::data::Data
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::DataTypeUInt8 {
     type OffsetT = WIPOffset<fb::DataTypeUInt8<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::DataTypeUInt8Builder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt8<'fb>> for odf::data::DataTypeUInt8 {
-    fn deserialize(proxy: fb::DataTypeUInt8<'fb>) -> Self {
-        odf::data::DataTypeUInt8 {}
+    fn deserialize(proxy: fb::DataTypeUInt8<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::data::DataTypeUInt8 {})
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetError
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetError
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetError {
+    type OffsetT = WIPOffset<fb::DatasetError<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let error_details_offset = { self.error_details.serialize(fb)? };
+        let mut builder = fb::DatasetErrorBuilder::new(fb);
+        builder.add_error_details(error_details_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetError<'fb>> for odf::protocol::DatasetError {
+    fn deserialize(proxy: fb::DatasetError<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetError {
+            error_details: proxy
+                .error_details()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::ErrorDetails::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetError.errorDetails"))?,
+        })
     }
 }
 
@@ -1536,263 +2289,943 @@ impl<'fb> FlatbuffersDeserializable<fb::DataTypeUInt8<'fb>> for odf::data::DataT
 // Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetKind
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::dataset::DatasetKind> for fb::DatasetKind {
-    fn from(v: odf::dataset::DatasetKind) -> Self {
+impl TryFrom<&odf::dataset::DatasetKind> for fb::DatasetKind {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::dataset::DatasetKind) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::dataset::DatasetKind::Root => fb::DatasetKind::Root,
-            odf::dataset::DatasetKind::Derivative => fb::DatasetKind::Derivative,
+            odf::dataset::DatasetKind::Root => Ok(fb::DatasetKind::Root),
+            odf::dataset::DatasetKind::Derivative => Ok(fb::DatasetKind::Derivative),
         }
     }
 }
 
-impl Into<odf::dataset::DatasetKind> for fb::DatasetKind {
-    fn into(self) -> odf::dataset::DatasetKind {
-        match self {
-            fb::DatasetKind::Root => odf::dataset::DatasetKind::Root,
-            fb::DatasetKind::Derivative => odf::dataset::DatasetKind::Derivative,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::DatasetKind> for odf::dataset::DatasetKind {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::DatasetKind) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::DatasetKind::Root => Ok(odf::dataset::DatasetKind::Root),
+            fb::DatasetKind::Derivative => Ok(odf::dataset::DatasetKind::Derivative),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "DatasetKind",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// DatasetSpec
-// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpec
+// DatasetMetadataPullResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetMetadataPullResponse
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::DatasetSpec {
-    type OffsetT = WIPOffset<fb::DatasetSpec<'fb>>;
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetMetadataPullResponse {
+    type OffsetT = WIPOffset<fb::DatasetMetadataPullResponse<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let did_offset = { fb.create_vector(&self.did.as_bytes()) };
-        let metadata_offset = {
-            let offsets: Vec<_> = self
-                .metadata
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let blocks_offset = { self.blocks.serialize(fb)? };
+        let mut builder = fb::DatasetMetadataPullResponseBuilder::new(fb);
+        builder.add_blocks(blocks_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetMetadataPullResponse<'fb>>
+    for odf::protocol::DatasetMetadataPullResponse
+{
+    fn deserialize(
+        proxy: fb::DatasetMetadataPullResponse<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetMetadataPullResponse {
+            blocks: proxy
+                .blocks()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::ObjectsBatch::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetMetadataPullResponse.blocks"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullMetadataRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullMetadataRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPullMetadataRequest {
+    type OffsetT = WIPOffset<fb::DatasetPullMetadataRequest<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let mut builder = fb::DatasetPullMetadataRequestBuilder::new(fb);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPullMetadataRequest<'fb>>
+    for odf::protocol::DatasetPullMetadataRequest
+{
+    fn deserialize(
+        proxy: fb::DatasetPullMetadataRequest<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPullMetadataRequest {})
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPullObjectsTransferRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPullObjectsTransferRequest {
+    type OffsetT = WIPOffset<fb::DatasetPullObjectsTransferRequest<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_files_offset = {
+            let offsets = self
+                .object_files
                 .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
-                    let mut builder = fb::MetadataEventWrapperBuilder::new(fb);
-                    builder.add_value_type(value_type);
-                    builder.add_value(value_offset);
-                    builder.finish()
-                })
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let volume_offset = { self.volume.serialize(fb) };
-        let mut builder = fb::DatasetSpecBuilder::new(fb);
-        builder.add_did(did_offset);
-        builder.add_kind(self.kind.into());
-        builder.add_metadata(metadata_offset);
-        builder.add_volume(volume_offset);
-        builder.finish()
+        let mut builder = fb::DatasetPullObjectsTransferRequestBuilder::new(fb);
+        builder.add_object_files(object_files_offset);
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::DatasetSpec<'fb>> for odf::dataset::DatasetSpec {
-    fn deserialize(proxy: fb::DatasetSpec<'fb>) -> Self {
-        odf::dataset::DatasetSpec {
-            did: proxy
-                .did()
-                .map(|v| odf::dataset::DatasetID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
-            kind: proxy.kind().into(),
-            metadata: proxy
-                .metadata()
-                .map(|v| {
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPullObjectsTransferRequest<'fb>>
+    for odf::protocol::DatasetPullObjectsTransferRequest
+{
+    fn deserialize(
+        proxy: fb::DatasetPullObjectsTransferRequest<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPullObjectsTransferRequest {
+            object_files: proxy
+                .object_files()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
-                        .map(|i| {
-                            odf::dataset::MetadataEvent::deserialize(
-                                i.value().unwrap(),
-                                i.value_type(),
-                            )
-                        })
-                        .collect()
+                        .map(|i| odf::protocol::ObjectFileReference::deserialize(i))
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-            volume: proxy
-                .volume()
-                .map(|v| odf::resource::ResourceHandle::deserialize(v))
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("DatasetPullObjectsTransferRequest.objectFiles")
+                })?,
+        })
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// DatasetSpecInput
-// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpecInput
+// DatasetPullObjectsTransferResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferResponse
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::DatasetSpecInput {
-    type OffsetT = WIPOffset<fb::DatasetSpecInput<'fb>>;
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPullObjectsTransferResponse {
+    type OffsetT = WIPOffset<fb::DatasetPullObjectsTransferResponse<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let did_offset = self.did.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let metadata_offset = {
-            let offsets: Vec<_> = self
-                .metadata
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_transfer_strategies_offset = {
+            let offsets = self
+                .object_transfer_strategies
                 .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
-                    let mut builder = fb::MetadataEventWrapperBuilder::new(fb);
-                    builder.add_value_type(value_type);
-                    builder.add_value(value_offset);
-                    builder.finish()
-                })
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let volume_offset = self.volume.as_ref().map(|v| v.serialize(fb));
-        let mut builder = fb::DatasetSpecInputBuilder::new(fb);
-        did_offset.map(|off| builder.add_did(off));
-        builder.add_kind(self.kind.into());
-        builder.add_metadata(metadata_offset);
-        volume_offset.map(|off| builder.add_volume(off));
-        builder.finish()
+        let mut builder = fb::DatasetPullObjectsTransferResponseBuilder::new(fb);
+        builder.add_object_transfer_strategies(object_transfer_strategies_offset);
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::DatasetSpecInput<'fb>> for odf::dataset::DatasetSpecInput {
-    fn deserialize(proxy: fb::DatasetSpecInput<'fb>) -> Self {
-        odf::dataset::DatasetSpecInput {
-            did: proxy
-                .did()
-                .map(|v| odf::dataset::DatasetID::from_bytes(v.bytes()).unwrap()),
-            kind: proxy.kind().into(),
-            metadata: proxy
-                .metadata()
-                .map(|v| {
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPullObjectsTransferResponse<'fb>>
+    for odf::protocol::DatasetPullObjectsTransferResponse
+{
+    fn deserialize(
+        proxy: fb::DatasetPullObjectsTransferResponse<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPullObjectsTransferResponse {
+            object_transfer_strategies: proxy
+                .object_transfer_strategies()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
-                        .map(|i| {
-                            odf::dataset::MetadataEvent::deserialize(
-                                i.value().unwrap(),
-                                i.value_type(),
-                            )
-                        })
-                        .collect()
+                        .map(|i| odf::protocol::PullObjectTransferStrategy::deserialize(i))
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-            volume: proxy
-                .volume()
-                .map(|v| odf::storage::PersistentVolumeRef::deserialize(v)),
-        }
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("DatasetPullObjectsTransferResponse.objectTransferStrategies")
+                })?,
+        })
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// DatasetVocabulary
-// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetVocabulary
+// DatasetPullRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullRequest
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::DatasetVocabulary {
-    type OffsetT = WIPOffset<fb::DatasetVocabulary<'fb>>;
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPullRequest {
+    type OffsetT = WIPOffset<fb::DatasetPullRequest<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let offset_column_offset = self.offset_column.as_ref().map(|v| fb.create_string(&v));
-        let operation_type_column_offset = self
-            .operation_type_column
-            .as_ref()
-            .map(|v| fb.create_string(&v));
-        let system_time_column_offset = self
-            .system_time_column
-            .as_ref()
-            .map(|v| fb.create_string(&v));
-        let event_time_column_offset = self
-            .event_time_column
-            .as_ref()
-            .map(|v| fb.create_string(&v));
-        let mut builder = fb::DatasetVocabularyBuilder::new(fb);
-        offset_column_offset.map(|off| builder.add_offset_column(off));
-        operation_type_column_offset.map(|off| builder.add_operation_type_column(off));
-        system_time_column_offset.map(|off| builder.add_system_time_column(off));
-        event_time_column_offset.map(|off| builder.add_event_time_column(off));
-        builder.finish()
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let dataset_id_offset = { fb.create_vector(&self.dataset_id.as_bytes()) };
+        let begin_after_offset = match &self.begin_after {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes().as_slice()) }),
+            None => None,
+        };
+        let stop_at_offset = match &self.stop_at {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes().as_slice()) }),
+            None => None,
+        };
+        let mut builder = fb::DatasetPullRequestBuilder::new(fb);
+        builder.add_dataset_id(dataset_id_offset);
+        begin_after_offset.map(|off| builder.add_begin_after(off));
+        stop_at_offset.map(|off| builder.add_stop_at(off));
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::DatasetVocabulary<'fb>>
-    for odf::dataset::DatasetVocabulary
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPullRequest<'fb>>
+    for odf::protocol::DatasetPullRequest
 {
-    fn deserialize(proxy: fb::DatasetVocabulary<'fb>) -> Self {
-        odf::dataset::DatasetVocabulary {
-            offset_column: proxy.offset_column().map(|v| v.to_owned()),
-            operation_type_column: proxy.operation_type_column().map(|v| v.to_owned()),
-            system_time_column: proxy.system_time_column().map(|v| v.to_owned()),
-            event_time_column: proxy.event_time_column().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::DatasetPullRequest<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPullRequest {
+            dataset_id: proxy
+                .dataset_id()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullRequest.datasetId"))?,
+            begin_after: proxy
+                .begin_after()
+                .map(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullRequest.beginAfter"))?,
+            stop_at: proxy
+                .stop_at()
+                .map(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullRequest.stopAt"))?,
+        })
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// DisablePollingSource
-// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePollingSource
+// DatasetPullResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullResponse
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::DisablePollingSource {
-    type OffsetT = WIPOffset<fb::DisablePollingSource<'fb>>;
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPullResponse {
+    type OffsetT = WIPOffset<fb::DatasetPullResponse<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let mut builder = fb::DisablePollingSourceBuilder::new(fb);
-        builder.finish()
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let size_estimation_offset = { self.size_estimation.serialize(fb)? };
+        let mut builder = fb::DatasetPullResponseBuilder::new(fb);
+        builder.add_size_estimation(size_estimation_offset);
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::DisablePollingSource<'fb>>
-    for odf::legacy::DisablePollingSource
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPullResponse<'fb>>
+    for odf::protocol::DatasetPullResponse
 {
-    fn deserialize(proxy: fb::DisablePollingSource<'fb>) -> Self {
-        odf::legacy::DisablePollingSource {}
+    fn deserialize(proxy: fb::DatasetPullResponse<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPullResponse {
+            size_estimation: proxy
+                .size_estimation()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::TransferSizeEstimation::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPullResponse.sizeEstimation"))?,
+        })
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// DisablePushSource
-// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePushSource
+// DatasetPushComplete
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushComplete
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::DisablePushSource {
-    type OffsetT = WIPOffset<fb::DisablePushSource<'fb>>;
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushComplete {
+    type OffsetT = WIPOffset<fb::DatasetPushComplete<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let source_name_offset = { fb.create_string(&self.source_name) };
-        let mut builder = fb::DisablePushSourceBuilder::new(fb);
-        builder.add_source_name(source_name_offset);
-        builder.finish()
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let mut builder = fb::DatasetPushCompleteBuilder::new(fb);
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::DisablePushSource<'fb>> for odf::legacy::DisablePushSource {
-    fn deserialize(proxy: fb::DisablePushSource<'fb>) -> Self {
-        odf::legacy::DisablePushSource {
-            source_name: proxy.source_name().map(|v| v.to_owned()).unwrap(),
-        }
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushComplete<'fb>>
+    for odf::protocol::DatasetPushComplete
+{
+    fn deserialize(proxy: fb::DatasetPushComplete<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushComplete {})
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// EnvVar
-// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EnvVar
+// DatasetPushCompleteConfirmed
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushCompleteConfirmed
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::source::EnvVar {
-    type OffsetT = WIPOffset<fb::EnvVar<'fb>>;
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushCompleteConfirmed {
+    type OffsetT = WIPOffset<fb::DatasetPushCompleteConfirmed<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let name_offset = { fb.create_string(&self.name) };
-        let value_offset = self.value.as_ref().map(|v| fb.create_string(&v));
-        let mut builder = fb::EnvVarBuilder::new(fb);
-        builder.add_name(name_offset);
-        value_offset.map(|off| builder.add_value(off));
-        builder.finish()
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let mut builder = fb::DatasetPushCompleteConfirmedBuilder::new(fb);
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::EnvVar<'fb>> for odf::source::EnvVar {
-    fn deserialize(proxy: fb::EnvVar<'fb>) -> Self {
-        odf::source::EnvVar {
-            name: proxy.name().map(|v| v.to_owned()).unwrap(),
-            value: proxy.value().map(|v| v.to_owned()),
-        }
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushCompleteConfirmed<'fb>>
+    for odf::protocol::DatasetPushCompleteConfirmed
+{
+    fn deserialize(
+        proxy: fb::DatasetPushCompleteConfirmed<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushCompleteConfirmed {})
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushMetadata
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadata
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushMetadata {
+    type OffsetT = WIPOffset<fb::DatasetPushMetadata<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let new_blocks_offset = { self.new_blocks.serialize(fb)? };
+        let mut builder = fb::DatasetPushMetadataBuilder::new(fb);
+        builder.add_new_blocks(new_blocks_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushMetadata<'fb>>
+    for odf::protocol::DatasetPushMetadata
+{
+    fn deserialize(proxy: fb::DatasetPushMetadata<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushMetadata {
+            new_blocks: proxy
+                .new_blocks()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::ObjectsBatch::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushMetadata.newBlocks"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushMetadataAccepted
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadataAccepted
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushMetadataAccepted {
+    type OffsetT = WIPOffset<fb::DatasetPushMetadataAccepted<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let mut builder = fb::DatasetPushMetadataAcceptedBuilder::new(fb);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushMetadataAccepted<'fb>>
+    for odf::protocol::DatasetPushMetadataAccepted
+{
+    fn deserialize(
+        proxy: fb::DatasetPushMetadataAccepted<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushMetadataAccepted {})
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushObjectsTransferRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushObjectsTransferRequest {
+    type OffsetT = WIPOffset<fb::DatasetPushObjectsTransferRequest<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_files_offset = {
+            let offsets = self
+                .object_files
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let mut builder = fb::DatasetPushObjectsTransferRequestBuilder::new(fb);
+        builder.add_object_files(object_files_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushObjectsTransferRequest<'fb>>
+    for odf::protocol::DatasetPushObjectsTransferRequest
+{
+    fn deserialize(
+        proxy: fb::DatasetPushObjectsTransferRequest<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushObjectsTransferRequest {
+            object_files: proxy
+                .object_files()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| odf::protocol::ObjectFileReference::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("DatasetPushObjectsTransferRequest.objectFiles")
+                })?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushObjectsTransferResponse
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferResponse
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushObjectsTransferResponse {
+    type OffsetT = WIPOffset<fb::DatasetPushObjectsTransferResponse<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_transfer_strategies_offset = {
+            let offsets = self
+                .object_transfer_strategies
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let mut builder = fb::DatasetPushObjectsTransferResponseBuilder::new(fb);
+        builder.add_object_transfer_strategies(object_transfer_strategies_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushObjectsTransferResponse<'fb>>
+    for odf::protocol::DatasetPushObjectsTransferResponse
+{
+    fn deserialize(
+        proxy: fb::DatasetPushObjectsTransferResponse<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushObjectsTransferResponse {
+            object_transfer_strategies: proxy
+                .object_transfer_strategies()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| odf::protocol::PushObjectTransferStrategy::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("DatasetPushObjectsTransferResponse.objectTransferStrategies")
+                })?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushRequest
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequest
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushRequest {
+    type OffsetT = WIPOffset<fb::DatasetPushRequest<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let dataset_id_offset = { fb.create_vector(&self.dataset_id.as_bytes()) };
+        let current_head_offset = { fb.create_vector(&self.current_head.as_bytes().as_slice()) };
+        let size_estimation_offset = { self.size_estimation.serialize(fb)? };
+        let mut builder = fb::DatasetPushRequestBuilder::new(fb);
+        builder.add_dataset_id(dataset_id_offset);
+        builder.add_current_head(current_head_offset);
+        builder.add_size_estimation(size_estimation_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushRequest<'fb>>
+    for odf::protocol::DatasetPushRequest
+{
+    fn deserialize(proxy: fb::DatasetPushRequest<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushRequest {
+            dataset_id: proxy
+                .dataset_id()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushRequest.datasetId"))?,
+            current_head: proxy
+                .current_head()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushRequest.currentHead"))?,
+            size_estimation: proxy
+                .size_estimation()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::TransferSizeEstimation::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetPushRequest.sizeEstimation"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetPushRequestAccepted
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequestAccepted
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::DatasetPushRequestAccepted {
+    type OffsetT = WIPOffset<fb::DatasetPushRequestAccepted<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let mut builder = fb::DatasetPushRequestAcceptedBuilder::new(fb);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetPushRequestAccepted<'fb>>
+    for odf::protocol::DatasetPushRequestAccepted
+{
+    fn deserialize(
+        proxy: fb::DatasetPushRequestAccepted<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::DatasetPushRequestAccepted {})
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetSpec
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpec
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::DatasetSpec {
+    type OffsetT = WIPOffset<fb::DatasetSpec<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let did_offset = { fb.create_vector(&self.did.as_bytes()) };
+        let metadata_offset = {
+            let offsets = self
+                .metadata
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
+                    let mut builder = fb::MetadataEventWrapperBuilder::new(fb);
+                    builder.add_value_type(value_type);
+                    builder.add_value(value_offset);
+                    Ok(builder.finish())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let volume_offset = { self.volume.serialize(fb)? };
+        let mut builder = fb::DatasetSpecBuilder::new(fb);
+        builder.add_did(did_offset);
+        builder.add_kind(fb::DatasetKind::try_from(&self.kind)?);
+        builder.add_metadata(metadata_offset);
+        builder.add_volume(volume_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetSpec<'fb>> for odf::dataset::DatasetSpec {
+    fn deserialize(proxy: fb::DatasetSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::DatasetSpec {
+            did: proxy
+                .did()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.did"))?,
+            kind: odf::dataset::DatasetKind::try_from(proxy.kind())
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.kind"))?,
+            metadata: proxy
+                .metadata()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::dataset::MetadataEvent::deserialize(v, i.value_type())
+                                })
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.metadata"))?,
+            volume: proxy
+                .volume()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpec.volume"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetSpecInput
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetSpecInput
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::DatasetSpecInput {
+    type OffsetT = WIPOffset<fb::DatasetSpecInput<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let did_offset = match &self.did {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let metadata_offset = {
+            let offsets = self
+                .metadata
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
+                    let mut builder = fb::MetadataEventWrapperBuilder::new(fb);
+                    builder.add_value_type(value_type);
+                    builder.add_value(value_offset);
+                    Ok(builder.finish())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let volume_offset = match &self.volume {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let mut builder = fb::DatasetSpecInputBuilder::new(fb);
+        did_offset.map(|off| builder.add_did(off));
+        builder.add_kind(fb::DatasetKind::try_from(&self.kind)?);
+        builder.add_metadata(metadata_offset);
+        volume_offset.map(|off| builder.add_volume(off));
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetSpecInput<'fb>> for odf::dataset::DatasetSpecInput {
+    fn deserialize(proxy: fb::DatasetSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::DatasetSpecInput {
+            did: proxy
+                .did()
+                .map(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.did"))?,
+            kind: odf::dataset::DatasetKind::try_from(proxy.kind())
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.kind"))?,
+            metadata: proxy
+                .metadata()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::dataset::MetadataEvent::deserialize(v, i.value_type())
+                                })
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.metadata"))?,
+            volume: proxy
+                .volume()
+                .map(|v| odf::storage::PersistentVolumeRef::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetSpecInput.volume"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DatasetVocabulary
+// Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetVocabulary
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::DatasetVocabulary {
+    type OffsetT = WIPOffset<fb::DatasetVocabulary<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let offset_column_offset = match &self.offset_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let operation_type_column_offset = match &self.operation_type_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let system_time_column_offset = match &self.system_time_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let event_time_column_offset = match &self.event_time_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let mut builder = fb::DatasetVocabularyBuilder::new(fb);
+        offset_column_offset.map(|off| builder.add_offset_column(off));
+        operation_type_column_offset.map(|off| builder.add_operation_type_column(off));
+        system_time_column_offset.map(|off| builder.add_system_time_column(off));
+        event_time_column_offset.map(|off| builder.add_event_time_column(off));
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DatasetVocabulary<'fb>>
+    for odf::dataset::DatasetVocabulary
+{
+    fn deserialize(proxy: fb::DatasetVocabulary<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::DatasetVocabulary {
+            offset_column: proxy
+                .offset_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetVocabulary.offsetColumn"))?,
+            operation_type_column: proxy
+                .operation_type_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("DatasetVocabulary.operationTypeColumn")
+                })?,
+            system_time_column: proxy
+                .system_time_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetVocabulary.systemTimeColumn"))?,
+            event_time_column: proxy
+                .event_time_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("DatasetVocabulary.eventTimeColumn"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DisablePollingSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePollingSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::DisablePollingSource {
+    type OffsetT = WIPOffset<fb::DisablePollingSource<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let mut builder = fb::DisablePollingSourceBuilder::new(fb);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DisablePollingSource<'fb>>
+    for odf::legacy::DisablePollingSource
+{
+    fn deserialize(proxy: fb::DisablePollingSource<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::DisablePollingSource {})
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// DisablePushSource
+// Schema: https://opendatafabric.org/schemas/legacy/v0/DisablePushSource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::DisablePushSource {
+    type OffsetT = WIPOffset<fb::DisablePushSource<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let source_name_offset = { fb.create_string(&self.source_name) };
+        let mut builder = fb::DisablePushSourceBuilder::new(fb);
+        builder.add_source_name(source_name_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::DisablePushSource<'fb>> for odf::legacy::DisablePushSource {
+    fn deserialize(proxy: fb::DisablePushSource<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::DisablePushSource {
+            source_name: proxy
+                .source_name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("DisablePushSource.sourceName"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EnvVar
+// Schema: https://opendatafabric.org/schemas/source/v1alpha1/EnvVar
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::source::EnvVar {
+    type OffsetT = WIPOffset<fb::EnvVar<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let name_offset = { fb.create_string(&self.name) };
+        let value_offset = match &self.value {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let mut builder = fb::EnvVarBuilder::new(fb);
+        builder.add_name(name_offset);
+        value_offset.map(|off| builder.add_value(off));
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::EnvVar<'fb>> for odf::source::EnvVar {
+    fn deserialize(proxy: fb::EnvVar<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::EnvVar {
+            name: proxy
+                .name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("EnvVar.name"))?,
+            value: proxy
+                .value()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("EnvVar.value"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ErrorDetails
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ErrorDetails
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::ErrorDetails {
+    type OffsetT = WIPOffset<fb::ErrorDetails<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let error_code_offset = { fb.create_string(&self.error_code) };
+        let description_offset = { fb.create_string(&self.description) };
+        let extras_offset = match &self.extras {
+            Some(v) => Some({
+                fb.create_string(
+                    &serde_json::to_string(&v).map_err(FlatbuffersEncodeError::invalid_value)?,
+                )
+            }),
+            None => None,
+        };
+        let mut builder = fb::ErrorDetailsBuilder::new(fb);
+        builder.add_error_code(error_code_offset);
+        builder.add_description(description_offset);
+        extras_offset.map(|off| builder.add_extras(off));
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ErrorDetails<'fb>> for odf::protocol::ErrorDetails {
+    fn deserialize(proxy: fb::ErrorDetails<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::ErrorDetails {
+            error_code: proxy
+                .error_code()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ErrorDetails.errorCode"))?,
+            description: proxy
+                .description()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ErrorDetails.description"))?,
+            extras: proxy
+                .extras()
+                .map(|v| serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ErrorDetails.extras"))?,
+        })
     }
 }
 
@@ -1801,43 +3234,259 @@ impl<'fb> FlatbuffersDeserializable<fb::EnvVar<'fb>> for odf::source::EnvVar {
 // Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::event::EventFilter {
-    type OffsetT = WIPOffset<fb::EventFilter<'fb>>;
+impl<'fb> FlatbuffersEnumSerializable<'fb, fb::EventFilter> for odf::event::EventFilter {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<(fb::EventFilter, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
+        match self {
+            odf::event::EventFilter::And(v) => Ok((
+                fb::EventFilter::EventFilterAnd,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::event::EventFilter::Or(v) => Ok((
+                fb::EventFilter::EventFilterOr,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::event::EventFilter::Not(v) => Ok((
+                fb::EventFilter::EventFilterNot,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::event::EventFilter::Match(v) => Ok((
+                fb::EventFilter::EventFilterMatch,
+                v.serialize(fb)?.as_union_value(),
+            )),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::EventFilter> for odf::event::EventFilter {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::EventFilter,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::EventFilter::EventFilterAnd => odf::event::EventFilterAnd::deserialize(unsafe {
+                fb::EventFilterAnd::init_from_table(table)
+            })
+            .map(odf::event::EventFilter::And),
+            fb::EventFilter::EventFilterOr => odf::event::EventFilterOr::deserialize(unsafe {
+                fb::EventFilterOr::init_from_table(table)
+            })
+            .map(odf::event::EventFilter::Or),
+            fb::EventFilter::EventFilterNot => odf::event::EventFilterNot::deserialize(unsafe {
+                fb::EventFilterNot::init_from_table(table)
+            })
+            .map(odf::event::EventFilter::Not),
+            fb::EventFilter::EventFilterMatch => {
+                odf::event::EventFilterMatch::deserialize(unsafe {
+                    fb::EventFilterMatch::init_from_table(table)
+                })
+                .map(odf::event::EventFilter::Match)
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "EventFilter",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterAnd
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/And
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::event::EventFilterAnd {
+    type OffsetT = WIPOffset<fb::EventFilterAnd<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let and_offset = {
+            let offsets = self
+                .and
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
+                    let mut builder = fb::EventFilterWrapperBuilder::new(fb);
+                    builder.add_value_type(value_type);
+                    builder.add_value(value_offset);
+                    Ok(builder.finish())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let mut builder = fb::EventFilterAndBuilder::new(fb);
+        builder.add_and(and_offset);
+        Ok(builder.finish())
+    }
+}
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+impl<'fb> FlatbuffersDeserializable<fb::EventFilterAnd<'fb>> for odf::event::EventFilterAnd {
+    fn deserialize(proxy: fb::EventFilterAnd<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::event::EventFilterAnd {
+            and: proxy
+                .and()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::event::EventFilter::deserialize(v, i.value_type())
+                                })
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("EventFilterAnd.$and"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterMatch
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Match
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::event::EventFilterMatch {
+    type OffsetT = WIPOffset<fb::EventFilterMatch<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = fb.create_string(&serde_json::to_string(value).unwrap());
-                let mut entry_builder = fb::EventFilterEntryBuilder::new(fb);
+                let value_offset = value.serialize(fb)?;
+                let mut entry_builder = fb::EventFilterMatchEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
-        let mut builder = fb::EventFilterBuilder::new(fb);
+        let mut builder = fb::EventFilterMatchBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::EventFilter<'fb>> for odf::event::EventFilter {
-    fn deserialize(proxy: fb::EventFilter<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
+impl<'fb> FlatbuffersDeserializable<fb::EventFilterMatch<'fb>> for odf::event::EventFilterMatch {
+    fn deserialize(proxy: fb::EventFilterMatch<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| odf::resource::FilterCondition::deserialize(v))?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("EventFilterMatch.entries"))?;
+        Ok(Self { entries })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterNot
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Not
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::event::EventFilterNot {
+    type OffsetT = WIPOffset<fb::EventFilterNot<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let not_offset = { self.not.serialize(fb)? };
+        let mut builder = fb::EventFilterNotBuilder::new(fb);
+        builder.add_not_type(not_offset.0);
+        builder.add_not(not_offset.1);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::EventFilterNot<'fb>> for odf::event::EventFilterNot {
+    fn deserialize(proxy: fb::EventFilterNot<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::event::EventFilterNot {
+            not: proxy
+                .not()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::event::EventFilter::deserialize(v, proxy.not_type()).map(Box::new)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("EventFilterNot.$not"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// EventFilterOr
+// Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Or
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::event::EventFilterOr {
+    type OffsetT = WIPOffset<fb::EventFilterOr<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let or_offset = {
+            let offsets = self
+                .or
                 .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = serde_json::from_str(entry.value().unwrap()).unwrap();
-                    (key, value)
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
+                    let mut builder = fb::EventFilterWrapperBuilder::new(fb);
+                    builder.add_value_type(value_type);
+                    builder.add_value(value_offset);
+                    Ok(builder.finish())
                 })
-                .collect(),
-        }
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let mut builder = fb::EventFilterOrBuilder::new(fb);
+        builder.add_or(or_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::EventFilterOr<'fb>> for odf::event::EventFilterOr {
+    fn deserialize(proxy: fb::EventFilterOr<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::event::EventFilterOr {
+            or: proxy
+                .or()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::event::EventFilter::deserialize(v, i.value_type())
+                                })
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("EventFilterOr.$or"))?,
+        })
     }
 }
 
@@ -1850,47 +3499,54 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::EventTimeSource> for odf::source:
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::EventTimeSource, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::EventTimeSource, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::source::EventTimeSource::FromMetadata(v) => (
+            odf::source::EventTimeSource::FromMetadata(v) => Ok((
                 fb::EventTimeSource::EventTimeSourceFromMetadata,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::EventTimeSource::FromPath(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::EventTimeSource::FromPath(v) => Ok((
                 fb::EventTimeSource::EventTimeSourceFromPath,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::EventTimeSource::FromSystemTime(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::EventTimeSource::FromSystemTime(v) => Ok((
                 fb::EventTimeSource::EventTimeSourceFromSystemTime,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::EventTimeSource> for odf::source::EventTimeSource {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::EventTimeSource) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::EventTimeSource,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::EventTimeSource::EventTimeSourceFromMetadata => {
-                odf::source::EventTimeSource::FromMetadata(
-                    odf::source::EventTimeSourceFromMetadata::deserialize(unsafe {
-                        fb::EventTimeSourceFromMetadata::init_from_table(table)
-                    }),
-                )
+                odf::source::EventTimeSourceFromMetadata::deserialize(unsafe {
+                    fb::EventTimeSourceFromMetadata::init_from_table(table)
+                })
+                .map(odf::source::EventTimeSource::FromMetadata)
             }
-            fb::EventTimeSource::EventTimeSourceFromPath => odf::source::EventTimeSource::FromPath(
+            fb::EventTimeSource::EventTimeSourceFromPath => {
                 odf::source::EventTimeSourceFromPath::deserialize(unsafe {
                     fb::EventTimeSourceFromPath::init_from_table(table)
-                }),
-            ),
+                })
+                .map(odf::source::EventTimeSource::FromPath)
+            }
             fb::EventTimeSource::EventTimeSourceFromSystemTime => {
-                odf::source::EventTimeSource::FromSystemTime(
-                    odf::source::EventTimeSourceFromSystemTime::deserialize(unsafe {
-                        fb::EventTimeSourceFromSystemTime::init_from_table(table)
-                    }),
-                )
+                odf::source::EventTimeSourceFromSystemTime::deserialize(unsafe {
+                    fb::EventTimeSourceFromSystemTime::init_from_table(table)
+                })
+                .map(odf::source::EventTimeSource::FromSystemTime)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "EventTimeSource",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -1903,17 +3559,22 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::EventTimeSource> for odf::sourc
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::EventTimeSourceFromMetadata {
     type OffsetT = WIPOffset<fb::EventTimeSourceFromMetadata<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::EventTimeSourceFromMetadataBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::EventTimeSourceFromMetadata<'fb>>
     for odf::source::EventTimeSourceFromMetadata
 {
-    fn deserialize(proxy: fb::EventTimeSourceFromMetadata<'fb>) -> Self {
-        odf::source::EventTimeSourceFromMetadata {}
+    fn deserialize(
+        proxy: fb::EventTimeSourceFromMetadata<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::EventTimeSourceFromMetadata {})
     }
 }
 
@@ -1925,24 +3586,42 @@ impl<'fb> FlatbuffersDeserializable<fb::EventTimeSourceFromMetadata<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::EventTimeSourceFromPath {
     type OffsetT = WIPOffset<fb::EventTimeSourceFromPath<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let pattern_offset = { fb.create_string(&self.pattern) };
-        let timestamp_format_offset = self.timestamp_format.as_ref().map(|v| fb.create_string(&v));
+        let timestamp_format_offset = match &self.timestamp_format {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::EventTimeSourceFromPathBuilder::new(fb);
         builder.add_pattern(pattern_offset);
         timestamp_format_offset.map(|off| builder.add_timestamp_format(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::EventTimeSourceFromPath<'fb>>
     for odf::source::EventTimeSourceFromPath
 {
-    fn deserialize(proxy: fb::EventTimeSourceFromPath<'fb>) -> Self {
-        odf::source::EventTimeSourceFromPath {
-            pattern: proxy.pattern().map(|v| v.to_owned()).unwrap(),
-            timestamp_format: proxy.timestamp_format().map(|v| v.to_owned()),
-        }
+    fn deserialize(
+        proxy: fb::EventTimeSourceFromPath<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::EventTimeSourceFromPath {
+            pattern: proxy
+                .pattern()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("EventTimeSourceFromPath.pattern"))?,
+            timestamp_format: proxy
+                .timestamp_format()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("EventTimeSourceFromPath.timestampFormat")
+                })?,
+        })
     }
 }
 
@@ -1954,17 +3633,22 @@ impl<'fb> FlatbuffersDeserializable<fb::EventTimeSourceFromPath<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::EventTimeSourceFromSystemTime {
     type OffsetT = WIPOffset<fb::EventTimeSourceFromSystemTime<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::EventTimeSourceFromSystemTimeBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::EventTimeSourceFromSystemTime<'fb>>
     for odf::source::EventTimeSourceFromSystemTime
 {
-    fn deserialize(proxy: fb::EventTimeSourceFromSystemTime<'fb>) -> Self {
-        odf::source::EventTimeSourceFromSystemTime {}
+    fn deserialize(
+        proxy: fb::EventTimeSourceFromSystemTime<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::EventTimeSourceFromSystemTime {})
     }
 }
 
@@ -1976,17 +3660,30 @@ impl<'fb> FlatbuffersDeserializable<fb::EventTimeSourceFromSystemTime<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::ExecuteTransform {
     type OffsetT = WIPOffset<fb::ExecuteTransform<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let query_inputs_offset = {
-            let offsets: Vec<_> = self.query_inputs.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .query_inputs
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let prev_checkpoint_offset = self
-            .prev_checkpoint
-            .as_ref()
-            .map(|v| fb.create_vector(&v.as_bytes().as_slice()));
-        let new_data_offset = self.new_data.as_ref().map(|v| v.serialize(fb));
-        let new_checkpoint_offset = self.new_checkpoint.as_ref().map(|v| v.serialize(fb));
+        let prev_checkpoint_offset = match &self.prev_checkpoint {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes().as_slice()) }),
+            None => None,
+        };
+        let new_data_offset = match &self.new_data {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let new_checkpoint_offset = match &self.new_checkpoint {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ExecuteTransformBuilder::new(fb);
         builder.add_query_inputs(query_inputs_offset);
         prev_checkpoint_offset.map(|off| builder.add_prev_checkpoint(off));
@@ -1995,33 +3692,51 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::ExecuteTransform {
         new_checkpoint_offset.map(|off| builder.add_new_checkpoint(off));
         self.new_watermark
             .map(|v| builder.add_new_watermark(&datetime_to_fb(&v)));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ExecuteTransform<'fb>> for odf::dataset::ExecuteTransform {
-    fn deserialize(proxy: fb::ExecuteTransform<'fb>) -> Self {
-        odf::dataset::ExecuteTransform {
+    fn deserialize(proxy: fb::ExecuteTransform<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::ExecuteTransform {
             query_inputs: proxy
                 .query_inputs()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::dataset::ExecuteTransformInput::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.queryInputs"))?,
             prev_checkpoint: proxy
                 .prev_checkpoint()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap()),
-            prev_offset: proxy.prev_offset().map(|v| v),
+                .map(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.prevCheckpoint"))?,
+            prev_offset: proxy
+                .prev_offset()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.prevOffset"))?,
             new_data: proxy
                 .new_data()
-                .map(|v| odf::dataset::DataSlice::deserialize(v)),
+                .map(|v| odf::dataset::DataSlice::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.newData"))?,
             new_checkpoint: proxy
                 .new_checkpoint()
-                .map(|v| odf::dataset::Checkpoint::deserialize(v)),
-            new_watermark: proxy.new_watermark().map(|v| fb_to_datetime(v)),
-        }
+                .map(|v| odf::dataset::Checkpoint::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.newCheckpoint"))?,
+            new_watermark: proxy
+                .new_watermark()
+                .map(|v| fb_to_datetime(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransform.newWatermark"))?,
+        })
     }
 }
 
@@ -2033,44 +3748,69 @@ impl<'fb> FlatbuffersDeserializable<fb::ExecuteTransform<'fb>> for odf::dataset:
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::ExecuteTransformInput {
     type OffsetT = WIPOffset<fb::ExecuteTransformInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let dataset_id_offset = { fb.create_vector(&self.dataset_id.as_bytes()) };
-        let prev_block_hash_offset = self
-            .prev_block_hash
-            .as_ref()
-            .map(|v| fb.create_vector(&v.as_bytes().as_slice()));
-        let new_block_hash_offset = self
-            .new_block_hash
-            .as_ref()
-            .map(|v| fb.create_vector(&v.as_bytes().as_slice()));
+        let prev_block_hash_offset = match &self.prev_block_hash {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes().as_slice()) }),
+            None => None,
+        };
+        let new_block_hash_offset = match &self.new_block_hash {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes().as_slice()) }),
+            None => None,
+        };
         let mut builder = fb::ExecuteTransformInputBuilder::new(fb);
         builder.add_dataset_id(dataset_id_offset);
         prev_block_hash_offset.map(|off| builder.add_prev_block_hash(off));
         new_block_hash_offset.map(|off| builder.add_new_block_hash(off));
         self.prev_offset.map(|v| builder.add_prev_offset(v));
         self.new_offset.map(|v| builder.add_new_offset(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ExecuteTransformInput<'fb>>
     for odf::dataset::ExecuteTransformInput
 {
-    fn deserialize(proxy: fb::ExecuteTransformInput<'fb>) -> Self {
-        odf::dataset::ExecuteTransformInput {
+    fn deserialize(proxy: fb::ExecuteTransformInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::ExecuteTransformInput {
             dataset_id: proxy
                 .dataset_id()
-                .map(|v| odf::dataset::DatasetID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.datasetId"))?,
             prev_block_hash: proxy
                 .prev_block_hash()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.prevBlockHash"))?,
             new_block_hash: proxy
                 .new_block_hash()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap()),
-            prev_offset: proxy.prev_offset().map(|v| v),
-            new_offset: proxy.new_offset().map(|v| v),
-        }
+                .map(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.newBlockHash"))?,
+            prev_offset: proxy
+                .prev_offset()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.prevOffset"))?,
+            new_offset: proxy
+                .new_offset()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ExecuteTransformInput.newOffset"))?,
+        })
     }
 }
 
@@ -2082,18 +3822,27 @@ impl<'fb> FlatbuffersDeserializable<fb::ExecuteTransformInput<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::data::ExtraAttributes {
     type OffsetT = WIPOffset<fb::ExtraAttributes<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries_offset = fb.create_string(&serde_json::to_string(&self.entries).unwrap());
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries_offset = fb.create_string(
+            &serde_json::to_string(&self.entries).map_err(FlatbuffersEncodeError::invalid_value)?,
+        );
         let mut builder = fb::ExtraAttributesBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ExtraAttributes<'fb>> for odf::data::ExtraAttributes {
-    fn deserialize(proxy: fb::ExtraAttributes<'fb>) -> Self {
-        let entries = serde_json::from_str(proxy.entries().unwrap()).unwrap();
-        Self { entries }
+    fn deserialize(proxy: fb::ExtraAttributes<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .ok_or_else(FlatbuffersDecodeError::missing_field)
+            .and_then(|v| serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value))
+            .map_err(|e| e.at("ExtraAttributes.entries"))?;
+        Ok(Self { entries })
     }
 }
 
@@ -2106,61 +3855,70 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::FetchStep> for odf::legacy::Fetch
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::FetchStep, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::FetchStep, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::legacy::FetchStep::Url(v) => (
+            odf::legacy::FetchStep::Url(v) => Ok((
                 fb::FetchStep::FetchStepUrl,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::legacy::FetchStep::FilesGlob(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::legacy::FetchStep::FilesGlob(v) => Ok((
                 fb::FetchStep::FetchStepFilesGlob,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::legacy::FetchStep::Container(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::legacy::FetchStep::Container(v) => Ok((
                 fb::FetchStep::FetchStepContainer,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::legacy::FetchStep::Mqtt(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::legacy::FetchStep::Mqtt(v) => Ok((
                 fb::FetchStep::FetchStepMqtt,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::legacy::FetchStep::EthereumLogs(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::legacy::FetchStep::EthereumLogs(v) => Ok((
                 fb::FetchStep::FetchStepEthereumLogs,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FetchStep> for odf::legacy::FetchStep {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::FetchStep) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::FetchStep,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
-            fb::FetchStep::FetchStepUrl => {
-                odf::legacy::FetchStep::Url(odf::legacy::FetchStepUrl::deserialize(unsafe {
-                    fb::FetchStepUrl::init_from_table(table)
-                }))
-            }
+            fb::FetchStep::FetchStepUrl => odf::legacy::FetchStepUrl::deserialize(unsafe {
+                fb::FetchStepUrl::init_from_table(table)
+            })
+            .map(odf::legacy::FetchStep::Url),
             fb::FetchStep::FetchStepFilesGlob => {
-                odf::legacy::FetchStep::FilesGlob(odf::legacy::FetchStepFilesGlob::deserialize(
-                    unsafe { fb::FetchStepFilesGlob::init_from_table(table) },
-                ))
+                odf::legacy::FetchStepFilesGlob::deserialize(unsafe {
+                    fb::FetchStepFilesGlob::init_from_table(table)
+                })
+                .map(odf::legacy::FetchStep::FilesGlob)
             }
             fb::FetchStep::FetchStepContainer => {
-                odf::legacy::FetchStep::Container(odf::legacy::FetchStepContainer::deserialize(
-                    unsafe { fb::FetchStepContainer::init_from_table(table) },
-                ))
-            }
-            fb::FetchStep::FetchStepMqtt => {
-                odf::legacy::FetchStep::Mqtt(odf::legacy::FetchStepMqtt::deserialize(unsafe {
-                    fb::FetchStepMqtt::init_from_table(table)
-                }))
+                odf::legacy::FetchStepContainer::deserialize(unsafe {
+                    fb::FetchStepContainer::init_from_table(table)
+                })
+                .map(odf::legacy::FetchStep::Container)
             }
-            fb::FetchStep::FetchStepEthereumLogs => odf::legacy::FetchStep::EthereumLogs(
+            fb::FetchStep::FetchStepMqtt => odf::legacy::FetchStepMqtt::deserialize(unsafe {
+                fb::FetchStepMqtt::init_from_table(table)
+            })
+            .map(odf::legacy::FetchStep::Mqtt),
+            fb::FetchStep::FetchStepEthereumLogs => {
                 odf::legacy::FetchStepEthereumLogs::deserialize(unsafe {
                     fb::FetchStepEthereumLogs::init_from_table(table)
-                }),
-            ),
-            _ => panic!("Invalid enum value: {}", t.0),
+                })
+                .map(odf::legacy::FetchStep::EthereumLogs)
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "FetchStep",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -2173,47 +3931,80 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FetchStep> for odf::legacy::Fet
 impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepContainer {
     type OffsetT = WIPOffset<fb::FetchStepContainer<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let image_offset = { fb.create_string(&self.image) };
-        let command_offset = self.command.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let args_offset = self.args.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let env_offset = self.env.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
+        let command_offset = match &self.command {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let args_offset = match &self.args {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let env_offset = match &self.env {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::FetchStepContainerBuilder::new(fb);
         builder.add_image(image_offset);
         command_offset.map(|off| builder.add_command(off));
         args_offset.map(|off| builder.add_args(off));
         env_offset.map(|off| builder.add_env(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FetchStepContainer<'fb>>
     for odf::legacy::FetchStepContainer
 {
-    fn deserialize(proxy: fb::FetchStepContainer<'fb>) -> Self {
-        odf::legacy::FetchStepContainer {
-            image: proxy.image().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::FetchStepContainer<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::FetchStepContainer {
+            image: proxy
+                .image()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.image"))?,
             command: proxy
                 .command()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.command"))?,
             args: proxy
                 .args()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-            env: proxy.env().map(|v| {
-                v.iter()
-                    .map(|i| odf::source::EnvVar::deserialize(i))
-                    .collect()
-            }),
-        }
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.args"))?,
+            env: proxy
+                .env()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::source::EnvVar::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepContainer.env"))?,
+        })
     }
 }
 
@@ -2225,29 +4016,57 @@ impl<'fb> FlatbuffersDeserializable<fb::FetchStepContainer<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepEthereumLogs {
     type OffsetT = WIPOffset<fb::FetchStepEthereumLogs<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let node_url_offset = self.node_url.as_ref().map(|v| fb.create_string(&v));
-        let filter_offset = self.filter.as_ref().map(|v| fb.create_string(&v));
-        let signature_offset = self.signature.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let node_url_offset = match &self.node_url {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let filter_offset = match &self.filter {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let signature_offset = match &self.signature {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::FetchStepEthereumLogsBuilder::new(fb);
         self.chain_id.map(|v| builder.add_chain_id(v));
         node_url_offset.map(|off| builder.add_node_url(off));
         filter_offset.map(|off| builder.add_filter(off));
         signature_offset.map(|off| builder.add_signature(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FetchStepEthereumLogs<'fb>>
     for odf::legacy::FetchStepEthereumLogs
 {
-    fn deserialize(proxy: fb::FetchStepEthereumLogs<'fb>) -> Self {
-        odf::legacy::FetchStepEthereumLogs {
-            chain_id: proxy.chain_id().map(|v| v),
-            node_url: proxy.node_url().map(|v| v.to_owned()),
-            filter: proxy.filter().map(|v| v.to_owned()),
-            signature: proxy.signature().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::FetchStepEthereumLogs<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::FetchStepEthereumLogs {
+            chain_id: proxy
+                .chain_id()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.chainId"))?,
+            node_url: proxy
+                .node_url()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.nodeUrl"))?,
+            filter: proxy
+                .filter()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.filter"))?,
+            signature: proxy
+                .signature()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepEthereumLogs.signature"))?,
+        })
     }
 }
 
@@ -2259,10 +4078,19 @@ impl<'fb> FlatbuffersDeserializable<fb::FetchStepEthereumLogs<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepFilesGlob {
     type OffsetT = WIPOffset<fb::FetchStepFilesGlob<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let path_offset = { fb.create_string(&self.path) };
-        let event_time_offset = self.event_time.as_ref().map(|v| v.serialize(fb));
-        let cache_offset = self.cache.as_ref().map(|v| v.serialize(fb));
+        let event_time_offset = match &self.event_time {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let cache_offset = match &self.cache {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::FetchStepFilesGlobBuilder::new(fb);
         builder.add_path(path_offset);
         event_time_offset.map(|(e, off)| {
@@ -2273,25 +4101,39 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepFilesGlob {
             builder.add_cache_type(e);
             builder.add_cache(off)
         });
-        self.order.map(|v| builder.add_order(v.into()));
-        builder.finish()
+        if let Some(v) = &self.order {
+            builder.add_order(fb::SourceOrdering::try_from(v)?);
+        }
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FetchStepFilesGlob<'fb>>
     for odf::legacy::FetchStepFilesGlob
 {
-    fn deserialize(proxy: fb::FetchStepFilesGlob<'fb>) -> Self {
-        odf::legacy::FetchStepFilesGlob {
-            path: proxy.path().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::FetchStepFilesGlob<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::FetchStepFilesGlob {
+            path: proxy
+                .path()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.path"))?,
             event_time: proxy
                 .event_time()
-                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type())),
+                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.eventTime"))?,
             cache: proxy
                 .cache()
-                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type())),
-            order: proxy.order().map(|v| v.into()),
-        }
+                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.cache"))?,
+            order: proxy
+                .order()
+                .map(|v| odf::source::SourceOrdering::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepFilesGlob.order"))?,
+        })
     }
 }
 
@@ -2303,12 +4145,25 @@ impl<'fb> FlatbuffersDeserializable<fb::FetchStepFilesGlob<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepMqtt {
     type OffsetT = WIPOffset<fb::FetchStepMqtt<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let host_offset = { fb.create_string(&self.host) };
-        let username_offset = self.username.as_ref().map(|v| fb.create_string(&v));
-        let password_offset = self.password.as_ref().map(|v| fb.create_string(&v));
+        let username_offset = match &self.username {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let password_offset = match &self.password {
+            Some(v) => Some({ fb.create_string(&v.expose()) }),
+            None => None,
+        };
         let topics_offset = {
-            let offsets: Vec<_> = self.topics.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .topics
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::FetchStepMqttBuilder::new(fb);
@@ -2317,26 +4172,40 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepMqtt {
         username_offset.map(|off| builder.add_username(off));
         password_offset.map(|off| builder.add_password(off));
         builder.add_topics(topics_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FetchStepMqtt<'fb>> for odf::legacy::FetchStepMqtt {
-    fn deserialize(proxy: fb::FetchStepMqtt<'fb>) -> Self {
-        odf::legacy::FetchStepMqtt {
-            host: proxy.host().map(|v| v.to_owned()).unwrap(),
-            port: proxy.port(),
-            username: proxy.username().map(|v| v.to_owned()),
-            password: proxy.password().map(|v| v.to_owned()),
+    fn deserialize(proxy: fb::FetchStepMqtt<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::FetchStepMqtt {
+            host: proxy
+                .host()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.host"))?,
+            port: Ok(proxy.port())
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.port"))?,
+            username: proxy
+                .username()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.username"))?,
+            password: proxy
+                .password()
+                .map(|v| Ok(v.to_owned()).map(odf::dtos::Sensitive::new))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.password"))?,
             topics: proxy
                 .topics()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::source::MqttTopicSubscription::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepMqtt.topics"))?,
+        })
     }
 }
 
@@ -2348,14 +4217,29 @@ impl<'fb> FlatbuffersDeserializable<fb::FetchStepMqtt<'fb>> for odf::legacy::Fet
 impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepUrl {
     type OffsetT = WIPOffset<fb::FetchStepUrl<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let url_offset = { fb.create_string(&self.url) };
-        let event_time_offset = self.event_time.as_ref().map(|v| v.serialize(fb));
-        let cache_offset = self.cache.as_ref().map(|v| v.serialize(fb));
-        let headers_offset = self.headers.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
+        let event_time_offset = match &self.event_time {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let cache_offset = match &self.cache {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let headers_offset = match &self.headers {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::FetchStepUrlBuilder::new(fb);
         builder.add_url(url_offset);
         event_time_offset.map(|(e, off)| {
@@ -2367,26 +4251,121 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::FetchStepUrl {
             builder.add_cache(off)
         });
         headers_offset.map(|off| builder.add_headers(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FetchStepUrl<'fb>> for odf::legacy::FetchStepUrl {
-    fn deserialize(proxy: fb::FetchStepUrl<'fb>) -> Self {
-        odf::legacy::FetchStepUrl {
-            url: proxy.url().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::FetchStepUrl<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::FetchStepUrl {
+            url: proxy
+                .url()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.url"))?,
             event_time: proxy
                 .event_time()
-                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type())),
+                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.eventTime"))?,
             cache: proxy
                 .cache()
-                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type())),
-            headers: proxy.headers().map(|v| {
-                v.iter()
-                    .map(|i| odf::source::RequestHeader::deserialize(i))
-                    .collect()
+                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.cache"))?,
+            headers: proxy
+                .headers()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::source::RequestHeader::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FetchStepUrl.headers"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// FilterCondition
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/FilterCondition
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::FilterCondition {
+    type OffsetT = WIPOffset<fb::FilterCondition<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let eq_offset = match &self.eq {
+            Some(v) => Some({
+                fb.create_string(
+                    &serde_json::to_string(&v).map_err(FlatbuffersEncodeError::invalid_value)?,
+                )
             }),
-        }
+            None => None,
+        };
+        let r#in_offset = match &self.r#in {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                        Ok({
+                            fb.create_string(
+                                &serde_json::to_string(&i)
+                                    .map_err(FlatbuffersEncodeError::invalid_value)?,
+                            )
+                        })
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let glob_offset = match &self.glob {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let mut builder = fb::FilterConditionBuilder::new(fb);
+        eq_offset.map(|off| builder.add_eq(off));
+        in_offset.map(|off| builder.add_in_(off));
+        self.exists.map(|v| builder.add_exists(v));
+        glob_offset.map(|off| builder.add_glob(off));
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::FilterCondition<'fb>> for odf::resource::FilterCondition {
+    fn deserialize(proxy: fb::FilterCondition<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::FilterCondition {
+            eq: proxy
+                .eq()
+                .map(|v| serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$eq"))?,
+            r#in: proxy
+                .in_()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| {
+                            serde_json::from_str(i).map_err(FlatbuffersDecodeError::invalid_value)
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$in"))?,
+            exists: proxy
+                .exists()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$exists"))?,
+            glob: proxy
+                .glob()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FilterCondition.$glob"))?,
+        })
     }
 }
 
@@ -2398,72 +4377,84 @@ impl<'fb> FlatbuffersDeserializable<fb::FetchStepUrl<'fb>> for odf::legacy::Fetc
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowSpec {
     type OffsetT = WIPOffset<fb::FlowSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let target_offset = { self.target.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let target_offset = { self.target.serialize(fb)? };
         let triggers_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .triggers
                 .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
                     let mut builder = fb::FlowTriggerWrapperBuilder::new(fb);
                     builder.add_value_type(value_type);
                     builder.add_value(value_offset);
-                    builder.finish()
+                    Ok(builder.finish())
                 })
-                .collect();
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let tasks_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .tasks
                 .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
                     let mut builder = fb::TaskSpecWrapperBuilder::new(fb);
                     builder.add_value_type(value_type);
                     builder.add_value(value_offset);
-                    builder.finish()
+                    Ok(builder.finish())
                 })
-                .collect();
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::FlowSpecBuilder::new(fb);
         builder.add_target(target_offset);
         builder.add_triggers(triggers_offset);
         builder.add_tasks(tasks_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowSpec<'fb>> for odf::flow::FlowSpec {
-    fn deserialize(proxy: fb::FlowSpec<'fb>) -> Self {
-        odf::flow::FlowSpec {
+    fn deserialize(proxy: fb::FlowSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowSpec {
             target: proxy
                 .target()
-                .map(|v| odf::resource::ResourceSelector::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceSelector::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpec.target"))?,
             triggers: proxy
                 .triggers()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| {
-                            odf::flow::FlowTrigger::deserialize(i.value().unwrap(), i.value_type())
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::flow::FlowTrigger::deserialize(v, i.value_type())
+                                })
                         })
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpec.triggers"))?,
             tasks: proxy
                 .tasks()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| {
-                            odf::flow::TaskSpec::deserialize(i.value().unwrap(), i.value_type())
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| odf::flow::TaskSpec::deserialize(v, i.value_type()))
                         })
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpec.tasks"))?,
+        })
     }
 }
 
@@ -2475,78 +4466,86 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowSpec<'fb>> for odf::flow::FlowSpec {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowSpecInput {
     type OffsetT = WIPOffset<fb::FlowSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let target_offset = { self.target.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let target_offset = { self.target.serialize(fb)? };
         let triggers_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .triggers
                 .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
                     let mut builder = fb::FlowTriggerInputWrapperBuilder::new(fb);
                     builder.add_value_type(value_type);
                     builder.add_value(value_offset);
-                    builder.finish()
+                    Ok(builder.finish())
                 })
-                .collect();
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let tasks_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .tasks
                 .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
                     let mut builder = fb::TaskSpecInputWrapperBuilder::new(fb);
                     builder.add_value_type(value_type);
                     builder.add_value(value_offset);
-                    builder.finish()
+                    Ok(builder.finish())
                 })
-                .collect();
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::FlowSpecInputBuilder::new(fb);
         builder.add_target(target_offset);
         builder.add_triggers(triggers_offset);
         builder.add_tasks(tasks_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowSpecInput<'fb>> for odf::flow::FlowSpecInput {
-    fn deserialize(proxy: fb::FlowSpecInput<'fb>) -> Self {
-        odf::flow::FlowSpecInput {
+    fn deserialize(proxy: fb::FlowSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowSpecInput {
             target: proxy
                 .target()
-                .map(|v| odf::resource::ResourceSelector::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceSelector::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpecInput.target"))?,
             triggers: proxy
                 .triggers()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| {
-                            odf::flow::FlowTriggerInput::deserialize(
-                                i.value().unwrap(),
-                                i.value_type(),
-                            )
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::flow::FlowTriggerInput::deserialize(v, i.value_type())
+                                })
                         })
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpecInput.triggers"))?,
             tasks: proxy
                 .tasks()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| {
-                            odf::flow::TaskSpecInput::deserialize(
-                                i.value().unwrap(),
-                                i.value_type(),
-                            )
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::flow::TaskSpecInput::deserialize(v, i.value_type())
+                                })
                         })
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowSpecInput.tasks"))?,
+        })
     }
 }
 
@@ -2559,52 +4558,62 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::FlowTrigger> for odf::flow::FlowT
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::FlowTrigger, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::FlowTrigger, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::flow::FlowTrigger::Schedule(v) => (
+            odf::flow::FlowTrigger::Schedule(v) => Ok((
                 fb::FlowTrigger::FlowTriggerSchedule,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::FlowTrigger::Event(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::FlowTrigger::Event(v) => Ok((
                 fb::FlowTrigger::FlowTriggerEvent,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::FlowTrigger::Source(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::FlowTrigger::Source(v) => Ok((
                 fb::FlowTrigger::FlowTriggerSource,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::FlowTrigger::Dataset(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::FlowTrigger::Dataset(v) => Ok((
                 fb::FlowTrigger::FlowTriggerDataset,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FlowTrigger> for odf::flow::FlowTrigger {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::FlowTrigger) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::FlowTrigger,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::FlowTrigger::FlowTriggerSchedule => {
-                odf::flow::FlowTrigger::Schedule(odf::flow::FlowTriggerSchedule::deserialize(
-                    unsafe { fb::FlowTriggerSchedule::init_from_table(table) },
-                ))
-            }
-            fb::FlowTrigger::FlowTriggerEvent => {
-                odf::flow::FlowTrigger::Event(odf::flow::FlowTriggerEvent::deserialize(unsafe {
-                    fb::FlowTriggerEvent::init_from_table(table)
-                }))
+                odf::flow::FlowTriggerSchedule::deserialize(unsafe {
+                    fb::FlowTriggerSchedule::init_from_table(table)
+                })
+                .map(odf::flow::FlowTrigger::Schedule)
             }
+            fb::FlowTrigger::FlowTriggerEvent => odf::flow::FlowTriggerEvent::deserialize(unsafe {
+                fb::FlowTriggerEvent::init_from_table(table)
+            })
+            .map(odf::flow::FlowTrigger::Event),
             fb::FlowTrigger::FlowTriggerSource => {
-                odf::flow::FlowTrigger::Source(odf::flow::FlowTriggerSource::deserialize(unsafe {
+                odf::flow::FlowTriggerSource::deserialize(unsafe {
                     fb::FlowTriggerSource::init_from_table(table)
-                }))
+                })
+                .map(odf::flow::FlowTrigger::Source)
             }
             fb::FlowTrigger::FlowTriggerDataset => {
-                odf::flow::FlowTrigger::Dataset(odf::flow::FlowTriggerDataset::deserialize(
-                    unsafe { fb::FlowTriggerDataset::init_from_table(table) },
-                ))
+                odf::flow::FlowTriggerDataset::deserialize(unsafe {
+                    fb::FlowTriggerDataset::init_from_table(table)
+                })
+                .map(odf::flow::FlowTrigger::Dataset)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "FlowTrigger",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -2617,30 +4626,42 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FlowTrigger> for odf::flow::Flo
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerDataset {
     type OffsetT = WIPOffset<fb::FlowTriggerDataset<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let dataset_offset = { self.dataset.serialize(fb) };
-        let events_offset = self.events.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let dataset_offset = { self.dataset.serialize(fb)? };
+        let events_offset = match &self.events {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::FlowTriggerDatasetBuilder::new(fb);
         builder.add_dataset(dataset_offset);
         events_offset.map(|off| builder.add_events(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerDataset<'fb>> for odf::flow::FlowTriggerDataset {
-    fn deserialize(proxy: fb::FlowTriggerDataset<'fb>) -> Self {
-        odf::flow::FlowTriggerDataset {
+    fn deserialize(proxy: fb::FlowTriggerDataset<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerDataset {
             dataset: proxy
                 .dataset()
-                .map(|v| odf::dataset::DatasetSelector::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::DatasetSelector::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerDataset.dataset"))?,
             events: proxy
                 .events()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-        }
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerDataset.events"))?,
+        })
     }
 }
 
@@ -2652,28 +4673,41 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerDataset<'fb>> for odf::flow::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerEvent {
     type OffsetT = WIPOffset<fb::FlowTriggerEvent<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let events_offset = { self.events.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let events_offset = { self.events.serialize(fb)? };
         let mut builder = fb::FlowTriggerEventBuilder::new(fb);
-        builder.add_events(events_offset);
+        builder.add_events_type(events_offset.0);
+        builder.add_events(events_offset.1);
         self.cooldown
             .map(|v| builder.add_cooldown(&duration_to_fb(&v)));
         self.cooldown_max_batch
             .map(|v| builder.add_cooldown_max_batch(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerEvent<'fb>> for odf::flow::FlowTriggerEvent {
-    fn deserialize(proxy: fb::FlowTriggerEvent<'fb>) -> Self {
-        odf::flow::FlowTriggerEvent {
+    fn deserialize(proxy: fb::FlowTriggerEvent<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerEvent {
             events: proxy
                 .events()
-                .map(|v| odf::event::EventFilter::deserialize(v))
-                .unwrap(),
-            cooldown: proxy.cooldown().map(|v| fb_to_duration(v)),
-            cooldown_max_batch: proxy.cooldown_max_batch().map(|v| v),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::event::EventFilter::deserialize(v, proxy.events_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerEvent.events"))?,
+            cooldown: proxy
+                .cooldown()
+                .map(|v| Ok(fb_to_duration(v)))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerEvent.cooldown"))?,
+            cooldown_max_batch: proxy
+                .cooldown_max_batch()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerEvent.cooldownMaxBatch"))?,
+        })
     }
 }
 
@@ -2685,21 +4719,28 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerEvent<'fb>> for odf::flow::Fl
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerSchedule {
     type OffsetT = WIPOffset<fb::FlowTriggerSchedule<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let cron_offset = { fb.create_string(&self.cron) };
         let mut builder = fb::FlowTriggerScheduleBuilder::new(fb);
         builder.add_cron(cron_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerSchedule<'fb>>
     for odf::flow::FlowTriggerSchedule
 {
-    fn deserialize(proxy: fb::FlowTriggerSchedule<'fb>) -> Self {
-        odf::flow::FlowTriggerSchedule {
-            cron: proxy.cron().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(proxy: fb::FlowTriggerSchedule<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerSchedule {
+            cron: proxy
+                .cron()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSchedule.cron"))?,
+        })
     }
 }
 
@@ -2711,28 +4752,40 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerSchedule<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerSource {
     type OffsetT = WIPOffset<fb::FlowTriggerSource<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let source_offset = { self.source.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let source_offset = { self.source.serialize(fb)? };
         let mut builder = fb::FlowTriggerSourceBuilder::new(fb);
         builder.add_source(source_offset);
         self.min_records_to_await
             .map(|v| builder.add_min_records_to_await(v));
         self.max_await_interval
             .map(|v| builder.add_max_await_interval(&duration_to_fb(&v)));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerSource<'fb>> for odf::flow::FlowTriggerSource {
-    fn deserialize(proxy: fb::FlowTriggerSource<'fb>) -> Self {
-        odf::flow::FlowTriggerSource {
+    fn deserialize(proxy: fb::FlowTriggerSource<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerSource {
             source: proxy
                 .source()
-                .map(|v| odf::resource::ResourceHandle::deserialize(v))
-                .unwrap(),
-            min_records_to_await: proxy.min_records_to_await().map(|v| v),
-            max_await_interval: proxy.max_await_interval().map(|v| fb_to_duration(v)),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSource.source"))?,
+            min_records_to_await: proxy
+                .min_records_to_await()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSource.minRecordsToAwait"))?,
+            max_await_interval: proxy
+                .max_await_interval()
+                .map(|v| Ok(fb_to_duration(v)))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerSource.maxAwaitInterval"))?,
+        })
     }
 }
 
@@ -2745,54 +4798,64 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::FlowTriggerInput> for odf::flow::
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::FlowTriggerInput, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::FlowTriggerInput, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::flow::FlowTriggerInput::Schedule(v) => (
+            odf::flow::FlowTriggerInput::Schedule(v) => Ok((
                 fb::FlowTriggerInput::FlowTriggerInputSchedule,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::FlowTriggerInput::Event(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::FlowTriggerInput::Event(v) => Ok((
                 fb::FlowTriggerInput::FlowTriggerInputEvent,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::FlowTriggerInput::Source(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::FlowTriggerInput::Source(v) => Ok((
                 fb::FlowTriggerInput::FlowTriggerInputSource,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::FlowTriggerInput::Dataset(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::FlowTriggerInput::Dataset(v) => Ok((
                 fb::FlowTriggerInput::FlowTriggerInputDataset,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FlowTriggerInput> for odf::flow::FlowTriggerInput {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::FlowTriggerInput) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::FlowTriggerInput,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::FlowTriggerInput::FlowTriggerInputSchedule => {
-                odf::flow::FlowTriggerInput::Schedule(
-                    odf::flow::FlowTriggerInputSchedule::deserialize(unsafe {
-                        fb::FlowTriggerInputSchedule::init_from_table(table)
-                    }),
-                )
+                odf::flow::FlowTriggerInputSchedule::deserialize(unsafe {
+                    fb::FlowTriggerInputSchedule::init_from_table(table)
+                })
+                .map(odf::flow::FlowTriggerInput::Schedule)
             }
             fb::FlowTriggerInput::FlowTriggerInputEvent => {
-                odf::flow::FlowTriggerInput::Event(odf::flow::FlowTriggerInputEvent::deserialize(
-                    unsafe { fb::FlowTriggerInputEvent::init_from_table(table) },
-                ))
+                odf::flow::FlowTriggerInputEvent::deserialize(unsafe {
+                    fb::FlowTriggerInputEvent::init_from_table(table)
+                })
+                .map(odf::flow::FlowTriggerInput::Event)
             }
             fb::FlowTriggerInput::FlowTriggerInputSource => {
-                odf::flow::FlowTriggerInput::Source(odf::flow::FlowTriggerInputSource::deserialize(
-                    unsafe { fb::FlowTriggerInputSource::init_from_table(table) },
-                ))
+                odf::flow::FlowTriggerInputSource::deserialize(unsafe {
+                    fb::FlowTriggerInputSource::init_from_table(table)
+                })
+                .map(odf::flow::FlowTriggerInput::Source)
             }
-            fb::FlowTriggerInput::FlowTriggerInputDataset => odf::flow::FlowTriggerInput::Dataset(
+            fb::FlowTriggerInput::FlowTriggerInputDataset => {
                 odf::flow::FlowTriggerInputDataset::deserialize(unsafe {
                     fb::FlowTriggerInputDataset::init_from_table(table)
-                }),
-            ),
-            _ => panic!("Invalid enum value: {}", t.0),
+                })
+                .map(odf::flow::FlowTriggerInput::Dataset)
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "FlowTriggerInput",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -2805,32 +4868,46 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::FlowTriggerInput> for odf::flow
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerInputDataset {
     type OffsetT = WIPOffset<fb::FlowTriggerInputDataset<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let dataset_offset = { self.dataset.serialize(fb) };
-        let events_offset = self.events.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let dataset_offset = { self.dataset.serialize(fb)? };
+        let events_offset = match &self.events {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::FlowTriggerInputDatasetBuilder::new(fb);
         builder.add_dataset(dataset_offset);
         events_offset.map(|off| builder.add_events(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputDataset<'fb>>
     for odf::flow::FlowTriggerInputDataset
 {
-    fn deserialize(proxy: fb::FlowTriggerInputDataset<'fb>) -> Self {
-        odf::flow::FlowTriggerInputDataset {
+    fn deserialize(
+        proxy: fb::FlowTriggerInputDataset<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerInputDataset {
             dataset: proxy
                 .dataset()
-                .map(|v| odf::dataset::DatasetSelector::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::DatasetSelector::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputDataset.dataset"))?,
             events: proxy
                 .events()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-        }
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputDataset.events"))?,
+        })
     }
 }
 
@@ -2842,30 +4919,45 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputDataset<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerInputEvent {
     type OffsetT = WIPOffset<fb::FlowTriggerInputEvent<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let events_offset = { self.events.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let events_offset = { self.events.serialize(fb)? };
         let mut builder = fb::FlowTriggerInputEventBuilder::new(fb);
-        builder.add_events(events_offset);
+        builder.add_events_type(events_offset.0);
+        builder.add_events(events_offset.1);
         self.cooldown
             .map(|v| builder.add_cooldown(&duration_to_fb(&v)));
         self.cooldown_max_batch
             .map(|v| builder.add_cooldown_max_batch(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputEvent<'fb>>
     for odf::flow::FlowTriggerInputEvent
 {
-    fn deserialize(proxy: fb::FlowTriggerInputEvent<'fb>) -> Self {
-        odf::flow::FlowTriggerInputEvent {
+    fn deserialize(proxy: fb::FlowTriggerInputEvent<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerInputEvent {
             events: proxy
                 .events()
-                .map(|v| odf::event::EventFilter::deserialize(v))
-                .unwrap(),
-            cooldown: proxy.cooldown().map(|v| fb_to_duration(v)),
-            cooldown_max_batch: proxy.cooldown_max_batch().map(|v| v),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::event::EventFilter::deserialize(v, proxy.events_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputEvent.events"))?,
+            cooldown: proxy
+                .cooldown()
+                .map(|v| Ok(fb_to_duration(v)))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputEvent.cooldown"))?,
+            cooldown_max_batch: proxy
+                .cooldown_max_batch()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("FlowTriggerInputEvent.cooldownMaxBatch")
+                })?,
+        })
     }
 }
 
@@ -2877,21 +4969,30 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputEvent<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerInputSchedule {
     type OffsetT = WIPOffset<fb::FlowTriggerInputSchedule<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let cron_offset = { fb.create_string(&self.cron) };
         let mut builder = fb::FlowTriggerInputScheduleBuilder::new(fb);
         builder.add_cron(cron_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputSchedule<'fb>>
     for odf::flow::FlowTriggerInputSchedule
 {
-    fn deserialize(proxy: fb::FlowTriggerInputSchedule<'fb>) -> Self {
-        odf::flow::FlowTriggerInputSchedule {
-            cron: proxy.cron().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(
+        proxy: fb::FlowTriggerInputSchedule<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerInputSchedule {
+            cron: proxy
+                .cron()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputSchedule.cron"))?,
+        })
     }
 }
 
@@ -2903,30 +5004,46 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputSchedule<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::FlowTriggerInputSource {
     type OffsetT = WIPOffset<fb::FlowTriggerInputSource<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let source_offset = { self.source.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let source_offset = { self.source.serialize(fb)? };
         let mut builder = fb::FlowTriggerInputSourceBuilder::new(fb);
         builder.add_source(source_offset);
         self.min_records_to_await
             .map(|v| builder.add_min_records_to_await(v));
         self.max_await_interval
             .map(|v| builder.add_max_await_interval(&duration_to_fb(&v)));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputSource<'fb>>
     for odf::flow::FlowTriggerInputSource
 {
-    fn deserialize(proxy: fb::FlowTriggerInputSource<'fb>) -> Self {
-        odf::flow::FlowTriggerInputSource {
+    fn deserialize(proxy: fb::FlowTriggerInputSource<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::FlowTriggerInputSource {
             source: proxy
                 .source()
-                .map(|v| odf::resource::ResourceRef::deserialize(v))
-                .unwrap(),
-            min_records_to_await: proxy.min_records_to_await().map(|v| v),
-            max_await_interval: proxy.max_await_interval().map(|v| fb_to_duration(v)),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceRef::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("FlowTriggerInputSource.source"))?,
+            min_records_to_await: proxy
+                .min_records_to_await()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("FlowTriggerInputSource.minRecordsToAwait")
+                })?,
+            max_await_interval: proxy
+                .max_await_interval()
+                .map(|v| Ok(fb_to_duration(v)))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("FlowTriggerInputSource.maxAwaitInterval")
+                })?,
+        })
     }
 }
 
@@ -2938,19 +5055,26 @@ impl<'fb> FlatbuffersDeserializable<fb::FlowTriggerInputSource<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngestParams {
     type OffsetT = WIPOffset<fb::IngestParams<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::IngestParamsBuilder::new(fb);
         self.target_slice_records
             .map(|v| builder.add_target_slice_records(v));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngestParams<'fb>> for odf::source::IngestParams {
-    fn deserialize(proxy: fb::IngestParams<'fb>) -> Self {
-        odf::source::IngestParams {
-            target_slice_records: proxy.target_slice_records().map(|v| v),
-        }
+    fn deserialize(proxy: fb::IngestParams<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngestParams {
+            target_slice_records: proxy
+                .target_slice_records()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngestParams.targetSliceRecords"))?,
+        })
     }
 }
 
@@ -2963,68 +5087,72 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::Ingress> for odf::source::Ingress
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::Ingress, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::Ingress, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
             odf::source::Ingress::Url(v) => {
-                (fb::Ingress::IngressUrl, v.serialize(fb).as_union_value())
+                Ok((fb::Ingress::IngressUrl, v.serialize(fb)?.as_union_value()))
             }
-            odf::source::Ingress::FilesGlob(v) => (
+            odf::source::Ingress::FilesGlob(v) => Ok((
                 fb::Ingress::IngressFilesGlob,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::Ingress::Container(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::Ingress::Container(v) => Ok((
                 fb::Ingress::IngressContainer,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
             odf::source::Ingress::Mqtt(v) => {
-                (fb::Ingress::IngressMqtt, v.serialize(fb).as_union_value())
+                Ok((fb::Ingress::IngressMqtt, v.serialize(fb)?.as_union_value()))
             }
-            odf::source::Ingress::EvmLogs(v) => (
+            odf::source::Ingress::EvmLogs(v) => Ok((
                 fb::Ingress::IngressEvmLogs,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::Ingress::RestEndpoint(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::Ingress::RestEndpoint(v) => Ok((
                 fb::Ingress::IngressRestEndpoint,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::Ingress> for odf::source::Ingress {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::Ingress) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::Ingress,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
-            fb::Ingress::IngressUrl => {
-                odf::source::Ingress::Url(odf::source::IngressUrl::deserialize(unsafe {
-                    fb::IngressUrl::init_from_table(table)
-                }))
-            }
-            fb::Ingress::IngressFilesGlob => {
-                odf::source::Ingress::FilesGlob(odf::source::IngressFilesGlob::deserialize(
-                    unsafe { fb::IngressFilesGlob::init_from_table(table) },
-                ))
-            }
-            fb::Ingress::IngressContainer => {
-                odf::source::Ingress::Container(odf::source::IngressContainer::deserialize(
-                    unsafe { fb::IngressContainer::init_from_table(table) },
-                ))
-            }
-            fb::Ingress::IngressMqtt => {
-                odf::source::Ingress::Mqtt(odf::source::IngressMqtt::deserialize(unsafe {
-                    fb::IngressMqtt::init_from_table(table)
-                }))
-            }
-            fb::Ingress::IngressEvmLogs => {
-                odf::source::Ingress::EvmLogs(odf::source::IngressEvmLogs::deserialize(unsafe {
-                    fb::IngressEvmLogs::init_from_table(table)
-                }))
-            }
+            fb::Ingress::IngressUrl => odf::source::IngressUrl::deserialize(unsafe {
+                fb::IngressUrl::init_from_table(table)
+            })
+            .map(odf::source::Ingress::Url),
+            fb::Ingress::IngressFilesGlob => odf::source::IngressFilesGlob::deserialize(unsafe {
+                fb::IngressFilesGlob::init_from_table(table)
+            })
+            .map(odf::source::Ingress::FilesGlob),
+            fb::Ingress::IngressContainer => odf::source::IngressContainer::deserialize(unsafe {
+                fb::IngressContainer::init_from_table(table)
+            })
+            .map(odf::source::Ingress::Container),
+            fb::Ingress::IngressMqtt => odf::source::IngressMqtt::deserialize(unsafe {
+                fb::IngressMqtt::init_from_table(table)
+            })
+            .map(odf::source::Ingress::Mqtt),
+            fb::Ingress::IngressEvmLogs => odf::source::IngressEvmLogs::deserialize(unsafe {
+                fb::IngressEvmLogs::init_from_table(table)
+            })
+            .map(odf::source::Ingress::EvmLogs),
             fb::Ingress::IngressRestEndpoint => {
-                odf::source::Ingress::RestEndpoint(odf::source::IngressRestEndpoint::deserialize(
-                    unsafe { fb::IngressRestEndpoint::init_from_table(table) },
-                ))
+                odf::source::IngressRestEndpoint::deserialize(unsafe {
+                    fb::IngressRestEndpoint::init_from_table(table)
+                })
+                .map(odf::source::Ingress::RestEndpoint)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "Ingress",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -3037,45 +5165,78 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::Ingress> for odf::source::Ingre
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressContainer {
     type OffsetT = WIPOffset<fb::IngressContainer<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let image_offset = { fb.create_string(&self.image) };
-        let command_offset = self.command.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let args_offset = self.args.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let env_offset = self.env.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
+        let command_offset = match &self.command {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let args_offset = match &self.args {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let env_offset = match &self.env {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::IngressContainerBuilder::new(fb);
         builder.add_image(image_offset);
         command_offset.map(|off| builder.add_command(off));
         args_offset.map(|off| builder.add_args(off));
         env_offset.map(|off| builder.add_env(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngressContainer<'fb>> for odf::source::IngressContainer {
-    fn deserialize(proxy: fb::IngressContainer<'fb>) -> Self {
-        odf::source::IngressContainer {
-            image: proxy.image().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::IngressContainer<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngressContainer {
+            image: proxy
+                .image()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.image"))?,
             command: proxy
                 .command()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.command"))?,
             args: proxy
                 .args()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-            env: proxy.env().map(|v| {
-                v.iter()
-                    .map(|i| odf::source::EnvVar::deserialize(i))
-                    .collect()
-            }),
-        }
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.args"))?,
+            env: proxy
+                .env()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::source::EnvVar::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressContainer.env"))?,
+        })
     }
 }
 
@@ -3087,27 +5248,55 @@ impl<'fb> FlatbuffersDeserializable<fb::IngressContainer<'fb>> for odf::source::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressEvmLogs {
     type OffsetT = WIPOffset<fb::IngressEvmLogs<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let node_url_offset = self.node_url.as_ref().map(|v| fb.create_string(&v));
-        let filter_offset = self.filter.as_ref().map(|v| fb.create_string(&v));
-        let signature_offset = self.signature.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let node_url_offset = match &self.node_url {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let filter_offset = match &self.filter {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let signature_offset = match &self.signature {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::IngressEvmLogsBuilder::new(fb);
         self.chain_id.map(|v| builder.add_chain_id(v));
         node_url_offset.map(|off| builder.add_node_url(off));
         filter_offset.map(|off| builder.add_filter(off));
         signature_offset.map(|off| builder.add_signature(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngressEvmLogs<'fb>> for odf::source::IngressEvmLogs {
-    fn deserialize(proxy: fb::IngressEvmLogs<'fb>) -> Self {
-        odf::source::IngressEvmLogs {
-            chain_id: proxy.chain_id().map(|v| v),
-            node_url: proxy.node_url().map(|v| v.to_owned()),
-            filter: proxy.filter().map(|v| v.to_owned()),
-            signature: proxy.signature().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::IngressEvmLogs<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngressEvmLogs {
+            chain_id: proxy
+                .chain_id()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.chainId"))?,
+            node_url: proxy
+                .node_url()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.nodeUrl"))?,
+            filter: proxy
+                .filter()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.filter"))?,
+            signature: proxy
+                .signature()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressEvmLogs.signature"))?,
+        })
     }
 }
 
@@ -3119,10 +5308,19 @@ impl<'fb> FlatbuffersDeserializable<fb::IngressEvmLogs<'fb>> for odf::source::In
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressFilesGlob {
     type OffsetT = WIPOffset<fb::IngressFilesGlob<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let path_offset = { fb.create_string(&self.path) };
-        let event_time_offset = self.event_time.as_ref().map(|v| v.serialize(fb));
-        let cache_offset = self.cache.as_ref().map(|v| v.serialize(fb));
+        let event_time_offset = match &self.event_time {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let cache_offset = match &self.cache {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::IngressFilesGlobBuilder::new(fb);
         builder.add_path(path_offset);
         event_time_offset.map(|(e, off)| {
@@ -3133,23 +5331,37 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressFilesGlob {
             builder.add_cache_type(e);
             builder.add_cache(off)
         });
-        self.order.map(|v| builder.add_order(v.into()));
-        builder.finish()
+        if let Some(v) = &self.order {
+            builder.add_order(fb::SourceOrdering::try_from(v)?);
+        }
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngressFilesGlob<'fb>> for odf::source::IngressFilesGlob {
-    fn deserialize(proxy: fb::IngressFilesGlob<'fb>) -> Self {
-        odf::source::IngressFilesGlob {
-            path: proxy.path().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::IngressFilesGlob<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngressFilesGlob {
+            path: proxy
+                .path()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.path"))?,
             event_time: proxy
                 .event_time()
-                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type())),
+                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.eventTime"))?,
             cache: proxy
                 .cache()
-                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type())),
-            order: proxy.order().map(|v| v.into()),
-        }
+                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.cache"))?,
+            order: proxy
+                .order()
+                .map(|v| odf::source::SourceOrdering::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressFilesGlob.order"))?,
+        })
     }
 }
 
@@ -3161,12 +5373,25 @@ impl<'fb> FlatbuffersDeserializable<fb::IngressFilesGlob<'fb>> for odf::source::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressMqtt {
     type OffsetT = WIPOffset<fb::IngressMqtt<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let host_offset = { fb.create_string(&self.host) };
-        let username_offset = self.username.as_ref().map(|v| fb.create_string(&v));
-        let password_offset = self.password.as_ref().map(|v| fb.create_string(&v));
+        let username_offset = match &self.username {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let password_offset = match &self.password {
+            Some(v) => Some({ fb.create_string(&v.expose()) }),
+            None => None,
+        };
         let topics_offset = {
-            let offsets: Vec<_> = self.topics.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .topics
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::IngressMqttBuilder::new(fb);
@@ -3175,26 +5400,39 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressMqtt {
         username_offset.map(|off| builder.add_username(off));
         password_offset.map(|off| builder.add_password(off));
         builder.add_topics(topics_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngressMqtt<'fb>> for odf::source::IngressMqtt {
-    fn deserialize(proxy: fb::IngressMqtt<'fb>) -> Self {
-        odf::source::IngressMqtt {
-            host: proxy.host().map(|v| v.to_owned()).unwrap(),
-            port: proxy.port(),
-            username: proxy.username().map(|v| v.to_owned()),
-            password: proxy.password().map(|v| v.to_owned()),
+    fn deserialize(proxy: fb::IngressMqtt<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngressMqtt {
+            host: proxy
+                .host()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.host"))?,
+            port: Ok(proxy.port()).map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.port"))?,
+            username: proxy
+                .username()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.username"))?,
+            password: proxy
+                .password()
+                .map(|v| Ok(v.to_owned()).map(odf::dtos::Sensitive::new))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.password"))?,
             topics: proxy
                 .topics()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::source::MqttTopicSubscription::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressMqtt.topics"))?,
+        })
     }
 }
 
@@ -3206,26 +5444,34 @@ impl<'fb> FlatbuffersDeserializable<fb::IngressMqtt<'fb>> for odf::source::Ingre
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressRestEndpoint {
     type OffsetT = WIPOffset<fb::IngressRestEndpoint<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let buffer_offset = self.buffer.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let buffer_offset = match &self.buffer {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::IngressRestEndpointBuilder::new(fb);
         buffer_offset.map(|(e, off)| {
             builder.add_buffer_type(e);
             builder.add_buffer(off)
         });
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngressRestEndpoint<'fb>>
     for odf::source::IngressRestEndpoint
 {
-    fn deserialize(proxy: fb::IngressRestEndpoint<'fb>) -> Self {
-        odf::source::IngressRestEndpoint {
+    fn deserialize(proxy: fb::IngressRestEndpoint<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngressRestEndpoint {
             buffer: proxy
                 .buffer()
-                .map(|v| odf::source::IngressBuffer::deserialize(v, proxy.buffer_type())),
-        }
+                .map(|v| odf::source::IngressBuffer::deserialize(v, proxy.buffer_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressRestEndpoint.buffer"))?,
+        })
     }
 }
 
@@ -3237,14 +5483,29 @@ impl<'fb> FlatbuffersDeserializable<fb::IngressRestEndpoint<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressUrl {
     type OffsetT = WIPOffset<fb::IngressUrl<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let url_offset = { fb.create_string(&self.url) };
-        let event_time_offset = self.event_time.as_ref().map(|v| v.serialize(fb));
-        let cache_offset = self.cache.as_ref().map(|v| v.serialize(fb));
-        let headers_offset = self.headers.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
+        let event_time_offset = match &self.event_time {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let cache_offset = match &self.cache {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let headers_offset = match &self.headers {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::IngressUrlBuilder::new(fb);
         builder.add_url(url_offset);
         event_time_offset.map(|(e, off)| {
@@ -3256,26 +5517,38 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressUrl {
             builder.add_cache(off)
         });
         headers_offset.map(|off| builder.add_headers(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngressUrl<'fb>> for odf::source::IngressUrl {
-    fn deserialize(proxy: fb::IngressUrl<'fb>) -> Self {
-        odf::source::IngressUrl {
-            url: proxy.url().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::IngressUrl<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngressUrl {
+            url: proxy
+                .url()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.url"))?,
             event_time: proxy
                 .event_time()
-                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type())),
+                .map(|v| odf::source::EventTimeSource::deserialize(v, proxy.event_time_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.eventTime"))?,
             cache: proxy
                 .cache()
-                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type())),
-            headers: proxy.headers().map(|v| {
-                v.iter()
-                    .map(|i| odf::source::RequestHeader::deserialize(i))
-                    .collect()
-            }),
-        }
+                .map(|v| odf::source::SourceCaching::deserialize(v, proxy.cache_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.cache"))?,
+            headers: proxy
+                .headers()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::source::RequestHeader::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressUrl.headers"))?,
+        })
     }
 }
 
@@ -3288,25 +5561,34 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::IngressBuffer> for odf::source::I
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::IngressBuffer, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::IngressBuffer, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::source::IngressBuffer::Memory(v) => (
+            odf::source::IngressBuffer::Memory(v) => Ok((
                 fb::IngressBuffer::IngressBufferMemory,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::IngressBuffer> for odf::source::IngressBuffer {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::IngressBuffer) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::IngressBuffer,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::IngressBuffer::IngressBufferMemory => {
-                odf::source::IngressBuffer::Memory(odf::source::IngressBufferMemory::deserialize(
-                    unsafe { fb::IngressBufferMemory::init_from_table(table) },
-                ))
+                odf::source::IngressBufferMemory::deserialize(unsafe {
+                    fb::IngressBufferMemory::init_from_table(table)
+                })
+                .map(odf::source::IngressBuffer::Memory)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "IngressBuffer",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -3319,23 +5601,37 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::IngressBuffer> for odf::source:
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::IngressBufferMemory {
     type OffsetT = WIPOffset<fb::IngressBufferMemory<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let overflow_policy_offset = self.overflow_policy.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let overflow_policy_offset = match &self.overflow_policy {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::IngressBufferMemoryBuilder::new(fb);
         self.buffer_size.map(|v| builder.add_buffer_size(v));
         overflow_policy_offset.map(|off| builder.add_overflow_policy(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::IngressBufferMemory<'fb>>
     for odf::source::IngressBufferMemory
 {
-    fn deserialize(proxy: fb::IngressBufferMemory<'fb>) -> Self {
-        odf::source::IngressBufferMemory {
-            buffer_size: proxy.buffer_size().map(|v| v),
-            overflow_policy: proxy.overflow_policy().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::IngressBufferMemory<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::IngressBufferMemory {
+            buffer_size: proxy
+                .buffer_size()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressBufferMemory.bufferSize"))?,
+            overflow_policy: proxy
+                .overflow_policy()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("IngressBufferMemory.overflowPolicy"))?,
+        })
     }
 }
 
@@ -3344,43 +5640,259 @@ impl<'fb> FlatbuffersDeserializable<fb::IngressBufferMemory<'fb>>
 // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::LabelFilter {
-    type OffsetT = WIPOffset<fb::LabelFilter<'fb>>;
+impl<'fb> FlatbuffersEnumSerializable<'fb, fb::LabelFilter> for odf::resource::LabelFilter {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<(fb::LabelFilter, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
+        match self {
+            odf::resource::LabelFilter::And(v) => Ok((
+                fb::LabelFilter::LabelFilterAnd,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::resource::LabelFilter::Or(v) => Ok((
+                fb::LabelFilter::LabelFilterOr,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::resource::LabelFilter::Not(v) => Ok((
+                fb::LabelFilter::LabelFilterNot,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::resource::LabelFilter::Match(v) => Ok((
+                fb::LabelFilter::LabelFilterMatch,
+                v.serialize(fb)?.as_union_value(),
+            )),
+        }
+    }
+}
+
+impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::LabelFilter> for odf::resource::LabelFilter {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::LabelFilter,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        match t {
+            fb::LabelFilter::LabelFilterAnd => odf::resource::LabelFilterAnd::deserialize(unsafe {
+                fb::LabelFilterAnd::init_from_table(table)
+            })
+            .map(odf::resource::LabelFilter::And),
+            fb::LabelFilter::LabelFilterOr => odf::resource::LabelFilterOr::deserialize(unsafe {
+                fb::LabelFilterOr::init_from_table(table)
+            })
+            .map(odf::resource::LabelFilter::Or),
+            fb::LabelFilter::LabelFilterNot => odf::resource::LabelFilterNot::deserialize(unsafe {
+                fb::LabelFilterNot::init_from_table(table)
+            })
+            .map(odf::resource::LabelFilter::Not),
+            fb::LabelFilter::LabelFilterMatch => {
+                odf::resource::LabelFilterMatch::deserialize(unsafe {
+                    fb::LabelFilterMatch::init_from_table(table)
+                })
+                .map(odf::resource::LabelFilter::Match)
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "LabelFilter",
+                    value: t.0,
+                },
+            )),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterAnd
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/And
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::LabelFilterAnd {
+    type OffsetT = WIPOffset<fb::LabelFilterAnd<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let and_offset = {
+            let offsets = self
+                .and
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
+                    let mut builder = fb::LabelFilterWrapperBuilder::new(fb);
+                    builder.add_value_type(value_type);
+                    builder.add_value(value_offset);
+                    Ok(builder.finish())
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let mut builder = fb::LabelFilterAndBuilder::new(fb);
+        builder.add_and(and_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::LabelFilterAnd<'fb>> for odf::resource::LabelFilterAnd {
+    fn deserialize(proxy: fb::LabelFilterAnd<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::LabelFilterAnd {
+            and: proxy
+                .and()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::resource::LabelFilter::deserialize(v, i.value_type())
+                                })
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("LabelFilterAnd.$and"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterMatch
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Match
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::LabelFilterMatch {
+    type OffsetT = WIPOffset<fb::LabelFilterMatch<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = fb.create_string(&serde_json::to_string(value).unwrap());
-                let mut entry_builder = fb::LabelFilterEntryBuilder::new(fb);
+                let value_offset = value.serialize(fb)?;
+                let mut entry_builder = fb::LabelFilterMatchEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
-        let mut builder = fb::LabelFilterBuilder::new(fb);
+        let mut builder = fb::LabelFilterMatchBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
-impl<'fb> FlatbuffersDeserializable<fb::LabelFilter<'fb>> for odf::resource::LabelFilter {
-    fn deserialize(proxy: fb::LabelFilter<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
+impl<'fb> FlatbuffersDeserializable<fb::LabelFilterMatch<'fb>> for odf::resource::LabelFilterMatch {
+    fn deserialize(proxy: fb::LabelFilterMatch<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| odf::resource::FilterCondition::deserialize(v))?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("LabelFilterMatch.entries"))?;
+        Ok(Self { entries })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterNot
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Not
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::LabelFilterNot {
+    type OffsetT = WIPOffset<fb::LabelFilterNot<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let not_offset = { self.not.serialize(fb)? };
+        let mut builder = fb::LabelFilterNotBuilder::new(fb);
+        builder.add_not_type(not_offset.0);
+        builder.add_not(not_offset.1);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::LabelFilterNot<'fb>> for odf::resource::LabelFilterNot {
+    fn deserialize(proxy: fb::LabelFilterNot<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::LabelFilterNot {
+            not: proxy
+                .not()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::LabelFilter::deserialize(v, proxy.not_type()).map(Box::new)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("LabelFilterNot.$not"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// LabelFilterOr
+// Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Or
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::LabelFilterOr {
+    type OffsetT = WIPOffset<fb::LabelFilterOr<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let or_offset = {
+            let offsets = self
+                .or
                 .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = serde_json::from_str(entry.value().unwrap()).unwrap();
-                    (key, value)
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    let (value_type, value_offset) = i.serialize(fb)?;
+                    let mut builder = fb::LabelFilterWrapperBuilder::new(fb);
+                    builder.add_value_type(value_type);
+                    builder.add_value(value_offset);
+                    Ok(builder.finish())
                 })
-                .collect(),
-        }
+                .collect::<Result<Vec<_>, _>>()?;
+            fb.create_vector(&offsets)
+        };
+        let mut builder = fb::LabelFilterOrBuilder::new(fb);
+        builder.add_or(or_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::LabelFilterOr<'fb>> for odf::resource::LabelFilterOr {
+    fn deserialize(proxy: fb::LabelFilterOr<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::LabelFilterOr {
+            or: proxy
+                .or()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| {
+                                    odf::resource::LabelFilter::deserialize(v, i.value_type())
+                                })
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("LabelFilterOr.$or"))?,
+        })
     }
 }
 
@@ -3393,65 +5905,74 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::MergeStrategy> for odf::source::M
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::MergeStrategy, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::MergeStrategy, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::source::MergeStrategy::Append(v) => (
+            odf::source::MergeStrategy::Append(v) => Ok((
                 fb::MergeStrategy::MergeStrategyAppend,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::MergeStrategy::Ledger(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::MergeStrategy::Ledger(v) => Ok((
                 fb::MergeStrategy::MergeStrategyLedger,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::MergeStrategy::Snapshot(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::MergeStrategy::Snapshot(v) => Ok((
                 fb::MergeStrategy::MergeStrategySnapshot,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::MergeStrategy::ChangelogStream(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::MergeStrategy::ChangelogStream(v) => Ok((
                 fb::MergeStrategy::MergeStrategyChangelogStream,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::MergeStrategy::UpsertStream(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::MergeStrategy::UpsertStream(v) => Ok((
                 fb::MergeStrategy::MergeStrategyUpsertStream,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::MergeStrategy> for odf::source::MergeStrategy {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::MergeStrategy) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::MergeStrategy,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::MergeStrategy::MergeStrategyAppend => {
-                odf::source::MergeStrategy::Append(odf::source::MergeStrategyAppend::deserialize(
-                    unsafe { fb::MergeStrategyAppend::init_from_table(table) },
-                ))
+                odf::source::MergeStrategyAppend::deserialize(unsafe {
+                    fb::MergeStrategyAppend::init_from_table(table)
+                })
+                .map(odf::source::MergeStrategy::Append)
             }
             fb::MergeStrategy::MergeStrategyLedger => {
-                odf::source::MergeStrategy::Ledger(odf::source::MergeStrategyLedger::deserialize(
-                    unsafe { fb::MergeStrategyLedger::init_from_table(table) },
-                ))
+                odf::source::MergeStrategyLedger::deserialize(unsafe {
+                    fb::MergeStrategyLedger::init_from_table(table)
+                })
+                .map(odf::source::MergeStrategy::Ledger)
             }
-            fb::MergeStrategy::MergeStrategySnapshot => odf::source::MergeStrategy::Snapshot(
+            fb::MergeStrategy::MergeStrategySnapshot => {
                 odf::source::MergeStrategySnapshot::deserialize(unsafe {
                     fb::MergeStrategySnapshot::init_from_table(table)
-                }),
-            ),
+                })
+                .map(odf::source::MergeStrategy::Snapshot)
+            }
             fb::MergeStrategy::MergeStrategyChangelogStream => {
-                odf::source::MergeStrategy::ChangelogStream(
-                    odf::source::MergeStrategyChangelogStream::deserialize(unsafe {
-                        fb::MergeStrategyChangelogStream::init_from_table(table)
-                    }),
-                )
+                odf::source::MergeStrategyChangelogStream::deserialize(unsafe {
+                    fb::MergeStrategyChangelogStream::init_from_table(table)
+                })
+                .map(odf::source::MergeStrategy::ChangelogStream)
             }
             fb::MergeStrategy::MergeStrategyUpsertStream => {
-                odf::source::MergeStrategy::UpsertStream(
-                    odf::source::MergeStrategyUpsertStream::deserialize(unsafe {
-                        fb::MergeStrategyUpsertStream::init_from_table(table)
-                    }),
-                )
+                odf::source::MergeStrategyUpsertStream::deserialize(unsafe {
+                    fb::MergeStrategyUpsertStream::init_from_table(table)
+                })
+                .map(odf::source::MergeStrategy::UpsertStream)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "MergeStrategy",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -3464,17 +5985,20 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::MergeStrategy> for odf::source:
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::MergeStrategyAppend {
     type OffsetT = WIPOffset<fb::MergeStrategyAppend<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::MergeStrategyAppendBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyAppend<'fb>>
     for odf::source::MergeStrategyAppend
 {
-    fn deserialize(proxy: fb::MergeStrategyAppend<'fb>) -> Self {
-        odf::source::MergeStrategyAppend {}
+    fn deserialize(proxy: fb::MergeStrategyAppend<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::MergeStrategyAppend {})
     }
 }
 
@@ -3486,31 +6010,39 @@ impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyAppend<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::MergeStrategyChangelogStream {
     type OffsetT = WIPOffset<fb::MergeStrategyChangelogStream<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let primary_key_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .primary_key
                 .iter()
-                .map(|i| fb.create_string(&i))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::MergeStrategyChangelogStreamBuilder::new(fb);
         builder.add_primary_key(primary_key_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyChangelogStream<'fb>>
     for odf::source::MergeStrategyChangelogStream
 {
-    fn deserialize(proxy: fb::MergeStrategyChangelogStream<'fb>) -> Self {
-        odf::source::MergeStrategyChangelogStream {
+    fn deserialize(
+        proxy: fb::MergeStrategyChangelogStream<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::MergeStrategyChangelogStream {
             primary_key: proxy
                 .primary_key()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("MergeStrategyChangelogStream.primaryKey")
+                })?,
+        })
     }
 }
 
@@ -3522,31 +6054,35 @@ impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyChangelogStream<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::MergeStrategyLedger {
     type OffsetT = WIPOffset<fb::MergeStrategyLedger<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let primary_key_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .primary_key
                 .iter()
-                .map(|i| fb.create_string(&i))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::MergeStrategyLedgerBuilder::new(fb);
         builder.add_primary_key(primary_key_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyLedger<'fb>>
     for odf::source::MergeStrategyLedger
 {
-    fn deserialize(proxy: fb::MergeStrategyLedger<'fb>) -> Self {
-        odf::source::MergeStrategyLedger {
+    fn deserialize(proxy: fb::MergeStrategyLedger<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::MergeStrategyLedger {
             primary_key: proxy
                 .primary_key()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .map_err(|e: FlatbuffersDecodeError| e.at("MergeStrategyLedger.primaryKey"))?,
+        })
     }
 }
 
@@ -3558,39 +6094,53 @@ impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyLedger<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::MergeStrategySnapshot {
     type OffsetT = WIPOffset<fb::MergeStrategySnapshot<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let primary_key_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .primary_key
                 .iter()
-                .map(|i| fb.create_string(&i))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let compare_columns_offset = self.compare_columns.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
+        let compare_columns_offset = match &self.compare_columns {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::MergeStrategySnapshotBuilder::new(fb);
         builder.add_primary_key(primary_key_offset);
         compare_columns_offset.map(|off| builder.add_compare_columns(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::MergeStrategySnapshot<'fb>>
     for odf::source::MergeStrategySnapshot
 {
-    fn deserialize(proxy: fb::MergeStrategySnapshot<'fb>) -> Self {
-        odf::source::MergeStrategySnapshot {
+    fn deserialize(proxy: fb::MergeStrategySnapshot<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::MergeStrategySnapshot {
             primary_key: proxy
                 .primary_key()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .map_err(|e: FlatbuffersDecodeError| e.at("MergeStrategySnapshot.primaryKey"))?,
             compare_columns: proxy
                 .compare_columns()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-        }
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("MergeStrategySnapshot.compareColumns")
+                })?,
+        })
     }
 }
 
@@ -3602,31 +6152,39 @@ impl<'fb> FlatbuffersDeserializable<fb::MergeStrategySnapshot<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::MergeStrategyUpsertStream {
     type OffsetT = WIPOffset<fb::MergeStrategyUpsertStream<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let primary_key_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .primary_key
                 .iter()
-                .map(|i| fb.create_string(&i))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::MergeStrategyUpsertStreamBuilder::new(fb);
         builder.add_primary_key(primary_key_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyUpsertStream<'fb>>
     for odf::source::MergeStrategyUpsertStream
 {
-    fn deserialize(proxy: fb::MergeStrategyUpsertStream<'fb>) -> Self {
-        odf::source::MergeStrategyUpsertStream {
+    fn deserialize(
+        proxy: fb::MergeStrategyUpsertStream<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::MergeStrategyUpsertStream {
             primary_key: proxy
                 .primary_key()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("MergeStrategyUpsertStream.primaryKey")
+                })?,
+        })
     }
 }
 
@@ -3638,35 +6196,62 @@ impl<'fb> FlatbuffersDeserializable<fb::MergeStrategyUpsertStream<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::MetadataBlock {
     type OffsetT = WIPOffset<fb::MetadataBlock<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let prev_block_hash_offset = self
-            .prev_block_hash
-            .as_ref()
-            .map(|v| fb.create_vector(&v.as_bytes().as_slice()));
-        let event_offset = { self.event.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let prev_block_hash_offset = match &self.prev_block_hash {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes().as_slice()) }),
+            None => None,
+        };
+        let event_offset = { self.event.serialize(fb)? };
         let mut builder = fb::MetadataBlockBuilder::new(fb);
         builder.add_system_time(&datetime_to_fb(&self.system_time));
         prev_block_hash_offset.map(|off| builder.add_prev_block_hash(off));
         builder.add_sequence_number(self.sequence_number);
         builder.add_event_type(event_offset.0);
         builder.add_event(event_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::MetadataBlock<'fb>> for odf::dataset::MetadataBlock {
-    fn deserialize(proxy: fb::MetadataBlock<'fb>) -> Self {
-        odf::dataset::MetadataBlock {
-            system_time: proxy.system_time().map(|v| fb_to_datetime(v)).unwrap(),
+    fn deserialize(proxy: fb::MetadataBlock<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::MetadataBlock {
+            system_time: proxy
+                .system_time()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| fb_to_datetime(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.systemTime"))?,
             prev_block_hash: proxy
                 .prev_block_hash()
-                .map(|v| odf::Multihash::from_bytes(v.bytes()).unwrap()),
-            sequence_number: proxy.sequence_number(),
+                .map(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.prevBlockHash"))?,
+            sequence_number: Ok(proxy.sequence_number())
+                .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.sequenceNumber"))?,
             event: proxy
                 .event()
-                .map(|v| odf::dataset::MetadataEvent::deserialize(v, proxy.event_type()))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::MetadataEvent::deserialize(v, proxy.event_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("MetadataBlock.event"))?,
+        })
+    }
+}
+
+impl FlatbuffersHashable for odf::dataset::MetadataBlock {
+    fn to_canonical_bytes(&self) -> Result<Vec<u8>, FlatbuffersEncodeError> {
+        let mut fb = canonical_builder();
+        let offset = self.serialize(&mut fb)?;
+        fb.finish(offset, None);
+        Ok(fb.finished_data().to_vec())
+    }
+
+    fn to_canonical_manifest(&self) -> Result<Vec<u8>, FlatbuffersEncodeError> {
+        Ok(canonical_manifest(0x400000, 2, &self.to_canonical_bytes()?))
     }
 }
 
@@ -3679,132 +6264,151 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::MetadataEvent> for odf::dataset::
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::MetadataEvent, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::MetadataEvent, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::dataset::MetadataEvent::AddData(v) => {
-                (fb::MetadataEvent::AddData, v.serialize(fb).as_union_value())
-            }
-            odf::dataset::MetadataEvent::ExecuteTransform(v) => (
+            odf::dataset::MetadataEvent::AddData(v) => Ok((
+                fb::MetadataEvent::AddData,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::ExecuteTransform(v) => Ok((
                 fb::MetadataEvent::ExecuteTransform,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
             odf::dataset::MetadataEvent::Seed(v) => {
-                (fb::MetadataEvent::Seed, v.serialize(fb).as_union_value())
+                Ok((fb::MetadataEvent::Seed, v.serialize(fb)?.as_union_value()))
             }
-            odf::dataset::MetadataEvent::SetPollingSource(v) => (
+            odf::dataset::MetadataEvent::SetPollingSource(v) => Ok((
                 fb::MetadataEvent::SetPollingSource,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::SetTransform(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::SetTransform(v) => Ok((
                 fb::MetadataEvent::SetTransform,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::SetVocab(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::SetVocab(v) => Ok((
                 fb::MetadataEvent::SetVocab,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::SetAttachments(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::SetAttachments(v) => Ok((
                 fb::MetadataEvent::SetAttachments,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::SetInfo(v) => {
-                (fb::MetadataEvent::SetInfo, v.serialize(fb).as_union_value())
-            }
-            odf::dataset::MetadataEvent::SetLicense(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::SetInfo(v) => Ok((
+                fb::MetadataEvent::SetInfo,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::SetLicense(v) => Ok((
                 fb::MetadataEvent::SetLicense,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::SetDataSchema(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::SetDataSchema(v) => Ok((
                 fb::MetadataEvent::SetDataSchema,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::AddPushSource(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::AddPushSource(v) => Ok((
                 fb::MetadataEvent::AddPushSource,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::DisablePushSource(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::DisablePushSource(v) => Ok((
                 fb::MetadataEvent::DisablePushSource,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::dataset::MetadataEvent::DisablePollingSource(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::DisablePollingSource(v) => Ok((
                 fb::MetadataEvent::DisablePollingSource,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::dataset::MetadataEvent::Unknown(odf::dtos::UnknownVariant::Flatbuffers {
+                tag,
+                bytes,
+                loc,
+            }) => Ok((
+                fb::MetadataEvent(*tag),
+                push_unknown_table(fb, bytes, *loc)?,
+            )),
+            odf::dataset::MetadataEvent::Unknown(odf::dtos::UnknownVariant::Json {
+                tag, ..
+            }) => Err(FlatbuffersEncodeError::invalid_value(format!(
+                "Unknown variant {tag} of MetadataEvent was read from a manifest and has no flatbuffers representation"
+            ))),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::MetadataEvent> for odf::dataset::MetadataEvent {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::MetadataEvent) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::MetadataEvent,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::MetadataEvent::AddData => {
-                odf::dataset::MetadataEvent::AddData(odf::dataset::AddData::deserialize(unsafe {
-                    fb::AddData::init_from_table(table)
-                }))
+                odf::dataset::AddData::deserialize(unsafe { fb::AddData::init_from_table(table) })
+                    .map(odf::dataset::MetadataEvent::AddData)
             }
-            fb::MetadataEvent::ExecuteTransform => odf::dataset::MetadataEvent::ExecuteTransform(
+            fb::MetadataEvent::ExecuteTransform => {
                 odf::dataset::ExecuteTransform::deserialize(unsafe {
                     fb::ExecuteTransform::init_from_table(table)
-                }),
-            ),
+                })
+                .map(odf::dataset::MetadataEvent::ExecuteTransform)
+            }
             fb::MetadataEvent::Seed => {
-                odf::dataset::MetadataEvent::Seed(odf::dataset::Seed::deserialize(unsafe {
-                    fb::Seed::init_from_table(table)
-                }))
+                odf::dataset::Seed::deserialize(unsafe { fb::Seed::init_from_table(table) })
+                    .map(odf::dataset::MetadataEvent::Seed)
             }
-            fb::MetadataEvent::SetPollingSource => odf::dataset::MetadataEvent::SetPollingSource(
+            fb::MetadataEvent::SetPollingSource => {
                 odf::legacy::SetPollingSource::deserialize(unsafe {
                     fb::SetPollingSource::init_from_table(table)
-                }),
-            ),
-            fb::MetadataEvent::SetTransform => {
-                odf::dataset::MetadataEvent::SetTransform(odf::dataset::SetTransform::deserialize(
-                    unsafe { fb::SetTransform::init_from_table(table) },
-                ))
+                })
+                .map(odf::dataset::MetadataEvent::SetPollingSource)
             }
+            fb::MetadataEvent::SetTransform => odf::dataset::SetTransform::deserialize(unsafe {
+                fb::SetTransform::init_from_table(table)
+            })
+            .map(odf::dataset::MetadataEvent::SetTransform),
             fb::MetadataEvent::SetVocab => {
-                odf::dataset::MetadataEvent::SetVocab(odf::dataset::SetVocab::deserialize(unsafe {
-                    fb::SetVocab::init_from_table(table)
-                }))
+                odf::dataset::SetVocab::deserialize(unsafe { fb::SetVocab::init_from_table(table) })
+                    .map(odf::dataset::MetadataEvent::SetVocab)
             }
-            fb::MetadataEvent::SetAttachments => odf::dataset::MetadataEvent::SetAttachments(
+            fb::MetadataEvent::SetAttachments => {
                 odf::dataset::SetAttachments::deserialize(unsafe {
                     fb::SetAttachments::init_from_table(table)
-                }),
-            ),
-            fb::MetadataEvent::SetInfo => {
-                odf::dataset::MetadataEvent::SetInfo(odf::dataset::SetInfo::deserialize(unsafe {
-                    fb::SetInfo::init_from_table(table)
-                }))
-            }
-            fb::MetadataEvent::SetLicense => {
-                odf::dataset::MetadataEvent::SetLicense(odf::dataset::SetLicense::deserialize(
-                    unsafe { fb::SetLicense::init_from_table(table) },
-                ))
+                })
+                .map(odf::dataset::MetadataEvent::SetAttachments)
             }
-            fb::MetadataEvent::SetDataSchema => odf::dataset::MetadataEvent::SetDataSchema(
-                odf::dataset::SetDataSchema::deserialize(unsafe {
-                    fb::SetDataSchema::init_from_table(table)
-                }),
-            ),
-            fb::MetadataEvent::AddPushSource => {
-                odf::dataset::MetadataEvent::AddPushSource(odf::legacy::AddPushSource::deserialize(
-                    unsafe { fb::AddPushSource::init_from_table(table) },
-                ))
+            fb::MetadataEvent::SetInfo => {
+                odf::dataset::SetInfo::deserialize(unsafe { fb::SetInfo::init_from_table(table) })
+                    .map(odf::dataset::MetadataEvent::SetInfo)
             }
-            fb::MetadataEvent::DisablePushSource => odf::dataset::MetadataEvent::DisablePushSource(
+            fb::MetadataEvent::SetLicense => odf::dataset::SetLicense::deserialize(unsafe {
+                fb::SetLicense::init_from_table(table)
+            })
+            .map(odf::dataset::MetadataEvent::SetLicense),
+            fb::MetadataEvent::SetDataSchema => odf::dataset::SetDataSchema::deserialize(unsafe {
+                fb::SetDataSchema::init_from_table(table)
+            })
+            .map(odf::dataset::MetadataEvent::SetDataSchema),
+            fb::MetadataEvent::AddPushSource => odf::legacy::AddPushSource::deserialize(unsafe {
+                fb::AddPushSource::init_from_table(table)
+            })
+            .map(odf::dataset::MetadataEvent::AddPushSource),
+            fb::MetadataEvent::DisablePushSource => {
                 odf::legacy::DisablePushSource::deserialize(unsafe {
                     fb::DisablePushSource::init_from_table(table)
-                }),
-            ),
+                })
+                .map(odf::dataset::MetadataEvent::DisablePushSource)
+            }
             fb::MetadataEvent::DisablePollingSource => {
-                odf::dataset::MetadataEvent::DisablePollingSource(
-                    odf::legacy::DisablePollingSource::deserialize(unsafe {
-                        fb::DisablePollingSource::init_from_table(table)
-                    }),
-                )
+                odf::legacy::DisablePollingSource::deserialize(unsafe {
+                    fb::DisablePollingSource::init_from_table(table)
+                })
+                .map(odf::dataset::MetadataEvent::DisablePollingSource)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            fb::MetadataEvent::NONE => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "MetadataEvent",
+                    value: t.0,
+                },
+            )),
+            _ => unknown_variant_from_table(table, t.0).map(odf::dataset::MetadataEvent::Unknown),
         }
     }
 }
@@ -3814,23 +6418,32 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::MetadataEvent> for odf::dataset
 // Schema: https://opendatafabric.org/schemas/source/v1alpha1/MqttQos
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::source::MqttQos> for fb::MqttQos {
-    fn from(v: odf::source::MqttQos) -> Self {
+impl TryFrom<&odf::source::MqttQos> for fb::MqttQos {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::source::MqttQos) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::source::MqttQos::AtMostOnce => fb::MqttQos::AtMostOnce,
-            odf::source::MqttQos::AtLeastOnce => fb::MqttQos::AtLeastOnce,
-            odf::source::MqttQos::ExactlyOnce => fb::MqttQos::ExactlyOnce,
+            odf::source::MqttQos::AtMostOnce => Ok(fb::MqttQos::AtMostOnce),
+            odf::source::MqttQos::AtLeastOnce => Ok(fb::MqttQos::AtLeastOnce),
+            odf::source::MqttQos::ExactlyOnce => Ok(fb::MqttQos::ExactlyOnce),
         }
     }
 }
 
-impl Into<odf::source::MqttQos> for fb::MqttQos {
-    fn into(self) -> odf::source::MqttQos {
-        match self {
-            fb::MqttQos::AtMostOnce => odf::source::MqttQos::AtMostOnce,
-            fb::MqttQos::AtLeastOnce => odf::source::MqttQos::AtLeastOnce,
-            fb::MqttQos::ExactlyOnce => odf::source::MqttQos::ExactlyOnce,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::MqttQos> for odf::source::MqttQos {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::MqttQos) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::MqttQos::AtMostOnce => Ok(odf::source::MqttQos::AtMostOnce),
+            fb::MqttQos::AtLeastOnce => Ok(odf::source::MqttQos::AtLeastOnce),
+            fb::MqttQos::ExactlyOnce => Ok(odf::source::MqttQos::ExactlyOnce),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "MqttQos",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
@@ -3843,26 +6456,232 @@ impl Into<odf::source::MqttQos> for fb::MqttQos {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::MqttTopicSubscription {
     type OffsetT = WIPOffset<fb::MqttTopicSubscription<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let path_offset = { fb.create_string(&self.path) };
         let mut builder = fb::MqttTopicSubscriptionBuilder::new(fb);
         builder.add_path(path_offset);
-        self.qos.map(|v| builder.add_qos(v.into()));
-        builder.finish()
+        if let Some(v) = &self.qos {
+            builder.add_qos(fb::MqttQos::try_from(v)?);
+        }
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::MqttTopicSubscription<'fb>>
     for odf::source::MqttTopicSubscription
 {
-    fn deserialize(proxy: fb::MqttTopicSubscription<'fb>) -> Self {
-        odf::source::MqttTopicSubscription {
-            path: proxy.path().map(|v| v.to_owned()).unwrap(),
-            qos: proxy.qos().map(|v| v.into()),
+    fn deserialize(proxy: fb::MqttTopicSubscription<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::MqttTopicSubscription {
+            path: proxy
+                .path()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("MqttTopicSubscription.path"))?,
+            qos: proxy
+                .qos()
+                .map(|v| odf::source::MqttQos::try_from(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("MqttTopicSubscription.qos"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectFileReference
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectFileReference
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::ObjectFileReference {
+    type OffsetT = WIPOffset<fb::ObjectFileReference<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let physical_hash_offset = { fb.create_vector(&self.physical_hash.as_bytes().as_slice()) };
+        let mut builder = fb::ObjectFileReferenceBuilder::new(fb);
+        builder.add_object_type(fb::ObjectType::try_from(&self.object_type)?);
+        builder.add_physical_hash(physical_hash_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ObjectFileReference<'fb>>
+    for odf::protocol::ObjectFileReference
+{
+    fn deserialize(proxy: fb::ObjectFileReference<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::ObjectFileReference {
+            object_type: odf::protocol::ObjectType::try_from(proxy.object_type())
+                .map_err(|e: FlatbuffersDecodeError| e.at("ObjectFileReference.objectType"))?,
+            physical_hash: proxy
+                .physical_hash()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::Multihash::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ObjectFileReference.physicalHash"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectPullStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPullStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl TryFrom<&odf::protocol::ObjectPullStrategy> for fb::ObjectPullStrategy {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::protocol::ObjectPullStrategy) -> Result<Self, FlatbuffersEncodeError> {
+        match v {
+            odf::protocol::ObjectPullStrategy::HttpDownload => {
+                Ok(fb::ObjectPullStrategy::HttpDownload)
+            }
+        }
+    }
+}
+
+impl TryFrom<fb::ObjectPullStrategy> for odf::protocol::ObjectPullStrategy {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::ObjectPullStrategy) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::ObjectPullStrategy::HttpDownload => {
+                Ok(odf::protocol::ObjectPullStrategy::HttpDownload)
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "ObjectPullStrategy",
+                    value: v.0 as i64,
+                },
+            )),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectPushStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPushStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl TryFrom<&odf::protocol::ObjectPushStrategy> for fb::ObjectPushStrategy {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::protocol::ObjectPushStrategy) -> Result<Self, FlatbuffersEncodeError> {
+        match v {
+            odf::protocol::ObjectPushStrategy::SkipUpload => Ok(fb::ObjectPushStrategy::SkipUpload),
+            odf::protocol::ObjectPushStrategy::HttpUpload => Ok(fb::ObjectPushStrategy::HttpUpload),
+        }
+    }
+}
+
+impl TryFrom<fb::ObjectPushStrategy> for odf::protocol::ObjectPushStrategy {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::ObjectPushStrategy) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::ObjectPushStrategy::SkipUpload => Ok(odf::protocol::ObjectPushStrategy::SkipUpload),
+            fb::ObjectPushStrategy::HttpUpload => Ok(odf::protocol::ObjectPushStrategy::HttpUpload),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "ObjectPushStrategy",
+                    value: v.0 as i64,
+                },
+            )),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectType
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectType
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl TryFrom<&odf::protocol::ObjectType> for fb::ObjectType {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::protocol::ObjectType) -> Result<Self, FlatbuffersEncodeError> {
+        match v {
+            odf::protocol::ObjectType::MetadataBlock => Ok(fb::ObjectType::MetadataBlock),
+            odf::protocol::ObjectType::DataSlice => Ok(fb::ObjectType::DataSlice),
+            odf::protocol::ObjectType::Checkpoint => Ok(fb::ObjectType::Checkpoint),
+        }
+    }
+}
+
+impl TryFrom<fb::ObjectType> for odf::protocol::ObjectType {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::ObjectType) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::ObjectType::MetadataBlock => Ok(odf::protocol::ObjectType::MetadataBlock),
+            fb::ObjectType::DataSlice => Ok(odf::protocol::ObjectType::DataSlice),
+            fb::ObjectType::Checkpoint => Ok(odf::protocol::ObjectType::Checkpoint),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "ObjectType",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// ObjectsBatch
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectsBatch
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::ObjectsBatch {
+    type OffsetT = WIPOffset<fb::ObjectsBatch<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let media_type_offset = { fb.create_string(&self.media_type) };
+        let encoding_offset = { fb.create_string(&self.encoding) };
+        let payload_offset = { fb.create_string(&self.payload) };
+        let mut builder = fb::ObjectsBatchBuilder::new(fb);
+        builder.add_objects_count(self.objects_count);
+        builder.add_object_type(fb::ObjectType::try_from(&self.object_type)?);
+        builder.add_media_type(media_type_offset);
+        builder.add_encoding(encoding_offset);
+        builder.add_payload(payload_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::ObjectsBatch<'fb>> for odf::protocol::ObjectsBatch {
+    fn deserialize(proxy: fb::ObjectsBatch<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::ObjectsBatch {
+            objects_count: Ok(proxy.objects_count())
+                .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.objectsCount"))?,
+            object_type: odf::protocol::ObjectType::try_from(proxy.object_type())
+                .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.objectType"))?,
+            media_type: proxy
+                .media_type()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.mediaType"))?,
+            encoding: proxy
+                .encoding()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.encoding"))?,
+            payload: proxy
+                .payload()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ObjectsBatch.payload"))?,
+        })
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // OffsetInterval
 // Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/OffsetInterval
@@ -3871,20 +6690,24 @@ impl<'fb> FlatbuffersDeserializable<fb::MqttTopicSubscription<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::OffsetInterval {
     type OffsetT = WIPOffset<fb::OffsetInterval<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::OffsetIntervalBuilder::new(fb);
         builder.add_start(self.start);
         builder.add_end(self.end);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::OffsetInterval<'fb>> for odf::dataset::OffsetInterval {
-    fn deserialize(proxy: fb::OffsetInterval<'fb>) -> Self {
-        odf::dataset::OffsetInterval {
-            start: proxy.start(),
-            end: proxy.end(),
-        }
+    fn deserialize(proxy: fb::OffsetInterval<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::OffsetInterval {
+            start: Ok(proxy.start())
+                .map_err(|e: FlatbuffersDecodeError| e.at("OffsetInterval.start"))?,
+            end: Ok(proxy.end()).map_err(|e: FlatbuffersDecodeError| e.at("OffsetInterval.end"))?,
+        })
     }
 }
 
@@ -3896,33 +6719,57 @@ impl<'fb> FlatbuffersDeserializable<fb::OffsetInterval<'fb>> for odf::dataset::O
 impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::PersistentVolumeRef {
     type OffsetT = WIPOffset<fb::PersistentVolumeRef<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let account_offset = self.account.as_ref().map(|v| v.serialize(fb));
-        let id_offset = self.id.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let name_offset = self.name.as_ref().map(|v| fb.create_string(&v.to_string()));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let account_offset = match &self.account {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let id_offset = match &self.id {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let name_offset = match &self.name {
+            Some(v) => Some({ fb.create_string(&v.to_string()) }),
+            None => None,
+        };
         let mut builder = fb::PersistentVolumeRefBuilder::new(fb);
         account_offset.map(|off| builder.add_account(off));
         id_offset.map(|off| builder.add_id(off));
         name_offset.map(|off| builder.add_name(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::PersistentVolumeRef<'fb>>
     for odf::storage::PersistentVolumeRef
 {
-    fn deserialize(proxy: fb::PersistentVolumeRef<'fb>) -> Self {
-        odf::storage::PersistentVolumeRef {
+    fn deserialize(proxy: fb::PersistentVolumeRef<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::storage::PersistentVolumeRef {
             account: proxy
                 .account()
-                .map(|v| odf::auth::AccountRef::deserialize(v)),
+                .map(|v| odf::auth::AccountRef::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeRef.account"))?,
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeRef.id"))?,
             name: proxy
                 .name()
-                .map(|v| odf::resource::ResourceName::try_from(v).unwrap()),
-        }
+                .map(|v| {
+                    odf::resource::ResourceName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeRef.name"))?,
+        })
     }
 }
 
@@ -3937,12 +6784,12 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::PersistentVolumeSpec>
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::PersistentVolumeSpec, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::PersistentVolumeSpec, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::storage::PersistentVolumeSpec::S3(v) => (
+            odf::storage::PersistentVolumeSpec::S3(v) => Ok((
                 fb::PersistentVolumeSpec::PersistentVolumeSpecS3,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
@@ -3950,16 +6797,23 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::PersistentVolumeSpec>
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::PersistentVolumeSpec>
     for odf::storage::PersistentVolumeSpec
 {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::PersistentVolumeSpec) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::PersistentVolumeSpec,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::PersistentVolumeSpec::PersistentVolumeSpecS3 => {
-                odf::storage::PersistentVolumeSpec::S3(
-                    odf::storage::PersistentVolumeSpecS3::deserialize(unsafe {
-                        fb::PersistentVolumeSpecS3::init_from_table(table)
-                    }),
-                )
+                odf::storage::PersistentVolumeSpecS3::deserialize(unsafe {
+                    fb::PersistentVolumeSpecS3::init_from_table(table)
+                })
+                .map(odf::storage::PersistentVolumeSpec::S3)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "PersistentVolumeSpec",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -3972,13 +6826,31 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::PersistentVolumeSpec>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::PersistentVolumeSpecS3 {
     type OffsetT = WIPOffset<fb::PersistentVolumeSpecS3<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let endpoint_offset = self.endpoint.as_ref().map(|v| fb.create_string(&v));
-        let region_offset = self.region.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let endpoint_offset = match &self.endpoint {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let region_offset = match &self.region {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let bucket_offset = { fb.create_string(&self.bucket) };
-        let prefix_offset = self.prefix.as_ref().map(|v| fb.create_string(&v));
-        let capacity_offset = self.capacity.as_ref().map(|v| v.serialize(fb));
-        let credentials_offset = self.credentials.as_ref().map(|v| v.serialize(fb));
+        let prefix_offset = match &self.prefix {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let capacity_offset = match &self.capacity {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let credentials_offset = match &self.credentials {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::PersistentVolumeSpecS3Builder::new(fb);
         endpoint_offset.map(|off| builder.add_endpoint(off));
         region_offset.map(|off| builder.add_region(off));
@@ -3986,26 +6858,46 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::PersistentVolumeSpecS3
         prefix_offset.map(|off| builder.add_prefix(off));
         capacity_offset.map(|off| builder.add_capacity(off));
         credentials_offset.map(|off| builder.add_credentials(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::PersistentVolumeSpecS3<'fb>>
     for odf::storage::PersistentVolumeSpecS3
 {
-    fn deserialize(proxy: fb::PersistentVolumeSpecS3<'fb>) -> Self {
-        odf::storage::PersistentVolumeSpecS3 {
-            endpoint: proxy.endpoint().map(|v| v.to_owned()),
-            region: proxy.region().map(|v| v.to_owned()),
-            bucket: proxy.bucket().map(|v| v.to_owned()).unwrap(),
-            prefix: proxy.prefix().map(|v| v.to_owned()),
+    fn deserialize(proxy: fb::PersistentVolumeSpecS3<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::storage::PersistentVolumeSpecS3 {
+            endpoint: proxy
+                .endpoint()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.endpoint"))?,
+            region: proxy
+                .region()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.region"))?,
+            bucket: proxy
+                .bucket()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.bucket"))?,
+            prefix: proxy
+                .prefix()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.prefix"))?,
             capacity: proxy
                 .capacity()
-                .map(|v| odf::storage::VolumeCapacity::deserialize(v)),
+                .map(|v| odf::storage::VolumeCapacity::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.capacity"))?,
             credentials: proxy
                 .credentials()
-                .map(|v| odf::storage::AwsCredentials::deserialize(v)),
-        }
+                .map(|v| odf::storage::AwsCredentials::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecS3.credentials"))?,
+        })
     }
 }
 
@@ -4020,12 +6912,13 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::PersistentVolumeSpecInput>
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::PersistentVolumeSpecInput, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::PersistentVolumeSpecInput, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError>
+    {
         match self {
-            odf::storage::PersistentVolumeSpecInput::S3(v) => (
+            odf::storage::PersistentVolumeSpecInput::S3(v) => Ok((
                 fb::PersistentVolumeSpecInput::PersistentVolumeSpecInputS3,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
@@ -4033,16 +6926,23 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::PersistentVolumeSpecInput>
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::PersistentVolumeSpecInput>
     for odf::storage::PersistentVolumeSpecInput
 {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::PersistentVolumeSpecInput) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::PersistentVolumeSpecInput,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::PersistentVolumeSpecInput::PersistentVolumeSpecInputS3 => {
-                odf::storage::PersistentVolumeSpecInput::S3(
-                    odf::storage::PersistentVolumeSpecInputS3::deserialize(unsafe {
-                        fb::PersistentVolumeSpecInputS3::init_from_table(table)
-                    }),
-                )
+                odf::storage::PersistentVolumeSpecInputS3::deserialize(unsafe {
+                    fb::PersistentVolumeSpecInputS3::init_from_table(table)
+                })
+                .map(odf::storage::PersistentVolumeSpecInput::S3)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "PersistentVolumeSpecInput",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -4055,13 +6955,31 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::PersistentVolumeSpecInput>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::PersistentVolumeSpecInputS3 {
     type OffsetT = WIPOffset<fb::PersistentVolumeSpecInputS3<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let endpoint_offset = self.endpoint.as_ref().map(|v| fb.create_string(&v));
-        let region_offset = self.region.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let endpoint_offset = match &self.endpoint {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let region_offset = match &self.region {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let bucket_offset = { fb.create_string(&self.bucket) };
-        let prefix_offset = self.prefix.as_ref().map(|v| fb.create_string(&v));
-        let capacity_offset = self.capacity.as_ref().map(|v| v.serialize(fb));
-        let credentials_offset = self.credentials.as_ref().map(|v| v.serialize(fb));
+        let prefix_offset = match &self.prefix {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let capacity_offset = match &self.capacity {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let credentials_offset = match &self.credentials {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::PersistentVolumeSpecInputS3Builder::new(fb);
         endpoint_offset.map(|off| builder.add_endpoint(off));
         region_offset.map(|off| builder.add_region(off));
@@ -4069,26 +6987,54 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::PersistentVolumeSpecInp
         prefix_offset.map(|off| builder.add_prefix(off));
         capacity_offset.map(|off| builder.add_capacity(off));
         credentials_offset.map(|off| builder.add_credentials(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::PersistentVolumeSpecInputS3<'fb>>
     for odf::storage::PersistentVolumeSpecInputS3
 {
-    fn deserialize(proxy: fb::PersistentVolumeSpecInputS3<'fb>) -> Self {
-        odf::storage::PersistentVolumeSpecInputS3 {
-            endpoint: proxy.endpoint().map(|v| v.to_owned()),
-            region: proxy.region().map(|v| v.to_owned()),
-            bucket: proxy.bucket().map(|v| v.to_owned()).unwrap(),
-            prefix: proxy.prefix().map(|v| v.to_owned()),
+    fn deserialize(
+        proxy: fb::PersistentVolumeSpecInputS3<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::storage::PersistentVolumeSpecInputS3 {
+            endpoint: proxy
+                .endpoint()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PersistentVolumeSpecInputS3.endpoint")
+                })?,
+            region: proxy
+                .region()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.region"))?,
+            bucket: proxy
+                .bucket()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.bucket"))?,
+            prefix: proxy
+                .prefix()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PersistentVolumeSpecInputS3.prefix"))?,
             capacity: proxy
                 .capacity()
-                .map(|v| odf::storage::VolumeCapacity::deserialize(v)),
+                .map(|v| odf::storage::VolumeCapacity::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PersistentVolumeSpecInputS3.capacity")
+                })?,
             credentials: proxy
                 .credentials()
-                .map(|v| odf::storage::AwsCredentialsInput::deserialize(v)),
-        }
+                .map(|v| odf::storage::AwsCredentialsInput::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PersistentVolumeSpecInputS3.credentials")
+                })?,
+        })
     }
 }
 
@@ -4101,33 +7047,42 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::PrepStep> for odf::source::PrepSt
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::PrepStep, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::PrepStep, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::source::PrepStep::Decompress(v) => (
+            odf::source::PrepStep::Decompress(v) => Ok((
                 fb::PrepStep::PrepStepDecompress,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::PrepStep::Pipe(v) => {
-                (fb::PrepStep::PrepStepPipe, v.serialize(fb).as_union_value())
-            }
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::PrepStep::Pipe(v) => Ok((
+                fb::PrepStep::PrepStepPipe,
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::PrepStep> for odf::source::PrepStep {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::PrepStep) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::PrepStep,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::PrepStep::PrepStepDecompress => {
-                odf::source::PrepStep::Decompress(odf::source::PrepStepDecompress::deserialize(
-                    unsafe { fb::PrepStepDecompress::init_from_table(table) },
-                ))
-            }
-            fb::PrepStep::PrepStepPipe => {
-                odf::source::PrepStep::Pipe(odf::source::PrepStepPipe::deserialize(unsafe {
-                    fb::PrepStepPipe::init_from_table(table)
-                }))
+                odf::source::PrepStepDecompress::deserialize(unsafe {
+                    fb::PrepStepDecompress::init_from_table(table)
+                })
+                .map(odf::source::PrepStep::Decompress)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            fb::PrepStep::PrepStepPipe => odf::source::PrepStepPipe::deserialize(unsafe {
+                fb::PrepStepPipe::init_from_table(table)
+            })
+            .map(odf::source::PrepStep::Pipe),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "PrepStep",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -4140,23 +7095,34 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::PrepStep> for odf::source::Prep
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::PrepStepDecompress {
     type OffsetT = WIPOffset<fb::PrepStepDecompress<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let sub_path_offset = self.sub_path.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let sub_path_offset = match &self.sub_path {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::PrepStepDecompressBuilder::new(fb);
-        builder.add_format(self.format.into());
+        builder.add_format(fb::CompressionFormat::try_from(&self.format)?);
         sub_path_offset.map(|off| builder.add_sub_path(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::PrepStepDecompress<'fb>>
     for odf::source::PrepStepDecompress
 {
-    fn deserialize(proxy: fb::PrepStepDecompress<'fb>) -> Self {
-        odf::source::PrepStepDecompress {
-            format: proxy.format().into(),
-            sub_path: proxy.sub_path().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::PrepStepDecompress<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::PrepStepDecompress {
+            format: odf::source::CompressionFormat::try_from(proxy.format())
+                .map_err(|e: FlatbuffersDecodeError| e.at("PrepStepDecompress.format"))?,
+            sub_path: proxy
+                .sub_path()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PrepStepDecompress.subPath"))?,
+        })
     }
 }
 
@@ -4168,25 +7134,33 @@ impl<'fb> FlatbuffersDeserializable<fb::PrepStepDecompress<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::PrepStepPipe {
     type OffsetT = WIPOffset<fb::PrepStepPipe<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let command_offset = {
-            let offsets: Vec<_> = self.command.iter().map(|i| fb.create_string(&i)).collect();
+            let offsets = self
+                .command
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::PrepStepPipeBuilder::new(fb);
         builder.add_command(command_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::PrepStepPipe<'fb>> for odf::source::PrepStepPipe {
-    fn deserialize(proxy: fb::PrepStepPipe<'fb>) -> Self {
-        odf::source::PrepStepPipe {
+    fn deserialize(proxy: fb::PrepStepPipe<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::PrepStepPipe {
             command: proxy
                 .command()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .map_err(|e: FlatbuffersDecodeError| e.at("PrepStepPipe.command"))?,
+        })
     }
 }
 
@@ -4198,36 +7172,45 @@ impl<'fb> FlatbuffersDeserializable<fb::PrepStepPipe<'fb>> for odf::source::Prep
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::ProjectionSpec {
     type OffsetT = WIPOffset<fb::ProjectionSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let inputs_offset = {
-            let offsets: Vec<_> = self.inputs.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .inputs
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let project_offset = { self.project.serialize(fb) };
+        let project_offset = { self.project.serialize(fb)? };
         let mut builder = fb::ProjectionSpecBuilder::new(fb);
         builder.add_inputs(inputs_offset);
         builder.add_project_type(project_offset.0);
         builder.add_project(project_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ProjectionSpec<'fb>> for odf::dataset::ProjectionSpec {
-    fn deserialize(proxy: fb::ProjectionSpec<'fb>) -> Self {
-        odf::dataset::ProjectionSpec {
+    fn deserialize(proxy: fb::ProjectionSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::ProjectionSpec {
             inputs: proxy
                 .inputs()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::dataset::TransformInput::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpec.inputs"))?,
             project: proxy
                 .project()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.project_type()))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::Transform::deserialize(v, proxy.project_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpec.project"))?,
+        })
     }
 }
 
@@ -4239,38 +7222,150 @@ impl<'fb> FlatbuffersDeserializable<fb::ProjectionSpec<'fb>> for odf::dataset::P
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::ProjectionSpecInput {
     type OffsetT = WIPOffset<fb::ProjectionSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let inputs_offset = {
-            let offsets: Vec<_> = self.inputs.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .inputs
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let project_offset = { self.project.serialize(fb) };
+        let project_offset = { self.project.serialize(fb)? };
         let mut builder = fb::ProjectionSpecInputBuilder::new(fb);
         builder.add_inputs(inputs_offset);
         builder.add_project_type(project_offset.0);
         builder.add_project(project_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ProjectionSpecInput<'fb>>
     for odf::dataset::ProjectionSpecInput
 {
-    fn deserialize(proxy: fb::ProjectionSpecInput<'fb>) -> Self {
-        odf::dataset::ProjectionSpecInput {
+    fn deserialize(proxy: fb::ProjectionSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::ProjectionSpecInput {
             inputs: proxy
                 .inputs()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::dataset::TransformInput::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpecInput.inputs"))?,
             project: proxy
                 .project()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.project_type()))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::Transform::deserialize(v, proxy.project_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ProjectionSpecInput.project"))?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PullObjectTransferStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PullObjectTransferStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::PullObjectTransferStrategy {
+    type OffsetT = WIPOffset<fb::PullObjectTransferStrategy<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_file_offset = { self.object_file.serialize(fb)? };
+        let download_from_offset = { self.download_from.serialize(fb)? };
+        let mut builder = fb::PullObjectTransferStrategyBuilder::new(fb);
+        builder.add_object_file(object_file_offset);
+        builder.add_pull_strategy(fb::ObjectPullStrategy::try_from(&self.pull_strategy)?);
+        builder.add_download_from(download_from_offset);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::PullObjectTransferStrategy<'fb>>
+    for odf::protocol::PullObjectTransferStrategy
+{
+    fn deserialize(
+        proxy: fb::PullObjectTransferStrategy<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::PullObjectTransferStrategy {
+            object_file: proxy
+                .object_file()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::ObjectFileReference::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PullObjectTransferStrategy.objectFile")
+                })?,
+            pull_strategy: odf::protocol::ObjectPullStrategy::try_from(proxy.pull_strategy())
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PullObjectTransferStrategy.pullStrategy")
+                })?,
+            download_from: proxy
+                .download_from()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::TransferUrl::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PullObjectTransferStrategy.downloadFrom")
+                })?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// PushObjectTransferStrategy
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PushObjectTransferStrategy
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::PushObjectTransferStrategy {
+    type OffsetT = WIPOffset<fb::PushObjectTransferStrategy<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let object_file_offset = { self.object_file.serialize(fb)? };
+        let upload_to_offset = match &self.upload_to {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let mut builder = fb::PushObjectTransferStrategyBuilder::new(fb);
+        builder.add_object_file(object_file_offset);
+        builder.add_push_strategy(fb::ObjectPushStrategy::try_from(&self.push_strategy)?);
+        upload_to_offset.map(|off| builder.add_upload_to(off));
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::PushObjectTransferStrategy<'fb>>
+    for odf::protocol::PushObjectTransferStrategy
+{
+    fn deserialize(
+        proxy: fb::PushObjectTransferStrategy<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::PushObjectTransferStrategy {
+            object_file: proxy
+                .object_file()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::protocol::ObjectFileReference::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PushObjectTransferStrategy.objectFile")
+                })?,
+            push_strategy: odf::protocol::ObjectPushStrategy::try_from(proxy.push_strategy())
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("PushObjectTransferStrategy.pushStrategy")
+                })?,
+            upload_to: proxy
+                .upload_to()
+                .map(|v| odf::protocol::TransferUrl::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("PushObjectTransferStrategy.uploadTo"))?,
+        })
     }
 }
 
@@ -4282,39 +7377,63 @@ impl<'fb> FlatbuffersDeserializable<fb::ProjectionSpecInput<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::RawQueryRequest {
     type OffsetT = WIPOffset<fb::RawQueryRequest<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let input_data_paths_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .input_data_paths
                 .iter()
-                .map(|i| fb.create_string(i.to_str().unwrap()))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    Ok({
+                        fb.create_string(i.to_str().ok_or_else(|| {
+                            FlatbuffersEncodeError::invalid_value("Path is not valid UTF-8")
+                        })?)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let transform_offset = { self.transform.serialize(fb) };
-        let output_data_path_offset = { fb.create_string(self.output_data_path.to_str().unwrap()) };
+        let transform_offset = { self.transform.serialize(fb)? };
+        let output_data_path_offset =
+            {
+                fb.create_string(self.output_data_path.to_str().ok_or_else(|| {
+                    FlatbuffersEncodeError::invalid_value("Path is not valid UTF-8")
+                })?)
+            };
         let mut builder = fb::RawQueryRequestBuilder::new(fb);
         builder.add_input_data_paths(input_data_paths_offset);
         builder.add_transform_type(transform_offset.0);
         builder.add_transform(transform_offset.1);
         builder.add_output_data_path(output_data_path_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RawQueryRequest<'fb>> for odf::engine::RawQueryRequest {
-    fn deserialize(proxy: fb::RawQueryRequest<'fb>) -> Self {
-        odf::engine::RawQueryRequest {
+    fn deserialize(proxy: fb::RawQueryRequest<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::RawQueryRequest {
             input_data_paths: proxy
                 .input_data_paths()
-                .map(|v| v.iter().map(|i| PathBuf::from(i)).collect())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| Ok(PathBuf::from(i)))
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryRequest.inputDataPaths"))?,
             transform: proxy
                 .transform()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.transform_type()))
-                .unwrap(),
-            output_data_path: proxy.output_data_path().map(|v| PathBuf::from(v)).unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::Transform::deserialize(v, proxy.transform_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryRequest.transform"))?,
+            output_data_path: proxy
+                .output_data_path()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(PathBuf::from(v)))
+                .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryRequest.outputDataPath"))?,
+        })
     }
 }
 
@@ -4327,24 +7446,24 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::RawQueryResponse> for odf::engine
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::RawQueryResponse, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::RawQueryResponse, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::engine::RawQueryResponse::Progress(v) => (
+            odf::engine::RawQueryResponse::Progress(v) => Ok((
                 fb::RawQueryResponse::RawQueryResponseProgress,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::engine::RawQueryResponse::Success(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::engine::RawQueryResponse::Success(v) => Ok((
                 fb::RawQueryResponse::RawQueryResponseSuccess,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::engine::RawQueryResponse::InvalidQuery(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::engine::RawQueryResponse::InvalidQuery(v) => Ok((
                 fb::RawQueryResponse::RawQueryResponseInvalidQuery,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::engine::RawQueryResponse::InternalError(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::engine::RawQueryResponse::InternalError(v) => Ok((
                 fb::RawQueryResponse::RawQueryResponseInternalError,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
@@ -4352,37 +7471,41 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::RawQueryResponse> for odf::engine
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::RawQueryResponse>
     for odf::engine::RawQueryResponse
 {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::RawQueryResponse) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::RawQueryResponse,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::RawQueryResponse::RawQueryResponseProgress => {
-                odf::engine::RawQueryResponse::Progress(
-                    odf::engine::RawQueryResponseProgress::deserialize(unsafe {
-                        fb::RawQueryResponseProgress::init_from_table(table)
-                    }),
-                )
+                odf::engine::RawQueryResponseProgress::deserialize(unsafe {
+                    fb::RawQueryResponseProgress::init_from_table(table)
+                })
+                .map(odf::engine::RawQueryResponse::Progress)
             }
             fb::RawQueryResponse::RawQueryResponseSuccess => {
-                odf::engine::RawQueryResponse::Success(
-                    odf::engine::RawQueryResponseSuccess::deserialize(unsafe {
-                        fb::RawQueryResponseSuccess::init_from_table(table)
-                    }),
-                )
+                odf::engine::RawQueryResponseSuccess::deserialize(unsafe {
+                    fb::RawQueryResponseSuccess::init_from_table(table)
+                })
+                .map(odf::engine::RawQueryResponse::Success)
             }
             fb::RawQueryResponse::RawQueryResponseInvalidQuery => {
-                odf::engine::RawQueryResponse::InvalidQuery(
-                    odf::engine::RawQueryResponseInvalidQuery::deserialize(unsafe {
-                        fb::RawQueryResponseInvalidQuery::init_from_table(table)
-                    }),
-                )
+                odf::engine::RawQueryResponseInvalidQuery::deserialize(unsafe {
+                    fb::RawQueryResponseInvalidQuery::init_from_table(table)
+                })
+                .map(odf::engine::RawQueryResponse::InvalidQuery)
             }
             fb::RawQueryResponse::RawQueryResponseInternalError => {
-                odf::engine::RawQueryResponse::InternalError(
-                    odf::engine::RawQueryResponseInternalError::deserialize(unsafe {
-                        fb::RawQueryResponseInternalError::init_from_table(table)
-                    }),
-                )
+                odf::engine::RawQueryResponseInternalError::deserialize(unsafe {
+                    fb::RawQueryResponseInternalError::init_from_table(table)
+                })
+                .map(odf::engine::RawQueryResponse::InternalError)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "RawQueryResponse",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -4395,24 +7518,44 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::RawQueryResponse>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::RawQueryResponseInternalError {
     type OffsetT = WIPOffset<fb::RawQueryResponseInternalError<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let message_offset = { fb.create_string(&self.message) };
-        let backtrace_offset = self.backtrace.as_ref().map(|v| fb.create_string(&v));
+        let backtrace_offset = match &self.backtrace {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::RawQueryResponseInternalErrorBuilder::new(fb);
         builder.add_message(message_offset);
         backtrace_offset.map(|off| builder.add_backtrace(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RawQueryResponseInternalError<'fb>>
     for odf::engine::RawQueryResponseInternalError
 {
-    fn deserialize(proxy: fb::RawQueryResponseInternalError<'fb>) -> Self {
-        odf::engine::RawQueryResponseInternalError {
-            message: proxy.message().map(|v| v.to_owned()).unwrap(),
-            backtrace: proxy.backtrace().map(|v| v.to_owned()),
-        }
+    fn deserialize(
+        proxy: fb::RawQueryResponseInternalError<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::RawQueryResponseInternalError {
+            message: proxy
+                .message()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("RawQueryResponseInternalError.message")
+                })?,
+            backtrace: proxy
+                .backtrace()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("RawQueryResponseInternalError.backtrace")
+                })?,
+        })
     }
 }
 
@@ -4424,21 +7567,32 @@ impl<'fb> FlatbuffersDeserializable<fb::RawQueryResponseInternalError<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::RawQueryResponseInvalidQuery {
     type OffsetT = WIPOffset<fb::RawQueryResponseInvalidQuery<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let message_offset = { fb.create_string(&self.message) };
         let mut builder = fb::RawQueryResponseInvalidQueryBuilder::new(fb);
         builder.add_message(message_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RawQueryResponseInvalidQuery<'fb>>
     for odf::engine::RawQueryResponseInvalidQuery
 {
-    fn deserialize(proxy: fb::RawQueryResponseInvalidQuery<'fb>) -> Self {
-        odf::engine::RawQueryResponseInvalidQuery {
-            message: proxy.message().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(
+        proxy: fb::RawQueryResponseInvalidQuery<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::RawQueryResponseInvalidQuery {
+            message: proxy
+                .message()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("RawQueryResponseInvalidQuery.message")
+                })?,
+        })
     }
 }
 
@@ -4450,17 +7604,22 @@ impl<'fb> FlatbuffersDeserializable<fb::RawQueryResponseInvalidQuery<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::RawQueryResponseProgress {
     type OffsetT = WIPOffset<fb::RawQueryResponseProgress<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::RawQueryResponseProgressBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RawQueryResponseProgress<'fb>>
     for odf::engine::RawQueryResponseProgress
 {
-    fn deserialize(proxy: fb::RawQueryResponseProgress<'fb>) -> Self {
-        odf::engine::RawQueryResponseProgress {}
+    fn deserialize(
+        proxy: fb::RawQueryResponseProgress<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::RawQueryResponseProgress {})
     }
 }
 
@@ -4472,20 +7631,26 @@ impl<'fb> FlatbuffersDeserializable<fb::RawQueryResponseProgress<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::RawQueryResponseSuccess {
     type OffsetT = WIPOffset<fb::RawQueryResponseSuccess<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::RawQueryResponseSuccessBuilder::new(fb);
         builder.add_num_records(self.num_records);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RawQueryResponseSuccess<'fb>>
     for odf::engine::RawQueryResponseSuccess
 {
-    fn deserialize(proxy: fb::RawQueryResponseSuccess<'fb>) -> Self {
-        odf::engine::RawQueryResponseSuccess {
-            num_records: proxy.num_records(),
-        }
+    fn deserialize(
+        proxy: fb::RawQueryResponseSuccess<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::RawQueryResponseSuccess {
+            num_records: Ok(proxy.num_records())
+                .map_err(|e: FlatbuffersDecodeError| e.at("RawQueryResponseSuccess.numRecords"))?,
+        })
     }
 }
 
@@ -4498,77 +7663,83 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::ReadStep> for odf::source::ReadSt
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::ReadStep, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::ReadStep, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
             odf::source::ReadStep::Csv(v) => {
-                (fb::ReadStep::ReadStepCsv, v.serialize(fb).as_union_value())
+                Ok((fb::ReadStep::ReadStepCsv, v.serialize(fb)?.as_union_value()))
             }
-            odf::source::ReadStep::GeoJson(v) => (
+            odf::source::ReadStep::GeoJson(v) => Ok((
                 fb::ReadStep::ReadStepGeoJson,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::ReadStep::EsriShapefile(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::ReadStep::EsriShapefile(v) => Ok((
                 fb::ReadStep::ReadStepEsriShapefile,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::ReadStep::Parquet(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::ReadStep::Parquet(v) => Ok((
                 fb::ReadStep::ReadStepParquet,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::ReadStep::Json(v) => {
-                (fb::ReadStep::ReadStepJson, v.serialize(fb).as_union_value())
-            }
-            odf::source::ReadStep::NdJson(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::ReadStep::Json(v) => Ok((
+                fb::ReadStep::ReadStepJson,
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::ReadStep::NdJson(v) => Ok((
                 fb::ReadStep::ReadStepNdJson,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::source::ReadStep::NdGeoJson(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::source::ReadStep::NdGeoJson(v) => Ok((
                 fb::ReadStep::ReadStepNdGeoJson,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::ReadStep> for odf::source::ReadStep {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::ReadStep) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::ReadStep,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
-            fb::ReadStep::ReadStepCsv => {
-                odf::source::ReadStep::Csv(odf::source::ReadStepCsv::deserialize(unsafe {
-                    fb::ReadStepCsv::init_from_table(table)
-                }))
-            }
-            fb::ReadStep::ReadStepGeoJson => {
-                odf::source::ReadStep::GeoJson(odf::source::ReadStepGeoJson::deserialize(unsafe {
-                    fb::ReadStepGeoJson::init_from_table(table)
-                }))
-            }
-            fb::ReadStep::ReadStepEsriShapefile => odf::source::ReadStep::EsriShapefile(
+            fb::ReadStep::ReadStepCsv => odf::source::ReadStepCsv::deserialize(unsafe {
+                fb::ReadStepCsv::init_from_table(table)
+            })
+            .map(odf::source::ReadStep::Csv),
+            fb::ReadStep::ReadStepGeoJson => odf::source::ReadStepGeoJson::deserialize(unsafe {
+                fb::ReadStepGeoJson::init_from_table(table)
+            })
+            .map(odf::source::ReadStep::GeoJson),
+            fb::ReadStep::ReadStepEsriShapefile => {
                 odf::source::ReadStepEsriShapefile::deserialize(unsafe {
                     fb::ReadStepEsriShapefile::init_from_table(table)
-                }),
-            ),
-            fb::ReadStep::ReadStepParquet => {
-                odf::source::ReadStep::Parquet(odf::source::ReadStepParquet::deserialize(unsafe {
-                    fb::ReadStepParquet::init_from_table(table)
-                }))
-            }
-            fb::ReadStep::ReadStepJson => {
-                odf::source::ReadStep::Json(odf::source::ReadStepJson::deserialize(unsafe {
-                    fb::ReadStepJson::init_from_table(table)
-                }))
-            }
-            fb::ReadStep::ReadStepNdJson => {
-                odf::source::ReadStep::NdJson(odf::source::ReadStepNdJson::deserialize(unsafe {
-                    fb::ReadStepNdJson::init_from_table(table)
-                }))
+                })
+                .map(odf::source::ReadStep::EsriShapefile)
             }
+            fb::ReadStep::ReadStepParquet => odf::source::ReadStepParquet::deserialize(unsafe {
+                fb::ReadStepParquet::init_from_table(table)
+            })
+            .map(odf::source::ReadStep::Parquet),
+            fb::ReadStep::ReadStepJson => odf::source::ReadStepJson::deserialize(unsafe {
+                fb::ReadStepJson::init_from_table(table)
+            })
+            .map(odf::source::ReadStep::Json),
+            fb::ReadStep::ReadStepNdJson => odf::source::ReadStepNdJson::deserialize(unsafe {
+                fb::ReadStepNdJson::init_from_table(table)
+            })
+            .map(odf::source::ReadStep::NdJson),
             fb::ReadStep::ReadStepNdGeoJson => {
-                odf::source::ReadStep::NdGeoJson(odf::source::ReadStepNdGeoJson::deserialize(
-                    unsafe { fb::ReadStepNdGeoJson::init_from_table(table) },
-                ))
+                odf::source::ReadStepNdGeoJson::deserialize(unsafe {
+                    fb::ReadStepNdGeoJson::init_from_table(table)
+                })
+                .map(odf::source::ReadStep::NdGeoJson)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "ReadStep",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -4581,19 +7752,52 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::ReadStep> for odf::source::Read
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepCsv {
     type OffsetT = WIPOffset<fb::ReadStepCsv<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let ddl_schema_offset = self.ddl_schema.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let separator_offset = self.separator.as_ref().map(|v| fb.create_string(&v));
-        let encoding_offset = self.encoding.as_ref().map(|v| fb.create_string(&v));
-        let quote_offset = self.quote.as_ref().map(|v| fb.create_string(&v));
-        let escape_offset = self.escape.as_ref().map(|v| fb.create_string(&v));
-        let null_value_offset = self.null_value.as_ref().map(|v| fb.create_string(&v));
-        let date_format_offset = self.date_format.as_ref().map(|v| fb.create_string(&v));
-        let timestamp_format_offset = self.timestamp_format.as_ref().map(|v| fb.create_string(&v));
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let ddl_schema_offset = match &self.ddl_schema {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let separator_offset = match &self.separator {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let encoding_offset = match &self.encoding {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let quote_offset = match &self.quote {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let escape_offset = match &self.escape {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let null_value_offset = match &self.null_value {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let date_format_offset = match &self.date_format {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let timestamp_format_offset = match &self.timestamp_format {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ReadStepCsvBuilder::new(fb);
         ddl_schema_offset.map(|off| builder.add_ddl_schema(off));
         separator_offset.map(|off| builder.add_separator(off));
@@ -4606,29 +7810,69 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepCsv {
         date_format_offset.map(|off| builder.add_date_format(off));
         timestamp_format_offset.map(|off| builder.add_timestamp_format(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ReadStepCsv<'fb>> for odf::source::ReadStepCsv {
-    fn deserialize(proxy: fb::ReadStepCsv<'fb>) -> Self {
-        odf::source::ReadStepCsv {
+    fn deserialize(proxy: fb::ReadStepCsv<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::ReadStepCsv {
             ddl_schema: proxy
                 .ddl_schema()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-            separator: proxy.separator().map(|v| v.to_owned()),
-            encoding: proxy.encoding().map(|v| v.to_owned()),
-            quote: proxy.quote().map(|v| v.to_owned()),
-            escape: proxy.escape().map(|v| v.to_owned()),
-            header: proxy.header().map(|v| v),
-            infer_schema: proxy.infer_schema().map(|v| v),
-            null_value: proxy.null_value().map(|v| v.to_owned()),
-            date_format: proxy.date_format().map(|v| v.to_owned()),
-            timestamp_format: proxy.timestamp_format().map(|v| v.to_owned()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.ddlSchema"))?,
+            separator: proxy
+                .separator()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.separator"))?,
+            encoding: proxy
+                .encoding()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.encoding"))?,
+            quote: proxy
+                .quote()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.quote"))?,
+            escape: proxy
+                .escape()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.escape"))?,
+            header: proxy
+                .header()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.header"))?,
+            infer_schema: proxy
+                .infer_schema()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.inferSchema"))?,
+            null_value: proxy
+                .null_value()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.nullValue"))?,
+            date_format: proxy
+                .date_format()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.dateFormat"))?,
+            timestamp_format: proxy
+                .timestamp_format()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.timestampFormat"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepCsv.schema"))?,
+        })
     }
 }
 
@@ -4640,34 +7884,57 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepCsv<'fb>> for odf::source::ReadS
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepEsriShapefile {
     type OffsetT = WIPOffset<fb::ReadStepEsriShapefile<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let ddl_schema_offset = self.ddl_schema.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let sub_path_offset = self.sub_path.as_ref().map(|v| fb.create_string(&v));
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let ddl_schema_offset = match &self.ddl_schema {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let sub_path_offset = match &self.sub_path {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ReadStepEsriShapefileBuilder::new(fb);
         ddl_schema_offset.map(|off| builder.add_ddl_schema(off));
         sub_path_offset.map(|off| builder.add_sub_path(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ReadStepEsriShapefile<'fb>>
     for odf::source::ReadStepEsriShapefile
 {
-    fn deserialize(proxy: fb::ReadStepEsriShapefile<'fb>) -> Self {
-        odf::source::ReadStepEsriShapefile {
+    fn deserialize(proxy: fb::ReadStepEsriShapefile<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::ReadStepEsriShapefile {
             ddl_schema: proxy
                 .ddl_schema()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-            sub_path: proxy.sub_path().map(|v| v.to_owned()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepEsriShapefile.ddlSchema"))?,
+            sub_path: proxy
+                .sub_path()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepEsriShapefile.subPath"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepEsriShapefile.schema"))?,
+        })
     }
 }
 
@@ -4679,29 +7946,45 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepEsriShapefile<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepGeoJson {
     type OffsetT = WIPOffset<fb::ReadStepGeoJson<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let ddl_schema_offset = self.ddl_schema.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let ddl_schema_offset = match &self.ddl_schema {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ReadStepGeoJsonBuilder::new(fb);
         ddl_schema_offset.map(|off| builder.add_ddl_schema(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ReadStepGeoJson<'fb>> for odf::source::ReadStepGeoJson {
-    fn deserialize(proxy: fb::ReadStepGeoJson<'fb>) -> Self {
-        odf::source::ReadStepGeoJson {
+    fn deserialize(proxy: fb::ReadStepGeoJson<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::ReadStepGeoJson {
             ddl_schema: proxy
                 .ddl_schema()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepGeoJson.ddlSchema"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepGeoJson.schema"))?,
+        })
     }
 }
 
@@ -4713,16 +7996,40 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepGeoJson<'fb>> for odf::source::R
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepJson {
     type OffsetT = WIPOffset<fb::ReadStepJson<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let sub_path_offset = self.sub_path.as_ref().map(|v| fb.create_string(&v));
-        let ddl_schema_offset = self.ddl_schema.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let date_format_offset = self.date_format.as_ref().map(|v| fb.create_string(&v));
-        let encoding_offset = self.encoding.as_ref().map(|v| fb.create_string(&v));
-        let timestamp_format_offset = self.timestamp_format.as_ref().map(|v| fb.create_string(&v));
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let sub_path_offset = match &self.sub_path {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let ddl_schema_offset = match &self.ddl_schema {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let date_format_offset = match &self.date_format {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let encoding_offset = match &self.encoding {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let timestamp_format_offset = match &self.timestamp_format {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ReadStepJsonBuilder::new(fb);
         sub_path_offset.map(|off| builder.add_sub_path(off));
         ddl_schema_offset.map(|off| builder.add_ddl_schema(off));
@@ -4730,24 +8037,44 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepJson {
         encoding_offset.map(|off| builder.add_encoding(off));
         timestamp_format_offset.map(|off| builder.add_timestamp_format(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ReadStepJson<'fb>> for odf::source::ReadStepJson {
-    fn deserialize(proxy: fb::ReadStepJson<'fb>) -> Self {
-        odf::source::ReadStepJson {
-            sub_path: proxy.sub_path().map(|v| v.to_owned()),
+    fn deserialize(proxy: fb::ReadStepJson<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::ReadStepJson {
+            sub_path: proxy
+                .sub_path()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.subPath"))?,
             ddl_schema: proxy
                 .ddl_schema()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-            date_format: proxy.date_format().map(|v| v.to_owned()),
-            encoding: proxy.encoding().map(|v| v.to_owned()),
-            timestamp_format: proxy.timestamp_format().map(|v| v.to_owned()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.ddlSchema"))?,
+            date_format: proxy
+                .date_format()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.dateFormat"))?,
+            encoding: proxy
+                .encoding()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.encoding"))?,
+            timestamp_format: proxy
+                .timestamp_format()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.timestampFormat"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepJson.schema"))?,
+        })
     }
 }
 
@@ -4759,29 +8086,45 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepJson<'fb>> for odf::source::Read
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepNdGeoJson {
     type OffsetT = WIPOffset<fb::ReadStepNdGeoJson<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let ddl_schema_offset = self.ddl_schema.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let ddl_schema_offset = match &self.ddl_schema {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ReadStepNdGeoJsonBuilder::new(fb);
         ddl_schema_offset.map(|off| builder.add_ddl_schema(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ReadStepNdGeoJson<'fb>> for odf::source::ReadStepNdGeoJson {
-    fn deserialize(proxy: fb::ReadStepNdGeoJson<'fb>) -> Self {
-        odf::source::ReadStepNdGeoJson {
+    fn deserialize(proxy: fb::ReadStepNdGeoJson<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::ReadStepNdGeoJson {
             ddl_schema: proxy
                 .ddl_schema()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdGeoJson.ddlSchema"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdGeoJson.schema"))?,
+        })
     }
 }
 
@@ -4793,38 +8136,75 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepNdGeoJson<'fb>> for odf::source:
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepNdJson {
     type OffsetT = WIPOffset<fb::ReadStepNdJson<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let ddl_schema_offset = self.ddl_schema.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let date_format_offset = self.date_format.as_ref().map(|v| fb.create_string(&v));
-        let encoding_offset = self.encoding.as_ref().map(|v| fb.create_string(&v));
-        let timestamp_format_offset = self.timestamp_format.as_ref().map(|v| fb.create_string(&v));
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let ddl_schema_offset = match &self.ddl_schema {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let date_format_offset = match &self.date_format {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let encoding_offset = match &self.encoding {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let timestamp_format_offset = match &self.timestamp_format {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ReadStepNdJsonBuilder::new(fb);
         ddl_schema_offset.map(|off| builder.add_ddl_schema(off));
         date_format_offset.map(|off| builder.add_date_format(off));
         encoding_offset.map(|off| builder.add_encoding(off));
         timestamp_format_offset.map(|off| builder.add_timestamp_format(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ReadStepNdJson<'fb>> for odf::source::ReadStepNdJson {
-    fn deserialize(proxy: fb::ReadStepNdJson<'fb>) -> Self {
-        odf::source::ReadStepNdJson {
+    fn deserialize(proxy: fb::ReadStepNdJson<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::ReadStepNdJson {
             ddl_schema: proxy
                 .ddl_schema()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-            date_format: proxy.date_format().map(|v| v.to_owned()),
-            encoding: proxy.encoding().map(|v| v.to_owned()),
-            timestamp_format: proxy.timestamp_format().map(|v| v.to_owned()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.ddlSchema"))?,
+            date_format: proxy
+                .date_format()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.dateFormat"))?,
+            encoding: proxy
+                .encoding()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.encoding"))?,
+            timestamp_format: proxy
+                .timestamp_format()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.timestampFormat"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepNdJson.schema"))?,
+        })
     }
 }
 
@@ -4836,29 +8216,45 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepNdJson<'fb>> for odf::source::Re
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::ReadStepParquet {
     type OffsetT = WIPOffset<fb::ReadStepParquet<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let ddl_schema_offset = self.ddl_schema.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let ddl_schema_offset = match &self.ddl_schema {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ReadStepParquetBuilder::new(fb);
         ddl_schema_offset.map(|off| builder.add_ddl_schema(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ReadStepParquet<'fb>> for odf::source::ReadStepParquet {
-    fn deserialize(proxy: fb::ReadStepParquet<'fb>) -> Self {
-        odf::source::ReadStepParquet {
+    fn deserialize(proxy: fb::ReadStepParquet<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::ReadStepParquet {
             ddl_schema: proxy
                 .ddl_schema()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepParquet.ddlSchema"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ReadStepParquet.schema"))?,
+        })
     }
 }
 
@@ -4870,37 +8266,54 @@ impl<'fb> FlatbuffersDeserializable<fb::ReadStepParquet<'fb>> for odf::source::R
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::Relation {
     type OffsetT = WIPOffset<fb::Relation<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let subject_offset = { self.subject.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let subject_offset = { self.subject.serialize(fb)? };
         let relation_offset = { fb.create_string(&self.relation) };
-        let value_offset = self
-            .value
-            .as_ref()
-            .map(|v| fb.create_string(&serde_json::to_string(&v).unwrap()));
-        let object_offset = { self.object.serialize(fb) };
+        let value_offset = match &self.value {
+            Some(v) => Some({
+                fb.create_string(
+                    &serde_json::to_string(&v).map_err(FlatbuffersEncodeError::invalid_value)?,
+                )
+            }),
+            None => None,
+        };
+        let object_offset = { self.object.serialize(fb)? };
         let mut builder = fb::RelationBuilder::new(fb);
         builder.add_subject(subject_offset);
         builder.add_relation(relation_offset);
         value_offset.map(|off| builder.add_value(off));
         builder.add_object(object_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Relation<'fb>> for odf::auth::Relation {
-    fn deserialize(proxy: fb::Relation<'fb>) -> Self {
-        odf::auth::Relation {
+    fn deserialize(proxy: fb::Relation<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::Relation {
             subject: proxy
                 .subject()
-                .map(|v| odf::resource::ResourceHandle::deserialize(v))
-                .unwrap(),
-            relation: proxy.relation().map(|v| v.to_owned()).unwrap(),
-            value: proxy.value().map(|v| serde_json::from_str(v).unwrap()),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Relation.subject"))?,
+            relation: proxy
+                .relation()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Relation.relation"))?,
+            value: proxy
+                .value()
+                .map(|v| serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("Relation.value"))?,
             object: proxy
                 .object()
-                .map(|v| odf::resource::ResourceHandle::deserialize(v))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Relation.object"))?,
+        })
     }
 }
 
@@ -4912,37 +8325,54 @@ impl<'fb> FlatbuffersDeserializable<fb::Relation<'fb>> for odf::auth::Relation {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::RelationInput {
     type OffsetT = WIPOffset<fb::RelationInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let subject_offset = { self.subject.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let subject_offset = { self.subject.serialize(fb)? };
         let relation_offset = { fb.create_string(&self.relation) };
-        let value_offset = self
-            .value
-            .as_ref()
-            .map(|v| fb.create_string(&serde_json::to_string(&v).unwrap()));
-        let object_offset = { self.object.serialize(fb) };
+        let value_offset = match &self.value {
+            Some(v) => Some({
+                fb.create_string(
+                    &serde_json::to_string(&v).map_err(FlatbuffersEncodeError::invalid_value)?,
+                )
+            }),
+            None => None,
+        };
+        let object_offset = { self.object.serialize(fb)? };
         let mut builder = fb::RelationInputBuilder::new(fb);
         builder.add_subject(subject_offset);
         builder.add_relation(relation_offset);
         value_offset.map(|off| builder.add_value(off));
         builder.add_object(object_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RelationInput<'fb>> for odf::auth::RelationInput {
-    fn deserialize(proxy: fb::RelationInput<'fb>) -> Self {
-        odf::auth::RelationInput {
+    fn deserialize(proxy: fb::RelationInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::RelationInput {
             subject: proxy
                 .subject()
-                .map(|v| odf::resource::ResourceRef::deserialize(v))
-                .unwrap(),
-            relation: proxy.relation().map(|v| v.to_owned()).unwrap(),
-            value: proxy.value().map(|v| serde_json::from_str(v).unwrap()),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceRef::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.subject"))?,
+            relation: proxy
+                .relation()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.relation"))?,
+            value: proxy
+                .value()
+                .map(|v| serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.value"))?,
             object: proxy
                 .object()
-                .map(|v| odf::resource::ResourceRef::deserialize(v))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceRef::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationInput.object"))?,
+        })
     }
 }
 
@@ -4954,42 +8384,55 @@ impl<'fb> FlatbuffersDeserializable<fb::RelationInput<'fb>> for odf::auth::Relat
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::RelationsSpec {
     type OffsetT = WIPOffset<fb::RelationsSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let relations_offset = {
-            let offsets: Vec<_> = self.relations.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .relations
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let attributes_offset = {
-            let offsets: Vec<_> = self.attributes.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .attributes
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::RelationsSpecBuilder::new(fb);
         builder.add_relations(relations_offset);
         builder.add_attributes(attributes_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RelationsSpec<'fb>> for odf::auth::RelationsSpec {
-    fn deserialize(proxy: fb::RelationsSpec<'fb>) -> Self {
-        odf::auth::RelationsSpec {
+    fn deserialize(proxy: fb::RelationsSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::RelationsSpec {
             relations: proxy
                 .relations()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::auth::Relation::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpec.relations"))?,
             attributes: proxy
                 .attributes()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::auth::Attribute::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpec.attributes"))?,
+        })
     }
 }
 
@@ -5001,36 +8444,59 @@ impl<'fb> FlatbuffersDeserializable<fb::RelationsSpec<'fb>> for odf::auth::Relat
 impl<'fb> FlatbuffersSerializable<'fb> for odf::auth::RelationsSpecInput {
     type OffsetT = WIPOffset<fb::RelationsSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let relations_offset = self.relations.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
-        let attributes_offset = self.attributes.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let relations_offset = match &self.relations {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let attributes_offset = match &self.attributes {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::RelationsSpecInputBuilder::new(fb);
         relations_offset.map(|off| builder.add_relations(off));
         attributes_offset.map(|off| builder.add_attributes(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RelationsSpecInput<'fb>> for odf::auth::RelationsSpecInput {
-    fn deserialize(proxy: fb::RelationsSpecInput<'fb>) -> Self {
-        odf::auth::RelationsSpecInput {
-            relations: proxy.relations().map(|v| {
-                v.iter()
-                    .map(|i| odf::auth::RelationInput::deserialize(i))
-                    .collect()
-            }),
-            attributes: proxy.attributes().map(|v| {
-                v.iter()
-                    .map(|i| odf::auth::AttributeInput::deserialize(i))
-                    .collect()
-            }),
-        }
+    fn deserialize(proxy: fb::RelationsSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::auth::RelationsSpecInput {
+            relations: proxy
+                .relations()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::auth::RelationInput::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpecInput.relations"))?,
+            attributes: proxy
+                .attributes()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::auth::AttributeInput::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("RelationsSpecInput.attributes"))?,
+        })
     }
 }
 
@@ -5042,22 +8508,33 @@ impl<'fb> FlatbuffersDeserializable<fb::RelationsSpecInput<'fb>> for odf::auth::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::RequestHeader {
     type OffsetT = WIPOffset<fb::RequestHeader<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let name_offset = { fb.create_string(&self.name) };
         let value_offset = { fb.create_string(&self.value) };
         let mut builder = fb::RequestHeaderBuilder::new(fb);
         builder.add_name(name_offset);
         builder.add_value(value_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::RequestHeader<'fb>> for odf::source::RequestHeader {
-    fn deserialize(proxy: fb::RequestHeader<'fb>) -> Self {
-        odf::source::RequestHeader {
-            name: proxy.name().map(|v| v.to_owned()).unwrap(),
-            value: proxy.value().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(proxy: fb::RequestHeader<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::RequestHeader {
+            name: proxy
+                .name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("RequestHeader.name"))?,
+            value: proxy
+                .value()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("RequestHeader.value"))?,
+        })
     }
 }
 
@@ -5069,42 +8546,55 @@ impl<'fb> FlatbuffersDeserializable<fb::RequestHeader<'fb>> for odf::source::Req
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceAnnotations {
     type OffsetT = WIPOffset<fb::ResourceAnnotations<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = fb.create_string(&serde_json::to_string(value).unwrap());
+                let value_offset = fb.create_string(
+                    &serde_json::to_string(value).map_err(FlatbuffersEncodeError::invalid_value)?,
+                );
                 let mut entry_builder = fb::ResourceAnnotationsEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
         let mut builder = fb::ResourceAnnotationsBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceAnnotations<'fb>>
     for odf::resource::ResourceAnnotations
 {
-    fn deserialize(proxy: fb::ResourceAnnotations<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
-                .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = serde_json::from_str(entry.value().unwrap()).unwrap();
-                    (key, value)
-                })
-                .collect(),
-        }
+    fn deserialize(proxy: fb::ResourceAnnotations<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| {
+                        serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value)
+                    })?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("ResourceAnnotations.entries"))?;
+        Ok(Self { entries })
     }
 }
 
@@ -5116,42 +8606,55 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceAnnotations<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceConditions {
     type OffsetT = WIPOffset<fb::ResourceConditions<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = fb.create_string(&serde_json::to_string(value).unwrap());
+                let value_offset = fb.create_string(
+                    &serde_json::to_string(value).map_err(FlatbuffersEncodeError::invalid_value)?,
+                );
                 let mut entry_builder = fb::ResourceConditionsEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
         let mut builder = fb::ResourceConditionsBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceConditions<'fb>>
     for odf::resource::ResourceConditions
 {
-    fn deserialize(proxy: fb::ResourceConditions<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
-                .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = serde_json::from_str(entry.value().unwrap()).unwrap();
-                    (key, value)
-                })
-                .collect(),
-        }
+    fn deserialize(proxy: fb::ResourceConditions<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| {
+                        serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value)
+                    })?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("ResourceConditions.entries"))?;
+        Ok(Self { entries })
     }
 }
 
@@ -5163,11 +8666,17 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceConditions<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceHandle {
     type OffsetT = WIPOffset<fb::ResourceHandle<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let account_offset = { self.account.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let account_offset = { self.account.serialize(fb)? };
         let r#type_offset = { fb.create_string(&self.r#type.to_string()) };
         let id_offset = { fb.create_vector(&self.id.as_bytes()) };
-        let did_offset = self.did.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
+        let did_offset = match &self.did {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
         let name_offset = { fb.create_string(&self.name.to_string()) };
         let mut builder = fb::ResourceHandleBuilder::new(fb);
         builder.add_account(account_offset);
@@ -5175,33 +8684,50 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceHandle {
         builder.add_id(id_offset);
         did_offset.map(|off| builder.add_did(off));
         builder.add_name(name_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceHandle<'fb>> for odf::resource::ResourceHandle {
-    fn deserialize(proxy: fb::ResourceHandle<'fb>) -> Self {
-        odf::resource::ResourceHandle {
+    fn deserialize(proxy: fb::ResourceHandle<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::ResourceHandle {
             account: proxy
                 .account()
-                .map(|v| odf::auth::AccountHandle::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::auth::AccountHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.account"))?,
             r#type: proxy
                 .type_()
-                .map(|v| odf::resource::TypeUri::try_from(v).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::TypeUri::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.type"))?,
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.id"))?,
             did: proxy
                 .did()
-                .map(|v| odf::Did::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::Did::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.did"))?,
             name: proxy
                 .name()
-                .map(|v| odf::resource::ResourceName::try_from(v).unwrap())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHandle.name"))?,
+        })
     }
 }
 
@@ -5213,12 +8739,15 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceHandle<'fb>> for odf::resource::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceHeaders {
     type OffsetT = WIPOffset<fb::ResourceHeaders<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let id_offset = { fb.create_vector(&self.id.as_bytes()) };
         let name_offset = { fb.create_string(&self.name.to_string()) };
-        let account_offset = { self.account.serialize(fb) };
-        let labels_offset = { self.labels.serialize(fb) };
-        let annotations_offset = { self.annotations.serialize(fb) };
+        let account_offset = { self.account.serialize(fb)? };
+        let labels_offset = { self.labels.serialize(fb)? };
+        let annotations_offset = { self.annotations.serialize(fb)? };
         let mut builder = fb::ResourceHeadersBuilder::new(fb);
         builder.add_id(id_offset);
         builder.add_name(name_offset);
@@ -5230,38 +8759,62 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceHeaders {
         builder.add_updated_at(&datetime_to_fb(&self.updated_at));
         self.deleted_at
             .map(|v| builder.add_deleted_at(&datetime_to_fb(&v)));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceHeaders<'fb>> for odf::resource::ResourceHeaders {
-    fn deserialize(proxy: fb::ResourceHeaders<'fb>) -> Self {
-        odf::resource::ResourceHeaders {
+    fn deserialize(proxy: fb::ResourceHeaders<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::ResourceHeaders {
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.id"))?,
             name: proxy
                 .name()
-                .map(|v| odf::resource::ResourceName::try_from(v).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.name"))?,
             account: proxy
                 .account()
-                .map(|v| odf::auth::AccountHandle::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::auth::AccountHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.account"))?,
             labels: proxy
                 .labels()
-                .map(|v| odf::resource::ResourceLabels::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceLabels::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.labels"))?,
             annotations: proxy
                 .annotations()
-                .map(|v| odf::resource::ResourceAnnotations::deserialize(v))
-                .unwrap(),
-            generation: proxy.generation(),
-            created_at: proxy.created_at().map(|v| fb_to_datetime(v)).unwrap(),
-            updated_at: proxy.updated_at().map(|v| fb_to_datetime(v)).unwrap(),
-            deleted_at: proxy.deleted_at().map(|v| fb_to_datetime(v)),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceAnnotations::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.annotations"))?,
+            generation: Ok(proxy.generation())
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.generation"))?,
+            created_at: proxy
+                .created_at()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| fb_to_datetime(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.createdAt"))?,
+            updated_at: proxy
+                .updated_at()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| fb_to_datetime(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.updatedAt"))?,
+            deleted_at: proxy
+                .deleted_at()
+                .map(|v| fb_to_datetime(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeaders.deletedAt"))?,
+        })
     }
 }
 
@@ -5273,44 +8826,74 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceHeaders<'fb>> for odf::resource:
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceHeadersInput {
     type OffsetT = WIPOffset<fb::ResourceHeadersInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let id_offset = self.id.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let id_offset = match &self.id {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
         let name_offset = { fb.create_string(&self.name.to_string()) };
-        let account_offset = self.account.as_ref().map(|v| v.serialize(fb));
-        let labels_offset = self.labels.as_ref().map(|v| v.serialize(fb));
-        let annotations_offset = self.annotations.as_ref().map(|v| v.serialize(fb));
+        let account_offset = match &self.account {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let labels_offset = match &self.labels {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let annotations_offset = match &self.annotations {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::ResourceHeadersInputBuilder::new(fb);
         id_offset.map(|off| builder.add_id(off));
         builder.add_name(name_offset);
         account_offset.map(|off| builder.add_account(off));
         labels_offset.map(|off| builder.add_labels(off));
         annotations_offset.map(|off| builder.add_annotations(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceHeadersInput<'fb>>
     for odf::resource::ResourceHeadersInput
 {
-    fn deserialize(proxy: fb::ResourceHeadersInput<'fb>) -> Self {
-        odf::resource::ResourceHeadersInput {
+    fn deserialize(proxy: fb::ResourceHeadersInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::ResourceHeadersInput {
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.id"))?,
             name: proxy
                 .name()
-                .map(|v| odf::resource::ResourceName::try_from(v).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.name"))?,
             account: proxy
                 .account()
-                .map(|v| odf::auth::AccountRef::deserialize(v)),
+                .map(|v| odf::auth::AccountRef::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.account"))?,
             labels: proxy
                 .labels()
-                .map(|v| odf::resource::ResourceLabels::deserialize(v)),
+                .map(|v| odf::resource::ResourceLabels::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.labels"))?,
             annotations: proxy
                 .annotations()
-                .map(|v| odf::resource::ResourceAnnotations::deserialize(v)),
-        }
+                .map(|v| odf::resource::ResourceAnnotations::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceHeadersInput.annotations"))?,
+        })
     }
 }
 
@@ -5322,40 +8905,53 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceHeadersInput<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceLabels {
     type OffsetT = WIPOffset<fb::ResourceLabels<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = fb.create_string(&serde_json::to_string(value).unwrap());
+                let value_offset = fb.create_string(
+                    &serde_json::to_string(value).map_err(FlatbuffersEncodeError::invalid_value)?,
+                );
                 let mut entry_builder = fb::ResourceLabelsEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
         let mut builder = fb::ResourceLabelsBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceLabels<'fb>> for odf::resource::ResourceLabels {
-    fn deserialize(proxy: fb::ResourceLabels<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
-                .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = serde_json::from_str(entry.value().unwrap()).unwrap();
-                    (key, value)
-                })
-                .collect(),
-        }
+    fn deserialize(proxy: fb::ResourceLabels<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| {
+                        serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value)
+                    })?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("ResourceLabels.entries"))?;
+        Ok(Self { entries })
     }
 }
 
@@ -5364,25 +8960,34 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceLabels<'fb>> for odf::resource::
 // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourcePhase
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::resource::ResourcePhase> for fb::ResourcePhase {
-    fn from(v: odf::resource::ResourcePhase) -> Self {
+impl TryFrom<&odf::resource::ResourcePhase> for fb::ResourcePhase {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::resource::ResourcePhase) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::resource::ResourcePhase::Pending => fb::ResourcePhase::Pending,
-            odf::resource::ResourcePhase::Reconciling => fb::ResourcePhase::Reconciling,
-            odf::resource::ResourcePhase::Ready => fb::ResourcePhase::Ready,
-            odf::resource::ResourcePhase::Failed => fb::ResourcePhase::Failed,
+            odf::resource::ResourcePhase::Pending => Ok(fb::ResourcePhase::Pending),
+            odf::resource::ResourcePhase::Reconciling => Ok(fb::ResourcePhase::Reconciling),
+            odf::resource::ResourcePhase::Ready => Ok(fb::ResourcePhase::Ready),
+            odf::resource::ResourcePhase::Failed => Ok(fb::ResourcePhase::Failed),
         }
     }
 }
 
-impl Into<odf::resource::ResourcePhase> for fb::ResourcePhase {
-    fn into(self) -> odf::resource::ResourcePhase {
-        match self {
-            fb::ResourcePhase::Pending => odf::resource::ResourcePhase::Pending,
-            fb::ResourcePhase::Reconciling => odf::resource::ResourcePhase::Reconciling,
-            fb::ResourcePhase::Ready => odf::resource::ResourcePhase::Ready,
-            fb::ResourcePhase::Failed => odf::resource::ResourcePhase::Failed,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::ResourcePhase> for odf::resource::ResourcePhase {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::ResourcePhase) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::ResourcePhase::Pending => Ok(odf::resource::ResourcePhase::Pending),
+            fb::ResourcePhase::Reconciling => Ok(odf::resource::ResourcePhase::Reconciling),
+            fb::ResourcePhase::Ready => Ok(odf::resource::ResourcePhase::Ready),
+            fb::ResourcePhase::Failed => Ok(odf::resource::ResourcePhase::Failed),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "ResourcePhase",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
@@ -5395,42 +9000,77 @@ impl Into<odf::resource::ResourcePhase> for fb::ResourcePhase {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceRef {
     type OffsetT = WIPOffset<fb::ResourceRef<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let account_offset = self.account.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let account_offset = match &self.account {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let r#type_offset = { fb.create_string(&self.r#type.to_string()) };
-        let id_offset = self.id.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let did_offset = self.did.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let name_offset = self.name.as_ref().map(|v| fb.create_string(&v.to_string()));
+        let id_offset = match &self.id {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let did_offset = match &self.did {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let name_offset = match &self.name {
+            Some(v) => Some({ fb.create_string(&v.to_string()) }),
+            None => None,
+        };
         let mut builder = fb::ResourceRefBuilder::new(fb);
         account_offset.map(|off| builder.add_account(off));
         builder.add_type_(type_offset);
         id_offset.map(|off| builder.add_id(off));
         did_offset.map(|off| builder.add_did(off));
         name_offset.map(|off| builder.add_name(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceRef<'fb>> for odf::resource::ResourceRef {
-    fn deserialize(proxy: fb::ResourceRef<'fb>) -> Self {
-        odf::resource::ResourceRef {
+    fn deserialize(proxy: fb::ResourceRef<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::ResourceRef {
             account: proxy
                 .account()
-                .map(|v| odf::auth::AccountRef::deserialize(v)),
+                .map(|v| odf::auth::AccountRef::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.account"))?,
             r#type: proxy
                 .type_()
-                .map(|v| odf::resource::TypeRef::try_from(v).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::TypeRef::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.type"))?,
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.id"))?,
             did: proxy
                 .did()
-                .map(|v| odf::Did::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::Did::from_bytes(v.bytes()).map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.did"))?,
             name: proxy
                 .name()
-                .map(|v| odf::resource::ResourceName::try_from(v).unwrap()),
-        }
+                .map(|v| {
+                    odf::resource::ResourceName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceRef.name"))?,
+        })
     }
 }
 
@@ -5442,30 +9082,43 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceRef<'fb>> for odf::resource::Res
 impl<'fb> FlatbuffersSerializable<'fb> for odf::resource::ResourceStatus {
     type OffsetT = WIPOffset<fb::ResourceStatus<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let conditions_offset = { self.conditions.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let conditions_offset = { self.conditions.serialize(fb)? };
         let mut builder = fb::ResourceStatusBuilder::new(fb);
-        builder.add_phase(self.phase.into());
+        builder.add_phase(fb::ResourcePhase::try_from(&self.phase)?);
         self.observed_generation
             .map(|v| builder.add_observed_generation(v));
         self.reconciled_at
             .map(|v| builder.add_reconciled_at(&datetime_to_fb(&v)));
         builder.add_conditions(conditions_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ResourceStatus<'fb>> for odf::resource::ResourceStatus {
-    fn deserialize(proxy: fb::ResourceStatus<'fb>) -> Self {
-        odf::resource::ResourceStatus {
-            phase: proxy.phase().into(),
-            observed_generation: proxy.observed_generation().map(|v| v),
-            reconciled_at: proxy.reconciled_at().map(|v| fb_to_datetime(v)),
+    fn deserialize(proxy: fb::ResourceStatus<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::resource::ResourceStatus {
+            phase: odf::resource::ResourcePhase::try_from(proxy.phase())
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.phase"))?,
+            observed_generation: proxy
+                .observed_generation()
+                .map(|v| Ok(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.observedGeneration"))?,
+            reconciled_at: proxy
+                .reconciled_at()
+                .map(|v| fb_to_datetime(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.reconciledAt"))?,
             conditions: proxy
                 .conditions()
-                .map(|v| odf::resource::ResourceConditions::deserialize(v))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceConditions::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ResourceStatus.conditions"))?,
+        })
     }
 }
 
@@ -5477,22 +9130,36 @@ impl<'fb> FlatbuffersDeserializable<fb::ResourceStatus<'fb>> for odf::resource::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::Secret {
     type OffsetT = WIPOffset<fb::Secret<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let value_offset = { fb.create_string(&self.value) };
-        let content_encoding_offset = self.content_encoding.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let value_offset = { fb.create_string(&self.value.expose()) };
+        let content_encoding_offset = match &self.content_encoding {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::SecretBuilder::new(fb);
         builder.add_value(value_offset);
         content_encoding_offset.map(|off| builder.add_content_encoding(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Secret<'fb>> for odf::config::Secret {
-    fn deserialize(proxy: fb::Secret<'fb>) -> Self {
-        odf::config::Secret {
-            value: proxy.value().map(|v| v.to_owned()).unwrap(),
-            content_encoding: proxy.content_encoding().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::Secret<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::Secret {
+            value: proxy
+                .value()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()).map(odf::dtos::Sensitive::new))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Secret.value"))?,
+            content_encoding: proxy
+                .content_encoding()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("Secret.contentEncoding"))?,
+        })
     }
 }
 
@@ -5504,22 +9171,26 @@ impl<'fb> FlatbuffersDeserializable<fb::Secret<'fb>> for odf::config::Secret {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::SecretSetSpec {
     type OffsetT = WIPOffset<fb::SecretSetSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let secrets_offset = { self.secrets.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let secrets_offset = { self.secrets.serialize(fb)? };
         let mut builder = fb::SecretSetSpecBuilder::new(fb);
         builder.add_secrets(secrets_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SecretSetSpec<'fb>> for odf::config::SecretSetSpec {
-    fn deserialize(proxy: fb::SecretSetSpec<'fb>) -> Self {
-        odf::config::SecretSetSpec {
+    fn deserialize(proxy: fb::SecretSetSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::SecretSetSpec {
             secrets: proxy
                 .secrets()
-                .map(|v| odf::config::Secrets::deserialize(v))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::config::Secrets::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SecretSetSpec.secrets"))?,
+        })
     }
 }
 
@@ -5531,24 +9202,28 @@ impl<'fb> FlatbuffersDeserializable<fb::SecretSetSpec<'fb>> for odf::config::Sec
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::SecretSetSpecInput {
     type OffsetT = WIPOffset<fb::SecretSetSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let secrets_offset = { self.secrets.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let secrets_offset = { self.secrets.serialize(fb)? };
         let mut builder = fb::SecretSetSpecInputBuilder::new(fb);
         builder.add_secrets(secrets_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SecretSetSpecInput<'fb>>
     for odf::config::SecretSetSpecInput
 {
-    fn deserialize(proxy: fb::SecretSetSpecInput<'fb>) -> Self {
-        odf::config::SecretSetSpecInput {
+    fn deserialize(proxy: fb::SecretSetSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::SecretSetSpecInput {
             secrets: proxy
                 .secrets()
-                .map(|v| odf::config::Secrets::deserialize(v))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::config::Secrets::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SecretSetSpecInput.secrets"))?,
+        })
     }
 }
 
@@ -5560,40 +9235,49 @@ impl<'fb> FlatbuffersDeserializable<fb::SecretSetSpecInput<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::Secrets {
     type OffsetT = WIPOffset<fb::Secrets<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = value.serialize(fb);
+                let value_offset = value.serialize(fb)?;
                 let mut entry_builder = fb::SecretsEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
         let mut builder = fb::SecretsBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Secrets<'fb>> for odf::config::Secrets {
-    fn deserialize(proxy: fb::Secrets<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
-                .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = odf::config::Secret::deserialize(entry.value().unwrap());
-                    (key, value)
-                })
-                .collect(),
-        }
+    fn deserialize(proxy: fb::Secrets<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| odf::config::Secret::deserialize(v))?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("Secrets.entries"))?;
+        Ok(Self { entries })
     }
 }
 
@@ -5605,24 +9289,32 @@ impl<'fb> FlatbuffersDeserializable<fb::Secrets<'fb>> for odf::config::Secrets {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::Seed {
     type OffsetT = WIPOffset<fb::Seed<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let dataset_id_offset = { fb.create_vector(&self.dataset_id.as_bytes()) };
         let mut builder = fb::SeedBuilder::new(fb);
         builder.add_dataset_id(dataset_id_offset);
-        builder.add_dataset_kind(self.dataset_kind.into());
-        builder.finish()
+        builder.add_dataset_kind(fb::DatasetKind::try_from(&self.dataset_kind)?);
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Seed<'fb>> for odf::dataset::Seed {
-    fn deserialize(proxy: fb::Seed<'fb>) -> Self {
-        odf::dataset::Seed {
+    fn deserialize(proxy: fb::Seed<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::Seed {
             dataset_id: proxy
                 .dataset_id()
-                .map(|v| odf::dataset::DatasetID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
-            dataset_kind: proxy.dataset_kind().into(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("Seed.datasetId"))?,
+            dataset_kind: odf::dataset::DatasetKind::try_from(proxy.dataset_kind())
+                .map_err(|e: FlatbuffersDecodeError| e.at("Seed.datasetKind"))?,
+        })
     }
 }
 
@@ -5634,23 +9326,27 @@ impl<'fb> FlatbuffersDeserializable<fb::Seed<'fb>> for odf::dataset::Seed {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::SetAttachments {
     type OffsetT = WIPOffset<fb::SetAttachments<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let attachments_offset = { self.attachments.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let attachments_offset = { self.attachments.serialize(fb)? };
         let mut builder = fb::SetAttachmentsBuilder::new(fb);
         builder.add_attachments_type(attachments_offset.0);
         builder.add_attachments(attachments_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SetAttachments<'fb>> for odf::dataset::SetAttachments {
-    fn deserialize(proxy: fb::SetAttachments<'fb>) -> Self {
-        odf::dataset::SetAttachments {
+    fn deserialize(proxy: fb::SetAttachments<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::SetAttachments {
             attachments: proxy
                 .attachments()
-                .map(|v| odf::dataset::Attachments::deserialize(v, proxy.attachments_type()))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::Attachments::deserialize(v, proxy.attachments_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetAttachments.attachments"))?,
+        })
     }
 }
 
@@ -5662,27 +9358,39 @@ impl<'fb> FlatbuffersDeserializable<fb::SetAttachments<'fb>> for odf::dataset::S
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::SetDataSchema {
     type OffsetT = WIPOffset<fb::SetDataSchema<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let raw_arrow_schema_offset = self
-            .raw_arrow_schema
-            .as_ref()
-            .map(|v| fb.create_vector(&v[..]));
-        let schema_offset = self.schema.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let raw_arrow_schema_offset = match &self.raw_arrow_schema {
+            Some(v) => Some({ fb.create_vector(&v[..]) }),
+            None => None,
+        };
+        let schema_offset = match &self.schema {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::SetDataSchemaBuilder::new(fb);
         raw_arrow_schema_offset.map(|off| builder.add_raw_arrow_schema(off));
         schema_offset.map(|off| builder.add_schema(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SetDataSchema<'fb>> for odf::dataset::SetDataSchema {
-    fn deserialize(proxy: fb::SetDataSchema<'fb>) -> Self {
-        odf::dataset::SetDataSchema {
-            raw_arrow_schema: proxy.raw_arrow_schema().map(|v| v.bytes().to_vec()),
+    fn deserialize(proxy: fb::SetDataSchema<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::SetDataSchema {
+            raw_arrow_schema: proxy
+                .raw_arrow_schema()
+                .map(|v| Ok(v.bytes().to_vec()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetDataSchema.rawArrowSchema"))?,
             schema: proxy
                 .schema()
-                .map(|v| odf::data::DataSchema::deserialize(v)),
-        }
+                .map(|v| odf::data::DataSchema::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetDataSchema.schema"))?,
+        })
     }
 }
 
@@ -5694,27 +9402,45 @@ impl<'fb> FlatbuffersDeserializable<fb::SetDataSchema<'fb>> for odf::dataset::Se
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::SetInfo {
     type OffsetT = WIPOffset<fb::SetInfo<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let description_offset = self.description.as_ref().map(|v| fb.create_string(&v));
-        let keywords_offset = self.keywords.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| fb.create_string(&i)).collect();
-            fb.create_vector(&offsets)
-        });
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let description_offset = match &self.description {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let keywords_offset = match &self.keywords {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::SetInfoBuilder::new(fb);
         description_offset.map(|off| builder.add_description(off));
         keywords_offset.map(|off| builder.add_keywords(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SetInfo<'fb>> for odf::dataset::SetInfo {
-    fn deserialize(proxy: fb::SetInfo<'fb>) -> Self {
-        odf::dataset::SetInfo {
-            description: proxy.description().map(|v| v.to_owned()),
+    fn deserialize(proxy: fb::SetInfo<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::SetInfo {
+            description: proxy
+                .description()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetInfo.description"))?,
             keywords: proxy
                 .keywords()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect()),
-        }
+                .map(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetInfo.keywords"))?,
+        })
     }
 }
 
@@ -5726,28 +9452,50 @@ impl<'fb> FlatbuffersDeserializable<fb::SetInfo<'fb>> for odf::dataset::SetInfo
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::SetLicense {
     type OffsetT = WIPOffset<fb::SetLicense<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let short_name_offset = { fb.create_string(&self.short_name) };
         let name_offset = { fb.create_string(&self.name) };
-        let spdx_id_offset = self.spdx_id.as_ref().map(|v| fb.create_string(&v));
+        let spdx_id_offset = match &self.spdx_id {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let website_url_offset = { fb.create_string(&self.website_url) };
         let mut builder = fb::SetLicenseBuilder::new(fb);
         builder.add_short_name(short_name_offset);
         builder.add_name(name_offset);
         spdx_id_offset.map(|off| builder.add_spdx_id(off));
         builder.add_website_url(website_url_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SetLicense<'fb>> for odf::dataset::SetLicense {
-    fn deserialize(proxy: fb::SetLicense<'fb>) -> Self {
-        odf::dataset::SetLicense {
-            short_name: proxy.short_name().map(|v| v.to_owned()).unwrap(),
-            name: proxy.name().map(|v| v.to_owned()).unwrap(),
-            spdx_id: proxy.spdx_id().map(|v| v.to_owned()),
-            website_url: proxy.website_url().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(proxy: fb::SetLicense<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::SetLicense {
+            short_name: proxy
+                .short_name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.shortName"))?,
+            name: proxy
+                .name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.name"))?,
+            spdx_id: proxy
+                .spdx_id()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.spdxId"))?,
+            website_url: proxy
+                .website_url()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetLicense.websiteUrl"))?,
+        })
     }
 }
 
@@ -5759,24 +9507,33 @@ impl<'fb> FlatbuffersDeserializable<fb::SetLicense<'fb>> for odf::dataset::SetLi
 impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::SetPollingSource {
     type OffsetT = WIPOffset<fb::SetPollingSource<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let fetch_offset = { self.fetch.serialize(fb) };
-        let prepare_offset = self.prepare.as_ref().map(|v| {
-            let offsets: Vec<_> = v
-                .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
-                    let mut builder = fb::PrepStepWrapperBuilder::new(fb);
-                    builder.add_value_type(value_type);
-                    builder.add_value(value_offset);
-                    builder.finish()
-                })
-                .collect();
-            fb.create_vector(&offsets)
-        });
-        let read_offset = { self.read.serialize(fb) };
-        let preprocess_offset = self.preprocess.as_ref().map(|v| v.serialize(fb));
-        let merge_offset = { self.merge.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let fetch_offset = { self.fetch.serialize(fb)? };
+        let prepare_offset = match &self.prepare {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                        let (value_type, value_offset) = i.serialize(fb)?;
+                        let mut builder = fb::PrepStepWrapperBuilder::new(fb);
+                        builder.add_value_type(value_type);
+                        builder.add_value(value_offset);
+                        Ok(builder.finish())
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let read_offset = { self.read.serialize(fb)? };
+        let preprocess_offset = match &self.preprocess {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let merge_offset = { self.merge.serialize(fb)? };
         let mut builder = fb::SetPollingSourceBuilder::new(fb);
         builder.add_fetch_type(fetch_offset.0);
         builder.add_fetch(fetch_offset.1);
@@ -5789,34 +9546,47 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::legacy::SetPollingSource {
         });
         builder.add_merge_type(merge_offset.0);
         builder.add_merge(merge_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SetPollingSource<'fb>> for odf::legacy::SetPollingSource {
-    fn deserialize(proxy: fb::SetPollingSource<'fb>) -> Self {
-        odf::legacy::SetPollingSource {
+    fn deserialize(proxy: fb::SetPollingSource<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::legacy::SetPollingSource {
             fetch: proxy
                 .fetch()
-                .map(|v| odf::legacy::FetchStep::deserialize(v, proxy.fetch_type()))
-                .unwrap(),
-            prepare: proxy.prepare().map(|v| {
-                v.iter()
-                    .map(|i| odf::source::PrepStep::deserialize(i.value().unwrap(), i.value_type()))
-                    .collect()
-            }),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::legacy::FetchStep::deserialize(v, proxy.fetch_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.fetch"))?,
+            prepare: proxy
+                .prepare()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| odf::source::PrepStep::deserialize(v, i.value_type()))
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.prepare"))?,
             read: proxy
                 .read()
-                .map(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.read"))?,
             preprocess: proxy
                 .preprocess()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type())),
+                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.preprocess"))?,
             merge: proxy
                 .merge()
-                .map(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type()))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetPollingSource.merge"))?,
+        })
     }
 }
 
@@ -5828,36 +9598,45 @@ impl<'fb> FlatbuffersDeserializable<fb::SetPollingSource<'fb>> for odf::legacy::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::SetTransform {
     type OffsetT = WIPOffset<fb::SetTransform<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let inputs_offset = {
-            let offsets: Vec<_> = self.inputs.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .inputs
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let transform_offset = { self.transform.serialize(fb) };
+        let transform_offset = { self.transform.serialize(fb)? };
         let mut builder = fb::SetTransformBuilder::new(fb);
         builder.add_inputs(inputs_offset);
         builder.add_transform_type(transform_offset.0);
         builder.add_transform(transform_offset.1);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SetTransform<'fb>> for odf::dataset::SetTransform {
-    fn deserialize(proxy: fb::SetTransform<'fb>) -> Self {
-        odf::dataset::SetTransform {
+    fn deserialize(proxy: fb::SetTransform<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::SetTransform {
             inputs: proxy
                 .inputs()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::dataset::TransformInput::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetTransform.inputs"))?,
             transform: proxy
                 .transform()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.transform_type()))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::Transform::deserialize(v, proxy.transform_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetTransform.transform"))?,
+        })
     }
 }
 
@@ -5869,37 +9648,59 @@ impl<'fb> FlatbuffersDeserializable<fb::SetTransform<'fb>> for odf::dataset::Set
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::SetVocab {
     type OffsetT = WIPOffset<fb::SetVocab<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let offset_column_offset = self.offset_column.as_ref().map(|v| fb.create_string(&v));
-        let operation_type_column_offset = self
-            .operation_type_column
-            .as_ref()
-            .map(|v| fb.create_string(&v));
-        let system_time_column_offset = self
-            .system_time_column
-            .as_ref()
-            .map(|v| fb.create_string(&v));
-        let event_time_column_offset = self
-            .event_time_column
-            .as_ref()
-            .map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let offset_column_offset = match &self.offset_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let operation_type_column_offset = match &self.operation_type_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let system_time_column_offset = match &self.system_time_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let event_time_column_offset = match &self.event_time_column {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::SetVocabBuilder::new(fb);
         offset_column_offset.map(|off| builder.add_offset_column(off));
         operation_type_column_offset.map(|off| builder.add_operation_type_column(off));
         system_time_column_offset.map(|off| builder.add_system_time_column(off));
         event_time_column_offset.map(|off| builder.add_event_time_column(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SetVocab<'fb>> for odf::dataset::SetVocab {
-    fn deserialize(proxy: fb::SetVocab<'fb>) -> Self {
-        odf::dataset::SetVocab {
-            offset_column: proxy.offset_column().map(|v| v.to_owned()),
-            operation_type_column: proxy.operation_type_column().map(|v| v.to_owned()),
-            system_time_column: proxy.system_time_column().map(|v| v.to_owned()),
-            event_time_column: proxy.event_time_column().map(|v| v.to_owned()),
-        }
+    fn deserialize(proxy: fb::SetVocab<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::SetVocab {
+            offset_column: proxy
+                .offset_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.offsetColumn"))?,
+            operation_type_column: proxy
+                .operation_type_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.operationTypeColumn"))?,
+            system_time_column: proxy
+                .system_time_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.systemTimeColumn"))?,
+            event_time_column: proxy
+                .event_time_column()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SetVocab.eventTimeColumn"))?,
+        })
     }
 }
 
@@ -5912,25 +9713,34 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::SourceCaching> for odf::source::S
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::SourceCaching, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::SourceCaching, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::source::SourceCaching::Forever(v) => (
+            odf::source::SourceCaching::Forever(v) => Ok((
                 fb::SourceCaching::SourceCachingForever,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::SourceCaching> for odf::source::SourceCaching {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::SourceCaching) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::SourceCaching,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::SourceCaching::SourceCachingForever => {
-                odf::source::SourceCaching::Forever(odf::source::SourceCachingForever::deserialize(
-                    unsafe { fb::SourceCachingForever::init_from_table(table) },
-                ))
+                odf::source::SourceCachingForever::deserialize(unsafe {
+                    fb::SourceCachingForever::init_from_table(table)
+                })
+                .map(odf::source::SourceCaching::Forever)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "SourceCaching",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -5943,17 +9753,20 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::SourceCaching> for odf::source:
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::SourceCachingForever {
     type OffsetT = WIPOffset<fb::SourceCachingForever<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::SourceCachingForeverBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SourceCachingForever<'fb>>
     for odf::source::SourceCachingForever
 {
-    fn deserialize(proxy: fb::SourceCachingForever<'fb>) -> Self {
-        odf::source::SourceCachingForever {}
+    fn deserialize(proxy: fb::SourceCachingForever<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::SourceCachingForever {})
     }
 }
 
@@ -5962,21 +9775,30 @@ impl<'fb> FlatbuffersDeserializable<fb::SourceCachingForever<'fb>>
 // Schema: https://opendatafabric.org/schemas/source/v1alpha1/SourceOrdering
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::source::SourceOrdering> for fb::SourceOrdering {
-    fn from(v: odf::source::SourceOrdering) -> Self {
+impl TryFrom<&odf::source::SourceOrdering> for fb::SourceOrdering {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::source::SourceOrdering) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::source::SourceOrdering::ByEventTime => fb::SourceOrdering::ByEventTime,
-            odf::source::SourceOrdering::ByName => fb::SourceOrdering::ByName,
+            odf::source::SourceOrdering::ByEventTime => Ok(fb::SourceOrdering::ByEventTime),
+            odf::source::SourceOrdering::ByName => Ok(fb::SourceOrdering::ByName),
         }
     }
 }
 
-impl Into<odf::source::SourceOrdering> for fb::SourceOrdering {
-    fn into(self) -> odf::source::SourceOrdering {
-        match self {
-            fb::SourceOrdering::ByEventTime => odf::source::SourceOrdering::ByEventTime,
-            fb::SourceOrdering::ByName => odf::source::SourceOrdering::ByName,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::SourceOrdering> for odf::source::SourceOrdering {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::SourceOrdering) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::SourceOrdering::ByEventTime => Ok(odf::source::SourceOrdering::ByEventTime),
+            fb::SourceOrdering::ByName => Ok(odf::source::SourceOrdering::ByName),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "SourceOrdering",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
@@ -5989,26 +9811,47 @@ impl Into<odf::source::SourceOrdering> for fb::SourceOrdering {
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::SourceSpec {
     type OffsetT = WIPOffset<fb::SourceSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let config_offset = self.config.as_ref().map(|v| v.serialize(fb));
-        let ingress_offset = self.ingress.as_ref().map(|v| v.serialize(fb));
-        let prepare_offset = self.prepare.as_ref().map(|v| {
-            let offsets: Vec<_> = v
-                .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
-                    let mut builder = fb::PrepStepWrapperBuilder::new(fb);
-                    builder.add_value_type(value_type);
-                    builder.add_value(value_offset);
-                    builder.finish()
-                })
-                .collect();
-            fb.create_vector(&offsets)
-        });
-        let read_offset = { self.read.serialize(fb) };
-        let preprocess_offset = self.preprocess.as_ref().map(|v| v.serialize(fb));
-        let merge_offset = self.merge.as_ref().map(|v| v.serialize(fb));
-        let vocab_offset = self.vocab.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let config_offset = match &self.config {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let ingress_offset = match &self.ingress {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let prepare_offset = match &self.prepare {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                        let (value_type, value_offset) = i.serialize(fb)?;
+                        let mut builder = fb::PrepStepWrapperBuilder::new(fb);
+                        builder.add_value_type(value_type);
+                        builder.add_value(value_offset);
+                        Ok(builder.finish())
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let read_offset = { self.read.serialize(fb)? };
+        let preprocess_offset = match &self.preprocess {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let merge_offset = match &self.merge {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let vocab_offset = match &self.vocab {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::SourceSpecBuilder::new(fb);
         config_offset.map(|off| builder.add_config(off));
         ingress_offset.map(|(e, off)| {
@@ -6027,38 +9870,57 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::SourceSpec {
             builder.add_merge(off)
         });
         vocab_offset.map(|off| builder.add_vocab(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SourceSpec<'fb>> for odf::source::SourceSpec {
-    fn deserialize(proxy: fb::SourceSpec<'fb>) -> Self {
-        odf::source::SourceSpec {
+    fn deserialize(proxy: fb::SourceSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::SourceSpec {
             config: proxy
                 .config()
-                .map(|v| odf::config::ValueRefs::deserialize(v)),
+                .map(|v| odf::config::ValueRefs::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.config"))?,
             ingress: proxy
                 .ingress()
-                .map(|v| odf::source::Ingress::deserialize(v, proxy.ingress_type())),
-            prepare: proxy.prepare().map(|v| {
-                v.iter()
-                    .map(|i| odf::source::PrepStep::deserialize(i.value().unwrap(), i.value_type()))
-                    .collect()
-            }),
+                .map(|v| odf::source::Ingress::deserialize(v, proxy.ingress_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.ingress"))?,
+            prepare: proxy
+                .prepare()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| odf::source::PrepStep::deserialize(v, i.value_type()))
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.prepare"))?,
             read: proxy
                 .read()
-                .map(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.read"))?,
             preprocess: proxy
                 .preprocess()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type())),
+                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.preprocess"))?,
             merge: proxy
                 .merge()
-                .map(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type())),
+                .map(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.merge"))?,
             vocab: proxy
                 .vocab()
-                .map(|v| odf::dataset::DatasetVocabulary::deserialize(v)),
-        }
+                .map(|v| odf::dataset::DatasetVocabulary::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpec.vocab"))?,
+        })
     }
 }
 
@@ -6070,26 +9932,47 @@ impl<'fb> FlatbuffersDeserializable<fb::SourceSpec<'fb>> for odf::source::Source
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::SourceSpecInput {
     type OffsetT = WIPOffset<fb::SourceSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let config_offset = self.config.as_ref().map(|v| v.serialize(fb));
-        let ingress_offset = self.ingress.as_ref().map(|v| v.serialize(fb));
-        let prepare_offset = self.prepare.as_ref().map(|v| {
-            let offsets: Vec<_> = v
-                .iter()
-                .map(|i| {
-                    let (value_type, value_offset) = i.serialize(fb);
-                    let mut builder = fb::PrepStepWrapperBuilder::new(fb);
-                    builder.add_value_type(value_type);
-                    builder.add_value(value_offset);
-                    builder.finish()
-                })
-                .collect();
-            fb.create_vector(&offsets)
-        });
-        let read_offset = { self.read.serialize(fb) };
-        let preprocess_offset = self.preprocess.as_ref().map(|v| v.serialize(fb));
-        let merge_offset = self.merge.as_ref().map(|v| v.serialize(fb));
-        let vocab_offset = self.vocab.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let config_offset = match &self.config {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let ingress_offset = match &self.ingress {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let prepare_offset = match &self.prepare {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                        let (value_type, value_offset) = i.serialize(fb)?;
+                        let mut builder = fb::PrepStepWrapperBuilder::new(fb);
+                        builder.add_value_type(value_type);
+                        builder.add_value(value_offset);
+                        Ok(builder.finish())
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let read_offset = { self.read.serialize(fb)? };
+        let preprocess_offset = match &self.preprocess {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let merge_offset = match &self.merge {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
+        let vocab_offset = match &self.vocab {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::SourceSpecInputBuilder::new(fb);
         config_offset.map(|off| builder.add_config(off));
         ingress_offset.map(|(e, off)| {
@@ -6108,38 +9991,57 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::SourceSpecInput {
             builder.add_merge(off)
         });
         vocab_offset.map(|off| builder.add_vocab(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SourceSpecInput<'fb>> for odf::source::SourceSpecInput {
-    fn deserialize(proxy: fb::SourceSpecInput<'fb>) -> Self {
-        odf::source::SourceSpecInput {
+    fn deserialize(proxy: fb::SourceSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::SourceSpecInput {
             config: proxy
                 .config()
-                .map(|v| odf::config::ValueRefs::deserialize(v)),
+                .map(|v| odf::config::ValueRefs::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.config"))?,
             ingress: proxy
                 .ingress()
-                .map(|v| odf::source::Ingress::deserialize(v, proxy.ingress_type())),
-            prepare: proxy.prepare().map(|v| {
-                v.iter()
-                    .map(|i| odf::source::PrepStep::deserialize(i.value().unwrap(), i.value_type()))
-                    .collect()
-            }),
+                .map(|v| odf::source::Ingress::deserialize(v, proxy.ingress_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.ingress"))?,
+            prepare: proxy
+                .prepare()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| {
+                            i.value()
+                                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                                .and_then(|v| odf::source::PrepStep::deserialize(v, i.value_type()))
+                        })
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.prepare"))?,
             read: proxy
                 .read()
-                .map(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::source::ReadStep::deserialize(v, proxy.read_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.read"))?,
             preprocess: proxy
                 .preprocess()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type())),
+                .map(|v| odf::dataset::Transform::deserialize(v, proxy.preprocess_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.preprocess"))?,
             merge: proxy
                 .merge()
-                .map(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type())),
+                .map(|v| odf::source::MergeStrategy::deserialize(v, proxy.merge_type()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.merge"))?,
             vocab: proxy
                 .vocab()
-                .map(|v| odf::dataset::DatasetVocabulary::deserialize(v)),
-        }
+                .map(|v| odf::dataset::DatasetVocabulary::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceSpecInput.vocab"))?,
+        })
     }
 }
 
@@ -6151,7 +10053,10 @@ impl<'fb> FlatbuffersDeserializable<fb::SourceSpecInput<'fb>> for odf::source::S
 impl<'fb> FlatbuffersSerializable<'fb> for odf::source::SourceState {
     type OffsetT = WIPOffset<fb::SourceState<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let source_name_offset = { fb.create_string(&self.source_name) };
         let kind_offset = { fb.create_string(&self.kind) };
         let value_offset = { fb.create_string(&self.value) };
@@ -6159,17 +10064,29 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::source::SourceState {
         builder.add_source_name(source_name_offset);
         builder.add_kind(kind_offset);
         builder.add_value(value_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SourceState<'fb>> for odf::source::SourceState {
-    fn deserialize(proxy: fb::SourceState<'fb>) -> Self {
-        odf::source::SourceState {
-            source_name: proxy.source_name().map(|v| v.to_owned()).unwrap(),
-            kind: proxy.kind().map(|v| v.to_owned()).unwrap(),
-            value: proxy.value().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(proxy: fb::SourceState<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::source::SourceState {
+            source_name: proxy
+                .source_name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceState.sourceName"))?,
+            kind: proxy
+                .kind()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceState.kind"))?,
+            value: proxy
+                .value()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SourceState.value"))?,
+        })
     }
 }
 
@@ -6181,22 +10098,36 @@ impl<'fb> FlatbuffersDeserializable<fb::SourceState<'fb>> for odf::source::Sourc
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::SqlQueryStep {
     type OffsetT = WIPOffset<fb::SqlQueryStep<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let alias_offset = self.alias.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let alias_offset = match &self.alias {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let query_offset = { fb.create_string(&self.query) };
         let mut builder = fb::SqlQueryStepBuilder::new(fb);
         alias_offset.map(|off| builder.add_alias(off));
         builder.add_query(query_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::SqlQueryStep<'fb>> for odf::dataset::SqlQueryStep {
-    fn deserialize(proxy: fb::SqlQueryStep<'fb>) -> Self {
-        odf::dataset::SqlQueryStep {
-            alias: proxy.alias().map(|v| v.to_owned()),
-            query: proxy.query().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(proxy: fb::SqlQueryStep<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::SqlQueryStep {
+            alias: proxy
+                .alias()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("SqlQueryStep.alias"))?,
+            query: proxy
+                .query()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("SqlQueryStep.query"))?,
+        })
     }
 }
 
@@ -6209,52 +10140,62 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::TaskSpec> for odf::flow::TaskSpec
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::TaskSpec, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::TaskSpec, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::flow::TaskSpec::Ingest(v) => (
+            odf::flow::TaskSpec::Ingest(v) => Ok((
                 fb::TaskSpec::TaskSpecIngest,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::TaskSpec::Compaction(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::TaskSpec::Compaction(v) => Ok((
                 fb::TaskSpec::TaskSpecCompaction,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::TaskSpec::GarbageCollection(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::TaskSpec::GarbageCollection(v) => Ok((
                 fb::TaskSpec::TaskSpecGarbageCollection,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::TaskSpec::WebhookCall(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::TaskSpec::WebhookCall(v) => Ok((
                 fb::TaskSpec::TaskSpecWebhookCall,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::TaskSpec> for odf::flow::TaskSpec {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::TaskSpec) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::TaskSpec,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
-            fb::TaskSpec::TaskSpecIngest => {
-                odf::flow::TaskSpec::Ingest(odf::flow::TaskSpecIngest::deserialize(unsafe {
-                    fb::TaskSpecIngest::init_from_table(table)
-                }))
-            }
+            fb::TaskSpec::TaskSpecIngest => odf::flow::TaskSpecIngest::deserialize(unsafe {
+                fb::TaskSpecIngest::init_from_table(table)
+            })
+            .map(odf::flow::TaskSpec::Ingest),
             fb::TaskSpec::TaskSpecCompaction => {
-                odf::flow::TaskSpec::Compaction(odf::flow::TaskSpecCompaction::deserialize(
-                    unsafe { fb::TaskSpecCompaction::init_from_table(table) },
-                ))
+                odf::flow::TaskSpecCompaction::deserialize(unsafe {
+                    fb::TaskSpecCompaction::init_from_table(table)
+                })
+                .map(odf::flow::TaskSpec::Compaction)
             }
-            fb::TaskSpec::TaskSpecGarbageCollection => odf::flow::TaskSpec::GarbageCollection(
+            fb::TaskSpec::TaskSpecGarbageCollection => {
                 odf::flow::TaskSpecGarbageCollection::deserialize(unsafe {
                     fb::TaskSpecGarbageCollection::init_from_table(table)
-                }),
-            ),
+                })
+                .map(odf::flow::TaskSpec::GarbageCollection)
+            }
             fb::TaskSpec::TaskSpecWebhookCall => {
-                odf::flow::TaskSpec::WebhookCall(odf::flow::TaskSpecWebhookCall::deserialize(
-                    unsafe { fb::TaskSpecWebhookCall::init_from_table(table) },
-                ))
+                odf::flow::TaskSpecWebhookCall::deserialize(unsafe {
+                    fb::TaskSpecWebhookCall::init_from_table(table)
+                })
+                .map(odf::flow::TaskSpec::WebhookCall)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "TaskSpec",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -6267,21 +10208,29 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::TaskSpec> for odf::flow::TaskSp
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecCompaction {
     type OffsetT = WIPOffset<fb::TaskSpecCompaction<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let params_offset = self.params.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let params_offset = match &self.params {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::TaskSpecCompactionBuilder::new(fb);
         params_offset.map(|off| builder.add_params(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecCompaction<'fb>> for odf::flow::TaskSpecCompaction {
-    fn deserialize(proxy: fb::TaskSpecCompaction<'fb>) -> Self {
-        odf::flow::TaskSpecCompaction {
+    fn deserialize(proxy: fb::TaskSpecCompaction<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecCompaction {
             params: proxy
                 .params()
-                .map(|v| odf::dataset::CompactionParams::deserialize(v)),
-        }
+                .map(|v| odf::dataset::CompactionParams::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecCompaction.params"))?,
+        })
     }
 }
 
@@ -6293,17 +10242,22 @@ impl<'fb> FlatbuffersDeserializable<fb::TaskSpecCompaction<'fb>> for odf::flow::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecGarbageCollection {
     type OffsetT = WIPOffset<fb::TaskSpecGarbageCollection<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::TaskSpecGarbageCollectionBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecGarbageCollection<'fb>>
     for odf::flow::TaskSpecGarbageCollection
 {
-    fn deserialize(proxy: fb::TaskSpecGarbageCollection<'fb>) -> Self {
-        odf::flow::TaskSpecGarbageCollection {}
+    fn deserialize(
+        proxy: fb::TaskSpecGarbageCollection<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecGarbageCollection {})
     }
 }
 
@@ -6315,27 +10269,36 @@ impl<'fb> FlatbuffersDeserializable<fb::TaskSpecGarbageCollection<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecIngest {
     type OffsetT = WIPOffset<fb::TaskSpecIngest<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let source_offset = { self.source.serialize(fb) };
-        let params_offset = self.params.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let source_offset = { self.source.serialize(fb)? };
+        let params_offset = match &self.params {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::TaskSpecIngestBuilder::new(fb);
         builder.add_source(source_offset);
         params_offset.map(|off| builder.add_params(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecIngest<'fb>> for odf::flow::TaskSpecIngest {
-    fn deserialize(proxy: fb::TaskSpecIngest<'fb>) -> Self {
-        odf::flow::TaskSpecIngest {
+    fn deserialize(proxy: fb::TaskSpecIngest<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecIngest {
             source: proxy
                 .source()
-                .map(|v| odf::resource::ResourceHandle::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecIngest.source"))?,
             params: proxy
                 .params()
-                .map(|v| odf::source::IngestParams::deserialize(v)),
-        }
+                .map(|v| odf::source::IngestParams::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecIngest.params"))?,
+        })
     }
 }
 
@@ -6347,27 +10310,38 @@ impl<'fb> FlatbuffersDeserializable<fb::TaskSpecIngest<'fb>> for odf::flow::Task
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecWebhookCall {
     type OffsetT = WIPOffset<fb::TaskSpecWebhookCall<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let target_offset = { self.target.serialize(fb) };
-        let payload_offset = self.payload.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let target_offset = { self.target.serialize(fb)? };
+        let payload_offset = match &self.payload {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::TaskSpecWebhookCallBuilder::new(fb);
         builder.add_target(target_offset);
         payload_offset.map(|off| builder.add_payload(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecWebhookCall<'fb>>
     for odf::flow::TaskSpecWebhookCall
 {
-    fn deserialize(proxy: fb::TaskSpecWebhookCall<'fb>) -> Self {
-        odf::flow::TaskSpecWebhookCall {
+    fn deserialize(proxy: fb::TaskSpecWebhookCall<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecWebhookCall {
             target: proxy
                 .target()
-                .map(|v| odf::resource::ResourceHandle::deserialize(v))
-                .unwrap(),
-            payload: proxy.payload().map(|v| v.to_owned()),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecWebhookCall.target"))?,
+            payload: proxy
+                .payload()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecWebhookCall.payload"))?,
+        })
     }
 }
 
@@ -6380,54 +10354,64 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::TaskSpecInput> for odf::flow::Tas
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::TaskSpecInput, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::TaskSpecInput, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::flow::TaskSpecInput::Ingest(v) => (
+            odf::flow::TaskSpecInput::Ingest(v) => Ok((
                 fb::TaskSpecInput::TaskSpecInputIngest,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::TaskSpecInput::Compaction(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::TaskSpecInput::Compaction(v) => Ok((
                 fb::TaskSpecInput::TaskSpecInputCompaction,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::TaskSpecInput::GarbageCollection(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::TaskSpecInput::GarbageCollection(v) => Ok((
                 fb::TaskSpecInput::TaskSpecInputGarbageCollection,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::flow::TaskSpecInput::WebhookCall(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::flow::TaskSpecInput::WebhookCall(v) => Ok((
                 fb::TaskSpecInput::TaskSpecInputWebhookCall,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::TaskSpecInput> for odf::flow::TaskSpecInput {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::TaskSpecInput) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::TaskSpecInput,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::TaskSpecInput::TaskSpecInputIngest => {
-                odf::flow::TaskSpecInput::Ingest(odf::flow::TaskSpecInputIngest::deserialize(
-                    unsafe { fb::TaskSpecInputIngest::init_from_table(table) },
-                ))
+                odf::flow::TaskSpecInputIngest::deserialize(unsafe {
+                    fb::TaskSpecInputIngest::init_from_table(table)
+                })
+                .map(odf::flow::TaskSpecInput::Ingest)
             }
-            fb::TaskSpecInput::TaskSpecInputCompaction => odf::flow::TaskSpecInput::Compaction(
+            fb::TaskSpecInput::TaskSpecInputCompaction => {
                 odf::flow::TaskSpecInputCompaction::deserialize(unsafe {
                     fb::TaskSpecInputCompaction::init_from_table(table)
-                }),
-            ),
+                })
+                .map(odf::flow::TaskSpecInput::Compaction)
+            }
             fb::TaskSpecInput::TaskSpecInputGarbageCollection => {
-                odf::flow::TaskSpecInput::GarbageCollection(
-                    odf::flow::TaskSpecInputGarbageCollection::deserialize(unsafe {
-                        fb::TaskSpecInputGarbageCollection::init_from_table(table)
-                    }),
-                )
+                odf::flow::TaskSpecInputGarbageCollection::deserialize(unsafe {
+                    fb::TaskSpecInputGarbageCollection::init_from_table(table)
+                })
+                .map(odf::flow::TaskSpecInput::GarbageCollection)
             }
-            fb::TaskSpecInput::TaskSpecInputWebhookCall => odf::flow::TaskSpecInput::WebhookCall(
+            fb::TaskSpecInput::TaskSpecInputWebhookCall => {
                 odf::flow::TaskSpecInputWebhookCall::deserialize(unsafe {
                     fb::TaskSpecInputWebhookCall::init_from_table(table)
-                }),
-            ),
-            _ => panic!("Invalid enum value: {}", t.0),
+                })
+                .map(odf::flow::TaskSpecInput::WebhookCall)
+            }
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "TaskSpecInput",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -6440,23 +10424,33 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::TaskSpecInput> for odf::flow::T
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecInputCompaction {
     type OffsetT = WIPOffset<fb::TaskSpecInputCompaction<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let params_offset = self.params.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let params_offset = match &self.params {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::TaskSpecInputCompactionBuilder::new(fb);
         params_offset.map(|off| builder.add_params(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputCompaction<'fb>>
     for odf::flow::TaskSpecInputCompaction
 {
-    fn deserialize(proxy: fb::TaskSpecInputCompaction<'fb>) -> Self {
-        odf::flow::TaskSpecInputCompaction {
+    fn deserialize(
+        proxy: fb::TaskSpecInputCompaction<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecInputCompaction {
             params: proxy
                 .params()
-                .map(|v| odf::dataset::CompactionParams::deserialize(v)),
-        }
+                .map(|v| odf::dataset::CompactionParams::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputCompaction.params"))?,
+        })
     }
 }
 
@@ -6468,17 +10462,22 @@ impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputCompaction<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecInputGarbageCollection {
     type OffsetT = WIPOffset<fb::TaskSpecInputGarbageCollection<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::TaskSpecInputGarbageCollectionBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputGarbageCollection<'fb>>
     for odf::flow::TaskSpecInputGarbageCollection
 {
-    fn deserialize(proxy: fb::TaskSpecInputGarbageCollection<'fb>) -> Self {
-        odf::flow::TaskSpecInputGarbageCollection {}
+    fn deserialize(
+        proxy: fb::TaskSpecInputGarbageCollection<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecInputGarbageCollection {})
     }
 }
 
@@ -6490,29 +10489,38 @@ impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputGarbageCollection<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecInputIngest {
     type OffsetT = WIPOffset<fb::TaskSpecInputIngest<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let source_offset = { self.source.serialize(fb) };
-        let params_offset = self.params.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let source_offset = { self.source.serialize(fb)? };
+        let params_offset = match &self.params {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::TaskSpecInputIngestBuilder::new(fb);
         builder.add_source(source_offset);
         params_offset.map(|off| builder.add_params(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputIngest<'fb>>
     for odf::flow::TaskSpecInputIngest
 {
-    fn deserialize(proxy: fb::TaskSpecInputIngest<'fb>) -> Self {
-        odf::flow::TaskSpecInputIngest {
+    fn deserialize(proxy: fb::TaskSpecInputIngest<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecInputIngest {
             source: proxy
                 .source()
-                .map(|v| odf::resource::ResourceRef::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceRef::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputIngest.source"))?,
             params: proxy
                 .params()
-                .map(|v| odf::source::IngestParams::deserialize(v)),
-        }
+                .map(|v| odf::source::IngestParams::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputIngest.params"))?,
+        })
     }
 }
 
@@ -6524,27 +10532,40 @@ impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputIngest<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::flow::TaskSpecInputWebhookCall {
     type OffsetT = WIPOffset<fb::TaskSpecInputWebhookCall<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let target_offset = { self.target.serialize(fb) };
-        let payload_offset = self.payload.as_ref().map(|v| fb.create_string(&v));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let target_offset = { self.target.serialize(fb)? };
+        let payload_offset = match &self.payload {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::TaskSpecInputWebhookCallBuilder::new(fb);
         builder.add_target(target_offset);
         payload_offset.map(|off| builder.add_payload(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputWebhookCall<'fb>>
     for odf::flow::TaskSpecInputWebhookCall
 {
-    fn deserialize(proxy: fb::TaskSpecInputWebhookCall<'fb>) -> Self {
-        odf::flow::TaskSpecInputWebhookCall {
+    fn deserialize(
+        proxy: fb::TaskSpecInputWebhookCall<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::flow::TaskSpecInputWebhookCall {
             target: proxy
                 .target()
-                .map(|v| odf::resource::ResourceRef::deserialize(v))
-                .unwrap(),
-            payload: proxy.payload().map(|v| v.to_owned()),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::resource::ResourceRef::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputWebhookCall.target"))?,
+            payload: proxy
+                .payload()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TaskSpecInputWebhookCall.payload"))?,
+        })
     }
 }
 
@@ -6556,32 +10577,40 @@ impl<'fb> FlatbuffersDeserializable<fb::TaskSpecInputWebhookCall<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::TemporalTable {
     type OffsetT = WIPOffset<fb::TemporalTable<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let name_offset = { fb.create_string(&self.name) };
         let primary_key_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .primary_key
                 .iter()
-                .map(|i| fb.create_string(&i))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ fb.create_string(&i) }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::TemporalTableBuilder::new(fb);
         builder.add_name(name_offset);
         builder.add_primary_key(primary_key_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TemporalTable<'fb>> for odf::dataset::TemporalTable {
-    fn deserialize(proxy: fb::TemporalTable<'fb>) -> Self {
-        odf::dataset::TemporalTable {
-            name: proxy.name().map(|v| v.to_owned()).unwrap(),
+    fn deserialize(proxy: fb::TemporalTable<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::TemporalTable {
+            name: proxy
+                .name()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TemporalTable.name"))?,
             primary_key: proxy
                 .primary_key()
-                .map(|v| v.iter().map(|i| i.to_owned()).collect())
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| v.iter().map(|i| Ok(i.to_owned())).collect::<Result<_, _>>())
+                .map_err(|e: FlatbuffersDecodeError| e.at("TemporalTable.primaryKey"))?,
+        })
     }
 }
 
@@ -6590,29 +10619,116 @@ impl<'fb> FlatbuffersDeserializable<fb::TemporalTable<'fb>> for odf::dataset::Te
 // Schema: https://opendatafabric.org/schemas/data/v1alpha1/TimeUnit
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::data::TimeUnit> for fb::TimeUnit {
-    fn from(v: odf::data::TimeUnit) -> Self {
+impl TryFrom<&odf::data::TimeUnit> for fb::TimeUnit {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::data::TimeUnit) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::data::TimeUnit::Second => fb::TimeUnit::Second,
-            odf::data::TimeUnit::Millisecond => fb::TimeUnit::Millisecond,
-            odf::data::TimeUnit::Microsecond => fb::TimeUnit::Microsecond,
-            odf::data::TimeUnit::Nanosecond => fb::TimeUnit::Nanosecond,
+            odf::data::TimeUnit::Second => Ok(fb::TimeUnit::Second),
+            odf::data::TimeUnit::Millisecond => Ok(fb::TimeUnit::Millisecond),
+            odf::data::TimeUnit::Microsecond => Ok(fb::TimeUnit::Microsecond),
+            odf::data::TimeUnit::Nanosecond => Ok(fb::TimeUnit::Nanosecond),
         }
     }
 }
 
-impl Into<odf::data::TimeUnit> for fb::TimeUnit {
-    fn into(self) -> odf::data::TimeUnit {
-        match self {
-            fb::TimeUnit::Second => odf::data::TimeUnit::Second,
-            fb::TimeUnit::Millisecond => odf::data::TimeUnit::Millisecond,
-            fb::TimeUnit::Microsecond => odf::data::TimeUnit::Microsecond,
-            fb::TimeUnit::Nanosecond => odf::data::TimeUnit::Nanosecond,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::TimeUnit> for odf::data::TimeUnit {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::TimeUnit) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::TimeUnit::Second => Ok(odf::data::TimeUnit::Second),
+            fb::TimeUnit::Millisecond => Ok(odf::data::TimeUnit::Millisecond),
+            fb::TimeUnit::Microsecond => Ok(odf::data::TimeUnit::Microsecond),
+            fb::TimeUnit::Nanosecond => Ok(odf::data::TimeUnit::Nanosecond),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "TimeUnit",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
 
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransferSizeEstimation
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferSizeEstimation
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::TransferSizeEstimation {
+    type OffsetT = WIPOffset<fb::TransferSizeEstimation<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let mut builder = fb::TransferSizeEstimationBuilder::new(fb);
+        builder.add_num_blocks(self.num_blocks);
+        builder.add_num_objects(self.num_objects);
+        builder.add_bytes_in_raw_blocks(self.bytes_in_raw_blocks);
+        builder.add_bytes_in_raw_objects(self.bytes_in_raw_objects);
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::TransferSizeEstimation<'fb>>
+    for odf::protocol::TransferSizeEstimation
+{
+    fn deserialize(proxy: fb::TransferSizeEstimation<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::TransferSizeEstimation {
+            num_blocks: Ok(proxy.num_blocks())
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.numBlocks"))?,
+            num_objects: Ok(proxy.num_objects())
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.numObjects"))?,
+            bytes_in_raw_blocks: Ok(proxy.bytes_in_raw_blocks()).map_err(
+                |e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.bytesInRawBlocks"),
+            )?,
+            bytes_in_raw_objects: Ok(proxy.bytes_in_raw_objects()).map_err(
+                |e: FlatbuffersDecodeError| e.at("TransferSizeEstimation.bytesInRawObjects"),
+            )?,
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// TransferUrl
+// Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferUrl
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'fb> FlatbuffersSerializable<'fb> for odf::protocol::TransferUrl {
+    type OffsetT = WIPOffset<fb::TransferUrl<'fb>>;
+
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let url_offset = { fb.create_string(&self.url) };
+        let mut builder = fb::TransferUrlBuilder::new(fb);
+        builder.add_url(url_offset);
+        self.expires_at
+            .map(|v| builder.add_expires_at(&datetime_to_fb(&v)));
+        Ok(builder.finish())
+    }
+}
+
+impl<'fb> FlatbuffersDeserializable<fb::TransferUrl<'fb>> for odf::protocol::TransferUrl {
+    fn deserialize(proxy: fb::TransferUrl<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::protocol::TransferUrl {
+            url: proxy
+                .url()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransferUrl.url"))?,
+            expires_at: proxy
+                .expires_at()
+                .map(|v| fb_to_datetime(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransferUrl.expiresAt"))?,
+        })
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 // Transform
 // Schema: https://opendatafabric.org/schemas/dataset/v1alpha1/Transform
@@ -6622,25 +10738,32 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::Transform> for odf::dataset::Tran
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::Transform, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::Transform, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::dataset::Transform::Sql(v) => (
+            odf::dataset::Transform::Sql(v) => Ok((
                 fb::Transform::TransformSql,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
 
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::Transform> for odf::dataset::Transform {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::Transform) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::Transform,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
-            fb::Transform::TransformSql => {
-                odf::dataset::Transform::Sql(odf::dataset::TransformSql::deserialize(unsafe {
-                    fb::TransformSql::init_from_table(table)
-                }))
-            }
-            _ => panic!("Invalid enum value: {}", t.0),
+            fb::Transform::TransformSql => odf::dataset::TransformSql::deserialize(unsafe {
+                fb::TransformSql::init_from_table(table)
+            })
+            .map(odf::dataset::Transform::Sql),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "Transform",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -6653,45 +10776,86 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::Transform> for odf::dataset::Tr
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::TransformSql {
     type OffsetT = WIPOffset<fb::TransformSql<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let engine_offset = { fb.create_string(&self.engine) };
-        let version_offset = self.version.as_ref().map(|v| fb.create_string(&v));
-        let query_offset = self.query.as_ref().map(|v| fb.create_string(&v));
-        let queries_offset = self.queries.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
-        let temporal_tables_offset = self.temporal_tables.as_ref().map(|v| {
-            let offsets: Vec<_> = v.iter().map(|i| i.serialize(fb)).collect();
-            fb.create_vector(&offsets)
-        });
+        let version_offset = match &self.version {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let query_offset = match &self.query {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let queries_offset = match &self.queries {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
+        let temporal_tables_offset = match &self.temporal_tables {
+            Some(v) => Some({
+                let offsets = v
+                    .iter()
+                    .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                    .collect::<Result<Vec<_>, _>>()?;
+                fb.create_vector(&offsets)
+            }),
+            None => None,
+        };
         let mut builder = fb::TransformSqlBuilder::new(fb);
         builder.add_engine(engine_offset);
         version_offset.map(|off| builder.add_version(off));
         query_offset.map(|off| builder.add_query(off));
         queries_offset.map(|off| builder.add_queries(off));
         temporal_tables_offset.map(|off| builder.add_temporal_tables(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformSql<'fb>> for odf::dataset::TransformSql {
-    fn deserialize(proxy: fb::TransformSql<'fb>) -> Self {
-        odf::dataset::TransformSql {
-            engine: proxy.engine().map(|v| v.to_owned()).unwrap(),
-            version: proxy.version().map(|v| v.to_owned()),
-            query: proxy.query().map(|v| v.to_owned()),
-            queries: proxy.queries().map(|v| {
-                v.iter()
-                    .map(|i| odf::dataset::SqlQueryStep::deserialize(i))
-                    .collect()
-            }),
-            temporal_tables: proxy.temporal_tables().map(|v| {
-                v.iter()
-                    .map(|i| odf::dataset::TemporalTable::deserialize(i))
-                    .collect()
-            }),
-        }
+    fn deserialize(proxy: fb::TransformSql<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::TransformSql {
+            engine: proxy
+                .engine()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.engine"))?,
+            version: proxy
+                .version()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.version"))?,
+            query: proxy
+                .query()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.query"))?,
+            queries: proxy
+                .queries()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::dataset::SqlQueryStep::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.queries"))?,
+            temporal_tables: proxy
+                .temporal_tables()
+                .map(|v| {
+                    v.iter()
+                        .map(|i| odf::dataset::TemporalTable::deserialize(i))
+                        .collect::<Result<_, _>>()
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformSql.temporalTables"))?,
+        })
     }
 }
 
@@ -6703,25 +10867,39 @@ impl<'fb> FlatbuffersDeserializable<fb::TransformSql<'fb>> for odf::dataset::Tra
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::TransformInput {
     type OffsetT = WIPOffset<fb::TransformInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let dataset_ref_offset = { fb.create_string(&self.dataset_ref.to_string()) };
-        let alias_offset = self.alias.as_ref().map(|v| fb.create_string(&v));
+        let alias_offset = match &self.alias {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::TransformInputBuilder::new(fb);
         builder.add_dataset_ref(dataset_ref_offset);
         alias_offset.map(|off| builder.add_alias(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformInput<'fb>> for odf::dataset::TransformInput {
-    fn deserialize(proxy: fb::TransformInput<'fb>) -> Self {
-        odf::dataset::TransformInput {
+    fn deserialize(proxy: fb::TransformInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::TransformInput {
             dataset_ref: proxy
                 .dataset_ref()
-                .map(|v| odf::dataset::DatasetRef::try_from(v).unwrap())
-                .unwrap(),
-            alias: proxy.alias().map(|v| v.to_owned()),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetRef::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformInput.datasetRef"))?,
+            alias: proxy
+                .alias()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformInput.alias"))?,
+        })
     }
 }
 
@@ -6733,22 +10911,42 @@ impl<'fb> FlatbuffersDeserializable<fb::TransformInput<'fb>> for odf::dataset::T
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformRequest {
     type OffsetT = WIPOffset<fb::TransformRequest<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let dataset_id_offset = { fb.create_vector(&self.dataset_id.as_bytes()) };
         let dataset_alias_offset = { fb.create_string(&self.dataset_alias.to_string()) };
-        let vocab_offset = { self.vocab.serialize(fb) };
-        let transform_offset = { self.transform.serialize(fb) };
+        let vocab_offset = { self.vocab.serialize(fb)? };
+        let transform_offset = { self.transform.serialize(fb)? };
         let query_inputs_offset = {
-            let offsets: Vec<_> = self.query_inputs.iter().map(|i| i.serialize(fb)).collect();
+            let offsets = self
+                .query_inputs
+                .iter()
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let prev_checkpoint_path_offset = self
-            .prev_checkpoint_path
-            .as_ref()
-            .map(|v| fb.create_string(v.to_str().unwrap()));
+        let prev_checkpoint_path_offset = match &self.prev_checkpoint_path {
+            Some(v) => Some({
+                fb.create_string(v.to_str().ok_or_else(|| {
+                    FlatbuffersEncodeError::invalid_value("Path is not valid UTF-8")
+                })?)
+            }),
+            None => None,
+        };
         let new_checkpoint_path_offset =
-            { fb.create_string(self.new_checkpoint_path.to_str().unwrap()) };
-        let new_data_path_offset = { fb.create_string(self.new_data_path.to_str().unwrap()) };
+            {
+                fb.create_string(self.new_checkpoint_path.to_str().ok_or_else(|| {
+                    FlatbuffersEncodeError::invalid_value("Path is not valid UTF-8")
+                })?)
+            };
+        let new_data_path_offset =
+            {
+                fb.create_string(self.new_data_path.to_str().ok_or_else(|| {
+                    FlatbuffersEncodeError::invalid_value("Path is not valid UTF-8")
+                })?)
+            };
         let mut builder = fb::TransformRequestBuilder::new(fb);
         builder.add_dataset_id(dataset_id_offset);
         builder.add_dataset_alias(dataset_alias_offset);
@@ -6761,46 +10959,71 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformRequest {
         prev_checkpoint_path_offset.map(|off| builder.add_prev_checkpoint_path(off));
         builder.add_new_checkpoint_path(new_checkpoint_path_offset);
         builder.add_new_data_path(new_data_path_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformRequest<'fb>> for odf::engine::TransformRequest {
-    fn deserialize(proxy: fb::TransformRequest<'fb>) -> Self {
-        odf::engine::TransformRequest {
+    fn deserialize(proxy: fb::TransformRequest<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::TransformRequest {
             dataset_id: proxy
                 .dataset_id()
-                .map(|v| odf::dataset::DatasetID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.datasetId"))?,
             dataset_alias: proxy
                 .dataset_alias()
-                .map(|v| odf::dataset::DatasetAlias::try_from(v).unwrap())
-                .unwrap(),
-            system_time: proxy.system_time().map(|v| fb_to_datetime(v)).unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetAlias::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.datasetAlias"))?,
+            system_time: proxy
+                .system_time()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| fb_to_datetime(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.systemTime"))?,
             vocab: proxy
                 .vocab()
-                .map(|v| odf::dataset::DatasetVocabulary::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::DatasetVocabulary::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.vocab"))?,
             transform: proxy
                 .transform()
-                .map(|v| odf::dataset::Transform::deserialize(v, proxy.transform_type()))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::Transform::deserialize(v, proxy.transform_type()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.transform"))?,
             query_inputs: proxy
                 .query_inputs()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::engine::TransformRequestInput::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-            next_offset: proxy.next_offset(),
-            prev_checkpoint_path: proxy.prev_checkpoint_path().map(|v| PathBuf::from(v)),
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.queryInputs"))?,
+            next_offset: Ok(proxy.next_offset())
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.nextOffset"))?,
+            prev_checkpoint_path: proxy
+                .prev_checkpoint_path()
+                .map(|v| Ok(PathBuf::from(v)))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.prevCheckpointPath"))?,
             new_checkpoint_path: proxy
                 .new_checkpoint_path()
-                .map(|v| PathBuf::from(v))
-                .unwrap(),
-            new_data_path: proxy.new_data_path().map(|v| PathBuf::from(v)).unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(PathBuf::from(v)))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.newCheckpointPath"))?,
+            new_data_path: proxy
+                .new_data_path()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(PathBuf::from(v)))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequest.newDataPath"))?,
+        })
     }
 }
 
@@ -6812,27 +11035,44 @@ impl<'fb> FlatbuffersDeserializable<fb::TransformRequest<'fb>> for odf::engine::
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformRequestInput {
     type OffsetT = WIPOffset<fb::TransformRequestInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let dataset_id_offset = { fb.create_vector(&self.dataset_id.as_bytes()) };
         let dataset_alias_offset = { fb.create_string(&self.dataset_alias.to_string()) };
         let query_alias_offset = { fb.create_string(&self.query_alias) };
-        let vocab_offset = { self.vocab.serialize(fb) };
-        let offset_interval_offset = self.offset_interval.as_ref().map(|v| v.serialize(fb));
+        let vocab_offset = { self.vocab.serialize(fb)? };
+        let offset_interval_offset = match &self.offset_interval {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let data_paths_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .data_paths
                 .iter()
-                .map(|i| fb.create_string(i.to_str().unwrap()))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> {
+                    Ok({
+                        fb.create_string(i.to_str().ok_or_else(|| {
+                            FlatbuffersEncodeError::invalid_value("Path is not valid UTF-8")
+                        })?)
+                    })
+                })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
-        let schema_file_offset = { fb.create_string(self.schema_file.to_str().unwrap()) };
+        let schema_file_offset =
+            {
+                fb.create_string(self.schema_file.to_str().ok_or_else(|| {
+                    FlatbuffersEncodeError::invalid_value("Path is not valid UTF-8")
+                })?)
+            };
         let explicit_watermarks_offset = {
-            let offsets: Vec<_> = self
+            let offsets = self
                 .explicit_watermarks
                 .iter()
-                .map(|i| i.serialize(fb))
-                .collect();
+                .map(|i| -> Result<_, FlatbuffersEncodeError> { Ok({ i.serialize(fb)? }) })
+                .collect::<Result<Vec<_>, _>>()?;
             fb.create_vector(&offsets)
         };
         let mut builder = fb::TransformRequestInputBuilder::new(fb);
@@ -6844,45 +11084,74 @@ impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformRequestInput {
         builder.add_data_paths(data_paths_offset);
         builder.add_schema_file(schema_file_offset);
         builder.add_explicit_watermarks(explicit_watermarks_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformRequestInput<'fb>>
     for odf::engine::TransformRequestInput
 {
-    fn deserialize(proxy: fb::TransformRequestInput<'fb>) -> Self {
-        odf::engine::TransformRequestInput {
+    fn deserialize(proxy: fb::TransformRequestInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::TransformRequestInput {
             dataset_id: proxy
                 .dataset_id()
-                .map(|v| odf::dataset::DatasetID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.datasetId"))?,
             dataset_alias: proxy
                 .dataset_alias()
-                .map(|v| odf::dataset::DatasetAlias::try_from(v).unwrap())
-                .unwrap(),
-            query_alias: proxy.query_alias().map(|v| v.to_owned()).unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::dataset::DatasetAlias::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.datasetAlias"))?,
+            query_alias: proxy
+                .query_alias()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.queryAlias"))?,
             vocab: proxy
                 .vocab()
-                .map(|v| odf::dataset::DatasetVocabulary::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::dataset::DatasetVocabulary::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.vocab"))?,
             offset_interval: proxy
                 .offset_interval()
-                .map(|v| odf::dataset::OffsetInterval::deserialize(v)),
+                .map(|v| odf::dataset::OffsetInterval::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("TransformRequestInput.offsetInterval")
+                })?,
             data_paths: proxy
                 .data_paths()
-                .map(|v| v.iter().map(|i| PathBuf::from(i)).collect())
-                .unwrap(),
-            schema_file: proxy.schema_file().map(|v| PathBuf::from(v)).unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    v.iter()
+                        .map(|i| Ok(PathBuf::from(i)))
+                        .collect::<Result<_, _>>()
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.dataPaths"))?,
+            schema_file: proxy
+                .schema_file()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(PathBuf::from(v)))
+                .map_err(|e: FlatbuffersDecodeError| e.at("TransformRequestInput.schemaFile"))?,
             explicit_watermarks: proxy
                 .explicit_watermarks()
-                .map(|v| {
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
                     v.iter()
                         .map(|i| odf::dataset::Watermark::deserialize(i))
-                        .collect()
+                        .collect::<Result<_, _>>()
                 })
-                .unwrap(),
-        }
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("TransformRequestInput.explicitWatermarks")
+                })?,
+        })
     }
 }
 
@@ -6897,24 +11166,24 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::TransformResponse>
     fn serialize(
         &self,
         fb: &mut FlatBufferBuilder<'fb>,
-    ) -> (fb::TransformResponse, WIPOffset<UnionWIPOffset>) {
+    ) -> Result<(fb::TransformResponse, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {
         match self {
-            odf::engine::TransformResponse::Progress(v) => (
+            odf::engine::TransformResponse::Progress(v) => Ok((
                 fb::TransformResponse::TransformResponseProgress,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::engine::TransformResponse::Success(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::engine::TransformResponse::Success(v) => Ok((
                 fb::TransformResponse::TransformResponseSuccess,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::engine::TransformResponse::InvalidQuery(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::engine::TransformResponse::InvalidQuery(v) => Ok((
                 fb::TransformResponse::TransformResponseInvalidQuery,
-                v.serialize(fb).as_union_value(),
-            ),
-            odf::engine::TransformResponse::InternalError(v) => (
+                v.serialize(fb)?.as_union_value(),
+            )),
+            odf::engine::TransformResponse::InternalError(v) => Ok((
                 fb::TransformResponse::TransformResponseInternalError,
-                v.serialize(fb).as_union_value(),
-            ),
+                v.serialize(fb)?.as_union_value(),
+            )),
         }
     }
 }
@@ -6922,37 +11191,41 @@ impl<'fb> FlatbuffersEnumSerializable<'fb, fb::TransformResponse>
 impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::TransformResponse>
     for odf::engine::TransformResponse
 {
-    fn deserialize(table: flatbuffers::Table<'fb>, t: fb::TransformResponse) -> Self {
+    fn deserialize(
+        table: flatbuffers::Table<'fb>,
+        t: fb::TransformResponse,
+    ) -> Result<Self, FlatbuffersDecodeError> {
         match t {
             fb::TransformResponse::TransformResponseProgress => {
-                odf::engine::TransformResponse::Progress(
-                    odf::engine::TransformResponseProgress::deserialize(unsafe {
-                        fb::TransformResponseProgress::init_from_table(table)
-                    }),
-                )
+                odf::engine::TransformResponseProgress::deserialize(unsafe {
+                    fb::TransformResponseProgress::init_from_table(table)
+                })
+                .map(odf::engine::TransformResponse::Progress)
             }
             fb::TransformResponse::TransformResponseSuccess => {
-                odf::engine::TransformResponse::Success(
-                    odf::engine::TransformResponseSuccess::deserialize(unsafe {
-                        fb::TransformResponseSuccess::init_from_table(table)
-                    }),
-                )
+                odf::engine::TransformResponseSuccess::deserialize(unsafe {
+                    fb::TransformResponseSuccess::init_from_table(table)
+                })
+                .map(odf::engine::TransformResponse::Success)
             }
             fb::TransformResponse::TransformResponseInvalidQuery => {
-                odf::engine::TransformResponse::InvalidQuery(
-                    odf::engine::TransformResponseInvalidQuery::deserialize(unsafe {
-                        fb::TransformResponseInvalidQuery::init_from_table(table)
-                    }),
-                )
+                odf::engine::TransformResponseInvalidQuery::deserialize(unsafe {
+                    fb::TransformResponseInvalidQuery::init_from_table(table)
+                })
+                .map(odf::engine::TransformResponse::InvalidQuery)
             }
             fb::TransformResponse::TransformResponseInternalError => {
-                odf::engine::TransformResponse::InternalError(
-                    odf::engine::TransformResponseInternalError::deserialize(unsafe {
-                        fb::TransformResponseInternalError::init_from_table(table)
-                    }),
-                )
+                odf::engine::TransformResponseInternalError::deserialize(unsafe {
+                    fb::TransformResponseInternalError::init_from_table(table)
+                })
+                .map(odf::engine::TransformResponse::InternalError)
             }
-            _ => panic!("Invalid enum value: {}", t.0),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant {
+                    typ: "TransformResponse",
+                    value: t.0,
+                },
+            )),
         }
     }
 }
@@ -6965,24 +11238,44 @@ impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::TransformResponse>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformResponseInternalError {
     type OffsetT = WIPOffset<fb::TransformResponseInternalError<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let message_offset = { fb.create_string(&self.message) };
-        let backtrace_offset = self.backtrace.as_ref().map(|v| fb.create_string(&v));
+        let backtrace_offset = match &self.backtrace {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::TransformResponseInternalErrorBuilder::new(fb);
         builder.add_message(message_offset);
         backtrace_offset.map(|off| builder.add_backtrace(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformResponseInternalError<'fb>>
     for odf::engine::TransformResponseInternalError
 {
-    fn deserialize(proxy: fb::TransformResponseInternalError<'fb>) -> Self {
-        odf::engine::TransformResponseInternalError {
-            message: proxy.message().map(|v| v.to_owned()).unwrap(),
-            backtrace: proxy.backtrace().map(|v| v.to_owned()),
-        }
+    fn deserialize(
+        proxy: fb::TransformResponseInternalError<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::TransformResponseInternalError {
+            message: proxy
+                .message()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("TransformResponseInternalError.message")
+                })?,
+            backtrace: proxy
+                .backtrace()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("TransformResponseInternalError.backtrace")
+                })?,
+        })
     }
 }
 
@@ -6994,21 +11287,32 @@ impl<'fb> FlatbuffersDeserializable<fb::TransformResponseInternalError<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformResponseInvalidQuery {
     type OffsetT = WIPOffset<fb::TransformResponseInvalidQuery<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let message_offset = { fb.create_string(&self.message) };
         let mut builder = fb::TransformResponseInvalidQueryBuilder::new(fb);
         builder.add_message(message_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformResponseInvalidQuery<'fb>>
     for odf::engine::TransformResponseInvalidQuery
 {
-    fn deserialize(proxy: fb::TransformResponseInvalidQuery<'fb>) -> Self {
-        odf::engine::TransformResponseInvalidQuery {
-            message: proxy.message().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(
+        proxy: fb::TransformResponseInvalidQuery<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::TransformResponseInvalidQuery {
+            message: proxy
+                .message()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("TransformResponseInvalidQuery.message")
+                })?,
+        })
     }
 }
 
@@ -7020,17 +11324,22 @@ impl<'fb> FlatbuffersDeserializable<fb::TransformResponseInvalidQuery<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformResponseProgress {
     type OffsetT = WIPOffset<fb::TransformResponseProgress<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::TransformResponseProgressBuilder::new(fb);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformResponseProgress<'fb>>
     for odf::engine::TransformResponseProgress
 {
-    fn deserialize(proxy: fb::TransformResponseProgress<'fb>) -> Self {
-        odf::engine::TransformResponseProgress {}
+    fn deserialize(
+        proxy: fb::TransformResponseProgress<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::TransformResponseProgress {})
     }
 }
 
@@ -7042,26 +11351,44 @@ impl<'fb> FlatbuffersDeserializable<fb::TransformResponseProgress<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::engine::TransformResponseSuccess {
     type OffsetT = WIPOffset<fb::TransformResponseSuccess<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let new_offset_interval_offset = self.new_offset_interval.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let new_offset_interval_offset = match &self.new_offset_interval {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::TransformResponseSuccessBuilder::new(fb);
         new_offset_interval_offset.map(|off| builder.add_new_offset_interval(off));
         self.new_watermark
             .map(|v| builder.add_new_watermark(&datetime_to_fb(&v)));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::TransformResponseSuccess<'fb>>
     for odf::engine::TransformResponseSuccess
 {
-    fn deserialize(proxy: fb::TransformResponseSuccess<'fb>) -> Self {
-        odf::engine::TransformResponseSuccess {
+    fn deserialize(
+        proxy: fb::TransformResponseSuccess<'fb>,
+    ) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::engine::TransformResponseSuccess {
             new_offset_interval: proxy
                 .new_offset_interval()
-                .map(|v| odf::dataset::OffsetInterval::deserialize(v)),
-            new_watermark: proxy.new_watermark().map(|v| fb_to_datetime(v)),
-        }
+                .map(|v| odf::dataset::OffsetInterval::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("TransformResponseSuccess.newOffsetInterval")
+                })?,
+            new_watermark: proxy
+                .new_watermark()
+                .map(|v| fb_to_datetime(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("TransformResponseSuccess.newWatermark")
+                })?,
+        })
     }
 }
 
@@ -7073,43 +11400,66 @@ impl<'fb> FlatbuffersDeserializable<fb::TransformResponseSuccess<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::ValueHandle {
     type OffsetT = WIPOffset<fb::ValueHandle<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let account_offset = { self.account.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let account_offset = { self.account.serialize(fb)? };
         let r#type_offset = { fb.create_string(&self.r#type.to_string()) };
         let id_offset = { fb.create_vector(&self.id.as_bytes()) };
         let name_offset = { fb.create_string(&self.name.to_string()) };
-        let path_offset = self.path.as_ref().map(|v| fb.create_string(&v));
+        let path_offset = match &self.path {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::ValueHandleBuilder::new(fb);
         builder.add_account(account_offset);
         builder.add_type_(type_offset);
         builder.add_id(id_offset);
         builder.add_name(name_offset);
         path_offset.map(|off| builder.add_path(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ValueHandle<'fb>> for odf::config::ValueHandle {
-    fn deserialize(proxy: fb::ValueHandle<'fb>) -> Self {
-        odf::config::ValueHandle {
+    fn deserialize(proxy: fb::ValueHandle<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::ValueHandle {
             account: proxy
                 .account()
-                .map(|v| odf::auth::AccountHandle::deserialize(v))
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::auth::AccountHandle::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.account"))?,
             r#type: proxy
                 .type_()
-                .map(|v| odf::resource::TypeUri::try_from(v).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::TypeUri::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.type"))?,
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.id"))?,
             name: proxy
                 .name()
-                .map(|v| odf::resource::ResourceName::try_from(v).unwrap())
-                .unwrap(),
-            path: proxy.path().map(|v| v.to_owned()),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::ResourceName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.name"))?,
+            path: proxy
+                .path()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueHandle.path"))?,
+        })
     }
 }
 
@@ -7121,40 +11471,75 @@ impl<'fb> FlatbuffersDeserializable<fb::ValueHandle<'fb>> for odf::config::Value
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::ValueRef {
     type OffsetT = WIPOffset<fb::ValueRef<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let account_offset = self.account.as_ref().map(|v| v.serialize(fb));
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let account_offset = match &self.account {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let r#type_offset = { fb.create_string(&self.r#type.to_string()) };
-        let id_offset = self.id.as_ref().map(|v| fb.create_vector(&v.as_bytes()));
-        let name_offset = self.name.as_ref().map(|v| fb.create_string(&v.to_string()));
-        let path_offset = self.path.as_ref().map(|v| fb.create_string(&v));
+        let id_offset = match &self.id {
+            Some(v) => Some({ fb.create_vector(&v.as_bytes()) }),
+            None => None,
+        };
+        let name_offset = match &self.name {
+            Some(v) => Some({ fb.create_string(&v.to_string()) }),
+            None => None,
+        };
+        let path_offset = match &self.path {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::ValueRefBuilder::new(fb);
         account_offset.map(|off| builder.add_account(off));
         builder.add_type_(type_offset);
         id_offset.map(|off| builder.add_id(off));
         name_offset.map(|off| builder.add_name(off));
         path_offset.map(|off| builder.add_path(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ValueRef<'fb>> for odf::config::ValueRef {
-    fn deserialize(proxy: fb::ValueRef<'fb>) -> Self {
-        odf::config::ValueRef {
+    fn deserialize(proxy: fb::ValueRef<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::ValueRef {
             account: proxy
                 .account()
-                .map(|v| odf::auth::AccountRef::deserialize(v)),
+                .map(|v| odf::auth::AccountRef::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.account"))?,
             r#type: proxy
                 .type_()
-                .map(|v| odf::resource::TypeRef::try_from(v).unwrap())
-                .unwrap(),
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| {
+                    odf::resource::TypeRef::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.type"))?,
             id: proxy
                 .id()
-                .map(|v| odf::resource::ResourceID::from_bytes(v.bytes()).unwrap()),
+                .map(|v| {
+                    odf::resource::ResourceID::from_bytes(v.bytes())
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.id"))?,
             name: proxy
                 .name()
-                .map(|v| odf::resource::ResourceName::try_from(v).unwrap()),
-            path: proxy.path().map(|v| v.to_owned()),
-        }
+                .map(|v| {
+                    odf::resource::ResourceName::try_from(v)
+                        .map_err(FlatbuffersDecodeError::invalid_value)
+                })
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.name"))?,
+            path: proxy
+                .path()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("ValueRef.path"))?,
+        })
     }
 }
 
@@ -7166,40 +11551,49 @@ impl<'fb> FlatbuffersDeserializable<fb::ValueRef<'fb>> for odf::config::ValueRef
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::ValueRefs {
     type OffsetT = WIPOffset<fb::ValueRefs<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = value.serialize(fb);
+                let value_offset = value.serialize(fb)?;
                 let mut entry_builder = fb::ValueRefsEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
         let mut builder = fb::ValueRefsBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::ValueRefs<'fb>> for odf::config::ValueRefs {
-    fn deserialize(proxy: fb::ValueRefs<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
-                .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = odf::config::ValueRef::deserialize(entry.value().unwrap());
-                    (key, value)
-                })
-                .collect(),
-        }
+    fn deserialize(proxy: fb::ValueRefs<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| odf::config::ValueRef::deserialize(v))?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("ValueRefs.entries"))?;
+        Ok(Self { entries })
     }
 }
 
@@ -7211,19 +11605,26 @@ impl<'fb> FlatbuffersDeserializable<fb::ValueRefs<'fb>> for odf::config::ValueRe
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::Variable {
     type OffsetT = WIPOffset<fb::Variable<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let value_offset = { fb.create_string(&self.value) };
         let mut builder = fb::VariableBuilder::new(fb);
         builder.add_value(value_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Variable<'fb>> for odf::config::Variable {
-    fn deserialize(proxy: fb::Variable<'fb>) -> Self {
-        odf::config::Variable {
-            value: proxy.value().map(|v| v.to_owned()).unwrap(),
-        }
+    fn deserialize(proxy: fb::Variable<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::Variable {
+            value: proxy
+                .value()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Variable.value"))?,
+        })
     }
 }
 
@@ -7235,22 +11636,26 @@ impl<'fb> FlatbuffersDeserializable<fb::Variable<'fb>> for odf::config::Variable
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::VariableSetSpec {
     type OffsetT = WIPOffset<fb::VariableSetSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let variables_offset = { self.variables.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let variables_offset = { self.variables.serialize(fb)? };
         let mut builder = fb::VariableSetSpecBuilder::new(fb);
         builder.add_variables(variables_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::VariableSetSpec<'fb>> for odf::config::VariableSetSpec {
-    fn deserialize(proxy: fb::VariableSetSpec<'fb>) -> Self {
-        odf::config::VariableSetSpec {
+    fn deserialize(proxy: fb::VariableSetSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::VariableSetSpec {
             variables: proxy
                 .variables()
-                .map(|v| odf::config::Variables::deserialize(v))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::config::Variables::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("VariableSetSpec.variables"))?,
+        })
     }
 }
 
@@ -7262,24 +11667,28 @@ impl<'fb> FlatbuffersDeserializable<fb::VariableSetSpec<'fb>> for odf::config::V
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::VariableSetSpecInput {
     type OffsetT = WIPOffset<fb::VariableSetSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let variables_offset = { self.variables.serialize(fb) };
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let variables_offset = { self.variables.serialize(fb)? };
         let mut builder = fb::VariableSetSpecInputBuilder::new(fb);
         builder.add_variables(variables_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::VariableSetSpecInput<'fb>>
     for odf::config::VariableSetSpecInput
 {
-    fn deserialize(proxy: fb::VariableSetSpecInput<'fb>) -> Self {
-        odf::config::VariableSetSpecInput {
+    fn deserialize(proxy: fb::VariableSetSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::config::VariableSetSpecInput {
             variables: proxy
                 .variables()
-                .map(|v| odf::config::Variables::deserialize(v))
-                .unwrap(),
-        }
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| odf::config::Variables::deserialize(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("VariableSetSpecInput.variables"))?,
+        })
     }
 }
 
@@ -7291,40 +11700,49 @@ impl<'fb> FlatbuffersDeserializable<fb::VariableSetSpecInput<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::config::Variables {
     type OffsetT = WIPOffset<fb::Variables<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
-        let entries: Vec<_> = self
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let entries = self
             .entries
             .iter()
-            .map(|(key, value)| {
+            .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {
                 let key_offset = fb.create_string(key.as_str());
-                let value_offset = value.serialize(fb);
+                let value_offset = value.serialize(fb)?;
                 let mut entry_builder = fb::VariablesEntryBuilder::new(fb);
                 entry_builder.add_key(key_offset);
                 entry_builder.add_value(value_offset);
-                entry_builder.finish()
+                Ok(entry_builder.finish())
             })
-            .collect();
+            .collect::<Result<Vec<_>, _>>()?;
         let entries_offset = fb.create_vector(&entries);
         let mut builder = fb::VariablesBuilder::new(fb);
         builder.add_entries(entries_offset);
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Variables<'fb>> for odf::config::Variables {
-    fn deserialize(proxy: fb::Variables<'fb>) -> Self {
-        Self {
-            entries: proxy
-                .entries()
-                .unwrap_or_default()
-                .iter()
-                .map(|entry| {
-                    let key = entry.key().parse().unwrap();
-                    let value = odf::config::Variable::deserialize(entry.value().unwrap());
-                    (key, value)
-                })
-                .collect(),
-        }
+    fn deserialize(proxy: fb::Variables<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        let entries = proxy
+            .entries()
+            .unwrap_or_default()
+            .iter()
+            .map(|entry| -> Result<_, FlatbuffersDecodeError> {
+                let key = entry
+                    .key()
+                    .parse()
+                    .map_err(FlatbuffersDecodeError::invalid_value)?;
+                let value = entry
+                    .value()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| odf::config::Variable::deserialize(v))?;
+                Ok((key, value))
+            })
+            .collect::<Result<_, _>>()
+            .map_err(|e| e.at("Variables.entries"))?;
+        Ok(Self { entries })
     }
 }
 
@@ -7336,18 +11754,25 @@ impl<'fb> FlatbuffersDeserializable<fb::Variables<'fb>> for odf::config::Variabl
 impl<'fb> FlatbuffersSerializable<'fb> for odf::storage::VolumeCapacity {
     type OffsetT = WIPOffset<fb::VolumeCapacity<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::VolumeCapacityBuilder::new(fb);
         self.storage.map(|v| builder.add_storage(v.as_u64()));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::VolumeCapacity<'fb>> for odf::storage::VolumeCapacity {
-    fn deserialize(proxy: fb::VolumeCapacity<'fb>) -> Self {
-        odf::storage::VolumeCapacity {
-            storage: proxy.storage().map(|v| ByteSize::from(v)),
-        }
+    fn deserialize(proxy: fb::VolumeCapacity<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::storage::VolumeCapacity {
+            storage: proxy
+                .storage()
+                .map(|v| Ok(ByteSize::from(v)))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("VolumeCapacity.storage"))?,
+        })
     }
 }
 
@@ -7359,20 +11784,31 @@ impl<'fb> FlatbuffersDeserializable<fb::VolumeCapacity<'fb>> for odf::storage::V
 impl<'fb> FlatbuffersSerializable<'fb> for odf::dataset::Watermark {
     type OffsetT = WIPOffset<fb::Watermark<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let mut builder = fb::WatermarkBuilder::new(fb);
         builder.add_system_time(&datetime_to_fb(&self.system_time));
         builder.add_event_time(&datetime_to_fb(&self.event_time));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::Watermark<'fb>> for odf::dataset::Watermark {
-    fn deserialize(proxy: fb::Watermark<'fb>) -> Self {
-        odf::dataset::Watermark {
-            system_time: proxy.system_time().map(|v| fb_to_datetime(v)).unwrap(),
-            event_time: proxy.event_time().map(|v| fb_to_datetime(v)).unwrap(),
-        }
+    fn deserialize(proxy: fb::Watermark<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::dataset::Watermark {
+            system_time: proxy
+                .system_time()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| fb_to_datetime(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Watermark.systemTime"))?,
+            event_time: proxy
+                .event_time()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| fb_to_datetime(v))
+                .map_err(|e: FlatbuffersDecodeError| e.at("Watermark.eventTime"))?,
+        })
     }
 }
 
@@ -7384,22 +11820,36 @@ impl<'fb> FlatbuffersDeserializable<fb::Watermark<'fb>> for odf::dataset::Waterm
 impl<'fb> FlatbuffersSerializable<'fb> for odf::sink::WebhookTargetSpec {
     type OffsetT = WIPOffset<fb::WebhookTargetSpec<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let url_offset = { fb.create_string(&self.url) };
-        let secret_offset = self.secret.as_ref().map(|v| v.serialize(fb));
+        let secret_offset = match &self.secret {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::WebhookTargetSpecBuilder::new(fb);
         builder.add_url(url_offset);
         secret_offset.map(|off| builder.add_secret(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::WebhookTargetSpec<'fb>> for odf::sink::WebhookTargetSpec {
-    fn deserialize(proxy: fb::WebhookTargetSpec<'fb>) -> Self {
-        odf::sink::WebhookTargetSpec {
-            url: proxy.url().map(|v| v.to_owned()).unwrap(),
-            secret: proxy.secret().map(|v| odf::config::Secret::deserialize(v)),
-        }
+    fn deserialize(proxy: fb::WebhookTargetSpec<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::sink::WebhookTargetSpec {
+            url: proxy
+                .url()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpec.url"))?,
+            secret: proxy
+                .secret()
+                .map(|v| odf::config::Secret::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpec.secret"))?,
+        })
     }
 }
 
@@ -7411,24 +11861,38 @@ impl<'fb> FlatbuffersDeserializable<fb::WebhookTargetSpec<'fb>> for odf::sink::W
 impl<'fb> FlatbuffersSerializable<'fb> for odf::sink::WebhookTargetSpecInput {
     type OffsetT = WIPOffset<fb::WebhookTargetSpecInput<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
         let url_offset = { fb.create_string(&self.url) };
-        let secret_offset = self.secret.as_ref().map(|v| v.serialize(fb));
+        let secret_offset = match &self.secret {
+            Some(v) => Some({ v.serialize(fb)? }),
+            None => None,
+        };
         let mut builder = fb::WebhookTargetSpecInputBuilder::new(fb);
         builder.add_url(url_offset);
         secret_offset.map(|off| builder.add_secret(off));
-        builder.finish()
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::WebhookTargetSpecInput<'fb>>
     for odf::sink::WebhookTargetSpecInput
 {
-    fn deserialize(proxy: fb::WebhookTargetSpecInput<'fb>) -> Self {
-        odf::sink::WebhookTargetSpecInput {
-            url: proxy.url().map(|v| v.to_owned()).unwrap(),
-            secret: proxy.secret().map(|v| odf::config::Secret::deserialize(v)),
-        }
+    fn deserialize(proxy: fb::WebhookTargetSpecInput<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::sink::WebhookTargetSpecInput {
+            url: proxy
+                .url()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| Ok(v.to_owned()))
+                .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpecInput.url"))?,
+            secret: proxy
+                .secret()
+                .map(|v| odf::config::Secret::deserialize(v))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetSpecInput.secret"))?,
+        })
     }
 }
 
@@ -7440,20 +11904,52 @@ impl<'fb> FlatbuffersDeserializable<fb::WebhookTargetSpecInput<'fb>>
 impl<'fb> FlatbuffersSerializable<'fb> for odf::sink::WebhookTargetStatus {
     type OffsetT = WIPOffset<fb::WebhookTargetStatus<'fb>>;
 
-    fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {
+    fn serialize(
+        &self,
+        fb: &mut FlatBufferBuilder<'fb>,
+    ) -> Result<Self::OffsetT, FlatbuffersEncodeError> {
+        let reason_offset = match &self.reason {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
+        let message_offset = match &self.message {
+            Some(v) => Some({ fb.create_string(&v) }),
+            None => None,
+        };
         let mut builder = fb::WebhookTargetStatusBuilder::new(fb);
-        builder.add_value(self.value.into());
-        builder.finish()
+        builder.add_value(fb::WebhookTargetStatusValue::try_from(&self.value)?);
+        builder.add_last_transition_time(&datetime_to_fb(&self.last_transition_time));
+        reason_offset.map(|off| builder.add_reason(off));
+        message_offset.map(|off| builder.add_message(off));
+        Ok(builder.finish())
     }
 }
 
 impl<'fb> FlatbuffersDeserializable<fb::WebhookTargetStatus<'fb>>
     for odf::sink::WebhookTargetStatus
 {
-    fn deserialize(proxy: fb::WebhookTargetStatus<'fb>) -> Self {
-        odf::sink::WebhookTargetStatus {
-            value: proxy.value().into(),
-        }
+    fn deserialize(proxy: fb::WebhookTargetStatus<'fb>) -> Result<Self, FlatbuffersDecodeError> {
+        Ok(odf::sink::WebhookTargetStatus {
+            value: odf::sink::WebhookTargetStatusValue::try_from(proxy.value())
+                .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetStatus.value"))?,
+            last_transition_time: proxy
+                .last_transition_time()
+                .ok_or_else(FlatbuffersDecodeError::missing_field)
+                .and_then(|v| fb_to_datetime(v))
+                .map_err(|e: FlatbuffersDecodeError| {
+                    e.at("WebhookTargetStatus.lastTransitionTime")
+                })?,
+            reason: proxy
+                .reason()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetStatus.reason"))?,
+            message: proxy
+                .message()
+                .map(|v| Ok(v.to_owned()))
+                .transpose()
+                .map_err(|e: FlatbuffersDecodeError| e.at("WebhookTargetStatus.message"))?,
+        })
     }
 }
 
@@ -7462,21 +11958,30 @@ impl<'fb> FlatbuffersDeserializable<fb::WebhookTargetStatus<'fb>>
 // Schema: https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus#/$defs/Value
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-impl From<odf::sink::WebhookTargetStatusValue> for fb::WebhookTargetStatusValue {
-    fn from(v: odf::sink::WebhookTargetStatusValue) -> Self {
+impl TryFrom<&odf::sink::WebhookTargetStatusValue> for fb::WebhookTargetStatusValue {
+    type Error = FlatbuffersEncodeError;
+
+    fn try_from(v: &odf::sink::WebhookTargetStatusValue) -> Result<Self, FlatbuffersEncodeError> {
         match v {
-            odf::sink::WebhookTargetStatusValue::Ready => fb::WebhookTargetStatusValue::Ready,
-            odf::sink::WebhookTargetStatusValue::Failed => fb::WebhookTargetStatusValue::Failed,
+            odf::sink::WebhookTargetStatusValue::Ready => Ok(fb::WebhookTargetStatusValue::Ready),
+            odf::sink::WebhookTargetStatusValue::Failed => Ok(fb::WebhookTargetStatusValue::Failed),
         }
     }
 }
 
-impl Into<odf::sink::WebhookTargetStatusValue> for fb::WebhookTargetStatusValue {
-    fn into(self) -> odf::sink::WebhookTargetStatusValue {
-        match self {
-            fb::WebhookTargetStatusValue::Ready => odf::sink::WebhookTargetStatusValue::Ready,
-            fb::WebhookTargetStatusValue::Failed => odf::sink::WebhookTargetStatusValue::Failed,
-            _ => panic!("Invalid enum value: {}", self.0),
+impl TryFrom<fb::WebhookTargetStatusValue> for odf::sink::WebhookTargetStatusValue {
+    type Error = FlatbuffersDecodeError;
+
+    fn try_from(v: fb::WebhookTargetStatusValue) -> Result<Self, FlatbuffersDecodeError> {
+        match v {
+            fb::WebhookTargetStatusValue::Ready => Ok(odf::sink::WebhookTargetStatusValue::Ready),
+            fb::WebhookTargetStatusValue::Failed => Ok(odf::sink::WebhookTargetStatusValue::Failed),
+            _ => Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::UnknownEnumValue {
+                    typ: "WebhookTargetStatusValue",
+                    value: v.0 as i64,
+                },
+            )),
         }
     }
 }
@@ -7494,23 +11999,30 @@ fn datetime_to_fb(dt: &DateTime<Utc>) -> fb::Timestamp {
     )
 }
 
-fn fb_to_datetime(dt: &fb::Timestamp) -> DateTime<Utc> {
-    let naive_date_time = NaiveDate::from_yo_opt(dt.year(), dt.ordinal() as u32)
-        .unwrap()
-        .and_time(
-            NaiveTime::from_num_seconds_from_midnight_opt(
-                dt.seconds_from_midnight(),
-                dt.nanoseconds(),
-            )
-            .unwrap(),
-        );
-    Utc.from_local_datetime(&naive_date_time).unwrap()
+pub(crate) fn fb_to_datetime(dt: &fb::Timestamp) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+    let date = NaiveDate::from_yo_opt(dt.year(), dt.ordinal() as u32).ok_or_else(|| {
+        FlatbuffersDecodeError::invalid_value(format!(
+            "Invalid date: year {} ordinal {}",
+            dt.year(),
+            dt.ordinal()
+        ))
+    })?;
+    let time =
+        NaiveTime::from_num_seconds_from_midnight_opt(dt.seconds_from_midnight(), dt.nanoseconds())
+            .ok_or_else(|| {
+                FlatbuffersDecodeError::invalid_value(format!(
+                    "Invalid time: {}s {}ns",
+                    dt.seconds_from_midnight(),
+                    dt.nanoseconds()
+                ))
+            })?;
+    Ok(Utc.from_utc_datetime(&date.and_time(time)))
 }
 
 fn duration_to_fb(v: &DurationString) -> fb::Duration {
     fb::Duration::new(v.as_nanos() as u64)
 }
 
-fn fb_to_duration(v: &fb::Duration) -> DurationString {
+pub(crate) fn fb_to_duration(v: &fb::Duration) -> DurationString {
     DurationString::new(std::time::Duration::from_nanos(v.nanoseconds()))
 }
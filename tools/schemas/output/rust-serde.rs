@@ -68,6 +68,40 @@ macro_rules! implement_serde_as {
             }
         }
     };
+    ($dto:ty, $proxy:ty, $($dto_t:ident => $proxy_t:ident),+) => {
+        impl<$($dto_t, $proxy_t),+> ::serde_with::SerializeAs<$dto> for $proxy
+        where
+            $dto: Clone,
+            $($proxy_t: From<$dto_t> + Serialize,)+
+        {
+            fn serialize_as<S>(value: &$dto, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                // TODO: PERF: Avoid cloning on serialize
+                let value: $proxy = value.clone().into();
+                value.serialize(serializer)
+            }
+        }
+
+        impl<'de, $($dto_t, $proxy_t),+> serde_with::DeserializeAs<'de, $dto> for $proxy
+        where
+            $(
+                $proxy_t: Deserialize<'de>,
+                $dto_t: TryFrom<$proxy_t>,
+                ValidationError: From<<$dto_t as TryFrom<$proxy_t>>::Error>,
+            )+
+        {
+            fn deserialize_as<D>(deserializer: D) -> Result<$dto, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                use ::serde::de::Error;
+                let proxy = <$proxy>::deserialize(deserializer)?;
+                proxy.try_into().map_err(D::Error::custom)
+            }
+        }
+    };
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -343,7 +377,7 @@ pub mod auth {
 
     impl TryFrom<AccountType> for dtos::auth::AccountType {
         type Error = ValidationError;
-        fn try_from(v: AccountType) -> Result<Self, Self::Error> {
+        fn try_from(v: AccountType) -> Result<Self, ValidationError> {
             match v {
                 AccountType::User => Ok(Self::User),
                 AccountType::Organization => Ok(Self::Organization),
@@ -676,7 +710,7 @@ pub mod config {
     impl From<dtos::config::Secret> for Secret {
         fn from(v: dtos::config::Secret) -> Self {
             Self {
-                value: v.value,
+                value: v.value.into_inner(),
                 content_encoding: v.content_encoding,
             }
         }
@@ -686,7 +720,7 @@ pub mod config {
         type Error = ValidationError;
         fn try_from(v: Secret) -> Result<Self, ValidationError> {
             Ok(Self {
-                value: v.value,
+                value: dtos::Sensitive::new(v.value),
                 content_encoding: v.content_encoding,
             })
         }
@@ -1325,7 +1359,7 @@ pub mod data {
 
     impl TryFrom<DataType> for dtos::data::DataType {
         type Error = ValidationError;
-        fn try_from(v: DataType) -> Result<Self, Self::Error> {
+        fn try_from(v: DataType) -> Result<Self, ValidationError> {
             match v {
                 DataType::Binary(v) => Ok(Self::Binary(v.try_into()?)),
                 DataType::Bool(v) => Ok(Self::Bool(v.try_into()?)),
@@ -2189,7 +2223,7 @@ pub mod data {
 
     impl TryFrom<OperationType> for dtos::data::OperationType {
         type Error = ValidationError;
-        fn try_from(v: OperationType) -> Result<Self, Self::Error> {
+        fn try_from(v: OperationType) -> Result<Self, ValidationError> {
             match v {
                 OperationType::Append => Ok(Self::Append),
                 OperationType::Retract => Ok(Self::Retract),
@@ -2235,7 +2269,7 @@ pub mod data {
 
     impl TryFrom<TimeUnit> for dtos::data::TimeUnit {
         type Error = ValidationError;
-        fn try_from(v: TimeUnit) -> Result<Self, Self::Error> {
+        fn try_from(v: TimeUnit) -> Result<Self, ValidationError> {
             match v {
                 TimeUnit::Second => Ok(Self::Second),
                 TimeUnit::Millisecond => Ok(Self::Millisecond),
@@ -2398,7 +2432,7 @@ pub mod dataset {
 
     impl TryFrom<Attachments> for dtos::dataset::Attachments {
         type Error = ValidationError;
-        fn try_from(v: Attachments) -> Result<Self, Self::Error> {
+        fn try_from(v: Attachments) -> Result<Self, ValidationError> {
             match v {
                 Attachments::Embedded(v) => Ok(Self::Embedded(v.try_into()?)),
             }
@@ -2594,7 +2628,7 @@ pub mod dataset {
 
     impl TryFrom<DatasetKind> for dtos::dataset::DatasetKind {
         type Error = ValidationError;
-        fn try_from(v: DatasetKind) -> Result<Self, Self::Error> {
+        fn try_from(v: DatasetKind) -> Result<Self, ValidationError> {
             match v {
                 DatasetKind::Root => Ok(Self::Root),
                 DatasetKind::Derivative => Ok(Self::Derivative),
@@ -2982,7 +3016,7 @@ pub mod dataset {
     #[derive(Debug, Serialize, Deserialize)]
     #[serde(deny_unknown_fields)]
     #[serde(tag = "kind")]
-    pub enum MetadataEvent {
+    pub enum MetadataEventKnown {
         #[serde(alias = "addData", alias = "adddata")]
         AddData(dataset::AddData),
         #[serde(alias = "executeTransform", alias = "executetransform")]
@@ -3011,6 +3045,87 @@ pub mod dataset {
         DisablePollingSource(legacy::DisablePollingSource),
     }
 
+    #[derive(Debug)]
+    pub enum MetadataEvent {
+        Known(MetadataEventKnown),
+        Unknown(dtos::UnknownVariant),
+    }
+
+    impl Serialize for MetadataEvent {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            match self {
+                Self::Known(v) => v.serialize(serializer),
+                Self::Unknown(dtos::UnknownVariant::Json { value, .. }) => {
+                    value.serialize(serializer)
+                }
+                Self::Unknown(dtos::UnknownVariant::Flatbuffers { tag, .. }) => {
+                    Err(::serde::ser::Error::custom(format!(
+                        "Variant {tag} of MetadataEvent was read from flatbuffers and can't be represented in this format"
+                    )))
+                }
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for MetadataEvent {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            use ::serde::de::Error;
+            const KNOWN_TAGS: &[&str] = &[
+                "AddData",
+                "AddPushSource",
+                "DisablePollingSource",
+                "DisablePushSource",
+                "ExecuteTransform",
+                "Seed",
+                "SetAttachments",
+                "SetDataSchema",
+                "SetInfo",
+                "SetLicense",
+                "SetPollingSource",
+                "SetTransform",
+                "SetVocab",
+                "addData",
+                "addPushSource",
+                "adddata",
+                "addpushsource",
+                "disablePollingSource",
+                "disablePushSource",
+                "disablepollingsource",
+                "disablepushsource",
+                "executeTransform",
+                "executetransform",
+                "seed",
+                "setAttachments",
+                "setDataSchema",
+                "setInfo",
+                "setLicense",
+                "setPollingSource",
+                "setTransform",
+                "setVocab",
+                "setattachments",
+                "setdataschema",
+                "setinfo",
+                "setlicense",
+                "setpollingsource",
+                "settransform",
+                "setvocab",
+            ];
+
+            let value = ::serde_json::Value::deserialize(deserializer)?;
+            match value.get("kind").and_then(|v| v.as_str()) {
+                Some(tag) if !KNOWN_TAGS.contains(&tag) => {
+                    Ok(Self::Unknown(dtos::UnknownVariant::Json {
+                        tag: tag.to_string(),
+                        value,
+                    }))
+                }
+                _ => MetadataEventKnown::deserialize(value)
+                    .map(Self::Known)
+                    .map_err(D::Error::custom),
+            }
+        }
+    }
+
     impl IntoDto for MetadataEvent {
         type Dto = dtos::dataset::MetadataEvent;
         fn into_dto(self) -> Result<Self::Dto, ValidationError> {
@@ -3021,50 +3136,92 @@ pub mod dataset {
     impl From<dtos::dataset::MetadataEvent> for MetadataEvent {
         fn from(v: dtos::dataset::MetadataEvent) -> Self {
             match v {
-                dtos::dataset::MetadataEvent::AddData(v) => Self::AddData(v.into()),
+                dtos::dataset::MetadataEvent::AddData(v) => {
+                    Self::Known(MetadataEventKnown::AddData(v.into()))
+                }
                 dtos::dataset::MetadataEvent::ExecuteTransform(v) => {
-                    Self::ExecuteTransform(v.into())
+                    Self::Known(MetadataEventKnown::ExecuteTransform(v.into()))
+                }
+                dtos::dataset::MetadataEvent::Seed(v) => {
+                    Self::Known(MetadataEventKnown::Seed(v.into()))
                 }
-                dtos::dataset::MetadataEvent::Seed(v) => Self::Seed(v.into()),
                 dtos::dataset::MetadataEvent::SetPollingSource(v) => {
-                    Self::SetPollingSource(v.into())
+                    Self::Known(MetadataEventKnown::SetPollingSource(v.into()))
+                }
+                dtos::dataset::MetadataEvent::SetTransform(v) => {
+                    Self::Known(MetadataEventKnown::SetTransform(v.into()))
+                }
+                dtos::dataset::MetadataEvent::SetVocab(v) => {
+                    Self::Known(MetadataEventKnown::SetVocab(v.into()))
+                }
+                dtos::dataset::MetadataEvent::SetAttachments(v) => {
+                    Self::Known(MetadataEventKnown::SetAttachments(v.into()))
+                }
+                dtos::dataset::MetadataEvent::SetInfo(v) => {
+                    Self::Known(MetadataEventKnown::SetInfo(v.into()))
+                }
+                dtos::dataset::MetadataEvent::SetLicense(v) => {
+                    Self::Known(MetadataEventKnown::SetLicense(v.into()))
+                }
+                dtos::dataset::MetadataEvent::SetDataSchema(v) => {
+                    Self::Known(MetadataEventKnown::SetDataSchema(v.into()))
+                }
+                dtos::dataset::MetadataEvent::AddPushSource(v) => {
+                    Self::Known(MetadataEventKnown::AddPushSource(v.into()))
                 }
-                dtos::dataset::MetadataEvent::SetTransform(v) => Self::SetTransform(v.into()),
-                dtos::dataset::MetadataEvent::SetVocab(v) => Self::SetVocab(v.into()),
-                dtos::dataset::MetadataEvent::SetAttachments(v) => Self::SetAttachments(v.into()),
-                dtos::dataset::MetadataEvent::SetInfo(v) => Self::SetInfo(v.into()),
-                dtos::dataset::MetadataEvent::SetLicense(v) => Self::SetLicense(v.into()),
-                dtos::dataset::MetadataEvent::SetDataSchema(v) => Self::SetDataSchema(v.into()),
-                dtos::dataset::MetadataEvent::AddPushSource(v) => Self::AddPushSource(v.into()),
                 dtos::dataset::MetadataEvent::DisablePushSource(v) => {
-                    Self::DisablePushSource(v.into())
+                    Self::Known(MetadataEventKnown::DisablePushSource(v.into()))
                 }
                 dtos::dataset::MetadataEvent::DisablePollingSource(v) => {
-                    Self::DisablePollingSource(v.into())
+                    Self::Known(MetadataEventKnown::DisablePollingSource(v.into()))
                 }
+                dtos::dataset::MetadataEvent::Unknown(v) => Self::Unknown(v),
             }
         }
     }
 
     impl TryFrom<MetadataEvent> for dtos::dataset::MetadataEvent {
         type Error = ValidationError;
-        fn try_from(v: MetadataEvent) -> Result<Self, Self::Error> {
+        fn try_from(v: MetadataEvent) -> Result<Self, ValidationError> {
             match v {
-                MetadataEvent::AddData(v) => Ok(Self::AddData(v.try_into()?)),
-                MetadataEvent::ExecuteTransform(v) => Ok(Self::ExecuteTransform(v.try_into()?)),
-                MetadataEvent::Seed(v) => Ok(Self::Seed(v.try_into()?)),
-                MetadataEvent::SetPollingSource(v) => Ok(Self::SetPollingSource(v.try_into()?)),
-                MetadataEvent::SetTransform(v) => Ok(Self::SetTransform(v.try_into()?)),
-                MetadataEvent::SetVocab(v) => Ok(Self::SetVocab(v.try_into()?)),
-                MetadataEvent::SetAttachments(v) => Ok(Self::SetAttachments(v.try_into()?)),
-                MetadataEvent::SetInfo(v) => Ok(Self::SetInfo(v.try_into()?)),
-                MetadataEvent::SetLicense(v) => Ok(Self::SetLicense(v.try_into()?)),
-                MetadataEvent::SetDataSchema(v) => Ok(Self::SetDataSchema(v.try_into()?)),
-                MetadataEvent::AddPushSource(v) => Ok(Self::AddPushSource(v.try_into()?)),
-                MetadataEvent::DisablePushSource(v) => Ok(Self::DisablePushSource(v.try_into()?)),
-                MetadataEvent::DisablePollingSource(v) => {
+                MetadataEvent::Known(MetadataEventKnown::AddData(v)) => {
+                    Ok(Self::AddData(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::ExecuteTransform(v)) => {
+                    Ok(Self::ExecuteTransform(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::Seed(v)) => Ok(Self::Seed(v.try_into()?)),
+                MetadataEvent::Known(MetadataEventKnown::SetPollingSource(v)) => {
+                    Ok(Self::SetPollingSource(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::SetTransform(v)) => {
+                    Ok(Self::SetTransform(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::SetVocab(v)) => {
+                    Ok(Self::SetVocab(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::SetAttachments(v)) => {
+                    Ok(Self::SetAttachments(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::SetInfo(v)) => {
+                    Ok(Self::SetInfo(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::SetLicense(v)) => {
+                    Ok(Self::SetLicense(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::SetDataSchema(v)) => {
+                    Ok(Self::SetDataSchema(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::AddPushSource(v)) => {
+                    Ok(Self::AddPushSource(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::DisablePushSource(v)) => {
+                    Ok(Self::DisablePushSource(v.try_into()?))
+                }
+                MetadataEvent::Known(MetadataEventKnown::DisablePollingSource(v)) => {
                     Ok(Self::DisablePollingSource(v.try_into()?))
                 }
+                MetadataEvent::Unknown(v) => Ok(Self::Unknown(v)),
             }
         }
     }
@@ -3586,7 +3743,7 @@ pub mod dataset {
 
     impl TryFrom<Transform> for dtos::dataset::Transform {
         type Error = ValidationError;
-        fn try_from(v: Transform) -> Result<Self, Self::Error> {
+        fn try_from(v: Transform) -> Result<Self, ValidationError> {
             match v {
                 Transform::Sql(v) => Ok(Self::Sql(v.try_into()?)),
             }
@@ -3827,7 +3984,7 @@ pub mod engine {
 
     impl TryFrom<RawQueryResponse> for dtos::engine::RawQueryResponse {
         type Error = ValidationError;
-        fn try_from(v: RawQueryResponse) -> Result<Self, Self::Error> {
+        fn try_from(v: RawQueryResponse) -> Result<Self, ValidationError> {
             match v {
                 RawQueryResponse::Progress(v) => Ok(Self::Progress(v.try_into()?)),
                 RawQueryResponse::Success(v) => Ok(Self::Success(v.try_into()?)),
@@ -4149,7 +4306,7 @@ pub mod engine {
 
     impl TryFrom<TransformResponse> for dtos::engine::TransformResponse {
         type Error = ValidationError;
-        fn try_from(v: TransformResponse) -> Result<Self, Self::Error> {
+        fn try_from(v: TransformResponse) -> Result<Self, ValidationError> {
             match v {
                 TransformResponse::Progress(v) => Ok(Self::Progress(v.try_into()?)),
                 TransformResponse::Success(v) => Ok(Self::Success(v.try_into()?)),
@@ -4326,10 +4483,12 @@ pub mod event {
 
     // Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct EventFilter {
-        #[serde(flatten)]
-        #[serde(with = "map_value_limited_precision")]
-        pub entries: std::collections::BTreeMap<String, serde_json::Value>,
+    #[serde(untagged)]
+    pub enum EventFilter {
+        And(event::EventFilterAnd),
+        Or(event::EventFilterOr),
+        Not(event::EventFilterNot),
+        Match(event::EventFilterMatch),
     }
 
     impl IntoDto for EventFilter {
@@ -4341,18 +4500,178 @@ pub mod event {
 
     impl From<dtos::event::EventFilter> for EventFilter {
         fn from(v: dtos::event::EventFilter) -> Self {
-            Self { entries: v.entries }
+            match v {
+                dtos::event::EventFilter::And(v) => Self::And(v.into()),
+                dtos::event::EventFilter::Or(v) => Self::Or(v.into()),
+                dtos::event::EventFilter::Not(v) => Self::Not(v.into()),
+                dtos::event::EventFilter::Match(v) => Self::Match(v.into()),
+            }
         }
     }
 
     impl TryFrom<EventFilter> for dtos::event::EventFilter {
         type Error = ValidationError;
-        fn try_from(v: EventFilter) -> Result<Self, Self::Error> {
-            Ok(Self { entries: v.entries })
+        fn try_from(v: EventFilter) -> Result<Self, ValidationError> {
+            match v {
+                EventFilter::And(v) => Ok(Self::And(v.try_into()?)),
+                EventFilter::Or(v) => Ok(Self::Or(v.try_into()?)),
+                EventFilter::Not(v) => Ok(Self::Not(v.try_into()?)),
+                EventFilter::Match(v) => Ok(Self::Match(v.try_into()?)),
+            }
         }
     }
 
     implement_serde_as!(dtos::event::EventFilter, EventFilter);
+
+    // Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/And
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct EventFilterAnd {
+        #[serde(rename = "$and")]
+        pub and: Vec<event::EventFilter>,
+    }
+
+    impl IntoDto for EventFilterAnd {
+        type Dto = dtos::event::EventFilterAnd;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::event::EventFilterAnd> for EventFilterAnd {
+        fn from(v: dtos::event::EventFilterAnd) -> Self {
+            Self {
+                and: v.and.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<EventFilterAnd> for dtos::event::EventFilterAnd {
+        type Error = ValidationError;
+        fn try_from(v: EventFilterAnd) -> Result<Self, ValidationError> {
+            Ok(Self {
+                and: v
+                    .and
+                    .into_iter()
+                    .map(|i| dtos::event::EventFilter::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::event::EventFilterAnd, EventFilterAnd);
+
+    // Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Match
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct EventFilterMatch {
+        #[serde(flatten)]
+        pub entries: std::collections::BTreeMap<String, StructOrString<resource::FilterCondition>>,
+    }
+
+    impl IntoDto for EventFilterMatch {
+        type Dto = dtos::event::EventFilterMatch;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::event::EventFilterMatch> for EventFilterMatch {
+        fn from(v: dtos::event::EventFilterMatch) -> Self {
+            Self {
+                entries: v.entries.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<EventFilterMatch> for dtos::event::EventFilterMatch {
+        type Error = ValidationError;
+        fn try_from(v: EventFilterMatch) -> Result<Self, Self::Error> {
+            Ok(Self {
+                entries: v
+                    .entries
+                    .into_iter()
+                    .map(|(k, v)| -> Result<_, ValidationError> { Ok((k, v.try_into()?)) })
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::event::EventFilterMatch, EventFilterMatch);
+
+    // Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Not
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct EventFilterNot {
+        #[serde(rename = "$not")]
+        pub not: Box<event::EventFilter>,
+    }
+
+    impl IntoDto for EventFilterNot {
+        type Dto = dtos::event::EventFilterNot;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::event::EventFilterNot> for EventFilterNot {
+        fn from(v: dtos::event::EventFilterNot) -> Self {
+            Self {
+                not: Box::new((*v.not).into()),
+            }
+        }
+    }
+
+    impl TryFrom<EventFilterNot> for dtos::event::EventFilterNot {
+        type Error = ValidationError;
+        fn try_from(v: EventFilterNot) -> Result<Self, ValidationError> {
+            Ok(Self {
+                not: Box::new(dtos::event::EventFilter::try_from(*v.not)?),
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::event::EventFilterNot, EventFilterNot);
+
+    // Schema: https://opendatafabric.org/schemas/event/v1alpha1/EventFilter#/$defs/Or
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct EventFilterOr {
+        #[serde(rename = "$or")]
+        pub or: Vec<event::EventFilter>,
+    }
+
+    impl IntoDto for EventFilterOr {
+        type Dto = dtos::event::EventFilterOr;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::event::EventFilterOr> for EventFilterOr {
+        fn from(v: dtos::event::EventFilterOr) -> Self {
+            Self {
+                or: v.or.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<EventFilterOr> for dtos::event::EventFilterOr {
+        type Error = ValidationError;
+        fn try_from(v: EventFilterOr) -> Result<Self, ValidationError> {
+            Ok(Self {
+                or: v
+                    .or
+                    .into_iter()
+                    .map(|i| dtos::event::EventFilter::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::event::EventFilterOr, EventFilterOr);
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -4494,7 +4813,7 @@ pub mod flow {
 
     impl TryFrom<FlowTrigger> for dtos::flow::FlowTrigger {
         type Error = ValidationError;
-        fn try_from(v: FlowTrigger) -> Result<Self, Self::Error> {
+        fn try_from(v: FlowTrigger) -> Result<Self, ValidationError> {
             match v {
                 FlowTrigger::Schedule(v) => Ok(Self::Schedule(v.try_into()?)),
                 FlowTrigger::Event(v) => Ok(Self::Event(v.try_into()?)),
@@ -4624,7 +4943,7 @@ pub mod flow {
 
     impl TryFrom<FlowTriggerInput> for dtos::flow::FlowTriggerInput {
         type Error = ValidationError;
-        fn try_from(v: FlowTriggerInput) -> Result<Self, Self::Error> {
+        fn try_from(v: FlowTriggerInput) -> Result<Self, ValidationError> {
             match v {
                 FlowTriggerInput::Schedule(v) => Ok(Self::Schedule(v.try_into()?)),
                 FlowTriggerInput::Event(v) => Ok(Self::Event(v.try_into()?)),
@@ -4905,7 +5224,7 @@ pub mod flow {
 
     impl TryFrom<TaskSpec> for dtos::flow::TaskSpec {
         type Error = ValidationError;
-        fn try_from(v: TaskSpec) -> Result<Self, Self::Error> {
+        fn try_from(v: TaskSpec) -> Result<Self, ValidationError> {
             match v {
                 TaskSpec::Ingest(v) => Ok(Self::Ingest(v.try_into()?)),
                 TaskSpec::Compaction(v) => Ok(Self::Compaction(v.try_into()?)),
@@ -5066,7 +5385,7 @@ pub mod flow {
 
     impl TryFrom<TaskSpecInput> for dtos::flow::TaskSpecInput {
         type Error = ValidationError;
-        fn try_from(v: TaskSpecInput) -> Result<Self, Self::Error> {
+        fn try_from(v: TaskSpecInput) -> Result<Self, ValidationError> {
             match v {
                 TaskSpecInput::Ingest(v) => Ok(Self::Ingest(v.try_into()?)),
                 TaskSpecInput::Compaction(v) => Ok(Self::Compaction(v.try_into()?)),
@@ -5285,6 +5604,7 @@ pub mod legacy {
     #[serde(deny_unknown_fields)]
     #[serde(rename_all = "camelCase")]
     pub struct AddPushSource {
+        #[serde(alias = "source")]
         pub source_name: String,
         pub read: source::ReadStep,
         #[serde(default)]
@@ -5405,6 +5725,7 @@ pub mod legacy {
     #[serde(deny_unknown_fields)]
     #[serde(rename_all = "camelCase")]
     pub struct DisablePushSource {
+        #[serde(alias = "source")]
         pub source_name: String,
     }
 
@@ -5472,7 +5793,7 @@ pub mod legacy {
 
     impl TryFrom<FetchStep> for dtos::legacy::FetchStep {
         type Error = ValidationError;
-        fn try_from(v: FetchStep) -> Result<Self, Self::Error> {
+        fn try_from(v: FetchStep) -> Result<Self, ValidationError> {
             match v {
                 FetchStep::Url(v) => Ok(Self::Url(v.try_into()?)),
                 FetchStep::FilesGlob(v) => Ok(Self::FilesGlob(v.try_into()?)),
@@ -5679,7 +6000,7 @@ pub mod legacy {
                 host: v.host,
                 port: v.port,
                 username: v.username,
-                password: v.password,
+                password: v.password.map(dtos::Sensitive::into_inner),
                 topics: v.topics.into_iter().map(Into::into).collect(),
             }
         }
@@ -5692,7 +6013,7 @@ pub mod legacy {
                 host: v.host,
                 port: v.port,
                 username: v.username,
-                password: v.password,
+                password: v.password.map(dtos::Sensitive::new),
                 topics: v
                     .topics
                     .into_iter()
@@ -5817,6 +6138,8 @@ pub mod legacy {
         }
     }
 
+    implement_serde_as!(dtos::legacy::Manifest<ContentTDto>, Manifest<ContentT>, ContentTDto => ContentT);
+
     // Schema: https://opendatafabric.org/schemas/legacy/v0/SetPollingSource
     #[derive(Debug, Serialize, Deserialize)]
     #[serde(deny_unknown_fields)]
@@ -5879,146 +6202,1374 @@ pub mod legacy {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-// resource
+// protocol
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-pub mod resource {
+pub mod protocol {
     #[allow(unused_imports)]
     use super::*;
 
-    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetError
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct LabelFilter {
-        #[serde(flatten)]
-        #[serde(with = "map_value_limited_precision")]
-        pub entries: std::collections::BTreeMap<String, serde_json::Value>,
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetError {
+        pub error_details: protocol::ErrorDetails,
     }
 
-    impl IntoDto for LabelFilter {
-        type Dto = dtos::resource::LabelFilter;
+    impl IntoDto for DatasetError {
+        type Dto = dtos::protocol::DatasetError;
         fn into_dto(self) -> Result<Self::Dto, ValidationError> {
             self.try_into()
         }
     }
 
-    impl From<dtos::resource::LabelFilter> for LabelFilter {
-        fn from(v: dtos::resource::LabelFilter) -> Self {
-            Self { entries: v.entries }
+    impl From<dtos::protocol::DatasetError> for DatasetError {
+        fn from(v: dtos::protocol::DatasetError) -> Self {
+            Self {
+                error_details: v.error_details.into(),
+            }
         }
     }
 
-    impl TryFrom<LabelFilter> for dtos::resource::LabelFilter {
+    impl TryFrom<DatasetError> for dtos::protocol::DatasetError {
         type Error = ValidationError;
-        fn try_from(v: LabelFilter) -> Result<Self, Self::Error> {
-            Ok(Self { entries: v.entries })
+        fn try_from(v: DatasetError) -> Result<Self, ValidationError> {
+            Ok(Self {
+                error_details: dtos::protocol::ErrorDetails::try_from(v.error_details)?,
+            })
         }
     }
 
-    implement_serde_as!(dtos::resource::LabelFilter, LabelFilter);
+    implement_serde_as!(dtos::protocol::DatasetError, DatasetError);
 
-    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/Resource
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetMetadataPullResponse
     #[derive(Debug, Serialize, Deserialize)]
     #[serde(deny_unknown_fields)]
     #[serde(rename_all = "camelCase")]
-    pub struct Resource<SpecT> {
-        #[serde(rename = "$schema")]
-        pub schema: TypeUri,
-        pub headers: resource::ResourceHeaders,
-        pub spec: SpecT,
-        pub status: resource::ResourceStatus,
+    pub struct DatasetMetadataPullResponse {
+        pub blocks: protocol::ObjectsBatch,
     }
 
-    impl<SpecT> IntoDto for Resource<SpecT>
-    where
-        SpecT: IntoDto,
-        <SpecT as IntoDto>::Dto: TryFrom<SpecT>,
-        ValidationError: From<<<SpecT as IntoDto>::Dto as TryFrom<SpecT>>::Error>,
-    {
-        type Dto = dtos::resource::Resource<SpecT::Dto>;
+    impl IntoDto for DatasetMetadataPullResponse {
+        type Dto = dtos::protocol::DatasetMetadataPullResponse;
         fn into_dto(self) -> Result<Self::Dto, ValidationError> {
             self.try_into()
         }
     }
 
-    impl<SpecTFrom, SpecTTo> From<dtos::resource::Resource<SpecTFrom>> for Resource<SpecTTo>
-    where
-        SpecTTo: From<SpecTFrom>,
-    {
-        fn from(v: dtos::resource::Resource<SpecTFrom>) -> Self {
+    impl From<dtos::protocol::DatasetMetadataPullResponse> for DatasetMetadataPullResponse {
+        fn from(v: dtos::protocol::DatasetMetadataPullResponse) -> Self {
             Self {
-                schema: v.schema,
-                headers: v.headers.into(),
-                spec: v.spec.into(),
-                status: v.status.into(),
+                blocks: v.blocks.into(),
             }
         }
     }
 
-    impl<SpecTFrom, SpecTTo> TryFrom<Resource<SpecTFrom>> for dtos::resource::Resource<SpecTTo>
-    where
-        SpecTTo: TryFrom<SpecTFrom>,
-        ValidationError: From<<SpecTTo as TryFrom<SpecTFrom>>::Error>,
-    {
+    impl TryFrom<DatasetMetadataPullResponse> for dtos::protocol::DatasetMetadataPullResponse {
         type Error = ValidationError;
-        fn try_from(v: Resource<SpecTFrom>) -> Result<Self, ValidationError> {
+        fn try_from(v: DatasetMetadataPullResponse) -> Result<Self, ValidationError> {
             Ok(Self {
-                schema: v.schema,
-                headers: dtos::resource::ResourceHeaders::try_from(v.headers)?,
-                spec: SpecTTo::try_from(v.spec)?,
-                status: dtos::resource::ResourceStatus::try_from(v.status)?,
+                blocks: dtos::protocol::ObjectsBatch::try_from(v.blocks)?,
             })
         }
     }
 
-    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceAnnotations
+    implement_serde_as!(
+        dtos::protocol::DatasetMetadataPullResponse,
+        DatasetMetadataPullResponse
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullMetadataRequest
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResourceAnnotations {
-        #[serde(flatten)]
-        #[serde(with = "map_value_limited_precision")]
-        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
-    }
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPullMetadataRequest {}
 
-    impl IntoDto for ResourceAnnotations {
-        type Dto = dtos::resource::ResourceAnnotations;
+    impl IntoDto for DatasetPullMetadataRequest {
+        type Dto = dtos::protocol::DatasetPullMetadataRequest;
         fn into_dto(self) -> Result<Self::Dto, ValidationError> {
             self.try_into()
         }
     }
 
-    impl From<dtos::resource::ResourceAnnotations> for ResourceAnnotations {
-        fn from(v: dtos::resource::ResourceAnnotations) -> Self {
-            Self { entries: v.entries }
+    impl From<dtos::protocol::DatasetPullMetadataRequest> for DatasetPullMetadataRequest {
+        fn from(v: dtos::protocol::DatasetPullMetadataRequest) -> Self {
+            Self {}
         }
     }
 
-    impl TryFrom<ResourceAnnotations> for dtos::resource::ResourceAnnotations {
+    impl TryFrom<DatasetPullMetadataRequest> for dtos::protocol::DatasetPullMetadataRequest {
         type Error = ValidationError;
-        fn try_from(v: ResourceAnnotations) -> Result<Self, Self::Error> {
-            Ok(Self { entries: v.entries })
+        fn try_from(v: DatasetPullMetadataRequest) -> Result<Self, ValidationError> {
+            Ok(Self {})
         }
     }
 
-    implement_serde_as!(dtos::resource::ResourceAnnotations, ResourceAnnotations);
+    implement_serde_as!(
+        dtos::protocol::DatasetPullMetadataRequest,
+        DatasetPullMetadataRequest
+    );
 
-    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceConditions
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferRequest
     #[derive(Debug, Serialize, Deserialize)]
-    pub struct ResourceConditions {
-        #[serde(flatten)]
-        #[serde(with = "map_value_limited_precision")]
-        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPullObjectsTransferRequest {
+        pub object_files: Vec<protocol::ObjectFileReference>,
     }
 
-    impl IntoDto for ResourceConditions {
-        type Dto = dtos::resource::ResourceConditions;
+    impl IntoDto for DatasetPullObjectsTransferRequest {
+        type Dto = dtos::protocol::DatasetPullObjectsTransferRequest;
         fn into_dto(self) -> Result<Self::Dto, ValidationError> {
             self.try_into()
         }
     }
 
-    impl From<dtos::resource::ResourceConditions> for ResourceConditions {
-        fn from(v: dtos::resource::ResourceConditions) -> Self {
-            Self { entries: v.entries }
-        }
+    impl From<dtos::protocol::DatasetPullObjectsTransferRequest> for DatasetPullObjectsTransferRequest {
+        fn from(v: dtos::protocol::DatasetPullObjectsTransferRequest) -> Self {
+            Self {
+                object_files: v.object_files.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPullObjectsTransferRequest>
+        for dtos::protocol::DatasetPullObjectsTransferRequest
+    {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPullObjectsTransferRequest) -> Result<Self, ValidationError> {
+            Ok(Self {
+                object_files: v
+                    .object_files
+                    .into_iter()
+                    .map(|i| dtos::protocol::ObjectFileReference::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::DatasetPullObjectsTransferRequest,
+        DatasetPullObjectsTransferRequest
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullObjectsTransferResponse
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPullObjectsTransferResponse {
+        pub object_transfer_strategies: Vec<protocol::PullObjectTransferStrategy>,
+    }
+
+    impl IntoDto for DatasetPullObjectsTransferResponse {
+        type Dto = dtos::protocol::DatasetPullObjectsTransferResponse;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPullObjectsTransferResponse>
+        for DatasetPullObjectsTransferResponse
+    {
+        fn from(v: dtos::protocol::DatasetPullObjectsTransferResponse) -> Self {
+            Self {
+                object_transfer_strategies: v
+                    .object_transfer_strategies
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPullObjectsTransferResponse>
+        for dtos::protocol::DatasetPullObjectsTransferResponse
+    {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPullObjectsTransferResponse) -> Result<Self, ValidationError> {
+            Ok(Self {
+                object_transfer_strategies: v
+                    .object_transfer_strategies
+                    .into_iter()
+                    .map(|i| dtos::protocol::PullObjectTransferStrategy::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::DatasetPullObjectsTransferResponse,
+        DatasetPullObjectsTransferResponse
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullRequest
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPullRequest {
+        pub dataset_id: DatasetID,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub begin_after: Option<Multihash>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub stop_at: Option<Multihash>,
+    }
+
+    impl IntoDto for DatasetPullRequest {
+        type Dto = dtos::protocol::DatasetPullRequest;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPullRequest> for DatasetPullRequest {
+        fn from(v: dtos::protocol::DatasetPullRequest) -> Self {
+            Self {
+                dataset_id: v.dataset_id,
+                begin_after: v.begin_after,
+                stop_at: v.stop_at,
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPullRequest> for dtos::protocol::DatasetPullRequest {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPullRequest) -> Result<Self, ValidationError> {
+            Ok(Self {
+                dataset_id: v.dataset_id,
+                begin_after: v.begin_after,
+                stop_at: v.stop_at,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::DatasetPullRequest, DatasetPullRequest);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPullResponse
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPullResponse {
+        pub size_estimation: protocol::TransferSizeEstimation,
+    }
+
+    impl IntoDto for DatasetPullResponse {
+        type Dto = dtos::protocol::DatasetPullResponse;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPullResponse> for DatasetPullResponse {
+        fn from(v: dtos::protocol::DatasetPullResponse) -> Self {
+            Self {
+                size_estimation: v.size_estimation.into(),
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPullResponse> for dtos::protocol::DatasetPullResponse {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPullResponse) -> Result<Self, ValidationError> {
+            Ok(Self {
+                size_estimation: dtos::protocol::TransferSizeEstimation::try_from(
+                    v.size_estimation,
+                )?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::DatasetPullResponse, DatasetPullResponse);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushComplete
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushComplete {}
+
+    impl IntoDto for DatasetPushComplete {
+        type Dto = dtos::protocol::DatasetPushComplete;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushComplete> for DatasetPushComplete {
+        fn from(v: dtos::protocol::DatasetPushComplete) -> Self {
+            Self {}
+        }
+    }
+
+    impl TryFrom<DatasetPushComplete> for dtos::protocol::DatasetPushComplete {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushComplete) -> Result<Self, ValidationError> {
+            Ok(Self {})
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::DatasetPushComplete, DatasetPushComplete);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushCompleteConfirmed
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushCompleteConfirmed {}
+
+    impl IntoDto for DatasetPushCompleteConfirmed {
+        type Dto = dtos::protocol::DatasetPushCompleteConfirmed;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushCompleteConfirmed> for DatasetPushCompleteConfirmed {
+        fn from(v: dtos::protocol::DatasetPushCompleteConfirmed) -> Self {
+            Self {}
+        }
+    }
+
+    impl TryFrom<DatasetPushCompleteConfirmed> for dtos::protocol::DatasetPushCompleteConfirmed {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushCompleteConfirmed) -> Result<Self, ValidationError> {
+            Ok(Self {})
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::DatasetPushCompleteConfirmed,
+        DatasetPushCompleteConfirmed
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadata
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushMetadata {
+        pub new_blocks: protocol::ObjectsBatch,
+    }
+
+    impl IntoDto for DatasetPushMetadata {
+        type Dto = dtos::protocol::DatasetPushMetadata;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushMetadata> for DatasetPushMetadata {
+        fn from(v: dtos::protocol::DatasetPushMetadata) -> Self {
+            Self {
+                new_blocks: v.new_blocks.into(),
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPushMetadata> for dtos::protocol::DatasetPushMetadata {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushMetadata) -> Result<Self, ValidationError> {
+            Ok(Self {
+                new_blocks: dtos::protocol::ObjectsBatch::try_from(v.new_blocks)?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::DatasetPushMetadata, DatasetPushMetadata);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushMetadataAccepted
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushMetadataAccepted {}
+
+    impl IntoDto for DatasetPushMetadataAccepted {
+        type Dto = dtos::protocol::DatasetPushMetadataAccepted;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushMetadataAccepted> for DatasetPushMetadataAccepted {
+        fn from(v: dtos::protocol::DatasetPushMetadataAccepted) -> Self {
+            Self {}
+        }
+    }
+
+    impl TryFrom<DatasetPushMetadataAccepted> for dtos::protocol::DatasetPushMetadataAccepted {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushMetadataAccepted) -> Result<Self, ValidationError> {
+            Ok(Self {})
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::DatasetPushMetadataAccepted,
+        DatasetPushMetadataAccepted
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferRequest
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushObjectsTransferRequest {
+        pub object_files: Vec<protocol::ObjectFileReference>,
+    }
+
+    impl IntoDto for DatasetPushObjectsTransferRequest {
+        type Dto = dtos::protocol::DatasetPushObjectsTransferRequest;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushObjectsTransferRequest> for DatasetPushObjectsTransferRequest {
+        fn from(v: dtos::protocol::DatasetPushObjectsTransferRequest) -> Self {
+            Self {
+                object_files: v.object_files.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPushObjectsTransferRequest>
+        for dtos::protocol::DatasetPushObjectsTransferRequest
+    {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushObjectsTransferRequest) -> Result<Self, ValidationError> {
+            Ok(Self {
+                object_files: v
+                    .object_files
+                    .into_iter()
+                    .map(|i| dtos::protocol::ObjectFileReference::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::DatasetPushObjectsTransferRequest,
+        DatasetPushObjectsTransferRequest
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushObjectsTransferResponse
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushObjectsTransferResponse {
+        pub object_transfer_strategies: Vec<protocol::PushObjectTransferStrategy>,
+    }
+
+    impl IntoDto for DatasetPushObjectsTransferResponse {
+        type Dto = dtos::protocol::DatasetPushObjectsTransferResponse;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushObjectsTransferResponse>
+        for DatasetPushObjectsTransferResponse
+    {
+        fn from(v: dtos::protocol::DatasetPushObjectsTransferResponse) -> Self {
+            Self {
+                object_transfer_strategies: v
+                    .object_transfer_strategies
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPushObjectsTransferResponse>
+        for dtos::protocol::DatasetPushObjectsTransferResponse
+    {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushObjectsTransferResponse) -> Result<Self, ValidationError> {
+            Ok(Self {
+                object_transfer_strategies: v
+                    .object_transfer_strategies
+                    .into_iter()
+                    .map(|i| dtos::protocol::PushObjectTransferStrategy::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::DatasetPushObjectsTransferResponse,
+        DatasetPushObjectsTransferResponse
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequest
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushRequest {
+        pub dataset_id: DatasetID,
+        pub current_head: Multihash,
+        pub size_estimation: protocol::TransferSizeEstimation,
+    }
+
+    impl IntoDto for DatasetPushRequest {
+        type Dto = dtos::protocol::DatasetPushRequest;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushRequest> for DatasetPushRequest {
+        fn from(v: dtos::protocol::DatasetPushRequest) -> Self {
+            Self {
+                dataset_id: v.dataset_id,
+                current_head: v.current_head,
+                size_estimation: v.size_estimation.into(),
+            }
+        }
+    }
+
+    impl TryFrom<DatasetPushRequest> for dtos::protocol::DatasetPushRequest {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushRequest) -> Result<Self, ValidationError> {
+            Ok(Self {
+                dataset_id: v.dataset_id,
+                current_head: v.current_head,
+                size_estimation: dtos::protocol::TransferSizeEstimation::try_from(
+                    v.size_estimation,
+                )?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::DatasetPushRequest, DatasetPushRequest);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/DatasetPushRequestAccepted
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct DatasetPushRequestAccepted {}
+
+    impl IntoDto for DatasetPushRequestAccepted {
+        type Dto = dtos::protocol::DatasetPushRequestAccepted;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::DatasetPushRequestAccepted> for DatasetPushRequestAccepted {
+        fn from(v: dtos::protocol::DatasetPushRequestAccepted) -> Self {
+            Self {}
+        }
+    }
+
+    impl TryFrom<DatasetPushRequestAccepted> for dtos::protocol::DatasetPushRequestAccepted {
+        type Error = ValidationError;
+        fn try_from(v: DatasetPushRequestAccepted) -> Result<Self, ValidationError> {
+            Ok(Self {})
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::DatasetPushRequestAccepted,
+        DatasetPushRequestAccepted
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ErrorDetails
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ErrorDetails {
+        pub error_code: String,
+        pub description: String,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub extras: Option<serde_json::Value>,
+    }
+
+    impl IntoDto for ErrorDetails {
+        type Dto = dtos::protocol::ErrorDetails;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::ErrorDetails> for ErrorDetails {
+        fn from(v: dtos::protocol::ErrorDetails) -> Self {
+            Self {
+                error_code: v.error_code,
+                description: v.description,
+                extras: v.extras,
+            }
+        }
+    }
+
+    impl TryFrom<ErrorDetails> for dtos::protocol::ErrorDetails {
+        type Error = ValidationError;
+        fn try_from(v: ErrorDetails) -> Result<Self, ValidationError> {
+            Ok(Self {
+                error_code: v.error_code,
+                description: v.description,
+                extras: v.extras,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::ErrorDetails, ErrorDetails);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectFileReference
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ObjectFileReference {
+        pub object_type: protocol::ObjectType,
+        pub physical_hash: Multihash,
+    }
+
+    impl IntoDto for ObjectFileReference {
+        type Dto = dtos::protocol::ObjectFileReference;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::ObjectFileReference> for ObjectFileReference {
+        fn from(v: dtos::protocol::ObjectFileReference) -> Self {
+            Self {
+                object_type: v.object_type.into(),
+                physical_hash: v.physical_hash,
+            }
+        }
+    }
+
+    impl TryFrom<ObjectFileReference> for dtos::protocol::ObjectFileReference {
+        type Error = ValidationError;
+        fn try_from(v: ObjectFileReference) -> Result<Self, ValidationError> {
+            Ok(Self {
+                object_type: dtos::protocol::ObjectType::try_from(v.object_type)?,
+                physical_hash: v.physical_hash,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::ObjectFileReference, ObjectFileReference);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPullStrategy
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub enum ObjectPullStrategy {
+        #[serde(alias = "httpDownload", alias = "httpdownload")]
+        HttpDownload,
+    }
+
+    impl IntoDto for ObjectPullStrategy {
+        type Dto = dtos::protocol::ObjectPullStrategy;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::ObjectPullStrategy> for ObjectPullStrategy {
+        fn from(v: dtos::protocol::ObjectPullStrategy) -> Self {
+            match v {
+                dtos::protocol::ObjectPullStrategy::HttpDownload => Self::HttpDownload,
+            }
+        }
+    }
+
+    impl TryFrom<ObjectPullStrategy> for dtos::protocol::ObjectPullStrategy {
+        type Error = ValidationError;
+        fn try_from(v: ObjectPullStrategy) -> Result<Self, ValidationError> {
+            match v {
+                ObjectPullStrategy::HttpDownload => Ok(Self::HttpDownload),
+            }
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::ObjectPullStrategy, ObjectPullStrategy);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectPushStrategy
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub enum ObjectPushStrategy {
+        #[serde(alias = "skipUpload", alias = "skipupload")]
+        SkipUpload,
+        #[serde(alias = "httpUpload", alias = "httpupload")]
+        HttpUpload,
+    }
+
+    impl IntoDto for ObjectPushStrategy {
+        type Dto = dtos::protocol::ObjectPushStrategy;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::ObjectPushStrategy> for ObjectPushStrategy {
+        fn from(v: dtos::protocol::ObjectPushStrategy) -> Self {
+            match v {
+                dtos::protocol::ObjectPushStrategy::SkipUpload => Self::SkipUpload,
+                dtos::protocol::ObjectPushStrategy::HttpUpload => Self::HttpUpload,
+            }
+        }
+    }
+
+    impl TryFrom<ObjectPushStrategy> for dtos::protocol::ObjectPushStrategy {
+        type Error = ValidationError;
+        fn try_from(v: ObjectPushStrategy) -> Result<Self, ValidationError> {
+            match v {
+                ObjectPushStrategy::SkipUpload => Ok(Self::SkipUpload),
+                ObjectPushStrategy::HttpUpload => Ok(Self::HttpUpload),
+            }
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::ObjectPushStrategy, ObjectPushStrategy);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectType
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    pub enum ObjectType {
+        #[serde(alias = "metadataBlock", alias = "metadatablock")]
+        MetadataBlock,
+        #[serde(alias = "dataSlice", alias = "dataslice")]
+        DataSlice,
+        #[serde(alias = "checkpoint")]
+        Checkpoint,
+    }
+
+    impl IntoDto for ObjectType {
+        type Dto = dtos::protocol::ObjectType;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::ObjectType> for ObjectType {
+        fn from(v: dtos::protocol::ObjectType) -> Self {
+            match v {
+                dtos::protocol::ObjectType::MetadataBlock => Self::MetadataBlock,
+                dtos::protocol::ObjectType::DataSlice => Self::DataSlice,
+                dtos::protocol::ObjectType::Checkpoint => Self::Checkpoint,
+            }
+        }
+    }
+
+    impl TryFrom<ObjectType> for dtos::protocol::ObjectType {
+        type Error = ValidationError;
+        fn try_from(v: ObjectType) -> Result<Self, ValidationError> {
+            match v {
+                ObjectType::MetadataBlock => Ok(Self::MetadataBlock),
+                ObjectType::DataSlice => Ok(Self::DataSlice),
+                ObjectType::Checkpoint => Ok(Self::Checkpoint),
+            }
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::ObjectType, ObjectType);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/ObjectsBatch
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct ObjectsBatch {
+        pub objects_count: u64,
+        pub object_type: protocol::ObjectType,
+        pub media_type: String,
+        pub encoding: String,
+        pub payload: String,
+    }
+
+    impl IntoDto for ObjectsBatch {
+        type Dto = dtos::protocol::ObjectsBatch;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::ObjectsBatch> for ObjectsBatch {
+        fn from(v: dtos::protocol::ObjectsBatch) -> Self {
+            Self {
+                objects_count: v.objects_count,
+                object_type: v.object_type.into(),
+                media_type: v.media_type,
+                encoding: v.encoding,
+                payload: v.payload,
+            }
+        }
+    }
+
+    impl TryFrom<ObjectsBatch> for dtos::protocol::ObjectsBatch {
+        type Error = ValidationError;
+        fn try_from(v: ObjectsBatch) -> Result<Self, ValidationError> {
+            Ok(Self {
+                objects_count: v.objects_count,
+                object_type: dtos::protocol::ObjectType::try_from(v.object_type)?,
+                media_type: v.media_type,
+                encoding: v.encoding,
+                payload: v.payload,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::ObjectsBatch, ObjectsBatch);
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PullObjectTransferStrategy
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PullObjectTransferStrategy {
+        pub object_file: protocol::ObjectFileReference,
+        pub pull_strategy: protocol::ObjectPullStrategy,
+        pub download_from: protocol::TransferUrl,
+    }
+
+    impl IntoDto for PullObjectTransferStrategy {
+        type Dto = dtos::protocol::PullObjectTransferStrategy;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::PullObjectTransferStrategy> for PullObjectTransferStrategy {
+        fn from(v: dtos::protocol::PullObjectTransferStrategy) -> Self {
+            Self {
+                object_file: v.object_file.into(),
+                pull_strategy: v.pull_strategy.into(),
+                download_from: v.download_from.into(),
+            }
+        }
+    }
+
+    impl TryFrom<PullObjectTransferStrategy> for dtos::protocol::PullObjectTransferStrategy {
+        type Error = ValidationError;
+        fn try_from(v: PullObjectTransferStrategy) -> Result<Self, ValidationError> {
+            Ok(Self {
+                object_file: dtos::protocol::ObjectFileReference::try_from(v.object_file)?,
+                pull_strategy: dtos::protocol::ObjectPullStrategy::try_from(v.pull_strategy)?,
+                download_from: dtos::protocol::TransferUrl::try_from(v.download_from)?,
+            })
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::PullObjectTransferStrategy,
+        PullObjectTransferStrategy
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/PushObjectTransferStrategy
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct PushObjectTransferStrategy {
+        pub object_file: protocol::ObjectFileReference,
+        pub push_strategy: protocol::ObjectPushStrategy,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub upload_to: Option<protocol::TransferUrl>,
+    }
+
+    impl IntoDto for PushObjectTransferStrategy {
+        type Dto = dtos::protocol::PushObjectTransferStrategy;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::PushObjectTransferStrategy> for PushObjectTransferStrategy {
+        fn from(v: dtos::protocol::PushObjectTransferStrategy) -> Self {
+            Self {
+                object_file: v.object_file.into(),
+                push_strategy: v.push_strategy.into(),
+                upload_to: v.upload_to.map(|v| v.into()),
+            }
+        }
+    }
+
+    impl TryFrom<PushObjectTransferStrategy> for dtos::protocol::PushObjectTransferStrategy {
+        type Error = ValidationError;
+        fn try_from(v: PushObjectTransferStrategy) -> Result<Self, ValidationError> {
+            Ok(Self {
+                object_file: dtos::protocol::ObjectFileReference::try_from(v.object_file)?,
+                push_strategy: dtos::protocol::ObjectPushStrategy::try_from(v.push_strategy)?,
+                upload_to: v
+                    .upload_to
+                    .map(|v| dtos::protocol::TransferUrl::try_from(v))
+                    .transpose()?,
+            })
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::PushObjectTransferStrategy,
+        PushObjectTransferStrategy
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferSizeEstimation
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TransferSizeEstimation {
+        pub num_blocks: u64,
+        pub num_objects: u64,
+        pub bytes_in_raw_blocks: u64,
+        pub bytes_in_raw_objects: u64,
+    }
+
+    impl IntoDto for TransferSizeEstimation {
+        type Dto = dtos::protocol::TransferSizeEstimation;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::TransferSizeEstimation> for TransferSizeEstimation {
+        fn from(v: dtos::protocol::TransferSizeEstimation) -> Self {
+            Self {
+                num_blocks: v.num_blocks,
+                num_objects: v.num_objects,
+                bytes_in_raw_blocks: v.bytes_in_raw_blocks,
+                bytes_in_raw_objects: v.bytes_in_raw_objects,
+            }
+        }
+    }
+
+    impl TryFrom<TransferSizeEstimation> for dtos::protocol::TransferSizeEstimation {
+        type Error = ValidationError;
+        fn try_from(v: TransferSizeEstimation) -> Result<Self, ValidationError> {
+            Ok(Self {
+                num_blocks: v.num_blocks,
+                num_objects: v.num_objects,
+                bytes_in_raw_blocks: v.bytes_in_raw_blocks,
+                bytes_in_raw_objects: v.bytes_in_raw_objects,
+            })
+        }
+    }
+
+    implement_serde_as!(
+        dtos::protocol::TransferSizeEstimation,
+        TransferSizeEstimation
+    );
+
+    // Schema: https://opendatafabric.org/schemas/protocol/v1alpha1/TransferUrl
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TransferUrl {
+        pub url: String,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        #[serde(with = "datetime_rfc3339_opt")]
+        pub expires_at: Option<DateTime<Utc>>,
+    }
+
+    impl IntoDto for TransferUrl {
+        type Dto = dtos::protocol::TransferUrl;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::protocol::TransferUrl> for TransferUrl {
+        fn from(v: dtos::protocol::TransferUrl) -> Self {
+            Self {
+                url: v.url,
+                expires_at: v.expires_at,
+            }
+        }
+    }
+
+    impl TryFrom<TransferUrl> for dtos::protocol::TransferUrl {
+        type Error = ValidationError;
+        fn try_from(v: TransferUrl) -> Result<Self, ValidationError> {
+            Ok(Self {
+                url: v.url,
+                expires_at: v.expires_at,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::protocol::TransferUrl, TransferUrl);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// resource
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod resource {
+    #[allow(unused_imports)]
+    use super::*;
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/FilterCondition
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct FilterCondition {
+        #[serde(rename = "$eq")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub eq: Option<serde_json::Value>,
+        #[serde(rename = "$in")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub r#in: Option<Vec<serde_json::Value>>,
+        #[serde(rename = "$exists")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub exists: Option<bool>,
+        #[serde(rename = "$glob")]
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub glob: Option<String>,
+    }
+
+    impl IntoDto for FilterCondition {
+        type Dto = dtos::resource::FilterCondition;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl std::str::FromStr for FilterCondition {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let v = dtos::resource::FilterCondition::try_from(s).map_err(|e| e.to_string())?;
+            Ok(v.into())
+        }
+    }
+
+    impl From<dtos::resource::FilterCondition> for StructOrString<FilterCondition> {
+        fn from(v: dtos::resource::FilterCondition) -> Self {
+            Self(v.into())
+        }
+    }
+    impl TryFrom<StructOrString<FilterCondition>> for dtos::resource::FilterCondition {
+        type Error = ValidationError;
+        fn try_from(v: StructOrString<FilterCondition>) -> Result<Self, ValidationError> {
+            v.0.try_into()
+        }
+    }
+
+    impl From<dtos::resource::FilterCondition> for FilterCondition {
+        fn from(v: dtos::resource::FilterCondition) -> Self {
+            Self {
+                eq: v.eq,
+                r#in: v.r#in,
+                exists: v.exists,
+                glob: v.glob,
+            }
+        }
+    }
+
+    impl TryFrom<FilterCondition> for dtos::resource::FilterCondition {
+        type Error = ValidationError;
+        fn try_from(v: FilterCondition) -> Result<Self, ValidationError> {
+            Ok(Self {
+                eq: v.eq,
+                r#in: v.r#in,
+                exists: v.exists,
+                glob: v.glob,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::resource::FilterCondition, FilterCondition);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(untagged)]
+    pub enum LabelFilter {
+        And(resource::LabelFilterAnd),
+        Or(resource::LabelFilterOr),
+        Not(resource::LabelFilterNot),
+        Match(resource::LabelFilterMatch),
+    }
+
+    impl IntoDto for LabelFilter {
+        type Dto = dtos::resource::LabelFilter;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::resource::LabelFilter> for LabelFilter {
+        fn from(v: dtos::resource::LabelFilter) -> Self {
+            match v {
+                dtos::resource::LabelFilter::And(v) => Self::And(v.into()),
+                dtos::resource::LabelFilter::Or(v) => Self::Or(v.into()),
+                dtos::resource::LabelFilter::Not(v) => Self::Not(v.into()),
+                dtos::resource::LabelFilter::Match(v) => Self::Match(v.into()),
+            }
+        }
+    }
+
+    impl TryFrom<LabelFilter> for dtos::resource::LabelFilter {
+        type Error = ValidationError;
+        fn try_from(v: LabelFilter) -> Result<Self, ValidationError> {
+            match v {
+                LabelFilter::And(v) => Ok(Self::And(v.try_into()?)),
+                LabelFilter::Or(v) => Ok(Self::Or(v.try_into()?)),
+                LabelFilter::Not(v) => Ok(Self::Not(v.try_into()?)),
+                LabelFilter::Match(v) => Ok(Self::Match(v.try_into()?)),
+            }
+        }
+    }
+
+    implement_serde_as!(dtos::resource::LabelFilter, LabelFilter);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/And
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LabelFilterAnd {
+        #[serde(rename = "$and")]
+        pub and: Vec<resource::LabelFilter>,
+    }
+
+    impl IntoDto for LabelFilterAnd {
+        type Dto = dtos::resource::LabelFilterAnd;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::resource::LabelFilterAnd> for LabelFilterAnd {
+        fn from(v: dtos::resource::LabelFilterAnd) -> Self {
+            Self {
+                and: v.and.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<LabelFilterAnd> for dtos::resource::LabelFilterAnd {
+        type Error = ValidationError;
+        fn try_from(v: LabelFilterAnd) -> Result<Self, ValidationError> {
+            Ok(Self {
+                and: v
+                    .and
+                    .into_iter()
+                    .map(|i| dtos::resource::LabelFilter::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::resource::LabelFilterAnd, LabelFilterAnd);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Match
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct LabelFilterMatch {
+        #[serde(flatten)]
+        pub entries: std::collections::BTreeMap<String, StructOrString<resource::FilterCondition>>,
+    }
+
+    impl IntoDto for LabelFilterMatch {
+        type Dto = dtos::resource::LabelFilterMatch;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::resource::LabelFilterMatch> for LabelFilterMatch {
+        fn from(v: dtos::resource::LabelFilterMatch) -> Self {
+            Self {
+                entries: v.entries.into_iter().map(|(k, v)| (k, v.into())).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<LabelFilterMatch> for dtos::resource::LabelFilterMatch {
+        type Error = ValidationError;
+        fn try_from(v: LabelFilterMatch) -> Result<Self, Self::Error> {
+            Ok(Self {
+                entries: v
+                    .entries
+                    .into_iter()
+                    .map(|(k, v)| -> Result<_, ValidationError> { Ok((k, v.try_into()?)) })
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::resource::LabelFilterMatch, LabelFilterMatch);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Not
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LabelFilterNot {
+        #[serde(rename = "$not")]
+        pub not: Box<resource::LabelFilter>,
+    }
+
+    impl IntoDto for LabelFilterNot {
+        type Dto = dtos::resource::LabelFilterNot;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::resource::LabelFilterNot> for LabelFilterNot {
+        fn from(v: dtos::resource::LabelFilterNot) -> Self {
+            Self {
+                not: Box::new((*v.not).into()),
+            }
+        }
+    }
+
+    impl TryFrom<LabelFilterNot> for dtos::resource::LabelFilterNot {
+        type Error = ValidationError;
+        fn try_from(v: LabelFilterNot) -> Result<Self, ValidationError> {
+            Ok(Self {
+                not: Box::new(dtos::resource::LabelFilter::try_from(*v.not)?),
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::resource::LabelFilterNot, LabelFilterNot);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter#/$defs/Or
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct LabelFilterOr {
+        #[serde(rename = "$or")]
+        pub or: Vec<resource::LabelFilter>,
+    }
+
+    impl IntoDto for LabelFilterOr {
+        type Dto = dtos::resource::LabelFilterOr;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::resource::LabelFilterOr> for LabelFilterOr {
+        fn from(v: dtos::resource::LabelFilterOr) -> Self {
+            Self {
+                or: v.or.into_iter().map(Into::into).collect(),
+            }
+        }
+    }
+
+    impl TryFrom<LabelFilterOr> for dtos::resource::LabelFilterOr {
+        type Error = ValidationError;
+        fn try_from(v: LabelFilterOr) -> Result<Self, ValidationError> {
+            Ok(Self {
+                or: v
+                    .or
+                    .into_iter()
+                    .map(|i| dtos::resource::LabelFilter::try_from(i))
+                    .collect::<Result<_, _>>()?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::resource::LabelFilterOr, LabelFilterOr);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/Resource
+    #[derive(Debug, Serialize, Deserialize)]
+    #[serde(deny_unknown_fields)]
+    #[serde(rename_all = "camelCase")]
+    pub struct Resource<SpecT> {
+        #[serde(rename = "$schema")]
+        pub schema: TypeUri,
+        pub headers: resource::ResourceHeaders,
+        pub spec: SpecT,
+        pub status: resource::ResourceStatus,
+    }
+
+    impl<SpecT> IntoDto for Resource<SpecT>
+    where
+        SpecT: IntoDto,
+        <SpecT as IntoDto>::Dto: TryFrom<SpecT>,
+        ValidationError: From<<<SpecT as IntoDto>::Dto as TryFrom<SpecT>>::Error>,
+    {
+        type Dto = dtos::resource::Resource<SpecT::Dto>;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl<SpecTFrom, SpecTTo> From<dtos::resource::Resource<SpecTFrom>> for Resource<SpecTTo>
+    where
+        SpecTTo: From<SpecTFrom>,
+    {
+        fn from(v: dtos::resource::Resource<SpecTFrom>) -> Self {
+            Self {
+                schema: v.schema,
+                headers: v.headers.into(),
+                spec: v.spec.into(),
+                status: v.status.into(),
+            }
+        }
+    }
+
+    impl<SpecTFrom, SpecTTo> TryFrom<Resource<SpecTFrom>> for dtos::resource::Resource<SpecTTo>
+    where
+        SpecTTo: TryFrom<SpecTFrom>,
+        ValidationError: From<<SpecTTo as TryFrom<SpecTFrom>>::Error>,
+    {
+        type Error = ValidationError;
+        fn try_from(v: Resource<SpecTFrom>) -> Result<Self, ValidationError> {
+            Ok(Self {
+                schema: v.schema,
+                headers: dtos::resource::ResourceHeaders::try_from(v.headers)?,
+                spec: SpecTTo::try_from(v.spec)?,
+                status: dtos::resource::ResourceStatus::try_from(v.status)?,
+            })
+        }
+    }
+
+    implement_serde_as!(dtos::resource::Resource<SpecTDto>, Resource<SpecT>, SpecTDto => SpecT);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceAnnotations
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResourceAnnotations {
+        #[serde(flatten)]
+        #[serde(with = "map_value_limited_precision")]
+        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    }
+
+    impl IntoDto for ResourceAnnotations {
+        type Dto = dtos::resource::ResourceAnnotations;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::resource::ResourceAnnotations> for ResourceAnnotations {
+        fn from(v: dtos::resource::ResourceAnnotations) -> Self {
+            Self { entries: v.entries }
+        }
+    }
+
+    impl TryFrom<ResourceAnnotations> for dtos::resource::ResourceAnnotations {
+        type Error = ValidationError;
+        fn try_from(v: ResourceAnnotations) -> Result<Self, Self::Error> {
+            Ok(Self { entries: v.entries })
+        }
+    }
+
+    implement_serde_as!(dtos::resource::ResourceAnnotations, ResourceAnnotations);
+
+    // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceConditions
+    #[derive(Debug, Serialize, Deserialize)]
+    pub struct ResourceConditions {
+        #[serde(flatten)]
+        #[serde(with = "map_value_limited_precision")]
+        pub entries: std::collections::BTreeMap<TypeRef, serde_json::Value>,
+    }
+
+    impl IntoDto for ResourceConditions {
+        type Dto = dtos::resource::ResourceConditions;
+        fn into_dto(self) -> Result<Self::Dto, ValidationError> {
+            self.try_into()
+        }
+    }
+
+    impl From<dtos::resource::ResourceConditions> for ResourceConditions {
+        fn from(v: dtos::resource::ResourceConditions) -> Self {
+            Self { entries: v.entries }
+        }
     }
 
     impl TryFrom<ResourceConditions> for dtos::resource::ResourceConditions {
@@ -6256,6 +7807,8 @@ pub mod resource {
         }
     }
 
+    implement_serde_as!(dtos::resource::ResourceInput<SpecTDto>, ResourceInput<SpecT>, SpecTDto => SpecT);
+
     // Schema: https://opendatafabric.org/schemas/resource/v1alpha1/ResourceLabels
     #[derive(Debug, Serialize, Deserialize)]
     pub struct ResourceLabels {
@@ -6320,7 +7873,7 @@ pub mod resource {
 
     impl TryFrom<ResourcePhase> for dtos::resource::ResourcePhase {
         type Error = ValidationError;
-        fn try_from(v: ResourcePhase) -> Result<Self, Self::Error> {
+        fn try_from(v: ResourcePhase) -> Result<Self, ValidationError> {
             match v {
                 ResourcePhase::Pending => Ok(Self::Pending),
                 ResourcePhase::Reconciling => Ok(Self::Reconciling),
@@ -6607,6 +8160,14 @@ pub mod sink {
     #[serde(rename_all = "camelCase")]
     pub struct WebhookTargetStatus {
         pub value: sink::WebhookTargetStatusValue,
+        #[serde(with = "datetime_rfc3339")]
+        pub last_transition_time: DateTime<Utc>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub reason: Option<String>,
+        #[serde(default)]
+        #[serde(skip_serializing_if = "Option::is_none")]
+        pub message: Option<String>,
     }
 
     impl IntoDto for WebhookTargetStatus {
@@ -6620,6 +8181,9 @@ pub mod sink {
         fn from(v: dtos::sink::WebhookTargetStatus) -> Self {
             Self {
                 value: v.value.into(),
+                last_transition_time: v.last_transition_time,
+                reason: v.reason,
+                message: v.message,
             }
         }
     }
@@ -6629,6 +8193,9 @@ pub mod sink {
         fn try_from(v: WebhookTargetStatus) -> Result<Self, ValidationError> {
             Ok(Self {
                 value: dtos::sink::WebhookTargetStatusValue::try_from(v.value)?,
+                last_transition_time: v.last_transition_time,
+                reason: v.reason,
+                message: v.message,
             })
         }
     }
@@ -6663,7 +8230,7 @@ pub mod sink {
 
     impl TryFrom<WebhookTargetStatusValue> for dtos::sink::WebhookTargetStatusValue {
         type Error = ValidationError;
-        fn try_from(v: WebhookTargetStatusValue) -> Result<Self, Self::Error> {
+        fn try_from(v: WebhookTargetStatusValue) -> Result<Self, ValidationError> {
             match v {
                 WebhookTargetStatusValue::Ready => Ok(Self::Ready),
                 WebhookTargetStatusValue::Failed => Ok(Self::Failed),
@@ -6713,7 +8280,7 @@ pub mod source {
 
     impl TryFrom<CompressionFormat> for dtos::source::CompressionFormat {
         type Error = ValidationError;
-        fn try_from(v: CompressionFormat) -> Result<Self, Self::Error> {
+        fn try_from(v: CompressionFormat) -> Result<Self, ValidationError> {
             match v {
                 CompressionFormat::Gzip => Ok(Self::Gzip),
                 CompressionFormat::Zip => Ok(Self::Zip),
@@ -6806,7 +8373,7 @@ pub mod source {
 
     impl TryFrom<EventTimeSource> for dtos::source::EventTimeSource {
         type Error = ValidationError;
-        fn try_from(v: EventTimeSource) -> Result<Self, Self::Error> {
+        fn try_from(v: EventTimeSource) -> Result<Self, ValidationError> {
             match v {
                 EventTimeSource::FromMetadata(v) => Ok(Self::FromMetadata(v.try_into()?)),
                 EventTimeSource::FromPath(v) => Ok(Self::FromPath(v.try_into()?)),
@@ -6998,7 +8565,7 @@ pub mod source {
 
     impl TryFrom<Ingress> for dtos::source::Ingress {
         type Error = ValidationError;
-        fn try_from(v: Ingress) -> Result<Self, Self::Error> {
+        fn try_from(v: Ingress) -> Result<Self, ValidationError> {
             match v {
                 Ingress::Url(v) => Ok(Self::Url(v.try_into()?)),
                 Ingress::FilesGlob(v) => Ok(Self::FilesGlob(v.try_into()?)),
@@ -7038,7 +8605,7 @@ pub mod source {
 
     impl TryFrom<IngressBuffer> for dtos::source::IngressBuffer {
         type Error = ValidationError;
-        fn try_from(v: IngressBuffer) -> Result<Self, Self::Error> {
+        fn try_from(v: IngressBuffer) -> Result<Self, ValidationError> {
             match v {
                 IngressBuffer::Memory(v) => Ok(Self::Memory(v.try_into()?)),
             }
@@ -7282,7 +8849,7 @@ pub mod source {
                 host: v.host,
                 port: v.port,
                 username: v.username,
-                password: v.password,
+                password: v.password.map(dtos::Sensitive::into_inner),
                 topics: v.topics.into_iter().map(Into::into).collect(),
             }
         }
@@ -7295,7 +8862,7 @@ pub mod source {
                 host: v.host,
                 port: v.port,
                 username: v.username,
-                password: v.password,
+                password: v.password.map(dtos::Sensitive::new),
                 topics: v
                     .topics
                     .into_iter()
@@ -7446,7 +9013,7 @@ pub mod source {
 
     impl TryFrom<MergeStrategy> for dtos::source::MergeStrategy {
         type Error = ValidationError;
-        fn try_from(v: MergeStrategy) -> Result<Self, Self::Error> {
+        fn try_from(v: MergeStrategy) -> Result<Self, ValidationError> {
             match v {
                 MergeStrategy::Append(v) => Ok(Self::Append(v.try_into()?)),
                 MergeStrategy::Ledger(v) => Ok(Self::Ledger(v.try_into()?)),
@@ -7665,7 +9232,7 @@ pub mod source {
 
     impl TryFrom<MqttQos> for dtos::source::MqttQos {
         type Error = ValidationError;
-        fn try_from(v: MqttQos) -> Result<Self, Self::Error> {
+        fn try_from(v: MqttQos) -> Result<Self, ValidationError> {
             match v {
                 MqttQos::AtMostOnce => Ok(Self::AtMostOnce),
                 MqttQos::AtLeastOnce => Ok(Self::AtLeastOnce),
@@ -7747,7 +9314,7 @@ pub mod source {
 
     impl TryFrom<PrepStep> for dtos::source::PrepStep {
         type Error = ValidationError;
-        fn try_from(v: PrepStep) -> Result<Self, Self::Error> {
+        fn try_from(v: PrepStep) -> Result<Self, ValidationError> {
             match v {
                 PrepStep::Decompress(v) => Ok(Self::Decompress(v.try_into()?)),
                 PrepStep::Pipe(v) => Ok(Self::Pipe(v.try_into()?)),
@@ -7841,7 +9408,13 @@ pub mod source {
         Parquet(source::ReadStepParquet),
         #[serde(alias = "json")]
         Json(source::ReadStepJson),
-        #[serde(alias = "ndJson", alias = "ndjson")]
+        #[serde(
+            alias = "JsonLines",
+            alias = "jsonLines",
+            alias = "jsonlines",
+            alias = "ndJson",
+            alias = "ndjson"
+        )]
         NdJson(source::ReadStepNdJson),
         #[serde(alias = "ndGeoJson", alias = "ndgeojson")]
         NdGeoJson(source::ReadStepNdGeoJson),
@@ -7870,7 +9443,7 @@ pub mod source {
 
     impl TryFrom<ReadStep> for dtos::source::ReadStep {
         type Error = ValidationError;
-        fn try_from(v: ReadStep) -> Result<Self, Self::Error> {
+        fn try_from(v: ReadStep) -> Result<Self, ValidationError> {
             match v {
                 ReadStep::Csv(v) => Ok(Self::Csv(v.try_into()?)),
                 ReadStep::GeoJson(v) => Ok(Self::GeoJson(v.try_into()?)),
@@ -8353,7 +9926,7 @@ pub mod source {
 
     impl TryFrom<SourceCaching> for dtos::source::SourceCaching {
         type Error = ValidationError;
-        fn try_from(v: SourceCaching) -> Result<Self, Self::Error> {
+        fn try_from(v: SourceCaching) -> Result<Self, ValidationError> {
             match v {
                 SourceCaching::Forever(v) => Ok(Self::Forever(v.try_into()?)),
             }
@@ -8418,7 +9991,7 @@ pub mod source {
 
     impl TryFrom<SourceOrdering> for dtos::source::SourceOrdering {
         type Error = ValidationError;
-        fn try_from(v: SourceOrdering) -> Result<Self, Self::Error> {
+        fn try_from(v: SourceOrdering) -> Result<Self, ValidationError> {
             match v {
                 SourceOrdering::ByEventTime => Ok(Self::ByEventTime),
                 SourceOrdering::ByName => Ok(Self::ByName),
@@ -8839,7 +10412,7 @@ pub mod storage {
 
     impl TryFrom<PersistentVolumeSpec> for dtos::storage::PersistentVolumeSpec {
         type Error = ValidationError;
-        fn try_from(v: PersistentVolumeSpec) -> Result<Self, Self::Error> {
+        fn try_from(v: PersistentVolumeSpec) -> Result<Self, ValidationError> {
             match v {
                 PersistentVolumeSpec::S3(v) => Ok(Self::S3(v.try_into()?)),
             }
@@ -8874,7 +10447,7 @@ pub mod storage {
 
     impl TryFrom<PersistentVolumeSpecInput> for dtos::storage::PersistentVolumeSpecInput {
         type Error = ValidationError;
-        fn try_from(v: PersistentVolumeSpecInput) -> Result<Self, Self::Error> {
+        fn try_from(v: PersistentVolumeSpecInput) -> Result<Self, ValidationError> {
             match v {
                 PersistentVolumeSpecInput::S3(v) => Ok(Self::S3(v.try_into()?)),
             }
@@ -0,0 +1,354 @@
+use convert_case::{Case, Casing};
+use serde_json::{Value, json};
+
+use crate::json_schema::SCHEMA_URL_RE;
+use crate::model::{MetaType, Model, Type, TypeDefinition, TypeId};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Operation of the resource API that every resource type supports, see RFC-018
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operation {
+    /// Lists resources matching a selector, or streams changes to them as newline-delimited events with `watch=true`
+    List,
+    /// Creates a resource from a manifest, failing if one with the same name already exists
+    Create,
+    /// Creates a resource or merges a manifest into its live state, see [`crate::merge::apply`]
+    Apply,
+    Get,
+    /// Replaces the desired state of a resource with the one of a manifest
+    Update,
+    /// Replaces the status of a resource, which is how controllers report reconciliation results
+    UpdateStatus,
+    Delete,
+}
+
+impl Operation {
+    pub const ALL: [Operation; 7] = [
+        Operation::List,
+        Operation::Create,
+        Operation::Apply,
+        Operation::Get,
+        Operation::Update,
+        Operation::UpdateStatus,
+        Operation::Delete,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Operation::List => "list",
+            Operation::Create => "create",
+            Operation::Apply => "apply",
+            Operation::Get => "get",
+            Operation::Update => "update",
+            Operation::UpdateStatus => "updateStatus",
+            Operation::Delete => "delete",
+        }
+    }
+
+    pub fn method(&self) -> &'static str {
+        match self {
+            Operation::List | Operation::Get => "GET",
+            Operation::Create => "POST",
+            Operation::Apply | Operation::Update | Operation::UpdateStatus => "PUT",
+            Operation::Delete => "DELETE",
+        }
+    }
+
+    /// Whether the operation addresses a single resource by the `{ref}` path segment
+    pub fn is_item(&self) -> bool {
+        !matches!(self, Operation::List | Operation::Create | Operation::Apply)
+    }
+
+    pub fn request(&self) -> Option<Payload> {
+        match self {
+            Operation::Create | Operation::Apply | Operation::Update => Some(Payload::Manifest),
+            Operation::UpdateStatus => Some(Payload::Status),
+            Operation::List | Operation::Get | Operation::Delete => None,
+        }
+    }
+
+    pub fn response(&self) -> Payload {
+        match self {
+            Operation::List => Payload::ResourceList,
+            _ => Payload::Resource,
+        }
+    }
+
+    /// Status of a successful response, creation is told apart from updates by the server for [`Operation::Apply`]
+    pub fn success_status(&self) -> u16 {
+        match self {
+            Operation::Create => 201,
+            _ => 200,
+        }
+    }
+
+    /// Statuses of error responses of the operation, including the ones of the HTTP transport shared by all operations,
+    /// see [`ApiError`] and [`crate::server::ResourceServer::serve`]
+    pub fn error_statuses(&self) -> Vec<u16> {
+        // Any request can be malformed, too large or too slow, and be rejected by the router or a busy server
+        let mut statuses = vec![400, 404, 405, 408, 411, 413, 431, 503];
+        match self {
+            // Watches can't resume from events that are no longer kept
+            Operation::List => statuses.push(410),
+            Operation::Get => {}
+            Operation::Create | Operation::Apply | Operation::Update => statuses.extend([409, 500]),
+            // Changes are persisted, which may fail
+            Operation::UpdateStatus | Operation::Delete => statuses.push(500),
+        }
+        statuses.sort();
+        statuses
+    }
+
+    pub fn query_params(&self) -> &'static [QueryParam] {
+        match self {
+            Operation::List => &[
+                QueryParam::Account,
+                QueryParam::Selector,
+                QueryParam::Watch,
+                QueryParam::Since,
+            ],
+            Operation::Apply => &[QueryParam::Account, QueryParam::Force],
+            _ => &[QueryParam::Account],
+        }
+    }
+}
+
+/// Body of a request or a response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Payload {
+    /// User-authored manifest of the resource type, e.g. `Dataset` with a `DatasetSpecInput`
+    Manifest,
+    /// `ResourceStatus` of a resource
+    Status,
+    /// Canonical `Resource` instantiated with the canonical spec, e.g. `DatasetSpec`
+    Resource,
+    /// Object with the canonical resources in `items`
+    ResourceList,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryParam {
+    Account,
+    Selector,
+    Watch,
+    Since,
+    Force,
+}
+
+impl QueryParam {
+    pub fn name(&self) -> &'static str {
+        match self {
+            QueryParam::Account => "account",
+            QueryParam::Selector => "selector",
+            QueryParam::Watch => "watch",
+            QueryParam::Since => "since",
+            QueryParam::Force => "force",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            QueryParam::Account => {
+                "Account acted upon as a name or a DID, the one of the auth subject when absent or empty."
+            }
+            QueryParam::Selector => {
+                "`ResourceSelector` in the short string form or as JSON, the type defaults to the one of the endpoint."
+            }
+            QueryParam::Watch => {
+                "Stream the matching resources and their subsequent changes as newline-delimited `ResourceEvent`s."
+            }
+            QueryParam::Since => {
+                "Sequence number of the last event seen by a watch, to resume it without the initial listing."
+            }
+            QueryParam::Force => {
+                "Overwrite values that were changed by others since the last apply."
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Endpoints of a resource type
+#[derive(Debug, Clone)]
+pub struct ResourceApi {
+    /// Resource type, which is also the type of its manifests, e.g. `Dataset`
+    pub kind: TypeId,
+    /// Type of the spec in manifests, e.g. `DatasetSpecInput`
+    pub spec_input: TypeId,
+    /// Type of the spec in canonical resources, e.g. `DatasetSpec`
+    pub spec: TypeId,
+    /// Path of the collection, e.g. `/dataset/v1alpha1/dataset`
+    pub path: String,
+}
+
+impl ResourceApi {
+    /// Path template of the operation where a single resource is addressed by `{ref}`, e.g.
+    /// `/dataset/v1alpha1/dataset/{ref}/status`
+    pub fn route_path(&self, operation: Operation) -> String {
+        match operation {
+            Operation::UpdateStatus => format!("{}/{{ref}}/status", self.path),
+            op if op.is_item() => format!("{}/{{ref}}", self.path),
+            _ => self.path.clone(),
+        }
+    }
+}
+
+/// Derives the endpoints of all resource types in the model, laid out as `/<context>/<version>/<type>` for listing and
+/// `/<context>/<version>/<type>/<id-did-name>` for single resources
+pub fn resource_apis(model: &Model) -> Vec<ResourceApi> {
+    model
+        .types
+        .values()
+        .filter(|t| matches!(t.metatype(), MetaType::Resource))
+        .map(|t| {
+            let TypeDefinition::Struct(s) = t else {
+                panic!("Resource {} must be a struct", t.id().join("::"));
+            };
+            let Some(Type::Custom(spec_input)) = s.fields.get("spec").map(|f| &f.typ) else {
+                panic!("Resource {} must define a `spec` property", s.id.join("::"));
+            };
+            let spec = model.types[spec_input]
+                .canonical_type()
+                .unwrap_or(spec_input)
+                .clone();
+
+            let cap = SCHEMA_URL_RE.captures(s.id.schema_id()).unwrap();
+            ResourceApi {
+                path: format!(
+                    "/{}/{}/{}",
+                    &cap["context"],
+                    &cap["version"],
+                    s.id.name().to_case(Case::Kebab)
+                ),
+                kind: s.id.clone(),
+                spec_input: spec_input.clone(),
+                spec,
+            }
+        })
+        .collect()
+}
+
+/// Request matched to an operation of a resource type
+#[derive(Debug, Clone)]
+pub struct Route<'a> {
+    pub api: &'a ResourceApi,
+    pub operation: Operation,
+    /// Percent-decoded `{ref}` segment of item operations
+    pub reference: Option<String>,
+}
+
+/// Matches a request to an operation by its method and percent-encoded path
+pub fn route<'a>(apis: &'a [ResourceApi], method: &str, path: &str) -> Result<Route<'a>, ApiError> {
+    let not_found = || ApiError::new(404, format!("No resource endpoint at {path}"));
+
+    let (api, rest) = apis
+        .iter()
+        .find_map(|api| {
+            let rest = path.strip_prefix(&api.path)?;
+            (rest.is_empty() || rest.starts_with('/')).then_some((api, rest))
+        })
+        .ok_or_else(not_found)?;
+
+    let segments: Vec<_> = rest.split('/').skip(1).collect();
+    let (reference, candidates): (_, &[Operation]) = match segments[..] {
+        [] | [""] => (
+            None,
+            &[Operation::List, Operation::Create, Operation::Apply],
+        ),
+        [r] if !r.is_empty() => (
+            Some(percent_decode(r)?),
+            &[Operation::Get, Operation::Update, Operation::Delete],
+        ),
+        [r, "status"] if !r.is_empty() => (Some(percent_decode(r)?), &[Operation::UpdateStatus]),
+        _ => return Err(not_found()),
+    };
+
+    let Some(operation) = candidates.iter().find(|op| op.method() == method) else {
+        let allowed: Vec<_> = candidates.iter().map(|op| op.method()).collect();
+        return Err(ApiError::new(
+            405,
+            format!(
+                "Method {method} is not allowed at {path}, expected one of: {}",
+                allowed.join(", ")
+            ),
+        ));
+    };
+
+    Ok(Route {
+        api,
+        operation: *operation,
+        reference,
+    })
+}
+
+/// Decodes `%XX` escapes and `+` of a path segment or a query argument
+pub fn percent_decode(s: &str) -> Result<String, ApiError> {
+    let invalid = || ApiError::new(400, format!("Invalid percent-encoding in {s}"));
+
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'%' => {
+                let hex = [
+                    iter.next().ok_or_else(invalid)?,
+                    iter.next().ok_or_else(invalid)?,
+                ];
+                let hex = std::str::from_utf8(&hex).map_err(|_| invalid())?;
+                bytes.push(u8::from_str_radix(hex, 16).map_err(|_| invalid())?);
+            }
+            b'+' => bytes.push(b' '),
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| invalid())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Error response of the resource API
+#[derive(Debug, Clone, PartialEq)]
+pub struct ApiError {
+    /// HTTP status code
+    pub status: u16,
+    pub message: String,
+    /// Individual problems, e.g. validation diagnostics or merge conflicts
+    pub details: Vec<String>,
+}
+
+impl ApiError {
+    pub fn new(status: u16, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+            details: Vec::new(),
+        }
+    }
+
+    pub fn details(mut self, details: Vec<String>) -> Self {
+        self.details = details;
+        self
+    }
+
+    pub fn to_value(&self) -> Value {
+        json!({
+            "status": self.status,
+            "message": self.message,
+            "details": self.details,
+        })
+    }
+}
+
+impl std::fmt::Display for ApiError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.status, self.message)?;
+        for detail in &self.details {
+            write!(f, "\n  {detail}")?;
+        }
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,352 @@
+use serde_json::Value;
+
+use crate::graph::{Dependency, DependencyGraph};
+use crate::model::{MetaType, Model, Type, TypeDefinition, TypeId};
+use crate::resolve::{Query, ResourceStore, StoredResource, split_short_form};
+use crate::validation::{child_path, resolve_variant};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const ACCOUNT_REF: &str = "https://opendatafabric.org/schemas/auth/v1alpha1/AccountRef";
+pub(crate) const VALUE_REF: &str = "https://opendatafabric.org/schemas/config/v1alpha1/ValueRef";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProblemKind {
+    /// Reference is malformed, e.g. doesn't specify the type of the resource
+    Invalid,
+    /// No resource in the bundle matches the reference
+    Dangling,
+    /// Reference specifies or resolves to a resource of a type other than the expected one
+    TypeMismatch,
+    /// `ValueRef.path` points to a key that the referenced `VariableSet` or `SecretSet` doesn't have
+    MissingValue,
+    /// Resources reference each other
+    Cycle,
+}
+
+/// Reference of a resource in a bundle that doesn't hold
+#[derive(Debug, Clone)]
+pub struct Problem<'a> {
+    pub resource: &'a StoredResource,
+    /// Dot-separated path to the reference within the manifest, e.g. `spec.tasks[0].source`
+    pub path: String,
+    pub kind: ProblemKind,
+    pub message: String,
+}
+
+impl std::fmt::Display for Problem<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}: {}", self.resource, self.path, self.message)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Follows every reference in the resources of a bundle and reports the ones that don't resolve within it.
+///
+/// References are found by the schema: all properties typed as a `ResourceRef` metatype (e.g. `ResourceRef`,
+/// `ValueRef`, `PersistentVolumeRef`) and `dataset-ref` strings with a `targetType` (e.g. inputs of a `Projection`) are
+/// checked, and those that omit the type are resolved to the `targetType` of the property. Account references only scope other references, as accounts are usually provisioned by the node rather
+/// than bundled.
+pub fn check<'a>(store: &'a ResourceStore) -> Vec<Problem<'a>> {
+    let resources = store.resources();
+    let (dependencies, mut problems) = resolve_references(store);
+
+    for cycle in DependencyGraph::new(resources, dependencies).cycles() {
+        problems.push(Problem {
+            resource: &resources[cycle.from],
+            path: cycle.path.clone(),
+            kind: ProblemKind::Cycle,
+            message: format!(
+                "Reference cycle: {}",
+                cycle
+                    .resources
+                    .iter()
+                    .map(|i| resources[*i].to_string())
+                    .collect::<Vec<_>>()
+                    .join(" -> ")
+            ),
+        });
+    }
+
+    problems
+}
+
+/// Resolves references of all resources in the store into dependencies between them, reporting the ones that don't
+/// resolve
+pub fn resolve_references<'a>(store: &'a ResourceStore) -> (Vec<Dependency>, Vec<Problem<'a>>) {
+    let model = store.model();
+    let resources = store.resources();
+
+    let mut dependencies = Vec::new();
+    let mut problems = Vec::new();
+
+    for (i, resource) in resources.iter().enumerate() {
+        let mut refs = Vec::new();
+        collect_type_def(
+            model,
+            &resource.type_id,
+            None,
+            &resource.manifest,
+            "",
+            &mut refs,
+        );
+
+        for r in refs {
+            match check_ref(store, &r) {
+                Ok(target) => {
+                    let j = resources
+                        .iter()
+                        .position(|other| std::ptr::eq(other, target))
+                        .unwrap();
+                    dependencies.push(Dependency {
+                        from: i,
+                        to: j,
+                        path: r.path,
+                    });
+                }
+                Err((kind, message)) => problems.push(Problem {
+                    resource,
+                    path: r.path,
+                    kind,
+                    message,
+                }),
+            }
+        }
+    }
+
+    (dependencies, problems)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RefKind {
+    Resource,
+    Value,
+    /// `dataset-ref` string, e.g. `account/name` or a DID
+    Dataset,
+}
+
+struct Ref<'v> {
+    path: String,
+    kind: RefKind,
+    /// Type of the resource that the property expects
+    target_type: Option<TypeId>,
+    value: &'v Value,
+}
+
+fn collect_type<'v>(
+    model: &Model,
+    typ: &Type,
+    target_type: Option<&TypeId>,
+    value: &'v Value,
+    path: &str,
+    refs: &mut Vec<Ref<'v>>,
+) {
+    match typ {
+        Type::Custom(id) => collect_type_def(model, id, target_type, value, path, refs),
+        // Remote datasets are outside of the bundle
+        Type::DatasetRef if target_type.is_some() => {
+            if let Some(s) = value.as_str()
+                && !s.contains("://")
+                && s.matches('/').count() <= 1
+            {
+                refs.push(Ref {
+                    path: path.to_string(),
+                    kind: RefKind::Dataset,
+                    target_type: target_type.cloned(),
+                    value,
+                });
+            }
+        }
+        Type::Array(arr) => {
+            for (i, item) in value.as_array().into_iter().flatten().enumerate() {
+                let path = format!("{path}[{i}]");
+                collect_type(model, &arr.item_type, target_type, item, &path, refs);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn collect_type_def<'v>(
+    model: &Model,
+    id: &TypeId,
+    target_type: Option<&TypeId>,
+    value: &'v Value,
+    path: &str,
+    refs: &mut Vec<Ref<'v>>,
+) {
+    let Some(typ) = model.types.get(id) else {
+        panic!("Reference to unknown type {}", id.schema_id());
+    };
+
+    if matches!(typ.metatype(), MetaType::ResourceRef) {
+        if id.schema_id().as_str() != ACCOUNT_REF {
+            let kind = if id.schema_id().as_str() == VALUE_REF {
+                RefKind::Value
+            } else {
+                RefKind::Resource
+            };
+            refs.push(Ref {
+                path: path.to_string(),
+                kind,
+                target_type: target_type.cloned(),
+                value,
+            });
+        }
+        return;
+    }
+
+    match (typ, value) {
+        (TypeDefinition::Struct(t), Value::Object(obj)) => {
+            for (key, value) in obj {
+                if let Some((field, _)) = t.field_by_json_name(key) {
+                    collect_type(
+                        model,
+                        &field.typ,
+                        field.target_type.as_ref(),
+                        value,
+                        &child_path(path, key),
+                        refs,
+                    );
+                }
+            }
+        }
+        (TypeDefinition::Union(t), Value::Object(_)) => {
+            if let Some(variant) = resolve_variant(model, t, value) {
+                collect_type_def(model, &variant.id, None, value, path, refs);
+            }
+        }
+        (TypeDefinition::Map(t), Value::Object(obj)) => {
+            for (key, value) in obj {
+                collect_type(
+                    model,
+                    &t.value_type,
+                    target_type,
+                    value,
+                    &child_path(path, key),
+                    refs,
+                );
+            }
+        }
+        _ => (),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn check_ref<'a>(
+    store: &'a ResourceStore,
+    r: &Ref,
+) -> Result<&'a StoredResource, (ProblemKind, String)> {
+    let invalid = |e: String| (ProblemKind::Invalid, e);
+
+    // Explicit type must agree with the one the property expects
+    let explicit_type = match r.value {
+        Value::String(_) if r.kind == RefKind::Dataset => None,
+        Value::String(s) if s.starts_with("did:") => None,
+        Value::String(s) => split_short_form(s).0,
+        Value::Object(obj) => obj.get("type").and_then(Value::as_str),
+        _ => None,
+    };
+    if let Some(expected) = &r.target_type
+        && let Some(explicit_type) = explicit_type
+    {
+        let explicit_type = store.resolve_type(explicit_type).map_err(invalid)?;
+        if &explicit_type != expected {
+            return Err((
+                ProblemKind::TypeMismatch,
+                format!(
+                    "Expected a reference to {} but got {}",
+                    expected.name(),
+                    explicit_type.name()
+                ),
+            ));
+        }
+    }
+
+    let is_value_ref = r.kind == RefKind::Value;
+    let query = if is_value_ref {
+        Query::ValueRef(r.value.clone())
+    } else {
+        Query::Ref {
+            reference: r.value.clone(),
+            default_type: r
+                .target_type
+                .as_ref()
+                .map(|t| t.schema_id().as_str().to_string()),
+        }
+    };
+
+    let found = store.find(&query).map_err(invalid)?;
+    let Some(target) = found.into_iter().next() else {
+        // Names are only unique within a type, so resources of another type are only blamed for implicit types and IDs
+        let by_identity =
+            explicit_type.is_none() || r.value.get("id").is_some() || r.value.get("did").is_some();
+        let others = if by_identity {
+            store.find_other_types(&query).map_err(invalid)?
+        } else {
+            Vec::new()
+        };
+        return Err(match others.first() {
+            Some(other) => (
+                ProblemKind::TypeMismatch,
+                format!("Reference {} points to {other} of another type", r.value),
+            ),
+            None => (
+                ProblemKind::Dangling,
+                format!("Reference {} does not match any resource", r.value),
+            ),
+        });
+    };
+
+    if is_value_ref
+        && let Some((_, Some(path))) = store.resolve_value_ref(r.value).map_err(invalid)?
+    {
+        let Some(values) = values_of(store.model(), target) else {
+            return Err((
+                ProblemKind::TypeMismatch,
+                format!("Referenced {target} does not hold values"),
+            ));
+        };
+        if value_at(values, &path).is_none() {
+            return Err((
+                ProblemKind::MissingValue,
+                format!("Referenced {target} has no value `{path}`"),
+            ));
+        }
+    }
+
+    Ok(target)
+}
+
+// Values of a `VariableSet` or a `SecretSet` are held in a map property of the spec
+pub(crate) fn values_of<'r>(model: &Model, resource: &'r StoredResource) -> Option<&'r Value> {
+    let TypeDefinition::Struct(t) = &model.types[&resource.type_id] else {
+        return None;
+    };
+    let Type::Custom(spec_id) = &t.fields.get("spec")?.typ else {
+        return None;
+    };
+    let TypeDefinition::Struct(spec) = model.types.get(spec_id)? else {
+        return None;
+    };
+
+    spec.fields.values().find_map(|field| match &field.typ {
+        Type::Custom(id) if matches!(model.types.get(id), Some(TypeDefinition::Map(_))) => {
+            resource.manifest["spec"].get(field.json_name())
+        }
+        _ => None,
+    })
+}
+
+// Keys can contain dots themselves, so they are looked up as a whole before as a path
+pub(crate) fn value_at<'v>(values: &'v Value, path: &str) -> Option<&'v Value> {
+    values
+        .get(path)
+        .or_else(|| path.split('.').try_fold(values, |v, key| v.get(key)))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
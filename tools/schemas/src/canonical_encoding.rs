@@ -0,0 +1,671 @@
+use flatbuffers::{FlatBufferBuilder, Push, PushAlignment, UnionWIPOffset, WIPOffset};
+use serde_json::{Map, Value};
+use sha3::Digest as _;
+
+use crate::canonicalization::canonicalize;
+use crate::codegen::flatbuffers_schema::struct_field_ids;
+use crate::json_schema::{CodegenHint, CodegenLanguage, FlatbuffersHashable, FlatbuffersMapFormat};
+use crate::model::{self, Model, Type, TypeDefinition, TypeId};
+use crate::validation::resolve_untagged_variant;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reference implementation of the canonical flatbuffers encoding that works directly on JSON values.
+///
+/// It issues exactly the same sequence of builder calls as the generated `FlatbuffersSerializable` implementations
+/// and is used to produce golden test vectors that generated code in other languages and crates must reproduce:
+/// - Tables are written depth-first: all offsets of a struct are serialized in order of field declaration before the
+///   table itself is started, then all fields are added in order of declaration
+/// - Scalars equal to their default value (zero, first enum variant) are omitted unless the field is optional
+/// - Identical vtables are written once and shared by all tables that use them
+/// - Map entries and properties of JSON values are ordered by key, the same way `serde_json::Value` orders them
+///   unless the `preserve_order` feature is enabled
+/// - Structs are aligned to their largest field, unlike in Rust proxies generated by `flatc` 23
+/// - Buffers have no file identifier and no size prefix
+///
+/// Values that don't match the type are reported with the path of the offending field.
+pub fn encode(model: &Model, type_id: &TypeId, value: Value) -> Result<Vec<u8>, String> {
+    let Some(TypeDefinition::Struct(typ)) = model.types.get(type_id) else {
+        return Err(format!(
+            "Only object types can be encoded: {}",
+            type_id.join("::")
+        ));
+    };
+
+    let value = canonicalize(model, type_id, value);
+
+    let mut encoder = Encoder {
+        model,
+        fb: FlatBufferBuilder::new(),
+    };
+    encoder.fb.force_defaults(false);
+
+    let offset = encoder.encode_struct(typ, &value, &type_id.join("::"))?;
+    encoder.fb.finish(offset, None);
+    Ok(encoder.fb.finished_data().to_vec())
+}
+
+/// Wraps the canonical encoding of a hashable type into a `Manifest` that the content hash is computed over
+pub fn encode_manifest(hashable: FlatbuffersHashable, content: &[u8]) -> Vec<u8> {
+    let mut fb = FlatBufferBuilder::new();
+    fb.force_defaults(false);
+
+    // See `legacy/v0/Manifest`
+    let content_offset = fb.create_vector(content);
+    let start = fb.start_table();
+    fb.push_slot::<i64>(slot(0), hashable.kind, 0);
+    fb.push_slot::<i32>(slot(1), hashable.version, 0);
+    fb.push_slot_always(slot(2), content_offset);
+    let offset = fb.end_table(start);
+    fb.finish(offset, None);
+    fb.finished_data().to_vec()
+}
+
+/// SHA3-256 multihash of the data in `base16` multibase encoding
+pub fn multihash(data: &[u8]) -> String {
+    let digest = sha3::Sha3_256::digest(data);
+    // Multicodec code of `sha3-256` followed by digest length
+    let mut res = String::from("f1620");
+    for b in digest {
+        res.push_str(&format!("{b:02x}"));
+    }
+    res
+}
+
+/// Computes the content hash of a value of a hashable type
+pub fn content_hash(model: &Model, type_id: &TypeId, value: Value) -> Result<String, String> {
+    let Some(hashable) = model.types.get(type_id).and_then(|t| match t {
+        TypeDefinition::Struct(t) => t.hashable(),
+        _ => None,
+    }) else {
+        return Err(format!("Type is not hashable: {}", type_id.join("::")));
+    };
+
+    let content = encode(model, type_id, value)?;
+    Ok(multihash(&encode_manifest(hashable, &content)))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Encoder<'a> {
+    model: &'a Model,
+    fb: FlatBufferBuilder<'static>,
+}
+
+/// Value of a table field as it is added to the table after all offsets were written
+enum Slot {
+    Offset(WIPOffset<UnionWIPOffset>),
+    Union(u8, WIPOffset<UnionWIPOffset>),
+    Scalar(Scalar),
+    Timestamp(Timestamp),
+}
+
+#[derive(Clone, Copy)]
+enum Scalar {
+    Bool(bool),
+    Int8(i8),
+    Int16(i16),
+    Int32(i32),
+    Int64(i64),
+    UInt8(u8),
+    UInt16(u16),
+    UInt32(u32),
+    UInt64(u64),
+}
+
+impl Encoder<'_> {
+    fn encode_struct(
+        &mut self,
+        typ: &model::Struct,
+        value: &Value,
+        ctx: &str,
+    ) -> Result<WIPOffset<UnionWIPOffset>, String> {
+        let Value::Object(obj) = value else {
+            return Err(format!("Expected an object: {ctx}"));
+        };
+
+        let ids = struct_field_ids(typ, self.model);
+
+        let mut slots = Vec::new();
+        for (field, id) in typ.fields.values().zip(ids) {
+            let ctx = format!("{ctx}.{}", field.json_name());
+            match obj.get(&field.json_name()) {
+                None | Some(Value::Null) if field.optional => (),
+                None | Some(Value::Null) => return Err(format!("Missing required field: {ctx}")),
+                Some(v) => {
+                    let slot = self.encode_field_value(&field.typ, v, &ctx)?;
+                    slots.push((id, field.optional, slot));
+                }
+            }
+        }
+
+        let start = self.fb.start_table();
+        for (id, optional, slot) in slots {
+            match slot {
+                Slot::Offset(offset) => self.fb.push_slot_always(self::slot(id), offset),
+                Slot::Union(tag, offset) => {
+                    self.fb.push_slot::<u8>(self::slot(id - 1), tag, 0);
+                    self.fb.push_slot_always(self::slot(id), offset);
+                }
+                Slot::Scalar(v) => self.push_scalar(self::slot(id), v, optional),
+                Slot::Timestamp(v) => self.fb.push_slot_always(self::slot(id), &v),
+            }
+        }
+        Ok(self.fb.end_table(start).as_union_value())
+    }
+
+    fn push_scalar(&mut self, slot: u16, value: Scalar, optional: bool) {
+        // Optional scalars are written even when equal to the default to distinguish them from absent values
+        macro_rules! push {
+            ($v:expr, $default:expr) => {
+                if optional {
+                    self.fb.push_slot_always(slot, $v)
+                } else {
+                    self.fb.push_slot(slot, $v, $default)
+                }
+            };
+        }
+
+        match value {
+            Scalar::Bool(v) => push!(v, false),
+            Scalar::Int8(v) => push!(v, 0),
+            Scalar::Int16(v) => push!(v, 0),
+            Scalar::Int32(v) => push!(v, 0),
+            Scalar::Int64(v) => push!(v, 0),
+            Scalar::UInt8(v) => push!(v, 0),
+            Scalar::UInt16(v) => push!(v, 0),
+            Scalar::UInt32(v) => push!(v, 0),
+            Scalar::UInt64(v) => push!(v, 0),
+        }
+    }
+
+    fn encode_field_value(&mut self, typ: &Type, value: &Value, ctx: &str) -> Result<Slot, String> {
+        Ok(match typ {
+            Type::DateTime => Slot::Timestamp(parse_timestamp(expect_str(value, ctx)?, ctx)?),
+            Type::Custom(id) => match &self.model.types[id] {
+                TypeDefinition::Union(t) => {
+                    let (tag, offset) = self.encode_union(t, value, ctx)?;
+                    Slot::Union(tag, offset)
+                }
+                TypeDefinition::Enum(t) => Slot::Scalar(encode_enum(t, value, ctx)?),
+                _ => Slot::Offset(self.encode_offset(typ, value, ctx)?),
+            },
+            _ => match scalar(typ, value, ctx)? {
+                Some(v) => Slot::Scalar(v),
+                None => Slot::Offset(self.encode_offset(typ, value, ctx)?),
+            },
+        })
+    }
+
+    fn encode_offset(
+        &mut self,
+        typ: &Type,
+        value: &Value,
+        ctx: &str,
+    ) -> Result<WIPOffset<UnionWIPOffset>, String> {
+        Ok(match typ {
+            Type::String
+            | Type::AccountName
+            | Type::ResourceName
+            | Type::TypeUri
+            | Type::TypeName
+            | Type::TypeRef
+            | Type::DatasetAlias
+            | Type::DatasetRef
+            | Type::Path
+            | Type::Regex
+            | Type::Url => self
+                .fb
+                .create_string(expect_str(value, ctx)?)
+                .as_union_value(),
+            Type::Did | Type::AccountId | Type::DatasetId | Type::ResourceId => {
+                let s = expect_str(value, ctx)?;
+                let Some(s) = s.strip_prefix("did:odf:") else {
+                    return Err(format!(
+                        "Only `did:odf` identifiers are supported: {ctx}: {s}"
+                    ));
+                };
+                let bytes = decode_multibase(s, ctx)?;
+                self.fb.create_vector(&bytes).as_union_value()
+            }
+            Type::Multihash => {
+                let bytes = decode_multibase(expect_str(value, ctx)?, ctx)?;
+                self.fb.create_vector(&bytes).as_union_value()
+            }
+            Type::Flatbuffers => {
+                let bytes = decode_base64(expect_str(value, ctx)?, ctx)?;
+                self.fb.create_vector(&bytes).as_union_value()
+            }
+            Type::AnyJson => self
+                .fb
+                .create_string(&serde_json::to_string(&sort_keys(value)).unwrap())
+                .as_union_value(),
+            Type::Array(arr) => self.encode_array(&arr.item_type, value, ctx)?,
+            Type::Custom(id) => match &self.model.types[id] {
+                TypeDefinition::Struct(t) => self.encode_struct(t, value, ctx)?,
+                TypeDefinition::Map(t) => self.encode_map(t, value, ctx)?,
+                TypeDefinition::Union(_) | TypeDefinition::Enum(_) => unreachable!(),
+            },
+            Type::Boolean
+            | Type::Int8
+            | Type::Int16
+            | Type::Int32
+            | Type::Int64
+            | Type::UInt8
+            | Type::UInt16
+            | Type::UInt32
+            | Type::UInt64
+            | Type::DateTime => unreachable!(),
+            Type::ByteSize | Type::Duration | Type::Multicodec | Type::Generic(_) => {
+                return Err(format!(
+                    "Canonical encoding of {typ:?} is not supported: {ctx}"
+                ));
+            }
+        })
+    }
+
+    fn encode_array(
+        &mut self,
+        item_type: &Type,
+        value: &Value,
+        ctx: &str,
+    ) -> Result<WIPOffset<UnionWIPOffset>, String> {
+        let Value::Array(items) = value else {
+            return Err(format!("Expected an array: {ctx}"));
+        };
+
+        let enum_type = match item_type {
+            Type::Custom(id) => match &self.model.types[id] {
+                TypeDefinition::Enum(t) => Some(t),
+                TypeDefinition::Union(t) => {
+                    // Unions are wrapped into tables, see `XWrapper` types in flatbuffers schema
+                    let offsets = items
+                        .iter()
+                        .map(|i| {
+                            let (tag, offset) = self.encode_union(t, i, ctx)?;
+                            let start = self.fb.start_table();
+                            self.fb.push_slot::<u8>(slot(0), tag, 0);
+                            self.fb.push_slot_always(slot(1), offset);
+                            Ok(self.fb.end_table(start).as_union_value())
+                        })
+                        .collect::<Result<Vec<_>, String>>()?;
+                    return Ok(self.fb.create_vector(&offsets).as_union_value());
+                }
+                _ => None,
+            },
+            _ => None,
+        };
+
+        let format = enum_type.map_or(item_type, |t| &t.format);
+        if matches!(
+            format,
+            Type::Boolean
+                | Type::Int8
+                | Type::Int16
+                | Type::Int32
+                | Type::Int64
+                | Type::UInt8
+                | Type::UInt16
+                | Type::UInt32
+                | Type::UInt64
+        ) {
+            let scalars = items
+                .iter()
+                .map(|i| match enum_type {
+                    Some(t) => encode_enum(t, i, ctx),
+                    None => Ok(scalar(item_type, i, ctx)?.unwrap()),
+                })
+                .collect::<Result<Vec<_>, String>>()?;
+            return Ok(self.create_scalar_vector(format, &scalars));
+        }
+
+        let offsets = items
+            .iter()
+            .map(|i| self.encode_offset(item_type, i, ctx))
+            .collect::<Result<Vec<_>, String>>()?;
+        Ok(self.fb.create_vector(&offsets).as_union_value())
+    }
+
+    fn create_scalar_vector(
+        &mut self,
+        format: &Type,
+        items: &[Scalar],
+    ) -> WIPOffset<UnionWIPOffset> {
+        // Element type defines the alignment, so it matters even for empty vectors
+        macro_rules! create {
+            ($variant:ident) => {{
+                let items: Vec<_> = items
+                    .iter()
+                    .map(|i| match i {
+                        Scalar::$variant(v) => *v,
+                        _ => unreachable!(),
+                    })
+                    .collect();
+                self.fb.create_vector(&items).as_union_value()
+            }};
+        }
+
+        match format {
+            Type::Boolean => create!(Bool),
+            Type::Int8 => create!(Int8),
+            Type::Int16 => create!(Int16),
+            Type::Int32 => create!(Int32),
+            Type::Int64 => create!(Int64),
+            Type::UInt8 => create!(UInt8),
+            Type::UInt16 => create!(UInt16),
+            Type::UInt32 => create!(UInt32),
+            Type::UInt64 => create!(UInt64),
+            _ => unreachable!(),
+        }
+    }
+
+    fn encode_union(
+        &mut self,
+        typ: &model::Union,
+        value: &Value,
+        ctx: &str,
+    ) -> Result<(u8, WIPOffset<UnionWIPOffset>), String> {
+        let variant = match (typ.discriminator.property(), value) {
+            (None, _) => resolve_untagged_variant(self.model, typ, value),
+            (Some(_), Value::String(tag)) if typ.from_string => {
+                typ.variant_by_tag(tag).map(|(v, _)| v)
+            }
+            (Some(property), Value::Object(obj)) => obj
+                .get(property)
+                .and_then(Value::as_str)
+                .and_then(|tag| typ.variant_by_tag(tag))
+                .map(|(v, _)| v),
+            _ => None,
+        };
+        let Some(variant) = variant else {
+            return Err(format!(
+                "Unknown variant of {}: {ctx}: {value}",
+                typ.id.join("::")
+            ));
+        };
+
+        // Short form of a union only carries the tag
+        let value = match value {
+            Value::String(_) => &Value::Object(Map::new()),
+            _ => value,
+        };
+
+        let ctx = format!("{ctx}.{}", variant.id.name());
+        let TypeDefinition::Struct(t) = &self.model.types[&variant.id] else {
+            return Err(format!("Union variants must be structs: {ctx}"));
+        };
+
+        // Flatbuffers union tags start from 1, as 0 is reserved for `NONE`
+        let tag = typ
+            .variants
+            .iter()
+            .position(|v| v.id == variant.id)
+            .unwrap()
+            + 1;
+        Ok((tag as u8, self.encode_struct(t, value, &ctx)?))
+    }
+
+    fn encode_map(
+        &mut self,
+        typ: &model::Map,
+        value: &Value,
+        ctx: &str,
+    ) -> Result<WIPOffset<UnionWIPOffset>, String> {
+        let Value::Object(obj) = value else {
+            return Err(format!("Expected an object: {ctx}"));
+        };
+
+        // Maps are `BTreeMap`s in DTOs, so entries are always ordered by key
+        let mut entries: Vec<_> = obj.iter().collect();
+        entries.sort_by_key(|(a, _)| *a);
+
+        let entries_offset = match typ
+            .get_hint::<FlatbuffersMapFormat>(CodegenLanguage::Flatbuffers, CodegenHint::MapFormat)
+        {
+            None => {
+                let offsets = entries
+                    .into_iter()
+                    .map(|(key, value)| {
+                        let ctx = format!("{ctx}.{key}");
+                        let key_offset = self.fb.create_string(key);
+                        let value_offset = self.encode_offset(&typ.value_type, value, &ctx)?;
+                        let start = self.fb.start_table();
+                        self.fb.push_slot_always(slot(0), key_offset);
+                        self.fb.push_slot_always(slot(1), value_offset);
+                        Ok(self.fb.end_table(start).as_union_value())
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                self.fb.create_vector(&offsets).as_union_value()
+            }
+            Some(FlatbuffersMapFormat::JsonEncodedString) => {
+                let entries: Map<_, _> = entries
+                    .into_iter()
+                    .map(|(k, v)| (k.clone(), sort_keys(v)))
+                    .collect();
+                self.fb
+                    .create_string(&serde_json::to_string(&entries).unwrap())
+                    .as_union_value()
+            }
+        };
+
+        let start = self.fb.start_table();
+        self.fb.push_slot_always(slot(0), entries_offset);
+        Ok(self.fb.end_table(start).as_union_value())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn slot(id: u32) -> u16 {
+    flatbuffers::field_index_to_field_offset(id as u16)
+}
+
+fn expect_str<'a>(value: &'a Value, ctx: &str) -> Result<&'a str, String> {
+    value
+        .as_str()
+        .ok_or_else(|| format!("Expected a string: {ctx}: {value}"))
+}
+
+fn scalar(typ: &Type, value: &Value, ctx: &str) -> Result<Option<Scalar>, String> {
+    fn int<T: TryFrom<i128>>(value: &Value, ctx: &str) -> Result<T, String> {
+        value
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| value.as_u64().map(i128::from))
+            .and_then(|v| T::try_from(v).ok())
+            .ok_or_else(|| format!("Expected an integer in range: {ctx}: {value}"))
+    }
+
+    Ok(Some(match typ {
+        Type::Boolean => Scalar::Bool(
+            value
+                .as_bool()
+                .ok_or_else(|| format!("Expected a boolean: {ctx}: {value}"))?,
+        ),
+        Type::Int8 => Scalar::Int8(int(value, ctx)?),
+        Type::Int16 => Scalar::Int16(int(value, ctx)?),
+        Type::Int32 => Scalar::Int32(int(value, ctx)?),
+        Type::Int64 => Scalar::Int64(int(value, ctx)?),
+        Type::UInt8 => Scalar::UInt8(int(value, ctx)?),
+        Type::UInt16 => Scalar::UInt16(int(value, ctx)?),
+        Type::UInt32 => Scalar::UInt32(int(value, ctx)?),
+        Type::UInt64 => Scalar::UInt64(int(value, ctx)?),
+        _ => return Ok(None),
+    }))
+}
+
+fn encode_enum(typ: &model::Enum, value: &Value, ctx: &str) -> Result<Scalar, String> {
+    let s = expect_str(value, ctx)?;
+    let Some(index) = typ.variants.iter().position(|v| model::is_same_name(v, s)) else {
+        return Err(format!(
+            "Unknown value of {}: {ctx}: {s}",
+            typ.id.join("::")
+        ));
+    };
+    scalar(&typ.format, &Value::from(index), ctx)?
+        .ok_or_else(|| format!("Invalid enum format {:?}: {ctx}", typ.format))
+}
+
+fn decode_multibase(s: &str, ctx: &str) -> Result<Vec<u8>, String> {
+    let Some(hex) = s.strip_prefix('f') else {
+        return Err(format!(
+            "Only `base16` multibase encoding is supported: {ctx}: {s}"
+        ));
+    };
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return Err(format!("Invalid base16 string: {ctx}: {s}"));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|_| format!("Invalid base16 string: {ctx}: {s}"))
+        })
+        .collect()
+}
+
+fn decode_base64(s: &str, ctx: &str) -> Result<Vec<u8>, String> {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let invalid = || format!("Invalid base64 string: {ctx}: {s}");
+
+    let mut res = Vec::new();
+    let mut acc = 0u32;
+    let mut bits = 0;
+    for c in s.trim_end_matches('=').bytes() {
+        let Some(v) = ALPHABET.iter().position(|a| *a == c) else {
+            return Err(invalid());
+        };
+        acc = (acc << 6) | v as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            res.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+
+    // Leftover bits don't form a byte and must be zero, otherwise several strings would decode into the same bytes
+    if bits >= 6 || acc != 0 {
+        return Err(invalid());
+    }
+    Ok(res)
+}
+
+/// Copy of a JSON value with properties of all nested objects ordered by key
+fn sort_keys(value: &Value) -> Value {
+    match value {
+        Value::Object(obj) => {
+            let mut entries: Vec<_> = obj.iter().collect();
+            entries.sort_by_key(|(k, _)| *k);
+            Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k.clone(), sort_keys(v)))
+                    .collect(),
+            )
+        }
+        Value::Array(arr) => Value::Array(arr.iter().map(sort_keys).collect()),
+        _ => value.clone(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+// Timestamp
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Layout of the `Timestamp` struct: `year: int32`, `ordinal: uint16`, padding, `seconds_from_midnight: uint32`,
+/// `nanoseconds: uint32`
+struct Timestamp([u8; 16]);
+
+impl Push for Timestamp {
+    type Output = Timestamp;
+
+    unsafe fn push(&self, dst: &mut [u8], _written_len: usize) {
+        dst[..16].copy_from_slice(&self.0);
+    }
+
+    fn alignment() -> PushAlignment {
+        PushAlignment::new(4)
+    }
+}
+
+static DATETIME_RE: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
+    regex::Regex::new(
+        r"^(\d{4})-(\d{2})-(\d{2})[Tt ](\d{2}):(\d{2}):(\d{2})(?:\.(\d{1,9}))?(?:([Zz])|([+-])(\d{2}):(\d{2}))$",
+    )
+    .unwrap()
+});
+
+/// Parses an RFC3339 date-time into a UTC timestamp
+fn parse_timestamp(s: &str, ctx: &str) -> Result<Timestamp, String> {
+    let Some(cap) = DATETIME_RE.captures(s) else {
+        return Err(format!("Invalid date-time: {ctx}: {s}"));
+    };
+    let num = |i: usize| -> i64 { cap.get(i).map_or(0, |m| m.as_str().parse().unwrap()) };
+
+    let nanos = cap
+        .get(7)
+        .map_or(0, |m| format!("{:0<9}", m.as_str()).parse::<u32>().unwrap());
+    let offset = match cap.get(9).map(|m| m.as_str()) {
+        Some("-") => -(num(10) * 3600 + num(11) * 60),
+        Some(_) => num(10) * 3600 + num(11) * 60,
+        None => 0,
+    };
+
+    let (month, day) = (num(2), num(3));
+    let days_in_month = match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(num(1)) => 29,
+        2 => 28,
+        _ => 0,
+    };
+    if day < 1 || day > days_in_month || num(4) > 23 || num(5) > 59 || num(6) > 60 {
+        return Err(format!("Invalid date-time: {ctx}: {s}"));
+    }
+
+    let seconds =
+        days_from_civil(num(1), num(2), num(3)) * 86400 + num(4) * 3600 + num(5) * 60 + num(6)
+            - offset;
+    let days = seconds.div_euclid(86400);
+    let seconds_from_midnight = seconds.rem_euclid(86400) as u32;
+
+    let year = civil_from_days(days);
+    let ordinal = (days - days_from_civil(year, 1, 1) + 1) as u16;
+
+    let mut buf = [0; 16];
+    buf[0..4].copy_from_slice(&(year as i32).to_le_bytes());
+    buf[4..6].copy_from_slice(&ordinal.to_le_bytes());
+    buf[8..12].copy_from_slice(&seconds_from_midnight.to_le_bytes());
+    buf[12..16].copy_from_slice(&nanos.to_le_bytes());
+    Ok(Timestamp(buf))
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Days since Unix epoch in the proleptic Gregorian calendar
+/// See: http://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Year of the specified number of days since Unix epoch
+fn civil_from_days(days: i64) -> i64 {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400;
+    if month <= 2 { year + 1 } else { year }
+}
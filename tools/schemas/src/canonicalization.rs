@@ -0,0 +1,131 @@
+use serde_json::{Map, Value};
+
+use crate::model::{self, Model, Type, TypeDefinition, TypeId};
+use crate::validation::{manifest_type, resolve_untagged_variant};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Rewrites a manifest into its canonical form, replacing deprecated aliases of properties and union variants
+/// (as well as lowercase / camelCase spellings of variants) with their current names.
+///
+/// Values that do not conform to the schema are left untouched - use validation to detect them.
+pub fn canonicalize_manifest(model: &Model, manifest: Value) -> Value {
+    match manifest_type(model, &manifest) {
+        Ok(id) => canonicalize(model, &id, manifest),
+        Err(_) => manifest,
+    }
+}
+
+/// Rewrites a value of the specified type into its canonical form
+pub fn canonicalize(model: &Model, type_id: &TypeId, value: Value) -> Value {
+    canonicalize_type_def(model, type_id, value)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn canonicalize_type_def(model: &Model, id: &TypeId, value: Value) -> Value {
+    let Some(typ) = model.types.get(id) else {
+        panic!("Reference to unknown type {}", id.schema_id());
+    };
+
+    match typ {
+        TypeDefinition::Struct(t) => canonicalize_struct(model, t, value),
+        TypeDefinition::Union(t) => canonicalize_union(model, t, value),
+        TypeDefinition::Enum(t) => match value {
+            Value::String(s) => Value::String(
+                t.variants
+                    .iter()
+                    .find(|v| model::is_same_name(v, &s))
+                    .cloned()
+                    .unwrap_or(s),
+            ),
+            value => value,
+        },
+        TypeDefinition::Map(t) => match value {
+            Value::Object(obj) => Value::Object(
+                obj.into_iter()
+                    .map(|(k, v)| (k, canonicalize_type(model, &t.value_type, v)))
+                    .collect(),
+            ),
+            value => value,
+        },
+    }
+}
+
+fn canonicalize_struct(model: &Model, t: &model::Struct, value: Value) -> Value {
+    let Value::Object(obj) = value else {
+        return value;
+    };
+
+    let mut res = Map::new();
+
+    for (key, value) in obj {
+        match t.field_by_json_name(&key) {
+            Some((field, _)) => {
+                let value = canonicalize_type(model, &field.typ, value);
+                let name = field.json_name();
+                // If both the alias and the new name are present the new name wins
+                if key == name || !res.contains_key(&name) {
+                    res.insert(name, value);
+                }
+            }
+            None => {
+                res.insert(key, value);
+            }
+        }
+    }
+
+    Value::Object(res)
+}
+
+fn canonicalize_union(model: &Model, t: &model::Union, value: Value) -> Value {
+    let Some(property) = t.discriminator.property() else {
+        return match resolve_untagged_variant(model, t, &value) {
+            Some(variant) => canonicalize_type_def(model, &variant.id, value),
+            None => value,
+        };
+    };
+
+    match value {
+        Value::String(kind) if t.from_string => match t.variant_by_tag(&kind) {
+            Some((variant, _)) => Value::String(variant.id.name().to_string()),
+            None => Value::String(kind),
+        },
+        Value::Object(mut obj) => {
+            let Some(variant) = obj
+                .get(property)
+                .and_then(Value::as_str)
+                .and_then(|tag| t.variant_by_tag(tag))
+                .map(|(variant, _)| variant)
+            else {
+                return Value::Object(obj);
+            };
+
+            obj.insert(
+                property.to_string(),
+                Value::String(variant.tag.clone().unwrap()),
+            );
+
+            canonicalize_type_def(model, &variant.id, Value::Object(obj))
+        }
+        value => value,
+    }
+}
+
+fn canonicalize_type(model: &Model, typ: &Type, value: Value) -> Value {
+    match typ {
+        Type::Custom(id) => canonicalize_type_def(model, id, value),
+        Type::Array(arr) => match value {
+            Value::Array(items) => Value::Array(
+                items
+                    .into_iter()
+                    .map(|item| canonicalize_type(model, &arr.item_type, item))
+                    .collect(),
+            ),
+            value => value,
+        },
+        _ => value,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
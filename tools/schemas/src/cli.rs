@@ -19,6 +19,17 @@ pub struct Cli {
 pub enum Command {
     Lint(Lint),
     Codegen(Codegen),
+    Validate(Validate),
+    Canonicalize(Canonicalize),
+    Hash(Hash),
+    Fuzz(Fuzz),
+    Diff(Diff),
+    Apply(Apply),
+    Resolve(Resolve),
+    Check(Check),
+    Graph(Graph),
+    Interpolate(Interpolate),
+    Serve(Serve),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -41,10 +52,242 @@ pub enum CodegenLang {
     FlatbuffersSchema,
     Markdown,
     MermaidErd,
+    Openapi,
+    ProtocolAsyncapi,
+    ProtocolOpenapi,
     RustDtos,
+    RustFlatbuffersViews,
     RustGraphql,
+    RustProptest,
     RustSerde,
     RustSerdeFlatbuffers,
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Validate manifests against the schemas
+#[derive(Debug, clap::Args)]
+pub struct Validate {
+    #[arg(required = true)]
+    pub paths: Vec<PathBuf>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Rewrite a manifest replacing deprecated names with the current ones
+#[derive(Debug, clap::Args)]
+pub struct Canonicalize {
+    #[arg()]
+    pub path: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Compute the content hash of a value of a hashable type
+#[derive(Debug, clap::Args)]
+pub struct Hash {
+    /// Name or schema ID of the type, e.g. `MetadataBlock`
+    #[arg(long = "type")]
+    pub typ: String,
+
+    /// Print the canonical encoding in hex instead of the hash
+    #[arg(long)]
+    pub bytes: bool,
+
+    #[arg()]
+    pub path: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Generate a `cargo fuzz` crate for the decoders of untrusted inputs
+#[derive(Debug, clap::Args)]
+pub struct Fuzz {
+    /// Name of the crate that includes the generated Rust code
+    #[arg(long, default_value = "odf-metadata")]
+    pub crate_name: String,
+
+    /// Path to that crate relative to the output directory
+    #[arg(long, default_value = "..")]
+    pub crate_path: PathBuf,
+
+    /// Example manifests to seed the corpora of resource targets with
+    #[arg(long, default_value = "examples/")]
+    pub examples_dir: PathBuf,
+
+    /// Golden vectors to seed the corpora of flatbuffers targets with
+    #[arg(long, default_value = "tools/schemas/tests/vectors/")]
+    pub vectors_dir: PathBuf,
+
+    #[arg()]
+    pub out_dir: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Show structural differences between two versions of a manifest
+#[derive(Debug, clap::Args)]
+pub struct Diff {
+    /// Print an RFC 6902 JSON Patch instead of the summary
+    #[arg(long)]
+    pub json_patch: bool,
+
+    #[arg()]
+    pub old: PathBuf,
+
+    #[arg()]
+    pub new: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Merge a resource manifest into the live state of the resource
+#[derive(Debug, clap::Args)]
+pub struct Apply {
+    /// Canonical live state of the resource, missing if the resource doesn't exist yet
+    #[arg(long)]
+    pub live: Option<PathBuf>,
+
+    /// Manifest applied previously, missing on the first apply
+    #[arg(long)]
+    pub last_applied: Option<PathBuf>,
+
+    /// Overwrite values that were changed by others since the last apply
+    #[arg(long)]
+    pub force: bool,
+
+    /// Print the new state instead of writing it into `--live` and the manifest into `--last-applied`
+    #[arg(long)]
+    pub dry_run: bool,
+
+    #[arg()]
+    pub path: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Resolve a resource reference or a selector against a directory of manifests, explaining why each resource matched
+#[derive(Debug, clap::Args)]
+pub struct Resolve {
+    /// Directory to load resource manifests from recursively
+    #[arg(long, default_value = ".")]
+    pub dir: PathBuf,
+
+    /// Account that resources and references without an explicit `account` belong to
+    #[arg(long)]
+    pub account: Option<String>,
+
+    #[arg(long, value_enum, default_value_t = ResolveKind::Ref)]
+    pub kind: ResolveKind,
+
+    /// Type of the resource for references that omit it, e.g. `Dataset`
+    #[arg(long = "type")]
+    pub typ: Option<String>,
+
+    /// Reference in short string form or a YAML object, e.g. `Dataset:bob/bobs-dataset` or `{type: Dataset, name: "%"}`
+    #[arg()]
+    pub value: String,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum ResolveKind {
+    /// `ResourceRef` pointing at a single resource
+    Ref,
+    /// `ValueRef` pointing at a value within a `VariableSet` or a `SecretSet`
+    ValueRef,
+    /// `ResourceSelector` matching any number of resources
+    Selector,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Check that references between the resources of a bundle resolve within it
+#[derive(Debug, clap::Args)]
+pub struct Check {
+    /// Account that resources and references without an explicit `account` belong to
+    #[arg(long)]
+    pub account: Option<String>,
+
+    /// Directory to load resource manifests from recursively
+    #[arg()]
+    pub dir: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Print the order in which the resources of a bundle are applied or the graph of their dependencies
+#[derive(Debug, clap::Args)]
+pub struct Graph {
+    /// Account that resources and references without an explicit `account` belong to
+    #[arg(long)]
+    pub account: Option<String>,
+
+    #[arg(long, value_enum, default_value_t = GraphFormat::CreationOrder)]
+    pub format: GraphFormat,
+
+    /// Directory to load resource manifests from recursively
+    #[arg()]
+    pub dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum GraphFormat {
+    /// Resources in the order they have to be created, dependencies first
+    CreationOrder,
+    /// Resources in the order they have to be deleted, dependents first
+    DeletionOrder,
+    Mermaid,
+    Dot,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Substitute variables and secrets into a manifest, printing it with secrets redacted
+#[derive(Debug, clap::Args)]
+pub struct Interpolate {
+    /// Directory to load `VariableSet` and `SecretSet` manifests from recursively, defaults to the one of the manifest
+    #[arg(long)]
+    pub dir: Option<PathBuf>,
+
+    /// Account that resources and references without an explicit `account` belong to
+    #[arg(long)]
+    pub account: Option<String>,
+
+    /// Value available to templates as `${{ name.path }}`, given as `name=<yaml>`, e.g. `trigger={event: {type: x}}`
+    #[arg(long = "scope")]
+    pub scopes: Vec<String>,
+
+    /// Print secret-derived values instead of redacting them
+    #[arg(long)]
+    pub show_secrets: bool,
+
+    #[arg()]
+    pub path: PathBuf,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Serve the resource API over HTTP from a local directory, for testing controllers without a node
+#[derive(Debug, clap::Args)]
+pub struct Serve {
+    /// Directory to persist resources in, they are only kept in memory when omitted
+    #[arg(long)]
+    pub dir: Option<PathBuf>,
+
+    /// Address to listen on
+    #[arg(long, default_value = "127.0.0.1:8080")]
+    pub listen: String,
+
+    /// `AccountHandle` of the auth subject as a YAML object
+    #[arg(
+        long,
+        default_value = "{id: 00000000-0000-4000-8000-000000000000, did: 'did:odf:fed0100000000000000000000000000000000000000000000000000000000000000', name: local}"
+    )]
+    pub account: String,
+
+    /// Directory of manifests to apply recursively on start, in the order of their dependencies
+    #[arg(long)]
+    pub apply: Option<PathBuf>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
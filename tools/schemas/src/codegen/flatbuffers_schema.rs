@@ -4,7 +4,7 @@ use crate::json_schema::{self, CodegenHint, CodegenLanguage, FlatbuffersMapForma
 use crate::model;
 use crate::utils::indent_writer::IndentWriter;
 use std::borrow::Cow;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
 use std::io::Write;
 use std::path::PathBuf;
 
@@ -41,8 +41,16 @@ fn render_impl(
     model: model::Model,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
+    // Types with names that clash across contexts are placed into context namespaces along with their wrappers
+    let ambiguous: BTreeSet<String> = model
+        .ambiguous_names()
+        .into_iter()
+        .flat_map(|n| [format!("{n}Wrapper"), format!("{n}Root"), n])
+        .collect();
+
     let (model, wrappers) = wrap_union_arrays(model);
     let (model, roots) = wrap_root_unions_with_tables(model);
+    let (model, instances) = monomorphize_generic_structs(model);
 
     writeln!(w, "{}", PREAMBLE)?;
 
@@ -60,7 +68,8 @@ fn render_impl(
             writeln!(w, "// {}", typ.id().join(""))?;
             render_description(typ.description(), None, None, w)?;
             writeln!(w, "//")?;
-            writeln!(w, "// Schema: {}", typ.id().schema_id())?;
+            let schema_id = instances.get(typ.id()).unwrap_or(typ.id()).schema_id();
+            writeln!(w, "// Schema: {schema_id}")?;
             writeln!(
                 w,
                 "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
@@ -68,19 +77,61 @@ fn render_impl(
             writeln!(w, "")?;
         }
 
+        let namespaced = ambiguous.contains(typ.id().join("").as_ref());
+        if namespaced {
+            writeln!(w, "namespace {};", typ.id().context())?;
+            writeln!(w)?;
+        }
+
         match &typ {
-            model::TypeDefinition::Struct(t) => render_struct(t, &model, w)?,
-            model::TypeDefinition::Union(t) => render_union(t, w)?,
-            model::TypeDefinition::Enum(t) => render_enum(t, w)?,
-            model::TypeDefinition::Map(t) => render_map(t, w)?,
+            model::TypeDefinition::Struct(t) => render_struct(t, &model, &ambiguous, w)?,
+            model::TypeDefinition::Union(t) => render_union(t, &model, &ambiguous, w)?,
+            model::TypeDefinition::Enum(t) => render_enum(t, &ambiguous, w)?,
+            model::TypeDefinition::Map(t) => render_map(t, &ambiguous, w)?,
         }
         writeln!(w, "")?;
+
+        if namespaced {
+            writeln!(w, "namespace;")?;
+            writeln!(w)?;
+        }
     }
     Ok(())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Flatbuffers has no generics, so we emit a table per concrete instantiation of a bounded generic struct,
+/// e.g. `ResourceDatasetSpec` for `Resource<DatasetSpec>`. The type-erased version is still emitted for
+/// parameters that cannot be monomorphized.
+fn monomorphize_generic_structs(
+    mut model: model::Model,
+) -> (model::Model, BTreeMap<model::TypeId, model::TypeId>) {
+    let mut instances = BTreeMap::new();
+
+    let generic_structs: Vec<_> = model
+        .types
+        .values()
+        .filter_map(|t| match t {
+            model::TypeDefinition::Struct(t) if !t.generics.is_empty() => Some(t.clone()),
+            _ => None,
+        })
+        .collect();
+
+    for t in generic_structs {
+        for inst in model.instantiations(&t) {
+            instances.insert(inst.typ.id.clone(), t.id.clone());
+            model
+                .types
+                .insert(inst.typ.id.clone(), model::TypeDefinition::Struct(inst.typ));
+        }
+    }
+
+    (model, instances)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 // TODO: Can't find a related issue, is this a general Fb limitation or just of rust codegen?
 /// Flatbuffers cannot directly store union types in arrays. To solve this we introduce special wrapper table types.
 fn wrap_union_arrays(model: model::Model) -> (model::Model, Vec<model::TypeId>) {
@@ -124,10 +175,16 @@ fn wrap_union_arrays(model: model::Model) -> (model::Model, Vec<model::TypeId>)
                             constant: None,
                             examples: None,
                             deprecated: false,
+                            aliases: Vec::new(),
+                            read_only: false,
+                            target_type: None,
+                            templatable: false,
+                            sensitive: false,
                             codegen_hints: Default::default(),
                         },
                     )]),
                     generics: Vec::new(),
+                    canonical_type: None,
                     description: String::new(),
                     from_string: false,
                     codegen_hints: Default::default(),
@@ -216,10 +273,16 @@ fn wrap_root_unions_with_tables(mut model: model::Model) -> (model::Model, HashS
                     constant: None,
                     examples: None,
                     deprecated: false,
+                    aliases: Vec::new(),
+                    read_only: false,
+                    target_type: None,
+                    templatable: false,
+                    sensitive: false,
                     codegen_hints: Default::default(),
                 },
             )]),
             generics: Vec::new(),
+            canonical_type: None,
             description: String::new(),
             from_string: false,
             codegen_hints: Default::default(),
@@ -275,7 +338,7 @@ fn in_dependency_order_rec(
 
             for variant in &type_union.variants {
                 in_dependency_order_rec_t(
-                    &model::Type::Custom(variant.clone()),
+                    &model::Type::Custom(variant.id.clone()),
                     model,
                     visited,
                     res,
@@ -315,6 +378,7 @@ fn in_dependency_order_rec_t(
 fn render_struct(
     typ: &model::Struct,
     model: &model::Model,
+    ambiguous: &BTreeSet<String>,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
     let fields_with_tags = allocate_struct_field_ids(typ, model);
@@ -357,6 +421,9 @@ fn render_struct(
             };
 
             let mut attributes = Vec::new();
+            if is_json(&field.typ) {
+                attributes.push("json".to_string());
+            }
             if let Some(tag) = id {
                 let hint = match &field.typ {
                     model::Type::Custom(fid) => match &model.types[fid] {
@@ -380,7 +447,7 @@ fn render_struct(
                 i,
                 "{}: {}{}{};",
                 format_ident(&field.name),
-                format_type(&field.typ),
+                format_type(&field.typ, ambiguous),
                 optionality_modifier,
                 attributes,
             )?;
@@ -395,13 +462,42 @@ fn render_struct(
 
 fn render_union(
     typ: &model::Union,
+    model: &model::Model,
+    ambiguous: &BTreeSet<String>,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
+    // Union members must be tables, which tagged variants are guaranteed to be
+    if let Some(variant) = typ.variants.iter().find(|v| {
+        !matches!(
+            model.types.get(&v.id),
+            Some(model::TypeDefinition::Struct(_) | model::TypeDefinition::Map(_))
+        )
+    }) {
+        return Err(std::io::Error::other(format!(
+            "Union {} can't be represented in flatbuffers: variant {} is not a table",
+            typ.id.join("::"),
+            variant.id.join("::")
+        )));
+    }
+
+    // Variants are identified by their position in binary form, so serialized tags only go into the comments
+    if let Some(discriminator) = typ.discriminator_description() {
+        writeln!(w, "// {discriminator}")?;
+    }
     writeln!(w, "union {} {{", typ.id.join(""))?;
     {
         let mut i = w.indent();
         for variant in &typ.variants {
-            writeln!(i, "{},", variant.join(""))?;
+            if variant.has_custom_tag() {
+                writeln!(i, "// Tagged as `{}`", variant.tag.as_deref().unwrap())?;
+            }
+            let name = variant.id.join("");
+            if ambiguous.contains(name.as_ref()) {
+                // Alias keeps the discriminant name free of the namespace
+                writeln!(i, "{name}: {},", format_type_name(&variant.id, ambiguous))?;
+            } else {
+                writeln!(i, "{name},")?;
+            }
         }
     }
     writeln!(w, "}}")?;
@@ -413,13 +509,14 @@ fn render_union(
 
 fn render_enum(
     typ: &model::Enum,
+    ambiguous: &BTreeSet<String>,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
     writeln!(
         w,
         "enum {}: {} {{",
         typ.id.join(""),
-        format_type(&typ.format)
+        format_type(&typ.format, ambiguous)
     )?;
     {
         let mut i = w.indent();
@@ -436,21 +533,23 @@ fn render_enum(
 
 fn render_map(
     typ: &model::Map,
+    ambiguous: &BTreeSet<String>,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
     match typ.get_hint::<FlatbuffersMapFormat>(CodegenLanguage::Flatbuffers, CodegenHint::MapFormat)
     {
-        None => render_map_with_entry_table(typ, w),
+        None => render_map_with_entry_table(typ, ambiguous, w),
         Some(FlatbuffersMapFormat::JsonEncodedString) => render_map_json_encoded_string(typ, w),
     }
 }
 
 fn render_map_with_entry_table(
     typ: &model::Map,
+    ambiguous: &BTreeSet<String>,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
     let name = typ.id.join("");
-    let value_type = format_type(&typ.value_type);
+    let value_type = format_type(&typ.value_type, ambiguous);
 
     writeln!(w, "table {name}Entry {{")?;
     {
@@ -460,7 +559,12 @@ fn render_map_with_entry_table(
             model::Type::AnyJson => writeln!(i, "// JSON encoded")?,
             _ => (),
         }
-        writeln!(i, "value: {value_type};")?;
+        let attributes = if is_json(&typ.value_type) {
+            " (json)"
+        } else {
+            ""
+        };
+        writeln!(i, "value: {value_type}{attributes};")?;
     }
     writeln!(w, "}}")?;
 
@@ -497,7 +601,16 @@ fn format_ident(name: &str) -> Cow<'_, str> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn format_type(typ: &model::Type) -> String {
+fn format_type_name(id: &model::TypeId, ambiguous: &BTreeSet<String>) -> String {
+    let name = id.join("");
+    if ambiguous.contains(name.as_ref()) {
+        format!("{}.{name}", id.context())
+    } else {
+        name.into_owned()
+    }
+}
+
+fn format_type(typ: &model::Type, ambiguous: &BTreeSet<String>) -> String {
     match typ {
         model::Type::Boolean => format!("bool"),
         model::Type::Int8 => format!("byte"),
@@ -535,9 +648,19 @@ fn format_type(typ: &model::Type) -> String {
 
         model::Type::Flatbuffers => format!("[ubyte]"),
         model::Type::Generic(_) => format!("[ubyte]"),
-        model::Type::Array(t) => format!("[{}]", format_type(&t.item_type)),
-        model::Type::Custom(name) => name.join("").to_string(),
-        model::Type::AnyJson => format!("string (json)"),
+        model::Type::Array(t) => format!("[{}]", format_type(&t.item_type, ambiguous)),
+        model::Type::Custom(id) => format_type_name(id, ambiguous),
+        model::Type::AnyJson => format!("string"),
+    }
+}
+
+/// Whether values are stored as JSON encoded strings, which is marked by an attribute of the field as flatbuffers
+/// don't allow attributes on vector items
+fn is_json(typ: &model::Type) -> bool {
+    match typ {
+        model::Type::AnyJson => true,
+        model::Type::Array(t) => is_json(&t.item_type),
+        _ => false,
     }
 }
 
@@ -581,6 +704,28 @@ struct FieldWithId {
     deprecated: bool,
 }
 
+/// Flatbuffers IDs of struct fields in order of declaration. For unions this is the ID of the value, while the type
+/// discriminant takes the preceding one.
+pub(crate) fn struct_field_ids(typ: &model::Struct, model: &model::Model) -> Vec<u32> {
+    let mut next_id = 0;
+    allocate_struct_field_ids(typ, model)
+        .into_iter()
+        .filter_map(|f| {
+            let slots_per_field = match &f.field.typ {
+                model::Type::Custom(fid) => match &model.types[fid] {
+                    model::TypeDefinition::Union(_) => 2,
+                    _ => 1,
+                },
+                _ => 1,
+            };
+            let id = f.id.unwrap_or(next_id + slots_per_field - 1);
+            next_id = id + 1;
+            // Dummy fields only reserve IDs
+            (!f.deprecated).then_some(id)
+        })
+        .collect()
+}
+
 /// This process decides what tag should each schema field have. There are a few caveats:
 /// - Union type fields take up two tags, one for type another for value (see https://flatbuffers.dev/schema/#attributes).
 /// - All tags must be sequential and start with 0. In light of this we have to produce dummy fields in cases when explicit tag is used with a gap.
@@ -640,6 +785,11 @@ fn allocate_struct_field_ids(typ: &model::Struct, model: &model::Model) -> Vec<F
                 explicit_tag: None,
                 // Marking dummy fields as deprecated ensures they cannot be assigned or read by accident
                 deprecated: true,
+                aliases: Vec::new(),
+                read_only: false,
+                target_type: None,
+                templatable: false,
+                sensitive: false,
                 codegen_hints: Default::default(),
             };
 
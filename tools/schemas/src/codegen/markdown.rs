@@ -1,3 +1,4 @@
+use std::collections::BTreeSet;
 use std::path::Path;
 
 use crate::model;
@@ -7,7 +8,9 @@ use convert_case::{Case, Casing};
 
 pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
     let lvl = 4;
-    render_toc(&model, w)?;
+    let ambiguous = model.ambiguous_names();
+
+    render_toc(&model, &ambiguous, w)?;
 
     writeln!(w)?;
 
@@ -15,7 +18,7 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
         model.types.values().map(|t| t.id().context()).collect();
 
     for context in contexts {
-        render_section(&model, context, lvl, w)?;
+        render_section(&model, &ambiguous, context, lvl, w)?;
     }
 
     Ok(())
@@ -27,8 +30,14 @@ fn section_id(name: &str) -> String {
     format!("reference-{}", name.to_lowercase().replace(" ", "-"))
 }
 
-fn schema_id(name: &str) -> String {
-    format!("{}-schema", name.to_lowercase().replace("::", "-"))
+// Anchors of types whose names clash across contexts are qualified with a context
+fn schema_id(id: &model::TypeId, ambiguous: &BTreeSet<String>) -> String {
+    let name = id.join("::").to_lowercase().replace("::", "-");
+    if ambiguous.contains(id.join("").as_ref()) {
+        format!("{}-{name}-schema", id.context())
+    } else {
+        format!("{name}-schema")
+    }
 }
 
 fn types_by_context<'a>(model: &'a model::Model, context: &str) -> Vec<&'a model::TypeDefinition> {
@@ -62,12 +71,16 @@ fn render_table(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn render_toc(model: &model::Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+fn render_toc(
+    model: &model::Model,
+    ambiguous: &BTreeSet<String>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
     let contexts: std::collections::BTreeSet<&str> =
         model.types.values().map(|t| t.id().context()).collect();
 
     for context in contexts {
-        render_toc_section(context, model, w)?;
+        render_toc_section(context, model, ambiguous, w)?;
     }
 
     Ok(())
@@ -76,6 +89,7 @@ fn render_toc(model: &model::Model, w: &mut dyn std::io::Write) -> Result<(), st
 fn render_toc_section(
     context: &str,
     model: &model::Model,
+    ambiguous: &BTreeSet<String>,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     let id = section_id(context);
@@ -85,7 +99,7 @@ fn render_toc_section(
             continue;
         }
         let name = &typ.id().name();
-        let id = schema_id(&name);
+        let id = schema_id(typ.id(), ambiguous);
         writeln!(w, "  - [{name}](#{id})")?;
     }
     Ok(())
@@ -95,13 +109,14 @@ fn render_toc_section(
 
 fn render_section(
     model: &model::Model,
+    ambiguous: &BTreeSet<String>,
     context: &str,
     lvl: usize,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     render_header(context, None, lvl, w)?;
     for typ in types_by_context(model, context) {
-        render_type(typ, lvl + 1, model, w)?;
+        render_type(typ, lvl + 1, model, ambiguous, w)?;
         writeln!(w)?;
     }
     Ok(())
@@ -128,13 +143,14 @@ fn render_type(
     typ: &model::TypeDefinition,
     lvl: usize,
     model: &model::Model,
+    ambiguous: &BTreeSet<String>,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     match typ {
-        model::TypeDefinition::Struct(t) => render_struct(t, lvl, w)?,
-        model::TypeDefinition::Union(t) => render_union(t, lvl, model, w)?,
-        model::TypeDefinition::Enum(t) => render_enum(t, lvl, w)?,
-        model::TypeDefinition::Map(t) => render_map(t, lvl, w)?,
+        model::TypeDefinition::Struct(t) => render_struct(t, lvl, ambiguous, w)?,
+        model::TypeDefinition::Union(t) => render_union(t, lvl, model, ambiguous, w)?,
+        model::TypeDefinition::Enum(t) => render_enum(t, lvl, ambiguous, w)?,
+        model::TypeDefinition::Map(t) => render_map(t, lvl, ambiguous, w)?,
     }
     Ok(())
 }
@@ -158,13 +174,23 @@ fn render_schema_links(path: &Path, w: &mut dyn std::io::Write) -> Result<(), st
 fn render_struct(
     typ: &model::Struct,
     lvl: usize,
+    ambiguous: &BTreeSet<String>,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     let name = typ.id.join("::");
-    render_header(name.as_ref(), Some(schema_id(name.as_ref())), lvl, w)?;
+    render_header(name.as_ref(), Some(schema_id(&typ.id, ambiguous)), lvl, w)?;
     writeln!(w, "{}", typ.description)?;
     writeln!(w)?;
 
+    if let Some(hashable) = typ.hashable() {
+        writeln!(
+            w,
+            "Values are identified by the SHA3-256 hash of their canonical flatbuffers encoding wrapped into a [Manifest](#manifest-schema) of kind `{:#x}` and version `{}`.",
+            hashable.kind, hashable.version
+        )?;
+        writeln!(w)?;
+    }
+
     if !typ.fields.is_empty() {
         render_table(
             vec!["Property", "Type", "Required", "Format", "Description"],
@@ -178,9 +204,19 @@ fn render_struct(
                         description += format!("\n\nDefault: {default}").as_str();
                     }
 
+                    if !f.aliases.is_empty() {
+                        description +=
+                            format!("\n\nFormerly: {}", format_aliases(&f.aliases)).as_str();
+                    }
+
+                    if f.sensitive {
+                        description +=
+                            "\n\nSensitive: redacted in logs and not exposed via GraphQL";
+                    }
+
                     vec![
                         format!("`{}`", f.name.to_case(Case::Camel)),
-                        as_json_type(&f.typ),
+                        as_json_type(&f.typ, ambiguous),
                         if f.optional {
                             String::new()
                         } else {
@@ -206,33 +242,65 @@ fn render_union(
     typ: &model::Union,
     lvl: usize,
     model: &model::Model,
+    ambiguous: &BTreeSet<String>,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     let name = typ.id.join("::");
-    render_header(name.as_ref(), Some(schema_id(name.as_ref())), lvl, w)?;
+    render_header(name.as_ref(), Some(schema_id(&typ.id, ambiguous)), lvl, w)?;
     writeln!(w, "{}", typ.description)?;
     writeln!(w)?;
 
+    if let Some(discriminator) = typ.discriminator_description() {
+        writeln!(w, "{discriminator}")?;
+        writeln!(w)?;
+    }
+
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "Readers must preserve variants they don't recognize, as newer versions of the specification may add them."
+        )?;
+        writeln!(w)?;
+    }
+
+    // Only show the tags column when it says something the type names don't
+    let show_tags = typ.variants.iter().any(|v| v.has_custom_tag());
+
+    let (header, align) = if show_tags {
+        (
+            vec!["Union Type", "Tag", "Description"],
+            vec![":---:", ":---:", "---"],
+        )
+    } else {
+        (vec!["Union Type", "Description"], vec![":---:", "---"])
+    };
+
     render_table(
-        vec!["Union Type", "Description"],
-        vec![":---:", "---"],
+        header,
+        align,
         typ.variants
             .iter()
             .map(|v| {
-                model
+                let t = model
                     .types
-                    .get(v)
-                    .expect(&format!("Expected to find type {}", v.join("::")))
-            })
-            .map(|t| {
-                vec![
-                    format!(
-                        "[{}](#{})",
-                        t.id().join("::"),
-                        schema_id(t.id().join("::").as_ref())
-                    ),
-                    t.description().to_string(),
-                ]
+                    .get(&v.id)
+                    .expect(&format!("Expected to find type {}", v.id.join("::")));
+
+                let mut description = t.description().to_string();
+                if !v.aliases.is_empty() {
+                    description += format!("\n\nFormerly: {}", format_aliases(&v.aliases)).as_str();
+                }
+
+                let mut row = vec![format!(
+                    "[{}](#{})",
+                    t.id().join("::"),
+                    schema_id(t.id(), ambiguous)
+                )];
+                if show_tags {
+                    row.push(format!("`{}`", v.tag.as_deref().unwrap()));
+                }
+                row.push(description);
+                row
             })
             .collect(),
         w,
@@ -247,13 +315,22 @@ fn render_union(
 fn render_enum(
     typ: &model::Enum,
     lvl: usize,
+    ambiguous: &BTreeSet<String>,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     let name = typ.id.join("::");
-    render_header(name.as_ref(), Some(schema_id(name.as_ref())), lvl, w)?;
+    render_header(name.as_ref(), Some(schema_id(&typ.id, ambiguous)), lvl, w)?;
     writeln!(w, "{}", typ.description)?;
     writeln!(w)?;
 
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "Readers must preserve values they don't recognize, as newer versions of the specification may add them."
+        )?;
+        writeln!(w)?;
+    }
+
     render_table(
         vec!["Enum Value"],
         vec![":---:"],
@@ -270,10 +347,11 @@ fn render_enum(
 fn render_map(
     typ: &model::Map,
     lvl: usize,
+    ambiguous: &BTreeSet<String>,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     let name = typ.id.join("::");
-    render_header(name.as_ref(), Some(schema_id(name.as_ref())), lvl, w)?;
+    render_header(name.as_ref(), Some(schema_id(&typ.id, ambiguous)), lvl, w)?;
     writeln!(w, "{}", typ.description)?;
     writeln!(w)?;
 
@@ -328,7 +406,16 @@ fn render_map(
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
-fn as_json_type(typ: &model::Type) -> String {
+
+fn format_aliases(aliases: &[String]) -> String {
+    aliases
+        .iter()
+        .map(|a| format!("`{a}`"))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn as_json_type(typ: &model::Type, ambiguous: &BTreeSet<String>) -> String {
     match typ {
         model::Type::Boolean => format!("`boolean`"),
         model::Type::Int8
@@ -361,9 +448,9 @@ fn as_json_type(typ: &model::Type) -> String {
         | model::Type::ResourceName
         | model::Type::String => format!("`string`"),
         model::Type::Generic(_) => format!("`object`"),
-        model::Type::Array(t) => format!("array({})", as_json_type(&*t.item_type)),
+        model::Type::Array(t) => format!("array({})", as_json_type(&t.item_type, ambiguous)),
         model::Type::Custom(t) => {
-            format!("[{}](#{})", t.join("::"), schema_id(t.join("::").as_ref()))
+            format!("[{}](#{})", t.join("::"), schema_id(t, ambiguous))
         }
         model::Type::AnyJson => format!("`any`"),
     }
@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use crate::model::{self, Type, TypeDefinition, TypeId};
 
@@ -10,7 +10,7 @@ const EXCLUDED_CONTEXTS: &[&str] = &["metaschemas"];
 const COLLAPSED_UNIONS: &[&str] = &["DataType", "ReadStep"];
 
 // One fill color per context (background, keeping text black)
-const CONTEXT_COLORS: &[(&str, &str)] = &[
+pub(crate) const CONTEXT_COLORS: &[(&str, &str)] = &[
     ("auth", "#f0e0ff"),
     ("config", "#fff0b3"),
     ("data", "#d0f0d0"),
@@ -19,6 +19,7 @@ const CONTEXT_COLORS: &[(&str, &str)] = &[
     ("event", "#e0f7fa"),
     ("flow", "#ffd6e0"),
     ("legacy", "#e0e0e0"),
+    ("protocol", "#fde2e4"),
     ("resource", "#f5f5f5"),
     ("sink", "#ffe8cc"),
     ("source", "#d6f0e8"),
@@ -57,6 +58,9 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
     }
     writeln!(w)?;
 
+    // Node IDs are global, so names that clash across contexts get qualified
+    let ambiguous = model.ambiguous_names();
+
     // Nodes grouped by context in subgraph boxes
     let mut by_context: BTreeMap<&str, Vec<&TypeId>> = BTreeMap::new();
     for t in &types {
@@ -67,14 +71,14 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
         writeln!(w, "  subgraph {context}[\"{context}\"]")?;
         let mut node_ids = Vec::new();
         for id in ids {
-            let node_id = id.join("_");
+            let node_id = format_node_id(id, &ambiguous);
             let label = id.join("::");
             if node_id == label {
                 writeln!(w, "    {node_id}")?;
             } else {
                 writeln!(w, "    {node_id}[\"{label}\"]")?;
             }
-            node_ids.push(node_id.into_owned());
+            node_ids.push(node_id);
         }
         writeln!(w, "  end")?;
         writeln!(w, "  class {} ctx_{context}", node_ids.join(","))?;
@@ -83,18 +87,22 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
 
     // Edges: one per Custom-typed field / array item
     for t in &types {
-        let from = t.id().join("_");
+        let from = format_node_id(t.id(), &ambiguous);
         match t {
             TypeDefinition::Struct(s) => {
                 for (fname, field) in &s.fields {
-                    emit_type_edges(&from, fname, &field.typ, w)?;
+                    emit_type_edges(&from, fname, &field.typ, &ambiguous, w)?;
                 }
             }
             TypeDefinition::Union(u) => {
                 if !COLLAPSED_UNIONS.contains(&u.id.name()) {
-                    for variant_id in &u.variants {
-                        if !EXCLUDED_CONTEXTS.contains(&variant_id.context()) {
-                            writeln!(w, "  {from} -->|\"variant\"| {}", variant_id.join("_"))?;
+                    for variant in &u.variants {
+                        if !EXCLUDED_CONTEXTS.contains(&variant.id.context()) {
+                            writeln!(
+                                w,
+                                "  {from} -->|\"variant\"| {}",
+                                format_node_id(&variant.id, &ambiguous)
+                            )?;
                         }
                     }
                 }
@@ -102,7 +110,11 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
             TypeDefinition::Map(m) => {
                 if let Type::Custom(ref_id) = &m.value_type {
                     if !EXCLUDED_CONTEXTS.contains(&ref_id.context()) {
-                        writeln!(w, "  {from} -->|\"values\"| {}", ref_id.join("_"))?;
+                        writeln!(
+                            w,
+                            "  {from} -->|\"values\"| {}",
+                            format_node_id(ref_id, &ambiguous)
+                        )?;
                     }
                 }
             }
@@ -119,18 +131,33 @@ fn emit_type_edges(
     from: &str,
     field_name: &str,
     typ: &Type,
+    ambiguous: &BTreeSet<String>,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     match typ {
         Type::Custom(ref_id) => {
             if !EXCLUDED_CONTEXTS.contains(&ref_id.context()) {
-                writeln!(w, "  {from} -->|\"{field_name}\"| {}", ref_id.join("_"))?;
+                writeln!(
+                    w,
+                    "  {from} -->|\"{field_name}\"| {}",
+                    format_node_id(ref_id, ambiguous)
+                )?;
             }
         }
         Type::Array(arr) => {
-            emit_type_edges(from, field_name, &arr.item_type, w)?;
+            emit_type_edges(from, field_name, &arr.item_type, ambiguous, w)?;
         }
         _ => {}
     }
     Ok(())
 }
+
+fn format_node_id(id: &TypeId, ambiguous: &BTreeSet<String>) -> String {
+    if ambiguous.contains(id.join("").as_ref()) {
+        format!("{}_{}", id.context(), id.join("_"))
+    } else {
+        id.join("_").into_owned()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -1,8 +1,13 @@
 pub mod flatbuffers_schema;
 pub mod markdown;
 pub mod mermaid_erd;
+pub mod openapi;
+pub mod protocol;
 pub mod rust_common;
 pub mod rust_dtos;
+pub mod rust_flatbuffers_views;
+pub mod rust_fuzz;
 pub mod rust_graphql;
+pub mod rust_proptest;
 pub mod rust_serde;
 pub mod rust_serde_flatbuffers;
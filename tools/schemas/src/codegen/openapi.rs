@@ -0,0 +1,573 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use convert_case::{Case, Casing};
+use serde_json::{Map, Value, json};
+
+use crate::api::{self, Operation, Payload, QueryParam, ResourceApi};
+use crate::model::{self, Discriminator, Model, Struct, Type, TypeDefinition, TypeId};
+use crate::server::EventType;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub(crate) const PREAMBLE: &str = indoc::indoc!(
+    r#"
+    ########################################################################################################################
+    # WARNING: This file is auto-generated from Open Data Fabric Schemas
+    # See: http://opendatafabric.org/
+    ########################################################################################################################
+    "#
+);
+
+const RESOURCE: &str = "https://opendatafabric.org/schemas/resource/v1alpha1/Resource";
+const RESOURCE_STATUS: &str = "https://opendatafabric.org/schemas/resource/v1alpha1/ResourceStatus";
+const RESOURCE_SELECTOR: &str =
+    "https://opendatafabric.org/schemas/resource/v1alpha1/ResourceSelector";
+
+// Hand-written components that don't come from the schemas
+const API_ERROR: &str = "ApiError";
+const EVENT_TYPE: &str = "ResourceEventType";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Renders an OpenAPI 3.1 document of the resource API (see [`crate::api`]) with component schemas of manifests, that
+/// are made of `*Input` types, and of canonical resources, only including the types reachable from the endpoints
+pub fn render(model: Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+    let apis = api::resource_apis(&model);
+    let mut components = Components::new(&model);
+
+    let TypeDefinition::Struct(resource) = &model.types[&type_id(RESOURCE)] else {
+        panic!("Resource must be a struct");
+    };
+    let instantiations = model.instantiations(resource);
+    let status = components.add(&type_id(RESOURCE_STATUS));
+    let selector = components.add(&type_id(RESOURCE_SELECTOR));
+
+    let mut paths = Map::new();
+    let mut tags = BTreeSet::new();
+    for api in &apis {
+        let mut resource = instantiations
+            .iter()
+            .find(|i| i.args == [api.spec.clone()])
+            .unwrap_or_else(|| panic!("Resource is not instantiated with {}", api.spec.join("::")))
+            .typ
+            .clone();
+        let schema = resource.fields.get_mut("$schema").unwrap();
+        schema.constant = Some(json!(api.kind.schema_id().as_str()));
+        schema.description = "Identifies this resource type.".to_string();
+
+        let endpoint = Endpoint {
+            api,
+            name: model_name(&api.kind, &components.ambiguous),
+            manifest: components.add(&api.kind),
+            resource: components.add_struct(&resource),
+            status: status.clone(),
+        };
+        tags.insert(api.kind.context().to_string());
+
+        for op in Operation::ALL {
+            paths
+                .entry(api.route_path(op))
+                .or_insert_with(|| json!({}))
+                .as_object_mut()
+                .unwrap()
+                .insert(op.method().to_lowercase(), endpoint.operation(op));
+        }
+    }
+
+    let mut schemas: Map<_, _> = components.schemas.into_iter().collect();
+    schemas.insert(
+        API_ERROR.to_string(),
+        json!({
+            "type": "object",
+            "description": "Error response of the resource API.",
+            "required": ["status", "message", "details"],
+            "properties": {
+                "status": {
+                    "type": "integer",
+                    "format": "uint16",
+                    "description": "HTTP status code."
+                },
+                "message": {
+                    "type": "string"
+                },
+                "details": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Individual problems, e.g. validation diagnostics or merge conflicts."
+                }
+            }
+        }),
+    );
+    schemas.insert(
+        EVENT_TYPE.to_string(),
+        json!({
+            "type": "string",
+            "description": "Kind of change to a resource streamed by a watch.",
+            "enum": EventType::ALL.iter().map(|t| t.as_str()).collect::<Vec<_>>()
+        }),
+    );
+
+    let mut parameters = Map::new();
+    parameters.insert(
+        "ref".to_string(),
+        json!({
+            "name": "ref",
+            "in": "path",
+            "required": true,
+            "description": "ID, DID, or name of the resource.",
+            "schema": { "type": "string" }
+        }),
+    );
+    for param in [
+        QueryParam::Account,
+        QueryParam::Selector,
+        QueryParam::Watch,
+        QueryParam::Since,
+        QueryParam::Force,
+    ] {
+        let mut value = json!({
+            "name": param.name(),
+            "in": "query",
+            "description": param.description(),
+        });
+        match param {
+            // Selectors are either a short-form string or a struct, that are both valid YAML
+            QueryParam::Selector => {
+                value["content"] = json!({ "application/json": { "schema": selector } });
+            }
+            QueryParam::Account => value["schema"] = json!({ "type": "string" }),
+            QueryParam::Watch | QueryParam::Force => value["schema"] = json!({ "type": "boolean" }),
+            QueryParam::Since => value["schema"] = json!({ "type": "integer", "format": "uint64" }),
+        }
+        parameters.insert(param.name().to_string(), value);
+    }
+
+    let mut statuses: Vec<_> = Operation::ALL
+        .iter()
+        .flat_map(Operation::error_statuses)
+        .collect();
+    statuses.sort();
+    statuses.dedup();
+
+    let mut responses = Map::new();
+    for status in statuses {
+        let (name, description) = error_response(status);
+        responses.insert(
+            name.to_string(),
+            json!({
+                "description": description,
+                "content": { "application/json": { "schema": schema_ref(API_ERROR) } }
+            }),
+        );
+    }
+
+    let document = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "ODF Resource API",
+            "description": "Declarative management of Open Data Fabric resources, see RFC-018. Every resource type supports the same set of operations on manifests authored by users and canonical resources maintained by the node.",
+            "license": {
+                "name": "Apache 2.0",
+                "url": "http://www.apache.org/licenses/LICENSE-2.0.html"
+            },
+            "version": env!("CARGO_PKG_VERSION")
+        },
+        "servers": [{
+            "url": "http://127.0.0.1:8080",
+            "description": "Resource server of schema tooling started by `odf-schemas serve`."
+        }],
+        "tags": tags.into_iter().map(|name| json!({ "name": name })).collect::<Vec<_>>(),
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+            "parameters": parameters,
+            "responses": responses
+        }
+    });
+
+    write!(w, "{PREAMBLE}")?;
+    writeln!(w)?;
+    write!(w, "{}", serde_yaml::to_string(&document).unwrap())?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Endpoint<'a> {
+    api: &'a ResourceApi,
+    /// Component name of the resource type
+    name: String,
+    /// Schema of manifests, e.g. `Dataset`
+    manifest: Value,
+    /// Schema of canonical resources, e.g. `ResourceDatasetSpec`
+    resource: Value,
+    status: Value,
+}
+
+impl Endpoint<'_> {
+    fn operation(&self, op: Operation) -> Value {
+        let kind = &self.name;
+
+        let mut parameters = Vec::new();
+        if op.is_item() {
+            parameters.push(json!({ "$ref": "#/components/parameters/ref" }));
+        }
+        for param in op.query_params() {
+            parameters.push(json!({ "$ref": format!("#/components/parameters/{}", param.name()) }));
+        }
+
+        let mut value = json!({
+            "operationId": match op {
+                Operation::UpdateStatus => format!("update{kind}Status"),
+                _ => format!("{}{kind}", op.name()),
+            },
+            "summary": match op {
+                Operation::List => format!("List {kind} resources or watch their changes"),
+                Operation::Create => format!("Create {kind} resource"),
+                Operation::Apply => format!("Apply {kind} manifest, creating the resource if needed"),
+                Operation::Get => format!("Get {kind} resource"),
+                Operation::Update => format!("Replace desired state of {kind} resource"),
+                Operation::UpdateStatus => format!("Replace status of {kind} resource"),
+                Operation::Delete => format!("Delete {kind} resource"),
+            },
+            "tags": [self.api.kind.context()],
+            "parameters": parameters,
+        });
+
+        if let Some(payload) = op.request() {
+            let schema = self.payload(payload);
+            value["requestBody"] = json!({
+                "required": true,
+                "content": {
+                    "application/json": { "schema": schema },
+                    "application/yaml": { "schema": schema }
+                }
+            });
+        }
+
+        let mut responses = Map::new();
+        let statuses: &[u16] = match op {
+            Operation::Apply => &[200, 201],
+            _ => &[op.success_status()],
+        };
+        for status in statuses {
+            let description = match (op, status) {
+                (Operation::List, _) => {
+                    "Matching resources, or a stream of their changes with `watch=true`"
+                }
+                (_, 201) => "Created resource",
+                (Operation::Get, _) => "Resource",
+                (Operation::UpdateStatus, _) => "Resource with the updated status",
+                (Operation::Delete, _) => "Deleted resource",
+                _ => "Updated resource",
+            };
+            let mut content =
+                json!({ "application/json": { "schema": self.payload(op.response()) } });
+            if op == Operation::List {
+                content["application/x-ndjson"] = json!({
+                    "schema": {
+                        "type": "object",
+                        "description": "Change to a resource, one per line.",
+                        "required": ["type", "sequence", "resource"],
+                        "properties": {
+                            "type": schema_ref(EVENT_TYPE),
+                            "sequence": {
+                                "type": "integer",
+                                "format": "uint64",
+                                "description": "Position of the change that can be passed as `since` to resume the watch."
+                            },
+                            "resource": self.resource
+                        }
+                    }
+                });
+            }
+            responses.insert(
+                status.to_string(),
+                json!({ "description": description, "content": content }),
+            );
+        }
+        for status in op.error_statuses() {
+            let (name, _) = error_response(status);
+            responses.insert(
+                status.to_string(),
+                json!({ "$ref": format!("#/components/responses/{name}") }),
+            );
+        }
+        value["responses"] = Value::Object(responses);
+
+        value
+    }
+
+    fn payload(&self, payload: Payload) -> Value {
+        match payload {
+            Payload::Manifest => self.manifest.clone(),
+            Payload::Status => self.status.clone(),
+            Payload::Resource => self.resource.clone(),
+            Payload::ResourceList => json!({
+                "type": "object",
+                "required": ["items"],
+                "properties": {
+                    "items": { "type": "array", "items": self.resource }
+                }
+            }),
+        }
+    }
+}
+
+fn error_response(status: u16) -> (&'static str, &'static str) {
+    match status {
+        400 => (
+            "BadRequest",
+            "Malformed request, invalid manifest or status, or unresolved references",
+        ),
+        404 => ("NotFound", "Resource, account or endpoint does not exist"),
+        405 => ("MethodNotAllowed", "Endpoint does not support the method"),
+        408 => ("RequestTimeout", "Request was not received in time"),
+        409 => (
+            "Conflict",
+            "Resource with the name already exists, or the apply conflicts with changes made by others",
+        ),
+        410 => (
+            "Gone",
+            "Events after the `since` sequence number are no longer kept, the watch has to start over",
+        ),
+        411 => (
+            "LengthRequired",
+            "Request body does not specify its `Content-Length`",
+        ),
+        413 => ("ContentTooLarge", "Request body is too large"),
+        431 => (
+            "RequestHeaderFieldsTooLarge",
+            "Request line and headers are too large or too many",
+        ),
+        500 => ("InternalServerError", "Change could not be persisted"),
+        503 => ("ServiceUnavailable", "Server has too many connections"),
+        _ => unreachable!(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Component schemas of the added types and all types they refer to, keyed by name
+pub(crate) struct Components<'a> {
+    model: &'a Model,
+    pub(crate) ambiguous: BTreeSet<String>,
+    pub(crate) schemas: BTreeMap<String, Value>,
+}
+
+impl<'a> Components<'a> {
+    pub(crate) fn new(model: &'a Model) -> Self {
+        Self {
+            model,
+            ambiguous: model.ambiguous_names(),
+            schemas: BTreeMap::new(),
+        }
+    }
+
+    /// Adds a type with all types it refers to, returning a reference to its schema
+    pub(crate) fn add(&mut self, id: &TypeId) -> Value {
+        let name = model_name(id, &self.ambiguous);
+        if !self.schemas.contains_key(&name) {
+            // Placeholder breaks cycles of recursive types
+            self.schemas.insert(name.clone(), Value::Null);
+            let schema = match &self.model.types[id] {
+                TypeDefinition::Struct(s) => self.struct_schema(s),
+                TypeDefinition::Union(u) => self.union_schema(u),
+                TypeDefinition::Enum(e) => self.enum_schema(e),
+                TypeDefinition::Map(m) => self.map_schema(m),
+            };
+            self.schemas.insert(name.clone(), schema);
+        }
+        schema_ref(&name)
+    }
+
+    /// Adds a struct that is not in the model, like an instantiation of a generic one
+    fn add_struct(&mut self, s: &Struct) -> Value {
+        let name = model_name(&s.id, &self.ambiguous);
+        let schema = self.struct_schema(s);
+        self.schemas.insert(name.clone(), schema);
+        schema_ref(&name)
+    }
+
+    fn struct_schema(&mut self, s: &Struct) -> Value {
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for field in s.fields.values() {
+            if !field.optional {
+                required.push(field.json_name());
+            }
+            properties.insert(field.json_name(), self.field_schema(field));
+        }
+
+        let mut object = Map::new();
+        object.insert("type".to_string(), json!("object"));
+        if !required.is_empty() {
+            object.insert("required".to_string(), json!(required));
+        }
+        object.insert("properties".to_string(), Value::Object(properties));
+
+        let mut schema = described(&s.description);
+        if s.from_string {
+            schema.insert("oneOf".to_string(), json!([{ "type": "string" }, object]));
+        } else {
+            schema.extend(object);
+        }
+        Value::Object(schema)
+    }
+
+    fn union_schema(&mut self, u: &model::Union) -> Value {
+        let mut variants = Vec::new();
+        if u.from_string {
+            variants.push(json!({ "type": "string" }));
+        }
+        for variant in &u.variants {
+            let schema = self.add(&variant.id);
+            variants.push(match (&u.discriminator, &variant.tag) {
+                (Discriminator::Property(property), Some(tag)) => json!({
+                    "allOf": [
+                        {
+                            "type": "object",
+                            "required": [property],
+                            "properties": { property: { "type": "string", "const": tag } }
+                        },
+                        schema
+                    ]
+                }),
+                _ => schema,
+            });
+        }
+
+        let mut schema = described(&u.description);
+        // Untagged unions pick the first variant that matches
+        let combinator = match u.discriminator {
+            Discriminator::Property(_) => "oneOf",
+            Discriminator::Untagged => "anyOf",
+        };
+        schema.insert(combinator.to_string(), json!(variants));
+        Value::Object(schema)
+    }
+
+    fn enum_schema(&self, e: &model::Enum) -> Value {
+        // Enums are serialized by name, the format only affects the binary encoding
+        let mut schema = described(&e.description);
+        schema.insert("type".to_string(), json!("string"));
+        schema.insert("enum".to_string(), json!(e.variants));
+        Value::Object(schema)
+    }
+
+    fn map_schema(&mut self, m: &model::Map) -> Value {
+        let mut schema = described(&m.description);
+        schema.insert("type".to_string(), json!("object"));
+        if let Some(pattern) = &m.key_pattern {
+            schema.insert("propertyNames".to_string(), json!({ "pattern": pattern }));
+        }
+        schema.insert(
+            "additionalProperties".to_string(),
+            self.type_schema(&m.value_type),
+        );
+        Value::Object(schema)
+    }
+
+    fn field_schema(&mut self, field: &model::Field) -> Value {
+        let mut schema = self.type_schema(&field.typ);
+        let props = schema.as_object_mut().unwrap();
+        if !field.description.is_empty() {
+            props.insert("description".to_string(), json!(field.description));
+        }
+        for validation in &field.validations {
+            match validation {
+                model::Validation::Enum(e) => {
+                    props.insert("enum".to_string(), json!(e.values));
+                }
+            }
+        }
+        if let Some(constant) = &field.constant {
+            props.insert("const".to_string(), constant.clone());
+        }
+        if let Some(default) = &field.default {
+            props.insert("default".to_string(), default.clone());
+        }
+        if let Some(examples) = &field.examples {
+            props.insert("examples".to_string(), json!(examples));
+        }
+        if field.read_only {
+            props.insert("readOnly".to_string(), json!(true));
+        }
+        if field.deprecated {
+            props.insert("deprecated".to_string(), json!(true));
+        }
+        schema
+    }
+
+    pub(crate) fn type_schema(&mut self, typ: &Type) -> Value {
+        let format = match typ {
+            Type::Boolean => return json!({ "type": "boolean" }),
+            Type::Int8 => return json!({ "type": "integer", "format": "int8" }),
+            Type::Int16 => return json!({ "type": "integer", "format": "int16" }),
+            Type::Int32 => return json!({ "type": "integer", "format": "int32" }),
+            Type::Int64 => return json!({ "type": "integer", "format": "int64" }),
+            Type::UInt8 => return json!({ "type": "integer", "format": "uint8" }),
+            Type::UInt16 => return json!({ "type": "integer", "format": "uint16" }),
+            Type::UInt32 => return json!({ "type": "integer", "format": "uint32" }),
+            Type::UInt64 => return json!({ "type": "integer", "format": "uint64" }),
+            Type::String => return json!({ "type": "string" }),
+            Type::ByteSize => "byte-size",
+            Type::DateTime => "date-time",
+            Type::Duration => "duration",
+            Type::Multicodec => "multicodec",
+            Type::Multihash => "multihash",
+            Type::Path => "path",
+            Type::Regex => "regex",
+            Type::Url => "uri",
+            Type::Did => "did",
+            Type::TypeName => "type-name",
+            Type::TypeUri => "type-uri",
+            Type::TypeRef => "type-ref",
+            Type::AccountId => "account-id",
+            Type::AccountName => "account-name",
+            Type::DatasetAlias => "dataset-alias",
+            Type::DatasetId => "dataset-id",
+            Type::DatasetRef => "dataset-ref",
+            Type::ResourceId => "resource-id",
+            Type::ResourceName => "resource-name",
+            Type::Flatbuffers => "flatbuffers",
+            Type::Generic(name) => panic!("Generic type {name} must be instantiated"),
+            Type::Array(arr) => {
+                return json!({ "type": "array", "items": self.type_schema(&arr.item_type) });
+            }
+            Type::Custom(id) => return self.add(id),
+            Type::AnyJson => return json!({}),
+        };
+        json!({ "type": "string", "format": format })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Component name of a type, qualifying names defined in several contexts with the context, e.g. `AuthAccount`
+pub(crate) fn model_name(id: &TypeId, ambiguous: &BTreeSet<String>) -> String {
+    let name = id.join("");
+    if ambiguous.contains(name.as_ref()) {
+        format!("{}{name}", id.context().to_case(Case::Pascal))
+    } else {
+        name.into_owned()
+    }
+}
+
+pub(crate) fn schema_ref(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{name}") })
+}
+
+fn described(description: &str) -> Map<String, Value> {
+    let mut schema = Map::new();
+    if !description.is_empty() {
+        schema.insert("description".to_string(), json!(description));
+    }
+    schema
+}
+
+fn type_id(schema_id: &str) -> TypeId {
+    TypeId::new(crate::json_schema::SchemaId::new(schema_id))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
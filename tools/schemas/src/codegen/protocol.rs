@@ -0,0 +1,276 @@
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value, json};
+
+use super::openapi::{Components, PREAMBLE, schema_ref};
+use crate::json_schema::{AsyncapiOperation, CodegenHint, CodegenLanguage};
+use crate::model::{MetaType, Model, Type, TypeId};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const PROTOCOL_VERSION: &str = "0.1.0";
+
+const DESCRIPTION: &str =
+    "Open Data Fabric (ODF) extended protocol for dataset exchange between remote parties.";
+
+// Value of the `Sec-WebSocket-Protocol` header that the HTTP endpoints upgrade to
+const WEBSOCKET_PROTOCOL: &str = "odf/smart-transfer-protocol/v1";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Renders an AsyncAPI 2.5 document of the smart transfer protocol messages exchanged over a WebSocket, with a channel
+/// per [`MetaType::ProtocolMessage`] type
+pub fn render_asyncapi(model: Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+    let mut components = Components::new(&model);
+
+    let mut channels = Map::new();
+    for (channel, (id, operation)) in messages(&model) {
+        let name = id.join("");
+        let prefix = channel.split('/').next().unwrap();
+        channels.insert(
+            channel.clone(),
+            json!({
+                operation.as_str(): {
+                    "operationId": format!("odf.{}", channel.replace('/', ".")),
+                    "summary": model.types[&id].description(),
+                    "security": [{ "jwt": [] }],
+                    "message": {
+                        "messageId": format!("odf.{prefix}.{name}"),
+                        "name": name,
+                        "contentType": "application/json",
+                        "payload": components.add(&id)
+                    }
+                }
+            }),
+        );
+    }
+
+    let document = json!({
+        "asyncapi": "2.5.0",
+        "info": {
+            "title": "ODF Smart Transfer Protocol (asynchronous messages part)",
+            "version": PROTOCOL_VERSION,
+            "description": DESCRIPTION,
+            "license": {
+                "name": "Apache 2.0",
+                "url": "https://www.apache.org/licenses/LICENSE-2.0"
+            }
+        },
+        "channels": channels,
+        "components": {
+            "schemas": components.schemas.into_iter().collect::<Map<_, _>>(),
+            "securitySchemes": {
+                "jwt": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "bearerFormat": "JWT"
+                }
+            }
+        }
+    });
+
+    write!(w, "{PREAMBLE}")?;
+    writeln!(w)?;
+    write!(w, "{}", serde_yaml::to_string(&document).unwrap())?;
+    Ok(())
+}
+
+/// Renders an OpenAPI 3.1 document of the HTTP endpoints of the smart transfer protocol, that serve individual objects
+/// and upgrade connections to the WebSocket protocol described by [`render_asyncapi`]
+pub fn render_openapi(model: Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+    let mut components = Components::new(&model);
+
+    let mut multihash = components.type_schema(&Type::Multihash);
+    multihash["examples"] = json!(["zW1qJPmDvBxGS9GeC7PFseSCy7koHjvurUmisf1VWscY3AX"]);
+
+    let mut paths = Map::new();
+    for (path, operation_id, description, param, response) in [
+        (
+            "/refs/{reference}",
+            "rest.dataset.get_ref",
+            "Fetch the hash of specified reference related to the current dataset",
+            "reference",
+            "Successfully fetched hash of the dataset reference",
+        ),
+        (
+            "/blocks/{blockHash}",
+            "rest.dataset.get_block",
+            "Fetch the content of the block with the specified hash",
+            "blockHash",
+            "Successfully fetched block contents",
+        ),
+        (
+            "/data/{physicalHash}",
+            "rest.dataset.get_data",
+            "Fetch the content of the data object with the given physical hash",
+            "physicalHash",
+            "Successfully fetched data object contents",
+        ),
+        (
+            "/checkpoints/{physicalHash}",
+            "rest.dataset.get_checkpoint",
+            "Fetch the content of the checkpoint object with the given physical hash",
+            "physicalHash",
+            "Successfully fetched checkpoint object contents",
+        ),
+    ] {
+        // References resolve to a hash, while hashes to the raw object
+        let content = if param == "reference" {
+            json!({ "text/plain": { "schema": schema_ref("MultiHash") } })
+        } else {
+            json!({ "application/octet-stream": { "schema": { "type": "string", "format": "binary" } } })
+        };
+        paths.insert(
+            path.to_string(),
+            json!({
+                "get": {
+                    "operationId": operation_id,
+                    "description": description,
+                    "security": [{ "odf_access_token": [] }],
+                    "parameters": [{ "$ref": format!("#/components/parameters/{param}") }],
+                    "responses": {
+                        "200": { "description": response, "content": content },
+                        "default": { "$ref": "#/components/responses/Error" }
+                    }
+                }
+            }),
+        );
+    }
+
+    let messages = messages(&model);
+    for flow in ["pull", "push"] {
+        let channels: Vec<_> = messages
+            .keys()
+            .filter(|c| c.starts_with(&format!("dataset/{flow}/")))
+            .map(|c| format!("`{c}`"))
+            .collect();
+        paths.insert(
+            format!("/{flow}"),
+            json!({
+                "get": {
+                    "operationId": format!("rest.dataset.{flow}"),
+                    "description": format!(
+                        "Requests upgrading to Websocket protocol to implement smart {flow} flow over channels {}",
+                        channels.join(", ")
+                    ),
+                    "security": [{ "odf_access_token": [] }],
+                    "parameters": [
+                        { "$ref": "#/components/parameters/upgradeToWebSocket" },
+                        { "$ref": "#/components/parameters/connectionUpgrade" },
+                        { "$ref": "#/components/parameters/webSocketProtocol" },
+                        { "$ref": "#/components/parameters/webSocketProtocolVersion" },
+                        { "$ref": "#/components/parameters/odfSmartTransferProtocolVersion" }
+                    ],
+                    "responses": {
+                        "101": { "$ref": "#/components/responses/SwitchedToWebSocketResponse" },
+                        "default": { "$ref": "#/components/responses/Error" }
+                    }
+                }
+            }),
+        );
+    }
+
+    let header = |name: &str, schema: Value, required: bool| json!({ "in": "header", "name": name, "schema": schema, "required": required });
+    let upgrade = json!({ "type": "string", "enum": ["websocket"] });
+    let connection = json!({ "type": "string", "enum": ["Upgrade"] });
+
+    let document = json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "ODF Smart Transfer Protocol (HTTP endpoints part)",
+            "description": format!("{DESCRIPTION} Defines REST API endpoints that enable simple operation and upgrading to asynchronous API protocol."),
+            "license": {
+                "name": "Apache 2.0",
+                "url": "http://www.apache.org/licenses/LICENSE-2.0.html"
+            },
+            "version": PROTOCOL_VERSION
+        },
+        "paths": paths,
+        "components": {
+            "schemas": {
+                "MultiHash": multihash,
+                "OdfWebSocketProtocol": {
+                    "type": "string",
+                    "enum": [WEBSOCKET_PROTOCOL]
+                }
+            },
+            "parameters": {
+                "reference": {
+                    "in": "path",
+                    "name": "reference",
+                    "description": "Name of the reference",
+                    "required": true,
+                    "schema": { "type": "string", "enum": ["head"] }
+                },
+                "blockHash": {
+                    "in": "path",
+                    "name": "blockHash",
+                    "description": "Hash of the requested block",
+                    "required": true,
+                    "schema": schema_ref("MultiHash")
+                },
+                "physicalHash": {
+                    "in": "path",
+                    "name": "physicalHash",
+                    "description": "Physical hash of the requested object file",
+                    "required": true,
+                    "schema": schema_ref("MultiHash")
+                },
+                "upgradeToWebSocket": header("Upgrade", upgrade.clone(), true),
+                "connectionUpgrade": header("Connection", connection.clone(), true),
+                "webSocketProtocol": header("Sec-WebSocket-Protocol", schema_ref("OdfWebSocketProtocol"), true),
+                "webSocketProtocolVersion": header("Sec-WebSocket-Version", json!({ "type": "string", "examples": ["13"] }), false),
+                "odfSmartTransferProtocolVersion": header("x-odf-smtp-version", json!({ "type": "string", "examples": ["1"] }), true)
+            },
+            "responses": {
+                "SwitchedToWebSocketResponse": {
+                    "description": "Confirms accepted switching to the WebSocket protocol",
+                    "headers": {
+                        "Upgrade": { "schema": upgrade, "required": true },
+                        "Connection": { "schema": connection, "required": true },
+                        "Sec-WebSocket-Protocol": { "schema": schema_ref("OdfWebSocketProtocol"), "required": true },
+                        "Sec-WebSocket-Version": { "schema": { "type": "string" }, "required": false }
+                    }
+                },
+                "Error": {
+                    "description": "Request error",
+                    "content": { "application/json": { "schema": { "type": "object" } } }
+                }
+            },
+            "securitySchemes": {
+                "odf_access_token": {
+                    "type": "http",
+                    "scheme": "bearer",
+                    "bearerFormat": "org.odf.access_token"
+                }
+            }
+        }
+    });
+
+    write!(w, "{PREAMBLE}")?;
+    writeln!(w)?;
+    write!(w, "{}", serde_yaml::to_string(&document).unwrap())?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Protocol messages keyed by their channel, see [`crate::model::check_protocol_messages`]
+pub fn messages(model: &Model) -> BTreeMap<String, (TypeId, AsyncapiOperation)> {
+    model
+        .types
+        .values()
+        .filter(|t| matches!(t.metatype(), MetaType::ProtocolMessage))
+        .map(|t| {
+            let channel = t
+                .get_hint(CodegenLanguage::Asyncapi, CodegenHint::Channel)
+                .unwrap_or_else(|| panic!("Message {} has no channel", t.id().join("::")));
+            let operation = t
+                .get_hint(CodegenLanguage::Asyncapi, CodegenHint::Operation)
+                .unwrap_or_else(|| panic!("Message {} has no operation", t.id().join("::")));
+            (channel, (t.id().clone(), operation))
+        })
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -4,19 +4,112 @@ use std::sync::OnceLock;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Names that `flatc` suffixes with `_` in the generated Rust proxies: keywords, primitive types and the names of
+/// methods it generates itself
+const FLATC_RESERVED_WORDS: &[&str] = &[
+    "as",
+    "break",
+    "const",
+    "continue",
+    "crate",
+    "else",
+    "enum",
+    "extern",
+    "false",
+    "fn",
+    "for",
+    "if",
+    "impl",
+    "in",
+    "let",
+    "loop",
+    "match",
+    "mod",
+    "move",
+    "mut",
+    "pub",
+    "ref",
+    "return",
+    "Self",
+    "self",
+    "static",
+    "struct",
+    "super",
+    "trait",
+    "true",
+    "type",
+    "unsafe",
+    "use",
+    "where",
+    "while",
+    "abstract",
+    "alignof",
+    "become",
+    "box",
+    "do",
+    "final",
+    "macro",
+    "offsetof",
+    "override",
+    "priv",
+    "proc",
+    "pure",
+    "sizeof",
+    "typeof",
+    "unsized",
+    "virtual",
+    "yield",
+    "std",
+    "usize",
+    "isize",
+    "u8",
+    "i8",
+    "u16",
+    "i16",
+    "u32",
+    "i32",
+    "u64",
+    "i64",
+    "u128",
+    "i128",
+    "f32",
+    "f64",
+    "follow",
+    "push",
+    "size",
+    "alignment",
+    "to_little_endian",
+    "from_little_endian",
+];
+
+/// Name of the field accessor in flatbuffers proxies
+pub fn format_flatbuffers_accessor<'a>(ident: &'a str) -> Cow<'a, str> {
+    let ident = ident.trim_start_matches("$");
+    if FLATC_RESERVED_WORDS.contains(&ident) {
+        Cow::Owned(format!("{ident}_"))
+    } else {
+        Cow::Borrowed(ident)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 static RESERVED_WORDS: OnceLock<HashSet<&'static str>> = OnceLock::new();
 
 pub fn format_ident<'a>(ident: &'a str) -> Cow<'a, str> {
     let reserved = RESERVED_WORDS.get_or_init(|| {
         let mut set = HashSet::new();
         set.insert("type");
+        set.insert("in");
         set
     });
 
+    // Operator properties like `$in` can collide with keywords once the prefix is stripped
+    let ident = ident.trim_start_matches("$");
     if reserved.contains(ident) {
         Cow::Owned(format!("r#{ident}"))
     } else {
-        Cow::Borrowed(ident.trim_start_matches("$"))
+        Cow::Borrowed(ident)
     }
 }
 
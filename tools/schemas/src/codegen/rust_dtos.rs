@@ -1,4 +1,4 @@
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 use super::rust_common::format_ident;
 use crate::{
@@ -29,6 +29,102 @@ const PREAMBLE: &str = indoc::indoc!(
     use crate::dataset::*;
     use crate::formats::*;
     use crate::resource::*;
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Union variant that is not known to this version of the schemas, preserved verbatim
+    #[derive(Clone, PartialEq, Eq, Debug)]
+    pub enum UnknownVariant {
+        /// Read from a manifest, carrying the discriminator value and the whole object
+        Json { tag: String, value: serde_json::Value },
+        /// Read from a flatbuffer, carrying the union type, the bytes of the table along with everything it refers to, and
+        /// the location of the table in them
+        Flatbuffers { tag: u8, bytes: Vec<u8>, loc: usize },
+    }
+
+    /// Enum value that is not known to this version of the schemas, preserved verbatim
+    #[derive(Clone, PartialEq, Eq, Hash, Debug)]
+    pub enum UnknownEnumValue {
+        /// Read from a manifest
+        Json(String),
+        /// Read from a flatbuffer
+        Flatbuffers(i64),
+    }
+
+    /// Marks a required field of a builder that was not set yet
+    #[derive(Clone, Copy, Debug, Default)]
+    pub struct Unset;
+
+    /// Value of a `sensitive` string field, e.g. a password, that `Debug` prints as `***`
+    #[derive(Clone, Default, PartialEq, Eq, Hash)]
+    pub struct Sensitive<T>(T);
+
+    impl<T> Sensitive<T> {
+        pub fn new(value: T) -> Self {
+            Self(value)
+        }
+
+        /// Gives access to the underlying value, which should never be logged
+        pub fn expose(&self) -> &T {
+            &self.0
+        }
+
+        pub fn expose_mut(&mut self) -> &mut T {
+            &mut self.0
+        }
+
+        pub fn into_inner(self) -> T {
+            self.0
+        }
+    }
+
+    impl<T> From<T> for Sensitive<T> {
+        fn from(value: T) -> Self {
+            Self(value)
+        }
+    }
+
+    impl<T> std::fmt::Debug for Sensitive<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("***")
+        }
+    }
+
+    /// Implemented by DTOs that hold `sensitive` fields to get a copy that is safe to log or serialize
+    pub trait Redact {
+        /// Returns a copy with every secret string replaced by `***`
+        fn redact(&self) -> Self;
+    }
+
+    impl Redact for String {
+        fn redact(&self) -> Self {
+            "***".to_string()
+        }
+    }
+
+    impl<T: Redact> Redact for Sensitive<T> {
+        fn redact(&self) -> Self {
+            Self(self.0.redact())
+        }
+    }
+
+    impl<T: Redact> Redact for Option<T> {
+        fn redact(&self) -> Self {
+            self.as_ref().map(Redact::redact)
+        }
+    }
+
+    impl<T: Redact> Redact for Vec<T> {
+        fn redact(&self) -> Self {
+            self.iter().map(Redact::redact).collect()
+        }
+    }
+
+    impl<T: Redact> Redact for Box<T> {
+        fn redact(&self) -> Self {
+            Box::new((**self).redact())
+        }
+    }
     "#
 );
 
@@ -37,6 +133,8 @@ const PREAMBLE: &str = indoc::indoc!(
 pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
     writeln!(w, "{}", PREAMBLE)?;
 
+    let redactable = model.redactable_types();
+
     // Group by `context` and sort by names
     let types_by_context: BTreeMap<&str, BTreeMap<String, &model::TypeDefinition>> =
         model.types.values().fold(BTreeMap::new(), |mut map, t| {
@@ -77,11 +175,23 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
             }
 
             render_description(typ.description(), None, None, w)?;
+            if let model::TypeDefinition::Union(t) = typ
+                && let Some(discriminator) = t.discriminator_description()
+            {
+                writeln!(w, "///")?;
+                writeln!(w, "/// {discriminator}")?;
+            }
             writeln!(w, "///")?;
             writeln!(w, "/// Schema: {}", typ.id().schema_id())?;
 
             match &typ {
-                model::TypeDefinition::Struct(t) => render_struct(t, w)?,
+                model::TypeDefinition::Struct(t) => {
+                    render_struct(t, w)?;
+                    if !t.fields.is_empty() {
+                        render_builder(t, w)?;
+                    }
+                    render_instantiations(&model, t, w)?;
+                }
                 model::TypeDefinition::Union(t) => {
                     render_union(t, w)?;
 
@@ -93,11 +203,15 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
                 model::TypeDefinition::Enum(t) => render_enum(t, w)?,
                 model::TypeDefinition::Map(t) => render_map(t, w)?,
             }
+            render_redact(typ, &redactable, w)?;
             writeln!(w)?;
         }
 
         writeln!(w, "}}")?;
     }
+
+    render_visitors(&types_by_context, w)?;
+
     Ok(())
 }
 
@@ -105,7 +219,7 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
 
 fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
     let name = typ.id.join("");
-    let generics = format!("<{}>", typ.generics.join(", "));
+    let generics = format!("<{}>", typ.generic_names().join(", "));
 
     let mut derives = vec!["Clone", "Debug", "Eq"];
 
@@ -116,6 +230,19 @@ fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(),
         derives.push("Default");
     }
 
+    let documented_generics: Vec<_> = typ
+        .generics
+        .iter()
+        .filter_map(|g| Some((&g.name, g.description.as_ref()?)))
+        .collect();
+    if !documented_generics.is_empty() {
+        writeln!(w, "///")?;
+        writeln!(w, "/// Type parameters:")?;
+        for (name, desc) in documented_generics {
+            writeln!(w, "/// - `{name}`: {desc}")?;
+        }
+    }
+
     writeln!(w, "#[derive({})]", derives.join(", "))?;
     writeln!(w, "pub struct {name}{generics} {{")?;
 
@@ -137,6 +264,9 @@ fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(),
         {
             typ = format!("{container}<{typ}>");
         }
+        if field.wraps_sensitive() {
+            typ = format!("super::Sensitive<{typ}>");
+        }
         if field.optional {
             typ = format!("Option<{typ}>");
         }
@@ -260,7 +390,13 @@ fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(),
         writeln!(w, "impl PartialEq for {} {{", typ.id.join(""))?;
         writeln!(w, "fn eq(&self, other: &Self) -> bool {{")?;
 
-        for (i, field) in typ.fields.values().enumerate() {
+        // Constants are not stored in the struct
+        for (i, field) in typ
+            .fields
+            .values()
+            .filter(|f| f.constant.is_none())
+            .enumerate()
+        {
             let fname = format_ident(&field.name);
             if i != 0 {
                 writeln!(w, "&&")?;
@@ -296,18 +432,297 @@ fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(),
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// Builder tracks every required field in a type parameter that turns from `Unset` into the field's type once
+// the value is provided, so that `build()` only exists when all required fields were set
+fn render_builder(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+    let name = typ.id.join("");
+    let builder = format!("{name}Builder");
+    let generics = typ.generic_names();
+
+    let fields: Vec<_> = typ
+        .fields
+        .values()
+        .filter(|f| f.constant.is_none())
+        .map(|f| {
+            let mut ftyp = format_type(&f.typ);
+            if let Some(container) =
+                f.get_hint::<String>(CodegenLanguage::Rust, CodegenHint::Container)
+            {
+                ftyp = format!("{container}<{ftyp}>");
+            }
+            if f.wraps_sensitive() {
+                ftyp = format!("super::Sensitive<{ftyp}>");
+            }
+            let state = (!f.optional)
+                .then(|| format!("T{}", f.name.trim_start_matches('$').to_case(Case::Pascal)));
+            (f, format_ident(&f.name), ftyp, state)
+        })
+        .collect();
+
+    let states: Vec<&str> = fields.iter().filter_map(|(.., s)| s.as_deref()).collect();
+
+    // Formats builder type arguments, substituting the state of one field
+    let builder_args = |subst: Option<(&str, &str)>| -> String {
+        let args: Vec<&str> = generics
+            .iter()
+            .copied()
+            .chain(states.iter().map(|s| match subst {
+                Some((from, to)) if from == *s => to,
+                _ => s,
+            }))
+            .collect();
+        if args.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", args.join(", "))
+        }
+    };
+    let impl_params = |exclude: Option<&str>| -> String {
+        let params: Vec<&str> = generics
+            .iter()
+            .copied()
+            .chain(states.iter().copied().filter(|s| Some(*s) != exclude))
+            .collect();
+        if params.is_empty() {
+            String::new()
+        } else {
+            format!("<{}>", params.join(", "))
+        }
+    };
+    let struct_args = if generics.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", generics.join(", "))
+    };
+
+    writeln!(w)?;
+    writeln!(
+        w,
+        "/// Builder for [`{name}`] that requires all mandatory fields to be set before calling `build()`"
+    )?;
+    writeln!(w, "#[must_use]")?;
+    writeln!(w, "#[derive(Clone, Debug)]")?;
+    let decl_params: Vec<String> = generics
+        .iter()
+        .map(|g| g.to_string())
+        .chain(states.iter().map(|s| format!("{s} = Unset")))
+        .collect();
+    if decl_params.is_empty() {
+        writeln!(w, "pub struct {builder} {{")?;
+    } else {
+        writeln!(w, "pub struct {builder}<{}> {{", decl_params.join(", "))?;
+    }
+    for (_, fname, ftyp, state) in &fields {
+        match state {
+            Some(state) => writeln!(w, "{fname}: {state},")?,
+            None => writeln!(w, "{fname}: Option<{ftyp}>,")?,
+        }
+    }
+    // Struct parameters are otherwise unused while their fields are unset
+    if !generics.is_empty() {
+        writeln!(
+            w,
+            "_marker: std::marker::PhantomData<({},)>,",
+            generics.join(", ")
+        )?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    // Entry point
+    let unset_args: Vec<&str> = generics
+        .iter()
+        .copied()
+        .chain(states.iter().map(|_| "Unset"))
+        .collect();
+    let unset_args = if unset_args.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", unset_args.join(", "))
+    };
+    writeln!(w, "impl{struct_args} {name}{struct_args} {{")?;
+    writeln!(w, "pub fn builder() -> {builder}{unset_args} {{")?;
+    writeln!(w, "{builder} {{")?;
+    for (_, fname, _, state) in &fields {
+        match state {
+            Some(_) => writeln!(w, "{fname}: Unset,")?,
+            None => writeln!(w, "{fname}: None,")?,
+        }
+    }
+    if !generics.is_empty() {
+        writeln!(w, "_marker: std::marker::PhantomData,")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    // Required fields change the builder type
+    for (field, fname, ftyp, state) in &fields {
+        let Some(state) = state else {
+            continue;
+        };
+        writeln!(
+            w,
+            "impl{} {builder}{} {{",
+            impl_params(Some(state)),
+            builder_args(Some((state, "Unset")))
+        )?;
+        render_description(&field.description, None, None, w)?;
+        writeln!(
+            w,
+            "pub fn {fname}(self, v: {}) -> {builder}{} {{",
+            setter_arg(field, ftyp),
+            builder_args(Some((state, ftyp)))
+        )?;
+        writeln!(w, "{builder} {{")?;
+        for (_, other, ..) in &fields {
+            if other == fname {
+                writeln!(w, "{other}: {},", setter_value(field))?;
+            } else {
+                writeln!(w, "{other}: self.{other},")?;
+            }
+        }
+        if !generics.is_empty() {
+            writeln!(w, "_marker: self._marker,")?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w, "}}")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+
+    // Optional fields, including ones that have defaults, can be set in any state
+    if fields.iter().any(|(.., state)| state.is_none()) {
+        writeln!(
+            w,
+            "impl{} {builder}{} {{",
+            impl_params(None),
+            builder_args(None)
+        )?;
+        for (field, fname, ftyp, state) in &fields {
+            if state.is_some() {
+                continue;
+            }
+            render_description(&field.description, field.default.as_ref(), None, w)?;
+            writeln!(
+                w,
+                "pub fn {fname}(mut self, v: {}) -> Self {{",
+                setter_arg(field, ftyp)
+            )?;
+            writeln!(w, "self.{fname} = Some({});", setter_value(field))?;
+            writeln!(w, "self")?;
+            writeln!(w, "}}")?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+
+    // Finalization is only available once every required field is set
+    let built_args: Vec<&str> = generics
+        .iter()
+        .copied()
+        .chain(
+            fields
+                .iter()
+                .filter(|(.., s)| s.is_some())
+                .map(|(_, _, t, _)| t.as_str()),
+        )
+        .collect();
+    let built_args = if built_args.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", built_args.join(", "))
+    };
+    writeln!(w, "impl{struct_args} {builder}{built_args} {{")?;
+    writeln!(w, "pub fn build(self) -> {name}{struct_args} {{")?;
+    writeln!(w, "{name} {{")?;
+    for (_, fname, ..) in &fields {
+        writeln!(w, "{fname}: self.{fname},")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+// Setters accept anything convertible into the field type, except for type parameters that need to stay inferrable
+fn setter_arg(field: &model::Field, ftyp: &str) -> String {
+    if field.typ.generic_name().is_some() {
+        ftyp.to_string()
+    } else if let Some(inner) = ftyp
+        .strip_prefix("super::Sensitive<")
+        .and_then(|t| t.strip_suffix('>'))
+    {
+        // Sensitive values are wrapped by the builder, so plain values can be passed
+        format!("impl Into<{inner}>")
+    } else {
+        format!("impl Into<{ftyp}>")
+    }
+}
+
+fn setter_value(field: &model::Field) -> &'static str {
+    if field.wraps_sensitive() {
+        "super::Sensitive::new(v.into())"
+    } else {
+        "v.into()"
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Aliases give concrete instances of generic structs the same names they have in flatbuffers and GraphQL
+fn render_instantiations(
+    model: &model::Model,
+    typ: &model::Struct,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let name = typ.id.join("");
+    let instances = model.instantiations(typ);
+
+    if !instances.is_empty() {
+        writeln!(w)?;
+    }
+
+    for inst in instances {
+        let args: Vec<_> = inst
+            .args
+            .iter()
+            .map(|a| format_type(&model::Type::Custom(a.clone())))
+            .collect();
+
+        writeln!(
+            w,
+            "pub type {} = {name}<{}>;",
+            inst.typ.id.join(""),
+            args.join(", ")
+        )?;
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
     writeln!(w, "#[derive(Clone, PartialEq, Eq, Debug)]")?;
     writeln!(w, "pub enum {} {{", typ.id.join(""))?;
     for variant in &typ.variants {
+        if variant.has_custom_tag() {
+            writeln!(w, "/// Tagged as `{}`", variant.tag.as_deref().unwrap())?;
+        }
         writeln!(
             w,
             "{}({}::{}),",
-            variant.name(),
-            variant.context(),
-            variant.join("")
+            variant.id.name(),
+            variant.id.context(),
+            variant.id.join("")
         )?;
     }
+    if typ.has_unknown_variant() {
+        writeln!(w, "Unknown(UnknownVariant),")?;
+    }
     writeln!(w, "}}")?;
     writeln!(w)?;
     writeln!(w, "impl_enum_with_variants!({});", typ.id.name())?;
@@ -316,9 +731,9 @@ fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), st
             w,
             "impl_enum_variant!({}::{}({}::{}));",
             typ.id.name(),
-            variant.name(),
-            variant.context(),
-            variant.join("")
+            variant.id.name(),
+            variant.id.context(),
+            variant.id.join("")
         )?;
     }
 
@@ -328,16 +743,26 @@ fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), st
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 fn render_enum(typ: &model::Enum, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+    // Unknown values carry data, so such enums can't be `Copy`
+    let copy = if typ.has_unknown_variant() {
+        ""
+    } else {
+        "Copy, "
+    };
+
     if typ.id.name() == "DatasetKind" {
         // TODO: Introduce `extra_derives`
-        writeln!(w, "#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]")?;
+        writeln!(w, "#[derive(Clone, {copy}PartialEq, Eq, Hash, Debug)]")?;
     } else {
-        writeln!(w, "#[derive(Clone, Copy, PartialEq, Eq, Debug)]")?;
+        writeln!(w, "#[derive(Clone, {copy}PartialEq, Eq, Debug)]")?;
     }
     writeln!(w, "pub enum {} {{", typ.id.join(""))?;
     for variant in &typ.variants {
         writeln!(w, "{variant},")?;
     }
+    if typ.has_unknown_variant() {
+        writeln!(w, "Unknown(UnknownEnumValue),")?;
+    }
     writeln!(w, "}}")?;
 
     Ok(())
@@ -369,6 +794,91 @@ fn render_map(typ: &model::Map, w: &mut dyn std::io::Write) -> Result<(), std::i
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// Generic structs get a bounded impl as their arguments may be redactable
+fn render_redact(
+    typ: &model::TypeDefinition,
+    redactable: &BTreeSet<model::TypeId>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    fn holds(typ: &model::Type, redactable: &BTreeSet<model::TypeId>) -> bool {
+        match typ {
+            model::Type::Custom(id) => redactable.contains(id),
+            model::Type::Array(arr) => holds(&arr.item_type, redactable),
+            model::Type::Generic(_) => true,
+            _ => false,
+        }
+    }
+
+    let generic = matches!(typ, model::TypeDefinition::Struct(t) if !t.generics.is_empty());
+    if !generic && !redactable.contains(typ.id()) {
+        return Ok(());
+    }
+
+    let name = typ.id().join("");
+    writeln!(w)?;
+
+    match typ {
+        model::TypeDefinition::Struct(t) => {
+            let generics = t.generic_names();
+            if generics.is_empty() {
+                writeln!(w, "impl Redact for {name} {{")?;
+            } else {
+                let params: Vec<_> = generics.iter().map(|g| format!("{g}: Redact")).collect();
+                writeln!(
+                    w,
+                    "impl<{}> Redact for {name}<{}> {{",
+                    params.join(", "),
+                    generics.join(", ")
+                )?;
+            }
+            writeln!(w, "fn redact(&self) -> Self {{")?;
+            writeln!(w, "Self {{")?;
+            for field in t.fields.values() {
+                if field.constant.is_some() {
+                    continue;
+                }
+                let fname = format_ident(&field.name);
+                if field.sensitive || holds(&field.typ, redactable) {
+                    writeln!(w, "{fname}: self.{fname}.redact(),")?;
+                } else {
+                    writeln!(w, "{fname}: self.{fname}.clone(),")?;
+                }
+            }
+            writeln!(w, "}}")?;
+        }
+        model::TypeDefinition::Union(t) => {
+            writeln!(w, "impl Redact for {name} {{")?;
+            writeln!(w, "fn redact(&self) -> Self {{")?;
+            writeln!(w, "match self {{")?;
+            for variant in t.variants.iter().filter(|v| redactable.contains(&v.id)) {
+                let vname = variant.id.name();
+                writeln!(w, "Self::{vname}(v) => Self::{vname}(v.redact()),")?;
+            }
+            if t.has_unknown_variant() || t.variants.iter().any(|v| !redactable.contains(&v.id)) {
+                writeln!(w, "_ => self.clone(),")?;
+            }
+            writeln!(w, "}}")?;
+        }
+        model::TypeDefinition::Map(_) => {
+            writeln!(w, "impl Redact for {name} {{")?;
+            writeln!(w, "fn redact(&self) -> Self {{")?;
+            writeln!(w, "Self {{")?;
+            writeln!(
+                w,
+                "entries: self.entries.iter().map(|(k, v)| (k.clone(), v.redact())).collect(),"
+            )?;
+            writeln!(w, "}}")?;
+        }
+        model::TypeDefinition::Enum(_) => unreachable!(),
+    }
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 fn format_type(typ: &model::Type) -> String {
     match typ {
         model::Type::Boolean => format!("bool"),
@@ -453,10 +963,14 @@ fn render_union_bitflags(
         writeln!(
             w,
             "        const {} = 1 << {};",
-            variant.name().to_case(Case::UpperSnake),
+            variant.id.name().to_case(Case::UpperSnake),
             i
         )?;
     }
+    if typ.has_unknown_variant() {
+        // Highest bit keeps the flag stable as new variants are added
+        writeln!(w, "        const UNKNOWN = 1 << 31;")?;
+    }
     writeln!(w, "    }}")?;
     writeln!(w, "}}")?;
     writeln!(w)?;
@@ -474,8 +988,15 @@ fn render_union_bitflags(
             w,
             "            {}::{}(_) => Self::{},",
             typ.id.name(),
-            variant.name(),
-            variant.name().to_case(Case::UpperSnake),
+            variant.id.name(),
+            variant.id.name().to_case(Case::UpperSnake),
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "            {}::Unknown(_) => Self::UNKNOWN,",
+            typ.id.name()
         )?;
     }
     writeln!(w, "        }}")?;
@@ -485,3 +1006,369 @@ fn render_union_bitflags(
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Scalar kinds that visitors get a dedicated method for, keyed by the method name
+fn scalar_method(typ: &model::Type) -> Option<&'static str> {
+    let name = match typ {
+        model::Type::Boolean => "bool",
+        model::Type::Int8 => "i8",
+        model::Type::Int16 => "i16",
+        model::Type::Int32 => "i32",
+        model::Type::Int64 => "i64",
+        model::Type::UInt8 => "u8",
+        model::Type::UInt16 => "u16",
+        model::Type::UInt32 => "u32",
+        model::Type::UInt64 => "u64",
+        model::Type::String => "string",
+        model::Type::ByteSize => "byte_size",
+        model::Type::DateTime => "date_time",
+        model::Type::Duration => "duration",
+        model::Type::Multicodec => "multicodec",
+        model::Type::Multihash => "multihash",
+        model::Type::Path => "path",
+        model::Type::Regex => "regex",
+        model::Type::Url => "url",
+        model::Type::Did => "did",
+        model::Type::TypeName => "type_name",
+        model::Type::TypeUri => "type_uri",
+        model::Type::TypeRef => "type_ref",
+        model::Type::AccountId => "account_id",
+        model::Type::AccountName => "account_name",
+        model::Type::DatasetAlias => "dataset_alias",
+        model::Type::DatasetId => "dataset_id",
+        model::Type::DatasetRef => "dataset_ref",
+        model::Type::ResourceId => "resource_id",
+        model::Type::ResourceName => "resource_name",
+        model::Type::Flatbuffers => "flatbuffers",
+        model::Type::AnyJson => "any_json",
+        model::Type::Generic(_) | model::Type::Array(_) | model::Type::Custom(_) => return None,
+    };
+    Some(name)
+}
+
+const SCALARS: &[model::Type] = &[
+    model::Type::Boolean,
+    model::Type::Int8,
+    model::Type::Int16,
+    model::Type::Int32,
+    model::Type::Int64,
+    model::Type::UInt8,
+    model::Type::UInt16,
+    model::Type::UInt32,
+    model::Type::UInt64,
+    model::Type::String,
+    model::Type::ByteSize,
+    model::Type::DateTime,
+    model::Type::Duration,
+    model::Type::Multicodec,
+    model::Type::Multihash,
+    model::Type::Path,
+    model::Type::Regex,
+    model::Type::Url,
+    model::Type::Did,
+    model::Type::TypeName,
+    model::Type::TypeUri,
+    model::Type::TypeRef,
+    model::Type::AccountId,
+    model::Type::AccountName,
+    model::Type::DatasetAlias,
+    model::Type::DatasetId,
+    model::Type::DatasetRef,
+    model::Type::ResourceId,
+    model::Type::ResourceName,
+    model::Type::Flatbuffers,
+    model::Type::AnyJson,
+];
+
+fn type_method(id: &model::TypeId) -> String {
+    format!("{}_{}", id.context(), id.join("").to_case(Case::Snake))
+}
+
+// Generates `Visit` and `VisitMut` traits with a method per type and scalar kind. Default methods of types call
+// `walk_*` functions that descend into all fields, array items, map values, and union variants, while scalars are leaves.
+fn render_visitors(
+    types_by_context: &BTreeMap<&str, BTreeMap<String, &model::TypeDefinition>>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let types: Vec<&model::TypeDefinition> = types_by_context
+        .values()
+        .flat_map(|types| types.values().copied())
+        .collect();
+
+    for typ in &types {
+        let method = type_method(typ.id());
+        if SCALARS
+            .iter()
+            .any(|s| scalar_method(s) == Some(method.as_str()))
+        {
+            panic!(
+                "Visitor method for type {}::{} clashes with a scalar kind",
+                typ.id().context(),
+                typ.id().join("")
+            );
+        }
+    }
+
+    writeln!(
+        w,
+        "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
+    )?;
+    writeln!(w, "// Visitors")?;
+    writeln!(
+        w,
+        "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////\n"
+    )?;
+
+    writeln!(w, "pub mod visit {{")?;
+    writeln!(w, "#[allow(unused_imports)]")?;
+    writeln!(w, "use super::*;\n")?;
+
+    writeln!(
+        w,
+        "/// Implemented by all DTOs to dispatch into the matching visitor method, e.g. for type parameters of generic types"
+    )?;
+    writeln!(w, "pub trait Visitable {{")?;
+    writeln!(w, "fn accept<V: Visit + ?Sized>(&self, visitor: &mut V);")?;
+    writeln!(
+        w,
+        "fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V);"
+    )?;
+    writeln!(w, "}}\n")?;
+
+    for mutable in [false, true] {
+        let (trait_name, sfx, rf) = if mutable {
+            ("VisitMut", "_mut", "&mut ")
+        } else {
+            ("Visit", "", "&")
+        };
+
+        if mutable {
+            writeln!(
+                w,
+                "/// Traversal of DTOs that can modify values in place, e.g. to rewrite references."
+            )?;
+        } else {
+            writeln!(
+                w,
+                "/// Read-only traversal of DTOs, e.g. to collect references."
+            )?;
+        }
+        writeln!(w, "///")?;
+        writeln!(
+            w,
+            "/// Default methods descend into all nested values, so implementations only override the ones they care about."
+        )?;
+        writeln!(w, "pub trait {trait_name} {{")?;
+
+        for scalar in SCALARS {
+            let method = scalar_method(scalar).unwrap();
+            writeln!(
+                w,
+                "fn visit_{method}{sfx}(&mut self, _v: {rf}{}) {{}}",
+                format_type(scalar)
+            )?;
+        }
+        writeln!(w)?;
+
+        for typ in &types {
+            let method = type_method(typ.id());
+            let (params, args) = visitor_generics(typ);
+            let walked = is_walked(typ);
+            let name = format!("{}::{}{args}", typ.id().context(), typ.id().join(""));
+            if walked {
+                writeln!(
+                    w,
+                    "fn visit_{method}{sfx}{params}(&mut self, v: {rf}{name}) {{"
+                )?;
+                writeln!(w, "walk_{method}{sfx}(self, v)")?;
+                writeln!(w, "}}")?;
+            } else {
+                writeln!(
+                    w,
+                    "fn visit_{method}{sfx}{params}(&mut self, _v: {rf}{name}) {{}}"
+                )?;
+            }
+        }
+        writeln!(w, "}}\n")?;
+    }
+
+    // Walkers
+    for mutable in [false, true] {
+        for typ in &types {
+            if is_walked(typ) {
+                render_walk(typ, mutable, w)?;
+            }
+        }
+    }
+
+    // Dispatch
+    for typ in &types {
+        let method = type_method(typ.id());
+        let (params, args) = visitor_generics(typ);
+        writeln!(
+            w,
+            "impl{params} Visitable for {}::{}{args} {{",
+            typ.id().context(),
+            typ.id().join("")
+        )?;
+        writeln!(w, "fn accept<V: Visit + ?Sized>(&self, visitor: &mut V) {{")?;
+        writeln!(w, "visitor.visit_{method}(self)")?;
+        writeln!(w, "}}")?;
+        writeln!(
+            w,
+            "fn accept_mut<V: VisitMut + ?Sized>(&mut self, visitor: &mut V) {{"
+        )?;
+        writeln!(w, "visitor.visit_{method}_mut(self)")?;
+        writeln!(w, "}}")?;
+        writeln!(w, "}}\n")?;
+    }
+
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+// Type parameters of generic structs are walked through `Visitable`
+fn visitor_generics(typ: &model::TypeDefinition) -> (String, String) {
+    let model::TypeDefinition::Struct(t) = typ else {
+        return (String::new(), String::new());
+    };
+    if t.generics.is_empty() {
+        return (String::new(), String::new());
+    }
+    let names = t.generic_names();
+    let params: Vec<_> = names.iter().map(|g| format!("{g}: Visitable")).collect();
+    (
+        format!("<{}>", params.join(", ")),
+        format!("<{}>", names.join(", ")),
+    )
+}
+
+// Enums and externally defined types are leaves
+fn is_walked(typ: &model::TypeDefinition) -> bool {
+    if typ
+        .get_hint::<String>(CodegenLanguage::Rust, CodegenHint::DtoType)
+        .is_some()
+    {
+        return false;
+    }
+    match typ {
+        model::TypeDefinition::Struct(t) => t.fields.values().any(|f| f.constant.is_none()),
+        model::TypeDefinition::Union(_) | model::TypeDefinition::Map(_) => true,
+        model::TypeDefinition::Enum(_) => false,
+    }
+}
+
+fn render_walk(
+    typ: &model::TypeDefinition,
+    mutable: bool,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let (trait_name, sfx, rf) = if mutable {
+        ("VisitMut", "_mut", "&mut ")
+    } else {
+        ("Visit", "", "&")
+    };
+    let method = type_method(typ.id());
+    let name = format!("{}::{}", typ.id().context(), typ.id().join(""));
+    let (params, args) = visitor_generics(typ);
+    let params = if params.is_empty() {
+        format!("<V: {trait_name} + ?Sized>")
+    } else {
+        format!("<V: {trait_name} + ?Sized, {}", &params[1..])
+    };
+
+    writeln!(
+        w,
+        "pub fn walk_{method}{sfx}{params}(visitor: &mut V, v: {rf}{name}{args}) {{"
+    )?;
+
+    match typ {
+        model::TypeDefinition::Struct(t) => {
+            for field in t.fields.values() {
+                if field.constant.is_some() {
+                    continue;
+                }
+                let fname = format_ident(&field.name);
+                if field.optional {
+                    writeln!(w, "if let Some(x) = {rf}v.{fname} {{")?;
+                    if field.wraps_sensitive() {
+                        render_walk_value(&field.typ, &format!("x.expose{sfx}()"), mutable, 0, w)?;
+                    } else {
+                        render_walk_value(&field.typ, "x", mutable, 0, w)?;
+                    }
+                    writeln!(w, "}}")?;
+                } else if field.wraps_sensitive() {
+                    render_walk_value(
+                        &field.typ,
+                        &format!("v.{fname}.expose{sfx}()"),
+                        mutable,
+                        0,
+                        w,
+                    )?;
+                } else {
+                    render_walk_value(&field.typ, &format!("{rf}v.{fname}"), mutable, 0, w)?;
+                }
+            }
+        }
+        model::TypeDefinition::Union(t) => {
+            writeln!(w, "match v {{")?;
+            for variant in &t.variants {
+                writeln!(
+                    w,
+                    "{name}::{}(x) => visitor.visit_{}{sfx}(x),",
+                    variant.id.name(),
+                    type_method(&variant.id)
+                )?;
+            }
+            if t.has_unknown_variant() {
+                writeln!(w, "{name}::Unknown(_) => {{}}")?;
+            }
+            writeln!(w, "}}")?;
+        }
+        // Keys can't be modified in place, so only values are walked
+        model::TypeDefinition::Map(t) => {
+            writeln!(w, "for x in v.entries.values{sfx}() {{")?;
+            render_walk_value(&t.value_type, "x", mutable, 0, w)?;
+            writeln!(w, "}}")?;
+        }
+        model::TypeDefinition::Enum(_) => unreachable!(),
+    }
+
+    writeln!(w, "}}\n")?;
+    Ok(())
+}
+
+fn render_walk_value(
+    typ: &model::Type,
+    expr: &str,
+    mutable: bool,
+    depth: usize,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let sfx = if mutable { "_mut" } else { "" };
+    match typ {
+        model::Type::Custom(id) => {
+            writeln!(w, "visitor.visit_{}{sfx}({expr});", type_method(id))?;
+        }
+        model::Type::Generic(_) => {
+            let place = expr.trim_start_matches("&mut ").trim_start_matches('&');
+            writeln!(w, "{place}.accept{sfx}(visitor);")?;
+        }
+        model::Type::Array(a) => {
+            let item = format!("x{}", depth + 1);
+            writeln!(w, "for {item} in {expr} {{")?;
+            render_walk_value(&a.item_type, &item, mutable, depth + 1, w)?;
+            writeln!(w, "}}")?;
+        }
+        scalar => {
+            writeln!(
+                w,
+                "visitor.visit_{}{sfx}({expr});",
+                scalar_method(scalar).unwrap()
+            )?;
+        }
+    }
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
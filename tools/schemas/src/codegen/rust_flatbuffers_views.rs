@@ -0,0 +1,600 @@
+use crate::codegen::rust_common::{format_flatbuffers_accessor, format_ident};
+use crate::json_schema::{CodegenHint, CodegenLanguage, FlatbuffersMapFormat};
+use crate::model;
+use crate::utils::indent_writer::IndentWriter;
+use convert_case::{Case, Casing};
+use std::collections::BTreeSet;
+use std::io::Write;
+
+const PREAMBLE: &str = indoc::indoc!(
+    r#"
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+    // WARNING: This file is auto-generated from Open Data Fabric Schemas
+    // See: http://opendatafabric.org/
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    #![allow(unused_imports)]
+    #![allow(clippy::all)]
+    #![allow(clippy::pedantic)]
+
+    use super::convertors_generated::*;
+    use super::proxies_generated as fb;
+    use crate as odf;
+    use std::path::Path;
+
+    use ::flatbuffers::Table;
+    use chrono::prelude::*;
+    use setty::types::{ByteSize, DurationString};
+
+    /// Verifies the buffer within the specified limits and returns a view over its root table
+    pub fn view_verified<'fb, P, V>(
+        buf: &'fb [u8],
+        limits: &FlatbuffersDecodeLimits,
+    ) -> Result<V, FlatbuffersDecodeError>
+    where
+        P: ::flatbuffers::Follow<'fb, Inner = P> + ::flatbuffers::Verifiable + 'fb,
+        V: From<P>,
+    {
+        root_verified::<P>(buf, limits).map(V::from)
+    }
+    "#
+);
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Helpers {
+    model: model::Model,
+    ambiguous_names: BTreeSet<String>,
+}
+
+impl Helpers {
+    /// Path of the flatbuffers proxy type relative to the `fb` module
+    fn fb_name(&self, id: &model::TypeId) -> String {
+        let name = id.join("");
+        if self.ambiguous_names.contains(name.as_ref()) {
+            format!("{}::{name}", id.context())
+        } else {
+            name.into_owned()
+        }
+    }
+
+    /// Views live in a single module, so ambiguous names are prefixed with their context
+    fn view_name(&self, id: &model::TypeId) -> String {
+        let name = id.join("");
+        if self.ambiguous_names.contains(name.as_ref()) {
+            format!("{}{name}View", id.context().to_case(Case::Pascal))
+        } else {
+            format!("{name}View")
+        }
+    }
+
+    /// Externally defined types have no views and are decoded into owned values
+    fn has_view(&self, id: &model::TypeId) -> bool {
+        let typ = self.model.types.get(id).unwrap();
+        !matches!(typ, model::TypeDefinition::Enum(_))
+            && typ
+                .get_hint::<String>(CodegenLanguage::Rust, CodegenHint::DtoType)
+                .is_none()
+    }
+
+    fn is_scalar(&self, typ: &model::Type) -> bool {
+        match typ {
+            model::Type::Boolean
+            | model::Type::Int8
+            | model::Type::Int16
+            | model::Type::Int32
+            | model::Type::Int64
+            | model::Type::UInt8
+            | model::Type::UInt16
+            | model::Type::UInt32
+            | model::Type::UInt64
+            | model::Type::ByteSize => true,
+            model::Type::Custom(id) => matches!(
+                self.model.types.get(id).unwrap(),
+                model::TypeDefinition::Enum(_)
+            ),
+            _ => false,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+    let mut w = IndentWriter::new(w, "  ");
+    render_impl(model, &mut w)
+}
+
+fn render_impl(
+    model: model::Model,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    writeln!(w, "{}", PREAMBLE)?;
+
+    let helpers = Helpers {
+        model: model.clone(),
+        ambiguous_names: model.ambiguous_names(),
+    };
+
+    // Same set of types as in flatbuffers convertors
+    for typ in model.types.values() {
+        if typ.id().name() == "Manifest"
+            || typ.id().name() == "DatasetSnapshot"
+            || typ.id().name() == "Resource"
+            || typ.id().name() == "ResourceInput"
+        {
+            continue;
+        }
+
+        if matches!(typ.metatype(), model::MetaType::Resource) {
+            continue;
+        }
+
+        if matches!(typ, model::TypeDefinition::Struct(t) if !t.generics.is_empty()) {
+            continue;
+        }
+
+        if matches!(typ, model::TypeDefinition::Enum(_)) || !helpers.has_view(typ.id()) {
+            continue;
+        }
+
+        // Multicodec codes are strings in DTOs but integers in flatbuffers, so there is no view type to map them to
+        if let model::TypeDefinition::Struct(t) = typ
+            && let Some(field) = t
+                .fields
+                .values()
+                .find(|f| f.constant.is_none() && is_multicodec(&f.typ))
+        {
+            return Err(std::io::Error::other(format!(
+                "Field {}.{} has format `multicodec` that is not supported by flatbuffers views",
+                t.id.join(""),
+                field.name
+            )));
+        }
+
+        writeln!(
+            w,
+            "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
+        )?;
+        writeln!(w, "// {}", typ.id().join(""))?;
+        writeln!(w, "// Schema: {}", typ.id().schema_id())?;
+        writeln!(
+            w,
+            "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
+        )?;
+        writeln!(w)?;
+
+        match &typ {
+            model::TypeDefinition::Struct(t) => render_struct(t, &helpers, w)?,
+            model::TypeDefinition::Union(t) => render_union(t, &helpers, w)?,
+            model::TypeDefinition::Map(t) => render_map(t, &helpers, w)?,
+            model::TypeDefinition::Enum(_) => unreachable!(),
+        }
+        writeln!(w)?;
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_struct(
+    typ: &model::Struct,
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
+    let view_name = helpers.view_name(&typ.id);
+
+    writeln!(w, "/// Read-only view over [`odf::{context}::{name}`]")?;
+    writeln!(w, "#[derive(Debug, Clone, Copy)]")?;
+    writeln!(w, "pub struct {view_name}<'fb> {{")?;
+    writeln!(w, "proxy: fb::{fb_name}<'fb>,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "impl<'fb> From<fb::{fb_name}<'fb>> for {view_name}<'fb> {{"
+    )?;
+    writeln!(w, "fn from(proxy: fb::{fb_name}<'fb>) -> Self {{")?;
+    writeln!(w, "Self {{ proxy }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<'fb> {view_name}<'fb> {{")?;
+    for field in typ.fields.values() {
+        if field.constant.is_some() {
+            continue;
+        }
+        render_field_accessor(&typ.id, field, helpers, w)?;
+        writeln!(w)?;
+    }
+
+    writeln!(w, "/// Decodes the whole value into an owned DTO")?;
+    writeln!(
+        w,
+        "pub fn to_owned(&self) -> Result<odf::{context}::{name}, FlatbuffersDecodeError> {{"
+    )?;
+    writeln!(w, "odf::{context}::{name}::deserialize(self.proxy)")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+fn render_field_accessor(
+    parent: &model::TypeId,
+    field: &model::Field,
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let name = format_ident(&field.name);
+    let accessor = format_flatbuffers_accessor(&field.name);
+    let segment = format!("{}.{}", parent.join(""), field.json_name());
+    let enum_t = format!("self.proxy.{}_type()", name.trim_start_matches("r#"));
+
+    let mut typ = format_view_type(&field.typ, helpers);
+    if field.wraps_sensitive() {
+        typ = format!("odf::dtos::Sensitive<{typ}>");
+    }
+    if field.optional {
+        typ = format!("Option<{typ}>");
+    }
+    let wrap = |conv: String| {
+        if field.wraps_sensitive() {
+            format!("{conv}.map(odf::dtos::Sensitive::new)")
+        } else {
+            conv
+        }
+    };
+
+    for line in field.description.split('\n') {
+        writeln!(w, "/// {line}")?;
+    }
+    writeln!(
+        w,
+        "pub fn {name}(&self) -> Result<{typ}, FlatbuffersDecodeError> {{"
+    )?;
+    if !field.optional && helpers.is_scalar(&field.typ) {
+        let conv = format_view_conv(
+            format!("self.proxy.{accessor}()"),
+            &field.typ,
+            &enum_t,
+            helpers,
+        );
+        writeln!(w, "{conv}")?;
+    } else if field.optional {
+        let conv = wrap(format_view_conv(
+            "v".to_string(),
+            &field.typ,
+            &enum_t,
+            helpers,
+        ));
+        writeln!(w, "self.proxy.{accessor}().map(|v| {conv}).transpose()")?;
+    } else {
+        let conv = wrap(format_view_conv(
+            "v".to_string(),
+            &field.typ,
+            &enum_t,
+            helpers,
+        ));
+        writeln!(
+            w,
+            "self.proxy.{accessor}().ok_or_else(FlatbuffersDecodeError::missing_field).and_then(|v| {conv})"
+        )?;
+    }
+    writeln!(
+        w,
+        ".map_err(|e: FlatbuffersDecodeError| e.at(\"{segment}\"))"
+    )?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn is_multicodec(typ: &model::Type) -> bool {
+    match typ {
+        model::Type::Multicodec => true,
+        model::Type::Array(array) => is_multicodec(&array.item_type),
+        _ => false,
+    }
+}
+
+/// Type returned by a view accessor, borrowing from the buffer where possible
+fn format_view_type(typ: &model::Type, helpers: &Helpers) -> String {
+    match typ {
+        model::Type::Boolean => "bool".into(),
+        model::Type::Int8 => "i8".into(),
+        model::Type::Int16 => "i16".into(),
+        model::Type::Int32 => "i32".into(),
+        model::Type::Int64 => "i64".into(),
+        model::Type::UInt8 => "u8".into(),
+        model::Type::UInt16 => "u16".into(),
+        model::Type::UInt32 => "u32".into(),
+        model::Type::UInt64 => "u64".into(),
+        model::Type::ByteSize => "ByteSize".into(),
+        model::Type::DateTime => "DateTime<Utc>".into(),
+        model::Type::Duration => "DurationString".into(),
+        model::Type::Did => "odf::Did".into(),
+        model::Type::DatasetId => "odf::dataset::DatasetID".into(),
+        model::Type::AccountId => "odf::auth::AccountID".into(),
+        model::Type::ResourceId => "odf::resource::ResourceID".into(),
+        model::Type::Multihash => "odf::Multihash".into(),
+        model::Type::Multicodec => unreachable!("Rejected in render_impl"),
+        // Parsing identifiers from strings allocates, so they are returned as is
+        model::Type::String
+        | model::Type::Regex
+        | model::Type::Url
+        | model::Type::DatasetAlias
+        | model::Type::DatasetRef
+        | model::Type::AccountName
+        | model::Type::ResourceName
+        | model::Type::TypeUri
+        | model::Type::TypeName
+        | model::Type::TypeRef
+        | model::Type::AnyJson => "&'fb str".into(),
+        model::Type::Path => "&'fb Path".into(),
+        model::Type::Flatbuffers | model::Type::Generic(_) => "&'fb [u8]".into(),
+        model::Type::Array(array) => format!(
+            "impl ExactSizeIterator<Item = Result<{}, FlatbuffersDecodeError>> + 'fb",
+            format_view_type(&array.item_type, helpers)
+        ),
+        model::Type::Custom(id) if helpers.has_view(id) => {
+            format!("{}<'fb>", helpers.view_name(id))
+        }
+        model::Type::Custom(id) => format!("odf::{}::{}", id.context(), id.join("")),
+    }
+}
+
+/// Expression of `Result<T, FlatbuffersDecodeError>` that turns a proxy value into a view value
+fn format_view_conv(name: String, typ: &model::Type, enum_t: &str, helpers: &Helpers) -> String {
+    match typ {
+        model::Type::Boolean
+        | model::Type::Int8
+        | model::Type::Int16
+        | model::Type::Int32
+        | model::Type::Int64
+        | model::Type::UInt8
+        | model::Type::UInt16
+        | model::Type::UInt32
+        | model::Type::UInt64
+        | model::Type::String
+        | model::Type::Regex
+        | model::Type::Url
+        | model::Type::DatasetAlias
+        | model::Type::DatasetRef
+        | model::Type::AccountName
+        | model::Type::ResourceName
+        | model::Type::TypeUri
+        | model::Type::TypeName
+        | model::Type::TypeRef
+        | model::Type::AnyJson => format!("Ok({name})"),
+        model::Type::ByteSize => format!("Ok(ByteSize::from({name}))"),
+        model::Type::DateTime => format!("fb_to_datetime({name})"),
+        model::Type::Duration => format!("Ok(fb_to_duration({name}))"),
+        model::Type::Did => format!(
+            "odf::Did::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        ),
+        model::Type::DatasetId => format!(
+            "odf::dataset::DatasetID::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        ),
+        model::Type::AccountId => format!(
+            "odf::auth::AccountID::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        ),
+        model::Type::ResourceId => format!(
+            "odf::resource::ResourceID::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        ),
+        model::Type::Multihash => format!(
+            "odf::Multihash::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        ),
+        model::Type::Multicodec => unreachable!("Rejected in render_impl"),
+        model::Type::Path => format!("Ok(Path::new({name}))"),
+        model::Type::Flatbuffers | model::Type::Generic(_) => format!("Ok({name}.bytes())"),
+        model::Type::Array(array) => {
+            let item = if let model::Type::Custom(id) = array.item_type.as_ref()
+                && matches!(
+                    helpers.model.types.get(id).unwrap(),
+                    model::TypeDefinition::Union(_)
+                ) {
+                format!(
+                    "i.value().ok_or_else(FlatbuffersDecodeError::missing_field).and_then(|v| {})",
+                    format_view_conv("v".to_string(), &array.item_type, "i.value_type()", helpers)
+                )
+            } else {
+                format_view_conv("i".to_string(), &array.item_type, "", helpers)
+            };
+            format!("Ok({name}.iter().map(|i| {item}))")
+        }
+        model::Type::Custom(id) => {
+            let typ = helpers.model.types.get(id).unwrap();
+            let dto = format!("odf::{}::{}", id.context(), id.join(""));
+            match typ {
+                model::TypeDefinition::Enum(_) => format!("{dto}::try_from({name})"),
+                model::TypeDefinition::Union(_) if helpers.has_view(id) => {
+                    format!("{}::new({name}, {enum_t})", helpers.view_name(id))
+                }
+                model::TypeDefinition::Union(_) => format!("{dto}::deserialize({name}, {enum_t})"),
+                _ if helpers.has_view(id) => format!("Ok({}::from({name}))", helpers.view_name(id)),
+                _ => format!("{dto}::deserialize({name})"),
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_union(
+    typ: &model::Union,
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
+    let view_name = helpers.view_name(&typ.id);
+
+    writeln!(w, "/// Read-only view over [`odf::{context}::{name}`]")?;
+    writeln!(w, "#[derive(Debug, Clone, Copy)]")?;
+    writeln!(w, "pub enum {view_name}<'fb> {{")?;
+    for variant in &typ.variants {
+        writeln!(
+            w,
+            "{}({}<'fb>),",
+            variant.id.name(),
+            helpers.view_name(&variant.id)
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "/// Variant that is not known to this version of the schemas"
+        )?;
+        writeln!(w, "Unknown {{ tag: u8, table: Table<'fb> }},")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<'fb> {view_name}<'fb> {{")?;
+    writeln!(
+        w,
+        "pub fn new(table: Table<'fb>, t: fb::{fb_name}) -> Result<Self, FlatbuffersDecodeError> {{"
+    )?;
+    writeln!(w, "match t {{")?;
+    for variant in &typ.variants {
+        let typ = variant.id.join("");
+        let var = variant.id.name();
+        let var_fb_name = helpers.fb_name(&variant.id);
+        let var_view_name = helpers.view_name(&variant.id);
+        writeln!(
+            w,
+            "fb::{fb_name}::{typ} => Ok(Self::{var}({var_view_name}::from(unsafe {{ fb::{var_fb_name}::init_from_table(table) }}))),"
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "fb::{fb_name}::NONE => Err(FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::UnknownUnionVariant {{ typ: \"{name}\", value: t.0 }})),"
+        )?;
+        writeln!(w, "_ => Ok(Self::Unknown {{ tag: t.0, table }}),")?;
+    } else {
+        writeln!(
+            w,
+            "_ => Err(FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::UnknownUnionVariant {{ typ: \"{name}\", value: t.0 }})),"
+        )?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "/// Decodes the whole value into an owned DTO")?;
+    writeln!(
+        w,
+        "pub fn to_owned(&self) -> Result<odf::{context}::{name}, FlatbuffersDecodeError> {{"
+    )?;
+    writeln!(w, "match self {{")?;
+    for variant in &typ.variants {
+        let var = variant.id.name();
+        writeln!(
+            w,
+            "Self::{var}(v) => v.to_owned().map(odf::{context}::{name}::{var}),"
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "Self::Unknown {{ tag, table }} => unknown_variant_from_table(*table, *tag).map(odf::{context}::{name}::Unknown),"
+        )?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_map(
+    typ: &model::Map,
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
+    let view_name = helpers.view_name(&typ.id);
+
+    writeln!(w, "/// Read-only view over [`odf::{context}::{name}`]")?;
+    writeln!(w, "#[derive(Debug, Clone, Copy)]")?;
+    writeln!(w, "pub struct {view_name}<'fb> {{")?;
+    writeln!(w, "proxy: fb::{fb_name}<'fb>,")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        "impl<'fb> From<fb::{fb_name}<'fb>> for {view_name}<'fb> {{"
+    )?;
+    writeln!(w, "fn from(proxy: fb::{fb_name}<'fb>) -> Self {{")?;
+    writeln!(w, "Self {{ proxy }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(w, "impl<'fb> {view_name}<'fb> {{")?;
+    match typ.get_hint::<FlatbuffersMapFormat>(CodegenLanguage::Flatbuffers, CodegenHint::MapFormat)
+    {
+        None => {
+            let value_typ = format_view_type(&typ.value_type, helpers);
+            let value_conv = format_view_conv("v".to_string(), &typ.value_type, "", helpers);
+            writeln!(
+                w,
+                "pub fn entries(&self) -> impl ExactSizeIterator<Item = Result<(&'fb str, {value_typ}), FlatbuffersDecodeError>> + 'fb {{"
+            )?;
+            writeln!(
+                w,
+                "self.proxy.entries().unwrap_or_default().iter().map(|entry| {{"
+            )?;
+            writeln!(
+                w,
+                "entry.value().ok_or_else(FlatbuffersDecodeError::missing_field).and_then(|v| {value_conv}).map(|value| (entry.key(), value))"
+            )?;
+            writeln!(
+                w,
+                ".map_err(|e: FlatbuffersDecodeError| e.at(\"{name}.entries\"))"
+            )?;
+            writeln!(w, "}})")?;
+            writeln!(w, "}}")?;
+        }
+        Some(FlatbuffersMapFormat::JsonEncodedString) => {
+            writeln!(w, "/// Entries encoded as a JSON object")?;
+            writeln!(
+                w,
+                "pub fn entries_json(&self) -> Result<&'fb str, FlatbuffersDecodeError> {{"
+            )?;
+            writeln!(
+                w,
+                "self.proxy.entries().ok_or_else(FlatbuffersDecodeError::missing_field)"
+            )?;
+            writeln!(w, ".map_err(|e| e.at(\"{name}.entries\"))")?;
+            writeln!(w, "}}")?;
+        }
+    }
+    writeln!(w)?;
+
+    writeln!(w, "/// Decodes the whole value into an owned DTO")?;
+    writeln!(
+        w,
+        "pub fn to_owned(&self) -> Result<odf::{context}::{name}, FlatbuffersDecodeError> {{"
+    )?;
+    writeln!(w, "odf::{context}::{name}::deserialize(self.proxy)")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
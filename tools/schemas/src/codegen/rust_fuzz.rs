@@ -0,0 +1,520 @@
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use convert_case::{Case, Casing};
+
+use crate::model;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const HEADER: &str = indoc::indoc!(
+    r#"
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+    // WARNING: This file is auto-generated from Open Data Fabric Schemas
+    // See: http://opendatafabric.org/
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+    "#
+);
+
+// Enough to cover every variant of small unions without slowing down the start of fuzzing
+const ARBITRARY_SEEDS: usize = 32;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct Options {
+    /// Name of the crate that includes the generated code
+    pub crate_name: String,
+    /// Path to that crate relative to the fuzz directory
+    pub crate_path: PathBuf,
+    /// Directory of example manifests to seed the YAML and JSON targets with
+    pub examples_dir: PathBuf,
+    /// Directory of golden vectors to seed the flatbuffers targets with
+    pub vectors_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    Flatbuffers,
+    Yaml,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct FuzzTarget {
+    pub name: String,
+    /// Hashable type for flatbuffers targets, or a resource kind for YAML and JSON ones
+    pub type_id: model::TypeId,
+    pub encoding: Encoding,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Untrusted inputs: hashable types arrive as flatbuffers from remotes, while resources are authored by users
+pub fn targets(model: &model::Model) -> Vec<FuzzTarget> {
+    let mut ret = Vec::new();
+
+    for typ in model.types.values() {
+        let model::TypeDefinition::Struct(t) = typ else {
+            continue;
+        };
+
+        let base = format!("{}_{}", t.id.context(), t.id.join("").to_case(Case::Snake));
+
+        if t.hashable().is_some() {
+            ret.push(FuzzTarget {
+                name: format!("{base}_flatbuffers"),
+                type_id: t.id.clone(),
+                encoding: Encoding::Flatbuffers,
+            });
+        }
+
+        if matches!(t.metatype, model::MetaType::Resource) {
+            for (suffix, encoding) in [("yaml", Encoding::Yaml), ("json", Encoding::Json)] {
+                ret.push(FuzzTarget {
+                    name: format!("{base}_{suffix}"),
+                    type_id: t.id.clone(),
+                    encoding,
+                });
+            }
+        }
+    }
+
+    ret
+}
+
+/// Writes a `cargo fuzz` crate with a target per untrusted input and its seed corpora
+pub fn render(model: &model::Model, options: &Options, out_dir: &Path) {
+    let targets = targets(model);
+
+    write_file(&out_dir.join("Cargo.toml"), |w| {
+        render_manifest(&targets, options, w)
+    });
+    write_file(&out_dir.join("seed_corpus.rs"), |w| {
+        render_seed_corpus(model, &targets, options, w)
+    });
+    write_file(&out_dir.join("run.sh"), |w| render_run_script(&targets, w));
+
+    for target in &targets {
+        write_file(
+            &out_dir
+                .join("fuzz_targets")
+                .join(format!("{}.rs", target.name)),
+            |w| render_target(model, target, options, w),
+        );
+
+        let corpus_dir = out_dir.join("corpus").join(&target.name);
+        std::fs::create_dir_all(&corpus_dir).unwrap();
+        for (name, data) in seeds(target, options) {
+            std::fs::write(corpus_dir.join(name), data).unwrap();
+        }
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(
+            out_dir.join("run.sh"),
+            std::fs::Permissions::from_mode(0o755),
+        )
+        .unwrap();
+    }
+}
+
+fn write_file(
+    path: &Path,
+    render: impl FnOnce(&mut dyn std::io::Write) -> Result<(), std::io::Error>,
+) {
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let mut w = std::io::BufWriter::new(
+        std::fs::File::create(path)
+            .unwrap_or_else(|e| panic!("Failed to create {}: {e}", path.display())),
+    );
+    render(&mut w).unwrap();
+    w.flush().unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Seeds from example manifests of the resource kind and from golden vectors of the hashable type
+pub fn seeds(target: &FuzzTarget, options: &Options) -> Vec<(String, Vec<u8>)> {
+    let mut ret = Vec::new();
+
+    match target.encoding {
+        Encoding::Flatbuffers => {
+            let path = options.vectors_dir.join(format!(
+                "{}.yaml",
+                target
+                    .type_id
+                    .schema_id()
+                    .strip_prefix("https://opendatafabric.org/schemas/")
+                    .unwrap()
+            ));
+            if !path.exists() {
+                return ret;
+            }
+
+            let vectors: Vec<serde_json::Value> =
+                serde_yaml::from_str(&std::fs::read_to_string(&path).unwrap())
+                    .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()));
+            for v in vectors {
+                let hex = v["bytes"].as_str().unwrap();
+                let bytes = (0..hex.len())
+                    .step_by(2)
+                    .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+                    .collect();
+                ret.push((format!("vector-{}", v["name"].as_str().unwrap()), bytes));
+            }
+        }
+        Encoding::Yaml | Encoding::Json => {
+            let pattern = options.examples_dir.join("**/*.yaml");
+            let mut paths: Vec<_> = glob::glob(pattern.to_str().unwrap())
+                .unwrap()
+                .map(Result::unwrap)
+                .collect();
+            paths.sort();
+
+            for path in paths {
+                let content = std::fs::read_to_string(&path).unwrap();
+                let manifest: serde_json::Value = serde_yaml::from_str(&content)
+                    .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()));
+                if manifest.get("$schema").and_then(|s| s.as_str())
+                    != Some(target.type_id.schema_id().as_str())
+                {
+                    continue;
+                }
+
+                let name = path
+                    .strip_prefix(&options.examples_dir)
+                    .unwrap()
+                    .with_extension("")
+                    .to_string_lossy()
+                    .replace(std::path::MAIN_SEPARATOR, "-");
+
+                if target.encoding == Encoding::Yaml {
+                    ret.push((format!("example-{name}.yaml"), content.into_bytes()));
+                } else {
+                    ret.push((
+                        format!("example-{name}.json"),
+                        serde_json::to_vec_pretty(&manifest).unwrap(),
+                    ));
+                }
+            }
+        }
+    }
+
+    ret
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_manifest(
+    targets: &[FuzzTarget],
+    options: &Options,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    writeln!(
+        w,
+        "# WARNING: This file is auto-generated from Open Data Fabric Schemas"
+    )?;
+    writeln!(w, "# See: http://opendatafabric.org/")?;
+    writeln!(w)?;
+    writeln!(w, "[package]")?;
+    writeln!(w, "name = \"{}-fuzz\"", options.crate_name)?;
+    writeln!(w, "version = \"0.0.0\"")?;
+    writeln!(w, "publish = false")?;
+    writeln!(w, "edition = \"2024\"")?;
+    writeln!(w)?;
+    writeln!(w, "[package.metadata]")?;
+    writeln!(w, "cargo-fuzz = true")?;
+    writeln!(w)?;
+    writeln!(w, "[dependencies]")?;
+    writeln!(w, "libfuzzer-sys = \"0.4\"")?;
+    writeln!(w, "proptest = \"1\"")?;
+    writeln!(w, "serde_json = \"1\"")?;
+    writeln!(w, "serde_yaml = \"0.9\"")?;
+    writeln!(
+        w,
+        "{} = {{ path = \"{}\", features = [\"proptest\"] }}",
+        options.crate_name,
+        options.crate_path.display()
+    )?;
+    writeln!(w)?;
+    writeln!(w, "# Keeps the fuzz crate out of the parent workspace")?;
+    writeln!(w, "[workspace]")?;
+    writeln!(w, "members = [\".\"]")?;
+
+    let bins = std::iter::once(("seed_corpus".to_string(), "seed_corpus.rs".to_string())).chain(
+        targets
+            .iter()
+            .map(|t| (t.name.clone(), format!("fuzz_targets/{}.rs", t.name))),
+    );
+    for (name, path) in bins {
+        writeln!(w)?;
+        writeln!(w, "[[bin]]")?;
+        writeln!(w, "name = \"{name}\"")?;
+        writeln!(w, "path = \"{path}\"")?;
+        writeln!(w, "test = false")?;
+        writeln!(w, "doc = false")?;
+        writeln!(w, "bench = false")?;
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_target(
+    model: &model::Model,
+    target: &FuzzTarget,
+    options: &Options,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    writeln!(w, "{HEADER}")?;
+    writeln!(w, "#![no_main]")?;
+    writeln!(w)?;
+    writeln!(w, "use libfuzzer_sys::fuzz_target;")?;
+    writeln!(w, "use {} as odf;", options.crate_name.to_case(Case::Snake))?;
+
+    match target.encoding {
+        Encoding::Flatbuffers => {
+            let context = target.type_id.context();
+            let name = target.type_id.join("");
+            let fb_name = if model.ambiguous_names().contains(name.as_ref()) {
+                format!("{context}::{name}")
+            } else {
+                name.to_string()
+            };
+
+            writeln!(w, "use odf::serde::flatbuffers::convertors_generated::*;")?;
+            writeln!(w, "use odf::serde::flatbuffers::proxies_generated as fb;")?;
+            writeln!(w)?;
+            writeln!(w, "fuzz_target!(|data: &[u8]| {{")?;
+            writeln!(
+                w,
+                "    // Malformed buffers must be rejected with an error instead of a panic"
+            )?;
+            writeln!(
+                w,
+                "    let Ok(value) = deserialize_verified::<fb::{fb_name}, odf::{context}::{name}>(data, &FlatbuffersDecodeLimits::default()) else {{"
+            )?;
+            writeln!(w, "        return;")?;
+            writeln!(w, "    }};")?;
+            writeln!(w)?;
+            writeln!(
+                w,
+                "    // Decoded values, including unknown variants, must encode back into a buffer that decodes again"
+            )?;
+            writeln!(w, "    let mut builder = canonical_builder();")?;
+            writeln!(
+                w,
+                "    let offset = value.serialize(&mut builder).expect(\"Decoded value must be encodable\");"
+            )?;
+            writeln!(w, "    builder.finish(offset, None);")?;
+            writeln!(
+                w,
+                "    deserialize_verified::<fb::{fb_name}, odf::{context}::{name}>(builder.finished_data(), &FlatbuffersDecodeLimits::default())"
+            )?;
+            writeln!(
+                w,
+                "        .expect(\"Encoded value must be decodable\");"
+            )?;
+            writeln!(w, "}});")?;
+        }
+        Encoding::Yaml | Encoding::Json => {
+            let model::TypeDefinition::Struct(t) = &model.types[&target.type_id] else {
+                unreachable!()
+            };
+            let Some(model::Field {
+                typ: model::Type::Custom(spec),
+                ..
+            }) = t.fields.get("spec")
+            else {
+                panic!("Resource {} must define a `spec` property", t.id.join("::"));
+            };
+
+            let (from, to) = match target.encoding {
+                Encoding::Yaml => ("serde_yaml::from_slice", "serde_yaml::to_string"),
+                _ => ("serde_json::from_slice", "serde_json::to_string"),
+            };
+
+            writeln!(w, "use odf::serde::yaml::derivations_generated as yaml;")?;
+            writeln!(w, "use yaml::IntoDto;")?;
+            writeln!(w)?;
+            writeln!(
+                w,
+                "type Proxy = yaml::resource::ResourceInput<yaml::{}::{}>;",
+                spec.context(),
+                spec.join("")
+            )?;
+            writeln!(w)?;
+            writeln!(w, "fuzz_target!(|data: &[u8]| {{")?;
+            writeln!(w, "    let Ok(proxy) = {from}::<Proxy>(data) else {{")?;
+            writeln!(w, "        return;")?;
+            writeln!(w, "    }};")?;
+            writeln!(w, "    let Ok(value) = proxy.into_dto() else {{")?;
+            writeln!(w, "        return;")?;
+            writeln!(w, "    }};")?;
+            writeln!(w)?;
+            writeln!(w, "    // Accepted manifests must survive a round trip")?;
+            writeln!(
+                w,
+                "    let text = {to}(&Proxy::from(value.clone())).unwrap();"
+            )?;
+            writeln!(
+                w,
+                "    let actual = {from}::<Proxy>(text.as_bytes()).unwrap().into_dto().unwrap();"
+            )?;
+            writeln!(w, "    assert_eq!(actual, value, \"{{text}}\");")?;
+            writeln!(w, "}});")?;
+        }
+    }
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_seed_corpus(
+    model: &model::Model,
+    targets: &[FuzzTarget],
+    options: &Options,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    writeln!(w, "{HEADER}")?;
+    writeln!(
+        w,
+        "//! Adds values produced by the arbitrary generators to the seed corpora"
+    )?;
+    writeln!(w)?;
+    writeln!(w, "use std::path::Path;")?;
+    writeln!(w)?;
+    writeln!(w, "use {} as odf;", options.crate_name.to_case(Case::Snake))?;
+    writeln!(w, "use odf::serde::flatbuffers::convertors_generated::*;")?;
+    writeln!(w, "use odf::serde::yaml::derivations_generated as yaml;")?;
+    writeln!(w, "use proptest::prelude::*;")?;
+    writeln!(w, "use proptest::strategy::ValueTree;")?;
+    writeln!(w, "use proptest::test_runner::TestRunner;")?;
+    writeln!(w)?;
+    writeln!(w, "const SEEDS_PER_TARGET: usize = {ARBITRARY_SEEDS};")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "fn generate<T: Arbitrary>(target: &str, encode: impl Fn(T) -> Vec<u8>) {{"
+    )?;
+    writeln!(
+        w,
+        "    let dir = Path::new(env!(\"CARGO_MANIFEST_DIR\")).join(\"corpus\").join(target);"
+    )?;
+    writeln!(w, "    std::fs::create_dir_all(&dir).unwrap();")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "    // Same seeds on every run to keep the corpora stable"
+    )?;
+    writeln!(w, "    let mut runner = TestRunner::deterministic();")?;
+    writeln!(w, "    let strategy = any::<T>();")?;
+    writeln!(w, "    for i in 0..SEEDS_PER_TARGET {{")?;
+    writeln!(
+        w,
+        "        let value = strategy.new_tree(&mut runner).unwrap().current();"
+    )?;
+    writeln!(
+        w,
+        "        std::fs::write(dir.join(format!(\"arbitrary-{{i}}\")), encode(value)).unwrap();"
+    )?;
+    writeln!(w, "    }}")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+    writeln!(w, "fn main() {{")?;
+
+    for target in targets {
+        match target.encoding {
+            Encoding::Flatbuffers => writeln!(
+                w,
+                "    generate::<odf::{}::{}>(\"{}\", |v| v.to_canonical_bytes().unwrap());",
+                target.type_id.context(),
+                target.type_id.join(""),
+                target.name
+            )?,
+            Encoding::Yaml | Encoding::Json => {
+                let model::TypeDefinition::Struct(t) = &model.types[&target.type_id] else {
+                    unreachable!()
+                };
+                let Some(model::Field {
+                    typ: model::Type::Custom(spec),
+                    ..
+                }) = t.fields.get("spec")
+                else {
+                    unreachable!()
+                };
+
+                let to = if target.encoding == Encoding::Yaml {
+                    "serde_yaml::to_string"
+                } else {
+                    "serde_json::to_string"
+                };
+
+                writeln!(
+                    w,
+                    "    generate::<odf::resource::ResourceInput<odf::{0}::{1}>>(\"{2}\", |v| {{ {to}(&yaml::resource::ResourceInput::<yaml::{0}::{1}>::from(v)).unwrap().into_bytes() }});",
+                    spec.context(),
+                    spec.join(""),
+                    target.name
+                )?;
+            }
+        }
+    }
+
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_run_script(
+    targets: &[FuzzTarget],
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    writeln!(w, "#!/usr/bin/env bash")?;
+    writeln!(
+        w,
+        "# WARNING: This file is auto-generated from Open Data Fabric Schemas"
+    )?;
+    writeln!(w, "#")?;
+    writeln!(
+        w,
+        "# Runs every fuzz target for a bounded time and fails on the first crash, e.g.:"
+    )?;
+    writeln!(w, "#   FUZZ_SECONDS=30 ./run.sh")?;
+    writeln!(
+        w,
+        "#   ./run.sh {}",
+        targets.first().map_or("", |t| &t.name)
+    )?;
+    writeln!(w, "set -euo pipefail")?;
+    writeln!(w)?;
+    writeln!(w, "cd \"$(dirname \"$0\")\"")?;
+    writeln!(w, "FUZZ_SECONDS=\"${{FUZZ_SECONDS:-60}}\"")?;
+    writeln!(w)?;
+    writeln!(w, "TARGETS=(")?;
+    for target in targets {
+        writeln!(w, "    {}", target.name)?;
+    }
+    writeln!(w, ")")?;
+    writeln!(w, "if [ $# -gt 0 ]; then")?;
+    writeln!(w, "    TARGETS=(\"$@\")")?;
+    writeln!(w, "fi")?;
+    writeln!(w)?;
+    writeln!(w, "cargo run --release --bin seed_corpus")?;
+    writeln!(w)?;
+    writeln!(w, "for target in \"${{TARGETS[@]}}\"; do")?;
+    writeln!(w, "    echo \"Fuzzing $target for ${{FUZZ_SECONDS}}s\"")?;
+    writeln!(
+        w,
+        "    cargo fuzz run \"$target\" \"corpus/$target\" -- -max_total_time=\"$FUZZ_SECONDS\""
+    )?;
+    writeln!(w, "done")?;
+
+    Ok(())
+}
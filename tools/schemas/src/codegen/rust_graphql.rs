@@ -1,3 +1,7 @@
+use std::collections::BTreeSet;
+
+use convert_case::{Case, Casing};
+
 use crate::{
     codegen::rust_common::format_ident,
     json_schema::{CodegenHint, CodegenLanguage},
@@ -25,6 +29,30 @@ const PREAMBLE: &str = indoc::indoc!(
     "#
 );
 
+// Shared by all unions that preserve variants unknown to this version of the schemas
+const UNKNOWN_VARIANT: &str = indoc::indoc!(
+    r#"
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    #[derive(SimpleObject, Debug, Clone)]
+    pub struct UnknownVariant {
+        /// Discriminator of the variant as written by the producer
+        pub tag: String,
+    }
+
+    impl From<odf::metadata::dtos::UnknownVariant> for UnknownVariant {
+        fn from(v: odf::metadata::dtos::UnknownVariant) -> Self {
+            match v {
+                odf::metadata::dtos::UnknownVariant::Json { tag, .. } => Self { tag },
+                odf::metadata::dtos::UnknownVariant::Flatbuffers { tag, .. } => Self {
+                    tag: tag.to_string(),
+                },
+            }
+        }
+    }
+    "#
+);
+
 const CUSTOM_TYPES: [(&str, &str); 11] = [
     (
         "TransformInput",
@@ -683,13 +711,18 @@ fn collect_types_rec(
 
     match typ {
         model::TypeDefinition::Struct(t) => {
-            for f in t.fields.values() {
+            for f in t.fields.values().filter(|f| !f.sensitive) {
                 collect_types_rec_2(model, &f.typ, types);
             }
+            for inst in model.instantiations(t) {
+                for arg in &inst.args {
+                    collect_types_rec(model, arg, types);
+                }
+            }
         }
         model::TypeDefinition::Union(t) => {
             for v in &t.variants {
-                collect_types_rec(model, v, types);
+                collect_types_rec(model, &v.id, types);
             }
         }
         model::TypeDefinition::Enum(_) => {}
@@ -721,8 +754,19 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
     let types = collect_types(&model, &roots);
     let custom_types = std::collections::BTreeMap::from(CUSTOM_TYPES);
 
+    // GQL has a single namespace, so names that clash across contexts get prefixed
+    let ambiguous = model.ambiguous_names();
+
     writeln!(w, "{}", PREAMBLE)?;
 
+    let has_unknown_variants = types.iter().any(|id| match &model.types[id] {
+        model::TypeDefinition::Union(t) => t.has_unknown_variant(),
+        _ => false,
+    });
+    if has_unknown_variants {
+        writeln!(w, "{}", UNKNOWN_VARIANT)?;
+    }
+
     for typ in types {
         let typ = &model.types[&typ];
 
@@ -761,10 +805,25 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
             writeln!(w, "{custom}")?;
         } else {
             match &typ {
-                model::TypeDefinition::Struct(t) => render_struct(t, w)?,
-                model::TypeDefinition::Union(t) => render_union(t, w)?,
-                model::TypeDefinition::Enum(t) => render_enum(t, w)?,
-                model::TypeDefinition::Map(t) => render_map(t, w)?,
+                model::TypeDefinition::Struct(t) => {
+                    render_struct(t, &format_dto_type(t), &ambiguous, w)?;
+
+                    // GQL has no generics, so concrete instances are rendered as separate types
+                    for inst in model.instantiations(t) {
+                        let args: Vec<_> = inst.args.iter().map(format_dto_type_name).collect();
+                        let dto_type = format!(
+                            "odf::metadata::{}::{}<{}>",
+                            t.id.context(),
+                            t.id.join(""),
+                            args.join(", ")
+                        );
+                        writeln!(w)?;
+                        render_struct(&inst.typ, &dto_type, &ambiguous, w)?;
+                    }
+                }
+                model::TypeDefinition::Union(t) => render_union(t, &model, &ambiguous, w)?,
+                model::TypeDefinition::Enum(t) => render_enum(t, &ambiguous, w)?,
+                model::TypeDefinition::Map(t) => render_map(t, &ambiguous, w)?,
             }
         }
         writeln!(w)?;
@@ -774,33 +833,52 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
-    let context = typ.id.context();
-    let name = typ.id.join("");
-    let generics = format!(
-        "<{}>",
+fn format_dto_type_name(id: &model::TypeId) -> String {
+    format!("odf::metadata::{}::{}", id.context(), id.join(""))
+}
+
+// Type parameters are erased to JSON values
+fn format_dto_type(typ: &model::Struct) -> String {
+    if typ.generics.is_empty() {
+        return format_dto_type_name(&typ.id);
+    }
+    format!(
+        "{}<{}>",
+        format_dto_type_name(&typ.id),
         typ.generics
             .iter()
             .map(|_| "serde_json::Value".to_string())
             .collect::<Vec<_>>()
             .join(", ")
-    );
+    )
+}
+
+fn render_struct(
+    typ: &model::Struct,
+    dto_type: &str,
+    ambiguous: &BTreeSet<String>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let gql_name = format_type_name(&typ.id, ambiguous);
+
+    // Secrets are never exposed through the API
+    let fields: Vec<_> = typ.fields.values().filter(|f| !f.sensitive).collect();
 
     writeln!(w, "#[derive(SimpleObject, Debug, Clone)]")?;
-    writeln!(w, "pub struct {name} {{")?;
+    writeln!(w, "pub struct {gql_name} {{")?;
 
-    if typ.fields.is_empty() {
+    if fields.is_empty() {
         writeln!(w, "pub _dummy: Option<String>,")?;
     }
 
-    for field in typ.fields.values() {
+    for field in &fields {
         render_description(
             &field.description,
             field.default.as_ref(),
             field.examples.as_ref(),
             w,
         )?;
-        let mut typ = format_type(&field.typ);
+        let mut typ = format_type(&field.typ, ambiguous);
         if let Some(container) =
             field.get_hint::<String>(CodegenLanguage::Rust, CodegenHint::Container)
         {
@@ -815,21 +893,15 @@ fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(),
     writeln!(w, "}}")?;
 
     writeln!(w)?;
-    writeln!(
-        w,
-        "impl From<odf::metadata::{context}::{name}{generics}> for {name} {{"
-    )?;
-    writeln!(
-        w,
-        "fn from(v: odf::metadata::{context}::{name}{generics}) -> Self {{"
-    )?;
+    writeln!(w, "impl From<{dto_type}> for {gql_name} {{")?;
+    writeln!(w, "fn from(v: {dto_type}) -> Self {{")?;
     writeln!(w, "Self {{")?;
 
-    if typ.fields.is_empty() {
+    if fields.is_empty() {
         writeln!(w, "_dummy: None")?;
     }
 
-    for field in typ.fields.values() {
+    for field in &fields {
         let fname = format_ident(&field.name);
 
         let container = field.get_hint::<String>(CodegenLanguage::Rust, CodegenHint::Container);
@@ -869,30 +941,70 @@ fn render_struct(typ: &model::Struct, w: &mut dyn std::io::Write) -> Result<(),
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+fn render_union(
+    typ: &model::Union,
+    model: &model::Model,
+    ambiguous: &BTreeSet<String>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = typ.id.join("");
+    let gql_name = format_type_name(&typ.id, ambiguous);
+
+    // Clients tell variants apart by their GQL type names, so serialized tags only go into the docs
+    if let Some(discriminator) = typ.discriminator_description() {
+        writeln!(w, "///")?;
+        writeln!(w, "/// {discriminator}")?;
+    }
+
+    // GQL unions can only consist of object types, which only tagged variants are guaranteed to be, so other unions
+    // are exposed in their serialized form
+    if typ.variants.iter().any(|v| {
+        !matches!(
+            model.types.get(&v.id),
+            Some(model::TypeDefinition::Struct(_))
+        )
+    }) {
+        return render_json_scalar(&typ.id, ambiguous, w);
+    }
 
     writeln!(w, "#[derive(Union, Debug, Clone)]")?;
-    writeln!(w, "pub enum {name} {{")?;
+    writeln!(w, "pub enum {gql_name} {{")?;
     for variant in &typ.variants {
-        writeln!(w, "{}({}),", variant.name(), variant.join(""))?;
+        if variant.has_custom_tag() {
+            writeln!(w, "/// Tagged as `{}`", variant.tag.as_deref().unwrap())?;
+        }
+        writeln!(
+            w,
+            "{}({}),",
+            variant.id.name(),
+            format_type_name(&variant.id, ambiguous)
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(w, "Unknown(UnknownVariant),")?;
     }
     writeln!(w, "}}")?;
     writeln!(w)?;
     writeln!(
         w,
-        "impl From<odf::metadata::{context}::{name}> for {name} {{"
+        "impl From<odf::metadata::{context}::{name}> for {gql_name} {{"
     )?;
     writeln!(w, "fn from(v: odf::metadata::{context}::{name}) -> Self {{")?;
     writeln!(w, "match v {{")?;
     for variant in &typ.variants {
-        let varname = &variant.name();
+        let varname = &variant.id.name();
         writeln!(
             w,
             "odf::metadata::{context}::{name}::{varname}(v) => Self::{varname}(v.into()),"
         )?;
     }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "odf::metadata::{context}::{name}::Unknown(v) => Self::Unknown(v.into()),"
+        )?;
+    }
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
@@ -902,40 +1014,88 @@ fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), st
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn render_enum(typ: &model::Enum, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+fn render_enum(
+    typ: &model::Enum,
+    ambiguous: &BTreeSet<String>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = typ.id.join("");
+    let gql_name = format_type_name(&typ.id, ambiguous);
 
     writeln!(w, "#[derive(Enum, Debug, Clone, Copy, PartialEq, Eq)]")?;
-    writeln!(
-        w,
-        "#[graphql(remote = \"odf::metadata::{context}::{name}\")]"
-    )?;
+    // Remote derive can't map the data-carrying unknown variant, so conversion is one-way
+    if !typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "#[graphql(remote = \"odf::metadata::{context}::{name}\")]"
+        )?;
+    }
 
-    writeln!(w, "pub enum {} {{", typ.id.join(""))?;
+    writeln!(w, "pub enum {gql_name} {{")?;
     for variant in &typ.variants {
         writeln!(w, "{variant},")?;
     }
+    if typ.has_unknown_variant() {
+        writeln!(w, "Unknown,")?;
+    }
     writeln!(w, "}}")?;
 
+    if typ.has_unknown_variant() {
+        writeln!(w)?;
+        writeln!(
+            w,
+            "impl From<odf::metadata::{context}::{name}> for {gql_name} {{"
+        )?;
+        writeln!(w, "fn from(v: odf::metadata::{context}::{name}) -> Self {{")?;
+        writeln!(w, "match v {{")?;
+        for variant in &typ.variants {
+            writeln!(
+                w,
+                "odf::metadata::{context}::{name}::{variant} => Self::{variant},"
+            )?;
+        }
+        writeln!(
+            w,
+            "odf::metadata::{context}::{name}::Unknown(_) => Self::Unknown,"
+        )?;
+        writeln!(w, "}}")?;
+        writeln!(w, "}}")?;
+        writeln!(w, "}}")?;
+    }
+
     Ok(())
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 // We represent all maps as JSON scalars
-fn render_map(typ: &model::Map, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
-    let context = typ.id.context();
-    let name = typ.id.join("");
+fn render_map(
+    typ: &model::Map,
+    ambiguous: &BTreeSet<String>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    render_json_scalar(&typ.id, ambiguous, w)
+}
+
+/// Renders a scalar that is passed around in the same form as in YAML manifests
+fn render_json_scalar(
+    id: &model::TypeId,
+    ambiguous: &BTreeSet<String>,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let context = id.context();
+    let name = id.join("");
+    let gql_name = format_type_name(id, ambiguous);
 
     writeln!(
         w,
         r#"
         #[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]
-        pub struct {name}(odf::metadata::{context}::{name});
+        pub struct {gql_name}(odf::metadata::{context}::{name});
         
         #[async_graphql::Scalar]
-        impl async_graphql::ScalarType for {name} {{
+        impl async_graphql::ScalarType for {gql_name} {{
             fn parse(value: async_graphql::Value) -> async_graphql::InputValueResult<Self> {{
                 let proxy: odf::metadata::serde::yaml::{context}::{name} = async_graphql::from_value(value)?;
                 let dto = proxy.try_into().map_err(|e: odf::metadata::errors::ValidationError| async_graphql::InputValueError::custom(e.to_string()))?;
@@ -954,7 +1114,16 @@ fn render_map(typ: &model::Map, w: &mut dyn std::io::Write) -> Result<(), std::i
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn format_type(typ: &model::Type) -> String {
+fn format_type_name(id: &model::TypeId, ambiguous: &BTreeSet<String>) -> String {
+    let name = id.join("");
+    if ambiguous.contains(name.as_ref()) {
+        format!("{}{name}", id.context().to_case(Case::Pascal))
+    } else {
+        name.into_owned()
+    }
+}
+
+fn format_type(typ: &model::Type, ambiguous: &BTreeSet<String>) -> String {
     match typ {
         model::Type::Boolean => format!("bool"),
         model::Type::Int8 => format!("i8"),
@@ -980,8 +1149,8 @@ fn format_type(typ: &model::Type) -> String {
         model::Type::Url => format!("String"),
         model::Type::Did => format!("Did<'static>"),
         model::Type::Generic(_) => format!("serde_json::Value"),
-        model::Type::Array(t) => format!("Vec<{}>", format_type(&t.item_type)),
-        model::Type::Custom(name) => name.join("").to_string(),
+        model::Type::Array(t) => format!("Vec<{}>", format_type(&t.item_type, ambiguous)),
+        model::Type::Custom(id) => format_type_name(id, ambiguous),
         model::Type::AnyJson => format!("serde_json::Value"),
         model::Type::AccountId => format!("AccountID<'static>"),
         model::Type::AccountName => format!("AccountName<'static>"),
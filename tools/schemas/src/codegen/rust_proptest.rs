@@ -0,0 +1,748 @@
+use crate::codegen::rust_common::format_ident;
+use crate::json_schema::{self, CodegenHint, CodegenLanguage};
+use crate::model;
+use crate::utils::indent_writer::IndentWriter;
+use convert_case::{Case, Casing};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+
+const PREAMBLE: &str = indoc::indoc!(
+    r#"
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+    // WARNING: This file is auto-generated from Open Data Fabric Schemas
+    // See: http://opendatafabric.org/
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    #![allow(unused_imports)]
+    #![allow(clippy::all)]
+    #![allow(clippy::pedantic)]
+
+    use crate as odf;
+    use crate::serde::flatbuffers::convertors_generated::*;
+    use crate::serde::flatbuffers::proxies_generated as fb;
+    use crate::serde::yaml::derivations_generated as yaml;
+
+    use chrono::prelude::*;
+    use proptest::prelude::*;
+    use setty::types::{ByteSize, DurationString};
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    // Patterns of ODF string formats that values are generated from
+    pub const DID: &str = r"did:odf:fed01[0-9a-f]{64}";
+    pub const MULTIHASH: &str = r"f1620[0-9a-f]{64}";
+    pub const MULTICODEC: &str = r"[a-z][a-z0-9-]{0,15}";
+    pub const NAME: &str = r"[a-z]([a-z0-9-]{0,14}[a-z0-9])?";
+    pub const DATASET_ALIAS: &str = r"([a-z]([a-z0-9-]{0,14}[a-z0-9])?/)?[a-z]([a-z0-9-]{0,14}[a-z0-9])?";
+    pub const DATASET_REF: &str = r"did:odf:fed01[0-9a-f]{64}|([a-z]([a-z0-9-]{0,14}[a-z0-9])?/)?[a-z]([a-z0-9-]{0,14}[a-z0-9])?";
+    pub const RESOURCE_ID: &str = r"[0-9a-f]{8}-[0-9a-f]{4}-4[0-9a-f]{3}-[89ab][0-9a-f]{3}-[0-9a-f]{12}";
+    pub const TYPE_NAME: &str = r"[A-Z][A-Za-z0-9]{0,15}";
+    pub const TYPE_URI: &str = r"https://opendatafabric\.org/schemas/[a-z]{1,8}/v1alpha1/[A-Z][A-Za-z0-9]{0,15}";
+    pub const TYPE_REF: &str = r"[A-Z][A-Za-z0-9]{0,15}|https://opendatafabric\.org/schemas/[a-z]{1,8}/v1alpha1/[A-Z][A-Za-z0-9]{0,15}";
+    pub const DURATION: &str = r"[1-9][0-9]{0,4}(ms|s|m|h|d)";
+    pub const PATH: &str = r"(/[a-z0-9_.-]{1,8}){1,4}";
+    pub const URL: &str = r"https://[a-z]{1,8}\.example\.com(/[a-z0-9]{1,8}){0,3}";
+    pub const REGEX: &str = r"\^?[a-z]{1,8}(\.\*)?\$?";
+
+    /// Generates values from their text representation, parsing them the same way manifests are read
+    pub fn from_text<T: serde::de::DeserializeOwned + std::fmt::Debug>(pattern: &'static str) -> impl Strategy<Value = T> {
+        proptest::string::string_regex(pattern)
+            .unwrap()
+            .prop_map(|s| serde_json::from_value(serde_json::Value::String(s)).unwrap())
+    }
+
+    pub fn any_byte_size() -> impl Strategy<Value = ByteSize> {
+        any::<u32>().prop_map(|v| serde_json::from_value(v.into()).unwrap())
+    }
+
+    /// Timestamps between the epoch and the end of year 9999 with nanosecond precision
+    pub fn any_date_time() -> impl Strategy<Value = DateTime<Utc>> {
+        (0i64..253402300800, 0u32..1_000_000_000).prop_map(|(s, ns)| Utc.timestamp_opt(s, ns).unwrap())
+    }
+
+    /// JSON values without floats, as those don't survive a round trip through YAML
+    pub fn any_json() -> impl Strategy<Value = serde_json::Value> {
+        let leaf = prop_oneof![
+            Just(serde_json::Value::Null),
+            any::<bool>().prop_map(serde_json::Value::from),
+            any::<i64>().prop_map(serde_json::Value::from),
+            "[a-z0-9 ]{0,8}".prop_map(serde_json::Value::from),
+        ];
+        leaf.prop_recursive(3, 16, 4, |inner| {
+            prop_oneof![
+                proptest::collection::vec(inner.clone(), 0..4).prop_map(serde_json::Value::from),
+                proptest::collection::btree_map("[a-z]{1,8}", inner, 0..4)
+                    .prop_map(|m| serde_json::Value::Object(m.into_iter().collect())),
+            ]
+        })
+    }
+
+    /// Asserts that the value survives a round trip through JSON and YAML using its serde proxy `P`
+    pub fn check_serde_roundtrip<T, P>(value: &T) -> Result<(), TestCaseError>
+    where
+        T: Clone + PartialEq + std::fmt::Debug,
+        P: From<T> + TryInto<T> + serde::Serialize + serde::de::DeserializeOwned,
+        <P as TryInto<T>>::Error: std::fmt::Debug,
+    {
+        let json = serde_json::to_string(&P::from(value.clone())).unwrap();
+        let actual: T = serde_json::from_str::<P>(&json).unwrap().try_into().unwrap();
+        prop_assert_eq!(&actual, value, "JSON: {}", json);
+
+        let yaml = serde_yaml::to_string(&P::from(value.clone())).unwrap();
+        let actual: T = serde_yaml::from_str::<P>(&yaml).unwrap().try_into().unwrap();
+        prop_assert_eq!(&actual, value, "YAML: {}", yaml);
+        Ok(())
+    }
+
+    /// Asserts that the value survives a round trip through a verified flatbuffer with root proxy `$proxy`
+    macro_rules! check_flatbuffers_roundtrip {
+        ($value:expr, $dto:ty, $proxy:ty) => {{
+            let mut fb = ::flatbuffers::FlatBufferBuilder::new();
+            let offset = FlatbuffersSerializable::serialize(&$value, &mut fb).unwrap();
+            fb.finish(offset, None);
+            let actual: $dto = deserialize_verified::<$proxy, $dto>(
+                fb.finished_data(),
+                &FlatbuffersDecodeLimits::default(),
+            )
+            .unwrap();
+            prop_assert_eq!(&actual, &$value);
+        }};
+    }
+    "#
+);
+
+// Limits of the recursive strategies: nesting depth, total number of nodes, and items per collection
+const RECURSION_DEPTH: u32 = 3;
+const RECURSION_SIZE: u32 = 16;
+const RECURSION_ITEMS: u32 = 4;
+
+// Proptest implements strategies for tuples of up to 12 elements
+const MAX_TUPLE_LEN: usize = 12;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Helpers {
+    model: model::Model,
+    ambiguous_names: BTreeSet<String>,
+    /// Recursive unions and the types that refer back to them
+    recursive: BTreeMap<model::TypeId, BTreeSet<model::TypeId>>,
+}
+
+impl Helpers {
+    /// Path of the flatbuffers proxy type relative to the `fb` module
+    fn fb_name(&self, id: &model::TypeId) -> String {
+        let name = id.join("");
+        if self.ambiguous_names.contains(name.as_ref()) {
+            format!("{}::{name}", id.context())
+        } else {
+            name.into_owned()
+        }
+    }
+
+    fn is_external(&self, id: &model::TypeId) -> bool {
+        self.model
+            .types
+            .get(id)
+            .unwrap()
+            .get_hint::<String>(CodegenLanguage::Rust, CodegenHint::DtoType)
+            .is_some()
+    }
+
+    /// Recursive union that the type is a part of, if any
+    fn recursion_root(&self, id: &model::TypeId) -> Option<&model::TypeId> {
+        self.recursive
+            .iter()
+            .find(|(root, members)| *root == id || members.contains(id))
+            .map(|(root, _)| root)
+    }
+
+    /// Same set of types as in flatbuffers convertors
+    fn has_flatbuffers(&self, typ: &model::TypeDefinition) -> bool {
+        !matches!(
+            typ.id().name(),
+            "Manifest" | "DatasetSnapshot" | "Resource" | "ResourceInput"
+        ) && !matches!(typ.metatype(), model::MetaType::Resource)
+            && !self.is_external(typ.id())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+    let mut w = IndentWriter::new(w, "  ");
+    render_impl(model, &mut w)
+}
+
+fn render_impl(
+    model: model::Model,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    writeln!(w, "{}", PREAMBLE)?;
+
+    let helpers = Helpers {
+        model: model.clone(),
+        ambiguous_names: model.ambiguous_names(),
+        recursive: recursive_unions(&model),
+    };
+
+    for typ in model.types.values() {
+        // Externally defined types should implement `Arbitrary` by hand
+        if helpers.is_external(typ.id()) {
+            continue;
+        }
+
+        writeln!(
+            w,
+            "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
+        )?;
+        writeln!(w, "// {}", typ.id().join(""))?;
+        writeln!(w, "// Schema: {}", typ.id().schema_id())?;
+        writeln!(
+            w,
+            "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
+        )?;
+        writeln!(w)?;
+
+        match &typ {
+            model::TypeDefinition::Struct(t) => render_struct(t, &helpers, w)?,
+            model::TypeDefinition::Union(t) => render_union(t, &helpers, w)?,
+            model::TypeDefinition::Enum(t) => render_enum(t, w)?,
+            model::TypeDefinition::Map(t) => render_map(t, &helpers, w)?,
+        }
+        writeln!(w)?;
+    }
+
+    render_tests(&helpers, w)?;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Generating a value of a recursive type eagerly would never terminate, so each recursive union becomes a root of
+// `prop_recursive` strategy and all types on the cycle back to it are generated from its bounded inner strategy
+fn recursive_unions(model: &model::Model) -> BTreeMap<model::TypeId, BTreeSet<model::TypeId>> {
+    let reachable: BTreeMap<&model::TypeId, BTreeSet<model::TypeId>> = model
+        .types
+        .keys()
+        .map(|id| (id, reachable_types(model, id)))
+        .collect();
+
+    let mut ret = BTreeMap::new();
+    for (id, reach) in &reachable {
+        if !reach.contains(*id) {
+            continue;
+        }
+
+        let cycle: BTreeSet<model::TypeId> = reach
+            .iter()
+            .filter(|other| reachable[other].contains(*id))
+            .cloned()
+            .collect();
+
+        let roots: Vec<_> = cycle
+            .iter()
+            .filter(|t| matches!(model.types[*t], model::TypeDefinition::Union(_)))
+            .collect();
+
+        match roots.as_slice() {
+            [root] if *root == *id => {
+                ret.insert(
+                    (*id).clone(),
+                    cycle.into_iter().filter(|t| t != *id).collect(),
+                );
+            }
+            [_] => (),
+            _ => panic!(
+                "Recursive types must refer back to themselves through exactly one union: {}",
+                id.join("::")
+            ),
+        }
+    }
+
+    for members in ret.values() {
+        for id in members {
+            if !matches!(model.types[id], model::TypeDefinition::Struct(_)) {
+                panic!(
+                    "Only structs can refer back to a recursive union: {}",
+                    id.join("::")
+                );
+            }
+        }
+    }
+
+    ret
+}
+
+fn reachable_types(model: &model::Model, id: &model::TypeId) -> BTreeSet<model::TypeId> {
+    let mut ret = BTreeSet::new();
+    let mut stack = referenced_types(&model.types[id]);
+
+    while let Some(id) = stack.pop() {
+        if ret.insert(id.clone()) {
+            stack.extend(referenced_types(&model.types[&id]));
+        }
+    }
+
+    ret
+}
+
+fn referenced_types(typ: &model::TypeDefinition) -> Vec<model::TypeId> {
+    fn custom(typ: &model::Type) -> Option<model::TypeId> {
+        match typ {
+            model::Type::Custom(id) => Some(id.clone()),
+            model::Type::Array(arr) => custom(&arr.item_type),
+            _ => None,
+        }
+    }
+
+    match typ {
+        model::TypeDefinition::Struct(t) => {
+            t.fields.values().filter_map(|f| custom(&f.typ)).collect()
+        }
+        model::TypeDefinition::Union(t) => t.variants.iter().map(|v| v.id.clone()).collect(),
+        model::TypeDefinition::Enum(_) => Vec::new(),
+        model::TypeDefinition::Map(t) => custom(&t.value_type).into_iter().collect(),
+    }
+}
+
+/// Name of the function that generates a type on a cycle from the strategy of its recursive union
+fn recursive_fn_name(id: &model::TypeId) -> String {
+    format!(
+        "arbitrary_{}_{}",
+        id.context(),
+        id.join("").to_case(Case::Snake)
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_struct(
+    typ: &model::Struct,
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+    let root = helpers.recursion_root(&typ.id).map(|root| (root, "inner"));
+
+    let fields: Vec<_> = typ
+        .fields
+        .values()
+        .filter(|f| f.constant.is_none())
+        .collect();
+
+    let strategies: Vec<_> = fields
+        .iter()
+        .map(|f| format_field_strategy(f, root, helpers))
+        .collect();
+    let idents: Vec<_> = fields.iter().map(|f| format_ident(&f.name)).collect();
+
+    let format_value = |ctor: &str| {
+        if fields.is_empty() {
+            format!("Just({ctor} {{}})")
+        } else {
+            format!(
+                "{}.prop_map(|{}| {ctor} {{ {} }})",
+                format_tuple(&strategies),
+                format_tuple(&idents),
+                idents.join(", ")
+            )
+        }
+    };
+
+    if let Some((root, arg)) = root {
+        writeln!(
+            w,
+            "/// Generates [`odf::{context}::{name}`] with nested [`odf::{}::{}`] values drawn from the bounded strategy",
+            root.context(),
+            root.join("")
+        )?;
+        writeln!(
+            w,
+            "pub fn {}({arg}: BoxedStrategy<odf::{}::{}>) -> BoxedStrategy<odf::{context}::{name}> {{",
+            recursive_fn_name(&typ.id),
+            root.context(),
+            root.join("")
+        )?;
+        writeln!(w, "{}", format_value(&format!("odf::{context}::{name}")))?;
+        writeln!(w, ".boxed()")?;
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+
+    if typ.generics.is_empty() {
+        writeln!(w, "impl Arbitrary for odf::{context}::{name} {{")?;
+    } else {
+        let generics = typ.generic_names();
+        writeln!(
+            w,
+            "impl<{}> Arbitrary for odf::{context}::{name}<{}> {{",
+            generics
+                .iter()
+                .map(|g| format!("{g}: Arbitrary + 'static"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            generics.join(", ")
+        )?;
+    }
+    writeln!(w, "type Parameters = ();")?;
+    writeln!(w, "type Strategy = BoxedStrategy<Self>;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {{"
+    )?;
+    if let Some((root, _)) = root {
+        writeln!(
+            w,
+            "{}(any::<odf::{}::{}>())",
+            recursive_fn_name(&typ.id),
+            root.context(),
+            root.join("")
+        )?;
+    } else {
+        writeln!(w, "{}", format_value("Self"))?;
+        writeln!(w, ".boxed()")?;
+    }
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_union(
+    typ: &model::Union,
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+
+    // Unknown variants are never generated as they can't be serialized
+    let format_variant = |v: &model::UnionVariant, strategy: String| {
+        format!(
+            "{strategy}.prop_map(odf::{context}::{name}::{})",
+            v.id.name()
+        )
+    };
+
+    writeln!(w, "impl Arbitrary for odf::{context}::{name} {{")?;
+    writeln!(w, "type Parameters = ();")?;
+    writeln!(w, "type Strategy = BoxedStrategy<Self>;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {{"
+    )?;
+
+    if let Some(members) = helpers.recursive.get(&typ.id) {
+        let (recursive, leaves): (Vec<_>, Vec<_>) =
+            typ.variants.iter().partition(|v| members.contains(&v.id));
+
+        if leaves.is_empty() {
+            panic!(
+                "Recursive union must have at least one non-recursive variant: {}",
+                typ.id.join("::")
+            );
+        }
+
+        writeln!(w, "let leaf = prop_oneof![")?;
+        for v in leaves {
+            writeln!(
+                w,
+                "{},",
+                format_variant(
+                    v,
+                    format!("any::<odf::{}::{}>()", v.id.context(), v.id.join(""))
+                )
+            )?;
+        }
+        writeln!(w, "];")?;
+        writeln!(
+            w,
+            "leaf.prop_recursive({RECURSION_DEPTH}, {RECURSION_SIZE}, {RECURSION_ITEMS}, |inner| {{"
+        )?;
+        writeln!(w, "prop_oneof![")?;
+        for v in recursive {
+            writeln!(
+                w,
+                "{},",
+                format_variant(v, format!("{}(inner.clone())", recursive_fn_name(&v.id)))
+            )?;
+        }
+        writeln!(w, "]")?;
+        writeln!(w, "}})")?;
+        writeln!(w, ".boxed()")?;
+    } else {
+        writeln!(w, "prop_oneof![")?;
+        for v in &typ.variants {
+            writeln!(
+                w,
+                "{},",
+                format_variant(
+                    v,
+                    format!("any::<odf::{}::{}>()", v.id.context(), v.id.join(""))
+                )
+            )?;
+        }
+        writeln!(w, "]")?;
+        writeln!(w, ".boxed()")?;
+    }
+
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_enum(
+    typ: &model::Enum,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+
+    writeln!(w, "impl Arbitrary for odf::{context}::{name} {{")?;
+    writeln!(w, "type Parameters = ();")?;
+    writeln!(w, "type Strategy = BoxedStrategy<Self>;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {{"
+    )?;
+    writeln!(w, "proptest::sample::select(vec![")?;
+    for variant in &typ.variants {
+        writeln!(w, "Self::{variant},")?;
+    }
+    writeln!(w, "])")?;
+    writeln!(w, ".boxed()")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_map(
+    typ: &model::Map,
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+
+    let key_type = match typ
+        .get_hint::<json_schema::Format>(CodegenLanguage::Rust, CodegenHint::MapKeyFormat)
+    {
+        None => model::Type::String,
+        Some(json_schema::Format::TypeRef) => model::Type::TypeRef,
+        Some(fmt) => panic!("Unsupported map key format {fmt:?}"),
+    };
+
+    writeln!(w, "impl Arbitrary for odf::{context}::{name} {{")?;
+    writeln!(w, "type Parameters = ();")?;
+    writeln!(w, "type Strategy = BoxedStrategy<Self>;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {{"
+    )?;
+    writeln!(
+        w,
+        "proptest::collection::btree_map({}, {}, 0..{RECURSION_ITEMS})",
+        format_strategy(&key_type, None, helpers),
+        format_strategy(&typ.value_type, None, helpers),
+    )?;
+    writeln!(w, ".prop_map(|entries| Self {{ entries }})")?;
+    writeln!(w, ".boxed()")?;
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_tests(
+    helpers: &Helpers,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    writeln!(
+        w,
+        "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
+    )?;
+    writeln!(w, "// Round trips")?;
+    writeln!(
+        w,
+        "////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////"
+    )?;
+    writeln!(w)?;
+
+    writeln!(w, "#[cfg(test)]")?;
+    writeln!(w, "mod tests {{")?;
+    writeln!(w, "use super::*;")?;
+    writeln!(w)?;
+    writeln!(w, "proptest! {{")?;
+
+    for typ in helpers.model.types.values() {
+        // Resources have no serde proxies, and generic types are only serialized via their instances
+        if helpers.is_external(typ.id()) || matches!(typ.metatype(), model::MetaType::Resource) {
+            continue;
+        }
+        if let model::TypeDefinition::Struct(t) = typ
+            && !t.generics.is_empty()
+        {
+            continue;
+        }
+
+        let context = typ.id().context();
+        let name = typ.id().join("");
+
+        writeln!(w, "#[test]")?;
+        writeln!(
+            w,
+            "fn test_roundtrip_{context}_{}(value in any::<odf::{context}::{name}>()) {{",
+            name.to_case(Case::Snake)
+        )?;
+        writeln!(
+            w,
+            "check_serde_roundtrip::<_, yaml::{context}::{name}>(&value)?;"
+        )?;
+
+        // Only tables can be the root of a flatbuffer
+        if matches!(typ, model::TypeDefinition::Struct(_)) && helpers.has_flatbuffers(typ) {
+            writeln!(
+                w,
+                "check_flatbuffers_roundtrip!(value, odf::{context}::{name}, fb::{}<'_>);",
+                helpers.fb_name(typ.id())
+            )?;
+        }
+        writeln!(w, "}}")?;
+        writeln!(w)?;
+    }
+
+    writeln!(w, "}}")?;
+    writeln!(w, "}}")?;
+
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn format_field_strategy(
+    field: &model::Field,
+    root: Option<(&model::TypeId, &str)>,
+    helpers: &Helpers,
+) -> String {
+    let mut strategy = if field.validations.is_empty() {
+        format_strategy(&field.typ, root, helpers)
+    } else {
+        // Values are parsed from JSON so that any scalar type can be restricted
+        if matches!(field.typ, model::Type::Custom(_) | model::Type::Array(_)) {
+            panic!(
+                "Enum validations are only supported on scalar fields: {}",
+                field.name
+            );
+        }
+
+        let mut values = Vec::new();
+        for validation in &field.validations {
+            match validation {
+                model::Validation::Enum(e) => {
+                    values.extend(e.values.iter().map(|v| format!("r#\"{v}\"#")))
+                }
+            }
+        }
+        format!(
+            "proptest::sample::select(vec![{}]).prop_map(|v| serde_json::from_str(v).unwrap())",
+            values.join(", ")
+        )
+    };
+
+    if let Some(container) = field.get_hint::<String>(CodegenLanguage::Rust, CodegenHint::Container)
+    {
+        strategy = format!("{strategy}.prop_map({container}::new)");
+    }
+    if field.wraps_sensitive() {
+        strategy = format!("{strategy}.prop_map(odf::dtos::Sensitive::new)");
+    }
+    if field.optional {
+        strategy = format!("proptest::option::of({strategy})");
+    }
+    strategy
+}
+
+fn format_strategy(
+    typ: &model::Type,
+    root: Option<(&model::TypeId, &str)>,
+    helpers: &Helpers,
+) -> String {
+    match typ {
+        model::Type::Boolean => "any::<bool>()".to_string(),
+        model::Type::Int8 => "any::<i8>()".to_string(),
+        model::Type::Int16 => "any::<i16>()".to_string(),
+        model::Type::Int32 => "any::<i32>()".to_string(),
+        model::Type::Int64 => "any::<i64>()".to_string(),
+        model::Type::UInt8 => "any::<u8>()".to_string(),
+        model::Type::UInt16 => "any::<u16>()".to_string(),
+        model::Type::UInt32 => "any::<u32>()".to_string(),
+        model::Type::UInt64 => "any::<u64>()".to_string(),
+        model::Type::String => "any::<String>()".to_string(),
+
+        model::Type::ByteSize => "any_byte_size()".to_string(),
+        model::Type::DateTime => "any_date_time()".to_string(),
+        model::Type::Duration => "from_text::<DurationString>(DURATION)".to_string(),
+        model::Type::Multicodec => "from_text::<String>(MULTICODEC)".to_string(),
+        model::Type::Multihash => "from_text::<odf::Multihash>(MULTIHASH)".to_string(),
+        model::Type::Path => "from_text::<std::path::PathBuf>(PATH)".to_string(),
+        model::Type::Regex => "from_text::<String>(REGEX)".to_string(),
+        model::Type::Url => "from_text::<String>(URL)".to_string(),
+        model::Type::Did => "from_text::<odf::Did>(DID)".to_string(),
+
+        model::Type::AccountId => "from_text::<odf::AccountID>(DID)".to_string(),
+        model::Type::AccountName => "from_text::<odf::AccountName>(NAME)".to_string(),
+        model::Type::DatasetAlias => "from_text::<odf::DatasetAlias>(DATASET_ALIAS)".to_string(),
+        model::Type::DatasetRef => "from_text::<odf::DatasetRef>(DATASET_REF)".to_string(),
+        model::Type::DatasetId => "from_text::<odf::DatasetID>(DID)".to_string(),
+        model::Type::ResourceId => "from_text::<odf::ResourceID>(RESOURCE_ID)".to_string(),
+        model::Type::ResourceName => "from_text::<odf::ResourceName>(NAME)".to_string(),
+        model::Type::TypeUri => "from_text::<odf::TypeUri>(TYPE_URI)".to_string(),
+        model::Type::TypeName => "from_text::<odf::TypeName>(TYPE_NAME)".to_string(),
+        model::Type::TypeRef => "from_text::<odf::TypeRef>(TYPE_REF)".to_string(),
+
+        model::Type::Flatbuffers => "proptest::collection::vec(any::<u8>(), 0..64)".to_string(),
+        model::Type::Generic(name) => format!("any::<{name}>()"),
+        model::Type::Array(t) => format!(
+            "proptest::collection::vec({}, 0..{RECURSION_ITEMS})",
+            format_strategy(&t.item_type, root, helpers)
+        ),
+        model::Type::Custom(id) => match root {
+            Some((root, arg)) if root == id => format!("{arg}.clone()"),
+            Some((root, arg)) if helpers.recursive[root].contains(id) => {
+                format!("{}({arg}.clone())", recursive_fn_name(id))
+            }
+            _ => format!("any::<odf::{}::{}>()", id.context(), id.join("")),
+        },
+        model::Type::AnyJson => "any_json()".to_string(),
+    }
+}
+
+/// Groups items into a tuple, nesting them when there are more than proptest supports
+fn format_tuple<S: AsRef<str>>(items: &[S]) -> String {
+    if items.len() <= MAX_TUPLE_LEN {
+        let items: Vec<_> = items.iter().map(|i| i.as_ref()).collect();
+        if items.len() == 1 {
+            format!("({},)", items[0])
+        } else {
+            format!("({})", items.join(", "))
+        }
+    } else {
+        let chunks: Vec<_> = items.chunks(MAX_TUPLE_LEN).map(format_tuple).collect();
+        format_tuple(&chunks)
+    }
+}
@@ -75,6 +75,40 @@ const PREAMBLE: &str = indoc::indoc!(
                 }
             }
         };
+        ($dto:ty, $proxy:ty, $($dto_t:ident => $proxy_t:ident),+) => {
+            impl<$($dto_t, $proxy_t),+> ::serde_with::SerializeAs<$dto> for $proxy
+            where
+                $dto: Clone,
+                $($proxy_t: From<$dto_t> + Serialize,)+
+            {
+                fn serialize_as<S>(value: &$dto, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    // TODO: PERF: Avoid cloning on serialize
+                    let value: $proxy = value.clone().into();
+                    value.serialize(serializer)
+                }
+            }
+
+            impl<'de, $($dto_t, $proxy_t),+> serde_with::DeserializeAs<'de, $dto> for $proxy
+            where
+                $(
+                    $proxy_t: Deserialize<'de>,
+                    $dto_t: TryFrom<$proxy_t>,
+                    ValidationError: From<<$dto_t as TryFrom<$proxy_t>>::Error>,
+                )+
+            {
+                fn deserialize_as<D>(deserializer: D) -> Result<$dto, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    use ::serde::de::Error;
+                    let proxy = <$proxy>::deserialize(deserializer)?;
+                    proxy.try_into().map_err(D::Error::custom)
+                }
+            }
+        };
     }
     "#
 );
@@ -123,10 +157,25 @@ pub fn render(model: model::Model, w: &mut dyn std::io::Write) -> Result<(), std
 
             writeln!(w)?;
 
-            // TODO: Support #[serde_as(as = "X")] for generic types
-            if !matches!(typ, model::TypeDefinition::Struct(t) if !t.generics.is_empty()) {
-                let name = typ.id().join("");
-                writeln!(w, "implement_serde_as!(dtos::{context}::{name}, {name});\n")?;
+            let name = typ.id().join("");
+            match typ {
+                model::TypeDefinition::Struct(t) if !t.generics.is_empty() => {
+                    let generics = t.generic_names();
+                    let generics_dto: Vec<_> = generics.iter().map(|g| format!("{g}Dto")).collect();
+                    writeln!(
+                        w,
+                        "implement_serde_as!(dtos::{context}::{name}<{}>, {name}<{}>, {});\n",
+                        generics_dto.join(", "),
+                        generics.join(", "),
+                        generics_dto
+                            .iter()
+                            .zip(&generics)
+                            .map(|(d, p)| format!("{d} => {p}"))
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    )?;
+                }
+                _ => writeln!(w, "implement_serde_as!(dtos::{context}::{name}, {name});\n")?,
             }
         }
 
@@ -145,7 +194,7 @@ fn render_struct(
 ) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = typ.id.join("");
-    let generics = format!("<{}>", typ.generics.join(", "));
+    let generics = format!("<{}>", typ.generic_names().join(", "));
     let is_external = typ
         .get_hint::<String>(CodegenLanguage::Rust, CodegenHint::DtoType)
         .is_some();
@@ -165,7 +214,7 @@ fn render_struct(
 
     let generics_dto = format!(
         "<{}>",
-        typ.generics
+        typ.generic_names()
             .iter()
             .map(|v| format!("{v}::Dto"))
             .collect::<Vec<_>>()
@@ -175,7 +224,7 @@ fn render_struct(
     writeln!(w, "impl{generics} IntoDto for {name}{generics}")?;
     if !typ.generics.is_empty() {
         writeln!(w, "where")?;
-        for generic in &typ.generics {
+        for generic in typ.generic_names() {
             writeln!(w, "{generic}: IntoDto,")?;
             writeln!(
                 w,
@@ -229,15 +278,23 @@ fn render_struct(
 
     writeln!(w)?;
 
-    let generics_from: Vec<_> = typ.generics.iter().map(|v| format!("{v}From")).collect();
-    let generics_to: Vec<_> = typ.generics.iter().map(|v| format!("{v}To")).collect();
+    let generics_from: Vec<_> = typ
+        .generic_names()
+        .iter()
+        .map(|v| format!("{v}From"))
+        .collect();
+    let generics_to: Vec<_> = typ
+        .generic_names()
+        .iter()
+        .map(|v| format!("{v}To"))
+        .collect();
     let generics_from_to: Vec<_> = generics_from
         .iter()
         .chain(generics_to.iter())
         .cloned()
         .collect();
     let whereas: Vec<_> = typ
-        .generics
+        .generic_names()
         .iter()
         .map(|v| format!("{v}To: From<{v}From>"))
         .collect();
@@ -270,7 +327,7 @@ fn render_struct(
     writeln!(w)?;
 
     let whereas: Vec<_> = typ
-        .generics
+        .generic_names()
         .iter()
         .map(|v| format!("{v}To: TryFrom<{v}From>, ValidationError: From<<{v}To as TryFrom<{v}From>>::Error>,"))
         .collect();
@@ -308,10 +365,19 @@ fn render_field(
 ) -> Result<(), std::io::Error> {
     let ident = format_ident(&field.name);
 
-    if ident != field.name && !ident.starts_with("r#") {
+    if ident.trim_start_matches("r#") != field.name {
         writeln!(w, "#[serde(rename = \"{}\")]", field.name)?;
     }
 
+    if !field.aliases.is_empty() {
+        let aliases: Vec<_> = field
+            .aliases
+            .iter()
+            .map(|a| format!("alias = \"{a}\""))
+            .collect();
+        writeln!(w, "#[serde({})]", aliases.join(", "))?;
+    }
+
     let container = field.get_hint::<String>(CodegenLanguage::Rust, CodegenHint::Container);
 
     if field.optional {
@@ -371,7 +437,14 @@ fn render_field_into(
 
     let fname = format_ident(&field.name);
 
-    let convert = if !needs_conversion(&field.typ) {
+    let convert = if field.wraps_sensitive() {
+        // Serde structs hold plain strings, so sensitive values are unwrapped
+        if field.optional {
+            format!("v.{fname}.map(dtos::Sensitive::into_inner)")
+        } else {
+            format!("v.{fname}.into_inner()")
+        }
+    } else if !needs_conversion(&field.typ) {
         format!("v.{fname}")
     } else if let Some(container) = container {
         format!(
@@ -405,7 +478,13 @@ fn render_field_try_into(
 
     let fname = format_ident(&field.name);
 
-    let convert = if !needs_conversion(&field.typ) {
+    let convert = if field.wraps_sensitive() {
+        if field.optional {
+            format!("v.{fname}.map(dtos::Sensitive::new)")
+        } else {
+            format!("dtos::Sensitive::new(v.{fname})")
+        }
+    } else if !needs_conversion(&field.typ) {
         format!("v.{fname}")
     } else if let Some(container) = container {
         format!(
@@ -448,23 +527,61 @@ fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), st
     let context = typ.id.context();
     let name = typ.id.join("");
 
+    // Derived impls can't fall back on unknown tags without also swallowing errors in known variants,
+    // so known variants go into a separate enum that the hand-written impls delegate to
+    let known = if typ.has_unknown_variant() {
+        format!("{name}Known")
+    } else {
+        name.to_string()
+    };
+    let wrap = |v: String| {
+        if typ.has_unknown_variant() {
+            format!("Self::Known({known}::{v})")
+        } else {
+            format!("Self::{v}")
+        }
+    };
+
     writeln!(w, "#[derive(Debug, Serialize, Deserialize)]")?;
-    writeln!(w, "#[serde(deny_unknown_fields)]")?;
-    writeln!(w, "#[serde(tag = \"kind\")]")?;
-    writeln!(w, "pub enum {name} {{")?;
+    match &typ.discriminator {
+        model::Discriminator::Property(property) => {
+            writeln!(w, "#[serde(deny_unknown_fields)]")?;
+            writeln!(w, "#[serde(tag = \"{property}\")]")?;
+        }
+        // Variants are tried in order and rely on their own `deny_unknown_fields` to be told apart
+        model::Discriminator::Untagged => writeln!(w, "#[serde(untagged)]")?,
+    }
+    writeln!(w, "pub enum {known} {{")?;
 
     for variant in &typ.variants {
-        let var_ctx = variant.context();
-        let varname = variant.name();
-        let typename = variant.join("");
+        let var_ctx = variant.id.context();
+        let varname = variant.id.name();
+        let typename = variant.id.join("");
 
-        // Allow lowercase and camelCase names
-        render_aliases(varname, w)?;
+        if variant.has_custom_tag() {
+            // Custom tags are matched verbatim, as well as the former tags of the variant
+            writeln!(
+                w,
+                "#[serde(rename = \"{}\")]",
+                variant.tag.as_ref().unwrap()
+            )?;
+            for alias in &variant.aliases {
+                writeln!(w, "#[serde(alias = \"{alias}\")]")?;
+            }
+        } else if variant.tag.is_some() {
+            // Allow lowercase and camelCase names, as well as former names of the variant
+            render_aliases(varname, &variant.aliases, w)?;
+        }
         writeln!(w, "{varname}({var_ctx}::{typename}),")?;
     }
 
     writeln!(w, "}}")?;
 
+    if typ.has_unknown_variant() {
+        writeln!(w)?;
+        render_union_unknown_wrapper(typ, &known, w)?;
+    }
+
     if typ.from_string {
         writeln!(w)?;
 
@@ -495,10 +612,17 @@ fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), st
     writeln!(w, "fn from(v: dtos::{context}::{name}) -> Self {{")?;
     writeln!(w, "match v {{")?;
     for variant in &typ.variants {
-        let varname = variant.name();
+        let varname = variant.id.name();
+        writeln!(
+            w,
+            "dtos::{context}::{name}::{varname}(v) => {},",
+            wrap(format!("{varname}(v.into())"))
+        )?;
+    }
+    if typ.has_unknown_variant() {
         writeln!(
             w,
-            "dtos::{context}::{name}::{varname}(v) => Self::{varname}(v.into()),"
+            "dtos::{context}::{name}::Unknown(v) => Self::Unknown(v),"
         )?;
     }
     writeln!(w, "}}")?;
@@ -509,14 +633,19 @@ fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), st
 
     writeln!(w, "impl TryFrom<{name}> for dtos::{context}::{name} {{")?;
     writeln!(w, "type Error = ValidationError;")?;
-    writeln!(w, "fn try_from(v: {name}) -> Result<Self, Self::Error> {{")?;
+    writeln!(w, "fn try_from(v: {name}) -> Result<Self, ValidationError> {{")?;
     writeln!(w, "match v {{")?;
     for variant in &typ.variants {
-        let varname = variant.name();
-        writeln!(
-            w,
-            "{name}::{varname}(v) => Ok(Self::{varname}(v.try_into()?)),"
-        )?;
+        let varname = variant.id.name();
+        let pattern = if typ.has_unknown_variant() {
+            format!("{name}::Known({known}::{varname}(v))")
+        } else {
+            format!("{name}::{varname}(v)")
+        };
+        writeln!(w, "{pattern} => Ok(Self::{varname}(v.try_into()?)),")?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(w, "{name}::Unknown(v) => Ok(Self::Unknown(v)),")?;
     }
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
@@ -525,6 +654,73 @@ fn render_union(typ: &model::Union, w: &mut dyn std::io::Write) -> Result<(), st
     Ok(())
 }
 
+fn render_union_unknown_wrapper(
+    typ: &model::Union,
+    known: &str,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let name = typ.id.join("");
+    let property = typ
+        .discriminator
+        .property()
+        .expect("Unknown variants require a tagged union");
+
+    // All spellings of tags accepted by the derived impl
+    let mut tags = std::collections::BTreeSet::new();
+    for variant in &typ.variants {
+        if variant.has_custom_tag() {
+            tags.insert(variant.tag.clone().unwrap());
+            tags.extend(variant.aliases.iter().cloned());
+        } else {
+            tags.insert(variant.id.name().to_string());
+            tags.extend(alias_set(variant.id.name(), &variant.aliases));
+        }
+    }
+    let tags: Vec<_> = tags.into_iter().map(|t| format!("\"{t}\"")).collect();
+
+    writeln!(w, "#[derive(Debug)]")?;
+    writeln!(w, "pub enum {name} {{")?;
+    writeln!(w, "Known({known}),")?;
+    writeln!(w, "Unknown(dtos::UnknownVariant),")?;
+    writeln!(w, "}}")?;
+    writeln!(w)?;
+
+    writeln!(
+        w,
+        r#"
+        impl Serialize for {name} {{
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {{
+                match self {{
+                    Self::Known(v) => v.serialize(serializer),
+                    Self::Unknown(dtos::UnknownVariant::Json {{ value, .. }}) => value.serialize(serializer),
+                    Self::Unknown(dtos::UnknownVariant::Flatbuffers {{ tag, .. }}) => Err(::serde::ser::Error::custom(format!(
+                        "Variant {{tag}} of {name} was read from flatbuffers and can't be represented in this format"
+                    ))),
+                }}
+            }}
+        }}
+
+        impl<'de> Deserialize<'de> for {name} {{
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {{
+                use ::serde::de::Error;
+                const KNOWN_TAGS: &[&str] = &[{tags}];
+
+                let value = ::serde_json::Value::deserialize(deserializer)?;
+                match value.get("{property}").and_then(|v| v.as_str()) {{
+                    Some(tag) if !KNOWN_TAGS.contains(&tag) => Ok(Self::Unknown(dtos::UnknownVariant::Json {{
+                        tag: tag.to_string(),
+                        value,
+                    }})),
+                    _ => {known}::deserialize(value).map(Self::Known).map_err(D::Error::custom),
+                }}
+            }}
+        }}"#,
+        tags = tags.join(", "),
+    )?;
+
+    Ok(())
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 fn render_enum(typ: &model::Enum, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
@@ -536,9 +732,14 @@ fn render_enum(typ: &model::Enum, w: &mut dyn std::io::Write) -> Result<(), std:
     writeln!(w, "pub enum {name} {{")?;
     {
         for variant in &typ.variants {
-            render_aliases(&variant, w)?;
+            render_aliases(&variant, &[], w)?;
             writeln!(w, "{variant},")?;
         }
+        if typ.has_unknown_variant() {
+            // Only tried once none of the known values match
+            writeln!(w, "#[serde(untagged)]")?;
+            writeln!(w, "Unknown(String),")?;
+        }
     }
     writeln!(w, "}}")?;
 
@@ -560,6 +761,16 @@ fn render_enum(typ: &model::Enum, w: &mut dyn std::io::Write) -> Result<(), std:
     for variant in &typ.variants {
         writeln!(w, "dtos::{context}::{name}::{variant} => Self::{variant},")?;
     }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "dtos::{context}::{name}::Unknown(dtos::UnknownEnumValue::Json(v)) => Self::Unknown(v),"
+        )?;
+        writeln!(
+            w,
+            "dtos::{context}::{name}::Unknown(dtos::UnknownEnumValue::Flatbuffers(v)) => Self::Unknown(v.to_string()),"
+        )?;
+    }
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
@@ -568,11 +779,17 @@ fn render_enum(typ: &model::Enum, w: &mut dyn std::io::Write) -> Result<(), std:
 
     writeln!(w, "impl TryFrom<{name}> for dtos::{context}::{name} {{")?;
     writeln!(w, "type Error = ValidationError;")?;
-    writeln!(w, "fn try_from(v: {name}) -> Result<Self, Self::Error> {{")?;
+    writeln!(w, "fn try_from(v: {name}) -> Result<Self, ValidationError> {{")?;
     writeln!(w, "match v {{")?;
     for variant in &typ.variants {
         writeln!(w, "{name}::{variant} => Ok(Self::{variant}),")?;
     }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "{name}::Unknown(v) => Ok(Self::Unknown(dtos::UnknownEnumValue::Json(v))),"
+        )?;
+    }
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
@@ -663,12 +880,13 @@ fn render_map(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-/// Allow lowercase and camelCase names for enums
-fn render_aliases(name: &str, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
-    let aliases: Vec<_> =
-        std::collections::BTreeSet::from([name.to_lowercase(), name.to_case(Case::Camel)])
-            .into_iter()
-            .collect();
+/// Allow lowercase and camelCase names for enums, including the former names
+fn render_aliases(
+    name: &str,
+    former_names: &[String],
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
+    let aliases = alias_set(name, former_names);
 
     write!(w, "#[serde(")?;
     for (i, alias) in aliases.into_iter().enumerate() {
@@ -682,6 +900,20 @@ fn render_aliases(name: &str, w: &mut dyn std::io::Write) -> Result<(), std::io:
     Ok(())
 }
 
+/// Lowercase and camelCase spellings of the name, as well as former names with their spellings
+fn alias_set(name: &str, former_names: &[String]) -> std::collections::BTreeSet<String> {
+    let mut aliases =
+        std::collections::BTreeSet::from([name.to_lowercase(), name.to_case(Case::Camel)]);
+
+    for former in former_names {
+        aliases.insert(former.clone());
+        aliases.insert(former.to_lowercase());
+        aliases.insert(former.to_case(Case::Camel));
+    }
+
+    aliases
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 fn format_type(model: &model::Model, typ: &model::Type) -> String {
@@ -1,8 +1,8 @@
-use crate::codegen::rust_common::format_ident;
-use crate::json_schema::{CodegenHint, CodegenLanguage, FlatbuffersMapFormat};
+use crate::codegen::rust_common::{format_flatbuffers_accessor, format_ident};
+use crate::json_schema::{CodegenHint, CodegenLanguage, FlatbuffersHashable, FlatbuffersMapFormat};
 use crate::model;
 use crate::utils::indent_writer::IndentWriter;
-use std::borrow::Cow;
+use std::collections::BTreeSet;
 use std::io::Write;
 
 const PREAMBLE: &str = indoc::indoc!(
@@ -28,22 +28,291 @@ const PREAMBLE: &str = indoc::indoc!(
 
     pub trait FlatbuffersSerializable<'fb> {
         type OffsetT;
-        fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT;
+        fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Result<Self::OffsetT, FlatbuffersEncodeError>;
     }
 
     pub trait FlatbuffersDeserializable<T> {
-        fn deserialize(fb: T) -> Self;
+        fn deserialize(fb: T) -> Result<Self, FlatbuffersDecodeError>
+        where
+            Self: Sized;
     }
 
     pub trait FlatbuffersEnumSerializable<'fb, E> {
-        fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> (E, WIPOffset<UnionWIPOffset>);
+        fn serialize(
+            &self,
+            fb: &mut FlatBufferBuilder<'fb>,
+        ) -> Result<(E, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError>;
     }
 
     pub trait FlatbuffersEnumDeserializable<'fb, E> {
-        fn deserialize(table: Table<'fb>, t: E) -> Self
+        fn deserialize(table: Table<'fb>, t: E) -> Result<Self, FlatbuffersDecodeError>
         where
             Self: Sized;
     }
+
+    /// Value identified by the hash of its canonical flatbuffers encoding
+    pub trait FlatbuffersHashable {
+        /// Canonical flatbuffers encoding of the value
+        fn to_canonical_bytes(&self) -> Result<Vec<u8>, FlatbuffersEncodeError>;
+
+        /// Canonical encoding wrapped into a `Manifest` that the content hash is computed over
+        fn to_canonical_manifest(&self) -> Result<Vec<u8>, FlatbuffersEncodeError>;
+
+        /// SHA3-256 multihash of the canonical manifest
+        fn multihash(&self) -> Result<odf::Multihash, FlatbuffersEncodeError> {
+            Ok(odf::Multihash::from_digest_sha3_256(&self.to_canonical_manifest()?))
+        }
+    }
+
+    /// Returns a builder for the canonical encoding. Layout of the buffer is fully determined by the value when it is
+    /// serialized via `FlatbuffersSerializable`:
+    /// - All offsets of a table are serialized depth-first in order of field declaration before the table is started
+    /// - Fields are added in order of declaration rather than by size
+    /// - Scalars equal to their default value are omitted, unless the field is optional
+    /// - Identical vtables are written once and shared by all tables that use them
+    /// - Map entries and properties of JSON values are ordered by key
+    /// - Structs are aligned to their largest field, as in proxies generated by `flatc` 24 or newer
+    /// - Buffers have no file identifier and no size prefix
+    pub fn canonical_builder<'fb>() -> FlatBufferBuilder<'fb> {
+        let mut fb = FlatBufferBuilder::with_capacity(0);
+        fb.force_defaults(false);
+        fb
+    }
+
+    /// Wraps the canonical encoding of a value into a `Manifest` of the specified kind and version
+    pub fn canonical_manifest(kind: i64, version: i32, content: &[u8]) -> Vec<u8> {
+        let mut fb = canonical_builder();
+        let content_offset = fb.create_vector(content);
+        let mut builder = fb::ManifestBuilder::new(&mut fb);
+        builder.add_kind(kind);
+        builder.add_version(version);
+        builder.add_content(content_offset);
+        let offset = builder.finish();
+        fb.finish(offset, None);
+        fb.finished_data().to_vec()
+    }
+
+    /// Verifies the buffer within the specified limits and decodes its root table
+    pub fn deserialize_verified<'fb, P, T>(
+        buf: &'fb [u8],
+        limits: &FlatbuffersDecodeLimits,
+    ) -> Result<T, FlatbuffersDecodeError>
+    where
+        P: ::flatbuffers::Follow<'fb, Inner = P> + ::flatbuffers::Verifiable + 'fb,
+        T: FlatbuffersDeserializable<P>,
+    {
+        T::deserialize(root_verified::<P>(buf, limits)?)
+    }
+
+    /// Verifies the buffer within the specified limits and returns its root proxy
+    pub fn root_verified<'fb, P>(
+        buf: &'fb [u8],
+        limits: &FlatbuffersDecodeLimits,
+    ) -> Result<P, FlatbuffersDecodeError>
+    where
+        P: ::flatbuffers::Follow<'fb, Inner = P> + ::flatbuffers::Verifiable + 'fb,
+    {
+        if buf.len() > limits.max_buffer_size {
+            return Err(FlatbuffersDecodeError::new(
+                FlatbuffersDecodeErrorKind::BufferTooLarge {
+                    size: buf.len(),
+                    limit: limits.max_buffer_size,
+                },
+            ));
+        }
+
+        let opts = ::flatbuffers::VerifierOptions {
+            max_depth: limits.max_depth,
+            max_tables: limits.max_tables,
+            ..Default::default()
+        };
+
+        ::flatbuffers::root_with_opts::<P>(&opts, buf).map_err(|e| {
+            FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::Verification(e))
+        })
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Captures the table of an unknown union variant so that it can be written back as is.
+    ///
+    /// A table only refers to values that were written before it, which lie after it in the buffer, while its vtable
+    /// may be on either side. So the bytes from whichever comes first to the end of the buffer hold everything the table
+    /// needs, along with values written before the table that it doesn't refer to, as types of its fields are unknown.
+    /// They are padded at the front to keep alignment of values, which is relative to the end of the buffer.
+    pub fn unknown_variant_from_table(
+        table: Table<'_>,
+        tag: u8,
+    ) -> Result<odf::dtos::UnknownVariant, FlatbuffersDecodeError> {
+        let buf = table.buf();
+        let loc = table.loc();
+
+        // Verifier doesn't look into tables of unknown types, so their location is not trusted
+        let vtable_loc = buf
+            .get(loc..)
+            .and_then(|b| b.get(..4))
+            .map(|b| loc as i64 - i32::from_le_bytes([b[0], b[1], b[2], b[3]]) as i64)
+            .filter(|vtable_loc| *vtable_loc >= 0 && *vtable_loc as usize + 4 <= buf.len())
+            .ok_or_else(|| {
+                FlatbuffersDecodeError::invalid_value(format!(
+                    "Table of unknown variant {tag} is out of bounds"
+                ))
+            })? as usize;
+
+        let start = loc.min(vtable_loc);
+        let padding = (8 - (buf.len() - start) % 8) % 8;
+        let mut bytes = vec![0; padding];
+        bytes.extend_from_slice(&buf[start..]);
+
+        Ok(odf::dtos::UnknownVariant::Flatbuffers {
+            tag,
+            bytes,
+            loc: padding + loc - start,
+        })
+    }
+
+    /// Writes back the bytes captured by [`unknown_variant_from_table`] and returns the offset of the table
+    pub fn push_unknown_table<'fb>(
+        fb: &mut FlatBufferBuilder<'fb>,
+        bytes: &[u8],
+        loc: usize,
+    ) -> Result<WIPOffset<UnionWIPOffset>, FlatbuffersEncodeError> {
+        if bytes.len() % 8 != 0 || loc >= bytes.len() {
+            return Err(FlatbuffersEncodeError::invalid_value(format!(
+                "Table at {loc} is outside of {} captured bytes",
+                bytes.len()
+            )));
+        }
+
+        // Pushing whole words back to front places the bytes at the same alignment they had in the original buffer
+        let mut start = 0;
+        for word in bytes.chunks_exact(8).rev() {
+            start = fb
+                .push(u64::from_le_bytes(std::array::from_fn(|i| word[i])))
+                .value();
+        }
+        Ok(WIPOffset::new(start - loc as u32))
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Limits enforced by the verifier when decoding untrusted buffers
+    #[derive(Debug, Clone)]
+    pub struct FlatbuffersDecodeLimits {
+        /// Maximum nesting depth of tables and vectors
+        pub max_depth: usize,
+        /// Maximum number of tables in the buffer
+        pub max_tables: usize,
+        /// Maximum size of the buffer in bytes
+        pub max_buffer_size: usize,
+    }
+
+    impl Default for FlatbuffersDecodeLimits {
+        fn default() -> Self {
+            Self {
+                max_depth: 64,
+                max_tables: 1_000_000,
+                max_buffer_size: 1 << 31,
+            }
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    #[derive(Debug)]
+    pub struct FlatbuffersDecodeError {
+        /// Path to the offending value, outermost first, e.g. `["MetadataBlock.event", "AddData.newData"]`
+        pub path: Vec<&'static str>,
+        pub kind: FlatbuffersDecodeErrorKind,
+    }
+
+    #[derive(Debug)]
+    pub enum FlatbuffersDecodeErrorKind {
+        BufferTooLarge { size: usize, limit: usize },
+        Verification(::flatbuffers::InvalidFlatbuffer),
+        MissingField,
+        UnknownUnionVariant { typ: &'static str, value: u8 },
+        UnknownEnumValue { typ: &'static str, value: i64 },
+        InvalidValue(String),
+    }
+
+    impl FlatbuffersDecodeError {
+        pub fn new(kind: FlatbuffersDecodeErrorKind) -> Self {
+            Self {
+                path: Vec::new(),
+                kind,
+            }
+        }
+
+        pub fn missing_field() -> Self {
+            Self::new(FlatbuffersDecodeErrorKind::MissingField)
+        }
+
+        pub fn invalid_value<E: std::fmt::Display>(e: E) -> Self {
+            Self::new(FlatbuffersDecodeErrorKind::InvalidValue(e.to_string()))
+        }
+
+        /// Prepends a `Type.field` segment as the error propagates outwards
+        pub fn at(mut self, segment: &'static str) -> Self {
+            self.path.insert(0, segment);
+            self
+        }
+    }
+
+    impl std::fmt::Display for FlatbuffersDecodeError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            if !self.path.is_empty() {
+                write!(f, "{}: ", self.path.join(" > "))?;
+            }
+            match &self.kind {
+                FlatbuffersDecodeErrorKind::BufferTooLarge { size, limit } => {
+                    write!(f, "Buffer of {size} bytes exceeds the limit of {limit} bytes")
+                }
+                FlatbuffersDecodeErrorKind::Verification(e) => write!(f, "Invalid flatbuffer: {e}"),
+                FlatbuffersDecodeErrorKind::MissingField => write!(f, "Missing required field"),
+                FlatbuffersDecodeErrorKind::UnknownUnionVariant { typ, value } => {
+                    write!(f, "Unknown variant {value} of union {typ}")
+                }
+                FlatbuffersDecodeErrorKind::UnknownEnumValue { typ, value } => {
+                    write!(f, "Unknown value {value} of enum {typ}")
+                }
+                FlatbuffersDecodeErrorKind::InvalidValue(e) => write!(f, "Invalid value: {e}"),
+            }
+        }
+    }
+
+    impl std::error::Error for FlatbuffersDecodeError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            match &self.kind {
+                FlatbuffersDecodeErrorKind::Verification(e) => Some(e),
+                _ => None,
+            }
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Value that has no flatbuffers representation, e.g. an unknown variant that was read from a manifest
+    #[derive(Debug)]
+    pub struct FlatbuffersEncodeError {
+        pub message: String,
+    }
+
+    impl FlatbuffersEncodeError {
+        pub fn invalid_value<E: std::fmt::Display>(e: E) -> Self {
+            Self {
+                message: e.to_string(),
+            }
+        }
+    }
+
+    impl std::fmt::Display for FlatbuffersEncodeError {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Value can't be encoded as flatbuffers: {}", self.message)
+        }
+    }
+
+    impl std::error::Error for FlatbuffersEncodeError {}
     "#
 );
 
@@ -62,24 +331,33 @@ const FOOTER: &str = indoc::indoc!(
         )
     }
 
-    fn fb_to_datetime(dt: &fb::Timestamp) -> DateTime<Utc> {
-        let naive_date_time = NaiveDate::from_yo_opt(dt.year(), dt.ordinal() as u32)
-            .unwrap()
-            .and_time(
-                NaiveTime::from_num_seconds_from_midnight_opt(
-                    dt.seconds_from_midnight(),
-                    dt.nanoseconds(),
-                )
-                .unwrap(),
-            );
-        Utc.from_local_datetime(&naive_date_time).unwrap()
+    pub(crate) fn fb_to_datetime(dt: &fb::Timestamp) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {
+        let date = NaiveDate::from_yo_opt(dt.year(), dt.ordinal() as u32).ok_or_else(|| {
+            FlatbuffersDecodeError::invalid_value(format!(
+                "Invalid date: year {} ordinal {}",
+                dt.year(),
+                dt.ordinal()
+            ))
+        })?;
+        let time = NaiveTime::from_num_seconds_from_midnight_opt(
+            dt.seconds_from_midnight(),
+            dt.nanoseconds(),
+        )
+        .ok_or_else(|| {
+            FlatbuffersDecodeError::invalid_value(format!(
+                "Invalid time: {}s {}ns",
+                dt.seconds_from_midnight(),
+                dt.nanoseconds()
+            ))
+        })?;
+        Ok(Utc.from_utc_datetime(&date.and_time(time)))
     }
 
     fn duration_to_fb(v: &DurationString) -> fb::Duration {
         fb::Duration::new(v.as_nanos() as u64)
     }
 
-    fn fb_to_duration(v: &fb::Duration) -> DurationString {
+    pub(crate) fn fb_to_duration(v: &fb::Duration) -> DurationString {
         DurationString::new(std::time::Duration::from_nanos(v.nanoseconds()))
     }
     "#
@@ -89,9 +367,20 @@ const FOOTER: &str = indoc::indoc!(
 
 struct Helpers {
     model: model::Model,
+    ambiguous_names: BTreeSet<String>,
 }
 
 impl Helpers {
+    /// Path of the flatbuffers proxy type relative to the `fb` module
+    fn fb_name(&self, id: &model::TypeId) -> String {
+        let name = id.join("");
+        if self.ambiguous_names.contains(name.as_ref()) {
+            format!("{}::{name}", id.context())
+        } else {
+            name.into_owned()
+        }
+    }
+
     fn is_union(&self, typ: &model::Type) -> bool {
         match typ {
             model::Type::Custom(id) => {
@@ -187,6 +476,7 @@ fn render_impl(
 
     let helpers = Helpers {
         model: model.clone(),
+        ambiguous_names: model.ambiguous_names(),
     };
 
     for typ in model.types.values() {
@@ -203,6 +493,11 @@ fn render_impl(
             continue;
         }
 
+        // Generic DTOs have no single flatbuffers representation
+        if matches!(typ, model::TypeDefinition::Struct(t) if !t.generics.is_empty()) {
+            continue;
+        }
+
         if typ
             .get_hint::<String>(CodegenLanguage::Rust, CodegenHint::DtoType)
             .is_some()
@@ -226,9 +521,9 @@ fn render_impl(
 
         match &typ {
             model::TypeDefinition::Struct(t) => render_struct(t, &helpers, w)?,
-            model::TypeDefinition::Union(t) => render_union(t, w)?,
-            model::TypeDefinition::Enum(t) => render_enum(t, w)?,
-            model::TypeDefinition::Map(t) => render_map(t, w)?,
+            model::TypeDefinition::Union(t) => render_union(t, &helpers, w)?,
+            model::TypeDefinition::Enum(t) => render_enum(t, &helpers, w)?,
+            model::TypeDefinition::Map(t) => render_map(t, &helpers, w)?,
         }
         writeln!(w)?;
     }
@@ -246,16 +541,17 @@ fn render_struct(
 ) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
 
     writeln!(
         w,
         "impl<'fb> FlatbuffersSerializable<'fb> for odf::{context}::{name} {{"
     )?;
-    writeln!(w, "type OffsetT = WIPOffset<fb::{name}<'fb>>;")?;
+    writeln!(w, "type OffsetT = WIPOffset<fb::{fb_name}<'fb>>;")?;
     writeln!(w)?;
     writeln!(
         w,
-        "fn serialize(&self, fb: &mut FlatBufferBuilder <'fb>) -> Self::OffsetT {{"
+        "fn serialize(&self, fb: &mut FlatBufferBuilder <'fb>) -> Result<Self::OffsetT, FlatbuffersEncodeError> {{"
     )?;
 
     let mut preserialized = Vec::new();
@@ -264,31 +560,67 @@ fn render_struct(
         preserialized.push(preser);
     }
 
-    writeln!(w, "let mut builder = fb::{name}Builder::new(fb);")?;
+    writeln!(w, "let mut builder = fb::{fb_name}Builder::new(fb);")?;
 
     for (field, preserialized) in typ.fields.values().zip(preserialized) {
         render_field_ser(field, preserialized, helpers, w)?;
     }
 
-    writeln!(w, "builder.finish()")?;
+    writeln!(w, "Ok(builder.finish())")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
     writeln!(w)?;
 
     writeln!(
         w,
-        "impl<'fb> FlatbuffersDeserializable<fb::{name}<'fb>> for odf::{context}::{name} {{"
+        "impl<'fb> FlatbuffersDeserializable<fb::{fb_name}<'fb>> for odf::{context}::{name} {{"
+    )?;
+    writeln!(
+        w,
+        "fn deserialize(proxy: fb::{fb_name}<'fb>) -> Result<Self, FlatbuffersDecodeError> {{"
     )?;
-    writeln!(w, "fn deserialize(proxy: fb::{name}<'fb>) -> Self {{")?;
-    writeln!(w, "odf::{context}::{name} {{")?;
+    writeln!(w, "Ok(odf::{context}::{name} {{")?;
 
-    for field in typ.fields.values() {
-        render_field_de(field, helpers, w)?;
+    for field in typ.fields.values().filter(|f| f.constant.is_none()) {
+        render_field_de(&typ.id, field, helpers, w)?;
     }
 
+    writeln!(w, "}})")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
-    writeln!(w, "}}")?;
+
+    if let Some(hashable) = typ.hashable() {
+        writeln!(w)?;
+        render_struct_hashable(typ, hashable, w)?;
+    }
+    Ok(())
+}
+
+fn render_struct_hashable(
+    typ: &model::Struct,
+    hashable: FlatbuffersHashable,
+    w: &mut IndentWriter<&mut dyn std::io::Write>,
+) -> Result<(), std::io::Error> {
+    let context = typ.id.context();
+    let name = typ.id.join("");
+    let FlatbuffersHashable { kind, version } = hashable;
+
+    writeln!(
+        w,
+        r#"
+        impl FlatbuffersHashable for odf::{context}::{name} {{
+            fn to_canonical_bytes(&self) -> Result<Vec<u8>, FlatbuffersEncodeError> {{
+                let mut fb = canonical_builder();
+                let offset = self.serialize(&mut fb)?;
+                fb.finish(offset, None);
+                Ok(fb.finished_data().to_vec())
+            }}
+
+            fn to_canonical_manifest(&self) -> Result<Vec<u8>, FlatbuffersEncodeError> {{
+                Ok(canonical_manifest({kind:#x}, {version}, &self.to_canonical_bytes()?))
+            }}
+        }}"#
+    )?;
     Ok(())
 }
 
@@ -302,22 +634,38 @@ fn render_field_pre_ser(
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<bool, std::io::Error> {
     let name = format_ident(&field.name);
+    let expose = if field.wraps_sensitive() {
+        ".expose()"
+    } else {
+        ""
+    };
 
     if field.optional {
         let mut buf = Vec::<u8>::new();
-        format_pre_ser_type(format!("v"), &field.typ, helpers, &mut buf)?;
+        format_pre_ser_type(format!("v{expose}"), &field.typ, helpers, &mut buf)?;
         if !buf.is_empty() {
-            writeln!(w, "let {name}_offset = self.{name}.as_ref().map(|v| {{")?;
+            // Matching instead of mapping lets errors propagate out of the conversion
+            writeln!(w, "let {name}_offset = match &self.{name} {{")?;
+            writeln!(w, "Some(v) => Some({{")?;
             w.write_all(&buf)?;
-            writeln!(w, "}});")?;
+            writeln!(w, "}}),")?;
+            writeln!(w, "None => None,")?;
+            writeln!(w, "}};")?;
             Ok(true)
         } else {
             Ok(false)
         }
     } else {
+        // Constants are not stored in DTOs but are still written into the buffer
+        let value = if field.constant.is_some() {
+            format!("Self::{}()", format_ident(&field.name))
+        } else {
+            format!("self.{}{expose}", format_ident(&field.name))
+        };
+
         let mut buf = Vec::<u8>::new();
         format_pre_ser_type(
-            format!("self.{}", format_ident(&field.name)),
+            value,
             &field.typ,
             helpers,
             &mut buf,
@@ -371,41 +719,49 @@ fn format_pre_ser_type(
         }
         model::Type::Multicodec => todo!(),
         model::Type::Multihash => writeln!(w, "fb.create_vector(&{name}.as_bytes().as_slice())")?,
-        model::Type::Path => writeln!(w, "fb.create_string({name}.to_str().unwrap())")?,
+        model::Type::Path => writeln!(
+            w,
+            "fb.create_string({name}.to_str().ok_or_else(|| FlatbuffersEncodeError::invalid_value(\"Path is not valid UTF-8\"))?)"
+        )?,
         model::Type::Regex | model::Type::Url => writeln!(w, "fb.create_string(&{name})")?,
         model::Type::Array(array) => {
-            writeln!(w, "let offsets: Vec<_> = {name}.iter().map(|i| {{")?;
+            writeln!(
+                w,
+                "let offsets = {name}.iter().map(|i| -> Result<_, FlatbuffersEncodeError> {{"
+            )?;
             if helpers.is_union(&array.item_type) {
                 let model::Type::Custom(type_id) = array.item_type.as_ref() else {
                     unreachable!()
                 };
-                writeln!(w, "let (value_type, value_offset) = i.serialize(fb);")?;
+                writeln!(w, "let (value_type, value_offset) = i.serialize(fb)?;")?;
                 writeln!(
                     w,
                     "let mut builder = fb::{}WrapperBuilder::new(fb);",
-                    type_id.join("")
+                    helpers.fb_name(type_id)
                 )?;
                 writeln!(w, "builder.add_value_type(value_type);")?;
                 writeln!(w, "builder.add_value(value_offset);")?;
-                writeln!(w, "builder.finish()")?;
+                writeln!(w, "Ok(builder.finish())")?;
             } else {
                 let mut buf = Vec::<u8>::new();
                 format_pre_ser_type(format!("i"), &array.item_type, helpers, &mut buf)?;
 
+                writeln!(w, "Ok({{")?;
                 if !buf.is_empty() {
                     w.write_all(&buf)?;
                 } else {
                     render_type_ser(format!("i"), &array.item_type, helpers, w)?;
                 }
+                writeln!(w, "}})")?;
             }
-            writeln!(w, "}}).collect();")?;
+            writeln!(w, "}}).collect::<Result<Vec<_>, _>>()?;")?;
             writeln!(w, "fb.create_vector(&offsets)")?;
         }
         model::Type::Custom(id) if helpers.is_enum_id(id) => (),
-        model::Type::Custom(_) => writeln!(w, "{name}.serialize(fb)")?,
+        model::Type::Custom(_) => writeln!(w, "{name}.serialize(fb)?")?,
         model::Type::AnyJson => writeln!(
             w,
-            "fb.create_string(&serde_json::to_string(&{name}).unwrap())"
+            "fb.create_string(&serde_json::to_string(&{name}).map_err(FlatbuffersEncodeError::invalid_value)?)"
         )?,
     }
     Ok(())
@@ -423,7 +779,7 @@ fn render_field_ser(
 ) -> Result<(), std::io::Error> {
     let ident = format_ident(&field.name);
     let name = ident.trim_start_matches("r#");
-    let accessor = format_accessor(&field.name);
+    let accessor = format_flatbuffers_accessor(&field.name);
 
     if preserialized {
         if !field.optional {
@@ -437,19 +793,31 @@ fn render_field_ser(
             if helpers.is_union(&field.typ) {
                 writeln!(
                     w,
-                    "{name}_offset.map(|(e, off)| {{ builder.add_{name}_type(e); builder.add_{name}(off) }});"
+                    "{name}_offset.map(|(e, off)| {{ builder.add_{name}_type(e); builder.add_{accessor}(off) }});"
                 )?;
             } else {
-                writeln!(w, "{name}_offset.map(|off| builder.add_{name}(off));")?;
+                writeln!(w, "{name}_offset.map(|off| builder.add_{accessor}(off));")?;
             }
         }
     } else {
         if !field.optional {
-            writeln!(w, "builder.add_{name}(")?;
-            render_type_ser(format!("self.{name}"), &field.typ, helpers, w)?;
+            // Enums are converted by reference, as unknown values make them not `Copy`
+            let value = if helpers.is_enum(&field.typ) {
+                format!("&self.{ident}")
+            } else {
+                format!("self.{ident}")
+            };
+            writeln!(w, "builder.add_{accessor}(")?;
+            render_type_ser(value, &field.typ, helpers, w)?;
+            writeln!(w, ");")?;
+        } else if helpers.is_enum(&field.typ) {
+            writeln!(w, "if let Some(v) = &self.{ident} {{")?;
+            writeln!(w, "builder.add_{accessor}(")?;
+            render_type_ser("v".to_string(), &field.typ, helpers, w)?;
             writeln!(w, ");")?;
+            writeln!(w, "}}")?;
         } else {
-            writeln!(w, "self.{name}.map(|v| builder.add_{name}(")?;
+            writeln!(w, "self.{ident}.map(|v| builder.add_{accessor}(")?;
             render_type_ser(format!("v"), &field.typ, helpers, w)?;
             writeln!(w, "));")?;
         }
@@ -497,7 +865,7 @@ fn render_type_ser(
         model::Type::DateTime => writeln!(w, "&datetime_to_fb(&{name})")?,
         model::Type::Duration => writeln!(w, "&duration_to_fb(&{name})")?,
         model::Type::Custom(type_id) if helpers.is_enum_id(type_id) => {
-            writeln!(w, "{name}.into()")?
+            writeln!(w, "fb::{}::try_from({name})?", helpers.fb_name(type_id))?
         }
         model::Type::Custom(_) => (),
         model::Type::AnyJson => (),
@@ -510,15 +878,18 @@ fn render_type_ser(
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 fn render_field_de(
+    parent: &model::TypeId,
     field: &model::Field,
     helpers: &Helpers,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
     let name = format_ident(&field.name);
-    let accessor = format_accessor(&field.name);
+    let accessor = format_flatbuffers_accessor(&field.name);
+    let segment = format!("{}.{}", parent.join(""), field.json_name());
 
     let container = field.get_hint::<String>(CodegenLanguage::Rust, CodegenHint::Container);
 
+    // Every conversion is an expression of `Result<T, FlatbuffersDecodeError>`
     writeln!(w, "{name}:")?;
     if !field.optional
         && (helpers.is_enum(&field.typ)
@@ -533,9 +904,13 @@ fn render_field_de(
             w,
         )?;
     } else {
-        writeln!(w, "proxy.{accessor}().map(|v| {{")?;
-        if let Some(container) = &container {
-            writeln!(w, "{container}::new(")?;
+        if field.optional {
+            writeln!(w, "proxy.{accessor}().map(|v| {{")?;
+        } else {
+            writeln!(
+                w,
+                "proxy.{accessor}().ok_or_else(FlatbuffersDecodeError::missing_field).and_then(|v| {{"
+            )?;
         }
         render_type_de(
             format!("v"),
@@ -544,15 +919,22 @@ fn render_field_de(
             helpers,
             w,
         )?;
-        if container.is_some() {
-            writeln!(w, ")")?;
+        if let Some(container) = &container {
+            writeln!(w, ".map({container}::new)")?;
+        }
+        if field.wraps_sensitive() {
+            writeln!(w, ".map(odf::dtos::Sensitive::new)")?;
         }
         writeln!(w, "}})")?;
-        if !field.optional {
-            writeln!(w, ".unwrap()")?;
+        if field.optional {
+            writeln!(w, ".transpose()")?;
         }
     }
-    writeln!(w, ",")?;
+    // Annotation pins the error type of conversions that are infallible
+    writeln!(
+        w,
+        ".map_err(|e: FlatbuffersDecodeError| e.at(\"{segment}\"))?,"
+    )?;
     Ok(())
 }
 
@@ -572,49 +954,65 @@ fn render_type_de(
         | model::Type::UInt8
         | model::Type::UInt16
         | model::Type::UInt32
-        | model::Type::UInt64 => writeln!(w, "{name}")?,
-        model::Type::ByteSize => writeln!(w, "ByteSize::from({name})")?,
-        model::Type::String => writeln!(w, "{name}.to_owned()")?,
-        model::Type::Did => writeln!(w, "odf::Did::from_bytes({name}.bytes()).unwrap()")?,
-        model::Type::DatasetAlias => {
-            writeln!(w, "odf::dataset::DatasetAlias::try_from({name}).unwrap()")?
-        }
+        | model::Type::UInt64 => writeln!(w, "Ok({name})")?,
+        model::Type::ByteSize => writeln!(w, "Ok(ByteSize::from({name}))")?,
+        model::Type::String => writeln!(w, "Ok({name}.to_owned())")?,
+        model::Type::Did => writeln!(
+            w,
+            "odf::Did::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::DatasetAlias => writeln!(
+            w,
+            "odf::dataset::DatasetAlias::try_from({name}).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
         model::Type::DatasetId => writeln!(
             w,
-            "odf::dataset::DatasetID::from_bytes({name}.bytes()).unwrap()"
+            "odf::dataset::DatasetID::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::DatasetRef => writeln!(
+            w,
+            "odf::dataset::DatasetRef::try_from({name}).map_err(FlatbuffersDecodeError::invalid_value)"
         )?,
-        model::Type::DatasetRef => {
-            writeln!(w, "odf::dataset::DatasetRef::try_from({name}).unwrap()")?
-        }
         model::Type::DateTime => writeln!(w, "fb_to_datetime({name})")?,
-        model::Type::Duration => writeln!(w, "fb_to_duration({name})")?,
+        model::Type::Duration => writeln!(w, "Ok(fb_to_duration({name}))")?,
         model::Type::Flatbuffers | model::Type::Generic(_) => {
-            writeln!(w, "{name}.bytes().to_vec()")?
+            writeln!(w, "Ok({name}.bytes().to_vec())")?
         }
         model::Type::Multicodec => todo!(),
-        model::Type::Multihash => {
-            writeln!(w, "odf::Multihash::from_bytes({name}.bytes()).unwrap()")?
-        }
-        model::Type::Path => writeln!(w, "PathBuf::from({name})")?,
-        model::Type::Regex => writeln!(w, "{name}.to_owned()")?,
-        model::Type::Url => writeln!(w, "{name}.to_owned()")?,
+        model::Type::Multihash => writeln!(
+            w,
+            "odf::Multihash::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::Path => writeln!(w, "Ok(PathBuf::from({name}))")?,
+        model::Type::Regex => writeln!(w, "Ok({name}.to_owned())")?,
+        model::Type::Url => writeln!(w, "Ok({name}.to_owned())")?,
         model::Type::Array(array) => {
             writeln!(w, "{name}.iter().map(|i| ")?;
             if helpers.is_union(&array.item_type) {
+                writeln!(
+                    w,
+                    "i.value().ok_or_else(FlatbuffersDecodeError::missing_field).and_then(|v| "
+                )?;
                 render_type_de(
-                    format!("i.value().unwrap()"),
+                    format!("v"),
                     &array.item_type,
                     format!("i.value_type()"),
                     helpers,
                     w,
                 )?;
+                writeln!(w, ")")?;
             } else {
                 render_type_de(format!("i"), &array.item_type, "".to_string(), helpers, w)?;
             }
-            writeln!(w, ").collect()")?;
+            writeln!(w, ").collect::<Result<_, _>>()")?;
         }
         model::Type::Custom(type_id) => match helpers.model.types.get(type_id).unwrap() {
-            model::TypeDefinition::Enum(_) => writeln!(w, "{name}.into()")?,
+            model::TypeDefinition::Enum(_) => writeln!(
+                w,
+                "odf::{}::{}::try_from({name})",
+                type_id.context(),
+                type_id.join("")
+            )?,
             model::TypeDefinition::Struct(_) | model::TypeDefinition::Map(_) => writeln!(
                 w,
                 "odf::{}::{}::deserialize({name})",
@@ -628,24 +1026,38 @@ fn render_type_de(
                 type_id.join("")
             )?,
         },
-        model::Type::AnyJson => writeln!(w, "serde_json::from_str({name}).unwrap()")?,
+        model::Type::AnyJson => writeln!(
+            w,
+            "serde_json::from_str({name}).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
         model::Type::AccountId => writeln!(
             w,
-            "odf::auth::AccountID::from_bytes({name}.bytes()).unwrap()"
+            "odf::auth::AccountID::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::AccountName => writeln!(
+            w,
+            "odf::auth::AccountName::try_from({name}).map_err(FlatbuffersDecodeError::invalid_value)"
         )?,
-        model::Type::AccountName => {
-            writeln!(w, "odf::auth::AccountName::try_from({name}).unwrap()")?
-        }
         model::Type::ResourceId => writeln!(
             w,
-            "odf::resource::ResourceID::from_bytes({name}.bytes()).unwrap()"
+            "odf::resource::ResourceID::from_bytes({name}.bytes()).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::ResourceName => writeln!(
+            w,
+            "odf::resource::ResourceName::try_from({name}).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::TypeUri => writeln!(
+            w,
+            "odf::resource::TypeUri::try_from({name}).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::TypeName => writeln!(
+            w,
+            "odf::resource::TypeName::try_from({name}).map_err(FlatbuffersDecodeError::invalid_value)"
+        )?,
+        model::Type::TypeRef => writeln!(
+            w,
+            "odf::resource::TypeRef::try_from({name}).map_err(FlatbuffersDecodeError::invalid_value)"
         )?,
-        model::Type::ResourceName => {
-            writeln!(w, "odf::resource::ResourceName::try_from({name}).unwrap()")?
-        }
-        model::Type::TypeUri => writeln!(w, "odf::resource::TypeUri::try_from({name}).unwrap()")?,
-        model::Type::TypeName => writeln!(w, "odf::resource::TypeName::try_from({name}).unwrap()")?,
-        model::Type::TypeRef => writeln!(w, "odf::resource::TypeRef::try_from({name}).unwrap()")?,
     }
     Ok(())
 }
@@ -654,27 +1066,40 @@ fn render_type_de(
 
 fn render_union(
     typ: &model::Union,
+    helpers: &Helpers,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
 
     writeln!(
         w,
-        "impl<'fb> FlatbuffersEnumSerializable<'fb, fb::{name}> for odf::{context}::{name} {{"
+        "impl<'fb> FlatbuffersEnumSerializable<'fb, fb::{fb_name}> for odf::{context}::{name} {{"
     )?;
     writeln!(
         w,
-        "fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> (fb::{name}, WIPOffset<UnionWIPOffset>) {{"
+        "fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Result<(fb::{fb_name}, WIPOffset<UnionWIPOffset>), FlatbuffersEncodeError> {{"
     )?;
     writeln!(w, "match self {{")?;
 
     for variant in &typ.variants {
-        let typ = variant.join("");
-        let var = variant.name();
+        let typ = variant.id.join("");
+        let var = variant.id.name();
+        writeln!(
+            w,
+            "odf::{context}::{name}::{var}(v) => Ok((fb::{fb_name}::{typ}, v.serialize(fb)?.as_union_value())),"
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        // Tables read from flatbuffers are written back as is, while the layout of ones read from manifests is not known
+        writeln!(
+            w,
+            "odf::{context}::{name}::Unknown(odf::dtos::UnknownVariant::Flatbuffers {{ tag, bytes, loc }}) => Ok((fb::{fb_name}(*tag), push_unknown_table(fb, bytes, *loc)?)),"
+        )?;
         writeln!(
             w,
-            "odf::{context}::{name}::{var}(v) => (fb::{name}::{typ}, v.serialize(fb).as_union_value()),"
+            "odf::{context}::{name}::Unknown(odf::dtos::UnknownVariant::Json {{ tag, .. }}) => Err(FlatbuffersEncodeError::invalid_value(format!(\"Unknown variant {{tag}} of {name} was read from a manifest and has no flatbuffers representation\"))),"
         )?;
     }
 
@@ -684,25 +1109,45 @@ fn render_union(
     writeln!(w)?;
     writeln!(
         w,
-        "impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::{name}> for odf::{context}::{name} {{"
+        "impl<'fb> FlatbuffersEnumDeserializable<'fb, fb::{fb_name}> for odf::{context}::{name} {{"
     )?;
     writeln!(
         w,
-        "fn deserialize(table: flatbuffers::Table<'fb>, t: fb::{name}) -> Self {{"
+        "fn deserialize(table: flatbuffers::Table<'fb>, t: fb::{fb_name}) -> Result<Self, FlatbuffersDecodeError> {{"
     )?;
     writeln!(w, "match t {{")?;
 
     for variant in &typ.variants {
-        let var_ctx = variant.context();
-        let typ = variant.join("");
-        let var = variant.name();
-        writeln!(w, "fb::{name}::{typ} => odf::{context}::{name}::{var}(")?;
-        writeln!(w, "    odf::{var_ctx}::{typ}::deserialize(")?;
-        writeln!(w, "        unsafe {{ fb::{typ}::init_from_table(table) }}")?;
-        writeln!(w, "    )")?;
-        writeln!(w, "),")?;
+        let var_ctx = variant.id.context();
+        let typ = variant.id.join("");
+        let var = variant.id.name();
+        let var_fb_name = helpers.fb_name(&variant.id);
+        writeln!(
+            w,
+            "fb::{fb_name}::{typ} => odf::{var_ctx}::{typ}::deserialize("
+        )?;
+        writeln!(
+            w,
+            "    unsafe {{ fb::{var_fb_name}::init_from_table(table) }}"
+        )?;
+        writeln!(w, ").map(odf::{context}::{name}::{var}),")?;
+    }
+    if typ.has_unknown_variant() {
+        // Newer peers may write variants we don't know, so we preserve them verbatim
+        writeln!(
+            w,
+            "fb::{fb_name}::NONE => Err(FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::UnknownUnionVariant {{ typ: \"{name}\", value: t.0 }})),"
+        )?;
+        writeln!(
+            w,
+            "_ => unknown_variant_from_table(table, t.0).map(odf::{context}::{name}::Unknown),"
+        )?;
+    } else {
+        writeln!(
+            w,
+            "_ => Err(FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::UnknownUnionVariant {{ typ: \"{name}\", value: t.0 }})),"
+        )?;
     }
-    writeln!(w, "_ => panic!(\"Invalid enum value: {{}}\", t.0),")?;
 
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
@@ -715,34 +1160,72 @@ fn render_union(
 
 fn render_enum(
     typ: &model::Enum,
+    helpers: &Helpers,
     w: &mut IndentWriter<&mut dyn std::io::Write>,
 ) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = &typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
 
-    writeln!(w, "impl From<odf::{context}::{name}> for fb::{name} {{")?;
-    writeln!(w, "fn from(v: odf::{context}::{name}) -> Self {{")?;
+    writeln!(
+        w,
+        "impl TryFrom<&odf::{context}::{name}> for fb::{fb_name} {{"
+    )?;
+    writeln!(w, "type Error = FlatbuffersEncodeError;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "fn try_from(v: &odf::{context}::{name}) -> Result<Self, FlatbuffersEncodeError> {{"
+    )?;
     writeln!(w, "match v {{")?;
     for variant in &typ.variants {
         writeln!(
             w,
-            "odf::{context}::{name}::{variant} => fb::{name}::{variant},"
+            "odf::{context}::{name}::{variant} => Ok(fb::{fb_name}::{variant}),"
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "odf::{context}::{name}::Unknown(odf::dtos::UnknownEnumValue::Flatbuffers(v)) => Ok(fb::{fb_name}(*v as _)),"
+        )?;
+        writeln!(
+            w,
+            "odf::{context}::{name}::Unknown(odf::dtos::UnknownEnumValue::Json(v)) => Err(FlatbuffersEncodeError::invalid_value(format!(\"Unknown value {{v}} of {name} was read from a manifest and has no flatbuffers representation\"))),"
         )?;
     }
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
     writeln!(w, "")?;
-    writeln!(w, "impl Into<odf::{context}::{name}> for fb::{name} {{")?;
-    writeln!(w, "fn into(self) -> odf::{context}::{name} {{")?;
-    writeln!(w, "match self {{")?;
+    writeln!(
+        w,
+        "impl TryFrom<fb::{fb_name}> for odf::{context}::{name} {{"
+    )?;
+    writeln!(w, "type Error = FlatbuffersDecodeError;")?;
+    writeln!(w)?;
+    writeln!(
+        w,
+        "fn try_from(v: fb::{fb_name}) -> Result<Self, FlatbuffersDecodeError> {{"
+    )?;
+    writeln!(w, "match v {{")?;
     for variant in &typ.variants {
         writeln!(
             w,
-            "fb::{name}::{variant} => odf::{context}::{name}::{variant},"
+            "fb::{fb_name}::{variant} => Ok(odf::{context}::{name}::{variant}),"
+        )?;
+    }
+    if typ.has_unknown_variant() {
+        writeln!(
+            w,
+            "_ => Ok(odf::{context}::{name}::Unknown(odf::dtos::UnknownEnumValue::Flatbuffers(v.0 as i64))),"
+        )?;
+    } else {
+        writeln!(
+            w,
+            "_ => Err(FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::UnknownEnumValue {{ typ: \"{name}\", value: v.0 as i64 }})),"
         )?;
     }
-    writeln!(w, "_ => panic!(\"Invalid enum value: {{}}\", self.0),")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
     writeln!(w, "}}")?;
@@ -752,34 +1235,42 @@ fn render_enum(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn render_map(typ: &model::Map, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+fn render_map(
+    typ: &model::Map,
+    helpers: &Helpers,
+    w: &mut dyn std::io::Write,
+) -> Result<(), std::io::Error> {
     match typ.get_hint::<FlatbuffersMapFormat>(CodegenLanguage::Flatbuffers, CodegenHint::MapFormat)
     {
-        None => render_map_with_entry_table(typ, w),
-        Some(FlatbuffersMapFormat::JsonEncodedString) => render_map_json_encoded_string(typ, w),
+        None => render_map_with_entry_table(typ, helpers, w),
+        Some(FlatbuffersMapFormat::JsonEncodedString) => {
+            render_map_json_encoded_string(typ, helpers, w)
+        }
     }
 }
 
 fn render_map_with_entry_table(
     typ: &model::Map,
+    helpers: &Helpers,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
 
     let value_ser = match &typ.value_type {
-        model::Type::AnyJson => format!("fb.create_string(&serde_json::to_string(value).unwrap())"),
-        model::Type::Custom(_) => format!("value.serialize(fb)"),
+        model::Type::AnyJson => format!(
+            "fb.create_string(&serde_json::to_string(value).map_err(FlatbuffersEncodeError::invalid_value)?)"
+        ),
+        model::Type::Custom(_) => format!("value.serialize(fb)?"),
         _ => unreachable!(),
     };
     let value_de = match &typ.value_type {
-        model::Type::AnyJson => format!("serde_json::from_str(entry.value().unwrap()).unwrap()"),
+        model::Type::AnyJson => {
+            format!("serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value)")
+        }
         model::Type::Custom(id) => {
-            format!(
-                "odf::{}::{}::deserialize(entry.value().unwrap())",
-                id.context(),
-                id.join("")
-            )
+            format!("odf::{}::{}::deserialize(v)", id.context(), id.join(""))
         }
         _ => unreachable!(),
     };
@@ -788,42 +1279,48 @@ fn render_map_with_entry_table(
         w,
         r#"
         impl<'fb> FlatbuffersSerializable<'fb> for odf::{context}::{name} {{
-            type OffsetT = WIPOffset<fb::{name}<'fb>>;
+            type OffsetT = WIPOffset<fb::{fb_name}<'fb>>;
 
-            fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {{
-                let entries: Vec<_> = self
+            fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Result<Self::OffsetT, FlatbuffersEncodeError> {{
+                let entries = self
                     .entries
                     .iter()
-                    .map(|(key, value)| {{
+                    .map(|(key, value)| -> Result<_, FlatbuffersEncodeError> {{
                         let key_offset = fb.create_string(key.as_str());
                         let value_offset = {value_ser};
-                        let mut entry_builder = fb::{name}EntryBuilder::new(fb);
+                        let mut entry_builder = fb::{fb_name}EntryBuilder::new(fb);
                         entry_builder.add_key(key_offset);
                         entry_builder.add_value(value_offset);
-                        entry_builder.finish()
+                        Ok(entry_builder.finish())
                     }})
-                    .collect();
+                    .collect::<Result<Vec<_>, _>>()?;
                 let entries_offset = fb.create_vector(&entries);
-                let mut builder = fb::{name}Builder::new(fb);
+                let mut builder = fb::{fb_name}Builder::new(fb);
                 builder.add_entries(entries_offset);
-                builder.finish()
+                Ok(builder.finish())
             }}
         }}
 
-        impl<'fb> FlatbuffersDeserializable<fb::{name}<'fb>> for odf::{context}::{name} {{
-            fn deserialize(proxy: fb::{name}<'fb>) -> Self {{
-                Self {{
-                    entries: proxy
-                        .entries()
-                        .unwrap_or_default()
-                        .iter()
-                        .map(|entry| {{
-                            let key = entry.key().parse().unwrap();
-                            let value = {value_de};
-                            (key, value)
-                        }})
-                        .collect(),
-                }}
+        impl<'fb> FlatbuffersDeserializable<fb::{fb_name}<'fb>> for odf::{context}::{name} {{
+            fn deserialize(proxy: fb::{fb_name}<'fb>) -> Result<Self, FlatbuffersDecodeError> {{
+                let entries = proxy
+                    .entries()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|entry| -> Result<_, FlatbuffersDecodeError> {{
+                        let key = entry
+                            .key()
+                            .parse()
+                            .map_err(FlatbuffersDecodeError::invalid_value)?;
+                        let value = entry
+                            .value()
+                            .ok_or_else(FlatbuffersDecodeError::missing_field)
+                            .and_then(|v| {value_de})?;
+                        Ok((key, value))
+                    }})
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| e.at("{name}.entries"))?;
+                Ok(Self {{ entries }})
             }}
         }}
         "#
@@ -833,29 +1330,37 @@ fn render_map_with_entry_table(
 
 fn render_map_json_encoded_string(
     typ: &model::Map,
+    helpers: &Helpers,
     w: &mut dyn std::io::Write,
 ) -> Result<(), std::io::Error> {
     let context = typ.id.context();
     let name = typ.id.join("");
+    let fb_name = helpers.fb_name(&typ.id);
 
     writeln!(
         w,
         r#"
         impl<'fb> FlatbuffersSerializable<'fb> for odf::{context}::{name} {{
-            type OffsetT = WIPOffset<fb::{name}<'fb>>;
+            type OffsetT = WIPOffset<fb::{fb_name}<'fb>>;
 
-            fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Self::OffsetT {{
-                let entries_offset = fb.create_string(&serde_json::to_string(&self.entries).unwrap());
-                let mut builder = fb::{name}Builder::new(fb);
+            fn serialize(&self, fb: &mut FlatBufferBuilder<'fb>) -> Result<Self::OffsetT, FlatbuffersEncodeError> {{
+                let entries_offset = fb.create_string(
+                    &serde_json::to_string(&self.entries).map_err(FlatbuffersEncodeError::invalid_value)?,
+                );
+                let mut builder = fb::{fb_name}Builder::new(fb);
                 builder.add_entries(entries_offset);
-                builder.finish()
+                Ok(builder.finish())
             }}
         }}
 
-        impl<'fb> FlatbuffersDeserializable<fb::{name}<'fb>> for odf::{context}::{name} {{
-            fn deserialize(proxy: fb::{name}<'fb>) -> Self {{
-                let entries = serde_json::from_str(proxy.entries().unwrap()).unwrap();
-                Self {{ entries }}
+        impl<'fb> FlatbuffersDeserializable<fb::{fb_name}<'fb>> for odf::{context}::{name} {{
+            fn deserialize(proxy: fb::{fb_name}<'fb>) -> Result<Self, FlatbuffersDecodeError> {{
+                let entries = proxy
+                    .entries()
+                    .ok_or_else(FlatbuffersDecodeError::missing_field)
+                    .and_then(|v| serde_json::from_str(v).map_err(FlatbuffersDecodeError::invalid_value))
+                    .map_err(|e| e.at("{name}.entries"))?;
+                Ok(Self {{ entries }})
             }}
         }}
         "#
@@ -865,11 +1370,3 @@ fn render_map_json_encoded_string(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn format_accessor<'a>(ident: &'a str) -> Cow<'a, str> {
-    match ident {
-        "type" => "type_".into(),
-        _ => Cow::Borrowed(ident.trim_start_matches("$")),
-    }
-}
-
-////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,476 @@
+use serde_json::{Map, Value};
+
+use crate::model::{self, Model, Type, TypeDefinition, TypeId};
+use crate::validation::{child_path, manifest_type, resolve_variant};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Single operation of an RFC 6902 JSON Patch
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "op", rename_all = "lowercase")]
+pub enum PatchOp {
+    Add { path: String, value: Value },
+    Remove { path: String },
+    Replace { path: String, value: Value },
+    Move { from: String, path: String },
+    Copy { from: String, path: String },
+    Test { path: String, value: Value },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChangeKind {
+    Added(Value),
+    Removed(Value),
+    Modified {
+        old: Value,
+        new: Value,
+    },
+    /// Array item identified by a merge key changed its position
+    Moved {
+        from: usize,
+        to: usize,
+    },
+}
+
+/// Difference between two versions of a value
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    /// Dot-separated path to the value that identifies array items by their merge keys, e.g. `spec.env[name=FOO]`
+    pub path: String,
+    pub kind: ChangeKind,
+    /// Operation that applies this change on top of the preceding ones
+    pub op: PatchOp,
+}
+
+impl std::fmt::Display for Change {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let path = if self.path.is_empty() {
+            "<root>"
+        } else {
+            &self.path
+        };
+        match &self.kind {
+            ChangeKind::Added(value) => write!(f, "+ {path}: {value}"),
+            ChangeKind::Removed(value) => write!(f, "- {path}: {value}"),
+            ChangeKind::Modified { old, new } => write!(f, "~ {path}: {old} -> {new}"),
+            ChangeKind::Moved { from, to } => {
+                write!(f, "~ {path}: moved from index {from} to {to}")
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Computes the difference between two versions of a manifest of the same type specified in their `$schema` properties
+pub fn diff_manifests(model: &Model, old: &Value, new: &Value) -> Result<Vec<Change>, String> {
+    let old_id = manifest_type(model, old)?;
+    let new_id = manifest_type(model, new)?;
+    if old_id != new_id {
+        return Err(format!(
+            "Manifests are of different types: {} and {}",
+            old_id.schema_id(),
+            new_id.schema_id()
+        ));
+    }
+    Ok(diff(model, &old_id, old, new))
+}
+
+/// Computes the difference between two values of the specified type.
+///
+/// Struct properties and map entries are compared by name, union values only when they are of the same variant, and
+/// array items by their merge keys (or by position when the array doesn't have one).
+pub fn diff(model: &Model, type_id: &TypeId, old: &Value, new: &Value) -> Vec<Change> {
+    let mut d = Differ {
+        model,
+        changes: Vec::new(),
+    };
+    d.diff_type_def(type_id, old, new, "", "");
+    d.changes
+}
+
+/// Converts changes into a JSON Patch document
+pub fn to_json_patch(changes: &[Change]) -> Value {
+    Value::Array(
+        changes
+            .iter()
+            .map(|c| serde_json::to_value(&c.op).unwrap())
+            .collect(),
+    )
+}
+
+/// Applies a JSON Patch, leaving the value unchanged if any of the operations fails
+pub fn apply(value: &mut Value, patch: &[PatchOp]) -> Result<(), String> {
+    let mut res = value.clone();
+    for op in patch {
+        apply_op(&mut res, op)?;
+    }
+    *value = res;
+    Ok(())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Differ<'a> {
+    model: &'a Model,
+    changes: Vec<Change>,
+}
+
+impl Differ<'_> {
+    fn added(&mut self, ptr: String, path: String, value: &Value) {
+        self.changes.push(Change {
+            path,
+            kind: ChangeKind::Added(value.clone()),
+            op: PatchOp::Add {
+                path: ptr,
+                value: value.clone(),
+            },
+        });
+    }
+
+    fn removed(&mut self, ptr: String, path: String, value: &Value) {
+        self.changes.push(Change {
+            path,
+            kind: ChangeKind::Removed(value.clone()),
+            op: PatchOp::Remove { path: ptr },
+        });
+    }
+
+    fn modified(&mut self, ptr: &str, path: &str, old: &Value, new: &Value) {
+        self.changes.push(Change {
+            path: path.to_string(),
+            kind: ChangeKind::Modified {
+                old: old.clone(),
+                new: new.clone(),
+            },
+            op: PatchOp::Replace {
+                path: ptr.to_string(),
+                value: new.clone(),
+            },
+        });
+    }
+
+    fn diff_type_def(&mut self, id: &TypeId, old: &Value, new: &Value, ptr: &str, path: &str) {
+        if old == new {
+            return;
+        }
+
+        let model = self.model;
+        let Some(typ) = model.types.get(id) else {
+            panic!("Reference to unknown type {}", id.schema_id());
+        };
+
+        match (typ, old, new) {
+            (TypeDefinition::Struct(t), Value::Object(old), Value::Object(new)) => self
+                .diff_object(old, new, ptr, path, |key| {
+                    t.field_by_json_name(key).map(|(f, _)| &f.typ)
+                }),
+            (TypeDefinition::Union(t), _, _) => self.diff_union(t, old, new, ptr, path),
+            (TypeDefinition::Map(t), Value::Object(old), Value::Object(new)) => {
+                self.diff_object(old, new, ptr, path, |_| Some(&t.value_type))
+            }
+            _ => self.modified(ptr, path, old, new),
+        }
+    }
+
+    fn diff_union(&mut self, t: &model::Union, old: &Value, new: &Value, ptr: &str, path: &str) {
+        let old_variant = resolve_variant(self.model, t, old);
+        let new_variant = resolve_variant(self.model, t, new);
+
+        // Values of different variants have nothing in common
+        match (old_variant, new_variant) {
+            (Some(a), Some(b)) if a.id == b.id => self.diff_type_def(&a.id, old, new, ptr, path),
+            _ => self.modified(ptr, path, old, new),
+        }
+    }
+
+    // Properties that are not in the schema are compared as plain JSON
+    fn diff_object<'t>(
+        &mut self,
+        old: &Map<String, Value>,
+        new: &Map<String, Value>,
+        ptr: &str,
+        path: &str,
+        field_type: impl Fn(&str) -> Option<&'t Type>,
+    ) {
+        for (key, old_value) in old {
+            let child_ptr = format!("{ptr}/{}", escape_pointer(key));
+            let child = child_path(path, key);
+            match new.get(key) {
+                None => self.removed(child_ptr, child, old_value),
+                Some(new_value) => match field_type(key) {
+                    Some(typ) => self.diff_type(typ, old_value, new_value, &child_ptr, &child),
+                    None => self.diff_json(old_value, new_value, &child_ptr, &child),
+                },
+            }
+        }
+        for (key, new_value) in new {
+            if !old.contains_key(key) {
+                self.added(
+                    format!("{ptr}/{}", escape_pointer(key)),
+                    child_path(path, key),
+                    new_value,
+                );
+            }
+        }
+    }
+
+    fn diff_type(&mut self, typ: &Type, old: &Value, new: &Value, ptr: &str, path: &str) {
+        if old == new {
+            return;
+        }
+
+        match (typ, old, new) {
+            (Type::Custom(id), _, _) => self.diff_type_def(id, old, new, ptr, path),
+            (Type::Array(arr), Value::Array(old), Value::Array(new)) => {
+                match arr
+                    .merge_key
+                    .as_deref()
+                    .and_then(|key| Some((key, item_keys(old, key)?, item_keys(new, key)?)))
+                {
+                    Some((key, old_keys, new_keys)) => self.diff_keyed_array(
+                        &arr.item_type,
+                        key,
+                        (old, old_keys),
+                        (new, new_keys),
+                        ptr,
+                        path,
+                    ),
+                    None => self.diff_array(old, new, ptr, path, |d, old, new, ptr, path| {
+                        d.diff_type(&arr.item_type, old, new, ptr, path)
+                    }),
+                }
+            }
+            (Type::AnyJson | Type::Generic(_), _, _) => self.diff_json(old, new, ptr, path),
+            _ => self.modified(ptr, path, old, new),
+        }
+    }
+
+    // Emits operations so that each one applies to the result of the previous: removals go first from the end of the
+    // array, followed by moves and insertions in the order of the new items
+    fn diff_keyed_array(
+        &mut self,
+        item_type: &Type,
+        key: &str,
+        (old, old_keys): (&[Value], Vec<String>),
+        (new, new_keys): (&[Value], Vec<String>),
+        ptr: &str,
+        path: &str,
+    ) {
+        let item_path = |k: &str| format!("{path}[{key}={k}]");
+
+        let mut current = old_keys.clone();
+
+        for i in (0..old.len()).rev() {
+            if !new_keys.contains(&old_keys[i]) {
+                self.removed(format!("{ptr}/{i}"), item_path(&old_keys[i]), &old[i]);
+                current.remove(i);
+            }
+        }
+
+        for (i, (k, new_item)) in new_keys.iter().zip(new).enumerate() {
+            let item_ptr = format!("{ptr}/{i}");
+
+            let Some(j) = current.iter().position(|c| c == k) else {
+                self.added(item_ptr, item_path(k), new_item);
+                current.insert(i, k.clone());
+                continue;
+            };
+
+            if j != i {
+                self.changes.push(Change {
+                    path: item_path(k),
+                    kind: ChangeKind::Moved { from: j, to: i },
+                    op: PatchOp::Move {
+                        from: format!("{ptr}/{j}"),
+                        path: item_ptr.clone(),
+                    },
+                });
+                let k = current.remove(j);
+                current.insert(i, k);
+            }
+
+            let old_item = &old[old_keys.iter().position(|o| o == k).unwrap()];
+            self.diff_type(item_type, old_item, new_item, &item_ptr, &item_path(k));
+        }
+    }
+
+    fn diff_array(
+        &mut self,
+        old: &[Value],
+        new: &[Value],
+        ptr: &str,
+        path: &str,
+        diff_item: impl Fn(&mut Self, &Value, &Value, &str, &str),
+    ) {
+        for (i, (old_item, new_item)) in old.iter().zip(new).enumerate() {
+            diff_item(
+                self,
+                old_item,
+                new_item,
+                &format!("{ptr}/{i}"),
+                &format!("{path}[{i}]"),
+            );
+        }
+        for (i, new_item) in new.iter().enumerate().skip(old.len()) {
+            self.added(format!("{ptr}/{i}"), format!("{path}[{i}]"), new_item);
+        }
+        for (i, old_item) in old.iter().enumerate().skip(new.len()).rev() {
+            self.removed(format!("{ptr}/{i}"), format!("{path}[{i}]"), old_item);
+        }
+    }
+
+    fn diff_json(&mut self, old: &Value, new: &Value, ptr: &str, path: &str) {
+        if old == new {
+            return;
+        }
+
+        match (old, new) {
+            (Value::Object(old), Value::Object(new)) => {
+                self.diff_object(old, new, ptr, path, |_| None)
+            }
+            (Value::Array(old), Value::Array(new)) => {
+                self.diff_array(old, new, ptr, path, |d, old, new, ptr, path| {
+                    d.diff_json(old, new, ptr, path)
+                })
+            }
+            _ => self.modified(ptr, path, old, new),
+        }
+    }
+}
+
+// Returns `None` if any of the items lacks a scalar key or keys are not unique, in which case the array is compared
+// by position
+pub(crate) fn item_keys(items: &[Value], key: &str) -> Option<Vec<String>> {
+    let mut keys = Vec::with_capacity(items.len());
+    for item in items {
+        let k = match item.as_object()?.get(key)? {
+            Value::String(s) => s.clone(),
+            v @ (Value::Number(_) | Value::Bool(_)) => v.to_string(),
+            _ => return None,
+        };
+        if keys.contains(&k) {
+            return None;
+        }
+        keys.push(k);
+    }
+    Some(keys)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn escape_pointer(token: &str) -> String {
+    token.replace('~', "~0").replace('/', "~1")
+}
+
+fn parse_pointer(pointer: &str) -> Result<Vec<String>, String> {
+    if pointer.is_empty() {
+        return Ok(Vec::new());
+    }
+    let Some(rest) = pointer.strip_prefix('/') else {
+        return Err(format!("Invalid JSON pointer `{pointer}`"));
+    };
+    Ok(rest
+        .split('/')
+        .map(|t| t.replace("~1", "/").replace("~0", "~"))
+        .collect())
+}
+
+fn parse_index(token: &str, len: usize, pointer: &str) -> Result<usize, String> {
+    match token.parse::<usize>() {
+        Ok(i) if i < len && (token == "0" || !token.starts_with('0')) => Ok(i),
+        _ => Err(format!("Invalid array index at `{pointer}`")),
+    }
+}
+
+fn resolve<'a>(
+    doc: &'a mut Value,
+    tokens: &[String],
+    pointer: &str,
+) -> Result<&'a mut Value, String> {
+    let mut cur = doc;
+    for token in tokens {
+        cur = match cur {
+            Value::Object(obj) => obj
+                .get_mut(token)
+                .ok_or_else(|| format!("Path `{pointer}` does not exist"))?,
+            Value::Array(arr) => {
+                let i = parse_index(token, arr.len(), pointer)?;
+                &mut arr[i]
+            }
+            _ => return Err(format!("Path `{pointer}` does not exist")),
+        };
+    }
+    Ok(cur)
+}
+
+fn add(doc: &mut Value, pointer: &str, value: Value) -> Result<(), String> {
+    let tokens = parse_pointer(pointer)?;
+    let Some((last, parent)) = tokens.split_last() else {
+        *doc = value;
+        return Ok(());
+    };
+
+    match resolve(doc, parent, pointer)? {
+        Value::Object(obj) => {
+            obj.insert(last.clone(), value);
+        }
+        Value::Array(arr) if last == "-" => arr.push(value),
+        Value::Array(arr) => {
+            // Inserting right after the last item is allowed
+            let i = parse_index(last, arr.len() + 1, pointer)?;
+            arr.insert(i, value);
+        }
+        _ => return Err(format!("Path `{pointer}` does not exist")),
+    }
+    Ok(())
+}
+
+fn remove(doc: &mut Value, pointer: &str) -> Result<Value, String> {
+    let tokens = parse_pointer(pointer)?;
+    let Some((last, parent)) = tokens.split_last() else {
+        return Err("Cannot remove the root of the document".to_string());
+    };
+
+    match resolve(doc, parent, pointer)? {
+        Value::Object(obj) => obj
+            .shift_remove(last)
+            .ok_or_else(|| format!("Path `{pointer}` does not exist")),
+        Value::Array(arr) => {
+            let i = parse_index(last, arr.len(), pointer)?;
+            Ok(arr.remove(i))
+        }
+        _ => Err(format!("Path `{pointer}` does not exist")),
+    }
+}
+
+fn apply_op(doc: &mut Value, op: &PatchOp) -> Result<(), String> {
+    match op {
+        PatchOp::Add { path, value } => add(doc, path, value.clone()),
+        PatchOp::Remove { path } => remove(doc, path).map(|_| ()),
+        PatchOp::Replace { path, value } => {
+            *resolve(doc, &parse_pointer(path)?, path)? = value.clone();
+            Ok(())
+        }
+        PatchOp::Move { from, path } => {
+            if path.starts_with(&format!("{from}/")) {
+                return Err(format!("Cannot move `{from}` into its own child `{path}`"));
+            }
+            let value = remove(doc, from)?;
+            add(doc, path, value)
+        }
+        PatchOp::Copy { from, path } => {
+            let value = resolve(doc, &parse_pointer(from)?, from)?.clone();
+            add(doc, path, value)
+        }
+        PatchOp::Test { path, value } => {
+            if resolve(doc, &parse_pointer(path)?, path)? == value {
+                Ok(())
+            } else {
+                Err(format!("Value at `{path}` is not {value}"))
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,407 @@
+use serde_json::{Map, Value};
+
+use crate::model::{self, Discriminator, Model, Type, TypeDefinition, TypeId};
+use crate::validation::child_path;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Header properties that filters match as identity fields, all other keys refer to labels
+pub(crate) const HEADER_FIELDS: &[&str] =
+    &["id", "name", "account.id", "account.did", "account.name"];
+
+/// Parsed filter expression, e.g. of a `LabelFilter` or an `EventFilter`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Matches when all of the nested filters match
+    And(Vec<Filter>),
+    /// Matches when at least one of the nested filters matches
+    Or(Vec<Filter>),
+    /// Matches when the nested filter does not match
+    Not(Box<Filter>),
+    /// Matches when every field satisfies its conditions, matches anything when empty
+    Match(Vec<FieldCondition>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldCondition {
+    /// Name of the label or a dot-separated path to the field, e.g. `account.name`
+    pub field: String,
+    /// Conditions that all must hold for the field
+    pub conditions: Vec<Condition>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Condition {
+    Eq(Value),
+    In(Vec<Value>),
+    Exists(bool),
+    Glob(glob::Pattern),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterError {
+    /// Dot-separated path to the offending value within the filter, e.g. `$or[1].env`
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for FilterError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Parses a filter of the specified type.
+///
+/// The grammar is taken from the schema: a filter type is an untagged union of logical operators (structs with a
+/// single `$`-prefixed property) and a map of field conditions, whose value type lists the condition operators.
+pub fn parse(model: &Model, type_id: &TypeId, value: &Value) -> Result<Filter, FilterError> {
+    let grammar = match model.types.get(type_id) {
+        Some(TypeDefinition::Union(t)) => Grammar::new(model, t).map_err(|e| FilterError {
+            path: String::new(),
+            message: e,
+        })?,
+        _ => None,
+    };
+    let Some(grammar) = grammar else {
+        return Err(FilterError {
+            path: String::new(),
+            message: format!("{} is not a filter type", type_id.join("::")),
+        });
+    };
+    grammar.parse_filter(value, "")
+}
+
+/// Parses a value of a union if it is a filter type
+pub(crate) fn parse_union(
+    model: &Model,
+    t: &model::Union,
+    value: &Value,
+) -> Option<Result<Filter, FilterError>> {
+    match Grammar::new(model, t) {
+        Ok(grammar) => grammar.map(|g| g.parse_filter(value, "")),
+        Err(e) => Some(Err(FilterError {
+            path: String::new(),
+            message: e,
+        })),
+    }
+}
+
+/// Checks that a union shaped as a filter only uses operators that the parser supports
+pub(crate) fn check_grammar(model: &Model, t: &model::Union) -> Result<(), String> {
+    Grammar::new(model, t).map(|_| ())
+}
+
+impl Filter {
+    /// Evaluates the filter against the fields resolved by the specified function
+    pub fn matches<'v>(&self, field: &impl Fn(&str) -> Option<&'v Value>) -> bool {
+        match self {
+            Filter::And(filters) => filters.iter().all(|f| f.matches(field)),
+            Filter::Or(filters) => filters.iter().any(|f| f.matches(field)),
+            Filter::Not(filter) => !filter.matches(field),
+            Filter::Match(fields) => fields.iter().all(|fc| {
+                let value = field(&fc.field).filter(|v| !v.is_null());
+                fc.conditions.iter().all(|c| c.matches(value))
+            }),
+        }
+    }
+
+    /// Evaluates the filter against `ResourceHeaders`, resolving identity fields like `name` or `account.name` from
+    /// the headers and all other keys from labels, where short names match full URIs of the types they name
+    pub fn matches_headers(&self, model: &Model, headers: &Value) -> bool {
+        self.matches(&|key| {
+            if HEADER_FIELDS.contains(&key) {
+                lookup(headers, key)
+            } else {
+                let labels = headers.get("labels")?.as_object()?;
+                labels.get(key).or_else(|| {
+                    labels
+                        .iter()
+                        .find(|(label, _)| is_same_label(model, label, key))
+                        .map(|(_, v)| v)
+                })
+            }
+        })
+    }
+
+    /// Evaluates the filter against an event payload, resolving keys as dot-separated paths, e.g. `dataset.id`
+    pub fn matches_event(&self, event: &Value) -> bool {
+        self.matches(&|key| lookup(event, key))
+    }
+}
+
+impl Condition {
+    pub fn matches(&self, value: Option<&Value>) -> bool {
+        match (self, value) {
+            (Condition::Exists(exists), _) => value.is_some() == *exists,
+            (_, None) => false,
+            (Condition::Eq(expected), Some(value)) => value == expected,
+            (Condition::In(values), Some(value)) => values.contains(value),
+            (Condition::Glob(pattern), Some(value)) => {
+                value.as_str().is_some_and(|s| pattern.matches(s))
+            }
+        }
+    }
+}
+
+// Label keys are type references, so `datasetKind` is the same label as `https://.../DatasetKind` when that is the
+// only type the short name refers to
+pub(crate) fn is_same_label(model: &Model, a: &str, b: &str) -> bool {
+    let is_type = |short_name: &str, uri: &str| {
+        let mut types = model
+            .types
+            .keys()
+            .filter(|id| model::is_same_name(id.name(), short_name));
+        match (types.next(), types.next()) {
+            (Some(id), None) => id.schema_id().as_str() == uri,
+            _ => false,
+        }
+    };
+    match (a.contains("://"), b.contains("://")) {
+        (true, false) => is_type(b, a),
+        (false, true) => is_type(a, b),
+        _ => a == b,
+    }
+}
+
+fn lookup<'v>(value: &'v Value, path: &str) -> Option<&'v Value> {
+    path.split('.').try_fold(value, |v, key| v.get(key))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy)]
+enum Operator {
+    And,
+    Or,
+    Not,
+}
+
+struct Grammar<'a> {
+    id: &'a TypeId,
+    /// Logical operators with their property names, e.g. `$and`
+    operators: Vec<(String, Operator)>,
+    /// Condition operator property names, e.g. `$in`
+    conditions: Vec<String>,
+}
+
+impl<'a> Grammar<'a> {
+    // Returns `None` for unions that are not shaped as filters and an error for filters with unsupported operators
+    fn new(model: &'a Model, t: &'a model::Union) -> Result<Option<Self>, String> {
+        if t.discriminator != Discriminator::Untagged {
+            return Ok(None);
+        }
+
+        let mut operators = Vec::new();
+        let mut conditions = None;
+
+        for variant in &t.variants {
+            let Some(variant) = model.types.get(&variant.id) else {
+                return Ok(None);
+            };
+            match variant {
+                TypeDefinition::Struct(s) if s.fields.len() == 1 => {
+                    let field = s.fields.values().next().unwrap();
+                    let name = field.json_name();
+                    let is_operand = match &field.typ {
+                        Type::Custom(id) => id == &t.id,
+                        Type::Array(arr) => {
+                            matches!(&*arr.item_type, Type::Custom(id) if id == &t.id)
+                        }
+                        _ => false,
+                    };
+                    if !name.starts_with('$') || !is_operand {
+                        return Ok(None);
+                    }
+                    let op = match name.as_str() {
+                        "$and" => Operator::And,
+                        "$or" => Operator::Or,
+                        "$not" => Operator::Not,
+                        _ => {
+                            return Err(format!(
+                                "Filter operator `{name}` of {} is not supported",
+                                t.id.join("::")
+                            ));
+                        }
+                    };
+                    operators.push((name, op));
+                }
+                TypeDefinition::Map(m) => {
+                    let Type::Custom(cond_id) = &m.value_type else {
+                        return Ok(None);
+                    };
+                    let Some(TypeDefinition::Struct(cond)) = model.types.get(cond_id) else {
+                        return Ok(None);
+                    };
+                    let names: Vec<_> = cond.fields.values().map(|f| f.json_name()).collect();
+                    if !names.iter().all(|n| n.starts_with('$')) {
+                        return Ok(None);
+                    }
+                    if let Some(name) = names
+                        .iter()
+                        .find(|n| !["$eq", "$in", "$exists", "$glob"].contains(&n.as_str()))
+                    {
+                        return Err(format!(
+                            "Filter condition operator `{name}` of {} is not supported",
+                            cond_id.join("::")
+                        ));
+                    }
+                    conditions = Some(names);
+                }
+                _ => return Ok(None),
+            }
+        }
+
+        Ok(conditions.map(|conditions| Self {
+            id: &t.id,
+            operators,
+            conditions,
+        }))
+    }
+
+    fn error(path: &str, message: String) -> FilterError {
+        FilterError {
+            path: path.to_string(),
+            message,
+        }
+    }
+
+    fn parse_filter(&self, value: &Value, path: &str) -> Result<Filter, FilterError> {
+        let Some(obj) = value.as_object() else {
+            return Err(Self::error(
+                path,
+                format!("Expected an object of type {}", self.id.join("::")),
+            ));
+        };
+
+        let Some(key) = obj.keys().find(|k| k.starts_with('$')) else {
+            return self.parse_match(obj, path);
+        };
+
+        let op_path = child_path(path, key);
+        let Some((_, op)) = self.operators.iter().find(|(name, _)| name == key) else {
+            return Err(Self::error(
+                &op_path,
+                format!(
+                    "Unknown operator `{key}`, expected one of: {}",
+                    self.operators
+                        .iter()
+                        .map(|(name, _)| name.as_str())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ));
+        };
+
+        if obj.len() != 1 {
+            return Err(Self::error(
+                path,
+                format!(
+                    "Operator `{key}` can't be combined with other properties, use `$and` to combine them"
+                ),
+            ));
+        }
+
+        let operand = &obj[key];
+        match op {
+            Operator::Not => Ok(Filter::Not(Box::new(self.parse_filter(operand, &op_path)?))),
+            Operator::And | Operator::Or => {
+                let Some(items) = operand.as_array() else {
+                    return Err(Self::error(
+                        &op_path,
+                        "Expected an array of filters".to_string(),
+                    ));
+                };
+                let filters = items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| self.parse_filter(item, &format!("{op_path}[{i}]")))
+                    .collect::<Result<_, _>>()?;
+                Ok(match op {
+                    Operator::And => Filter::And(filters),
+                    _ => Filter::Or(filters),
+                })
+            }
+        }
+    }
+
+    fn parse_match(&self, obj: &Map<String, Value>, path: &str) -> Result<Filter, FilterError> {
+        let mut fields = Vec::new();
+        for (field, value) in obj {
+            fields.push(FieldCondition {
+                field: field.clone(),
+                conditions: self.parse_condition(value, &child_path(path, field))?,
+            });
+        }
+        Ok(Filter::Match(fields))
+    }
+
+    fn parse_condition(&self, value: &Value, path: &str) -> Result<Vec<Condition>, FilterError> {
+        let obj = match value {
+            Value::String(_) => return Ok(vec![Condition::Eq(value.clone())]),
+            Value::Object(obj) => obj,
+            _ => {
+                return Err(Self::error(
+                    path,
+                    format!(
+                        "Expected a string or an object with one of: {}, use `$eq` to match values of other types",
+                        self.conditions.join(", ")
+                    ),
+                ));
+            }
+        };
+
+        if obj.is_empty() {
+            return Err(Self::error(
+                path,
+                format!(
+                    "Condition must specify at least one of: {}",
+                    self.conditions.join(", ")
+                ),
+            ));
+        }
+
+        let mut conditions = Vec::new();
+        for (key, operand) in obj {
+            let op_path = child_path(path, key);
+            if !self.conditions.contains(key) {
+                return Err(Self::error(
+                    &op_path,
+                    format!(
+                        "Unknown operator `{key}`, expected one of: {}",
+                        self.conditions.join(", ")
+                    ),
+                ));
+            }
+
+            let condition = match (key.as_str(), operand) {
+                ("$eq", _) => Condition::Eq(operand.clone()),
+                ("$in", Value::Array(values)) => Condition::In(values.clone()),
+                ("$exists", Value::Bool(exists)) => Condition::Exists(*exists),
+                ("$glob", Value::String(pattern)) => match glob::Pattern::new(pattern) {
+                    Ok(pattern) => Condition::Glob(pattern),
+                    Err(e) => {
+                        return Err(Self::error(&op_path, format!("Invalid glob pattern: {e}")));
+                    }
+                },
+                ("$in", _) => {
+                    return Err(Self::error(&op_path, "Expected an array".to_string()));
+                }
+                ("$exists", _) => {
+                    return Err(Self::error(&op_path, "Expected a boolean".to_string()));
+                }
+                _ => return Err(Self::error(&op_path, "Expected a string".to_string())),
+            };
+            conditions.push(condition);
+        }
+        Ok(conditions)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
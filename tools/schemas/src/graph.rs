@@ -0,0 +1,193 @@
+use std::collections::BTreeSet;
+
+use crate::bundle;
+use crate::codegen::mermaid_erd::CONTEXT_COLORS;
+use crate::resolve::{ResourceStore, StoredResource};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Reference from one resource of a bundle to another that has to exist before it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dependency {
+    /// Index of the referencing resource
+    pub from: usize,
+    /// Index of the referenced resource
+    pub to: usize,
+    /// Dot-separated path to the reference within the referencing manifest, e.g. `spec.volume`
+    pub path: String,
+}
+
+/// Resources that reference each other
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Resource whose reference closes the cycle
+    pub from: usize,
+    /// Path to that reference
+    pub path: String,
+    /// Resources in the cycle starting and ending with the same one
+    pub resources: Vec<usize>,
+}
+
+/// Dependencies between the resources of a bundle
+pub struct DependencyGraph<'a> {
+    pub resources: &'a [StoredResource],
+    pub dependencies: Vec<Dependency>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+impl<'a> DependencyGraph<'a> {
+    pub fn new(resources: &'a [StoredResource], dependencies: Vec<Dependency>) -> Self {
+        Self {
+            resources,
+            dependencies,
+        }
+    }
+
+    /// Builds the graph of a bundle, ignoring references that don't resolve within it (see [`bundle::check`])
+    pub fn build(store: &'a ResourceStore) -> Self {
+        let (dependencies, _) = bundle::resolve_references(store);
+        Self::new(store.resources(), dependencies)
+    }
+
+    pub fn cycles(&self) -> Vec<Cycle> {
+        #[derive(Clone, Copy, PartialEq)]
+        enum State {
+            New,
+            Visiting,
+            Done,
+        }
+
+        fn visit(
+            i: usize,
+            dependencies: &[Dependency],
+            states: &mut [State],
+            stack: &mut Vec<usize>,
+            cycles: &mut Vec<Cycle>,
+        ) {
+            states[i] = State::Visiting;
+            stack.push(i);
+            for dep in dependencies.iter().filter(|d| d.from == i) {
+                match states[dep.to] {
+                    State::New => visit(dep.to, dependencies, states, stack, cycles),
+                    State::Visiting => {
+                        let start = stack.iter().position(|k| *k == dep.to).unwrap();
+                        let mut resources = stack[start..].to_vec();
+                        resources.push(dep.to);
+                        cycles.push(Cycle {
+                            from: i,
+                            path: dep.path.clone(),
+                            resources,
+                        });
+                    }
+                    State::Done => (),
+                }
+            }
+            stack.pop();
+            states[i] = State::Done;
+        }
+
+        let mut states = vec![State::New; self.resources.len()];
+        let mut cycles = Vec::new();
+        for i in 0..self.resources.len() {
+            if states[i] == State::New {
+                visit(
+                    i,
+                    &self.dependencies,
+                    &mut states,
+                    &mut Vec::new(),
+                    &mut cycles,
+                );
+            }
+        }
+        cycles
+    }
+
+    /// Orders resources so that each one comes after the resources it references, otherwise keeping the order they
+    /// were loaded in
+    pub fn creation_order(&self) -> Result<Vec<&'a StoredResource>, Vec<Cycle>> {
+        let cycles = self.cycles();
+        if !cycles.is_empty() {
+            return Err(cycles);
+        }
+
+        let mut remaining: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); self.resources.len()];
+        for dep in &self.dependencies {
+            remaining[dep.from].insert(dep.to);
+        }
+
+        let mut order = Vec::new();
+        let mut done = vec![false; self.resources.len()];
+        while order.len() < self.resources.len() {
+            let i = (0..self.resources.len())
+                .find(|i| !done[*i] && remaining[*i].is_empty())
+                .expect("Graph has no cycles");
+            done[i] = true;
+            order.push(&self.resources[i]);
+            for deps in &mut remaining {
+                deps.remove(&i);
+            }
+        }
+        Ok(order)
+    }
+
+    /// Orders resources so that each one comes before the resources it references
+    pub fn deletion_order(&self) -> Result<Vec<&'a StoredResource>, Vec<Cycle>> {
+        let mut order = self.creation_order()?;
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Renders the graph as a Mermaid flowchart with nodes colored by context like in the ERD
+    pub fn render_mermaid(&self, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+        writeln!(w, "graph LR")?;
+        writeln!(w)?;
+
+        for (context, color) in CONTEXT_COLORS {
+            writeln!(
+                w,
+                "  classDef ctx_{context} fill:{color},stroke:#999,color:#000"
+            )?;
+        }
+        writeln!(w)?;
+
+        for (i, r) in self.resources.iter().enumerate() {
+            writeln!(w, "  n{i}[\"{r}\"]")?;
+            writeln!(w, "  class n{i} ctx_{}", r.type_id.context())?;
+        }
+        writeln!(w)?;
+
+        for dep in &self.dependencies {
+            writeln!(w, "  n{} -->|\"{}\"| n{}", dep.from, dep.path, dep.to)?;
+        }
+
+        Ok(())
+    }
+
+    /// Renders the graph in the Graphviz DOT language
+    pub fn render_dot(&self, w: &mut dyn std::io::Write) -> Result<(), std::io::Error> {
+        let escape = |s: String| s.replace('\\', "\\\\").replace('"', "\\\"");
+
+        writeln!(w, "digraph dependencies {{")?;
+        writeln!(w, "  rankdir=LR;")?;
+
+        for (i, r) in self.resources.iter().enumerate() {
+            writeln!(w, "  n{i} [label=\"{}\"];", escape(r.to_string()))?;
+        }
+
+        for dep in &self.dependencies {
+            writeln!(
+                w,
+                "  n{} -> n{} [label=\"{}\"];",
+                dep.from,
+                dep.to,
+                escape(dep.path.clone())
+            )?;
+        }
+
+        writeln!(w, "}}")?;
+        Ok(())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
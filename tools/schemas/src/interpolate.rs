@@ -0,0 +1,425 @@
+use std::collections::BTreeSet;
+
+use serde_json::{Map, Value};
+
+use crate::bundle::{VALUE_REF, value_at, values_of};
+use crate::model::{MetaType, Model, Type, TypeDefinition, TypeId};
+use crate::resolve::{ResourceStore, StoredResource};
+use crate::validation::{child_path, manifest_type, resolve_variant};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const SECRET_SET: &str = "https://opendatafabric.org/schemas/config/v1alpha1/SecretSet";
+
+/// Placeholder that secret-derived values are replaced with in logs
+pub const REDACTED: &str = "***";
+
+static TEMPLATE_RE: std::sync::LazyLock<regex::Regex> =
+    std::sync::LazyLock::new(|| regex::Regex::new(r"\$\{\{(.*?)\}\}").unwrap());
+
+/// Finds the `VariableSet` or `SecretSet` that a `ValueRef` points to
+pub trait SetResolver {
+    /// Returns the referenced resource along with the path to the value within it, or `None` if nothing matches
+    fn resolve_set(
+        &self,
+        reference: &Value,
+    ) -> Result<Option<(&StoredResource, Option<String>)>, String>;
+}
+
+impl SetResolver for ResourceStore<'_> {
+    fn resolve_set(
+        &self,
+        reference: &Value,
+    ) -> Result<Option<(&StoredResource, Option<String>)>, String> {
+        self.resolve_value_ref(reference)
+    }
+}
+
+/// Value that can't be substituted
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InterpolationError {
+    /// Dot-separated path to the value within the manifest, e.g. `spec.ingress.headers[0].value`
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for InterpolationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Manifest with all values substituted.
+///
+/// `Debug` and `Display` print the manifest with secret-derived values replaced by [`REDACTED`], so it's safe to log.
+#[derive(Clone, PartialEq)]
+pub struct Interpolated {
+    value: Value,
+    secrets: BTreeSet<String>,
+}
+
+impl Interpolated {
+    /// Substituted manifest including the secrets
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn into_value(self) -> Value {
+        self.value
+    }
+
+    /// Paths to the values derived from secrets, e.g. `spec.ingress.headers[0].value`
+    pub fn secret_paths(&self) -> impl Iterator<Item = &str> {
+        self.secrets.iter().map(String::as_str)
+    }
+
+    pub fn is_secret(&self, path: &str) -> bool {
+        self.secrets.contains(path)
+    }
+
+    /// Copy of the manifest with secret-derived values replaced by [`REDACTED`]
+    pub fn redacted(&self) -> Value {
+        fn redact(value: &mut Value, path: &str, secrets: &BTreeSet<String>) {
+            if secrets.contains(path) {
+                *value = Value::String(REDACTED.to_string());
+                return;
+            }
+            match value {
+                Value::Object(obj) => {
+                    for (key, value) in obj {
+                        redact(value, &child_path(path, key), secrets);
+                    }
+                }
+                Value::Array(arr) => {
+                    for (i, item) in arr.iter_mut().enumerate() {
+                        redact(item, &format!("{path}[{i}]"), secrets);
+                    }
+                }
+                _ => (),
+            }
+        }
+
+        let mut value = self.value.clone();
+        redact(&mut value, "", &self.secrets);
+        value
+    }
+}
+
+impl std::fmt::Debug for Interpolated {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Interpolated")
+            .field("value", &self.redacted())
+            .field("secrets", &self.secrets)
+            .finish()
+    }
+}
+
+impl std::fmt::Display for Interpolated {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.redacted())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Substitutes variables and secrets into a manifest.
+///
+/// Substitution happens in two passes guided by the schema:
+/// - every `ValueRef` is replaced with the value it points to in a `VariableSet` or a `SecretSet`
+/// - `${{ scope.path }}` expressions are expanded in properties marked as `templatable` (e.g. `RequestHeader.value`)
+///
+/// Expressions look values up in `scopes` provided by the caller (e.g. `trigger` of a flow) and in the `ValueRefs`
+/// properties of the spec that are exposed under their own name (e.g. `config` of a `Source`), which take precedence.
+/// Values replaced from a `SecretSet` and strings expanded from them are tracked as secrets. Expressions in properties
+/// that are not templatable are left as is.
+pub fn interpolate(
+    model: &Model,
+    resolver: &dyn SetResolver,
+    manifest: &Value,
+    scopes: &Map<String, Value>,
+) -> Result<Interpolated, InterpolationError> {
+    let type_id = manifest_type(model, manifest).map_err(|message| InterpolationError {
+        path: "$schema".to_string(),
+        message,
+    })?;
+
+    let mut i = Interpolator {
+        model,
+        resolver,
+        pass: Pass::ValueRefs,
+        scopes: scopes.clone(),
+        secret_scopes: BTreeSet::new(),
+        secrets: BTreeSet::new(),
+    };
+
+    let mut value = manifest.clone();
+    i.interpolate_type_def(&type_id, &mut value, "")?;
+
+    for name in value_scopes(model, &type_id) {
+        let prefix = format!("spec.{name}.");
+        for secret in &i.secrets {
+            if let Some(key) = secret.strip_prefix(&prefix) {
+                i.secret_scopes.insert(format!("{name}.{key}"));
+            }
+        }
+        i.scopes.insert(
+            name.clone(),
+            value["spec"].get(&name).cloned().unwrap_or_default(),
+        );
+    }
+
+    i.pass = Pass::Templates;
+    i.interpolate_type_def(&type_id, &mut value, "")?;
+
+    Ok(Interpolated {
+        value,
+        secrets: i.secrets,
+    })
+}
+
+// Names of the spec properties that bring values into the templating scope
+fn value_scopes(model: &Model, type_id: &TypeId) -> Vec<String> {
+    let TypeDefinition::Struct(t) = &model.types[type_id] else {
+        return Vec::new();
+    };
+    let Some(Type::Custom(spec_id)) = t.fields.get("spec").map(|f| &f.typ) else {
+        return Vec::new();
+    };
+    let Some(TypeDefinition::Struct(spec)) = model.types.get(spec_id) else {
+        return Vec::new();
+    };
+
+    spec.fields
+        .values()
+        .filter(|field| match &field.typ {
+            Type::Custom(id) => matches!(
+                model.types.get(id),
+                Some(TypeDefinition::Map(m))
+                    if matches!(&m.value_type, Type::Custom(v) if v.schema_id().as_str() == VALUE_REF)
+            ),
+            _ => false,
+        })
+        .map(|field| field.json_name())
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pass {
+    ValueRefs,
+    Templates,
+}
+
+struct Interpolator<'a> {
+    model: &'a Model,
+    resolver: &'a dyn SetResolver,
+    pass: Pass,
+    scopes: Map<String, Value>,
+    /// Paths within the scopes that hold secrets, e.g. `config.apiKey`
+    secret_scopes: BTreeSet<String>,
+    /// Paths within the manifest that hold secrets
+    secrets: BTreeSet<String>,
+}
+
+impl Interpolator<'_> {
+    fn interpolate_type(
+        &mut self,
+        typ: &Type,
+        templatable: bool,
+        value: &mut Value,
+        path: &str,
+    ) -> Result<(), InterpolationError> {
+        match typ {
+            Type::Custom(id) => self.interpolate_type_def(id, value, path),
+            Type::String | Type::Url if templatable && self.pass == Pass::Templates => {
+                if let Value::String(s) = value {
+                    let (rendered, secret) = self.render(s, path)?;
+                    *s = rendered;
+                    if secret {
+                        self.secrets.insert(path.to_string());
+                    }
+                }
+                Ok(())
+            }
+            Type::Array(arr) => {
+                for (i, item) in value.as_array_mut().into_iter().flatten().enumerate() {
+                    let path = format!("{path}[{i}]");
+                    self.interpolate_type(&arr.item_type, templatable, item, &path)?;
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn interpolate_type_def(
+        &mut self,
+        id: &TypeId,
+        value: &mut Value,
+        path: &str,
+    ) -> Result<(), InterpolationError> {
+        let model = self.model;
+        let Some(typ) = model.types.get(id) else {
+            panic!("Reference to unknown type {}", id.schema_id());
+        };
+
+        if matches!(typ.metatype(), MetaType::ResourceRef) {
+            if id.schema_id().as_str() == VALUE_REF && self.pass == Pass::ValueRefs {
+                let (resolved, secret) = self.resolve_value_ref(value, path)?;
+                *value = Value::String(resolved);
+                if secret {
+                    self.secrets.insert(path.to_string());
+                }
+            }
+            return Ok(());
+        }
+
+        match (typ, value) {
+            (TypeDefinition::Struct(t), Value::Object(obj)) => {
+                for (key, value) in obj {
+                    if let Some((field, _)) = t.field_by_json_name(key) {
+                        self.interpolate_type(
+                            &field.typ,
+                            field.templatable,
+                            value,
+                            &child_path(path, key),
+                        )?;
+                    }
+                }
+            }
+            (TypeDefinition::Union(t), value @ Value::Object(_)) => {
+                if let Some(variant) = resolve_variant(model, t, value) {
+                    self.interpolate_type_def(&variant.id, value, path)?;
+                }
+            }
+            (TypeDefinition::Map(t), Value::Object(obj)) => {
+                for (key, value) in obj {
+                    self.interpolate_type(&t.value_type, false, value, &child_path(path, key))?;
+                }
+            }
+            _ => (),
+        }
+        Ok(())
+    }
+
+    // Returns the referenced value and whether it's a secret
+    fn resolve_value_ref(
+        &self,
+        reference: &Value,
+        path: &str,
+    ) -> Result<(String, bool), InterpolationError> {
+        let error = |message: String| InterpolationError {
+            path: path.to_string(),
+            message,
+        };
+
+        let Some((set, value_path)) = self.resolver.resolve_set(reference).map_err(error)? else {
+            return Err(error(format!(
+                "Reference {reference} does not match any resource"
+            )));
+        };
+        let Some(value_path) = value_path else {
+            return Err(error(format!(
+                "Reference {reference} does not specify the path to a value"
+            )));
+        };
+        let Some(values) = values_of(self.model, set) else {
+            return Err(error(format!("Referenced {set} does not hold values")));
+        };
+        let Some(value) = value_at(values, &value_path) else {
+            return Err(error(format!(
+                "Referenced {set} has no value `{value_path}`"
+            )));
+        };
+
+        // Values are either raw strings or structs that can carry an encoding
+        let resolved = match value {
+            Value::String(s) => s.clone(),
+            Value::Object(obj) => {
+                if let Some(encoding) = obj.get("contentEncoding").and_then(Value::as_str) {
+                    return Err(error(format!(
+                        "Value `{value_path}` of {set} is encoded as `{encoding}` and has to be decoded first"
+                    )));
+                }
+                match obj.get("value") {
+                    Some(Value::String(s)) => s.clone(),
+                    _ => {
+                        return Err(error(format!(
+                            "Value `{value_path}` of {set} is not a string"
+                        )));
+                    }
+                }
+            }
+            _ => {
+                return Err(error(format!(
+                    "Value `{value_path}` of {set} is not a string"
+                )));
+            }
+        };
+
+        Ok((resolved, set.type_id.schema_id().as_str() == SECRET_SET))
+    }
+
+    // Expands expressions of a template, returning whether any of them is a secret
+    fn render(&self, template: &str, path: &str) -> Result<(String, bool), InterpolationError> {
+        let error = |message: String| InterpolationError {
+            path: path.to_string(),
+            message,
+        };
+
+        let mut rendered = String::new();
+        let mut secret = false;
+        let mut last = 0;
+        for caps in TEMPLATE_RE.captures_iter(template) {
+            let whole = caps.get(0).unwrap();
+            let expr = caps[1].trim();
+            if expr.is_empty() || expr.contains(char::is_whitespace) {
+                return Err(error(format!("Invalid template expression `{expr}`")));
+            }
+
+            let (scope, key) = match expr.split_once('.') {
+                Some((scope, key)) => (scope, Some(key)),
+                None => (expr, None),
+            };
+            let Some(scope_value) = self.scopes.get(scope) else {
+                return Err(error(format!(
+                    "Template expression `{expr}` refers to an unknown scope `{scope}`"
+                )));
+            };
+            let value = match key {
+                Some(key) => value_at(scope_value, key),
+                None => Some(scope_value),
+            };
+            let Some(value) = value else {
+                return Err(error(format!(
+                    "Template expression `{expr}` refers to a missing value"
+                )));
+            };
+
+            // Secrets can be nested within a value or contain it
+            secret |= self.secret_scopes.iter().any(|s| {
+                s == expr
+                    || expr.starts_with(&format!("{s}."))
+                    || s.starts_with(&format!("{expr}."))
+            });
+
+            rendered.push_str(&template[last..whole.start()]);
+            match value {
+                Value::String(s) => rendered.push_str(s),
+                other => rendered.push_str(&other.to_string()),
+            }
+            last = whole.end();
+        }
+
+        let rest = &template[last..];
+        if rest.contains("${{") {
+            return Err(error("Unterminated template expression".to_string()));
+        }
+        rendered.push_str(rest);
+
+        Ok((rendered, secret))
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -69,6 +69,36 @@ pub struct Schema {
     /// Marks schema as deprecated
     pub deprecated: Option<bool>,
 
+    /// Former names of a property or a union variant that are still accepted when reading manifests
+    pub aliases: Option<Vec<String>>,
+
+    /// Type parameters of a generic struct that can be referenced by its `fragment` properties
+    pub generics: Option<IndexMap<String, GenericParam>>,
+
+    /// Name of the type parameter that a `fragment` property is bound to
+    pub generic: Option<String>,
+
+    /// Customizes how the variants of a union are told apart
+    pub discriminator: Option<Discriminator>,
+
+    /// Property of array items that identifies them when diffing two versions of a manifest
+    pub merge_key: Option<String>,
+
+    /// Whether a union or a map value is merged or replaced as a whole when applying manifests
+    pub merge_strategy: Option<MergeStrategy>,
+
+    /// Marks a property as populated by the system, so values from user-authored manifests are never applied to it
+    pub read_only: Option<bool>,
+
+    /// Type of the resource that a reference property points to when the reference doesn't specify one
+    pub target_type: Option<SchemaId>,
+
+    /// Marks a string property as supporting `${{ scope.path }}` expressions substituted during interpolation
+    pub templatable: Option<bool>,
+
+    /// Marks a property holding a secret that generated code keeps out of logs and GraphQL
+    pub sensitive: Option<bool>,
+
     pub examples: Option<Vec<serde_json::Value>>,
 
     pub src: Option<PathBuf>,
@@ -97,6 +127,7 @@ impl<'a> std::fmt::Display for SchemaDisplay<'a> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+/// Loads all schemas from the directory, whose paths relative to its parent must match the schema IDs
 pub fn load_schemas(schemas_dir: &Path) -> Vec<Schema> {
     let mut schemas = Vec::new();
 
@@ -116,14 +147,19 @@ pub fn load_schemas(schemas_dir: &Path) -> Vec<Schema> {
             path.display()
         ));
 
-        schema.src = Some(path.clone());
+        // Sources are kept relative to the repo root, which is the parent of the schemas directory
+        let src = path
+            .strip_prefix(schemas_dir.parent().unwrap_or(Path::new("")))
+            .unwrap()
+            .to_path_buf();
 
         assert_eq!(
             format!("{id}.json"),
-            format!("https://opendatafabric.org/{}", path.display()),
+            format!("https://opendatafabric.org/{}", src.display()),
             "Schema ID does not correspond to the file name"
         );
 
+        schema.src = Some(src);
         schemas.push(schema);
     }
 
@@ -159,6 +195,16 @@ pub fn lint(top_level_schemas: &[Schema]) {
         tag: None,
         codegen: None,
         deprecated: None,
+        aliases: None,
+        generics: None,
+        generic: None,
+        discriminator: None,
+        merge_key: None,
+        merge_strategy: None,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: None,
         src: None,
     };
@@ -167,19 +213,11 @@ pub fn lint(top_level_schemas: &[Schema]) {
     for s in top_level_schemas {
         let id = s.id.clone().expect("Top level schema without $id");
 
-        // Add all defs
-        if let Some(defs) = &s.defs {
-            for (name, ds) in defs {
-                let def_id = id.subtype(name);
-                schemas.insert(def_id, ds);
-            }
-        }
-
-        // Add top-level schema
-        schemas.insert(id, s);
+        // Add top-level schema and all (possibly nested) defs
+        add_schema_with_defs(id, s, &mut schemas);
     }
 
-    // Check names are unique, as some codegens don't support context-level modularity yet
+    // Check names are unique within a context, as codegens flatten nested subtypes into a single name
     let mut seen_names = HashMap::new();
     for id in schemas.keys() {
         if id.as_str() == SchemaId::METASCHEMA_JSONSCHEMA
@@ -188,14 +226,17 @@ pub fn lint(top_level_schemas: &[Schema]) {
             continue;
         }
 
-        let name = if let Some(p) = id.parent() {
-            format!("{}{}", p.name(), id.name())
-        } else {
-            id.name().to_string()
-        };
-        if let Some(prev) = seen_names.insert(name.clone(), id) {
+        let context = SCHEMA_URL_RE
+            .captures(id)
+            .unwrap_or_else(|| panic!("Invalid schema $id: {id}"))
+            .name("context")
+            .unwrap()
+            .as_str();
+
+        let name = id.path().concat();
+        if let Some(prev) = seen_names.insert((context, name.clone()), id) {
             panic!(
-                "Name {name} in schema {id} is already used by schema {prev}. We don't allow it as some codegens don't yet support context-level modularity",
+                "Name {name} in schema {id} is already used by schema {prev} in the same context"
             )
         }
     }
@@ -211,6 +252,7 @@ pub fn lint(top_level_schemas: &[Schema]) {
                 || sch.schema.as_deref() == Some(SchemaId::METASCHEMA_RESOURCE_INPUT)
                 || sch.schema.as_deref() == Some(SchemaId::METASCHEMA_RESOURCE_CONDITION)
                 || sch.schema.as_deref() == Some(SchemaId::METASCHEMA_ENGINE_MESSAGE)
+                || sch.schema.as_deref() == Some(SchemaId::METASCHEMA_PROTOCOL_MESSAGE)
                 || id.name() == "Manifest"
                 || id.name() == "DatasetSnapshot"
                 || id.name() == "MetadataBlock"
@@ -238,7 +280,7 @@ pub fn lint(top_level_schemas: &[Schema]) {
         for reff in extract_refs(schema) {
             let ref_id = match reff {
                 Ref::Global(id) => id,
-                Ref::Def(name) => id.root().subtype(name),
+                Ref::Def(path) => id.local_def(path),
             };
 
             if !explored.contains(&ref_id) {
@@ -267,6 +309,20 @@ pub fn lint(top_level_schemas: &[Schema]) {
     }
 }
 
+fn add_schema_with_defs<'a>(
+    id: SchemaId,
+    schema: &'a Schema,
+    schemas: &mut HashMap<SchemaId, &'a Schema>,
+) {
+    if let Some(defs) = &schema.defs {
+        for (name, ds) in defs {
+            add_schema_with_defs(id.subtype(name), ds, schemas);
+        }
+    }
+
+    schemas.insert(id, schema);
+}
+
 fn extract_refs(schema: &Schema) -> Vec<Ref> {
     let mut refs = Vec::new();
 
@@ -327,10 +383,10 @@ fn decode_ref(reff: &str) -> Ref {
 pub struct SchemaId(String);
 
 // Matches: https://opendatafabric.org/schemas/{context}/{version}/{Name}
-// Also matches with an optional #/$defs/{Def} fragment.
+// Also matches with an optional #/$defs/{Def} fragment, which can be nested as #/$defs/{Def}/$defs/{SubDef}.
 pub static SCHEMA_URL_RE: std::sync::LazyLock<regex::Regex> = std::sync::LazyLock::new(|| {
     regex::Regex::new(
-        r"^https://opendatafabric\.org/schemas/(?P<context>[^/]+)/(?P<version>[^/]+)/(?P<name>[^/.]+)(?:#/\$defs/(?P<def>[^/]+))?$",
+        r"^https://opendatafabric\.org/schemas/(?P<context>[^/]+)/(?P<version>[^/]+)/(?P<name>[^/.]+)(?:#(?P<defs>(?:/\$defs/[^/]+)+))?$",
     )
     .unwrap()
 });
@@ -352,6 +408,8 @@ impl SchemaId {
         "https://opendatafabric.org/schemas/metaschemas/v1alpha1/ResourceCondition";
     pub const METASCHEMA_ENGINE_MESSAGE: &str =
         "https://opendatafabric.org/schemas/metaschemas/v1alpha1/EngineMessage";
+    pub const METASCHEMA_PROTOCOL_MESSAGE: &str =
+        "https://opendatafabric.org/schemas/metaschemas/v1alpha1/ProtocolMessage";
 
     pub fn new(name: impl Into<String>) -> Self {
         Self(name.into())
@@ -365,20 +423,36 @@ impl SchemaId {
         }
     }
 
+    /// Returns the immediately enclosing schema of a `$defs` subtype
     pub fn parent(&self) -> Option<SchemaId> {
-        if let Some(i) = self.0.find("#/$defs/") {
-            Some(Self(self.0[..i].into()))
+        let i = self.0.rfind("/$defs/")?;
+        Some(Self(self.0[..i].trim_end_matches('#').into()))
+    }
+
+    pub fn subtype(&self, name: impl AsRef<str>) -> Self {
+        if self.0.contains('#') {
+            Self(format!("{self}/$defs/{}", name.as_ref()))
         } else {
-            None
+            Self(format!("{self}#/$defs/{}", name.as_ref()))
         }
     }
 
-    pub fn subtype(&self, name: impl AsRef<str>) -> Self {
-        assert!(
-            !self.0.contains('#'),
-            "Nesting of subtypes is not supported"
-        );
-        Self(format!("{self}#/$defs/{}", name.as_ref()))
+    /// Resolves a local reference like `#/$defs/A/$defs/B` relative to the root of this schema
+    pub fn local_def(&self, path: impl AsRef<str>) -> Self {
+        Self(format!("{}#/$defs/{}", self.root(), path.as_ref()))
+    }
+
+    /// Names of all schemas from the root to this subtype, e.g. `[DataType, List]`
+    pub fn path(&self) -> Vec<&str> {
+        let cap = SCHEMA_URL_RE
+            .captures(&self.0)
+            .unwrap_or_else(|| panic!("Invalid schema $id: {self}"));
+
+        let mut path = vec![cap.name("name").unwrap().as_str()];
+        if let Some(defs) = cap.name("defs") {
+            path.extend(defs.as_str().split("/$defs/").skip(1));
+        }
+        path
     }
 
     pub fn root_name(&self) -> &str {
@@ -394,8 +468,8 @@ impl SchemaId {
             .captures(&self.0)
             .unwrap_or_else(|| panic!("Invalid schema $id: {self}"));
 
-        if let Some(name) = cap.name("def") {
-            name.as_str()
+        if let Some(defs) = cap.name("defs") {
+            defs.as_str().rsplit("/$defs/").next().unwrap()
         } else {
             cap.name("name").unwrap().as_str()
         }
@@ -493,9 +567,54 @@ pub enum Format {
     StructOrString,
 }
 
+#[skip_serializing_none]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct GenericParam {
+    pub description: Option<String>,
+
+    /// Restricts the set of types the parameter can be instantiated with
+    pub bound: Option<GenericBound>,
+}
+
+#[skip_serializing_none]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+#[serde(rename_all = "camelCase")]
+pub struct Discriminator {
+    /// Name of the property that carries the variant tag, defaults to `kind`
+    pub property_name: Option<String>,
+
+    /// Resolve variants by their shape in order of declaration instead of by a tag property
+    pub untagged: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum GenericBound {
+    /// Canonical spec of any resource, e.g. `DatasetSpec`
+    ResourceSpec,
+    /// User-authored spec of any resource, e.g. `DatasetSpecInput`
+    ResourceSpecInput,
+}
+
+#[derive(
+    Debug, Default, Clone, Copy, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum MergeStrategy {
+    /// Union values of the same variant are merged field by field, and map values key by key
+    #[default]
+    Merge,
+    /// Value is always replaced with the desired one
+    Replace,
+}
+
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "kebab-case")]
 pub enum CodegenLanguage {
+    Asyncapi,
     Flatbuffers,
     Rust,
 }
@@ -503,10 +622,18 @@ pub enum CodegenLanguage {
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum CodegenHint {
+    /// Address of the AsyncAPI channel a protocol message is sent over, e.g. `dataset/pull/request`
+    Channel,
     Container,
     DtoType,
+    /// Generates a canonical encoding and a content hash, see [`FlatbuffersHashable`]
+    Hashable,
     MapFormat,
     MapKeyFormat,
+    /// AsyncAPI operation of the channel a protocol message is sent over, `publish` or `subscribe`
+    Operation,
+    /// Generates an `Unknown` fallback for union variants and enum values written by newer peers
+    UnknownVariant,
 }
 
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -522,3 +649,32 @@ impl std::str::FromStr for FlatbuffersMapFormat {
         serde_json::from_value(serde_json::Value::String(s.into()))
     }
 }
+
+/// Manifest that wraps the canonical flatbuffers encoding of a hashable type before its content hash is computed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FlatbuffersHashable {
+    /// Multicodec code of the resource, e.g. `4194304` (`0x400000`) for `odf-metadata-block`
+    pub kind: i64,
+    /// Major version of the resource layout
+    pub version: i32,
+}
+
+/// AsyncAPI operation of a protocol message channel, named from the perspective of the server like in AsyncAPI 2
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AsyncapiOperation {
+    /// Sent by the server
+    Publish,
+    /// Sent by the client
+    Subscribe,
+}
+
+impl AsyncapiOperation {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AsyncapiOperation::Publish => "publish",
+            AsyncapiOperation::Subscribe => "subscribe",
+        }
+    }
+}
@@ -1,5 +1,18 @@
+pub mod api;
+pub mod bundle;
+pub mod canonical_encoding;
+pub mod canonicalization;
 pub mod cli;
 pub mod codegen;
+pub mod diff;
+pub mod filter;
+pub mod graph;
+pub mod interpolate;
 pub mod json_schema;
+pub mod merge;
 pub mod model;
+pub mod resolve;
+pub mod server;
+pub mod status;
 pub(crate) mod utils;
+pub mod validation;
@@ -1,7 +1,10 @@
 use std::path::{Path, PathBuf};
 
 use clap::Parser;
-use odf_schemas::{cli, codegen, json_schema, model};
+use odf_schemas::{
+    bundle, canonical_encoding, canonicalization, cli, codegen, diff, graph, interpolate,
+    json_schema, merge, model, resolve, server, validation,
+};
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -11,6 +14,17 @@ fn main() {
     match args.command {
         cli::Command::Lint(cmd) => lint(cmd, &schemas_dir),
         cli::Command::Codegen(cmd) => codegen(cmd, &schemas_dir),
+        cli::Command::Validate(cmd) => validate(cmd, &schemas_dir),
+        cli::Command::Canonicalize(cmd) => canonicalize(cmd, &schemas_dir),
+        cli::Command::Hash(cmd) => hash(cmd, &schemas_dir),
+        cli::Command::Fuzz(cmd) => fuzz(cmd, &schemas_dir),
+        cli::Command::Diff(cmd) => diff(cmd, &schemas_dir),
+        cli::Command::Apply(cmd) => apply(cmd, &schemas_dir),
+        cli::Command::Resolve(cmd) => resolve(cmd, &schemas_dir),
+        cli::Command::Check(cmd) => check(cmd, &schemas_dir),
+        cli::Command::Graph(cmd) => graph(cmd, &schemas_dir),
+        cli::Command::Interpolate(cmd) => interpolate(cmd, &schemas_dir),
+        cli::Command::Serve(cmd) => serve(cmd, &schemas_dir),
     }
 }
 
@@ -25,6 +39,35 @@ fn lint(_cmd: cli::Lint, schemas_dir: &Path) {
     let model = model::parse_jsonschema(schemas);
 
     model::check_explicit_tags_sequence(&model);
+    model::check_aliases(&model);
+    model::check_merge_keys(&model);
+    model::check_filters(&model);
+    model::check_target_types(&model);
+    model::check_templatable(&model);
+    model::check_sensitive(&model);
+    model::check_conditions(&model);
+    model::check_protocol_messages(&model);
+
+    // Protocol documents are committed alongside the schemas they are generated from
+    let protocols_dir = schemas_dir.parent().unwrap().join("protocols");
+    for (file, render) in [
+        (
+            "smart-transfer-protocol.asyncapi.yaml",
+            codegen::protocol::render_asyncapi as fn(_, &mut dyn std::io::Write) -> _,
+        ),
+        (
+            "smart-transfer-protocol.openapi.yaml",
+            codegen::protocol::render_openapi,
+        ),
+    ] {
+        let path = protocols_dir.join(file);
+        let mut expected = Vec::new();
+        render(model.clone(), &mut expected).unwrap();
+        let actual = std::fs::read(&path).unwrap_or_default();
+        if actual != expected {
+            panic!("{} is out of date, run `make codegen`", path.display());
+        }
+    }
 
     eprintln!("Successfully linted {} types", model.types.len());
 }
@@ -46,12 +89,27 @@ fn codegen(cmd: cli::Codegen, schemas_dir: &Path) {
         cli::CodegenLang::MermaidErd => {
             codegen::mermaid_erd::render(model, &mut w).unwrap();
         }
+        cli::CodegenLang::Openapi => {
+            codegen::openapi::render(model, &mut w).unwrap();
+        }
+        cli::CodegenLang::ProtocolAsyncapi => {
+            codegen::protocol::render_asyncapi(model, &mut w).unwrap();
+        }
+        cli::CodegenLang::ProtocolOpenapi => {
+            codegen::protocol::render_openapi(model, &mut w).unwrap();
+        }
         cli::CodegenLang::RustDtos => {
             codegen::rust_dtos::render(model, &mut w).unwrap();
         }
+        cli::CodegenLang::RustFlatbuffersViews => {
+            codegen::rust_flatbuffers_views::render(model, &mut w).unwrap();
+        }
         cli::CodegenLang::RustGraphql => {
             codegen::rust_graphql::render(model, &mut w).unwrap();
         }
+        cli::CodegenLang::RustProptest => {
+            codegen::rust_proptest::render(model, &mut w).unwrap();
+        }
         cli::CodegenLang::RustSerde => {
             codegen::rust_serde::render(model, &mut w).unwrap();
         }
@@ -62,3 +120,401 @@ fn codegen(cmd: cli::Codegen, schemas_dir: &Path) {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn validate(cmd: cli::Validate, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let mut errors = 0;
+    for path in &cmd.paths {
+        let manifest = load_manifest(path);
+        for diag in validation::validate_manifest(&model, &manifest) {
+            if diag.severity == validation::Severity::Error {
+                errors += 1;
+            }
+            eprintln!("{}: {diag}", path.display());
+        }
+    }
+
+    if errors != 0 {
+        eprintln!("Validation failed with {errors} errors");
+        std::process::exit(1);
+    }
+    eprintln!("Successfully validated {} manifests", cmd.paths.len());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn canonicalize(cmd: cli::Canonicalize, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let manifest = load_manifest(&cmd.path);
+    let manifest = canonicalization::canonicalize_manifest(&model, manifest);
+
+    serde_yaml::to_writer(std::io::stdout(), &manifest).unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn hash(cmd: cli::Hash, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let candidates: Vec<_> = model
+        .types
+        .keys()
+        .filter(|id| id.schema_id().as_str() == cmd.typ || id.join("") == cmd.typ)
+        .collect();
+    let [type_id] = candidates[..] else {
+        panic!(
+            "Expected exactly one type matching {}, found: {candidates:?}",
+            cmd.typ
+        );
+    };
+
+    let value = load_manifest(&cmd.path);
+
+    let res = if cmd.bytes {
+        canonical_encoding::encode(&model, type_id, value)
+            .map(|bytes| bytes.iter().map(|b| format!("{b:02x}")).collect())
+    } else {
+        canonical_encoding::content_hash(&model, type_id, value)
+    };
+    match res {
+        Ok(res) => println!("{res}"),
+        Err(e) => {
+            eprintln!("{}: {e}", cmd.path.display());
+            std::process::exit(1);
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn fuzz(cmd: cli::Fuzz, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let options = codegen::rust_fuzz::Options {
+        crate_name: cmd.crate_name,
+        crate_path: cmd.crate_path,
+        examples_dir: cmd.examples_dir,
+        vectors_dir: cmd.vectors_dir,
+    };
+    codegen::rust_fuzz::render(&model, &options, &cmd.out_dir);
+
+    eprintln!(
+        "Generated {} fuzz targets in {}",
+        codegen::rust_fuzz::targets(&model).len(),
+        cmd.out_dir.display()
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn diff(cmd: cli::Diff, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let old = load_manifest(&cmd.old);
+    let new = load_manifest(&cmd.new);
+    let changes = diff::diff_manifests(&model, &old, &new).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    });
+
+    if cmd.json_patch {
+        serde_json::to_writer_pretty(std::io::stdout(), &diff::to_json_patch(&changes)).unwrap();
+        println!();
+    } else {
+        for change in &changes {
+            println!("{change}");
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn apply(cmd: cli::Apply, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    if !cmd.dry_run && cmd.live.is_none() {
+        eprintln!("Specify --live to write the new state into or use --dry-run");
+        std::process::exit(1);
+    }
+
+    // Missing files stand for a resource that doesn't exist yet or was never applied
+    let load_existing =
+        |path: &Option<PathBuf>| path.as_deref().filter(|p| p.exists()).map(load_manifest);
+
+    let desired = load_manifest(&cmd.path);
+    let live = load_existing(&cmd.live);
+    let last_applied = load_existing(&cmd.last_applied);
+
+    let applied = merge::apply(&model, last_applied.as_ref(), live.as_ref(), &desired)
+        .unwrap_or_else(|e| {
+            eprintln!("{e}");
+            std::process::exit(1);
+        });
+
+    let mut errors = 0;
+    for conflict in &applied.conflicts {
+        if conflict.reason == merge::ConflictReason::Modified && !cmd.force {
+            errors += 1;
+            eprintln!("error: {conflict}");
+        } else {
+            eprintln!("warning: {conflict}");
+        }
+    }
+    if errors != 0 {
+        eprintln!("Apply failed with {errors} conflicts, use --force to overwrite");
+        std::process::exit(1);
+    }
+
+    if cmd.dry_run {
+        serde_yaml::to_writer(std::io::stdout(), &applied.resource).unwrap();
+    } else {
+        let write = |path: &Path, value: &serde_json::Value| {
+            std::fs::write(path, serde_yaml::to_string(value).unwrap())
+                .unwrap_or_else(|e| panic!("Failed to write {}: {e}", path.display()));
+        };
+        write(cmd.live.as_deref().unwrap(), &applied.resource);
+        if let Some(path) = &cmd.last_applied {
+            write(path, &desired);
+        }
+    }
+
+    if applied.changed {
+        eprintln!("Resource updated");
+    } else {
+        eprintln!("Resource unchanged");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn resolve(cmd: cli::Resolve, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let store = load_store(&model, &cmd.dir, cmd.account);
+
+    let value: serde_json::Value = serde_yaml::from_str(&cmd.value)
+        .unwrap_or_else(|e| fail(format!("Failed to parse {}: {e}", cmd.value)));
+    let query = match cmd.kind {
+        cli::ResolveKind::Ref => resolve::Query::Ref {
+            reference: value,
+            default_type: cmd.typ,
+        },
+        cli::ResolveKind::ValueRef => resolve::Query::ValueRef(value),
+        cli::ResolveKind::Selector => resolve::Query::Selector(value),
+    };
+
+    let candidates = store.explain(&query).unwrap_or_else(|e| fail(e));
+    for candidate in &candidates {
+        let path = candidate
+            .resource
+            .path
+            .as_ref()
+            .map(|p| format!(" ({})", p.display()))
+            .unwrap_or_default();
+        if candidate.is_match() {
+            println!("+ {}{path}", candidate.resource);
+        } else {
+            println!(
+                "- {}{path}: {}",
+                candidate.resource,
+                candidate.mismatches.join(", ")
+            );
+        }
+    }
+
+    let matched = candidates.iter().filter(|c| c.is_match()).count();
+    eprintln!("Matched {matched} of {} resources", candidates.len());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn check(cmd: cli::Check, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let store = load_store(&model, &cmd.dir, cmd.account);
+
+    let problems = bundle::check(&store);
+    for problem in &problems {
+        match &problem.resource.path {
+            Some(path) => eprintln!(
+                "error: {}: {}: {}",
+                path.display(),
+                problem.path,
+                problem.message
+            ),
+            None => eprintln!("error: {problem}"),
+        }
+    }
+
+    if !problems.is_empty() {
+        eprintln!("Check failed with {} errors", problems.len());
+        std::process::exit(1);
+    }
+    eprintln!("Successfully checked {} resources", store.resources().len());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn graph(cmd: cli::Graph, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let store = load_store(&model, &cmd.dir, cmd.account);
+
+    let graph = graph::DependencyGraph::build(&store);
+    let mut w = std::io::BufWriter::new(std::io::stdout());
+
+    let order = match cmd.format {
+        cli::GraphFormat::Mermaid => return graph.render_mermaid(&mut w).unwrap(),
+        cli::GraphFormat::Dot => return graph.render_dot(&mut w).unwrap(),
+        cli::GraphFormat::CreationOrder => graph.creation_order(),
+        cli::GraphFormat::DeletionOrder => graph.deletion_order(),
+    };
+
+    match order {
+        Ok(order) => {
+            for r in order {
+                let path = r
+                    .path
+                    .as_ref()
+                    .map(|p| format!(" ({})", p.display()))
+                    .unwrap_or_default();
+                println!("{r}{path}");
+            }
+        }
+        Err(cycles) => {
+            for cycle in &cycles {
+                eprintln!(
+                    "error: {}: {}: Reference cycle: {}",
+                    graph.resources[cycle.from],
+                    cycle.path,
+                    cycle
+                        .resources
+                        .iter()
+                        .map(|i| graph.resources[*i].to_string())
+                        .collect::<Vec<_>>()
+                        .join(" -> ")
+                );
+            }
+            fail(format!("Bundle has {} reference cycles", cycles.len()));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn interpolate(cmd: cli::Interpolate, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let dir = cmd
+        .dir
+        .clone()
+        .unwrap_or_else(|| cmd.path.parent().unwrap_or(Path::new(".")).to_path_buf());
+    let store = load_store(&model, &dir, cmd.account);
+
+    let mut scopes = serde_json::Map::new();
+    for scope in &cmd.scopes {
+        let Some((name, value)) = scope.split_once('=') else {
+            fail(format!(
+                "Scope must be specified as `name=value`, got {scope}"
+            ));
+        };
+        let value = serde_yaml::from_str(value)
+            .unwrap_or_else(|e| fail(format!("Failed to parse scope {name}: {e}")));
+        scopes.insert(name.to_string(), value);
+    }
+
+    let manifest = load_manifest(&cmd.path);
+    let interpolated = interpolate::interpolate(&model, &store, &manifest, &scopes)
+        .unwrap_or_else(|e| fail(format!("error: {}: {e}", cmd.path.display())));
+
+    let value = if cmd.show_secrets {
+        interpolated.into_value()
+    } else {
+        interpolated.redacted()
+    };
+    serde_yaml::to_writer(std::io::stdout(), &value).unwrap();
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn serve(cmd: cli::Serve, schemas_dir: &Path) {
+    let schemas = json_schema::load_schemas(schemas_dir);
+    let model = model::parse_jsonschema(schemas);
+
+    let account = serde_yaml::from_str(&cmd.account)
+        .unwrap_or_else(|e| fail(format!("Failed to parse {}: {e}", cmd.account)));
+    let server = server::ResourceServer::open(model.clone(), cmd.dir.as_deref(), account)
+        .unwrap_or_else(|e| fail(e));
+
+    if let Some(dir) = &cmd.apply {
+        let store = load_store(&model, dir, None);
+
+        let order = graph::DependencyGraph::build(&store)
+            .creation_order()
+            .unwrap_or_else(|_| fail(format!("Bundle in {} has reference cycles", dir.display())));
+        for r in order {
+            if let Err(e) = server.apply(&r.manifest, None, false) {
+                fail(format!("error: {r}: {e}"));
+            }
+        }
+        eprintln!("Applied {} resources", store.resources().len());
+    }
+
+    let listener = std::net::TcpListener::bind(&cmd.listen)
+        .unwrap_or_else(|e| fail(format!("Failed to listen on {}: {e}", cmd.listen)));
+    eprintln!(
+        "Serving {} resource types on http://{}",
+        server.apis().len(),
+        cmd.listen
+    );
+    server
+        .serve(&listener)
+        .unwrap_or_else(|e| fail(e.to_string()));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn load_manifest(path: &Path) -> serde_json::Value {
+    let content = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("Failed to read {}: {e}", path.display()));
+    serde_yaml::from_str(&content)
+        .unwrap_or_else(|e| panic!("Failed to parse {}: {e}", path.display()))
+}
+
+/// Loads resources from a directory on behalf of an optional account, warning about files that were skipped
+fn load_store<'a>(
+    model: &'a model::Model,
+    dir: &Path,
+    account: Option<String>,
+) -> resolve::ResourceStore<'a> {
+    let account = account
+        .map(|a| resolve::AccountRef::parse(&serde_json::Value::String(a)))
+        .transpose()
+        .unwrap_or_else(|e| fail(e));
+
+    let mut store = resolve::ResourceStore::new(model, account);
+    store.load_dir(dir).unwrap_or_else(|e| fail(e));
+    for (path, reason) in &store.skipped {
+        eprintln!("warning: {}: {reason}", path.display());
+    }
+    store
+}
+
+fn fail(e: impl std::fmt::Display) -> ! {
+    eprintln!("{e}");
+    std::process::exit(1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,399 @@
+use serde_json::{Map, Value};
+
+use crate::diff::item_keys;
+use crate::json_schema::{MergeStrategy, SchemaId};
+use crate::model::{MetaType, Model, Type, TypeDefinition, TypeId};
+use crate::validation::{child_path, manifest_type, resolve_variant};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const RESOURCE: &str = "https://opendatafabric.org/schemas/resource/v1alpha1/Resource";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConflictReason {
+    /// Value was changed by someone else since the last apply and is overwritten by the desired one
+    Modified,
+    /// Value is populated by the system and the desired one is ignored
+    ReadOnly,
+}
+
+/// Field that is owned by someone other than the author of the applied manifest
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conflict {
+    /// Dot-separated path to the value that identifies array items by their merge keys, e.g. `spec.env[name=FOO]`
+    pub path: String,
+    pub reason: ConflictReason,
+    pub live: Option<Value>,
+    pub desired: Option<Value>,
+}
+
+impl std::fmt::Display for Conflict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let value = |v: &Option<Value>| match v {
+            Some(v) => v.to_string(),
+            None => "<none>".to_string(),
+        };
+        match self.reason {
+            ConflictReason::Modified => write!(
+                f,
+                "{}: live value {} was changed since the last apply and will be replaced with {}",
+                self.path,
+                value(&self.live),
+                value(&self.desired)
+            ),
+            ConflictReason::ReadOnly => write!(
+                f,
+                "{}: field is populated by the system, ignoring {}",
+                self.path,
+                value(&self.desired)
+            ),
+        }
+    }
+}
+
+/// Outcome of applying a manifest
+#[derive(Debug, Clone, PartialEq)]
+pub struct Applied {
+    /// New canonical state of the resource
+    pub resource: Value,
+    /// Whether the desired state (headers or spec) has changed, in which case the generation was incremented
+    pub changed: bool,
+    pub conflicts: Vec<Conflict>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Applies a user-authored resource manifest onto the live canonical state of the resource using a three-way merge.
+///
+/// The manifest applied previously tells apart the values that the user has removed from those populated by other
+/// parties, which are preserved. Values are merged according to the schema:
+/// - `readOnly` fields (e.g. `generation`, `status`) always keep their live values
+/// - struct properties and map entries are merged by name, unless the type has `"mergeStrategy": "replace"`
+/// - union values are merged when they are of the same variant and replaced otherwise
+/// - array items are merged by their `mergeKey`, arrays without one are replaced as a whole
+///
+/// References are expected to be resolved into handles beforehand, otherwise they replace live handles as a whole.
+/// Timestamps are left for the caller to populate.
+pub fn apply(
+    model: &Model,
+    last_applied: Option<&Value>,
+    live: Option<&Value>,
+    desired: &Value,
+) -> Result<Applied, String> {
+    let input_id = manifest_type(model, desired)?;
+    let Some(TypeDefinition::Struct(input)) = model
+        .types
+        .get(&input_id)
+        .filter(|t| matches!(t.metatype(), MetaType::Resource))
+    else {
+        return Err(format!(
+            "{} is not a resource manifest",
+            input_id.schema_id()
+        ));
+    };
+
+    for (what, other) in [
+        ("Live resource", live),
+        ("Last applied manifest", last_applied),
+    ] {
+        if let Some(other) = other
+            && other.get("$schema") != desired.get("$schema")
+        {
+            return Err(format!(
+                "{what} is of type {} while the manifest is {}",
+                other.get("$schema").unwrap_or(&Value::Null),
+                input_id.schema_id()
+            ));
+        }
+    }
+
+    let Some(Type::Custom(spec_input)) = input.fields.get("spec").map(|f| &f.typ) else {
+        panic!(
+            "Resource {} must define a `spec` property",
+            input_id.join("::")
+        );
+    };
+    let spec_type = model.types[spec_input]
+        .canonical_type()
+        .unwrap_or(spec_input)
+        .clone();
+
+    let mut m = Merger {
+        model,
+        spec_type,
+        conflicts: Vec::new(),
+    };
+    let mut resource = m.merge_type_def(
+        &TypeId::new(SchemaId::new(RESOURCE)),
+        last_applied,
+        live.unwrap_or(&Value::Object(Map::new())),
+        desired,
+        "",
+    );
+
+    let changed = ["headers", "spec"]
+        .iter()
+        .any(|k| live.and_then(|l| l.get(k)) != resource.get(k));
+
+    if changed && let Some(headers) = resource.get_mut("headers").and_then(Value::as_object_mut) {
+        let generation = headers
+            .get("generation")
+            .and_then(Value::as_u64)
+            .unwrap_or(0);
+        headers.insert("generation".to_string(), (generation + 1).into());
+    }
+
+    Ok(Applied {
+        resource,
+        changed,
+        conflicts: m.conflicts,
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Merger<'a> {
+    model: &'a Model,
+    /// Canonical spec type that the generic `Resource` is instantiated with
+    spec_type: TypeId,
+    conflicts: Vec<Conflict>,
+}
+
+impl Merger<'_> {
+    // Overwriting a value is a conflict unless it is what the user has applied last time
+    fn check(
+        &mut self,
+        path: &str,
+        last: Option<&Value>,
+        live: Option<&Value>,
+        desired: Option<&Value>,
+    ) {
+        if live != desired && last != live {
+            self.conflicts.push(Conflict {
+                path: path.to_string(),
+                reason: ConflictReason::Modified,
+                live: live.cloned(),
+                desired: desired.cloned(),
+            });
+        }
+    }
+
+    fn replace(
+        &mut self,
+        last: Option<&Value>,
+        live: &Value,
+        desired: &Value,
+        path: &str,
+    ) -> Value {
+        self.check(path, last, Some(live), Some(desired));
+        desired.clone()
+    }
+
+    fn merge_type(
+        &mut self,
+        typ: &Type,
+        last: Option<&Value>,
+        live: Option<&Value>,
+        desired: Option<&Value>,
+        path: &str,
+    ) -> Option<Value> {
+        match (live, desired) {
+            // Values the user has never set belong to someone else
+            (_, None) if last.is_none() => live.cloned(),
+            (_, None) => {
+                self.check(path, last, live, None);
+                None
+            }
+            (None, Some(desired)) => {
+                self.check(path, last, None, Some(desired));
+                Some(desired.clone())
+            }
+            (Some(live), Some(desired)) => Some(self.merge_value(typ, last, live, desired, path)),
+        }
+    }
+
+    fn merge_value(
+        &mut self,
+        typ: &Type,
+        last: Option<&Value>,
+        live: &Value,
+        desired: &Value,
+        path: &str,
+    ) -> Value {
+        if live == desired {
+            return live.clone();
+        }
+
+        match typ {
+            Type::Custom(id) => self.merge_type_def(id, last, live, desired, path),
+            Type::Generic(_) => {
+                let spec_type = self.spec_type.clone();
+                self.merge_type_def(&spec_type, last, live, desired, path)
+            }
+            Type::Array(arr) => match (&arr.merge_key, live, desired) {
+                (Some(key), Value::Array(live_items), Value::Array(desired_items)) => {
+                    match (item_keys(live_items, key), item_keys(desired_items, key)) {
+                        (Some(live_keys), Some(desired_keys)) => {
+                            Value::Array(self.merge_keyed_array(
+                                &arr.item_type,
+                                key,
+                                last,
+                                (live_items, live_keys),
+                                (desired_items, desired_keys),
+                                path,
+                            ))
+                        }
+                        _ => self.replace(last, live, desired, path),
+                    }
+                }
+                _ => self.replace(last, live, desired, path),
+            },
+            Type::AnyJson => match (live, desired) {
+                (Value::Object(live_obj), Value::Object(desired_obj)) => {
+                    self.merge_object(last, live_obj, desired_obj, path, |_| {
+                        Some((&Type::AnyJson, false))
+                    })
+                }
+                _ => self.replace(last, live, desired, path),
+            },
+            _ => self.replace(last, live, desired, path),
+        }
+    }
+
+    fn merge_keyed_array(
+        &mut self,
+        item_type: &Type,
+        key: &str,
+        last: Option<&Value>,
+        (live, live_keys): (&[Value], Vec<String>),
+        (desired, desired_keys): (&[Value], Vec<String>),
+        path: &str,
+    ) -> Vec<Value> {
+        // Items of a malformed last applied array are treated as if they were never applied
+        let last: Vec<(String, &Value)> = last
+            .and_then(Value::as_array)
+            .and_then(|last| Some(item_keys(last, key)?.into_iter().zip(last).collect()))
+            .unwrap_or_default();
+        let last_item = |k: &str| last.iter().find(|(l, _)| l == k).map(|(_, v)| *v);
+
+        let mut res = Vec::new();
+        for (k, item) in desired_keys.iter().zip(desired) {
+            let live_item = live_keys.iter().position(|l| l == k).map(|i| &live[i]);
+            res.extend(self.merge_type(
+                item_type,
+                last_item(k),
+                live_item,
+                Some(item),
+                &format!("{path}[{key}={k}]"),
+            ));
+        }
+
+        // Items added by others are kept at the end
+        for (k, item) in live_keys.iter().zip(live) {
+            if !desired_keys.contains(k) {
+                res.extend(self.merge_type(
+                    item_type,
+                    last_item(k),
+                    Some(item),
+                    None,
+                    &format!("{path}[{key}={k}]"),
+                ));
+            }
+        }
+
+        res
+    }
+
+    fn merge_type_def(
+        &mut self,
+        id: &TypeId,
+        last: Option<&Value>,
+        live: &Value,
+        desired: &Value,
+        path: &str,
+    ) -> Value {
+        let model = self.model;
+        let Some(typ) = model.types.get(id) else {
+            panic!("Reference to unknown type {}", id.schema_id());
+        };
+
+        match (typ, live, desired) {
+            (TypeDefinition::Struct(t), Value::Object(live), Value::Object(desired)) => self
+                .merge_object(last, live, desired, path, |key| {
+                    t.field_by_json_name(key)
+                        .map(|(f, _)| (&f.typ, f.read_only))
+                }),
+            (TypeDefinition::Union(t), _, _) if t.merge_strategy == MergeStrategy::Merge => {
+                match (
+                    resolve_variant(model, t, live),
+                    resolve_variant(model, t, desired),
+                ) {
+                    (Some(a), Some(b)) if a.id == b.id => {
+                        // Last applied value of another variant has nothing in common with the current one
+                        let last = last
+                            .filter(|l| resolve_variant(model, t, l).is_some_and(|v| v.id == a.id));
+                        self.merge_type_def(&a.id, last, live, desired, path)
+                    }
+                    _ => self.replace(last, live, desired, path),
+                }
+            }
+            (TypeDefinition::Map(t), Value::Object(live), Value::Object(desired))
+                if t.merge_strategy == MergeStrategy::Merge =>
+            {
+                self.merge_object(last, live, desired, path, |_| Some((&t.value_type, false)))
+            }
+            _ => self.replace(last, live, desired, path),
+        }
+    }
+
+    // Properties that are not in the schema are merged as plain JSON
+    fn merge_object<'t>(
+        &mut self,
+        last: Option<&Value>,
+        live: &Map<String, Value>,
+        desired: &Map<String, Value>,
+        path: &str,
+        field_type: impl Fn(&str) -> Option<(&'t Type, bool)>,
+    ) -> Value {
+        let last = last.and_then(Value::as_object);
+
+        let mut res = Map::new();
+        for key in live
+            .keys()
+            .chain(desired.keys().filter(|k| !live.contains_key(*k)))
+        {
+            let child = child_path(path, key);
+            let (typ, read_only) = field_type(key).unwrap_or((&Type::AnyJson, false));
+
+            if read_only {
+                if let Some(desired_value) = desired.get(key)
+                    && live.get(key) != Some(desired_value)
+                {
+                    self.conflicts.push(Conflict {
+                        path: child,
+                        reason: ConflictReason::ReadOnly,
+                        live: live.get(key).cloned(),
+                        desired: Some(desired_value.clone()),
+                    });
+                }
+                if let Some(live_value) = live.get(key) {
+                    res.insert(key.clone(), live_value.clone());
+                }
+                continue;
+            }
+
+            if let Some(value) = self.merge_type(
+                typ,
+                last.and_then(|l| l.get(key)),
+                live.get(key),
+                desired.get(key),
+                &child,
+            ) {
+                res.insert(key.clone(), value);
+            }
+        }
+        Value::Object(res)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
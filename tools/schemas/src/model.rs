@@ -1,7 +1,7 @@
 use convert_case::{Case, Casing};
 use std::{
     borrow::Cow,
-    collections::BTreeMap,
+    collections::{BTreeMap, BTreeSet},
     path::{Path, PathBuf},
 };
 
@@ -16,6 +16,158 @@ pub struct Model {
     pub types: BTreeMap<TypeId, TypeDefinition>,
 }
 
+impl Model {
+    /// Flattened type names (see [`TypeId::join`]) that are defined in more than one context.
+    ///
+    /// Type names only need to be unique within a context. Codegens for targets without context-level
+    /// modules qualify only these names with a context, keeping all other generated names stable.
+    pub fn ambiguous_names(&self) -> BTreeSet<String> {
+        let mut contexts: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+        for id in self.types.keys() {
+            contexts
+                .entry(id.join("").into_owned())
+                .or_default()
+                .insert(id.context());
+        }
+
+        contexts
+            .into_iter()
+            .filter(|(_, c)| c.len() > 1)
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Types that hold `sensitive` fields, directly or within nested values, and need to be redacted before logging
+    pub fn redactable_types(&self) -> BTreeSet<TypeId> {
+        fn holds(typ: &Type, found: &BTreeSet<TypeId>) -> bool {
+            match typ {
+                Type::Custom(id) => found.contains(id),
+                Type::Array(arr) => holds(&arr.item_type, found),
+                _ => false,
+            }
+        }
+
+        // Containment propagates up one level per pass until nothing changes
+        let mut found = BTreeSet::new();
+        loop {
+            let before = found.len();
+            for typ in self.types.values() {
+                let redactable = match typ {
+                    TypeDefinition::Struct(t) => t
+                        .fields
+                        .values()
+                        .any(|f| f.sensitive || holds(&f.typ, &found)),
+                    TypeDefinition::Union(t) => t.variants.iter().any(|v| found.contains(&v.id)),
+                    TypeDefinition::Map(t) => holds(&t.value_type, &found),
+                    TypeDefinition::Enum(_) => false,
+                };
+                if redactable {
+                    found.insert(typ.id().clone());
+                }
+            }
+            if found.len() == before {
+                return found;
+            }
+        }
+    }
+
+    /// Concrete types that satisfy the bound of a type parameter
+    pub fn types_satisfying(&self, bound: json_schema::GenericBound) -> Vec<TypeId> {
+        let mut res = BTreeSet::new();
+
+        for typ in self.types.values() {
+            let TypeDefinition::Struct(t) = typ else {
+                continue;
+            };
+            if !matches!(t.metatype, MetaType::Resource) {
+                continue;
+            }
+
+            let Some(Field {
+                typ: Type::Custom(spec),
+                ..
+            }) = t.fields.get("spec")
+            else {
+                panic!("Resource {} must define a `spec` property", t.id.join("::"));
+            };
+
+            match bound {
+                json_schema::GenericBound::ResourceSpecInput => {
+                    res.insert(spec.clone());
+                }
+                json_schema::GenericBound::ResourceSpec => {
+                    let Some(spec_input) = self.types.get(spec) else {
+                        panic!("Reference to unknown type {}", spec.schema_id());
+                    };
+                    res.insert(spec_input.canonical_type().unwrap_or(spec).clone());
+                }
+            }
+        }
+
+        res.into_iter().collect()
+    }
+
+    /// All concrete instances of a generic struct, one per combination of types that satisfy the bounds of its
+    /// parameters. Structs that have unbounded parameters cannot be monomorphized and produce no instances.
+    pub fn instantiations(&self, t: &Struct) -> Vec<Instantiation> {
+        if t.generics.is_empty() || t.generics.iter().any(|g| g.bound.is_none()) {
+            return Vec::new();
+        }
+
+        let mut combinations: Vec<Vec<TypeId>> = vec![Vec::new()];
+        for g in &t.generics {
+            let candidates = self.types_satisfying(g.bound.unwrap());
+            combinations = combinations
+                .into_iter()
+                .flat_map(|args| {
+                    candidates.iter().map(move |c| {
+                        let mut args = args.clone();
+                        args.push(c.clone());
+                        args
+                    })
+                })
+                .collect();
+        }
+
+        combinations
+            .into_iter()
+            .map(|args| {
+                let substitutions: BTreeMap<&str, &TypeId> = t
+                    .generics
+                    .iter()
+                    .map(|g| g.name.as_str())
+                    .zip(args.iter())
+                    .collect();
+
+                let suffix: String = args.iter().map(|a| a.join("")).collect();
+
+                let mut typ = t.clone();
+                typ.id = TypeId::new(json_schema::SchemaId::new(format!(
+                    "{}{suffix}",
+                    t.id.schema_id()
+                )));
+                typ.generics = Vec::new();
+                for field in typ.fields.values_mut() {
+                    field.typ = substitute_generics(&field.typ, &substitutions);
+                }
+
+                Instantiation { args, typ }
+            })
+            .collect()
+    }
+}
+
+fn substitute_generics(typ: &Type, substitutions: &BTreeMap<&str, &TypeId>) -> Type {
+    match typ {
+        Type::Generic(name) => Type::Custom(substitutions[name.as_str()].clone()),
+        Type::Array(arr) => Type::Array(Array {
+            item_type: Box::new(substitute_generics(&arr.item_type, substitutions)),
+            merge_key: arr.merge_key.clone(),
+        }),
+        _ => typ.clone(),
+    }
+}
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -55,16 +207,21 @@ impl TypeId {
     }
 
     pub fn root<'a>(&'a self) -> Cow<'a, TypeId> {
-        if let Some(p) = self.parent() {
-            Cow::Owned(p)
-        } else {
-            Cow::Borrowed(self)
+        match self.0.root() {
+            Cow::Owned(r) => Cow::Owned(TypeId::new(r)),
+            Cow::Borrowed(_) => Cow::Borrowed(self),
         }
     }
 
+    /// Names of all types from the root to this subtype, e.g. `[DataType, List]`
+    pub fn path(&self) -> Vec<&str> {
+        self.0.path()
+    }
+
+    /// Joins the path of a (possibly nested) subtype, e.g. `DataType::List` when `sep` is `::`
     pub fn join<'a, 'b>(&'a self, sep: &'b str) -> Cow<'a, str> {
-        if let Some(p) = self.0.parent() {
-            Cow::Owned(format!("{}{sep}{}", p.name(), self.0.name()))
+        if self.0.parent().is_some() {
+            Cow::Owned(self.path().join(sep))
         } else {
             Cow::Borrowed(self.0.name())
         }
@@ -73,13 +230,16 @@ impl TypeId {
 
 impl PartialOrd for TypeId {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.join("::").partial_cmp(&other.join("::"))
+        Some(self.cmp(other))
     }
 }
 
+// Types are ordered by name, with the full ID distinguishing same names in different contexts
 impl Ord for TypeId {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.join("::").cmp(&other.join("::"))
+        self.join("::")
+            .cmp(&other.join("::"))
+            .then_with(|| self.0.as_str().cmp(other.0.as_str()))
     }
 }
 
@@ -112,6 +272,14 @@ impl TypeDefinition {
         }
     }
 
+    pub fn canonical_type(&self) -> Option<&TypeId> {
+        match self {
+            TypeDefinition::Struct(v) => v.canonical_type.as_ref(),
+            TypeDefinition::Union(v) => v.canonical_type.as_ref(),
+            TypeDefinition::Enum(_) | TypeDefinition::Map(_) => None,
+        }
+    }
+
     pub fn description(&self) -> &str {
         match self {
             TypeDefinition::Struct(v) => &v.description,
@@ -170,7 +338,9 @@ pub struct Struct {
     pub id: TypeId,
     pub metatype: MetaType,
     pub fields: IndexMap<String, Field>,
-    pub generics: Vec<String>,
+    pub generics: Vec<GenericParam>,
+    /// Canonical form of this type if it represents a user-authored input
+    pub canonical_type: Option<TypeId>,
     pub description: String,
     pub from_string: bool,
     pub codegen_hints: CodegenHints,
@@ -178,6 +348,30 @@ pub struct Struct {
 }
 
 impl Struct {
+    pub fn generic_names(&self) -> Vec<&str> {
+        self.generics.iter().map(|g| g.name.as_str()).collect()
+    }
+
+    /// Finds a field by its serialized name, returning `true` as the second element if the name is a deprecated alias
+    pub fn field_by_json_name(&self, name: &str) -> Option<(&Field, bool)> {
+        if let Some(f) = self.fields.values().find(|f| f.json_name() == name) {
+            return Some((f, false));
+        }
+        self.fields
+            .values()
+            .find(|f| f.aliases.iter().any(|a| a == name))
+            .map(|f| (f, true))
+    }
+
+    /// Manifest parameters of types whose content hash is computed over their canonical flatbuffers encoding
+    pub fn hashable(&self) -> Option<json_schema::FlatbuffersHashable> {
+        get_hint(
+            &self.codegen_hints,
+            CodegenLanguage::Flatbuffers,
+            CodegenHint::Hashable,
+        )
+    }
+
     pub fn get_hint<V: serde::de::DeserializeOwned>(
         &self,
         lang: CodegenLanguage,
@@ -187,17 +381,136 @@ impl Struct {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct GenericParam {
+    pub name: String,
+    pub description: Option<String>,
+    /// Unbounded parameters are type-erased by codegens that don't support generics
+    pub bound: Option<json_schema::GenericBound>,
+}
+
+/// Concrete instance of a generic struct
+#[derive(Debug, Clone)]
+pub struct Instantiation {
+    /// Types substituted for each of the struct's type parameters in order of declaration
+    pub args: Vec<TypeId>,
+    /// Monomorphized struct named after the generic type and its arguments, e.g. `ResourceDatasetSpec`
+    pub typ: Struct,
+}
+
 #[derive(Debug, Clone)]
 pub struct Union {
     pub id: TypeId,
     pub metatype: MetaType,
-    pub variants: Vec<TypeId>,
+    pub discriminator: Discriminator,
+    pub variants: Vec<UnionVariant>,
+    /// Canonical form of this type if it represents a user-authored input
+    pub canonical_type: Option<TypeId>,
     pub description: String,
     pub from_string: bool,
+    pub merge_strategy: json_schema::MergeStrategy,
     pub codegen_hints: CodegenHints,
     pub src: PathBuf,
 }
 
+impl Union {
+    /// Whether readers should preserve variants they don't know instead of failing
+    pub fn has_unknown_variant(&self) -> bool {
+        get_hint(
+            &self.codegen_hints,
+            CodegenLanguage::Rust,
+            CodegenHint::UnknownVariant,
+        )
+        .unwrap_or(false)
+    }
+
+    /// Finds a variant by its discriminator value, returning `true` as the second element if the value is a
+    /// deprecated alias. Mirrors serde codegen by also accepting lowercase and camelCase spellings of tags that
+    /// match the variant names.
+    pub fn variant_by_tag(&self, tag: &str) -> Option<(&UnionVariant, bool)> {
+        if let Some(v) = self
+            .variants
+            .iter()
+            .find(|v| v.tag.as_ref().is_some_and(|t| v.matches_tag(t, tag)))
+        {
+            return Some((v, false));
+        }
+        self.variants
+            .iter()
+            .find(|v| v.aliases.iter().any(|a| v.matches_tag(a, tag)))
+            .map(|v| (v, true))
+    }
+
+    /// Explains how variants are told apart in serialized form, unless the default `kind` property is used
+    pub fn discriminator_description(&self) -> Option<String> {
+        match &self.discriminator {
+            Discriminator::Property(p) if p != Discriminator::DEFAULT_PROPERTY => {
+                Some(format!("Variants are discriminated by the `{p}` property."))
+            }
+            Discriminator::Property(_) => None,
+            Discriminator::Untagged => Some(
+                "Variants are not tagged and are resolved by their shape in order of declaration."
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+/// Describes how the variants of a union are told apart in serialized form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Discriminator {
+    /// Variant is identified by the value of the specified property
+    Property(String),
+    /// Variant is resolved by trying to match the value against each variant in order of declaration
+    Untagged,
+}
+
+impl Discriminator {
+    pub const DEFAULT_PROPERTY: &str = "kind";
+
+    pub fn property(&self) -> Option<&str> {
+        match self {
+            Discriminator::Property(p) => Some(p),
+            Discriminator::Untagged => None,
+        }
+    }
+}
+
+impl Default for Discriminator {
+    fn default() -> Self {
+        Self::Property(Self::DEFAULT_PROPERTY.to_string())
+    }
+}
+
+/// Checks if `s` is the `name` itself or its lowercase / camelCase spelling
+pub fn is_same_name(name: &str, s: &str) -> bool {
+    name == s || name.to_lowercase() == s || name.to_case(Case::Camel) == s
+}
+
+#[derive(Debug, Clone)]
+pub struct UnionVariant {
+    pub id: TypeId,
+    /// Value of the discriminator property that identifies this variant, `None` in untagged unions
+    pub tag: Option<String>,
+    pub aliases: Vec<String>,
+}
+
+impl UnionVariant {
+    /// Whether the variant uses a custom tag instead of its name
+    pub fn has_custom_tag(&self) -> bool {
+        self.tag.as_deref().is_some_and(|t| t != self.id.name())
+    }
+
+    // Custom tags are matched verbatim, while name-based ones accept different spellings
+    fn matches_tag(&self, expected: &str, actual: &str) -> bool {
+        if self.has_custom_tag() {
+            expected == actual
+        } else {
+            is_same_name(expected, actual)
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Enum {
     pub id: TypeId,
@@ -209,12 +522,27 @@ pub struct Enum {
     pub format: Type,
 }
 
+impl Enum {
+    /// Whether readers should preserve values they don't know instead of failing
+    pub fn has_unknown_variant(&self) -> bool {
+        get_hint(
+            &self.codegen_hints,
+            CodegenLanguage::Rust,
+            CodegenHint::UnknownVariant,
+        )
+        .unwrap_or(false)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct Map {
     pub id: TypeId,
     pub metatype: MetaType,
     pub description: String,
     pub value_type: Type,
+    /// Pattern that all keys must match when the map doesn't allow any other properties
+    pub key_pattern: Option<String>,
+    pub merge_strategy: json_schema::MergeStrategy,
     pub codegen_hints: CodegenHints,
     pub src: PathBuf,
 }
@@ -292,6 +620,17 @@ pub enum Type {
     AnyJson,
 }
 
+impl Type {
+    /// Name of the type parameter this type refers to, directly or as an array item
+    pub fn generic_name(&self) -> Option<&str> {
+        match self {
+            Type::Generic(name) => Some(name),
+            Type::Array(arr) => arr.item_type.generic_name(),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum MetaType {
     Manifest,
@@ -300,6 +639,7 @@ pub enum MetaType {
     ResourceHandle,
     ResourceCondition,
     EngineMessage,
+    ProtocolMessage,
     Fragment,
 }
 
@@ -315,6 +655,7 @@ impl MetaType {
             json_schema::SchemaId::METASCHEMA_RESOURCE_HANDLE => Self::ResourceHandle,
             json_schema::SchemaId::METASCHEMA_RESOURCE_CONDITION => Self::ResourceCondition,
             json_schema::SchemaId::METASCHEMA_ENGINE_MESSAGE => Self::EngineMessage,
+            json_schema::SchemaId::METASCHEMA_PROTOCOL_MESSAGE => Self::ProtocolMessage,
             json_schema::SchemaId::METASCHEMA_JSONSCHEMA => Self::Fragment,
             _ => panic!("Unrecognized meta-schema: {id}"),
         }
@@ -324,6 +665,8 @@ impl MetaType {
 #[derive(Debug, Clone)]
 pub struct Array {
     pub item_type: Box<Type>,
+    /// Property of the items that identifies them when diffing, see [`check_merge_keys`]
+    pub merge_key: Option<String>,
 }
 
 #[derive(Debug, Clone)]
@@ -338,10 +681,34 @@ pub struct Field {
     pub constant: Option<serde_json::Value>,
     pub explicit_tag: Option<u32>,
     pub deprecated: bool,
+    pub aliases: Vec<String>,
+    /// Populated by the system and preserved when applying user-authored manifests
+    pub read_only: bool,
+    /// Type of the resource that a reference points to when it doesn't specify one, see [`check_target_types`]
+    pub target_type: Option<TypeId>,
+    /// Supports `${{ scope.path }}` expressions, see [`crate::interpolate`]
+    pub templatable: bool,
+    /// Holds a secret, see [`Model::redactable_types`]
+    pub sensitive: bool,
     pub codegen_hints: CodegenHints,
 }
 
 impl Field {
+    /// Name of the property as it appears in serialized manifests
+    pub fn json_name(&self) -> String {
+        if self.name.starts_with('$') {
+            self.name.clone()
+        } else {
+            self.name.to_case(Case::Camel)
+        }
+    }
+
+    /// Whether the value is a secret string that generated code wraps to keep it out of logs. Sensitive fields of
+    /// types like `config::Secret` are not wrapped, as such types hide their own secrets, see [`check_sensitive`].
+    pub fn wraps_sensitive(&self) -> bool {
+        self.sensitive && matches!(self.typ, Type::String)
+    }
+
     pub fn get_hint<V: serde::de::DeserializeOwned>(
         &self,
         lang: CodegenLanguage,
@@ -381,7 +748,13 @@ pub fn parse_jsonschema(schemas: Vec<json_schema::Schema>) -> Model {
             metatype,
             schema.unevaluated_properties.take() == Some(false),
         ) {
-            (MetaType::Manifest | MetaType::Resource | MetaType::EngineMessage, true) => (),
+            (
+                MetaType::Manifest
+                | MetaType::Resource
+                | MetaType::EngineMessage
+                | MetaType::ProtocolMessage,
+                true,
+            ) => (),
             (
                 MetaType::Fragment
                 | MetaType::ResourceRef
@@ -416,22 +789,20 @@ pub fn parse_jsonschema(schemas: Vec<json_schema::Schema>) -> Model {
 
         let src = schema.src.take().expect("Schema without source path");
 
-        // Extract all $defs into top-level types
-        for (dname, dsch) in schema.defs.take().unwrap_or_default() {
-            let def_id = root_id.subtype(dname);
+        let ctx = root_id.name().to_string();
+        parse_type_definition_with_defs(root_id, schema, src, ctx, &mut types);
+    }
 
-            let typ = parse_type_definition(
-                def_id.clone(),
-                dsch,
-                src.clone(),
-                format!("{}.$defs.{}", root_id.name(), def_id.name()),
-            );
-            types.insert(typ.id().clone(), typ);
+    // Only tables can be the root of a flatbuffer
+    for typ in types.values() {
+        if !matches!(typ, TypeDefinition::Struct(_))
+            && typ
+                .codegen_hints()
+                .get(&CodegenLanguage::Flatbuffers)
+                .is_some_and(|h| h.contains_key(&CodegenHint::Hashable))
+        {
+            panic!("Only object types can be hashable: {}", typ.id().join("::"));
         }
-
-        let typ =
-            parse_type_definition(root_id.clone(), schema, src, format!("{}", root_id.name()));
-        types.insert(typ.id().clone(), typ);
     }
 
     Model { types }
@@ -439,6 +810,26 @@ pub fn parse_jsonschema(schemas: Vec<json_schema::Schema>) -> Model {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// Extracts all (possibly nested) $defs into top-level types
+fn parse_type_definition_with_defs(
+    id: TypeId,
+    mut schema: json_schema::Schema,
+    src: PathBuf,
+    ctx: String,
+    types: &mut BTreeMap<TypeId, TypeDefinition>,
+) {
+    for (dname, dsch) in schema.defs.take().unwrap_or_default() {
+        let def_id = id.subtype(dname);
+        let def_ctx = format!("{ctx}.$defs.{}", def_id.name());
+        parse_type_definition_with_defs(def_id, dsch, src.clone(), def_ctx, types);
+    }
+
+    let typ = parse_type_definition(id, schema, src, ctx);
+    types.insert(typ.id().clone(), typ);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
 fn parse_type_definition(
     id: TypeId,
     schema: json_schema::Schema,
@@ -510,6 +901,16 @@ fn parse_type_definition(
                 tag: obj.tag,
                 codegen: obj.codegen,
                 deprecated: obj.deprecated,
+                aliases: obj.aliases,
+                generics: obj.generics,
+                generic: obj.generic,
+                discriminator: obj.discriminator,
+                merge_key: obj.merge_key,
+                merge_strategy: obj.merge_strategy,
+                read_only: obj.read_only,
+                target_type: obj.target_type,
+                templatable: obj.templatable,
+                sensitive: obj.sensitive,
                 examples: obj.examples,
                 src: obj.src,
             };
@@ -559,13 +960,23 @@ fn parse_type_struct(
         items: None,
         r#ref: None,
         r#const: None,
-        canonical_type: _,
+        canonical_type,
         format: None,
         default: None,
         description: Some(description),
         tag: None,
         codegen,
         deprecated: None,
+        aliases: None,
+        generics: schema_generics,
+        generic: None,
+        discriminator: None,
+        merge_key: None,
+        merge_strategy: None,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: _,
         src: None,
     } = schema
@@ -576,7 +987,15 @@ fn parse_type_struct(
     let metatype = MetaType::from_metaschema(metaschema.as_ref());
 
     let mut fields = IndexMap::new();
-    let mut generics = Vec::new();
+    let mut generics: Vec<_> = schema_generics
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, p)| GenericParam {
+            name,
+            description: p.description,
+            bound: p.bound,
+        })
+        .collect();
 
     for (pname, mut psch) in properties {
         let fconst = psch.r#const.take();
@@ -592,21 +1011,42 @@ fn parse_type_struct(
         let ftag = psch.tag.take();
         let codegen_hints = psch.codegen.take().unwrap_or_default();
         let fdeprecated = psch.deprecated.take().unwrap_or(false);
+        let faliases = psch.aliases.take().unwrap_or_default();
+        let fread_only = psch.read_only.take().unwrap_or(false);
+        let ftarget_type = psch.target_type.take().map(TypeId::new);
+        let ftemplatable = psch.templatable.take().unwrap_or(false);
+        let fsensitive = psch.sensitive.take().unwrap_or(false);
 
         let validations = parse_validations(&mut psch, format!("{ctx}.{pname}"));
 
         let ftype = parse_type(psch, &id, format!("{ctx}.{pname}"));
         let fname = pname.to_case(Case::Snake);
 
+        // Fragments that don't refer to a declared parameter get an implicit unbounded one named after the field
         let ftype = match ftype {
-            Type::Generic(_) => {
+            Type::Generic(name) if name.is_empty() => {
                 let generic_t = format!("{}{}T", fname[0..1].to_uppercase(), &fname[1..]);
-                generics.push(generic_t.clone());
+                if generics.iter().any(|g| g.name == generic_t) {
+                    panic!(
+                        "Implicit type parameter {generic_t} clashes with a declared one: {ctx}.{pname}"
+                    );
+                }
+                generics.push(GenericParam {
+                    name: generic_t.clone(),
+                    description: None,
+                    bound: None,
+                });
                 Type::Generic(generic_t)
             }
             _ => ftype,
         };
 
+        if let Some(name) = ftype.generic_name()
+            && !generics.iter().any(|g| g.name == name)
+        {
+            panic!("Reference to undeclared type parameter {name}: {ctx}.{pname}");
+        }
+
         let field = Field {
             name: fname.clone(),
             typ: ftype,
@@ -618,6 +1058,11 @@ fn parse_type_struct(
             constant: fconst,
             explicit_tag: ftag,
             deprecated: fdeprecated,
+            aliases: faliases,
+            read_only: fread_only,
+            target_type: ftarget_type,
+            templatable: ftemplatable,
+            sensitive: fsensitive,
             codegen_hints,
         };
 
@@ -635,12 +1080,22 @@ fn parse_type_struct(
         }
     }
 
+    for g in &generics {
+        if !fields
+            .values()
+            .any(|f: &Field| f.typ.generic_name() == Some(&g.name))
+        {
+            panic!("Type parameter {} is never used: {ctx}", g.name);
+        }
+    }
+
     Struct {
         id,
         metatype,
         description,
         fields,
         generics,
+        canonical_type: canonical_type.map(TypeId::new),
         from_string,
         codegen_hints: codegen.unwrap_or_default(),
         src,
@@ -666,13 +1121,23 @@ fn parse_type_union(id: TypeId, schema: json_schema::Schema, src: PathBuf, ctx:
         items: None,
         r#ref: None,
         r#const: None,
-        canonical_type: _,
+        canonical_type,
         format,
         default: None,
         description: Some(description),
         tag: None,
-        codegen: None,
+        codegen,
         deprecated: None,
+        aliases: None,
+        generics: None,
+        generic: None,
+        discriminator,
+        merge_key: None,
+        merge_strategy,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: _,
         src: None,
     } = schema
@@ -685,26 +1150,81 @@ fn parse_type_union(id: TypeId, schema: json_schema::Schema, src: PathBuf, ctx:
         _ => false,
     };
 
+    let discriminator = match discriminator {
+        None => Discriminator::default(),
+        Some(json_schema::Discriminator {
+            property_name: None,
+            untagged: Some(true),
+        }) => Discriminator::Untagged,
+        Some(json_schema::Discriminator {
+            property_name: Some(name),
+            untagged: None | Some(false),
+        }) => Discriminator::Property(name),
+        Some(d) => panic!("Invalid union discriminator: {ctx}: {d:?}"),
+    };
+
+    assert!(
+        !from_string || discriminator == Discriminator::default(),
+        "Unions with `union-or-string` format must use the default `kind` discriminator: {ctx}"
+    );
+
     let mut variants = Vec::new();
     for (i, schema) in one_of.into_iter().enumerate() {
-        let var = parse_type_union_variant(&id, schema, format!("{ctx}.$oneOf.[{i}]"));
+        let var =
+            parse_type_union_variant(&id, &discriminator, schema, format!("{ctx}.$oneOf.[{i}]"));
         variants.push(var);
     }
 
     assert!(!variants.is_empty(), "Union must have at least one variant");
 
-    Union {
+    let mut tags = BTreeSet::new();
+    for tag in variants.iter().filter_map(|v| v.tag.as_ref()) {
+        assert!(
+            tags.insert(tag),
+            "Union variants must have unique discriminator values: {ctx}: {tag}"
+        );
+    }
+
+    let union = Union {
         id,
         metatype: MetaType::from_metaschema(metaschema.as_ref()),
+        discriminator,
         variants,
+        canonical_type: canonical_type.map(TypeId::new),
         description,
         from_string,
-        codegen_hints: Default::default(),
+        merge_strategy: merge_strategy.unwrap_or_default(),
+        codegen_hints: codegen.unwrap_or_default(),
         src,
-    }
+    };
+
+    // Unknown variants are recognized by their tag
+    assert!(
+        !union.has_unknown_variant() || union.discriminator != Discriminator::Untagged,
+        "Untagged unions can't have an unknown variant: {ctx}"
+    );
+
+    union
 }
 
-fn parse_type_union_variant(parent: &TypeId, schema: json_schema::Schema, ctx: String) -> TypeId {
+fn parse_type_union_variant(
+    parent: &TypeId,
+    discriminator: &Discriminator,
+    schema: json_schema::Schema,
+    ctx: String,
+) -> UnionVariant {
+    let property = match discriminator {
+        Discriminator::Property(property) => property,
+        Discriminator::Untagged => {
+            // Untagged variants are plain references
+            return UnionVariant {
+                id: parse_ref(schema, parent, false, ctx),
+                tag: None,
+                aliases: Vec::new(),
+            };
+        }
+    };
+
     let json_schema::Schema {
         id: None,
         schema: None,
@@ -728,6 +1248,16 @@ fn parse_type_union_variant(parent: &TypeId, schema: json_schema::Schema, ctx: S
         tag: None,
         codegen: None,
         deprecated: None,
+        aliases,
+        generics: None,
+        generic: None,
+        discriminator: None,
+        merge_key: None,
+        merge_strategy: None,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: None,
         src: None,
     } = schema
@@ -738,7 +1268,7 @@ fn parse_type_union_variant(parent: &TypeId, schema: json_schema::Schema, ctx: S
     assert_eq!(
         all_of.len(),
         2,
-        "Union variants should use `allOf` with `kind` constant and a `$ref`"
+        "Union variants should use `allOf` with `{property}` constant and a `$ref`: {ctx}"
     );
 
     let type_id = parse_ref(
@@ -748,23 +1278,37 @@ fn parse_type_union_variant(parent: &TypeId, schema: json_schema::Schema, ctx: S
         format!("{ctx}.$allOf.[1]"),
     );
 
+    // Tag value defaults to the variant name but can be customized, e.g. `dataset.ref.updated`
+    let tag = all_of[0]
+        .properties
+        .as_ref()
+        .and_then(|p| p.get(property))
+        .and_then(|p| p.r#const.as_ref())
+        .and_then(|c| c.as_str())
+        .unwrap_or_else(|| panic!("Missing `{property}` constant on union variant: {ctx}"))
+        .to_string();
+
     assert_eq!(
         all_of[0].to_value(),
         serde_json::json!({
           "properties": {
-            "kind": {
+            property: {
               "type": "string",
-              "const": type_id.name(),
+              "const": tag,
             }
           },
           "required": [
-            "kind"
+            property
           ]
         }),
-        "Invalid `kind` tag schema on union variant"
+        "Invalid `{property}` tag schema on union variant: {ctx}"
     );
 
-    type_id
+    UnionVariant {
+        id: type_id,
+        tag: Some(tag),
+        aliases: aliases.unwrap_or_default(),
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -793,6 +1337,16 @@ fn parse_type_enum(id: TypeId, schema: json_schema::Schema, src: PathBuf, ctx: S
         tag: None,
         codegen,
         deprecated: None,
+        aliases: None,
+        generics: None,
+        generic: None,
+        discriminator: None,
+        merge_key: None,
+        merge_strategy: None,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: None,
         src: None,
     } = schema
@@ -852,7 +1406,7 @@ fn parse_type_map(id: TypeId, schema: json_schema::Schema, src: PathBuf, ctx: St
         required: None,
         properties: None,
         pattern_properties: Some(pattern_properties),
-        additional_properties: None,
+        additional_properties,
         unevaluated_properties: None,
         one_of: None,
         all_of: None,
@@ -867,6 +1421,16 @@ fn parse_type_map(id: TypeId, schema: json_schema::Schema, src: PathBuf, ctx: St
         tag: None,
         codegen,
         deprecated: None,
+        aliases: None,
+        generics: None,
+        generic: None,
+        discriminator: None,
+        merge_key: None,
+        merge_strategy,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: _,
         src: None,
     } = schema
@@ -878,7 +1442,18 @@ fn parse_type_map(id: TypeId, schema: json_schema::Schema, src: PathBuf, ctx: St
         panic!("Only one pattern is supported in map schema: {ctx}",)
     }
 
-    let value_schema = pattern_properties.into_iter().next().unwrap().1;
+    let (pattern, value_schema) = pattern_properties.into_iter().next().unwrap();
+
+    let key_pattern = match additional_properties {
+        None => None,
+        Some(false) => {
+            if let Err(e) = regex::Regex::new(&pattern) {
+                panic!("Invalid key pattern in map schema: {ctx}: {e}")
+            }
+            Some(pattern)
+        }
+        Some(true) => panic!("Maps can't allow additional properties: {ctx}"),
+    };
 
     let value_type = parse_type(value_schema, &id, ctx.clone());
 
@@ -892,6 +1467,8 @@ fn parse_type_map(id: TypeId, schema: json_schema::Schema, src: PathBuf, ctx: St
         metatype: MetaType::from_metaschema(metaschema.as_ref()),
         description: description.clone(),
         value_type,
+        key_pattern,
+        merge_strategy: merge_strategy.unwrap_or_default(),
         codegen_hints: codegen.unwrap_or_default(),
         src,
     }
@@ -960,6 +1537,16 @@ fn parse_type_array(schema: json_schema::Schema, root: &TypeId, ctx: String) ->
         tag: None,
         codegen: None,
         deprecated: None,
+        aliases: None,
+        generics: None,
+        generic: None,
+        discriminator: None,
+        merge_key,
+        merge_strategy: None,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: _,
         src: None,
     } = schema
@@ -969,7 +1556,10 @@ fn parse_type_array(schema: json_schema::Schema, root: &TypeId, ctx: String) ->
 
     let item_type = Box::new(parse_type(*items, root, format!("{ctx}.items")));
 
-    Array { item_type }
+    Array {
+        item_type,
+        merge_key,
+    }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -998,6 +1588,16 @@ fn parse_type_scalar(schema: json_schema::Schema, ctx: String) -> Type {
         tag: None,
         codegen: None,
         deprecated: None,
+        aliases: None,
+        generics: None,
+        generic,
+        discriminator: None,
+        merge_key: None,
+        merge_strategy: None,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: _,
         src: None,
     } = &schema
@@ -1005,6 +1605,10 @@ fn parse_type_scalar(schema: json_schema::Schema, ctx: String) -> Type {
         panic!("Invalid scalar schema: {ctx}: {}", schema.display())
     };
 
+    if generic.is_some() && *format != Some(json_schema::Format::Fragment) {
+        panic!("Only `fragment` properties can refer to type parameters: {ctx}");
+    }
+
     match (typ, format) {
         (json_schema::Type::Boolean, None) => Type::Boolean,
         (json_schema::Type::Integer, Some(format)) => match format {
@@ -1046,7 +1650,7 @@ fn parse_type_scalar(schema: json_schema::Schema, ctx: String) -> Type {
 
         (json_schema::Type::String, Some(json_schema::Format::Flatbuffers)) => Type::Flatbuffers,
         (json_schema::Type::Object, Some(json_schema::Format::Fragment)) => {
-            Type::Generic(String::new())
+            Type::Generic(generic.clone().unwrap_or_default())
         }
         _ => panic!("Invalid scalar schema: {ctx}: {}", schema.display()),
     }
@@ -1095,6 +1699,16 @@ fn parse_ref(
         tag: None,
         codegen: None,
         deprecated: None,
+        aliases: None,
+        generics: None,
+        generic: None,
+        discriminator: None,
+        merge_key: None,
+        merge_strategy: None,
+        read_only: None,
+        target_type: None,
+        templatable: None,
+        sensitive: None,
         examples: None,
         src: None,
     } = schema
@@ -1117,7 +1731,7 @@ pub(crate) fn ref_to_type_id(reff: &str, parent: &TypeId, ctx: String) -> TypeId
     if reff.starts_with("http:") || reff.starts_with("https:") {
         TypeId::new(json_schema::SchemaId::new(reff))
     } else if let Some(local) = reff.strip_prefix("#/$defs/") {
-        parent.root().subtype(local)
+        TypeId::new(parent.schema_id().local_def(local))
     } else {
         panic!("Invalid reference: {ctx}: {reff}")
     }
@@ -1162,3 +1776,257 @@ pub fn check_explicit_tags_sequence(model: &Model) {
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Checks that aliases, which let old manifests keep working after a rename, are not ambiguous:
+/// - An alias cannot collide with a name of another field / variant in the same type
+/// - The same alias cannot be claimed by two fields / variants
+pub fn check_aliases(model: &Model) {
+    for (id, t) in &model.types {
+        let mut names: Vec<(String, String)> = match t {
+            TypeDefinition::Struct(t) => t
+                .fields
+                .values()
+                .flat_map(|f| {
+                    std::iter::once((f.json_name(), f.json_name()))
+                        .chain(f.aliases.iter().map(|a| (a.clone(), f.json_name())))
+                })
+                .collect(),
+            TypeDefinition::Union(t) => {
+                // Variants are told apart by their tags, which differ from the names when custom, and by other
+                // spellings of the name-based ones
+                let tag =
+                    |v: &UnionVariant| v.tag.clone().unwrap_or_else(|| v.id.name().to_string());
+                for v in &t.variants {
+                    for alias in &v.aliases {
+                        if let Some((other, _)) = t.variant_by_tag(alias)
+                            && other.id != v.id
+                        {
+                            panic!(
+                                "Ambiguous name {alias} in {}: used by both {} and {}",
+                                id.join("::"),
+                                tag(other),
+                                tag(v),
+                            );
+                        }
+                    }
+                }
+
+                t.variants
+                    .iter()
+                    .flat_map(|v| {
+                        std::iter::once((tag(v), tag(v)))
+                            .chain(v.aliases.iter().map(|a| (a.clone(), tag(v))))
+                    })
+                    .collect()
+            }
+            TypeDefinition::Enum(_) | TypeDefinition::Map(_) => continue,
+        };
+
+        names.sort();
+
+        for pair in names.windows(2) {
+            if pair[0].0 == pair[1].0 {
+                panic!(
+                    "Ambiguous name {} in {}: used by both {} and {}",
+                    pair[0].0,
+                    id.join("::"),
+                    pair[0].1,
+                    pair[1].1,
+                );
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Checks that merge keys, which identify array items across versions of a manifest, refer to a required scalar
+/// property of the item struct
+pub fn check_merge_keys(model: &Model) {
+    fn check(model: &Model, typ: &Type, ctx: &str) {
+        let Type::Array(arr) = typ else {
+            return;
+        };
+
+        check(model, &arr.item_type, ctx);
+
+        let Some(key) = &arr.merge_key else {
+            return;
+        };
+
+        let item = match arr.item_type.as_ref() {
+            Type::Custom(id) => match model.types.get(id) {
+                Some(TypeDefinition::Struct(t)) => t,
+                _ => panic!("Merge key `{key}` can only be used on arrays of structs: {ctx}"),
+            },
+            _ => panic!("Merge key `{key}` can only be used on arrays of structs: {ctx}"),
+        };
+
+        let Some((field, false)) = item.field_by_json_name(key) else {
+            panic!(
+                "Merge key `{key}` is not a property of {}: {ctx}",
+                item.id.join("::")
+            );
+        };
+
+        if field.optional || matches!(field.typ, Type::Array(_) | Type::Custom(_) | Type::AnyJson) {
+            panic!("Merge key `{key}` must be a required scalar property: {ctx}");
+        }
+    }
+
+    for (id, t) in &model.types {
+        let TypeDefinition::Struct(t) = t else {
+            continue;
+        };
+        for field in t.fields.values() {
+            check(
+                model,
+                &field.typ,
+                &format!("{}.{}", id.join("::"), field.json_name()),
+            );
+        }
+    }
+}
+
+/// Checks that unions shaped as filters only use operators that [`crate::filter`] can parse
+pub fn check_filters(model: &Model) {
+    for t in model.types.values() {
+        let TypeDefinition::Union(t) = t else {
+            continue;
+        };
+        if let Err(e) = crate::filter::check_grammar(model, t) {
+            panic!("{e}");
+        }
+    }
+}
+
+/// Checks that `targetType` is only used on reference and `dataset-ref` properties and points to a resource type
+pub fn check_target_types(model: &Model) {
+    for (id, t) in &model.types {
+        let TypeDefinition::Struct(t) = t else {
+            continue;
+        };
+        for field in t.fields.values() {
+            let Some(target) = &field.target_type else {
+                continue;
+            };
+            let ctx = format!("{}.{}", id.join("::"), field.json_name());
+
+            let is_ref = match &field.typ {
+                Type::Custom(ref_id) => {
+                    matches!(model.types[ref_id].metatype(), MetaType::ResourceRef)
+                }
+                Type::DatasetRef => true,
+                _ => false,
+            };
+            if !is_ref {
+                panic!(
+                    "Target type can only be specified on reference and `dataset-ref` properties: {ctx}"
+                );
+            }
+
+            match model.types.get(target) {
+                Some(t) if matches!(t.metatype(), MetaType::Resource) => (),
+                Some(_) => panic!(
+                    "Target type {} is not a resource: {ctx}",
+                    target.schema_id()
+                ),
+                None => panic!("Reference to unknown type {}: {ctx}", target.schema_id()),
+            }
+        }
+    }
+}
+
+/// Checks that `sensitive` is only used on strings and on types that hold sensitive fields themselves, so that they
+/// can be redacted. Fields of the latter are skipped when wrapping secrets, as their values already redact the
+/// sensitive parts, see [`Field::wraps_sensitive`].
+pub fn check_sensitive(model: &Model) {
+    let redactable = model.redactable_types();
+    for (id, t) in &model.types {
+        let TypeDefinition::Struct(t) = t else {
+            continue;
+        };
+        for field in t.fields.values().filter(|f| f.sensitive) {
+            let ok = match &field.typ {
+                Type::String => true,
+                Type::Custom(id) => redactable.contains(id),
+                _ => false,
+            };
+            if !ok {
+                panic!(
+                    "Sensitive property must be a string or a type with sensitive properties: {}.{}",
+                    id.join("::"),
+                    field.json_name()
+                );
+            }
+        }
+    }
+}
+
+/// Checks that condition schemas define the properties that [`crate::status`] maintains
+pub fn check_conditions(model: &Model) {
+    for (id, t) in &model.types {
+        if !matches!(t.metatype(), MetaType::ResourceCondition) {
+            continue;
+        }
+        let TypeDefinition::Struct(t) = t else {
+            panic!("Condition schemas must be objects: {}", id.join("::"));
+        };
+        let has = |name: &str| t.fields.values().find(|f| f.json_name() == name);
+        let valid = has("value").is_some_and(|f| !f.optional)
+            && has("lastTransitionTime")
+                .is_some_and(|f| !f.optional && matches!(f.typ, Type::DateTime));
+        if !valid {
+            panic!(
+                "Condition schemas must define required `value` and `lastTransitionTime` date-time properties: {}",
+                id.join("::")
+            );
+        }
+    }
+}
+
+/// Checks that every protocol message is sent over a channel of its own
+pub fn check_protocol_messages(model: &Model) {
+    let mut channels = BTreeMap::new();
+    for (id, t) in &model.types {
+        if !matches!(t.metatype(), MetaType::ProtocolMessage) {
+            continue;
+        }
+        let channel: Option<String> = t.get_hint(CodegenLanguage::Asyncapi, CodegenHint::Channel);
+        let operation: Option<json_schema::AsyncapiOperation> =
+            t.get_hint(CodegenLanguage::Asyncapi, CodegenHint::Operation);
+        let (Some(channel), Some(_)) = (channel, operation) else {
+            panic!(
+                "Protocol messages must define `channel` and `operation` asyncapi codegen hints: {}",
+                id.join("::")
+            );
+        };
+        if let Some(prev) = channels.insert(channel.clone(), id) {
+            panic!(
+                "Channel {channel} of {} is already used by {}",
+                id.join("::"),
+                prev.join("::")
+            );
+        }
+    }
+}
+
+/// Checks that `templatable` is only used on string properties
+pub fn check_templatable(model: &Model) {
+    for (id, t) in &model.types {
+        let TypeDefinition::Struct(t) = t else {
+            continue;
+        };
+        for field in t.fields.values() {
+            if field.templatable && !matches!(field.typ, Type::String | Type::Url) {
+                panic!(
+                    "Only string properties can be templatable: {}.{}",
+                    id.join("::"),
+                    field.json_name()
+                );
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,683 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+use serde_json::{Value, json};
+
+use crate::filter::{self, Condition, Filter};
+use crate::json_schema::SchemaId;
+use crate::model::{MetaType, Model, TypeId};
+use crate::validation::manifest_type;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const LABEL_FILTER: &str = "https://opendatafabric.org/schemas/resource/v1alpha1/LabelFilter";
+const ACCOUNT: &str = "https://opendatafabric.org/schemas/auth/v1alpha1/Account";
+
+/// Reference to an account by any combination of its identifiers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountRef {
+    pub id: Option<String>,
+    pub did: Option<String>,
+    pub name: Option<String>,
+}
+
+impl AccountRef {
+    /// Parses the short string form that can be an ID, a DID, or a name, or the struct form
+    pub fn parse(value: &Value) -> Result<Self, String> {
+        match value {
+            Value::String(s) if s.starts_with("did:") => Ok(Self {
+                did: Some(s.clone()),
+                ..Default::default()
+            }),
+            Value::String(s) if is_uuid(s) => Ok(Self {
+                id: Some(s.clone()),
+                ..Default::default()
+            }),
+            Value::String(s) => Ok(Self {
+                name: Some(s.clone()),
+                ..Default::default()
+            }),
+            Value::Object(obj) => {
+                let field = |key: &str| obj.get(key).and_then(Value::as_str).map(str::to_string);
+                let account = Self {
+                    id: field("id"),
+                    did: field("did"),
+                    name: field("name"),
+                };
+                if account == Self::default() {
+                    return Err(format!("Account reference {value} has no identifiers"));
+                }
+                Ok(account)
+            }
+            _ => Err(format!("Invalid account reference {value}")),
+        }
+    }
+
+    /// Whether both refer to the same account judging by the identifiers they have in common
+    pub fn is_same(&self, other: &Self) -> bool {
+        [
+            (&self.id, &other.id),
+            (&self.did, &other.did),
+            (&self.name, &other.name),
+        ]
+        .into_iter()
+        .find_map(|(a, b)| a.as_ref().zip(b.as_ref()))
+        .is_some_and(|(a, b)| a == b)
+    }
+}
+
+impl std::fmt::Display for AccountRef {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let id = self
+            .name
+            .as_ref()
+            .or(self.did.as_ref())
+            .or(self.id.as_ref());
+        write!(f, "{}", id.map(String::as_str).unwrap_or("<unknown>"))
+    }
+}
+
+/// Resource manifest loaded into a [`ResourceStore`]
+#[derive(Debug, Clone)]
+pub struct StoredResource {
+    /// File the manifest was loaded from
+    pub path: Option<PathBuf>,
+    pub type_id: TypeId,
+    pub id: Option<String>,
+    /// DID of the object that the resource manages, e.g. of a dataset or an account
+    pub did: Option<String>,
+    pub name: String,
+    /// Owning account, `None` for resources of the current account
+    pub account: Option<AccountRef>,
+    pub manifest: Value,
+}
+
+impl std::fmt::Display for StoredResource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:", self.type_id.name())?;
+        if let Some(account) = &self.account {
+            write!(f, "{account}/")?;
+        }
+        write!(f, "{}", self.name)
+    }
+}
+
+/// What to look up in a [`ResourceStore`]
+#[derive(Debug, Clone)]
+pub enum Query {
+    /// `ResourceSelector`, e.g. `Dataset:org.example.%` or `{ type: Dataset, labels: { env: prod } }`
+    Selector(Value),
+    /// `ResourceRef`, e.g. `Dataset:bob/bobs-dataset`, where the type can be omitted when the default one is given
+    Ref {
+        reference: Value,
+        default_type: Option<String>,
+    },
+    /// `ValueRef`, e.g. `SecretSet:my-secrets#apiKey`
+    ValueRef(Value),
+}
+
+/// Resource considered by a query along with the reasons why it doesn't match
+#[derive(Debug, Clone)]
+pub struct Candidate<'a> {
+    pub resource: &'a StoredResource,
+    pub mismatches: Vec<String>,
+}
+
+impl Candidate<'_> {
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// In-memory set of resource manifests indexed by type, identifiers, and labels.
+///
+/// Resources and queries that don't specify an `account` belong to the current account, as they would when applied by
+/// the user to a node.
+pub struct ResourceStore<'a> {
+    model: &'a Model,
+    current_account: Option<AccountRef>,
+    resources: Vec<StoredResource>,
+    by_type: BTreeMap<TypeId, BTreeSet<usize>>,
+    by_id: BTreeMap<String, usize>,
+    by_did: BTreeMap<String, usize>,
+    /// Label key and JSON-encoded value
+    by_label: BTreeMap<(String, String), BTreeSet<usize>>,
+    /// Files that were not loaded and why
+    pub skipped: Vec<(PathBuf, String)>,
+}
+
+#[derive(Debug, Default)]
+struct Criteria {
+    type_id: Option<TypeId>,
+    account: Option<AccountRef>,
+    id: Option<String>,
+    did: Option<String>,
+    name: Option<NamePattern>,
+    labels: Option<Filter>,
+}
+
+#[derive(Debug)]
+enum NamePattern {
+    Exact(String),
+    /// SQL `LIKE` pattern
+    Like(String, regex::Regex),
+}
+
+impl<'a> ResourceStore<'a> {
+    pub fn new(model: &'a Model, current_account: Option<AccountRef>) -> Self {
+        Self {
+            model,
+            current_account,
+            resources: Vec::new(),
+            by_type: BTreeMap::new(),
+            by_id: BTreeMap::new(),
+            by_did: BTreeMap::new(),
+            by_label: BTreeMap::new(),
+            skipped: Vec::new(),
+        }
+    }
+
+    /// Loads all YAML and JSON resource manifests in the directory recursively, recording others as skipped
+    pub fn load_dir(&mut self, dir: &Path) -> Result<(), String> {
+        let pattern = format!("{}/**/*", dir.display());
+        let mut paths: Vec<_> = glob::glob(&pattern)
+            .map_err(|e| format!("Invalid directory {}: {e}", dir.display()))?
+            .filter_map(Result::ok)
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|e| e == "yaml" || e == "yml" || e == "json")
+            })
+            .collect();
+        paths.sort();
+
+        for path in paths {
+            let content = std::fs::read_to_string(&path)
+                .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+            let manifest = match serde_yaml::from_str(&content) {
+                Ok(manifest) => manifest,
+                Err(e) => {
+                    self.skipped.push((path, format!("Failed to parse: {e}")));
+                    continue;
+                }
+            };
+            if let Err(e) = self.insert(Some(path.clone()), manifest) {
+                self.skipped.push((path, e));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn insert(&mut self, path: Option<PathBuf>, manifest: Value) -> Result<(), String> {
+        let resource = self.to_stored(path, manifest)?;
+
+        if let Some(other) = self.resources.iter().find(|r| {
+            r.type_id == resource.type_id
+                && r.name == resource.name
+                && self.is_same_account(r.account.as_ref(), resource.account.as_ref())
+        }) {
+            return Err(format!(
+                "Resource {resource} is already defined{}",
+                other
+                    .path
+                    .as_ref()
+                    .map(|p| format!(" in {}", p.display()))
+                    .unwrap_or_default()
+            ));
+        }
+
+        let i = self.resources.len();
+        self.by_type
+            .entry(resource.type_id.clone())
+            .or_default()
+            .insert(i);
+        if let Some(id) = &resource.id {
+            self.by_id.insert(id.clone(), i);
+        }
+        if let Some(did) = &resource.did {
+            self.by_did.insert(did.clone(), i);
+        }
+        if let Some(labels) = resource.manifest["headers"]
+            .get("labels")
+            .and_then(Value::as_object)
+        {
+            for (key, value) in labels {
+                self.by_label
+                    .entry((key.clone(), value.to_string()))
+                    .or_default()
+                    .insert(i);
+            }
+        }
+        self.resources.push(resource);
+        Ok(())
+    }
+
+    pub fn model(&self) -> &'a Model {
+        self.model
+    }
+
+    pub fn resources(&self) -> &[StoredResource] {
+        &self.resources
+    }
+
+    /// Checks whether a manifest that is not in the store matches a `ResourceSelector`
+    pub fn matches(&self, selector: &Value, manifest: &Value) -> Result<bool, String> {
+        let (criteria, _) = self.criteria(&Query::Selector(selector.clone()))?;
+        let resource = self.to_stored(None, manifest.clone())?;
+        Ok(self.mismatches(&criteria, &resource).is_empty())
+    }
+
+    /// Returns all resources matching a `ResourceSelector`
+    pub fn select(&self, selector: &Value) -> Result<Vec<&StoredResource>, String> {
+        self.find(&Query::Selector(selector.clone()))
+    }
+
+    /// Resolves a `ResourceRef`, returning `None` for dangling references
+    pub fn resolve_ref(
+        &self,
+        reference: &Value,
+        default_type: Option<&str>,
+    ) -> Result<Option<&StoredResource>, String> {
+        let found = self.find(&Query::Ref {
+            reference: reference.clone(),
+            default_type: default_type.map(str::to_string),
+        })?;
+        Ok(found.into_iter().next())
+    }
+
+    /// Resolves a `ValueRef` into the resource and the path to the value within it, returning `None` for dangling
+    /// references
+    pub fn resolve_value_ref(
+        &self,
+        reference: &Value,
+    ) -> Result<Option<(&StoredResource, Option<String>)>, String> {
+        let (_, path) = self.criteria(&Query::ValueRef(reference.clone()))?;
+        let found = self.find(&Query::ValueRef(reference.clone()))?;
+        Ok(found.into_iter().next().map(|r| (r, path)))
+    }
+
+    /// Returns all resources matching the query, narrowing candidates down with indexes
+    pub fn find(&self, query: &Query) -> Result<Vec<&StoredResource>, String> {
+        let (criteria, _) = self.criteria(query)?;
+
+        let mut candidates: BTreeSet<usize> = if let Some(id) = &criteria.id {
+            self.by_id.get(id).copied().into_iter().collect()
+        } else if let Some(did) = &criteria.did {
+            self.by_did.get(did).copied().into_iter().collect()
+        } else if let Some(type_id) = &criteria.type_id {
+            self.by_type.get(type_id).cloned().unwrap_or_default()
+        } else {
+            (0..self.resources.len()).collect()
+        };
+
+        // Labels compared for equality at the top level of the filter must be present in the index
+        if let Some(Filter::Match(fields)) = &criteria.labels {
+            for field in fields {
+                for condition in &field.conditions {
+                    if let Condition::Eq(value) = condition
+                        && !filter::HEADER_FIELDS.contains(&field.field.as_str())
+                    {
+                        let value = value.to_string();
+                        let indexed = self
+                            .by_label
+                            .iter()
+                            .filter(|((key, v), _)| {
+                                filter::is_same_label(self.model, key, &field.field) && *v == value
+                            })
+                            .flat_map(|(_, ids)| ids.iter().copied())
+                            .collect();
+                        candidates = candidates.intersection(&indexed).copied().collect();
+                    }
+                }
+            }
+        }
+
+        Ok(candidates
+            .into_iter()
+            .map(|i| &self.resources[i])
+            .filter(|r| self.mismatches(&criteria, r).is_empty())
+            .collect())
+    }
+
+    /// Checks every resource of the queried type, explaining why it matched or not
+    pub fn explain(&self, query: &Query) -> Result<Vec<Candidate<'_>>, String> {
+        let (criteria, _) = self.criteria(query)?;
+        Ok(self
+            .resources
+            .iter()
+            .filter(|r| criteria.type_id.as_ref().is_none_or(|t| t == &r.type_id))
+            .map(|resource| Candidate {
+                resource,
+                mismatches: self.mismatches(&criteria, resource),
+            })
+            .collect())
+    }
+
+    /// Returns resources of other types that would otherwise match the query, to tell apart references of a wrong type
+    /// from dangling ones
+    pub fn find_other_types(&self, query: &Query) -> Result<Vec<&StoredResource>, String> {
+        let (mut criteria, _) = self.criteria(query)?;
+        let type_id = criteria.type_id.take();
+        Ok(self
+            .resources
+            .iter()
+            .filter(|r| type_id.as_ref() != Some(&r.type_id))
+            .filter(|r| self.mismatches(&criteria, r).is_empty())
+            .collect())
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Resolves a short type name like `SecretSet` or a full type URI into a resource type
+    pub fn resolve_type(&self, type_ref: &str) -> Result<TypeId, String> {
+        let is_resource =
+            |id: &&TypeId| matches!(self.model.types[*id].metatype(), MetaType::Resource);
+
+        if type_ref.contains("://") {
+            let id = TypeId::new(SchemaId::new(type_ref));
+            return match self.model.types.get(&id) {
+                Some(_) if is_resource(&&id) => Ok(id),
+                Some(_) => Err(format!("{type_ref} is not a resource type")),
+                None => Err(format!("Unknown type {type_ref}")),
+            };
+        }
+
+        let candidates: Vec<_> = self
+            .model
+            .types
+            .keys()
+            .filter(is_resource)
+            .filter(|id| id.name() == type_ref)
+            .collect();
+        match candidates[..] {
+            [id] => Ok(id.clone()),
+            [] => Err(format!("Unknown resource type `{type_ref}`")),
+            _ => Err(format!(
+                "Ambiguous resource type `{type_ref}`, use one of: {}",
+                candidates
+                    .iter()
+                    .map(|id| id.schema_id().as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )),
+        }
+    }
+
+    // Returns the criteria and the value path of a `ValueRef`
+    fn to_stored(&self, path: Option<PathBuf>, manifest: Value) -> Result<StoredResource, String> {
+        let type_id = manifest_type(self.model, &manifest)?;
+        if !matches!(self.model.types[&type_id].metatype(), MetaType::Resource) {
+            return Err(format!(
+                "{} is not a resource manifest",
+                type_id.schema_id()
+            ));
+        }
+
+        let headers = &manifest["headers"];
+        let Some(name) = headers.get("name").and_then(Value::as_str) else {
+            return Err("Resource has no name".to_string());
+        };
+        Ok(StoredResource {
+            path,
+            id: headers
+                .get("id")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            did: manifest
+                .pointer("/spec/did")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            name: name.to_string(),
+            account: headers.get("account").map(AccountRef::parse).transpose()?,
+            type_id,
+            manifest,
+        })
+    }
+
+    fn criteria(&self, query: &Query) -> Result<(Criteria, Option<String>), String> {
+        let (value, default_type, is_selector) = match query {
+            Query::Selector(v) => (v, None, true),
+            Query::Ref {
+                reference,
+                default_type,
+            } => (reference, default_type.as_deref(), false),
+            Query::ValueRef(v) => (v, None, false),
+        };
+
+        let mut criteria = Criteria::default();
+        let path;
+        let type_ref;
+        let name;
+
+        match value {
+            // Dataset references can also be DIDs, which are unique across types and accounts
+            Value::String(s) if s.starts_with("did:") => {
+                type_ref = None;
+                criteria.did = Some(s.clone());
+                name = None;
+                path = None;
+            }
+            Value::String(s) => {
+                let (short_type, account, short_name, short_path) = split_short_form(s);
+                type_ref = short_type.map(str::to_string);
+                criteria.account = account
+                    .map(|a| AccountRef::parse(&Value::String(a.to_string())))
+                    .transpose()?;
+                name = Some(short_name.to_string());
+                path = short_path.map(str::to_string);
+            }
+            Value::Object(obj) => {
+                let field = |key: &str| -> Result<Option<String>, String> {
+                    match obj.get(key) {
+                        None => Ok(None),
+                        Some(Value::String(s)) => Ok(Some(s.clone())),
+                        Some(v) => Err(format!("Expected a string in `{key}` but got {v}")),
+                    }
+                };
+                type_ref = field("type")?;
+                criteria.account = obj.get("account").map(AccountRef::parse).transpose()?;
+                criteria.id = field("id")?;
+                criteria.did = field("did")?;
+                name = field("name")?;
+                path = field("path")?;
+                if let Some(labels) = obj.get("labels") {
+                    let filter = filter::parse(
+                        self.model,
+                        &TypeId::new(SchemaId::new(LABEL_FILTER)),
+                        labels,
+                    )
+                    .map_err(|e| format!("Invalid label filter: {e}"))?;
+                    criteria.labels = Some(filter);
+                }
+            }
+            _ => return Err(format!("Invalid reference {value}")),
+        }
+
+        if path.is_some() && !matches!(query, Query::ValueRef(_)) {
+            return Err(format!(
+                "Only value references can specify a path, got {value}"
+            ));
+        }
+
+        let Some(type_ref) = type_ref.as_deref().or(default_type) else {
+            return Err(format!(
+                "Reference {value} does not specify the resource type"
+            ));
+        };
+        criteria.type_id = Some(self.resolve_type(type_ref)?);
+
+        criteria.name = match name {
+            Some(name) if is_selector => {
+                let re = like_to_regex(&name);
+                Some(NamePattern::Like(name, re))
+            }
+            Some(name) => Some(NamePattern::Exact(name)),
+            None => None,
+        };
+
+        if !is_selector
+            && criteria.id.is_none()
+            && criteria.did.is_none()
+            && criteria.name.is_none()
+        {
+            return Err(format!(
+                "Reference {value} must specify an `id`, a `did`, or a `name`"
+            ));
+        }
+
+        Ok((criteria, path))
+    }
+
+    fn mismatches(&self, criteria: &Criteria, r: &StoredResource) -> Vec<String> {
+        let mut res = Vec::new();
+        let opt = |v: &Option<String>| v.clone().unwrap_or_else(|| "none".to_string());
+
+        if let Some(type_id) = &criteria.type_id
+            && type_id != &r.type_id
+        {
+            res.push(format!(
+                "type {} is not {}",
+                r.type_id.name(),
+                type_id.name()
+            ));
+        }
+        if let Some(id) = &criteria.id
+            && r.id.as_ref() != Some(id)
+        {
+            res.push(format!("id {} is not {id}", opt(&r.id)));
+        }
+        if let Some(did) = &criteria.did
+            && r.did.as_ref() != Some(did)
+        {
+            res.push(format!("did {} is not {did}", opt(&r.did)));
+        }
+        match &criteria.name {
+            Some(NamePattern::Exact(name)) if &r.name != name => {
+                res.push(format!("name `{}` is not `{name}`", r.name));
+            }
+            Some(NamePattern::Like(pattern, re)) if !re.is_match(&r.name) => {
+                res.push(format!("name `{}` does not match `{pattern}`", r.name));
+            }
+            _ => (),
+        }
+
+        // Stable identifiers make the account irrelevant
+        if criteria.id.is_none()
+            && criteria.did.is_none()
+            && !self.is_same_account(r.account.as_ref(), criteria.account.as_ref())
+        {
+            let show = |a: Option<&AccountRef>| match a.or(self.current_account.as_ref()) {
+                Some(a) => format!("account {a}"),
+                None => "the current account".to_string(),
+            };
+            res.push(format!(
+                "owned by {}, not {}",
+                show(r.account.as_ref()),
+                show(criteria.account.as_ref())
+            ));
+        }
+
+        if let Some(labels) = &criteria.labels
+            && !labels.matches_headers(self.model, &self.headers(r))
+        {
+            res.push("labels don't match the filter".to_string());
+        }
+
+        res
+    }
+
+    fn is_same_account(&self, a: Option<&AccountRef>, b: Option<&AccountRef>) -> bool {
+        match (
+            a.or(self.current_account.as_ref()),
+            b.or(self.current_account.as_ref()),
+        ) {
+            (None, None) => true,
+            (Some(a), Some(b)) => self.complete_account(a).is_same(&self.complete_account(b)),
+            _ => false,
+        }
+    }
+
+    // Fills in the missing identifiers from the `Account` resource in the store, if any
+    fn complete_account(&self, account: &AccountRef) -> AccountRef {
+        let Some(accounts) = self.by_type.get(&TypeId::new(SchemaId::new(ACCOUNT))) else {
+            return account.clone();
+        };
+
+        accounts
+            .iter()
+            .map(|i| &self.resources[*i])
+            .map(|r| AccountRef {
+                id: r.id.clone(),
+                did: r.did.clone(),
+                name: Some(r.name.clone()),
+            })
+            .find(|a| a.is_same(account))
+            .unwrap_or_else(|| account.clone())
+    }
+
+    // Headers in the canonical form that label filters are evaluated against
+    fn headers(&self, r: &StoredResource) -> Value {
+        let account = r
+            .account
+            .as_ref()
+            .or(self.current_account.as_ref())
+            .map(|a| self.complete_account(a));
+        json!({
+            "id": r.id,
+            "name": r.name,
+            "account": account.map(|a| json!({ "id": a.id, "did": a.did, "name": a.name })),
+            "labels": r.manifest["headers"].get("labels").cloned().unwrap_or(json!({})),
+        })
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Splits the short form `[Type:][account/]name[#path]` into its parts, where the type can be a full URI
+pub(crate) fn split_short_form(s: &str) -> (Option<&str>, Option<&str>, &str, Option<&str>) {
+    let (s, path) = match s.split_once('#') {
+        Some((s, path)) => (s, Some(path)),
+        None => (s, None),
+    };
+
+    let scheme_end = s.find("://").map(|i| i + 3).unwrap_or(0);
+    let (typ, s) = match s[scheme_end..].find(':') {
+        Some(i) => (Some(&s[..scheme_end + i]), &s[scheme_end + i + 1..]),
+        None => (None, s),
+    };
+
+    let (account, name) = match s.split_once('/') {
+        Some((account, name)) => (Some(account), name),
+        None => (None, s),
+    };
+
+    (typ, account, name, path)
+}
+
+// SQL `LIKE` pattern where `%` matches any sequence of characters, `_` a single character, and `\` escapes them
+fn like_to_regex(pattern: &str) -> regex::Regex {
+    let mut re = String::from("^");
+    let mut chars = pattern.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '%' => re.push_str(".*"),
+            '_' => re.push('.'),
+            '\\' => {
+                if let Some(c) = chars.next() {
+                    re.push_str(&regex::escape(&c.to_string()));
+                }
+            }
+            c => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    re.push('$');
+    regex::Regex::new(&re).unwrap()
+}
+
+pub(crate) fn is_uuid(s: &str) -> bool {
+    let groups: Vec<_> = s.split('-').map(str::len).collect();
+    groups == [8, 4, 4, 4, 12] && s.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
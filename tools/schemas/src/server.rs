@@ -0,0 +1,1512 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{BufRead, BufReader, ErrorKind, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant, SystemTime};
+
+use indexmap::IndexMap;
+use serde_json::{Value, json};
+
+use crate::api::{self, ApiError, Operation, ResourceApi};
+use crate::bundle::VALUE_REF;
+use crate::canonicalization::canonicalize_manifest;
+use crate::json_schema::SchemaId;
+use crate::merge::{self, ConflictReason};
+use crate::model::{MetaType, Model, Type, TypeDefinition, TypeId};
+use crate::resolve::{AccountRef, ResourceStore, is_uuid, split_short_form};
+use crate::status;
+use crate::validation::{self, Severity, child_path, manifest_type, resolve_variant};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const ACCOUNT: &str = "https://opendatafabric.org/schemas/auth/v1alpha1/Account";
+const ACCOUNT_REF: &str = "https://opendatafabric.org/schemas/auth/v1alpha1/AccountRef";
+const ACCOUNT_HANDLE: &str = "https://opendatafabric.org/schemas/auth/v1alpha1/AccountHandle";
+const RESOURCE_HEADERS: &str =
+    "https://opendatafabric.org/schemas/resource/v1alpha1/ResourceHeaders";
+const RESOURCE_STATUS: &str = "https://opendatafabric.org/schemas/resource/v1alpha1/ResourceStatus";
+
+/// Largest request body in bytes accepted over HTTP, larger requests are rejected with `413`
+pub const MAX_BODY_SIZE: usize = 16 * 1024 * 1024;
+
+/// Largest request line and headers in bytes accepted over HTTP, larger requests are rejected with `431`
+pub const MAX_HEAD_SIZE: usize = 64 * 1024;
+
+/// Largest number of headers accepted over HTTP, requests with more are rejected with `431`
+pub const MAX_HEADERS: usize = 100;
+
+/// Time to wait for every read of a request over HTTP, slower requests are rejected with `408`
+pub const READ_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Largest number of HTTP connections served at once, including watches
+pub const MAX_CONNECTIONS: usize = 64;
+
+/// Number of latest events kept for watches to resume from, older ones are rejected with `410`
+pub const MAX_EVENTS: usize = 10_000;
+
+/// File in the directory of the server that holds the sequence number of the latest event
+const SEQUENCE_FILE: &str = "sequence";
+
+/// Kind of change to a resource
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventType {
+    Added,
+    Modified,
+    Deleted,
+}
+
+impl EventType {
+    pub const ALL: [EventType; 3] = [EventType::Added, EventType::Modified, EventType::Deleted];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventType::Added => "Added",
+            EventType::Modified => "Modified",
+            EventType::Deleted => "Deleted",
+        }
+    }
+}
+
+/// Change to a resource streamed by a [`Watch`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Event {
+    /// Position of the change in the log of the server that can be passed as `since` to resume a watch
+    pub sequence: u64,
+    pub typ: EventType,
+    /// Canonical resource after the change, with `deletedAt` set for deletions
+    pub resource: Value,
+}
+
+impl Event {
+    pub fn to_value(&self) -> Value {
+        json!({
+            "type": self.typ.as_str(),
+            "sequence": self.sequence,
+            "resource": self.resource,
+        })
+    }
+}
+
+/// Request to the resource API decoupled from the transport
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub method: String,
+    /// Percent-encoded path without the query string
+    pub path: String,
+    pub query: BTreeMap<String, String>,
+    pub body: Option<Value>,
+}
+
+impl Request {
+    /// Parses the request target (path and query string) and a JSON or YAML body
+    pub fn parse(method: &str, target: &str, body: &[u8]) -> Result<Self, ApiError> {
+        let (path, query) = target.split_once('?').unwrap_or((target, ""));
+        let query = query
+            .split('&')
+            .filter(|p| !p.is_empty())
+            .map(|p| {
+                let (k, v) = p.split_once('=').unwrap_or((p, ""));
+                Ok((api::percent_decode(k)?, api::percent_decode(v)?))
+            })
+            .collect::<Result<_, ApiError>>()?;
+
+        let body = if body.iter().all(u8::is_ascii_whitespace) {
+            None
+        } else {
+            let body = std::str::from_utf8(body)
+                .map_err(|_| ApiError::new(400, "Request body is not valid UTF-8"))?;
+            Some(serde_yaml::from_str(body).map_err(|e| {
+                ApiError::new(400, format!("Failed to parse the request body: {e}"))
+            })?)
+        };
+
+        Ok(Self {
+            method: method.to_string(),
+            path: path.to_string(),
+            query,
+            body,
+        })
+    }
+}
+
+pub enum Response<'a> {
+    Json { status: u16, body: Value },
+    Watch(Box<Watch<'a>>),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Resource store of a node implementing the RFC-018 resource API in-process and over HTTP (see [`ResourceServer::serve`]),
+/// meant for testing controllers without a real node.
+///
+/// Manifests are validated against their schemas, references in them are resolved into handles of the stored
+/// resources, and the result is merged into the live state as described in [`merge::apply`]. The server assigns
+/// identity and timestamps, keeps the status that controllers report, and streams every change to watchers. When
+/// created with a directory, resources are persisted in it as `<context>/<version>/<type>/<id>.json` along with the
+/// manifests applied last.
+pub struct ResourceServer {
+    model: Model,
+    apis: Vec<ResourceApi>,
+    /// `AccountHandle` of the auth subject
+    account: Value,
+    dir: Option<PathBuf>,
+    clock: fn() -> String,
+    state: Mutex<State>,
+    changed: Condvar,
+}
+
+#[derive(Default)]
+struct State {
+    /// Resources by ID in the order of creation
+    resources: IndexMap<String, Entry>,
+    /// Latest [`MAX_EVENTS`] events
+    events: VecDeque<Event>,
+    /// Sequence number of the latest event, which continues across restarts when the server is backed by a directory
+    sequence: u64,
+}
+
+impl State {
+    // Events after the sequence number, failing if some of them were already dropped from the log
+    fn events_after(&self, sequence: u64) -> Result<impl Iterator<Item = &Event>, ApiError> {
+        if sequence > self.sequence {
+            return Err(ApiError::new(
+                400,
+                format!(
+                    "Sequence {sequence} is ahead of the latest event {}",
+                    self.sequence
+                ),
+            ));
+        }
+        let first = self.sequence + 1 - self.events.len() as u64;
+        if sequence + 1 < first {
+            return Err(ApiError::new(
+                410,
+                format!(
+                    "Events after sequence {sequence} are no longer available, the oldest one is {first}"
+                ),
+            ));
+        }
+        Ok(self.events.range((sequence + 1 - first) as usize..))
+    }
+}
+
+struct Entry {
+    /// Canonical state of the resource
+    resource: Value,
+    /// Manifest applied last with references resolved, which is what the live state is merged against
+    last_applied: Option<Value>,
+}
+
+impl ResourceServer {
+    /// Creates a server acting on behalf of the account with the specified `AccountHandle`, loading the resources
+    /// persisted in the directory if any
+    pub fn open(model: Model, dir: Option<&Path>, account: Value) -> Result<Self, String> {
+        let diagnostics = validation::validate(
+            &model,
+            &TypeId::new(SchemaId::new(ACCOUNT_HANDLE)),
+            &account,
+        );
+        if let Some(d) = diagnostics.iter().find(|d| d.severity == Severity::Error) {
+            return Err(format!("Invalid account handle: {d}"));
+        }
+
+        let server = Self {
+            apis: api::resource_apis(&model),
+            model,
+            account,
+            dir: dir.map(Path::to_path_buf),
+            clock: utc_now,
+            state: Mutex::new(State::default()),
+            changed: Condvar::new(),
+        };
+        if let Some(dir) = dir {
+            server.load(dir)?;
+        }
+        Ok(server)
+    }
+
+    /// Replaces the source of timestamps, e.g. to make them deterministic in tests
+    pub fn with_clock(mut self, clock: fn() -> String) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    pub fn model(&self) -> &Model {
+        &self.model
+    }
+
+    pub fn apis(&self) -> &[ResourceApi] {
+        &self.apis
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Creates a resource from a manifest, failing if the account already has a resource of the type with the name
+    pub fn create(&self, manifest: &Value, account: Option<&str>) -> Result<Value, ApiError> {
+        let mut state = self.lock();
+        let account = self.acting_account(&state, account)?;
+        let desired = self.resolve_manifest(&state, manifest, &account)?;
+
+        if let Some(live) = self.find_live(&state, &desired)? {
+            return Err(ApiError::new(
+                409,
+                format!("Resource {} already exists", describe(&live.resource)),
+            ));
+        }
+        self.commit(&mut state, None, desired, None, false)
+            .map(|(resource, _)| resource)
+    }
+
+    /// Creates a resource or merges a manifest into the live state of the one with the same ID or name, returning it
+    /// along with whether it was created.
+    ///
+    /// Values that were changed by others since the last apply are conflicts that fail the request unless `force` is
+    /// set.
+    pub fn apply(
+        &self,
+        manifest: &Value,
+        account: Option<&str>,
+        force: bool,
+    ) -> Result<(Value, bool), ApiError> {
+        let mut state = self.lock();
+        let account = self.acting_account(&state, account)?;
+        let desired = self.resolve_manifest(&state, manifest, &account)?;
+
+        let id = self
+            .find_live(&state, &desired)?
+            .map(|e| e.resource["headers"]["id"].as_str().unwrap().to_string());
+        let created = id.is_none();
+        self.commit(&mut state, id, desired, None, force)
+            .map(|(resource, _)| (resource, created))
+    }
+
+    pub fn get(
+        &self,
+        kind: &TypeId,
+        reference: &str,
+        account: Option<&str>,
+    ) -> Result<Value, ApiError> {
+        let state = self.lock();
+        let id = self.find_by_ref(&state, kind, reference, account)?;
+        Ok(state.resources[&id].resource.clone())
+    }
+
+    /// Lists resources of the type matching a `ResourceSelector`, defaulting to all resources of the account
+    pub fn list(
+        &self,
+        kind: &TypeId,
+        selector: Option<&Value>,
+        account: Option<&str>,
+    ) -> Result<Vec<Value>, ApiError> {
+        let state = self.lock();
+        let account = self.acting_account(&state, account)?;
+        let selector = self.selector(&state, kind, selector, &account)?;
+        self.select(&state, &selector, &account)
+    }
+
+    /// Replaces the desired state of a resource with a manifest, which can also rename it.
+    ///
+    /// The manifest is not recorded as applied, so that a later apply reports the values it has changed as conflicts,
+    /// same as changes made by other parties.
+    pub fn update(
+        &self,
+        kind: &TypeId,
+        reference: &str,
+        manifest: &Value,
+        account: Option<&str>,
+    ) -> Result<Value, ApiError> {
+        let mut state = self.lock();
+        let id = self.find_by_ref(&state, kind, reference, account)?;
+        let account = self.acting_account(&state, account)?;
+        let desired = self.resolve_manifest(&state, manifest, &account)?;
+
+        if desired["$schema"] != state.resources[&id].resource["$schema"] {
+            return Err(ApiError::new(
+                400,
+                format!(
+                    "Resource {} can't be updated with a manifest of type {}",
+                    describe(&state.resources[&id].resource),
+                    desired["$schema"]
+                ),
+            ));
+        }
+        if let Some(other) = desired.pointer("/headers/id").and_then(Value::as_str)
+            && other != id
+        {
+            return Err(ApiError::new(
+                400,
+                format!("Manifest of resource {other} can't update resource {id}"),
+            ));
+        }
+        if let Some(other) = self.find_live(&state, &desired)?
+            && other.resource["headers"]["id"] != id
+        {
+            return Err(ApiError::new(
+                409,
+                format!("Resource {} already exists", describe(&other.resource)),
+            ));
+        }
+
+        // Treating the live state as applied last makes the manifest replace it rather than merge into it
+        let live = state.resources[&id].resource.clone();
+        self.commit(&mut state, Some(id), desired, Some(live), true)
+            .map(|(resource, _)| resource)
+    }
+
+    /// Replaces the status of a resource, validating its conditions against the ones declared for the type. Status
+    /// changes don't affect the generation.
+    pub fn update_status(
+        &self,
+        kind: &TypeId,
+        reference: &str,
+        status: &Value,
+        account: Option<&str>,
+    ) -> Result<Value, ApiError> {
+        let mut state = self.lock();
+        let id = self.find_by_ref(&state, kind, reference, account)?;
+
+        let mut resource = state.resources[&id].resource.clone();
+        resource["status"] = status.clone();
+
+        let mut diagnostics: Vec<_> = validation::validate(
+            &self.model,
+            &TypeId::new(SchemaId::new(RESOURCE_STATUS)),
+            status,
+        )
+        .into_iter()
+        .map(|mut d| {
+            d.path = child_path("status", &d.path)
+                .trim_end_matches('.')
+                .to_string();
+            d
+        })
+        .collect();
+        diagnostics.extend(
+            status::validate_status(&self.model, kind, &resource)
+                .map_err(|e| ApiError::new(400, e))?
+                .into_iter()
+                .filter(|d| d.path != "status.phase" || d.severity == Severity::Error),
+        );
+        check_diagnostics("Status is invalid", &diagnostics)?;
+
+        if resource["status"] == state.resources[&id].resource["status"] {
+            return Ok(resource);
+        }
+        resource["headers"]["updatedAt"] = (self.clock)().into();
+        self.store_resource(&mut state, resource.clone(), None)?;
+        self.emit(&mut state, EventType::Modified, resource.clone())?;
+        Ok(resource)
+    }
+
+    /// Deletes a resource, returning its final state with `deletedAt` set
+    pub fn delete(
+        &self,
+        kind: &TypeId,
+        reference: &str,
+        account: Option<&str>,
+    ) -> Result<Value, ApiError> {
+        let mut state = self.lock();
+        let id = self.find_by_ref(&state, kind, reference, account)?;
+
+        // Files are removed first, so that the resource stays in place if that fails
+        if let Some(path) = self.resource_path(&state.resources[&id].resource)? {
+            for path in [
+                path.with_extension("json"),
+                path.with_extension("last-applied.json"),
+            ] {
+                if path.exists() {
+                    std::fs::remove_file(&path).map_err(|e| {
+                        ApiError::new(500, format!("Failed to delete {}: {e}", path.display()))
+                    })?;
+                }
+            }
+        }
+        let Entry { mut resource, .. } = state.resources.shift_remove(&id).unwrap();
+        resource["headers"]["deletedAt"] = (self.clock)().into();
+        self.emit(&mut state, EventType::Deleted, resource.clone())?;
+        Ok(resource)
+    }
+
+    /// Streams changes to resources of the type matching a selector.
+    ///
+    /// Without `since` the stream starts with an `Added` event for every matching resource, otherwise it resumes after
+    /// the event with that sequence number. Resources that stop matching the selector after a change are not reported.
+    /// Only the latest [`MAX_EVENTS`] events are kept, so resuming from an older one fails with `410`.
+    pub fn watch(
+        &self,
+        kind: &TypeId,
+        selector: Option<&Value>,
+        account: Option<&str>,
+        since: Option<u64>,
+    ) -> Result<Watch<'_>, ApiError> {
+        let state = self.lock();
+        let account = self.acting_account(&state, account)?;
+        let selector = self.selector(&state, kind, selector, &account)?;
+        let initial = match since {
+            None => self.select(&state, &selector, &account)?,
+            Some(_) => Vec::new(),
+        };
+
+        let sequence = since.unwrap_or(state.sequence);
+        // Fails early for sequence numbers that can't be resumed from
+        let _ = state.events_after(sequence)?;
+
+        Ok(Watch {
+            server: self,
+            store: ResourceStore::new(&self.model, AccountRef::parse(&account).ok()),
+            selector,
+            sequence,
+            initial: initial
+                .into_iter()
+                .map(|resource| Event {
+                    sequence,
+                    typ: EventType::Added,
+                    resource,
+                })
+                .collect(),
+        })
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    /// Routes a request to an operation, turning errors into responses
+    pub fn handle(&self, request: &Request) -> Response<'_> {
+        self.try_handle(request).unwrap_or_else(|e| Response::Json {
+            status: e.status,
+            body: e.to_value(),
+        })
+    }
+
+    fn try_handle(&self, request: &Request) -> Result<Response<'_>, ApiError> {
+        let route = api::route(&self.apis, &request.method, &request.path)?;
+        let kind = &route.api.kind;
+        let reference = route.reference.as_deref().unwrap_or_default();
+        let account = request.query.get("account").map(String::as_str);
+        let flag = |name: &str| {
+            request
+                .query
+                .get(name)
+                .is_some_and(|v| v.is_empty() || v == "true")
+        };
+
+        let body = || {
+            request
+                .body
+                .as_ref()
+                .ok_or_else(|| ApiError::new(400, "Request body is required"))
+        };
+        let manifest = || {
+            let manifest = body()?;
+            let manifest_kind =
+                manifest_type(&self.model, manifest).map_err(|e| ApiError::new(400, e))?;
+            if &manifest_kind != kind {
+                return Err(ApiError::new(
+                    400,
+                    format!(
+                        "Expected a manifest of {} but got {}",
+                        kind.schema_id(),
+                        manifest_kind.schema_id()
+                    ),
+                ));
+            }
+            Ok(manifest)
+        };
+
+        let (status, body) = match route.operation {
+            Operation::List => {
+                let selector = request
+                    .query
+                    .get("selector")
+                    .map(|s| {
+                        serde_yaml::from_str::<Value>(s).map_err(|e| {
+                            ApiError::new(400, format!("Failed to parse the selector: {e}"))
+                        })
+                    })
+                    .transpose()?;
+                if flag("watch") {
+                    let since = request
+                        .query
+                        .get("since")
+                        .map(|s| {
+                            s.parse().map_err(|_| {
+                                ApiError::new(400, format!("Invalid sequence number {s}"))
+                            })
+                        })
+                        .transpose()?;
+                    return Ok(Response::Watch(Box::new(self.watch(
+                        kind,
+                        selector.as_ref(),
+                        account,
+                        since,
+                    )?)));
+                }
+                let items = self.list(kind, selector.as_ref(), account)?;
+                (200, json!({ "items": items }))
+            }
+            Operation::Create => (201, self.create(manifest()?, account)?),
+            Operation::Apply => match self.apply(manifest()?, account, flag("force"))? {
+                (resource, true) => (201, resource),
+                (resource, false) => (200, resource),
+            },
+            Operation::Get => (200, self.get(kind, reference, account)?),
+            Operation::Update => (200, self.update(kind, reference, manifest()?, account)?),
+            Operation::UpdateStatus => {
+                (200, self.update_status(kind, reference, body()?, account)?)
+            }
+            Operation::Delete => (200, self.delete(kind, reference, account)?),
+        };
+        Ok(Response::Json { status, body })
+    }
+
+    /// Serves the resource API over HTTP/1.1, handling every connection in its own thread until the listener fails.
+    ///
+    /// Request bodies must specify their `Content-Length`, which is limited to [`MAX_BODY_SIZE`]. Request line and
+    /// headers are limited to [`MAX_HEAD_SIZE`] bytes and [`MAX_HEADERS`] headers, and every read has to complete
+    /// within [`READ_TIMEOUT`]. At most [`MAX_CONNECTIONS`] connections are served at once, others are rejected with
+    /// `503`.
+    pub fn serve(&self, listener: &TcpListener) -> std::io::Result<()> {
+        let connections = AtomicUsize::new(0);
+        std::thread::scope(|s| {
+            for stream in listener.incoming() {
+                let mut stream = stream?;
+                if connections.fetch_add(1, Ordering::AcqRel) >= MAX_CONNECTIONS {
+                    connections.fetch_sub(1, Ordering::AcqRel);
+                    let e = ApiError::new(503, "Too many connections");
+                    let _ = stream
+                        .set_write_timeout(Some(READ_TIMEOUT))
+                        .and_then(|_| write_json(&mut stream, e.status, &e.to_value()));
+                    continue;
+                }
+
+                let connections = &connections;
+                s.spawn(move || {
+                    if let Err(e) = self.serve_connection(stream) {
+                        eprintln!("warning: Connection failed: {e}");
+                    }
+                    connections.fetch_sub(1, Ordering::AcqRel);
+                });
+            }
+            Ok(())
+        })
+    }
+
+    fn serve_connection(&self, mut stream: TcpStream) -> std::io::Result<()> {
+        stream.set_read_timeout(Some(READ_TIMEOUT))?;
+        stream.set_write_timeout(Some(READ_TIMEOUT))?;
+        let mut reader = BufReader::new(stream.try_clone()?);
+
+        let (method, target, request) = match read_head(&mut reader) {
+            Ok(head) => {
+                let request = read_body(&mut reader, &head)
+                    .and_then(|body| Request::parse(&head.method, &head.target, &body));
+                (head.method, head.target, request)
+            }
+            Err(e) => (String::new(), String::new(), Err(e)),
+        };
+        let response = match request {
+            Ok(request) => self.handle(&request),
+            Err(e) => Response::Json {
+                status: e.status,
+                body: e.to_value(),
+            },
+        };
+
+        match response {
+            Response::Json { status, body } => {
+                eprintln!("{method} {target} {status}");
+                write_json(&mut stream, status, &body)
+            }
+            Response::Watch(mut watch) => {
+                eprintln!("{method} {target} 200 (watching)");
+                write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/x-ndjson\r\nConnection: close\r\n\r\n"
+                )?;
+                stream.flush()?;
+
+                // Stream ends when the client disconnects, which is detected while waiting for events
+                stream.set_read_timeout(Some(Duration::from_millis(1)))?;
+                loop {
+                    match watch.next_timeout(Duration::from_secs(1)) {
+                        Ok(Some(event)) => {
+                            serde_json::to_writer(&mut stream, &event.to_value())?;
+                            stream.write_all(b"\n")?;
+                            stream.flush()?;
+                        }
+                        Ok(None) => {
+                            if let Ok(0) = stream.peek(&mut [0]) {
+                                return Ok(());
+                            }
+                        }
+                        // Watcher fell behind the event log and has to start over
+                        Err(e) => {
+                            serde_json::to_writer(
+                                &mut stream,
+                                &json!({ "type": "Error", "error": e.to_value() }),
+                            )?;
+                            stream.write_all(b"\n")?;
+                            return stream.flush();
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    ////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+    // State is only modified by inserting or removing whole entries and appending events, so a panic while the lock
+    // is held can't leave it half-updated and the poisoning is safe to ignore
+    fn lock(&self) -> MutexGuard<'_, State> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    // Merges the desired state into the resource with the ID, if any, and stores the result, returning it along with
+    // whether it has changed. Desired state is recorded as applied last unless it is merged against another one.
+    fn commit(
+        &self,
+        state: &mut State,
+        id: Option<String>,
+        desired: Value,
+        last_applied: Option<Value>,
+        force: bool,
+    ) -> Result<(Value, bool), ApiError> {
+        let entry = id.as_ref().map(|id| &state.resources[id]);
+        let live = entry.map(|e| e.resource.clone());
+        let is_apply = last_applied.is_none();
+        let last_applied = last_applied.or_else(|| entry.and_then(|e| e.last_applied.clone()));
+
+        let applied = merge::apply(&self.model, last_applied.as_ref(), live.as_ref(), &desired)
+            .map_err(|e| ApiError::new(400, e))?;
+
+        let conflicts: Vec<_> = applied
+            .conflicts
+            .iter()
+            .filter(|c| c.reason == ConflictReason::Modified)
+            .map(ToString::to_string)
+            .collect();
+        if !conflicts.is_empty() && !force {
+            return Err(ApiError::new(
+                409,
+                format!(
+                    "Apply failed with {} conflicts, use `force` to overwrite",
+                    conflicts.len()
+                ),
+            )
+            .details(conflicts));
+        }
+
+        let now = (self.clock)();
+        let mut resource = applied.resource;
+        let Some(headers) = resource["headers"].as_object_mut() else {
+            return Err(ApiError::new(400, "Manifest `headers` must be an object"));
+        };
+        for key in ["labels", "annotations"] {
+            headers.entry(key).or_insert_with(|| json!({}));
+        }
+        if live.is_none() {
+            headers.insert("id".to_string(), new_id().into());
+            headers.insert("createdAt".to_string(), now.clone().into());
+        }
+        if applied.changed {
+            headers.insert("updatedAt".to_string(), now.into());
+        }
+
+        if let Some(resource) = resource.as_object_mut() {
+            resource
+                .entry("status")
+                .or_insert_with(|| json!({ "conditions": {} }));
+        }
+        let phase = status::derive_phase(&resource);
+        resource["status"]["phase"] = phase.as_str().into();
+        self.sort_properties(&mut resource);
+
+        if live.as_ref() == Some(&resource) {
+            return Ok((resource, false));
+        }
+
+        self.store_resource(state, resource.clone(), is_apply.then_some(desired))?;
+        let typ = if live.is_none() {
+            EventType::Added
+        } else {
+            EventType::Modified
+        };
+        self.emit(state, typ, resource.clone())?;
+        Ok((resource, true))
+    }
+
+    // Merging appends the populated headers, which are put back into the order of the schemas for readability
+    fn sort_properties(&self, resource: &mut Value) {
+        let Some(TypeDefinition::Struct(headers_type)) = self
+            .model
+            .types
+            .get(&TypeId::new(SchemaId::new(RESOURCE_HEADERS)))
+        else {
+            panic!("Unknown type {RESOURCE_HEADERS}");
+        };
+
+        let sort = |value: &mut Value, order: &[String]| {
+            let obj = value.as_object_mut().unwrap();
+            let mut sorted = serde_json::Map::new();
+            for key in order {
+                if let Some(v) = obj.remove(key) {
+                    sorted.insert(key.clone(), v);
+                }
+            }
+            sorted.append(obj);
+            *obj = sorted;
+        };
+        let order: Vec<_> = headers_type
+            .fields
+            .values()
+            .map(|f| f.json_name())
+            .collect();
+        sort(&mut resource["headers"], &order);
+        sort(&mut resource["status"], &["phase".to_string()]);
+    }
+
+    fn store_resource(
+        &self,
+        state: &mut State,
+        resource: Value,
+        last_applied: Option<Value>,
+    ) -> Result<(), ApiError> {
+        let id = resource["headers"]["id"].as_str().unwrap().to_string();
+        let last_applied = last_applied.or_else(|| {
+            state
+                .resources
+                .get(&id)
+                .and_then(|e| e.last_applied.clone())
+        });
+
+        if let Some(path) = self.resource_path(&resource)? {
+            let write = |path: &Path, value: &Value| {
+                std::fs::create_dir_all(path.parent().unwrap())
+                    .and_then(|_| {
+                        std::fs::write(path, serde_json::to_string_pretty(value).unwrap())
+                    })
+                    .map_err(|e| {
+                        ApiError::new(500, format!("Failed to write {}: {e}", path.display()))
+                    })
+            };
+            write(&path.with_extension("json"), &resource)?;
+            if let Some(last_applied) = &last_applied {
+                write(&path.with_extension("last-applied.json"), last_applied)?;
+            }
+        }
+
+        state.resources.insert(
+            id,
+            Entry {
+                resource,
+                last_applied,
+            },
+        );
+        Ok(())
+    }
+
+    // Appends an event to the log and persists its sequence number, so that numbering continues after a restart and
+    // watches can't resume from a sequence number of another run
+    fn emit(&self, state: &mut State, typ: EventType, resource: Value) -> Result<(), ApiError> {
+        state.sequence += 1;
+        if state.events.len() == MAX_EVENTS {
+            state.events.pop_front();
+        }
+        state.events.push_back(Event {
+            sequence: state.sequence,
+            typ,
+            resource,
+        });
+        self.changed.notify_all();
+
+        if let Some(dir) = &self.dir {
+            let path = dir.join(SEQUENCE_FILE);
+            std::fs::write(&path, state.sequence.to_string()).map_err(|e| {
+                ApiError::new(500, format!("Failed to write {}: {e}", path.display()))
+            })?;
+        }
+        Ok(())
+    }
+
+    // Path of the persisted resource without the extension
+    fn resource_path(&self, resource: &Value) -> Result<Option<PathBuf>, ApiError> {
+        let Some(dir) = &self.dir else {
+            return Ok(None);
+        };
+        let api = self
+            .apis
+            .iter()
+            .find(|api| resource["$schema"] == api.kind.schema_id().as_str())
+            .ok_or_else(|| {
+                ApiError::new(
+                    400,
+                    format!("Unknown resource type {}", resource["$schema"]),
+                )
+            })?;
+        let id = resource["headers"]["id"].as_str().ok_or_else(|| {
+            ApiError::new(500, format!("Resource {} has no ID", describe(resource)))
+        })?;
+        Ok(Some(dir.join(api.path.trim_start_matches('/')).join(id)))
+    }
+
+    fn load(&self, dir: &Path) -> Result<(), String> {
+        let mut state = self.lock();
+
+        let sequence_path = dir.join(SEQUENCE_FILE);
+        if sequence_path.exists() {
+            state.sequence = std::fs::read_to_string(&sequence_path)
+                .map_err(|e| e.to_string())
+                .and_then(|s| {
+                    s.trim()
+                        .parse()
+                        .map_err(|e: std::num::ParseIntError| e.to_string())
+                })
+                .map_err(|e| format!("Failed to read {}: {e}", sequence_path.display()))?;
+        }
+
+        let mut loaded = Vec::new();
+        for api in &self.apis {
+            let pattern = format!("{}{}/*.json", dir.display(), api.path);
+            let paths = glob::glob(&pattern)
+                .map_err(|e| format!("Invalid directory {}: {e}", dir.display()))?;
+            for path in paths.filter_map(Result::ok) {
+                let name = path.file_name().unwrap().to_string_lossy();
+                if name.ends_with(".last-applied.json") {
+                    continue;
+                }
+
+                let read = |path: &Path| -> Result<Value, String> {
+                    let content = std::fs::read_to_string(path)
+                        .map_err(|e| format!("Failed to read {}: {e}", path.display()))?;
+                    serde_json::from_str(&content)
+                        .map_err(|e| format!("Failed to parse {}: {e}", path.display()))
+                };
+                let resource = read(&path)?;
+                let last_applied_path = path.with_extension("last-applied.json");
+                let last_applied = last_applied_path
+                    .exists()
+                    .then(|| read(&last_applied_path))
+                    .transpose()?;
+                loaded.push((resource, last_applied));
+            }
+        }
+
+        let created_at = |r: &Value| {
+            let headers = &r["headers"];
+            (headers["createdAt"].to_string(), headers["id"].to_string())
+        };
+        loaded.sort_by_key(|(r, _)| created_at(r));
+        for (resource, last_applied) in loaded {
+            let id = resource["headers"]["id"]
+                .as_str()
+                .unwrap_or_default()
+                .to_string();
+            state.resources.insert(
+                id,
+                Entry {
+                    resource,
+                    last_applied,
+                },
+            );
+        }
+        Ok(())
+    }
+
+    // Resource store over the live state that resolves references within the account
+    fn store<'a>(&'a self, state: &State, account: &Value) -> ResourceStore<'a> {
+        let mut store = ResourceStore::new(&self.model, AccountRef::parse(account).ok());
+        for entry in state.resources.values() {
+            store.insert(None, entry.resource.clone()).unwrap();
+        }
+        store
+    }
+
+    // Handle of the account that the request acts upon
+    fn acting_account(&self, state: &State, account: Option<&str>) -> Result<Value, ApiError> {
+        match account {
+            None | Some("") => Ok(self.account.clone()),
+            Some(account) => {
+                let account = AccountRef::parse(&Value::String(account.to_string()))
+                    .map_err(|e| ApiError::new(400, e))?;
+                self.account_handle(state, &account)
+            }
+        }
+    }
+
+    // Accounts other than the auth subject are looked up among the `Account` resources, which must have a DID
+    fn account_handle(&self, state: &State, account: &AccountRef) -> Result<Value, ApiError> {
+        if AccountRef::parse(&self.account).unwrap().is_same(account) {
+            return Ok(self.account.clone());
+        }
+
+        for entry in state.resources.values() {
+            let r = &entry.resource;
+            if r["$schema"] != ACCOUNT {
+                continue;
+            }
+            let candidate = AccountRef {
+                id: r["headers"]["id"].as_str().map(str::to_string),
+                did: r
+                    .pointer("/spec/did")
+                    .and_then(Value::as_str)
+                    .map(str::to_string),
+                name: r["headers"]["name"].as_str().map(str::to_string),
+            };
+            if !candidate.is_same(account) {
+                continue;
+            }
+            let Some(did) = candidate.did else {
+                return Err(ApiError::new(
+                    400,
+                    format!("Account {account} does not have a DID"),
+                ));
+            };
+            return Ok(json!({ "id": candidate.id, "did": did, "name": candidate.name }));
+        }
+
+        Err(ApiError::new(404, format!("Unknown account {account}")))
+    }
+
+    // Validates a manifest and resolves references in it into handles, as expected by `merge::apply`
+    fn resolve_manifest(
+        &self,
+        state: &State,
+        manifest: &Value,
+        account: &Value,
+    ) -> Result<Value, ApiError> {
+        let mut manifest = canonicalize_manifest(&self.model, manifest.clone());
+
+        let diagnostics = validation::validate_manifest(&self.model, &manifest);
+        check_diagnostics("Manifest is invalid", &diagnostics)?;
+
+        let kind = manifest_type(&self.model, &manifest).map_err(|e| ApiError::new(400, e))?;
+        if !self.apis.iter().any(|api| api.kind == kind) {
+            return Err(ApiError::new(
+                400,
+                format!("{} is not a resource manifest", kind.schema_id()),
+            ));
+        }
+
+        let store = self.store(state, account);
+        let mut resolver = Resolver {
+            server: self,
+            state,
+            store: &store,
+            problems: Vec::new(),
+        };
+        resolver.resolve_type_def(&kind, None, &mut manifest, "");
+        if !resolver.problems.is_empty() {
+            return Err(
+                ApiError::new(400, "Manifest has unresolved references").details(resolver.problems)
+            );
+        }
+
+        let headers = manifest["headers"].as_object_mut().unwrap();
+        headers.entry("account").or_insert_with(|| account.clone());
+        Ok(manifest)
+    }
+
+    // Live resource with the ID of the manifest, or of its type and name within its account
+    fn find_live<'s>(
+        &self,
+        state: &'s State,
+        desired: &Value,
+    ) -> Result<Option<&'s Entry>, ApiError> {
+        let headers = &desired["headers"];
+        if let Some(id) = headers.get("id").and_then(Value::as_str) {
+            return match state.resources.get(id) {
+                Some(entry) if entry.resource["$schema"] == desired["$schema"] => Ok(Some(entry)),
+                _ => Err(ApiError::new(
+                    404,
+                    format!(
+                        "Resource {id} of type {} does not exist",
+                        desired["$schema"].as_str().unwrap_or_default()
+                    ),
+                )),
+            };
+        }
+
+        Ok(state.resources.values().find(|e| {
+            let r = &e.resource;
+            r["$schema"] == desired["$schema"]
+                && r["headers"]["name"] == headers["name"]
+                && r["headers"]["account"]["id"] == headers["account"]["id"]
+        }))
+    }
+
+    // ID of the resource of the type addressed by an ID, a DID, or a name within the account
+    fn find_by_ref(
+        &self,
+        state: &State,
+        kind: &TypeId,
+        reference: &str,
+        account: Option<&str>,
+    ) -> Result<String, ApiError> {
+        let account = self.acting_account(state, account)?;
+        let query = if is_uuid(reference) {
+            json!({ "id": reference })
+        } else {
+            Value::String(reference.to_string())
+        };
+
+        let store = self.store(state, &account);
+        let found = store
+            .resolve_ref(&query, Some(kind.schema_id().as_str()))
+            .map_err(|e| ApiError::new(400, e))?;
+        let Some(found) = found else {
+            return Err(ApiError::new(
+                404,
+                format!("Resource {}:{reference} does not exist", kind.name()),
+            ));
+        };
+        Ok(found.id.clone().unwrap())
+    }
+
+    // Selector of the listing with the type of the endpoint filled in when omitted
+    fn selector(
+        &self,
+        state: &State,
+        kind: &TypeId,
+        selector: Option<&Value>,
+        account: &Value,
+    ) -> Result<Value, ApiError> {
+        let kind_uri = kind.schema_id().as_str();
+        let selector = match selector {
+            None => json!({ "type": kind_uri }),
+            Some(Value::String(s)) if split_short_form(s).0.is_none() && !s.starts_with("did:") => {
+                Value::String(format!("{kind_uri}:{s}"))
+            }
+            Some(Value::Object(obj)) if !obj.contains_key("type") => {
+                let mut obj = obj.clone();
+                obj.insert("type".to_string(), kind_uri.into());
+                Value::Object(obj)
+            }
+            Some(selector) => selector.clone(),
+        };
+
+        let explicit = match &selector {
+            Value::String(s) => split_short_form(s).0,
+            Value::Object(obj) => obj.get("type").and_then(Value::as_str),
+            _ => None,
+        };
+        if let Some(explicit) = explicit {
+            let store = self.store(state, account);
+            let explicit = store
+                .resolve_type(explicit)
+                .map_err(|e| ApiError::new(400, e))?;
+            if &explicit != kind {
+                return Err(ApiError::new(
+                    400,
+                    format!(
+                        "Selector of {} resources can't be used to list {} resources",
+                        explicit.name(),
+                        kind.name()
+                    ),
+                ));
+            }
+        }
+        Ok(selector)
+    }
+
+    fn select(
+        &self,
+        state: &State,
+        selector: &Value,
+        account: &Value,
+    ) -> Result<Vec<Value>, ApiError> {
+        let store = self.store(state, account);
+        let found = store
+            .select(selector)
+            .map_err(|e| ApiError::new(400, format!("Invalid selector: {e}")))?;
+        Ok(found.into_iter().map(|r| r.manifest.clone()).collect())
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Stream of changes to resources, see [`ResourceServer::watch`]
+pub struct Watch<'a> {
+    server: &'a ResourceServer,
+    /// Empty store of the acting account to match events against the selector
+    store: ResourceStore<'a>,
+    selector: Value,
+    /// Sequence number of the last event in the log that was considered
+    sequence: u64,
+    initial: VecDeque<Event>,
+}
+
+impl Watch<'_> {
+    /// Waits for the next change, returning `None` if there was none within the timeout, or fails with `410` if the
+    /// watch fell behind the events kept by the server
+    pub fn next_timeout(&mut self, timeout: Duration) -> Result<Option<Event>, ApiError> {
+        if let Some(event) = self.initial.pop_front() {
+            return Ok(Some(event));
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut state = self.server.lock();
+        loop {
+            for event in state.events_after(self.sequence)? {
+                self.sequence = event.sequence;
+                if self
+                    .store
+                    .matches(&self.selector, &event.resource)
+                    .unwrap_or(false)
+                {
+                    return Ok(Some(event.clone()));
+                }
+            }
+
+            let now = Instant::now();
+            if now >= deadline {
+                return Ok(None);
+            }
+            state = self
+                .server
+                .changed
+                .wait_timeout(state, deadline - now)
+                .unwrap_or_else(PoisonError::into_inner)
+                .0;
+        }
+    }
+}
+
+impl Iterator for Watch<'_> {
+    type Item = Result<Event, ApiError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(event) = self.next_timeout(Duration::from_secs(60)).transpose() {
+                return Some(event);
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Resolver<'a> {
+    server: &'a ResourceServer,
+    state: &'a State,
+    store: &'a ResourceStore<'a>,
+    /// Dot-separated paths to the references that don't resolve and why
+    problems: Vec<String>,
+}
+
+impl Resolver<'_> {
+    fn resolve_type(
+        &mut self,
+        typ: &Type,
+        target_type: Option<&TypeId>,
+        value: &mut Value,
+        path: &str,
+    ) {
+        match typ {
+            Type::Custom(id) => self.resolve_type_def(id, target_type, value, path),
+            Type::Array(arr) => {
+                for (i, item) in value.as_array_mut().into_iter().flatten().enumerate() {
+                    let path = format!("{path}[{i}]");
+                    self.resolve_type(&arr.item_type, target_type, item, &path);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn resolve_type_def(
+        &mut self,
+        id: &TypeId,
+        target_type: Option<&TypeId>,
+        value: &mut Value,
+        path: &str,
+    ) {
+        let model = &self.server.model;
+        let Some(typ) = model.types.get(id) else {
+            panic!("Reference to unknown type {}", id.schema_id());
+        };
+
+        if matches!(typ.metatype(), MetaType::ResourceRef) {
+            match self.handle(id, target_type, value) {
+                Ok(handle) => *value = handle,
+                Err(e) => self.problems.push(format!("{path}: {e}")),
+            }
+            return;
+        }
+
+        match typ {
+            TypeDefinition::Struct(t) => {
+                for (key, value) in value.as_object_mut().into_iter().flatten() {
+                    if let Some((field, _)) = t.field_by_json_name(key) {
+                        let path = child_path(path, key);
+                        self.resolve_type(&field.typ, field.target_type.as_ref(), value, &path);
+                    }
+                }
+            }
+            TypeDefinition::Union(t) => {
+                if value.is_object()
+                    && let Some(variant) = resolve_variant(model, t, value)
+                {
+                    let variant = variant.id.clone();
+                    self.resolve_type_def(&variant, None, value, path);
+                }
+            }
+            TypeDefinition::Map(t) => {
+                for (key, value) in value.as_object_mut().into_iter().flatten() {
+                    let path = child_path(path, key);
+                    self.resolve_type(&t.value_type, target_type, value, &path);
+                }
+            }
+            TypeDefinition::Enum(_) => (),
+        }
+    }
+
+    // Handle of the resource, the account, or the value that a reference points to
+    fn handle(
+        &self,
+        id: &TypeId,
+        target_type: Option<&TypeId>,
+        value: &Value,
+    ) -> Result<Value, String> {
+        if id.schema_id().as_str() == ACCOUNT_REF {
+            let account = AccountRef::parse(value)?;
+            return self
+                .server
+                .account_handle(self.state, &account)
+                .map_err(|e| e.message);
+        }
+
+        if id.schema_id().as_str() == VALUE_REF {
+            let Some((target, path)) = self.store.resolve_value_ref(value)? else {
+                return Err(format!("Reference {value} does not match any resource"));
+            };
+            let mut handle = json!({
+                "account": target.manifest["headers"]["account"],
+                "type": target.type_id.schema_id().as_str(),
+                "id": target.id,
+                "name": target.name,
+            });
+            if let Some(path) = path {
+                handle["path"] = path.into();
+            }
+            return Ok(handle);
+        }
+
+        let default_type = target_type.map(|t| t.schema_id().as_str());
+        let Some(target) = self.store.resolve_ref(value, default_type)? else {
+            return Err(format!("Reference {value} does not match any resource"));
+        };
+        if let Some(expected) = target_type
+            && expected != &target.type_id
+        {
+            return Err(format!(
+                "Expected a reference to {} but got {}",
+                expected.name(),
+                target.type_id.name()
+            ));
+        }
+        let mut handle = json!({
+            "account": target.manifest["headers"]["account"],
+            "type": target.type_id.schema_id().as_str(),
+            "id": target.id,
+            "name": target.name,
+        });
+        if let Some(did) = &target.did {
+            handle["did"] = did.as_str().into();
+        }
+        Ok(handle)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Current time in RFC 3339 format with a second precision, e.g. `2026-01-01T00:00:00Z`
+pub fn utc_now() -> String {
+    let secs = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    let (days, rem) = (secs / 86400, secs % 86400);
+
+    // Civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3600,
+        rem % 3600 / 60,
+        rem % 60
+    )
+}
+
+// Random UUID v4
+fn new_id() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+fn check_diagnostics(
+    message: &str,
+    diagnostics: &[validation::Diagnostic],
+) -> Result<(), ApiError> {
+    if diagnostics.iter().any(|d| d.severity == Severity::Error) {
+        return Err(ApiError::new(400, message)
+            .details(diagnostics.iter().map(ToString::to_string).collect()));
+    }
+    Ok(())
+}
+
+// Short form of a canonical resource, e.g. `Dataset:alice/my-dataset`
+fn describe(resource: &Value) -> String {
+    let headers = &resource["headers"];
+    format!(
+        "{}:{}/{}",
+        resource["$schema"]
+            .as_str()
+            .and_then(|s| s.rsplit('/').next())
+            .unwrap_or_default(),
+        headers["account"]["name"].as_str().unwrap_or_default(),
+        headers["name"].as_str().unwrap_or_default()
+    )
+}
+
+// Reason phrases of the statuses that the server responds with
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        201 => "Created",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        409 => "Conflict",
+        410 => "Gone",
+        411 => "Length Required",
+        413 => "Content Too Large",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "",
+    }
+}
+
+fn write_json(stream: &mut TcpStream, status: u16, body: &Value) -> std::io::Result<()> {
+    let body = serde_json::to_vec(body).unwrap();
+    write!(
+        stream,
+        "HTTP/1.1 {status} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        reason_phrase(status),
+        body.len()
+    )?;
+    stream.write_all(&body)?;
+    stream.flush()
+}
+
+/// Request line and the headers that the server cares about
+struct Head {
+    method: String,
+    target: String,
+    content_length: Result<usize, ApiError>,
+    chunked: bool,
+}
+
+// Reads the request line and headers, never buffering more than [`MAX_HEAD_SIZE`] bytes
+fn read_head(reader: &mut BufReader<TcpStream>) -> Result<Head, ApiError> {
+    let too_large = || {
+        ApiError::new(
+            431,
+            format!(
+                "Request line and headers exceed the limit of {MAX_HEAD_SIZE} bytes and {MAX_HEADERS} headers"
+            ),
+        )
+    };
+
+    let mut reader = reader.take(MAX_HEAD_SIZE as u64);
+    let mut read_line = |line: &mut String| {
+        line.clear();
+        reader.read_line(line).map_err(read_error)?;
+        if !line.ends_with('\n') {
+            return Err(if reader.limit() == 0 {
+                too_large()
+            } else {
+                ApiError::new(400, "Request ended before the end of headers")
+            });
+        }
+        Ok(())
+    };
+
+    let mut line = String::new();
+    read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let mut head = Head {
+        method: parts.next().unwrap_or_default().to_string(),
+        target: parts.next().unwrap_or_default().to_string(),
+        content_length: Ok(0),
+        chunked: false,
+    };
+
+    for i in 0.. {
+        read_line(&mut line)?;
+        if line.trim().is_empty() {
+            break;
+        }
+        if i == MAX_HEADERS {
+            return Err(too_large());
+        }
+        if let Some((name, value)) = line.split_once(':') {
+            let value = value.trim();
+            if name.eq_ignore_ascii_case("content-length") {
+                head.content_length = value.parse::<usize>().map_err(|_| {
+                    ApiError::new(400, format!("Invalid Content-Length header `{value}`"))
+                });
+            } else if name.eq_ignore_ascii_case("transfer-encoding") {
+                head.chunked = value.eq_ignore_ascii_case("chunked");
+            }
+        }
+    }
+    Ok(head)
+}
+
+// Reads the body once its length is known to be acceptable
+fn read_body(reader: &mut BufReader<TcpStream>, head: &Head) -> Result<Vec<u8>, ApiError> {
+    match head.content_length {
+        _ if head.chunked => Err(ApiError::new(
+            411,
+            "Request body must specify its Content-Length",
+        )),
+        Ok(len) if len > MAX_BODY_SIZE => Err(ApiError::new(
+            413,
+            format!("Request body of {len} bytes exceeds the limit of {MAX_BODY_SIZE} bytes"),
+        )),
+        Ok(len) => {
+            let mut body = vec![0; len];
+            reader.read_exact(&mut body).map_err(read_error)?;
+            Ok(body)
+        }
+        Err(ref e) => Err(e.clone()),
+    }
+}
+
+fn read_error(e: std::io::Error) -> ApiError {
+    match e.kind() {
+        ErrorKind::WouldBlock | ErrorKind::TimedOut => ApiError::new(
+            408,
+            format!(
+                "Request was not received within {} seconds",
+                READ_TIMEOUT.as_secs()
+            ),
+        ),
+        ErrorKind::InvalidData => ApiError::new(400, "Request line and headers must be UTF-8"),
+        _ => ApiError::new(400, format!("Failed to read the request: {e}")),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,311 @@
+use serde_json::{Map, Value, json};
+
+use crate::json_schema::{SCHEMA_URL_RE, SchemaId};
+use crate::model::{MetaType, Model, TypeId};
+use crate::validation::{Diagnostic, Severity, child_path, validate};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Lifecycle stage of a resource, mirrors `ResourcePhase`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Pending,
+    Reconciling,
+    Ready,
+    Failed,
+}
+
+impl Phase {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Phase::Pending => "Pending",
+            Phase::Reconciling => "Reconciling",
+            Phase::Ready => "Ready",
+            Phase::Failed => "Failed",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Pending" => Some(Phase::Pending),
+            "Reconciling" => Some(Phase::Reconciling),
+            "Ready" => Some(Phase::Ready),
+            "Failed" => Some(Phase::Failed),
+            _ => None,
+        }
+    }
+
+    // Phase of a resource is the most severe one among its conditions
+    fn severity(&self) -> u8 {
+        match self {
+            Phase::Ready => 0,
+            Phase::Pending => 1,
+            Phase::Reconciling => 2,
+            Phase::Failed => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Condition reported by a controller, see [`set_condition`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Condition {
+    pub value: Value,
+    /// Machine-readable cause of the value, e.g. `ConnectionRefused`
+    pub reason: Option<String>,
+    pub message: Option<String>,
+}
+
+impl Condition {
+    pub fn new(value: impl Into<Value>) -> Self {
+        Self {
+            value: value.into(),
+            reason: None,
+            message: None,
+        }
+    }
+
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = Some(reason.into());
+        self
+    }
+
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Condition types that resources of the specified kind may carry, which are the `ResourceCondition` schemas declared
+/// in the same context, e.g. `WebhookTargetStatus` for `WebhookTarget`
+pub fn declared_conditions(model: &Model, kind: &TypeId) -> Vec<TypeId> {
+    model
+        .types
+        .values()
+        .filter(|t| matches!(t.metatype(), MetaType::ResourceCondition))
+        .filter(|t| t.id().context() == kind.context())
+        .map(|t| t.id().clone())
+        .collect()
+}
+
+/// Inserts or updates a condition in the status of a canonical resource and re-derives its phase, returning whether
+/// the status has changed.
+///
+/// `lastTransitionTime` is set to `now` only when the condition appears or its value flips, so that updating the reason
+/// or the message of a condition keeps the time since which it holds.
+pub fn set_condition(
+    resource: &mut Value,
+    condition_type: &str,
+    condition: Condition,
+    now: &str,
+) -> bool {
+    let status = status_mut(resource);
+    let conditions = status
+        .entry("conditions")
+        .or_insert_with(|| json!({}))
+        .as_object_mut()
+        .expect("Resource conditions must be an object");
+
+    let existing = conditions.get(condition_type);
+    let last_transition_time = existing
+        .filter(|c| c.get("value") == Some(&condition.value))
+        .and_then(|c| c.get("lastTransitionTime"))
+        .cloned()
+        .unwrap_or_else(|| now.into());
+
+    let mut updated = Map::new();
+    updated.insert("value".to_string(), condition.value);
+    updated.insert("lastTransitionTime".to_string(), last_transition_time);
+    if let Some(reason) = condition.reason {
+        updated.insert("reason".to_string(), reason.into());
+    }
+    if let Some(message) = condition.message {
+        updated.insert("message".to_string(), message.into());
+    }
+    let updated = Value::Object(updated);
+
+    let changed = existing != Some(&updated);
+    conditions.insert(condition_type.to_string(), updated);
+    update_phase(resource) || changed
+}
+
+/// Removes a condition from the status of a canonical resource and re-derives its phase, returning whether the status
+/// has changed
+pub fn remove_condition(resource: &mut Value, condition_type: &str) -> bool {
+    let removed = status_mut(resource)
+        .get_mut("conditions")
+        .and_then(Value::as_object_mut)
+        .and_then(|c| c.remove(condition_type))
+        .is_some();
+    update_phase(resource) || removed
+}
+
+/// Records that the controller has reconciled the current generation of a canonical resource
+pub fn mark_reconciled(resource: &mut Value, now: &str) {
+    let generation = generation(resource);
+    let status = status_mut(resource);
+    status.insert("observedGeneration".to_string(), generation.into());
+    status.insert("reconciledAt".to_string(), now.into());
+    update_phase(resource);
+}
+
+/// Whether the desired state of a canonical resource has changed since the controller last reconciled it
+pub fn is_stale(resource: &Value) -> bool {
+    observed_generation(resource).is_none_or(|observed| observed < generation(resource))
+}
+
+/// Derives the phase of a canonical resource:
+/// - `Pending` until the controller observes any generation
+/// - `Reconciling` while the observed generation lags behind the current one
+/// - otherwise the most severe phase named by a condition value (`Failed`, `Reconciling`, `Pending`), or `Ready` when
+///   there is none, as values that don't name a phase are informational
+pub fn derive_phase(resource: &Value) -> Phase {
+    if observed_generation(resource).is_none() {
+        return Phase::Pending;
+    }
+    if is_stale(resource) {
+        return Phase::Reconciling;
+    }
+
+    resource
+        .pointer("/status/conditions")
+        .and_then(Value::as_object)
+        .into_iter()
+        .flat_map(|c| c.values())
+        .filter_map(|c| c.get("value")?.as_str().and_then(Phase::parse))
+        .max_by_key(Phase::severity)
+        .unwrap_or(Phase::Ready)
+}
+
+/// Validates the status of a canonical resource of the specified kind: that its conditions are declared for the kind
+/// and conform to their schemas, that the observed generation is not ahead of the current one, and that the phase
+/// agrees with the one derived from the rest of the status
+pub fn validate_status(
+    model: &Model,
+    kind: &TypeId,
+    resource: &Value,
+) -> Result<Vec<Diagnostic>, String> {
+    if !model
+        .types
+        .get(kind)
+        .is_some_and(|t| matches!(t.metatype(), MetaType::Resource))
+    {
+        return Err(format!("{} is not a resource type", kind.schema_id()));
+    }
+
+    let Some(status) = resource.get("status") else {
+        return Ok(Vec::new());
+    };
+
+    let mut diagnostics = Vec::new();
+    let error = |path: String, message: String| Diagnostic {
+        severity: Severity::Error,
+        path,
+        message,
+    };
+
+    if let Some(observed) = observed_generation(resource)
+        && observed > generation(resource)
+    {
+        diagnostics.push(error(
+            "status.observedGeneration".to_string(),
+            format!(
+                "Observed generation {observed} is ahead of the resource generation {}",
+                generation(resource)
+            ),
+        ));
+    }
+
+    let declared = declared_conditions(model, kind);
+    let conditions = status.get("conditions").and_then(Value::as_object);
+    for (key, condition) in conditions.into_iter().flatten() {
+        let path = child_path("status.conditions", key);
+        let id = TypeId::new(SchemaId::new(key));
+
+        if !SCHEMA_URL_RE.is_match(key) || !model.types.contains_key(&id) {
+            diagnostics.push(error(path, format!("Unknown condition type {key}")));
+        } else if !declared.contains(&id) {
+            let expected = if declared.is_empty() {
+                format!("{} does not declare any conditions", kind.name())
+            } else {
+                let names: Vec<_> = declared.iter().map(|d| d.name()).collect();
+                format!("expected one of {}", names.join(", "))
+            };
+            diagnostics.push(error(
+                path,
+                format!(
+                    "Condition {} is not declared for {}, {expected}",
+                    id.name(),
+                    kind.name()
+                ),
+            ));
+        } else {
+            for mut d in validate(model, &id, condition) {
+                d.path = if d.path.is_empty() {
+                    path.clone()
+                } else {
+                    child_path(&path, &d.path)
+                };
+                diagnostics.push(d);
+            }
+        }
+    }
+
+    let derived = derive_phase(resource);
+    if let Some(phase) = status.get("phase").and_then(Value::as_str)
+        && Phase::parse(phase) != Some(derived)
+    {
+        diagnostics.push(Diagnostic {
+            severity: Severity::Warning,
+            path: "status.phase".to_string(),
+            message: format!(
+                "Phase {phase} does not match {derived} derived from the generation and conditions"
+            ),
+        });
+    }
+
+    Ok(diagnostics)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn generation(resource: &Value) -> u64 {
+    resource
+        .pointer("/headers/generation")
+        .and_then(Value::as_u64)
+        .unwrap_or(0)
+}
+
+fn observed_generation(resource: &Value) -> Option<u64> {
+    resource
+        .pointer("/status/observedGeneration")
+        .and_then(Value::as_u64)
+}
+
+// Status is created as pending when the resource doesn't have one yet
+fn status_mut(resource: &mut Value) -> &mut Map<String, Value> {
+    resource
+        .as_object_mut()
+        .expect("Resource must be an object")
+        .entry("status")
+        .or_insert_with(|| json!({ "phase": Phase::Pending.as_str(), "conditions": {} }))
+        .as_object_mut()
+        .expect("Resource status must be an object")
+}
+
+fn update_phase(resource: &mut Value) -> bool {
+    let phase = Value::from(derive_phase(resource).as_str());
+    let status = status_mut(resource);
+    if status.get("phase") == Some(&phase) {
+        return false;
+    }
+    status.insert("phase".to_string(), phase);
+    true
+}
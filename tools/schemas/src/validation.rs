@@ -0,0 +1,446 @@
+use serde_json::Value;
+
+use crate::model::{self, Model, Type, TypeDefinition, TypeId};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    /// Dot-separated path to the offending value, e.g. `spec.metadata[0].kind`
+    pub path: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let severity = match self.severity {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        if self.path.is_empty() {
+            write!(f, "{severity}: {}", self.message)
+        } else {
+            write!(f, "{severity}: {}: {}", self.path, self.message)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Validates a manifest against the type specified in its `$schema` property
+pub fn validate_manifest(model: &Model, manifest: &Value) -> Vec<Diagnostic> {
+    match manifest_type(model, manifest) {
+        Ok(id) => validate(model, &id, manifest),
+        Err(message) => vec![Diagnostic {
+            severity: Severity::Error,
+            path: "$schema".to_string(),
+            message,
+        }],
+    }
+}
+
+/// Validates a value against the specified type
+pub fn validate(model: &Model, type_id: &TypeId, value: &Value) -> Vec<Diagnostic> {
+    let mut v = Validator {
+        model,
+        diagnostics: Vec::new(),
+    };
+    v.check_type_def(type_id, value, "");
+    v.diagnostics
+}
+
+/// Resolves the type of a manifest from its `$schema` property
+pub fn manifest_type(model: &Model, manifest: &Value) -> Result<TypeId, String> {
+    let Some(schema) = manifest.get("$schema").and_then(Value::as_str) else {
+        return Err("Manifest does not specify a $schema".to_string());
+    };
+
+    let id = TypeId::new(crate::json_schema::SchemaId::new(schema));
+    if !crate::json_schema::SCHEMA_URL_RE.is_match(schema) || !model.types.contains_key(&id) {
+        return Err(format!("Unknown schema {schema}"));
+    }
+    Ok(id)
+}
+
+/// Resolves the variant of an untagged union by picking the first one that the value conforms to
+pub(crate) fn resolve_untagged_variant<'a>(
+    model: &Model,
+    t: &'a model::Union,
+    value: &Value,
+) -> Option<&'a model::UnionVariant> {
+    t.variants.iter().find(|v| {
+        validate(model, &v.id, value)
+            .iter()
+            .all(|d| d.severity != Severity::Error)
+    })
+}
+
+/// Resolves the variant of a union value by its tag or, for untagged unions, by its shape
+pub(crate) fn resolve_variant<'a>(
+    model: &Model,
+    t: &'a model::Union,
+    value: &Value,
+) -> Option<&'a model::UnionVariant> {
+    let Some(property) = t.discriminator.property() else {
+        return resolve_untagged_variant(model, t, value);
+    };
+    let tag = value.as_object()?.get(property)?.as_str()?;
+    t.variant_by_tag(tag).map(|(variant, _)| variant)
+}
+
+pub(crate) fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Validator<'a> {
+    model: &'a Model,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Validator<'_> {
+    fn error(&mut self, path: &str, message: String) {
+        self.diagnostics.push(Diagnostic {
+            severity: Severity::Error,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn warning(&mut self, path: &str, message: String) {
+        self.diagnostics.push(Diagnostic {
+            severity: Severity::Warning,
+            path: path.to_string(),
+            message,
+        });
+    }
+
+    fn check_type_def(&mut self, id: &TypeId, value: &Value, path: &str) {
+        let model = self.model;
+        let Some(typ) = model.types.get(id) else {
+            panic!("Reference to unknown type {}", id.schema_id());
+        };
+
+        match typ {
+            TypeDefinition::Struct(t) => self.check_struct(t, value, path, &[]),
+            TypeDefinition::Union(t) => self.check_union(t, value, path),
+            TypeDefinition::Enum(t) => self.check_enum(t, value, path),
+            TypeDefinition::Map(t) => self.check_map(t, value, path),
+        }
+    }
+
+    fn check_struct(&mut self, t: &model::Struct, value: &Value, path: &str, skip: &[&str]) {
+        if t.from_string && value.is_string() {
+            return;
+        }
+
+        let Some(obj) = value.as_object() else {
+            self.error(
+                path,
+                format!("Expected an object of type {}", t.id.join("::")),
+            );
+            return;
+        };
+
+        for (key, value) in obj {
+            if skip.contains(&key.as_str()) {
+                continue;
+            }
+
+            let field_path = child_path(path, key);
+
+            let Some((field, is_alias)) = t.field_by_json_name(key) else {
+                self.error(&field_path, format!("Unknown property `{key}`"));
+                continue;
+            };
+
+            if is_alias {
+                let name = field.json_name();
+                if obj.contains_key(&name) {
+                    self.error(
+                        &field_path,
+                        format!("Both `{key}` and its replacement `{name}` are specified"),
+                    );
+                } else {
+                    self.warning(
+                        &field_path,
+                        format!("Property `{key}` is deprecated, use `{name}` instead"),
+                    );
+                }
+            } else if field.deprecated {
+                self.warning(&field_path, format!("Property `{key}` is deprecated"));
+            }
+
+            if let Some(constant) = &field.constant
+                && value != constant
+            {
+                self.error(&field_path, format!("Expected constant value {constant}"));
+                continue;
+            }
+
+            for validation in &field.validations {
+                match validation {
+                    model::Validation::Enum(e) => {
+                        if !e.values.contains(value) {
+                            self.error(
+                                &field_path,
+                                format!("Value {value} is not one of {:?}", e.values),
+                            );
+                        }
+                    }
+                }
+            }
+
+            self.check_type(&field.typ, value, &field_path);
+        }
+
+        for field in t.fields.values() {
+            if field.optional || skip.contains(&field.json_name().as_str()) {
+                continue;
+            }
+            let present = obj.contains_key(&field.json_name())
+                || field.aliases.iter().any(|a| obj.contains_key(a));
+            if !present {
+                self.error(
+                    path,
+                    format!("Missing required property `{}`", field.json_name()),
+                );
+            }
+        }
+    }
+
+    fn check_union(&mut self, t: &model::Union, value: &Value, path: &str) {
+        let Some(property) = t.discriminator.property() else {
+            self.check_untagged_union(t, value, path);
+            return;
+        };
+
+        let tag = match value {
+            Value::String(s) if t.from_string => s,
+            Value::Object(obj) => match obj.get(property) {
+                Some(Value::String(s)) => s,
+                Some(_) => {
+                    self.error(&child_path(path, property), "Expected a string".to_string());
+                    return;
+                }
+                None => {
+                    self.error(path, format!("Missing required property `{property}`"));
+                    return;
+                }
+            },
+            _ => {
+                self.error(
+                    path,
+                    format!("Expected an object of type {}", t.id.join("::")),
+                );
+                return;
+            }
+        };
+
+        let tag_path = if value.is_string() {
+            path.to_string()
+        } else {
+            child_path(path, property)
+        };
+
+        let Some((variant, is_alias)) = t.variant_by_tag(tag) else {
+            // Readers preserve such variants, so they are only reported
+            if t.has_unknown_variant() {
+                self.warning(
+                    &tag_path,
+                    format!(
+                        "Unknown variant `{tag}` of {} will be preserved as is",
+                        t.id.join("::")
+                    ),
+                );
+                return;
+            }
+
+            self.error(
+                &tag_path,
+                format!(
+                    "Unknown variant `{tag}`, expected one of: {}",
+                    t.variants
+                        .iter()
+                        .filter_map(|v| v.tag.as_deref())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            );
+            return;
+        };
+
+        if is_alias {
+            self.warning(
+                &tag_path,
+                format!(
+                    "Variant `{tag}` is deprecated, use `{}` instead",
+                    variant.tag.as_deref().unwrap()
+                ),
+            );
+        }
+
+        // Short string form will be populated with default values
+        if value.is_string() {
+            return;
+        }
+
+        let model = self.model;
+        match model.types.get(&variant.id) {
+            Some(TypeDefinition::Struct(s)) => self.check_struct(s, value, path, &[property]),
+            Some(_) => self.check_type_def(&variant.id, value, path),
+            None => panic!("Reference to unknown type {}", variant.id.schema_id()),
+        }
+    }
+
+    fn check_untagged_union(&mut self, t: &model::Union, value: &Value, path: &str) {
+        match resolve_untagged_variant(self.model, t, value) {
+            // Re-validate in place to report warnings under the correct path
+            Some(variant) => self.check_type_def(&variant.id, value, path),
+            // Filters explain which operator is wrong rather than listing the variants
+            None if let Some(Err(e)) = crate::filter::parse_union(self.model, t, value) => {
+                let path = if e.path.is_empty() {
+                    path.to_string()
+                } else {
+                    child_path(path, &e.path)
+                };
+                self.error(&path, e.message)
+            }
+            None => self.error(
+                path,
+                format!(
+                    "Value does not match any variant of {}: {}",
+                    t.id.join("::"),
+                    t.variants
+                        .iter()
+                        .map(|v| v.id.name())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            ),
+        }
+    }
+
+    fn check_enum(&mut self, t: &model::Enum, value: &Value, path: &str) {
+        let Some(s) = value.as_str() else {
+            self.error(
+                path,
+                format!("Expected a string of type {}", t.id.join("::")),
+            );
+            return;
+        };
+
+        if !t.variants.iter().any(|v| model::is_same_name(v, s)) {
+            if t.has_unknown_variant() {
+                self.warning(
+                    path,
+                    format!(
+                        "Unknown value `{s}` of {} will be preserved as is",
+                        t.id.join("::")
+                    ),
+                );
+                return;
+            }
+
+            self.error(
+                path,
+                format!(
+                    "Unknown value `{s}`, expected one of: {}",
+                    t.variants.join(", ")
+                ),
+            );
+        }
+    }
+
+    fn check_map(&mut self, t: &model::Map, value: &Value, path: &str) {
+        let Some(obj) = value.as_object() else {
+            self.error(
+                path,
+                format!("Expected an object of type {}", t.id.join("::")),
+            );
+            return;
+        };
+
+        let key_pattern = t
+            .key_pattern
+            .as_ref()
+            .map(|p| regex::Regex::new(p).unwrap());
+
+        for (key, value) in obj {
+            let value_path = child_path(path, key);
+            if let Some(re) = &key_pattern
+                && !re.is_match(key)
+            {
+                self.error(
+                    &value_path,
+                    format!("Key `{key}` does not match pattern `{}`", re.as_str()),
+                );
+                continue;
+            }
+            self.check_type(&t.value_type, value, &value_path);
+        }
+    }
+
+    fn check_type(&mut self, typ: &Type, value: &Value, path: &str) {
+        let ok = match typ {
+            Type::Boolean => value.is_boolean(),
+            Type::Int8 | Type::Int16 | Type::Int32 | Type::Int64 => value.is_i64(),
+            Type::UInt8 | Type::UInt16 | Type::UInt32 | Type::UInt64 => value.is_u64(),
+            Type::ByteSize => value.is_string() || value.is_u64(),
+            Type::String
+            | Type::DateTime
+            | Type::Duration
+            | Type::Multicodec
+            | Type::Multihash
+            | Type::Path
+            | Type::Regex
+            | Type::Url
+            | Type::Did
+            | Type::TypeName
+            | Type::TypeUri
+            | Type::TypeRef
+            | Type::AccountId
+            | Type::AccountName
+            | Type::DatasetAlias
+            | Type::DatasetId
+            | Type::DatasetRef
+            | Type::ResourceId
+            | Type::ResourceName
+            | Type::Flatbuffers => value.is_string(),
+            Type::Generic(_) => value.is_object(),
+            Type::AnyJson => true,
+            Type::Array(arr) => {
+                let Some(items) = value.as_array() else {
+                    self.error(path, "Expected an array".to_string());
+                    return;
+                };
+                for (i, item) in items.iter().enumerate() {
+                    self.check_type(&arr.item_type, item, &format!("{path}[{i}]"));
+                }
+                return;
+            }
+            Type::Custom(id) => {
+                self.check_type_def(id, value, path);
+                return;
+            }
+        };
+
+        if !ok {
+            self.error(path, format!("Expected a value of type {typ:?}"));
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -0,0 +1,326 @@
+//! Crate built from the generated code of a model, for tests that compile and run it instead of matching strings.
+//!
+//! Flatbuffers proxies are produced by `flatc`, which is looked up in the `FLATC` environment variable and then in
+//! `PATH`. Tests that need them are skipped with a notice when it's not available.
+
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use odf_schemas::codegen;
+use odf_schemas::model::Model;
+
+use super::{TempDir, render};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Types that ODF crate defines by hand and re-exports from context modules
+const FORMATS: &[(&str, &[&str])] = &[
+    ("auth", &["AccountID", "AccountName"]),
+    ("dataset", &["DatasetAlias", "DatasetID", "DatasetRef"]),
+    (
+        "resource",
+        &[
+            "IntoResourceRef",
+            "ResourceID",
+            "ResourceName",
+            "TypeName",
+            "TypeRef",
+            "TypeUri",
+        ],
+    ),
+];
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn flatc() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("FLATC") {
+        return Some(PathBuf::from(path));
+    }
+    std::env::split_paths(&std::env::var_os("PATH")?)
+        .map(|dir| dir.join("flatc"))
+        .find(|path| path.is_file())
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Crate named `odf-metadata` laid out like the ODF crate, with generated DTOs under `dtos`, flatbuffers code under
+/// `serde::flatbuffers`, and a module per context that re-exports both DTOs and hand-written types
+pub struct GeneratedCrate {
+    dir: TempDir,
+    flatc_version: Option<String>,
+}
+
+/// Generated code included in the crate, each level adding to the previous one
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Contents {
+    Dtos,
+    Flatbuffers,
+    Proptest,
+}
+
+impl GeneratedCrate {
+    /// Generates the crate, or returns `None` when `flatc` is not available
+    pub fn new(model: &Model) -> Option<Self> {
+        Self::generate(model, Contents::Flatbuffers)
+    }
+
+    /// Generates the crate with DTOs only, for models that have no flatbuffers representation, e.g. with nested arrays
+    pub fn new_dtos(model: &Model) -> Self {
+        Self::generate(model, Contents::Dtos).unwrap()
+    }
+
+    /// Same as [`Self::new`], but also includes serde proxies under `serde::yaml` and proptest strategies that are
+    /// enabled by the `proptest` feature, and whose round trips [`Self::test`] runs.
+    ///
+    /// Serde proxies of the repo schemas rely on hand-written conversions of ODF crate, so this only works for fixtures.
+    pub fn new_with_proptest(model: &Model) -> Option<Self> {
+        Self::generate(model, Contents::Proptest)
+    }
+
+    fn generate(model: &Model, contents: Contents) -> Option<Self> {
+        let flatc = if contents >= Contents::Flatbuffers {
+            let Some(flatc) = flatc() else {
+                eprintln!(
+                    "Skipping compilation of generated code: flatc not found in FLATC or PATH"
+                );
+                return None;
+            };
+            Some(flatc)
+        } else {
+            None
+        };
+
+        let this = Self {
+            dir: TempDir::new("generated-crate"),
+            flatc_version: flatc.as_ref().map(|flatc| {
+                let version = output(Command::new(flatc).arg("--version"));
+                version.trim().rsplit(' ').next().unwrap().to_string()
+            }),
+        };
+
+        // Proxies only work with the runtime library of the same version
+        this.write(
+            "Cargo.toml",
+            &cargo_toml(this.flatc_version.as_deref(), contents),
+        );
+        this.write(
+            "setty/Cargo.toml",
+            &format!(
+                "{}\n[dependencies]\nserde = \"1\"\n",
+                stub_cargo_toml("setty")
+            ),
+        );
+        this.write("setty/src/lib.rs", include_str!("stubs/setty.rs"));
+        this.write(
+            "enum_variants/Cargo.toml",
+            &stub_cargo_toml("enum_variants"),
+        );
+        this.write(
+            "enum_variants/src/lib.rs",
+            include_str!("stubs/enum_variants.rs"),
+        );
+        this.write("src/errors.rs", include_str!("stubs/errors.rs"));
+        this.write("src/formats.rs", include_str!("stubs/formats.rs"));
+        this.write("src/lib.rs", &lib_rs(model, contents));
+
+        this.write("src/dtos.rs", &render(model, codegen::rust_dtos::render));
+        let Some(flatc) = flatc else {
+            return Some(this);
+        };
+
+        this.write(
+            "src/serde/flatbuffers/convertors_generated.rs",
+            &render(model, codegen::rust_serde_flatbuffers::render),
+        );
+        this.write(
+            "src/serde/flatbuffers/views_generated.rs",
+            &render(model, codegen::rust_flatbuffers_views::render),
+        );
+        if contents >= Contents::Proptest {
+            this.write(
+                "src/serde/yaml/formats.rs",
+                include_str!("stubs/serde_formats.rs"),
+            );
+            this.write(
+                "src/serde/yaml/derivations_generated.rs",
+                &render(model, codegen::rust_serde::render),
+            );
+            this.write(
+                "src/proptest_generated.rs",
+                &render(model, codegen::rust_proptest::render),
+            );
+        }
+
+        // Canonical encoding wraps values into a `Manifest` table, which fixture schemas don't define
+        let mut fbs = render(model, codegen::flatbuffers_schema::render);
+        if !fbs.contains("\ntable Manifest {") {
+            fbs.push_str(
+                "\ntable Manifest {\n  kind: int64;\n  version: int32;\n  content: [ubyte];\n}\n",
+            );
+        }
+        this.write("schema/odf.fbs", &fbs);
+        output(
+            Command::new(flatc)
+                .arg("--rust")
+                .arg("-o")
+                .arg(this.path().join("schema"))
+                .arg(this.path().join("schema/odf.fbs")),
+        );
+        std::fs::rename(
+            this.path().join("schema/odf_generated.rs"),
+            this.path()
+                .join("src/serde/flatbuffers/proxies_generated.rs"),
+        )
+        .unwrap();
+
+        Some(this)
+    }
+
+    pub fn path(&self) -> &Path {
+        self.dir.path()
+    }
+
+    /// Major version of `flatc` that generated the proxies
+    pub fn flatc_major_version(&self) -> u32 {
+        self.flatc_version
+            .as_deref()
+            .expect("Crate has no flatbuffers code")
+            .split('.')
+            .next()
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    /// Adds an integration test to the crate, which refers to it as `odf`
+    pub fn add_test(&self, name: &str, code: &str) {
+        self.write(
+            &format!("tests/{name}.rs"),
+            &format!("#![allow(unused_imports)]\n\nuse odf_metadata as odf;\n\n{code}"),
+        );
+    }
+
+    /// Builds an integration test that must not compile and returns the errors of the compiler
+    pub fn compile_error(&self, name: &str, code: &str) -> String {
+        self.add_test(name, code);
+        let output = self
+            .command("", &["test", "--no-run", "--test", name])
+            .output()
+            .unwrap();
+        std::fs::remove_file(self.path().join(format!("tests/{name}.rs"))).unwrap();
+
+        assert!(!output.status.success(), "Test {name} compiled:\n{code}");
+        String::from_utf8(output.stderr).unwrap()
+    }
+
+    /// Builds the crate and runs its tests, failing with the output of cargo
+    pub fn test(&self) {
+        self.cargo("", &["test", "--quiet"]);
+    }
+
+    /// Runs cargo in a directory of the crate, e.g. a generated fuzz crate, and returns its output
+    pub fn cargo(&self, dir: &str, args: &[&str]) -> String {
+        output(&mut self.command(dir, args))
+    }
+
+    fn command(&self, dir: &str, args: &[&str]) -> Command {
+        let mut cmd = Command::new(std::env::var("CARGO").unwrap_or("cargo".to_string()));
+        cmd.args(args).current_dir(self.path().join(dir)).env(
+            "CARGO_TARGET_DIR",
+            Path::new(env!("CARGO_TARGET_TMPDIR")).join("generated-crate-target"),
+        );
+        cmd
+    }
+
+    fn write(&self, path: &str, contents: &str) {
+        let path = self.path().join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, contents).unwrap();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn output(cmd: &mut Command) -> String {
+    let output = cmd.output().unwrap();
+    assert!(
+        output.status.success(),
+        "{cmd:?} failed:\n{}\n{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).unwrap()
+}
+
+fn cargo_toml(flatbuffers_version: Option<&str>, contents: Contents) -> String {
+    let mut toml = String::from(
+        r#"[package]
+name = "odf-metadata"
+version = "0.0.0"
+edition = "2024"
+publish = false
+
+[workspace]
+
+[dependencies]
+bitflags = { version = "2", features = ["serde"] }
+chrono = "0.4"
+enum_variants = { path = "enum_variants" }
+serde = { version = "1", features = ["derive"] }
+serde_json = "1"
+setty = { path = "setty" }
+sha3 = "0.10"
+"#,
+    );
+    if let Some(version) = flatbuffers_version {
+        toml.push_str(&format!("flatbuffers = \"={version}\"\n"));
+    }
+    if contents >= Contents::Proptest {
+        toml.push_str(
+            "base64 = \"0.22\"\nproptest = \"1\"\nserde_with = \"3\"\nserde_yaml = \"0.9\"\n\n[features]\nproptest = []\n",
+        );
+    }
+    toml
+}
+
+fn stub_cargo_toml(name: &str) -> String {
+    format!("[package]\nname = \"{name}\"\nversion = \"0.0.0\"\nedition = \"2024\"\n")
+}
+
+fn lib_rs(model: &Model, contents: Contents) -> String {
+    let mut contexts: BTreeSet<&str> = model.types.keys().map(|id| id.context()).collect();
+    contexts.extend(FORMATS.iter().map(|(context, _)| *context));
+
+    let mut lib = String::from(
+        "#![allow(warnings)]\n\npub mod dtos;\npub mod errors;\npub mod formats;\n\npub use formats::*;\n\n",
+    );
+    for context in contexts {
+        lib.push_str(&format!("pub mod {context} {{\n"));
+        if model.types.keys().any(|id| id.context() == context) {
+            lib.push_str(&format!("    pub use crate::dtos::{context}::*;\n"));
+        }
+        if let Some((_, types)) = FORMATS.iter().find(|(c, _)| *c == context) {
+            lib.push_str(&format!(
+                "    pub use crate::formats::{{{}}};\n",
+                types.join(", ")
+            ));
+        }
+        lib.push_str("}\n\n");
+    }
+    if contents == Contents::Dtos {
+        return lib;
+    }
+
+    lib.push_str(
+        "pub mod serde {\n    pub mod flatbuffers {\n        pub mod convertors_generated;\n        pub mod proxies_generated;\n        pub mod views_generated;\n    }\n",
+    );
+    if contents >= Contents::Proptest {
+        lib.push_str(
+            "    pub mod yaml {\n        pub mod derivations_generated;\n        pub mod formats;\n    }\n}\n\n#[cfg(any(test, feature = \"proptest\"))]\nmod proptest_generated;\n",
+        );
+    } else {
+        lib.push_str("}\n");
+    }
+    lib
+}
@@ -0,0 +1,46 @@
+//! Resource server listening on a random port, for tests that talk to it over HTTP.
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+
+use odf_schemas::model::Model;
+use odf_schemas::server::ResourceServer;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// `AccountHandle` that test servers act on behalf of
+pub fn account() -> Value {
+    json!({ "id": "6767a4ee-d74d-436e-84f9-709407869a26", "did": "did:odf:fed01", "name": "alice" })
+}
+
+/// Serves an in-memory server on a random port for the rest of the test run and returns its address
+pub fn serve(model: Model) -> String {
+    serve_server(ResourceServer::open(model, None, account()).unwrap())
+}
+
+/// Same as [`serve`], but for a server that was already set up
+pub fn serve_server(server: ResourceServer) -> String {
+    let server: &'static ResourceServer = Box::leak(Box::new(server));
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    std::thread::spawn(move || server.serve(&listener));
+    addr
+}
+
+/// Sends a raw HTTP request and returns the whole response
+pub fn http_raw(addr: &str, request: &str) -> String {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    stream.write_all(request.as_bytes()).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    response
+}
+
+/// Sends a raw HTTP request and returns the status and the body of the response
+pub fn http(addr: &str, request: &str) -> (u16, Value) {
+    let response = http_raw(addr, request);
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split_whitespace().nth(1).unwrap().parse().unwrap();
+    (status, serde_json::from_str(body).unwrap())
+}
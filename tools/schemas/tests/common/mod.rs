@@ -0,0 +1,181 @@
+//! Helpers shared by the integration tests.
+//!
+//! Every test binary includes this module with `mod common;`, so not all helpers are used by each of them.
+#![allow(dead_code)]
+
+pub mod generated_crate;
+pub mod http;
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use odf_schemas::json_schema::{self, SchemaId};
+use odf_schemas::model::{self, Model, TypeId};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub const BASE: &str = "https://opendatafabric.org/schemas/test/v1alpha1";
+pub const METASCHEMA_MANIFEST: &str =
+    "https://opendatafabric.org/schemas/metaschemas/v1alpha1/Manifest";
+pub const METASCHEMA_RESOURCE_INPUT: &str =
+    "https://opendatafabric.org/schemas/metaschemas/v1alpha1/ResourceInput";
+pub const METASCHEMA_JSONSCHEMA: &str = "https://json-schema.org/draft/2020-12/schema";
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Model of the schemas in the repo
+pub fn load_repo_model() -> Model {
+    model::parse_jsonschema(json_schema::load_schemas(&repo_root().join("schemas")))
+}
+
+/// Model of the schemas in the repo where types that ODF crate defines by hand (`dtoType` hint) are generated too, so
+/// the generated code compiles on its own
+pub fn load_repo_model_standalone() -> Model {
+    fn strip_dto_types(value: &mut Value) {
+        match value {
+            Value::Object(obj) => {
+                obj.remove("dtoType");
+                obj.values_mut().for_each(strip_dto_types);
+            }
+            Value::Array(arr) => arr.iter_mut().for_each(strip_dto_types),
+            _ => (),
+        }
+    }
+
+    fn read_schemas(dir: &Path, schemas: &mut Vec<Value>) {
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            if path.is_dir() {
+                read_schemas(&path, schemas);
+            } else if path.extension().is_some_and(|e| e == "json") {
+                let mut schema: Value =
+                    serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+                strip_dto_types(&mut schema);
+                schemas.push(schema);
+            }
+        }
+    }
+
+    let mut schemas = Vec::new();
+    read_schemas(&repo_root().join("schemas"), &mut schemas);
+    load_model(schemas)
+}
+
+/// Loads fixture schemas the same way as the repo schemas, including the lint
+pub fn load_model(schemas: Vec<Value>) -> Model {
+    let dir = TempDir::new("schemas");
+    let schemas_dir = write_schemas(dir.path(), &schemas);
+
+    let schemas = json_schema::load_schemas(&schemas_dir);
+    json_schema::lint(&schemas);
+    model::parse_jsonschema(schemas)
+}
+
+/// Writes schemas into `{dir}/schemas` under paths that match their IDs, along with the metaschemas that lint expects
+/// to be present
+pub fn write_schemas(dir: &Path, schemas: &[Value]) -> PathBuf {
+    for schema in schemas {
+        let id = schema["$id"].as_str().unwrap();
+        let path = dir.join(format!(
+            "{}.json",
+            id.strip_prefix("https://opendatafabric.org/").unwrap()
+        ));
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, serde_json::to_string_pretty(schema).unwrap()).unwrap();
+    }
+
+    let metaschemas_dir = dir.join("schemas/metaschemas/v1alpha1");
+    std::fs::create_dir_all(&metaschemas_dir).unwrap();
+    for entry in std::fs::read_dir(repo_root().join("schemas/metaschemas/v1alpha1")).unwrap() {
+        let path = entry.unwrap().path();
+        std::fs::copy(&path, metaschemas_dir.join(path.file_name().unwrap())).unwrap();
+    }
+
+    dir.join("schemas")
+}
+
+/// ID of a type by its schema path, e.g. `dataset/v1alpha1/MetadataBlock`
+pub fn type_id(id: &str) -> TypeId {
+    TypeId::new(SchemaId::new(format!(
+        "https://opendatafabric.org/schemas/{id}"
+    )))
+}
+
+/// ID of a type defined by fixture schemas
+pub fn test_type_id(name: &str) -> TypeId {
+    TypeId::new(SchemaId::new(format!("{BASE}/{name}")))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Variant of a union that is tagged by the `kind` property
+pub fn tagged_variant(name: &str) -> Value {
+    tagged_variant_by("kind", name, name)
+}
+
+/// Variant of a union that is tagged by a custom property and value
+pub fn tagged_variant_by(property: &str, tag: &str, name: &str) -> Value {
+    json!({
+        "allOf": [
+            {
+                "properties": {
+                    property: { "type": "string", "const": tag }
+                },
+                "required": [property]
+            },
+            { "$ref": format!("#/$defs/{name}") }
+        ]
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Renders the model with one of the codegen targets
+pub fn render(
+    model: &Model,
+    render: fn(Model, &mut dyn std::io::Write) -> Result<(), std::io::Error>,
+) -> String {
+    let mut buf = Vec::new();
+    render(model.clone(), &mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Directory under the cargo's temporary directory for tests that is removed when dropped
+pub struct TempDir(PathBuf);
+
+impl TempDir {
+    pub fn new(name: &str) -> Self {
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(format!(
+            "{name}-{}-{}",
+            std::process::id(),
+            COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let _ = std::fs::remove_dir_all(&path);
+        std::fs::create_dir_all(&path).unwrap();
+        Self(path)
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.0);
+    }
+}
@@ -0,0 +1,17 @@
+//! Minimal stand-in for the macros that generated code uses to relate unions to their variants
+
+#[macro_export]
+macro_rules! impl_enum_with_variants {
+    ($typ:ident) => {};
+}
+
+#[macro_export]
+macro_rules! impl_enum_variant {
+    ($typ:ident :: $variant:ident ($inner:ty)) => {
+        impl From<$inner> for $typ {
+            fn from(v: $inner) -> Self {
+                Self::$variant(v)
+            }
+        }
+    };
+}
@@ -0,0 +1,18 @@
+//! Minimal stand-in for the error that generated serde proxies return when converting into DTOs
+
+#[derive(Debug)]
+pub struct ValidationError(pub String);
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+impl From<std::convert::Infallible> for ValidationError {
+    fn from(v: std::convert::Infallible) -> Self {
+        match v {}
+    }
+}
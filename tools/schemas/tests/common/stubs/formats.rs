@@ -0,0 +1,190 @@
+//! Minimal stand-ins for the identity and format types that generated code expects from the ODF crate
+
+macro_rules! string_type {
+    ($($name:ident),*) => {$(
+        #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(String);
+
+        impl $name {
+            pub fn new_unchecked(v: &str) -> Self {
+                Self(v.to_string())
+            }
+
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl std::str::FromStr for $name {
+            type Err = InvalidValue;
+
+            fn from_str(v: &str) -> Result<Self, Self::Err> {
+                Self::try_from(v)
+            }
+        }
+
+        impl TryFrom<&str> for $name {
+            type Error = InvalidValue;
+
+            fn try_from(v: &str) -> Result<Self, Self::Error> {
+                if v.is_empty() {
+                    return Err(InvalidValue(stringify!($name)));
+                }
+                Ok(Self(v.to_string()))
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    )*};
+}
+
+macro_rules! bytes_type {
+    ($($name:ident),*) => {$(
+        #[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        pub struct $name(Vec<u8>);
+
+        impl $name {
+            pub fn from_bytes(v: &[u8]) -> Result<Self, InvalidValue> {
+                if v.is_empty() {
+                    return Err(InvalidValue(stringify!($name)));
+                }
+                Ok(Self(v.to_vec()))
+            }
+
+            pub fn as_bytes(&self) -> Vec<u8> {
+                self.0.clone()
+            }
+        }
+    )*};
+}
+
+/// Text form of bytes is a prefix followed by bytes in hex
+macro_rules! hex_text {
+    ($($name:ident => $prefix:literal),*) => {$(
+        impl std::str::FromStr for $name {
+            type Err = InvalidValue;
+
+            fn from_str(v: &str) -> Result<Self, Self::Err> {
+                let err = || InvalidValue(stringify!($name));
+                let hex = v.strip_prefix($prefix).ok_or_else(err)?;
+                Self::from_bytes(&decode_hex(hex).ok_or_else(err)?)
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str($prefix)?;
+                self.0.iter().try_for_each(|b| write!(f, "{b:02x}"))
+            }
+        }
+    )*};
+}
+
+/// Serializes values as their text form
+macro_rules! serde_as_text {
+    ($($name:ident),*) => {$(
+        impl serde::Serialize for $name {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> serde::Deserialize<'de> for $name {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let s = String::deserialize(deserializer)?;
+                s.parse().map_err(serde::de::Error::custom)
+            }
+        }
+    )*};
+}
+
+string_type!(
+    AccountName,
+    DatasetAlias,
+    DatasetRef,
+    ResourceName,
+    TypeName,
+    TypeRef,
+    TypeUri
+);
+bytes_type!(AccountID, DatasetID, Did, Multihash, ResourceID);
+hex_text!(
+    AccountID => "did:odf:f",
+    DatasetID => "did:odf:f",
+    Did => "did:odf:f",
+    Multihash => "f"
+);
+serde_as_text!(
+    AccountName,
+    DatasetAlias,
+    DatasetRef,
+    ResourceName,
+    TypeName,
+    TypeRef,
+    TypeUri,
+    AccountID,
+    DatasetID,
+    Did,
+    Multihash,
+    ResourceID
+);
+
+impl Multihash {
+    pub fn from_digest_sha3_256(data: &[u8]) -> Self {
+        use sha3::Digest;
+        // Multicodec code of `sha3-256` followed by digest length
+        let mut bytes = vec![0x16, 0x20];
+        bytes.extend(sha3::Sha3_256::digest(data));
+        Self(bytes)
+    }
+}
+
+// Text form of resource IDs is a UUID
+impl std::str::FromStr for ResourceID {
+    type Err = InvalidValue;
+
+    fn from_str(v: &str) -> Result<Self, Self::Err> {
+        let bytes = decode_hex(&v.replace('-', "")).filter(|b| b.len() == 16);
+        Self::from_bytes(&bytes.ok_or(InvalidValue("ResourceID"))?)
+    }
+}
+
+impl std::fmt::Display for ResourceID {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (i, b) in self.0.iter().enumerate() {
+            if [4, 6, 8, 10].contains(&i) {
+                f.write_str("-")?;
+            }
+            write!(f, "{b:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Marker of types that can be referred to by `ResourceRef`
+pub trait IntoResourceRef {}
+
+#[derive(Debug)]
+pub struct InvalidValue(&'static str);
+
+impl std::fmt::Display for InvalidValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid {}", self.0)
+    }
+}
+
+impl std::error::Error for InvalidValue {}
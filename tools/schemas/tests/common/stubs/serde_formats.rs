@@ -0,0 +1,125 @@
+//! Minimal stand-ins for the serde helpers that generated YAML / JSON proxies use
+
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub mod datetime_rfc3339 {
+    use super::*;
+    use chrono::{DateTime, SecondsFormat, Utc};
+
+    pub fn serialize<S: Serializer>(v: &DateTime<Utc>, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&v.to_rfc3339_opts(SecondsFormat::AutoSi, true))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<DateTime<Utc>, D::Error> {
+        let s = String::deserialize(d)?;
+        DateTime::parse_from_rfc3339(&s)
+            .map(|v| v.with_timezone(&Utc))
+            .map_err(::serde::de::Error::custom)
+    }
+}
+
+pub mod datetime_rfc3339_opt {
+    use super::*;
+    use chrono::{DateTime, Utc};
+
+    pub fn serialize<S: Serializer>(v: &Option<DateTime<Utc>>, s: S) -> Result<S::Ok, S::Error> {
+        match v {
+            Some(v) => super::datetime_rfc3339::serialize(v, s),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<DateTime<Utc>>, D::Error> {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super::datetime_rfc3339")] DateTime<Utc>);
+
+        Ok(Option::<Wrapper>::deserialize(d)?.map(|w| w.0))
+    }
+}
+
+pub mod base64 {
+    use super::*;
+    use ::base64::Engine as _;
+    use ::base64::engine::general_purpose::STANDARD;
+
+    pub fn serialize<S: Serializer>(v: &Vec<u8>, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str(&STANDARD.encode(v))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(d)?;
+        STANDARD.decode(s).map_err(::serde::de::Error::custom)
+    }
+}
+
+pub mod base64_opt {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(v: &Option<Vec<u8>>, s: S) -> Result<S::Ok, S::Error> {
+        match v {
+            Some(v) => super::base64::serialize(v, s),
+            None => s.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Vec<u8>>, D::Error> {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super::base64")] Vec<u8>);
+
+        Ok(Option::<Wrapper>::deserialize(d)?.map(|w| w.0))
+    }
+}
+
+/// Values are kept as is, as generated values have no floats
+pub mod map_value_limited_precision {
+    use super::*;
+    use std::collections::BTreeMap;
+
+    pub fn serialize<S: Serializer>(
+        v: &BTreeMap<String, serde_json::Value>,
+        s: S,
+    ) -> Result<S::Ok, S::Error> {
+        v.serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        d: D,
+    ) -> Result<BTreeMap<String, serde_json::Value>, D::Error> {
+        BTreeMap::deserialize(d)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+macro_rules! or_string {
+    ($($name:ident),*) => {$(
+        /// Value that can also be written in its short string form
+        #[derive(Debug)]
+        pub struct $name<T>(pub T);
+
+        impl<T: Serialize> Serialize for $name<T> {
+            fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(s)
+            }
+        }
+
+        impl<'de, T> Deserialize<'de> for $name<T>
+        where
+            T: Deserialize<'de> + std::str::FromStr,
+            <T as std::str::FromStr>::Err: std::fmt::Display,
+        {
+            fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+                match serde_json::Value::deserialize(d)? {
+                    serde_json::Value::String(s) => {
+                        s.parse().map(Self).map_err(::serde::de::Error::custom)
+                    }
+                    v => T::deserialize(v).map(Self).map_err(::serde::de::Error::custom),
+                }
+            }
+        }
+    )*};
+}
+
+or_string!(StructOrString, UnionOrString);
@@ -0,0 +1,79 @@
+//! Minimal stand-in for the configuration types that generated code uses
+
+pub mod types {
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct ByteSize(u64);
+
+    impl ByteSize {
+        pub fn as_u64(&self) -> u64 {
+            self.0
+        }
+    }
+
+    impl From<u64> for ByteSize {
+        fn from(v: u64) -> Self {
+            Self(v)
+        }
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+    pub struct DurationString(std::time::Duration);
+
+    impl DurationString {
+        pub fn new(v: std::time::Duration) -> Self {
+            Self(v)
+        }
+    }
+
+    impl std::ops::Deref for DurationString {
+        type Target = std::time::Duration;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    impl From<std::time::Duration> for DurationString {
+        fn from(v: std::time::Duration) -> Self {
+            Self(v)
+        }
+    }
+
+    // Sizes are plain numbers and durations are written in milliseconds, which is enough for values to round-trip
+
+    impl serde::Serialize for ByteSize {
+        fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            s.serialize_u64(self.0)
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for ByteSize {
+        fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            u64::deserialize(d).map(Self)
+        }
+    }
+
+    impl serde::Serialize for DurationString {
+        fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+            s.collect_str(&format_args!("{}ms", self.0.as_millis()))
+        }
+    }
+
+    impl<'de> serde::Deserialize<'de> for DurationString {
+        fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+            let s = String::deserialize(d)?;
+            let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+            let (value, unit) = s.split_at(split);
+            let value: u64 = value.parse().map_err(serde::de::Error::custom)?;
+            let ms = match unit {
+                "ms" => 1,
+                "s" => 1000,
+                "m" => 60 * 1000,
+                "h" => 60 * 60 * 1000,
+                "d" => 24 * 60 * 60 * 1000,
+                _ => return Err(serde::de::Error::custom(format!("Invalid duration: {s}"))),
+            };
+            Ok(Self(std::time::Duration::from_millis(value * ms)))
+        }
+    }
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::*;
+use odf_schemas::{canonicalization, validation};
+use serde_json::json;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn messages(diagnostics: &[validation::Diagnostic]) -> Vec<(validation::Severity, String)> {
+    diagnostics
+        .iter()
+        .map(|d| (d.severity, d.to_string()))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_deprecated_names() {
+    let model = load_repo_model();
+    let typ = type_id("legacy/v0/AddPushSource");
+
+    let value = json!({
+        "source": "http",
+        "read": {
+            "kind": "JsonLines",
+        },
+        "merge": {
+            "kind": "Append",
+        },
+    });
+
+    assert_eq!(
+        messages(&validation::validate(&model, &typ, &value)),
+        vec![
+            (
+                validation::Severity::Warning,
+                "warning: source: Property `source` is deprecated, use `sourceName` instead"
+                    .to_string(),
+            ),
+            (
+                validation::Severity::Warning,
+                "warning: read.kind: Variant `JsonLines` is deprecated, use `NdJson` instead"
+                    .to_string(),
+            ),
+        ],
+    );
+
+    let canonical = canonicalization::canonicalize(&model, &typ, value);
+    assert_eq!(
+        canonical,
+        json!({
+            "sourceName": "http",
+            "read": {
+                "kind": "NdJson",
+            },
+            "merge": {
+                "kind": "Append",
+            },
+        })
+    );
+    assert!(validation::validate(&model, &typ, &canonical).is_empty());
+}
+
+#[test]
+fn test_deprecated_and_current_names() {
+    let model = load_repo_model();
+    let typ = type_id("legacy/v0/AddPushSource");
+
+    // Specifying both old and new names is ambiguous
+    let value = json!({
+        "source": "http",
+        "sourceName": "http",
+        "read": {
+            "kind": "NdJson",
+        },
+        "merge": {
+            "kind": "Append",
+        },
+    });
+
+    assert_eq!(
+        messages(&validation::validate(&model, &typ, &value)),
+        vec![(
+            validation::Severity::Error,
+            "error: source: Both `source` and its replacement `sourceName` are specified"
+                .to_string(),
+        )],
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_validation_errors() {
+    let model = load_repo_model();
+    let typ = type_id("legacy/v0/AddPushSource");
+
+    let value = json!({
+        "sourceName": "http",
+        "read": {
+            "kind": "Parquet2",
+        },
+        "foo": 1,
+    });
+
+    let errors: Vec<_> = validation::validate(&model, &typ, &value)
+        .into_iter()
+        .map(|d| d.to_string())
+        .collect();
+
+    assert_eq!(errors.len(), 3, "{errors:#?}");
+    assert!(errors[0].starts_with("error: read.kind: Unknown variant `Parquet2`"));
+    assert_eq!(errors[1], "error: foo: Unknown property `foo`");
+    assert_eq!(errors[2], "error: Missing required property `merge`");
+}
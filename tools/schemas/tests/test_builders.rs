@@ -0,0 +1,166 @@
+mod common;
+
+use common::generated_crate::GeneratedCrate;
+use common::*;
+use odf_schemas::codegen;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// `Job` mixes required, optional, defaulted, and constant fields, while `Envelope` is generic
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": format!("{BASE}/Job"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Job.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "name", "dataset"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "const": format!("{BASE}/Job"),
+                    "description": "Schema."
+                },
+                "name": { "type": "string", "description": "Name." },
+                "dataset": { "type": "string", "format": "dataset-ref", "description": "Dataset." },
+                "maxSize": { "type": "string", "format": "byte-size", "description": "Max size." },
+                "policy": {
+                    "$ref": format!("{BASE}/Policy"),
+                    "description": "Policy.",
+                    "default": "Reject",
+                    "unevaluatedProperties": false
+                },
+                "enabled": { "type": "boolean", "description": "Enabled.", "default": true }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Policy"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Policy.",
+            "type": "string",
+            "format": "int32",
+            "enum": ["Reject", "DropOldest"]
+        }),
+        json!({
+            "$id": format!("{BASE}/Envelope"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Envelope.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "spec"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "spec": {
+                    "type": "object",
+                    "format": "fragment",
+                    "description": "Spec."
+                },
+                "previous": {
+                    "type": "object",
+                    "format": "fragment",
+                    "generic": "SpecT",
+                    "description": "Previous."
+                }
+            }
+        }),
+    ]
+}
+
+const BUILDERS_TEST: &str = r#"
+use odf::dataset::DatasetRef;
+use odf::resource::TypeUri;
+use odf::test::{Envelope, Job, Policy};
+
+#[test]
+fn builders_required_fields() {
+    let job = Job::builder()
+        .name("job")
+        .dataset(DatasetRef::new_unchecked("dataset"))
+        .build();
+
+    assert_eq!(job.name, "job");
+    assert_eq!(job.dataset, DatasetRef::new_unchecked("dataset"));
+    assert_eq!(job.max_size, None);
+    assert_eq!(job.policy, None);
+    assert_eq!(job.enabled, None);
+}
+
+#[test]
+fn builders_optional_fields_in_any_state() {
+    let job = Job::builder()
+        .enabled(false)
+        .name("job")
+        .policy(Policy::DropOldest)
+        .dataset(DatasetRef::new_unchecked("dataset"))
+        .max_size(1024u64)
+        .build();
+
+    assert_eq!(job.max_size.map(|v| v.as_u64()), Some(1024));
+    assert_eq!(job.policy, Some(Policy::DropOldest));
+    assert_eq!(job.enabled, Some(false));
+}
+
+#[test]
+fn builders_generic() {
+    let envelope = Envelope::builder()
+        .schema(TypeUri::new_unchecked("https://example.com/Envelope"))
+        .spec(1u32)
+        .previous(2)
+        .build();
+
+    assert_eq!(envelope.spec, 1);
+    assert_eq!(envelope.previous, Some(2));
+}
+"#;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Required fields are set in any order and optional ones keep their state, and type parameters of generic structs are
+// inferred from the values
+#[test]
+fn test_builders() {
+    let Some(krate) = GeneratedCrate::new(&load_model(fixture_schemas())) else {
+        return;
+    };
+
+    krate.add_test("builders", BUILDERS_TEST);
+    krate.test();
+}
+
+// Only a builder with all required fields set can be finalized
+#[test]
+fn test_builders_missing_required_field() {
+    let Some(krate) = GeneratedCrate::new(&load_model(fixture_schemas())) else {
+        return;
+    };
+
+    let errors = krate.compile_error(
+        "builders_missing_required_field",
+        "#[test]\nfn build() {\n    odf::test::Job::builder().name(\"job\").build();\n}\n",
+    );
+    assert!(errors.contains("no method named `build` found"), "{errors}");
+}
+
+// Constants are not settable, and documented defaults are repeated on setters
+#[test]
+fn test_builders_docs() {
+    let code = render(&load_model(fixture_schemas()), codegen::rust_dtos::render);
+
+    assert!(
+        code.contains("pub struct JobBuilder<TName = Unset, TDataset = Unset> {"),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "/// Enabled.\n///\n/// Defaults to: true\npub fn enabled(mut self, v: impl Into<bool>) -> Self {"
+        ),
+        "{code}"
+    );
+}
@@ -0,0 +1,215 @@
+mod common;
+
+use common::*;
+use odf_schemas::bundle::{self, ProblemKind};
+use odf_schemas::model::Model;
+use odf_schemas::resolve::ResourceStore;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn resource(kind: &str, name: &str, spec: Value) -> Value {
+    json!({
+        "$schema": format!("https://opendatafabric.org/schemas/{kind}"),
+        "headers": { "name": name },
+        "spec": spec
+    })
+}
+
+fn relations(name: &str, object: &str) -> Value {
+    resource(
+        "auth/v1alpha1/Relations",
+        name,
+        json!({
+            "relations": [
+                { "subject": "Account:alice", "relation": "role", "value": "reader", "object": object }
+            ]
+        }),
+    )
+}
+
+fn check_example(model: &Model, name: &str) -> Vec<(String, ProblemKind)> {
+    let mut store = ResourceStore::new(model, None);
+    store
+        .load_dir(&repo_root().join("examples").join(name))
+        .unwrap();
+    bundle::check(&store)
+        .iter()
+        .map(|p| (p.path.clone(), p.kind))
+        .collect()
+}
+
+/// Problems of the given kind in a bundle that has all of them, as resource, path and message
+fn check_bundle(kind: ProblemKind) -> Vec<(String, String, String)> {
+    let model = load_repo_model();
+    let mut store = ResourceStore::new(&model, None);
+    for manifest in [
+        resource(
+            "auth/v1alpha1/Account",
+            "alice",
+            json!({ "accountType": "User" }),
+        ),
+        resource(
+            "config/v1alpha1/SecretSet",
+            "aws",
+            json!({ "secrets": { "accessKey": "a", "s3.secretKey": "b" } }),
+        ),
+        resource(
+            "dataset/v1alpha1/Dataset",
+            "ca.bankofcanada",
+            json!({ "kind": "Root", "metadata": [], "volume": "Dataset:ca.bankofcanada" }),
+        ),
+        resource(
+            "storage/v1alpha1/PersistentVolume",
+            "s3",
+            json!({
+                "kind": "S3",
+                "bucket": "my-bucket",
+                "credentials": {
+                    "accessKey": "SecretSet:aws#accessKey",
+                    "secretKey": "SecretSet:aws#s3.secretKey"
+                }
+            }),
+        ),
+        resource(
+            "flow/v1alpha1/Flow",
+            "ca.bankofcanada",
+            json!({
+                "target": "Dataset:ca.bankofcanada",
+                "triggers": [{ "kind": "Source", "source": "ca.bankofcanada" }],
+                "tasks": [
+                    { "kind": "Ingest", "source": { "type": "Source", "name": "ca.bankofcanada" } },
+                    { "kind": "WebhookCall", "target": "com.example.hook" }
+                ]
+            }),
+        ),
+        resource(
+            "config/v1alpha1/VariableSet",
+            "vars",
+            json!({ "variables": { "port": "5113" } }),
+        ),
+        resource(
+            "source/v1alpha1/Source",
+            "fx",
+            json!({
+                "config": {
+                    "host": "VariableSet:vars#host",
+                    "port": "VariableSet:vars#port",
+                    "token": "Dataset:ca.bankofcanada#token"
+                },
+                "ingress": { "kind": "Url", "url": "https://example.com" },
+                "read": { "kind": "Csv" },
+                "merge": { "kind": "Append" }
+            }),
+        ),
+        relations("a", "Relations:b"),
+        relations("b", "Relations:a"),
+        relations("c", "Relations:c"),
+    ] {
+        store.insert(None, manifest).unwrap();
+    }
+
+    bundle::check(&store)
+        .into_iter()
+        .filter(|p| p.kind == kind)
+        .map(|p| (p.resource.to_string(), p.path, p.message))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Examples are self-contained bundles, except for the secrets and variables of a source
+#[test]
+fn test_bundle_examples() {
+    let model = load_repo_model();
+
+    for name in ["storage-volume", "sink-webhook-dataset-events"] {
+        let problems = check_example(&model, name);
+        assert!(problems.is_empty(), "{name}: {problems:#?}");
+    }
+    assert_eq!(
+        check_example(&model, "source-polling-url"),
+        [
+            ("spec.config.apiKey".to_string(), ProblemKind::Dangling),
+            ("spec.config.startDate".to_string(), ProblemKind::Dangling),
+        ]
+    );
+}
+
+#[test]
+fn test_bundle_type_mismatch() {
+    assert_eq!(
+        check_bundle(ProblemKind::TypeMismatch),
+        [
+            (
+                "Dataset:ca.bankofcanada".to_string(),
+                "spec.volume".to_string(),
+                "Expected a reference to PersistentVolume but got Dataset".to_string()
+            ),
+            (
+                "Flow:ca.bankofcanada".to_string(),
+                "spec.triggers[0].source".to_string(),
+                "Reference \"ca.bankofcanada\" points to Dataset:ca.bankofcanada of another type"
+                    .to_string()
+            ),
+            (
+                "Source:fx".to_string(),
+                "spec.config.token".to_string(),
+                "Referenced Dataset:ca.bankofcanada does not hold values".to_string()
+            ),
+        ]
+    );
+}
+
+#[test]
+fn test_bundle_dangling() {
+    assert_eq!(
+        check_bundle(ProblemKind::Dangling),
+        [
+            (
+                "Flow:ca.bankofcanada".to_string(),
+                "spec.tasks[0].source".to_string(),
+                "Reference {\"type\":\"Source\",\"name\":\"ca.bankofcanada\"} does not match any resource"
+                    .to_string()
+            ),
+            (
+                "Flow:ca.bankofcanada".to_string(),
+                "spec.tasks[1].target".to_string(),
+                "Reference \"com.example.hook\" does not match any resource".to_string()
+            ),
+        ]
+    );
+}
+
+// Value references are checked against the keys of the referenced set
+#[test]
+fn test_bundle_missing_value() {
+    assert_eq!(
+        check_bundle(ProblemKind::MissingValue),
+        [(
+            "Source:fx".to_string(),
+            "spec.config.host".to_string(),
+            "Referenced VariableSet:vars has no value `host`".to_string()
+        )]
+    );
+}
+
+// Cycles are reported once, on the resource that closes them
+#[test]
+fn test_bundle_cycles() {
+    assert_eq!(
+        check_bundle(ProblemKind::Cycle),
+        [
+            (
+                "Relations:b".to_string(),
+                "spec.relations[0].object".to_string(),
+                "Reference cycle: Relations:a -> Relations:b -> Relations:a".to_string()
+            ),
+            (
+                "Relations:c".to_string(),
+                "spec.relations[0].object".to_string(),
+                "Reference cycle: Relations:c -> Relations:c".to_string()
+            ),
+        ]
+    );
+}
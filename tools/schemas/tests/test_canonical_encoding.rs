@@ -0,0 +1,382 @@
+mod common;
+
+use common::generated_crate::GeneratedCrate;
+use common::*;
+use odf_schemas::model::{Model, TypeDefinition, TypeId};
+use odf_schemas::{canonical_encoding, codegen};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+/// Golden vectors of every hashable type, that must exist for its current schema version
+fn vectors(model: &Model) -> Vec<(TypeId, Vec<Value>)> {
+    let vectors_dir = repo_root().join("tools/schemas/tests/vectors");
+
+    let hashable: Vec<_> = model
+        .types
+        .values()
+        .filter_map(|t| match t {
+            TypeDefinition::Struct(t) if t.hashable().is_some() => Some(t),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        hashable.iter().map(|t| t.id.join("")).collect::<Vec<_>>(),
+        ["MetadataBlock"]
+    );
+
+    hashable
+        .into_iter()
+        .map(|typ| {
+            let path = vectors_dir.join(format!(
+                "{}.yaml",
+                typ.id
+                    .schema_id()
+                    .strip_prefix("https://opendatafabric.org/schemas/")
+                    .unwrap()
+            ));
+            let content = std::fs::read_to_string(&path)
+                .unwrap_or_else(|e| panic!("Missing vectors {}: {e}", path.display()));
+            let vectors: Vec<Value> = serde_yaml::from_str(&content).unwrap();
+            assert!(!vectors.is_empty(), "{}", path.display());
+            (typ.id.clone(), vectors)
+        })
+        .collect()
+}
+
+/// Encoding of golden vectors by proxies generated with flatc 23, which is not canonical
+fn flatc_23_vectors() -> Vec<Value> {
+    let path = repo_root()
+        .join("tools/schemas/tests/vectors/dataset/v1alpha1/MetadataBlock.flatc-23.yaml");
+    let vectors: Vec<Value> =
+        serde_yaml::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+    assert!(!vectors.is_empty());
+    vectors
+}
+
+fn encode_block(model: &Model, value: Value) -> String {
+    hex(
+        &canonical_encoding::encode(model, &type_id("dataset/v1alpha1/MetadataBlock"), value)
+            .unwrap(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_canonical_encoding_vectors() {
+    let model = load_repo_model();
+
+    for (type_id, vectors) in vectors(&model) {
+        for v in vectors {
+            let name = v["name"].as_str().unwrap();
+            let value = v["value"].clone();
+
+            assert_eq!(
+                hex(&canonical_encoding::encode(&model, &type_id, value.clone()).unwrap()),
+                v["bytes"].as_str().unwrap(),
+                "{name}"
+            );
+            assert_eq!(
+                canonical_encoding::content_hash(&model, &type_id, value).unwrap(),
+                v["multihash"].as_str().unwrap(),
+                "{name}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_canonical_encoding_layout() {
+    let model = load_repo_model();
+    let encode = |value| encode_block(&model, value);
+
+    // Layout does not depend on how the value was written
+    assert_eq!(
+        encode(json!({
+            "systemTime": "2024-01-01T00:00:00Z",
+            "sequenceNumber": 5,
+            "event": {
+                "kind": "SetDataSchema",
+                "schema": {
+                    "fields": [{ "name": "a", "type": "Int64" }],
+                    "extra": { "b.io/x": 1, "a.io/y": { "q": 1, "p": 2 } }
+                }
+            }
+        })),
+        encode(json!({
+            "event": {
+                "schema": {
+                    "extra": { "a.io/y": { "p": 2, "q": 1 }, "b.io/x": 1 },
+                    "fields": [{ "type": { "kind": "int64" }, "name": "a" }]
+                },
+                "kind": "setDataSchema"
+            },
+            "prevBlockHash": null,
+            "sequenceNumber": 5,
+            "systemTime": "2024-01-01T01:00:00+01:00"
+        })),
+    );
+}
+
+#[test]
+fn test_canonical_encoding_defaults() {
+    let model = load_repo_model();
+    let encode = |value| encode_block(&model, value);
+
+    // Defaults of required scalars are omitted, while optional ones are written to tell them apart from absent values
+    let block_at = |sequence_number: u64, prev_offset: Option<u64>| {
+        let mut event = json!({ "kind": "AddData" });
+        if let Some(prev_offset) = prev_offset {
+            event["prevOffset"] = prev_offset.into();
+        }
+        encode(json!({
+            "systemTime": "2024-01-01T00:00:00Z",
+            "sequenceNumber": sequence_number,
+            "event": event,
+        }))
+    };
+    assert!(block_at(0, None).len() < block_at(1, None).len());
+    assert!(block_at(0, None).len() < block_at(0, Some(0)).len());
+    assert_eq!(block_at(0, Some(0)).len(), block_at(0, Some(1)).len());
+}
+
+#[test]
+fn test_canonical_encoding_invalid() {
+    let model = load_repo_model();
+    let encode = |value| {
+        canonical_encoding::encode(&model, &type_id("dataset/v1alpha1/MetadataBlock"), value)
+            .unwrap_err()
+    };
+    let block = |event: Value| {
+        json!({
+            "systemTime": "2024-01-01T00:00:00Z",
+            "sequenceNumber": 0,
+            "event": event,
+        })
+    };
+
+    // Malformed values are reported with the path of the field instead of panicking
+    assert_eq!(
+        encode(json!({ "systemTime": "2024-01-01T00:00:00Z", "sequenceNumber": 0 })),
+        "Missing required field: MetadataBlock.event",
+    );
+    assert_eq!(
+        encode(json!({
+            "systemTime": "2024-02-30T00:00:00Z",
+            "sequenceNumber": 0,
+            "event": { "kind": "AddData" },
+        })),
+        "Invalid date-time: MetadataBlock.systemTime: 2024-02-30T00:00:00Z",
+    );
+    assert_eq!(
+        encode(json!({
+            "systemTime": "yesterday",
+            "sequenceNumber": 0,
+            "event": { "kind": "AddData" },
+        })),
+        "Invalid date-time: MetadataBlock.systemTime: yesterday",
+    );
+    assert_eq!(
+        encode(block(json!({
+            "kind": "Seed",
+            "datasetId": "did:key:z6Mk",
+            "datasetKind": "Root",
+        }))),
+        "Only `did:odf` identifiers are supported: MetadataBlock.event.Seed.datasetId: did:key:z6Mk",
+    );
+    assert_eq!(
+        encode(block(json!({
+            "kind": "Seed",
+            "datasetId": "did:odf:zabc",
+            "datasetKind": "Root",
+        }))),
+        "Only `base16` multibase encoding is supported: MetadataBlock.event.Seed.datasetId: zabc",
+    );
+    assert_eq!(
+        encode(block(json!({
+            "kind": "Seed",
+            "datasetId": "did:odf:fed0x",
+            "datasetKind": "Root",
+        }))),
+        "Invalid base16 string: MetadataBlock.event.Seed.datasetId: fed0x",
+    );
+
+    // Base64 strings with non-zero leftover bits would decode into the same bytes as the canonical one
+    let schema = |raw: &str| block(json!({ "kind": "SetDataSchema", "rawArrowSchema": raw }));
+    canonical_encoding::encode(
+        &model,
+        &type_id("dataset/v1alpha1/MetadataBlock"),
+        schema("QQ=="),
+    )
+    .unwrap();
+    assert_eq!(
+        encode(schema("QR==")),
+        "Invalid base64 string: MetadataBlock.event.SetDataSchema.rawArrowSchema: QR==",
+    );
+    assert_eq!(
+        encode(schema("QUJDR")),
+        "Invalid base64 string: MetadataBlock.event.SetDataSchema.rawArrowSchema: QUJDR",
+    );
+
+    assert_eq!(
+        encode(block(json!({ "kind": "Unknown" }))),
+        format!(
+            "Unknown variant of MetadataEvent: MetadataBlock.event: {}",
+            json!({ "kind": "Unknown" })
+        ),
+    );
+
+    // Content hash of a type that isn't hashable is an error too
+    assert_eq!(
+        canonical_encoding::content_hash(
+            &model,
+            &type_id("dataset/v1alpha1/DatasetVocabulary"),
+            json!({})
+        )
+        .unwrap_err(),
+        "Type is not hashable: DatasetVocabulary",
+    );
+}
+
+#[test]
+fn test_canonical_encoding_codegen() {
+    let model = load_repo_model();
+
+    // Generated code produces the same layout via the canonical builder
+    let code = render(&model, codegen::rust_serde_flatbuffers::render);
+    assert!(
+        code.contains("impl FlatbuffersHashable for odf::dataset::MetadataBlock {"),
+        "{code}"
+    );
+    assert!(
+        code.contains("canonical_manifest(0x400000, 2, &self.to_canonical_bytes()?)"),
+        "{code}"
+    );
+    assert!(code.contains("fb.force_defaults(false);"), "{code}");
+}
+
+#[test]
+fn test_canonical_encoding_generated_vectors() {
+    let model = load_repo_model_standalone();
+    let Some(krate) = GeneratedCrate::new(&model) else {
+        return;
+    };
+
+    // Proxies generated by flatc 23 write structs like `Timestamp` unaligned, so they are checked against the layout
+    // they are known to produce instead
+    match krate.flatc_major_version() {
+        ..23 => {
+            eprintln!(
+                "Skipping canonical encoding of generated code: flatc 23 or newer is required"
+            );
+            return;
+        }
+        23 => {
+            let golden = &vectors(&model)[0].1;
+            let mut cases = String::new();
+            for v in flatc_23_vectors() {
+                let name = v["name"].as_str().unwrap();
+                let g = golden.iter().find(|g| g["name"] == name).unwrap();
+                assert_ne!(v["bytes"], g["bytes"], "{name}");
+                cases.push_str(&format!(
+                    "    ({name:?}, {:?}, {:?}),\n",
+                    g["bytes"].as_str().unwrap(),
+                    v["bytes"].as_str().unwrap(),
+                ));
+            }
+
+            krate.add_test(
+                "vectors",
+                &format!(
+                    "const VECTORS: &[(&str, &str, &str)] = &[\n{cases}];\n{FLATC_23_VECTORS_TEST}"
+                ),
+            );
+            krate.test();
+            return;
+        }
+        _ => (),
+    }
+
+    let mut cases = String::new();
+    for (id, vectors) in vectors(&model) {
+        assert_eq!(id, type_id("dataset/v1alpha1/MetadataBlock"));
+        for v in vectors {
+            cases.push_str(&format!(
+                "    ({:?}, {}, {:?}, {:?}),\n",
+                v["name"].as_str().unwrap(),
+                v["value"]["sequenceNumber"].as_u64().unwrap(),
+                v["bytes"].as_str().unwrap(),
+                v["multihash"].as_str().unwrap(),
+            ));
+        }
+    }
+
+    krate.add_test(
+        "vectors",
+        &format!("const VECTORS: &[(&str, u64, &str, &str)] = &[\n{cases}];\n{VECTORS_TEST}"),
+    );
+    krate.test();
+}
+
+/// Decodes golden bytes with generated convertors and checks that generated canonical encoding reproduces them
+const VECTORS_TEST: &str = r#"
+use odf::serde::flatbuffers::convertors_generated::*;
+use odf::serde::flatbuffers::proxies_generated as fb;
+
+fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn golden_vectors() {
+    for (name, sequence_number, bytes, multihash) in VECTORS {
+        let bytes = unhex(bytes);
+        let block: odf::dataset::MetadataBlock =
+            deserialize_verified::<fb::MetadataBlock, _>(&bytes, &FlatbuffersDecodeLimits::default())
+                .unwrap();
+        assert_eq!(block.sequence_number, *sequence_number, "{name}");
+
+        assert_eq!(block.to_canonical_bytes().unwrap(), bytes, "{name}");
+        assert_eq!(
+            block.to_canonical_manifest().unwrap(),
+            canonical_manifest(0x400000, 2, &bytes),
+            "{name}"
+        );
+        assert_eq!(block.multihash().unwrap().to_string(), *multihash, "{name}");
+    }
+}
+"#;
+
+/// Decodes golden bytes with generated convertors and checks that generated encoding differs from them exactly as
+/// recorded for flatc 23
+const FLATC_23_VECTORS_TEST: &str = r#"
+use odf::serde::flatbuffers::convertors_generated::*;
+use odf::serde::flatbuffers::proxies_generated as fb;
+
+fn unhex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+#[test]
+fn flatc_23_vectors() {
+    for (name, golden, bytes) in VECTORS {
+        let block: odf::dataset::MetadataBlock = deserialize_verified::<fb::MetadataBlock, _>(
+            &unhex(golden),
+            &FlatbuffersDecodeLimits::default(),
+        )
+        .unwrap();
+        assert_eq!(block.to_canonical_bytes().unwrap(), unhex(bytes), "{name}");
+    }
+}
+"#;
@@ -0,0 +1,134 @@
+mod common;
+
+use common::*;
+use odf_schemas::codegen;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Two contexts that both define a `Status` type
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": "https://opendatafabric.org/schemas/alpha/v1alpha1/Job",
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Job.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "status", "outcome"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "status": {
+                    "$ref": "https://opendatafabric.org/schemas/alpha/v1alpha1/Status",
+                    "description": "Status.",
+                    "unevaluatedProperties": false
+                },
+                "outcome": {
+                    "$ref": "https://opendatafabric.org/schemas/alpha/v1alpha1/Outcome",
+                    "description": "Outcome.",
+                    "unevaluatedProperties": false
+                }
+            }
+        }),
+        json!({
+            "$id": "https://opendatafabric.org/schemas/alpha/v1alpha1/Status",
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Status of a job.",
+            "type": "string",
+            "enum": ["Running", "Finished"]
+        }),
+        json!({
+            "$id": "https://opendatafabric.org/schemas/alpha/v1alpha1/Outcome",
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Outcome.",
+            "oneOf": [
+                {
+                    "allOf": [
+                        {
+                            "properties": { "kind": { "type": "string", "const": "Status" } },
+                            "required": ["kind"]
+                        },
+                        { "$ref": "https://opendatafabric.org/schemas/beta/v1alpha1/Status" }
+                    ]
+                }
+            ]
+        }),
+        json!({
+            "$id": "https://opendatafabric.org/schemas/beta/v1alpha1/Status",
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Status of a delivery.",
+            "type": "object",
+            "required": ["code"],
+            "properties": {
+                "code": { "type": "integer", "format": "int32", "description": "Code." }
+            }
+        }),
+    ]
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_ambiguous_names() {
+    let model = load_model(fixture_schemas());
+
+    assert_eq!(
+        model.ambiguous_names().into_iter().collect::<Vec<_>>(),
+        ["Status"]
+    );
+}
+
+#[test]
+fn test_context_modules_flatbuffers() {
+    let model = load_model(fixture_schemas());
+
+    // Clashing types are placed into context namespaces
+    let fbs = render(&model, codegen::flatbuffers_schema::render);
+    assert!(
+        fbs.contains("namespace alpha;\n\nenum Status: int32 {"),
+        "{fbs}"
+    );
+    assert!(fbs.contains("namespace beta;\n\ntable Status {"), "{fbs}");
+    assert!(fbs.contains("status: alpha.Status;"), "{fbs}");
+    assert!(fbs.contains("Status: beta.Status,"), "{fbs}");
+    assert!(fbs.contains("table Job {"), "{fbs}");
+
+    let fb_serde = render(&model, codegen::rust_serde_flatbuffers::render);
+    assert!(
+        fb_serde.contains("type OffsetT = WIPOffset<fb::beta::Status<'fb>>;"),
+        "{fb_serde}"
+    );
+    assert!(
+        fb_serde.contains("unsafe { fb::beta::Status::init_from_table(table) }"),
+        "{fb_serde}"
+    );
+    assert!(
+        fb_serde.contains("impl TryFrom<&odf::alpha::Status> for fb::alpha::Status {"),
+        "{fb_serde}"
+    );
+}
+
+#[test]
+fn test_context_modules_markdown() {
+    let model = load_model(fixture_schemas());
+
+    // Clashing types get context-qualified anchors
+    let md = render(&model, codegen::markdown::render);
+    assert!(md.contains("<a name=\"alpha-status-schema\"></a>"), "{md}");
+    assert!(md.contains("<a name=\"beta-status-schema\"></a>"), "{md}");
+    assert!(md.contains("<a name=\"job-schema\"></a>"), "{md}");
+    assert!(md.contains("[Status](#alpha-status-schema)"), "{md}");
+}
+
+#[test]
+fn test_context_modules_mermaid() {
+    let model = load_model(fixture_schemas());
+
+    // Clashing node IDs are qualified
+    let erd = render(&model, codegen::mermaid_erd::render);
+    assert!(erd.contains("Job -->|\"status\"| alpha_Status"), "{erd}");
+}
@@ -0,0 +1,218 @@
+mod common;
+
+use common::*;
+use odf_schemas::diff::{self, ChangeKind, PatchOp};
+use odf_schemas::model::Model;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn dataset(labels: Value, fields: Value, events: Value) -> Value {
+    let mut metadata = vec![json!({
+        "kind": "SetDataSchema",
+        "schema": { "fields": fields }
+    })];
+    metadata.extend(events.as_array().unwrap().iter().cloned());
+
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/dataset/v1alpha1/Dataset",
+        "headers": { "name": "ca.bankofcanada", "labels": labels },
+        "spec": { "kind": "Root", "metadata": metadata }
+    })
+}
+
+fn summary(model: &Model, old: &Value, new: &Value) -> Vec<String> {
+    let changes = diff::diff_manifests(model, old, new).unwrap();
+
+    // Patch must always reproduce the new version
+    let patch: Vec<PatchOp> = serde_json::from_value(diff::to_json_patch(&changes)).unwrap();
+    let mut patched = old.clone();
+    diff::apply(&mut patched, &patch).unwrap();
+    assert_eq!(&patched, new);
+
+    changes.iter().map(ToString::to_string).collect()
+}
+
+fn license() -> Value {
+    json!({
+        "kind": "SetLicense",
+        "name": "Apache 2.0",
+        "shortName": "Apache-2.0",
+        "websiteUrl": "https://www.apache.org/licenses/LICENSE-2.0"
+    })
+}
+
+fn old() -> Value {
+    dataset(
+        json!({ "team": "fx", "tier": "gold" }),
+        json!([
+            { "name": "offset", "type": "UInt64" },
+            { "name": "op", "type": "Int32" },
+            { "name": "event_time", "type": "Timestamp" },
+            { "name": "rate", "type": { "kind": "Decimal", "precision": 19, "scale": 6 } }
+        ]),
+        json!([license()]),
+    )
+}
+
+fn renamed() -> Value {
+    dataset(
+        json!({ "team": "rates", "owner/id": "alice" }),
+        json!([
+            { "name": "event_time", "type": "Timestamp" },
+            { "name": "offset", "type": "UInt64" },
+            { "name": "rate", "type": { "kind": "Decimal", "precision": 19, "scale": 8 } },
+            { "name": "source", "type": "String" }
+        ]),
+        json!([license()]),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_diff_identical() {
+    let model = load_repo_model();
+
+    assert!(summary(&model, &old(), &old()).is_empty());
+}
+
+// Fields are matched by name regardless of their position, labels by key
+#[test]
+fn test_diff_merge_keys() {
+    let model = load_repo_model();
+
+    assert_eq!(
+        summary(&model, &old(), &renamed()),
+        [
+            "~ headers.labels.team: \"fx\" -> \"rates\"",
+            "- headers.labels.tier: \"gold\"",
+            "+ headers.labels.owner/id: \"alice\"",
+            "- spec.metadata[0].schema.fields[name=op]: {\"name\":\"op\",\"type\":\"Int32\"}",
+            "~ spec.metadata[0].schema.fields[name=event_time]: moved from index 1 to 0",
+            "~ spec.metadata[0].schema.fields[name=rate].type.scale: 6 -> 8",
+            "+ spec.metadata[0].schema.fields[name=source]: {\"name\":\"source\",\"type\":\"String\"}",
+        ]
+    );
+}
+
+#[test]
+fn test_diff_json_patch() {
+    let model = load_repo_model();
+
+    let changes = diff::diff_manifests(&model, &old(), &renamed()).unwrap();
+    assert_eq!(
+        changes[2].op,
+        PatchOp::Add {
+            path: "/headers/labels/owner~1id".to_string(),
+            value: json!("alice"),
+        }
+    );
+    assert_eq!(
+        changes[4].op,
+        PatchOp::Move {
+            from: "/spec/metadata/0/schema/fields/1".to_string(),
+            path: "/spec/metadata/0/schema/fields/0".to_string(),
+        }
+    );
+}
+
+// Values of a different union variant are replaced as a whole, fields within a variant are compared
+#[test]
+fn test_diff_unions() {
+    let model = load_repo_model();
+
+    let new = dataset(
+        json!({ "team": "fx", "tier": "gold" }),
+        json!([
+            { "name": "offset", "type": "UInt64" },
+            { "name": "op", "type": "Int32" },
+            { "name": "event_time", "type": "Timestamp" },
+            { "name": "rate", "type": "Float64" }
+        ]),
+        json!([{ "kind": "SetLicense", "name": "MIT", "shortName": "MIT", "websiteUrl": "https://mit-license.org" }]),
+    );
+    assert_eq!(
+        summary(&model, &old(), &new),
+        [
+            "~ spec.metadata[0].schema.fields[name=rate].type: {\"kind\":\"Decimal\",\"precision\":19,\"scale\":6} -> \"Float64\"",
+            "~ spec.metadata[1].name: \"Apache 2.0\" -> \"MIT\"",
+            "~ spec.metadata[1].shortName: \"Apache-2.0\" -> \"MIT\"",
+            "~ spec.metadata[1].websiteUrl: \"https://www.apache.org/licenses/LICENSE-2.0\" -> \"https://mit-license.org\"",
+        ]
+    );
+}
+
+// Arrays with duplicate keys fall back to positional comparison
+#[test]
+fn test_diff_duplicate_keys() {
+    let model = load_repo_model();
+
+    let dup = dataset(
+        json!({ "team": "fx", "tier": "gold" }),
+        json!([
+            { "name": "offset", "type": "UInt64" },
+            { "name": "offset", "type": "Int64" }
+        ]),
+        json!([]),
+    );
+    let changes = summary(&model, &old(), &dup);
+    assert!(
+        changes.contains(
+            &"~ spec.metadata[0].schema.fields[1].name: \"op\" -> \"offset\"".to_string()
+        ),
+        "{changes:#?}"
+    );
+    assert!(
+        changes.contains(&"- spec.metadata[1]: {\"kind\":\"SetLicense\",\"name\":\"Apache 2.0\",\"shortName\":\"Apache-2.0\",\"websiteUrl\":\"https://www.apache.org/licenses/LICENSE-2.0\"}".to_string()),
+        "{changes:#?}"
+    );
+}
+
+// Manifests of different kinds are not comparable
+#[test]
+fn test_diff_different_kinds() {
+    let model = load_repo_model();
+
+    let other = json!({ "$schema": "https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolume" });
+    assert!(diff::diff_manifests(&model, &old(), &other).is_err());
+}
+
+// Failed patches leave the value untouched
+#[test]
+fn test_diff_apply_failed() {
+    let old = old();
+    let mut value = old.clone();
+    let err = diff::apply(
+        &mut value,
+        &[
+            PatchOp::Remove {
+                path: "/headers/labels/team".to_string(),
+            },
+            PatchOp::Test {
+                path: "/headers/name".to_string(),
+                value: json!("other"),
+            },
+        ],
+    )
+    .unwrap_err();
+    assert_eq!(err, "Value at `/headers/name` is not \"other\"");
+    assert_eq!(value, old);
+}
+
+// Any type can be compared, not only manifests
+#[test]
+fn test_diff_any_type() {
+    let model = load_repo_model();
+
+    let changes = diff::diff(
+        &model,
+        &type_id("data/v1alpha1/DataField"),
+        &json!({ "name": "a", "type": "String" }),
+        &json!({ "name": "a", "type": "String", "extra": { "a": 1 } }),
+    );
+    assert!(
+        matches!(&changes[..], [c] if c.path == "extra" && c.kind == ChangeKind::Added(json!({ "a": 1 }))),
+        "{changes:#?}"
+    );
+}
@@ -0,0 +1,346 @@
+mod common;
+
+use common::*;
+use odf_schemas::model::{self, Discriminator, Model, TypeDefinition};
+use odf_schemas::{canonicalization, codegen, validation};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn object(description: &str, properties: &[&str]) -> Value {
+    let props: serde_json::Map<_, _> = properties
+        .iter()
+        .map(|p| {
+            (
+                p.to_string(),
+                json!({ "type": "string", "description": "Prop." }),
+            )
+        })
+        .collect();
+
+    json!({
+        "description": description,
+        "type": "object",
+        "required": properties,
+        "properties": props
+    })
+}
+
+// `WebhookEvent` is tagged by `type` with custom values, while `LogPayload` is resolved by shape
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": format!("{BASE}/Hook"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Hook.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "event", "payload"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "event": {
+                    "$ref": format!("{BASE}/WebhookEvent"),
+                    "description": "Event.",
+                    "unevaluatedProperties": false
+                },
+                "payload": {
+                    "$ref": format!("{BASE}/LogPayload"),
+                    "description": "Payload.",
+                    "unevaluatedProperties": false
+                }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/WebhookEvent"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Webhook event.",
+            "discriminator": { "propertyName": "type" },
+            "$defs": {
+                "RefUpdated": object("Ref updated.", &["dataset", "ref"]),
+                "Deleted": object("Deleted.", &["dataset"]),
+            },
+            "oneOf": [
+                {
+                    "aliases": ["dataset.head.updated"],
+                    "allOf": tagged_variant_by("type", "dataset.ref.updated", "RefUpdated")["allOf"]
+                },
+                tagged_variant_by("type", "Deleted", "Deleted"),
+            ]
+        }),
+        json!({
+            "$id": format!("{BASE}/LogPayload"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Log payload.",
+            "discriminator": { "untagged": true },
+            "$defs": {
+                "Transfer": object("Transfer.", &["from", "to", "value"]),
+                "Approval": object("Approval.", &["owner", "spender", "value"]),
+            },
+            "oneOf": [
+                { "$ref": "#/$defs/Transfer" },
+                { "$ref": "#/$defs/Approval" },
+            ]
+        }),
+    ]
+}
+
+fn get_union<'a>(model: &'a Model, name: &str) -> &'a model::Union {
+    let Some(TypeDefinition::Union(t)) = model.types.get(&test_type_id(name)) else {
+        panic!("{name} is not a union");
+    };
+    t
+}
+
+fn messages(diagnostics: &[validation::Diagnostic]) -> Vec<String> {
+    diagnostics.iter().map(|d| d.to_string()).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_discriminator_model() {
+    let model = load_model(fixture_schemas());
+
+    let event = get_union(&model, "WebhookEvent");
+    assert_eq!(event.discriminator, Discriminator::Property("type".into()));
+    assert_eq!(
+        event.variants[0].tag.as_deref(),
+        Some("dataset.ref.updated")
+    );
+    assert!(event.variants[0].has_custom_tag());
+    assert!(!event.variants[1].has_custom_tag());
+
+    let payload = get_union(&model, "LogPayload");
+    assert_eq!(payload.discriminator, Discriminator::Untagged);
+    assert!(payload.variants.iter().all(|v| v.tag.is_none()));
+
+    // Custom tags are matched verbatim, while name-based tags accept other spellings
+    assert!(event.variant_by_tag("dataset.ref.updated").is_some());
+    assert!(event.variant_by_tag("Dataset.Ref.Updated").is_none());
+    assert!(event.variant_by_tag("deleted").is_some());
+    assert!(event.variant_by_tag("dataset.head.updated").unwrap().1);
+}
+
+#[test]
+fn test_discriminators_rust_serde() {
+    let model = load_model(fixture_schemas());
+
+    let serde = render(&model, codegen::rust_serde::render);
+    assert!(
+        serde.contains(
+            "#[serde(tag = \"type\")]\npub enum WebhookEvent {\n\
+             #[serde(rename = \"dataset.ref.updated\")]\n\
+             #[serde(alias = \"dataset.head.updated\")]\n\
+             RefUpdated(test::WebhookEventRefUpdated),\n\
+             #[serde(alias = \"deleted\")]\n\
+             Deleted(test::WebhookEventDeleted),"
+        ),
+        "{serde}"
+    );
+    assert!(
+        serde.contains(
+            "#[derive(Debug, Serialize, Deserialize)]\n#[serde(untagged)]\npub enum LogPayload {\n\
+             Transfer(test::LogPayloadTransfer),\n\
+             Approval(test::LogPayloadApproval),"
+        ),
+        "{serde}"
+    );
+}
+
+#[test]
+fn test_discriminators_markdown() {
+    let model = load_model(fixture_schemas());
+
+    let md = render(&model, codegen::markdown::render);
+    assert!(
+        md.contains("Variants are discriminated by the `type` property."),
+        "{md}"
+    );
+    assert!(md.contains("| Union Type | Tag | Description |"), "{md}");
+    assert!(md.contains("| `dataset.ref.updated` |"), "{md}");
+    assert!(
+        md.contains("Variants are not tagged and are resolved by their shape"),
+        "{md}"
+    );
+}
+
+#[test]
+fn test_discriminators_validation() {
+    let model = load_model(fixture_schemas());
+
+    // Validation reads the configured property and resolves untagged variants by shape
+    let hook = test_type_id("Hook");
+    let manifest = json!({
+        "$schema": format!("{BASE}/Hook"),
+        "event": { "type": "dataset.head.updated", "dataset": "foo", "ref": "head" },
+        "payload": { "owner": "0x1", "spender": "0x2", "value": "1" }
+    });
+    assert_eq!(
+        messages(&validation::validate(&model, &hook, &manifest)),
+        [
+            "warning: event.type: Variant `dataset.head.updated` is deprecated, use `dataset.ref.updated` instead"
+        ]
+    );
+
+    let canonical = canonicalization::canonicalize(&model, &hook, manifest);
+    assert_eq!(canonical["event"]["type"], "dataset.ref.updated");
+    assert!(validation::validate(&model, &hook, &canonical).is_empty());
+
+    let manifest = json!({
+        "$schema": format!("{BASE}/Hook"),
+        "event": { "kind": "Deleted", "dataset": "foo" },
+        "payload": { "owner": "0x1", "value": "1" }
+    });
+    assert_eq!(
+        messages(&validation::validate(&model, &hook, &manifest)),
+        [
+            "error: event: Missing required property `type`",
+            "error: payload: Value does not match any variant of LogPayload: Transfer, Approval",
+        ]
+    );
+}
+
+#[test]
+fn test_discriminators_conflict() {
+    // Union cannot be both tagged by a property and untagged
+    let mut schemas = fixture_schemas();
+    schemas[2]["discriminator"]["propertyName"] = json!("type");
+
+    let err = std::panic::catch_unwind(|| load_model(schemas)).unwrap_err();
+    assert!(
+        err.downcast_ref::<String>()
+            .unwrap()
+            .starts_with("Invalid union discriminator: LogPayload"),
+        "{:?}",
+        err.downcast_ref::<String>()
+    );
+}
+
+#[test]
+fn test_discriminators_aliases() {
+    model::check_aliases(&load_model(fixture_schemas()));
+
+    // Aliases can't claim a custom tag or a spelling of a name-based tag of another variant
+    let ambiguous = |variant: usize, alias: &str| {
+        let mut schemas = fixture_schemas();
+        let variant = &mut schemas[1]["oneOf"][variant];
+        variant["aliases"] = json!([alias]);
+        let model = load_model(schemas);
+        let err = std::panic::catch_unwind(|| model::check_aliases(&model)).unwrap_err();
+        err.downcast_ref::<String>().unwrap().clone()
+    };
+    assert_eq!(
+        ambiguous(1, "dataset.ref.updated"),
+        "Ambiguous name dataset.ref.updated in WebhookEvent: used by both dataset.ref.updated and Deleted"
+    );
+    assert_eq!(
+        ambiguous(0, "deleted"),
+        "Ambiguous name deleted in WebhookEvent: used by both Deleted and dataset.ref.updated"
+    );
+}
+
+#[test]
+fn test_discriminators_rust_dtos() {
+    let model = load_model(fixture_schemas());
+
+    let dtos = render(&model, codegen::rust_dtos::render);
+    assert!(
+        dtos.contains(
+            "/// Webhook event.\n\
+             ///\n\
+             /// Variants are discriminated by the `type` property.\n\
+             ///\n\
+             /// Schema: https://opendatafabric.org/schemas/test/v1alpha1/WebhookEvent\n"
+        ),
+        "{dtos}"
+    );
+    assert!(
+        dtos.contains(
+            "pub enum WebhookEvent {\n\
+             /// Tagged as `dataset.ref.updated`\n\
+             RefUpdated(test::WebhookEventRefUpdated),\n\
+             Deleted(test::WebhookEventDeleted),\n"
+        ),
+        "{dtos}"
+    );
+    assert!(
+        dtos.contains(
+            "/// Log payload.\n\
+             ///\n\
+             /// Variants are not tagged and are resolved by their shape in order of declaration.\n\
+             ///\n\
+             /// Schema: https://opendatafabric.org/schemas/test/v1alpha1/LogPayload\n"
+        ),
+        "{dtos}"
+    );
+}
+
+#[test]
+fn test_discriminators_graphql() {
+    // GQL codegen has hardcoded roots, so we check it against the real schemas
+    let model = load_repo_model();
+
+    // Untagged unions of non-object variants can't be GQL unions and are exposed as scalars instead
+    let gql = render(&model, codegen::rust_graphql::render);
+    let gql = gql.lines().map(str::trim).collect::<Vec<_>>().join("\n");
+    assert!(
+        gql.contains(
+            "/// Variants are not tagged and are resolved by their shape in order of declaration.\n\n\
+             #[nutype::nutype(derive(AsRef, Clone, Debug, From, Into))]\n\
+             pub struct LabelFilter(odf::metadata::resource::LabelFilter);\n"
+        ),
+        "{gql}"
+    );
+    assert!(!gql.contains("pub enum LabelFilter {"), "{gql}");
+}
+
+#[test]
+fn test_discriminators_flatbuffers() {
+    let model = load_model(fixture_schemas());
+
+    let fbs = render(&model, codegen::flatbuffers_schema::render);
+    assert!(
+        fbs.contains(
+            "// Variants are discriminated by the `type` property.\n\
+             union WebhookEvent {\n  \
+             // Tagged as `dataset.ref.updated`\n  \
+             WebhookEventRefUpdated,\n  \
+             WebhookEventDeleted,\n\
+             }\n"
+        ),
+        "{fbs}"
+    );
+    assert!(
+        fbs.contains(
+            "// Variants are not tagged and are resolved by their shape in order of declaration.\n\
+             union LogPayload {\n"
+        ),
+        "{fbs}"
+    );
+}
+
+#[test]
+fn test_discriminators_flatbuffers_non_table_variant() {
+    // Flatbuffers unions can only hold tables, while untagged variants can be any type
+    let mut schemas = fixture_schemas();
+    schemas[2]["$defs"]["Level"] = json!({
+        "description": "Level.",
+        "type": "string",
+        "enum": ["Low", "High"]
+    });
+    schemas[2]["oneOf"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({ "$ref": "#/$defs/Level" }));
+    let model = load_model(schemas);
+
+    let err = codegen::flatbuffers_schema::render(model, &mut Vec::new()).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Union LogPayload can't be represented in flatbuffers: variant LogPayload::Level is not a table"
+    );
+}
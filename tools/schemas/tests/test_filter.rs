@@ -0,0 +1,258 @@
+mod common;
+
+use common::{load_repo_model, render, type_id};
+use odf_schemas::filter::{self, Condition, Filter};
+use odf_schemas::model::{self, Model, TypeDefinition, TypeId};
+use odf_schemas::{codegen, validation};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn flow(labels: Value) -> Value {
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/flow/v1alpha1/Flow",
+        "headers": { "name": "compact-all-roots" },
+        "spec": {
+            "target": { "type": "Dataset", "name": "%", "labels": labels },
+            "triggers": [{ "kind": "Event", "events": { "type": "dataset.ref.updated" } }],
+            "tasks": [{ "kind": "Compaction" }]
+        }
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn label_filter() -> TypeId {
+    type_id("resource/v1alpha1/LabelFilter")
+}
+
+fn event_filter() -> TypeId {
+    type_id("event/v1alpha1/EventFilter")
+}
+
+fn parse(model: &Model, id: &TypeId, value: Value) -> Result<Filter, filter::FilterError> {
+    filter::parse(model, id, &value)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_filter_parse() {
+    let model = load_repo_model();
+    let label_filter = label_filter();
+    let parse = |id: &TypeId, value: Value| parse(&model, id, value);
+
+    // Short form compares strings, objects combine operators
+    assert_eq!(
+        parse(
+            &label_filter,
+            json!({ "kind": "Root", "tier": { "$exists": true, "$in": ["gold"] } })
+        )
+        .unwrap(),
+        Filter::Match(vec![
+            filter::FieldCondition {
+                field: "kind".to_string(),
+                conditions: vec![Condition::Eq(json!("Root"))],
+            },
+            filter::FieldCondition {
+                field: "tier".to_string(),
+                conditions: vec![Condition::Exists(true), Condition::In(vec![json!("gold")])],
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_filter_matches_headers() {
+    let model = load_repo_model();
+    let label_filter = label_filter();
+    let parse = |id: &TypeId, value: Value| parse(&model, id, value);
+
+    // Resource headers expose identity fields and labels
+    let headers = json!({
+        "id": "aa-11",
+        "name": "ca.bankofcanada.fx",
+        "account": { "id": "bb-22", "did": "did:odf:alice", "name": "alice" },
+        "labels": { "kind": "Root", "tier": "gold", "app.kubernetes.io/name": "fx" }
+    });
+    let matches = |value: Value| {
+        parse(&label_filter, value)
+            .unwrap()
+            .matches_headers(&model, &headers)
+    };
+    assert!(matches(json!({})));
+    assert!(matches(json!({ "kind": "Root", "account.name": "alice" })));
+    assert!(matches(json!({ "name": { "$glob": "ca.*.fx" } })));
+    assert!(matches(json!({ "app.kubernetes.io/name": "fx" })));
+    assert!(matches(
+        json!({ "$or": [{ "name": "foo" }, { "tier": { "$in": ["gold", "silver"] } }] })
+    ));
+    assert!(matches(
+        json!({ "$not": { "deprecated": { "$exists": true } } })
+    ));
+    assert!(!matches(json!({ "$not": { "kind": "Root" } })));
+    assert!(!matches(
+        json!({ "$and": [{ "kind": "Root" }, { "tier": "silver" }] })
+    ));
+    assert!(!matches(json!({ "generation": { "$exists": true } })));
+
+    // Short names match labels keyed by the URI of the type they refer to, but not other URIs with the same name
+    let typed = |uri: &str, value: Value| {
+        let headers = json!({ "name": "fx", "labels": { uri: "Root" } });
+        parse(&label_filter, value)
+            .unwrap()
+            .matches_headers(&model, &headers)
+    };
+    let dataset_kind = "https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetKind";
+    assert!(typed(dataset_kind, json!({ "datasetKind": "Root" })));
+    assert!(typed(dataset_kind, json!({ "DatasetKind": "Root" })));
+    assert!(!typed(
+        "https://example.com/schemas/v1/DatasetKind",
+        json!({ "datasetKind": "Root" })
+    ));
+    assert!(!typed(dataset_kind, json!({ "accountKind": "Root" })));
+}
+
+#[test]
+fn test_filter_matches_event() {
+    let model = load_repo_model();
+    let event_filter = event_filter();
+    let parse = |id: &TypeId, value: Value| parse(&model, id, value);
+
+    // Event fields are addressed by paths
+    let event = json!({
+        "type": "dataset.ref.updated",
+        "dataset": { "id": "did:odf:fx", "ref": "head" },
+        "newBlocks": 3
+    });
+    let matches = |value: Value| parse(&event_filter, value).unwrap().matches_event(&event);
+    assert!(matches(json!({ "type": "dataset.ref.updated" })));
+    assert!(matches(
+        json!({ "type": { "$glob": "dataset.*" }, "dataset.ref": "head" })
+    ));
+    assert!(matches(json!({ "newBlocks": { "$eq": 3 } })));
+    assert!(!matches(json!({ "newBlocks": { "$in": [1, 2] } })));
+    assert!(!matches(json!({ "dataset.alias": { "$glob": "*" } })));
+}
+
+#[test]
+fn test_filter_errors() {
+    let model = load_repo_model();
+    let label_filter = label_filter();
+    let event_filter = event_filter();
+    let parse = |id: &TypeId, value: Value| parse(&model, id, value);
+
+    // Mistakes are explained rather than reported as a mismatch of all variants
+    let err = |id: &TypeId, value: Value| parse(id, value).unwrap_err().to_string();
+    assert_eq!(
+        err(
+            &label_filter,
+            json!({ "$or": [{ "env": "prod" }, { "$nott": { "env": "prod" } }] })
+        ),
+        "$or[1].$nott: Unknown operator `$nott`, expected one of: $and, $or, $not"
+    );
+    assert_eq!(
+        err(&event_filter, json!({ "type": { "$regex": "dataset.*" } })),
+        "type.$regex: Unknown operator `$regex`, expected one of: $eq, $in, $exists, $glob"
+    );
+    assert_eq!(
+        err(
+            &label_filter,
+            json!({ "kind": "Root", "$not": { "env": "prod" } })
+        ),
+        "Operator `$not` can't be combined with other properties, use `$and` to combine them"
+    );
+    assert_eq!(
+        err(&label_filter, json!({ "replicas": 3 })),
+        "replicas: Expected a string or an object with one of: $eq, $in, $exists, $glob, use `$eq` to match values of other types"
+    );
+    assert_eq!(
+        err(&label_filter, json!({ "tier": {} })),
+        "tier: Condition must specify at least one of: $eq, $in, $exists, $glob"
+    );
+    assert_eq!(
+        err(&label_filter, json!({ "name": { "$glob": "ca.[" } })),
+        "name.$glob: Invalid glob pattern: Pattern syntax error near position 3: invalid range pattern"
+    );
+    assert!(
+        filter::parse(
+            &model,
+            &type_id("resource/v1alpha1/ResourceLabels"),
+            &json!({})
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn test_filter_unsupported_operators() {
+    let mut model = load_repo_model();
+    model::check_filters(&model);
+
+    let and = model
+        .types
+        .values_mut()
+        .find_map(|t| match t {
+            TypeDefinition::Struct(t) if t.id.join("") == "LabelFilterAnd" => Some(t),
+            _ => None,
+        })
+        .unwrap();
+    and.fields.values_mut().next().unwrap().name = "$xor".to_string();
+
+    // Operators the parser doesn't know are reported by model checks, and by parsing rather than by panicking
+    let err = std::panic::catch_unwind(|| model::check_filters(&model)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<String>().unwrap(),
+        "Filter operator `$xor` of LabelFilter is not supported"
+    );
+    assert_eq!(
+        parse(&model, &label_filter(), json!({ "kind": "Root" }))
+            .unwrap_err()
+            .to_string(),
+        "Filter operator `$xor` of LabelFilter is not supported"
+    );
+    assert_eq!(
+        validation::validate_manifest(&model, &flow(json!({ "replicas": 3 })))
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["error: spec.target.labels: Filter operator `$xor` of LabelFilter is not supported"]
+    );
+}
+
+#[test]
+fn test_filter_validation() {
+    let model = load_repo_model();
+
+    // Validation of manifests uses the same messages
+    assert!(validation::validate_manifest(&model, &flow(json!({ "kind": "Root" }))).is_empty());
+    let diagnostics = validation::validate_manifest(
+        &model,
+        &flow(json!({ "$and": [{ "kind": "Root" }, { "tier": { "$in": "gold" } }] })),
+    );
+    assert_eq!(
+        diagnostics
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+        ["error: spec.target.labels.$and[1].tier.$in: Expected an array"]
+    );
+}
+
+#[test]
+fn test_filter_flatbuffers() {
+    let model = load_repo_model();
+
+    // Conditions on non-string values are stored as JSON, which flatbuffers can only mark on the whole vector
+    let fbs = render(&model, codegen::flatbuffers_schema::render);
+    assert!(fbs.contains("eq: string (json);"), "{fbs}");
+    assert!(fbs.contains("in: [string] (json);"), "{fbs}");
+
+    // Accessors follow the names in proxies generated by `flatc`, which suffixes keywords
+    let fb_serde = render(&model, codegen::rust_serde_flatbuffers::render);
+    assert!(
+        fb_serde.contains("in_offset.map(|off| builder.add_in_(off));"),
+        "{fb_serde}"
+    );
+    assert!(fb_serde.contains("proxy.in_()"), "{fb_serde}");
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use common::*;
+use odf_schemas::codegen;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn render_fb_serde() -> String {
+    render(&load_repo_model(), codegen::rust_serde_flatbuffers::render)
+}
+
+/// Implementation blocks that decode flatbuffers into DTOs
+fn decoders(code: &str) -> Vec<&str> {
+    code.split("impl")
+        .filter(|block| block.contains("Deserializable") || block.contains("TryFrom<fb::"))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_flatbuffers_decode_verifies_buffers() {
+    let code = render_fb_serde();
+
+    // Untrusted buffers are verified before decoding
+    assert!(
+        code.contains("pub fn deserialize_verified<'fb, P, T>("),
+        "{code}"
+    );
+    assert!(
+        code.contains("::flatbuffers::root_with_opts::<P>(&opts, buf)"),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_decode_error_paths() {
+    let code = render_fb_serde();
+
+    // Failures carry the type / field path
+    assert!(
+        code.contains(
+            "fn deserialize(proxy: fb::SetTransform<'fb>) -> Result<Self, FlatbuffersDecodeError> {"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains(".map_err(|e: FlatbuffersDecodeError| e.at(\"SetTransform.inputs\"))?,"),
+        "{code}"
+    );
+    assert!(
+        code.contains(".map_err(|e: FlatbuffersDecodeError| e.at(\"AddData.prevCheckpoint\"))?,"),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_decode_unknown_discriminants() {
+    let code = render_fb_serde();
+
+    // Unknown discriminants are reported instead of panicking
+    assert!(
+        code.contains(
+            "_ => Err(FlatbuffersDecodeError::new(FlatbuffersDecodeErrorKind::UnknownUnionVariant { typ: \"Transform\", value: t.0 })),"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "_ => unknown_variant_from_table(table, t.0).map(odf::dataset::MetadataEvent::Unknown),"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("impl TryFrom<fb::AccountType> for odf::auth::AccountType {"),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_decode_never_panics() {
+    let code = render_fb_serde();
+
+    // No decoding path is allowed to panic
+    let decoders = decoders(&code);
+    assert!(!decoders.is_empty());
+    for block in decoders {
+        assert!(!block.contains("unwrap()"), "{block}");
+        assert!(!block.contains("panic!"), "{block}");
+    }
+
+    let helper = code
+        .split("fn fb_to_datetime")
+        .nth(1)
+        .unwrap()
+        .split("fn duration_to_fb")
+        .next()
+        .unwrap();
+    assert!(!helper.contains("unwrap()"), "{helper}");
+}
@@ -0,0 +1,428 @@
+mod common;
+
+use common::generated_crate::GeneratedCrate;
+use common::*;
+use odf_schemas::codegen;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// `Block` covers every kind of accessor: scalars, borrowed strings, parsed identifiers, arrays, unions, enums, and maps
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": format!("{BASE}/Block"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Block.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "systemTime", "size", "tags", "event", "color"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "systemTime": { "type": "string", "format": "date-time", "description": "Time." },
+                "note": { "type": "string", "description": "Note." },
+                "size": { "type": "integer", "format": "uint64", "description": "Size." },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Tags."
+                },
+                "event": {
+                    "$ref": format!("{BASE}/Event"),
+                    "description": "Event.",
+                    "unevaluatedProperties": false
+                },
+                "history": {
+                    "type": "array",
+                    "items": {
+                        "$ref": format!("{BASE}/Event"),
+                        "unevaluatedProperties": false
+                    },
+                    "description": "History."
+                },
+                "color": {
+                    "$ref": format!("{BASE}/Color"),
+                    "description": "Color.",
+                    "unevaluatedProperties": false
+                },
+                "hash": { "type": "string", "format": "multihash", "description": "Hash." },
+                "labels": {
+                    "$ref": format!("{BASE}/Labels"),
+                    "description": "Labels.",
+                    "unevaluatedProperties": false
+                }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Event"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Event.",
+            "codegen": { "rust": { "unknownVariant": true } },
+            "$defs": {
+                "Created": {
+                    "description": "Created.",
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": {
+                        "name": { "type": "string", "description": "Name." }
+                    }
+                },
+                "Deleted": {
+                    "description": "Deleted.",
+                    "type": "object",
+                    "required": [],
+                    "properties": {}
+                },
+            },
+            "oneOf": [tagged_variant("Created"), tagged_variant("Deleted")]
+        }),
+        json!({
+            "$id": format!("{BASE}/Color"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Color.",
+            "type": "string",
+            "format": "int32",
+            "enum": ["Red", "Green"]
+        }),
+        json!({
+            "$id": format!("{BASE}/Labels"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Labels.",
+            "type": "object",
+            "patternProperties": {
+                ".*": {}
+            }
+        }),
+    ]
+}
+
+fn render_views(schemas: Vec<Value>) -> String {
+    render(
+        &load_model(schemas),
+        codegen::rust_flatbuffers_views::render,
+    )
+}
+
+fn render_views_err(schemas: Vec<Value>) -> String {
+    let mut buf = Vec::new();
+    codegen::rust_flatbuffers_views::render(load_model(schemas), &mut buf)
+        .unwrap_err()
+        .to_string()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_flatbuffers_views_struct() {
+    let code = render_views(fixture_schemas());
+
+    // Views wrap proxies and are cheap to copy around
+    assert!(
+        code.contains(
+            "#[derive(Debug, Clone, Copy)]\npub struct BlockView<'fb> {\nproxy: fb::Block<'fb>,\n}"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("impl<'fb> From<fb::Block<'fb>> for BlockView<'fb> {"),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_views_scalars() {
+    let code = render_views(fixture_schemas());
+
+    // Strings are borrowed from the buffer, while small values are returned as is
+    assert!(
+        code.contains("pub fn note(&self) -> Result<Option<&'fb str>, FlatbuffersDecodeError> {"),
+        "{code}"
+    );
+    assert!(
+        code.contains("pub fn size(&self) -> Result<u64, FlatbuffersDecodeError> {"),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "pub fn system_time(&self) -> Result<DateTime<Utc>, FlatbuffersDecodeError> {"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "pub fn hash(&self) -> Result<Option<odf::Multihash>, FlatbuffersDecodeError> {"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("pub fn color(&self) -> Result<odf::test::Color, FlatbuffersDecodeError> {"),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_views_arrays() {
+    let code = render_views(fixture_schemas());
+
+    // Arrays are iterated lazily
+    assert!(
+        code.contains(
+            "pub fn tags(&self) -> Result<impl ExactSizeIterator<Item = Result<&'fb str, FlatbuffersDecodeError>> + 'fb, FlatbuffersDecodeError> {"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "Ok(v.iter().map(|i| i.value().ok_or_else(FlatbuffersDecodeError::missing_field).and_then(|v| EventView::new(v, i.value_type()))))"
+        ),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_views_nested() {
+    let code = render_views(fixture_schemas());
+
+    // Nested values are views too, with errors carrying the field path
+    assert!(
+        code.contains("pub fn event(&self) -> Result<EventView<'fb>, FlatbuffersDecodeError> {"),
+        "{code}"
+    );
+    assert!(
+        code.contains(".and_then(|v| EventView::new(v, self.proxy.event_type()))\n.map_err(|e: FlatbuffersDecodeError| e.at(\"Block.event\"))"),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "pub fn labels(&self) -> Result<Option<LabelsView<'fb>>, FlatbuffersDecodeError> {"
+        ),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_views_unions() {
+    let code = render_views(fixture_schemas());
+
+    // Unions keep unknown variants when the type is hinted
+    assert!(
+        code.contains(
+            "pub enum EventView<'fb> {\nCreated(EventCreatedView<'fb>),\nDeleted(EventDeletedView<'fb>),\n"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("_ => Ok(Self::Unknown { tag: t.0, table }),"),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_flatbuffers_views_to_owned() {
+    let code = render_views(fixture_schemas());
+
+    // Owned DTOs are only decoded on request
+    assert!(
+        code.contains(
+            "pub fn to_owned(&self) -> Result<odf::test::Block, FlatbuffersDecodeError> {\nodf::test::Block::deserialize(self.proxy)\n}"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("Self::Created(v) => v.to_owned().map(odf::test::Event::Created),"),
+        "{code}"
+    );
+
+    // Decoding a view never materializes owned strings
+    let accessors = code
+        .split("/// Decodes the whole value into an owned DTO")
+        .next()
+        .unwrap();
+    assert!(!accessors.contains("to_owned()"), "{accessors}");
+    assert!(!accessors.contains("to_vec()"), "{accessors}");
+}
+
+#[test]
+fn test_flatbuffers_views_reject_multicodec() {
+    let err = render_views_err(vec![json!({
+        "$id": format!("{BASE}/Codec"),
+        "$schema": METASCHEMA_MANIFEST,
+        "description": "Codec.",
+        "type": "object",
+        "unevaluatedProperties": false,
+        "required": ["$schema", "kind"],
+        "properties": {
+            "$schema": {
+                "type": "string",
+                "format": "type-uri",
+                "description": "Schema."
+            },
+            "kind": { "type": "string", "format": "multicodec", "description": "Kind." }
+        }
+    })]);
+    assert_eq!(
+        err,
+        "Field Codec.kind has format `multicodec` that is not supported by flatbuffers views"
+    );
+}
+
+#[test]
+fn test_flatbuffers_views_reject_multicodec_array() {
+    let err = render_views_err(vec![json!({
+        "$id": format!("{BASE}/Codecs"),
+        "$schema": METASCHEMA_MANIFEST,
+        "description": "Codecs.",
+        "type": "object",
+        "unevaluatedProperties": false,
+        "required": ["$schema", "kinds"],
+        "properties": {
+            "$schema": {
+                "type": "string",
+                "format": "type-uri",
+                "description": "Schema."
+            },
+            "kinds": {
+                "type": "array",
+                "items": { "type": "string", "format": "multicodec" },
+                "description": "Kinds."
+            }
+        }
+    })]);
+    assert_eq!(
+        err,
+        "Field Codecs.kinds has format `multicodec` that is not supported by flatbuffers views"
+    );
+}
+
+#[test]
+fn test_flatbuffers_views_decode() {
+    let model = load_model(fixture_schemas());
+    let Some(krate) = GeneratedCrate::new(&model) else {
+        return;
+    };
+
+    krate.add_test("views", VIEWS_TEST);
+    krate.test();
+}
+
+/// Reads a buffer written by generated convertors through generated views
+const VIEWS_TEST: &str = r#"
+use chrono::prelude::*;
+use flatbuffers::FlatBufferBuilder;
+use odf::serde::flatbuffers::convertors_generated::*;
+use odf::serde::flatbuffers::proxies_generated as fb;
+use odf::serde::flatbuffers::views_generated::*;
+
+fn block() -> odf::test::Block {
+    odf::test::Block {
+        schema: odf::resource::TypeUri::new_unchecked(
+            "https://opendatafabric.org/schemas/test/v1alpha1/Block",
+        ),
+        system_time: Utc.with_ymd_and_hms(2024, 1, 2, 3, 4, 5).unwrap(),
+        note: Some("note".to_string()),
+        size: 42,
+        tags: vec!["a".to_string(), "b".to_string()],
+        event: odf::test::Event::Created(odf::test::EventCreated {
+            name: "foo".to_string(),
+        }),
+        history: Some(vec![
+            odf::test::Event::Deleted(odf::test::EventDeleted {}),
+            odf::test::Event::Created(odf::test::EventCreated {
+                name: "bar".to_string(),
+            }),
+        ]),
+        color: odf::test::Color::Green,
+        hash: Some(odf::Multihash::from_digest_sha3_256(b"foo")),
+        labels: Some(odf::test::Labels {
+            entries: [("x".to_string(), serde_json::json!({ "y": [1, 2] }))].into(),
+        }),
+    }
+}
+
+fn encode(block: &odf::test::Block) -> Vec<u8> {
+    let mut fb = FlatBufferBuilder::new();
+    let offset = block.serialize(&mut fb).unwrap();
+    fb.finish(offset, None);
+    fb.finished_data().to_vec()
+}
+
+fn view(buf: &[u8]) -> BlockView<'_> {
+    view_verified::<fb::Block, BlockView>(buf, &FlatbuffersDecodeLimits::default()).unwrap()
+}
+
+#[test]
+fn views_read_fields() {
+    let expected = block();
+    let buf = encode(&expected);
+    let view = view(&buf);
+
+    assert_eq!(view.schema().unwrap(), expected.schema.as_str());
+    assert_eq!(view.system_time().unwrap(), expected.system_time);
+    assert_eq!(view.note().unwrap(), Some("note"));
+    assert_eq!(view.size().unwrap(), 42);
+    assert_eq!(
+        view.tags().unwrap().collect::<Result<Vec<_>, _>>().unwrap(),
+        ["a", "b"]
+    );
+    assert_eq!(view.color().unwrap(), odf::test::Color::Green);
+    assert_eq!(view.hash().unwrap(), expected.hash);
+
+    let EventView::Created(created) = view.event().unwrap() else {
+        panic!("Unexpected variant");
+    };
+    assert_eq!(created.name().unwrap(), "foo");
+
+    let history = view
+        .history()
+        .unwrap()
+        .unwrap()
+        .map(|e| e.and_then(|e| e.to_owned()))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(Some(history), expected.history);
+
+    let labels = view
+        .labels()
+        .unwrap()
+        .unwrap()
+        .entries()
+        .map(|e| e.map(|(k, v)| (k, serde_json::from_str::<serde_json::Value>(v).unwrap())))
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+    assert_eq!(labels, [("x", serde_json::json!({ "y": [1, 2] }))]);
+}
+
+#[test]
+fn views_read_absent_fields() {
+    let mut expected = block();
+    expected.note = None;
+    expected.history = None;
+    expected.hash = None;
+    expected.labels = None;
+    let buf = encode(&expected);
+    let view = view(&buf);
+
+    assert_eq!(view.note().unwrap(), None);
+    assert!(view.history().unwrap().is_none());
+    assert_eq!(view.hash().unwrap(), None);
+    assert!(view.labels().unwrap().is_none());
+    assert_eq!(view.to_owned().unwrap(), expected);
+}
+
+#[test]
+fn views_to_owned() {
+    let expected = block();
+    let buf = encode(&expected);
+
+    assert_eq!(view(&buf).to_owned().unwrap(), expected);
+    assert_eq!(
+        view(&buf).to_owned().unwrap(),
+        deserialize_verified::<fb::Block, odf::test::Block>(&buf, &FlatbuffersDecodeLimits::default())
+            .unwrap()
+    );
+}
+"#;
@@ -0,0 +1,247 @@
+mod common;
+
+use std::path::PathBuf;
+
+use common::generated_crate::GeneratedCrate;
+use common::*;
+use odf_schemas::codegen::rust_fuzz::{self, Encoding, FuzzTarget};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn repo_options() -> rust_fuzz::Options {
+    rust_fuzz::Options {
+        crate_name: "odf-metadata".to_string(),
+        crate_path: PathBuf::from(".."),
+        examples_dir: repo_root().join("examples"),
+        vectors_dir: repo_root().join("tools/schemas/tests/vectors"),
+    }
+}
+
+fn repo_target(name: &str) -> FuzzTarget {
+    rust_fuzz::targets(&load_repo_model())
+        .into_iter()
+        .find(|t| t.name == name)
+        .unwrap_or_else(|| panic!("No target {name}"))
+}
+
+fn read(path: PathBuf) -> String {
+    std::fs::read_to_string(&path).unwrap_or_else(|e| panic!("{}: {e}", path.display()))
+}
+
+// `Block` is hashable and holds a union, so decoding it exercises unknown variants too
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": format!("{BASE}/Block"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Block.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "codegen": {
+                "flatbuffers": { "hashable": { "kind": 4194304, "version": 1 } }
+            },
+            "required": ["$schema", "sequenceNumber", "event"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "sequenceNumber": { "type": "integer", "format": "uint64", "description": "Number." },
+                "note": { "type": "string", "description": "Note." },
+                "event": {
+                    "$ref": format!("{BASE}/Event"),
+                    "description": "Event.",
+                    "unevaluatedProperties": false
+                }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Event"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Event.",
+            "codegen": { "rust": { "unknownVariant": true } },
+            "$defs": {
+                "Created": {
+                    "description": "Created.",
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": {
+                        "name": { "type": "string", "description": "Name." }
+                    }
+                },
+                "Deleted": {
+                    "description": "Deleted.",
+                    "type": "object",
+                    "required": [],
+                    "properties": {
+                        "reason": { "type": "string", "description": "Reason." }
+                    }
+                }
+            },
+            "oneOf": [tagged_variant("Created"), tagged_variant("Deleted")]
+        }),
+    ]
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Hashable types are fuzzed as flatbuffers, and every resource kind as YAML and JSON
+#[test]
+fn test_fuzz_targets() {
+    let targets = rust_fuzz::targets(&load_repo_model());
+    let names: Vec<_> = targets.iter().map(|t| t.name.as_str()).collect();
+
+    assert!(
+        names.contains(&"dataset_metadata_block_flatbuffers"),
+        "{names:?}"
+    );
+    assert!(names.contains(&"dataset_dataset_yaml"), "{names:?}");
+    assert!(names.contains(&"dataset_dataset_json"), "{names:?}");
+
+    let count = |encoding| targets.iter().filter(|t| t.encoding == encoding).count();
+    assert_eq!(count(Encoding::Flatbuffers), 1);
+    assert_eq!(count(Encoding::Yaml), count(Encoding::Json));
+}
+
+// Corpora of flatbuffers targets are seeded with golden vectors
+#[test]
+fn test_fuzz_seeds_vectors() {
+    let seeds = rust_fuzz::seeds(
+        &repo_target("dataset_metadata_block_flatbuffers"),
+        &repo_options(),
+    );
+
+    let vectors =
+        read(repo_root().join("tools/schemas/tests/vectors/dataset/v1alpha1/MetadataBlock.yaml"));
+    let (_, seed) = seeds
+        .iter()
+        .find(|(name, _)| name == "vector-seed")
+        .unwrap();
+    let hex: String = seed.iter().map(|b| format!("{b:02x}")).collect();
+    assert!(vectors.contains(&hex));
+}
+
+// Corpora of YAML and JSON targets are seeded with example manifests of the matching kind only
+#[test]
+fn test_fuzz_seeds_examples() {
+    let yaml = rust_fuzz::seeds(
+        &repo_target("storage_persistent_volume_yaml"),
+        &repo_options(),
+    );
+    let names: Vec<_> = yaml.iter().map(|(name, _)| name.as_str()).collect();
+    assert!(
+        names.contains(&"example-storage-volume-volume.yaml"),
+        "{names:?}"
+    );
+    assert!(
+        !names.contains(&"example-storage-volume-dataset.yaml"),
+        "{names:?}"
+    );
+    let (_, seed) = &yaml[names
+        .iter()
+        .position(|n| *n == "example-storage-volume-volume.yaml")
+        .unwrap()];
+    assert_eq!(
+        String::from_utf8(seed.clone()).unwrap(),
+        read(repo_root().join("examples/storage-volume/volume.yaml"))
+    );
+
+    let json = rust_fuzz::seeds(
+        &repo_target("storage_persistent_volume_json"),
+        &repo_options(),
+    );
+    let (_, seed) = json
+        .iter()
+        .find(|(name, _)| name == "example-storage-volume-volume.json")
+        .unwrap();
+    let seed: Value = serde_json::from_slice(seed).unwrap();
+    assert_eq!(
+        seed["$schema"],
+        "https://opendatafabric.org/schemas/storage/v1alpha1/PersistentVolume"
+    );
+}
+
+// Every target is a binary of the fuzz crate next to the seed generator, and the script runs all of them
+#[test]
+fn test_fuzz_crate_layout() {
+    let model = load_repo_model();
+    let dir = TempDir::new("fuzz");
+    rust_fuzz::render(&model, &repo_options(), dir.path());
+
+    let targets = rust_fuzz::targets(&model);
+    let manifest = read(dir.path().join("Cargo.toml"));
+    assert_eq!(
+        manifest.matches("[[bin]]").count(),
+        targets.len() + 1,
+        "{manifest}"
+    );
+    assert!(
+        manifest.contains("odf-metadata = { path = \"..\", features = [\"proptest\"] }"),
+        "{manifest}"
+    );
+    for target in &targets {
+        assert!(
+            dir.path()
+                .join(format!("fuzz_targets/{}.rs", target.name))
+                .exists()
+        );
+    }
+
+    let script = read(dir.path().join("run.sh"));
+    assert!(
+        script.contains("-max_total_time=\"$FUZZ_SECONDS\""),
+        "{script}"
+    );
+    for target in &targets {
+        assert!(
+            script.contains(&format!("    {}\n", target.name)),
+            "{script}"
+        );
+    }
+}
+
+// Seed generator fills the corpora with encoded arbitrary values, which the fuzz targets then accept without panics
+#[test]
+fn test_fuzz_run() {
+    let model = load_model(fixture_schemas());
+    let Some(krate) = GeneratedCrate::new_with_proptest(&model) else {
+        return;
+    };
+
+    let empty = TempDir::new("fuzz-seeds");
+    let options = rust_fuzz::Options {
+        crate_name: "odf-metadata".to_string(),
+        crate_path: PathBuf::from(".."),
+        examples_dir: empty.path().to_path_buf(),
+        vectors_dir: empty.path().to_path_buf(),
+    };
+    rust_fuzz::render(&model, &options, &krate.path().join("fuzz"));
+
+    let targets = rust_fuzz::targets(&model);
+    assert_eq!(
+        targets.iter().map(|t| t.name.as_str()).collect::<Vec<_>>(),
+        ["test_block_flatbuffers"]
+    );
+
+    krate.cargo("fuzz", &["run", "--quiet", "--bin", "seed_corpus"]);
+    for target in &targets {
+        let corpus = krate.path().join("fuzz/corpus").join(&target.name);
+        assert!(std::fs::read_dir(&corpus).unwrap().count() > 0);
+
+        // Without cargo-fuzz the target is a plain libFuzzer binary that runs every input of the corpus once
+        krate.cargo(
+            "fuzz",
+            &[
+                "run",
+                "--quiet",
+                "--bin",
+                &target.name,
+                "--",
+                corpus.to_str().unwrap(),
+                "-runs=0",
+            ],
+        );
+    }
+}
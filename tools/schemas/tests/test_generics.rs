@@ -0,0 +1,243 @@
+mod common;
+
+use common::*;
+use odf_schemas::codegen;
+use odf_schemas::json_schema::GenericBound;
+use odf_schemas::model::{self, Model, TypeDefinition};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn resource_schema(name: &str, spec: &str) -> Value {
+    json!({
+        "$id": format!("{BASE}/{name}"),
+        "$schema": METASCHEMA_RESOURCE_INPUT,
+        "description": "Resource.",
+        "type": "object",
+        "unevaluatedProperties": false,
+        "required": ["$schema", "spec"],
+        "properties": {
+            "$schema": {
+                "type": "string",
+                "format": "type-uri",
+                "description": "Schema."
+            },
+            "spec": {
+                "$ref": format!("{BASE}/{spec}"),
+                "description": "Spec.",
+                "unevaluatedProperties": false
+            }
+        }
+    })
+}
+
+// `Thing` has separate input and canonical specs, while `Widget` uses the same spec for both
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        resource_schema("Thing", "ThingSpecInput"),
+        json!({
+            "$id": format!("{BASE}/ThingSpecInput"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "canonicalType": format!("{BASE}/ThingSpec"),
+            "description": "Thing spec input.",
+            "type": "object",
+            "required": [],
+            "properties": {
+                "size": { "type": "integer", "format": "int32", "description": "Size." }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/ThingSpec"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Thing spec.",
+            "type": "object",
+            "required": ["size"],
+            "properties": {
+                "size": { "type": "integer", "format": "int32", "description": "Size." }
+            }
+        }),
+        resource_schema("Widget", "WidgetSpec"),
+        json!({
+            "$id": format!("{BASE}/WidgetSpec"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Widget spec.",
+            "type": "object",
+            "required": ["color"],
+            "properties": {
+                "color": { "type": "string", "description": "Color." }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Envelope"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Envelope.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "spec", "history"],
+            "generics": {
+                "SpecT": {
+                    "description": "Spec type.",
+                    "bound": "resource-spec"
+                }
+            },
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "spec": {
+                    "type": "object",
+                    "format": "fragment",
+                    "generic": "SpecT",
+                    "description": "Current spec."
+                },
+                "history": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "format": "fragment",
+                        "generic": "SpecT"
+                    },
+                    "description": "Previous specs."
+                }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Bag"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Bag.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "content"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "content": {
+                    "type": "object",
+                    "format": "fragment",
+                    "description": "Content."
+                }
+            }
+        }),
+    ]
+}
+
+fn get_struct<'a>(model: &'a Model, name: &str) -> &'a model::Struct {
+    let Some(TypeDefinition::Struct(t)) = model.types.get(&test_type_id(name)) else {
+        panic!("{name} is not a struct");
+    };
+    t
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_generic_parameters() {
+    let model = load_model(fixture_schemas());
+
+    // Explicit parameter is reused across fields
+    let envelope = get_struct(&model, "Envelope");
+    assert_eq!(envelope.generic_names(), ["SpecT"]);
+    assert_eq!(envelope.generics[0].bound, Some(GenericBound::ResourceSpec));
+    assert_eq!(envelope.fields["spec"].typ.generic_name(), Some("SpecT"));
+    assert_eq!(envelope.fields["history"].typ.generic_name(), Some("SpecT"));
+
+    // Implicit parameter is inferred from the field name and has no bound
+    let bag = get_struct(&model, "Bag");
+    assert_eq!(bag.generic_names(), ["ContentT"]);
+    assert_eq!(bag.generics[0].bound, None);
+    assert!(model.instantiations(bag).is_empty());
+}
+
+#[test]
+fn test_generic_instantiations() {
+    let model = load_model(fixture_schemas());
+
+    // Canonical specs satisfy the bound
+    let names: Vec<_> = model
+        .instantiations(get_struct(&model, "Envelope"))
+        .into_iter()
+        .map(|i| i.typ.id.join("").into_owned())
+        .collect();
+    assert_eq!(names, ["EnvelopeThingSpec", "EnvelopeWidgetSpec"]);
+
+    let names: Vec<_> = model
+        .types_satisfying(GenericBound::ResourceSpecInput)
+        .into_iter()
+        .map(|id| id.join("").into_owned())
+        .collect();
+    assert_eq!(names, ["ThingSpecInput", "WidgetSpec"]);
+}
+
+#[test]
+fn test_generics_rust() {
+    let model = load_model(fixture_schemas());
+
+    let dtos = render(&model, codegen::rust_dtos::render);
+    assert!(dtos.contains("pub struct Envelope<SpecT> {"), "{dtos}");
+    assert!(dtos.contains("pub history: Vec<SpecT>,"), "{dtos}");
+    assert!(
+        dtos.contains("pub type EnvelopeThingSpec = Envelope<test::ThingSpec>;"),
+        "{dtos}"
+    );
+
+    let serde = render(&model, codegen::rust_serde::render);
+    assert!(
+        serde.contains(
+            "implement_serde_as!(dtos::test::Envelope<SpecTDto>, Envelope<SpecT>, SpecTDto => SpecT);"
+        ),
+        "{serde}"
+    );
+    assert!(
+        serde.contains("implement_serde_as!(dtos::test::Bag<ContentTDto>, Bag<ContentT>, ContentTDto => ContentT);"),
+        "{serde}"
+    );
+}
+
+#[test]
+fn test_generics_flatbuffers_schema() {
+    let model = load_model(fixture_schemas());
+
+    // Flatbuffers have no generics, so each instantiation gets its own table
+    let fbs = render(&model, codegen::flatbuffers_schema::render);
+    assert!(fbs.contains("table EnvelopeThingSpec {"), "{fbs}");
+    assert!(
+        fbs.contains("  spec: ThingSpec;\n  // Previous specs.\n  history: [ThingSpec];"),
+        "{fbs}"
+    );
+    assert!(fbs.contains("table EnvelopeWidgetSpec {"), "{fbs}");
+    assert!(fbs.contains("  content: [ubyte];"), "{fbs}");
+}
+
+#[test]
+fn test_generics_undeclared_parameter() {
+    // Fields can only refer to declared parameters
+    let mut schemas = fixture_schemas();
+    schemas.last_mut().unwrap()["properties"]["content"]["generic"] = json!("FooT");
+
+    let err = std::panic::catch_unwind(|| load_model(schemas)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<String>().unwrap(),
+        "Reference to undeclared type parameter FooT: Bag.content"
+    );
+}
+
+#[test]
+fn test_generics_graphql() {
+    // GQL codegen has hardcoded roots, so we check it against the real schemas
+    let model = load_repo_model();
+
+    let gql = render(&model, codegen::rust_graphql::render);
+    assert!(gql.contains("pub struct Resource {"), "{gql}");
+    assert!(gql.contains("pub struct ResourceDatasetSpec {"), "{gql}");
+    assert!(
+        gql.contains(
+            "impl From<odf::metadata::resource::Resource<odf::metadata::dataset::DatasetSpec>> for ResourceDatasetSpec {"
+        ),
+        "{gql}"
+    );
+}
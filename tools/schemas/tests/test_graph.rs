@@ -0,0 +1,173 @@
+mod common;
+
+use common::*;
+use odf_schemas::graph::DependencyGraph;
+use odf_schemas::model::Model;
+use odf_schemas::resolve::{ResourceStore, StoredResource};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn projection(name: &str, inputs: &[&str]) -> Value {
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/dataset/v1alpha1/Projection",
+        "headers": { "name": name },
+        "spec": {
+            "inputs": inputs.iter().map(|i| json!({ "datasetRef": i })).collect::<Vec<_>>(),
+            "project": { "kind": "Sql", "engine": "datafusion", "query": "select 1" }
+        }
+    })
+}
+
+fn relations(name: &str, object: &str) -> Value {
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/auth/v1alpha1/Relations",
+        "headers": { "name": name },
+        "spec": {
+            "relations": [
+                { "subject": "Account:alice", "relation": "role", "value": "reader", "object": object }
+            ]
+        }
+    })
+}
+
+fn names(order: Vec<&StoredResource>) -> Vec<String> {
+    order.iter().map(ToString::to_string).collect()
+}
+
+fn storage_volume(model: &Model) -> ResourceStore<'_> {
+    let mut store = ResourceStore::new(model, None);
+    store
+        .load_dir(&repo_root().join("examples/storage-volume"))
+        .unwrap();
+    store
+}
+
+/// Projection that reads one local dataset by alias, one by DID, and a remote one
+fn projection_store(model: &Model) -> ResourceStore<'_> {
+    let mut store = ResourceStore::new(model, None);
+    for manifest in [
+        projection("fx.latest", &["fx", "did:odf:fed01rates", "remote/bob/fx"]),
+        json!({
+            "$schema": "https://opendatafabric.org/schemas/dataset/v1alpha1/Dataset",
+            "headers": { "name": "fx" },
+            "spec": { "kind": "Root", "metadata": [] }
+        }),
+        json!({
+            "$schema": "https://opendatafabric.org/schemas/dataset/v1alpha1/Dataset",
+            "headers": { "name": "rates" },
+            "spec": { "kind": "Root", "metadata": [], "did": "did:odf:fed01rates" }
+        }),
+    ] {
+        store.insert(None, manifest).unwrap();
+    }
+    store
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Volume needs its secrets and the dataset needs the volume, regardless of the order of files
+#[test]
+fn test_graph_order() {
+    let model = load_repo_model();
+    let store = storage_volume(&model);
+    let graph = DependencyGraph::build(&store);
+
+    assert_eq!(
+        names(graph.creation_order().unwrap()),
+        [
+            "SecretSet:my-aws-secrets",
+            "PersistentVolume:my-s3-bucket",
+            "Dataset:my-dataset"
+        ]
+    );
+    assert_eq!(
+        names(graph.deletion_order().unwrap()),
+        [
+            "Dataset:my-dataset",
+            "PersistentVolume:my-s3-bucket",
+            "SecretSet:my-aws-secrets"
+        ]
+    );
+}
+
+#[test]
+fn test_graph_dot() {
+    let model = load_repo_model();
+    let store = storage_volume(&model);
+    let graph = DependencyGraph::build(&store);
+
+    let mut dot = Vec::new();
+    graph.render_dot(&mut dot).unwrap();
+    assert_eq!(
+        String::from_utf8(dot).unwrap(),
+        indoc::indoc!(
+            r#"
+            digraph dependencies {
+              rankdir=LR;
+              n0 [label="Dataset:my-dataset"];
+              n1 [label="SecretSet:my-aws-secrets"];
+              n2 [label="PersistentVolume:my-s3-bucket"];
+              n0 -> n2 [label="spec.volume"];
+              n2 -> n1 [label="spec.credentials.accessKey"];
+              n2 -> n1 [label="spec.credentials.secretKey"];
+            }
+            "#
+        )
+    );
+}
+
+// Projections come after the datasets they read, referenced by alias or DID, while remote ones are ignored
+#[test]
+fn test_graph_dataset_refs() {
+    let model = load_repo_model();
+    let store = projection_store(&model);
+    let graph = DependencyGraph::build(&store);
+
+    assert_eq!(
+        names(graph.creation_order().unwrap()),
+        ["Dataset:fx", "Dataset:rates", "Projection:fx.latest"]
+    );
+}
+
+#[test]
+fn test_graph_mermaid() {
+    let model = load_repo_model();
+    let store = projection_store(&model);
+    let graph = DependencyGraph::build(&store);
+
+    let mut mermaid = Vec::new();
+    graph.render_mermaid(&mut mermaid).unwrap();
+    let mermaid = String::from_utf8(mermaid).unwrap();
+    assert!(mermaid.starts_with("graph LR\n"), "{mermaid}");
+    assert!(
+        mermaid.contains("  n0[\"Projection:fx.latest\"]\n  class n0 ctx_dataset\n"),
+        "{mermaid}"
+    );
+    assert!(
+        mermaid.contains("  n0 -->|\"spec.inputs[1].datasetRef\"| n2\n"),
+        "{mermaid}"
+    );
+}
+
+// Cycles make ordering impossible
+#[test]
+fn test_graph_cycles() {
+    let model = load_repo_model();
+    let mut store = projection_store(&model);
+    store.insert(None, relations("a", "Relations:b")).unwrap();
+    store.insert(None, relations("b", "Relations:a")).unwrap();
+    let graph = DependencyGraph::build(&store);
+
+    let cycles = graph.creation_order().unwrap_err();
+    assert_eq!(cycles.len(), 1);
+    assert_eq!(
+        cycles[0]
+            .resources
+            .iter()
+            .map(|i| graph.resources[*i].to_string())
+            .collect::<Vec<_>>(),
+        ["Relations:a", "Relations:b", "Relations:a"]
+    );
+    assert_eq!(cycles[0].path, "spec.relations[0].object");
+}
@@ -0,0 +1,246 @@
+mod common;
+
+use common::*;
+use odf_schemas::interpolate::{self, Interpolated, InterpolationError};
+use odf_schemas::model::Model;
+use odf_schemas::resolve::ResourceStore;
+use serde_json::{Map, Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn resource(kind: &str, name: &str, spec: Value) -> Value {
+    json!({
+        "$schema": format!("https://opendatafabric.org/schemas/{kind}"),
+        "headers": { "name": name },
+        "spec": spec
+    })
+}
+
+fn source(config: Value, ingress: Value) -> Value {
+    resource(
+        "source/v1alpha1/Source",
+        "fx",
+        json!({
+            "config": config,
+            "ingress": ingress,
+            "read": { "kind": "Csv" },
+            "merge": { "kind": "Append" }
+        }),
+    )
+}
+
+fn url(url: &str) -> Value {
+    json!({ "kind": "Url", "url": url })
+}
+
+fn hook_flow() -> Value {
+    resource(
+        "flow/v1alpha1/Flow",
+        "hook",
+        json!({
+            "target": "Dataset:ca.bankofcanada",
+            "triggers": [],
+            "tasks": [{ "kind": "WebhookCall", "target": "hook", "payload": "${{trigger.event}}" }]
+        }),
+    )
+}
+
+/// Example source along with the secrets and variables it refers to
+fn store(model: &Model) -> ResourceStore<'_> {
+    let mut store = ResourceStore::new(model, None);
+    store
+        .load_dir(&repo_root().join("examples/source-polling-url"))
+        .unwrap();
+    for manifest in [
+        resource(
+            "config/v1alpha1/SecretSet",
+            "ca.bankofcanada",
+            json!({
+                "secrets": {
+                    "apiKey": "s3cr3t",
+                    "mqtt.password": "hunter2",
+                    "sealed": { "value": "eyJhbGciOi", "contentEncoding": "jwe" }
+                }
+            }),
+        ),
+        resource(
+            "config/v1alpha1/VariableSet",
+            "ca.bankofcanada",
+            json!({ "variables": { "startDate": "2024-01-01", "mqtt.host": { "value": "broker" } } }),
+        ),
+    ] {
+        store.insert(None, manifest).unwrap();
+    }
+    store
+}
+
+fn interpolate_example(model: &Model) -> Interpolated {
+    let store = store(model);
+    let manifest = store
+        .resolve_ref(&json!("Source:ca.bankofcanada"), None)
+        .unwrap()
+        .unwrap()
+        .manifest
+        .clone();
+    interpolate::interpolate(model, &store, &manifest, &Map::new()).unwrap()
+}
+
+fn interpolate_err(manifest: Value, scopes: &Map<String, Value>) -> InterpolationError {
+    let model = load_repo_model();
+    let store = store(&model);
+    interpolate::interpolate(&model, &store, &manifest, scopes).unwrap_err()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Secrets and variables are substituted into templates of the example source
+#[test]
+fn test_interpolate_example() {
+    let model = load_repo_model();
+    let interpolated = interpolate_example(&model);
+
+    assert_eq!(
+        interpolated.value()["spec"]["ingress"],
+        json!({
+            "kind": "Url",
+            "url": "https://www.bankofcanada.ca/valet/observations/group/FX_RATES_DAILY/csv?start_date=2024-01-01",
+            "headers": [{ "name": "Authorization", "value": "Bearer s3cr3t" }]
+        })
+    );
+    assert_eq!(
+        interpolated.secret_paths().collect::<Vec<_>>(),
+        ["spec.config.apiKey", "spec.ingress.headers[0].value"]
+    );
+}
+
+// Values that contain secrets are redacted when printed
+#[test]
+fn test_interpolate_redacted() {
+    let model = load_repo_model();
+    let interpolated = interpolate_example(&model);
+
+    assert_eq!(
+        interpolated.redacted()["spec"]["ingress"]["headers"][0]["value"],
+        "***"
+    );
+    assert!(!format!("{interpolated:?}").contains("s3cr3t"));
+    assert!(!interpolated.to_string().contains("s3cr3t"));
+}
+
+// Struct references and dotted keys resolve, expressions outside of templatable properties are kept
+#[test]
+fn test_interpolate_references() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    let interpolated = interpolate::interpolate(
+        &model,
+        &store,
+        &source(
+            json!({
+                "host": "VariableSet:ca.bankofcanada#mqtt.host",
+                "password": { "type": "SecretSet", "name": "ca.bankofcanada", "path": "mqtt.password" }
+            }),
+            json!({
+                "kind": "Mqtt",
+                "host": "${{ config.host }}",
+                "port": 1883,
+                "password": "${{ config.password }}",
+                "topics": [{ "path": "fx" }]
+            }),
+        ),
+        &Map::new(),
+    )
+    .unwrap();
+    assert_eq!(interpolated.value()["spec"]["config"]["host"], "broker");
+    assert_eq!(
+        interpolated.value()["spec"]["ingress"]["host"],
+        "${{ config.host }}"
+    );
+    assert_eq!(
+        interpolated.value()["spec"]["ingress"]["password"],
+        "hunter2"
+    );
+    assert!(interpolated.is_secret("spec.ingress.password"));
+}
+
+// Caller provides the scopes that are only known at runtime
+#[test]
+fn test_interpolate_scopes() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    let mut scopes = Map::new();
+    scopes.insert("trigger".to_string(), json!({ "event": { "type": "x" } }));
+    let interpolated = interpolate::interpolate(&model, &store, &hook_flow(), &scopes).unwrap();
+    assert_eq!(
+        interpolated.value()["spec"]["tasks"][0]["payload"],
+        r#"{"type":"x"}"#
+    );
+    assert_eq!(interpolated.secret_paths().count(), 0);
+}
+
+// Errors point at the value that can't be substituted
+#[test]
+fn test_interpolate_missing_value() {
+    assert_eq!(
+        interpolate_err(
+            source(
+                json!({ "key": "SecretSet:ca.bankofcanada#apiKeyy" }),
+                url("https://example.com")
+            ),
+            &Map::new()
+        ),
+        InterpolationError {
+            path: "spec.config.key".to_string(),
+            message: "Referenced SecretSet:ca.bankofcanada has no value `apiKeyy`".to_string(),
+        }
+    );
+}
+
+#[test]
+fn test_interpolate_encoded_value() {
+    assert_eq!(
+        interpolate_err(
+            source(
+                json!({ "key": "SecretSet:ca.bankofcanada#sealed" }),
+                url("https://example.com")
+            ),
+            &Map::new()
+        )
+        .message,
+        "Value `sealed` of SecretSet:ca.bankofcanada is encoded as `jwe` and has to be decoded first"
+    );
+}
+
+#[test]
+fn test_interpolate_missing_template_value() {
+    assert_eq!(
+        interpolate_err(
+            source(json!({}), url("https://example.com/${{ config.key }}")),
+            &Map::new()
+        )
+        .to_string(),
+        "spec.ingress.url: Template expression `config.key` refers to a missing value"
+    );
+}
+
+#[test]
+fn test_interpolate_unknown_scope() {
+    assert_eq!(
+        interpolate_err(hook_flow(), &Map::new()).to_string(),
+        "spec.tasks[0].payload: Template expression `trigger.event` refers to an unknown scope `trigger`"
+    );
+}
+
+#[test]
+fn test_interpolate_unterminated() {
+    assert_eq!(
+        interpolate_err(
+            source(json!({}), url("https://example.com/${{ config.key")),
+            &Map::new()
+        )
+        .message,
+        "Unterminated template expression"
+    );
+}
@@ -0,0 +1,241 @@
+mod common;
+
+use common::*;
+use odf_schemas::merge::{self, ConflictReason};
+use odf_schemas::model::Model;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn source(url: &str, headers: Value, fields: Value) -> Value {
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/source/v1alpha1/Source",
+        "headers": { "name": "ca.bankofcanada", "labels": { "team": "fx" } },
+        "spec": {
+            "ingress": { "kind": "Url", "url": url, "headers": headers },
+            "read": { "kind": "Csv", "header": true, "schema": { "fields": fields } },
+            "merge": { "kind": "Ledger", "primaryKey": ["date"] }
+        }
+    })
+}
+
+fn v1() -> Value {
+    source(
+        "https://example.com/v1",
+        json!([
+            { "name": "Authorization", "value": "Bearer ${{ config.apiKey }}" },
+            { "name": "Accept", "value": "text/csv" }
+        ]),
+        json!([
+            { "name": "date", "type": "Timestamp" },
+            { "name": "rate", "type": { "kind": "Decimal", "precision": 19, "scale": 6 } }
+        ]),
+    )
+}
+
+// User removes a header they have added before, changes a field matched by name, and moves another
+fn v2() -> Value {
+    source(
+        "https://example.com/v1",
+        json!([
+            { "name": "Authorization", "value": "Bearer ${{ config.apiKey }}" }
+        ]),
+        json!([
+            { "name": "rate", "type": { "kind": "Decimal", "precision": 19, "scale": 8 } },
+            { "name": "date", "type": "Timestamp" }
+        ]),
+    )
+}
+
+// Live state after `v1` was applied, where system populated read-only fields, while another party added a label and a
+// header
+fn live(model: &Model) -> Value {
+    let mut live = merge::apply(model, None, None, &v1()).unwrap().resource;
+    live["headers"]["id"] = json!("aa-11");
+    live["headers"]["createdAt"] = json!("2026-01-01T00:00:00Z");
+    live["headers"]["labels"]["owner"] = json!("ui");
+    live["spec"]["ingress"]["headers"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({ "name": "X-Trace", "value": "1" }));
+    live["status"] = json!({ "phase": "Ready", "observedGeneration": 1 });
+    live
+}
+
+fn conflicts(applied: &merge::Applied) -> Vec<(&str, ConflictReason)> {
+    applied
+        .conflicts
+        .iter()
+        .map(|c| (c.path.as_str(), c.reason))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// First apply creates the resource
+#[test]
+fn test_merge_create() {
+    let model = load_repo_model();
+
+    let applied = merge::apply(&model, None, None, &v1()).unwrap();
+    assert!(applied.changed);
+    assert!(applied.conflicts.is_empty(), "{:#?}", applied.conflicts);
+    assert_eq!(applied.resource["headers"]["generation"], json!(1));
+    assert_eq!(applied.resource["spec"], v1()["spec"]);
+}
+
+// Re-applying the same manifest keeps values set by others
+#[test]
+fn test_merge_unchanged() {
+    let model = load_repo_model();
+    let live = live(&model);
+
+    let applied = merge::apply(&model, Some(&v1()), Some(&live), &v1()).unwrap();
+    assert!(!applied.changed);
+    assert!(applied.conflicts.is_empty(), "{:#?}", applied.conflicts);
+    assert_eq!(applied.resource, live);
+}
+
+#[test]
+fn test_merge_keys() {
+    let model = load_repo_model();
+
+    let applied = merge::apply(&model, Some(&v1()), Some(&live(&model)), &v2()).unwrap();
+    assert!(applied.changed);
+    assert!(applied.conflicts.is_empty(), "{:#?}", applied.conflicts);
+    assert_eq!(
+        applied.resource,
+        json!({
+            "$schema": "https://opendatafabric.org/schemas/source/v1alpha1/Source",
+            "headers": {
+                "name": "ca.bankofcanada",
+                "labels": { "team": "fx", "owner": "ui" },
+                "generation": 2,
+                "id": "aa-11",
+                "createdAt": "2026-01-01T00:00:00Z"
+            },
+            "spec": {
+                "ingress": {
+                    "kind": "Url",
+                    "url": "https://example.com/v1",
+                    "headers": [
+                        { "name": "Authorization", "value": "Bearer ${{ config.apiKey }}" },
+                        { "name": "X-Trace", "value": "1" }
+                    ]
+                },
+                "read": {
+                    "kind": "Csv",
+                    "header": true,
+                    "schema": {
+                        "fields": [
+                            { "name": "rate", "type": { "kind": "Decimal", "precision": 19, "scale": 8 } },
+                            { "name": "date", "type": "Timestamp" }
+                        ]
+                    }
+                },
+                "merge": { "kind": "Ledger", "primaryKey": ["date"] }
+            },
+            "status": { "phase": "Ready", "observedGeneration": 1 }
+        })
+    );
+}
+
+// Overwriting values changed by others and setting read-only ones are reported
+#[test]
+fn test_merge_conflicts() {
+    let model = load_repo_model();
+
+    let mut live = merge::apply(&model, Some(&v1()), Some(&live(&model)), &v2())
+        .unwrap()
+        .resource;
+    live["spec"]["ingress"]["url"] = json!("https://example.com/v2");
+
+    let mut v3 = v2();
+    v3["spec"]["ingress"]["url"] = json!("https://example.com/v3");
+    v3["headers"]["id"] = json!("bb-22");
+
+    let applied = merge::apply(&model, Some(&v2()), Some(&live), &v3).unwrap();
+    assert_eq!(
+        conflicts(&applied),
+        [
+            ("headers.id", ConflictReason::ReadOnly),
+            ("spec.ingress.url", ConflictReason::Modified),
+        ]
+    );
+    assert_eq!(
+        applied.conflicts[1].to_string(),
+        "spec.ingress.url: live value \"https://example.com/v2\" was changed since the last apply and will be replaced with \"https://example.com/v3\""
+    );
+    assert_eq!(applied.resource["headers"]["id"], json!("aa-11"));
+    assert_eq!(
+        applied.resource["spec"]["ingress"]["url"],
+        json!("https://example.com/v3")
+    );
+    assert_eq!(applied.resource["headers"]["generation"], json!(3));
+}
+
+// Variant changes replace the whole value
+#[test]
+fn test_merge_union_variant() {
+    let model = load_repo_model();
+
+    let live = merge::apply(&model, Some(&v1()), Some(&live(&model)), &v2())
+        .unwrap()
+        .resource;
+    let mut v3 = v2();
+    v3["spec"]["read"] = json!({ "kind": "NdJson" });
+
+    let applied = merge::apply(&model, Some(&v2()), Some(&live), &v3).unwrap();
+    assert_eq!(
+        applied.resource["spec"]["read"],
+        json!({ "kind": "NdJson" })
+    );
+}
+
+// Filters are replaced as a whole rather than merged by key
+#[test]
+fn test_merge_filters() {
+    let model = load_repo_model();
+
+    let flow = |labels: Value| {
+        json!({
+            "$schema": "https://opendatafabric.org/schemas/flow/v1alpha1/Flow",
+            "headers": { "name": "compact-all-roots" },
+            "spec": {
+                "target": { "type": "Dataset", "name": "%", "labels": labels },
+                "tasks": [{ "kind": "Compaction" }]
+            }
+        })
+    };
+    let f1 = flow(json!({ "kind": "Root" }));
+    let f2 = flow(json!({ "$not": { "kind": "Root" } }));
+    let mut live = merge::apply(&model, None, None, &f1).unwrap().resource;
+    live["spec"]["target"]["labels"]["tier"] = json!("gold");
+
+    let applied = merge::apply(&model, Some(&f1), Some(&live), &f2).unwrap();
+    assert_eq!(
+        conflicts(&applied),
+        [("spec.target.labels", ConflictReason::Modified)]
+    );
+    assert_eq!(
+        applied.resource["spec"]["target"]["labels"],
+        json!({ "$not": { "kind": "Root" } })
+    );
+}
+
+// Only resource manifests of the same type can be applied
+#[test]
+fn test_merge_different_types() {
+    let model = load_repo_model();
+
+    let flow = json!({
+        "$schema": "https://opendatafabric.org/schemas/flow/v1alpha1/Flow",
+        "headers": { "name": "compact-all-roots" },
+        "spec": {
+            "target": { "type": "Dataset", "name": "%" },
+            "tasks": [{ "kind": "Compaction" }]
+        }
+    });
+    let live = merge::apply(&model, None, None, &flow).unwrap().resource;
+    assert!(merge::apply(&model, None, Some(&live), &v1()).is_err());
+}
@@ -0,0 +1,161 @@
+mod common;
+
+use common::*;
+use odf_schemas::json_schema::SchemaId;
+use odf_schemas::model::{self, TypeDefinition, TypeId};
+use serde_json::json;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const DRAWING: &str = "https://opendatafabric.org/schemas/test/v1alpha1/Drawing";
+
+fn drawing_schema() -> serde_json::Value {
+    json!({
+        "$id": DRAWING,
+        "$schema": METASCHEMA_MANIFEST,
+        "description": "Drawing.",
+        "type": "object",
+        "unevaluatedProperties": false,
+        "required": ["$schema", "shape"],
+        "properties": {
+            "$schema": {
+                "type": "string",
+                "format": "type-uri",
+                "description": "Schema."
+            },
+            "shape": {
+                "$ref": "#/$defs/Shape",
+                "description": "Shape.",
+                "unevaluatedProperties": false
+            }
+        },
+        "$defs": {
+            "Shape": {
+                "description": "Shape.",
+                "oneOf": [
+                    {
+                        "allOf": [
+                            {
+                                "properties": { "kind": { "type": "string", "const": "Circle" } },
+                                "required": ["kind"]
+                            },
+                            { "$ref": "#/$defs/Shape/$defs/Circle" }
+                        ]
+                    },
+                    {
+                        "allOf": [
+                            {
+                                "properties": { "kind": { "type": "string", "const": "Group" } },
+                                "required": ["kind"]
+                            },
+                            { "$ref": "#/$defs/Shape/$defs/Group" }
+                        ]
+                    }
+                ],
+                "$defs": {
+                    "Circle": {
+                        "description": "Circle.",
+                        "type": "object",
+                        "required": ["center"],
+                        "properties": {
+                            "center": {
+                                "$ref": "#/$defs/Shape/$defs/Circle/$defs/Point",
+                                "description": "Center.",
+                                "unevaluatedProperties": false
+                            }
+                        },
+                        "$defs": {
+                            "Point": {
+                                "description": "Point.",
+                                "type": "object",
+                                "required": ["x", "y"],
+                                "properties": {
+                                    "x": { "type": "integer", "format": "int64", "description": "X." },
+                                    "y": { "type": "integer", "format": "int64", "description": "Y." }
+                                }
+                            }
+                        }
+                    },
+                    "Group": {
+                        "description": "Group.",
+                        "type": "object",
+                        "required": ["children"],
+                        "properties": {
+                            "children": {
+                                "type": "array",
+                                "items": {
+                                    "$ref": "#/$defs/Shape",
+                                    "unevaluatedProperties": false
+                                },
+                                "description": "Children."
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn point_id() -> TypeId {
+    TypeId::new(SchemaId::new(format!(
+        "{DRAWING}#/$defs/Shape/$defs/Circle/$defs/Point"
+    )))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_nested_type_id() {
+    let point = point_id();
+
+    assert_eq!(point.name(), "Point");
+    assert_eq!(point.path(), ["Drawing", "Shape", "Circle", "Point"]);
+    assert_eq!(point.join("::"), "Drawing::Shape::Circle::Point");
+    assert_eq!(
+        point.parent().unwrap().schema_id().as_str(),
+        format!("{DRAWING}#/$defs/Shape/$defs/Circle")
+    );
+    assert_eq!(point.root().schema_id().as_str(), DRAWING);
+}
+
+#[test]
+fn test_nested_defs() {
+    let model = load_model(vec![drawing_schema()]);
+    let point = point_id();
+
+    let mut names: Vec<_> = model.types.keys().map(|id| id.join("::")).collect();
+    names.sort();
+    assert_eq!(
+        names,
+        [
+            "Drawing",
+            "Drawing::Shape",
+            "Drawing::Shape::Circle",
+            "Drawing::Shape::Circle::Point",
+            "Drawing::Shape::Group",
+        ]
+    );
+
+    let Some(TypeDefinition::Struct(circle)) = model.types.get(point.parent().as_ref().unwrap())
+    else {
+        panic!("Circle is not a struct");
+    };
+    let model::Type::Custom(center) = &circle.fields["center"].typ else {
+        panic!("Unexpected field type");
+    };
+    assert_eq!(center, &point);
+}
+
+#[test]
+fn test_nested_defs_rust_dtos() {
+    let model = load_model(vec![drawing_schema()]);
+
+    let code = render(&model, odf_schemas::codegen::rust_dtos::render);
+    assert!(
+        code.contains("pub struct DrawingShapeCirclePoint<"),
+        "{code}"
+    );
+}
@@ -0,0 +1,358 @@
+mod common;
+
+use std::path::Path;
+
+use common::http::{account, http, serve_server};
+use common::*;
+use odf_schemas::api::{self, Operation};
+use odf_schemas::codegen::openapi;
+use odf_schemas::model::Model;
+use odf_schemas::server::{MAX_HEADERS, ResourceServer};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn document(model: &Model) -> Value {
+    let document: Value = serde_yaml::from_str(&render(model, openapi::render)).unwrap();
+    assert_eq!(document["openapi"], "3.1.0");
+    document
+}
+
+fn read_manifest(path: &Path) -> Value {
+    serde_yaml::from_reader(std::fs::File::open(path).unwrap()).unwrap()
+}
+
+// Validates a value against a component schema, resolving references within the document
+fn validate(document: &Value, component: &str, value: &Value) -> Vec<String> {
+    let schema = json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "$ref": format!("#/components/schemas/{component}"),
+        "components": document["components"],
+    });
+    let validator = jsonschema::options()
+        .build(&schema)
+        .unwrap_or_else(|e| panic!("Failed to compile {component}: {e}"));
+    validator
+        .iter_errors(value)
+        .map(|e| format!("{}: {e}", e.instance_path))
+        .collect()
+}
+
+fn collect_refs<'a>(value: &'a Value, refs: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(obj) => {
+            for (k, v) in obj {
+                match (k.as_str(), v) {
+                    ("$ref", Value::String(r)) => refs.push(r),
+                    _ => collect_refs(v, refs),
+                }
+            }
+        }
+        Value::Array(arr) => arr.iter().for_each(|v| collect_refs(v, refs)),
+        _ => {}
+    }
+}
+
+/// Resources that the server returns for the example secrets and volume, along with their components
+fn server_resources(model: &Model) -> Vec<(Value, &'static str)> {
+    let server = ResourceServer::open(model.clone(), None, account()).unwrap();
+
+    [
+        ("secrets.yaml", "ResourceSecretSetSpec"),
+        ("volume.yaml", "ResourcePersistentVolumeSpec"),
+    ]
+    .into_iter()
+    .map(|(file, component)| {
+        let manifest = read_manifest(&repo_root().join("examples/storage-volume").join(file));
+        (server.create(&manifest, None).unwrap(), component)
+    })
+    .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Every operation of every resource type gets an endpoint
+#[test]
+fn test_openapi_status_endpoint() {
+    let document = document(&load_repo_model());
+
+    let status = &document["paths"]["/dataset/v1alpha1/dataset/{ref}/status"]["put"];
+    assert_eq!(status["operationId"], "updateDatasetStatus");
+    assert_eq!(
+        status["requestBody"]["content"]["application/json"]["schema"],
+        json!({ "$ref": "#/components/schemas/ResourceStatus" })
+    );
+}
+
+#[test]
+fn test_openapi_collection_endpoint() {
+    let document = document(&load_repo_model());
+
+    let collection = &document["paths"]["/storage/v1alpha1/persistent-volume"];
+    assert_eq!(
+        ["get", "post", "put"].map(|m| collection[m]["operationId"].as_str().unwrap()),
+        [
+            "listPersistentVolume",
+            "createPersistentVolume",
+            "applyPersistentVolume"
+        ]
+    );
+    assert_eq!(
+        collection["get"]["parameters"],
+        json!([
+            { "$ref": "#/components/parameters/account" },
+            { "$ref": "#/components/parameters/selector" },
+            { "$ref": "#/components/parameters/watch" },
+            { "$ref": "#/components/parameters/since" },
+        ])
+    );
+    assert_eq!(
+        document["components"]["parameters"]["selector"]["content"]["application/json"]["schema"],
+        json!({ "$ref": "#/components/schemas/ResourceSelector" })
+    );
+}
+
+// Manifests are made of input types, while responses carry canonical resources
+#[test]
+fn test_openapi_create_endpoint() {
+    let document = document(&load_repo_model());
+
+    let create = &document["paths"]["/storage/v1alpha1/persistent-volume"]["post"];
+    assert_eq!(
+        create["requestBody"]["content"]["application/json"]["schema"],
+        json!({ "$ref": "#/components/schemas/PersistentVolume" })
+    );
+    assert_eq!(
+        create["responses"]["201"]["content"]["application/json"]["schema"],
+        json!({ "$ref": "#/components/schemas/ResourcePersistentVolumeSpec" })
+    );
+    assert_eq!(
+        create["responses"]["409"],
+        json!({ "$ref": "#/components/responses/Conflict" })
+    );
+}
+
+// Generic resources are only present as instantiations for each spec
+#[test]
+fn test_openapi_schemas() {
+    let document = document(&load_repo_model());
+
+    let schemas = document["components"]["schemas"].as_object().unwrap();
+    assert_eq!(
+        schemas["PersistentVolume"]["properties"]["spec"]["$ref"],
+        "#/components/schemas/PersistentVolumeSpecInput"
+    );
+    assert_eq!(
+        schemas["ResourcePersistentVolumeSpec"]["properties"]["spec"]["$ref"],
+        "#/components/schemas/PersistentVolumeSpec"
+    );
+    assert!(!schemas.contains_key("Resource"));
+    assert!(!schemas.contains_key("ResourceInput"));
+}
+
+#[test]
+fn test_openapi_refs() {
+    let document = document(&load_repo_model());
+
+    let mut refs = Vec::new();
+    collect_refs(&document, &mut refs);
+    for r in refs {
+        let (kind, name) = r
+            .strip_prefix("#/components/")
+            .and_then(|r| r.split_once('/'))
+            .unwrap_or_else(|| panic!("Unexpected reference {r}"));
+        assert!(
+            document["components"][kind].get(name).is_some(),
+            "Dangling reference {r}"
+        );
+    }
+}
+
+// Examples are valid manifests, except for canonical views that have no schema of their own
+#[test]
+fn test_openapi_examples() {
+    let document = document(&load_repo_model());
+
+    let pattern = repo_root().join("examples/**/*.yaml");
+    for entry in glob::glob(pattern.to_str().unwrap()).unwrap() {
+        let path = entry.unwrap();
+        let manifest = read_manifest(&path);
+        let kind = manifest["$schema"]
+            .as_str()
+            .unwrap()
+            .rsplit('/')
+            .next()
+            .unwrap();
+        if kind == "Resource" {
+            continue;
+        }
+        let errors = validate(&document, kind, &manifest);
+        assert!(errors.is_empty(), "{}: {errors:#?}", path.display());
+    }
+}
+
+// Resources returned by the server match the canonical schemas
+#[test]
+fn test_openapi_server_resources() {
+    let model = load_repo_model();
+    let document = document(&model);
+
+    for (resource, component) in server_resources(&model) {
+        let errors = validate(&document, component, &resource);
+        assert!(errors.is_empty(), "{component}: {errors:#?}");
+    }
+}
+
+#[test]
+fn test_openapi_invalid_resource() {
+    let model = load_repo_model();
+    let document = document(&model);
+
+    let (mut invalid, component) = server_resources(&model).swap_remove(0);
+    invalid["headers"]["generation"] = json!("1");
+    assert_eq!(
+        validate(&document, component, &invalid),
+        [r#"/headers/generation: "1" is not of type "integer""#]
+    );
+}
+
+// Errors that the server responds with are documented for the operation of the route
+#[test]
+fn test_openapi_error_statuses() {
+    let model = load_repo_model();
+    let document = document(&model);
+    let apis = api::resource_apis(&model);
+
+    // Server is restarted after the first event, so that resuming a watch from it fails
+    let dir = TempDir::new("openapi-server");
+    let open = || ResourceServer::open(model.clone(), Some(dir.path()), account()).unwrap();
+
+    let volumes = "/storage/v1alpha1/persistent-volume";
+    let secret_set = r#"{"$schema": "https://opendatafabric.org/schemas/config/v1alpha1/SecretSet", "headers": {"name": "my-secrets"}, "spec": {"secrets": {}}}"#;
+    let post = |path: &str, body: &str| {
+        format!(
+            "POST {path} HTTP/1.1\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+    };
+    let secret_sets = "/config/v1alpha1/secret-set";
+    open()
+        .create(&serde_json::from_str(secret_set).unwrap(), None)
+        .unwrap();
+    let addr = serve_server(open());
+
+    for (expected, method, path, request) in [
+        (400, "POST", volumes, post(volumes, "{")),
+        (
+            404,
+            "GET",
+            "/storage/v1alpha1/persistent-volume/none",
+            String::new(),
+        ),
+        (405, "PATCH", volumes, String::new()),
+        (409, "POST", secret_sets, post(secret_sets, secret_set)),
+        (
+            410,
+            "GET",
+            volumes,
+            format!("GET {volumes}?watch=true&since=0 HTTP/1.1\r\n\r\n"),
+        ),
+        (
+            411,
+            "POST",
+            volumes,
+            format!("POST {volumes} HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n"),
+        ),
+        (
+            413,
+            "PUT",
+            volumes,
+            format!(
+                "PUT {volumes} HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+                usize::MAX
+            ),
+        ),
+        (
+            431,
+            "DELETE",
+            "/storage/v1alpha1/persistent-volume/none",
+            format!(
+                "DELETE /storage/v1alpha1/persistent-volume/none HTTP/1.1\r\n{}",
+                "X-Header: a\r\n".repeat(MAX_HEADERS + 1)
+            ),
+        ),
+    ] {
+        let request = if request.is_empty() {
+            format!("{method} {path} HTTP/1.1\r\n\r\n")
+        } else {
+            request
+        };
+        let (status, body) = http(&addr, &request);
+        assert_eq!(status, expected, "{method} {path}: {body}");
+
+        // Unsupported methods are checked against every operation of the path
+        let operations: Vec<_> = match api::route(&apis, method, path) {
+            Ok(route) => vec![route.operation],
+            Err(_) => Operation::ALL
+                .into_iter()
+                .filter(|op| !op.is_item())
+                .collect(),
+        };
+        for operation in operations {
+            let path = match operation {
+                Operation::UpdateStatus => format!("{path}/{{ref}}/status"),
+                _ if operation.is_item() => {
+                    format!("{}/{{ref}}", path.rsplit_once('/').unwrap().0)
+                }
+                _ => path.to_string(),
+            };
+            let responses =
+                &document["paths"][&path][operation.method().to_lowercase()]["responses"];
+            assert!(
+                responses.get(status.to_string()).is_some(),
+                "{status} of {method} {path} is not documented: {responses:#}"
+            );
+        }
+    }
+}
+
+// Every operation documents all statuses it can fail with
+#[test]
+fn test_openapi_operation_statuses() {
+    let document = document(&load_repo_model());
+
+    for (path, method, operation) in [
+        ("/dataset/v1alpha1/dataset", "get", Operation::List),
+        ("/dataset/v1alpha1/dataset", "post", Operation::Create),
+        ("/dataset/v1alpha1/dataset", "put", Operation::Apply),
+        ("/dataset/v1alpha1/dataset/{ref}", "get", Operation::Get),
+        ("/dataset/v1alpha1/dataset/{ref}", "put", Operation::Update),
+        (
+            "/dataset/v1alpha1/dataset/{ref}/status",
+            "put",
+            Operation::UpdateStatus,
+        ),
+        (
+            "/dataset/v1alpha1/dataset/{ref}",
+            "delete",
+            Operation::Delete,
+        ),
+    ] {
+        let responses = document["paths"][path][method]["responses"]
+            .as_object()
+            .unwrap();
+        let mut expected: Vec<_> = operation
+            .error_statuses()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        expected.push(operation.success_status().to_string());
+        if operation == Operation::Apply {
+            expected.push("201".to_string());
+        }
+        expected.sort();
+        let mut documented: Vec<_> = responses.keys().cloned().collect();
+        documented.sort();
+        assert_eq!(documented, expected, "{method} {path}");
+    }
+}
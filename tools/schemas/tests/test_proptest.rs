@@ -0,0 +1,244 @@
+mod common;
+
+use common::generated_crate::GeneratedCrate;
+use common::*;
+use odf_schemas::codegen;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// `Tree` refers back to itself both through an array and a boxed field
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": format!("{BASE}/Block"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Block.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "name", "tags", "policy", "tree", "color"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "name": { "type": "string", "description": "Name." },
+                "hash": { "type": "string", "format": "multihash", "description": "Hash." },
+                "tags": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Tags."
+                },
+                "policy": {
+                    "type": "string",
+                    "enum": ["Reject", "DropOldest"],
+                    "description": "Policy."
+                },
+                "tree": {
+                    "$ref": format!("{BASE}/Tree"),
+                    "description": "Tree.",
+                    "unevaluatedProperties": false
+                },
+                "color": {
+                    "$ref": format!("{BASE}/Color"),
+                    "description": "Color.",
+                    "unevaluatedProperties": false
+                },
+                "labels": {
+                    "$ref": format!("{BASE}/Labels"),
+                    "description": "Labels.",
+                    "unevaluatedProperties": false
+                }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Tree"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Tree.",
+            "codegen": { "rust": { "unknownVariant": true } },
+            "$defs": {
+                "Leaf": {
+                    "description": "Leaf.",
+                    "type": "object",
+                    "required": ["value"],
+                    "properties": {
+                        "value": { "type": "integer", "format": "int64", "description": "Value." }
+                    }
+                },
+                "Branch": {
+                    "description": "Branch.",
+                    "type": "object",
+                    "required": ["children"],
+                    "properties": {
+                        "children": {
+                            "type": "array",
+                            "items": {
+                                "$ref": format!("{BASE}/Tree"),
+                                "unevaluatedProperties": false
+                            },
+                            "description": "Children."
+                        }
+                    }
+                },
+                "Wrapped": {
+                    "description": "Wrapped.",
+                    "type": "object",
+                    "required": ["inner"],
+                    "properties": {
+                        "inner": {
+                            "$ref": format!("{BASE}/Tree"),
+                            "description": "Inner.",
+                            "unevaluatedProperties": false,
+                            "codegen": { "rust": { "container": "Box" } }
+                        }
+                    }
+                },
+            },
+            "oneOf": [
+                tagged_variant("Leaf"),
+                tagged_variant("Branch"),
+                tagged_variant("Wrapped")
+            ]
+        }),
+        json!({
+            "$id": format!("{BASE}/Color"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Color.",
+            "type": "string",
+            "format": "int32",
+            "enum": ["Red", "Green"]
+        }),
+        json!({
+            "$id": format!("{BASE}/Labels"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Labels.",
+            "type": "object",
+            "patternProperties": {
+                ".*": {}
+            }
+        }),
+    ]
+}
+
+fn render_proptest() -> String {
+    render(
+        &load_model(fixture_schemas()),
+        codegen::rust_proptest::render,
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Values follow ODF string formats, optionality, and enum validations
+#[test]
+fn test_proptest_fields() {
+    let code = render_proptest();
+
+    assert!(
+        code.contains("impl Arbitrary for odf::test::Block {"),
+        "{code}"
+    );
+    assert!(
+        code.contains("from_text::<odf::TypeUri>(TYPE_URI),"),
+        "{code}"
+    );
+    assert!(
+        code.contains("proptest::option::of(from_text::<odf::Multihash>(MULTIHASH)),"),
+        "{code}"
+    );
+    assert!(
+        code.contains("proptest::collection::vec(any::<String>(), 0..4),"),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            r##"proptest::sample::select(vec![r#""Reject""#, r#""DropOldest""#]).prop_map(|v| serde_json::from_str(v).unwrap()),"##
+        ),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_proptest_maps_and_enums() {
+    let code = render_proptest();
+
+    assert!(
+        code.contains(
+            "proptest::collection::btree_map(any::<String>(), any_json(), 0..4)\n.prop_map(|entries| Self { entries })"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("proptest::sample::select(vec![\nSelf::Red,\nSelf::Green,\n])"),
+        "{code}"
+    );
+}
+
+// Recursive unions bound the depth of generated values
+#[test]
+fn test_proptest_recursive_unions() {
+    let code = render_proptest();
+
+    assert!(
+        code.contains(
+            "let leaf = prop_oneof![\nany::<odf::test::TreeLeaf>().prop_map(odf::test::Tree::Leaf),\n];"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("leaf.prop_recursive(3, 16, 4, |inner| {\nprop_oneof![\narbitrary_test_tree_branch(inner.clone()).prop_map(odf::test::Tree::Branch),\narbitrary_test_tree_wrapped(inner.clone()).prop_map(odf::test::Tree::Wrapped),\n]"),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "pub fn arbitrary_test_tree_branch(inner: BoxedStrategy<odf::test::Tree>) -> BoxedStrategy<odf::test::TreeBranch> {\n(proptest::collection::vec(inner.clone(), 0..4),)"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("(inner.clone().prop_map(Box::new),)"),
+        "{code}"
+    );
+    assert!(
+        code.contains("arbitrary_test_tree_wrapped(any::<odf::test::Tree>())"),
+        "{code}"
+    );
+}
+
+// Unknown variants are never generated as they can't be serialized
+#[test]
+fn test_proptest_unknown_variants() {
+    let code = render_proptest();
+
+    assert!(!code.contains("Tree::Unknown"), "{code}");
+}
+
+// Every type round-trips through serde, and tables also through flatbuffers
+#[test]
+fn test_proptest_roundtrips() {
+    let code = render_proptest();
+
+    assert!(
+        code.contains(
+            "fn test_roundtrip_test_block(value in any::<odf::test::Block>()) {\ncheck_serde_roundtrip::<_, yaml::test::Block>(&value)?;\ncheck_flatbuffers_roundtrip!(value, odf::test::Block, fb::Block<'_>);\n}"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains(
+            "fn test_roundtrip_test_tree(value in any::<odf::test::Tree>()) {\ncheck_serde_roundtrip::<_, yaml::test::Tree>(&value)?;\n}"
+        ),
+        "{code}"
+    );
+}
+
+// Generated strategies only produce values that survive the round trips they are checked with
+#[test]
+fn test_proptest_run() {
+    let Some(krate) = GeneratedCrate::new_with_proptest(&load_model(fixture_schemas())) else {
+        return;
+    };
+
+    krate.test();
+}
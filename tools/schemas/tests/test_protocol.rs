@@ -0,0 +1,138 @@
+mod common;
+
+use common::*;
+use odf_schemas::codegen::{protocol, rust_dtos};
+use odf_schemas::model::MetaType;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn asyncapi() -> String {
+    render(&load_repo_model(), protocol::render_asyncapi)
+}
+
+fn openapi() -> String {
+    render(&load_repo_model(), protocol::render_openapi)
+}
+
+fn read_protocol(name: &str) -> String {
+    std::fs::read_to_string(repo_root().join("protocols").join(name)).unwrap()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Every message has its own channel
+#[test]
+fn test_protocol_messages() {
+    let model = load_repo_model();
+
+    let messages = protocol::messages(&model);
+    assert_eq!(messages.len(), 15);
+    assert_eq!(
+        messages["dataset/push/objects/transfer/response"]
+            .0
+            .join(""),
+        "DatasetPushObjectsTransferResponse"
+    );
+}
+
+// Fragments are shared between messages
+#[test]
+fn test_protocol_fragments() {
+    let model = load_repo_model();
+
+    let batch = model
+        .types
+        .values()
+        .find(|t| t.id().join("") == "ObjectsBatch")
+        .unwrap();
+    assert!(matches!(batch.metatype(), MetaType::Fragment));
+}
+
+#[test]
+fn test_protocol_asyncapi_up_to_date() {
+    assert_eq!(
+        asyncapi(),
+        read_protocol("smart-transfer-protocol.asyncapi.yaml")
+    );
+}
+
+#[test]
+fn test_protocol_asyncapi_channels() {
+    let document: Value = serde_yaml::from_str(&asyncapi()).unwrap();
+
+    assert_eq!(
+        document["channels"]["dataset/pull/request"],
+        json!({
+            "subscribe": {
+                "operationId": "odf.dataset.pull.request",
+                "summary": "Initiates dataset pull from the ODF server.",
+                "security": [{ "jwt": [] }],
+                "message": {
+                    "messageId": "odf.dataset.DatasetPullRequest",
+                    "name": "DatasetPullRequest",
+                    "contentType": "application/json",
+                    "payload": { "$ref": "#/components/schemas/DatasetPullRequest" }
+                }
+            }
+        })
+    );
+}
+
+// Payloads share the formats of the core schemas instead of redefining them
+#[test]
+fn test_protocol_asyncapi_schemas() {
+    let document: Value = serde_yaml::from_str(&asyncapi()).unwrap();
+
+    let schemas = &document["components"]["schemas"];
+    assert_eq!(
+        schemas["DatasetPullRequest"]["properties"]["datasetId"]["format"],
+        "dataset-id"
+    );
+    assert_eq!(
+        schemas["DatasetPullResponse"]["properties"]["sizeEstimation"]["$ref"],
+        "#/components/schemas/TransferSizeEstimation"
+    );
+    assert_eq!(
+        schemas["DatasetPushMetadata"]["required"],
+        json!(["newBlocks"])
+    );
+}
+
+#[test]
+fn test_protocol_openapi_up_to_date() {
+    assert_eq!(
+        openapi(),
+        read_protocol("smart-transfer-protocol.openapi.yaml")
+    );
+}
+
+#[test]
+fn test_protocol_openapi() {
+    let document: Value = serde_yaml::from_str(&openapi()).unwrap();
+
+    assert_eq!(
+        document["paths"]["/blocks/{blockHash}"]["get"]["operationId"],
+        "rest.dataset.get_block"
+    );
+    assert_eq!(
+        document["components"]["schemas"]["MultiHash"]["format"],
+        "multihash"
+    );
+}
+
+// Same messages are available as Rust DTOs
+#[test]
+fn test_protocol_dtos() {
+    let dtos = render(&load_repo_model(), rust_dtos::render);
+
+    for name in [
+        "DatasetPullRequest",
+        "DatasetPullResponse",
+        "ObjectsBatch",
+        "TransferSizeEstimation",
+        "ErrorDetails",
+    ] {
+        assert!(dtos.contains(&format!("pub struct {name}<> {{")), "{name}");
+    }
+}
@@ -0,0 +1,278 @@
+mod common;
+
+use common::*;
+use odf_schemas::model::Model;
+use odf_schemas::resolve::{AccountRef, Query, ResourceStore};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn dataset(name: &str, account: Option<&str>, labels: Value) -> Value {
+    let mut headers = json!({ "name": name, "labels": labels });
+    if let Some(account) = account {
+        headers["account"] = json!(account);
+    }
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/dataset/v1alpha1/Dataset",
+        "headers": headers,
+        "spec": { "kind": "Root", "metadata": [] }
+    })
+}
+
+fn account(name: &str, id: &str) -> Value {
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/auth/v1alpha1/Account",
+        "headers": { "name": name, "id": id },
+        "spec": { "accountType": "User" }
+    })
+}
+
+/// Store of account `alice` with datasets owned by her, by `bob`, and by no account
+fn store(model: &Model) -> ResourceStore<'_> {
+    let current = AccountRef::parse(&json!("alice")).unwrap();
+    let mut store = ResourceStore::new(model, Some(current));
+
+    store
+        .insert(None, account("bob", "0b0b0b0b-0000-4000-8000-000000000001"))
+        .unwrap();
+    store
+        .insert(
+            None,
+            dataset(
+                "ca.bankofcanada.fx",
+                None,
+                json!({ "https://opendatafabric.org/schemas/dataset/v1alpha1/DatasetKind": "Root" }),
+            ),
+        )
+        .unwrap();
+    store
+        .insert(
+            None,
+            dataset(
+                "ca.bankofcanada.rates",
+                Some("alice"),
+                json!({ "env": "prod" }),
+            ),
+        )
+        .unwrap();
+    store
+        .insert(
+            None,
+            dataset("bobs-dataset", Some("bob"), json!({ "env": "prod" })),
+        )
+        .unwrap();
+    store
+        .insert(
+            None,
+            json!({
+                "$schema": "https://opendatafabric.org/schemas/config/v1alpha1/SecretSet",
+                "headers": { "name": "my-secrets" },
+                "spec": { "secrets": { "apiKey": "123" } }
+            }),
+        )
+        .unwrap();
+
+    store
+}
+
+fn resolve(
+    store: &ResourceStore<'_>,
+    reference: Value,
+    default_type: Option<&str>,
+) -> Option<String> {
+    store
+        .resolve_ref(&reference, default_type)
+        .unwrap()
+        .map(ToString::to_string)
+}
+
+fn names(store: &ResourceStore<'_>, selector: Value) -> Vec<String> {
+    store
+        .select(&selector)
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Resources of the current account conflict regardless of whether it's explicit
+#[test]
+fn test_resolve_duplicates() {
+    let model = load_repo_model();
+    let mut store = store(&model);
+
+    assert_eq!(
+        store
+            .insert(
+                None,
+                dataset("ca.bankofcanada.fx", Some("alice"), json!({}))
+            )
+            .unwrap_err(),
+        "Resource Dataset:alice/ca.bankofcanada.fx is already defined"
+    );
+}
+
+// Short and struct forms, short type names and full URIs are equivalent
+#[test]
+fn test_resolve_ref_forms() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    assert_eq!(
+        resolve(&store, json!("Dataset:bob/bobs-dataset"), None).as_deref(),
+        Some("Dataset:bob/bobs-dataset")
+    );
+    assert_eq!(
+        resolve(
+            &store,
+            json!({
+                "type": "https://opendatafabric.org/schemas/dataset/v1alpha1/Dataset",
+                "account": { "id": "0b0b0b0b-0000-4000-8000-000000000001" },
+                "name": "bobs-dataset"
+            }),
+            None
+        )
+        .as_deref(),
+        Some("Dataset:bob/bobs-dataset")
+    );
+    assert_eq!(
+        resolve(&store, json!("ca.bankofcanada.fx"), Some("Dataset")).as_deref(),
+        Some("Dataset:ca.bankofcanada.fx")
+    );
+}
+
+// References without an account point to the current one, dangling references are not an error
+#[test]
+fn test_resolve_ref_dangling() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    assert_eq!(resolve(&store, json!("Dataset:bobs-dataset"), None), None);
+    assert_eq!(
+        resolve(&store, json!("Dataset:nobody/bobs-dataset"), None),
+        None
+    );
+}
+
+// Selectors match names as SQL `LIKE` patterns and filter by labels
+#[test]
+fn test_resolve_selectors() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    assert_eq!(
+        names(&store, json!("Dataset:ca.bankofcanada.%")),
+        [
+            "Dataset:ca.bankofcanada.fx",
+            "Dataset:alice/ca.bankofcanada.rates"
+        ]
+    );
+    assert_eq!(
+        names(&store, json!("Dataset:ca.bankofcanada._")),
+        Vec::<String>::new()
+    );
+    assert_eq!(
+        names(
+            &store,
+            json!({ "type": "Dataset", "account": "bob", "name": "%" })
+        ),
+        ["Dataset:bob/bobs-dataset"]
+    );
+    assert_eq!(
+        names(
+            &store,
+            json!({ "type": "Dataset", "labels": { "datasetKind": "Root" } })
+        ),
+        ["Dataset:ca.bankofcanada.fx"]
+    );
+    assert_eq!(
+        names(
+            &store,
+            json!({ "type": "Dataset", "labels": { "$not": { "env": "prod" } } })
+        ),
+        ["Dataset:ca.bankofcanada.fx"]
+    );
+}
+
+// Value references carry the path within the resource
+#[test]
+fn test_resolve_value_ref() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    let (resource, path) = store
+        .resolve_value_ref(&json!("SecretSet:my-secrets#apiKey"))
+        .unwrap()
+        .unwrap();
+    assert_eq!(resource.name, "my-secrets");
+    assert_eq!(path.as_deref(), Some("apiKey"));
+}
+
+#[test]
+fn test_resolve_malformed_refs() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    let err = |reference: Value| store.resolve_ref(&reference, None).unwrap_err();
+    assert_eq!(
+        err(json!("bobs-dataset")),
+        "Reference \"bobs-dataset\" does not specify the resource type"
+    );
+    assert_eq!(
+        err(json!("Datasett:bobs-dataset")),
+        "Unknown resource type `Datasett`"
+    );
+    assert_eq!(
+        err(json!("Dataset:bobs-dataset#kind")),
+        "Only value references can specify a path, got \"Dataset:bobs-dataset#kind\""
+    );
+}
+
+// Every resource of the type is explained
+#[test]
+fn test_resolve_explain() {
+    let model = load_repo_model();
+    let store = store(&model);
+
+    let explained: Vec<_> = store
+        .explain(&Query::Selector(
+            json!({ "type": "Dataset", "name": "ca.%", "labels": { "env": "prod" } }),
+        ))
+        .unwrap()
+        .into_iter()
+        .map(|c| format!("{}: {}", c.resource, c.mismatches.join(", ")))
+        .collect();
+    assert_eq!(
+        explained,
+        [
+            "Dataset:ca.bankofcanada.fx: labels don't match the filter",
+            "Dataset:alice/ca.bankofcanada.rates: ",
+            "Dataset:bob/bobs-dataset: name `bobs-dataset` does not match `ca.%`, owned by account bob, not account alice",
+        ]
+    );
+}
+
+// Examples reuse names across directories, so duplicates are skipped
+#[test]
+fn test_resolve_load_examples() {
+    let model = load_repo_model();
+    let examples = repo_root().join("examples");
+
+    let mut store = ResourceStore::new(&model, None);
+    store.load_dir(&examples).unwrap();
+    assert!(
+        store
+            .skipped
+            .iter()
+            .any(|(_, e)| e.starts_with("Resource Dataset:ca.bankofcanada is already defined"))
+    );
+    assert_eq!(
+        store
+            .resolve_ref(&json!("Dataset:bob/bobs-dataset"), None)
+            .unwrap()
+            .and_then(|r| r.path.clone()),
+        Some(examples.join("auth-accounts-permissions/dataset-bobs.yaml"))
+    );
+}
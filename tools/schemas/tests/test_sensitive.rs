@@ -0,0 +1,149 @@
+mod common;
+
+use common::*;
+use odf_schemas::codegen;
+use odf_schemas::model::{self, Model, TypeDefinition};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn struct_mut<'a>(model: &'a mut Model, name: &str) -> &'a mut model::Struct {
+    model
+        .types
+        .values_mut()
+        .find_map(|t| match t {
+            TypeDefinition::Struct(t) if t.id.join("") == name => Some(t),
+            _ => None,
+        })
+        .unwrap()
+}
+
+fn redactable(model: &Model) -> Vec<String> {
+    model
+        .redactable_types()
+        .iter()
+        .map(|id| format!("{}::{}", id.context(), id.join("::")))
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Secrets propagate to every type that holds them, through unions, maps, and generic resources
+#[test]
+fn test_sensitive_propagation() {
+    let model = load_repo_model();
+    model::check_sensitive(&model);
+
+    let redactable = redactable(&model);
+    for name in [
+        "auth::AccountSpec",
+        "auth::Account",
+        "config::Secret",
+        "config::Secrets",
+        "config::SecretSet",
+        "sink::WebhookTargetSpec",
+        "source::Ingress::Mqtt",
+        "source::Ingress",
+        "source::SourceSpec",
+        "legacy::FetchStep::Mqtt",
+        "legacy::FetchStep",
+        "dataset::MetadataEvent",
+        "dataset::DatasetSpec",
+    ] {
+        assert!(
+            redactable.iter().any(|r| r == name),
+            "{name}: {redactable:?}"
+        );
+    }
+}
+
+// Types without secrets and variants of unions that hold none are not redacted
+#[test]
+fn test_sensitive_propagation_stops() {
+    let model = load_repo_model();
+
+    let redactable = redactable(&model);
+    assert!(!redactable.iter().any(|r| r == "data::DataSchema"));
+    assert!(!redactable.iter().any(|r| r == "source::Ingress::Url"));
+    assert!(!redactable.iter().any(|r| r == "legacy::FetchStep::Url"));
+}
+
+// DTOs wrap sensitive strings, while builders still accept plain values
+#[test]
+fn test_sensitive_dtos() {
+    let code = render(&load_repo_model(), codegen::rust_dtos::render);
+
+    assert!(code.contains("pub value: super::Sensitive<String>,"), "{code}");
+    assert!(
+        code.contains(
+            "pub fn password(mut self, v: impl Into<String>) -> Self {\nself.password = Some(super::Sensitive::new(v.into()));"
+        ),
+        "{code}"
+    );
+
+    // Types that redact their own sensitive properties are not wrapped again
+    assert!(
+        code.contains("pub password: Option<config::Secret>,"),
+        "{code}"
+    );
+    assert!(!code.contains("super::Sensitive<config::Secret>"), "{code}");
+    assert!(
+        code.contains("visitor.visit_string(v.value.expose());"),
+        "{code}"
+    );
+}
+
+// Only types that hold secrets can be redacted, including generic resources
+#[test]
+fn test_sensitive_redact() {
+    let code = render(&load_repo_model(), codegen::rust_dtos::render);
+
+    assert!(
+        code.contains(
+            "impl Redact for Ingress {\nfn redact(&self) -> Self {\nmatch self {\nSelf::Mqtt(v) => Self::Mqtt(v.redact()),\n_ => self.clone(),\n}"
+        ),
+        "{code}"
+    );
+    assert!(
+        code.contains("impl<SpecT: Redact> Redact for Resource<SpecT> {"),
+        "{code}"
+    );
+    assert!(!code.contains("impl Redact for DataSchema {"), "{code}");
+}
+
+// Secrets are not exposed via GraphQL
+#[test]
+fn test_sensitive_graphql() {
+    let code = render(&load_repo_model(), codegen::rust_graphql::render);
+
+    assert!(
+        code.contains("impl From<odf::metadata::sink::WebhookTargetSpec> for WebhookTargetSpec {\nfn from(v: odf::metadata::sink::WebhookTargetSpec) -> Self {\nSelf {\nurl: v.url.into(),\n}"),
+        "{code}"
+    );
+}
+
+#[test]
+fn test_sensitive_docs() {
+    let docs = render(&load_repo_model(), codegen::markdown::render);
+
+    assert!(
+        docs.contains("Sensitive: redacted in logs and not exposed via GraphQL"),
+        "{docs}"
+    );
+}
+
+// References to secrets are not secrets and can't be marked
+#[test]
+fn test_sensitive_references() {
+    let mut model = load_repo_model();
+    struct_mut(&mut model, "AwsCredentials")
+        .fields
+        .get_mut("secret_key")
+        .unwrap()
+        .sensitive = true;
+
+    let err = std::panic::catch_unwind(|| model::check_sensitive(&model)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<String>().unwrap(),
+        "Sensitive property must be a string or a type with sensitive properties: AwsCredentials.secretKey"
+    );
+}
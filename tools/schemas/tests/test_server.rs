@@ -0,0 +1,602 @@
+mod common;
+
+use std::path::Path;
+use std::time::Duration;
+
+use common::http::{account, http, http_raw, serve};
+use common::*;
+use odf_schemas::api::{self, Operation};
+use odf_schemas::model::Model;
+use odf_schemas::server::{
+    EventType, MAX_BODY_SIZE, MAX_HEAD_SIZE, MAX_HEADERS, Request, ResourceServer, Response,
+};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const NOW: &str = "2026-01-01T00:00:00Z";
+
+fn manifest(kind: &str, name: &str, spec: Value) -> Value {
+    json!({
+        "$schema": format!("https://opendatafabric.org/schemas/{kind}"),
+        "headers": { "name": name },
+        "spec": spec
+    })
+}
+
+fn secrets() -> Value {
+    manifest(
+        "config/v1alpha1/SecretSet",
+        "my-aws-secrets",
+        json!({ "secrets": { "accessKey": "AKIA", "secretKey": "s3cr3t" } }),
+    )
+}
+
+fn volume() -> Value {
+    manifest(
+        "storage/v1alpha1/PersistentVolume",
+        "my-s3-bucket",
+        json!({
+            "kind": "S3",
+            "bucket": "my-bucket",
+            "credentials": {
+                "accessKey": "SecretSet:my-aws-secrets#accessKey",
+                "secretKey": "SecretSet:my-aws-secrets#secretKey"
+            }
+        }),
+    )
+}
+
+fn dataset() -> Value {
+    manifest(
+        "dataset/v1alpha1/Dataset",
+        "my-dataset",
+        json!({ "kind": "Root", "metadata": [], "volume": "my-s3-bucket" }),
+    )
+}
+
+fn labeled(name: &str, env: &str) -> Value {
+    let mut dataset = dataset();
+    dataset["headers"]["name"] = json!(name);
+    dataset["headers"]["labels"] = json!({ "env": env });
+    dataset
+}
+
+fn open(model: &Model, dir: Option<&Path>) -> ResourceServer {
+    ResourceServer::open(model.clone(), dir, account())
+        .unwrap()
+        .with_clock(|| NOW.to_string())
+}
+
+/// Creates the secrets and the volume that refers to them, returning both as stored
+fn create_storage(server: &ResourceServer) -> (Value, Value) {
+    let secrets = server.create(&secrets(), None).unwrap();
+    let volume = server.create(&volume(), None).unwrap();
+    (secrets, volume)
+}
+
+/// Creates the dataset, labels it with `apply`, and reports it as reconciled, returning its ID
+fn create_reconciled_dataset(server: &ResourceServer) -> String {
+    create_storage(server);
+    server.create(&dataset(), None).unwrap();
+    let (applied, _) = server
+        .apply(&labeled("my-dataset", "prod"), None, false)
+        .unwrap();
+    let id = applied["headers"]["id"].as_str().unwrap().to_string();
+    server
+        .update_status(
+            &type_id("dataset/v1alpha1/Dataset"),
+            &id,
+            &json!({ "phase": "Ready", "observedGeneration": 2, "conditions": {} }),
+            None,
+        )
+        .unwrap();
+    id
+}
+
+/// Renames the reconciled dataset and changes the label that was applied before
+fn update_dataset(server: &ResourceServer) -> Value {
+    server
+        .update(
+            &type_id("dataset/v1alpha1/Dataset"),
+            "my-dataset",
+            &labeled("my-renamed-dataset", "dev"),
+            None,
+        )
+        .unwrap()
+}
+
+fn request(server: &ResourceServer, method: &str, target: &str, body: &str) -> (u16, Value) {
+    let request = Request::parse(method, target, body.as_bytes()).unwrap();
+    match server.handle(&request) {
+        Response::Json { status, body } => (status, body),
+        Response::Watch(_) => panic!("Unexpected watch response"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Endpoints are derived for every resource type
+#[test]
+fn test_server_routes() {
+    let model = load_repo_model();
+
+    let apis = api::resource_apis(&model);
+    let paths: Vec<_> = apis.iter().map(|a| a.path.as_str()).collect();
+    for path in [
+        "/auth/v1alpha1/account",
+        "/dataset/v1alpha1/dataset",
+        "/storage/v1alpha1/persistent-volume",
+    ] {
+        assert!(paths.contains(&path), "{paths:?}");
+    }
+    let route = api::route(
+        &apis,
+        "PUT",
+        "/dataset/v1alpha1/dataset/did%3Aodf%3Afed01/status",
+    )
+    .unwrap();
+    assert_eq!(route.operation, Operation::UpdateStatus);
+    assert_eq!(route.reference.as_deref(), Some("did:odf:fed01"));
+    assert_eq!(
+        api::route(&apis, "PATCH", "/dataset/v1alpha1/dataset")
+            .unwrap_err()
+            .status,
+        405
+    );
+}
+
+// References of manifests must resolve before they are stored
+#[test]
+fn test_server_create_unresolved() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+
+    let err = server.create(&volume(), None).unwrap_err();
+    assert_eq!(
+        (err.status, err.message.as_str(), err.details),
+        (
+            400,
+            "Manifest has unresolved references",
+            vec![
+                r#"spec.credentials.accessKey: Reference "SecretSet:my-aws-secrets#accessKey" does not match any resource"#.to_string(),
+                r#"spec.credentials.secretKey: Reference "SecretSet:my-aws-secrets#secretKey" does not match any resource"#.to_string(),
+            ]
+        )
+    );
+}
+
+// Created resources get headers filled in by the server and a pending status
+#[test]
+fn test_server_create() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+
+    let secrets = server.create(&secrets(), None).unwrap();
+    let secrets_id = secrets["headers"]["id"].as_str().unwrap();
+    assert!(
+        uuid::Uuid::parse_str(secrets_id).is_ok_and(|id| id.get_version_num() == 4),
+        "{secrets_id}"
+    );
+    assert_eq!(
+        secrets["headers"],
+        json!({
+            "id": secrets_id,
+            "name": "my-aws-secrets",
+            "account": account(),
+            "labels": {},
+            "annotations": {},
+            "generation": 1,
+            "createdAt": NOW,
+            "updatedAt": NOW
+        })
+    );
+    assert_eq!(
+        secrets["status"],
+        json!({ "phase": "Pending", "conditions": {} })
+    );
+}
+
+// References are resolved into handles
+#[test]
+fn test_server_create_handles() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+
+    let (secrets, volume) = create_storage(&server);
+    assert_eq!(
+        volume["spec"]["credentials"]["secretKey"],
+        json!({
+            "account": secrets["headers"]["account"],
+            "type": "https://opendatafabric.org/schemas/config/v1alpha1/SecretSet",
+            "id": secrets["headers"]["id"],
+            "name": "my-aws-secrets",
+            "path": "secretKey"
+        })
+    );
+
+    let created = server.create(&dataset(), None).unwrap();
+    assert_eq!(created["spec"]["volume"]["id"], volume["headers"]["id"]);
+}
+
+#[test]
+fn test_server_create_invalid() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+
+    let err = server
+        .create(
+            &manifest(
+                "dataset/v1alpha1/Dataset",
+                "my-dataset",
+                json!({ "kind": "Root", "metadat": [] }),
+            ),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(err.status, 400);
+    assert_eq!(
+        err.details,
+        [
+            "error: spec.metadat: Unknown property `metadat`",
+            "error: spec: Missing required property `metadata`"
+        ]
+    );
+}
+
+#[test]
+fn test_server_create_existing() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+    create_storage(&server);
+
+    server.create(&dataset(), None).unwrap();
+    assert_eq!(server.create(&dataset(), None).unwrap_err().status, 409);
+}
+
+// Applying merges the manifest and bumps the generation only when the desired state changes
+#[test]
+fn test_server_apply() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+    create_storage(&server);
+    server.create(&dataset(), None).unwrap();
+
+    let labeled = labeled("my-dataset", "prod");
+    let (applied, created) = server.apply(&labeled, None, false).unwrap();
+    assert!(!created);
+    assert_eq!(applied["headers"]["generation"], 2);
+    assert_eq!(server.apply(&labeled, None, false).unwrap().0, applied);
+}
+
+// Controllers report the status, which is validated against the conditions declared for the type
+#[test]
+fn test_server_update_status_undeclared() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+    create_storage(&server);
+    server.create(&dataset(), None).unwrap();
+
+    let err = server
+        .update_status(
+            &type_id("dataset/v1alpha1/Dataset"),
+            "my-dataset",
+            &json!({ "phase": "Ready", "conditions": { "https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus": { "value": "Ready", "lastTransitionTime": NOW } } }),
+            None,
+        )
+        .unwrap_err();
+    assert_eq!(
+        err.details,
+        [
+            "error: status.conditions.https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus: Condition WebhookTargetStatus is not declared for Dataset, Dataset does not declare any conditions"
+        ]
+    );
+}
+
+// Status updates don't change the desired state
+#[test]
+fn test_server_update_status() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+
+    let id = create_reconciled_dataset(&server);
+    let reconciled = server
+        .get(&type_id("dataset/v1alpha1/Dataset"), &id, None)
+        .unwrap();
+    assert_eq!(reconciled["headers"]["generation"], 2);
+    assert_eq!(reconciled["status"]["phase"], "Ready");
+}
+
+// Updates replace the desired state, so a later apply of the old manifest conflicts with them
+#[test]
+fn test_server_update() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+    create_reconciled_dataset(&server);
+
+    let updated = update_dataset(&server);
+    assert_eq!(updated["headers"]["generation"], 3);
+    assert_eq!(updated["status"]["phase"], "Reconciling");
+
+    let labeled = labeled("my-renamed-dataset", "prod");
+    let err = server.apply(&labeled, None, false).unwrap_err();
+    assert_eq!(
+        (err.status, err.details),
+        (
+            409,
+            vec![r#"headers.labels.env: live value "dev" was changed since the last apply and will be replaced with "prod""#.to_string()]
+        )
+    );
+    assert_eq!(
+        server.apply(&labeled, None, true).unwrap().0["headers"]["labels"],
+        json!({ "env": "prod" })
+    );
+}
+
+// Listing filters by selectors, defaulting to the type of the endpoint
+#[test]
+fn test_server_list() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+    create_storage(&server);
+    server
+        .create(&labeled("my-renamed-dataset", "prod"), None)
+        .unwrap();
+
+    let (status, body) = request(
+        &server,
+        "GET",
+        "/dataset/v1alpha1/dataset?selector=%7Blabels%3A%20%7Benv%3A%20prod%7D%7D",
+        "",
+    );
+    assert_eq!(status, 200);
+    assert_eq!(body["items"][0]["headers"]["name"], "my-renamed-dataset");
+    let (status, body) = request(
+        &server,
+        "GET",
+        "/dataset/v1alpha1/dataset?selector=my-%25",
+        "",
+    );
+    assert_eq!((status, body["items"].as_array().unwrap().len()), (200, 1));
+    let (status, body) = request(
+        &server,
+        "GET",
+        "/dataset/v1alpha1/dataset?selector=SecretSet%3A%25",
+        "",
+    );
+    assert_eq!(
+        (status, body["message"].as_str().unwrap()),
+        (
+            400,
+            "Selector of SecretSet resources can't be used to list Dataset resources"
+        )
+    );
+}
+
+// Watching from the start replays every change of resources of the type
+#[test]
+fn test_server_watch() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+    create_reconciled_dataset(&server);
+    update_dataset(&server);
+
+    let mut watch = server
+        .watch(&type_id("dataset/v1alpha1/Dataset"), None, None, Some(0))
+        .unwrap();
+    let events: Vec<_> = std::iter::from_fn(|| watch.next_timeout(Duration::ZERO).unwrap())
+        .map(|e| (e.sequence, e.typ))
+        .collect();
+    assert_eq!(
+        events,
+        [
+            (3, EventType::Added),
+            (4, EventType::Modified),
+            (5, EventType::Modified),
+            (6, EventType::Modified),
+        ]
+    );
+}
+
+// Deleting streams the final state to watchers
+#[test]
+fn test_server_delete() {
+    let model = load_repo_model();
+    let server = open(&model, None);
+    create_storage(&server);
+    server.create(&dataset(), None).unwrap();
+
+    // Watch starts after the creation of the dataset
+    let mut watch = server
+        .watch(&type_id("dataset/v1alpha1/Dataset"), None, None, Some(3))
+        .unwrap();
+
+    let (status, body) = request(
+        &server,
+        "DELETE",
+        "/dataset/v1alpha1/dataset/my-dataset",
+        "",
+    );
+    assert_eq!((status, &body["headers"]["deletedAt"]), (200, &json!(NOW)));
+    let event = watch.next_timeout(Duration::from_secs(1)).unwrap().unwrap();
+    assert_eq!((event.sequence, event.typ), (4, EventType::Deleted));
+    let (status, body) = request(&server, "GET", "/dataset/v1alpha1/dataset/my-dataset", "");
+    assert_eq!(
+        (status, body),
+        (
+            404,
+            json!({ "status": 404, "message": "Resource Dataset:my-dataset does not exist", "details": [] })
+        )
+    );
+}
+
+// Resources and manifests applied last survive restarts
+#[test]
+fn test_server_restart() {
+    let model = load_repo_model();
+    let dir = TempDir::new("server");
+    let (_, volume) = create_storage(&open(&model, Some(dir.path())));
+
+    let server = open(&model, Some(dir.path()));
+    let (status, body) = request(
+        &server,
+        "GET",
+        &format!(
+            "/storage/v1alpha1/persistent-volume/{}",
+            volume["headers"]["id"].as_str().unwrap()
+        ),
+        "",
+    );
+    assert_eq!((status, body), (200, volume));
+    let (status, _) = request(
+        &server,
+        "PUT",
+        "/storage/v1alpha1/persistent-volume",
+        &serde_json::to_string(&manifest(
+            "storage/v1alpha1/PersistentVolume",
+            "my-s3-bucket",
+            json!({ "kind": "S3", "bucket": "my-bucket" }),
+        ))
+        .unwrap(),
+    );
+    assert_eq!(status, 200);
+    assert_eq!(
+        server
+            .get(
+                &type_id("storage/v1alpha1/PersistentVolume"),
+                "my-s3-bucket",
+                None
+            )
+            .unwrap()["spec"],
+        json!({ "kind": "S3", "bucket": "my-bucket" })
+    );
+}
+
+// Sequence numbers continue after a restart, while events of the previous run can't be resumed from
+#[test]
+fn test_server_restart_watch() {
+    let model = load_repo_model();
+    let dir = TempDir::new("server");
+    create_storage(&open(&model, Some(dir.path())));
+
+    let server = open(&model, Some(dir.path()));
+    let kind = type_id("config/v1alpha1/SecretSet");
+    assert_eq!(
+        server
+            .watch(&kind, None, None, Some(1))
+            .err()
+            .map(|e| (e.status, e.message)),
+        Some((
+            410,
+            "Events after sequence 1 are no longer available, the oldest one is 3".to_string()
+        ))
+    );
+
+    let mut watch = server.watch(&kind, None, None, Some(2)).unwrap();
+    server
+        .create(
+            &manifest(
+                "config/v1alpha1/SecretSet",
+                "my-other-secrets",
+                json!({ "secrets": {} }),
+            ),
+            None,
+        )
+        .unwrap();
+    let event = watch.next_timeout(Duration::ZERO).unwrap().unwrap();
+    assert_eq!((event.sequence, event.typ), (3, EventType::Added));
+}
+
+// Oversized bodies are rejected before anything is allocated for them
+#[test]
+fn test_server_http_body_limit() {
+    let addr = serve(load_repo_model());
+
+    let (status, body) = http(
+        &addr,
+        &format!(
+            "POST /config/v1alpha1/secret-set HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            usize::MAX
+        ),
+    );
+    assert_eq!(status, 413);
+    assert_eq!(
+        body["message"],
+        format!(
+            "Request body of {} bytes exceeds the limit of {MAX_BODY_SIZE} bytes",
+            usize::MAX
+        )
+    );
+}
+
+#[test]
+fn test_server_http_invalid_length() {
+    let addr = serve(load_repo_model());
+
+    let (status, body) = http(
+        &addr,
+        "POST /config/v1alpha1/secret-set HTTP/1.1\r\nContent-Length: 12abc\r\n\r\n",
+    );
+    assert_eq!(
+        (status, body["message"].as_str().unwrap()),
+        (400, "Invalid Content-Length header `12abc`")
+    );
+}
+
+#[test]
+fn test_server_http_create() {
+    let addr = serve(load_repo_model());
+
+    let manifest = r#"{"$schema": "https://opendatafabric.org/schemas/config/v1alpha1/SecretSet", "headers": {"name": "my-secrets"}, "spec": {"secrets": {}}}"#;
+    let (status, body) = http(
+        &addr,
+        &format!(
+            "POST /config/v1alpha1/secret-set HTTP/1.1\r\nContent-Length: {}\r\n\r\n{manifest}",
+            manifest.len()
+        ),
+    );
+    assert_eq!((status, &body["headers"]["generation"]), (201, &json!(1)));
+}
+
+#[test]
+fn test_server_http_reason_phrase() {
+    let addr = serve(load_repo_model());
+
+    let response = http_raw(
+        &addr,
+        "GET /config/v1alpha1/secret-set/none HTTP/1.1\r\n\r\n",
+    );
+    assert!(
+        response.starts_with("HTTP/1.1 404 Not Found\r\n"),
+        "{response}"
+    );
+}
+
+// Request line and headers are read up to a limit instead of buffering whatever the client sends
+#[test]
+fn test_server_http_head_size_limit() {
+    let addr = serve(load_repo_model());
+
+    let (status, body) = http(&addr, &format!("GET /{}", "a".repeat(MAX_HEAD_SIZE - 5)));
+    assert_eq!(status, 431);
+    assert_eq!(
+        body["message"],
+        format!(
+            "Request line and headers exceed the limit of {MAX_HEAD_SIZE} bytes and {MAX_HEADERS} headers"
+        )
+    );
+}
+
+#[test]
+fn test_server_http_header_count_limit() {
+    let addr = serve(load_repo_model());
+
+    let headers: String = (0..=MAX_HEADERS)
+        .map(|i| format!("X-Header-{i}: a\r\n"))
+        .collect();
+    let (status, _) = http(
+        &addr,
+        &format!("GET /config/v1alpha1/secret-set HTTP/1.1\r\n{headers}"),
+    );
+    assert_eq!(status, 431);
+}
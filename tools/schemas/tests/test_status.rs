@@ -0,0 +1,264 @@
+mod common;
+
+use common::*;
+use odf_schemas::json_schema::SchemaId;
+use odf_schemas::model::{Model, TypeId};
+use odf_schemas::status::{self, Condition, Phase};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+const WEBHOOK_TARGET_STATUS: &str =
+    "https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus";
+
+fn webhook_target() -> TypeId {
+    type_id("sink/v1alpha1/WebhookTarget")
+}
+
+fn errors(model: &Model, kind: &TypeId, resource: &Value) -> Vec<String> {
+    status::validate_status(model, kind, resource)
+        .unwrap()
+        .iter()
+        .map(ToString::to_string)
+        .collect()
+}
+
+fn failed() -> Condition {
+    Condition::new("Failed").reason("ConnectionRefused")
+}
+
+/// Resource that the controller has not observed yet
+fn pending() -> Value {
+    json!({
+        "$schema": "https://opendatafabric.org/schemas/resource/v1alpha1/Resource",
+        "headers": { "name": "hook", "generation": 1 },
+        "spec": { "url": "https://example.com/hook" }
+    })
+}
+
+/// Resource reconciled with a failed condition
+fn reconciled_failed() -> Value {
+    let mut resource = pending();
+    assert!(status::set_condition(
+        &mut resource,
+        WEBHOOK_TARGET_STATUS,
+        failed(),
+        "2026-01-01T00:00:00Z"
+    ));
+    status::mark_reconciled(&mut resource, "2026-01-01T00:00:01Z");
+    resource
+}
+
+/// Resource reconciled with a ready condition
+fn reconciled_ready() -> Value {
+    let mut resource = reconciled_failed();
+    assert!(status::set_condition(
+        &mut resource,
+        WEBHOOK_TARGET_STATUS,
+        Condition::new("Ready"),
+        "2026-01-01T00:03:00Z"
+    ));
+    resource
+}
+
+/// Resource whose status contradicts the schemas of its conditions and its generation
+fn invalid_status() -> Value {
+    json!({
+        "headers": { "name": "hook", "generation": 1 },
+        "status": {
+            "phase": "Ready",
+            "observedGeneration": 2,
+            "conditions": {
+                WEBHOOK_TARGET_STATUS: { "value": "Broken" },
+                "https://opendatafabric.org/schemas/sink/v1alpha1/Unknown": { "value": "Ready" },
+            }
+        }
+    })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_status_declared_conditions() {
+    let model = load_repo_model();
+
+    assert_eq!(
+        status::declared_conditions(&model, &webhook_target()),
+        [TypeId::new(SchemaId::new(WEBHOOK_TARGET_STATUS))]
+    );
+}
+
+// Resource without status is pending until the controller observes it
+#[test]
+fn test_status_pending() {
+    let mut resource = pending();
+    assert_eq!(status::derive_phase(&resource), Phase::Pending);
+    assert!(status::is_stale(&resource));
+
+    assert!(status::set_condition(
+        &mut resource,
+        WEBHOOK_TARGET_STATUS,
+        failed(),
+        "2026-01-01T00:00:00Z"
+    ));
+    assert_eq!(resource["status"]["phase"], "Pending");
+}
+
+#[test]
+fn test_status_reconciled() {
+    let resource = reconciled_failed();
+
+    assert!(!status::is_stale(&resource));
+    assert_eq!(
+        resource["status"],
+        json!({
+            "phase": "Failed",
+            "conditions": {
+                WEBHOOK_TARGET_STATUS: {
+                    "value": "Failed",
+                    "lastTransitionTime": "2026-01-01T00:00:00Z",
+                    "reason": "ConnectionRefused"
+                }
+            },
+            "observedGeneration": 1,
+            "reconciledAt": "2026-01-01T00:00:01Z"
+        })
+    );
+}
+
+// Transition time is kept while the value holds, even when details change
+#[test]
+fn test_status_transition_time_kept() {
+    let mut resource = reconciled_failed();
+
+    assert!(!status::set_condition(
+        &mut resource,
+        WEBHOOK_TARGET_STATUS,
+        failed(),
+        "2026-01-01T00:01:00Z"
+    ));
+    assert!(status::set_condition(
+        &mut resource,
+        WEBHOOK_TARGET_STATUS,
+        failed().message("Retrying in 1m"),
+        "2026-01-01T00:02:00Z"
+    ));
+    assert_eq!(
+        resource["status"]["conditions"][WEBHOOK_TARGET_STATUS]["lastTransitionTime"],
+        "2026-01-01T00:00:00Z"
+    );
+}
+
+// Flipping the value moves the transition time and the phase
+#[test]
+fn test_status_transition() {
+    let model = load_repo_model();
+    let resource = reconciled_ready();
+
+    assert_eq!(
+        resource["status"]["conditions"][WEBHOOK_TARGET_STATUS],
+        json!({ "value": "Ready", "lastTransitionTime": "2026-01-01T00:03:00Z" })
+    );
+    assert_eq!(resource["status"]["phase"], "Ready");
+    assert_eq!(
+        errors(&model, &webhook_target(), &resource),
+        Vec::<String>::new()
+    );
+}
+
+// A new generation is reconciling until observed, regardless of conditions
+#[test]
+fn test_status_new_generation() {
+    let model = load_repo_model();
+    let mut resource = reconciled_ready();
+
+    resource["headers"]["generation"] = json!(2);
+    assert!(status::is_stale(&resource));
+    assert_eq!(status::derive_phase(&resource), Phase::Reconciling);
+    assert_eq!(
+        errors(&model, &webhook_target(), &resource),
+        [
+            "warning: status.phase: Phase Ready does not match Reconciling derived from the generation and conditions"
+        ]
+    );
+}
+
+// Resource without conditions is ready once reconciled
+#[test]
+fn test_status_remove_condition() {
+    let mut resource = reconciled_failed();
+
+    assert!(status::remove_condition(
+        &mut resource,
+        WEBHOOK_TARGET_STATUS
+    ));
+    status::mark_reconciled(&mut resource, "2026-01-01T00:04:00Z");
+    assert_eq!(resource["status"]["phase"], "Ready");
+}
+
+// Conditions must be declared for the kind and conform to their schemas
+#[test]
+fn test_status_invalid_conditions() {
+    let model = load_repo_model();
+
+    assert_eq!(
+        errors(&model, &webhook_target(), &invalid_status()),
+        [
+            "error: status.observedGeneration: Observed generation 2 is ahead of the resource generation 1",
+            "error: status.conditions.https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus.value: Unknown value `Broken`, expected one of: Ready, Failed",
+            "error: status.conditions.https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus: Missing required property `lastTransitionTime`",
+            "error: status.conditions.https://opendatafabric.org/schemas/sink/v1alpha1/Unknown: Unknown condition type https://opendatafabric.org/schemas/sink/v1alpha1/Unknown",
+        ]
+    );
+}
+
+#[test]
+fn test_status_undeclared_condition() {
+    let model = load_repo_model();
+
+    let mut resource = invalid_status();
+    resource["status"]["conditions"] = json!({
+        WEBHOOK_TARGET_STATUS: { "value": "Ready", "lastTransitionTime": "2026-01-01T00:00:00Z" }
+    });
+    resource["status"]["observedGeneration"] = json!(1);
+    assert_eq!(
+        errors(&model, &type_id("dataset/v1alpha1/Dataset"), &resource),
+        [
+            "error: status.conditions.https://opendatafabric.org/schemas/sink/v1alpha1/WebhookTargetStatus: Condition WebhookTargetStatus is not declared for Dataset, Dataset does not declare any conditions"
+        ]
+    );
+}
+
+// Status of the example canonical resource is consistent
+#[test]
+fn test_status_example() {
+    let model = load_repo_model();
+
+    let example: Value = serde_yaml::from_str(
+        &std::fs::read_to_string(
+            repo_root().join("examples/sink-webhook-dataset-events/webhook-target-canonical.yaml"),
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(
+        errors(&model, &webhook_target(), &example),
+        Vec::<String>::new()
+    );
+    assert_eq!(status::derive_phase(&example), Phase::Failed);
+}
+
+#[test]
+fn test_status_not_resource() {
+    let model = load_repo_model();
+
+    assert_eq!(
+        status::validate_status(
+            &model,
+            &type_id("resource/v1alpha1/Resource"),
+            &invalid_status()
+        )
+        .unwrap_err(),
+        "https://opendatafabric.org/schemas/resource/v1alpha1/Resource is not a resource type"
+    );
+}
@@ -0,0 +1,360 @@
+mod common;
+
+use common::generated_crate::GeneratedCrate;
+use common::*;
+use odf_schemas::model::TypeDefinition;
+use odf_schemas::{codegen, validation};
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Both `Event` and `Color` preserve what they don't recognize, while `Level` stays strict
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": format!("{BASE}/Log"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Log.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "event", "color", "level"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "event": {
+                    "$ref": format!("{BASE}/Event"),
+                    "description": "Event.",
+                    "unevaluatedProperties": false
+                },
+                "color": {
+                    "$ref": format!("{BASE}/Color"),
+                    "description": "Color.",
+                    "unevaluatedProperties": false
+                },
+                "level": {
+                    "$ref": format!("{BASE}/Level"),
+                    "description": "Level.",
+                    "unevaluatedProperties": false
+                }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Event"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Event.",
+            "codegen": { "rust": { "unknownVariant": true } },
+            "$defs": {
+                "Created": {
+                    "description": "Created.",
+                    "type": "object",
+                    "required": ["name"],
+                    "properties": {
+                        "name": { "type": "string", "description": "Name." }
+                    }
+                },
+                "Deleted": {
+                    "description": "Deleted.",
+                    "type": "object",
+                    "required": [],
+                    "properties": {}
+                },
+            },
+            "oneOf": [tagged_variant("Created"), tagged_variant("Deleted")]
+        }),
+        json!({
+            "$id": format!("{BASE}/Color"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Color.",
+            "type": "string",
+            "format": "int32",
+            "codegen": { "rust": { "unknownVariant": true } },
+            "enum": ["Red", "Green"]
+        }),
+        json!({
+            "$id": format!("{BASE}/Level"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Level.",
+            "type": "string",
+            "format": "int32",
+            "enum": ["Info", "Error"]
+        }),
+    ]
+}
+
+fn messages(diagnostics: &[validation::Diagnostic]) -> Vec<String> {
+    diagnostics.iter().map(|d| d.to_string()).collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn test_unknown_variants_model() {
+    let model = load_model(fixture_schemas());
+
+    let Some(TypeDefinition::Union(event)) = model.types.get(&test_type_id("Event")) else {
+        panic!("Event is not a union");
+    };
+    assert!(event.has_unknown_variant());
+
+    let Some(TypeDefinition::Enum(color)) = model.types.get(&test_type_id("Color")) else {
+        panic!("Color is not an enum");
+    };
+    assert!(color.has_unknown_variant());
+
+    let Some(TypeDefinition::Enum(level)) = model.types.get(&test_type_id("Level")) else {
+        panic!("Level is not an enum");
+    };
+    assert!(!level.has_unknown_variant());
+}
+
+#[test]
+fn test_unknown_variants_rust_dtos() {
+    let model = load_model(fixture_schemas());
+
+    // Enums carrying raw values can no longer be `Copy`
+    let dtos = render(&model, codegen::rust_dtos::render);
+    assert!(
+        dtos.contains(
+            "pub enum Event {\nCreated(test::EventCreated),\nDeleted(test::EventDeleted),\nUnknown(UnknownVariant),\n}"
+        ),
+        "{dtos}"
+    );
+    assert!(
+        dtos.contains(
+            "#[derive(Clone, PartialEq, Eq, Debug)]\npub enum Color {\nRed,\nGreen,\nUnknown(UnknownEnumValue),\n}"
+        ),
+        "{dtos}"
+    );
+    assert!(
+        !dtos.contains("Unknown(UnknownEnumValue),\n}\n\n/// Level"),
+        "{dtos}"
+    );
+}
+
+#[test]
+fn test_unknown_variants_rust_serde() {
+    let model = load_model(fixture_schemas());
+
+    // Known tags are dispatched to the derived enum, anything else is kept as raw JSON
+    let serde = render(&model, codegen::rust_serde::render);
+    assert!(
+        serde.contains("pub enum Event {\nKnown(EventKnown),\nUnknown(dtos::UnknownVariant),\n}"),
+        "{serde}"
+    );
+    assert!(
+        serde.contains(
+            "const KNOWN_TAGS: &[&str] = &[\"Created\", \"Deleted\", \"created\", \"deleted\"];"
+        ),
+        "{serde}"
+    );
+    assert!(
+        serde.contains("#[serde(untagged)]\nUnknown(String),\n}\n\nimpl IntoDto for Color {"),
+        "{serde}"
+    );
+}
+
+#[test]
+fn test_unknown_variants_flatbuffers() {
+    let model = load_model(fixture_schemas());
+
+    // Flatbuffers keep the raw table of unknown variants instead of failing
+    let fb = render(&model, codegen::rust_serde_flatbuffers::render);
+    assert!(
+        fb.contains("_ => unknown_variant_from_table(table, t.0).map(odf::test::Event::Unknown),"),
+        "{fb}"
+    );
+    assert!(
+        fb.contains(
+            "_ => Ok(odf::test::Color::Unknown(odf::dtos::UnknownEnumValue::Flatbuffers(v.0 as i64))),"
+        ),
+        "{fb}"
+    );
+}
+
+#[test]
+fn test_unknown_variants_markdown() {
+    let model = load_model(fixture_schemas());
+
+    let md = render(&model, codegen::markdown::render);
+    assert!(
+        md.contains("Readers must preserve variants they don't recognize"),
+        "{md}"
+    );
+}
+
+#[test]
+fn test_unknown_variants_validation() {
+    let model = load_model(fixture_schemas());
+
+    // Unrecognized tags and values are only reported for hinted types
+    let log = test_type_id("Log");
+    let manifest = json!({
+        "$schema": format!("{BASE}/Log"),
+        "event": { "kind": "Renamed", "to": "bar" },
+        "color": "Blue",
+        "level": "Debug"
+    });
+    assert_eq!(
+        messages(&validation::validate(&model, &log, &manifest)),
+        [
+            "warning: event.kind: Unknown variant `Renamed` of Event will be preserved as is",
+            "warning: color: Unknown value `Blue` of Color will be preserved as is",
+            "error: level: Unknown value `Debug`, expected one of: Info, Error",
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_variants_untagged() {
+    let mut schemas = fixture_schemas();
+    schemas[1]["discriminator"] = json!({ "untagged": true });
+    schemas[1]["oneOf"] = json!([
+        { "$ref": "#/$defs/Created" },
+        { "$ref": "#/$defs/Deleted" },
+    ]);
+
+    let err = std::panic::catch_unwind(|| load_model(schemas)).unwrap_err();
+    assert_eq!(
+        err.downcast_ref::<String>().unwrap(),
+        "Untagged unions can't have an unknown variant: Event"
+    );
+}
+
+#[test]
+fn test_unknown_variants_flatbuffers_round_trip() {
+    let model = load_model(fixture_schemas());
+    let Some(krate) = GeneratedCrate::new(&model) else {
+        return;
+    };
+
+    krate.add_test("round_trip", ROUND_TRIP_TEST);
+    krate.test();
+}
+
+/// Exercises generated convertors with a buffer written by a newer version of the schemas, where `Event` has a third
+/// variant and `Color` a third value
+const ROUND_TRIP_TEST: &str = r#"
+use flatbuffers::{FlatBufferBuilder, ForwardsUOffset, Table};
+use odf::serde::flatbuffers::convertors_generated::*;
+use odf::serde::flatbuffers::proxies_generated as fb;
+
+/// Unknown variant is a table with a string, a nested table and a scalar
+fn newer_log() -> Vec<u8> {
+    let mut fb = FlatBufferBuilder::new();
+    let schema = fb.create_string("https://opendatafabric.org/schemas/test/v1alpha1/Log");
+
+    let inner_name = fb.create_string("inner");
+    let start = fb.start_table();
+    fb.push_slot_always(4, inner_name);
+    let inner = fb.end_table(start);
+
+    let name = fb.create_string("renamed");
+    let start = fb.start_table();
+    fb.push_slot_always(4, name);
+    fb.push_slot_always(6, inner);
+    fb.push_slot::<u64>(8, 42, 0);
+    let event = fb.end_table(start);
+
+    let mut builder = fb::LogBuilder::new(&mut fb);
+    builder.add_schema(schema);
+    builder.add_event_type(fb::Event(3));
+    builder.add_event(event.as_union_value());
+    builder.add_color(fb::Color(2));
+    builder.add_level(fb::Level::Error);
+    let log = builder.finish();
+    fb.finish(log, None);
+    fb.finished_data().to_vec()
+}
+
+fn decode(buf: &[u8]) -> odf::test::Log {
+    deserialize_verified::<fb::Log, odf::test::Log>(buf, &FlatbuffersDecodeLimits::default()).unwrap()
+}
+
+fn encode(log: &odf::test::Log) -> Result<Vec<u8>, FlatbuffersEncodeError> {
+    let mut fb = FlatBufferBuilder::new();
+    let offset = log.serialize(&mut fb)?;
+    fb.finish(offset, None);
+    Ok(fb.finished_data().to_vec())
+}
+
+/// Reads the unknown variant the way the newer version would
+fn read_unknown(buf: &[u8]) -> (u8, String, String, u64, i32) {
+    let log = flatbuffers::root::<fb::Log>(buf).unwrap();
+    let table = log.event().unwrap();
+    unsafe {
+        let inner = table.get::<ForwardsUOffset<Table>>(6, None).unwrap();
+        (
+            log.event_type().0,
+            table.get::<ForwardsUOffset<&str>>(4, None).unwrap().to_string(),
+            inner.get::<ForwardsUOffset<&str>>(4, None).unwrap().to_string(),
+            table.get::<u64>(8, Some(0)).unwrap(),
+            log.color().0,
+        )
+    }
+}
+
+#[test]
+fn unknown_variant_round_trip() {
+    let log = decode(&newer_log());
+    assert!(
+        matches!(
+            &log.event,
+            odf::test::Event::Unknown(odf::dtos::UnknownVariant::Flatbuffers { tag: 3, .. })
+        ),
+        "{:?}",
+        log.event
+    );
+    assert_eq!(
+        log.color,
+        odf::test::Color::Unknown(odf::dtos::UnknownEnumValue::Flatbuffers(2))
+    );
+    assert_eq!(log.level, odf::test::Level::Error);
+
+    // Unknowns are written back as is, so the newer version can still read them after any number of round trips
+    let expected = (3, "renamed".to_string(), "inner".to_string(), 42, 2);
+    let mut buf = encode(&log).unwrap();
+    for _ in 0..3 {
+        assert_eq!(read_unknown(&buf), expected);
+
+        let decoded = decode(&buf);
+        assert_eq!(decoded.schema, log.schema);
+        assert_eq!(decoded.color, log.color);
+        assert_eq!(decoded.level, log.level);
+        buf = encode(&decoded).unwrap();
+    }
+}
+
+#[test]
+fn unknown_variant_from_manifest_is_not_encodable() {
+    let mut log = decode(&newer_log());
+    log.event = odf::test::Event::Unknown(odf::dtos::UnknownVariant::Json {
+        tag: "Renamed".to_string(),
+        value: serde_json::json!({ "kind": "Renamed", "to": "bar" }),
+    });
+    assert_eq!(
+        encode(&log).unwrap_err().to_string(),
+        "Value can't be encoded as flatbuffers: Unknown variant Renamed of Event was read from a manifest and has no \
+         flatbuffers representation"
+    );
+
+    let mut log = decode(&newer_log());
+    log.color = odf::test::Color::Unknown(odf::dtos::UnknownEnumValue::Json("Blue".to_string()));
+    assert!(encode(&log).is_err());
+}
+
+#[test]
+fn unknown_variant_out_of_bounds() {
+    // Verifier doesn't look into tables of unknown variants, so a broken one is reported while decoding
+    let mut buf = newer_log();
+    let log = flatbuffers::root::<fb::Log>(&buf).unwrap();
+    let loc = log.event().unwrap().loc();
+    buf[loc..loc + 4].copy_from_slice(&i32::MIN.to_le_bytes());
+
+    let err = deserialize_verified::<fb::Log, odf::test::Log>(&buf, &FlatbuffersDecodeLimits::default())
+        .unwrap_err();
+    assert!(err.to_string().contains("Table of unknown variant 3 is out of bounds"), "{err}");
+}
+"#;
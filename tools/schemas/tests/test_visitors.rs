@@ -0,0 +1,249 @@
+mod common;
+
+use common::generated_crate::GeneratedCrate;
+use common::*;
+use odf_schemas::codegen;
+use serde_json::{Value, json};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// `Job` nests references in optional arrays, a union with unknown variants, an enum, a map, and a generic spec
+fn fixture_schemas() -> Vec<Value> {
+    vec![
+        json!({
+            "$id": format!("{BASE}/Job"),
+            "$schema": METASCHEMA_MANIFEST,
+            "description": "Job.",
+            "type": "object",
+            "unevaluatedProperties": false,
+            "required": ["$schema", "target", "step", "spec"],
+            "properties": {
+                "$schema": {
+                    "type": "string",
+                    "format": "type-uri",
+                    "description": "Schema."
+                },
+                "target": { "type": "string", "format": "dataset-ref", "description": "Target." },
+                "inputs": {
+                    "type": "array",
+                    "items": {
+                        "type": "array",
+                        "items": { "type": "string", "format": "dataset-ref" }
+                    },
+                    "description": "Inputs."
+                },
+                "step": {
+                    "$ref": format!("{BASE}/Step"),
+                    "description": "Step.",
+                    "unevaluatedProperties": false
+                },
+                "policy": {
+                    "$ref": format!("{BASE}/Policy"),
+                    "description": "Policy.",
+                    "unevaluatedProperties": false
+                },
+                "labels": {
+                    "$ref": format!("{BASE}/Labels"),
+                    "description": "Labels.",
+                    "unevaluatedProperties": false
+                },
+                "spec": {
+                    "type": "object",
+                    "format": "fragment",
+                    "description": "Spec."
+                }
+            }
+        }),
+        json!({
+            "$id": format!("{BASE}/Step"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Step.",
+            "codegen": { "rust": { "unknownVariant": true } },
+            "$defs": {
+                "Fetch": {
+                    "description": "Fetch.",
+                    "type": "object",
+                    "required": ["hash"],
+                    "properties": {
+                        "hash": { "type": "string", "format": "multihash", "description": "Hash." }
+                    }
+                },
+                "Skip": {
+                    "description": "Skip.",
+                    "type": "object",
+                    "required": [],
+                    "properties": {}
+                }
+            },
+            "oneOf": [
+                tagged_variant("Fetch"),
+                tagged_variant("Skip")
+            ]
+        }),
+        json!({
+            "$id": format!("{BASE}/Policy"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Policy.",
+            "type": "string",
+            "format": "int32",
+            "enum": ["Reject", "DropOldest"]
+        }),
+        json!({
+            "$id": format!("{BASE}/Labels"),
+            "$schema": METASCHEMA_JSONSCHEMA,
+            "description": "Labels.",
+            "type": "object",
+            "patternProperties": {
+                ".*": {}
+            }
+        }),
+    ]
+}
+
+const VISITORS_TEST: &str = r#"
+use std::collections::BTreeMap;
+
+use odf::dataset::DatasetRef;
+use odf::dtos::UnknownVariant;
+use odf::dtos::visit::{Visit, VisitMut, Visitable};
+use odf::resource::TypeUri;
+use odf::test::{Job, Labels, Policy, Step, StepFetch, StepSkip};
+
+fn job<SpecT>(step: Step, spec: SpecT) -> Job<SpecT> {
+    Job {
+        schema: TypeUri::new_unchecked("https://example.com/Job"),
+        target: DatasetRef::new_unchecked("target"),
+        inputs: Some(vec![
+            vec![DatasetRef::new_unchecked("a"), DatasetRef::new_unchecked("b")],
+            vec![DatasetRef::new_unchecked("c")],
+        ]),
+        step,
+        policy: Some(Policy::DropOldest),
+        labels: Some(Labels {
+            entries: BTreeMap::from([("team".to_string(), serde_json::json!("data"))]),
+        }),
+        spec,
+    }
+}
+
+fn fetch() -> Step {
+    Step::Fetch(StepFetch {
+        hash: odf::Multihash::from_digest_sha3_256(b"data"),
+    })
+}
+
+#[derive(Default)]
+struct Collector {
+    refs: Vec<String>,
+    hashes: usize,
+    json: Vec<serde_json::Value>,
+    policies: Vec<Policy>,
+    skips: usize,
+}
+
+impl Visit for Collector {
+    fn visit_dataset_ref(&mut self, v: &DatasetRef) {
+        self.refs.push(v.to_string());
+    }
+    fn visit_multihash(&mut self, _v: &odf::Multihash) {
+        self.hashes += 1;
+    }
+    fn visit_any_json(&mut self, v: &serde_json::Value) {
+        self.json.push(v.clone());
+    }
+    fn visit_test_policy(&mut self, v: &Policy) {
+        self.policies.push(v.clone());
+    }
+    fn visit_test_step_skip(&mut self, _v: &StepSkip) {
+        self.skips += 1;
+    }
+}
+
+struct Renamer;
+
+impl VisitMut for Renamer {
+    fn visit_dataset_ref_mut(&mut self, v: &mut DatasetRef) {
+        *v = DatasetRef::new_unchecked(&format!("renamed-{v}"));
+    }
+}
+
+#[test]
+fn visit_walks_all_fields() {
+    let mut collector = Collector::default();
+    job(fetch(), Policy::Reject).accept(&mut collector);
+
+    assert_eq!(collector.refs, ["target", "a", "b", "c"]);
+    assert_eq!(collector.hashes, 1);
+    assert_eq!(collector.json, [serde_json::json!("data")]);
+
+    // Type parameters dispatch into the visitor method of their type
+    assert_eq!(collector.policies, [Policy::DropOldest, Policy::Reject]);
+}
+
+#[test]
+fn visit_dispatches_union_variants() {
+    let mut collector = Collector::default();
+    job(Step::Skip(StepSkip {}), Policy::Reject).accept(&mut collector);
+    assert_eq!(collector.skips, 1);
+    assert_eq!(collector.hashes, 0);
+
+    let unknown = Step::Unknown(UnknownVariant::Json {
+        tag: "Retry".to_string(),
+        value: serde_json::json!({ "kind": "Retry" }),
+    });
+    let mut collector = Collector::default();
+    job(unknown, Policy::Reject).accept(&mut collector);
+    assert_eq!(collector.skips, 0);
+    assert_eq!(collector.hashes, 0);
+}
+
+#[test]
+fn visit_mut_rewrites_values() {
+    let mut value = job(fetch(), Policy::Reject);
+    value.accept_mut(&mut Renamer);
+
+    assert_eq!(value.target, DatasetRef::new_unchecked("renamed-target"));
+    assert_eq!(
+        value.inputs.unwrap()[1],
+        [DatasetRef::new_unchecked("renamed-c")]
+    );
+}
+"#;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Visitors reach values in optional fields, nested arrays, union variants, map values and type parameters, and
+// mutable visitors can rewrite them
+#[test]
+fn test_visitors() {
+    let krate = GeneratedCrate::new_dtos(&load_model(fixture_schemas()));
+    krate.add_test("visitors", VISITORS_TEST);
+    krate.test();
+}
+
+// Scalar kinds are leaves with a method each, even when they share a Rust type
+#[test]
+fn test_visitors_scalar_kinds() {
+    let code = render(&load_model(fixture_schemas()), codegen::rust_dtos::render);
+
+    assert!(
+        code.contains("fn visit_url(&mut self, _v: &String) {}"),
+        "{code}"
+    );
+    assert!(
+        code.contains("fn visit_regex(&mut self, _v: &String) {}"),
+        "{code}"
+    );
+}
+
+// Structs without fields have nothing to walk
+#[test]
+fn test_visitors_empty_structs() {
+    let code = render(&load_model(fixture_schemas()), codegen::rust_dtos::render);
+
+    assert!(
+        code.contains("fn visit_test_step_skip(&mut self, _v: &test::StepSkip) {}"),
+        "{code}"
+    );
+    assert!(!code.contains("fn walk_test_step_skip"), "{code}");
+}